@@ -0,0 +1,91 @@
+//! Verification of [PEP 740](https://peps.python.org/pep-0740/) attestations for distributions
+//! published to a package index.
+//!
+//! An index that supports PEP 740 serves a provenance object alongside each file, containing one
+//! or more Sigstore attestations grouped by the trusted publisher that produced them. Each
+//! attestation is verified against a Sigstore trusted root: the signing certificate must chain to
+//! a trusted certificate authority, the attestation must be recorded in a trusted transparency log,
+//! the DSSE envelope must be signed by the certificate, and the certificate's identity must match
+//! the publisher. Since the index controls the provenance it serves, the publisher can also be
+//! pinned per package via an [`AttestationPolicy`].
+
+use std::path::PathBuf;
+
+use thiserror::Error;
+
+use uv_fs::Simplified;
+
+pub use crate::policy::{AttestationPolicy, TrustedPublisher, TrustedPublisherError};
+pub use crate::provenance::{Attestation, AttestationBundle, Provenance, Publisher};
+pub use crate::trusted_root::TrustedRoot;
+pub use crate::verify::verify;
+
+mod policy;
+mod provenance;
+#[cfg(any(test, feature = "test"))]
+pub mod testing;
+mod trusted_root;
+mod verify;
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("Failed to read Sigstore trusted root: `{}`", _0.user_display())]
+    ReadTrustedRoot(PathBuf, #[source] std::io::Error),
+
+    #[error("Failed to parse Sigstore trusted root")]
+    ParseTrustedRoot(#[source] serde_json::Error),
+
+    #[error("Failed to parse provenance")]
+    ParseProvenance(#[source] serde_json::Error),
+
+    #[error("Unsupported provenance version: {0}")]
+    UnsupportedVersion(u8),
+
+    #[error("The provenance does not include any attestations")]
+    NoAttestations,
+
+    #[error(
+        "Unsupported trusted publisher; only GitHub, GitLab, and Google publishers are supported"
+    )]
+    UnsupportedPublisher,
+
+    #[error("Failed to parse the signing certificate")]
+    InvalidCertificate,
+
+    #[error("The signing certificate does not chain to a trusted Sigstore root: {0}")]
+    UntrustedCertificate(webpki::Error),
+
+    #[error("The attestation is not recorded in a trusted transparency log: {0}")]
+    TransparencyLog(&'static str),
+
+    #[error("Failed to serialize the transparency log entry")]
+    SerializeEntry(#[source] serde_json::Error),
+
+    #[error("The attestation signature is invalid")]
+    InvalidSignature,
+
+    #[error("Failed to parse the attestation statement")]
+    ParseStatement(#[source] serde_json::Error),
+
+    #[error("The attestation statement is malformed: {0}")]
+    InvalidStatement(&'static str),
+
+    #[error("Unsupported attestation predicate type: `{0}`")]
+    UnsupportedPredicate(String),
+
+    #[error("The attestation is for `{attested}`, but the distribution is `{expected}`")]
+    SubjectMismatch { attested: String, expected: String },
+
+    #[error("The attestations for `{0}` attest to different digests")]
+    ConflictingDigests(String),
+
+    #[error(
+        "The signing certificate identity `{identity}` does not match the trusted publisher: {publisher}"
+    )]
+    PublisherMismatch { identity: String, publisher: String },
+
+    #[error(
+        "The attestations were published by {publisher}, which is not a trusted publisher for the package (expected: {expected})"
+    )]
+    UntrustedPublisher { publisher: String, expected: String },
+}
@@ -0,0 +1,290 @@
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use std::sync::Arc;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use uv_normalize::PackageName;
+
+use crate::Publisher;
+
+/// The user's policy for verifying attestations, as read from user-level configuration.
+///
+/// An index controls the provenance that it serves, including the publisher named in each
+/// attestation bundle, so verifying an attestation against its own publisher only proves that
+/// _someone_ with a Sigstore identity published the file. Pinning the expected publishers for a
+/// package ensures that the file was published by a trusted identity.
+#[derive(Debug, Clone, Default)]
+pub struct AttestationPolicy {
+    /// Whether registry distributions without attestations should be rejected.
+    required: bool,
+    /// The publishers that are trusted to publish each package.
+    trusted_publishers: Arc<BTreeMap<PackageName, Vec<TrustedPublisher>>>,
+}
+
+impl AttestationPolicy {
+    /// Create an [`AttestationPolicy`].
+    pub fn new(
+        required: bool,
+        trusted_publishers: BTreeMap<PackageName, Vec<TrustedPublisher>>,
+    ) -> Self {
+        Self {
+            required,
+            trusted_publishers: Arc::new(trusted_publishers),
+        }
+    }
+
+    /// Returns `true` if registry distributions must include attestations.
+    pub fn is_required(&self) -> bool {
+        self.required
+    }
+
+    /// Return the publishers that are trusted to publish the given package, if pinned.
+    pub fn trusted_publishers(&self, name: &PackageName) -> &[TrustedPublisher] {
+        self.trusted_publishers
+            .get(name)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+}
+
+/// A trusted publisher pinned in user configuration, e.g., `github:pallets/flask` or
+/// `github:pallets/flask@publish.yaml`.
+///
+/// If the workflow is omitted, any workflow in the repository is trusted.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum TrustedPublisher {
+    GitHub {
+        repository: String,
+        workflow: Option<String>,
+    },
+    GitLab {
+        repository: String,
+        workflow_filepath: Option<String>,
+    },
+    Google {
+        email: String,
+    },
+}
+
+impl TrustedPublisher {
+    /// Returns `true` if the [`Publisher`] named in an attestation bundle matches this pinned
+    /// publisher.
+    pub(crate) fn matches(&self, publisher: &Publisher) -> bool {
+        match (self, publisher) {
+            (
+                Self::GitHub {
+                    repository,
+                    workflow,
+                },
+                Publisher::GitHub {
+                    repository: attested_repository,
+                    workflow: attested_workflow,
+                },
+            ) => {
+                repository.eq_ignore_ascii_case(attested_repository)
+                    && workflow
+                        .as_ref()
+                        .is_none_or(|workflow| workflow == attested_workflow)
+            }
+            (
+                Self::GitLab {
+                    repository,
+                    workflow_filepath,
+                },
+                Publisher::GitLab {
+                    repository: attested_repository,
+                    workflow_filepath: attested_workflow_filepath,
+                },
+            ) => {
+                repository.eq_ignore_ascii_case(attested_repository)
+                    && workflow_filepath
+                        .as_ref()
+                        .is_none_or(|workflow| workflow == attested_workflow_filepath)
+            }
+            (
+                Self::Google { email },
+                Publisher::Google {
+                    email: attested_email,
+                },
+            ) => email == attested_email,
+            _ => false,
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum TrustedPublisherError {
+    #[error(
+        "Invalid trusted publisher `{0}`; expected `github:<owner>/<repository>[@<workflow>]`, `gitlab:<namespace>/<project>[@<workflow>]`, or `google:<email>`"
+    )]
+    InvalidFormat(String),
+}
+
+impl FromStr for TrustedPublisher {
+    type Err = TrustedPublisherError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || TrustedPublisherError::InvalidFormat(s.to_string());
+        let (kind, identity) = s.split_once(':').ok_or_else(invalid)?;
+        if identity.is_empty() {
+            return Err(invalid());
+        }
+        match kind {
+            "github" => {
+                let (repository, workflow) = match identity.split_once('@') {
+                    Some((repository, workflow)) if !workflow.is_empty() => {
+                        (repository, Some(workflow.to_string()))
+                    }
+                    Some(_) => return Err(invalid()),
+                    None => (identity, None),
+                };
+                if repository
+                    .split('/')
+                    .filter(|part| !part.is_empty())
+                    .count()
+                    != 2
+                {
+                    return Err(invalid());
+                }
+                Ok(Self::GitHub {
+                    repository: repository.to_string(),
+                    workflow,
+                })
+            }
+            "gitlab" => {
+                let (repository, workflow_filepath) = match identity.split_once('@') {
+                    Some((repository, workflow)) if !workflow.is_empty() => {
+                        (repository, Some(workflow.to_string()))
+                    }
+                    Some(_) => return Err(invalid()),
+                    None => (identity, None),
+                };
+                if !repository.contains('/') {
+                    return Err(invalid());
+                }
+                Ok(Self::GitLab {
+                    repository: repository.to_string(),
+                    workflow_filepath,
+                })
+            }
+            "google" => Ok(Self::Google {
+                email: identity.to_string(),
+            }),
+            _ => Err(invalid()),
+        }
+    }
+}
+
+impl Display for TrustedPublisher {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::GitHub {
+                repository,
+                workflow,
+            } => {
+                write!(f, "github:{repository}")?;
+                if let Some(workflow) = workflow {
+                    write!(f, "@{workflow}")?;
+                }
+                Ok(())
+            }
+            Self::GitLab {
+                repository,
+                workflow_filepath,
+            } => {
+                write!(f, "gitlab:{repository}")?;
+                if let Some(workflow_filepath) = workflow_filepath {
+                    write!(f, "@{workflow_filepath}")?;
+                }
+                Ok(())
+            }
+            Self::Google { email } => write!(f, "google:{email}"),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for TrustedPublisher {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Self::from_str(&s).map_err(serde::de::Error::custom)
+    }
+}
+
+impl Serialize for TrustedPublisher {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for TrustedPublisher {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        std::borrow::Cow::Borrowed("TrustedPublisher")
+    }
+
+    fn json_schema(_generator: &mut schemars::generate::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "type": "string",
+            "description": "A trusted publisher, e.g., `github:<owner>/<repository>[@<workflow>]`, `gitlab:<namespace>/<project>[@<workflow>]`, or `google:<email>`."
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TrustedPublisher;
+
+    #[test]
+    fn parse() {
+        assert_eq!(
+            "github:pallets/flask".parse::<TrustedPublisher>().unwrap(),
+            TrustedPublisher::GitHub {
+                repository: "pallets/flask".to_string(),
+                workflow: None,
+            }
+        );
+        assert_eq!(
+            "github:pallets/flask@publish.yaml"
+                .parse::<TrustedPublisher>()
+                .unwrap(),
+            TrustedPublisher::GitHub {
+                repository: "pallets/flask".to_string(),
+                workflow: Some("publish.yaml".to_string()),
+            }
+        );
+        assert_eq!(
+            "gitlab:group/subgroup/project@ci/release.yml"
+                .parse::<TrustedPublisher>()
+                .unwrap(),
+            TrustedPublisher::GitLab {
+                repository: "group/subgroup/project".to_string(),
+                workflow_filepath: Some("ci/release.yml".to_string()),
+            }
+        );
+        assert_eq!(
+            "google:release@example.iam.gserviceaccount.com"
+                .parse::<TrustedPublisher>()
+                .unwrap(),
+            TrustedPublisher::Google {
+                email: "release@example.iam.gserviceaccount.com".to_string(),
+            }
+        );
+
+        assert!("pallets/flask".parse::<TrustedPublisher>().is_err());
+        assert!("github:flask".parse::<TrustedPublisher>().is_err());
+        assert!("github:pallets/flask@".parse::<TrustedPublisher>().is_err());
+        assert!(
+            "bitbucket:pallets/flask"
+                .parse::<TrustedPublisher>()
+                .is_err()
+        );
+    }
+}
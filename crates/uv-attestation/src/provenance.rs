@@ -0,0 +1,177 @@
+use std::fmt::{Display, Formatter};
+
+use base64::Engine;
+use base64::prelude::BASE64_STANDARD;
+use serde::{Deserialize, Deserializer};
+
+use crate::Error;
+
+/// A [PEP 740](https://peps.python.org/pep-0740/#provenance-objects) provenance object, as served
+/// by the index for a single file.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Provenance {
+    pub version: u8,
+    pub attestation_bundles: Vec<AttestationBundle>,
+}
+
+impl Provenance {
+    /// Parse a [`Provenance`] from its JSON representation.
+    pub fn from_slice(bytes: &[u8]) -> Result<Self, Error> {
+        let provenance: Self = serde_json::from_slice(bytes).map_err(Error::ParseProvenance)?;
+        if provenance.version != 1 {
+            return Err(Error::UnsupportedVersion(provenance.version));
+        }
+        Ok(provenance)
+    }
+}
+
+/// A set of attestations produced by a single trusted publisher.
+#[derive(Debug, Clone, Deserialize)]
+pub struct AttestationBundle {
+    pub publisher: Publisher,
+    pub attestations: Vec<Attestation>,
+}
+
+/// The trusted publisher that produced a set of attestations.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "kind")]
+pub enum Publisher {
+    GitHub {
+        /// The repository, as `owner/name`.
+        repository: String,
+        /// The filename of the workflow, e.g., `release.yml`.
+        workflow: String,
+    },
+    GitLab {
+        /// The project, as `namespace/name`.
+        repository: String,
+        /// The path of the CI configuration file within the project.
+        workflow_filepath: String,
+    },
+    Google {
+        /// The email address of the service account.
+        email: String,
+    },
+    #[serde(other)]
+    Unknown,
+}
+
+impl Display for Publisher {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::GitHub {
+                repository,
+                workflow,
+            } => write!(
+                f,
+                "GitHub repository `{repository}` (workflow `{workflow}`)"
+            ),
+            Self::GitLab {
+                repository,
+                workflow_filepath,
+            } => write!(
+                f,
+                "GitLab project `{repository}` (workflow `{workflow_filepath}`)"
+            ),
+            Self::Google { email } => write!(f, "Google account `{email}`"),
+            Self::Unknown => write!(f, "unknown publisher"),
+        }
+    }
+}
+
+/// A single PEP 740 attestation: a DSSE envelope containing an in-toto statement, along with the
+/// material needed to verify its signature.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Attestation {
+    pub version: u8,
+    pub verification_material: VerificationMaterial,
+    pub envelope: Envelope,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct VerificationMaterial {
+    /// The DER-encoded signing certificate.
+    pub certificate: Base64,
+    pub transparency_entries: Vec<TransparencyEntry>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Envelope {
+    /// The serialized in-toto statement.
+    pub statement: Base64,
+    /// The signature over the statement's DSSE pre-authentication encoding.
+    pub signature: Base64,
+}
+
+/// A Rekor transparency log entry, in the JSON encoding of Sigstore's `TransparencyLogEntry`.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransparencyEntry {
+    #[serde(deserialize_with = "deserialize_int")]
+    pub log_index: i64,
+    pub log_id: LogId,
+    pub kind_version: KindVersion,
+    #[serde(deserialize_with = "deserialize_int")]
+    pub integrated_time: i64,
+    pub inclusion_promise: Option<InclusionPromise>,
+    pub canonicalized_body: Base64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LogId {
+    pub key_id: Base64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct KindVersion {
+    pub kind: String,
+    pub version: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InclusionPromise {
+    pub signed_entry_timestamp: Base64,
+}
+
+/// Bytes encoded as standard, padded base64.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Base64(pub Vec<u8>);
+
+impl AsRef<[u8]> for Base64 {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl<'de> Deserialize<'de> for Base64 {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let encoded = String::deserialize(deserializer)?;
+        BASE64_STANDARD
+            .decode(encoded)
+            .map(Self)
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+/// Deserialize a 64-bit integer, which the Protobuf JSON mapping encodes as a string.
+fn deserialize_int<'de, D>(deserializer: D) -> Result<i64, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Int {
+        Number(i64),
+        String(String),
+    }
+
+    match Int::deserialize(deserializer)? {
+        Int::Number(value) => Ok(value),
+        Int::String(value) => value.parse().map_err(serde::de::Error::custom),
+    }
+}
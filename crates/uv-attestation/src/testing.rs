@@ -0,0 +1,244 @@
+//! A self-contained Sigstore certificate authority and transparency log, for testing the
+//! verification of attestations without network access.
+
+use base64::Engine;
+use base64::prelude::BASE64_STANDARD;
+use rcgen::{
+    BasicConstraints, CertificateParams, CustomExtension, ExtendedKeyUsagePurpose, IsCa, Issuer,
+    KeyPair, KeyUsagePurpose, PublicKeyData, date_time_ymd,
+};
+use ring::rand::SystemRandom;
+use ring::signature::{ECDSA_P256_SHA256_ASN1_SIGNING, EcdsaKeyPair};
+use serde_json::json;
+use sha2::{Digest, Sha256};
+
+pub use rcgen::{Certificate, KeyPair as SigningKey};
+
+use crate::verify::{IN_TOTO_STATEMENT_TYPE, SignedEntry, pae};
+
+/// The GitHub repository to which [`Sigstore::issue_github`] issues certificates.
+pub const REPOSITORY: &str = "astral-sh/example";
+
+/// 2024-01-01T12:00:00Z, within the validity of the signing certificates.
+const INTEGRATED_TIME: i64 = 1_704_110_400;
+
+/// A certificate authority and transparency log, along with a trusted root that trusts them.
+pub struct Sigstore {
+    ca: Issuer<'static, KeyPair>,
+    ca_certificate: Certificate,
+    log: KeyPair,
+}
+
+impl Sigstore {
+    /// Create a new certificate authority and transparency log.
+    pub fn new() -> Self {
+        let mut params = CertificateParams::default();
+        params.is_ca = IsCa::Ca(BasicConstraints::Unconstrained);
+        params.key_usages = vec![KeyUsagePurpose::KeyCertSign];
+        let key = KeyPair::generate().unwrap();
+        let ca_certificate = params.self_signed(&key).unwrap();
+        Self {
+            ca: Issuer::new(params, key),
+            ca_certificate,
+            log: KeyPair::generate().unwrap(),
+        }
+    }
+
+    /// Return a `trusted_root.json` that trusts this certificate authority and transparency log.
+    pub fn trusted_root(&self) -> Vec<u8> {
+        trusted_root(&self.ca_certificate, &self.log)
+    }
+
+    /// Return a `trusted_root.json` that trusts this certificate authority, and the transparency
+    /// log of `other`.
+    pub fn trusted_root_with_log(&self, other: &Self) -> Vec<u8> {
+        trusted_root(&self.ca_certificate, &other.log)
+    }
+
+    /// Return a `trusted_root.json` that trusts the certificate authority of `other`, and this
+    /// transparency log.
+    pub fn trusted_root_with_ca(&self, other: &Self) -> Vec<u8> {
+        trusted_root(&other.ca_certificate, &self.log)
+    }
+
+    /// Issue a code signing certificate with the given Fulcio extensions.
+    pub fn issue(&self, extensions: &[(&[u64], &str)]) -> (Certificate, KeyPair) {
+        let mut params = CertificateParams::default();
+        params.not_before = date_time_ymd(2024, 1, 1);
+        params.not_after = date_time_ymd(2024, 1, 2);
+        params.extended_key_usages = vec![ExtendedKeyUsagePurpose::CodeSigning];
+        params.custom_extensions = extensions
+            .iter()
+            .map(|(oid, value)| {
+                let mut content = vec![0x0c, u8::try_from(value.len()).unwrap()];
+                content.extend_from_slice(value.as_bytes());
+                CustomExtension::from_oid_content(oid, content)
+            })
+            .collect();
+        let key = KeyPair::generate().unwrap();
+        let certificate = params.signed_by(&key, &self.ca).unwrap();
+        (certificate, key)
+    }
+
+    /// Issue a certificate to a GitHub Actions workflow in [`REPOSITORY`].
+    pub fn issue_github(&self, workflow: &str) -> (Certificate, KeyPair) {
+        self.issue(&[
+            (
+                &[1, 3, 6, 1, 4, 1, 57264, 1, 8],
+                "https://token.actions.githubusercontent.com",
+            ),
+            (
+                &[1, 3, 6, 1, 4, 1, 57264, 1, 9],
+                &format!(
+                    "https://github.com/{REPOSITORY}/.github/workflows/{workflow}@refs/tags/v1.0.0"
+                ),
+            ),
+            (
+                &[1, 3, 6, 1, 4, 1, 57264, 1, 12],
+                &format!("https://github.com/{REPOSITORY}"),
+            ),
+        ])
+    }
+
+    /// Create a provenance object with a single attestation that `subject` has the SHA-256
+    /// `digest`, signed by `signer` and recorded in the transparency log.
+    pub fn provenance(
+        &self,
+        publisher: &serde_json::Value,
+        certificate: &Certificate,
+        signer: &KeyPair,
+        subject: &str,
+        digest: &str,
+    ) -> Vec<u8> {
+        self.provenance_with_predicate(
+            publisher,
+            certificate,
+            signer,
+            subject,
+            digest,
+            "https://docs.pypi.org/attestations/publish/v1",
+        )
+    }
+
+    /// Create a provenance object as in [`Sigstore::provenance`], with a statement of the given
+    /// predicate type.
+    pub fn provenance_with_predicate(
+        &self,
+        publisher: &serde_json::Value,
+        certificate: &Certificate,
+        signer: &KeyPair,
+        subject: &str,
+        digest: &str,
+        predicate_type: &str,
+    ) -> Vec<u8> {
+        let statement = serde_json::to_vec(&json!({
+            "_type": IN_TOTO_STATEMENT_TYPE,
+            "subject": [{"name": subject, "digest": {"sha256": digest}}],
+            "predicateType": predicate_type,
+            "predicate": null,
+        }))
+        .unwrap();
+        let signature = sign(signer, &pae(&statement));
+
+        let body = serde_json::to_vec(&json!({
+            "apiVersion": "0.0.1",
+            "kind": "dsse",
+            "spec": {
+                "payloadHash": {
+                    "algorithm": "sha256",
+                    "value": hex::encode(Sha256::digest(&statement)),
+                },
+                "signatures": [{
+                    "signature": BASE64_STANDARD.encode(&signature),
+                    "verifier": BASE64_STANDARD.encode(certificate.pem()),
+                }],
+            },
+        }))
+        .unwrap();
+        let key_id = Sha256::digest(self.log.subject_public_key_info());
+        let entry = serde_json::to_vec(&SignedEntry {
+            body: BASE64_STANDARD.encode(&body),
+            integrated_time: INTEGRATED_TIME,
+            log_id: hex::encode(key_id),
+            log_index: 42,
+        })
+        .unwrap();
+
+        serde_json::to_vec(&json!({
+            "version": 1,
+            "attestation_bundles": [{
+                "publisher": publisher,
+                "attestations": [{
+                    "version": 1,
+                    "verification_material": {
+                        "certificate": BASE64_STANDARD.encode(certificate.der()),
+                        "transparency_entries": [{
+                            "logIndex": "42",
+                            "logId": {"keyId": BASE64_STANDARD.encode(key_id)},
+                            "kindVersion": {"kind": "dsse", "version": "0.0.1"},
+                            "integratedTime": INTEGRATED_TIME.to_string(),
+                            "inclusionPromise": {
+                                "signedEntryTimestamp": BASE64_STANDARD.encode(sign(&self.log, &entry)),
+                            },
+                            "canonicalizedBody": BASE64_STANDARD.encode(&body),
+                        }],
+                    },
+                    "envelope": {
+                        "statement": BASE64_STANDARD.encode(&statement),
+                        "signature": BASE64_STANDARD.encode(&signature),
+                    },
+                }],
+            }],
+        }))
+        .unwrap()
+    }
+}
+
+impl Default for Sigstore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The trusted publisher for a GitHub Actions workflow in [`REPOSITORY`].
+pub fn github(workflow: &str) -> serde_json::Value {
+    json!({
+        "kind": "GitHub",
+        "repository": REPOSITORY,
+        "workflow": workflow,
+        "environment": null,
+    })
+}
+
+/// Create a `trusted_root.json` that trusts the given certificate authority and transparency log.
+fn trusted_root(ca_certificate: &Certificate, log: &KeyPair) -> Vec<u8> {
+    serde_json::to_vec(&json!({
+        "mediaType": "application/vnd.dev.sigstore.trustedroot+json;version=0.1",
+        "tlogs": [{
+            "baseUrl": "https://rekor.example.com",
+            "hashAlgorithm": "SHA2_256",
+            "publicKey": {
+                "rawBytes": BASE64_STANDARD.encode(log.subject_public_key_info()),
+                "keyDetails": "PKIX_ECDSA_P256_SHA_256",
+            },
+            "logId": {
+                "keyId": BASE64_STANDARD.encode(Sha256::digest(log.subject_public_key_info())),
+            },
+        }],
+        "certificateAuthorities": [{
+            "certChain": {
+                "certificates": [{
+                    "rawBytes": BASE64_STANDARD.encode(ca_certificate.der()),
+                }],
+            },
+        }],
+    }))
+    .unwrap()
+}
+
+fn sign(key: &KeyPair, message: &[u8]) -> Vec<u8> {
+    let rng = SystemRandom::new();
+    let key = EcdsaKeyPair::from_pkcs8(&ECDSA_P256_SHA256_ASN1_SIGNING, &key.serialize_der(), &rng)
+        .unwrap();
+    key.sign(&rng, message).unwrap().as_ref().to_vec()
+}
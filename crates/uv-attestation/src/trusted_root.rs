@@ -0,0 +1,248 @@
+use std::path::Path;
+use std::sync::OnceLock;
+use std::time::Duration;
+
+use ring::signature::{
+    ECDSA_P256_SHA256_ASN1, ECDSA_P384_SHA384_ASN1, ED25519, UnparsedPublicKey,
+    VerificationAlgorithm,
+};
+use rustls_pki_types::{CertificateDer, SignatureVerificationAlgorithm, UnixTime};
+use serde::Deserialize;
+use webpki::{EndEntityCert, KeyUsage, anchor_from_trusted_cert};
+use x509_parser::prelude::FromDer;
+use x509_parser::x509::SubjectPublicKeyInfo;
+
+use uv_static::EnvVars;
+
+use crate::Error;
+use crate::provenance::{Base64, LogId};
+
+/// The DER-encoded OID for the `id-kp-codeSigning` extended key usage.
+const CODE_SIGNING: &[u8] = &[0x2b, 0x06, 0x01, 0x05, 0x05, 0x07, 0x03, 0x03];
+
+/// The signature algorithms accepted for certificates issued by a Sigstore certificate authority.
+static CERTIFICATE_ALGORITHMS: &[&dyn SignatureVerificationAlgorithm] = &[
+    webpki::ring::ECDSA_P256_SHA256,
+    webpki::ring::ECDSA_P256_SHA384,
+    webpki::ring::ECDSA_P384_SHA256,
+    webpki::ring::ECDSA_P384_SHA384,
+    webpki::ring::ED25519,
+    webpki::ring::RSA_PKCS1_2048_8192_SHA256,
+    webpki::ring::RSA_PKCS1_2048_8192_SHA384,
+    webpki::ring::RSA_PKCS1_2048_8192_SHA512,
+];
+
+/// The trusted root of the Sigstore public-good instance, as distributed via Sigstore's TUF
+/// repository (`https://tuf-repo-cdn.sigstore.dev`).
+static PUBLIC_GOOD_TRUSTED_ROOT: &[u8] = include_bytes!("trusted_root.json");
+
+/// The trusted root used to verify the attestations of registry distributions.
+static TRUSTED_ROOT: OnceLock<TrustedRoot> = OnceLock::new();
+
+/// The Sigstore certificate authorities and transparency logs trusted for verifying attestations,
+/// as read from a Sigstore `trusted_root.json`.
+#[derive(Debug, Clone)]
+pub struct TrustedRoot {
+    /// The self-signed root certificates of each certificate authority.
+    anchors: Vec<CertificateDer<'static>>,
+    /// The intermediate certificates of each certificate authority.
+    intermediates: Vec<CertificateDer<'static>>,
+    /// The transparency logs.
+    logs: Vec<TransparencyLog>,
+}
+
+#[derive(Debug, Clone)]
+struct TransparencyLog {
+    /// The log ID, i.e., the SHA-256 digest of the log's DER-encoded public key.
+    key_id: Vec<u8>,
+    /// The raw public key of the log.
+    public_key: Vec<u8>,
+    /// The signature algorithm used by the log.
+    algorithm: &'static dyn VerificationAlgorithm,
+}
+
+impl TrustedRoot {
+    /// Return the [`TrustedRoot`] at the path given by `UV_ATTESTATION_TRUSTED_ROOT`, or the
+    /// bundled trusted root of the Sigstore public-good instance if unset.
+    ///
+    /// The trusted root is read once, and reused for the remainder of the process.
+    pub fn from_env() -> Result<&'static Self, Error> {
+        if let Some(root) = TRUSTED_ROOT.get() {
+            return Ok(root);
+        }
+        let root = match std::env::var_os(EnvVars::UV_ATTESTATION_TRUSTED_ROOT)
+            .filter(|path| !path.is_empty())
+        {
+            Some(path) => Self::from_path(Path::new(&path))?,
+            None => Self::public_good()?,
+        };
+        Ok(TRUSTED_ROOT.get_or_init(|| root))
+    }
+
+    /// Return the bundled [`TrustedRoot`] of the Sigstore public-good instance, which signs the
+    /// attestations served by PyPI.
+    pub fn public_good() -> Result<Self, Error> {
+        Self::from_slice(PUBLIC_GOOD_TRUSTED_ROOT)
+    }
+
+    /// Read a [`TrustedRoot`] from a `trusted_root.json` file.
+    pub fn from_path(path: &Path) -> Result<Self, Error> {
+        let contents =
+            fs_err::read(path).map_err(|err| Error::ReadTrustedRoot(path.to_path_buf(), err))?;
+        Self::from_slice(&contents)
+    }
+
+    /// Parse a [`TrustedRoot`] from the JSON contents of a `trusted_root.json` file.
+    pub fn from_slice(bytes: &[u8]) -> Result<Self, Error> {
+        let wire: TrustedRootWire =
+            serde_json::from_slice(bytes).map_err(Error::ParseTrustedRoot)?;
+
+        // Each chain is ordered from the intermediates down to the root.
+        let mut anchors = Vec::new();
+        let mut intermediates = Vec::new();
+        for authority in wire.certificate_authorities {
+            let mut certificates = authority.cert_chain.certificates;
+            if let Some(root) = certificates.pop() {
+                anchors.push(CertificateDer::from(root.raw_bytes.0));
+            }
+            intermediates.extend(
+                certificates
+                    .into_iter()
+                    .map(|certificate| CertificateDer::from(certificate.raw_bytes.0)),
+            );
+        }
+
+        // Logs with unsupported key types are ignored, as in other Sigstore clients.
+        let logs = wire
+            .tlogs
+            .into_iter()
+            .filter_map(|log| {
+                let algorithm: &'static dyn VerificationAlgorithm =
+                    match log.public_key.key_details.as_str() {
+                        "PKIX_ECDSA_P256_SHA_256" => &ECDSA_P256_SHA256_ASN1,
+                        "PKIX_ECDSA_P384_SHA_384" => &ECDSA_P384_SHA384_ASN1,
+                        "PKIX_ED25519" => &ED25519,
+                        _ => return None,
+                    };
+                let (_, spki) = SubjectPublicKeyInfo::from_der(&log.public_key.raw_bytes.0).ok()?;
+                Some(TransparencyLog {
+                    key_id: log.log_id.key_id.0,
+                    public_key: spki.subject_public_key.data.into_owned(),
+                    algorithm,
+                })
+            })
+            .collect();
+
+        Ok(Self {
+            anchors,
+            intermediates,
+            logs,
+        })
+    }
+
+    /// Verify that a DER-encoded signing certificate chains to a trusted certificate authority,
+    /// and was valid for code signing at the given Unix timestamp.
+    pub(crate) fn verify_certificate(&self, certificate: &[u8], time: i64) -> Result<(), Error> {
+        let certificate = CertificateDer::from(certificate);
+        let end_entity =
+            EndEntityCert::try_from(&certificate).map_err(|_| Error::InvalidCertificate)?;
+        let anchors = self
+            .anchors
+            .iter()
+            .map(anchor_from_trusted_cert)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(Error::UntrustedCertificate)?;
+        let time = UnixTime::since_unix_epoch(Duration::from_secs(
+            u64::try_from(time).map_err(|_| Error::TransparencyLog("invalid integrated time"))?,
+        ));
+        end_entity
+            .verify_for_usage(
+                CERTIFICATE_ALGORITHMS,
+                &anchors,
+                &self.intermediates,
+                time,
+                KeyUsage::required(CODE_SIGNING),
+                None,
+                None,
+            )
+            .map_err(Error::UntrustedCertificate)?;
+        Ok(())
+    }
+
+    /// Verify a signature by the transparency log with the given ID.
+    ///
+    /// Returns `false` if the log isn't trusted, or if the signature is invalid.
+    pub(crate) fn verify_log_signature(
+        &self,
+        log_id: &LogId,
+        message: &[u8],
+        signature: &[u8],
+    ) -> bool {
+        self.logs
+            .iter()
+            .filter(|log| log.key_id == log_id.key_id.0)
+            .any(|log| {
+                UnparsedPublicKey::new(log.algorithm, &log.public_key)
+                    .verify(message, signature)
+                    .is_ok()
+            })
+    }
+
+    /// Returns `true` if the transparency log with the given ID is trusted.
+    pub(crate) fn has_log(&self, log_id: &LogId) -> bool {
+        self.logs.iter().any(|log| log.key_id == log_id.key_id.0)
+    }
+}
+
+/// The subset of Sigstore's `TrustedRoot` message needed to verify attestations.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TrustedRootWire {
+    #[serde(default)]
+    tlogs: Vec<TransparencyLogWire>,
+    #[serde(default)]
+    certificate_authorities: Vec<CertificateAuthorityWire>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TransparencyLogWire {
+    public_key: PublicKeyWire,
+    log_id: LogId,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PublicKeyWire {
+    raw_bytes: Base64,
+    key_details: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CertificateAuthorityWire {
+    cert_chain: CertificateChainWire,
+}
+
+#[derive(Debug, Deserialize)]
+struct CertificateChainWire {
+    certificates: Vec<CertificateWire>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CertificateWire {
+    raw_bytes: Base64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TrustedRoot;
+
+    #[test]
+    fn public_good() {
+        let root = TrustedRoot::public_good().unwrap();
+        assert!(!root.anchors.is_empty());
+        assert!(!root.logs.is_empty());
+    }
+}
@@ -0,0 +1,651 @@
+use base64::Engine;
+use base64::prelude::BASE64_STANDARD;
+use rustls_pki_types::{CertificateDer, SignatureVerificationAlgorithm};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use webpki::EndEntityCert;
+use x509_parser::certificate::X509Certificate;
+use x509_parser::der_parser::der::parse_der_utf8string;
+use x509_parser::extensions::GeneralName;
+use x509_parser::pem::parse_x509_pem;
+use x509_parser::prelude::FromDer;
+
+use crate::provenance::{Attestation, Base64, Provenance, Publisher, TransparencyEntry};
+use crate::{Error, TrustedPublisher, TrustedRoot};
+
+/// The payload type of a DSSE envelope containing an in-toto statement.
+const IN_TOTO_PAYLOAD_TYPE: &str = "application/vnd.in-toto+json";
+
+/// The type of an in-toto v1 statement.
+pub(crate) const IN_TOTO_STATEMENT_TYPE: &str = "https://in-toto.io/Statement/v1";
+
+/// The predicate types accepted for PEP 740 attestations: PyPI's publish attestation, and SLSA
+/// provenance.
+const PREDICATE_TYPES: &[&str] = &[
+    "https://docs.pypi.org/attestations/publish/v1",
+    "https://slsa.dev/provenance/v1",
+];
+
+/// The OIDC issuer of the signing certificate (Fulcio extension `1.3.6.1.4.1.57264.1.8`).
+const ISSUER_OID: &str = "1.3.6.1.4.1.57264.1.8";
+
+/// The URI of the workflow that signed the attestation (Fulcio extension
+/// `1.3.6.1.4.1.57264.1.9`).
+const BUILD_SIGNER_URI_OID: &str = "1.3.6.1.4.1.57264.1.9";
+
+/// The URI of the source repository (Fulcio extension `1.3.6.1.4.1.57264.1.12`).
+const SOURCE_REPOSITORY_URI_OID: &str = "1.3.6.1.4.1.57264.1.12";
+
+/// The signature algorithms accepted for the ephemeral signing key.
+static SIGNING_ALGORITHMS: &[&dyn SignatureVerificationAlgorithm] = &[
+    webpki::ring::ECDSA_P256_SHA256,
+    webpki::ring::ECDSA_P384_SHA384,
+    webpki::ring::ED25519,
+];
+
+/// Verify the attestations in a [`Provenance`] for the distribution with the given filename.
+///
+/// Every attestation must verify against the [`TrustedRoot`] and the identity of its publisher.
+/// If any `trusted_publishers` are given, the publisher of every attestation bundle must match one
+/// of them, as the publisher named in the provenance is chosen by the index.
+///
+/// Returns the hex-encoded SHA-256 digest of the distribution to which the attestations attest,
+/// which the caller is responsible for comparing against the downloaded file.
+pub fn verify(
+    provenance: &Provenance,
+    filename: &str,
+    root: &TrustedRoot,
+    trusted_publishers: &[TrustedPublisher],
+) -> Result<String, Error> {
+    let mut digest: Option<String> = None;
+    for bundle in &provenance.attestation_bundles {
+        if !trusted_publishers.is_empty()
+            && !trusted_publishers
+                .iter()
+                .any(|trusted| trusted.matches(&bundle.publisher))
+        {
+            return Err(Error::UntrustedPublisher {
+                publisher: bundle.publisher.to_string(),
+                expected: trusted_publishers
+                    .iter()
+                    .map(|trusted| format!("`{trusted}`"))
+                    .collect::<Vec<_>>()
+                    .join(", "),
+            });
+        }
+        for attestation in &bundle.attestations {
+            let attested = verify_attestation(attestation, &bundle.publisher, filename, root)?;
+            match &digest {
+                Some(digest) if *digest != attested => {
+                    return Err(Error::ConflictingDigests(filename.to_string()));
+                }
+                Some(_) => {}
+                None => digest = Some(attested),
+            }
+        }
+    }
+    digest.ok_or(Error::NoAttestations)
+}
+
+/// Verify a single [`Attestation`], returning the SHA-256 digest to which it attests.
+fn verify_attestation(
+    attestation: &Attestation,
+    publisher: &Publisher,
+    filename: &str,
+    root: &TrustedRoot,
+) -> Result<String, Error> {
+    let certificate = attestation.verification_material.certificate.as_ref();
+
+    // Verify that the attestation was recorded in a trusted transparency log, and determine when.
+    let integrated_time = verify_transparency_entries(attestation, root)?;
+
+    // Verify that the certificate was issued by a trusted authority, and was valid at the time.
+    root.verify_certificate(certificate, integrated_time)?;
+
+    // Verify the signature over the statement.
+    let certificate_der = CertificateDer::from(certificate);
+    let end_entity =
+        EndEntityCert::try_from(&certificate_der).map_err(|_| Error::InvalidCertificate)?;
+    let message = pae(attestation.envelope.statement.as_ref());
+    if !SIGNING_ALGORITHMS.iter().any(|algorithm| {
+        end_entity
+            .verify_signature(
+                *algorithm,
+                &message,
+                attestation.envelope.signature.as_ref(),
+            )
+            .is_ok()
+    }) {
+        return Err(Error::InvalidSignature);
+    }
+
+    // Verify that the certificate was issued to the trusted publisher.
+    let (_, parsed) =
+        X509Certificate::from_der(certificate).map_err(|_| Error::InvalidCertificate)?;
+    verify_publisher(&parsed, publisher)?;
+
+    // Verify that the statement is about the distribution.
+    let statement: Statement = serde_json::from_slice(attestation.envelope.statement.as_ref())
+        .map_err(Error::ParseStatement)?;
+    if statement.type_ != IN_TOTO_STATEMENT_TYPE {
+        return Err(Error::InvalidStatement("unsupported statement type"));
+    }
+    if !PREDICATE_TYPES.contains(&statement.predicate_type.as_str()) {
+        return Err(Error::UnsupportedPredicate(statement.predicate_type));
+    }
+    let [subject] = statement.subject.as_slice() else {
+        return Err(Error::InvalidStatement("expected exactly one subject"));
+    };
+    if subject.name != filename {
+        return Err(Error::SubjectMismatch {
+            attested: subject.name.clone(),
+            expected: filename.to_string(),
+        });
+    }
+    let Some(digest) = subject.digest.sha256.as_ref() else {
+        return Err(Error::InvalidStatement("missing SHA-256 digest"));
+    };
+
+    Ok(digest.to_ascii_lowercase())
+}
+
+/// Verify the transparency log entries for an [`Attestation`], returning the time at which the
+/// attestation was integrated into the log.
+///
+/// At least one entry must carry a valid inclusion promise (a signed entry timestamp) from a
+/// trusted log, and must record the attestation's statement, signature, and certificate.
+fn verify_transparency_entries(
+    attestation: &Attestation,
+    root: &TrustedRoot,
+) -> Result<i64, Error> {
+    let mut result = Err(Error::TransparencyLog("no transparency log entries"));
+    for entry in &attestation.verification_material.transparency_entries {
+        result = verify_transparency_entry(entry, attestation, root);
+        if result.is_ok() {
+            break;
+        }
+    }
+    result
+}
+
+fn verify_transparency_entry(
+    entry: &TransparencyEntry,
+    attestation: &Attestation,
+    root: &TrustedRoot,
+) -> Result<i64, Error> {
+    if entry.kind_version.kind != "dsse" {
+        return Err(Error::TransparencyLog("unsupported entry kind"));
+    }
+    if !root.has_log(&entry.log_id) {
+        return Err(Error::TransparencyLog("unknown transparency log"));
+    }
+    let Some(promise) = entry.inclusion_promise.as_ref() else {
+        return Err(Error::TransparencyLog("missing inclusion promise"));
+    };
+
+    // Verify the signed entry timestamp over the canonical JSON form of the entry.
+    let payload = serde_json::to_vec(&SignedEntry {
+        body: BASE64_STANDARD.encode(&entry.canonicalized_body),
+        integrated_time: entry.integrated_time,
+        log_id: hex::encode(&entry.log_id.key_id),
+        log_index: entry.log_index,
+    })
+    .map_err(Error::SerializeEntry)?;
+    if !root.verify_log_signature(
+        &entry.log_id,
+        &payload,
+        promise.signed_entry_timestamp.as_ref(),
+    ) {
+        return Err(Error::TransparencyLog("invalid signed entry timestamp"));
+    }
+
+    // Verify that the entry records this attestation.
+    let body: DsseEntry = serde_json::from_slice(entry.canonicalized_body.as_ref())
+        .map_err(|_| Error::TransparencyLog("malformed entry body"))?;
+    let payload_hash = hex::encode(Sha256::digest(&attestation.envelope.statement));
+    if body.spec.payload_hash.algorithm != "sha256" || body.spec.payload_hash.value != payload_hash
+    {
+        return Err(Error::TransparencyLog("entry does not match the statement"));
+    }
+    if !body.spec.signatures.iter().any(|signature| {
+        signature.signature == attestation.envelope.signature
+            && parse_x509_pem(signature.verifier.as_ref()).is_ok_and(|(_, pem)| {
+                pem.contents == attestation.verification_material.certificate.as_ref()
+            })
+    }) {
+        return Err(Error::TransparencyLog("entry does not match the signature"));
+    }
+
+    Ok(entry.integrated_time)
+}
+
+/// Verify that a signing certificate was issued to the given [`Publisher`].
+fn verify_publisher(certificate: &X509Certificate, publisher: &Publisher) -> Result<(), Error> {
+    let issuer = extension(certificate, ISSUER_OID);
+    let build_signer = extension(certificate, BUILD_SIGNER_URI_OID);
+    let source_repository = extension(certificate, SOURCE_REPOSITORY_URI_OID);
+
+    let matches = match publisher {
+        Publisher::GitHub {
+            repository,
+            workflow,
+        } => {
+            let repository_uri = format!("https://github.com/{repository}");
+            let workflow_prefix = format!("{repository_uri}/.github/workflows/{workflow}@");
+            issuer.as_deref() == Some("https://token.actions.githubusercontent.com")
+                && source_repository
+                    .as_deref()
+                    .is_some_and(|uri| uri.eq_ignore_ascii_case(&repository_uri))
+                && build_signer.as_deref().is_some_and(|uri| {
+                    uri.get(..workflow_prefix.len())
+                        .is_some_and(|prefix| prefix.eq_ignore_ascii_case(&workflow_prefix))
+                })
+        }
+        Publisher::GitLab {
+            repository,
+            workflow_filepath,
+        } => {
+            let repository_uri = format!("https://gitlab.com/{repository}");
+            let workflow_prefix = format!("{repository_uri}//{workflow_filepath}@");
+            issuer.as_deref() == Some("https://gitlab.com")
+                && source_repository
+                    .as_deref()
+                    .is_some_and(|uri| uri.eq_ignore_ascii_case(&repository_uri))
+                && build_signer.as_deref().is_some_and(|uri| {
+                    uri.get(..workflow_prefix.len())
+                        .is_some_and(|prefix| prefix.eq_ignore_ascii_case(&workflow_prefix))
+                })
+        }
+        Publisher::Google { email } => {
+            issuer.as_deref() == Some("https://accounts.google.com")
+                && emails(certificate).any(|candidate| candidate == email)
+        }
+        Publisher::Unknown => return Err(Error::UnsupportedPublisher),
+    };
+
+    if matches {
+        Ok(())
+    } else {
+        let identity = build_signer
+            .or_else(|| emails(certificate).next().map(ToString::to_string))
+            .unwrap_or_else(|| "unknown".to_string());
+        Err(Error::PublisherMismatch {
+            identity,
+            publisher: publisher.to_string(),
+        })
+    }
+}
+
+/// Return the value of a Fulcio certificate extension, encoded as a DER UTF-8 string.
+fn extension(certificate: &X509Certificate, oid: &str) -> Option<String> {
+    let extension = certificate
+        .extensions()
+        .iter()
+        .find(|extension| extension.oid.to_id_string() == oid)?;
+    let (_, value) = parse_der_utf8string(extension.value).ok()?;
+    value.as_str().ok().map(ToString::to_string)
+}
+
+/// Return the email addresses in the certificate's subject alternative names.
+fn emails<'a>(certificate: &'a X509Certificate) -> impl Iterator<Item = &'a str> {
+    certificate
+        .subject_alternative_name()
+        .ok()
+        .flatten()
+        .into_iter()
+        .flat_map(|extension| extension.value.general_names.iter())
+        .filter_map(|name| match name {
+            GeneralName::RFC822Name(email) => Some(*email),
+            _ => None,
+        })
+}
+
+/// Compute the DSSE pre-authentication encoding of an in-toto statement.
+pub(crate) fn pae(payload: &[u8]) -> Vec<u8> {
+    let mut message = format!(
+        "DSSEv1 {} {IN_TOTO_PAYLOAD_TYPE} {} ",
+        IN_TOTO_PAYLOAD_TYPE.len(),
+        payload.len()
+    )
+    .into_bytes();
+    message.extend_from_slice(payload);
+    message
+}
+
+/// The canonical form of a Rekor entry, as signed by the log's signed entry timestamp.
+///
+/// Fields are declared in lexicographic order, as required by the canonical JSON encoding.
+#[derive(Serialize)]
+pub(crate) struct SignedEntry {
+    pub(crate) body: String,
+    #[serde(rename = "integratedTime")]
+    pub(crate) integrated_time: i64,
+    #[serde(rename = "logID")]
+    pub(crate) log_id: String,
+    #[serde(rename = "logIndex")]
+    pub(crate) log_index: i64,
+}
+
+/// The body of a Rekor `dsse` entry.
+#[derive(Deserialize)]
+struct DsseEntry {
+    spec: DsseSpec,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct DsseSpec {
+    payload_hash: EntryHash,
+    signatures: Vec<EntrySignature>,
+}
+
+#[derive(Deserialize)]
+struct EntryHash {
+    algorithm: String,
+    value: String,
+}
+
+#[derive(Deserialize)]
+struct EntrySignature {
+    signature: Base64,
+    /// The PEM-encoded signing certificate.
+    verifier: Base64,
+}
+
+/// An in-toto v1 statement.
+#[derive(Deserialize)]
+struct Statement {
+    #[serde(rename = "_type")]
+    type_: String,
+    subject: Vec<Subject>,
+    #[serde(rename = "predicateType")]
+    predicate_type: String,
+}
+
+#[derive(Deserialize)]
+struct Subject {
+    name: String,
+    digest: SubjectDigest,
+}
+
+#[derive(Deserialize)]
+struct SubjectDigest {
+    sha256: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use rcgen::KeyPair;
+    use serde_json::json;
+
+    use crate::testing::{Sigstore, github};
+    use crate::{Error, Provenance, TrustedPublisher, TrustedRoot, verify};
+
+    const FILENAME: &str = "example-1.0.0-py3-none-any.whl";
+    const DIGEST: &str = "4ad8b7b4d9fbf8ad0a4d1dbf0bd1a3a33b4fef8e6f4e1e0e7a8e1b9b2b3c4d5e";
+
+    fn provenance(
+        sigstore: &Sigstore,
+        publisher: &serde_json::Value,
+        certificate: &rcgen::Certificate,
+        signer: &KeyPair,
+        subject: &str,
+    ) -> Provenance {
+        let provenance = sigstore.provenance(publisher, certificate, signer, subject, DIGEST);
+        Provenance::from_slice(&provenance).unwrap()
+    }
+
+    fn trusted_root(root: &[u8]) -> TrustedRoot {
+        TrustedRoot::from_slice(root).unwrap()
+    }
+
+    #[test]
+    fn verify_github() {
+        let sigstore = Sigstore::new();
+        let (certificate, key) = sigstore.issue_github("release.yml");
+        let provenance = provenance(
+            &sigstore,
+            &github("release.yml"),
+            &certificate,
+            &key,
+            FILENAME,
+        );
+
+        let digest = verify(
+            &provenance,
+            FILENAME,
+            &trusted_root(&sigstore.trusted_root()),
+            &[],
+        )
+        .unwrap();
+        assert_eq!(digest, DIGEST);
+    }
+
+    #[test]
+    fn verify_google() {
+        let sigstore = Sigstore::new();
+        let (certificate, key) = sigstore.issue(&[(
+            &[1, 3, 6, 1, 4, 1, 57264, 1, 8],
+            "https://accounts.google.com",
+        )]);
+        let provenance = provenance(
+            &sigstore,
+            &json!({"kind": "Google", "email": "release@example.iam.gserviceaccount.com"}),
+            &certificate,
+            &key,
+            FILENAME,
+        );
+
+        // The certificate doesn't include the service account's email address.
+        let err = verify(
+            &provenance,
+            FILENAME,
+            &trusted_root(&sigstore.trusted_root()),
+            &[],
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "The signing certificate identity `unknown` does not match the trusted publisher: Google account `release@example.iam.gserviceaccount.com`"
+        );
+    }
+
+    #[test]
+    fn publisher_mismatch() {
+        let sigstore = Sigstore::new();
+        let (certificate, key) = sigstore.issue_github("untrusted.yml");
+        let provenance = provenance(
+            &sigstore,
+            &github("release.yml"),
+            &certificate,
+            &key,
+            FILENAME,
+        );
+
+        let err = verify(
+            &provenance,
+            FILENAME,
+            &trusted_root(&sigstore.trusted_root()),
+            &[],
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "The signing certificate identity `https://github.com/astral-sh/example/.github/workflows/untrusted.yml@refs/tags/v1.0.0` does not match the trusted publisher: GitHub repository `astral-sh/example` (workflow `release.yml`)"
+        );
+    }
+
+    #[test]
+    fn subject_mismatch() {
+        let sigstore = Sigstore::new();
+        let (certificate, key) = sigstore.issue_github("release.yml");
+        let provenance = provenance(
+            &sigstore,
+            &github("release.yml"),
+            &certificate,
+            &key,
+            "example-1.0.0.tar.gz",
+        );
+
+        let err = verify(
+            &provenance,
+            FILENAME,
+            &trusted_root(&sigstore.trusted_root()),
+            &[],
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "The attestation is for `example-1.0.0.tar.gz`, but the distribution is `example-1.0.0-py3-none-any.whl`"
+        );
+    }
+
+    #[test]
+    fn invalid_signature() {
+        let sigstore = Sigstore::new();
+        let (certificate, _) = sigstore.issue_github("release.yml");
+        let other = KeyPair::generate().unwrap();
+        let provenance = provenance(
+            &sigstore,
+            &github("release.yml"),
+            &certificate,
+            &other,
+            FILENAME,
+        );
+
+        let err = verify(
+            &provenance,
+            FILENAME,
+            &trusted_root(&sigstore.trusted_root()),
+            &[],
+        )
+        .unwrap_err();
+        assert!(matches!(err, Error::InvalidSignature), "{err}");
+    }
+
+    #[test]
+    fn untrusted_certificate() {
+        let sigstore = Sigstore::new();
+        let (certificate, key) = sigstore.issue_github("release.yml");
+        let provenance = provenance(
+            &sigstore,
+            &github("release.yml"),
+            &certificate,
+            &key,
+            FILENAME,
+        );
+
+        // A trusted root with the same transparency log, but a different certificate authority.
+        let other = Sigstore::new();
+        let root = trusted_root(&sigstore.trusted_root_with_ca(&other));
+
+        let err = verify(&provenance, FILENAME, &root, &[]).unwrap_err();
+        assert!(matches!(err, Error::UntrustedCertificate(_)), "{err}");
+    }
+
+    #[test]
+    fn untrusted_log() {
+        let sigstore = Sigstore::new();
+        let (certificate, key) = sigstore.issue_github("release.yml");
+        let provenance = provenance(
+            &sigstore,
+            &github("release.yml"),
+            &certificate,
+            &key,
+            FILENAME,
+        );
+
+        // A trusted root with the same certificate authority, but a different transparency log.
+        let other = Sigstore::new();
+        let root = trusted_root(&sigstore.trusted_root_with_log(&other));
+
+        let err = verify(&provenance, FILENAME, &root, &[]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "The attestation is not recorded in a trusted transparency log: unknown transparency log"
+        );
+    }
+
+    #[test]
+    fn trusted_publisher() {
+        let sigstore = Sigstore::new();
+        let (certificate, key) = sigstore.issue_github("release.yml");
+        let provenance = provenance(
+            &sigstore,
+            &github("release.yml"),
+            &certificate,
+            &key,
+            FILENAME,
+        );
+        let root = trusted_root(&sigstore.trusted_root());
+
+        // The repository matches, with any workflow.
+        let trusted = ["github:astral-sh/example"
+            .parse::<TrustedPublisher>()
+            .unwrap()];
+        let digest = verify(&provenance, FILENAME, &root, &trusted).unwrap();
+        assert_eq!(digest, DIGEST);
+
+        // The repository and workflow match.
+        let trusted = ["github:astral-sh/example@release.yml"
+            .parse::<TrustedPublisher>()
+            .unwrap()];
+        verify(&provenance, FILENAME, &root, &trusted).unwrap();
+    }
+
+    #[test]
+    fn untrusted_publisher() {
+        // An index can serve a validly-signed attestation from a publisher it controls.
+        let sigstore = Sigstore::new();
+        let (certificate, key) = sigstore.issue_github("release.yml");
+        let provenance = provenance(
+            &sigstore,
+            &github("release.yml"),
+            &certificate,
+            &key,
+            FILENAME,
+        );
+        let root = trusted_root(&sigstore.trusted_root());
+
+        let trusted = [
+            "github:pallets/example"
+                .parse::<TrustedPublisher>()
+                .unwrap(),
+            "github:astral-sh/example@publish.yml"
+                .parse::<TrustedPublisher>()
+                .unwrap(),
+        ];
+        let err = verify(&provenance, FILENAME, &root, &trusted).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "The attestations were published by GitHub repository `astral-sh/example` (workflow `release.yml`), which is not a trusted publisher for the package (expected: `github:pallets/example`, `github:astral-sh/example@publish.yml`)"
+        );
+    }
+
+    #[test]
+    fn unsupported_predicate() {
+        let sigstore = Sigstore::new();
+        let (certificate, key) = sigstore.issue_github("release.yml");
+        let provenance = sigstore.provenance_with_predicate(
+            &github("release.yml"),
+            &certificate,
+            &key,
+            FILENAME,
+            DIGEST,
+            "https://example.com/predicate/v1",
+        );
+        let provenance = Provenance::from_slice(&provenance).unwrap();
+
+        let err = verify(
+            &provenance,
+            FILENAME,
+            &trusted_root(&sigstore.trusted_root()),
+            &[],
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Unsupported attestation predicate type: `https://example.com/predicate/v1`"
+        );
+    }
+}
@@ -3,6 +3,7 @@ use std::fmt;
 use std::io::Read;
 use std::io::Write;
 use std::str::FromStr;
+use std::sync::Arc;
 
 use base64::prelude::BASE64_STANDARD;
 use base64::read::DecoderReader;
@@ -19,6 +20,8 @@ use url::Url;
 use uv_redacted::DisplaySafeUrl;
 use uv_static::EnvVars;
 
+use crate::providers::CloudTokenProvider;
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum Credentials {
     /// RFC 7617 HTTP Basic Authentication
@@ -393,6 +396,9 @@ pub(crate) enum Authentication {
 
     /// Google Cloud signing.
     GcsSigner(GcsDefaultSigner),
+
+    /// Short-lived tokens minted for a cloud artifact registry.
+    CloudToken(Arc<CloudTokenProvider>),
 }
 
 impl PartialEq for Authentication {
@@ -401,6 +407,7 @@ impl PartialEq for Authentication {
             (Self::Credentials(a), Self::Credentials(b)) => a == b,
             (Self::AwsSigner(..), Self::AwsSigner(..)) => true,
             (Self::GcsSigner(..), Self::GcsSigner(..)) => true,
+            (Self::CloudToken(a), Self::CloudToken(b)) => Arc::ptr_eq(a, b),
             _ => false,
         }
     }
@@ -426,12 +433,18 @@ impl From<GcsDefaultSigner> for Authentication {
     }
 }
 
+impl From<CloudTokenProvider> for Authentication {
+    fn from(provider: CloudTokenProvider) -> Self {
+        Self::CloudToken(Arc::new(provider))
+    }
+}
+
 impl Authentication {
     /// Return the password used for authentication, if any.
     pub(crate) fn password(&self) -> Option<&str> {
         match self {
             Self::Credentials(credentials) => credentials.password(),
-            Self::AwsSigner(..) | Self::GcsSigner(..) => None,
+            Self::AwsSigner(..) | Self::GcsSigner(..) | Self::CloudToken(..) => None,
         }
     }
 
@@ -439,7 +452,7 @@ impl Authentication {
     pub(crate) fn username(&self) -> Option<&str> {
         match self {
             Self::Credentials(credentials) => credentials.username(),
-            Self::AwsSigner(..) | Self::GcsSigner(..) => None,
+            Self::AwsSigner(..) | Self::GcsSigner(..) | Self::CloudToken(..) => None,
         }
     }
 
@@ -447,7 +460,9 @@ impl Authentication {
     pub(crate) fn as_username(&self) -> Cow<'_, Username> {
         match self {
             Self::Credentials(credentials) => credentials.as_username(),
-            Self::AwsSigner(..) | Self::GcsSigner(..) => Cow::Owned(Username::none()),
+            Self::AwsSigner(..) | Self::GcsSigner(..) | Self::CloudToken(..) => {
+                Cow::Owned(Username::none())
+            }
         }
     }
 
@@ -455,7 +470,7 @@ impl Authentication {
     pub(crate) fn to_username(&self) -> Username {
         match self {
             Self::Credentials(credentials) => credentials.to_username(),
-            Self::AwsSigner(..) | Self::GcsSigner(..) => Username::none(),
+            Self::AwsSigner(..) | Self::GcsSigner(..) | Self::CloudToken(..) => Username::none(),
         }
     }
 
@@ -463,7 +478,7 @@ impl Authentication {
     pub(crate) fn is_authenticated(&self) -> bool {
         match self {
             Self::Credentials(credentials) => credentials.is_authenticated(),
-            Self::AwsSigner(..) | Self::GcsSigner(..) => true,
+            Self::AwsSigner(..) | Self::GcsSigner(..) | Self::CloudToken(..) => true,
         }
     }
 
@@ -471,7 +486,7 @@ impl Authentication {
     pub(crate) fn is_empty(&self) -> bool {
         match self {
             Self::Credentials(credentials) => credentials.is_empty(),
-            Self::AwsSigner(..) | Self::GcsSigner(..) => false,
+            Self::AwsSigner(..) | Self::GcsSigner(..) | Self::CloudToken(..) => false,
         }
     }
 
@@ -538,6 +553,10 @@ impl Authentication {
                 }
                 request
             }
+            Self::CloudToken(provider) => match provider.credentials().await {
+                Some(credentials) => credentials.authenticate(request),
+                None => request,
+            },
         }
     }
 }
@@ -59,6 +59,8 @@ pub struct Index {
     /// For PEP 503 endpoints, this excludes `/simple`.
     pub root_url: DisplaySafeUrl,
     pub auth_policy: AuthPolicy,
+    /// The keyring service to query for credentials, in lieu of the index URL.
+    pub keyring_service: Option<String>,
 }
 
 impl Index {
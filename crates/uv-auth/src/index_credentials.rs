@@ -0,0 +1,218 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::Credentials;
+
+/// Credentials configured for an index, as in:
+///
+/// ```toml
+/// [[tool.uv.index]]
+/// name = "internal"
+/// url = "https://example.com/simple"
+/// credentials = { username = "ci", password = "${INTERNAL_INDEX_PASSWORD}" }
+/// ```
+///
+/// Each value may reference environment variables with the `${NAME}` syntax, which are expanded
+/// when the credentials are read.
+#[derive(Clone, Default, Hash, Eq, PartialEq, Ord, PartialOrd, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct IndexCredentials {
+    /// The username to use for HTTP basic authentication.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+    /// The password to use for HTTP basic authentication.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub password: Option<String>,
+    /// The token to use for bearer authentication.
+    ///
+    /// Cannot be combined with a `username` or `password`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub token: Option<String>,
+    /// The keyring service name to query for the password.
+    ///
+    /// When set, uv queries the keyring for this service, rather than for the index URL. Requires
+    /// a `keyring-provider`. Cannot be combined with a `password` or `token`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub keyring_service: Option<String>,
+}
+
+impl fmt::Debug for IndexCredentials {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("IndexCredentials")
+            .field("username", &self.username)
+            .field("password", &self.password.as_ref().map(|_| "****"))
+            .field("token", &self.token.as_ref().map(|_| "****"))
+            .field("keyring_service", &self.keyring_service)
+            .finish()
+    }
+}
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum IndexCredentialsError {
+    #[error("`token` cannot be provided with `username` or `password`")]
+    TokenWithBasic,
+    #[error("`keyring-service` cannot be provided with `password` or `token`")]
+    KeyringServiceWithSecret,
+    #[error("Environment variable `{0}` is not set")]
+    MissingEnvVar(String),
+    #[error("Unterminated environment variable reference in `{0}`")]
+    UnterminatedEnvVar(String),
+}
+
+impl IndexCredentials {
+    /// Validate that the configured fields can be used together.
+    pub fn validate(&self) -> Result<(), IndexCredentialsError> {
+        if self.token.is_some() && (self.username.is_some() || self.password.is_some()) {
+            return Err(IndexCredentialsError::TokenWithBasic);
+        }
+        if self.keyring_service.is_some() && (self.password.is_some() || self.token.is_some()) {
+            return Err(IndexCredentialsError::KeyringServiceWithSecret);
+        }
+        Ok(())
+    }
+
+    /// Resolve the configured [`Credentials`], expanding any environment variable references.
+    ///
+    /// Returns `None` if only a keyring service is configured, in which case the credentials are
+    /// fetched from the keyring on demand.
+    pub fn resolve(&self) -> Result<Option<Credentials>, IndexCredentialsError> {
+        self.validate()?;
+
+        if let Some(token) = self.token.as_deref() {
+            return Ok(Some(Credentials::bearer(
+                expand_env_vars(token)?.into_bytes(),
+            )));
+        }
+
+        let username = self.username.as_deref().map(expand_env_vars).transpose()?;
+        let password = self.password.as_deref().map(expand_env_vars).transpose()?;
+        if username.is_none() && password.is_none() {
+            return Ok(None);
+        }
+        Ok(Some(Credentials::basic(username, password)))
+    }
+
+    /// Resolve the configured keyring service name, expanding any environment variable
+    /// references.
+    pub fn keyring_service(&self) -> Result<Option<String>, IndexCredentialsError> {
+        self.keyring_service
+            .as_deref()
+            .map(expand_env_vars)
+            .transpose()
+    }
+}
+
+/// Expand `${NAME}` references to environment variables in the given value.
+///
+/// Unlike shell expansion, references to unset variables are an error, to avoid silently sending
+/// incomplete credentials. A `$` that isn't followed by `{` is preserved as-is.
+fn expand_env_vars(value: &str) -> Result<String, IndexCredentialsError> {
+    expand_with(value, |name| {
+        std::env::var_os(name).map(|var| var.to_string_lossy().into_owned())
+    })
+}
+
+fn expand_with(
+    value: &str,
+    lookup: impl Fn(&str) -> Option<String>,
+) -> Result<String, IndexCredentialsError> {
+    let mut expanded = String::with_capacity(value.len());
+    let mut rest = value;
+    while let Some(start) = rest.find("${") {
+        expanded.push_str(&rest[..start]);
+        let Some(end) = rest[start + 2..].find('}') else {
+            return Err(IndexCredentialsError::UnterminatedEnvVar(value.to_string()));
+        };
+        let name = &rest[start + 2..start + 2 + end];
+        let Some(var) = lookup(name) else {
+            return Err(IndexCredentialsError::MissingEnvVar(name.to_string()));
+        };
+        expanded.push_str(&var);
+        rest = &rest[start + 2 + end + 1..];
+    }
+    expanded.push_str(rest);
+    Ok(expanded)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expand() {
+        let lookup = |name: &str| match name {
+            "USER" => Some("user".to_string()),
+            "TOKEN" => Some("secret".to_string()),
+            _ => None,
+        };
+
+        assert_eq!(expand_with("plain", lookup).unwrap(), "plain");
+        assert_eq!(
+            expand_with("$not-a-reference", lookup).unwrap(),
+            "$not-a-reference"
+        );
+        assert_eq!(expand_with("${USER}", lookup).unwrap(), "user");
+        assert_eq!(
+            expand_with("${USER}:${TOKEN}!", lookup).unwrap(),
+            "user:secret!"
+        );
+        assert_eq!(
+            expand_with("${MISSING}", lookup),
+            Err(IndexCredentialsError::MissingEnvVar("MISSING".to_string()))
+        );
+        assert_eq!(
+            expand_with("${USER", lookup),
+            Err(IndexCredentialsError::UnterminatedEnvVar(
+                "${USER".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn resolve() {
+        let credentials = IndexCredentials {
+            username: Some("user".to_string()),
+            password: Some("password".to_string()),
+            ..IndexCredentials::default()
+        };
+        let resolved = credentials.resolve().unwrap().unwrap();
+        assert_eq!(resolved.username(), Some("user"));
+        assert_eq!(resolved.password(), Some("password"));
+
+        let credentials = IndexCredentials {
+            token: Some("token".to_string()),
+            ..IndexCredentials::default()
+        };
+        let resolved = credentials.resolve().unwrap().unwrap();
+        assert!(matches!(resolved, Credentials::Bearer { .. }));
+
+        let credentials = IndexCredentials {
+            keyring_service: Some("service".to_string()),
+            ..IndexCredentials::default()
+        };
+        assert_eq!(credentials.resolve().unwrap(), None);
+
+        let credentials = IndexCredentials {
+            username: Some("user".to_string()),
+            token: Some("token".to_string()),
+            ..IndexCredentials::default()
+        };
+        assert_eq!(
+            credentials.resolve(),
+            Err(IndexCredentialsError::TokenWithBasic)
+        );
+
+        let credentials = IndexCredentials {
+            password: Some("password".to_string()),
+            keyring_service: Some("service".to_string()),
+            ..IndexCredentials::default()
+        };
+        assert_eq!(
+            credentials.resolve(),
+            Err(IndexCredentialsError::KeyringServiceWithSecret)
+        );
+    }
+}
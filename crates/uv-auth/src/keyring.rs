@@ -258,6 +258,25 @@ impl KeyringProvider {
         credentials.map(|(username, password)| Credentials::basic(Some(username), Some(password)))
     }
 
+    /// Fetch credentials for the given keyring service name.
+    ///
+    /// Unlike [`KeyringProvider::fetch`], the service name is used as-is, without falling back to
+    /// the host.
+    pub async fn fetch_service(
+        &self,
+        service: &str,
+        username: Option<&str>,
+    ) -> Option<Credentials> {
+        trace!("Checking keyring for service {service}");
+        let credentials = match self.backend {
+            KeyringProviderBackend::Native => self.fetch_native(service, username).await,
+            KeyringProviderBackend::Subprocess => self.fetch_subprocess(service, username).await,
+            #[cfg(test)]
+            KeyringProviderBackend::Dummy(ref store) => Self::fetch_dummy(store, service, username),
+        };
+        credentials.map(|(username, password)| Credentials::basic(Some(username), Some(password)))
+    }
+
     #[instrument(skip(self))]
     async fn fetch_subprocess(
         &self,
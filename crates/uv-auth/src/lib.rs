@@ -1,9 +1,12 @@
 pub use access_token::AccessToken;
 pub use cache::CredentialsCache;
+pub use credential_helper::CredentialHelper;
 pub use credentials::{Credentials, Username};
 pub use index::{AuthPolicy, Index, Indexes};
+pub use index_credentials::{IndexCredentials, IndexCredentialsError};
 pub use keyring::KeyringProvider;
 pub use middleware::AuthMiddleware;
+pub use pypirc::{Pypirc, PypircError, PypircRepository};
 pub use pyx::{
     DEFAULT_TOLERANCE_SECS, PyxJwt, PyxOAuthTokens, PyxTokenStore, PyxTokens, TokenStoreError,
     is_default_pyx_domain,
@@ -14,11 +17,14 @@ pub use store::{AuthBackend, AuthScheme, TextCredentialStore, TomlCredentialErro
 
 mod access_token;
 mod cache;
+mod credential_helper;
 mod credentials;
 mod index;
+mod index_credentials;
 mod keyring;
 mod middleware;
 mod providers;
+mod pypirc;
 mod pyx;
 mod realm;
 mod service;
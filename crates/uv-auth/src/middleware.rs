@@ -5,6 +5,7 @@ use http::{Extensions, StatusCode};
 use netrc::Netrc;
 use reqwest::{Request, Response};
 use reqwest_middleware::{ClientWithMiddleware, Error, Middleware, Next};
+use rustc_hash::FxHashMap;
 use tokio::sync::Mutex;
 use tracing::{debug, trace, warn};
 
@@ -14,7 +15,9 @@ use uv_static::EnvVars;
 use uv_warnings::owo_colors::OwoColorize;
 
 use crate::credentials::Authentication;
-use crate::providers::{GcsEndpointProvider, HuggingFaceProvider, S3EndpointProvider};
+use crate::providers::{
+    CloudRegistry, CloudTokenProvider, GcsEndpointProvider, HuggingFaceProvider, S3EndpointProvider,
+};
 use crate::pyx::{DEFAULT_TOLERANCE_SECS, PyxTokenStore};
 use crate::{
     AccessToken, CredentialsCache, KeyringProvider,
@@ -23,7 +26,7 @@ use crate::{
     index::{AuthPolicy, Indexes},
     realm::Realm,
 };
-use crate::{Index, TextCredentialStore};
+use crate::{CredentialHelper, Index, Pypirc, TextCredentialStore};
 
 /// Cached check for whether we're running in Dependabot.
 static IS_DEPENDABOT: LazyLock<bool> =
@@ -63,6 +66,36 @@ impl NetrcMode {
     }
 }
 
+/// Strategy for loading `.pypirc` files.
+enum PypircMode {
+    Automatic(LazyLock<Option<Pypirc>>),
+    Enabled(Pypirc),
+    Disabled,
+}
+
+impl Default for PypircMode {
+    fn default() -> Self {
+        Self::Automatic(LazyLock::new(|| match Pypirc::from_default_path() {
+            Ok(pypirc) => pypirc,
+            Err(err) => {
+                warn!("Error reading `.pypirc` file: {err}");
+                None
+            }
+        }))
+    }
+}
+
+impl PypircMode {
+    /// Get the parsed `.pypirc` file if enabled.
+    fn get(&self) -> Option<&Pypirc> {
+        match self {
+            Self::Automatic(lock) => lock.as_ref(),
+            Self::Enabled(pypirc) => Some(pypirc),
+            Self::Disabled => None,
+        }
+    }
+}
+
 /// Strategy for loading text-based credential files.
 enum TextStoreMode {
     Automatic(tokio::sync::OnceCell<Option<TextCredentialStore>>),
@@ -150,11 +183,14 @@ enum GcsCredentialState {
 /// A middleware that adds basic authentication to requests.
 ///
 /// Uses a cache to propagate credentials from previously seen requests and
-/// fetches credentials from a netrc file, TOML file, and the keyring.
+/// fetches credentials from a netrc file, `.pypirc` file, TOML file, credential helper, and the
+/// keyring.
 pub struct AuthMiddleware {
     netrc: NetrcMode,
+    pypirc: PypircMode,
     text_store: TextStoreMode,
     keyring: Option<KeyringProvider>,
+    credential_helper: Option<CredentialHelper>,
     /// Global authentication cache for a uv invocation to share credentials across uv clients.
     cache: Arc<CredentialsCache>,
     /// Auth policies for specific URLs.
@@ -172,6 +208,8 @@ pub struct AuthMiddleware {
     s3_credential_state: Mutex<S3CredentialState>,
     /// Cached GCS credentials to avoid running the credential helper multiple times.
     gcs_credential_state: Mutex<GcsCredentialState>,
+    /// Cached token providers for cloud registries, which refresh their tokens as they expire.
+    cloud_token_providers: Mutex<FxHashMap<CloudRegistry, Arc<Authentication>>>,
     preview: Preview,
 }
 
@@ -185,8 +223,10 @@ impl AuthMiddleware {
     pub fn new() -> Self {
         Self {
             netrc: NetrcMode::default(),
+            pypirc: PypircMode::default(),
             text_store: TextStoreMode::default(),
             keyring: None,
+            credential_helper: None,
             // TODO(konsti): There shouldn't be a credential cache without that in the initializer.
             cache: Arc::new(CredentialsCache::default()),
             indexes: Indexes::new(),
@@ -196,6 +236,7 @@ impl AuthMiddleware {
             pyx_token_state: Mutex::new(TokenState::Uninitialized),
             s3_credential_state: Mutex::new(S3CredentialState::Uninitialized),
             gcs_credential_state: Mutex::new(GcsCredentialState::Uninitialized),
+            cloud_token_providers: Mutex::new(FxHashMap::default()),
             preview: Preview::default(),
         }
     }
@@ -213,6 +254,19 @@ impl AuthMiddleware {
         self
     }
 
+    /// Configure the [`Pypirc`] credential file to use.
+    ///
+    /// `None` disables authentication via `.pypirc`.
+    #[must_use]
+    pub fn with_pypirc(mut self, pypirc: Option<Pypirc>) -> Self {
+        self.pypirc = if let Some(pypirc) = pypirc {
+            PypircMode::Enabled(pypirc)
+        } else {
+            PypircMode::Disabled
+        };
+        self
+    }
+
     /// Configure the text credential store to use.
     ///
     /// `None` disables authentication via text store.
@@ -233,6 +287,13 @@ impl AuthMiddleware {
         self
     }
 
+    /// Configure the [`CredentialHelper`] to use.
+    #[must_use]
+    pub fn with_credential_helper(mut self, credential_helper: Option<CredentialHelper>) -> Self {
+        self.credential_helper = credential_helper;
+        self
+    }
+
     /// Configure the [`Preview`] features to use.
     #[must_use]
     pub fn with_preview(mut self, preview: Preview) -> Self {
@@ -307,7 +368,7 @@ impl Middleware for AuthMiddleware {
     /// The discovered credentials must have the requested username to be used.
     ///
     /// - Check the cache (index URL or realm key) for a password
-    /// - Check the netrc for a password
+    /// - Check the netrc or `.pypirc` for a password
     /// - Check the keyring for a password
     /// - Perform the request
     /// - Add the username and password to the cache if successful
@@ -324,7 +385,7 @@ impl Middleware for AuthMiddleware {
     /// - Perform the request
     /// - On 401, 403, or 404 check for authentication if there was a cache miss
     ///     - Check the cache (index URL or realm key) for the username and password
-    ///     - Check the netrc for a username and password
+    ///     - Check the netrc or `.pypirc` for a username and password
     ///     - Perform the request again if found
     ///     - Add the username and password to the cache if successful
     async fn handle(
@@ -748,6 +809,21 @@ impl AuthMiddleware {
             }
         }
 
+        if let Some(registry) = CloudRegistry::from_url(url, self.preview) {
+            let credentials = self
+                .cloud_token_providers
+                .lock()
+                .await
+                .entry(registry.clone())
+                .or_insert_with(|| {
+                    Arc::new(Authentication::from(CloudTokenProvider::new(registry)))
+                })
+                .clone();
+            debug!("Using cloud registry token for {url}");
+            self.cache().fetches.done(key, Some(credentials.clone()));
+            return Some(credentials);
+        }
+
         // If this is a known URL, authenticate it via the token store.
         if let Some(base_client) = self.base_client.as_ref() {
             if let Some(token_store) = self.pyx_token_store.as_ref() {
@@ -802,6 +878,19 @@ impl AuthMiddleware {
             debug!("Found credentials in netrc file for {url}");
             Some(credentials)
 
+        // `.pypirc` support, for compatibility with `twine`.
+        } else if let Some(credentials) = self.pypirc.get().and_then(|pypirc| {
+            debug!("Checking `.pypirc` for credentials for {url}");
+            pypirc.credentials_for(
+                url,
+                credentials
+                    .as_ref()
+                    .and_then(|credentials| credentials.username()),
+            )
+        }) {
+            debug!("Found credentials in `.pypirc` file for {url}");
+            Some(credentials)
+
         // Text credential store support.
         } else if let Some(credentials) = self.text_store.get().await.and_then(|text_store| {
             debug!("Checking text store for credentials for {url}");
@@ -820,6 +909,23 @@ impl AuthMiddleware {
         }) {
             debug!("Found credentials in plaintext store for {url}");
             Some(credentials)
+
+        // Credential helper support.
+        } else if let Some(credentials) = match self.credential_helper {
+            Some(ref helper) => {
+                helper
+                    .fetch(
+                        url,
+                        credentials
+                            .as_ref()
+                            .and_then(|credentials| credentials.username()),
+                    )
+                    .await
+            }
+            None => None,
+        } {
+            debug!("Found credentials from credential helper for {url}");
+            Some(credentials)
         } else if let Some(credentials) = {
             if self.preview.is_enabled(PreviewFeature::NativeAuth) {
                 let native_store = KeyringProvider::native();
@@ -860,7 +966,13 @@ impl AuthMiddleware {
                 // The subprocess keyring provider is _slow_ so we do not perform fetches for all
                 // URLs; instead, we fetch if there's a username or if the user has requested to
                 // always authenticate.
-                if let Some(username) = credentials.and_then(|credentials| credentials.username()) {
+                if let Some(service) = index.and_then(|index| index.keyring_service.as_deref()) {
+                    let username = credentials.and_then(|credentials| credentials.username());
+                    debug!("Checking keyring service `{service}` for credentials for {url}");
+                    keyring.fetch_service(service, username).await
+                } else if let Some(username) =
+                    credentials.and_then(|credentials| credentials.username())
+                {
                     if let Some(index) = index {
                         debug!(
                             "Checking keyring for credentials for index URL {}@{}",
@@ -1248,6 +1360,42 @@ mod tests {
         Ok(())
     }
 
+    #[test(tokio::test)]
+    async fn test_pypirc_file_matching_realm() -> Result<(), Error> {
+        let username = "user";
+        let password = "password";
+        let server = start_test_server(username, password).await;
+
+        let mut pypirc_file = NamedTempFile::new()?;
+        writeln!(
+            pypirc_file,
+            "[distutils]\nindex-servers = private\n\n[private]\nrepository = {}/upload/\nusername = {username}\npassword = {password}",
+            server.uri()
+        )?;
+
+        let client = test_client_builder()
+            .with(
+                AuthMiddleware::new()
+                    .with_cache(CredentialsCache::new())
+                    .with_pypirc(Some(
+                        Pypirc::from_file(pypirc_file.path()).expect("Test has valid pypirc file"),
+                    )),
+            )
+            .build();
+
+        assert_eq!(
+            client
+                .get(format!("{}/simple/", server.uri()))
+                .send()
+                .await?
+                .status(),
+            200,
+            "Credentials should be pulled from the pypirc file"
+        );
+
+        Ok(())
+    }
+
     #[test(tokio::test)]
     async fn test_netrc_file_mismatched_host() -> Result<(), Error> {
         let username = "user";
@@ -2105,11 +2253,13 @@ mod tests {
                 url: DisplaySafeUrl::from_url(base_url_1.clone()),
                 root_url: DisplaySafeUrl::from_url(base_url_1.clone()),
                 auth_policy: AuthPolicy::Auto,
+                keyring_service: None,
             },
             Index {
                 url: DisplaySafeUrl::from_url(base_url_2.clone()),
                 root_url: DisplaySafeUrl::from_url(base_url_2.clone()),
                 auth_policy: AuthPolicy::Auto,
+                keyring_service: None,
             },
         ]);
 
@@ -2179,6 +2329,72 @@ mod tests {
         Ok(())
     }
 
+    /// Credentials should be fetched from a configured credential helper.
+    #[cfg(unix)]
+    #[test(tokio::test)]
+    async fn test_credentials_from_credential_helper() -> Result<(), Error> {
+        let username = "user";
+        let password = "password";
+
+        let server = start_test_server(username, password).await;
+        let client = test_client_builder()
+            .with(
+                AuthMiddleware::new()
+                    .with_cache(CredentialsCache::new())
+                    .with_credential_helper(Some(CredentialHelper::new(format!(
+                        "printf 'username={username}\\npassword={password}\\n' #"
+                    )))),
+            )
+            .build();
+
+        assert_eq!(
+            client.get(server.uri()).send().await?.status(),
+            200,
+            "Credentials should be fetched from the credential helper"
+        );
+
+        Ok(())
+    }
+
+    /// An index with a configured keyring service should query the keyring for that service,
+    /// even without a username.
+    #[test(tokio::test)]
+    async fn test_credentials_from_keyring_service() -> Result<(), Error> {
+        let username = "user";
+        let password = "password";
+
+        let server = start_test_server(username, password).await;
+        let base_url = Url::parse(&server.uri())?;
+        let index_url = base_url.join("simple/")?;
+        let indexes = Indexes::from_indexes(vec![Index {
+            url: DisplaySafeUrl::from_url(index_url.clone()),
+            root_url: DisplaySafeUrl::from_url(index_url.clone()),
+            auth_policy: AuthPolicy::Auto,
+            keyring_service: Some("my-service".to_string()),
+        }]);
+
+        let client = test_client_builder()
+            .with(
+                AuthMiddleware::new()
+                    .with_cache(CredentialsCache::new())
+                    .with_keyring(Some(KeyringProvider::dummy([(
+                        "my-service",
+                        username,
+                        password,
+                    )])))
+                    .with_indexes(indexes),
+            )
+            .build();
+
+        assert_eq!(
+            client.get(index_url.join("foo")?).send().await?.status(),
+            200,
+            "Credentials should be fetched from the keyring service"
+        );
+
+        Ok(())
+    }
+
     /// Demonstrates that when an index' credentials are cached for its realm, we
     /// find those credentials if they're not present in the keyring.
     #[test(tokio::test)]
@@ -2213,6 +2429,7 @@ mod tests {
             url: DisplaySafeUrl::from_url(index_url.clone()),
             root_url: DisplaySafeUrl::from_url(index_url.clone()),
             auth_policy: AuthPolicy::Auto,
+            keyring_service: None,
         }]);
 
         let client = test_client_builder()
@@ -2272,6 +2489,7 @@ mod tests {
             url: url.clone(),
             root_url: url.clone(),
             auth_policy: policy,
+            keyring_service: None,
         }])
     }
 
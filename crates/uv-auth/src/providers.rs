@@ -3,7 +3,7 @@ use std::sync::LazyLock;
 
 use reqsign::aws::DefaultSigner as AwsDefaultSigner;
 use reqsign::google::DefaultSigner as GcsDefaultSigner;
-use tracing::debug;
+use tracing::{debug, trace};
 use url::Url;
 
 use uv_preview::{Preview, PreviewFeature};
@@ -143,3 +143,384 @@ impl GcsEndpointProvider {
         reqsign::google::default_signer("storage.googleapis.com")
     }
 }
+
+/// The tolerance before expiry at which a cloud registry token is refreshed, in seconds.
+const CLOUD_TOKEN_TOLERANCE_SECS: i64 = 5 * 60;
+
+/// A cloud artifact registry that issues short-lived tokens via its command-line tooling.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) enum CloudRegistry {
+    /// AWS `CodeArtifact`, as in
+    /// `https://<domain>-<owner>.d.codeartifact.<region>.amazonaws.com/pypi/<repository>/simple/`.
+    CodeArtifact {
+        domain: String,
+        owner: String,
+        region: String,
+    },
+    /// Google Artifact Registry, as in `https://<region>-python.pkg.dev/<project>/<repository>/simple/`.
+    ArtifactRegistry,
+    /// Azure Artifacts, as in `https://pkgs.dev.azure.com/<organization>/_packaging/<feed>/pypi/simple/`.
+    AzureArtifacts,
+}
+
+impl std::fmt::Display for CloudRegistry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::CodeArtifact { domain, .. } => write!(f, "AWS CodeArtifact domain `{domain}`"),
+            Self::ArtifactRegistry => write!(f, "Google Artifact Registry"),
+            Self::AzureArtifacts => write!(f, "Azure Artifacts"),
+        }
+    }
+}
+
+/// An error that can occur when minting a cloud registry token.
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum CloudTokenError {
+    #[error("Failed to run `{0}`")]
+    Spawn(&'static str, #[source] std::io::Error),
+    #[error("`{0}` exited with {1}: {2}")]
+    Failed(&'static str, std::process::ExitStatus, String),
+    #[error("`{0}` returned an invalid token: {1}")]
+    Parse(&'static str, String),
+}
+
+impl CloudRegistry {
+    /// Returns the [`CloudRegistry`] that serves the given URL, if the `cloud-registry-auth`
+    /// preview feature is enabled.
+    pub(crate) fn from_url(url: &Url, preview: Preview) -> Option<Self> {
+        if !preview.is_enabled(PreviewFeature::CloudRegistryAuth) {
+            return None;
+        }
+        Self::parse(url)
+    }
+
+    fn parse(url: &Url) -> Option<Self> {
+        if url.scheme() != "https" {
+            return None;
+        }
+        let host = url.host_str()?.to_ascii_lowercase();
+
+        // `<domain>-<owner>.d.codeartifact.<region>.amazonaws.com`, where the owner is a 12-digit
+        // AWS account ID.
+        if let Some(rest) = host.strip_suffix(".amazonaws.com") {
+            let (prefix, region) = rest.split_once(".d.codeartifact.")?;
+            let (domain, owner) = prefix.rsplit_once('-')?;
+            if domain.is_empty()
+                || region.is_empty()
+                || region.contains('.')
+                || owner.len() != 12
+                || !owner.bytes().all(|byte| byte.is_ascii_digit())
+            {
+                return None;
+            }
+            return Some(Self::CodeArtifact {
+                domain: domain.to_string(),
+                owner: owner.to_string(),
+                region: region.to_string(),
+            });
+        }
+
+        if host
+            .strip_suffix("-python.pkg.dev")
+            .is_some_and(|region| !region.is_empty() && !region.contains('.'))
+        {
+            return Some(Self::ArtifactRegistry);
+        }
+
+        if host == "pkgs.dev.azure.com" || host.ends_with(".pkgs.visualstudio.com") {
+            return Some(Self::AzureArtifacts);
+        }
+
+        None
+    }
+
+    /// The command-line tool used to mint tokens for the registry.
+    fn program(&self) -> &'static str {
+        match self {
+            Self::CodeArtifact { .. } => "aws",
+            Self::ArtifactRegistry => "gcloud",
+            Self::AzureArtifacts => "az",
+        }
+    }
+
+    /// The arguments passed to the command-line tool to mint a token.
+    fn args(&self) -> Vec<&str> {
+        match self {
+            Self::CodeArtifact {
+                domain,
+                owner,
+                region,
+            } => vec![
+                "codeartifact",
+                "get-authorization-token",
+                "--domain",
+                domain,
+                "--domain-owner",
+                owner,
+                "--region",
+                region,
+                "--output",
+                "json",
+            ],
+            Self::ArtifactRegistry => vec!["auth", "print-access-token"],
+            // The resource ID of Azure DevOps, which hosts Azure Artifacts.
+            Self::AzureArtifacts => vec![
+                "account",
+                "get-access-token",
+                "--resource",
+                "499b84ac-1321-427f-aa17-267ca6975798",
+                "--output",
+                "json",
+            ],
+        }
+    }
+
+    /// Mint a new token for the registry.
+    async fn mint(&self) -> Result<CloudToken, CloudTokenError> {
+        let program = self.program();
+        debug!("Minting token for {self} with `{program}`");
+        let output = tokio::process::Command::new(program)
+            .args(self.args())
+            .stdin(std::process::Stdio::null())
+            .output()
+            .await
+            .map_err(|err| CloudTokenError::Spawn(program, err))?;
+        if !output.status.success() {
+            return Err(CloudTokenError::Failed(
+                program,
+                output.status,
+                String::from_utf8_lossy(&output.stderr).trim().to_string(),
+            ));
+        }
+        self.parse_token(
+            &String::from_utf8_lossy(&output.stdout),
+            jiff::Timestamp::now(),
+        )
+    }
+
+    /// Parse the output of the command-line tool into a [`CloudToken`].
+    fn parse_token(
+        &self,
+        stdout: &str,
+        now: jiff::Timestamp,
+    ) -> Result<CloudToken, CloudTokenError> {
+        let program = self.program();
+        let invalid = |reason: &str| CloudTokenError::Parse(program, reason.to_string());
+        match self {
+            Self::CodeArtifact { .. } => {
+                #[derive(serde::Deserialize)]
+                #[serde(rename_all = "camelCase")]
+                struct Response {
+                    authorization_token: String,
+                    expiration: Option<String>,
+                }
+                let response: Response =
+                    serde_json::from_str(stdout).map_err(|err| invalid(&err.to_string()))?;
+                // CodeArtifact tokens are valid for 12 hours by default.
+                let expires_at = response
+                    .expiration
+                    .and_then(|expiration| expiration.parse::<jiff::Timestamp>().ok())
+                    .unwrap_or_else(|| now + jiff::SignedDuration::from_hours(12));
+                Ok(CloudToken {
+                    credentials: Credentials::basic(
+                        Some("aws".to_string()),
+                        Some(response.authorization_token),
+                    ),
+                    expires_at,
+                })
+            }
+            Self::ArtifactRegistry => {
+                let token = stdout.trim();
+                if token.is_empty() {
+                    return Err(invalid("empty output"));
+                }
+                // `gcloud` doesn't report the expiry, and may return a cached token that's valid
+                // for less than its full hour, so re-run it periodically; it only issues a new
+                // token once the cached one is close to expiry.
+                Ok(CloudToken {
+                    credentials: Credentials::basic(
+                        Some("oauth2accesstoken".to_string()),
+                        Some(token.to_string()),
+                    ),
+                    expires_at: now + jiff::SignedDuration::from_mins(15),
+                })
+            }
+            Self::AzureArtifacts => {
+                #[derive(serde::Deserialize)]
+                struct Response {
+                    #[serde(rename = "accessToken")]
+                    access_token: String,
+                    expires_on: Option<i64>,
+                }
+                let response: Response =
+                    serde_json::from_str(stdout).map_err(|err| invalid(&err.to_string()))?;
+                // Older versions of `az` only report the expiry in local time, so assume a short
+                // lifetime instead.
+                let expires_at = response
+                    .expires_on
+                    .and_then(|expires_on| jiff::Timestamp::from_second(expires_on).ok())
+                    .unwrap_or_else(|| now + jiff::SignedDuration::from_mins(15));
+                Ok(CloudToken {
+                    credentials: Credentials::basic(
+                        Some("azure".to_string()),
+                        Some(response.access_token),
+                    ),
+                    expires_at,
+                })
+            }
+        }
+    }
+}
+
+/// A short-lived token for a [`CloudRegistry`].
+#[derive(Debug, Clone)]
+struct CloudToken {
+    credentials: Credentials,
+    expires_at: jiff::Timestamp,
+}
+
+impl CloudToken {
+    /// Returns `true` if the token expires within the refresh tolerance.
+    fn is_expiring(&self, now: jiff::Timestamp) -> bool {
+        self.expires_at < now + jiff::SignedDuration::from_secs(CLOUD_TOKEN_TOLERANCE_SECS)
+    }
+}
+
+/// A provider for short-lived credentials for a [`CloudRegistry`], which mints a new token
+/// whenever the current token is close to expiry.
+#[derive(Debug)]
+pub(crate) struct CloudTokenProvider {
+    registry: CloudRegistry,
+    token: tokio::sync::Mutex<Option<CloudToken>>,
+}
+
+impl CloudTokenProvider {
+    pub(crate) fn new(registry: CloudRegistry) -> Self {
+        Self {
+            registry,
+            token: tokio::sync::Mutex::new(None),
+        }
+    }
+
+    /// Returns the current credentials for the registry, minting a new token if necessary.
+    pub(crate) async fn credentials(&self) -> Option<Credentials> {
+        let mut token = self.token.lock().await;
+        let now = jiff::Timestamp::now();
+        if let Some(token) = token.as_ref().filter(|token| !token.is_expiring(now)) {
+            return Some(token.credentials.clone());
+        }
+        if token.is_some() {
+            debug!("Token for {} is expiring; refreshing", self.registry);
+        }
+        match self.registry.mint().await {
+            Ok(minted) => {
+                trace!(
+                    "Minted token for {} (expires at `{}`)",
+                    self.registry, minted.expires_at
+                );
+                let credentials = minted.credentials.clone();
+                *token = Some(minted);
+                Some(credentials)
+            }
+            Err(err) => {
+                warn_user_once!("Failed to fetch a token for {}: {err}", self.registry);
+                None
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_cloud_registry() {
+        let parse = |url: &str| CloudRegistry::parse(&Url::parse(url).unwrap());
+
+        assert_eq!(
+            parse(
+                "https://my-domain-111122223333.d.codeartifact.us-west-2.amazonaws.com/pypi/my-repo/simple/"
+            ),
+            Some(CloudRegistry::CodeArtifact {
+                domain: "my-domain".to_string(),
+                owner: "111122223333".to_string(),
+                region: "us-west-2".to_string(),
+            })
+        );
+        assert_eq!(
+            parse("https://us-central1-python.pkg.dev/my-project/my-repo/simple/"),
+            Some(CloudRegistry::ArtifactRegistry)
+        );
+        assert_eq!(
+            parse("https://pkgs.dev.azure.com/my-org/_packaging/my-feed/pypi/simple/"),
+            Some(CloudRegistry::AzureArtifacts)
+        );
+        assert_eq!(
+            parse("https://my-org.pkgs.visualstudio.com/_packaging/my-feed/pypi/simple/"),
+            Some(CloudRegistry::AzureArtifacts)
+        );
+
+        // The account ID must be 12 digits.
+        assert_eq!(
+            parse(
+                "https://my-domain-1111.d.codeartifact.us-west-2.amazonaws.com/pypi/my-repo/simple/"
+            ),
+            None
+        );
+        // Tokens are never minted for insecure URLs.
+        assert_eq!(
+            parse("http://us-central1-python.pkg.dev/my-project/my-repo/simple/"),
+            None
+        );
+        assert_eq!(parse("https://pypi.org/simple/"), None);
+        assert_eq!(parse("https://s3.us-west-2.amazonaws.com/bucket/"), None);
+    }
+
+    #[test]
+    fn parse_cloud_token() {
+        let now = jiff::Timestamp::from_second(1_700_000_000).unwrap();
+
+        let registry = CloudRegistry::CodeArtifact {
+            domain: "my-domain".to_string(),
+            owner: "111122223333".to_string(),
+            region: "us-west-2".to_string(),
+        };
+        let token = registry
+            .parse_token(
+                r#"{"authorizationToken": "secret", "expiration": "2023-11-15T00:00:00+00:00"}"#,
+                now,
+            )
+            .unwrap();
+        assert_eq!(token.credentials.username(), Some("aws"));
+        assert_eq!(token.credentials.password(), Some("secret"));
+        assert_eq!(token.expires_at.to_string(), "2023-11-15T00:00:00Z");
+
+        let token = CloudRegistry::ArtifactRegistry
+            .parse_token("secret\n", now)
+            .unwrap();
+        assert_eq!(token.credentials.username(), Some("oauth2accesstoken"));
+        assert_eq!(token.credentials.password(), Some("secret"));
+        assert!(!token.is_expiring(now));
+        assert!(token.is_expiring(now + jiff::SignedDuration::from_mins(11)));
+
+        let token = CloudRegistry::AzureArtifacts
+            .parse_token(
+                r#"{"accessToken": "secret", "expiresOn": "2023-11-14 23:13:20.000000", "expires_on": 1700003600}"#,
+                now,
+            )
+            .unwrap();
+        assert_eq!(token.credentials.username(), Some("azure"));
+        assert_eq!(token.credentials.password(), Some("secret"));
+        assert_eq!(
+            token.expires_at,
+            jiff::Timestamp::from_second(1_700_003_600).unwrap()
+        );
+
+        assert!(
+            CloudRegistry::ArtifactRegistry
+                .parse_token("  \n", now)
+                .is_err()
+        );
+    }
+}
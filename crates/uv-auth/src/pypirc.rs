@@ -0,0 +1,270 @@
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use configparser::ini::Ini;
+use thiserror::Error;
+use tracing::debug;
+
+use uv_redacted::{DisplaySafeUrl, DisplaySafeUrlError};
+
+use crate::credentials::Credentials;
+use crate::realm::Realm;
+
+/// The upload URL used for the `pypi` repository if the `.pypirc` file doesn't define one.
+const DEFAULT_PYPI_URL: &str = "https://upload.pypi.org/legacy/";
+
+/// The upload URL used for the `testpypi` repository if the `.pypirc` file doesn't define one.
+const DEFAULT_TESTPYPI_URL: &str = "https://test.pypi.org/legacy/";
+
+#[derive(Debug, Error)]
+pub enum PypircError {
+    #[error("Failed to read `{0}`")]
+    Io(PathBuf, #[source] std::io::Error),
+    #[error("Failed to parse `{0}`: {1}")]
+    Parse(PathBuf, String),
+    #[error("Invalid `repository` URL for `{0}` in `{1}`")]
+    InvalidUrl(String, PathBuf, #[source] DisplaySafeUrlError),
+}
+
+/// A repository defined in a `.pypirc` file, as used by `twine`.
+#[derive(Clone)]
+pub struct PypircRepository {
+    /// The name of the repository, i.e., its section in the `.pypirc` file.
+    pub name: String,
+    /// The upload URL of the repository.
+    pub url: DisplaySafeUrl,
+    pub username: Option<String>,
+    pub password: Option<String>,
+}
+
+impl fmt::Debug for PypircRepository {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PypircRepository")
+            .field("name", &self.name)
+            .field("url", &self.url)
+            .field("username", &self.username)
+            .field("password", &self.password.as_ref().map(|_| "****"))
+            .finish()
+    }
+}
+
+impl PypircRepository {
+    /// The [`Credentials`] configured for the repository, if a password is set.
+    pub fn credentials(&self) -> Option<Credentials> {
+        let password = self.password.clone()?;
+        Some(Credentials::basic(self.username.clone(), Some(password)))
+    }
+}
+
+/// The repositories and credentials from a `.pypirc` file.
+///
+/// See: <https://packaging.python.org/en/latest/specifications/pypirc/>
+#[derive(Debug, Clone, Default)]
+pub struct Pypirc {
+    repositories: Vec<PypircRepository>,
+}
+
+impl Pypirc {
+    /// Read the `.pypirc` file from the user's home directory.
+    ///
+    /// Returns `None` if the file doesn't exist.
+    pub fn from_default_path() -> Result<Option<Self>, PypircError> {
+        let Ok(home) = etcetera::home_dir() else {
+            return Ok(None);
+        };
+        let path = home.join(".pypirc");
+        match Self::from_file(&path) {
+            Ok(pypirc) => Ok(Some(pypirc)),
+            Err(PypircError::Io(_, err)) if err.kind() == std::io::ErrorKind::NotFound => {
+                debug!("No `.pypirc` file found");
+                Ok(None)
+            }
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Read a `.pypirc` file from the given path.
+    pub fn from_file(path: &Path) -> Result<Self, PypircError> {
+        let contents =
+            fs_err::read_to_string(path).map_err(|err| PypircError::Io(path.to_path_buf(), err))?;
+        Self::parse(&contents, path)
+    }
+
+    fn parse(contents: &str, path: &Path) -> Result<Self, PypircError> {
+        let mut ini = Ini::new_cs();
+        ini.set_multiline(true);
+        // Like `twine`, treat `#` and `;` as part of the value, since they may appear in passwords.
+        ini.set_inline_comment_symbols(Some(&[]));
+        ini.read(contents.to_string())
+            .map_err(|err| PypircError::Parse(path.to_path_buf(), err))?;
+
+        // If `index-servers` is omitted, consider every section a repository.
+        let names: Vec<String> = match ini.get("distutils", "index-servers") {
+            Some(servers) => servers
+                .split_whitespace()
+                .map(ToString::to_string)
+                .collect(),
+            None => ini
+                .sections()
+                .into_iter()
+                .filter(|section| section != "distutils")
+                .collect(),
+        };
+
+        let mut repositories = Vec::with_capacity(names.len());
+        for name in names {
+            let url = match ini.get(&name, "repository") {
+                Some(url) => url,
+                None if name == "pypi" => DEFAULT_PYPI_URL.to_string(),
+                None if name == "testpypi" => DEFAULT_TESTPYPI_URL.to_string(),
+                None => {
+                    debug!("Skipping `.pypirc` repository `{name}` without a `repository` URL");
+                    continue;
+                }
+            };
+            let url = DisplaySafeUrl::parse(url.trim())
+                .map_err(|err| PypircError::InvalidUrl(name.clone(), path.to_path_buf(), err))?;
+            repositories.push(PypircRepository {
+                username: ini.get(&name, "username"),
+                password: ini.get(&name, "password"),
+                name,
+                url,
+            });
+        }
+
+        Ok(Self { repositories })
+    }
+
+    /// Return the repository with the given name.
+    pub fn repository(&self, name: &str) -> Option<&PypircRepository> {
+        self.repositories
+            .iter()
+            .find(|repository| repository.name == name)
+    }
+
+    /// Return the [`Credentials`] for the given URL.
+    ///
+    /// Repositories are matched by realm, as the configured URL is typically the upload URL,
+    /// rather than the URL of the index. If several repositories share the realm, the one whose URL
+    /// is the longest prefix of the request URL is preferred. If a username is provided, it must
+    /// match the configured username.
+    pub fn credentials_for(
+        &self,
+        url: &DisplaySafeUrl,
+        username: Option<&str>,
+    ) -> Option<Credentials> {
+        let realm = Realm::from(url);
+        self.repositories
+            .iter()
+            .filter(|repository| Realm::from(&repository.url) == realm)
+            .filter(|repository| {
+                username.is_none_or(|username| repository.username.as_deref() == Some(username))
+            })
+            .filter_map(|repository| {
+                let credentials = repository.credentials()?;
+                let specificity = if url.path().starts_with(repository.url.path()) {
+                    repository.url.path().len()
+                } else {
+                    0
+                };
+                Some((specificity, credentials))
+            })
+            // Prefer the first repository on ties, in the order of the file.
+            .rev()
+            .max_by_key(|(specificity, _)| *specificity)
+            .map(|(_, credentials)| credentials)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(contents: &str) -> Pypirc {
+        Pypirc::parse(contents, Path::new(".pypirc")).unwrap()
+    }
+
+    #[test]
+    fn repositories() {
+        let pypirc = parse(
+            "[distutils]\n\
+             index-servers =\n    pypi\n    testpypi\n    private\n\n\
+             [pypi]\n\
+             username = __token__\n\
+             password = pypi-token#;\n\n\
+             [testpypi]\n\
+             username = __token__\n\n\
+             [private]\n\
+             repository = https://example.com/api/pypi/upload/\n\
+             username = ci\n\
+             password = secret\n\n\
+             [unlisted]\n\
+             repository = https://unlisted.example.com/\n",
+        );
+
+        let pypi = pypirc.repository("pypi").unwrap();
+        assert_eq!(pypi.url.as_str(), DEFAULT_PYPI_URL);
+        assert_eq!(pypi.username.as_deref(), Some("__token__"));
+        assert_eq!(pypi.password.as_deref(), Some("pypi-token#;"));
+
+        let testpypi = pypirc.repository("testpypi").unwrap();
+        assert_eq!(testpypi.url.as_str(), DEFAULT_TESTPYPI_URL);
+        assert_eq!(testpypi.credentials(), None);
+
+        let private = pypirc.repository("private").unwrap();
+        assert_eq!(private.url.as_str(), "https://example.com/api/pypi/upload/");
+
+        // Sections not listed in `index-servers` are ignored.
+        assert!(pypirc.repository("unlisted").is_none());
+    }
+
+    #[test]
+    fn repositories_without_index_servers() {
+        let pypirc = parse(
+            "[private]\n\
+             repository = https://example.com/upload/\n\
+             username = ci\n\
+             password = secret\n\n\
+             [incomplete]\n\
+             username = ci\n",
+        );
+        assert!(pypirc.repository("private").is_some());
+        assert!(pypirc.repository("incomplete").is_none());
+    }
+
+    #[test]
+    fn credentials_for() {
+        let pypirc = parse(
+            "[distutils]\n\
+             index-servers = upload other\n\n\
+             [upload]\n\
+             repository = https://example.com/upload/\n\
+             username = upload\n\
+             password = upload-secret\n\n\
+             [other]\n\
+             repository = https://example.com/simple/other/\n\
+             username = other\n\
+             password = other-secret\n",
+        );
+
+        // The most specific repository in the realm is preferred.
+        let url = DisplaySafeUrl::parse("https://example.com/simple/other/anyio/").unwrap();
+        let credentials = pypirc.credentials_for(&url, None).unwrap();
+        assert_eq!(credentials.username(), Some("other"));
+
+        // Otherwise, the first repository in the realm is used.
+        let url = DisplaySafeUrl::parse("https://example.com/simple/anyio/").unwrap();
+        let credentials = pypirc.credentials_for(&url, None).unwrap();
+        assert_eq!(credentials.username(), Some("upload"));
+        assert_eq!(credentials.password(), Some("upload-secret"));
+
+        // The username must match, if provided.
+        let credentials = pypirc.credentials_for(&url, Some("other")).unwrap();
+        assert_eq!(credentials.password(), Some("other-secret"));
+        assert_eq!(pypirc.credentials_for(&url, Some("unknown")), None);
+
+        // Other realms don't match.
+        let url = DisplaySafeUrl::parse("http://example.com/simple/anyio/").unwrap();
+        assert_eq!(pypirc.credentials_for(&url, None), None);
+    }
+}
@@ -9,7 +9,7 @@ pub use metadata::{PyProjectToml, check_direct_build};
 pub use settings::{BuildBackendSettings, WheelDataIncludes};
 pub use source_dist::{build_source_dist, list_source_dist};
 use uv_warnings::warn_user_once;
-pub use wheel::{build_editable, build_wheel, list_wheel, metadata};
+pub use wheel::{build_editable, build_wheel, list_wheel, metadata, retag_wheel};
 
 use std::collections::HashSet;
 use std::ffi::OsStr;
@@ -20,6 +20,7 @@ use thiserror::Error;
 use tracing::debug;
 use walkdir::DirEntry;
 
+use uv_distribution_filename::WheelFilenameError;
 use uv_fs::Simplified;
 use uv_globfilter::PortableGlobError;
 use uv_normalize::PackageName;
@@ -83,6 +84,14 @@ pub enum Error {
     InconsistentSteps(&'static str),
     #[error("Failed to write to {}", _0.user_display())]
     TarWrite(PathBuf, #[source] io::Error),
+    #[error(transparent)]
+    InvalidWheelFilename(#[from] WheelFilenameError),
+    #[error("Expected exactly one `.dist-info/WHEEL` file in: {}", _0.user_display())]
+    MissingWheelFile(PathBuf),
+    #[error(
+        "The platform tag `{target}` is not plausible for a wheel tagged as `{original}`, since it targets a different operating system or architecture"
+    )]
+    ImplausiblePlatformTag { original: String, target: String },
 }
 
 /// Dispatcher between writing to a directory, writing to a zip, writing to a `.tar.gz` and
@@ -1,15 +1,17 @@
 use base64::{Engine, prelude::BASE64_URL_SAFE_NO_PAD as base64};
 use fs_err::File;
 use globset::{GlobSet, GlobSetBuilder};
+use itertools::Itertools;
 use rustc_hash::FxHashSet;
 use sha2::{Digest, Sha256};
-use std::fmt::{Display, Formatter};
+use std::fmt::{Display, Formatter, Write as _};
 use std::io::{BufReader, Read, Seek, Write};
 use std::path::{Component, Path, PathBuf};
+use std::str::FromStr;
 use std::{io, mem};
 use tracing::{debug, trace};
 use walkdir::WalkDir;
-use zip::{CompressionMethod, ZipWriter};
+use zip::{CompressionMethod, ZipArchive, ZipWriter};
 
 use uv_distribution_filename::WheelFilename;
 use uv_fs::Simplified;
@@ -386,6 +388,199 @@ pub fn metadata(
     Ok(dist_info_dir)
 }
 
+/// Rewrite the tags of an existing wheel, e.g., after patching and auditing it.
+///
+/// The `WHEEL` file is updated with the new tags, and the `RECORD` file is regenerated from the
+/// archive contents, such that patched files are recorded with their current hashes. `RECORD`
+/// signatures are dropped, since they would no longer be valid.
+///
+/// Tags that aren't provided are retained from the original wheel. Unless `force` is set, each new
+/// platform tag must target the same operating system and architecture as one of the original
+/// platform tags, e.g., `linux_x86_64` may be retagged as `manylinux_2_17_x86_64`, but not as
+/// `manylinux_2_17_aarch64`.
+pub fn retag_wheel(
+    wheel: &Path,
+    output_dir: &Path,
+    python_tags: Option<&[LanguageTag]>,
+    abi_tags: Option<&[AbiTag]>,
+    platform_tags: Option<&[PlatformTag]>,
+    force: bool,
+) -> Result<WheelFilename, Error> {
+    let filename = WheelFilename::from_str(
+        &wheel
+            .file_name()
+            .map(|file_name| file_name.to_string_lossy())
+            .unwrap_or_default(),
+    )?;
+
+    let python_tags = python_tags.unwrap_or(filename.python_tags());
+    let abi_tags = abi_tags.unwrap_or(filename.abi_tags());
+    let platform_tags = platform_tags.unwrap_or(filename.platform_tags());
+
+    if !force {
+        for platform_tag in platform_tags {
+            if !filename
+                .platform_tags()
+                .iter()
+                .any(|original| is_plausible_platform_tag(original, platform_tag))
+            {
+                return Err(Error::ImplausiblePlatformTag {
+                    original: filename.platform_tags().iter().join("."),
+                    target: platform_tag.to_string(),
+                });
+            }
+        }
+    }
+
+    let retagged = WheelFilename::from_str(&format!(
+        "{}-{}{}-{}-{}-{}.whl",
+        filename.name.as_dist_info_name(),
+        filename.version,
+        filename
+            .build_tag()
+            .map(|build_tag| format!("-{build_tag}"))
+            .unwrap_or_default(),
+        python_tags.iter().join("."),
+        abi_tags.iter().join("."),
+        platform_tags.iter().join("."),
+    ))?;
+
+    let mut archive = ZipArchive::new(BufReader::new(File::open(wheel)?))?;
+
+    // Locate the `.dist-info` directory, which may not match the normalized name in the filename
+    // if the wheel was patched by hand.
+    let dist_info_dir = archive
+        .file_names()
+        .filter_map(|path| path.strip_suffix("/WHEEL"))
+        .filter(|dir| dir.ends_with(".dist-info") && !dir.contains('/'))
+        .exactly_one()
+        .map(ToString::to_string)
+        .map_err(|_| Error::MissingWheelFile(wheel.to_path_buf()))?;
+
+    let wheel_path = output_dir.join(retagged.to_string());
+    debug!("Writing retagged wheel at {}", wheel_path.user_display());
+
+    let temp_file = uv_fs::tempfile_in(output_dir)?;
+    let mut writer = ZipDirectoryWriter::new_wheel(temp_file.as_file());
+    for index in 0..archive.len() {
+        let mut file = archive.by_index(index)?;
+        let path = file.name().to_string();
+
+        if file.is_dir() {
+            writer.write_directory(&path)?;
+            continue;
+        }
+
+        if path == format!("{dist_info_dir}/WHEEL") {
+            let mut contents = String::new();
+            file.read_to_string(&mut contents)?;
+            let contents = retag_wheel_info(&contents, python_tags, abi_tags, platform_tags);
+            writer.write_bytes(&path, contents.as_bytes())?;
+            continue;
+        }
+
+        // The `RECORD` file is regenerated on close.
+        if [
+            format!("{dist_info_dir}/RECORD"),
+            format!("{dist_info_dir}/RECORD.jws"),
+            format!("{dist_info_dir}/RECORD.p7s"),
+        ]
+        .contains(&path)
+        {
+            trace!("Dropping {path}");
+            continue;
+        }
+
+        trace!("Copying {path}");
+        let executable_bit = file.unix_mode().is_some_and(|mode| mode & 0o111 != 0);
+        let mut file_writer = writer.new_writer(&path, executable_bit)?;
+        let record = write_hashed(&path, &mut file, &mut file_writer)?;
+        drop(file_writer);
+        writer.record.push(record);
+    }
+    writer.close(&dist_info_dir)?;
+
+    // Release the original wheel before replacing it, in case the tags are unchanged.
+    drop(archive);
+    temp_file
+        .persist(&wheel_path)
+        .map_err(|err| Error::Persist(wheel_path.clone(), err.error))?;
+
+    Ok(retagged)
+}
+
+/// Returns `true` if a wheel tagged for the `original` platform could plausibly be valid for the
+/// `target` platform, i.e., both tags refer to the same operating system, C library, and
+/// architecture.
+fn is_plausible_platform_tag(original: &PlatformTag, target: &PlatformTag) -> bool {
+    if original == target {
+        return true;
+    }
+
+    // A pure Python wheel can't be made platform-specific, and vice versa.
+    if original.is_any() || target.is_any() {
+        return false;
+    }
+
+    let same_os = (original.is_linux() && target.is_linux())
+        || (original.is_macos() && target.is_macos())
+        || (original.is_windows() && target.is_windows())
+        || (original.is_android() && target.is_android());
+    if !same_os {
+        return false;
+    }
+
+    // `manylinux` wheels link against glibc and `musllinux` wheels against musl; only a generic
+    // `linux` wheel may be retagged as either.
+    let is_musllinux = |tag: &PlatformTag| matches!(tag, PlatformTag::Musllinux { .. });
+    if (original.is_manylinux() && is_musllinux(target))
+        || (is_musllinux(original) && target.is_manylinux())
+    {
+        return false;
+    }
+
+    let architectures: [fn(&PlatformTag) -> bool; 10] = [
+        PlatformTag::is_arm,
+        PlatformTag::is_x86_64,
+        PlatformTag::is_x86,
+        PlatformTag::is_ppc64le,
+        PlatformTag::is_ppc64,
+        PlatformTag::is_s390x,
+        PlatformTag::is_riscv64,
+        PlatformTag::is_loongarch64,
+        PlatformTag::is_armv7l,
+        PlatformTag::is_armv6l,
+    ];
+    architectures
+        .iter()
+        .any(|is_arch| is_arch(original) && is_arch(target))
+}
+
+/// Replace the `Tag` entries in the contents of a `WHEEL` file, retaining all other entries.
+fn retag_wheel_info(
+    contents: &str,
+    python_tags: &[LanguageTag],
+    abi_tags: &[AbiTag],
+    platform_tags: &[PlatformTag],
+) -> String {
+    let mut output = String::new();
+    for line in contents.lines() {
+        if line.starts_with("Tag:") {
+            continue;
+        }
+        output.push_str(line);
+        output.push('\n');
+    }
+    for python_tag in python_tags {
+        for abi_tag in abi_tags {
+            for platform_tag in platform_tags {
+                let _ = writeln!(output, "Tag: {python_tag}-{abi_tag}-{platform_tag}");
+            }
+        }
+    }
+    output
+}
+
 /// An entry in the `RECORD` file.
 ///
 /// <https://packaging.python.org/en/latest/specifications/recording-installed-packages/#the-record-file>
@@ -971,4 +1166,93 @@ mod test {
         Tag: py3-none-any
         ");
     }
+
+    #[test]
+    fn test_plausible_platform_tag() {
+        let plausible = |original: &str, target: &str| {
+            is_plausible_platform_tag(
+                &PlatformTag::from_str(original).unwrap(),
+                &PlatformTag::from_str(target).unwrap(),
+            )
+        };
+
+        assert!(plausible("linux_x86_64", "manylinux_2_17_x86_64"));
+        assert!(plausible("linux_x86_64", "musllinux_1_2_x86_64"));
+        assert!(plausible("manylinux_2_17_x86_64", "manylinux_2_28_x86_64"));
+        assert!(plausible("macosx_11_0_arm64", "macosx_14_0_arm64"));
+        assert!(plausible("win_amd64", "win_amd64"));
+        assert!(plausible("any", "any"));
+
+        assert!(!plausible("linux_x86_64", "manylinux_2_17_aarch64"));
+        assert!(!plausible("manylinux_2_17_x86_64", "musllinux_1_2_x86_64"));
+        assert!(!plausible("manylinux_2_17_x86_64", "macosx_11_0_x86_64"));
+        assert!(!plausible("win32", "win_amd64"));
+        assert!(!plausible("any", "manylinux_2_17_x86_64"));
+        assert!(!plausible("manylinux_2_17_x86_64", "any"));
+    }
+
+    /// Retag a wheel and check that `WHEEL` and `RECORD` are rewritten.
+    #[test]
+    fn test_retag_wheel() {
+        let _preview = uv_preview::test::with_features(&[]);
+        let built_by_uv = Path::new("../../test/packages/built-by-uv");
+        let wheel_dir = TempDir::new().unwrap();
+        let filename =
+            build_wheel(built_by_uv, wheel_dir.path(), None, "1.0.0+test", false).unwrap();
+
+        let output_dir = TempDir::new().unwrap();
+        let retagged = retag_wheel(
+            &wheel_dir.path().join(filename.to_string()),
+            output_dir.path(),
+            Some(&[LanguageTag::CPython {
+                python_version: (3, 12),
+            }]),
+            Some(&[AbiTag::Abi3]),
+            None,
+            false,
+        )
+        .unwrap();
+        assert_snapshot!(retagged, @"built_by_uv-0.1.0-cp312-abi3-any.whl");
+
+        let mut archive =
+            ZipArchive::new(File::open(output_dir.path().join(retagged.to_string())).unwrap())
+                .unwrap();
+        let mut wheel_file = String::new();
+        archive
+            .by_name("built_by_uv-0.1.0.dist-info/WHEEL")
+            .unwrap()
+            .read_to_string(&mut wheel_file)
+            .unwrap();
+        assert_snapshot!(wheel_file, @"
+        Wheel-Version: 1.0
+        Generator: uv 1.0.0+test
+        Root-Is-Purelib: true
+        Tag: cp312-abi3-any
+        ");
+
+        let mut record_file = String::new();
+        archive
+            .by_name("built_by_uv-0.1.0.dist-info/RECORD")
+            .unwrap()
+            .read_to_string(&mut record_file)
+            .unwrap();
+        let wheel_record = record_file
+            .lines()
+            .find(|line| line.starts_with("built_by_uv-0.1.0.dist-info/WHEEL,"))
+            .unwrap();
+        let wheel_hash = format!("sha256={}", base64.encode(Sha256::digest(&wheel_file)));
+        assert!(wheel_record.contains(&wheel_hash));
+
+        // A wheel can't be retagged for a different architecture.
+        let err = retag_wheel(
+            &output_dir.path().join(retagged.to_string()),
+            output_dir.path(),
+            None,
+            None,
+            Some(&[PlatformTag::from_str("manylinux_2_17_x86_64").unwrap()]),
+            false,
+        )
+        .unwrap_err();
+        assert_snapshot!(err, @"The platform tag `manylinux_2_17_x86_64` is not plausible for a wheel tagged as `any`, since it targets a different operating system or architecture");
+    }
 }
@@ -74,6 +74,8 @@ pub enum Error {
     RequirementsInstall(&'static str, #[source] AnyErrorBuild),
     #[error("Failed to create temporary virtualenv")]
     Virtualenv(#[from] uv_virtualenv::Error),
+    #[error("Failed to read cached build environment")]
+    CachedEnvironment(#[source] uv_python::Error),
     // Build backend errors
     #[error("Failed to run `{0}`")]
     CommandFailed(PathBuf, #[source] io::Error),
@@ -107,6 +109,7 @@ impl IsBuildBackendError for Error {
             | Self::RequirementsResolve(_, _)
             | Self::RequirementsInstall(_, _)
             | Self::Virtualenv(_)
+            | Self::CachedEnvironment(_)
             | Self::NoSourceDistBuild(_)
             | Self::NoSourceDistBuilds
             | Self::CyclicBuildDependency(_)
@@ -294,31 +297,80 @@ impl Display for MissingHeaderCause {
     }
 }
 
+/// The number of trailing lines of each output stream to include in a build error.
+const OUTPUT_TAIL_LINES: usize = 50;
+
+/// The trailing lines of a build backend output stream.
+#[derive(Debug, Default)]
+struct OutputTail {
+    lines: Vec<String>,
+    /// The number of leading lines that were omitted.
+    omitted: usize,
+}
+
+impl OutputTail {
+    fn new(lines: &[String]) -> Self {
+        let omitted = lines.len().saturating_sub(OUTPUT_TAIL_LINES);
+        Self {
+            lines: lines[omitted..].to_vec(),
+            omitted,
+        }
+    }
+
+    /// Returns `true` if the stream contains any non-whitespace output.
+    fn is_empty(&self) -> bool {
+        self.lines.iter().all(|line| line.trim().is_empty())
+    }
+
+    /// Write the stream under the given header, if it's non-empty.
+    fn write(&self, f: &mut Formatter<'_>, header: &str) -> std::fmt::Result {
+        if self.is_empty() {
+            return Ok(());
+        }
+        write!(f, "\n\n{}", header.red())?;
+        if self.omitted > 0 {
+            let s = if self.omitted == 1 { "" } else { "s" };
+            write!(
+                f,
+                "\n{}",
+                format!("[{} earlier line{s} omitted]", self.omitted).dimmed()
+            )?;
+        }
+        write!(f, "\n{}", self.lines.join("\n"))
+    }
+}
+
+/// Write a pointer to the full build log, if one was written.
+fn write_log_file(f: &mut Formatter<'_>, log_file: Option<&PathBuf>) -> std::fmt::Result {
+    if let Some(log_file) = log_file {
+        write!(
+            f,
+            "\n\nThe full build output was written to: {}",
+            log_file.user_display().cyan()
+        )?;
+    }
+    Ok(())
+}
+
 #[derive(Debug, Error)]
 pub struct BuildBackendError {
     message: String,
     exit_code: ExitStatus,
-    stdout: Vec<String>,
-    stderr: Vec<String>,
+    stdout: OutputTail,
+    stderr: OutputTail,
+    /// The file containing the full build output, if it isn't included above.
+    log_file: Option<PathBuf>,
 }
 
 impl Display for BuildBackendError {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "{} ({})", self.message, self.exit_code)?;
 
-        let mut non_empty = false;
-
-        if self.stdout.iter().any(|line| !line.trim().is_empty()) {
-            write!(f, "\n\n{}\n{}", "[stdout]".red(), self.stdout.join("\n"))?;
-            non_empty = true;
-        }
-
-        if self.stderr.iter().any(|line| !line.trim().is_empty()) {
-            write!(f, "\n\n{}\n{}", "[stderr]".red(), self.stderr.join("\n"))?;
-            non_empty = true;
-        }
+        self.stdout.write(f, "[stdout]")?;
+        self.stderr.write(f, "[stderr]")?;
+        write_log_file(f, self.log_file.as_ref())?;
 
-        if non_empty {
+        if !self.stdout.is_empty() || !self.stderr.is_empty() || self.log_file.is_some() {
             writeln!(f)?;
         }
 
@@ -337,8 +389,10 @@ impl Display for BuildBackendError {
 pub struct MissingHeaderError {
     message: String,
     exit_code: ExitStatus,
-    stdout: Vec<String>,
-    stderr: Vec<String>,
+    stdout: OutputTail,
+    stderr: OutputTail,
+    /// The file containing the full build output, if it isn't included above.
+    log_file: Option<PathBuf>,
     cause: MissingHeaderCause,
 }
 
@@ -346,13 +400,9 @@ impl Display for MissingHeaderError {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "{} ({})", self.message, self.exit_code)?;
 
-        if self.stdout.iter().any(|line| !line.trim().is_empty()) {
-            write!(f, "\n\n{}\n{}", "[stdout]".red(), self.stdout.join("\n"))?;
-        }
-
-        if self.stderr.iter().any(|line| !line.trim().is_empty()) {
-            write!(f, "\n\n{}\n{}", "[stderr]".red(), self.stderr.join("\n"))?;
-        }
+        self.stdout.write(f, "[stdout]")?;
+        self.stderr.write(f, "[stderr]")?;
+        write_log_file(f, self.log_file.as_ref())?;
 
         write!(
             f,
@@ -412,51 +462,45 @@ impl Error {
             }
         });
 
-        if let Some(missing_library) = missing_library {
-            return match level {
-                BuildOutput::Stderr | BuildOutput::Quiet => {
-                    Self::MissingHeader(MissingHeaderError {
-                        message,
-                        exit_code: output.status,
-                        stdout: vec![],
-                        stderr: vec![],
-                        cause: MissingHeaderCause {
-                            missing_library,
-                            package_name: name.cloned(),
-                            package_version: version.cloned(),
-                            version_id: version_id.map(ToString::to_string),
-                        },
-                    })
-                }
-                BuildOutput::Debug => Self::MissingHeader(MissingHeaderError {
-                    message,
-                    exit_code: output.status,
-                    stdout: output.stdout.clone(),
-                    stderr: output.stderr.clone(),
-                    cause: MissingHeaderCause {
-                        missing_library,
-                        package_name: name.cloned(),
-                        package_version: version.cloned(),
-                        version_id: version_id.map(ToString::to_string),
-                    },
-                }),
-            };
-        }
+        // Include the tail of the output, unless it was already streamed to the user. If the
+        // output isn't included in full, point to the log file instead.
+        let (stdout, stderr) = match level {
+            BuildOutput::Stderr | BuildOutput::Quiet => {
+                (OutputTail::default(), OutputTail::default())
+            }
+            BuildOutput::Debug => (
+                OutputTail::new(&output.stdout),
+                OutputTail::new(&output.stderr),
+            ),
+        };
+        let log_file = output
+            .log_file
+            .clone()
+            .filter(|_| level != BuildOutput::Debug || stdout.omitted > 0 || stderr.omitted > 0);
 
-        match level {
-            BuildOutput::Stderr | BuildOutput::Quiet => Self::BuildBackend(BuildBackendError {
-                message,
-                exit_code: output.status,
-                stdout: vec![],
-                stderr: vec![],
-            }),
-            BuildOutput::Debug => Self::BuildBackend(BuildBackendError {
+        if let Some(missing_library) = missing_library {
+            return Self::MissingHeader(MissingHeaderError {
                 message,
                 exit_code: output.status,
-                stdout: output.stdout.clone(),
-                stderr: output.stderr.clone(),
-            }),
+                stdout,
+                stderr,
+                log_file,
+                cause: MissingHeaderCause {
+                    missing_library,
+                    package_name: name.cloned(),
+                    package_version: version.cloned(),
+                    version_id: version_id.map(ToString::to_string),
+                },
+            });
         }
+
+        Self::BuildBackend(BuildBackendError {
+            message,
+            exit_code: output.status,
+            stdout,
+            stderr,
+            log_file,
+        })
     }
 }
 
@@ -464,6 +508,7 @@ impl Error {
 mod test {
     use crate::{Error, PythonRunnerOutput};
     use indoc::indoc;
+    use std::path::PathBuf;
     use std::process::ExitStatus;
     use std::str::FromStr;
     use uv_configuration::BuildOutput;
@@ -494,6 +539,7 @@ mod test {
                 error: command '/usr/bin/gcc' failed with exit code 1
                 "#
             ).lines().map(ToString::to_string).collect(),
+            log_file: None,
         };
 
         let err = Error::from_command_output(
@@ -553,6 +599,7 @@ mod test {
             .lines()
             .map(ToString::to_string)
             .collect(),
+            log_file: None,
         };
 
         let err = Error::from_command_output(
@@ -601,6 +648,7 @@ mod test {
             .lines()
             .map(ToString::to_string)
             .collect(),
+            log_file: None,
         };
 
         let err = Error::from_command_output(
@@ -652,6 +700,7 @@ mod test {
             .lines()
             .map(ToString::to_string)
             .collect(),
+            log_file: None,
         };
 
         let err = Error::from_command_output(
@@ -679,4 +728,91 @@ mod test {
         hint: `distutils` was removed from the standard library in Python 3.12. Consider adding a constraint (like `pygraphviz >1.11`) to avoid building a version of `pygraphviz` that depends on `distutils`.
         ");
     }
+
+    #[test]
+    fn truncated_output() {
+        let output = PythonRunnerOutput {
+            status: ExitStatus::default(), // This is wrong but `from_raw` is platform-gated.
+            stdout: Vec::new(),
+            stderr: (1..=52).map(|line| format!("line {line}")).collect(),
+            log_file: Some(PathBuf::from("build-logs-v0/foo/0123456789abcdef.log")),
+        };
+
+        let err = Error::from_command_output(
+            "Call to `setuptools.build_meta.build_wheel` failed".to_string(),
+            &output,
+            BuildOutput::Debug,
+            None,
+            None,
+            Some("foo-1.0.0"),
+        );
+        assert!(matches!(err, Error::BuildBackend { .. }));
+        // Unix uses exit status, Windows uses exit code.
+        let formatted = std::error::Error::source(&err)
+            .unwrap()
+            .to_string()
+            .replace("exit status: ", "exit code: ")
+            .replace('\\', "/");
+        let formatted = anstream::adapter::strip_str(&formatted);
+        insta::assert_snapshot!(formatted, @"
+        Call to `setuptools.build_meta.build_wheel` failed (exit code: 0)
+
+        [stderr]
+        [2 earlier lines omitted]
+        line 3
+        line 4
+        line 5
+        line 6
+        line 7
+        line 8
+        line 9
+        line 10
+        line 11
+        line 12
+        line 13
+        line 14
+        line 15
+        line 16
+        line 17
+        line 18
+        line 19
+        line 20
+        line 21
+        line 22
+        line 23
+        line 24
+        line 25
+        line 26
+        line 27
+        line 28
+        line 29
+        line 30
+        line 31
+        line 32
+        line 33
+        line 34
+        line 35
+        line 36
+        line 37
+        line 38
+        line 39
+        line 40
+        line 41
+        line 42
+        line 43
+        line 44
+        line 45
+        line 46
+        line 47
+        line 48
+        line 49
+        line 50
+        line 51
+        line 52
+
+        The full build output was written to: build-logs-v0/foo/0123456789abcdef.log
+
+        hint: This usually indicates a problem with the package or the build environment.
+        ");
+    }
 }
@@ -28,19 +28,21 @@ use tokio::process::Command;
 use tokio::sync::{Mutex, Semaphore};
 use tracing::{Instrument, debug, info_span, instrument, warn};
 use uv_auth::CredentialsCache;
-use uv_cache_key::cache_digest;
+use uv_cache::CacheBucket;
+use uv_cache_key::{cache_digest, hash_digest};
 use uv_configuration::{BuildKind, BuildOutput, NoSources};
 use uv_distribution::BuildRequires;
 use uv_distribution_types::{
-    ConfigSettings, ExtraBuildRequirement, ExtraBuildRequires, IndexLocations, Requirement,
-    Resolution,
+    BuiltDist, ConfigSettings, Dist, ExtraBuildRequirement, ExtraBuildRequires, Identifier,
+    IndexLocations, Requirement, Resolution, ResolvedDist, SourceDist,
 };
 use uv_fs::{LockedFile, LockedFileMode};
 use uv_fs::{PythonExt, Simplified};
 use uv_normalize::PackageName;
 use uv_pep440::Version;
+use uv_preview::PreviewFeature;
 use uv_pypi_types::VerbatimParsedUrl;
-use uv_python::{Interpreter, PythonEnvironment};
+use uv_python::{Interpreter, PythonEnvironment, canonicalize_executable};
 use uv_static::EnvVars;
 use uv_types::{AnyErrorBuild, BuildContext, BuildIsolation, BuildStack, SourceBuildTrait};
 use uv_warnings::warn_user_once;
@@ -241,6 +243,8 @@ impl SourceBuildContext {
 /// call which changes how we call `build_wheel`.
 pub struct SourceBuild {
     temp_dir: TempDir,
+    /// The directory used as `TMPDIR` by the build backend, removed when the build is dropped.
+    _scratch_dir: TempDir,
     source_tree: PathBuf,
     config_settings: ConfigSettings,
     /// If performing a PEP 517 build, the backend to use.
@@ -360,27 +364,17 @@ impl SourceBuild {
             .map_ok(Requirement::from)
             .collect::<Result<Vec<_>, _>>()?;
 
-        // Create a virtual environment, or install into the shared environment if requested.
-        let venv = if let Some(venv) = build_isolation.shared_environment(package_name.as_ref()) {
+        // Create a virtual environment, or install into the shared environment if requested. If
+        // build isolation is disabled, we assume the build environment is already setup.
+        //
+        // If the environment was retrieved from the cache, track the build requirements, since
+        // the environment must not be modified.
+        let mut cached_requirements = None;
+        let mut venv = if let Some(venv) = build_isolation.shared_environment(package_name.as_ref())
+        {
+            debug!("Proceeding without build isolation");
             venv.clone()
         } else {
-            uv_virtualenv::create_venv(
-                temp_dir.path(),
-                interpreter.clone(),
-                uv_virtualenv::Prompt::None,
-                false,
-                uv_virtualenv::OnExisting::Remove(
-                    uv_virtualenv::RemovalReason::TemporaryEnvironment,
-                ),
-                false,
-                false,
-                false,
-            )?
-        };
-
-        // Set up the build environment. If build isolation is disabled, we assume the build
-        // environment is already setup.
-        if build_isolation.is_isolated(package_name.as_ref()) {
             debug!("Resolving build requirements");
 
             let dependency_sources = if extra_build_dependencies.is_empty() {
@@ -398,13 +392,29 @@ impl SourceBuild {
             )
             .await?;
 
-            build_context
-                .install(&resolved_requirements, &venv, build_stack)
-                .await
-                .map_err(|err| Error::RequirementsInstall(dependency_sources, err.into()))?;
-        } else {
-            debug!("Proceeding without build isolation");
-        }
+            if let Some(venv) = cached_build_environment(
+                &resolved_requirements,
+                interpreter,
+                build_context,
+                build_stack,
+                dependency_sources,
+            )
+            .await?
+            {
+                cached_requirements = Some((resolved_requirements, dependency_sources));
+                venv
+            } else {
+                create_build_environment(
+                    &temp_dir,
+                    interpreter,
+                    &resolved_requirements,
+                    build_context,
+                    build_stack,
+                    dependency_sources,
+                )
+                .await?
+            }
+        };
 
         // Figure out what the modified path should be, and remove the PATH variable from the
         // environment variables if it's there.
@@ -429,21 +439,38 @@ impl SourceBuild {
             os_path
         };
 
+        // Point the build backend at a scratch directory that is removed along with the build,
+        // rather than at the system temporary directory, unless the user overrode it.
+        let scratch_dir = build_context.cache().scratch_dir()?;
+        for name in [EnvVars::TMPDIR, EnvVars::TEMP, EnvVars::TMP] {
+            environment_variables
+                .entry(OsString::from(name))
+                .or_insert_with(|| scratch_dir.path().as_os_str().to_owned());
+        }
+
         // Prepend the venv bin directory to the modified path
-        let modified_path = if let Some(path) = modified_path {
-            let venv_path = iter::once(venv.scripts().to_path_buf()).chain(env::split_paths(&path));
-            env::join_paths(venv_path).map_err(Error::BuildScriptPath)?
-        } else {
-            OsString::from(venv.scripts())
-        };
+        let base_path = modified_path;
+        let mut modified_path =
+            prepend_scripts_to_path(&venv, base_path.as_ref()).map_err(Error::BuildScriptPath)?;
 
         // Create the PEP 517 build environment. If build isolation is disabled, we assume the build
         // environment is already setup.
-        let runner = PythonRunner::new(source_build_context.concurrent_build_slots.clone(), level);
+        let runner = PythonRunner::new(
+            source_build_context.concurrent_build_slots.clone(),
+            level,
+            Some(build_log_path(
+                &build_context.cache().bucket(CacheBucket::BuildLogs),
+                package_name.as_ref(),
+                &version_id.map_or_else(
+                    || cache_digest(&source_tree),
+                    |version_id| cache_digest(&version_id),
+                ),
+            )),
+        );
         if build_isolation.is_isolated(package_name.as_ref()) {
             debug!("Creating PEP 517 build environment");
 
-            create_pep517_build_environment(
+            let resolution = create_pep517_build_environment(
                 &runner,
                 &source_tree,
                 install_path,
@@ -466,10 +493,55 @@ impl SourceBuild {
                 credentials_cache,
             )
             .await?;
+
+            // If the backend requires additional packages, install them, or switch to a cached
+            // environment that includes them.
+            if let Some(resolution) = resolution {
+                if let Some(cached) = cached_build_environment(
+                    &resolution,
+                    interpreter,
+                    build_context,
+                    build_stack,
+                    "`build-system.requires`",
+                )
+                .await?
+                {
+                    venv = cached;
+                    modified_path = prepend_scripts_to_path(&venv, base_path.as_ref())
+                        .map_err(Error::BuildScriptPath)?;
+                } else {
+                    // If the current environment is cached, recreate it outside the cache before
+                    // installing the additional requirements.
+                    if let Some((resolved_requirements, dependency_sources)) = cached_requirements {
+                        venv = create_build_environment(
+                            &temp_dir,
+                            interpreter,
+                            &resolved_requirements,
+                            build_context,
+                            build_stack,
+                            dependency_sources,
+                        )
+                        .await?;
+                        modified_path = prepend_scripts_to_path(&venv, base_path.as_ref())
+                            .map_err(Error::BuildScriptPath)?;
+                    }
+
+                    build_context
+                        .install(&resolution, &venv, build_stack)
+                        .await
+                        .map_err(|err| {
+                            Error::RequirementsInstall(
+                                "`build-system.requires`",
+                                AnyErrorBuild::from(err),
+                            )
+                        })?;
+                }
+            }
         }
 
         Ok(Self {
             temp_dir,
+            _scratch_dir: scratch_dir,
             source_tree,
             pep517_backend,
             project,
@@ -973,6 +1045,142 @@ impl SourceBuildTrait for SourceBuild {
     }
 }
 
+/// Prepend the scripts directory of the build environment to the given `PATH`.
+fn prepend_scripts_to_path(
+    venv: &PythonEnvironment,
+    path: Option<&OsString>,
+) -> Result<OsString, env::JoinPathsError> {
+    if let Some(path) = path {
+        let venv_path = iter::once(venv.scripts().to_path_buf()).chain(env::split_paths(path));
+        env::join_paths(venv_path)
+    } else {
+        Ok(OsString::from(venv.scripts()))
+    }
+}
+
+/// Create a temporary build environment with the given build requirements installed.
+async fn create_build_environment(
+    temp_dir: &TempDir,
+    interpreter: &Interpreter,
+    resolution: &Resolution,
+    build_context: &impl BuildContext,
+    build_stack: &BuildStack,
+    dependency_sources: &'static str,
+) -> Result<PythonEnvironment, Error> {
+    let venv = uv_virtualenv::create_venv(
+        temp_dir.path(),
+        interpreter.clone(),
+        uv_virtualenv::Prompt::None,
+        false,
+        uv_virtualenv::OnExisting::Remove(uv_virtualenv::RemovalReason::TemporaryEnvironment),
+        false,
+        false,
+        false,
+    )?;
+
+    build_context
+        .install(resolution, &venv, build_stack)
+        .await
+        .map_err(|err| Error::RequirementsInstall(dependency_sources, err.into()))?;
+
+    Ok(venv)
+}
+
+/// Get or create a build environment in the cache with the given build requirements installed,
+/// if build environment caching is enabled.
+///
+/// Cached build environments are keyed by the interpreter and the resolved build requirements,
+/// and are never modified once created, so they can be shared across builds. Returns `None` if
+/// caching is disabled, or if the build requirements include distributions from outside a
+/// registry, which may change without a change in version.
+async fn cached_build_environment(
+    resolution: &Resolution,
+    interpreter: &Interpreter,
+    build_context: &impl BuildContext,
+    build_stack: &BuildStack,
+    dependency_sources: &'static str,
+) -> Result<Option<PythonEnvironment>, Error> {
+    if !uv_preview::is_enabled(PreviewFeature::BuildEnvironmentCache) {
+        return Ok(None);
+    }
+
+    if !resolution.distributions().all(|dist| {
+        matches!(
+            dist,
+            ResolvedDist::Installable { dist, .. }
+                if matches!(
+                    dist.as_ref(),
+                    Dist::Built(BuiltDist::Registry(_)) | Dist::Source(SourceDist::Registry(_))
+                )
+        )
+    }) {
+        debug!("Skipping build environment cache for non-registry build requirements");
+        return Ok(None);
+    }
+
+    // Hash the resolved build requirements, independent of the order in which they were resolved.
+    let resolution_hash = {
+        let mut distributions = resolution.hashes().collect::<Vec<_>>();
+        distributions.sort_unstable_by(|(left, _), (right, _)| {
+            left.distribution_id().cmp(&right.distribution_id())
+        });
+        hash_digest(&distributions)
+    };
+
+    // Hash the interpreter, including its version, in case the interpreter at the canonicalized
+    // path is replaced.
+    let interpreter_hash = hash_digest(&(
+        canonicalize_executable(interpreter.sys_executable())?,
+        interpreter.python_full_version().to_string(),
+    ));
+
+    // Search in the content-addressed cache.
+    let cache = build_context.cache();
+    let cache_entry = cache.entry(
+        CacheBucket::Environments,
+        Path::new("build").join(interpreter_hash),
+        resolution_hash,
+    );
+
+    if let Ok(root) = cache.resolve_link(cache_entry.path())
+        && let Ok(venv) = PythonEnvironment::from_root(root, cache)
+    {
+        debug!(
+            "Using cached build environment at: {}",
+            venv.root().user_display()
+        );
+        return Ok(Some(venv));
+    }
+
+    // Create the environment in the cache, then relocate it to its content-addressed location.
+    let temp_dir = cache.venv_dir()?;
+    let venv = uv_virtualenv::create_venv(
+        temp_dir.path(),
+        interpreter.clone(),
+        uv_virtualenv::Prompt::None,
+        false,
+        uv_virtualenv::OnExisting::Remove(uv_virtualenv::RemovalReason::TemporaryEnvironment),
+        true,
+        false,
+        false,
+    )?;
+
+    build_context
+        .install(resolution, &venv, build_stack)
+        .await
+        .map_err(|err| Error::RequirementsInstall(dependency_sources, err.into()))?;
+
+    let id = cache.persist(temp_dir.keep(), cache_entry.path()).await?;
+    let venv = PythonEnvironment::from_root(cache.archive(&id), cache)
+        .map_err(Error::CachedEnvironment)?;
+    debug!(
+        "Created cached build environment at: {}",
+        venv.root().user_display()
+    );
+
+    Ok(Some(venv))
+}
+
 fn escape_path_for_python(path: &Path) -> String {
     path.to_string_lossy()
         .replace('\\', "\\\\")
@@ -980,6 +1188,9 @@ fn escape_path_for_python(path: &Path) -> String {
 }
 
 /// Not a method because we call it before the builder is completely initialized
+///
+/// Returns the resolution of the build requirements if the backend requires additional packages
+/// that must be installed into the build environment.
 async fn create_pep517_build_environment(
     runner: &PythonRunner,
     source_tree: &Path,
@@ -1001,7 +1212,7 @@ async fn create_pep517_build_environment(
     modified_path: &OsString,
     temp_dir: &TempDir,
     credentials_cache: &CredentialsCache,
-) -> Result<(), Error> {
+) -> Result<Option<Resolution>, Error> {
     // Write the hook output to a file so that we can read it back reliably.
     let outfile = temp_dir
         .path()
@@ -1125,15 +1336,21 @@ async fn create_pep517_build_environment(
                 Error::RequirementsResolve("`build-system.requires`", AnyErrorBuild::from(err))
             })?;
 
-        build_context
-            .install(&resolution, venv, build_stack)
-            .await
-            .map_err(|err| {
-                Error::RequirementsInstall("`build-system.requires`", AnyErrorBuild::from(err))
-            })?;
+        return Ok(Some(resolution));
     }
 
-    Ok(())
+    Ok(None)
+}
+
+/// Return the path to which the output of a failed build is written, given a digest of its
+/// source.
+fn build_log_path(bucket: &Path, package_name: Option<&PackageName>, digest: &str) -> PathBuf {
+    let filename = format!("{digest}.log");
+    if let Some(package_name) = package_name {
+        bucket.join(package_name.as_str()).join(filename)
+    } else {
+        bucket.join(filename)
+    }
 }
 
 /// A runner that manages the execution of external python processes with a
@@ -1142,6 +1359,8 @@ async fn create_pep517_build_environment(
 struct PythonRunner {
     concurrent_build_slots: Arc<Semaphore>,
     level: BuildOutput,
+    /// The file to which the output of failed scripts is written, if any.
+    log_file: Option<PathBuf>,
 }
 
 #[derive(Debug)]
@@ -1149,14 +1368,21 @@ struct PythonRunnerOutput {
     stdout: Vec<String>,
     stderr: Vec<String>,
     status: ExitStatus,
+    /// The file to which the full output was written, if the script failed.
+    log_file: Option<PathBuf>,
 }
 
 impl PythonRunner {
     /// Create a `PythonRunner` with the provided shared concurrency semaphore and output level.
-    fn new(concurrent_build_slots: Arc<Semaphore>, level: BuildOutput) -> Self {
+    fn new(
+        concurrent_build_slots: Arc<Semaphore>,
+        level: BuildOutput,
+        log_file: Option<PathBuf>,
+    ) -> Self {
         Self {
             concurrent_build_slots,
             level,
+            log_file,
         }
     }
 
@@ -1246,14 +1472,51 @@ impl PythonRunner {
             .await
             .map_err(|err| Error::CommandFailed(venv.python_executable().to_path_buf(), err))?;
 
+        // If the script failed, persist its output, which may be truncated in the error message.
+        let log_file = if status.success() {
+            None
+        } else {
+            self.log_file.as_ref().and_then(|log_file| {
+                match write_log(log_file, &stdout_buf, &stderr_buf) {
+                    Ok(()) => Some(log_file.clone()),
+                    Err(err) => {
+                        warn!(
+                            "Failed to write build log to {}: {err}",
+                            log_file.user_display()
+                        );
+                        None
+                    }
+                }
+            })
+        };
+
         Ok(PythonRunnerOutput {
             stdout: stdout_buf,
             stderr: stderr_buf,
             status,
+            log_file,
         })
     }
 }
 
+/// Write the `stdout` and `stderr` of a build script to the given log file.
+fn write_log(log_file: &Path, stdout: &[String], stderr: &[String]) -> io::Result<()> {
+    if let Some(parent) = log_file.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let mut contents = String::new();
+    for (name, lines) in [("[stdout]", stdout), ("[stderr]", stderr)] {
+        contents.push_str(name);
+        contents.push('\n');
+        for line in lines {
+            contents.push_str(line);
+            contents.push('\n');
+        }
+        contents.push('\n');
+    }
+    fs::write(log_file, contents)
+}
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum Printer {
     /// Send the build backend output to `stderr`.
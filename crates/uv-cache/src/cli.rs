@@ -29,6 +29,16 @@ pub struct CacheArgs {
     /// To view the location of the cache directory, run `uv cache dir`.
     #[arg(global = true, long, env = EnvVars::UV_CACHE_DIR, value_hint = ValueHint::DirPath)]
     pub cache_dir: Option<PathBuf>,
+
+    /// Path to the directory in which build backends should create temporary files.
+    ///
+    /// Build backends are run with `TMPDIR`, `TEMP`, and `TMP` pointing to a fresh directory
+    /// within the scratch directory, which is removed once the build completes, whether or not it
+    /// succeeds.
+    ///
+    /// Defaults to a directory within the cache directory.
+    #[arg(global = true, long, env = EnvVars::UV_SCRATCH_DIR, value_hint = ValueHint::DirPath)]
+    pub scratch_dir: Option<PathBuf>,
 }
 
 impl Cache {
@@ -82,7 +92,8 @@ impl TryFrom<CacheArgs> for Cache {
     type Error = io::Error;
 
     fn try_from(value: CacheArgs) -> Result<Self, Self::Error> {
-        Self::from_settings(value.no_cache, value.cache_dir)
+        Ok(Self::from_settings(value.no_cache, value.cache_dir)?
+            .with_scratch_dir(value.scratch_dir))
     }
 }
 
@@ -0,0 +1,152 @@
+/// The magic bytes that prefix a sealed cache entry.
+const MAGIC: &[u8; 4] = b"uvce";
+
+/// The version of the envelope layout, i.e., the header that precedes the payload.
+const ENVELOPE_VERSION: u8 = 1;
+
+/// The version of the serialized representation of sealed cache entries.
+///
+/// Bump this whenever the serialized form of a sealed cache entry (e.g., an archive pointer, a
+/// revision pointer, or cached metadata) changes such that other versions of uv could misread it.
+/// Entries written with a different schema version are treated as cache misses.
+pub const SCHEMA_VERSION: u32 = 1;
+
+/// The size of the fixed-length portion of the header: the magic bytes, the envelope version, the
+/// schema version, the payload checksum, and the length of the uv version.
+const HEADER_LEN: usize = MAGIC.len() + 1 + 4 + 8 + 1;
+
+/// An error validating the integrity metadata of a cache entry.
+#[derive(Debug, thiserror::Error)]
+pub enum IntegrityError {
+    #[error("Cache entry is missing integrity metadata")]
+    Unsealed,
+    #[error("Cache entry integrity metadata is truncated")]
+    Truncated,
+    #[error("Cache entry uses envelope version {0}, but expected {ENVELOPE_VERSION}")]
+    Envelope(u8),
+    #[error(
+        "Cache entry was written by uv {written_by} with schema version {found}, but expected {SCHEMA_VERSION}"
+    )]
+    Schema { found: u32, written_by: String },
+    #[error("Cache entry written by uv {written_by} failed checksum validation")]
+    Checksum { written_by: String },
+}
+
+impl IntegrityError {
+    /// Returns `true` if the entry was damaged after it was written (e.g., truncated or modified on
+    /// disk), as opposed to having been written by an incompatible version of uv.
+    pub fn is_corrupt(&self) -> bool {
+        matches!(self, Self::Truncated | Self::Checksum { .. })
+    }
+}
+
+/// Wrap a serialized cache entry with integrity metadata: the current schema version, the version
+/// of uv that wrote it, and a checksum of the payload.
+pub fn seal(payload: &[u8]) -> Vec<u8> {
+    let version = uv_version::version().as_bytes();
+    let version_len = u8::try_from(version.len()).unwrap_or(u8::MAX);
+    let version = &version[..usize::from(version_len)];
+
+    let mut bytes = Vec::with_capacity(HEADER_LEN + version.len() + payload.len());
+    bytes.extend_from_slice(MAGIC);
+    bytes.push(ENVELOPE_VERSION);
+    bytes.extend_from_slice(&SCHEMA_VERSION.to_le_bytes());
+    bytes.extend_from_slice(&seahash::hash(payload).to_le_bytes());
+    bytes.push(version_len);
+    bytes.extend_from_slice(version);
+    bytes.extend_from_slice(payload);
+    bytes
+}
+
+/// Validate the integrity metadata of a cache entry written by [`seal`], returning the payload.
+///
+/// Returns an error if the entry was written without integrity metadata (e.g., by an older version
+/// of uv), with an incompatible schema version, or if its payload doesn't match the checksum.
+pub fn unseal(bytes: &[u8]) -> Result<&[u8], IntegrityError> {
+    let Some(rest) = bytes.strip_prefix(MAGIC) else {
+        return Err(IntegrityError::Unsealed);
+    };
+    let Some((&envelope, rest)) = rest.split_first() else {
+        return Err(IntegrityError::Truncated);
+    };
+    if envelope != ENVELOPE_VERSION {
+        return Err(IntegrityError::Envelope(envelope));
+    }
+    let Some((schema, rest)) = rest.split_first_chunk::<4>() else {
+        return Err(IntegrityError::Truncated);
+    };
+    let Some((checksum, rest)) = rest.split_first_chunk::<8>() else {
+        return Err(IntegrityError::Truncated);
+    };
+    let Some((&version_len, rest)) = rest.split_first() else {
+        return Err(IntegrityError::Truncated);
+    };
+    let Some((version, payload)) = rest.split_at_checked(usize::from(version_len)) else {
+        return Err(IntegrityError::Truncated);
+    };
+    let written_by = || String::from_utf8_lossy(version).into_owned();
+
+    let schema = u32::from_le_bytes(*schema);
+    if schema != SCHEMA_VERSION {
+        return Err(IntegrityError::Schema {
+            found: schema,
+            written_by: written_by(),
+        });
+    }
+    if u64::from_le_bytes(*checksum) != seahash::hash(payload) {
+        return Err(IntegrityError::Checksum {
+            written_by: written_by(),
+        });
+    }
+    Ok(payload)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let sealed = seal(b"payload");
+        assert_eq!(unseal(&sealed).unwrap(), b"payload");
+    }
+
+    #[test]
+    fn unsealed() {
+        assert!(matches!(unseal(b"payload"), Err(IntegrityError::Unsealed)));
+        assert!(matches!(unseal(b""), Err(IntegrityError::Unsealed)));
+    }
+
+    #[test]
+    fn truncated() {
+        let sealed = seal(b"payload");
+        for len in MAGIC.len()..HEADER_LEN {
+            assert!(matches!(
+                unseal(&sealed[..len]),
+                Err(IntegrityError::Truncated)
+            ));
+        }
+    }
+
+    #[test]
+    fn schema_mismatch() {
+        let mut sealed = seal(b"payload");
+        sealed[MAGIC.len() + 1..MAGIC.len() + 5].copy_from_slice(&0u32.to_le_bytes());
+        let Err(IntegrityError::Schema { found, written_by }) = unseal(&sealed) else {
+            panic!("expected a schema mismatch");
+        };
+        assert_eq!(found, 0);
+        assert_eq!(written_by, uv_version::version());
+    }
+
+    #[test]
+    fn checksum_mismatch() {
+        let mut sealed = seal(b"payload");
+        let last = sealed.len() - 1;
+        sealed[last] ^= 0xff;
+        assert!(matches!(
+            unseal(&sealed),
+            Err(IntegrityError::Checksum { .. })
+        ));
+    }
+}
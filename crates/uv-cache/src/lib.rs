@@ -12,6 +12,7 @@ use tracing::{debug, trace, warn};
 use uv_cache_info::Timestamp;
 use uv_fs::{LockedFile, LockedFileError, LockedFileMode, Simplified, cachedir, directories};
 use uv_normalize::PackageName;
+use uv_pep440::Version;
 use uv_pypi_types::ResolutionMetadata;
 
 pub use crate::by_timestamp::CachedByTimestamp;
@@ -27,6 +28,7 @@ mod archive;
 mod by_timestamp;
 #[cfg(feature = "clap")]
 mod cli;
+pub mod integrity;
 mod removal;
 mod wheel;
 
@@ -171,6 +173,9 @@ pub struct Cache {
     /// Included to ensure that the temporary directory exists for the length of the operation, but
     /// is dropped at the end as appropriate.
     temp_dir: Option<Arc<tempfile::TempDir>>,
+    /// The directory in which to create scratch space for build backends, if it differs from the
+    /// cache directory.
+    scratch_dir: Option<Arc<Path>>,
     /// Ensure that `uv cache` operations don't remove items from the cache that are used by another
     /// uv process.
     lock_file: Option<Arc<LockedFile>>,
@@ -183,6 +188,7 @@ impl Cache {
             root: root.into(),
             refresh: Refresh::None(Timestamp::now()),
             temp_dir: None,
+            scratch_dir: None,
             lock_file: None,
         }
     }
@@ -194,6 +200,7 @@ impl Cache {
             root: temp_dir.path().to_path_buf(),
             refresh: Refresh::None(Timestamp::now()),
             temp_dir: Some(Arc::new(temp_dir)),
+            scratch_dir: None,
             lock_file: None,
         })
     }
@@ -204,12 +211,22 @@ impl Cache {
         Self { refresh, ..self }
     }
 
+    /// Set the directory in which to create scratch space for build backends.
+    #[must_use]
+    pub fn with_scratch_dir(self, scratch_dir: Option<PathBuf>) -> Self {
+        Self {
+            scratch_dir: scratch_dir.map(Arc::from),
+            ..self
+        }
+    }
+
     /// Acquire a lock that allows removing entries from the cache.
     pub async fn with_exclusive_lock(self) -> Result<Self, LockedFileError> {
         let Self {
             root,
             refresh,
             temp_dir,
+            scratch_dir,
             lock_file,
         } = self;
 
@@ -232,6 +249,7 @@ impl Cache {
             root,
             refresh,
             temp_dir,
+            scratch_dir,
             lock_file: Some(Arc::new(lock_file)),
         })
     }
@@ -244,6 +262,7 @@ impl Cache {
             root,
             refresh,
             temp_dir,
+            scratch_dir,
             lock_file,
         } = self;
 
@@ -256,12 +275,14 @@ impl Cache {
                 root,
                 refresh,
                 temp_dir,
+                scratch_dir,
                 lock_file: Some(Arc::new(lock_file)),
             }),
             None => Err(Self {
                 root,
                 refresh,
                 temp_dir,
+                scratch_dir,
                 lock_file,
             }),
         }
@@ -314,6 +335,22 @@ impl Cache {
         tempfile::tempdir_in(self.bucket(CacheBucket::Builds))
     }
 
+    /// Create a temporary directory to be used as scratch space (e.g., `TMPDIR`) by build backends.
+    ///
+    /// Defaults to the builds bucket in the cache, unless a scratch directory was provided via
+    /// [`Cache::with_scratch_dir`]. The directory is removed when the returned [`TempDir`] is
+    /// dropped, including when a build fails.
+    ///
+    /// [`TempDir`]: tempfile::TempDir
+    pub fn scratch_dir(&self) -> io::Result<tempfile::TempDir> {
+        let root = self
+            .scratch_dir
+            .as_deref()
+            .map_or_else(|| self.bucket(CacheBucket::Builds), Path::to_path_buf);
+        fs_err::create_dir_all(&root)?;
+        tempfile::tempdir_in(root)
+    }
+
     /// Returns `true` if a cache entry must be revalidated given the [`Refresh`] policy.
     pub fn must_revalidate_package(&self, package: &PackageName) -> bool {
         match &self.refresh {
@@ -626,7 +663,22 @@ impl Cache {
             Err(err) => return Err(err),
         }
 
-        // Third, if enabled, remove all unzipped wheels, leaving only the wheel archives.
+        // Third, remove any partial downloads. These are only retained to resume interrupted
+        // downloads, so there's no need to keep them around.
+        let downloads = self.bucket(CacheBucket::Downloads);
+        if downloads.is_dir() {
+            debug!("Removing partial downloads: {}", downloads.display());
+            summary += rm_rf(downloads)?;
+        }
+
+        // Fourth, remove any build logs. These are only retained to debug failed builds.
+        let build_logs = self.bucket(CacheBucket::BuildLogs);
+        if build_logs.is_dir() {
+            debug!("Removing build logs: {}", build_logs.display());
+            summary += rm_rf(build_logs)?;
+        }
+
+        // Fifth, if enabled, remove all unzipped wheels, leaving only the wheel archives.
         if ci {
             // Remove the entire pre-built wheel cache, since every entry is an unzipped wheel.
             match fs_err::read_dir(self.bucket(CacheBucket::Wheels)) {
@@ -683,7 +735,7 @@ impl Cache {
             }
         }
 
-        // Fourth, remove any unused archives (by searching for archives that are not symlinked).
+        // Sixth, remove any unused archives (by searching for archives that are not symlinked).
         let references = self.find_archive_references()?;
 
         match fs_err::read_dir(self.bucket(CacheBucket::Archive)) {
@@ -710,7 +762,7 @@ impl Cache {
     /// searches for all such references.
     ///
     /// Returns a map from archive path to paths that reference it.
-    fn find_archive_references(&self) -> Result<FxHashMap<PathBuf, Vec<PathBuf>>, io::Error> {
+    pub fn find_archive_references(&self) -> Result<FxHashMap<PathBuf, Vec<PathBuf>>, io::Error> {
         let mut references = FxHashMap::<PathBuf, Vec<PathBuf>>::default();
         for bucket in [CacheBucket::SourceDistributions, CacheBucket::Wheels] {
             let bucket_path = self.bucket(bucket);
@@ -1056,6 +1108,10 @@ pub enum CacheBucket {
     ///  * `built-wheels-v0/url/<digest(url)>/foo/foo-1.0.0.zip/{manifest.msgpack, metadata.msgpack, foo-1.0.0-py3-none-any.whl, ...other wheels}`
     ///  * `built-wheels-v0/git/<digest(url)>/<git sha>/foo/foo-1.0.0.zip/{metadata.msgpack, foo-1.0.0-py3-none-any.whl, ...other wheels}`
     ///
+    /// Source distribution archives that are downloaded as-is (e.g., by `uv pip download`) are
+    /// stored in the package directory, alongside their HTTP cache policy:
+    ///  * `built-wheels-v0/<digest(index-url)>/foo/{foo-1.0.0.tar.gz, foo-1.0.0.tar.gz.http}`
+    ///
     /// But the url filename does not need to be a valid source dist filename
     /// (<https://github.com/search?q=path%3A**%2Frequirements.txt+master.zip&type=code>),
     /// so it could also be the following and we have to take any string as filename:
@@ -1174,6 +1230,45 @@ pub enum CacheBucket {
     Python,
     /// Downloaded tool binaries (e.g., Ruff).
     Binaries,
+    /// Partially downloaded wheels, retained such that an interrupted download can be resumed
+    /// with an HTTP range request, rather than restarted from scratch.
+    ///
+    /// Cache structure:
+    ///  * `downloads-v0/<digest(url, validator)>/<filename>`
+    ///
+    /// The validator is the `ETag` or `Last-Modified` header of the response, such that a
+    /// partial download is never resumed against a file that has since changed.
+    Downloads,
+    /// The output of failed source distribution builds, retained for debugging.
+    ///
+    /// Cache structure:
+    ///  * `build-logs-v0/<package_name>/<digest(source)>.log`
+    ///  * `build-logs-v0/<digest(source)>.log`, if the package name is unknown
+    ///
+    /// Each log is overwritten by subsequent failed builds of the same source.
+    BuildLogs,
+    /// The versions selected for each package in prior resolutions, used to prioritize metadata
+    /// fetches in subsequent resolutions.
+    ///
+    /// Cache structure:
+    ///  * `selection-history-v0/pypi/history.msgpack`
+    ///  * `selection-history-v0/index/<digest(index_url)>/history.msgpack`
+    SelectionHistory,
+    /// Configuration files fetched from a remote URL (i.e., via `--config-file`), retained for use
+    /// when offline or when the URL is unreachable.
+    ///
+    /// Cache structure:
+    ///  * `remote-config-v0/<digest(url)>.toml`
+    RemoteConfig,
+    /// The import names provided by the distributions installed in an environment (i.e., for
+    /// `uv pip install --for-import`), derived from their `RECORD` files.
+    ///
+    /// Cache structure:
+    ///  * `import-names-v0/<digest(site-packages)>.msgpack`
+    ///
+    /// Each entry includes the modification time of every `RECORD` file from which it was derived,
+    /// and is rebuilt if the installed distributions change.
+    ImportNames,
 }
 
 impl CacheBucket {
@@ -1181,16 +1276,16 @@ impl CacheBucket {
         match self {
             // Note that when bumping this, you'll also need to bump it
             // in `crates/uv/tests/it/cache_prune.rs`.
-            Self::SourceDistributions => "sdists-v9",
-            Self::FlatIndex => "flat-index-v2",
+            Self::SourceDistributions => "sdists-v10",
+            Self::FlatIndex => "flat-index-v3",
             Self::Git => "git-v0",
-            Self::Interpreter => "interpreter-v4",
+            Self::Interpreter => "interpreter-v5",
             // Note that when bumping this, you'll also need to bump it
             // in `crates/uv/tests/it/cache_clean.rs`.
-            Self::Simple => "simple-v20",
+            Self::Simple => "simple-v22",
             // Note that when bumping this, you'll also need to bump it
             // in `crates/uv/tests/it/cache_prune.rs`.
-            Self::Wheels => "wheels-v6",
+            Self::Wheels => "wheels-v8",
             // Note that when bumping this, you'll also need to bump
             // `ARCHIVE_VERSION` in `crates/uv-cache/src/lib.rs`.
             Self::Archive => "archive-v0",
@@ -1198,55 +1293,66 @@ impl CacheBucket {
             Self::Environments => "environments-v2",
             Self::Python => "python-v0",
             Self::Binaries => "binaries-v0",
+            Self::Downloads => "downloads-v0",
+            Self::BuildLogs => "build-logs-v0",
+            Self::SelectionHistory => "selection-history-v0",
+            Self::RemoteConfig => "remote-config-v0",
+            Self::ImportNames => "import-names-v0",
         }
     }
 
-    /// Remove a package from the cache bucket.
-    ///
-    /// Returns the number of entries removed from the cache.
-    fn remove(self, cache: &Cache, name: &PackageName) -> Result<Removal, io::Error> {
+    /// Return the paths to the entries for a package in the cache bucket, some of which may not
+    /// exist.
+    pub fn package_entries(
+        self,
+        cache: &Cache,
+        name: &PackageName,
+    ) -> Result<Vec<PathBuf>, io::Error> {
         /// Returns `true` if the [`Path`] represents a built wheel for the given package.
         fn is_match(path: &Path, name: &PackageName) -> bool {
             let Ok(metadata) = fs_err::read(path.join("metadata.msgpack")) else {
                 return false;
             };
-            let Ok(metadata) = rmp_serde::from_slice::<ResolutionMetadata>(&metadata) else {
+            let Ok(metadata) = integrity::unseal(&metadata) else {
+                return false;
+            };
+            let Ok(metadata) = rmp_serde::from_slice::<ResolutionMetadata>(metadata) else {
                 return false;
             };
             metadata.name == *name
         }
 
-        let mut summary = Removal::default();
+        let mut entries = Vec::new();
         match self {
             Self::Wheels => {
                 // For `pypi` wheels, we expect a directory per package (indexed by name).
                 let root = cache.bucket(self).join(WheelCacheKind::Pypi);
-                summary += rm_rf(root.join(name.to_string()))?;
+                entries.push(root.join(name.to_string()));
 
                 // For alternate indices, we expect a directory for every index (under an `index`
                 // subdirectory), followed by a directory per package (indexed by name).
                 let root = cache.bucket(self).join(WheelCacheKind::Index);
                 for directory in directories(root)? {
-                    summary += rm_rf(directory.join(name.to_string()))?;
+                    entries.push(directory.join(name.to_string()));
                 }
 
                 // For direct URLs, we expect a directory for every URL, followed by a
                 // directory per package (indexed by name).
                 let root = cache.bucket(self).join(WheelCacheKind::Url);
                 for directory in directories(root)? {
-                    summary += rm_rf(directory.join(name.to_string()))?;
+                    entries.push(directory.join(name.to_string()));
                 }
             }
             Self::SourceDistributions => {
                 // For `pypi` wheels, we expect a directory per package (indexed by name).
                 let root = cache.bucket(self).join(WheelCacheKind::Pypi);
-                summary += rm_rf(root.join(name.to_string()))?;
+                entries.push(root.join(name.to_string()));
 
                 // For alternate indices, we expect a directory for every index (under an `index`
                 // subdirectory), followed by a directory per package (indexed by name).
                 let root = cache.bucket(self).join(WheelCacheKind::Index);
                 for directory in directories(root)? {
-                    summary += rm_rf(directory.join(name.to_string()))?;
+                    entries.push(directory.join(name.to_string()));
                 }
 
                 // For direct URLs, we expect a directory for every URL, followed by a
@@ -1255,7 +1361,7 @@ impl CacheBucket {
                 let root = cache.bucket(self).join(WheelCacheKind::Url);
                 for url in directories(root)? {
                     if directories(&url)?.any(|version| is_match(&version, name)) {
-                        summary += rm_rf(url)?;
+                        entries.push(url);
                     }
                 }
 
@@ -1265,7 +1371,7 @@ impl CacheBucket {
                 let root = cache.bucket(self).join(WheelCacheKind::Path);
                 for path in directories(root)? {
                     if directories(&path)?.any(|version| is_match(&version, name)) {
-                        summary += rm_rf(path)?;
+                        entries.push(path);
                     }
                 }
 
@@ -1276,7 +1382,7 @@ impl CacheBucket {
                 for repository in directories(root)? {
                     for sha in directories(repository)? {
                         if is_match(&sha, name) {
-                            summary += rm_rf(sha)?;
+                            entries.push(sha);
                         }
                     }
                 }
@@ -1284,20 +1390,31 @@ impl CacheBucket {
             Self::Simple => {
                 // For `pypi` wheels, we expect a rkyv file per package, indexed by name.
                 let root = cache.bucket(self).join(WheelCacheKind::Pypi);
-                summary += rm_rf(root.join(format!("{name}.rkyv")))?;
+                entries.push(root.join(format!("{name}.rkyv")));
 
                 // For alternate indices, we expect a directory for every index (under an `index`
                 // subdirectory), followed by a directory per package (indexed by name).
                 let root = cache.bucket(self).join(WheelCacheKind::Index);
                 for directory in directories(root)? {
-                    summary += rm_rf(directory.join(format!("{name}.rkyv")))?;
+                    entries.push(directory.join(format!("{name}.rkyv")));
                 }
             }
             Self::FlatIndex => {
-                // We can't know if the flat index includes a package, so we just remove the entire
+                // We can't know if the flat index includes a package, so we include the entire
                 // cache entry.
                 let root = cache.bucket(self);
-                summary += rm_rf(root)?;
+                entries.push(root);
+            }
+            Self::SelectionHistory => {
+                // The history for every package on an index is stored in a single file, so we
+                // include the entire cache entry; it's only used to prioritize metadata fetches.
+                let root = cache.bucket(self);
+                entries.push(root);
+            }
+            Self::BuildLogs => {
+                // We expect a directory per package (indexed by name).
+                let root = cache.bucket(self);
+                entries.push(root.join(name.to_string()));
             }
             Self::Git
             | Self::Interpreter
@@ -1305,10 +1422,70 @@ impl CacheBucket {
             | Self::Builds
             | Self::Environments
             | Self::Python
-            | Self::Binaries => {
+            | Self::Binaries
+            | Self::Downloads
+            | Self::RemoteConfig
+            | Self::ImportNames => {
                 // Nothing to do.
             }
         }
+        Ok(entries)
+    }
+
+    /// Return the paths to the entries for a specific version of a package in the cache bucket.
+    ///
+    /// Only registry entries are keyed by version; entries for direct URL, path, and Git
+    /// dependencies are returned in full, as in [`CacheBucket::package_entries`].
+    pub fn package_version_entries(
+        self,
+        cache: &Cache,
+        name: &PackageName,
+        version: &Version,
+    ) -> Result<Vec<PathBuf>, io::Error> {
+        let registries = [WheelCacheKind::Pypi, WheelCacheKind::Index]
+            .map(|kind| cache.bucket(self).join(kind.root()));
+
+        let mut entries = Vec::new();
+        for entry in self.package_entries(cache, name)? {
+            if !matches!(self, Self::Wheels | Self::SourceDistributions)
+                || !registries.iter().any(|root| entry.starts_with(root))
+            {
+                entries.push(entry);
+                continue;
+            }
+
+            // Registry entries are prefixed by the version, e.g., `1.0.0-py3-none-any.http` for
+            // wheels, or `1.0.0` for source distributions.
+            let children = match fs_err::read_dir(&entry) {
+                Ok(children) => children,
+                Err(err) if err.kind() == io::ErrorKind::NotFound => continue,
+                Err(err) => return Err(err),
+            };
+            for child in children {
+                let child = child?;
+                let file_name = child.file_name();
+                let Some(file_name) = file_name.to_str() else {
+                    continue;
+                };
+                let prefix = file_name
+                    .split_once('-')
+                    .map_or(file_name, |(prefix, _)| prefix);
+                if Version::from_str(prefix).is_ok_and(|prefix| prefix == *version) {
+                    entries.push(child.path());
+                }
+            }
+        }
+        Ok(entries)
+    }
+
+    /// Remove a package from the cache bucket.
+    ///
+    /// Returns the number of entries removed from the cache.
+    fn remove(self, cache: &Cache, name: &PackageName) -> Result<Removal, io::Error> {
+        let mut summary = Removal::default();
+        for entry in self.package_entries(cache, name)? {
+            summary += rm_rf(entry)?;
+        }
         Ok(summary)
     }
 
@@ -1325,6 +1502,11 @@ impl CacheBucket {
             Self::Builds,
             Self::Environments,
             Self::Binaries,
+            Self::Downloads,
+            Self::BuildLogs,
+            Self::SelectionHistory,
+            Self::RemoteConfig,
+            Self::ImportNames,
         ]
         .iter()
         .copied()
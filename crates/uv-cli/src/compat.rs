@@ -35,9 +35,6 @@ pub struct PipCompileCompatArgs {
     #[clap(long, hide = true)]
     cert: Option<String>,
 
-    #[clap(long, hide = true)]
-    client_cert: Option<String>,
-
     #[clap(long, hide = true)]
     emit_trusted_host: bool,
 
@@ -110,12 +107,6 @@ impl CompatArgs for PipCompileCompatArgs {
             ));
         }
 
-        if self.client_cert.is_some() {
-            return Err(anyhow!(
-                "pip-compile's `--client-cert` is unsupported (uv doesn't support dedicated client certificates)"
-            ));
-        }
-
         if self.emit_trusted_host {
             return Err(anyhow!(
                 "pip-compile's `--emit-trusted-host` is unsupported"
@@ -195,9 +186,6 @@ pub struct PipSyncCompatArgs {
     #[clap(long, hide = true)]
     cert: Option<String>,
 
-    #[clap(long, hide = true)]
-    client_cert: Option<String>,
-
     #[clap(long, hide = true)]
     config: Option<String>,
 
@@ -233,12 +221,6 @@ impl CompatArgs for PipSyncCompatArgs {
             ));
         }
 
-        if self.client_cert.is_some() {
-            return Err(anyhow!(
-                "pip-sync's `--client-cert` is unsupported (uv doesn't support dedicated client certificates)"
-            ));
-        }
-
         if self.config.is_some() {
             return Err(anyhow!(
                 "pip-sync's `--config` is unsupported (uv does not use a configuration file)"
@@ -1,12 +1,14 @@
 use std::ffi::OsString;
 use std::fmt::{self, Display, Formatter};
+use std::num::{NonZeroU64, NonZeroUsize};
 use std::ops::{Deref, DerefMut};
 use std::path::PathBuf;
 use std::str::FromStr;
+use std::time::Duration;
 
 use anyhow::{Result, anyhow};
 use clap::builder::styling::{AnsiColor, Effects, Style};
-use clap::builder::{PossibleValue, Styles, TypedValueParser, ValueParserFactory};
+use clap::builder::{EnumValueParser, PossibleValue, Styles, TypedValueParser, ValueParserFactory};
 use clap::error::ErrorKind;
 use clap::{Args, Parser, Subcommand};
 use clap::{ValueEnum, ValueHint};
@@ -15,21 +17,24 @@ use uv_auth::Service;
 use uv_cache::CacheArgs;
 use uv_configuration::{
     ExportFormat, IndexStrategy, KeyringProviderType, PackageNameSpecifier, PipCompileFormat,
-    ProjectBuildBackend, TargetTriple, TrustedHost, TrustedPublishing, VersionControlSystem,
+    ProjectBuildBackend, TargetTriple, TrustedHost, TrustedPublishing, UpgradeStrategy,
+    VersionControlSystem,
 };
 use uv_distribution_types::{
-    ConfigSettingEntry, ConfigSettingPackageEntry, Index, IndexUrl, Origin, PipExtraIndex,
-    PipFindLinks, PipIndex,
+    ConfigSettingEntry, ConfigSettingPackageEntry, ExtraBuildVariableEntry, Index, IndexUrl,
+    Origin, PipExtraIndex, PipFindLinks, PipIndex,
 };
 use uv_normalize::{ExtraName, GroupName, PackageName, PipGroupName};
+use uv_pep508::uv_pep440::Version;
 use uv_pep508::{MarkerTree, Requirement};
+use uv_platform_tags::{AbiTag, LanguageTag, PlatformTag};
 use uv_preview::PreviewFeature;
-use uv_pypi_types::VerbatimParsedUrl;
+use uv_pypi_types::{HashAlgorithm, VerbatimParsedUrl};
 use uv_python::{PythonDownloads, PythonPreference, PythonVersion};
 use uv_redacted::DisplaySafeUrl;
 use uv_resolver::{
-    AnnotationStyle, ExcludeNewerPackageEntry, ExcludeNewerValue, ForkStrategy, PrereleaseMode,
-    ResolutionMode,
+    AllowYankedEntry, AnnotationStyle, ExcludeNewerPackageEntry, ExcludeNewerValue, ForkStrategy,
+    PrereleaseMode, ResolutionMode,
 };
 use uv_settings::PythonInstallMirrors;
 use uv_static::EnvVars;
@@ -67,6 +72,24 @@ pub enum SyncFormat {
     Json,
 }
 
+#[derive(Debug, Default, Clone, Copy, clap::ValueEnum)]
+pub enum LockDiffFormat {
+    /// Display the changes in a human-readable format.
+    #[default]
+    Text,
+    /// Display the changes in JSON format.
+    Json,
+}
+
+#[derive(Debug, Default, Clone, Copy, clap::ValueEnum)]
+pub enum LicensesFormat {
+    /// Display the licenses in a human-readable table.
+    #[default]
+    Text,
+    /// Display the licenses in a machine-readable JSON format.
+    Json,
+}
+
 #[derive(Debug, Default, Clone, clap::ValueEnum)]
 pub enum ListFormat {
     /// Display the list of packages in a human-readable table.
@@ -79,6 +102,74 @@ pub enum ListFormat {
     Json,
 }
 
+#[derive(Debug, Default, Clone, Copy, clap::ValueEnum)]
+pub enum PipIndexFormat {
+    /// Display the list of versions in a human-readable table.
+    #[default]
+    Columns,
+    /// Display the list of versions in a machine-readable JSON format.
+    Json,
+}
+
+#[derive(Debug, Default, Clone, Copy, clap::ValueEnum)]
+pub enum PipCheckFormat {
+    /// Display the diagnostics in a human-readable format.
+    #[default]
+    Text,
+    /// Display the diagnostics in a machine-readable JSON format.
+    Json,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, clap::ValueEnum)]
+pub enum PipCheckSeverity {
+    /// Report both warnings and errors.
+    #[default]
+    Warning,
+    /// Only report errors.
+    Error,
+}
+
+#[derive(Debug, Default, Clone, Copy, clap::ValueEnum)]
+pub enum PipShowFormat {
+    /// Display the package information in a human-readable format.
+    #[default]
+    Text,
+    /// Display the package information in a machine-readable JSON format.
+    Json,
+}
+
+#[derive(Debug, Default, Clone, Copy, clap::ValueEnum)]
+pub enum PipVerifyFormat {
+    /// Display the problems in a human-readable format.
+    #[default]
+    Text,
+    /// Display the verified packages and their problems in a machine-readable JSON format.
+    Json,
+}
+
+#[derive(Debug, Clone, Copy, clap::ValueEnum)]
+pub enum PipHashAlgorithm {
+    /// Use the SHA-256 hash algorithm.
+    Sha256,
+    /// Use the SHA-384 hash algorithm.
+    Sha384,
+    /// Use the SHA-512 hash algorithm.
+    Sha512,
+    /// Use the `blake2b` hash algorithm, with a 256-bit digest.
+    Blake2b,
+}
+
+impl From<PipHashAlgorithm> for HashAlgorithm {
+    fn from(algorithm: PipHashAlgorithm) -> Self {
+        match algorithm {
+            PipHashAlgorithm::Sha256 => Self::Sha256,
+            PipHashAlgorithm::Sha384 => Self::Sha384,
+            PipHashAlgorithm::Sha512 => Self::Sha512,
+            PipHashAlgorithm::Blake2b => Self::Blake2b,
+        }
+    }
+}
+
 fn extra_name_with_clap_error(arg: &str) -> Result<ExtraName> {
     ExtraName::from_str(arg).map_err(|_err| {
         anyhow!(
@@ -125,6 +216,10 @@ pub struct TopLevelArgs {
 
     /// The path to a `uv.toml` file to use for configuration.
     ///
+    /// May also be an `https://` URL, e.g., to share a configuration file across an organization.
+    /// Relative paths in a remote configuration file are resolved against the current working
+    /// directory.
+    ///
     /// While uv configuration can be included in a `pyproject.toml` file, it is
     /// not allowed in this context.
     #[arg(
@@ -235,6 +330,14 @@ pub struct GlobalArgs {
     )]
     pub color: Option<ColorChoice>,
 
+    /// The format in which to report resolution failures.
+    ///
+    /// With `json`, a failure to find a solution is reported as a JSON object on stderr,
+    /// including the derivation tree of the conflict (the packages, version ranges, and causes
+    /// involved), rather than as prose. All other errors are reported as text.
+    #[arg(global = true, long, value_enum, value_name = "ERROR_FORMAT")]
+    pub error_format: Option<ErrorFormat>,
+
     /// Whether to load TLS certificates from the platform's native store [env: UV_NATIVE_TLS=]
     ///
     /// By default, uv loads certificates from the bundled `webpki-roots` crate. The
@@ -280,6 +383,121 @@ pub struct GlobalArgs {
     )]
     pub allow_insecure_host: Option<Vec<Maybe<TrustedHost>>>,
 
+    /// Treat unreachable indexes as empty, rather than failing.
+    ///
+    /// When enabled, if an index other than the default index can't be reached (e.g., due to a
+    /// connection error, a timeout, or a `5xx` status code), uv will emit a warning and continue
+    /// as if the index contained no packages. The default index (e.g., PyPI or `--index-url`) is
+    /// always required.
+    ///
+    /// Resolutions performed while an index is unreachable may differ from those performed when
+    /// all indexes are available; `uv pip compile` records any unreachable indexes in the output
+    /// file header.
+    #[arg(
+        global = true,
+        long,
+        env = EnvVars::UV_ALLOW_INDEX_FAILURE,
+        value_parser = clap::builder::BoolishValueParser::new(),
+    )]
+    pub allow_index_failure: bool,
+
+    /// How long to consider cached index responses fresh.
+    ///
+    /// By default, uv respects the `Cache-Control` header returned by the index (e.g., PyPI allows
+    /// Simple API responses to be cached for ten minutes). When set, cached Simple API responses
+    /// are instead considered fresh for the given duration, avoiding revalidation requests. A
+    /// `cache-control` setting on an index takes precedence over this option.
+    ///
+    /// Accepts a number of seconds, optionally followed by a unit (`s`, `m`, `h`, or `d`), e.g.,
+    /// `600` or `10m`.
+    #[arg(
+        global = true,
+        long,
+        env = EnvVars::UV_INDEX_CACHE_TTL,
+        value_parser = parse_cache_ttl,
+        value_name = "DURATION",
+    )]
+    pub index_cache_ttl: Option<Duration>,
+
+    /// Use stale cached responses for unreachable indexes, rather than failing.
+    ///
+    /// When enabled, if an index can't be reached (e.g., due to a connection error, a timeout, or
+    /// a `5xx` status code) while revalidating a cached Simple API response, uv will emit a
+    /// warning and use the cached response, regardless of its age.
+    #[arg(
+        global = true,
+        long,
+        env = EnvVars::UV_INDEX_STALE_IF_ERROR,
+        value_parser = clap::builder::BoolishValueParser::new(),
+    )]
+    pub index_stale_if_error: bool,
+
+    /// Append a JSON report of stale index metadata to the given file.
+    ///
+    /// When an index serves a PEP 658 metadata file that doesn't match the hash it advertises, uv
+    /// falls back to reading the metadata from the distribution itself. When provided, each such
+    /// distribution is also recorded as a JSON object on its own line, with the `index`,
+    /// `filename`, `url`, `expected`, and `actual` fields, e.g., for reporting to the index
+    /// operator.
+    #[arg(
+        global = true,
+        long,
+        env = EnvVars::UV_STALE_METADATA_REPORT,
+        value_hint = ValueHint::FilePath,
+    )]
+    pub stale_metadata_report: Option<PathBuf>,
+
+    /// The path to a client certificate to use for mTLS.
+    ///
+    /// Expects a PEM file containing the certificate and, unless `--client-key` is provided, the
+    /// private key. Takes precedence over the `SSL_CLIENT_CERT` environment variable.
+    #[arg(
+        global = true,
+        long,
+        env = EnvVars::UV_CLIENT_CERT,
+        value_hint = ValueHint::FilePath,
+    )]
+    pub client_cert: Option<PathBuf>,
+
+    /// The path to the private key for the client certificate.
+    ///
+    /// Expects a PEM file. Only necessary if the client certificate file doesn't include the
+    /// private key.
+    #[arg(
+        global = true,
+        long,
+        env = EnvVars::UV_CLIENT_KEY,
+        value_hint = ValueHint::FilePath,
+    )]
+    pub client_key: Option<PathBuf>,
+
+    /// The maximum download rate, in bytes per second, across all requests.
+    ///
+    /// Accepts a number of bytes, optionally followed by a unit (`K`, `M`, or `G`, in multiples of
+    /// 1024), e.g., `500K` or `10M`. Defaults to no limit.
+    #[arg(
+        global = true,
+        long,
+        env = EnvVars::UV_LIMIT_RATE,
+        value_parser = parse_limit_rate,
+        value_name = "RATE",
+    )]
+    pub limit_rate: Option<NonZeroU64>,
+
+    /// The maximum number of concurrent connections to each host.
+    ///
+    /// Requests beyond the limit wait until a response from the same host has been received.
+    /// Archive downloads (i.e., wheels and source distributions) hold their connection until the
+    /// body has been fully received; all other requests (e.g., for Simple API pages and metadata)
+    /// release it once the response headers arrive. Defaults to no limit, beyond the overall
+    /// `UV_CONCURRENT_DOWNLOADS`.
+    #[arg(
+        global = true,
+        long,
+        env = EnvVars::UV_MAX_CONNECTIONS_PER_HOST,
+    )]
+    pub max_connections_per_host: Option<NonZeroUsize>,
+
     /// Whether to enable all experimental preview features [env: UV_PREVIEW=]
     ///
     /// Preview features may change without warning.
@@ -323,6 +541,15 @@ pub struct GlobalArgs {
     #[arg(global = true, long, hide = true)]
     pub show_settings: bool,
 
+    /// Write a trace of the spans recorded during the run to the given file.
+    ///
+    /// The trace uses the Chrome trace event format, and can be viewed with `chrome://tracing` or
+    /// the Perfetto UI (<https://ui.perfetto.dev>).
+    ///
+    /// This option is used for debugging and development purposes.
+    #[arg(global = true, long, hide = true, value_hint = ValueHint::FilePath)]
+    pub trace_file: Option<PathBuf>,
+
     /// Hide all progress outputs [env: UV_NO_PROGRESS=]
     ///
     /// For example, spinners or progress bars.
@@ -358,6 +585,16 @@ pub struct GlobalArgs {
     pub project: Option<PathBuf>,
 }
 
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, clap::ValueEnum)]
+pub enum ErrorFormat {
+    /// Report errors as human-readable text.
+    #[default]
+    Text,
+
+    /// Report resolution failures as JSON.
+    Json,
+}
+
 #[derive(Debug, Copy, Clone, clap::ValueEnum)]
 pub enum ColorChoice {
     /// Enables colored output only when the output is going to a terminal or TTY with support.
@@ -523,6 +760,20 @@ pub enum Commands {
         hide = true
     )]
     Workspace(WorkspaceNamespace),
+    /// Modify built wheels.
+    #[command(
+        after_help = "Use `uv help wheel` for more details.",
+        after_long_help = "",
+        hide = true
+    )]
+    Wheel(WheelNamespace),
+    /// Manage local package indexes.
+    #[command(
+        after_help = "Use `uv help index` for more details.",
+        after_long_help = "",
+        hide = true
+    )]
+    Index(IndexNamespace),
     /// The implementation of the build backend.
     ///
     /// These commands are not directly exposed to the user, instead users invoke their build
@@ -820,6 +1071,132 @@ impl TypedValueParser for VersionBumpSpecValueParser {
     }
 }
 
+/// A pre-release strategy, as provided to `--prerelease`: either a [`PrereleaseMode`], or
+/// `allow:` followed by a comma-separated list of packages that may use pre-release versions
+/// (e.g., `allow:foo,bar`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PrereleaseSpec {
+    /// A strategy that applies to all packages.
+    Mode(PrereleaseMode),
+    /// Allow pre-release versions for the given packages only.
+    Packages(Vec<PackageName>),
+}
+
+impl PrereleaseSpec {
+    /// Returns the [`PrereleaseMode`], if the strategy applies to all packages.
+    pub fn mode(&self) -> Option<PrereleaseMode> {
+        match self {
+            Self::Mode(mode) => Some(*mode),
+            Self::Packages(_) => None,
+        }
+    }
+
+    /// Returns the packages that are allowed to have pre-release versions, if any.
+    pub fn into_packages(self) -> Option<Vec<PackageName>> {
+        match self {
+            Self::Mode(_) => None,
+            Self::Packages(packages) => Some(packages),
+        }
+    }
+}
+
+impl Display for PrereleaseSpec {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Mode(mode) => mode.fmt(f),
+            Self::Packages(packages) => {
+                write!(f, "allow:")?;
+                for (i, package) in packages.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{package}")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl FromStr for PrereleaseSpec {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let Some((name, packages)) = input.split_once(':') else {
+            return PrereleaseMode::from_str(input, false).map(Self::Mode);
+        };
+
+        if name != "allow" {
+            return Err(format!(
+                "`--prerelease {name}` does not accept packages; use `allow:<package>,...` instead"
+            ));
+        }
+
+        let packages = packages
+            .split(',')
+            .map(str::trim)
+            .filter(|package| !package.is_empty())
+            .map(|package| {
+                PackageName::from_str(package)
+                    .map_err(|err| format!("invalid package name `{package}`: {err}"))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if packages.is_empty() {
+            return Err("`--prerelease allow:` requires at least one package".to_string());
+        }
+
+        Ok(Self::Packages(packages))
+    }
+}
+
+impl ValueParserFactory for PrereleaseSpec {
+    type Parser = PrereleaseSpecValueParser;
+
+    fn value_parser() -> Self::Parser {
+        PrereleaseSpecValueParser
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct PrereleaseSpecValueParser;
+
+impl TypedValueParser for PrereleaseSpecValueParser {
+    type Value = PrereleaseSpec;
+
+    fn parse_ref(
+        &self,
+        cmd: &clap::Command,
+        arg: Option<&clap::Arg>,
+        value: &std::ffi::OsStr,
+    ) -> Result<Self::Value, clap::Error> {
+        let raw = value.to_str().ok_or_else(|| {
+            clap::Error::raw(
+                ErrorKind::InvalidUtf8,
+                "`--prerelease` values must be valid UTF-8\n",
+            )
+        })?;
+
+        // Defer to the enum parser for plain strategies, to retain its error messages.
+        if !raw.contains(':') {
+            return EnumValueParser::<PrereleaseMode>::new()
+                .parse_ref(cmd, arg, value)
+                .map(PrereleaseSpec::Mode);
+        }
+
+        PrereleaseSpec::from_str(raw)
+            .map_err(|message| clap::Error::raw(ErrorKind::InvalidValue, format!("{message}\n")))
+    }
+
+    fn possible_values(&self) -> Option<Box<dyn Iterator<Item = PossibleValue> + '_>> {
+        Some(Box::new(
+            PrereleaseMode::value_variants()
+                .iter()
+                .filter_map(ValueEnum::to_possible_value),
+        ))
+    }
+}
+
 #[derive(Args)]
 pub struct SelfNamespace {
     #[command(subcommand)]
@@ -889,6 +1266,32 @@ pub enum CacheCommand {
     /// wheels, source distributions, and other cached data. By default, outputs the size in raw
     /// bytes; use `--human` for human-readable output.
     Size(SizeArgs),
+    /// Populate the cache with the distributions required by a set of requirements.
+    ///
+    /// The requirements are resolved for each target environment, and the resulting index
+    /// metadata and wheels are fetched into the cache, such that subsequent installs of the same
+    /// requirements can be performed offline (e.g., when preparing a container image ahead of
+    /// time).
+    Warm(Box<CacheWarmArgs>),
+    /// List the wheels in the cache for a package.
+    ///
+    /// Displays each wheel that was downloaded or built from source for the package, such that
+    /// the available Python versions, ABIs, and platforms can be inspected from their tags.
+    List(CacheListArgs),
+    /// Verify the integrity of the cache, removing any corrupted entries.
+    ///
+    /// Validates the checksums of cached metadata and archive pointers, and compares the files in
+    /// each unzipped wheel against the hashes in its `RECORD` file. Corrupted entries are removed,
+    /// such that they're downloaded or built again the next time they're needed.
+    Verify(CacheVerifyArgs),
+    /// Export the cache to a bundle, to be imported on another machine.
+    ///
+    /// The bundle is a Zstandard-compressed tar archive of the cached index metadata and
+    /// distributions, which can be imported with `uv cache import` to pre-seed the cache on a
+    /// machine without network access (e.g., prior to running `uv pip install --offline`).
+    Export(CacheExportArgs),
+    /// Import a bundle created by `uv cache export` into the cache.
+    Import(CacheImportArgs),
 }
 
 #[derive(Args, Debug)]
@@ -905,6 +1308,15 @@ pub struct CleanArgs {
     pub force: bool,
 }
 
+#[derive(Args, Debug)]
+pub struct CacheListArgs {
+    /// The package to list the cached wheels for.
+    ///
+    /// May be provided multiple times.
+    #[arg(long, required = true, value_hint = ValueHint::Other)]
+    pub package: Vec<PackageName>,
+}
+
 #[derive(Args, Debug)]
 pub struct PruneArgs {
     /// Optimize the cache for persistence in a continuous integration environment, like GitHub
@@ -931,6 +1343,48 @@ pub struct PruneArgs {
     pub force: bool,
 }
 
+#[derive(Args, Debug)]
+pub struct CacheVerifyArgs {
+    /// Report any corrupted entries, but don't remove them from the cache.
+    ///
+    /// Exits with a non-zero status if any corrupted entries are found.
+    #[arg(long)]
+    pub dry_run: bool,
+
+    /// Force removal of corrupted entries, ignoring in-use checks.
+    ///
+    /// By default, `uv cache verify` will block until no process is reading the cache. When
+    /// `--force` is used, `uv cache verify` will proceed without taking a lock.
+    #[arg(long)]
+    pub force: bool,
+}
+
+#[derive(Args, Debug)]
+pub struct CacheExportArgs {
+    /// The path to which the bundle should be written, e.g., `bundle.tar.zst`.
+    #[arg(value_hint = ValueHint::FilePath)]
+    pub output: PathBuf,
+
+    /// Only export the cache entries for the packages listed in the given files.
+    ///
+    /// Each package must be pinned to an exact version (e.g., `flask==3.0.0`), and only the cache
+    /// entries for that version are exported. As such, the files should list every package in the
+    /// resolution, including transitive dependencies, as in the output of `uv pip compile` or `uv
+    /// export`. If not provided, the entire cache is exported.
+    ///
+    /// The following formats are supported: `requirements.txt`, `.py` files with inline metadata,
+    /// `pyproject.toml`, `setup.py`, `setup.cfg`, `poetry.lock`, and `Pipfile.lock`.
+    #[arg(long, value_parser = parse_file_path, value_hint = ValueHint::FilePath)]
+    pub filter: Vec<PathBuf>,
+}
+
+#[derive(Args, Debug)]
+pub struct CacheImportArgs {
+    /// The path to a bundle created by `uv cache export`.
+    #[arg(value_hint = ValueHint::FilePath)]
+    pub bundle: PathBuf,
+}
+
 #[derive(Args, Debug)]
 pub struct SizeArgs {
     /// Display the cache size in human-readable format (e.g., `1.2 GiB` instead of raw bytes).
@@ -939,48 +1393,181 @@ pub struct SizeArgs {
 }
 
 #[derive(Args)]
-pub struct PipNamespace {
-    #[command(subcommand)]
-    pub command: PipCommand,
-}
-
-#[derive(Subcommand)]
-pub enum PipCommand {
-    /// Compile a `requirements.in` file to a `requirements.txt` or `pylock.toml` file.
-    #[command(
-        after_help = "Use `uv help pip compile` for more details.",
-        after_long_help = ""
-    )]
-    Compile(PipCompileArgs),
-    /// Sync an environment with a `requirements.txt` or `pylock.toml` file.
+pub struct CacheWarmArgs {
+    /// Warm the cache with the packages listed in the given files.
     ///
-    /// When syncing an environment, any packages not listed in the `requirements.txt` or
-    /// `pylock.toml` file will be removed. To retain extraneous packages, use `uv pip install`
-    /// instead.
+    /// The following formats are supported: `requirements.txt`, `.py` files with inline metadata,
+    /// `pyproject.toml`, `setup.py`, `setup.cfg`, `poetry.lock`, and `Pipfile.lock`.
     ///
-    /// The input file is presumed to be the output of a `pip compile` or `uv export` operation,
-    /// in which it will include all transitive dependencies. If transitive dependencies are not
-    /// present in the file, they will not be installed. Use `--strict` to warn if any transitive
-    /// dependencies are missing.
-    #[command(
-        after_help = "Use `uv help pip sync` for more details.",
-        after_long_help = ""
-    )]
-    Sync(Box<PipSyncArgs>),
-    /// Install packages into an environment.
-    #[command(
-        after_help = "Use `uv help pip install` for more details.",
-        after_long_help = ""
-    )]
-    Install(PipInstallArgs),
-    /// Uninstall packages from an environment.
-    #[command(
-        after_help = "Use `uv help pip uninstall` for more details.",
-        after_long_help = ""
+    /// If `-` is provided, then requirements will be read from stdin.
+    #[arg(
+        long,
+        short,
+        alias = "requirement",
+        required = true,
+        value_parser = parse_file_path,
+        value_hint = ValueHint::FilePath,
     )]
-    Uninstall(PipUninstallArgs),
-    /// List, in requirements format, packages installed in an environment.
-    #[command(
+    pub requirements: Vec<PathBuf>,
+
+    /// Constrain versions using the given requirements files.
+    ///
+    /// Constraints files are `requirements.txt`-like files that only control the _version_ of a
+    /// requirement that's installed. However, including a package in a constraints file will _not_
+    /// trigger the installation of that package.
+    #[arg(
+        long,
+        short,
+        alias = "constraint",
+        env = EnvVars::UV_CONSTRAINT,
+        value_delimiter = ' ',
+        value_parser = parse_maybe_file_path,
+        value_hint = ValueHint::FilePath,
+    )]
+    pub constraints: Vec<Maybe<PathBuf>>,
+
+    /// Build wheels for any source distributions, and cache the built wheels.
+    ///
+    /// By default, only the metadata of source distributions is cached. Source distributions are
+    /// always built for the interpreter selected with `--python`, regardless of the target
+    /// environments.
+    #[arg(long)]
+    pub build_wheels: bool,
+
+    #[command(flatten)]
+    pub resolver: ResolverArgs,
+
+    /// The Python interpreter to use during resolution, and to build source distributions.
+    ///
+    /// The interpreter is also used as the target environment, unless `--python-version` or
+    /// `--python-platform` is provided.
+    ///
+    /// See `uv help python` for details on Python discovery and supported request formats.
+    #[arg(
+        long,
+        short,
+        env = EnvVars::UV_PYTHON,
+        verbatim_doc_comment,
+        help_heading = "Python options",
+        value_parser = parse_maybe_string,
+        value_hint = ValueHint::Other,
+    )]
+    pub python: Option<Maybe<String>>,
+
+    /// Use the system Python to resolve the requirements.
+    #[arg(
+        long,
+        env = EnvVars::UV_SYSTEM_PYTHON,
+        value_parser = clap::builder::BoolishValueParser::new(),
+        overrides_with("no_system")
+    )]
+    pub system: bool,
+
+    #[arg(long, overrides_with("system"), hide = true)]
+    pub no_system: bool,
+
+    /// A Python version for which the cache should be warmed (e.g., `3.12`).
+    ///
+    /// May be provided multiple times, in which case the requirements are resolved for each
+    /// version (and each `--python-platform`, if provided).
+    #[arg(long, help_heading = "Python options")]
+    pub python_version: Vec<PythonVersion>,
+
+    /// A platform for which the cache should be warmed, like `x86_64-unknown-linux-gnu` or
+    /// `aarch64-apple-darwin`.
+    ///
+    /// May be provided multiple times, in which case the requirements are resolved for each
+    /// platform (and each `--python-version`, if provided).
+    #[arg(long)]
+    pub python_platform: Vec<TargetTriple>,
+}
+
+#[derive(Args)]
+pub struct PipNamespace {
+    #[command(subcommand)]
+    pub command: PipCommand,
+}
+
+#[derive(Subcommand)]
+pub enum PipCommand {
+    /// Compile a `requirements.in` file to a `requirements.txt` or `pylock.toml` file.
+    #[command(
+        after_help = "Use `uv help pip compile` for more details.",
+        after_long_help = ""
+    )]
+    Compile(Box<PipCompileArgs>),
+    /// Explain why a package is included in a resolution.
+    ///
+    /// Resolves the given requirements, as with `uv pip compile`, and prints the chain of
+    /// requirements that led to each selected version of the package, along with any constraints
+    /// or overrides that applied to it.
+    ///
+    /// Accepts the same options as `uv pip compile`, such that an existing `uv pip compile`
+    /// invocation can be explained by replacing `compile` with `explain <PACKAGE>`. Options that
+    /// only affect the output of `uv pip compile` (e.g., `--output-file`) are ignored.
+    ///
+    /// If a single `uv.lock` or `pylock.toml` file is provided, the package is explained from the
+    /// lockfile as-is, without performing a resolution.
+    #[command(
+        after_help = "Use `uv help pip explain` for more details.",
+        after_long_help = ""
+    )]
+    Explain(PipExplainArgs),
+    /// Sync an environment with a `requirements.txt` or `pylock.toml` file.
+    ///
+    /// When syncing an environment, any packages not listed in the `requirements.txt` or
+    /// `pylock.toml` file will be removed. To retain extraneous packages, use `uv pip install`
+    /// instead.
+    ///
+    /// The input file is presumed to be the output of a `pip compile` or `uv export` operation,
+    /// in which it will include all transitive dependencies. If transitive dependencies are not
+    /// present in the file, they will not be installed. Use `--strict` to warn if any transitive
+    /// dependencies are missing.
+    #[command(
+        after_help = "Use `uv help pip sync` for more details.",
+        after_long_help = ""
+    )]
+    Sync(Box<PipSyncArgs>),
+    /// Install packages into an environment.
+    #[command(
+        after_help = "Use `uv help pip install` for more details.",
+        after_long_help = ""
+    )]
+    Install(PipInstallArgs),
+    /// Download packages into a directory, without installing them.
+    ///
+    /// The requirements are resolved for the target environment (as with `uv pip install`), and
+    /// the resulting wheels and source distributions are saved to the destination directory, which
+    /// can then be used as a `--find-links` source for offline installs.
+    #[command(
+        after_help = "Use `uv help pip download` for more details.",
+        after_long_help = ""
+    )]
+    Download(PipDownloadArgs),
+    /// Build wheels for packages into a directory, without installing them.
+    ///
+    /// The requirements are resolved for the target environment (as with `uv pip install`). Wheels
+    /// are downloaded for packages that provide them, while source distributions are built into
+    /// wheels.
+    #[command(
+        after_help = "Use `uv help pip wheel` for more details.",
+        after_long_help = ""
+    )]
+    Wheel(PipWheelArgs),
+    /// Uninstall packages from an environment.
+    #[command(
+        after_help = "Use `uv help pip uninstall` for more details.",
+        after_long_help = ""
+    )]
+    Uninstall(PipUninstallArgs),
+    /// Install stub distributions for packages that are provided outside of uv.
+    #[command(
+        after_help = "Use `uv help pip stub` for more details.",
+        after_long_help = ""
+    )]
+    Stub(PipStubArgs),
+    /// List, in requirements format, packages installed in an environment.
+    #[command(
         after_help = "Use `uv help pip freeze` for more details.",
         after_long_help = ""
     )]
@@ -1010,6 +1597,24 @@ pub enum PipCommand {
         after_long_help = ""
     )]
     Check(PipCheckArgs),
+    /// Verify installed files match the hashes recorded at install time.
+    #[command(
+        after_help = "Use `uv help pip verify` for more details.",
+        after_long_help = ""
+    )]
+    Verify(PipVerifyArgs),
+    /// Query the configured package indexes.
+    #[command(
+        after_help = "Use `uv help pip index` for more details.",
+        after_long_help = ""
+    )]
+    Index(PipIndexNamespace),
+    /// Compute the hashes of local distribution files.
+    #[command(
+        after_help = "Use `uv help pip hash` for more details.",
+        after_long_help = ""
+    )]
+    Hash(PipHashArgs),
     /// Display debug information (unsupported)
     #[command(hide = true)]
     Debug(PipDebugArgs),
@@ -1149,8 +1754,8 @@ pub enum ProjectCommand {
     Lock(LockArgs),
     /// Export the project's lockfile to an alternate format.
     ///
-    /// At present, `requirements.txt`, `pylock.toml` (PEP 751) and CycloneDX v1.5 JSON output
-    /// formats are supported.
+    /// At present, `requirements.txt`, `pylock.toml` (PEP 751), CycloneDX v1.5 JSON and SPDX v2.3
+    /// JSON output formats are supported.
     ///
     /// The project is re-locked before exporting unless the `--locked` or `--frozen` flag is
     /// provided.
@@ -1165,6 +1770,46 @@ pub enum ProjectCommand {
         after_long_help = ""
     )]
     Export(ExportArgs),
+    /// Download the project's locked dependencies into a directory.
+    ///
+    /// The distributions selected from `uv.lock` for the current platform are downloaded into the
+    /// vendor directory (`vendor` by default), along with a `requirements.txt` that refers to the
+    /// vendored files by relative path, such that the project's dependencies can later be
+    /// installed without network access.
+    ///
+    /// Workspace members are not vendored. Dependencies that aren't available as a wheel or source
+    /// distribution archive (e.g., Git or local directory dependencies) are only vendored with
+    /// `--build`, which builds a wheel for every source distribution.
+    ///
+    /// The project is re-locked before vendoring unless the `--locked` or `--frozen` flag is
+    /// provided.
+    #[command(
+        // NOTE: Hidden while in preview.
+        hide = true,
+        after_help = "Use `uv help vendor` for more details.",
+        after_long_help = ""
+    )]
+    Vendor(VendorArgs),
+    /// Report the licenses of the project's dependencies.
+    ///
+    /// Reads the license metadata from the locked distribution of each dependency, preferring the
+    /// SPDX license expression (PEP 639), then any license classifiers, then the free-form license
+    /// field. The distributions are selected from the lockfile for the current platform, and
+    /// fetched into the cache (or built, for source distributions) as needed. Workspace members are
+    /// omitted.
+    ///
+    /// With `--notice`, also writes a NOTICE file that attributes each package and includes the
+    /// text of the license files shipped in its distribution.
+    ///
+    /// The project is re-locked before reporting unless the `--locked` or `--frozen` flag is
+    /// provided.
+    #[command(
+        // NOTE: Hidden while in preview.
+        hide = true,
+        after_help = "Use `uv help licenses` for more details.",
+        after_long_help = ""
+    )]
+    Licenses(LicensesArgs),
     /// Display the project's dependency tree.
     Tree(TreeArgs),
     /// Format Python code in the project.
@@ -1315,6 +1960,44 @@ fn parse_insecure_host(input: &str) -> Result<Maybe<TrustedHost>, String> {
     }
 }
 
+/// Parse a cache TTL, as a number of seconds, optionally followed by a unit (`s`, `m`, `h`, or
+/// `d`).
+fn parse_cache_ttl(input: &str) -> Result<Duration, String> {
+    let input = input.trim();
+    let (value, multiplier) = match input.char_indices().last() {
+        Some((index, 's')) => (&input[..index], 1),
+        Some((index, 'm')) => (&input[..index], 60),
+        Some((index, 'h')) => (&input[..index], 60 * 60),
+        Some((index, 'd')) => (&input[..index], 60 * 60 * 24),
+        _ => (input, 1),
+    };
+    let value = value.trim().parse::<u64>().map_err(|_| {
+        format!("`{input}` is not a valid duration (expected, e.g., `600` or `10m`)")
+    })?;
+    value
+        .checked_mul(multiplier)
+        .map(Duration::from_secs)
+        .ok_or_else(|| format!("`{input}` is too large"))
+}
+
+/// Parse a download rate in bytes per second, with an optional `K`, `M`, or `G` suffix.
+fn parse_limit_rate(input: &str) -> Result<NonZeroU64, String> {
+    let input = input.trim();
+    let (value, multiplier) = match input.char_indices().last() {
+        Some((index, 'k' | 'K')) => (&input[..index], 1024),
+        Some((index, 'm' | 'M')) => (&input[..index], 1024 * 1024),
+        Some((index, 'g' | 'G')) => (&input[..index], 1024 * 1024 * 1024),
+        _ => (input, 1),
+    };
+    let value = value
+        .trim()
+        .parse::<NonZeroU64>()
+        .map_err(|_| format!("`{input}` is not a valid rate (expected, e.g., `500K` or `10M`)"))?;
+    value
+        .checked_mul(NonZeroU64::new(multiplier).unwrap())
+        .ok_or_else(|| format!("`{input}` is too large"))
+}
+
 /// Parse a string into a [`PathBuf`]. The string can represent a file, either as a path or a
 /// `file://` URL.
 fn parse_file_path(input: &str) -> Result<PathBuf, String> {
@@ -1464,6 +2147,32 @@ pub struct PipCompileArgs {
     #[command(flatten)]
     pub resolver: ResolverArgs,
 
+    /// The strategy to use when upgrading packages with `--upgrade` or `--upgrade-package`.
+    ///
+    /// With `eager`, the upgraded packages are upgraded along with all of their dependencies,
+    /// ignoring any pinned versions in the existing output file. `--upgrade` allows upgrades for
+    /// all packages, and `--upgrade-package` allows upgrades for the specified packages and their
+    /// transitive dependencies.
+    ///
+    /// With `only-if-needed`, the dependencies of the upgraded packages retain their pinned
+    /// versions, unless an upgrade is required to satisfy the requirements of the upgraded
+    /// packages. `--upgrade` only allows upgrades for the direct requirements, and
+    /// `--upgrade-package` only allows upgrades for the specified packages.
+    ///
+    /// By default, `--upgrade` is `eager`, while `--upgrade-package` is `only-if-needed`.
+    #[arg(long, value_enum, help_heading = "Resolver options")]
+    pub upgrade_strategy: Option<UpgradeStrategy>,
+
+    /// Update the versions pinned in the existing output file as little as possible.
+    ///
+    /// By default, if a pinned version is no longer compatible with the requirements (e.g., due to
+    /// an added requirement or an upgraded package), uv will select the latest compatible version
+    /// instead. With `--minimal-update`, uv will select the lowest compatible version above the
+    /// pinned version, such that only the pins that need to change are updated, and by as little
+    /// as possible.
+    #[arg(long, help_heading = "Resolver options")]
+    pub minimal_update: bool,
+
     #[command(flatten)]
     pub refresh: RefreshArgs,
 
@@ -1484,13 +2193,22 @@ pub struct PipCompileArgs {
 
     /// The format in which the resolution should be output.
     ///
-    /// Supports both `requirements.txt` and `pylock.toml` (PEP 751) output formats.
+    /// Supports `requirements.txt`, `pylock.toml` (PEP 751), CycloneDX v1.5 JSON and SPDX v2.3
+    /// JSON output formats.
     ///
-    /// uv will infer the output format from the file extension of the output file, if
-    /// provided. Otherwise, defaults to `requirements.txt`.
+    /// uv will infer the `requirements.txt` or `pylock.toml` format from the file extension of the
+    /// output file, if provided. Otherwise, defaults to `requirements.txt`. CycloneDX and SPDX
+    /// output must be requested explicitly.
     #[arg(long, value_enum)]
     pub format: Option<PipCompileFormat>,
 
+    /// Write the resolved dependency graph to the given file, in the Graphviz DOT format.
+    ///
+    /// Each node represents a resolved package, and each edge is labeled with the requirement that
+    /// introduced it, including its version specifier and markers.
+    #[arg(long, value_hint = ValueHint::FilePath)]
+    pub emit_graph: Option<PathBuf>,
+
     /// Include extras in the output file.
     ///
     /// By default, uv strips extras, as any packages pulled in by the extras are already included
@@ -1644,6 +2362,23 @@ pub struct PipCompileArgs {
     #[arg(long, help_heading = "Python options")]
     pub python_version: Option<PythonVersion>,
 
+    /// Resolve for each of the given Python versions, producing a single output that is
+    /// compatible with all of them.
+    ///
+    /// For example, `--python-versions 3.9,3.12`.
+    ///
+    /// Implies `--universal`, with the resolution limited to the given Python versions, rather than
+    /// every version above a lower bound. Where the versions require different pins, the output
+    /// includes each pin with a Python version marker; if the versions can't be resolved
+    /// together, the error identifies the Python version for which resolution failed.
+    #[arg(
+        long,
+        value_delimiter = ',',
+        help_heading = "Python options",
+        conflicts_with_all = ["python_version", "python_platform", "no_universal"]
+    )]
+    pub python_versions: Vec<PythonVersion>,
+
     /// The platform for which requirements should be resolved.
     ///
     /// Represented as a "target triple", a string that describes the target platform in terms of
@@ -1746,6 +2481,16 @@ pub struct PipCompileArgs {
     pub compat_args: compat::PipCompileCompatArgs,
 }
 
+#[derive(Args)]
+pub struct PipExplainArgs {
+    /// The package to explain.
+    #[arg(value_hint = ValueHint::Other)]
+    pub package: PackageName,
+
+    #[command(flatten)]
+    pub compile: Box<PipCompileArgs>,
+}
+
 #[derive(Args)]
 pub struct PipSyncArgs {
     /// Include the packages listed in the given files.
@@ -1756,6 +2501,9 @@ pub struct PipSyncArgs {
     /// If a `pyproject.toml`, `setup.py`, or `setup.cfg` file is provided, uv will
     /// extract the requirements for the relevant project.
     ///
+    /// A `poetry.lock` or `Pipfile.lock` can also be provided, in which case uv will install
+    /// the locked versions of its main (or default) dependencies.
+    ///
     /// If `-` is provided, then requirements will be read from stdin.
     #[arg(required(true), value_parser = parse_file_path, value_hint = ValueHint::FilePath)]
     pub src_file: Vec<PathBuf>,
@@ -1778,6 +2526,17 @@ pub struct PipSyncArgs {
     )]
     pub constraints: Vec<Maybe<PathBuf>>,
 
+    /// Install the packages listed in the given files on top of the base requirements.
+    ///
+    /// Whereas the base requirements (e.g., a `requirements.txt` or `pylock.toml` lock) are
+    /// installed exactly as pinned, the overlay requirements (e.g., extra development tools) are
+    /// resolved along with their dependencies, constrained to the versions pinned by the base.
+    ///
+    /// The overlay never changes a package from the base. Syncing again without `--overlay`
+    /// removes the overlay packages and leaves the base intact.
+    #[arg(long, value_parser = parse_file_path, value_hint = ValueHint::FilePath)]
+    pub overlay: Vec<PathBuf>,
+
     /// Constrain build dependencies using the given requirements files when building source
     /// distributions.
     ///
@@ -1840,6 +2599,9 @@ pub struct PipSyncArgs {
     /// - Editable installations are not supported.
     /// - Local dependencies are not supported, unless they point to a specific wheel (`.whl`) or
     ///   source archive (`.zip`, `.tar.gz`), as opposed to a directory.
+    ///
+    /// To install specific Git, editable, or local directory dependencies without hash-checking,
+    /// use `--allow-unhashed`.
     #[arg(
         long,
         env = EnvVars::UV_REQUIRE_HASHES,
@@ -1851,6 +2613,16 @@ pub struct PipSyncArgs {
     #[arg(long, overrides_with("require_hashes"), hide = true)]
     pub no_require_hashes: bool,
 
+    /// Allow a package to be installed without hash-checking in `--require-hashes` mode.
+    ///
+    /// Only applies to requirements that can't be hashed (i.e., Git, editable, and local directory
+    /// dependencies), which must be named (e.g., `black @ ./black`). All other requirements must
+    /// still include a hash.
+    ///
+    /// May be provided multiple times.
+    #[arg(long, value_hint = ValueHint::Other)]
+    pub allow_unhashed: Vec<PackageName>,
+
     #[arg(long, overrides_with("no_verify_hashes"), hide = true)]
     pub verify_hashes: bool,
 
@@ -2042,6 +2814,33 @@ pub struct PipSyncArgs {
     #[arg(long)]
     pub dry_run: bool,
 
+    /// Only modify packages that were installed by uv.
+    ///
+    /// If an installed package would be removed or replaced, but was installed by another tool
+    /// (e.g., conda or a system package manager), as indicated by its `INSTALLER` file, uv will
+    /// exit with an error rather than modifying it.
+    #[arg(long)]
+    pub only_uv_managed: bool,
+
+    /// Verify the installed files against the hashes in each package's `RECORD` file.
+    ///
+    /// After installation, uv will hash the files of each installed package and compare them to
+    /// the hashes recorded in the wheel, exiting with an error if any are missing or modified. This
+    /// can catch corruption introduced when linking or copying files from the cache.
+    ///
+    /// Use `uv pip verify` to re-check an environment later.
+    #[arg(long)]
+    pub verify_record: bool,
+
+    /// Reinstall any packages that were built against a different Python interpreter.
+    ///
+    /// When a package is built from source (including editables), uv records the version and ABI
+    /// of the interpreter it was built against. With this flag, any installed package whose
+    /// recorded interpreter differs from the environment's interpreter (e.g., after the interpreter
+    /// was upgraded in-place) will be rebuilt and reinstalled.
+    #[arg(long)]
+    pub reinstall_if_python_changed: bool,
+
     /// The backend to use when fetching packages in the PyTorch ecosystem (e.g., `cpu`, `cu126`, or `auto`).
     ///
     /// When set, uv will ignore the configured index URLs for packages in the PyTorch ecosystem,
@@ -2093,6 +2892,16 @@ pub struct PipInstallArgs {
     #[arg(long, short, group = "sources")]
     pub editable: Vec<String>,
 
+    /// Install the package that provides the given import name (e.g., `yaml` or `PIL.Image`).
+    ///
+    /// The import name is mapped to a package based on the packages installed in the environment,
+    /// falling back to a set of well-known mismatches (e.g., `yaml` is provided by `pyyaml`), and
+    /// otherwise assuming that the package shares a name with the top-level module.
+    ///
+    /// May be provided multiple times.
+    #[arg(long, group = "sources", value_name = "MODULE", value_hint = ValueHint::Other)]
+    pub for_import: Vec<String>,
+
     /// Constrain versions using the given requirements files.
     ///
     /// Constraints files are `requirements.txt`-like files that only control the _version_ of a
@@ -2191,6 +3000,22 @@ pub struct PipInstallArgs {
     #[command(flatten)]
     pub installer: ResolverInstallerArgs,
 
+    /// The strategy to use when upgrading packages with `--upgrade` or `--upgrade-package`.
+    ///
+    /// With `eager`, the upgraded packages are upgraded along with all of their dependencies,
+    /// ignoring any installed versions. `--upgrade` allows upgrades for all packages, and
+    /// `--upgrade-package` allows upgrades for the specified packages and their transitive
+    /// dependencies.
+    ///
+    /// With `only-if-needed`, the dependencies of the upgraded packages retain their installed
+    /// versions, unless an upgrade is required to satisfy the requirements of the upgraded
+    /// packages. `--upgrade` only allows upgrades for the requested packages, and
+    /// `--upgrade-package` only allows upgrades for the specified packages.
+    ///
+    /// By default, `--upgrade` is `eager`, while `--upgrade-package` is `only-if-needed`.
+    #[arg(long, value_enum, help_heading = "Resolver options")]
+    pub upgrade_strategy: Option<UpgradeStrategy>,
+
     #[command(flatten)]
     pub refresh: RefreshArgs,
 
@@ -2217,6 +3042,9 @@ pub struct PipInstallArgs {
     /// - Editable installations are not supported.
     /// - Local dependencies are not supported, unless they point to a specific wheel (`.whl`) or
     ///   source archive (`.zip`, `.tar.gz`), as opposed to a directory.
+    ///
+    /// To install specific Git, editable, or local directory dependencies without hash-checking,
+    /// use `--allow-unhashed`.
     #[arg(
         long,
         env = EnvVars::UV_REQUIRE_HASHES,
@@ -2228,6 +3056,16 @@ pub struct PipInstallArgs {
     #[arg(long, overrides_with("require_hashes"), hide = true)]
     pub no_require_hashes: bool,
 
+    /// Allow a package to be installed without hash-checking in `--require-hashes` mode.
+    ///
+    /// Only applies to requirements that can't be hashed (i.e., Git, editable, and local directory
+    /// dependencies), which must be named (e.g., `black @ ./black`). All other requirements must
+    /// still include a hash.
+    ///
+    /// May be provided multiple times.
+    #[arg(long, value_hint = ValueHint::Other)]
+    pub allow_unhashed: Vec<PackageName>,
+
     #[arg(long, overrides_with("no_verify_hashes"), hide = true)]
     pub verify_hashes: bool,
 
@@ -2424,6 +3262,24 @@ pub struct PipInstallArgs {
     #[arg(long)]
     pub dry_run: bool,
 
+    /// Only modify packages that were installed by uv.
+    ///
+    /// If an installed package would be removed or replaced, but was installed by another tool
+    /// (e.g., conda or a system package manager), as indicated by its `INSTALLER` file, uv will
+    /// exit with an error rather than modifying it.
+    #[arg(long)]
+    pub only_uv_managed: bool,
+
+    /// Verify the installed files against the hashes in each package's `RECORD` file.
+    ///
+    /// After installation, uv will hash the files of each installed package and compare them to
+    /// the hashes recorded in the wheel, exiting with an error if any are missing or modified. This
+    /// can catch corruption introduced when linking or copying files from the cache.
+    ///
+    /// Use `uv pip verify` to re-check an environment later.
+    #[arg(long)]
+    pub verify_record: bool,
+
     /// The backend to use when fetching packages in the PyTorch ecosystem (e.g., `cpu`, `cu126`, or `auto`)
     ///
     /// When set, uv will ignore the configured index URLs for packages in the PyTorch ecosystem,
@@ -2445,207 +3301,178 @@ pub struct PipInstallArgs {
 
 #[derive(Args)]
 #[command(group = clap::ArgGroup::new("sources").required(true).multiple(true))]
-pub struct PipUninstallArgs {
-    /// Uninstall all listed packages.
+pub struct PipDownloadArgs {
+    /// Download all listed packages.
+    ///
+    /// The order of the packages is used to determine priority during resolution.
     #[arg(group = "sources", value_hint = ValueHint::Other)]
     pub package: Vec<String>,
 
-    /// Uninstall the packages listed in the given files.
+    /// Download the packages listed in the given files.
     ///
     /// The following formats are supported: `requirements.txt`, `.py` files with inline metadata,
-    /// `pylock.toml`, `pyproject.toml`, `setup.py`, and `setup.cfg`.
-    #[arg(long, short, alias = "requirement", group = "sources", value_parser = parse_file_path, value_hint = ValueHint::FilePath)]
-    pub requirements: Vec<PathBuf>,
-
-    /// The Python interpreter from which packages should be uninstalled.
+    /// `pyproject.toml`, `setup.py`, and `setup.cfg`.
     ///
-    /// By default, uninstallation requires a virtual environment. A path to an alternative Python
-    /// can be provided, but it is only recommended in continuous integration (CI) environments and
-    /// should be used with caution, as it can modify the system Python installation.
+    /// If a `pyproject.toml`, `setup.py`, or `setup.cfg` file is provided, uv will extract the
+    /// requirements for the relevant project.
     ///
-    /// See `uv help python` for details on Python discovery and supported request formats.
+    /// If `-` is provided, then requirements will be read from stdin.
     #[arg(
         long,
         short,
-        env = EnvVars::UV_PYTHON,
-        verbatim_doc_comment,
-        help_heading = "Python options",
-        value_parser = parse_maybe_string,
-        value_hint = ValueHint::Other,
+        alias = "requirement",
+        group = "sources",
+        value_parser = parse_file_path,
+        value_hint = ValueHint::FilePath,
     )]
-    pub python: Option<Maybe<String>>,
+    pub requirements: Vec<PathBuf>,
 
-    /// Attempt to use `keyring` for authentication for remote requirements files.
-    ///
-    /// At present, only `--keyring-provider subprocess` is supported, which configures uv to use
-    /// the `keyring` CLI to handle authentication.
+    /// Constrain versions using the given requirements files.
     ///
-    /// Defaults to `disabled`.
-    #[arg(long, value_enum, env = EnvVars::UV_KEYRING_PROVIDER)]
-    pub keyring_provider: Option<KeyringProviderType>,
-
-    /// Use the system Python to uninstall packages.
+    /// Constraints files are `requirements.txt`-like files that only control the _version_ of a
+    /// requirement that's installed. However, including a package in a constraints file will _not_
+    /// trigger the installation of that package.
     ///
-    /// By default, uv uninstalls from the virtual environment in the current working directory or
-    /// any parent directory. The `--system` option instructs uv to instead use the first Python
-    /// found in the system `PATH`.
-    ///
-    /// WARNING: `--system` is intended for use in continuous integration (CI) environments and
-    /// should be used with caution, as it can modify the system Python installation.
+    /// This is equivalent to pip's `--constraint` option.
     #[arg(
         long,
-        env = EnvVars::UV_SYSTEM_PYTHON,
-        value_parser = clap::builder::BoolishValueParser::new(),
-        overrides_with("no_system")
+        short,
+        alias = "constraint",
+        env = EnvVars::UV_CONSTRAINT,
+        value_delimiter = ' ',
+        value_parser = parse_maybe_file_path,
+        value_hint = ValueHint::FilePath,
     )]
-    pub system: bool,
+    pub constraints: Vec<Maybe<PathBuf>>,
 
-    #[arg(long, overrides_with("system"), hide = true)]
-    pub no_system: bool,
+    /// Override versions using the given requirements files.
+    ///
+    /// Overrides files are `requirements.txt`-like files that force a specific version of a
+    /// requirement to be installed, regardless of the requirements declared by any constituent
+    /// package, and regardless of whether this would be considered an invalid resolution.
+    ///
+    /// While constraints are _additive_, in that they're combined with the requirements of the
+    /// constituent packages, overrides are _absolute_, in that they completely replace the
+    /// requirements of the constituent packages.
+    #[arg(
+        long,
+        alias = "override",
+        env = EnvVars::UV_OVERRIDE,
+        value_delimiter = ' ',
+        value_parser = parse_maybe_file_path,
+        value_hint = ValueHint::FilePath,
+    )]
+    pub overrides: Vec<Maybe<PathBuf>>,
 
-    /// Allow uv to modify an `EXTERNALLY-MANAGED` Python installation.
+    /// Constrain build dependencies using the given requirements files when building source
+    /// distributions.
     ///
-    /// WARNING: `--break-system-packages` is intended for use in continuous integration (CI)
-    /// environments, when installing into Python installations that are managed by an external
-    /// package manager, like `apt`. It should be used with caution, as such Python installations
-    /// explicitly recommend against modifications by other package managers (like uv or `pip`).
+    /// Constraints files are `requirements.txt`-like files that only control the _version_ of a
+    /// requirement that's installed. However, including a package in a constraints file will _not_
+    /// trigger the installation of that package.
     #[arg(
         long,
-        env = EnvVars::UV_BREAK_SYSTEM_PACKAGES,
-        value_parser = clap::builder::BoolishValueParser::new(),
-        overrides_with("no_break_system_packages")
+        short,
+        alias = "build-constraint",
+        env = EnvVars::UV_BUILD_CONSTRAINT,
+        value_delimiter = ' ',
+        value_parser = parse_maybe_file_path,
+        value_hint = ValueHint::FilePath,
     )]
-    pub break_system_packages: bool,
+    pub build_constraints: Vec<Maybe<PathBuf>>,
 
-    #[arg(long, overrides_with("break_system_packages"))]
-    pub no_break_system_packages: bool,
+    /// Include optional dependencies from the specified extra name; may be provided more than once.
+    ///
+    /// Only applies to `pyproject.toml`, `setup.py`, and `setup.cfg` sources.
+    #[arg(long, value_delimiter = ',', conflicts_with = "all_extras", value_parser = extra_name_with_clap_error)]
+    pub extra: Option<Vec<ExtraName>>,
 
-    /// Uninstall packages from the specified `--target` directory.
-    #[arg(short = 't', long, conflicts_with = "prefix", value_hint = ValueHint::DirPath)]
-    pub target: Option<PathBuf>,
+    /// Include all optional dependencies.
+    ///
+    /// Only applies to `pyproject.toml`, `setup.py`, and `setup.cfg` sources.
+    #[arg(long, conflicts_with = "extra", overrides_with = "no_all_extras")]
+    pub all_extras: bool,
 
-    /// Uninstall packages from the specified `--prefix` directory.
-    #[arg(long, conflicts_with = "target", value_hint = ValueHint::DirPath)]
-    pub prefix: Option<PathBuf>,
+    #[arg(long, overrides_with("all_extras"), hide = true)]
+    pub no_all_extras: bool,
 
-    /// Perform a dry run, i.e., don't actually uninstall anything but print the resulting plan.
-    #[arg(long)]
-    pub dry_run: bool,
+    /// Download the specified dependency group from a `pyproject.toml`.
+    ///
+    /// If no path is provided, the `pyproject.toml` in the working directory is used.
+    ///
+    /// May be provided multiple times.
+    #[arg(long, group = "sources")]
+    pub group: Vec<PipGroupName>,
 
-    #[command(flatten)]
-    pub compat_args: compat::PipGlobalCompatArgs,
-}
+    /// The directory into which the distributions should be downloaded.
+    ///
+    /// Defaults to the current working directory.
+    #[arg(long, short, value_hint = ValueHint::DirPath)]
+    pub dest: Option<PathBuf>,
 
-#[derive(Args)]
-pub struct PipFreezeArgs {
-    /// Exclude any editable packages from output.
-    #[arg(long)]
-    pub exclude_editable: bool,
+    #[command(flatten)]
+    pub resolver: ResolverArgs,
 
-    /// Exclude the specified package(s) from the output.
-    #[arg(long)]
-    pub r#exclude: Vec<PackageName>,
+    #[command(flatten)]
+    pub refresh: RefreshArgs,
 
-    /// Validate the Python environment, to detect packages with missing dependencies and other
-    /// issues.
-    #[arg(long, overrides_with("no_strict"))]
-    pub strict: bool,
+    /// Ignore package dependencies, instead only downloading those packages explicitly listed on
+    /// the command line or in the requirements files.
+    #[arg(long, overrides_with("deps"))]
+    pub no_deps: bool,
 
-    #[arg(long, overrides_with("strict"), hide = true)]
-    pub no_strict: bool,
+    #[arg(long, overrides_with("no_deps"), hide = true)]
+    pub deps: bool,
 
-    /// The Python interpreter for which packages should be listed.
+    /// Require a matching hash for each requirement.
     ///
-    /// By default, uv lists packages in a virtual environment but will show packages in a system
-    /// Python environment if no virtual environment is found.
+    /// By default, uv will verify any available hashes in the requirements file, but will not
+    /// require that all requirements have an associated hash.
     ///
-    /// See `uv help python` for details on Python discovery and supported request formats.
+    /// When `--require-hashes` is enabled, _all_ requirements must include a hash or set of hashes,
+    /// and _all_ requirements must either be pinned to exact versions (e.g., `==1.0.0`), or be
+    /// specified via direct URL.
+    ///
+    /// Hash-checking mode introduces a number of additional constraints:
+    ///
+    /// - Git dependencies are not supported.
+    /// - Editable installations are not supported.
+    /// - Local dependencies are not supported, unless they point to a specific wheel (`.whl`) or
+    ///   source archive (`.zip`, `.tar.gz`), as opposed to a directory.
     #[arg(
         long,
-        short,
-        env = EnvVars::UV_PYTHON,
-        verbatim_doc_comment,
-        help_heading = "Python options",
-        value_parser = parse_maybe_string,
-        value_hint = ValueHint::Other,
+        env = EnvVars::UV_REQUIRE_HASHES,
+        value_parser = clap::builder::BoolishValueParser::new(),
+        overrides_with("no_require_hashes"),
     )]
-    pub python: Option<Maybe<String>>,
+    pub require_hashes: bool,
 
-    /// Restrict to the specified installation path for listing packages (can be used multiple times).
-    #[arg(long("path"), value_parser = parse_file_path, value_hint = ValueHint::DirPath)]
-    pub paths: Option<Vec<PathBuf>>,
+    #[arg(long, overrides_with("require_hashes"), hide = true)]
+    pub no_require_hashes: bool,
 
-    /// List packages in the system Python environment.
-    ///
-    /// Disables discovery of virtual environments.
+    #[arg(long, overrides_with("no_verify_hashes"), hide = true)]
+    pub verify_hashes: bool,
+
+    /// Disable validation of hashes in the requirements file.
     ///
-    /// See `uv help python` for details on Python discovery.
+    /// By default, uv will verify any available hashes in the requirements file, but will not
+    /// require that all requirements have an associated hash. To enforce hash validation, use
+    /// `--require-hashes`.
     #[arg(
         long,
-        env = EnvVars::UV_SYSTEM_PYTHON,
+        env = EnvVars::UV_NO_VERIFY_HASHES,
         value_parser = clap::builder::BoolishValueParser::new(),
-        overrides_with("no_system")
+        overrides_with("verify_hashes"),
     )]
-    pub system: bool,
-
-    #[arg(long, overrides_with("system"), hide = true)]
-    pub no_system: bool,
-
-    /// List packages from the specified `--target` directory.
-    #[arg(short = 't', long, conflicts_with_all = ["prefix", "paths"], value_hint = ValueHint::DirPath)]
-    pub target: Option<PathBuf>,
-
-    /// List packages from the specified `--prefix` directory.
-    #[arg(long, conflicts_with_all = ["target", "paths"], value_hint = ValueHint::DirPath)]
-    pub prefix: Option<PathBuf>,
-
-    #[command(flatten)]
-    pub compat_args: compat::PipGlobalCompatArgs,
-}
-
-#[derive(Args)]
-pub struct PipListArgs {
-    /// Only include editable projects.
-    #[arg(short, long)]
-    pub editable: bool,
-
-    /// Exclude any editable packages from output.
-    #[arg(long, conflicts_with = "editable")]
-    pub exclude_editable: bool,
-
-    /// Exclude the specified package(s) from the output.
-    #[arg(long, value_hint = ValueHint::Other)]
-    pub r#exclude: Vec<PackageName>,
-
-    /// Select the output format.
-    #[arg(long, value_enum, default_value_t = ListFormat::default())]
-    pub format: ListFormat,
+    pub no_verify_hashes: bool,
 
-    /// List outdated packages.
+    /// The Python interpreter to use during resolution.
     ///
-    /// The latest version of each package will be shown alongside the installed version. Up-to-date
-    /// packages will be omitted from the output.
-    #[arg(long, overrides_with("no_outdated"))]
-    pub outdated: bool,
-
-    #[arg(long, overrides_with("outdated"), hide = true)]
-    pub no_outdated: bool,
-
-    /// Validate the Python environment, to detect packages with missing dependencies and other
-    /// issues.
-    #[arg(long, overrides_with("no_strict"))]
-    pub strict: bool,
-
-    #[arg(long, overrides_with("strict"), hide = true)]
-    pub no_strict: bool,
-
-    #[command(flatten)]
-    pub fetch: FetchArgs,
-
-    /// The Python interpreter for which packages should be listed.
+    /// A Python interpreter is required for building source distributions to determine package
+    /// metadata when there are not wheels.
     ///
-    /// By default, uv lists packages in a virtual environment but will show packages in a system
-    /// Python environment if no virtual environment is found.
+    /// The interpreter is also used as the target environment, unless `--python-version` or
+    /// `--python-platform` is provided.
     ///
     /// See `uv help python` for details on Python discovery and supported request formats.
     #[arg(
@@ -2659,11 +3486,12 @@ pub struct PipListArgs {
     )]
     pub python: Option<Maybe<String>>,
 
-    /// List packages in the system Python environment.
-    ///
-    /// Disables discovery of virtual environments.
+    /// Use the system Python to resolve the requirements.
     ///
-    /// See `uv help python` for details on Python discovery.
+    /// By default, uv uses the virtual environment in the current working directory or any parent
+    /// directory, falling back to searching for a Python executable in `PATH`. The `--system`
+    /// option instructs uv to avoid using a virtual environment Python and restrict its search to
+    /// the system path.
     #[arg(
         long,
         env = EnvVars::UV_SYSTEM_PYTHON,
@@ -2675,64 +3503,56 @@ pub struct PipListArgs {
     #[arg(long, overrides_with("system"), hide = true)]
     pub no_system: bool,
 
-    /// List packages from the specified `--target` directory.
-    #[arg(short = 't', long, conflicts_with = "prefix", value_hint = ValueHint::DirPath)]
-    pub target: Option<PathBuf>,
-
-    /// List packages from the specified `--prefix` directory.
-    #[arg(long, conflicts_with = "target", value_hint = ValueHint::DirPath)]
-    pub prefix: Option<PathBuf>,
-
-    #[command(flatten)]
-    pub compat_args: compat::PipListCompatArgs,
-}
-
-#[derive(Args)]
-pub struct PipCheckArgs {
-    /// The Python interpreter for which packages should be checked.
+    /// Don't build source distributions.
     ///
-    /// By default, uv checks packages in a virtual environment but will check packages in a system
-    /// Python environment if no virtual environment is found.
+    /// When enabled, resolving will not run arbitrary Python code. The cached wheels of
+    /// already-built source distributions will be reused, but operations that require building
+    /// distributions will exit with an error.
     ///
-    /// See `uv help python` for details on Python discovery and supported request formats.
+    /// Alias for `--only-binary :all:`.
     #[arg(
         long,
-        short,
-        env = EnvVars::UV_PYTHON,
-        verbatim_doc_comment,
-        help_heading = "Python options",
-        value_parser = parse_maybe_string,
-        value_hint = ValueHint::Other,
+        conflicts_with = "no_binary",
+        conflicts_with = "only_binary",
+        overrides_with("build")
     )]
-    pub python: Option<Maybe<String>>,
+    pub no_build: bool,
 
-    /// Check packages in the system Python environment.
-    ///
-    /// Disables discovery of virtual environments.
-    ///
-    /// See `uv help python` for details on Python discovery.
     #[arg(
         long,
-        env = EnvVars::UV_SYSTEM_PYTHON,
-        value_parser = clap::builder::BoolishValueParser::new(),
-        overrides_with("no_system")
+        conflicts_with = "no_binary",
+        conflicts_with = "only_binary",
+        overrides_with("no_build"),
+        hide = true
     )]
-    pub system: bool,
+    pub build: bool,
 
-    #[arg(long, overrides_with("system"), hide = true)]
-    pub no_system: bool,
+    /// Don't download pre-built wheels.
+    ///
+    /// Source distributions will be downloaded for the given packages instead. The resolver will
+    /// still use pre-built wheels to extract package metadata, if available.
+    ///
+    /// Multiple packages may be provided. Disable binaries for all packages with `:all:`.
+    /// Clear previously specified packages with `:none:`.
+    #[arg(long, value_delimiter = ',', conflicts_with = "no_build")]
+    pub no_binary: Option<Vec<PackageNameSpecifier>>,
 
-    /// The Python version against which packages should be checked.
+    /// Only download pre-built wheels; don't download or build source distributions.
     ///
-    /// By default, the installed packages are checked against the version of the current
-    /// interpreter.
-    #[arg(long)]
-    pub python_version: Option<PythonVersion>,
+    /// Multiple packages may be provided. Disable binaries for all packages with `:all:`.
+    /// Clear previously specified packages with `:none:`.
+    #[arg(long, value_delimiter = ',', conflicts_with = "no_build")]
+    pub only_binary: Option<Vec<PackageNameSpecifier>>,
 
-    /// The platform for which packages should be checked.
+    /// The minimum Python version that should be supported by the downloaded distributions (e.g.,
+    /// `3.7` or `3.7.9`).
     ///
-    /// By default, the installed packages are checked against the platform of the current
-    /// interpreter.
+    /// If a patch version is omitted, the minimum patch version is assumed. For example, `3.7` is
+    /// mapped to `3.7.0`.
+    #[arg(long, help_heading = "Python options")]
+    pub python_version: Option<PythonVersion>,
+
+    /// The platform for which the distributions should be downloaded.
     ///
     /// Represented as a "target triple", a string that describes the target platform in terms of
     /// its CPU, vendor, and operating system name, like `x86_64-unknown-linux-gnu` or
@@ -2751,92 +3571,179 @@ pub struct PipCheckArgs {
 }
 
 #[derive(Args)]
-pub struct PipShowArgs {
-    /// The package(s) to display.
-    #[arg(value_hint = ValueHint::Other)]
-    pub package: Vec<PackageName>,
-
-    /// Validate the Python environment, to detect packages with missing dependencies and other
-    /// issues.
-    #[arg(long, overrides_with("no_strict"))]
-    pub strict: bool,
-
-    #[arg(long, overrides_with("strict"), hide = true)]
-    pub no_strict: bool,
+#[command(group = clap::ArgGroup::new("sources").required(true).multiple(true))]
+pub struct PipWheelArgs {
+    /// Build wheels for all listed packages.
+    ///
+    /// The order of the packages is used to determine priority during resolution.
+    #[arg(group = "sources", value_hint = ValueHint::Other)]
+    pub package: Vec<String>,
 
-    /// Show the full list of installed files for each package.
-    #[arg(short, long)]
-    pub files: bool,
+    /// Build wheels for the packages listed in the given files.
+    ///
+    /// The following formats are supported: `requirements.txt`, `.py` files with inline metadata,
+    /// `pyproject.toml`, `setup.py`, and `setup.cfg`.
+    ///
+    /// If a `pyproject.toml`, `setup.py`, or `setup.cfg` file is provided, uv will extract the
+    /// requirements for the relevant project.
+    ///
+    /// If `-` is provided, then requirements will be read from stdin.
+    #[arg(
+        long,
+        short,
+        alias = "requirement",
+        group = "sources",
+        value_parser = parse_file_path,
+        value_hint = ValueHint::FilePath,
+    )]
+    pub requirements: Vec<PathBuf>,
 
-    /// The Python interpreter to find the package in.
+    /// Constrain versions using the given requirements files.
     ///
-    /// By default, uv looks for packages in a virtual environment but will look for packages in a
-    /// system Python environment if no virtual environment is found.
+    /// Constraints files are `requirements.txt`-like files that only control the _version_ of a
+    /// requirement that's installed. However, including a package in a constraints file will _not_
+    /// trigger the installation of that package.
     ///
-    /// See `uv help python` for details on Python discovery and supported request formats.
+    /// This is equivalent to pip's `--constraint` option.
     #[arg(
         long,
         short,
-        env = EnvVars::UV_PYTHON,
-        verbatim_doc_comment,
-        help_heading = "Python options",
-        value_parser = parse_maybe_string,
-        value_hint = ValueHint::Other,
+        alias = "constraint",
+        env = EnvVars::UV_CONSTRAINT,
+        value_delimiter = ' ',
+        value_parser = parse_maybe_file_path,
+        value_hint = ValueHint::FilePath,
     )]
-    pub python: Option<Maybe<String>>,
+    pub constraints: Vec<Maybe<PathBuf>>,
 
-    /// Show a package in the system Python environment.
+    /// Override versions using the given requirements files.
     ///
-    /// Disables discovery of virtual environments.
+    /// Overrides files are `requirements.txt`-like files that force a specific version of a
+    /// requirement to be installed, regardless of the requirements declared by any constituent
+    /// package, and regardless of whether this would be considered an invalid resolution.
     ///
-    /// See `uv help python` for details on Python discovery.
+    /// While constraints are _additive_, in that they're combined with the requirements of the
+    /// constituent packages, overrides are _absolute_, in that they completely replace the
+    /// requirements of the constituent packages.
     #[arg(
         long,
-        env = EnvVars::UV_SYSTEM_PYTHON,
-        value_parser = clap::builder::BoolishValueParser::new(),
-        overrides_with("no_system")
+        alias = "override",
+        env = EnvVars::UV_OVERRIDE,
+        value_delimiter = ' ',
+        value_parser = parse_maybe_file_path,
+        value_hint = ValueHint::FilePath,
     )]
-    pub system: bool,
+    pub overrides: Vec<Maybe<PathBuf>>,
 
-    #[arg(long, overrides_with("system"), hide = true)]
-    pub no_system: bool,
+    /// Constrain build dependencies using the given requirements files when building source
+    /// distributions.
+    ///
+    /// Constraints files are `requirements.txt`-like files that only control the _version_ of a
+    /// requirement that's installed. However, including a package in a constraints file will _not_
+    /// trigger the installation of that package.
+    #[arg(
+        long,
+        short,
+        alias = "build-constraint",
+        env = EnvVars::UV_BUILD_CONSTRAINT,
+        value_delimiter = ' ',
+        value_parser = parse_maybe_file_path,
+        value_hint = ValueHint::FilePath,
+    )]
+    pub build_constraints: Vec<Maybe<PathBuf>>,
 
-    /// Show a package from the specified `--target` directory.
-    #[arg(short = 't', long, conflicts_with = "prefix", value_hint = ValueHint::DirPath)]
-    pub target: Option<PathBuf>,
+    /// Include optional dependencies from the specified extra name; may be provided more than once.
+    ///
+    /// Only applies to `pyproject.toml`, `setup.py`, and `setup.cfg` sources.
+    #[arg(long, value_delimiter = ',', conflicts_with = "all_extras", value_parser = extra_name_with_clap_error)]
+    pub extra: Option<Vec<ExtraName>>,
 
-    /// Show a package from the specified `--prefix` directory.
-    #[arg(long, conflicts_with = "target", value_hint = ValueHint::DirPath)]
-    pub prefix: Option<PathBuf>,
+    /// Include all optional dependencies.
+    ///
+    /// Only applies to `pyproject.toml`, `setup.py`, and `setup.cfg` sources.
+    #[arg(long, conflicts_with = "extra", overrides_with = "no_all_extras")]
+    pub all_extras: bool,
 
-    #[command(flatten)]
-    pub compat_args: compat::PipGlobalCompatArgs,
-}
+    #[arg(long, overrides_with("all_extras"), hide = true)]
+    pub no_all_extras: bool,
 
-#[derive(Args)]
-pub struct PipTreeArgs {
-    /// Show the version constraint(s) imposed on each package.
-    #[arg(long)]
-    pub show_version_specifiers: bool,
+    /// Build wheels for the specified dependency group from a `pyproject.toml`.
+    ///
+    /// If no path is provided, the `pyproject.toml` in the working directory is used.
+    ///
+    /// May be provided multiple times.
+    #[arg(long, group = "sources")]
+    pub group: Vec<PipGroupName>,
+
+    /// The directory into which the wheels should be written.
+    ///
+    /// Defaults to the current working directory.
+    #[arg(long, short, value_hint = ValueHint::DirPath)]
+    pub wheel_dir: Option<PathBuf>,
 
     #[command(flatten)]
-    pub tree: DisplayTreeArgs,
+    pub resolver: ResolverArgs,
 
-    /// Validate the Python environment, to detect packages with missing dependencies and other
-    /// issues.
-    #[arg(long, overrides_with("no_strict"))]
-    pub strict: bool,
+    #[command(flatten)]
+    pub refresh: RefreshArgs,
 
-    #[arg(long, overrides_with("strict"), hide = true)]
-    pub no_strict: bool,
+    /// Ignore package dependencies, instead only building wheels for those packages explicitly
+    /// listed on the command line or in the requirements files.
+    #[arg(long, overrides_with("deps"))]
+    pub no_deps: bool,
 
-    #[command(flatten)]
-    pub fetch: FetchArgs,
+    #[arg(long, overrides_with("no_deps"), hide = true)]
+    pub deps: bool,
 
-    /// The Python interpreter for which packages should be listed.
+    /// Require a matching hash for each requirement.
     ///
-    /// By default, uv lists packages in a virtual environment but will show packages in a system
-    /// Python environment if no virtual environment is found.
+    /// By default, uv will verify any available hashes in the requirements file, but will not
+    /// require that all requirements have an associated hash.
+    ///
+    /// When `--require-hashes` is enabled, _all_ requirements must include a hash or set of hashes,
+    /// and _all_ requirements must either be pinned to exact versions (e.g., `==1.0.0`), or be
+    /// specified via direct URL.
+    ///
+    /// Hash-checking mode introduces a number of additional constraints:
+    ///
+    /// - Git dependencies are not supported.
+    /// - Editable installations are not supported.
+    /// - Local dependencies are not supported, unless they point to a specific wheel (`.whl`) or
+    ///   source archive (`.zip`, `.tar.gz`), as opposed to a directory.
+    #[arg(
+        long,
+        env = EnvVars::UV_REQUIRE_HASHES,
+        value_parser = clap::builder::BoolishValueParser::new(),
+        overrides_with("no_require_hashes"),
+    )]
+    pub require_hashes: bool,
+
+    #[arg(long, overrides_with("require_hashes"), hide = true)]
+    pub no_require_hashes: bool,
+
+    #[arg(long, overrides_with("no_verify_hashes"), hide = true)]
+    pub verify_hashes: bool,
+
+    /// Disable validation of hashes in the requirements file.
+    ///
+    /// By default, uv will verify any available hashes in the requirements file, but will not
+    /// require that all requirements have an associated hash. To enforce hash validation, use
+    /// `--require-hashes`.
+    #[arg(
+        long,
+        env = EnvVars::UV_NO_VERIFY_HASHES,
+        value_parser = clap::builder::BoolishValueParser::new(),
+        overrides_with("verify_hashes"),
+    )]
+    pub no_verify_hashes: bool,
+
+    /// The Python interpreter to use during resolution.
+    ///
+    /// A Python interpreter is required for building source distributions to determine package
+    /// metadata when there are not wheels.
+    ///
+    /// The interpreter is also used as the target environment, unless `--python-version` or
+    /// `--python-platform` is provided.
     ///
     /// See `uv help python` for details on Python discovery and supported request formats.
     #[arg(
@@ -2850,11 +3757,12 @@ pub struct PipTreeArgs {
     )]
     pub python: Option<Maybe<String>>,
 
-    /// List packages in the system Python environment.
-    ///
-    /// Disables discovery of virtual environments.
+    /// Use the system Python to resolve the requirements.
     ///
-    /// See `uv help python` for details on Python discovery.
+    /// By default, uv uses the virtual environment in the current working directory or any parent
+    /// directory, falling back to searching for a Python executable in `PATH`. The `--system`
+    /// option instructs uv to avoid using a virtual environment Python and restrict its search to
+    /// the system path.
     #[arg(
         long,
         env = EnvVars::UV_SYSTEM_PYTHON,
@@ -2866,12 +3774,748 @@ pub struct PipTreeArgs {
     #[arg(long, overrides_with("system"), hide = true)]
     pub no_system: bool,
 
-    #[command(flatten)]
-    pub compat_args: compat::PipGlobalCompatArgs,
-}
-
-#[derive(Args)]
-pub struct PipDebugArgs {
+    /// Don't build source distributions.
+    ///
+    /// When enabled, resolving will not run arbitrary Python code. The cached wheels of
+    /// already-built source distributions will be reused, but operations that require building
+    /// distributions will exit with an error.
+    ///
+    /// Alias for `--only-binary :all:`.
+    #[arg(
+        long,
+        conflicts_with = "no_binary",
+        conflicts_with = "only_binary",
+        overrides_with("build")
+    )]
+    pub no_build: bool,
+
+    #[arg(
+        long,
+        conflicts_with = "no_binary",
+        conflicts_with = "only_binary",
+        overrides_with("no_build"),
+        hide = true
+    )]
+    pub build: bool,
+
+    /// Don't use pre-built wheels.
+    ///
+    /// Wheels will be built from source distributions for the given packages instead. The resolver
+    /// will still use pre-built wheels to extract package metadata, if available.
+    ///
+    /// Multiple packages may be provided. Disable binaries for all packages with `:all:`.
+    /// Clear previously specified packages with `:none:`.
+    #[arg(long, value_delimiter = ',', conflicts_with = "no_build")]
+    pub no_binary: Option<Vec<PackageNameSpecifier>>,
+
+    /// Only use pre-built wheels; don't build source distributions.
+    ///
+    /// Multiple packages may be provided. Disable binaries for all packages with `:all:`.
+    /// Clear previously specified packages with `:none:`.
+    #[arg(long, value_delimiter = ',', conflicts_with = "no_build")]
+    pub only_binary: Option<Vec<PackageNameSpecifier>>,
+
+    /// The minimum Python version that should be supported by the wheels (e.g., `3.7` or
+    /// `3.7.9`).
+    ///
+    /// If a patch version is omitted, the minimum patch version is assumed. For example, `3.7` is
+    /// mapped to `3.7.0`.
+    #[arg(long, help_heading = "Python options")]
+    pub python_version: Option<PythonVersion>,
+
+    /// The platform for which the wheels should be collected.
+    ///
+    /// Source distributions are built for the current platform, so only pre-built wheels can be
+    /// collected for other platforms.
+    ///
+    /// Represented as a "target triple", a string that describes the target platform in terms of
+    /// its CPU, vendor, and operating system name, like `x86_64-unknown-linux-gnu` or
+    /// `aarch64-apple-darwin`.
+    ///
+    /// When targeting macOS (Darwin), the default minimum version is `13.0`. Use
+    /// `MACOSX_DEPLOYMENT_TARGET` to specify a different minimum version, e.g., `14.0`.
+    ///
+    /// When targeting iOS, the default minimum version is `13.0`. Use
+    /// `IPHONEOS_DEPLOYMENT_TARGET` to specify a different minimum version, e.g., `14.0`.
+    ///
+    /// When targeting Android, the default minimum Android API level is `24`. Use
+    /// `ANDROID_API_LEVEL` to specify a different minimum version, e.g., `26`.
+    #[arg(long)]
+    pub python_platform: Option<TargetTriple>,
+}
+
+#[derive(Args)]
+#[command(group = clap::ArgGroup::new("sources").required(true).multiple(true))]
+pub struct PipUninstallArgs {
+    /// Uninstall all listed packages.
+    #[arg(group = "sources", value_hint = ValueHint::Other)]
+    pub package: Vec<String>,
+
+    /// Uninstall the packages listed in the given files.
+    ///
+    /// The following formats are supported: `requirements.txt`, `.py` files with inline metadata,
+    /// `pylock.toml`, `pyproject.toml`, `setup.py`, and `setup.cfg`.
+    #[arg(long, short, alias = "requirement", group = "sources", value_parser = parse_file_path, value_hint = ValueHint::FilePath)]
+    pub requirements: Vec<PathBuf>,
+
+    /// The Python interpreter from which packages should be uninstalled.
+    ///
+    /// By default, uninstallation requires a virtual environment. A path to an alternative Python
+    /// can be provided, but it is only recommended in continuous integration (CI) environments and
+    /// should be used with caution, as it can modify the system Python installation.
+    ///
+    /// See `uv help python` for details on Python discovery and supported request formats.
+    #[arg(
+        long,
+        short,
+        env = EnvVars::UV_PYTHON,
+        verbatim_doc_comment,
+        help_heading = "Python options",
+        value_parser = parse_maybe_string,
+        value_hint = ValueHint::Other,
+    )]
+    pub python: Option<Maybe<String>>,
+
+    /// Attempt to use `keyring` for authentication for remote requirements files.
+    ///
+    /// At present, only `--keyring-provider subprocess` is supported, which configures uv to use
+    /// the `keyring` CLI to handle authentication.
+    ///
+    /// Defaults to `disabled`.
+    #[arg(long, value_enum, env = EnvVars::UV_KEYRING_PROVIDER)]
+    pub keyring_provider: Option<KeyringProviderType>,
+
+    /// Use the system Python to uninstall packages.
+    ///
+    /// By default, uv uninstalls from the virtual environment in the current working directory or
+    /// any parent directory. The `--system` option instructs uv to instead use the first Python
+    /// found in the system `PATH`.
+    ///
+    /// WARNING: `--system` is intended for use in continuous integration (CI) environments and
+    /// should be used with caution, as it can modify the system Python installation.
+    #[arg(
+        long,
+        env = EnvVars::UV_SYSTEM_PYTHON,
+        value_parser = clap::builder::BoolishValueParser::new(),
+        overrides_with("no_system")
+    )]
+    pub system: bool,
+
+    #[arg(long, overrides_with("system"), hide = true)]
+    pub no_system: bool,
+
+    /// Allow uv to modify an `EXTERNALLY-MANAGED` Python installation.
+    ///
+    /// WARNING: `--break-system-packages` is intended for use in continuous integration (CI)
+    /// environments, when installing into Python installations that are managed by an external
+    /// package manager, like `apt`. It should be used with caution, as such Python installations
+    /// explicitly recommend against modifications by other package managers (like uv or `pip`).
+    #[arg(
+        long,
+        env = EnvVars::UV_BREAK_SYSTEM_PACKAGES,
+        value_parser = clap::builder::BoolishValueParser::new(),
+        overrides_with("no_break_system_packages")
+    )]
+    pub break_system_packages: bool,
+
+    #[arg(long, overrides_with("break_system_packages"))]
+    pub no_break_system_packages: bool,
+
+    /// Uninstall packages from the specified `--target` directory.
+    #[arg(short = 't', long, conflicts_with = "prefix", value_hint = ValueHint::DirPath)]
+    pub target: Option<PathBuf>,
+
+    /// Uninstall packages from the specified `--prefix` directory.
+    #[arg(long, conflicts_with = "target", value_hint = ValueHint::DirPath)]
+    pub prefix: Option<PathBuf>,
+
+    /// Perform a dry run, i.e., don't actually uninstall anything but print the resulting plan.
+    #[arg(long)]
+    pub dry_run: bool,
+
+    /// Only uninstall packages that were installed by uv.
+    ///
+    /// If a package was installed by another tool (e.g., conda or a system package manager), as
+    /// indicated by its `INSTALLER` file, uv will exit with an error rather than uninstalling it.
+    #[arg(long)]
+    pub only_uv_managed: bool,
+
+    #[command(flatten)]
+    pub compat_args: compat::PipGlobalCompatArgs,
+}
+
+/// A package name and exact version for which to install a stub distribution, e.g.,
+/// `numpy==2.0.0`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StubDist {
+    pub name: PackageName,
+    pub version: Version,
+}
+
+impl Display for StubDist {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}=={}", self.name, self.version)
+    }
+}
+
+impl FromStr for StubDist {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let Some((name, version)) = input.split_once("==") else {
+            return Err(format!(
+                "Invalid stub `{input}`; expected a package name and version, e.g., `numpy==2.0.0`"
+            ));
+        };
+        let name = PackageName::from_str(name.trim()).map_err(|err| err.to_string())?;
+        let version = Version::from_str(version.trim()).map_err(|err| err.to_string())?;
+        Ok(Self { name, version })
+    }
+}
+
+#[derive(Args)]
+pub struct PipStubArgs {
+    /// The package(s) to stub, as a name and exact version (e.g., `numpy==2.0.0`).
+    ///
+    /// For each package, uv writes a `.dist-info` directory without any package files, recording
+    /// that the package is installed. Subsequent installs treat the package as satisfied by the
+    /// stub, which is useful when the package is provided outside of uv (e.g., a C extension
+    /// vendored by a system SDK).
+    ///
+    /// Stubs can be removed with `uv pip uninstall`.
+    #[arg(required = true, value_name = "PACKAGE==VERSION", value_hint = ValueHint::Other)]
+    pub package: Vec<StubDist>,
+
+    /// The Python interpreter into which stubs should be installed.
+    ///
+    /// By default, stubs are installed into a virtual environment. A path to an alternative Python
+    /// can be provided, but it is only recommended in continuous integration (CI) environments and
+    /// should be used with caution, as it can modify the system Python installation.
+    ///
+    /// See `uv help python` for details on Python discovery and supported request formats.
+    #[arg(
+        long,
+        short,
+        env = EnvVars::UV_PYTHON,
+        verbatim_doc_comment,
+        help_heading = "Python options",
+        value_parser = parse_maybe_string,
+        value_hint = ValueHint::Other,
+    )]
+    pub python: Option<Maybe<String>>,
+
+    /// Install stubs into the system Python environment.
+    ///
+    /// By default, uv installs stubs into the virtual environment in the current working directory
+    /// or any parent directory. The `--system` option instructs uv to instead use the first Python
+    /// found in the system `PATH`.
+    ///
+    /// WARNING: `--system` is intended for use in continuous integration (CI) environments and
+    /// should be used with caution, as it can modify the system Python installation.
+    #[arg(
+        long,
+        env = EnvVars::UV_SYSTEM_PYTHON,
+        value_parser = clap::builder::BoolishValueParser::new(),
+        overrides_with("no_system")
+    )]
+    pub system: bool,
+
+    #[arg(long, overrides_with("system"), hide = true)]
+    pub no_system: bool,
+
+    /// Allow uv to modify an `EXTERNALLY-MANAGED` Python installation.
+    ///
+    /// WARNING: `--break-system-packages` is intended for use in continuous integration (CI)
+    /// environments, when installing into Python installations that are managed by an external
+    /// package manager, like `apt`. It should be used with caution, as such Python installations
+    /// explicitly recommend against modifications by other package managers (like uv or `pip`).
+    #[arg(
+        long,
+        env = EnvVars::UV_BREAK_SYSTEM_PACKAGES,
+        value_parser = clap::builder::BoolishValueParser::new(),
+        overrides_with("no_break_system_packages")
+    )]
+    pub break_system_packages: bool,
+
+    #[arg(long, overrides_with("break_system_packages"))]
+    pub no_break_system_packages: bool,
+
+    /// Install stubs into the specified `--target` directory.
+    #[arg(short = 't', long, conflicts_with = "prefix", value_hint = ValueHint::DirPath)]
+    pub target: Option<PathBuf>,
+
+    /// Install stubs into the specified `--prefix` directory.
+    #[arg(long, conflicts_with = "target", value_hint = ValueHint::DirPath)]
+    pub prefix: Option<PathBuf>,
+
+    /// Perform a dry run, i.e., don't actually install any stubs but print the resulting plan.
+    #[arg(long)]
+    pub dry_run: bool,
+}
+
+#[derive(Args)]
+pub struct PipFreezeArgs {
+    /// Exclude any editable packages from output.
+    #[arg(long)]
+    pub exclude_editable: bool,
+
+    /// Exclude the specified package(s) from the output.
+    #[arg(long)]
+    pub r#exclude: Vec<PackageName>,
+
+    /// Validate the Python environment, to detect packages with missing dependencies and other
+    /// issues.
+    #[arg(long, overrides_with("no_strict"))]
+    pub strict: bool,
+
+    #[arg(long, overrides_with("strict"), hide = true)]
+    pub no_strict: bool,
+
+    /// The Python interpreter for which packages should be listed.
+    ///
+    /// By default, uv lists packages in a virtual environment but will show packages in a system
+    /// Python environment if no virtual environment is found.
+    ///
+    /// See `uv help python` for details on Python discovery and supported request formats.
+    #[arg(
+        long,
+        short,
+        env = EnvVars::UV_PYTHON,
+        verbatim_doc_comment,
+        help_heading = "Python options",
+        value_parser = parse_maybe_string,
+        value_hint = ValueHint::Other,
+    )]
+    pub python: Option<Maybe<String>>,
+
+    /// Restrict to the specified installation path for listing packages (can be used multiple times).
+    #[arg(long("path"), value_parser = parse_file_path, value_hint = ValueHint::DirPath)]
+    pub paths: Option<Vec<PathBuf>>,
+
+    /// List packages in the system Python environment.
+    ///
+    /// Disables discovery of virtual environments.
+    ///
+    /// See `uv help python` for details on Python discovery.
+    #[arg(
+        long,
+        env = EnvVars::UV_SYSTEM_PYTHON,
+        value_parser = clap::builder::BoolishValueParser::new(),
+        overrides_with("no_system")
+    )]
+    pub system: bool,
+
+    #[arg(long, overrides_with("system"), hide = true)]
+    pub no_system: bool,
+
+    /// List packages from the specified `--target` directory.
+    #[arg(short = 't', long, conflicts_with_all = ["prefix", "paths"], value_hint = ValueHint::DirPath)]
+    pub target: Option<PathBuf>,
+
+    /// List packages from the specified `--prefix` directory.
+    #[arg(long, conflicts_with_all = ["target", "paths"], value_hint = ValueHint::DirPath)]
+    pub prefix: Option<PathBuf>,
+
+    #[command(flatten)]
+    pub compat_args: compat::PipGlobalCompatArgs,
+}
+
+#[derive(Args)]
+pub struct PipListArgs {
+    /// Only include editable projects.
+    #[arg(short, long)]
+    pub editable: bool,
+
+    /// Exclude any editable packages from output.
+    #[arg(long, conflicts_with = "editable")]
+    pub exclude_editable: bool,
+
+    /// Exclude the specified package(s) from the output.
+    #[arg(long, value_hint = ValueHint::Other)]
+    pub r#exclude: Vec<PackageName>,
+
+    /// Only include packages that are not dependencies of other installed packages.
+    #[arg(long)]
+    pub not_required: bool,
+
+    /// Select the output format.
+    #[arg(long, value_enum, default_value_t = ListFormat::default())]
+    pub format: ListFormat,
+
+    /// List outdated packages.
+    ///
+    /// The latest version of each package will be shown alongside the installed version. Up-to-date
+    /// packages will be omitted from the output.
+    #[arg(long, overrides_with("no_outdated"))]
+    pub outdated: bool,
+
+    #[arg(long, overrides_with("outdated"), hide = true)]
+    pub no_outdated: bool,
+
+    /// The strategy to use when considering pre-release versions with `--outdated`.
+    ///
+    /// By default, uv will report pre-releases for packages that _only_ publish pre-releases, along
+    /// with packages for which a pre-release is installed (`if-necessary-or-explicit`).
+    ///
+    /// To allow pre-releases for specific packages only, provide them after `allow:` as a
+    /// comma-separated list (e.g., `allow:foo,bar`).
+    #[arg(long, env = EnvVars::UV_PRERELEASE)]
+    pub prerelease: Option<PrereleaseSpec>,
+
+    #[arg(long, hide = true, conflicts_with = "prerelease")]
+    pub pre: bool,
+
+    /// Validate the Python environment, to detect packages with missing dependencies and other
+    /// issues.
+    #[arg(long, overrides_with("no_strict"))]
+    pub strict: bool,
+
+    #[arg(long, overrides_with("strict"), hide = true)]
+    pub no_strict: bool,
+
+    #[command(flatten)]
+    pub fetch: FetchArgs,
+
+    /// The Python interpreter for which packages should be listed.
+    ///
+    /// By default, uv lists packages in a virtual environment but will show packages in a system
+    /// Python environment if no virtual environment is found.
+    ///
+    /// See `uv help python` for details on Python discovery and supported request formats.
+    #[arg(
+        long,
+        short,
+        env = EnvVars::UV_PYTHON,
+        verbatim_doc_comment,
+        help_heading = "Python options",
+        value_parser = parse_maybe_string,
+        value_hint = ValueHint::Other,
+    )]
+    pub python: Option<Maybe<String>>,
+
+    /// List packages in the system Python environment.
+    ///
+    /// Disables discovery of virtual environments.
+    ///
+    /// See `uv help python` for details on Python discovery.
+    #[arg(
+        long,
+        env = EnvVars::UV_SYSTEM_PYTHON,
+        value_parser = clap::builder::BoolishValueParser::new(),
+        overrides_with("no_system")
+    )]
+    pub system: bool,
+
+    #[arg(long, overrides_with("system"), hide = true)]
+    pub no_system: bool,
+
+    /// List packages from the specified `--target` directory.
+    #[arg(short = 't', long, conflicts_with = "prefix", value_hint = ValueHint::DirPath)]
+    pub target: Option<PathBuf>,
+
+    /// List packages from the specified `--prefix` directory.
+    #[arg(long, conflicts_with = "target", value_hint = ValueHint::DirPath)]
+    pub prefix: Option<PathBuf>,
+
+    #[command(flatten)]
+    pub compat_args: compat::PipListCompatArgs,
+}
+
+#[derive(Args)]
+pub struct PipCheckArgs {
+    /// Select the output format.
+    #[arg(long, value_enum, default_value_t = PipCheckFormat::default())]
+    pub format: PipCheckFormat,
+
+    /// The minimum severity of the diagnostics to report.
+    ///
+    /// Errors include missing or incompatible dependencies and broken packages. Warnings include
+    /// packages with multiple installed distributions and files in `site-packages` that aren't
+    /// owned by any installed package. The check fails if any errors are reported; warnings alone
+    /// don't affect the exit status.
+    #[arg(long, value_enum, default_value_t = PipCheckSeverity::default())]
+    pub severity: PipCheckSeverity,
+
+    /// The Python interpreter for which packages should be checked.
+    ///
+    /// By default, uv checks packages in a virtual environment but will check packages in a system
+    /// Python environment if no virtual environment is found.
+    ///
+    /// See `uv help python` for details on Python discovery and supported request formats.
+    #[arg(
+        long,
+        short,
+        env = EnvVars::UV_PYTHON,
+        verbatim_doc_comment,
+        help_heading = "Python options",
+        value_parser = parse_maybe_string,
+        value_hint = ValueHint::Other,
+    )]
+    pub python: Option<Maybe<String>>,
+
+    /// Check packages in the system Python environment.
+    ///
+    /// Disables discovery of virtual environments.
+    ///
+    /// See `uv help python` for details on Python discovery.
+    #[arg(
+        long,
+        env = EnvVars::UV_SYSTEM_PYTHON,
+        value_parser = clap::builder::BoolishValueParser::new(),
+        overrides_with("no_system")
+    )]
+    pub system: bool,
+
+    #[arg(long, overrides_with("system"), hide = true)]
+    pub no_system: bool,
+
+    /// The Python version against which packages should be checked.
+    ///
+    /// By default, the installed packages are checked against the version of the current
+    /// interpreter.
+    #[arg(long)]
+    pub python_version: Option<PythonVersion>,
+
+    /// The platform for which packages should be checked.
+    ///
+    /// By default, the installed packages are checked against the platform of the current
+    /// interpreter.
+    ///
+    /// Represented as a "target triple", a string that describes the target platform in terms of
+    /// its CPU, vendor, and operating system name, like `x86_64-unknown-linux-gnu` or
+    /// `aarch64-apple-darwin`.
+    ///
+    /// When targeting macOS (Darwin), the default minimum version is `13.0`. Use
+    /// `MACOSX_DEPLOYMENT_TARGET` to specify a different minimum version, e.g., `14.0`.
+    ///
+    /// When targeting iOS, the default minimum version is `13.0`. Use
+    /// `IPHONEOS_DEPLOYMENT_TARGET` to specify a different minimum version, e.g., `14.0`.
+    ///
+    /// When targeting Android, the default minimum Android API level is `24`. Use
+    /// `ANDROID_API_LEVEL` to specify a different minimum version, e.g., `26`.
+    #[arg(long)]
+    pub python_platform: Option<TargetTriple>,
+}
+
+#[derive(Args)]
+pub struct PipVerifyArgs {
+    /// The package(s) to verify.
+    ///
+    /// By default, all installed packages are verified.
+    #[arg(value_hint = ValueHint::Other)]
+    pub package: Vec<PackageName>,
+
+    /// Select the output format.
+    #[arg(long, value_enum, default_value_t = PipVerifyFormat::default())]
+    pub format: PipVerifyFormat,
+
+    /// The Python interpreter for which packages should be verified.
+    ///
+    /// By default, uv verifies packages in a virtual environment but will verify packages in a
+    /// system Python environment if no virtual environment is found.
+    ///
+    /// See `uv help python` for details on Python discovery and supported request formats.
+    #[arg(
+        long,
+        short,
+        env = EnvVars::UV_PYTHON,
+        verbatim_doc_comment,
+        help_heading = "Python options",
+        value_parser = parse_maybe_string,
+        value_hint = ValueHint::Other,
+    )]
+    pub python: Option<Maybe<String>>,
+
+    /// Verify packages in the system Python environment.
+    ///
+    /// Disables discovery of virtual environments.
+    ///
+    /// See `uv help python` for details on Python discovery.
+    #[arg(
+        long,
+        env = EnvVars::UV_SYSTEM_PYTHON,
+        value_parser = clap::builder::BoolishValueParser::new(),
+        overrides_with("no_system")
+    )]
+    pub system: bool,
+
+    #[arg(long, overrides_with("system"), hide = true)]
+    pub no_system: bool,
+}
+
+#[derive(Args)]
+pub struct PipIndexNamespace {
+    #[command(subcommand)]
+    pub command: PipIndexCommand,
+}
+
+#[derive(Subcommand)]
+pub enum PipIndexCommand {
+    /// List the available versions of a package.
+    ///
+    /// Queries the configured indexes (including any `--find-links` locations) and displays each
+    /// version of the package, along with whether wheels and source distributions are available,
+    /// whether the version is yanked, and its declared `Requires-Python`.
+    Versions(PipIndexVersionsArgs),
+}
+
+#[derive(Args)]
+pub struct PipIndexVersionsArgs {
+    /// The package for which versions should be listed.
+    #[arg(value_hint = ValueHint::Other)]
+    pub package: PackageName,
+
+    /// Select the output format.
+    #[arg(long, value_enum, default_value_t = PipIndexFormat::default())]
+    pub format: PipIndexFormat,
+
+    #[command(flatten)]
+    pub fetch: FetchArgs,
+}
+
+#[derive(Args, Debug)]
+pub struct PipHashArgs {
+    /// The wheel or source distribution file(s) to hash.
+    #[arg(required = true, value_hint = ValueHint::FilePath)]
+    pub paths: Vec<PathBuf>,
+
+    /// The hash algorithm(s) to use.
+    ///
+    /// May be provided multiple times, in which case a hash is printed for each algorithm.
+    #[arg(long, short, value_enum, default_values_t = [PipHashAlgorithm::Sha256])]
+    pub algorithm: Vec<PipHashAlgorithm>,
+}
+
+#[derive(Args)]
+pub struct PipShowArgs {
+    /// The package(s) to display.
+    #[arg(value_hint = ValueHint::Other)]
+    pub package: Vec<PackageName>,
+
+    /// Validate the Python environment, to detect packages with missing dependencies and other
+    /// issues.
+    #[arg(long, overrides_with("no_strict"))]
+    pub strict: bool,
+
+    #[arg(long, overrides_with("strict"), hide = true)]
+    pub no_strict: bool,
+
+    /// Show the full list of installed files for each package.
+    #[arg(short, long)]
+    pub files: bool,
+
+    /// Show the entry points (e.g., console scripts) declared by each package.
+    #[arg(long)]
+    pub entry_points: bool,
+
+    /// Select the output format.
+    #[arg(long, value_enum, default_value_t = PipShowFormat::default())]
+    pub format: PipShowFormat,
+
+    /// The Python interpreter to find the package in.
+    ///
+    /// By default, uv looks for packages in a virtual environment but will look for packages in a
+    /// system Python environment if no virtual environment is found.
+    ///
+    /// See `uv help python` for details on Python discovery and supported request formats.
+    #[arg(
+        long,
+        short,
+        env = EnvVars::UV_PYTHON,
+        verbatim_doc_comment,
+        help_heading = "Python options",
+        value_parser = parse_maybe_string,
+        value_hint = ValueHint::Other,
+    )]
+    pub python: Option<Maybe<String>>,
+
+    /// Show a package in the system Python environment.
+    ///
+    /// Disables discovery of virtual environments.
+    ///
+    /// See `uv help python` for details on Python discovery.
+    #[arg(
+        long,
+        env = EnvVars::UV_SYSTEM_PYTHON,
+        value_parser = clap::builder::BoolishValueParser::new(),
+        overrides_with("no_system")
+    )]
+    pub system: bool,
+
+    #[arg(long, overrides_with("system"), hide = true)]
+    pub no_system: bool,
+
+    /// Show a package from the specified `--target` directory.
+    #[arg(short = 't', long, conflicts_with = "prefix", value_hint = ValueHint::DirPath)]
+    pub target: Option<PathBuf>,
+
+    /// Show a package from the specified `--prefix` directory.
+    #[arg(long, conflicts_with = "target", value_hint = ValueHint::DirPath)]
+    pub prefix: Option<PathBuf>,
+
+    #[command(flatten)]
+    pub compat_args: compat::PipGlobalCompatArgs,
+}
+
+#[derive(Args)]
+pub struct PipTreeArgs {
+    /// Show the version constraint(s) imposed on each package.
+    #[arg(long)]
+    pub show_version_specifiers: bool,
+
+    #[command(flatten)]
+    pub tree: DisplayTreeArgs,
+
+    /// Validate the Python environment, to detect packages with missing dependencies and other
+    /// issues.
+    #[arg(long, overrides_with("no_strict"))]
+    pub strict: bool,
+
+    #[arg(long, overrides_with("strict"), hide = true)]
+    pub no_strict: bool,
+
+    #[command(flatten)]
+    pub fetch: FetchArgs,
+
+    /// The Python interpreter for which packages should be listed.
+    ///
+    /// By default, uv lists packages in a virtual environment but will show packages in a system
+    /// Python environment if no virtual environment is found.
+    ///
+    /// See `uv help python` for details on Python discovery and supported request formats.
+    #[arg(
+        long,
+        short,
+        env = EnvVars::UV_PYTHON,
+        verbatim_doc_comment,
+        help_heading = "Python options",
+        value_parser = parse_maybe_string,
+        value_hint = ValueHint::Other,
+    )]
+    pub python: Option<Maybe<String>>,
+
+    /// List packages in the system Python environment.
+    ///
+    /// Disables discovery of virtual environments.
+    ///
+    /// See `uv help python` for details on Python discovery.
+    #[arg(
+        long,
+        env = EnvVars::UV_SYSTEM_PYTHON,
+        value_parser = clap::builder::BoolishValueParser::new(),
+        overrides_with("no_system")
+    )]
+    pub system: bool,
+
+    #[arg(long, overrides_with("system"), hide = true)]
+    pub no_system: bool,
+
+    #[command(flatten)]
+    pub compat_args: compat::PipGlobalCompatArgs,
+}
+
+#[derive(Args)]
+pub struct PipDebugArgs {
     #[arg(long, hide = true)]
     pub platform: Option<String>,
 
@@ -4161,6 +5805,47 @@ pub struct LockArgs {
     )]
     pub dry_run: bool,
 
+    /// Update the versions pinned in the existing lockfile as little as possible.
+    ///
+    /// By default, if a locked version is no longer compatible with the project's requirements
+    /// (e.g., due to an added dependency or an upgraded package), uv will select the latest
+    /// compatible version instead. With `--minimal-update`, uv will select the lowest compatible
+    /// version above the locked version, such that only the pins that need to change are updated,
+    /// and by as little as possible.
+    #[arg(
+        long,
+        conflicts_with = "check_exists",
+        conflicts_with = "check",
+        conflicts_with = "locked"
+    )]
+    pub minimal_update: bool,
+
+    /// Compare the existing lockfile against a fresh resolution, without writing the lockfile.
+    ///
+    /// Reports the packages that would be added, removed, upgraded, or downgraded in the project
+    /// environment for the current platform, as synced by `uv sync --all-packages`. Combine with
+    /// `--upgrade` to compare against a resolution that ignores the pinned versions.
+    ///
+    /// Implies `--dry-run`.
+    #[arg(
+        long,
+        conflicts_with = "check_exists",
+        conflicts_with = "check",
+        conflicts_with = "locked"
+    )]
+    pub diff: bool,
+
+    /// The format in which to display the changes with `--diff`.
+    #[arg(long, value_enum, default_value_t = LockDiffFormat::default(), requires = "diff")]
+    pub output_format: LockDiffFormat,
+
+    /// Write the locked dependency graph to the given file, in the Graphviz DOT format.
+    ///
+    /// Each node represents a locked package, and each edge is labeled with the requirement that
+    /// introduced it, including its version specifier and markers, where known.
+    #[arg(long, value_hint = ValueHint::FilePath, conflicts_with = "check_exists")]
+    pub emit_graph: Option<PathBuf>,
+
     /// Lock the specified Python script, rather than the current project.
     ///
     /// If provided, uv will lock the script (based on its inline metadata table, in adherence with
@@ -4559,32 +6244,154 @@ pub struct RemoveArgs {
     )]
     pub optional: Option<ExtraName>,
 
-    /// Remove the packages from the specified dependency group.
-    #[arg(
-        long,
-        conflicts_with("dev"),
-        conflicts_with("optional"),
-        conflicts_with("script"),
-        value_hint = ValueHint::Other,
-    )]
-    pub group: Option<GroupName>,
+    /// Remove the packages from the specified dependency group.
+    #[arg(
+        long,
+        conflicts_with("dev"),
+        conflicts_with("optional"),
+        conflicts_with("script"),
+        value_hint = ValueHint::Other,
+    )]
+    pub group: Option<GroupName>,
+
+    /// Avoid syncing the virtual environment after re-locking the project [env: UV_NO_SYNC=]
+    #[arg(long)]
+    pub no_sync: bool,
+
+    /// Prefer the active virtual environment over the project's virtual environment.
+    ///
+    /// If the project virtual environment is active or no virtual environment is active, this has
+    /// no effect.
+    #[arg(long, overrides_with = "no_active")]
+    pub active: bool,
+
+    /// Prefer project's virtual environment over an active environment.
+    ///
+    /// This is the default behavior.
+    #[arg(long, overrides_with = "active", hide = true)]
+    pub no_active: bool,
+
+    /// Assert that the `uv.lock` will remain unchanged [env: UV_LOCKED=]
+    ///
+    /// Requires that the lockfile is up-to-date. If the lockfile is missing or needs to be updated,
+    /// uv will exit with an error.
+    #[arg(long, conflicts_with_all = ["frozen", "upgrade"])]
+    pub locked: bool,
+
+    /// Remove dependencies without re-locking the project [env: UV_FROZEN=]
+    ///
+    /// The project environment will not be synced.
+    #[arg(long, conflicts_with_all = ["locked", "upgrade", "no_sources"])]
+    pub frozen: bool,
+
+    #[command(flatten)]
+    pub installer: ResolverInstallerArgs,
+
+    #[command(flatten)]
+    pub build: BuildOptionsArgs,
+
+    #[command(flatten)]
+    pub refresh: RefreshArgs,
+
+    /// Remove the dependencies from a specific package in the workspace.
+    #[arg(long, conflicts_with = "isolated", value_hint = ValueHint::Other)]
+    pub package: Option<PackageName>,
+
+    /// Remove the dependency from the specified Python script, rather than from a project.
+    ///
+    /// If provided, uv will remove the dependency from the script's inline metadata table, in
+    /// adherence with PEP 723.
+    #[arg(long, value_hint = ValueHint::FilePath)]
+    pub script: Option<PathBuf>,
+
+    /// The Python interpreter to use for resolving and syncing.
+    ///
+    /// See `uv help python` for details on Python discovery and supported request formats.
+    #[arg(
+        long,
+        short,
+        env = EnvVars::UV_PYTHON,
+        verbatim_doc_comment,
+        help_heading = "Python options",
+        value_parser = parse_maybe_string,
+        value_hint = ValueHint::Other,
+    )]
+    pub python: Option<Maybe<String>>,
+}
+
+#[derive(Args)]
+pub struct TreeArgs {
+    /// Show a platform-independent dependency tree.
+    ///
+    /// Shows resolved package versions for all Python versions and platforms, rather than filtering
+    /// to those that are relevant for the current environment.
+    ///
+    /// Multiple versions may be shown for a each package.
+    #[arg(long)]
+    pub universal: bool,
+
+    #[command(flatten)]
+    pub tree: DisplayTreeArgs,
+
+    /// Include the development dependency group [env: UV_DEV=]
+    ///
+    /// Development dependencies are defined via `dependency-groups.dev` or
+    /// `tool.uv.dev-dependencies` in a `pyproject.toml`.
+    ///
+    /// This option is an alias for `--group dev`.
+    #[arg(long, overrides_with("no_dev"), hide = true, value_parser = clap::builder::BoolishValueParser::new())]
+    pub dev: bool,
+
+    /// Only include the development dependency group.
+    ///
+    /// The project and its dependencies will be omitted.
+    ///
+    /// This option is an alias for `--only-group dev`. Implies `--no-default-groups`.
+    #[arg(long, conflicts_with_all = ["group", "all_groups", "no_dev"])]
+    pub only_dev: bool,
+
+    /// Disable the development dependency group [env: UV_NO_DEV=]
+    ///
+    /// This option is an alias of `--no-group dev`.
+    /// See `--no-default-groups` to disable all default groups instead.
+    #[arg(long, overrides_with("dev"), value_parser = clap::builder::BoolishValueParser::new())]
+    pub no_dev: bool,
+
+    /// Include dependencies from the specified dependency group.
+    ///
+    /// May be provided multiple times.
+    #[arg(long, conflicts_with_all = ["only_group", "only_dev"])]
+    pub group: Vec<GroupName>,
 
-    /// Avoid syncing the virtual environment after re-locking the project [env: UV_NO_SYNC=]
-    #[arg(long)]
-    pub no_sync: bool,
+    /// Disable the specified dependency group.
+    ///
+    /// This option always takes precedence over default groups,
+    /// `--all-groups`, and `--group`.
+    ///
+    /// May be provided multiple times.
+    #[arg(long, env = EnvVars::UV_NO_GROUP, value_delimiter = ' ')]
+    pub no_group: Vec<GroupName>,
 
-    /// Prefer the active virtual environment over the project's virtual environment.
+    /// Ignore the default dependency groups.
     ///
-    /// If the project virtual environment is active or no virtual environment is active, this has
-    /// no effect.
-    #[arg(long, overrides_with = "no_active")]
-    pub active: bool,
+    /// uv includes the groups defined in `tool.uv.default-groups` by default.
+    /// This disables that option, however, specific groups can still be included with `--group`.
+    #[arg(long, env = EnvVars::UV_NO_DEFAULT_GROUPS, value_parser = clap::builder::BoolishValueParser::new())]
+    pub no_default_groups: bool,
 
-    /// Prefer project's virtual environment over an active environment.
+    /// Only include dependencies from the specified dependency group.
     ///
-    /// This is the default behavior.
-    #[arg(long, overrides_with = "active", hide = true)]
-    pub no_active: bool,
+    /// The project and its dependencies will be omitted.
+    ///
+    /// May be provided multiple times. Implies `--no-default-groups`.
+    #[arg(long, conflicts_with_all = ["group", "dev", "all_groups"])]
+    pub only_group: Vec<GroupName>,
+
+    /// Include dependencies from all dependency groups.
+    ///
+    /// `--no-group` can be used to exclude specific groups.
+    #[arg(long, conflicts_with_all = ["only_group", "only_dev"])]
+    pub all_groups: bool,
 
     /// Assert that the `uv.lock` will remain unchanged [env: UV_LOCKED=]
     ///
@@ -4593,33 +6400,51 @@ pub struct RemoveArgs {
     #[arg(long, conflicts_with_all = ["frozen", "upgrade"])]
     pub locked: bool,
 
-    /// Remove dependencies without re-locking the project [env: UV_FROZEN=]
+    /// Display the requirements without locking the project [env: UV_FROZEN=]
     ///
-    /// The project environment will not be synced.
+    /// If the lockfile is missing, uv will exit with an error.
     #[arg(long, conflicts_with_all = ["locked", "upgrade", "no_sources"])]
     pub frozen: bool,
 
-    #[command(flatten)]
-    pub installer: ResolverInstallerArgs,
-
     #[command(flatten)]
     pub build: BuildOptionsArgs,
 
     #[command(flatten)]
-    pub refresh: RefreshArgs,
-
-    /// Remove the dependencies from a specific package in the workspace.
-    #[arg(long, conflicts_with = "isolated", value_hint = ValueHint::Other)]
-    pub package: Option<PackageName>,
+    pub resolver: ResolverArgs,
 
-    /// Remove the dependency from the specified Python script, rather than from a project.
+    /// Show the dependency tree the specified PEP 723 Python script, rather than the current
+    /// project.
     ///
-    /// If provided, uv will remove the dependency from the script's inline metadata table, in
+    /// If provided, uv will resolve the dependencies based on its inline metadata table, in
     /// adherence with PEP 723.
     #[arg(long, value_hint = ValueHint::FilePath)]
     pub script: Option<PathBuf>,
 
-    /// The Python interpreter to use for resolving and syncing.
+    /// The Python version to use when filtering the tree.
+    ///
+    /// For example, pass `--python-version 3.10` to display the dependencies that would be included
+    /// when installing on Python 3.10.
+    ///
+    /// Defaults to the version of the discovered Python interpreter.
+    #[arg(long, conflicts_with = "universal")]
+    pub python_version: Option<PythonVersion>,
+
+    /// The platform to use when filtering the tree.
+    ///
+    /// For example, pass `--platform windows` to display the dependencies that would be included
+    /// when installing on Windows.
+    ///
+    /// Represented as a "target triple", a string that describes the target platform in terms of
+    /// its CPU, vendor, and operating system name, like `x86_64-unknown-linux-gnu` or
+    /// `aarch64-apple-darwin`.
+    #[arg(long, conflicts_with = "universal")]
+    pub python_platform: Option<TargetTriple>,
+
+    /// The Python interpreter to use for locking and filtering.
+    ///
+    /// By default, the tree is filtered to match the platform as reported by the Python
+    /// interpreter. Use `--universal` to display the tree for all platforms, or use
+    /// `--python-version` or `--python-platform` to override a subset of markers.
     ///
     /// See `uv help python` for details on Python discovery and supported request formats.
     #[arg(
@@ -4635,78 +6460,250 @@ pub struct RemoveArgs {
 }
 
 #[derive(Args)]
-pub struct TreeArgs {
-    /// Show a platform-independent dependency tree.
+pub struct ExportArgs {
+    /// The format to which `uv.lock` should be exported.
     ///
-    /// Shows resolved package versions for all Python versions and platforms, rather than filtering
-    /// to those that are relevant for the current environment.
+    /// Supports `requirements.txt`, `pylock.toml` (PEP 751), CycloneDX v1.5 JSON and SPDX v2.3
+    /// JSON output formats.
     ///
-    /// Multiple versions may be shown for a each package.
+    /// uv will infer the output format from the file extension of the output file, if
+    /// provided. Otherwise, defaults to `requirements.txt`.
+    #[arg(long, value_enum, conflicts_with = "sbom")]
+    pub format: Option<ExportFormat>,
+
+    /// Export a software bill of materials (SBOM).
+    ///
+    /// This option is an alias for `--format cyclonedx1.5`.
     #[arg(long)]
-    pub universal: bool,
+    pub sbom: bool,
 
-    #[command(flatten)]
-    pub tree: DisplayTreeArgs,
+    /// Export the entire workspace.
+    ///
+    /// The dependencies for all workspace members will be included in the exported requirements
+    /// file.
+    ///
+    /// Any extras or groups specified via `--extra`, `--group`, or related options will be applied
+    /// to all workspace members.
+    #[arg(long, conflicts_with = "package")]
+    pub all_packages: bool,
 
-    /// Include the development dependency group [env: UV_DEV=]
+    /// Export the dependencies for specific packages in the workspace.
     ///
-    /// Development dependencies are defined via `dependency-groups.dev` or
-    /// `tool.uv.dev-dependencies` in a `pyproject.toml`.
+    /// If any workspace member does not exist, uv will exit with an error.
+    #[arg(long, conflicts_with = "all_packages", value_hint = ValueHint::Other)]
+    pub package: Vec<PackageName>,
+
+    /// Prune the given package from the dependency tree.
+    ///
+    /// Pruned packages will be excluded from the exported requirements file, as will any
+    /// dependencies that are no longer required after the pruned package is removed.
+    #[arg(long, conflicts_with = "all_packages", value_name = "PACKAGE")]
+    pub prune: Vec<PackageName>,
+
+    /// Include optional dependencies from the specified extra name.
+    ///
+    /// May be provided more than once.
+    #[arg(long, value_delimiter = ',', conflicts_with = "all_extras", conflicts_with = "only_group", value_parser = extra_name_with_clap_error)]
+    pub extra: Option<Vec<ExtraName>>,
+
+    /// Include all optional dependencies.
+    #[arg(long, conflicts_with = "extra", conflicts_with = "only_group")]
+    pub all_extras: bool,
+
+    /// Exclude the specified optional dependencies, if `--all-extras` is supplied.
+    ///
+    /// May be provided multiple times.
+    #[arg(long)]
+    pub no_extra: Vec<ExtraName>,
+
+    #[arg(long, overrides_with("all_extras"), hide = true)]
+    pub no_all_extras: bool,
+
+    /// Include the development dependency group [env: UV_DEV=]
     ///
     /// This option is an alias for `--group dev`.
     #[arg(long, overrides_with("no_dev"), hide = true, value_parser = clap::builder::BoolishValueParser::new())]
     pub dev: bool,
 
-    /// Only include the development dependency group.
-    ///
-    /// The project and its dependencies will be omitted.
-    ///
-    /// This option is an alias for `--only-group dev`. Implies `--no-default-groups`.
-    #[arg(long, conflicts_with_all = ["group", "all_groups", "no_dev"])]
-    pub only_dev: bool,
+    /// Disable the development dependency group [env: UV_NO_DEV=]
+    ///
+    /// This option is an alias of `--no-group dev`.
+    /// See `--no-default-groups` to disable all default groups instead.
+    #[arg(long, overrides_with("dev"), value_parser = clap::builder::BoolishValueParser::new())]
+    pub no_dev: bool,
+
+    /// Only include the development dependency group.
+    ///
+    /// The project and its dependencies will be omitted.
+    ///
+    /// This option is an alias for `--only-group dev`. Implies `--no-default-groups`.
+    #[arg(long, conflicts_with_all = ["group", "all_groups", "no_dev"])]
+    pub only_dev: bool,
+
+    /// Include dependencies from the specified dependency group.
+    ///
+    /// May be provided multiple times.
+    #[arg(long, conflicts_with_all = ["only_group", "only_dev"])]
+    pub group: Vec<GroupName>,
+
+    /// Disable the specified dependency group.
+    ///
+    /// This option always takes precedence over default groups,
+    /// `--all-groups`, and `--group`.
+    ///
+    /// May be provided multiple times.
+    #[arg(long, env = EnvVars::UV_NO_GROUP, value_delimiter = ' ')]
+    pub no_group: Vec<GroupName>,
+
+    /// Ignore the default dependency groups.
+    ///
+    /// uv includes the groups defined in `tool.uv.default-groups` by default.
+    /// This disables that option, however, specific groups can still be included with `--group`.
+    #[arg(long, env = EnvVars::UV_NO_DEFAULT_GROUPS, value_parser = clap::builder::BoolishValueParser::new())]
+    pub no_default_groups: bool,
+
+    /// Only include dependencies from the specified dependency group.
+    ///
+    /// The project and its dependencies will be omitted.
+    ///
+    /// May be provided multiple times. Implies `--no-default-groups`.
+    #[arg(long, conflicts_with_all = ["group", "dev", "all_groups"])]
+    pub only_group: Vec<GroupName>,
+
+    /// Include dependencies from all dependency groups.
+    ///
+    /// `--no-group` can be used to exclude specific groups.
+    #[arg(long, conflicts_with_all = ["only_group", "only_dev"])]
+    pub all_groups: bool,
+
+    /// Exclude comment annotations indicating the source of each package.
+    #[arg(long, overrides_with("annotate"))]
+    pub no_annotate: bool,
+
+    #[arg(long, overrides_with("no_annotate"), hide = true)]
+    pub annotate: bool,
+
+    /// Exclude the comment header at the top of the generated output file.
+    #[arg(long, overrides_with("header"))]
+    pub no_header: bool,
+
+    #[arg(long, overrides_with("no_header"), hide = true)]
+    pub header: bool,
+
+    /// Export any non-editable dependencies, including the project and any workspace members, as
+    /// editable.
+    #[arg(long, overrides_with = "no_editable", hide = true)]
+    pub editable: bool,
+
+    /// Export any editable dependencies, including the project and any workspace members, as
+    /// non-editable [env: UV_NO_EDITABLE=]
+    #[arg(long, overrides_with = "editable", value_parser = clap::builder::BoolishValueParser::new())]
+    pub no_editable: bool,
+
+    /// Include hashes for all dependencies.
+    #[arg(long, overrides_with("no_hashes"), hide = true)]
+    pub hashes: bool,
+
+    /// Omit hashes in the generated output.
+    #[arg(long, overrides_with("hashes"))]
+    pub no_hashes: bool,
+
+    /// Write the exported requirements to the given file.
+    #[arg(long, short, value_hint = ValueHint::FilePath)]
+    pub output_file: Option<PathBuf>,
+
+    /// Do not emit the current project.
+    ///
+    /// By default, the current project is included in the exported requirements file with all of
+    /// its dependencies. The `--no-emit-project` option allows the project to be excluded, but all
+    /// of its dependencies to remain included.
+    ///
+    /// The inverse `--only-emit-project` can be used to emit _only_ the project itself, excluding
+    /// all dependencies.
+    #[arg(
+        long,
+        alias = "no-install-project",
+        conflicts_with = "only_emit_project"
+    )]
+    pub no_emit_project: bool,
+
+    /// Only emit the current project.
+    #[arg(
+        long,
+        alias = "only-install-project",
+        conflicts_with = "no_emit_project",
+        hide = true
+    )]
+    pub only_emit_project: bool,
 
-    /// Disable the development dependency group [env: UV_NO_DEV=]
+    /// Do not emit any workspace members, including the root project.
     ///
-    /// This option is an alias of `--no-group dev`.
-    /// See `--no-default-groups` to disable all default groups instead.
-    #[arg(long, overrides_with("dev"), value_parser = clap::builder::BoolishValueParser::new())]
-    pub no_dev: bool,
-
-    /// Include dependencies from the specified dependency group.
+    /// By default, all workspace members and their dependencies are included in the exported
+    /// requirements file, with all of their dependencies. The `--no-emit-workspace` option allows
+    /// exclusion of all the workspace members while retaining their dependencies.
     ///
-    /// May be provided multiple times.
-    #[arg(long, conflicts_with_all = ["only_group", "only_dev"])]
-    pub group: Vec<GroupName>,
+    /// The inverse `--only-emit-workspace` can be used to emit _only_ workspace members, excluding
+    /// all other dependencies.
+    #[arg(
+        long,
+        alias = "no-install-workspace",
+        conflicts_with = "only_emit_workspace"
+    )]
+    pub no_emit_workspace: bool,
 
-    /// Disable the specified dependency group.
+    /// Only emit workspace members, including the root project.
+    #[arg(
+        long,
+        alias = "only-install-workspace",
+        conflicts_with = "no_emit_workspace",
+        hide = true
+    )]
+    pub only_emit_workspace: bool,
+
+    /// Do not include local path dependencies in the exported requirements.
     ///
-    /// This option always takes precedence over default groups,
-    /// `--all-groups`, and `--group`.
+    /// Omits the current project, workspace members, and any other local (path or editable)
+    /// packages from the export. Only remote/indexed dependencies are written. Useful for Docker
+    /// and CI flows that want to export and cache third-party dependencies first.
     ///
-    /// May be provided multiple times.
-    #[arg(long, env = EnvVars::UV_NO_GROUP, value_delimiter = ' ')]
-    pub no_group: Vec<GroupName>,
+    /// The inverse `--only-emit-local` can be used to emit _only_ local packages, excluding all
+    /// remote dependencies.
+    #[arg(long, alias = "no-install-local", conflicts_with = "only_emit_local")]
+    pub no_emit_local: bool,
 
-    /// Ignore the default dependency groups.
-    ///
-    /// uv includes the groups defined in `tool.uv.default-groups` by default.
-    /// This disables that option, however, specific groups can still be included with `--group`.
-    #[arg(long, env = EnvVars::UV_NO_DEFAULT_GROUPS, value_parser = clap::builder::BoolishValueParser::new())]
-    pub no_default_groups: bool,
+    /// Only include local path dependencies in the exported requirements.
+    #[arg(
+        long,
+        alias = "only-install-local",
+        conflicts_with = "no_emit_local",
+        hide = true
+    )]
+    pub only_emit_local: bool,
 
-    /// Only include dependencies from the specified dependency group.
+    /// Do not emit the given package(s).
     ///
-    /// The project and its dependencies will be omitted.
+    /// By default, all project's dependencies are included in the exported requirements
+    /// file. The `--no-emit-package` option allows exclusion of specific packages.
     ///
-    /// May be provided multiple times. Implies `--no-default-groups`.
-    #[arg(long, conflicts_with_all = ["group", "dev", "all_groups"])]
-    pub only_group: Vec<GroupName>,
+    /// The inverse `--only-emit-package` can be used to emit _only_ the specified packages,
+    /// excluding all others.
+    #[arg(
+        long,
+        alias = "no-install-package",
+        conflicts_with = "only_emit_package",
+        value_hint = ValueHint::Other,
+    )]
+    pub no_emit_package: Vec<PackageName>,
 
-    /// Include dependencies from all dependency groups.
-    ///
-    /// `--no-group` can be used to exclude specific groups.
-    #[arg(long, conflicts_with_all = ["only_group", "only_dev"])]
-    pub all_groups: bool,
+    /// Only emit the given package(s).
+    #[arg(
+        long,
+        alias = "only-install-package",
+        conflicts_with = "no_emit_package",
+        hide = true,
+        value_hint = ValueHint::Other,
+    )]
+    pub only_emit_package: Vec<PackageName>,
 
     /// Assert that the `uv.lock` will remain unchanged [env: UV_LOCKED=]
     ///
@@ -4715,51 +6712,40 @@ pub struct TreeArgs {
     #[arg(long, conflicts_with_all = ["frozen", "upgrade"])]
     pub locked: bool,
 
-    /// Display the requirements without locking the project [env: UV_FROZEN=]
+    /// Do not update the `uv.lock` before exporting [env: UV_FROZEN=]
     ///
-    /// If the lockfile is missing, uv will exit with an error.
+    /// If a `uv.lock` does not exist, uv will exit with an error.
     #[arg(long, conflicts_with_all = ["locked", "upgrade", "no_sources"])]
     pub frozen: bool,
 
+    #[command(flatten)]
+    pub resolver: ResolverArgs,
+
     #[command(flatten)]
     pub build: BuildOptionsArgs,
 
     #[command(flatten)]
-    pub resolver: ResolverArgs,
+    pub refresh: RefreshArgs,
 
-    /// Show the dependency tree the specified PEP 723 Python script, rather than the current
+    /// Export the dependencies for the specified PEP 723 Python script, rather than the current
     /// project.
     ///
     /// If provided, uv will resolve the dependencies based on its inline metadata table, in
     /// adherence with PEP 723.
-    #[arg(long, value_hint = ValueHint::FilePath)]
+    #[arg(
+        long,
+        conflicts_with_all = ["all_packages", "package", "no_emit_project", "no_emit_workspace"],
+        value_hint = ValueHint::FilePath,
+    )]
     pub script: Option<PathBuf>,
 
-    /// The Python version to use when filtering the tree.
-    ///
-    /// For example, pass `--python-version 3.10` to display the dependencies that would be included
-    /// when installing on Python 3.10.
-    ///
-    /// Defaults to the version of the discovered Python interpreter.
-    #[arg(long, conflicts_with = "universal")]
-    pub python_version: Option<PythonVersion>,
-
-    /// The platform to use when filtering the tree.
-    ///
-    /// For example, pass `--platform windows` to display the dependencies that would be included
-    /// when installing on Windows.
+    /// The Python interpreter to use during resolution.
     ///
-    /// Represented as a "target triple", a string that describes the target platform in terms of
-    /// its CPU, vendor, and operating system name, like `x86_64-unknown-linux-gnu` or
-    /// `aarch64-apple-darwin`.
-    #[arg(long, conflicts_with = "universal")]
-    pub python_platform: Option<TargetTriple>,
-
-    /// The Python interpreter to use for locking and filtering.
+    /// A Python interpreter is required for building source distributions to determine package
+    /// metadata when there are not wheels.
     ///
-    /// By default, the tree is filtered to match the platform as reported by the Python
-    /// interpreter. Use `--universal` to display the tree for all platforms, or use
-    /// `--python-version` or `--python-platform` to override a subset of markers.
+    /// The interpreter is also used as the fallback value for the minimum Python version if
+    /// `requires-python` is not set.
     ///
     /// See `uv help python` for details on Python discovery and supported request formats.
     #[arg(
@@ -4775,39 +6761,40 @@ pub struct TreeArgs {
 }
 
 #[derive(Args)]
-pub struct ExportArgs {
-    /// The format to which `uv.lock` should be exported.
+pub struct VendorArgs {
+    /// The directory to store the vendored distributions in.
     ///
-    /// Supports `requirements.txt`, `pylock.toml` (PEP 751) and CycloneDX v1.5 JSON output formats.
+    /// Defaults to `vendor` in the workspace root.
+    #[arg(long, value_hint = ValueHint::DirPath)]
+    pub vendor_dir: Option<PathBuf>,
+
+    /// Write the requirements file for the vendored distributions to the given file.
     ///
-    /// uv will infer the output format from the file extension of the output file, if
-    /// provided. Otherwise, defaults to `requirements.txt`.
-    #[arg(long, value_enum)]
-    pub format: Option<ExportFormat>,
+    /// Defaults to `requirements.txt` in the vendor directory. The vendored distributions are
+    /// referenced by paths relative to the workspace root.
+    #[arg(long, short, value_hint = ValueHint::FilePath)]
+    pub output_file: Option<PathBuf>,
 
-    /// Export the entire workspace.
+    /// Build wheels for any source distributions, rather than vendoring them as-is.
     ///
-    /// The dependencies for all workspace members will be included in the exported requirements
-    /// file.
+    /// Required to vendor dependencies that aren't available as an archive, such as Git or local
+    /// directory dependencies.
+    #[arg(long)]
+    pub build_wheels: bool,
+
+    /// Vendor the dependencies for the entire workspace.
     ///
     /// Any extras or groups specified via `--extra`, `--group`, or related options will be applied
     /// to all workspace members.
     #[arg(long, conflicts_with = "package")]
     pub all_packages: bool,
 
-    /// Export the dependencies for specific packages in the workspace.
+    /// Vendor the dependencies for specific packages in the workspace.
     ///
     /// If any workspace member does not exist, uv will exit with an error.
     #[arg(long, conflicts_with = "all_packages", value_hint = ValueHint::Other)]
     pub package: Vec<PackageName>,
 
-    /// Prune the given package from the dependency tree.
-    ///
-    /// Pruned packages will be excluded from the exported requirements file, as will any
-    /// dependencies that are no longer required after the pruned package is removed.
-    #[arg(long, conflicts_with = "all_packages", value_name = "PACKAGE")]
-    pub prune: Vec<PackageName>,
-
     /// Include optional dependencies from the specified extra name.
     ///
     /// May be provided more than once.
@@ -4884,134 +6871,145 @@ pub struct ExportArgs {
     #[arg(long, conflicts_with_all = ["only_group", "only_dev"])]
     pub all_groups: bool,
 
-    /// Exclude comment annotations indicating the source of each package.
-    #[arg(long, overrides_with("annotate"))]
-    pub no_annotate: bool,
-
-    #[arg(long, overrides_with("no_annotate"), hide = true)]
-    pub annotate: bool,
-
-    /// Exclude the comment header at the top of the generated output file.
+    /// Exclude the comment header at the top of the generated requirements file.
     #[arg(long, overrides_with("header"))]
     pub no_header: bool,
 
     #[arg(long, overrides_with("no_header"), hide = true)]
     pub header: bool,
 
-    /// Export any non-editable dependencies, including the project and any workspace members, as
-    /// editable.
-    #[arg(long, overrides_with = "no_editable", hide = true)]
-    pub editable: bool,
+    /// Assert that the `uv.lock` will remain unchanged [env: UV_LOCKED=]
+    ///
+    /// Requires that the lockfile is up-to-date. If the lockfile is missing or needs to be updated,
+    /// uv will exit with an error.
+    #[arg(long, conflicts_with_all = ["frozen", "upgrade"])]
+    pub locked: bool,
 
-    /// Export any editable dependencies, including the project and any workspace members, as
-    /// non-editable [env: UV_NO_EDITABLE=]
-    #[arg(long, overrides_with = "editable", value_parser = clap::builder::BoolishValueParser::new())]
-    pub no_editable: bool,
+    /// Do not update the `uv.lock` before vendoring [env: UV_FROZEN=]
+    ///
+    /// If a `uv.lock` does not exist, uv will exit with an error.
+    #[arg(long, conflicts_with_all = ["locked", "upgrade", "no_sources"])]
+    pub frozen: bool,
 
-    /// Include hashes for all dependencies.
-    #[arg(long, overrides_with("no_hashes"), hide = true)]
-    pub hashes: bool,
+    #[command(flatten)]
+    pub resolver: ResolverArgs,
 
-    /// Omit hashes in the generated output.
-    #[arg(long, overrides_with("hashes"))]
-    pub no_hashes: bool,
+    #[command(flatten)]
+    pub build_options: BuildOptionsArgs,
 
-    /// Write the exported requirements to the given file.
-    #[arg(long, short, value_hint = ValueHint::FilePath)]
-    pub output_file: Option<PathBuf>,
+    #[command(flatten)]
+    pub refresh: RefreshArgs,
+
+    /// The Python interpreter to vendor dependencies for.
+    ///
+    /// Distributions are selected from the lockfile for the platform and Python version of the
+    /// interpreter, which is also used to build any source distributions.
+    ///
+    /// See `uv help python` for details on Python discovery and supported request formats.
+    #[arg(
+        long,
+        short,
+        env = EnvVars::UV_PYTHON,
+        verbatim_doc_comment,
+        help_heading = "Python options",
+        value_parser = parse_maybe_string,
+        value_hint = ValueHint::Other,
+    )]
+    pub python: Option<Maybe<String>>,
+}
+
+#[derive(Args)]
+pub struct LicensesArgs {
+    /// Exclude the specified package(s) from the report.
+    #[arg(long, value_hint = ValueHint::Other)]
+    pub r#exclude: Vec<PackageName>,
+
+    /// Select the output format.
+    #[arg(long, value_enum, default_value_t = LicensesFormat::default())]
+    pub format: LicensesFormat,
+
+    /// Write a NOTICE file to the given path.
+    ///
+    /// The NOTICE file attributes each package by name, version, and license, followed by the
+    /// contents of the license files included in its distribution.
+    #[arg(long, value_hint = ValueHint::FilePath)]
+    pub notice: Option<PathBuf>,
+
+    /// Include optional dependencies from the specified extra name.
+    ///
+    /// May be provided more than once.
+    #[arg(long, value_delimiter = ',', conflicts_with = "all_extras", conflicts_with = "only_group", value_parser = extra_name_with_clap_error)]
+    pub extra: Option<Vec<ExtraName>>,
+
+    /// Include all optional dependencies.
+    #[arg(long, conflicts_with = "extra", conflicts_with = "only_group")]
+    pub all_extras: bool,
+
+    /// Exclude the specified optional dependencies, if `--all-extras` is supplied.
+    ///
+    /// May be provided multiple times.
+    #[arg(long)]
+    pub no_extra: Vec<ExtraName>,
+
+    #[arg(long, overrides_with("all_extras"), hide = true)]
+    pub no_all_extras: bool,
 
-    /// Do not emit the current project.
-    ///
-    /// By default, the current project is included in the exported requirements file with all of
-    /// its dependencies. The `--no-emit-project` option allows the project to be excluded, but all
-    /// of its dependencies to remain included.
+    /// Include the development dependency group [env: UV_DEV=]
     ///
-    /// The inverse `--only-emit-project` can be used to emit _only_ the project itself, excluding
-    /// all dependencies.
-    #[arg(
-        long,
-        alias = "no-install-project",
-        conflicts_with = "only_emit_project"
-    )]
-    pub no_emit_project: bool,
+    /// This option is an alias for `--group dev`.
+    #[arg(long, overrides_with("no_dev"), hide = true, value_parser = clap::builder::BoolishValueParser::new())]
+    pub dev: bool,
 
-    /// Only emit the current project.
-    #[arg(
-        long,
-        alias = "only-install-project",
-        conflicts_with = "no_emit_project",
-        hide = true
-    )]
-    pub only_emit_project: bool,
+    /// Disable the development dependency group [env: UV_NO_DEV=]
+    ///
+    /// This option is an alias of `--no-group dev`.
+    /// See `--no-default-groups` to disable all default groups instead.
+    #[arg(long, overrides_with("dev"), value_parser = clap::builder::BoolishValueParser::new())]
+    pub no_dev: bool,
 
-    /// Do not emit any workspace members, including the root project.
+    /// Only include the development dependency group.
     ///
-    /// By default, all workspace members and their dependencies are included in the exported
-    /// requirements file, with all of their dependencies. The `--no-emit-workspace` option allows
-    /// exclusion of all the workspace members while retaining their dependencies.
+    /// The project and its dependencies will be omitted.
     ///
-    /// The inverse `--only-emit-workspace` can be used to emit _only_ workspace members, excluding
-    /// all other dependencies.
-    #[arg(
-        long,
-        alias = "no-install-workspace",
-        conflicts_with = "only_emit_workspace"
-    )]
-    pub no_emit_workspace: bool,
+    /// This option is an alias for `--only-group dev`. Implies `--no-default-groups`.
+    #[arg(long, conflicts_with_all = ["group", "all_groups", "no_dev"])]
+    pub only_dev: bool,
 
-    /// Only emit workspace members, including the root project.
-    #[arg(
-        long,
-        alias = "only-install-workspace",
-        conflicts_with = "no_emit_workspace",
-        hide = true
-    )]
-    pub only_emit_workspace: bool,
+    /// Include dependencies from the specified dependency group.
+    ///
+    /// May be provided multiple times.
+    #[arg(long, conflicts_with_all = ["only_group", "only_dev"])]
+    pub group: Vec<GroupName>,
 
-    /// Do not include local path dependencies in the exported requirements.
+    /// Disable the specified dependency group.
     ///
-    /// Omits the current project, workspace members, and any other local (path or editable)
-    /// packages from the export. Only remote/indexed dependencies are written. Useful for Docker
-    /// and CI flows that want to export and cache third-party dependencies first.
+    /// This option always takes precedence over default groups,
+    /// `--all-groups`, and `--group`.
     ///
-    /// The inverse `--only-emit-local` can be used to emit _only_ local packages, excluding all
-    /// remote dependencies.
-    #[arg(long, alias = "no-install-local", conflicts_with = "only_emit_local")]
-    pub no_emit_local: bool,
+    /// May be provided multiple times.
+    #[arg(long, env = EnvVars::UV_NO_GROUP, value_delimiter = ' ')]
+    pub no_group: Vec<GroupName>,
 
-    /// Only include local path dependencies in the exported requirements.
-    #[arg(
-        long,
-        alias = "only-install-local",
-        conflicts_with = "no_emit_local",
-        hide = true
-    )]
-    pub only_emit_local: bool,
+    /// Ignore the default dependency groups.
+    ///
+    /// uv includes the groups defined in `tool.uv.default-groups` by default.
+    /// This disables that option, however, specific groups can still be included with `--group`.
+    #[arg(long, env = EnvVars::UV_NO_DEFAULT_GROUPS, value_parser = clap::builder::BoolishValueParser::new())]
+    pub no_default_groups: bool,
 
-    /// Do not emit the given package(s).
+    /// Only include dependencies from the specified dependency group.
     ///
-    /// By default, all project's dependencies are included in the exported requirements
-    /// file. The `--no-emit-package` option allows exclusion of specific packages.
+    /// The project and its dependencies will be omitted.
     ///
-    /// The inverse `--only-emit-package` can be used to emit _only_ the specified packages,
-    /// excluding all others.
-    #[arg(
-        long,
-        alias = "no-install-package",
-        conflicts_with = "only_emit_package",
-        value_hint = ValueHint::Other,
-    )]
-    pub no_emit_package: Vec<PackageName>,
+    /// May be provided multiple times. Implies `--no-default-groups`.
+    #[arg(long, conflicts_with_all = ["group", "dev", "all_groups"])]
+    pub only_group: Vec<GroupName>,
 
-    /// Only emit the given package(s).
-    #[arg(
-        long,
-        alias = "only-install-package",
-        conflicts_with = "no_emit_package",
-        hide = true,
-        value_hint = ValueHint::Other,
-    )]
-    pub only_emit_package: Vec<PackageName>,
+    /// Include dependencies from all dependency groups.
+    ///
+    /// `--no-group` can be used to exclude specific groups.
+    #[arg(long, conflicts_with_all = ["only_group", "only_dev"])]
+    pub all_groups: bool,
 
     /// Assert that the `uv.lock` will remain unchanged [env: UV_LOCKED=]
     ///
@@ -5020,7 +7018,7 @@ pub struct ExportArgs {
     #[arg(long, conflicts_with_all = ["frozen", "upgrade"])]
     pub locked: bool,
 
-    /// Do not update the `uv.lock` before exporting [env: UV_FROZEN=]
+    /// Do not update the `uv.lock` before reporting licenses [env: UV_FROZEN=]
     ///
     /// If a `uv.lock` does not exist, uv will exit with an error.
     #[arg(long, conflicts_with_all = ["locked", "upgrade", "no_sources"])]
@@ -5030,30 +7028,15 @@ pub struct ExportArgs {
     pub resolver: ResolverArgs,
 
     #[command(flatten)]
-    pub build: BuildOptionsArgs,
+    pub build_options: BuildOptionsArgs,
 
     #[command(flatten)]
     pub refresh: RefreshArgs,
 
-    /// Export the dependencies for the specified PEP 723 Python script, rather than the current
-    /// project.
-    ///
-    /// If provided, uv will resolve the dependencies based on its inline metadata table, in
-    /// adherence with PEP 723.
-    #[arg(
-        long,
-        conflicts_with_all = ["all_packages", "package", "no_emit_project", "no_emit_workspace"],
-        value_hint = ValueHint::FilePath,
-    )]
-    pub script: Option<PathBuf>,
-
-    /// The Python interpreter to use during resolution.
-    ///
-    /// A Python interpreter is required for building source distributions to determine package
-    /// metadata when there are not wheels.
+    /// The Python interpreter to report licenses for.
     ///
-    /// The interpreter is also used as the fallback value for the minimum Python version if
-    /// `requires-python` is not set.
+    /// Distributions are selected from the lockfile for the platform and Python version of the
+    /// interpreter, which is also used to build any source distributions.
     ///
     /// See `uv help python` for details on Python discovery and supported request formats.
     #[arg(
@@ -5893,6 +7876,9 @@ pub struct ToolUpgradeArgs {
 
     /// Allow upgrades for a specific package, ignoring pinned versions in any existing output
     /// file. Implies `--refresh-package`.
+    ///
+    /// A version specifier can be included to bound the upgrade (e.g., `--upgrade-package
+    /// "django<5"`). The specifier is only applied as a constraint for this invocation.
     #[arg(hide = true, long, short = 'P', help_heading = "Resolver options")]
     pub upgrade_package: Vec<Requirement<VerbatimParsedUrl>>,
 
@@ -5967,13 +7953,15 @@ pub struct ToolUpgradeArgs {
     /// By default, uv will accept pre-releases for packages that _only_ publish pre-releases, along
     /// with first-party requirements that contain an explicit pre-release marker in the declared
     /// specifiers (`if-necessary-or-explicit`).
+    ///
+    /// To allow pre-releases for specific packages only, provide them after `allow:` as a
+    /// comma-separated list (e.g., `allow:foo,bar`).
     #[arg(
         long,
-        value_enum,
         env = EnvVars::UV_PRERELEASE,
         help_heading = "Resolver options"
     )]
-    pub prerelease: Option<PrereleaseMode>,
+    pub prerelease: Option<PrereleaseSpec>,
 
     #[arg(long, hide = true)]
     pub pre: bool,
@@ -6013,6 +8001,13 @@ pub struct ToolUpgradeArgs {
     )]
     pub config_setting_package: Option<Vec<ConfigSettingPackageEntry>>,
 
+    /// Environment variables to set when building a specific package, specified as
+    /// `PACKAGE:VAR=VALUE` pairs.
+    ///
+    /// Takes precedence over `extra-build-variables` in the configuration file.
+    #[arg(long, alias = "build-env", help_heading = "Build options")]
+    pub extra_build_variable: Option<Vec<ExtraBuildVariableEntry>>,
+
     /// Disable isolation when building source distributions.
     ///
     /// Assumes that build dependencies specified by PEP 518 are already installed.
@@ -6069,6 +8064,22 @@ pub struct ToolUpgradeArgs {
     #[arg(long, help_heading = "Resolver options")]
     pub exclude_newer_package: Option<Vec<ExcludeNewerPackageEntry>>,
 
+    /// Allow a specific yanked version of a package to be selected.
+    ///
+    /// By default, uv will only select a yanked version if it's pinned exactly (e.g., `flask==2.0.0`)
+    /// or locked, and will warn when doing so. When hash-checking is required (e.g., with
+    /// `--require-hashes`), selecting a yanked version is an error unless it's allowed here.
+    ///
+    /// Accepts package-version pairs in the format `PACKAGE==VERSION`.
+    ///
+    /// Can be provided multiple times for different packages.
+    #[arg(
+        long,
+        help_heading = "Resolver options",
+        value_name = "PACKAGE==VERSION"
+    )]
+    pub allow_yanked: Option<Vec<AllowYankedEntry>>,
+
     /// The method to use when installing packages from the global cache.
     ///
     /// Defaults to `clone` (also known as Copy-on-Write) on macOS and Linux, and `hardlink` on
@@ -7074,6 +9085,13 @@ pub struct InstallerArgs {
     )]
     pub config_settings_package: Option<Vec<ConfigSettingPackageEntry>>,
 
+    /// Environment variables to set when building a specific package, specified as
+    /// `PACKAGE:VAR=VALUE` pairs.
+    ///
+    /// Takes precedence over `extra-build-variables` in the configuration file.
+    #[arg(long, alias = "build-env", help_heading = "Build options")]
+    pub extra_build_variable: Option<Vec<ExtraBuildVariableEntry>>,
+
     /// Disable isolation when building source distributions.
     ///
     /// Assumes that build dependencies specified by PEP 518 are already installed.
@@ -7124,6 +9142,22 @@ pub struct InstallerArgs {
     #[arg(long, help_heading = "Resolver options")]
     pub exclude_newer_package: Option<Vec<ExcludeNewerPackageEntry>>,
 
+    /// Allow a specific yanked version of a package to be selected.
+    ///
+    /// By default, uv will only select a yanked version if it's pinned exactly (e.g., `flask==2.0.0`)
+    /// or locked, and will warn when doing so. When hash-checking is required (e.g., with
+    /// `--require-hashes`), selecting a yanked version is an error unless it's allowed here.
+    ///
+    /// Accepts package-version pairs in the format `PACKAGE==VERSION`.
+    ///
+    /// Can be provided multiple times for different packages.
+    #[arg(
+        long,
+        help_heading = "Resolver options",
+        value_name = "PACKAGE==VERSION"
+    )]
+    pub allow_yanked: Option<Vec<AllowYankedEntry>>,
+
     /// The method to use when installing packages from the global cache.
     ///
     /// Defaults to `clone` (also known as Copy-on-Write) on macOS and Linux, and `hardlink` on
@@ -7212,6 +9246,9 @@ pub struct ResolverArgs {
 
     /// Allow upgrades for a specific package, ignoring pinned versions in any existing output
     /// file. Implies `--refresh-package`.
+    ///
+    /// A version specifier can be included to bound the upgrade (e.g., `--upgrade-package
+    /// "django<5"`). The specifier is only applied as a constraint for this invocation.
     #[arg(long, short = 'P', help_heading = "Resolver options")]
     pub upgrade_package: Vec<Requirement<VerbatimParsedUrl>>,
 
@@ -7260,13 +9297,15 @@ pub struct ResolverArgs {
     /// By default, uv will accept pre-releases for packages that _only_ publish pre-releases, along
     /// with first-party requirements that contain an explicit pre-release marker in the declared
     /// specifiers (`if-necessary-or-explicit`).
+    ///
+    /// To allow pre-releases for specific packages only, provide them after `allow:` as a
+    /// comma-separated list (e.g., `allow:foo,bar`).
     #[arg(
         long,
-        value_enum,
         env = EnvVars::UV_PRERELEASE,
         help_heading = "Resolver options"
     )]
-    pub prerelease: Option<PrereleaseMode>,
+    pub prerelease: Option<PrereleaseSpec>,
 
     #[arg(long, hide = true, help_heading = "Resolver options")]
     pub pre: bool,
@@ -7306,6 +9345,13 @@ pub struct ResolverArgs {
     )]
     pub config_settings_package: Option<Vec<ConfigSettingPackageEntry>>,
 
+    /// Environment variables to set when building a specific package, specified as
+    /// `PACKAGE:VAR=VALUE` pairs.
+    ///
+    /// Takes precedence over `extra-build-variables` in the configuration file.
+    #[arg(long, alias = "build-env", help_heading = "Build options")]
+    pub extra_build_variable: Option<Vec<ExtraBuildVariableEntry>>,
+
     /// Disable isolation when building source distributions.
     ///
     /// Assumes that build dependencies specified by PEP 518 are already installed.
@@ -7362,6 +9408,22 @@ pub struct ResolverArgs {
     #[arg(long, help_heading = "Resolver options")]
     pub exclude_newer_package: Option<Vec<ExcludeNewerPackageEntry>>,
 
+    /// Allow a specific yanked version of a package to be selected.
+    ///
+    /// By default, uv will only select a yanked version if it's pinned exactly (e.g., `flask==2.0.0`)
+    /// or locked, and will warn when doing so. When hash-checking is required (e.g., with
+    /// `--require-hashes`), selecting a yanked version is an error unless it's allowed here.
+    ///
+    /// Accepts package-version pairs in the format `PACKAGE==VERSION`.
+    ///
+    /// Can be provided multiple times for different packages.
+    #[arg(
+        long,
+        help_heading = "Resolver options",
+        value_name = "PACKAGE==VERSION"
+    )]
+    pub allow_yanked: Option<Vec<AllowYankedEntry>>,
+
     /// The method to use when installing packages from the global cache.
     ///
     /// This option is only used when building source distributions.
@@ -7423,6 +9485,9 @@ pub struct ResolverInstallerArgs {
 
     /// Allow upgrades for a specific package, ignoring pinned versions in any existing output file.
     /// Implies `--refresh-package`.
+    ///
+    /// A version specifier can be included to bound the upgrade (e.g., `--upgrade-package
+    /// "django<5"`). The specifier is only applied as a constraint for this invocation.
     #[arg(long, short = 'P', help_heading = "Resolver options", value_hint = ValueHint::Other)]
     pub upgrade_package: Vec<Requirement<VerbatimParsedUrl>>,
 
@@ -7494,13 +9559,15 @@ pub struct ResolverInstallerArgs {
     /// By default, uv will accept pre-releases for packages that _only_ publish pre-releases, along
     /// with first-party requirements that contain an explicit pre-release marker in the declared
     /// specifiers (`if-necessary-or-explicit`).
+    ///
+    /// To allow pre-releases for specific packages only, provide them after `allow:` as a
+    /// comma-separated list (e.g., `allow:foo,bar`).
     #[arg(
         long,
-        value_enum,
         env = EnvVars::UV_PRERELEASE,
         help_heading = "Resolver options"
     )]
-    pub prerelease: Option<PrereleaseMode>,
+    pub prerelease: Option<PrereleaseSpec>,
 
     #[arg(long, hide = true)]
     pub pre: bool,
@@ -7542,6 +9609,13 @@ pub struct ResolverInstallerArgs {
     )]
     pub config_settings_package: Option<Vec<ConfigSettingPackageEntry>>,
 
+    /// Environment variables to set when building a specific package, specified as
+    /// `PACKAGE:VAR=VALUE` pairs.
+    ///
+    /// Takes precedence over `extra-build-variables` in the configuration file.
+    #[arg(long, alias = "build-env", help_heading = "Build options")]
+    pub extra_build_variable: Option<Vec<ExtraBuildVariableEntry>>,
+
     /// Disable isolation when building source distributions.
     ///
     /// Assumes that build dependencies specified by PEP 518 are already installed.
@@ -7603,6 +9677,22 @@ pub struct ResolverInstallerArgs {
     #[arg(long, help_heading = "Resolver options", value_hint = ValueHint::Other)]
     pub exclude_newer_package: Option<Vec<ExcludeNewerPackageEntry>>,
 
+    /// Allow a specific yanked version of a package to be selected.
+    ///
+    /// By default, uv will only select a yanked version if it's pinned exactly (e.g., `flask==2.0.0`)
+    /// or locked, and will warn when doing so. When hash-checking is required (e.g., with
+    /// `--require-hashes`), selecting a yanked version is an error unless it's allowed here.
+    ///
+    /// Accepts package-version pairs in the format `PACKAGE==VERSION`.
+    ///
+    /// Can be provided multiple times for different packages.
+    #[arg(
+        long,
+        help_heading = "Resolver options",
+        value_name = "PACKAGE==VERSION"
+    )]
+    pub allow_yanked: Option<Vec<AllowYankedEntry>>,
+
     /// The method to use when installing packages from the global cache.
     ///
     /// Defaults to `clone` (also known as Copy-on-Write) on macOS and Linux, and `hardlink` on
@@ -7776,6 +9866,9 @@ pub struct PublishArgs {
     /// uv publish --index pypi
     /// uv publish --publish-url https://upload.pypi.org/legacy/ --check-url https://pypi.org/simple
     /// ```
+    ///
+    /// If no index with the given name is configured, the repositories in `~/.pypirc` are used,
+    /// for compatibility with `twine`.
     #[arg(
         long,
         verbatim_doc_comment,
@@ -7845,7 +9938,14 @@ pub struct PublishArgs {
     /// and index upload.
     ///
     /// Defaults to PyPI's publish URL (<https://upload.pypi.org/legacy/>).
-    #[arg(long, env = EnvVars::UV_PUBLISH_URL, hide_env_values = true)]
+    ///
+    /// For compatibility with `twine`, `--repository-url` is accepted as an alias.
+    #[arg(
+        long,
+        alias = "repository-url",
+        env = EnvVars::UV_PUBLISH_URL,
+        hide_env_values = true
+    )]
     pub publish_url: Option<DisplaySafeUrl>,
 
     /// Check an index URL for existing files to skip duplicate uploads.
@@ -7919,6 +10019,132 @@ pub enum WorkspaceCommand {
 #[derive(Args, Debug)]
 pub struct MetadataArgs;
 
+#[derive(Args)]
+pub struct WheelNamespace {
+    #[command(subcommand)]
+    pub command: WheelCommand,
+}
+
+#[derive(Subcommand)]
+pub enum WheelCommand {
+    /// Rewrite the tags of a wheel.
+    ///
+    /// Updates the wheel filename and the tags in its `WHEEL` file, and regenerates the `RECORD`
+    /// file from the archive contents, e.g., after patching a wheel and auditing its shared library
+    /// dependencies.
+    ///
+    /// Tags that are not provided are retained from the original wheel. By default, each new
+    /// platform tag must target the same operating system and architecture as the original wheel,
+    /// e.g., `linux_x86_64` may be retagged as `manylinux_2_17_x86_64`, but not as
+    /// `manylinux_2_17_aarch64`.
+    Retag(WheelRetagArgs),
+}
+
+#[derive(Args, Debug)]
+pub struct WheelRetagArgs {
+    /// The path to the wheel to retag.
+    #[arg(value_hint = ValueHint::FilePath)]
+    pub wheel: PathBuf,
+
+    /// The Python tags for the wheel, e.g., `cp312`.
+    ///
+    /// Accepts multiple tags, separated by commas.
+    #[arg(long, value_delimiter = ',')]
+    pub python_tag: Option<Vec<LanguageTag>>,
+
+    /// The ABI tags for the wheel, e.g., `abi3`.
+    ///
+    /// Accepts multiple tags, separated by commas.
+    #[arg(long, value_delimiter = ',')]
+    pub abi_tag: Option<Vec<AbiTag>>,
+
+    /// The platform tags for the wheel, e.g., `manylinux_2_17_x86_64`.
+    ///
+    /// Accepts multiple tags, separated by commas.
+    #[arg(long, value_delimiter = ',')]
+    pub platform_tag: Option<Vec<PlatformTag>>,
+
+    /// The output directory to which the retagged wheel should be written.
+    ///
+    /// Defaults to the directory containing the original wheel.
+    #[arg(long, short, value_hint = ValueHint::DirPath)]
+    pub out_dir: Option<PathBuf>,
+
+    /// Remove the original wheel after retagging.
+    #[arg(long)]
+    pub remove: bool,
+
+    /// Skip the check that the new platform tags target the same operating system and
+    /// architecture as the original wheel.
+    #[arg(long)]
+    pub force: bool,
+}
+
+#[derive(Args)]
+pub struct IndexNamespace {
+    #[command(subcommand)]
+    pub command: IndexCommand,
+}
+
+#[derive(Subcommand)]
+pub enum IndexCommand {
+    /// Generate a Simple API index for a directory of distributions.
+    ///
+    /// Scans the directory for wheels and source distributions, and writes a static
+    /// [PEP 503](https://peps.python.org/pep-0503/) index that links to them, such that the
+    /// directory can be used with `--index-url`, either directly as a local path or when served by
+    /// any static file server.
+    Build(IndexBuildArgs),
+    /// Check the health of the configured indexes.
+    ///
+    /// For each index, requests the project page of a package and reports whether the request
+    /// succeeded (including whether the index accepted the configured credentials), the latency
+    /// of the request, the content type of the response, and which optional features the index
+    /// supports: the JSON-based Simple API ([PEP 691](https://peps.python.org/pep-0691/)),
+    /// metadata files ([PEP 658](https://peps.python.org/pep-0658/)), and range requests.
+    Ping(Box<IndexPingArgs>),
+}
+
+#[derive(Args, Debug)]
+pub struct IndexBuildArgs {
+    /// The directory containing the wheels and source distributions.
+    #[arg(value_name = "DIRECTORY", value_hint = ValueHint::DirPath)]
+    pub path: PathBuf,
+
+    /// The output directory to which the index should be written.
+    ///
+    /// Defaults to a `simple` directory within the distribution directory.
+    #[arg(long, short, value_hint = ValueHint::DirPath)]
+    pub out_dir: Option<PathBuf>,
+}
+
+#[derive(Args)]
+pub struct IndexPingArgs {
+    /// The package whose project page should be requested from each index.
+    ///
+    /// The features supported by an index are detected from the files listed for the package, so
+    /// the package should exist on every index.
+    #[arg(long, default_value = "pip", value_hint = ValueHint::Other)]
+    pub package: PackageName,
+
+    #[command(flatten)]
+    pub index_args: IndexArgs,
+
+    /// Attempt to use `keyring` for authentication for index URLs.
+    ///
+    /// At present, only `--keyring-provider subprocess` is supported, which configures uv to use
+    /// the `keyring` CLI to handle authentication.
+    ///
+    /// Defaults to `disabled`.
+    #[arg(
+        long,
+        value_enum,
+        env = EnvVars::UV_KEYRING_PROVIDER,
+        help_heading = "Index options"
+    )]
+    pub keyring_provider: Option<KeyringProviderType>,
+}
+
 #[derive(Args, Debug)]
 pub struct WorkspaceDirArgs {
     /// Display the path to a specific package in the workspace.
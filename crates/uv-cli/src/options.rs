@@ -4,14 +4,16 @@ use anstream::eprintln;
 
 use uv_cache::Refresh;
 use uv_configuration::{BuildIsolation, Reinstall, Upgrade};
-use uv_distribution_types::{ConfigSettings, PackageConfigSettings, Requirement};
+use uv_distribution_types::{
+    ConfigSettings, ExtraBuildVariables, PackageConfigSettings, Requirement,
+};
 use uv_resolver::{ExcludeNewer, ExcludeNewerPackage, PrereleaseMode};
 use uv_settings::{Combine, EnvFlag, PipOptions, ResolverInstallerOptions, ResolverOptions};
 use uv_warnings::owo_colors::OwoColorize;
 
 use crate::{
-    BuildOptionsArgs, FetchArgs, IndexArgs, InstallerArgs, Maybe, RefreshArgs, ResolverArgs,
-    ResolverInstallerArgs,
+    BuildOptionsArgs, FetchArgs, IndexArgs, InstallerArgs, Maybe, PrereleaseSpec, RefreshArgs,
+    ResolverArgs, ResolverInstallerArgs,
 };
 
 /// Given a boolean flag pair (like `--upgrade` and `--no-upgrade`), resolve the value of the flag.
@@ -210,6 +212,7 @@ impl From<ResolverArgs> for PipOptions {
             fork_strategy,
             config_setting,
             config_settings_package,
+            extra_build_variable,
             no_build_isolation,
             no_build_isolation_package,
             build_isolation,
@@ -218,6 +221,7 @@ impl From<ResolverArgs> for PipOptions {
             no_sources,
             no_sources_package,
             exclude_newer_package,
+            allow_yanked,
         } = args;
 
         Self {
@@ -230,8 +234,9 @@ impl From<ResolverArgs> for PipOptions {
             prerelease: if pre {
                 Some(PrereleaseMode::Allow)
             } else {
-                prerelease
+                prerelease.as_ref().and_then(PrereleaseSpec::mode)
             },
+            prerelease_packages: prerelease.and_then(PrereleaseSpec::into_packages),
             config_settings: config_setting
                 .map(|config_settings| config_settings.into_iter().collect::<ConfigSettings>()),
             config_settings_package: config_settings_package.map(|config_settings| {
@@ -239,10 +244,13 @@ impl From<ResolverArgs> for PipOptions {
                     .into_iter()
                     .collect::<PackageConfigSettings>()
             }),
+            extra_build_variables: extra_build_variable
+                .map(|variables| variables.into_iter().collect::<ExtraBuildVariables>()),
             no_build_isolation: flag(no_build_isolation, build_isolation, "build-isolation"),
             no_build_isolation_package: Some(no_build_isolation_package),
             exclude_newer,
             exclude_newer_package: exclude_newer_package.map(ExcludeNewerPackage::from_iter),
+            allow_yanked,
             link_mode,
             no_sources: if no_sources { Some(true) } else { None },
             no_sources_package: Some(no_sources_package),
@@ -262,6 +270,7 @@ impl From<InstallerArgs> for PipOptions {
             keyring_provider,
             config_setting,
             config_settings_package,
+            extra_build_variable,
             no_build_isolation,
             build_isolation,
             exclude_newer,
@@ -271,6 +280,7 @@ impl From<InstallerArgs> for PipOptions {
             no_sources,
             no_sources_package,
             exclude_newer_package,
+            allow_yanked,
         } = args;
 
         Self {
@@ -285,9 +295,12 @@ impl From<InstallerArgs> for PipOptions {
                     .into_iter()
                     .collect::<PackageConfigSettings>()
             }),
+            extra_build_variables: extra_build_variable
+                .map(|variables| variables.into_iter().collect::<ExtraBuildVariables>()),
             no_build_isolation: flag(no_build_isolation, build_isolation, "build-isolation"),
             exclude_newer,
             exclude_newer_package: exclude_newer_package.map(ExcludeNewerPackage::from_iter),
+            allow_yanked,
             link_mode,
             compile_bytecode: flag(compile_bytecode, no_compile_bytecode, "compile-bytecode"),
             no_sources: if no_sources { Some(true) } else { None },
@@ -315,6 +328,7 @@ impl From<ResolverInstallerArgs> for PipOptions {
             fork_strategy,
             config_setting,
             config_settings_package,
+            extra_build_variable,
             no_build_isolation,
             no_build_isolation_package,
             build_isolation,
@@ -325,6 +339,7 @@ impl From<ResolverInstallerArgs> for PipOptions {
             no_sources,
             no_sources_package,
             exclude_newer_package,
+            allow_yanked,
         } = args;
 
         Self {
@@ -338,8 +353,9 @@ impl From<ResolverInstallerArgs> for PipOptions {
             prerelease: if pre {
                 Some(PrereleaseMode::Allow)
             } else {
-                prerelease
+                prerelease.as_ref().and_then(PrereleaseSpec::mode)
             },
+            prerelease_packages: prerelease.and_then(PrereleaseSpec::into_packages),
             fork_strategy,
             config_settings: config_setting
                 .map(|config_settings| config_settings.into_iter().collect::<ConfigSettings>()),
@@ -348,10 +364,13 @@ impl From<ResolverInstallerArgs> for PipOptions {
                     .into_iter()
                     .collect::<PackageConfigSettings>()
             }),
+            extra_build_variables: extra_build_variable
+                .map(|variables| variables.into_iter().collect::<ExtraBuildVariables>()),
             no_build_isolation: flag(no_build_isolation, build_isolation, "build-isolation"),
             no_build_isolation_package: Some(no_build_isolation_package),
             exclude_newer,
             exclude_newer_package: exclude_newer_package.map(ExcludeNewerPackage::from_iter),
+            allow_yanked,
             link_mode,
             compile_bytecode: flag(compile_bytecode, no_compile_bytecode, "compile-bytecode"),
             no_sources: if no_sources { Some(true) } else { None },
@@ -438,6 +457,7 @@ pub fn resolver_options(
         fork_strategy,
         config_setting,
         config_settings_package,
+        extra_build_variable,
         no_build_isolation,
         no_build_isolation_package,
         build_isolation,
@@ -446,6 +466,7 @@ pub fn resolver_options(
         no_sources,
         no_sources_package,
         exclude_newer_package,
+        allow_yanked,
     } = resolver_args;
 
     let BuildOptionsArgs {
@@ -497,8 +518,9 @@ pub fn resolver_options(
         prerelease: if pre {
             Some(PrereleaseMode::Allow)
         } else {
-            prerelease
+            prerelease.as_ref().and_then(PrereleaseSpec::mode)
         },
+        prerelease_packages: prerelease.and_then(PrereleaseSpec::into_packages),
         fork_strategy,
         dependency_metadata: None,
         config_settings: config_setting
@@ -513,11 +535,13 @@ pub fn resolver_options(
             no_build_isolation_package,
         ),
         extra_build_dependencies: None,
-        extra_build_variables: None,
+        extra_build_variables: extra_build_variable
+            .map(|variables| variables.into_iter().collect::<ExtraBuildVariables>()),
         exclude_newer: ExcludeNewer::from_args(
             exclude_newer,
             exclude_newer_package.unwrap_or_default(),
         ),
+        allow_yanked,
         link_mode,
         torch_backend: None,
         no_build: flag(no_build, build, "build"),
@@ -526,6 +550,9 @@ pub fn resolver_options(
         no_binary_package: Some(no_binary_package),
         no_sources: if no_sources { Some(true) } else { None },
         no_sources_package: Some(no_sources_package),
+        excluded_packages: None,
+        resolution_priority: None,
+        max_backtracks: None,
     }
 }
 
@@ -550,11 +577,13 @@ pub fn resolver_installer_options(
         fork_strategy,
         config_setting,
         config_settings_package,
+        extra_build_variable,
         no_build_isolation,
         no_build_isolation_package,
         build_isolation,
         exclude_newer,
         exclude_newer_package,
+        allow_yanked,
         link_mode,
         compile_bytecode,
         no_compile_bytecode,
@@ -617,8 +646,9 @@ pub fn resolver_installer_options(
         prerelease: if pre {
             Some(PrereleaseMode::Allow)
         } else {
-            prerelease
+            prerelease.as_ref().and_then(PrereleaseSpec::mode)
         },
+        prerelease_packages: prerelease.and_then(PrereleaseSpec::into_packages),
         fork_strategy,
         dependency_metadata: None,
         config_settings: config_setting
@@ -633,9 +663,11 @@ pub fn resolver_installer_options(
             no_build_isolation_package,
         ),
         extra_build_dependencies: None,
-        extra_build_variables: None,
+        extra_build_variables: extra_build_variable
+            .map(|variables| variables.into_iter().collect::<ExtraBuildVariables>()),
         exclude_newer,
         exclude_newer_package: exclude_newer_package.map(ExcludeNewerPackage::from_iter),
+        allow_yanked,
         link_mode,
         compile_bytecode: flag(compile_bytecode, no_compile_bytecode, "compile-bytecode"),
         no_build: flag(no_build, build, "build"),
@@ -656,6 +688,10 @@ pub fn resolver_installer_options(
         } else {
             Some(no_sources_package)
         },
+        excluded_packages: None,
+        resolution_priority: None,
+        max_backtracks: None,
+        protected_packages: None,
         torch_backend: None,
     }
 }
@@ -1,8 +1,8 @@
 use std::error::Error;
 use std::fmt::Debug;
 use std::fmt::Write;
-use std::num::ParseIntError;
-use std::path::Path;
+use std::num::{NonZeroU64, NonZeroUsize, ParseIntError};
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::time::{Duration, SystemTime, SystemTimeError};
 use std::{env, io, iter};
@@ -16,7 +16,10 @@ use http::{
     },
 };
 use itertools::Itertools;
-use reqwest::{Client, ClientBuilder, IntoUrl, NoProxy, Proxy, Request, Response, multipart};
+use reqwest::{
+    Certificate, Client, ClientBuilder, Identity, IntoUrl, NoProxy, Proxy, Request, Response,
+    multipart,
+};
 use reqwest_middleware::{ClientWithMiddleware, Middleware};
 use reqwest_retry::policies::ExponentialBackoff;
 use reqwest_retry::{
@@ -30,7 +33,10 @@ use tracing::{debug, trace, warn};
 use url::ParseError;
 use url::Url;
 
-use uv_auth::{AuthMiddleware, Credentials, CredentialsCache, Indexes, PyxTokenStore};
+use uv_attestation::AttestationPolicy;
+use uv_auth::{
+    AuthMiddleware, CredentialHelper, Credentials, CredentialsCache, Indexes, PyxTokenStore, Realm,
+};
 use uv_configuration::ProxyUrlKind;
 use uv_configuration::{KeyringProviderType, ProxyUrl, TrustedHost};
 use uv_fs::Simplified;
@@ -43,9 +49,12 @@ use uv_static::EnvVars;
 use uv_version::version;
 use uv_warnings::warn_user_once;
 
+use crate::index_health::IndexHealth;
+use crate::limiter::{LimiterMiddleware, RequestLimiter};
 use crate::linehaul::LineHaul;
 use crate::middleware::OfflineMiddleware;
-use crate::tls::read_identity;
+use crate::mirrors::{IndexMirrors, MirrorMiddleware};
+use crate::tls::{CertificateError, read_ca_bundle, read_identity};
 use crate::{Connectivity, WrappedReqwestError};
 
 pub const DEFAULT_RETRIES: u32 = 3;
@@ -63,6 +72,15 @@ pub const DEFAULT_READ_TIMEOUT: Duration = Duration::from_secs(30);
 /// This value is set lower to fail relatively quickly when the index is unreachable or down.
 pub const DEFAULT_CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
 
+/// The maximum time a download may go without receiving any data before it's aborted and retried.
+///
+/// This value is set lower than [`DEFAULT_READ_TIMEOUT`], since an interrupted download can
+/// usually be resumed from the bytes received so far.
+pub const DEFAULT_STALL_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// The maximum delay between two attempts of a retried request.
+pub const DEFAULT_RETRY_MAX_DELAY: Duration = Duration::from_secs(30);
+
 /// Total duration an upload may take.
 ///
 /// reqwest does not support something like a read timeout for uploads, so we have to set a (large)
@@ -86,11 +104,13 @@ pub enum AuthIntegration {
 #[derive(Debug, Clone)]
 pub struct BaseClientBuilder<'a> {
     keyring: KeyringProviderType,
+    credential_helper: Option<String>,
     preview: Preview,
     allow_insecure_host: Vec<TrustedHost>,
     native_tls: bool,
     built_in_root_certs: bool,
     retries: u32,
+    retry_max_delay: Duration,
     pub connectivity: Connectivity,
     markers: Option<&'a MarkerEnvironment>,
     platform: Option<&'a Platform>,
@@ -100,10 +120,12 @@ pub struct BaseClientBuilder<'a> {
     indexes: Indexes,
     read_timeout: Duration,
     connect_timeout: Duration,
+    stall_timeout: Duration,
     extra_middleware: Option<ExtraMiddleware>,
     proxies: Vec<Proxy>,
     http_proxy: Option<ProxyUrl>,
     https_proxy: Option<ProxyUrl>,
+    all_proxy: Option<ProxyUrl>,
     no_proxy: Option<Vec<String>>,
     redirect_policy: RedirectPolicy,
     /// Whether credentials should be propagated during cross-origin redirects.
@@ -118,6 +140,35 @@ pub struct BaseClientBuilder<'a> {
     client_name: Option<&'static str>,
     /// Whether to disable retry delays (for testing).
     no_retry_delay: bool,
+    /// Whether to treat unreachable non-default indexes as empty, rather than failing.
+    allow_index_failure: bool,
+    /// How long to consider cached Simple API responses fresh, overriding the index's
+    /// `Cache-Control` header.
+    index_cache_ttl: Option<Duration>,
+    /// Whether to fall back to stale cached Simple API responses when an index is unreachable.
+    index_stale_if_error: bool,
+    /// The file to which to append a record of each distribution with stale index metadata.
+    stale_metadata_report: Option<PathBuf>,
+    /// The policy for verifying the attestations of registry distributions.
+    attestation_policy: AttestationPolicy,
+    /// The client identity to use for mTLS, overriding `SSL_CLIENT_CERT`.
+    client_identity: Option<Identity>,
+    /// The private key for the client certificate, if not included in the certificate file.
+    client_key: Option<PathBuf>,
+    /// Additional CA bundles to trust for requests to specific indexes.
+    index_ca_bundles: Vec<(DisplaySafeUrl, PathBuf)>,
+    /// The maximum number of bytes per second to receive, across all requests.
+    limit_rate: Option<NonZeroU64>,
+    /// The maximum number of concurrent connections to each host.
+    max_connections_per_host: Option<NonZeroUsize>,
+    /// The limiter enforcing `limit_rate` and `max_connections_per_host`, shared across all
+    /// clients created from this builder.
+    limiter: Option<Arc<RequestLimiter>>,
+    /// The mirrors to fail over to when an index is unavailable.
+    index_mirrors: Option<Arc<IndexMirrors>>,
+    /// The circuit breaker for index and mirror URLs, shared across all clients created from
+    /// this builder.
+    index_health: IndexHealth,
 }
 
 /// The policy for handling HTTP redirects.
@@ -159,12 +210,14 @@ impl Default for BaseClientBuilder<'_> {
     fn default() -> Self {
         Self {
             keyring: KeyringProviderType::default(),
+            credential_helper: None,
             preview: Preview::default(),
             allow_insecure_host: vec![],
             native_tls: false,
             built_in_root_certs: false,
             connectivity: Connectivity::Online,
             retries: DEFAULT_RETRIES,
+            retry_max_delay: DEFAULT_RETRY_MAX_DELAY,
             markers: None,
             platform: None,
             auth_integration: AuthIntegration::default(),
@@ -172,10 +225,12 @@ impl Default for BaseClientBuilder<'_> {
             indexes: Indexes::new(),
             read_timeout: DEFAULT_READ_TIMEOUT,
             connect_timeout: DEFAULT_CONNECT_TIMEOUT,
+            stall_timeout: DEFAULT_STALL_TIMEOUT,
             extra_middleware: None,
             proxies: vec![],
             http_proxy: None,
             https_proxy: None,
+            all_proxy: None,
             no_proxy: None,
             redirect_policy: RedirectPolicy::default(),
             cross_origin_credential_policy: CrossOriginCredentialsPolicy::Secure,
@@ -183,6 +238,19 @@ impl Default for BaseClientBuilder<'_> {
             subcommand: None,
             client_name: None,
             no_retry_delay: env::var_os(EnvVars::UV_TEST_NO_HTTP_RETRY_DELAY).is_some(),
+            allow_index_failure: false,
+            index_cache_ttl: None,
+            index_stale_if_error: false,
+            stale_metadata_report: None,
+            attestation_policy: AttestationPolicy::default(),
+            client_identity: None,
+            client_key: None,
+            index_ca_bundles: Vec::new(),
+            limit_rate: None,
+            max_connections_per_host: None,
+            limiter: None,
+            index_mirrors: None,
+            index_health: IndexHealth::default(),
         }
     }
 }
@@ -226,6 +294,12 @@ impl<'a> BaseClientBuilder<'a> {
         self
     }
 
+    #[must_use]
+    pub fn credential_helper(mut self, credential_helper: Option<String>) -> Self {
+        self.credential_helper = credential_helper;
+        self
+    }
+
     #[must_use]
     pub fn allow_insecure_host(mut self, allow_insecure_host: Vec<TrustedHost>) -> Self {
         self.allow_insecure_host = allow_insecure_host;
@@ -244,6 +318,13 @@ impl<'a> BaseClientBuilder<'a> {
         self
     }
 
+    /// Set the maximum delay between two attempts of a retried request.
+    #[must_use]
+    pub fn retry_max_delay(mut self, retry_max_delay: Duration) -> Self {
+        self.retry_max_delay = retry_max_delay;
+        self
+    }
+
     #[must_use]
     pub fn no_retry_delay(mut self, no_retry_delay: bool) -> Self {
         self.no_retry_delay = no_retry_delay;
@@ -298,6 +379,116 @@ impl<'a> BaseClientBuilder<'a> {
         self
     }
 
+    #[must_use]
+    pub fn stall_timeout(mut self, stall_timeout: Duration) -> Self {
+        self.stall_timeout = stall_timeout;
+        self
+    }
+
+    /// Treat indexes other than the default index as empty if they're unreachable (e.g., due to a
+    /// connection error, a timeout, or a server error), rather than failing.
+    #[must_use]
+    pub fn allow_index_failure(mut self, allow_index_failure: bool) -> Self {
+        self.allow_index_failure = allow_index_failure;
+        self
+    }
+
+    /// Consider cached Simple API responses fresh for the given duration, rather than respecting
+    /// the `Cache-Control` header returned by the index.
+    #[must_use]
+    pub fn index_cache_ttl(mut self, index_cache_ttl: Option<Duration>) -> Self {
+        self.index_cache_ttl = index_cache_ttl;
+        self
+    }
+
+    /// Fall back to stale cached Simple API responses if an index is unreachable (e.g., due to a
+    /// connection error, a timeout, or a server error), rather than failing.
+    #[must_use]
+    pub fn index_stale_if_error(mut self, index_stale_if_error: bool) -> Self {
+        self.index_stale_if_error = index_stale_if_error;
+        self
+    }
+
+    /// Append a JSON record to the given file for each distribution for which an index served
+    /// stale metadata.
+    #[must_use]
+    pub fn stale_metadata_report(mut self, stale_metadata_report: Option<PathBuf>) -> Self {
+        self.stale_metadata_report = stale_metadata_report;
+        self
+    }
+
+    /// Verify the attestations of registry distributions according to the given policy.
+    #[must_use]
+    pub fn attestation_policy(mut self, attestation_policy: AttestationPolicy) -> Self {
+        self.attestation_policy = attestation_policy;
+        self
+    }
+
+    /// Use the given client certificate for mTLS, rather than the one in `SSL_CLIENT_CERT`.
+    ///
+    /// If provided, the private key is read from a separate file; otherwise, the certificate file
+    /// must include it. The key also applies to the certificate in `SSL_CLIENT_CERT`.
+    ///
+    /// Unlike `SSL_CLIENT_CERT`, which is ignored with a warning if invalid, an explicitly
+    /// provided certificate that can't be read is an error.
+    pub fn client_cert(
+        mut self,
+        client_cert: Option<&Path>,
+        client_key: Option<PathBuf>,
+    ) -> Result<Self, ClientCertError> {
+        self.client_identity = client_cert
+            .map(|client_cert| {
+                read_identity(
+                    client_cert.as_os_str(),
+                    client_key.as_deref().map(Path::as_os_str),
+                )
+                .map_err(|err| ClientCertError {
+                    path: client_cert.to_path_buf(),
+                    err,
+                })
+            })
+            .transpose()?;
+        self.client_key = client_key;
+        Ok(self)
+    }
+
+    /// Limit the rate at which response bodies are received, in bytes per second, across all
+    /// requests.
+    #[must_use]
+    pub fn limit_rate(mut self, limit_rate: Option<NonZeroU64>) -> Self {
+        self.limit_rate = limit_rate;
+        self.limiter =
+            RequestLimiter::new(self.limit_rate, self.max_connections_per_host).map(Arc::new);
+        self
+    }
+
+    /// Limit the number of concurrent connections to each host.
+    #[must_use]
+    pub fn max_connections_per_host(
+        mut self,
+        max_connections_per_host: Option<NonZeroUsize>,
+    ) -> Self {
+        self.max_connections_per_host = max_connections_per_host;
+        self.limiter =
+            RequestLimiter::new(self.limit_rate, self.max_connections_per_host).map(Arc::new);
+        self
+    }
+
+    /// Fail over to the given mirrors when an index is unavailable.
+    #[must_use]
+    pub(crate) fn index_mirrors(mut self, index_mirrors: Option<IndexMirrors>) -> Self {
+        self.index_mirrors = index_mirrors.map(Arc::new);
+        self
+    }
+
+    /// Trust the certificates in the given CA bundles for requests to the host of the
+    /// corresponding index URL, in addition to the default trust roots.
+    #[must_use]
+    pub fn index_ca_bundles(mut self, index_ca_bundles: Vec<(DisplaySafeUrl, PathBuf)>) -> Self {
+        self.index_ca_bundles = index_ca_bundles;
+        self
+    }
+
     #[must_use]
     pub fn extra_middleware(mut self, middleware: ExtraMiddleware) -> Self {
         self.extra_middleware = Some(middleware);
@@ -322,6 +513,12 @@ impl<'a> BaseClientBuilder<'a> {
         self
     }
 
+    #[must_use]
+    pub fn all_proxy(mut self, all_proxy: Option<ProxyUrl>) -> Self {
+        self.all_proxy = all_proxy;
+        self
+    }
+
     #[must_use]
     pub fn no_proxy(mut self, no_proxy: Option<Vec<String>>) -> Self {
         self.no_proxy = no_proxy;
@@ -380,9 +577,29 @@ impl<'a> BaseClientBuilder<'a> {
         matches!(self.connectivity, Connectivity::Offline)
     }
 
+    pub fn is_allow_index_failure(&self) -> bool {
+        self.allow_index_failure
+    }
+
+    pub fn get_index_cache_ttl(&self) -> Option<Duration> {
+        self.index_cache_ttl
+    }
+
+    pub fn is_index_stale_if_error(&self) -> bool {
+        self.index_stale_if_error
+    }
+
+    pub fn get_stale_metadata_report(&self) -> Option<&Path> {
+        self.stale_metadata_report.as_deref()
+    }
+
+    pub fn get_attestation_policy(&self) -> &AttestationPolicy {
+        &self.attestation_policy
+    }
+
     /// Create a [`RetryPolicy`] for the client.
     pub fn retry_policy(&self) -> ExponentialBackoff {
-        retry_policy(self.retries, self.no_retry_delay)
+        retry_policy(self.retries, self.retry_max_delay, self.no_retry_delay)
     }
 
     pub fn build(&self) -> BaseClient {
@@ -409,22 +626,30 @@ impl<'a> BaseClientBuilder<'a> {
             }
         };
 
-        // Wrap in any relevant middleware and handle connectivity.
-        let client = RedirectClientWithMiddleware {
-            client: self.apply_middleware(raw_client.clone()),
-            redirect_policy: self.redirect_policy,
-            cross_origin_credentials_policy: self.cross_origin_credential_policy,
-        };
-        let dangerous_client = RedirectClientWithMiddleware {
-            client: self.apply_middleware(raw_dangerous_client.clone()),
-            redirect_policy: self.redirect_policy,
-            cross_origin_credentials_policy: self.cross_origin_credential_policy,
+        // Create a client for each index with a custom CA bundle, unless a custom client is used.
+        let raw_ca_bundle_clients = if self.custom_client.is_some() {
+            Vec::new()
+        } else {
+            self.create_ca_bundle_clients(&[])
         };
 
+        // Wrap in any relevant middleware and handle connectivity.
+        let client = self.wrap_client(raw_client.clone());
+        let dangerous_client = self.wrap_client(raw_dangerous_client.clone());
+        let ca_bundle_clients = raw_ca_bundle_clients
+            .into_iter()
+            .map(|(realm, raw_client)| CaBundleClient {
+                realm,
+                client: self.wrap_client(raw_client.clone()),
+                raw_client,
+            })
+            .collect();
+
         BaseClient {
             connectivity: self.connectivity,
             allow_insecure_host: self.allow_insecure_host.clone(),
             retries: self.retries,
+            retry_max_delay: self.retry_max_delay,
             no_retry_delay: self.no_retry_delay,
             client,
             raw_client,
@@ -432,28 +657,42 @@ impl<'a> BaseClientBuilder<'a> {
             raw_dangerous_client,
             read_timeout: self.read_timeout,
             connect_timeout: self.connect_timeout,
+            stall_timeout: self.stall_timeout,
             credentials_cache: self.credentials_cache.clone(),
+            ca_bundle_clients,
         }
     }
 
     /// Share the underlying client between two different middleware configurations.
     pub fn wrap_existing(&self, existing: &BaseClient) -> BaseClient {
         // Wrap in any relevant middleware and handle connectivity.
-        let client = RedirectClientWithMiddleware {
-            client: self.apply_middleware(existing.raw_client.clone()),
-            redirect_policy: self.redirect_policy,
-            cross_origin_credentials_policy: self.cross_origin_credential_policy,
-        };
-        let dangerous_client = RedirectClientWithMiddleware {
-            client: self.apply_middleware(existing.raw_dangerous_client.clone()),
-            redirect_policy: self.redirect_policy,
-            cross_origin_credentials_policy: self.cross_origin_credential_policy,
-        };
+        let client = self.wrap_client(existing.raw_client.clone());
+        let dangerous_client = self.wrap_client(existing.raw_dangerous_client.clone());
+
+        // Reuse the existing clients with custom CA bundles, and create any that are missing.
+        let raw_ca_bundle_clients = existing
+            .ca_bundle_clients
+            .iter()
+            .map(|ca_bundle_client| {
+                (
+                    ca_bundle_client.realm.clone(),
+                    ca_bundle_client.raw_client.clone(),
+                )
+            })
+            .chain(self.create_ca_bundle_clients(&existing.ca_bundle_clients));
+        let ca_bundle_clients = raw_ca_bundle_clients
+            .map(|(realm, raw_client)| CaBundleClient {
+                realm,
+                client: self.wrap_client(raw_client.clone()),
+                raw_client,
+            })
+            .collect();
 
         BaseClient {
             connectivity: self.connectivity,
             allow_insecure_host: self.allow_insecure_host.clone(),
             retries: self.retries,
+            retry_max_delay: self.retry_max_delay,
             no_retry_delay: self.no_retry_delay,
             client,
             dangerous_client,
@@ -461,7 +700,18 @@ impl<'a> BaseClientBuilder<'a> {
             raw_dangerous_client: existing.raw_dangerous_client.clone(),
             read_timeout: existing.read_timeout,
             connect_timeout: existing.connect_timeout,
+            stall_timeout: existing.stall_timeout,
             credentials_cache: existing.credentials_cache.clone(),
+            ca_bundle_clients,
+        }
+    }
+
+    /// Wrap a client in the configured middleware and redirect policy.
+    fn wrap_client(&self, client: Client) -> RedirectClientWithMiddleware {
+        RedirectClientWithMiddleware {
+            client: self.apply_middleware(client),
+            redirect_policy: self.redirect_policy,
+            cross_origin_credentials_policy: self.cross_origin_credential_policy,
         }
     }
 
@@ -470,94 +720,8 @@ impl<'a> BaseClientBuilder<'a> {
         read_timeout: Duration,
         connect_timeout: Duration,
     ) -> (Client, Client) {
-        // Create user agent.
-        let mut user_agent_string = format!("uv/{}", version());
-
-        // Add linehaul metadata.
-        let linehaul = LineHaul::new(self.markers, self.platform, self.subcommand.clone());
-        if let Ok(output) = serde_json::to_string(&linehaul) {
-            let _ = write!(user_agent_string, " {output}");
-        }
-
-        // Checks for the presence of `SSL_CERT_FILE`.
-        // Certificate loading support is delegated to `rustls-native-certs`.
-        // See https://github.com/rustls/rustls-native-certs/blob/813790a297ad4399efe70a8e5264ca1b420acbec/src/lib.rs#L118-L125
-        let ssl_cert_file_exists = env::var_os(EnvVars::SSL_CERT_FILE).is_some_and(|path| {
-            let path = Path::new(&path);
-            match path.metadata() {
-                Ok(metadata) if metadata.is_file() => true,
-                Ok(_) => {
-                    warn_user_once!(
-                        "Ignoring invalid `SSL_CERT_FILE`. Path is not a file: {}.",
-                        path.simplified_display().cyan()
-                    );
-                    false
-                }
-                Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
-                    warn_user_once!(
-                        "Ignoring invalid `SSL_CERT_FILE`. Path does not exist: {}.",
-                        path.simplified_display().cyan()
-                    );
-                    false
-                }
-                Err(err) => {
-                    warn_user_once!(
-                        "Ignoring invalid `SSL_CERT_FILE`. Path is not accessible: {} ({err}).",
-                        path.simplified_display().cyan()
-                    );
-                    false
-                }
-            }
-        });
-
-        // Checks for the presence of `SSL_CERT_DIR`.
-        // Certificate loading support is delegated to `rustls-native-certs`.
-        // See https://github.com/rustls/rustls-native-certs/blob/813790a297ad4399efe70a8e5264ca1b420acbec/src/lib.rs#L118-L125
-        let ssl_cert_dir_exists = env::var_os(EnvVars::SSL_CERT_DIR)
-            .filter(|v| !v.is_empty())
-            .is_some_and(|dirs| {
-                // Parse `SSL_CERT_DIR`, with support for multiple entries using
-                // a platform-specific delimiter (`:` on Unix, `;` on Windows)
-                let (existing, missing): (Vec<_>, Vec<_>) =
-                    env::split_paths(&dirs).partition(|p| p.exists());
-
-                if existing.is_empty() {
-                    let end_note = if missing.len() == 1 {
-                        "The directory does not exist."
-                    } else {
-                        "The entries do not exist."
-                    };
-                    warn_user_once!(
-                        "Ignoring invalid `SSL_CERT_DIR`. {end_note}: {}.",
-                        missing
-                            .iter()
-                            .map(Simplified::simplified_display)
-                            .join(", ")
-                            .cyan()
-                    );
-                    return false;
-                }
-
-                // Warn on any missing entries
-                if !missing.is_empty() {
-                    let end_note = if missing.len() == 1 {
-                        "The following directory does not exist:"
-                    } else {
-                        "The following entries do not exist:"
-                    };
-                    warn_user_once!(
-                        "Invalid entries in `SSL_CERT_DIR`. {end_note}: {}.",
-                        missing
-                            .iter()
-                            .map(Simplified::simplified_display)
-                            .join(", ")
-                            .cyan()
-                    );
-                }
-
-                // Proceed while ignoring missing entries
-                true
-            });
+        let user_agent_string = self.user_agent();
+        let (ssl_cert_file_exists, ssl_cert_dir_exists) = ssl_cert_env();
 
         // Create a secure client that validates certificates.
         let raw_client = self.create_client(
@@ -568,6 +732,7 @@ impl<'a> BaseClientBuilder<'a> {
             ssl_cert_dir_exists,
             Security::Secure,
             self.redirect_policy,
+            &[],
         );
 
         // Create an insecure client that accepts invalid certificates.
@@ -579,11 +744,71 @@ impl<'a> BaseClientBuilder<'a> {
             ssl_cert_dir_exists,
             Security::Insecure,
             self.redirect_policy,
+            &[],
         );
 
         (raw_client, raw_dangerous_client)
     }
 
+    /// Create a client for each index with a custom CA bundle, unless one exists for its realm.
+    fn create_ca_bundle_clients(&self, existing: &[CaBundleClient]) -> Vec<(Realm, Client)> {
+        if self.index_ca_bundles.is_empty() {
+            return Vec::new();
+        }
+
+        let user_agent_string = self.user_agent();
+        let (ssl_cert_file_exists, ssl_cert_dir_exists) = ssl_cert_env();
+
+        let mut clients: Vec<(Realm, Client)> = Vec::new();
+        for (url, ca_bundle) in &self.index_ca_bundles {
+            let realm = Realm::from(url);
+            if existing.iter().any(|client| client.realm == realm)
+                || clients.iter().any(|(existing, _)| *existing == realm)
+            {
+                continue;
+            }
+            let certificates = match read_ca_bundle(ca_bundle.as_os_str()) {
+                Ok(certificates) => certificates,
+                Err(err) => {
+                    warn_user_once!(
+                        "Ignoring invalid CA bundle for {url}: {}: {err}",
+                        ca_bundle.simplified_display().cyan()
+                    );
+                    continue;
+                }
+            };
+            debug!(
+                "Using CA bundle {} for {realm}",
+                ca_bundle.simplified_display()
+            );
+            let client = self.create_client(
+                &user_agent_string,
+                self.read_timeout,
+                self.connect_timeout,
+                ssl_cert_file_exists,
+                ssl_cert_dir_exists,
+                Security::Secure,
+                self.redirect_policy,
+                &certificates,
+            );
+            clients.push((realm, client));
+        }
+        clients
+    }
+
+    /// Create the user agent, including the linehaul metadata.
+    fn user_agent(&self) -> String {
+        let mut user_agent_string = format!("uv/{}", version());
+
+        // Add linehaul metadata.
+        let linehaul = LineHaul::new(self.markers, self.platform, self.subcommand.clone());
+        if let Ok(output) = serde_json::to_string(&linehaul) {
+            let _ = write!(user_agent_string, " {output}");
+        }
+
+        user_agent_string
+    }
+
     fn create_client(
         &self,
         user_agent: &str,
@@ -593,6 +818,7 @@ impl<'a> BaseClientBuilder<'a> {
         ssl_cert_dir_exists: bool,
         security: Security,
         redirect_policy: RedirectPolicy,
+        ca_bundle: &[Certificate],
     ) -> Client {
         // Configure the builder.
         let client_builder = ClientBuilder::new()
@@ -610,15 +836,25 @@ impl<'a> BaseClientBuilder<'a> {
             Security::Insecure => client_builder.danger_accept_invalid_certs(true),
         };
 
-        let client_builder = if self.native_tls || ssl_cert_file_exists || ssl_cert_dir_exists {
+        let mut client_builder = if self.native_tls || ssl_cert_file_exists || ssl_cert_dir_exists {
             client_builder.tls_built_in_native_certs(true)
         } else {
             client_builder.tls_built_in_webpki_certs(true)
         };
 
+        // Trust any additional certificates, e.g., for an index with a private CA.
+        for certificate in ca_bundle {
+            client_builder = client_builder.add_root_certificate(certificate.clone());
+        }
+
         // Configure mTLS.
-        let client_builder = if let Some(ssl_client_cert) = env::var_os(EnvVars::SSL_CLIENT_CERT) {
-            match read_identity(&ssl_client_cert) {
+        let client_builder = if let Some(identity) = &self.client_identity {
+            client_builder.identity(identity.clone())
+        } else if let Some(ssl_client_cert) = env::var_os(EnvVars::SSL_CLIENT_CERT) {
+            match read_identity(
+                &ssl_client_cert,
+                self.client_key.as_deref().map(Path::as_os_str),
+            ) {
                 Ok(identity) => client_builder.identity(identity),
                 Err(err) => {
                     warn_user_once!("Ignoring invalid `SSL_CLIENT_CERT`: {err}");
@@ -635,11 +871,15 @@ impl<'a> BaseClientBuilder<'a> {
             client_builder = client_builder.proxy(p.clone());
         }
 
-        let no_proxy = self
-            .no_proxy
-            .as_ref()
-            .and_then(|no_proxy| NoProxy::from_string(&no_proxy.join(",")));
+        // Configuring a proxy disables reqwest's environment lookup, so fall back to `NO_PROXY`
+        // if no `no-proxy` setting is provided.
+        let no_proxy = match &self.no_proxy {
+            Some(no_proxy) => NoProxy::from_string(&no_proxy.join(",")),
+            None => NoProxy::from_env(),
+        };
 
+        // Scheme-specific proxies take precedence over `all-proxy`, as reqwest uses the first
+        // matching proxy.
         if let Some(http_proxy) = &self.http_proxy {
             let proxy = http_proxy
                 .as_proxy(ProxyUrlKind::Http)
@@ -648,7 +888,14 @@ impl<'a> BaseClientBuilder<'a> {
         }
 
         if let Some(https_proxy) = &self.https_proxy {
-            let proxy = https_proxy.as_proxy(ProxyUrlKind::Https).no_proxy(no_proxy);
+            let proxy = https_proxy
+                .as_proxy(ProxyUrlKind::Https)
+                .no_proxy(no_proxy.clone());
+            client_builder = client_builder.proxy(proxy);
+        }
+
+        if let Some(all_proxy) = &self.all_proxy {
+            let proxy = all_proxy.as_proxy(ProxyUrlKind::All).no_proxy(no_proxy);
             client_builder = client_builder.proxy(proxy);
         }
 
@@ -683,6 +930,11 @@ impl<'a> BaseClientBuilder<'a> {
                         }
                     }
 
+                    // Apply the rate and connection limits to each individual request.
+                    if let Some(limiter) = &self.limiter {
+                        client = client.with(LimiterMiddleware(limiter.clone()));
+                    }
+
                     client.build()
                 };
 
@@ -705,6 +957,16 @@ impl<'a> BaseClientBuilder<'a> {
                     }
                 }
 
+                // Fail over to the mirrors of an unavailable index. The authentication middleware
+                // runs afterwards, such that each mirror is sent the index's credentials.
+                if let Some(index_mirrors) = &self.index_mirrors {
+                    client = client.with(MirrorMiddleware {
+                        mirrors: index_mirrors.clone(),
+                        health: self.index_health.clone(),
+                        timeout: self.read_timeout,
+                    });
+                }
+
                 // Initialize the authentication middleware to set headers.
                 match self.auth_integration {
                     AuthIntegration::Default => {
@@ -713,6 +975,9 @@ impl<'a> BaseClientBuilder<'a> {
                             .with_base_client(base_client)
                             .with_indexes(self.indexes.clone())
                             .with_keyring(self.keyring.to_provider())
+                            .with_credential_helper(
+                                self.credential_helper.clone().map(CredentialHelper::new),
+                            )
                             .with_preview(self.preview);
                         if let Ok(token_store) = PyxTokenStore::from_settings() {
                             auth_middleware = auth_middleware.with_pyx_token_store(token_store);
@@ -725,6 +990,9 @@ impl<'a> BaseClientBuilder<'a> {
                             .with_base_client(base_client)
                             .with_indexes(self.indexes.clone())
                             .with_keyring(self.keyring.to_provider())
+                            .with_credential_helper(
+                                self.credential_helper.clone().map(CredentialHelper::new),
+                            )
                             .with_preview(self.preview)
                             .with_only_authenticated(true);
                         if let Ok(token_store) = PyxTokenStore::from_settings() {
@@ -737,6 +1005,11 @@ impl<'a> BaseClientBuilder<'a> {
                     }
                 }
 
+                // Apply the rate and connection limits to each individual request.
+                if let Some(limiter) = &self.limiter {
+                    client = client.with(LimiterMiddleware(limiter.clone()));
+                }
+
                 client.build()
             }
             Connectivity::Offline => reqwest_middleware::ClientBuilder::new(client)
@@ -746,6 +1019,91 @@ impl<'a> BaseClientBuilder<'a> {
     }
 }
 
+/// Check for the presence of valid `SSL_CERT_FILE` and `SSL_CERT_DIR` variables.
+fn ssl_cert_env() -> (bool, bool) {
+    // Checks for the presence of `SSL_CERT_FILE`.
+    // Certificate loading support is delegated to `rustls-native-certs`.
+    // See https://github.com/rustls/rustls-native-certs/blob/813790a297ad4399efe70a8e5264ca1b420acbec/src/lib.rs#L118-L125
+    let ssl_cert_file_exists = env::var_os(EnvVars::SSL_CERT_FILE).is_some_and(|path| {
+        let path = Path::new(&path);
+        match path.metadata() {
+            Ok(metadata) if metadata.is_file() => true,
+            Ok(_) => {
+                warn_user_once!(
+                    "Ignoring invalid `SSL_CERT_FILE`. Path is not a file: {}.",
+                    path.simplified_display().cyan()
+                );
+                false
+            }
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+                warn_user_once!(
+                    "Ignoring invalid `SSL_CERT_FILE`. Path does not exist: {}.",
+                    path.simplified_display().cyan()
+                );
+                false
+            }
+            Err(err) => {
+                warn_user_once!(
+                    "Ignoring invalid `SSL_CERT_FILE`. Path is not accessible: {} ({err}).",
+                    path.simplified_display().cyan()
+                );
+                false
+            }
+        }
+    });
+
+    // Checks for the presence of `SSL_CERT_DIR`.
+    // Certificate loading support is delegated to `rustls-native-certs`.
+    // See https://github.com/rustls/rustls-native-certs/blob/813790a297ad4399efe70a8e5264ca1b420acbec/src/lib.rs#L118-L125
+    let ssl_cert_dir_exists = env::var_os(EnvVars::SSL_CERT_DIR)
+        .filter(|v| !v.is_empty())
+        .is_some_and(|dirs| {
+            // Parse `SSL_CERT_DIR`, with support for multiple entries using
+            // a platform-specific delimiter (`:` on Unix, `;` on Windows)
+            let (existing, missing): (Vec<_>, Vec<_>) =
+                env::split_paths(&dirs).partition(|p| p.exists());
+
+            if existing.is_empty() {
+                let end_note = if missing.len() == 1 {
+                    "The directory does not exist."
+                } else {
+                    "The entries do not exist."
+                };
+                warn_user_once!(
+                    "Ignoring invalid `SSL_CERT_DIR`. {end_note}: {}.",
+                    missing
+                        .iter()
+                        .map(Simplified::simplified_display)
+                        .join(", ")
+                        .cyan()
+                );
+                return false;
+            }
+
+            // Warn on any missing entries
+            if !missing.is_empty() {
+                let end_note = if missing.len() == 1 {
+                    "The following directory does not exist:"
+                } else {
+                    "The following entries do not exist:"
+                };
+                warn_user_once!(
+                    "Invalid entries in `SSL_CERT_DIR`. {end_note}: {}.",
+                    missing
+                        .iter()
+                        .map(Simplified::simplified_display)
+                        .join(", ")
+                        .cyan()
+                );
+            }
+
+            // Proceed while ignoring missing entries
+            true
+        });
+
+    (ssl_cert_file_exists, ssl_cert_dir_exists)
+}
+
 /// A base client for HTTP requests
 #[derive(Debug, Clone)]
 pub struct BaseClient {
@@ -763,14 +1121,28 @@ pub struct BaseClient {
     read_timeout: Duration,
     /// Configured client connect timeout.
     connect_timeout: Duration,
+    /// Configured download stall timeout.
+    stall_timeout: Duration,
     /// Hosts that are trusted to use the insecure client.
     allow_insecure_host: Vec<TrustedHost>,
     /// The number of retries to attempt on transient errors.
     retries: u32,
+    /// The maximum delay between two attempts of a retried request.
+    retry_max_delay: Duration,
     /// Whether to disable retry delays (for testing).
     no_retry_delay: bool,
     /// Global authentication cache for a uv invocation to share credentials across uv clients.
     credentials_cache: Arc<CredentialsCache>,
+    /// The HTTP clients that trust additional CA bundles, for specific indexes.
+    ca_bundle_clients: Vec<CaBundleClient>,
+}
+
+/// An HTTP client that trusts an additional CA bundle for requests to a given realm.
+#[derive(Debug, Clone)]
+struct CaBundleClient {
+    realm: Realm,
+    client: RedirectClientWithMiddleware,
+    raw_client: Client,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -786,11 +1158,24 @@ impl BaseClient {
     pub fn for_host(&self, url: &DisplaySafeUrl) -> &RedirectClientWithMiddleware {
         if self.disable_ssl(url) {
             &self.dangerous_client
+        } else if let Some(ca_bundle_client) = self.ca_bundle_client(url) {
+            &ca_bundle_client.client
         } else {
             &self.client
         }
     }
 
+    /// Returns the client with an additional CA bundle for the URL's realm, if any.
+    fn ca_bundle_client(&self, url: &DisplaySafeUrl) -> Option<&CaBundleClient> {
+        if self.ca_bundle_clients.is_empty() {
+            return None;
+        }
+        let realm = Realm::from(url);
+        self.ca_bundle_clients
+            .iter()
+            .find(|ca_bundle_client| ca_bundle_client.realm == realm)
+    }
+
     /// Executes a request, applying redirect policy.
     pub async fn execute(&self, req: Request) -> reqwest_middleware::Result<Response> {
         let client = self.for_host(&DisplaySafeUrl::from_url(req.url().clone()));
@@ -814,6 +1199,14 @@ impl BaseClient {
         self.connect_timeout
     }
 
+    /// The configured download stall timeout, if it's lower than the read timeout.
+    ///
+    /// A download that receives no data for longer than the read timeout is already aborted by
+    /// the client, so a stall timeout at or above the read timeout has no effect.
+    pub fn stall_timeout(&self) -> Option<Duration> {
+        (self.stall_timeout < self.read_timeout).then_some(self.stall_timeout)
+    }
+
     /// The configured connectivity mode.
     pub fn connectivity(&self) -> Connectivity {
         self.connectivity
@@ -821,7 +1214,7 @@ impl BaseClient {
 
     /// The [`RetryPolicy`] for the client.
     pub fn retry_policy(&self) -> ExponentialBackoff {
-        retry_policy(self.retries, self.no_retry_delay)
+        retry_policy(self.retries, self.retry_max_delay, self.no_retry_delay)
     }
 
     pub fn credentials_cache(&self) -> &CredentialsCache {
@@ -1150,16 +1543,17 @@ impl<'a> RequestBuilder<'a> {
     }
 }
 
-/// Create a [`RetryPolicy`] with the given number of retries.
-fn retry_policy(retries: u32, no_retry_delay: bool) -> ExponentialBackoff {
+/// Create a [`RetryPolicy`] with the given number of retries and maximum delay between attempts.
+fn retry_policy(retries: u32, max_delay: Duration, no_retry_delay: bool) -> ExponentialBackoff {
     let mut builder = ExponentialBackoff::builder();
     if no_retry_delay {
         builder = builder.retry_bounds(Duration::from_millis(0), Duration::from_millis(0));
     } else {
-        // Configure an effective minimum between attempts of 1s and a real maximum of 30s.
+        // Configure an effective minimum between attempts of 1s (unless the maximum is lower), and
+        // a real maximum of `max_delay`.
         builder = builder
             .jitter(Jitter::Bounded)
-            .retry_bounds(Duration::from_secs(2), Duration::from_secs(30));
+            .retry_bounds(Duration::from_secs(2).min(max_delay), max_delay);
     }
     builder.build_with_max_retries(retries)
 }
@@ -1469,6 +1863,15 @@ fn find_source<E: Error + 'static>(orig: &dyn Error) -> Option<&E> {
     None
 }
 
+/// An error reading the client certificate provided via `--client-cert`.
+#[derive(Debug, Error)]
+#[error("Failed to read client certificate from `{}`", path.user_display())]
+pub struct ClientCertError {
+    path: PathBuf,
+    #[source]
+    err: CertificateError,
+}
+
 // TODO(konsti): Remove once we find a native home for `retries_from_env`
 #[derive(Debug, Error)]
 pub enum RetryParsingError {
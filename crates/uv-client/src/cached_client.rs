@@ -8,7 +8,7 @@ use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use tracing::{Instrument, debug, info_span, instrument, trace, warn};
 
-use uv_cache::{CacheEntry, Freshness};
+use uv_cache::{CacheEntry, Freshness, integrity};
 use uv_fs::write_atomic;
 use uv_redacted::DisplaySafeUrl;
 
@@ -52,6 +52,10 @@ pub trait Cacheable: Sized {
 
 /// A wrapper type that makes anything with Serde support automatically
 /// implement `Cacheable`.
+///
+/// The serialized payload is sealed with integrity metadata (see
+/// [`uv_cache::integrity`]), such that entries written by an incompatible
+/// version of uv are treated as broken and refetched.
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(transparent)]
 pub(crate) struct SerdeCacheable<T> {
@@ -62,13 +66,13 @@ impl<T: Serialize + DeserializeOwned + Send + 'static> Cacheable for SerdeCachea
     type Target = T;
 
     fn from_aligned_bytes(bytes: AlignedVec) -> Result<T, Error> {
-        Ok(rmp_serde::from_slice::<T>(&bytes).map_err(ErrorKind::Decode)?)
+        let payload = integrity::unseal(&bytes).map_err(ErrorKind::Integrity)?;
+        Ok(rmp_serde::from_slice::<T>(payload).map_err(ErrorKind::Decode)?)
     }
 
     fn to_bytes(&self) -> Result<Cow<'_, [u8]>, Error> {
-        Ok(Cow::from(
-            rmp_serde::to_vec(&self.inner).map_err(ErrorKind::Encode)?,
-        ))
+        let payload = rmp_serde::to_vec(&self.inner).map_err(ErrorKind::Encode)?;
+        Ok(Cow::from(integrity::seal(&payload)))
     }
 
     fn into_target(self) -> Self::Target {
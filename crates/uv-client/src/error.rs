@@ -6,16 +6,18 @@ use std::time::{Duration, Instant};
 use async_http_range_reader::AsyncHttpRangeReaderError;
 use async_zip::error::ZipError;
 use reqwest::Response;
+use reqwest_retry::Retryable;
 use serde::Deserialize;
 use tracing::warn;
 
 use uv_cache::Error as CacheError;
 use uv_distribution_filename::{WheelFilename, WheelFilenameError};
 use uv_normalize::PackageName;
+use uv_pypi_types::HashDigest;
 use uv_redacted::DisplaySafeUrl;
 
 use crate::middleware::OfflineError;
-use crate::{FlatIndexError, html};
+use crate::{FlatIndexError, html, retryable_on_request_failure};
 
 /// RFC 9457 Problem Details for HTTP APIs
 ///
@@ -236,6 +238,12 @@ impl Error {
         matches!(err.kind(), std::io::ErrorKind::NotFound)
     }
 
+    /// Returns `true` if the error indicates that the server is unreachable (e.g., a connection
+    /// error, a timeout, or a `5xx` status code), as opposed to the resource being unavailable.
+    pub(crate) fn is_unreachable(&self) -> bool {
+        retryable_on_request_failure(self) == Some(Retryable::Transient)
+    }
+
     /// Returns `true` if the error is due to an SSL error.
     pub fn is_ssl(&self) -> bool {
         matches!(&*self.kind, ErrorKind::WrappedReqwestError(.., err) if err.is_ssl())
@@ -405,6 +413,17 @@ pub enum ErrorKind {
     #[error("{0} is not a valid wheel filename")]
     WheelFilename(#[source] WheelFilenameError),
 
+    /// The PEP 658 metadata file didn't match the hash advertised by the index.
+    #[error(
+        "Hash mismatch for the metadata of `{filename}` from {url}\n\nExpected:\n  {expected}\n\nComputed:\n  {actual}"
+    )]
+    MetadataHashMismatch {
+        filename: WheelFilename,
+        url: DisplaySafeUrl,
+        expected: HashDigest,
+        actual: HashDigest,
+    },
+
     #[error("Package metadata name `{metadata}` does not match given name `{given}`")]
     NameMismatch {
         given: PackageName,
@@ -429,6 +448,9 @@ pub enum ErrorKind {
     #[error("Cache serialization failed")]
     Encode(#[source] rmp_serde::encode::Error),
 
+    #[error("Cache entry failed integrity validation")]
+    Integrity(#[source] uv_cache::integrity::IntegrityError),
+
     #[error("Missing `Content-Type` header for {0}")]
     MissingContentType(DisplaySafeUrl),
 
@@ -302,8 +302,10 @@ impl<'a> FlatIndexClient<'a> {
 
             let file = File {
                 dist_info_metadata: false,
+                dist_info_metadata_hashes: HashDigests::empty(),
                 filename: filename.into(),
                 hashes: HashDigests::empty(),
+                provenance: None,
                 requires_python: None,
                 size: None,
                 upload_time_utc_ms: None,
@@ -4,6 +4,7 @@ use jiff::Timestamp;
 use tl::{HTMLTag, Node, Parser};
 use tracing::{debug, instrument, warn};
 
+use uv_distribution_filename::normalize_remote_filename;
 use uv_normalize::PackageName;
 use uv_pep440::VersionSpecifiers;
 use uv_pypi_types::{BaseUrl, CoreMetadata, Hashes, ProjectStatus, PypiFile, Status, Yanked};
@@ -230,9 +231,8 @@ impl SimpleDetailHTML {
         // Strip any query string from the filename.
         let filename = filename.split('?').next().unwrap_or(filename);
 
-        // Unquote the filename.
-        let filename = percent_encoding::percent_decode_str(filename)
-            .decode_utf8()
+        // Unquote the filename, repairing any `+` that a mirror mangled into a space.
+        let filename = normalize_remote_filename(filename)
             .map_err(|_| Error::UnsupportedFilename(filename.to_string()))?;
 
         // Extract the `requires-python` value, which should be set on the
@@ -305,11 +305,22 @@ impl SimpleDetailHTML {
             .map(|upload_time| html_escape::decode_html_entities(upload_time))
             .and_then(|upload_time| Timestamp::from_str(&upload_time).ok());
 
+        // Extract the `provenance` field, which should be set on the `data-provenance` attribute,
+        // per PEP 740.
+        let provenance = link
+            .attributes()
+            .get("data-provenance")
+            .flatten()
+            .and_then(|provenance| std::str::from_utf8(provenance.as_bytes()).ok())
+            .map(|provenance| html_escape::decode_html_entities(provenance))
+            .map(|provenance| SmallString::from(provenance.as_ref()));
+
         Ok(Some(PypiFile {
             core_metadata,
             yanked,
             requires_python,
             hashes,
+            provenance,
             filename: filename.into(),
             url: path.into(),
             size,
@@ -455,6 +466,7 @@ mod tests {
                         sha512: None,
                         blake2b: None,
                     },
+                    provenance: None,
                     requires_python: None,
                     size: None,
                     upload_time: None,
@@ -516,6 +528,7 @@ mod tests {
                         sha512: None,
                         blake2b: None,
                     },
+                    provenance: None,
                     requires_python: None,
                     size: None,
                     upload_time: None,
@@ -580,6 +593,7 @@ mod tests {
                         sha512: None,
                         blake2b: None,
                     },
+                    provenance: None,
                     requires_python: None,
                     size: None,
                     upload_time: None,
@@ -641,6 +655,7 @@ mod tests {
                         sha512: None,
                         blake2b: None,
                     },
+                    provenance: None,
                     requires_python: None,
                     size: None,
                     upload_time: None,
@@ -702,6 +717,7 @@ mod tests {
                         sha512: None,
                         blake2b: None,
                     },
+                    provenance: None,
                     requires_python: None,
                     size: None,
                     upload_time: None,
@@ -761,6 +777,7 @@ mod tests {
                         sha512: None,
                         blake2b: None,
                     },
+                    provenance: None,
                     requires_python: None,
                     size: None,
                     upload_time: None,
@@ -772,6 +789,66 @@ mod tests {
         "#);
     }
 
+    #[test]
+    fn parse_space_mangled_filepath() {
+        let text = r#"
+<!DOCTYPE html>
+<html>
+<body>
+<h1>Links for jinja2</h1>
+<a href="cpu/torchtext-0.17.0 cpu-cp39-cp39-win_amd64.whl">cpu/torchtext-0.17.0 cpu-cp39-cp39-win_amd64.whl</a><br/>
+</body>
+</html>
+<!--TIMESTAMP 1703347410-->
+    "#;
+        let base = DisplaySafeUrl::parse("https://download.pytorch.org/whl/jinja2/").unwrap();
+        let result = SimpleDetailHTML::parse(text, &base).unwrap();
+        insta::assert_debug_snapshot!(result, @r#"
+        SimpleDetailHTML {
+            project_status: ProjectStatus {
+                status: Active,
+                reason: None,
+            },
+            base: BaseUrl(
+                DisplaySafeUrl {
+                    scheme: "https",
+                    cannot_be_a_base: false,
+                    username: "",
+                    password: None,
+                    host: Some(
+                        Domain(
+                            "download.pytorch.org",
+                        ),
+                    ),
+                    port: None,
+                    path: "/whl/jinja2/",
+                    query: None,
+                    fragment: None,
+                },
+            ),
+            files: [
+                PypiFile {
+                    core_metadata: None,
+                    filename: "torchtext-0.17.0+cpu-cp39-cp39-win_amd64.whl",
+                    hashes: Hashes {
+                        md5: None,
+                        sha256: None,
+                        sha384: None,
+                        sha512: None,
+                        blake2b: None,
+                    },
+                    provenance: None,
+                    requires_python: None,
+                    size: None,
+                    upload_time: None,
+                    url: "cpu/torchtext-0.17.0 cpu-cp39-cp39-win_amd64.whl",
+                    yanked: None,
+                },
+            ],
+        }
+        "#);
+    }
+
     #[test]
     fn parse_missing_hash() {
         let text = r#"
@@ -820,6 +897,7 @@ mod tests {
                         sha512: None,
                         blake2b: None,
                     },
+                    provenance: None,
                     requires_python: None,
                     size: None,
                     upload_time: None,
@@ -963,6 +1041,7 @@ mod tests {
                         sha512: None,
                         blake2b: None,
                     },
+                    provenance: None,
                     requires_python: None,
                     size: None,
                     upload_time: None,
@@ -1022,6 +1101,7 @@ mod tests {
                         sha512: None,
                         blake2b: None,
                     },
+                    provenance: None,
                     requires_python: None,
                     size: None,
                     upload_time: None,
@@ -1082,6 +1162,7 @@ mod tests {
                             sha512: None,
                             blake2b: None,
                         },
+                        provenance: None,
                         requires_python: None,
                         size: None,
                         upload_time: None,
@@ -1143,6 +1224,7 @@ mod tests {
                             sha512: None,
                             blake2b: None,
                         },
+                        provenance: None,
                         requires_python: None,
                         size: None,
                         upload_time: None,
@@ -1223,6 +1305,7 @@ mod tests {
                         sha512: None,
                         blake2b: None,
                     },
+                    provenance: None,
                     requires_python: None,
                     size: None,
                     upload_time: None,
@@ -1239,6 +1322,7 @@ mod tests {
                         sha512: None,
                         blake2b: None,
                     },
+                    provenance: None,
                     requires_python: None,
                     size: None,
                     upload_time: None,
@@ -1311,6 +1395,7 @@ mod tests {
                         sha512: None,
                         blake2b: None,
                     },
+                    provenance: None,
                     requires_python: None,
                     size: None,
                     upload_time: None,
@@ -1329,6 +1414,7 @@ mod tests {
                         sha512: None,
                         blake2b: None,
                     },
+                    provenance: None,
                     requires_python: None,
                     size: None,
                     upload_time: None,
@@ -1347,6 +1433,7 @@ mod tests {
                         sha512: None,
                         blake2b: None,
                     },
+                    provenance: None,
                     requires_python: Some(
                         Ok(
                             VersionSpecifiers(
@@ -1418,6 +1505,7 @@ mod tests {
                         sha512: None,
                         blake2b: None,
                     },
+                    provenance: None,
                     requires_python: Some(
                         Ok(
                             VersionSpecifiers(
@@ -1440,6 +1528,70 @@ mod tests {
         "#);
     }
 
+    /// Respect PEP 740 (see: <https://peps.python.org/pep-0740/>).
+    #[test]
+    fn parse_provenance() {
+        let text = r#"
+<!DOCTYPE html>
+<html>
+<body>
+<h1>Links for jinja2</h1>
+<a href="/whl/Jinja2-3.1.2-py3-none-any.whl#sha256=6088930bfe239f0e6710546ab9c19c9ef35e29792895fed6e6e31a023a182a61" data-provenance="/integrity/jinja2/3.1.2/Jinja2-3.1.2-py3-none-any.whl/provenance">Jinja2-3.1.2-py3-none-any.whl</a><br/>
+</body>
+</html>
+    "#;
+        let base = DisplaySafeUrl::parse("https://download.pytorch.org/whl/jinja2/").unwrap();
+        let result = SimpleDetailHTML::parse(text, &base).unwrap();
+        insta::assert_debug_snapshot!(result, @r#"
+        SimpleDetailHTML {
+            project_status: ProjectStatus {
+                status: Active,
+                reason: None,
+            },
+            base: BaseUrl(
+                DisplaySafeUrl {
+                    scheme: "https",
+                    cannot_be_a_base: false,
+                    username: "",
+                    password: None,
+                    host: Some(
+                        Domain(
+                            "download.pytorch.org",
+                        ),
+                    ),
+                    port: None,
+                    path: "/whl/jinja2/",
+                    query: None,
+                    fragment: None,
+                },
+            ),
+            files: [
+                PypiFile {
+                    core_metadata: None,
+                    filename: "Jinja2-3.1.2-py3-none-any.whl",
+                    hashes: Hashes {
+                        md5: None,
+                        sha256: Some(
+                            "6088930bfe239f0e6710546ab9c19c9ef35e29792895fed6e6e31a023a182a61",
+                        ),
+                        sha384: None,
+                        sha512: None,
+                        blake2b: None,
+                    },
+                    provenance: Some(
+                        "/integrity/jinja2/3.1.2/Jinja2-3.1.2-py3-none-any.whl/provenance",
+                    ),
+                    requires_python: None,
+                    size: None,
+                    upload_time: None,
+                    url: "/whl/Jinja2-3.1.2-py3-none-any.whl",
+                    yanked: None,
+                },
+            ],
+        }
+        "#);
+    }
+
     /// Respect PEP 714 (see: <https://peps.python.org/pep-0714/>).
     #[test]
     fn parse_core_metadata() {
@@ -1497,6 +1649,7 @@ mod tests {
                         sha512: None,
                         blake2b: None,
                     },
+                    provenance: None,
                     requires_python: None,
                     size: None,
                     upload_time: None,
@@ -1517,6 +1670,7 @@ mod tests {
                         sha512: None,
                         blake2b: None,
                     },
+                    provenance: None,
                     requires_python: None,
                     size: None,
                     upload_time: None,
@@ -1537,6 +1691,7 @@ mod tests {
                         sha512: None,
                         blake2b: None,
                     },
+                    provenance: None,
                     requires_python: None,
                     size: None,
                     upload_time: None,
@@ -1557,6 +1712,7 @@ mod tests {
                         sha512: None,
                         blake2b: None,
                     },
+                    provenance: None,
                     requires_python: None,
                     size: None,
                     upload_time: None,
@@ -1577,6 +1733,7 @@ mod tests {
                         sha512: None,
                         blake2b: None,
                     },
+                    provenance: None,
                     requires_python: None,
                     size: None,
                     upload_time: None,
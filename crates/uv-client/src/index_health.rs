@@ -0,0 +1,94 @@
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use rustc_hash::FxHashMap;
+use tracing::debug;
+
+use uv_redacted::DisplaySafeUrl;
+
+/// The number of consecutive failures after which a URL is considered unavailable.
+const FAILURE_THRESHOLD: u32 = 3;
+
+/// The duration for which an unavailable URL is skipped before it's tried again.
+const COOLDOWN: Duration = Duration::from_secs(30);
+
+/// Tracks consecutive request failures for index URLs, such that a URL that is known to be failing
+/// (e.g., a mirror during an outage) is skipped in favor of its alternatives, rather than waiting
+/// on it for every package (i.e., a circuit breaker).
+///
+/// After [`FAILURE_THRESHOLD`] consecutive failures, a URL is skipped for [`COOLDOWN`]. Once the
+/// cooldown has elapsed, the URL is tried again; a single success resets its failure count.
+#[derive(Debug, Default, Clone)]
+pub(crate) struct IndexHealth(Arc<Mutex<FxHashMap<DisplaySafeUrl, UrlHealth>>>);
+
+#[derive(Debug, Default)]
+struct UrlHealth {
+    /// The number of consecutive failed requests to the URL.
+    consecutive_failures: u32,
+    /// The time until which the URL should be skipped, if any.
+    unavailable_until: Option<Instant>,
+}
+
+impl IndexHealth {
+    /// Returns `true` if requests should be sent to the given URL.
+    pub(crate) fn is_available(&self, url: &DisplaySafeUrl) -> bool {
+        let Ok(health) = self.0.lock() else {
+            return true;
+        };
+        health
+            .get(url)
+            .and_then(|health| health.unavailable_until)
+            .is_none_or(|until| Instant::now() >= until)
+    }
+
+    /// Record a successful request to the given URL.
+    pub(crate) fn record_success(&self, url: &DisplaySafeUrl) {
+        let Ok(mut health) = self.0.lock() else {
+            return;
+        };
+        health.remove(url);
+    }
+
+    /// Record a failed request to the given URL.
+    pub(crate) fn record_failure(&self, url: &DisplaySafeUrl) {
+        let Ok(mut health) = self.0.lock() else {
+            return;
+        };
+        let health = health.entry(url.clone()).or_default();
+        health.consecutive_failures += 1;
+        if health.consecutive_failures >= FAILURE_THRESHOLD {
+            debug!(
+                "Skipping `{url}` for {}s after {} consecutive failures",
+                COOLDOWN.as_secs(),
+                health.consecutive_failures
+            );
+            health.unavailable_until = Some(Instant::now() + COOLDOWN);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn circuit_breaker() {
+        let health = IndexHealth::default();
+        let url = DisplaySafeUrl::parse("https://mirror.example.com/simple").unwrap();
+        let other = DisplaySafeUrl::parse("https://pypi.org/simple").unwrap();
+
+        for _ in 0..FAILURE_THRESHOLD - 1 {
+            health.record_failure(&url);
+            assert!(health.is_available(&url));
+        }
+        health.record_failure(&url);
+        assert!(!health.is_available(&url));
+        assert!(health.is_available(&other));
+
+        // A success resets the failure count.
+        health.record_success(&url);
+        assert!(health.is_available(&url));
+        health.record_failure(&url);
+        assert!(health.is_available(&url));
+    }
+}
@@ -0,0 +1,165 @@
+use std::time::{Duration, Instant};
+
+use http::StatusCode;
+use http::header::{ACCEPT_RANGES, CONTENT_TYPE};
+use tracing::debug;
+use url::Url;
+
+use uv_distribution_types::{FileLocation, IndexUrl};
+use uv_normalize::PackageName;
+use uv_pypi_types::{CoreMetadata, PypiFile, PypiSimpleDetail};
+use uv_redacted::DisplaySafeUrl;
+use uv_small_str::SmallString;
+
+use crate::html::SimpleDetailHTML;
+use crate::registry_client::MediaType;
+use crate::{Error, ErrorKind, RegistryClient};
+
+/// The result of probing a remote index with [`RegistryClient::ping`].
+#[derive(Debug, Clone)]
+pub struct IndexPing {
+    /// The URL of the project page that was requested.
+    pub url: DisplaySafeUrl,
+    /// The status code of the response.
+    pub status: StatusCode,
+    /// The time until the response headers were received.
+    pub latency: Duration,
+    /// The `Content-Type` of the response, if any.
+    pub content_type: Option<String>,
+    /// Whether the response used a media type supported by the Simple API.
+    pub simple_api: bool,
+    /// Whether the response used the JSON-based Simple API (PEP 691).
+    pub json_api: bool,
+    /// The number of files listed on the project page.
+    pub files: usize,
+    /// The number of listed files that have a metadata file (PEP 658).
+    pub metadata_files: usize,
+    /// Whether the index supports range requests for the listed files, if any were listed.
+    pub range_requests: Option<bool>,
+}
+
+impl IndexPing {
+    /// Returns `true` if the index rejected the request due to missing or invalid credentials.
+    pub fn is_auth_failure(&self) -> bool {
+        matches!(
+            self.status,
+            StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN
+        )
+    }
+}
+
+impl RegistryClient {
+    /// Probe a remote index by requesting the project page for the given package.
+    ///
+    /// Unlike [`RegistryClient::simple_detail`], the response is never cached, and an unsuccessful
+    /// status code is reported rather than returned as an error. Only failures to reach the index
+    /// at all are returned as errors.
+    pub async fn ping(
+        &self,
+        index: &IndexUrl,
+        package_name: &PackageName,
+    ) -> Result<IndexPing, Error> {
+        let url = Self::simple_detail_url(index, package_name)?;
+
+        let start = Instant::now();
+        let response = self
+            .uncached_client(&url)
+            .get(Url::from(url.clone()))
+            .header("Accept", MediaType::pypi())
+            .send()
+            .await
+            .map_err(|err| Error::from_reqwest_middleware(url.clone(), err, start))?;
+        let latency = start.elapsed();
+
+        let status = response.status();
+        let content_type = response
+            .headers()
+            .get(CONTENT_TYPE)
+            .and_then(|content_type| content_type.to_str().ok())
+            .map(ToString::to_string);
+        let media_type = content_type
+            .as_deref()
+            .and_then(|content_type| content_type.split(';').next())
+            .and_then(|media_type| MediaType::from_str(media_type.trim()));
+
+        let mut ping = IndexPing {
+            url: url.clone(),
+            status,
+            latency,
+            content_type,
+            simple_api: media_type.is_some(),
+            json_api: matches!(media_type, Some(MediaType::PypiV1Json)),
+            files: 0,
+            metadata_files: 0,
+            range_requests: None,
+        };
+        if !status.is_success() {
+            return Ok(ping);
+        }
+
+        // Use the response URL, rather than the request URL, as the base for relative URLs.
+        let base = DisplaySafeUrl::from_url(response.url().clone());
+        let (files, base) = match media_type {
+            Some(MediaType::PypiV1Json) => {
+                let bytes = response
+                    .bytes()
+                    .await
+                    .map_err(|err| ErrorKind::from_reqwest(base.clone(), err))?;
+                let data: PypiSimpleDetail = serde_json::from_slice(bytes.as_ref())
+                    .map_err(|err| Error::from_json_err(err, base.clone()))?;
+                (data.files, SmallString::from(base.as_str()))
+            }
+            Some(MediaType::PypiV1Html | MediaType::TextHtml) => {
+                let text = response
+                    .text()
+                    .await
+                    .map_err(|err| ErrorKind::from_reqwest(base.clone(), err))?;
+                let SimpleDetailHTML { base, files, .. } = SimpleDetailHTML::parse(&text, &base)
+                    .map_err(|err| Error::from_html_err(err, base.clone()))?;
+                (files, SmallString::from(base.as_str()))
+            }
+            _ => return Ok(ping),
+        };
+
+        ping.files = files.len();
+        ping.metadata_files = files
+            .iter()
+            .filter(|file| {
+                file.core_metadata
+                    .as_ref()
+                    .is_some_and(CoreMetadata::is_available)
+            })
+            .count();
+        ping.range_requests = match files.first() {
+            Some(file) => Some(self.supports_range_requests(file, &base).await),
+            None => None,
+        };
+
+        Ok(ping)
+    }
+
+    /// Returns `true` if a `HEAD` request for the given file advertises support for byte ranges.
+    async fn supports_range_requests(&self, file: &PypiFile, base: &SmallString) -> bool {
+        let Ok(url) = FileLocation::new(file.url.clone(), base).to_url() else {
+            return false;
+        };
+        let response = match self
+            .uncached_client(&url)
+            .head(Url::from(url.clone()))
+            .header("accept-encoding", "identity")
+            .send()
+            .await
+        {
+            Ok(response) => response,
+            Err(err) => {
+                debug!("Failed to send `HEAD` request for `{url}`: {err}");
+                return false;
+            }
+        };
+        response.status().is_success()
+            && response
+                .headers()
+                .get(ACCEPT_RANGES)
+                .is_some_and(|value| value.as_bytes() == b"bytes")
+    }
+}
@@ -1,19 +1,22 @@
 pub use base_client::{
-    AuthIntegration, BaseClient, BaseClientBuilder, DEFAULT_CONNECT_TIMEOUT, DEFAULT_MAX_REDIRECTS,
-    DEFAULT_READ_TIMEOUT, DEFAULT_READ_TIMEOUT_UPLOAD, DEFAULT_RETRIES, ExtraMiddleware,
-    RedirectClientWithMiddleware, RedirectPolicy, RequestBuilder, RetriableError,
-    RetryParsingError, RetryState, UvRetryableStrategy, fetch_with_url_fallback,
-    retryable_on_request_failure,
+    AuthIntegration, BaseClient, BaseClientBuilder, ClientCertError, DEFAULT_CONNECT_TIMEOUT,
+    DEFAULT_MAX_REDIRECTS, DEFAULT_READ_TIMEOUT, DEFAULT_READ_TIMEOUT_UPLOAD, DEFAULT_RETRIES,
+    DEFAULT_RETRY_MAX_DELAY, DEFAULT_STALL_TIMEOUT, ExtraMiddleware, RedirectClientWithMiddleware,
+    RedirectPolicy, RequestBuilder, RetriableError, RetryParsingError, RetryState,
+    UvRetryableStrategy, fetch_with_url_fallback, retryable_on_request_failure,
 };
 pub use cached_client::{CacheControl, CachedClient, CachedClientError, DataWithCachePolicy};
 pub use error::{Error, ErrorKind, ProblemDetails, WrappedReqwestError};
 pub use flat_index::{FlatIndexClient, FlatIndexEntries, FlatIndexEntry, FlatIndexError};
+pub use index_ping::IndexPing;
 pub use linehaul::LineHaul;
 pub use registry_client::{
     Connectivity, MetadataFormat, RegistryClient, RegistryClientBuilder, SimpleDetailMetadata,
     SimpleDetailMetadatum, SimpleIndexMetadata, VersionFiles,
 };
 pub use rkyvutil::{Deserializer, OwnedArchive, Serializer, Validator};
+pub use simple_html::{SimpleHtmlFile, simple_detail_html, simple_index_html};
+pub use stall::StallDetector;
 
 mod base_client;
 mod cached_client;
@@ -21,9 +24,15 @@ mod error;
 mod flat_index;
 mod html;
 mod httpcache;
+mod index_health;
+mod index_ping;
+mod limiter;
 mod linehaul;
 mod middleware;
+mod mirrors;
 mod registry_client;
 mod remote_metadata;
 mod rkyvutil;
+mod simple_html;
+mod stall;
 mod tls;
@@ -0,0 +1,267 @@
+use std::num::{NonZeroU64, NonZeroUsize};
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+use bytes::Bytes;
+use futures::FutureExt;
+use http::Extensions;
+use http_body::{Body, Frame, SizeHint};
+use reqwest::{Request, Response, ResponseBuilderExt};
+use reqwest_middleware::{Middleware, Next};
+use rustc_hash::FxHashMap;
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+use tokio::time::{Instant, Sleep};
+use tracing::trace;
+
+use uv_distribution_filename::DistExtension;
+
+/// Limits on the bandwidth and the number of concurrent connections per host, shared across all
+/// requests made by clients created from the same builder.
+#[derive(Debug)]
+pub(crate) struct RequestLimiter {
+    rate: Option<RateLimiter>,
+    max_connections_per_host: Option<NonZeroUsize>,
+    hosts: Mutex<FxHashMap<String, Arc<Semaphore>>>,
+}
+
+impl RequestLimiter {
+    /// Create a [`RequestLimiter`], or `None` if neither limit is set.
+    pub(crate) fn new(
+        limit_rate: Option<NonZeroU64>,
+        max_connections_per_host: Option<NonZeroUsize>,
+    ) -> Option<Self> {
+        if limit_rate.is_none() && max_connections_per_host.is_none() {
+            return None;
+        }
+        Some(Self {
+            rate: limit_rate.map(RateLimiter::new),
+            max_connections_per_host,
+            hosts: Mutex::default(),
+        })
+    }
+
+    /// Wait for a connection slot for the given host, if the number of connections is limited.
+    async fn acquire(&self, host: &str) -> Option<OwnedSemaphorePermit> {
+        let max_connections = self.max_connections_per_host?;
+        let semaphore = self
+            .hosts
+            .lock()
+            .unwrap()
+            .entry(host.to_string())
+            .or_insert_with(|| Arc::new(Semaphore::new(max_connections.get())))
+            .clone();
+        if semaphore.available_permits() == 0 {
+            trace!("Waiting for a connection slot for `{host}`");
+        }
+        semaphore.acquire_owned().await.ok()
+    }
+}
+
+/// A token bucket that allows bursts of up to one second of traffic.
+#[derive(Debug)]
+struct RateLimiter {
+    /// The number of bytes per second.
+    rate: NonZeroU64,
+    state: Mutex<RateLimiterState>,
+}
+
+#[derive(Debug)]
+struct RateLimiterState {
+    /// The number of bytes that may be received before waiting; negative if over budget.
+    available: f64,
+    updated: Instant,
+}
+
+#[expect(clippy::cast_precision_loss)]
+impl RateLimiter {
+    fn new(rate: NonZeroU64) -> Self {
+        Self {
+            rate,
+            state: Mutex::new(RateLimiterState {
+                available: rate.get() as f64,
+                updated: Instant::now(),
+            }),
+        }
+    }
+
+    /// Record that `bytes` were received, returning how long to wait before receiving more.
+    fn consume(&self, bytes: usize) -> Duration {
+        let rate = self.rate.get() as f64;
+        let mut state = self.state.lock().unwrap();
+        let now = Instant::now();
+        let elapsed = now.duration_since(state.updated).as_secs_f64();
+        state.available = (state.available + elapsed * rate).min(rate) - bytes as f64;
+        state.updated = now;
+        if state.available < 0.0 {
+            Duration::from_secs_f64(-state.available / rate)
+        } else {
+            Duration::ZERO
+        }
+    }
+}
+
+/// A middleware that applies the [`RequestLimiter`] to requests and their response bodies.
+pub(crate) struct LimiterMiddleware(pub(crate) Arc<RequestLimiter>);
+
+#[async_trait::async_trait]
+impl Middleware for LimiterMiddleware {
+    async fn handle(
+        &self,
+        req: Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> reqwest_middleware::Result<Response> {
+        // Only archive downloads hold their connection slot until the body is received. Other
+        // responses (e.g., Simple API pages and metadata) may be held unread while further
+        // requests are made to the same host, which would deadlock once the host is at capacity.
+        let is_download = DistExtension::from_path(req.url().path()).is_ok();
+        let permit = match req.url().host_str() {
+            Some(host) => self.0.acquire(host).await,
+            None => None,
+        };
+        let response = next.run(req, extensions).await?;
+        let permit = permit.filter(|_| is_download);
+        Ok(LimitedBody::wrap(response, permit, self.0.clone()))
+    }
+}
+
+/// A response body that holds a connection slot, if any, until it's fully received, and throttles
+/// the received bytes to the configured rate.
+struct LimitedBody {
+    inner: reqwest::Body,
+    permit: Option<OwnedSemaphorePermit>,
+    limiter: Arc<RequestLimiter>,
+    /// The frame to return once the rate limit allows, and the deadline for returning it.
+    delayed: Option<(Frame<Bytes>, Pin<Box<Sleep>>)>,
+}
+
+impl LimitedBody {
+    /// Wrap the body of the given response.
+    fn wrap(
+        response: Response,
+        permit: Option<OwnedSemaphorePermit>,
+        limiter: Arc<RequestLimiter>,
+    ) -> Response {
+        let url = response.url().clone();
+        let (mut parts, inner) = http::Response::<reqwest::Body>::from(response).into_parts();
+
+        // Release the connection slot immediately if there's no body to receive.
+        let permit = permit.filter(|_| !inner.is_end_stream());
+
+        // Converting to an `http::Response` drops the URL, so restore it.
+        if let Ok(response) = http::Response::builder().url(url).body(()) {
+            parts.extensions.extend(response.into_parts().0.extensions);
+        }
+
+        let body = Self {
+            inner,
+            permit,
+            limiter,
+            delayed: None,
+        };
+        Response::from(http::Response::from_parts(parts, reqwest::Body::wrap(body)))
+    }
+}
+
+impl Body for LimitedBody {
+    type Data = Bytes;
+    type Error = reqwest::Error;
+
+    fn poll_frame(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+        let this = &mut *self;
+        if let Some((_, sleep)) = &mut this.delayed {
+            if sleep.poll_unpin(cx).is_pending() {
+                return Poll::Pending;
+            }
+        }
+        if let Some((frame, _)) = this.delayed.take() {
+            return Poll::Ready(Some(Ok(frame)));
+        }
+
+        match Pin::new(&mut this.inner).poll_frame(cx) {
+            Poll::Ready(Some(Ok(frame))) => {
+                let Some(rate) = &this.limiter.rate else {
+                    return Poll::Ready(Some(Ok(frame)));
+                };
+                let delay = rate.consume(frame.data_ref().map_or(0, Bytes::len));
+                if delay.is_zero() {
+                    return Poll::Ready(Some(Ok(frame)));
+                }
+                let mut sleep = Box::pin(tokio::time::sleep(delay));
+                if sleep.poll_unpin(cx).is_ready() {
+                    return Poll::Ready(Some(Ok(frame)));
+                }
+                this.delayed = Some((frame, sleep));
+                Poll::Pending
+            }
+            Poll::Ready(item) => {
+                // Release the connection slot once the body is complete, or failed.
+                this.permit.take();
+                Poll::Ready(item)
+            }
+            Poll::Pending => Poll::Pending,
+        }
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.delayed.is_none() && self.inner.is_end_stream()
+    }
+
+    fn size_hint(&self) -> SizeHint {
+        let mut size_hint = self.inner.size_hint();
+        if let Some(len) = self
+            .delayed
+            .as_ref()
+            .and_then(|(frame, _)| frame.data_ref())
+            .map(|data| data.len() as u64)
+        {
+            if let Some(upper) = size_hint.upper() {
+                size_hint.set_upper(upper + len);
+            }
+            size_hint.set_lower(size_hint.lower() + len);
+        }
+        size_hint
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rate_limiter() {
+        let limiter = RateLimiter::new(NonZeroU64::new(1000).unwrap());
+
+        // The first second of traffic is allowed immediately.
+        assert_eq!(limiter.consume(1000), Duration::ZERO);
+
+        // Further bytes are delayed according to the rate.
+        let delay = limiter.consume(500);
+        assert!(delay > Duration::from_millis(490) && delay <= Duration::from_millis(500));
+
+        // The budget refills over time.
+        limiter.state.lock().unwrap().updated -= Duration::from_millis(1500);
+        assert_eq!(limiter.consume(1000), Duration::ZERO);
+    }
+
+    #[tokio::test]
+    async fn connections_per_host() {
+        let limiter = RequestLimiter::new(None, NonZeroUsize::new(1)).unwrap();
+
+        let permit = limiter.acquire("example.com").await;
+        assert!(permit.is_some());
+
+        // Other hosts are not affected.
+        assert!(limiter.acquire("example.org").await.is_some());
+
+        // The host is at capacity until the first permit is released.
+        assert!(limiter.acquire("example.com").now_or_never().is_none());
+        drop(permit);
+        assert!(limiter.acquire("example.com").now_or_never().is_some());
+    }
+}
@@ -1,6 +1,8 @@
+use std::borrow::Cow;
 use std::collections::BTreeMap;
 use std::fmt::Debug;
-use std::path::PathBuf;
+use std::io;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 use std::sync::Arc;
 use std::time::Duration;
@@ -11,19 +13,26 @@ use http::{HeaderMap, StatusCode};
 use itertools::Either;
 use reqwest::{Proxy, Response};
 use rustc_hash::FxHashMap;
+use tokio::io::AsyncWriteExt;
 use tokio::sync::{Mutex, Semaphore};
 use tracing::{Instrument, debug, info_span, instrument, trace, warn};
 use url::Url;
 
+use uv_attestation::AttestationPolicy;
 use uv_auth::{CredentialsCache, Indexes, PyxTokenStore};
-use uv_cache::{Cache, CacheBucket, CacheEntry, WheelCache};
+use uv_cache::{Cache, CacheBucket, CacheEntry, Freshness, WheelCache};
 use uv_configuration::IndexStrategy;
 use uv_configuration::KeyringProviderType;
-use uv_distribution_filename::{DistFilename, SourceDistFilename, WheelFilename};
+use uv_distribution_filename::{
+    DistFilename, SourceDistFilename, WheelFilename, normalize_remote_filename,
+};
 use uv_distribution_types::{
-    BuiltDist, File, IndexCapabilities, IndexFormat, IndexLocations, IndexMetadataRef,
-    IndexStatusCodeDecision, IndexStatusCodeStrategy, IndexUrl, IndexUrls, Name,
+    BuiltDist, File, FileLocation, IndexCapabilities, IndexFormat, IndexLocations,
+    IndexMetadataRef, IndexStatusCodeDecision, IndexStatusCodeStrategy, IndexUrl, IndexUrls, Name,
+    StaleMetadata,
 };
+use uv_extract::hash::Hasher;
+use uv_fs::Simplified;
 use uv_metadata::{read_metadata_async_seek, read_metadata_async_stream};
 use uv_normalize::PackageName;
 use uv_pep440::Version;
@@ -31,18 +40,22 @@ use uv_pep508::MarkerEnvironment;
 use uv_platform_tags::Platform;
 use uv_pypi_types::ProjectStatus;
 use uv_pypi_types::{
-    PypiSimpleDetail, PypiSimpleIndex, PyxSimpleDetail, PyxSimpleIndex, ResolutionMetadata,
+    HashDigest, PypiSimpleDetail, PypiSimpleIndex, PyxSimpleDetail, PyxSimpleIndex,
+    ResolutionMetadata,
 };
 use uv_redacted::DisplaySafeUrl;
 use uv_small_str::SmallString;
 use uv_torch::TorchStrategy;
+use uv_warnings::{warn_user, warn_user_once};
 
 use crate::base_client::{BaseClientBuilder, ExtraMiddleware, RedirectPolicy};
 use crate::cached_client::CacheControl;
 use crate::flat_index::FlatIndexEntry;
 use crate::html::SimpleDetailHTML;
+use crate::mirrors::IndexMirrors;
 use crate::remote_metadata::wheel_metadata_from_remote_zip;
 use crate::rkyvutil::OwnedArchive;
+use crate::stall::StallDetector;
 use crate::{
     BaseClient, CachedClient, Error, ErrorKind, FlatIndexClient, FlatIndexEntries,
     RedirectClientWithMiddleware,
@@ -161,12 +174,15 @@ impl<'a> RegistryClientBuilder<'a> {
                         .map(ToString::to_string)
                         .unwrap_or_else(|| index.url.to_string())
                 );
-                if let Some(root_url) = index.root_url() {
+                // Mirrors serve the same content as the index, and so share its credentials.
+                for url in std::iter::once(&index.url).chain(index.mirrors()) {
+                    if let Some(root_url) = url.root() {
+                        self.base_client_builder
+                            .store_credentials(&root_url, credentials.clone());
+                    }
                     self.base_client_builder
-                        .store_credentials(&root_url, credentials.clone());
+                        .store_credentials(url.url(), credentials.clone());
                 }
-                self.base_client_builder
-                    .store_credentials(index.raw_url(), credentials);
             }
         }
     }
@@ -174,11 +190,24 @@ impl<'a> RegistryClientBuilder<'a> {
     pub fn build(mut self) -> RegistryClient {
         self.cache_index_credentials();
         let index_urls = self.index_locations.index_urls();
+        let allow_index_failure = self.base_client_builder.is_allow_index_failure();
+        let index_cache_control = self
+            .base_client_builder
+            .get_index_cache_ttl()
+            .map(|ttl| format!("max-age={}", ttl.as_secs()));
+        let index_stale_if_error = self.base_client_builder.is_index_stale_if_error();
+        let stale_metadata_report = self
+            .base_client_builder
+            .get_stale_metadata_report()
+            .map(Path::to_path_buf);
+        let attestation_policy = self.base_client_builder.get_attestation_policy().clone();
 
         // Build a base client
         let builder = self
             .base_client_builder
             .indexes(Indexes::from(&self.index_locations))
+            .index_ca_bundles(self.index_locations.ca_bundles())
+            .index_mirrors(IndexMirrors::from_index_locations(&self.index_locations))
             .redirect(RedirectPolicy::RetriggerMiddleware);
 
         let client = builder.build();
@@ -198,6 +227,12 @@ impl<'a> RegistryClientBuilder<'a> {
             client,
             read_timeout,
             flat_indexes: Arc::default(),
+            simple_indexes: Arc::default(),
+            allow_index_failure,
+            index_cache_control,
+            index_stale_if_error,
+            stale_metadata_report,
+            attestation_policy,
             pyx_token_store: PyxTokenStore::from_settings().ok(),
         }
     }
@@ -206,11 +241,24 @@ impl<'a> RegistryClientBuilder<'a> {
     pub fn wrap_existing(mut self, existing: &BaseClient) -> RegistryClient {
         self.cache_index_credentials();
         let index_urls = self.index_locations.index_urls();
+        let allow_index_failure = self.base_client_builder.is_allow_index_failure();
+        let index_cache_control = self
+            .base_client_builder
+            .get_index_cache_ttl()
+            .map(|ttl| format!("max-age={}", ttl.as_secs()));
+        let index_stale_if_error = self.base_client_builder.is_index_stale_if_error();
+        let stale_metadata_report = self
+            .base_client_builder
+            .get_stale_metadata_report()
+            .map(Path::to_path_buf);
+        let attestation_policy = self.base_client_builder.get_attestation_policy().clone();
 
         // Wrap in any relevant middleware and handle connectivity.
         let client = self
             .base_client_builder
             .indexes(Indexes::from(&self.index_locations))
+            .index_ca_bundles(self.index_locations.ca_bundles())
+            .index_mirrors(IndexMirrors::from_index_locations(&self.index_locations))
             .wrap_existing(existing);
 
         let read_timeout = client.read_timeout();
@@ -228,6 +276,12 @@ impl<'a> RegistryClientBuilder<'a> {
             client,
             read_timeout,
             flat_indexes: Arc::default(),
+            simple_indexes: Arc::default(),
+            allow_index_failure,
+            index_cache_control,
+            index_stale_if_error,
+            stale_metadata_report,
+            attestation_policy,
             pyx_token_store: PyxTokenStore::from_settings().ok(),
         }
     }
@@ -252,6 +306,19 @@ pub struct RegistryClient {
     read_timeout: Duration,
     /// The flat index entries for each `--find-links`-style index URL.
     flat_indexes: Arc<Mutex<FlatIndexCache>>,
+    /// The list of projects available on each Simple API index URL.
+    simple_indexes: Arc<Mutex<FxHashMap<IndexUrl, Arc<SimpleIndexMetadata>>>>,
+    /// Whether to treat unreachable non-default indexes as empty.
+    allow_index_failure: bool,
+    /// The `Cache-Control` header to apply to Simple API responses, derived from the configured
+    /// cache TTL.
+    index_cache_control: Option<String>,
+    /// Whether to fall back to stale cached Simple API responses when an index is unreachable.
+    index_stale_if_error: bool,
+    /// The file to which to append a record of each distribution with stale index metadata.
+    stale_metadata_report: Option<PathBuf>,
+    /// The policy for verifying the attestations of registry distributions.
+    attestation_policy: AttestationPolicy,
     /// The pyx token store to use for persistent credentials.
     // TODO(charlie): The token store is only needed for `is_known_url`; can we avoid storing it here?
     pyx_token_store: Option<PyxTokenStore>,
@@ -287,11 +354,21 @@ impl RegistryClient {
         self.connectivity
     }
 
+    /// Return the policy for verifying the attestations of registry distributions.
+    pub fn attestation_policy(&self) -> &AttestationPolicy {
+        &self.attestation_policy
+    }
+
     /// Return the timeout this client is configured with, in seconds.
     pub fn read_timeout(&self) -> Duration {
         self.read_timeout
     }
 
+    /// Return the timeout after which a stalled download is aborted, if any.
+    pub fn stall_timeout(&self) -> Option<Duration> {
+        self.client.uncached().stall_timeout()
+    }
+
     pub fn credentials_cache(&self) -> &CredentialsCache {
         self.client.uncached().credentials_cache()
     }
@@ -504,15 +581,7 @@ impl RegistryClient {
         capabilities: &IndexCapabilities,
         status_code_strategy: &IndexStatusCodeStrategy,
     ) -> Result<SimpleMetadataSearchOutcome, Error> {
-        // Format the URL for PyPI.
-        let mut url = index.url().clone();
-        url.path_segments_mut()
-            .map_err(|()| ErrorKind::CannotBeABase(index.url().clone()))?
-            .pop_if_empty()
-            .push(package_name.as_ref())
-            // The URL *must* end in a trailing slash for proper relative path behavior
-            // ref https://github.com/servo/rust-url/issues/333
-            .push("");
+        let url = Self::simple_detail_url(index, package_name)?;
 
         trace!("Fetching metadata for {package_name} from {url}");
 
@@ -521,20 +590,8 @@ impl RegistryClient {
             WheelCache::Index(index).root(),
             format!("{package_name}.rkyv"),
         );
-        let cache_control = match self.connectivity {
-            Connectivity::Online => {
-                if let Some(header) = self.index_urls.simple_api_cache_control_for(index) {
-                    CacheControl::Override(header)
-                } else {
-                    CacheControl::from(
-                        self.cache
-                            .freshness(&cache_entry, Some(package_name), None)
-                            .map_err(ErrorKind::Io)?,
-                    )
-                }
-            }
-            Connectivity::Offline => CacheControl::AllowStale,
-        };
+        let cache_control =
+            self.simple_api_cache_control(index, &cache_entry, Some(package_name))?;
 
         // Acquire an advisory lock, to guard against concurrent writes.
         #[cfg(windows)]
@@ -550,8 +607,46 @@ impl RegistryClient {
                 .await
         };
 
+        // If the index is unreachable, fall back to a stale cached response, if requested.
+        let result = match result {
+            Err(err)
+                if self.index_stale_if_error
+                    && err.is_unreachable()
+                    && cache_entry.path().is_file() =>
+            {
+                debug!("Failed to fetch metadata for {package_name} from {url}: {err}");
+                warn_user_once!(
+                    "Index `{}` is unreachable; using stale cached responses",
+                    index.without_credentials()
+                );
+                self.fetch_remote_simple_detail(
+                    package_name,
+                    &url,
+                    index,
+                    &cache_entry,
+                    CacheControl::AllowStale,
+                )
+                .await
+            }
+            result => result,
+        };
+
         match result {
             Ok(metadata) => Ok(SimpleMetadataSearchOutcome::Found(metadata)),
+            // The index is unreachable, but the user opted to continue without it.
+            Err(err)
+                if self.allow_index_failure
+                    && err.is_unreachable()
+                    && !self.index_urls.is_default_index(index) =>
+            {
+                debug!("Failed to fetch metadata for {package_name} from {url}: {err}");
+                warn_user_once!(
+                    "Index `{}` is unreachable and will be treated as empty",
+                    index.without_credentials()
+                );
+                capabilities.set_unreachable(index.clone());
+                Ok(SimpleMetadataSearchOutcome::NotFound)
+            }
             Err(err) => match err.kind() {
                 // The package could not be found in the remote index.
                 ErrorKind::WrappedReqwestError(.., reqwest_err) => {
@@ -582,6 +677,53 @@ impl RegistryClient {
         }
     }
 
+    /// Determine the [`CacheControl`] policy for a Simple API request to the given index.
+    ///
+    /// A `cache-control` setting on the index takes precedence over the configured cache TTL,
+    /// which in turn takes precedence over the `Cache-Control` header returned by the index. The
+    /// TTL is ignored if the entry must be revalidated (e.g., with `--refresh`).
+    fn simple_api_cache_control<'a>(
+        &'a self,
+        index: &'a IndexUrl,
+        cache_entry: &CacheEntry,
+        package_name: Option<&PackageName>,
+    ) -> Result<CacheControl<'a>, Error> {
+        match self.connectivity {
+            Connectivity::Online => {
+                if let Some(header) = self.index_urls.simple_api_cache_control_for(index) {
+                    return Ok(CacheControl::Override(header));
+                }
+                let freshness = self
+                    .cache
+                    .freshness(cache_entry, package_name, None)
+                    .map_err(ErrorKind::Io)?;
+                match (&self.index_cache_control, freshness) {
+                    (Some(header), Freshness::Fresh | Freshness::Missing) => {
+                        Ok(CacheControl::Override(header))
+                    }
+                    _ => Ok(CacheControl::from(freshness)),
+                }
+            }
+            Connectivity::Offline => Ok(CacheControl::AllowStale),
+        }
+    }
+
+    /// Format the Simple API URL for a package on the given index.
+    pub(crate) fn simple_detail_url(
+        index: &IndexUrl,
+        package_name: &PackageName,
+    ) -> Result<DisplaySafeUrl, Error> {
+        let mut url = index.url().clone();
+        url.path_segments_mut()
+            .map_err(|()| ErrorKind::CannotBeABase(index.url().clone()))?
+            .pop_if_empty()
+            .push(package_name.as_ref())
+            // The URL *must* end in a trailing slash for proper relative path behavior
+            // ref https://github.com/servo/rust-url/issues/333
+            .push("");
+        Ok(url)
+    }
+
     /// Fetch the [`SimpleDetailMetadata`] from a remote URL, using the PEP 503 Simple Repository API.
     async fn fetch_remote_simple_detail(
         &self,
@@ -612,10 +754,18 @@ impl RegistryClient {
             .map_err(|err| ErrorKind::from_reqwest(url.clone(), err))?;
         let parse_simple_response = |response: Response| {
             async {
+                let redirected = response.url().as_str() != url.as_str();
+
                 // Use the response URL, rather than the request URL, as the base for relative URLs.
                 // This ensures that we handle redirects and other URL transformations correctly.
                 let url = DisplaySafeUrl::from_url(response.url().clone());
 
+                // If the index redirected to a different project page (e.g., from the normalized
+                // name to a legacy name), check that it refers to the same project.
+                if redirected && let Some(served) = served_project_name(&url) {
+                    check_served_name(package_name, served, &url);
+                }
+
                 let content_type = response
                     .headers()
                     .get("content-type")
@@ -672,9 +822,12 @@ impl RegistryClient {
 
                         let data: PypiSimpleDetail = serde_json::from_slice(bytes.as_ref())
                             .map_err(|err| Error::from_json_err(err, url.clone()))?;
+                        if let Some(served) = &data.name {
+                            check_served_name(package_name, served, &url);
+                        }
 
                         SimpleDetailMetadata::from_pypi_files(
-                            data.files,
+                            normalize_filenames(data.files, package_name, &url),
                             package_name,
                             data.project_status,
                             &url,
@@ -712,10 +865,26 @@ impl RegistryClient {
         package_name: &PackageName,
         url: &DisplaySafeUrl,
     ) -> Result<OwnedArchive<SimpleDetailMetadata>, Error> {
-        let path = url
+        let mut directory = url
             .to_file_path()
-            .map_err(|()| ErrorKind::NonFileUrl(url.clone()))?
-            .join("index.html");
+            .map_err(|()| ErrorKind::NonFileUrl(url.clone()))?;
+
+        // Local indexes with legacy project names may use a non-normalized directory name (e.g.,
+        // `Foo_Bar` rather than `foo-bar`).
+        let mut url = Cow::Borrowed(url);
+        if !directory.is_dir()
+            && let Some((legacy_directory, legacy_url)) =
+                find_local_project_directory(&directory, package_name)
+        {
+            debug!(
+                "Using non-normalized directory `{}` for {package_name}",
+                legacy_directory.user_display()
+            );
+            directory = legacy_directory;
+            url = Cow::Owned(legacy_url);
+        }
+
+        let path = directory.join("index.html");
         let text = match fs_err::tokio::read_to_string(&path).await {
             Ok(text) => text,
             Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
@@ -727,7 +896,7 @@ impl RegistryClient {
                 return Err(Error::from(ErrorKind::Io(err)));
             }
         };
-        let metadata = SimpleDetailMetadata::from_html(&text, package_name, url)?;
+        let metadata = SimpleDetailMetadata::from_html(&text, package_name, &url)?;
         OwnedArchive::from_unarchived(&metadata)
     }
 
@@ -757,6 +926,53 @@ impl RegistryClient {
         }
     }
 
+    /// Fetch the list of projects from each Simple API index that would be queried for the given
+    /// package, e.g., to suggest similarly-named packages when a package can't be found.
+    ///
+    /// The project lists are cached in memory, such that each index is fetched at most once.
+    /// Indexes that fail to return a project list (e.g., because they don't serve the root of the
+    /// Simple API) are skipped.
+    pub async fn simple_index_projects<'index>(
+        &'index self,
+        package_name: &PackageName,
+        index: Option<IndexMetadataRef<'index>>,
+    ) -> Vec<Arc<SimpleIndexMetadata>> {
+        if self.index_urls.no_index() {
+            return Vec::new();
+        }
+
+        let indexes = if let Some(index) = index {
+            Either::Left(std::iter::once(index))
+        } else {
+            Either::Right(self.index_urls_for(package_name))
+        };
+
+        let mut cache = self.simple_indexes.lock().await;
+        let mut results = Vec::new();
+        for index in indexes {
+            if !matches!(index.format, IndexFormat::Simple) {
+                continue;
+            }
+            if let Some(metadata) = cache.get(index.url) {
+                results.push(metadata.clone());
+                continue;
+            }
+            let metadata = match self.fetch_simple_index(index.url).await {
+                Ok(metadata) => Arc::new(metadata),
+                Err(err) => {
+                    debug!(
+                        "Failed to fetch the list of projects from {}: {err}",
+                        index.url
+                    );
+                    Arc::default()
+                }
+            };
+            cache.insert(index.url.clone(), metadata.clone());
+            results.push(metadata);
+        }
+        results
+    }
+
     /// Fetch the list of projects from a remote Simple API index.
     async fn fetch_remote_simple_index(
         &self,
@@ -781,20 +997,7 @@ impl RegistryClient {
             WheelCache::Index(index).root(),
             "index.html.rkyv",
         );
-        let cache_control = match self.connectivity {
-            Connectivity::Online => {
-                if let Some(header) = self.index_urls.simple_api_cache_control_for(index) {
-                    CacheControl::Override(header)
-                } else {
-                    CacheControl::from(
-                        self.cache
-                            .freshness(&cache_entry, None, None)
-                            .map_err(ErrorKind::Io)?,
-                    )
-                }
-            }
-            Connectivity::Offline => CacheControl::AllowStale,
-        };
+        let cache_control = self.simple_api_cache_control(index, &cache_entry, None)?;
 
         let parse_simple_response = |response: Response| {
             async {
@@ -1008,78 +1211,144 @@ impl RegistryClient {
         url: &DisplaySafeUrl,
         capabilities: &IndexCapabilities,
     ) -> Result<ResolutionMetadata, Error> {
-        // If the metadata file is available at its own url (PEP 658), download it from there.
         let filename = WheelFilename::from_str(&file.filename).map_err(ErrorKind::WheelFilename)?;
-        if file.dist_info_metadata {
-            let mut url = url.clone();
-            let path = format!("{}.metadata", url.path());
-            url.set_path(&path);
-
-            let cache_entry = self.cache.entry(
-                CacheBucket::Wheels,
-                WheelCache::Index(index).wheel_dir(filename.name.as_ref()),
-                format!("{}.msgpack", filename.cache_key()),
-            );
-            let cache_control = match self.connectivity {
-                Connectivity::Online => {
-                    if let Some(header) = self.index_urls.artifact_cache_control_for(index) {
-                        CacheControl::Override(header)
-                    } else {
-                        CacheControl::from(
-                            self.cache
-                                .freshness(&cache_entry, Some(&filename.name), None)
-                                .map_err(ErrorKind::Io)?,
-                        )
+
+        // If the metadata file is available at its own url (PEP 658), download it from there,
+        // unless the index already served stale metadata for this file.
+        if file.dist_info_metadata && !capabilities.has_stale_metadata(index, &file.filename) {
+            match self
+                .wheel_metadata_pep658(index, file, &filename, url)
+                .await
+            {
+                Ok(metadata) => return Ok(metadata),
+                // If the metadata file doesn't match the hash advertised by the index, record it
+                // and fall back to reading the metadata from the wheel itself.
+                Err(err) => {
+                    let ErrorKind::MetadataHashMismatch {
+                        url,
+                        expected,
+                        actual,
+                        ..
+                    } = err.kind()
+                    else {
+                        return Err(err);
+                    };
+                    let stale_metadata = StaleMetadata {
+                        index: index.clone(),
+                        filename: file.filename.to_string(),
+                        url: url.clone(),
+                        expected: expected.clone(),
+                        actual: actual.clone(),
+                    };
+                    warn_user!(
+                        "{stale_metadata}; falling back to reading the metadata from the wheel (consider reporting this to the index operator)"
+                    );
+                    capabilities.set_stale_metadata(&stale_metadata);
+                    if let Some(report) = &self.stale_metadata_report
+                        && let Err(err) = write_stale_metadata(report, &stale_metadata).await
+                    {
+                        warn_user!(
+                            "Failed to write stale metadata report to `{}`: {err}",
+                            report.user_display()
+                        );
                     }
                 }
-                Connectivity::Offline => CacheControl::AllowStale,
-            };
+            }
+        }
 
-            // Acquire an advisory lock, to guard against concurrent writes.
-            #[cfg(windows)]
-            let _lock = {
-                let lock_entry = cache_entry.with_file(format!("{}.lock", filename.stem()));
-                lock_entry.lock().await.map_err(ErrorKind::CacheLock)?
-            };
+        // If we lack PEP 658 support, try using HTTP range requests to read only the
+        // `.dist-info/METADATA` file from the zip, and if that also fails, download the whole wheel
+        // into the cache and read from there
+        self.wheel_metadata_no_pep658(
+            &filename,
+            url,
+            Some(index),
+            WheelCache::Index(index),
+            capabilities,
+        )
+        .await
+    }
+
+    /// Fetch the metadata for a wheel from its [PEP 658](https://peps.python.org/pep-0658/)
+    /// metadata file, validating it against the hash advertised by the index (if any).
+    async fn wheel_metadata_pep658(
+        &self,
+        index: &IndexUrl,
+        file: &File,
+        filename: &WheelFilename,
+        url: &DisplaySafeUrl,
+    ) -> Result<ResolutionMetadata, Error> {
+        let mut url = url.clone();
+        let path = format!("{}.metadata", url.path());
+        url.set_path(&path);
 
-            let response_callback = async |response: Response| {
-                let bytes = response
-                    .bytes()
-                    .await
-                    .map_err(|err| ErrorKind::from_reqwest(url.clone(), err))?;
+        let cache_entry = self.cache.entry(
+            CacheBucket::Wheels,
+            WheelCache::Index(index).wheel_dir(filename.name.as_ref()),
+            format!("{}.msgpack", filename.cache_key()),
+        );
+        let cache_control = match self.connectivity {
+            Connectivity::Online => {
+                if let Some(header) = self.index_urls.artifact_cache_control_for(index) {
+                    CacheControl::Override(header)
+                } else {
+                    CacheControl::from(
+                        self.cache
+                            .freshness(&cache_entry, Some(&filename.name), None)
+                            .map_err(ErrorKind::Io)?,
+                    )
+                }
+            }
+            Connectivity::Offline => CacheControl::AllowStale,
+        };
 
-                info_span!("parse_metadata21")
-                    .in_scope(|| ResolutionMetadata::parse_metadata(bytes.as_ref()))
-                    .map_err(|err| {
-                        Error::from(ErrorKind::MetadataParseError(
-                            filename.clone(),
-                            url.to_string(),
-                            Box::new(err),
-                        ))
-                    })
-            };
-            let req = self
-                .uncached_client(&url)
-                .get(Url::from(url.clone()))
-                .build()
+        // Acquire an advisory lock, to guard against concurrent writes.
+        #[cfg(windows)]
+        let _lock = {
+            let lock_entry = cache_entry.with_file(format!("{}.lock", filename.stem()));
+            lock_entry.lock().await.map_err(ErrorKind::CacheLock)?
+        };
+
+        let response_callback = async |response: Response| {
+            let bytes = response
+                .bytes()
+                .await
                 .map_err(|err| ErrorKind::from_reqwest(url.clone(), err))?;
-            Ok(self
-                .cached_client()
-                .get_serde_with_retry(req, &cache_entry, cache_control, response_callback)
-                .await?)
-        } else {
-            // If we lack PEP 658 support, try using HTTP range requests to read only the
-            // `.dist-info/METADATA` file from the zip, and if that also fails, download the whole wheel
-            // into the cache and read from there
-            self.wheel_metadata_no_pep658(
-                &filename,
-                url,
-                Some(index),
-                WheelCache::Index(index),
-                capabilities,
-            )
-            .await
-        }
+
+            // Validate the metadata file against the strongest hash advertised by the index.
+            if let Some(expected) = file.dist_info_metadata_hashes.first() {
+                let mut hasher = Hasher::from(expected.algorithm());
+                hasher.update(bytes.as_ref());
+                let actual = HashDigest::from(hasher);
+                if !actual.digest.eq_ignore_ascii_case(&expected.digest) {
+                    return Err(Error::from(ErrorKind::MetadataHashMismatch {
+                        filename: filename.clone(),
+                        url: url.clone(),
+                        expected: expected.clone(),
+                        actual,
+                    }));
+                }
+            }
+
+            info_span!("parse_metadata21")
+                .in_scope(|| ResolutionMetadata::parse_metadata(bytes.as_ref()))
+                .map_err(|err| {
+                    Error::from(ErrorKind::MetadataParseError(
+                        filename.clone(),
+                        url.to_string(),
+                        Box::new(err),
+                    ))
+                })
+        };
+        let req = self
+            .uncached_client(&url)
+            .get(Url::from(url.clone()))
+            .build()
+            .map_err(|err| ErrorKind::from_reqwest(url.clone(), err))?;
+        Ok(self
+            .cached_client()
+            .get_serde_with_retry(req, &cache_entry, cache_control, response_callback)
+            .await?)
     }
 
     /// Get the wheel metadata if it isn't available in an index through PEP 658
@@ -1217,10 +1486,11 @@ impl RegistryClient {
         // Stream the file, searching for the METADATA.
         let read_metadata_stream = |response: Response| {
             async {
-                let reader = response
+                let stream = response
                     .bytes_stream()
-                    .map_err(|err| self.handle_response_errors(err))
-                    .into_async_read();
+                    .map_err(|err| self.handle_response_errors(err));
+                let reader =
+                    StallDetector::new(stream, url.clone(), self.stall_timeout()).into_async_read();
 
                 read_metadata_async_stream(filename, url.as_ref(), reader)
                     .await
@@ -1416,7 +1686,11 @@ impl SimpleDetailMetadata {
         for file in files {
             let Some(filename) = DistFilename::try_from_filename(&file.filename, package_name)
             else {
-                debug!("Skipping file for {package_name}: {}", file.filename);
+                report_skipped_file(
+                    &file.filename,
+                    &file_url(&FileLocation::new(file.url.clone(), &base)),
+                    package_name,
+                );
                 continue;
             };
             let file = match File::try_from_pypi(file, &base) {
@@ -1476,7 +1750,7 @@ impl SimpleDetailMetadata {
             };
             let Some(filename) = DistFilename::try_from_filename(&file.filename, package_name)
             else {
-                debug!("Skipping file for {package_name}: {}", file.filename);
+                report_skipped_file(&file.filename, &file_url(&file.url), package_name);
                 continue;
             };
             match version_map.entry(filename.version().clone()) {
@@ -1539,6 +1813,142 @@ impl SimpleDetailMetadata {
     }
 }
 
+/// Find the directory for a project in a local index that uses a non-normalized project name.
+///
+/// Returns the directory alongside its URL, for use as the base for relative links.
+fn find_local_project_directory(
+    directory: &Path,
+    package_name: &PackageName,
+) -> Option<(PathBuf, DisplaySafeUrl)> {
+    let index = directory.parent()?;
+    let entry = fs_err::read_dir(index)
+        .ok()?
+        .filter_map(Result::ok)
+        .find(|entry| {
+            entry.file_type().is_ok_and(|file_type| file_type.is_dir())
+                && entry
+                    .file_name()
+                    .to_str()
+                    .and_then(|name| PackageName::from_str(name).ok())
+                    .is_some_and(|name| name == *package_name)
+        })?;
+    let url = Url::from_directory_path(entry.path()).ok()?;
+    Some((entry.path(), DisplaySafeUrl::from_url(url)))
+}
+
+/// Append a [`StaleMetadata`] record to the given report, as a line of JSON.
+async fn write_stale_metadata(report: &Path, stale_metadata: &StaleMetadata) -> io::Result<()> {
+    let mut line = serde_json::to_vec(stale_metadata)?;
+    line.push(b'\n');
+    let mut file = fs_err::tokio::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(report)
+        .await?;
+    file.write_all(&line).await
+}
+
+/// Return the project name from the URL of a Simple API project page.
+///
+/// Project pages are directories (e.g., `https://example.com/simple/foo-bar/`), but an index may
+/// serve the page as a document within the directory instead (e.g.,
+/// `https://example.com/simple/Foo_Bar/index.html`), in which case the name is taken from the
+/// parent segment.
+fn served_project_name(url: &Url) -> Option<&str> {
+    let mut segments = url.path_segments()?;
+    let last = segments.next_back()?;
+    let is_document = Path::new(last)
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("html") || ext.eq_ignore_ascii_case("htm"));
+    let name = if last.is_empty() || is_document {
+        segments.next_back()?
+    } else {
+        last
+    };
+    (!name.is_empty()).then_some(name)
+}
+
+/// Report a project name served by an index that differs from the requested name.
+///
+/// Indexes with legacy project names may serve a project under a non-normalized name (e.g.,
+/// `Foo_Bar` for `foo-bar`), which is benign. A name that refers to a different project entirely,
+/// however, indicates a misconfigured index.
+fn check_served_name(package_name: &PackageName, served: &str, url: &DisplaySafeUrl) {
+    if served == package_name.as_str() {
+        return;
+    }
+    if PackageName::from_str(served).is_ok_and(|served| served == *package_name) {
+        debug!("Index at `{url}` serves `{package_name}` under the non-normalized name `{served}`");
+    } else {
+        warn_user_once!(
+            "The index at `{url}` returned the project `{served}` when `{package_name}` was requested"
+        );
+    }
+}
+
+/// Report a file that was skipped because its filename doesn't match the requested package.
+///
+/// Files whose project name only differs from the requested package by Unicode lookalikes (e.g.,
+/// a fullwidth letter or a non-ASCII dash) are likely a mistake on the part of the publisher, so
+/// they're surfaced to the user rather than silently ignored.
+fn report_skipped_file(filename: &str, url: &str, package_name: &PackageName) {
+    if let Some((name, _)) = filename.split_once('-')
+        && PackageName::from_lookalike(name).is_some_and(|name| name == *package_name)
+    {
+        warn_user_once!(
+            "Ignoring `{filename}` for `{package_name}` (`{url}`): the project name in the filename contains non-ASCII characters that resemble `{package_name}`"
+        );
+    } else {
+        debug!("Skipping file for {package_name}: {filename} (`{url}`)");
+    }
+}
+
+/// Repair the filenames in a JSON project page that were escaped or mangled by a mirror.
+///
+/// Filenames in HTML project pages are extracted from (percent-encoded) URLs, and so are decoded
+/// when the page is parsed.
+fn normalize_filenames(
+    files: Vec<uv_pypi_types::PypiFile>,
+    package_name: &PackageName,
+    base: &Url,
+) -> Vec<uv_pypi_types::PypiFile> {
+    files
+        .into_iter()
+        .filter_map(|mut file| {
+            match normalize_remote_filename(&file.filename) {
+                Ok(Cow::Borrowed(_)) => {}
+                Ok(Cow::Owned(filename)) => {
+                    debug!(
+                        "Normalized filename `{}` to `{filename}` for {package_name}",
+                        file.filename
+                    );
+                    file.filename = SmallString::from(filename);
+                }
+                Err(err) => {
+                    debug!(
+                        "Skipping file for {package_name}: `{}` at `{}` is not valid UTF-8 ({err})",
+                        file.filename,
+                        file_url(&FileLocation::new(
+                            file.url.clone(),
+                            &SmallString::from(base.as_str())
+                        ))
+                    );
+                    return None;
+                }
+            }
+            Some(file)
+        })
+        .collect()
+}
+
+/// Format the absolute URL of a file on a project page, for display.
+fn file_url(location: &FileLocation) -> String {
+    match location.to_url() {
+        Ok(url) => url.to_string(),
+        Err(_) => location.to_string(),
+    }
+}
+
 impl IntoIterator for SimpleDetailMetadata {
     type Item = SimpleDetailMetadatum;
     type IntoIter = std::vec::IntoIter<SimpleDetailMetadatum>;
@@ -1559,7 +1969,7 @@ impl ArchivedSimpleDetailMetadata {
 }
 
 #[derive(Debug)]
-enum MediaType {
+pub(crate) enum MediaType {
     PyxV1Msgpack,
     PyxV1Json,
     PypiV1Json,
@@ -1569,7 +1979,7 @@ enum MediaType {
 
 impl MediaType {
     /// Parse a media type from a string, returning `None` if the media type is not supported.
-    fn from_str(s: &str) -> Option<Self> {
+    pub(crate) fn from_str(s: &str) -> Option<Self> {
         match s {
             "application/vnd.pyx.simple.v1+msgpack" => Some(Self::PyxV1Msgpack),
             "application/vnd.pyx.simple.v1+json" => Some(Self::PyxV1Json),
@@ -1582,7 +1992,7 @@ impl MediaType {
 
     /// Return the `Accept` header value for all PyPI media types.
     #[inline]
-    const fn pypi() -> &'static str {
+    pub(crate) const fn pypi() -> &'static str {
         // See: https://peps.python.org/pep-0691/#version-format-selection
         "application/vnd.pypi.simple.v1+json, application/vnd.pypi.simple.v1+html;q=0.2, text/html;q=0.01"
     }
@@ -1637,12 +2047,16 @@ mod tests {
     use uv_redacted::DisplaySafeUrl;
 
     use crate::{
-        BaseClientBuilder, SimpleDetailMetadata, SimpleDetailMetadatum, html::SimpleDetailHTML,
+        BaseClientBuilder, MetadataFormat, OwnedArchive, SimpleDetailMetadata,
+        SimpleDetailMetadatum, html::SimpleDetailHTML,
     };
 
     use crate::RegistryClientBuilder;
+    use tokio::sync::Semaphore;
     use uv_cache::Cache;
-    use uv_distribution_types::{FileLocation, ToUrlError};
+    use uv_distribution_types::{
+        FileLocation, Index, IndexCapabilities, IndexLocations, IndexUrl, ToUrlError,
+    };
     use uv_small_str::SmallString;
     use wiremock::matchers::{basic_auth, method, path_regex};
     use wiremock::{Mock, MockServer, ResponseTemplate};
@@ -1819,6 +2233,171 @@ mod tests {
         Ok(())
     }
 
+    /// If an index fails with a server error, its mirrors should be queried in order.
+    #[tokio::test]
+    async fn test_simple_detail_mirror_fallback() -> Result<(), Error> {
+        let unavailable_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(503))
+            .mount(&unavailable_server)
+            .await;
+
+        let mirror_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path_regex("/simple/flask/"))
+            .respond_with(ResponseTemplate::new(200).set_body_raw(
+                r#"<a href="/files/flask-3.0.0-py3-none-any.whl">flask-3.0.0-py3-none-any.whl</a>"#,
+                "text/html",
+            ))
+            .mount(&mirror_server)
+            .await;
+
+        let mut index = Index::from_index_url(IndexUrl::from_str(&format!(
+            "{}/simple",
+            unavailable_server.uri()
+        ))?);
+        index.mirrors = vec![IndexUrl::from_str(&format!(
+            "{}/simple",
+            mirror_server.uri()
+        ))?];
+
+        let cache = Cache::temp()?;
+        let registry_client =
+            RegistryClientBuilder::new(BaseClientBuilder::default().retries(0), cache)
+                .index_locations(IndexLocations::new(vec![index], Vec::new(), false))
+                .build();
+
+        let results = registry_client
+            .simple_detail(
+                &PackageName::from_str("flask")?,
+                None,
+                &IndexCapabilities::default(),
+                &Semaphore::new(1),
+            )
+            .await?;
+        assert_eq!(results.len(), 1);
+        assert_eq!(
+            results[0].0.to_string(),
+            format!("{}/simple", unavailable_server.uri())
+        );
+
+        Ok(())
+    }
+
+    /// If an index fails with a server error, file downloads should fail over to its mirrors,
+    /// using the index's credentials.
+    #[tokio::test]
+    async fn test_file_download_mirror_fallback() -> Result<(), Error> {
+        let username = "user";
+        let password = "password";
+
+        let unavailable_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(503))
+            .mount(&unavailable_server)
+            .await;
+
+        let mirror_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path_regex("/files/flask-3.0.0-py3-none-any.whl"))
+            .and(basic_auth(username, password))
+            .respond_with(ResponseTemplate::new(200).set_body_raw("wheel", "application/zip"))
+            .mount(&mirror_server)
+            .await;
+
+        let index_url = format!("{}/simple", unavailable_server.uri())
+            .replace("http://", &format!("http://{username}:{password}@"));
+        let mut index = Index::from_index_url(IndexUrl::from_str(&index_url)?);
+        index.mirrors = vec![IndexUrl::from_str(&format!(
+            "{}/simple",
+            mirror_server.uri()
+        ))?];
+
+        let cache = Cache::temp()?;
+        let registry_client =
+            RegistryClientBuilder::new(BaseClientBuilder::default().retries(0), cache)
+                .index_locations(IndexLocations::new(vec![index], Vec::new(), false))
+                .build();
+
+        let url = DisplaySafeUrl::parse(&format!(
+            "{}/files/flask-3.0.0-py3-none-any.whl",
+            unavailable_server.uri()
+        ))?;
+        let response = registry_client
+            .uncached_client(&url)
+            .get(Url::from(url.clone()))
+            .send()
+            .await?;
+        assert_eq!(response.status(), http::StatusCode::OK);
+        assert_eq!(response.text().await?, "wheel");
+
+        Ok(())
+    }
+
+    /// A local index may use a non-normalized directory name for a project.
+    #[tokio::test]
+    async fn test_local_index_legacy_project_name() -> Result<(), Error> {
+        let index_dir = tempfile::tempdir()?;
+        let project_dir = index_dir.path().join("Foo_Bar");
+        fs_err::create_dir(&project_dir)?;
+        fs_err::write(
+            project_dir.join("index.html"),
+            r#"<a href="Foo_Bar-1.0.0.tar.gz">Foo_Bar-1.0.0.tar.gz</a>"#,
+        )?;
+
+        let index = Index::from_index_url(IndexUrl::from_str(
+            index_dir
+                .path()
+                .to_str()
+                .expect("temporary directory is valid UTF-8"),
+        )?);
+
+        let cache = Cache::temp()?;
+        let registry_client = RegistryClientBuilder::new(BaseClientBuilder::default(), cache)
+            .index_locations(IndexLocations::new(vec![index], Vec::new(), false))
+            .build();
+
+        let results = registry_client
+            .simple_detail(
+                &PackageName::from_str("foo-bar")?,
+                None,
+                &IndexCapabilities::default(),
+                &Semaphore::new(1),
+            )
+            .await?;
+        assert_eq!(results.len(), 1);
+        let versions = match &results[0].1 {
+            MetadataFormat::Simple(metadata) => OwnedArchive::deserialize(metadata)
+                .iter()
+                .map(|datum| datum.version.to_string())
+                .collect::<Vec<_>>(),
+            MetadataFormat::Flat(_) => Vec::new(),
+        };
+        assert_eq!(versions, vec!["1.0.0"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn served_project_name() {
+        let name = |url: &str| {
+            super::served_project_name(&Url::parse(url).unwrap()).map(ToString::to_string)
+        };
+        assert_eq!(
+            name("https://example.com/simple/foo-bar/"),
+            Some("foo-bar".to_string())
+        );
+        assert_eq!(
+            name("https://example.com/simple/Foo_Bar/index.html"),
+            Some("Foo_Bar".to_string())
+        );
+        assert_eq!(
+            name("https://example.com/simple/zope.interface"),
+            Some("zope.interface".to_string())
+        );
+        assert_eq!(name("https://example.com/"), None);
+    }
+
     #[test]
     fn ignore_failing_files() {
         // 1.7.7 has an invalid requires-python field (double comma), 1.7.8 is valid
@@ -1869,6 +2448,41 @@ mod tests {
         assert_eq!(versions, ["1.7.8".to_string()]);
     }
 
+    /// Filenames that a mirror percent-encoded or form-decoded in a JSON response are repaired.
+    #[test]
+    fn escaped_filenames_pypi_json() {
+        let response = r#"
+    {
+        "files": [
+        {
+            "filename": "torch-2.5.1%2Bcpu-cp312-cp312-linux_x86_64.whl",
+            "hashes": {},
+            "url": "https://download.pytorch.org/whl/cpu/torch-2.5.1%2Bcpu-cp312-cp312-linux_x86_64.whl"
+        },
+        {
+            "filename": "torch-2.5.0 cpu-cp312-cp312-linux_x86_64.whl",
+            "hashes": {},
+            "url": "https://download.pytorch.org/whl/cpu/torch-2.5.0%2Bcpu-cp312-cp312-linux_x86_64.whl"
+        }
+        ]
+    }
+    "#;
+        let data: PypiSimpleDetail = serde_json::from_str(response).unwrap();
+        let base = DisplaySafeUrl::parse("https://download.pytorch.org/whl/torch/").unwrap();
+        let package_name = PackageName::from_str("torch").unwrap();
+        let simple_metadata = SimpleDetailMetadata::from_pypi_files(
+            super::normalize_filenames(data.files, &package_name, &base),
+            &package_name,
+            data.project_status,
+            &base,
+        );
+        let versions: Vec<String> = simple_metadata
+            .iter()
+            .map(|SimpleDetailMetadatum { version, .. }| version.to_string())
+            .collect();
+        assert_eq!(versions, ["2.5.0+cpu".to_string(), "2.5.1+cpu".to_string()]);
+    }
+
     /// Test for project statuses from PyPI's JSON detail response.
     #[test]
     fn project_status_pypi_json() {
@@ -1937,6 +2551,9 @@ mod tests {
                                 },
                                 file: File {
                                     dist_info_metadata: false,
+                                    dist_info_metadata_hashes: HashDigests(
+                                        [],
+                                    ),
                                     filename: "pepy-2.1.1.tar.gz",
                                     hashes: HashDigests(
                                         [
@@ -1946,6 +2563,7 @@ mod tests {
                                             },
                                         ],
                                     ),
+                                    provenance: None,
                                     requires_python: Some(
                                         VersionSpecifiers(
                                             [
@@ -2029,6 +2647,9 @@ mod tests {
                                 },
                                 file: File {
                                     dist_info_metadata: false,
+                                    dist_info_metadata_hashes: HashDigests(
+                                        [],
+                                    ),
                                     filename: "pepy-2.1.1.tar.gz",
                                     hashes: HashDigests(
                                         [
@@ -2038,6 +2659,7 @@ mod tests {
                                             },
                                         ],
                                     ),
+                                    provenance: None,
                                     requires_python: Some(
                                         VersionSpecifiers(
                                             [
@@ -0,0 +1,116 @@
+//! Rendering of static [PEP 503](https://peps.python.org/pep-0503/) Simple API pages, e.g., for
+//! `uv index build` and for serving a local index during development.
+
+use std::fmt::Write;
+
+use uv_normalize::PackageName;
+
+/// The version of the Simple API implemented by the rendered pages.
+const API_VERSION: &str = "1.1";
+
+/// A file to link from a project page.
+#[derive(Debug, Clone, Copy)]
+pub struct SimpleHtmlFile<'a> {
+    /// The filename of the distribution, used as the text of the link.
+    pub filename: &'a str,
+    /// The URL of the distribution, relative to the project page.
+    pub url: &'a str,
+    /// The hex-encoded SHA-256 digest of the distribution.
+    pub sha256: &'a str,
+}
+
+/// Render the HTML page for the list of all projects (the root of the Simple API).
+pub fn simple_index_html<'a>(names: impl IntoIterator<Item = &'a PackageName>) -> String {
+    let mut page = header("Simple index");
+    for name in names {
+        let _ = writeln!(
+            page,
+            "    <a href=\"{href}/\">{text}</a><br />",
+            href = html_escape::encode_double_quoted_attribute(name.as_str()),
+            text = html_escape::encode_text(name.as_str()),
+        );
+    }
+    page.push_str(FOOTER);
+    page
+}
+
+/// Render the HTML page for the files of a single project.
+pub fn simple_detail_html<'a>(
+    name: &PackageName,
+    files: impl IntoIterator<Item = SimpleHtmlFile<'a>>,
+) -> String {
+    let title = format!("Links for {name}");
+    let mut page = header(&title);
+    let _ = writeln!(page, "    <h1>{}</h1>", html_escape::encode_text(&title));
+    for file in files {
+        let href = format!("{}#sha256={}", file.url, file.sha256);
+        let _ = writeln!(
+            page,
+            "    <a href=\"{href}\">{text}</a><br />",
+            href = html_escape::encode_double_quoted_attribute(&href),
+            text = html_escape::encode_text(file.filename),
+        );
+    }
+    page.push_str(FOOTER);
+    page
+}
+
+const FOOTER: &str = "  </body>\n</html>\n";
+
+fn header(title: &str) -> String {
+    format!(
+        "<!DOCTYPE html>\n<html>\n  <head>\n    \
+         <meta name=\"pypi:repository-version\" content=\"{API_VERSION}\">\n    \
+         <title>{title}</title>\n  </head>\n  <body>\n",
+        title = html_escape::encode_text(title),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use insta::assert_snapshot;
+
+    use uv_normalize::PackageName;
+    use uv_redacted::DisplaySafeUrl;
+
+    use crate::html::SimpleDetailHTML;
+
+    use super::{SimpleHtmlFile, simple_detail_html};
+
+    /// Characters with a meaning in HTML are escaped, and survive a round-trip through the parser.
+    #[test]
+    fn escape_detail_html() {
+        let name = PackageName::from_str("foo").unwrap();
+        let page = simple_detail_html(
+            &name,
+            [SimpleHtmlFile {
+                filename: "foo-1.0-py3-none-any.whl",
+                url: "../../files/a&b/\"<foo>\"/foo-1.0-py3-none-any.whl",
+                sha256: "2c26b46b68ffc68ff99b453c1d30413413422d706483bfa0f98a5e886266e7ae",
+            }],
+        );
+        assert_snapshot!(page, @r#"
+        <!DOCTYPE html>
+        <html>
+          <head>
+            <meta name="pypi:repository-version" content="1.1">
+            <title>Links for foo</title>
+          </head>
+          <body>
+            <h1>Links for foo</h1>
+            <a href="../../files/a&amp;b/&quot;&lt;foo&gt;&quot;/foo-1.0-py3-none-any.whl#sha256=2c26b46b68ffc68ff99b453c1d30413413422d706483bfa0f98a5e886266e7ae">foo-1.0-py3-none-any.whl</a><br />
+          </body>
+        </html>
+        "#);
+
+        let base = DisplaySafeUrl::parse("https://example.com/simple/foo/").unwrap();
+        let parsed = SimpleDetailHTML::parse(&page, &base).unwrap();
+        assert_eq!(parsed.files.len(), 1);
+        assert_eq!(
+            parsed.files[0].url.as_ref(),
+            "../../files/a&b/\"<foo>\"/foo-1.0-py3-none-any.whl"
+        );
+    }
+}
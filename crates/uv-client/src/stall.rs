@@ -0,0 +1,151 @@
+use std::io;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+use futures::{FutureExt, Stream};
+use tokio::time::{Instant, Sleep};
+use tracing::debug;
+
+use uv_redacted::DisplaySafeUrl;
+
+/// A stream of response bytes that fails if no data is received for a given duration.
+///
+/// A connection that stops delivering data without being closed (e.g., a proxy that half-closes
+/// the connection) would otherwise only be detected by the read timeout. Instead, the stream
+/// fails with [`io::ErrorKind::TimedOut`], which is retried by the caller, such that the download
+/// is re-requested (and, if possible, resumed from the bytes received so far).
+pub struct StallDetector<S> {
+    stream: S,
+    url: DisplaySafeUrl,
+    /// The timeout and the deadline by which the next chunk must be received, if enabled.
+    deadline: Option<(Duration, Pin<Box<Sleep>>)>,
+    /// Whether the deadline is armed, i.e., whether the consumer is waiting on the next chunk.
+    ///
+    /// The deadline is (re-)armed when the stream is polled, rather than when a chunk is received,
+    /// such that time spent by the consumer between polls (e.g., writing the chunk to disk) does
+    /// not count towards the timeout.
+    armed: bool,
+    /// The number of bytes received so far.
+    received: u64,
+}
+
+impl<S> StallDetector<S> {
+    /// Create a new [`StallDetector`] that wraps a stream of response bytes.
+    ///
+    /// If `timeout` is `None`, the stream is passed through unchanged.
+    pub fn new(stream: S, url: DisplaySafeUrl, timeout: Option<Duration>) -> Self {
+        Self {
+            stream,
+            url,
+            deadline: timeout.map(|timeout| (timeout, Box::pin(tokio::time::sleep(timeout)))),
+            armed: false,
+            received: 0,
+        }
+    }
+}
+
+impl<S, B> Stream for StallDetector<S>
+where
+    S: Stream<Item = io::Result<B>> + Unpin,
+    B: AsRef<[u8]>,
+{
+    type Item = io::Result<B>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = &mut *self;
+        if !this.armed {
+            if let Some((timeout, deadline)) = &mut this.deadline {
+                deadline.as_mut().reset(Instant::now() + *timeout);
+            }
+            this.armed = true;
+        }
+        match Pin::new(&mut this.stream).poll_next(cx) {
+            Poll::Ready(Some(Ok(chunk))) => {
+                this.received += chunk.as_ref().len() as u64;
+                this.armed = false;
+                Poll::Ready(Some(Ok(chunk)))
+            }
+            Poll::Ready(item) => {
+                this.armed = false;
+                Poll::Ready(item)
+            }
+            Poll::Pending => {
+                let Some((timeout, deadline)) = &mut this.deadline else {
+                    return Poll::Pending;
+                };
+                if deadline.poll_unpin(cx).is_pending() {
+                    return Poll::Pending;
+                }
+                this.armed = false;
+                debug!(
+                    "Download of `{}` stalled after {} bytes (no data received for {}s)",
+                    this.url,
+                    this.received,
+                    timeout.as_secs()
+                );
+                Poll::Ready(Some(Err(io::Error::new(
+                    io::ErrorKind::TimedOut,
+                    format!(
+                        "Download stalled: no data received for {}s. Try increasing UV_HTTP_STALL_TIMEOUT (current value: {}s).",
+                        timeout.as_secs(),
+                        timeout.as_secs()
+                    ),
+                ))))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::{StreamExt, stream};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn stall() {
+        let url = DisplaySafeUrl::parse("https://example.com/foo.whl").unwrap();
+        let chunks = stream::iter([Ok::<_, io::Error>(vec![0u8; 16])]).chain(stream::pending());
+        let mut stream = StallDetector::new(chunks, url, Some(Duration::from_millis(10)));
+
+        let chunk = stream.next().await.unwrap().unwrap();
+        assert_eq!(chunk.len(), 16);
+
+        let err = stream.next().await.unwrap().unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::TimedOut);
+        assert_eq!(stream.received, 16);
+    }
+
+    #[tokio::test]
+    async fn no_stall() {
+        let url = DisplaySafeUrl::parse("https://example.com/foo.whl").unwrap();
+        let chunks = stream::iter([Ok::<_, io::Error>(vec![0u8; 16]), Ok(vec![0u8; 8])]);
+        let stream = StallDetector::new(chunks, url, Some(Duration::from_millis(10)));
+
+        let chunks = stream.collect::<Vec<_>>().await;
+        assert_eq!(chunks.len(), 2);
+        assert!(chunks.iter().all(Result::is_ok));
+    }
+
+    #[tokio::test]
+    async fn no_stall_slow_consumer() {
+        let url = DisplaySafeUrl::parse("https://example.com/foo.whl").unwrap();
+        let chunks = stream::iter([Ok::<_, io::Error>(vec![0u8; 16])])
+            .chain(stream::once(async {
+                tokio::time::sleep(Duration::from_millis(5)).await;
+                Ok(vec![0u8; 8])
+            }))
+            .boxed();
+        let mut stream = StallDetector::new(chunks, url, Some(Duration::from_millis(50)));
+
+        // Time spent by the consumer before polling doesn't count towards the timeout.
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert!(stream.next().await.unwrap().is_ok());
+
+        // Nor does time spent between polls.
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert!(stream.next().await.unwrap().is_ok());
+        assert!(stream.next().await.is_none());
+    }
+}
@@ -1,4 +1,4 @@
-use reqwest::Identity;
+use reqwest::{Certificate, Identity};
 use std::ffi::OsStr;
 use std::io::Read;
 
@@ -8,14 +8,39 @@ pub(crate) enum CertificateError {
     Io(#[from] std::io::Error),
     #[error(transparent)]
     Reqwest(reqwest::Error),
+    #[error("No certificates found in `{0}`")]
+    Empty(String),
 }
 
 /// Return the `Identity` from the provided file.
-pub(crate) fn read_identity(ssl_client_cert: &OsStr) -> Result<Identity, CertificateError> {
+///
+/// If a separate private key file is provided, it's appended to the certificate. Otherwise, the
+/// certificate file must contain both the certificate and the private key.
+pub(crate) fn read_identity(
+    ssl_client_cert: &OsStr,
+    ssl_client_key: Option<&OsStr>,
+) -> Result<Identity, CertificateError> {
     let mut buf = Vec::new();
     fs_err::File::open(ssl_client_cert)?.read_to_end(&mut buf)?;
+    if let Some(ssl_client_key) = ssl_client_key {
+        buf.push(b'\n');
+        fs_err::File::open(ssl_client_key)?.read_to_end(&mut buf)?;
+    }
     Identity::from_pem(&buf).map_err(|tls_err| {
         debug_assert!(tls_err.is_builder(), "must be a rustls::Error internally");
         CertificateError::Reqwest(tls_err)
     })
 }
+
+/// Return the [`Certificate`]s from the provided PEM bundle.
+pub(crate) fn read_ca_bundle(ca_bundle: &OsStr) -> Result<Vec<Certificate>, CertificateError> {
+    let mut buf = Vec::new();
+    fs_err::File::open(ca_bundle)?.read_to_end(&mut buf)?;
+    let certificates = Certificate::from_pem_bundle(&buf).map_err(CertificateError::Reqwest)?;
+    if certificates.is_empty() {
+        return Err(CertificateError::Empty(
+            ca_bundle.to_string_lossy().into_owned(),
+        ));
+    }
+    Ok(certificates)
+}
@@ -0,0 +1,102 @@
+//! An integration test for download rate and connection limits in `uv-client`.
+
+use std::num::{NonZeroU64, NonZeroUsize};
+use std::time::{Duration, Instant};
+
+use anyhow::Result;
+use wiremock::matchers::method;
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use uv_client::BaseClientBuilder;
+
+#[tokio::test]
+async fn limit_rate() -> Result<()> {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .respond_with(ResponseTemplate::new(200).set_body_bytes(vec![0u8; 3000]))
+        .mount(&server)
+        .await;
+
+    let client = BaseClientBuilder::default()
+        .limit_rate(NonZeroU64::new(1000))
+        .build();
+
+    let url = format!("{}/file.whl", server.uri());
+    let start = Instant::now();
+    let response = client.for_host(&url.parse()?).get(&url).send().await?;
+
+    // The response is otherwise unchanged.
+    assert_eq!(response.status(), 200);
+    assert_eq!(response.url().as_str(), url);
+    assert_eq!(response.content_length(), Some(3000));
+
+    // The first 1000 bytes are allowed as a burst, the remaining 2000 bytes take ~2s.
+    let body = response.bytes().await?;
+    assert_eq!(body.len(), 3000);
+    assert!(
+        start.elapsed() >= Duration::from_millis(1500),
+        "Download should have been throttled"
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn max_connections_per_host() -> Result<()> {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .respond_with(ResponseTemplate::new(200).set_body_bytes(vec![0u8; 16]))
+        .mount(&server)
+        .await;
+
+    let client = BaseClientBuilder::default()
+        .max_connections_per_host(NonZeroUsize::new(1))
+        .build();
+
+    let url = format!("{}/file.whl", server.uri());
+    let first = client.for_host(&url.parse()?).get(&url).send().await?;
+
+    // The second download waits until the body of the first response is received.
+    let second = client.for_host(&url.parse()?).get(&url).send();
+    tokio::pin!(second);
+    assert!(
+        tokio::time::timeout(Duration::from_millis(200), &mut second)
+            .await
+            .is_err(),
+        "Request should wait for a connection slot"
+    );
+
+    assert_eq!(first.bytes().await?.len(), 16);
+    let second = tokio::time::timeout(Duration::from_secs(5), second).await??;
+    assert_eq!(second.bytes().await?.len(), 16);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn max_connections_per_host_releases_non_downloads() -> Result<()> {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .respond_with(ResponseTemplate::new(200).set_body_bytes(vec![0u8; 16]))
+        .mount(&server)
+        .await;
+
+    let client = BaseClientBuilder::default()
+        .max_connections_per_host(NonZeroUsize::new(1))
+        .build();
+
+    // Responses other than archive downloads release their connection slot once the headers are
+    // received, such that holding them unread doesn't block further requests.
+    let url = format!("{}/simple/flask/", server.uri());
+    let first = client.for_host(&url.parse()?).get(&url).send().await?;
+    let second = tokio::time::timeout(
+        Duration::from_secs(5),
+        client.for_host(&url.parse()?).get(&url).send(),
+    )
+    .await??;
+
+    assert_eq!(first.bytes().await?.len(), 16);
+    assert_eq!(second.bytes().await?.len(), 16);
+
+    Ok(())
+}
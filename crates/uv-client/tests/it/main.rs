@@ -1,5 +1,7 @@
 mod http_util;
+mod limits;
 mod proxy;
 mod remote_metadata;
 mod ssl_certs;
+mod stale_metadata;
 mod user_agent_version;
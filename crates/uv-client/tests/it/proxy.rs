@@ -1,7 +1,7 @@
 //! An integration test for proxy support in `uv-client`.
 
 use anyhow::Result;
-use wiremock::matchers::{any, method};
+use wiremock::matchers::{any, header, method};
 use wiremock::{Mock, MockServer, ResponseTemplate};
 
 use uv_client::BaseClientBuilder;
@@ -84,3 +84,45 @@ async fn no_proxy() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn all_proxy_with_auth() -> Result<()> {
+    // Start a mock server to act as the target.
+    let target_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .respond_with(ResponseTemplate::new(200))
+        .mount(&target_server)
+        .await;
+
+    // Start a mock server to act as the proxy, requiring Basic authentication.
+    let proxy_server = MockServer::start().await;
+    Mock::given(header("proxy-authorization", "Basic dXNlcjpwYUBzcw=="))
+        .respond_with(ResponseTemplate::new(200))
+        .mount(&proxy_server)
+        .await;
+    Mock::given(any())
+        .respond_with(ResponseTemplate::new(407))
+        .mount(&proxy_server)
+        .await;
+
+    // Create a client with the proxy, including percent-encoded credentials.
+    let proxy_url = format!("http://user:pa%40ss@{}", proxy_server.address());
+    let client = BaseClientBuilder::default()
+        .all_proxy(Some(proxy_url.parse::<ProxyUrl>()?))
+        .build();
+
+    // Make a request to the target.
+    let response = client
+        .for_host(&target_server.uri().parse()?)
+        .get(target_server.uri())
+        .send()
+        .await?;
+
+    assert_eq!(response.status(), 200);
+
+    // Assert that the proxy was called.
+    let received_requests = proxy_server.received_requests().await.unwrap();
+    assert_eq!(received_requests.len(), 1);
+
+    Ok(())
+}
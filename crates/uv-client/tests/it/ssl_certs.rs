@@ -7,6 +7,7 @@ use url::Url;
 use uv_cache::Cache;
 use uv_client::BaseClientBuilder;
 use uv_client::RegistryClientBuilder;
+use uv_distribution_types::{Index, IndexLocations, IndexUrl};
 use uv_redacted::DisplaySafeUrl;
 use uv_static::EnvVars;
 
@@ -343,3 +344,82 @@ async fn ssl_env_vars() -> Result<()> {
     // Fin.
     Ok(())
 }
+
+#[tokio::test]
+async fn index_ca_bundle_and_client_cert() -> Result<()> {
+    // Create temporary cert dirs
+    let cert_dir = test_cert_dir();
+    fs_err::create_dir_all(&cert_dir).expect("Failed to create test cert bucket");
+    let cert_dir =
+        tempfile::TempDir::new_in(cert_dir).expect("Failed to create test cert directory");
+
+    // Generate self-signed CA, server, and client certs
+    let (ca_cert, server_cert, client_cert) = generate_self_signed_certs_with_ca()?;
+    let ca_public_pem_path = cert_dir.path().join("ca_public.pem");
+    let client_public_pem_path = cert_dir.path().join("client_public.pem");
+    let client_private_pem_path = cert_dir.path().join("client_private.pem");
+    fs_err::write(ca_public_pem_path.as_path(), ca_cert.public.pem())?;
+    fs_err::write(client_public_pem_path.as_path(), client_cert.public.pem())?;
+    fs_err::write(
+        client_private_pem_path.as_path(),
+        client_cert.private.serialize_pem(),
+    )?;
+
+    // ** Trust our CA for the index only, and provide the client cert and key separately
+    // ** Then verify our request successfully establishes a connection
+
+    let (server_task, addr) = start_https_mtls_user_agent_server(&ca_cert, &server_cert).await?;
+    let url = DisplaySafeUrl::from_str(&format!("https://{addr}"))?;
+    let mut index = Index::from_index_url(IndexUrl::from_str(&format!("https://{addr}/simple"))?);
+    index.ca_bundle = Some(ca_public_pem_path);
+    let cache = Cache::temp()?.init().await?;
+    let client = RegistryClientBuilder::new(
+        BaseClientBuilder::default()
+            .no_retry_delay(true)
+            .client_cert(
+                Some(client_public_pem_path.as_path()),
+                Some(client_private_pem_path),
+            )?,
+        cache,
+    )
+    .index_locations(IndexLocations::new(vec![index], Vec::new(), false))
+    .build();
+    let res = client
+        .cached_client()
+        .uncached()
+        .for_host(&url)
+        .get(Url::from(url))
+        .send()
+        .await;
+    assert!(res.is_ok());
+    let _ = server_task.await?; // wait for server shutdown
+
+    Ok(())
+}
+
+#[test]
+fn invalid_client_cert() -> Result<()> {
+    let cert_dir = tempfile::TempDir::new()?;
+
+    // A missing certificate is an error.
+    let missing = cert_dir.path().join("missing.pem");
+    let err = BaseClientBuilder::default()
+        .client_cert(Some(missing.as_path()), None)
+        .unwrap_err();
+    assert!(
+        err.to_string()
+            .starts_with("Failed to read client certificate")
+    );
+
+    // As is a certificate without a private key.
+    let (_, _, client_cert) = generate_self_signed_certs_with_ca()?;
+    let client_public_pem_path = cert_dir.path().join("client_public.pem");
+    fs_err::write(client_public_pem_path.as_path(), client_cert.public.pem())?;
+    assert!(
+        BaseClientBuilder::default()
+            .client_cert(Some(client_public_pem_path.as_path()), None)
+            .is_err()
+    );
+
+    Ok(())
+}
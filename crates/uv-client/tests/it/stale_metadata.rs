@@ -0,0 +1,79 @@
+use std::str::FromStr;
+
+use anyhow::Result;
+use wiremock::matchers::path;
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use uv_cache::Cache;
+use uv_client::{BaseClientBuilder, RegistryClientBuilder};
+use uv_distribution_filename::WheelFilename;
+use uv_distribution_types::{
+    BuiltDist, File, FileLocation, IndexCapabilities, IndexUrl, RegistryBuiltDist,
+    RegistryBuiltWheel, UrlString,
+};
+use uv_pypi_types::{HashAlgorithm, HashDigest, HashDigests};
+use uv_redacted::DisplaySafeUrl;
+
+/// If the PEP 658 metadata file doesn't match the hash advertised by the index, the metadata
+/// should be read from the wheel itself, and the mismatch should be recorded for the index.
+#[tokio::test]
+async fn stale_metadata_falls_back_to_wheel() -> Result<()> {
+    let filename = "basic_package-0.1.0-py3-none-any.whl";
+    let wheel = fs_err::read(
+        std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("../../test/links")
+            .join(filename),
+    )?;
+
+    // Serve a metadata file with the wrong version, alongside the wheel itself.
+    let server = MockServer::start().await;
+    Mock::given(path(format!("/files/{filename}.metadata")))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_string("Metadata-Version: 2.1\nName: basic-package\nVersion: 0.0.1\n"),
+        )
+        .mount(&server)
+        .await;
+    Mock::given(path(format!("/files/{filename}")))
+        .respond_with(ResponseTemplate::new(200).set_body_bytes(wheel))
+        .mount(&server)
+        .await;
+
+    let index = IndexUrl::from_str(&format!("{}/simple", server.uri()))?;
+    let url = DisplaySafeUrl::parse(&format!("{}/files/{filename}", server.uri()))?;
+    let file = File {
+        dist_info_metadata: true,
+        dist_info_metadata_hashes: HashDigests::from(HashDigest {
+            algorithm: HashAlgorithm::Sha256,
+            digest: "0".repeat(64).into(),
+        }),
+        filename: filename.into(),
+        hashes: HashDigests::empty(),
+        provenance: None,
+        requires_python: None,
+        size: None,
+        upload_time_utc_ms: None,
+        url: FileLocation::AbsoluteUrl(UrlString::from(url)),
+        yanked: None,
+        zstd: None,
+    };
+    let dist = BuiltDist::Registry(RegistryBuiltDist {
+        wheels: vec![RegistryBuiltWheel {
+            filename: WheelFilename::from_str(filename)?,
+            file: Box::new(file),
+            index: index.clone(),
+        }],
+        best_wheel_index: 0,
+        sdist: None,
+    });
+
+    let cache = Cache::temp()?.init().await?;
+    let client = RegistryClientBuilder::new(BaseClientBuilder::default(), cache).build();
+    let capabilities = IndexCapabilities::default();
+
+    let metadata = client.wheel_metadata(&dist, &capabilities).await?;
+    assert_eq!(metadata.version.to_string(), "0.1.0");
+    assert!(capabilities.has_stale_metadata(&index, filename));
+
+    Ok(())
+}
@@ -1,5 +1,7 @@
 use std::fmt::{Display, Formatter};
+use std::path::PathBuf;
 
+use uv_fs::Simplified;
 use uv_normalize::PackageName;
 
 use crate::{PackageNameSpecifier, PackageNameSpecifiers};
@@ -58,6 +60,25 @@ impl BuildOptions {
         }
     }
 
+    /// Combine with the `--no-binary` and `--no-build` flags from requirements files, returning
+    /// an error if any of the flags contradict one another.
+    pub fn try_combine(
+        self,
+        no_binary: NoBinary,
+        no_build: NoBuild,
+        origins: BuildOptionsOrigins,
+    ) -> Result<Self, BuildOptionsError> {
+        let mut all = BuildOptionsOrigins::default();
+        all.push(
+            self.no_binary.clone(),
+            self.no_build.clone(),
+            BuildOptionsOrigin::Settings,
+        );
+        all.extend(origins);
+        all.check()?;
+        Ok(self.combine(no_binary, no_build))
+    }
+
     pub fn no_binary_package(&self, package_name: &PackageName) -> bool {
         match &self.no_binary {
             NoBinary::None => false,
@@ -311,6 +332,102 @@ impl NoBuild {
     }
 }
 
+/// The origin of a `--no-binary` or `--no-build` policy.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BuildOptionsOrigin {
+    /// The policy was provided via command-line arguments or a `uv.toml` or `pyproject.toml` file.
+    Settings,
+    /// The policy was provided in a requirements file (e.g., `--only-binary` in a
+    /// `requirements.txt` file).
+    File(PathBuf),
+}
+
+impl Display for BuildOptionsOrigin {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Settings => f.write_str("command-line arguments or settings"),
+            Self::File(path) => write!(f, "`{}`", path.user_display()),
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum BuildOptionsError {
+    #[error(
+        "Conflicting build options for `{package}`: `--no-binary` (from {no_binary}) and `--only-binary` (from {no_build}) disallow both wheels and source distributions"
+    )]
+    Package {
+        package: PackageName,
+        no_binary: BuildOptionsOrigin,
+        no_build: BuildOptionsOrigin,
+    },
+    #[error(
+        "Conflicting build options: `--no-binary :all:` (from {no_binary}) and `--only-binary :all:` (from {no_build}) disallow both wheels and source distributions"
+    )]
+    All {
+        no_binary: BuildOptionsOrigin,
+        no_build: BuildOptionsOrigin,
+    },
+}
+
+/// The `--no-binary` and `--no-build` policies declared by each origin.
+///
+/// When combined, policies of the same kind are merged (e.g., `--no-binary foo` and
+/// `--no-binary bar` disallow wheels for both `foo` and `bar`), and a policy for a specific package
+/// takes precedence over an `:all:` policy of the opposite kind (e.g., `--no-binary :all:` and
+/// `--only-binary foo` disallow source distributions for `foo`, and wheels for all other packages).
+///
+/// Naming the same package (or `:all:`) in both a `--no-binary` and an `--only-binary` policy is a
+/// contradiction, as no distribution could be installed, and is rejected by
+/// [`BuildOptionsOrigins::check`].
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct BuildOptionsOrigins(Vec<(NoBinary, NoBuild, BuildOptionsOrigin)>);
+
+impl BuildOptionsOrigins {
+    /// Record the policies declared by the given origin.
+    pub fn push(&mut self, no_binary: NoBinary, no_build: NoBuild, origin: BuildOptionsOrigin) {
+        if no_binary.is_none() && no_build.is_none() {
+            return;
+        }
+        self.0.push((no_binary, no_build, origin));
+    }
+
+    /// Extend the policies with those declared by other origins.
+    pub fn extend(&mut self, other: Self) {
+        self.0.extend(other.0);
+    }
+
+    /// Returns an error if the policies disallow both wheels and source distributions for any
+    /// package, including both origins in the error.
+    pub fn check(&self) -> Result<(), BuildOptionsError> {
+        for (no_binary, _, no_binary_origin) in &self.0 {
+            for (_, no_build, no_build_origin) in &self.0 {
+                match (no_binary, no_build) {
+                    (NoBinary::All, NoBuild::All) => {
+                        return Err(BuildOptionsError::All {
+                            no_binary: no_binary_origin.clone(),
+                            no_build: no_build_origin.clone(),
+                        });
+                    }
+                    (NoBinary::Packages(no_binary), NoBuild::Packages(no_build)) => {
+                        if let Some(package) =
+                            no_binary.iter().find(|package| no_build.contains(package))
+                        {
+                            return Err(BuildOptionsError::Package {
+                                package: package.clone(),
+                                no_binary: no_binary_origin.clone(),
+                                no_build: no_build_origin.clone(),
+                            });
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 #[serde(deny_unknown_fields, rename_all = "kebab-case")]
 #[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
@@ -416,4 +533,43 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn build_options_origins() -> Result<(), Error> {
+        let requirements_txt = BuildOptionsOrigin::File(PathBuf::from("requirements.txt"));
+
+        // A specific package takes precedence over `:all:`.
+        let mut origins = BuildOptionsOrigins::default();
+        origins.push(NoBinary::All, NoBuild::None, BuildOptionsOrigin::Settings);
+        origins.push(
+            NoBinary::None,
+            NoBuild::Packages(vec![PackageName::from_str("foo")?]),
+            requirements_txt.clone(),
+        );
+        assert!(origins.check().is_ok());
+
+        // The same package can't be both `--no-binary` and `--only-binary`.
+        origins.push(
+            NoBinary::Packages(vec![
+                PackageName::from_str("bar")?,
+                PackageName::from_str("foo")?,
+            ]),
+            NoBuild::None,
+            BuildOptionsOrigin::Settings,
+        );
+        insta::assert_snapshot!(
+            origins.check().unwrap_err(),
+            @"Conflicting build options for `foo`: `--no-binary` (from command-line arguments or settings) and `--only-binary` (from `requirements.txt`) disallow both wheels and source distributions"
+        );
+
+        // Nor can `:all:`.
+        let mut origins = BuildOptionsOrigins::default();
+        origins.push(NoBinary::All, NoBuild::All, requirements_txt);
+        insta::assert_snapshot!(
+            origins.check().unwrap_err(),
+            @"Conflicting build options: `--no-binary :all:` (from `requirements.txt`) and `--only-binary :all:` (from `requirements.txt`) disallow both wheels and source distributions"
+        );
+
+        Ok(())
+    }
 }
@@ -22,6 +22,10 @@ pub enum ExportFormat {
         clap(name = "cyclonedx1.5", alias = "cyclonedx1.5+json")
     )]
     CycloneDX1_5,
+    /// Export in SPDX v2.3 JSON format.
+    #[serde(rename = "spdx2.3")]
+    #[cfg_attr(feature = "clap", clap(name = "spdx2.3", alias = "spdx2.3+json"))]
+    Spdx2_3,
 }
 
 /// The output format to use in `uv pip compile`.
@@ -41,4 +45,15 @@ pub enum PipCompileFormat {
     #[serde(rename = "pylock.toml", alias = "pylock-toml")]
     #[cfg_attr(feature = "clap", clap(name = "pylock.toml", alias = "pylock-toml"))]
     PylockToml,
+    /// Export in `CycloneDX` v1.5 JSON format.
+    #[serde(rename = "cyclonedx1.5")]
+    #[cfg_attr(
+        feature = "clap",
+        clap(name = "cyclonedx1.5", alias = "cyclonedx1.5+json")
+    )]
+    CycloneDX1_5,
+    /// Export in SPDX v2.3 JSON format.
+    #[serde(rename = "spdx2.3")]
+    #[cfg_attr(feature = "clap", clap(name = "spdx2.3", alias = "spdx2.3+json"))]
+    Spdx2_3,
 }
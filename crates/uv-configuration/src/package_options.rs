@@ -131,9 +131,24 @@ impl From<Reinstall> for Refresh {
     }
 }
 
-/// Strategy for determining which packages to consider for upgrade.
-#[derive(Debug, Default, Clone)]
+/// The strategy to use when upgrading packages with `--upgrade`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
 pub enum UpgradeStrategy {
+    /// Upgrade the requested packages, along with all of their dependencies, ignoring any pinned
+    /// or installed versions.
+    #[default]
+    Eager,
+
+    /// Upgrade the requested packages, but only upgrade their dependencies if required to
+    /// satisfy the requirements of the upgraded packages.
+    OnlyIfNeeded,
+}
+
+/// Selection of packages to consider for upgrade.
+#[derive(Debug, Default, Clone)]
+enum UpgradeSelection {
     /// Prefer pinned versions from the existing lockfile, if possible.
     #[default]
     None,
@@ -141,6 +156,13 @@ pub enum UpgradeStrategy {
     /// Allow package upgrades for all packages, ignoring the existing lockfile.
     All,
 
+    /// Allow package upgrades for the direct requirements, but prefer pinned versions for all
+    /// other packages.
+    ///
+    /// Resolved to [`UpgradeSelection::Packages`] by [`Upgrade::with_requested`] once the direct
+    /// requirements are known.
+    Requested,
+
     /// Allow package upgrades, but only for the specified packages.
     Packages(FxHashSet<PackageName>),
 }
@@ -149,47 +171,52 @@ pub enum UpgradeStrategy {
 #[derive(Debug, Default, Clone)]
 pub struct Upgrade {
     /// Strategy for picking packages to consider for upgrade.
-    strategy: UpgradeStrategy,
+    strategy: UpgradeSelection,
 
     /// Additional version constraints for specific packages.
     constraints: FxHashMap<PackageName, Vec<Requirement>>,
+
+    /// Whether to upgrade the dependencies of the selected packages, too.
+    eager: bool,
 }
 
 impl Upgrade {
     /// Create a new [`Upgrade`] with no upgrades nor constraints.
     pub fn none() -> Self {
         Self {
-            strategy: UpgradeStrategy::None,
+            strategy: UpgradeSelection::None,
             constraints: FxHashMap::default(),
+            eager: false,
         }
     }
 
     /// Create a new [`Upgrade`] to consider all packages.
     pub fn all() -> Self {
         Self {
-            strategy: UpgradeStrategy::All,
+            strategy: UpgradeSelection::All,
             constraints: FxHashMap::default(),
+            eager: false,
         }
     }
 
     /// Determine the upgrade selection strategy from the command-line arguments.
     pub fn from_args(upgrade: Option<bool>, upgrade_package: Vec<Requirement>) -> Option<Self> {
         let strategy = match upgrade {
-            Some(true) => UpgradeStrategy::All,
+            Some(true) => UpgradeSelection::All,
             Some(false) => {
                 if upgrade_package.is_empty() {
                     return Some(Self::none());
                 }
                 // `--no-upgrade` with `--upgrade-package` allows selecting the specified packages for upgrade.
                 let packages = upgrade_package.iter().map(|req| req.name.clone()).collect();
-                UpgradeStrategy::Packages(packages)
+                UpgradeSelection::Packages(packages)
             }
             None => {
                 if upgrade_package.is_empty() {
                     return None;
                 }
                 let packages = upgrade_package.iter().map(|req| req.name.clone()).collect();
-                UpgradeStrategy::Packages(packages)
+                UpgradeSelection::Packages(packages)
             }
         };
 
@@ -210,6 +237,7 @@ impl Upgrade {
         Some(Self {
             strategy,
             constraints,
+            eager: false,
         })
     }
 
@@ -218,27 +246,68 @@ impl Upgrade {
         let mut packages = FxHashSet::default();
         packages.insert(package_name);
         Self {
-            strategy: UpgradeStrategy::Packages(packages),
+            strategy: UpgradeSelection::Packages(packages),
             constraints: FxHashMap::default(),
+            eager: false,
         }
     }
 
     /// Returns `true` if no packages should be upgraded.
     pub fn is_none(&self) -> bool {
-        matches!(self.strategy, UpgradeStrategy::None)
+        matches!(self.strategy, UpgradeSelection::None)
     }
 
     /// Returns `true` if all packages should be upgraded.
     pub fn is_all(&self) -> bool {
-        matches!(self.strategy, UpgradeStrategy::All)
+        matches!(self.strategy, UpgradeSelection::All)
     }
 
     /// Returns `true` if the specified package should be upgraded.
     pub fn contains(&self, package_name: &PackageName) -> bool {
         match &self.strategy {
-            UpgradeStrategy::None => false,
-            UpgradeStrategy::All => true,
-            UpgradeStrategy::Packages(packages) => packages.contains(package_name),
+            UpgradeSelection::None | UpgradeSelection::Requested => false,
+            UpgradeSelection::All => true,
+            UpgradeSelection::Packages(packages) => packages.contains(package_name),
+        }
+    }
+
+    /// Returns `true` if the dependencies of the upgraded packages should be upgraded, too.
+    pub fn is_eager(&self) -> bool {
+        self.eager
+    }
+
+    /// Apply an [`UpgradeStrategy`] to the selection of packages to upgrade.
+    ///
+    /// With [`UpgradeStrategy::OnlyIfNeeded`], upgrading all packages is narrowed to the direct
+    /// requirements, which must then be provided via [`Upgrade::with_requested`].
+    ///
+    /// With [`UpgradeStrategy::Eager`], upgrading specific packages is extended to their
+    /// dependencies, which are discovered during resolution.
+    #[must_use]
+    pub fn with_strategy(self, strategy: UpgradeStrategy) -> Self {
+        match (strategy, &self.strategy) {
+            (UpgradeStrategy::OnlyIfNeeded, UpgradeSelection::All) => Self {
+                strategy: UpgradeSelection::Requested,
+                ..self
+            },
+            (UpgradeStrategy::Eager, UpgradeSelection::Packages(_)) => Self {
+                eager: true,
+                ..self
+            },
+            _ => self,
+        }
+    }
+
+    /// Resolve an upgrade of the direct requirements to the given set of requested packages.
+    #[must_use]
+    pub fn with_requested<'a>(self, requested: impl IntoIterator<Item = &'a PackageName>) -> Self {
+        if matches!(self.strategy, UpgradeSelection::Requested) {
+            Self {
+                strategy: UpgradeSelection::Packages(requested.into_iter().cloned().collect()),
+                ..self
+            }
+        } else {
+            self
         }
     }
 
@@ -256,16 +325,17 @@ impl Upgrade {
     pub fn combine(self, other: Self) -> Self {
         // For `strategy`: `other` takes precedence for an explicit `All` or `None`; otherwise, merge.
         let strategy = match (self.strategy, other.strategy) {
-            (_, UpgradeStrategy::All) => UpgradeStrategy::All,
-            (_, UpgradeStrategy::None) => UpgradeStrategy::None,
+            (_, UpgradeSelection::All) => UpgradeSelection::All,
+            (_, UpgradeSelection::None) => UpgradeSelection::None,
+            (_, UpgradeSelection::Requested) => UpgradeSelection::Requested,
             (
-                UpgradeStrategy::Packages(mut self_packages),
-                UpgradeStrategy::Packages(other_packages),
+                UpgradeSelection::Packages(mut self_packages),
+                UpgradeSelection::Packages(other_packages),
             ) => {
                 self_packages.extend(other_packages);
-                UpgradeStrategy::Packages(self_packages)
+                UpgradeSelection::Packages(self_packages)
             }
-            (_, UpgradeStrategy::Packages(packages)) => UpgradeStrategy::Packages(packages),
+            (_, UpgradeSelection::Packages(packages)) => UpgradeSelection::Packages(packages),
         };
 
         // For `constraints`: always merge the constraints of `self` and `other`.
@@ -280,6 +350,7 @@ impl Upgrade {
         Self {
             strategy,
             constraints: combined_constraints,
+            eager: self.eager || other.eager,
         }
     }
 }
@@ -288,9 +359,12 @@ impl Upgrade {
 impl From<Upgrade> for Refresh {
     fn from(value: Upgrade) -> Self {
         match value.strategy {
-            UpgradeStrategy::None => Self::None(Timestamp::now()),
-            UpgradeStrategy::All => Self::All(Timestamp::now()),
-            UpgradeStrategy::Packages(packages) => Self::Packages(
+            UpgradeSelection::None => Self::None(Timestamp::now()),
+            // The requested packages (or their dependencies) aren't known yet, so refresh all
+            // packages.
+            UpgradeSelection::All | UpgradeSelection::Requested => Self::All(Timestamp::now()),
+            UpgradeSelection::Packages(_) if value.eager => Self::All(Timestamp::now()),
+            UpgradeSelection::Packages(packages) => Self::Packages(
                 packages.into_iter().collect::<Vec<_>>(),
                 Vec::new(),
                 Timestamp::now(),
@@ -17,6 +17,8 @@ use crate::generate_sysconfig_mappings::Args as GenerateSysconfigMetadataArgs;
 use crate::list_packages::ListPackagesArgs;
 #[cfg(feature = "render")]
 use crate::render_benchmarks::RenderBenchmarksArgs;
+use crate::resolve_many::{ResolveManyArgs, ResolveManyDiffArgs};
+use crate::serve_index::ServeIndexArgs;
 use crate::validate_zip::ValidateZipArgs;
 use crate::wheel_metadata::WheelMetadataArgs;
 
@@ -30,6 +32,8 @@ mod generate_options_reference;
 mod generate_sysconfig_mappings;
 mod list_packages;
 mod render_benchmarks;
+mod resolve_many;
+mod serve_index;
 mod validate_zip;
 mod wheel_metadata;
 
@@ -47,6 +51,12 @@ enum Cli {
     ClearCompile(ClearCompileArgs),
     /// List all packages from a Simple API index.
     ListPackages(ListPackagesArgs),
+    /// Serve a directory of wheels and source distributions as a Simple API index.
+    ServeIndex(ServeIndexArgs),
+    /// Resolve each requirement in a list independently and report the outcome of each.
+    ResolveMany(ResolveManyArgs),
+    /// Compare two `resolve-many` reports.
+    ResolveManyDiff(ResolveManyDiffArgs),
     /// Run all code and documentation generation steps.
     GenerateAll(GenerateAllArgs),
     /// Generate JSON schema for the TOML configuration file.
@@ -74,6 +84,9 @@ pub async fn run() -> Result<()> {
         Cli::Compile(args) => compile::compile(args).await?,
         Cli::ClearCompile(args) => clear_compile::clear_compile(&args)?,
         Cli::ListPackages(args) => list_packages::list_packages(args, environment).await?,
+        Cli::ServeIndex(args) => serve_index::serve_index(args).await?,
+        Cli::ResolveMany(args) => resolve_many::resolve_many(args).await?,
+        Cli::ResolveManyDiff(args) => resolve_many::resolve_many_diff(&args)?,
         Cli::GenerateAll(args) => generate_all::main(&args).await?,
         Cli::GenerateJSONSchema(args) => generate_json_schema::main(&args)?,
         Cli::GenerateOptionsReference(args) => generate_options_reference::main(&args)?,
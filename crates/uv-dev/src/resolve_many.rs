@@ -0,0 +1,424 @@
+use std::collections::BTreeMap;
+use std::env::consts::EXE_SUFFIX;
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+use std::time::{Duration, Instant};
+
+use anstream::{eprintln, println};
+use anyhow::{Context, Result, bail};
+use clap::Parser;
+use futures::StreamExt;
+use owo_colors::OwoColorize;
+use serde::{Deserialize, Serialize};
+use tokio::io::AsyncWriteExt;
+use tokio::process::Command;
+use tracing::debug;
+
+use uv_cache::CacheArgs;
+
+#[derive(Parser)]
+pub(crate) struct ResolveManyArgs {
+    /// A file containing one requirement per line (e.g., a list of popular PyPI packages).
+    list: PathBuf,
+    /// Write a report of the results to the given path.
+    ///
+    /// The report format is inferred from the file extension (`.csv` or `.json`).
+    #[arg(long, short)]
+    report: Option<PathBuf>,
+    /// Only resolve the first N requirements in the list.
+    #[arg(long)]
+    limit: Option<usize>,
+    /// The number of resolutions to run concurrently.
+    #[arg(long, default_value_t = 8)]
+    concurrency: usize,
+    /// Abort a resolution after the given number of seconds.
+    #[arg(long)]
+    timeout: Option<u64>,
+    /// The `uv` executable to resolve with.
+    ///
+    /// Defaults to the `uv` executable next to `uv-dev`, if it exists, and otherwise `uv` from
+    /// the `PATH`.
+    #[arg(long)]
+    uv: Option<PathBuf>,
+    /// The minimum Python version to resolve for.
+    #[arg(long)]
+    python_version: Option<String>,
+    /// Limit candidate packages to those that were uploaded prior to the given date.
+    #[arg(long)]
+    exclude_newer: Option<String>,
+    /// Additional arguments to pass to `uv pip compile`.
+    #[arg(last = true)]
+    extra_args: Vec<String>,
+    #[command(flatten)]
+    cache_args: CacheArgs,
+}
+
+#[derive(Parser)]
+pub(crate) struct ResolveManyDiffArgs {
+    /// The report from the baseline run.
+    baseline: PathBuf,
+    /// The report from the run to compare against the baseline.
+    candidate: PathBuf,
+}
+
+/// The outcome of resolving a single requirement.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+enum Status {
+    Success,
+    Failure,
+    Timeout,
+}
+
+impl std::fmt::Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Success => f.write_str("success"),
+            Self::Failure => f.write_str("failure"),
+            Self::Timeout => f.write_str("timeout"),
+        }
+    }
+}
+
+/// A single row in a `resolve-many` report.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Record {
+    /// The requirement that was resolved.
+    requirement: String,
+    /// The outcome of the resolution.
+    status: Status,
+    /// The number of packages in the resolution, if it succeeded.
+    packages: Option<usize>,
+    /// The wall-clock duration of the resolution, in milliseconds.
+    duration_ms: u64,
+    /// The error message, if the resolution failed.
+    reason: Option<String>,
+}
+
+/// The file format of a `resolve-many` report.
+#[derive(Debug, Clone, Copy)]
+enum ReportFormat {
+    Csv,
+    Json,
+}
+
+impl ReportFormat {
+    fn from_path(path: &Path) -> Result<Self> {
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some(extension) if extension.eq_ignore_ascii_case("csv") => Ok(Self::Csv),
+            Some(extension) if extension.eq_ignore_ascii_case("json") => Ok(Self::Json),
+            _ => bail!(
+                "Unsupported report format for `{}` (expected a `.csv` or `.json` file)",
+                path.display()
+            ),
+        }
+    }
+}
+
+fn write_report(path: &Path, records: &[Record]) -> Result<()> {
+    match ReportFormat::from_path(path)? {
+        ReportFormat::Csv => {
+            let mut writer = csv::Writer::from_path(path)
+                .with_context(|| format!("Failed to create `{}`", path.display()))?;
+            for record in records {
+                writer.serialize(record)?;
+            }
+            writer.flush()?;
+        }
+        ReportFormat::Json => {
+            let file = fs_err::File::create(path)?;
+            serde_json::to_writer_pretty(file, records)?;
+        }
+    }
+    Ok(())
+}
+
+fn read_report(path: &Path) -> Result<Vec<Record>> {
+    match ReportFormat::from_path(path)? {
+        ReportFormat::Csv => {
+            let mut reader = csv::Reader::from_path(path)
+                .with_context(|| format!("Failed to read `{}`", path.display()))?;
+            reader
+                .deserialize()
+                .collect::<Result<Vec<Record>, _>>()
+                .with_context(|| format!("Failed to parse `{}`", path.display()))
+        }
+        ReportFormat::Json => {
+            let contents = fs_err::read_to_string(path)?;
+            serde_json::from_str(&contents)
+                .with_context(|| format!("Failed to parse `{}`", path.display()))
+        }
+    }
+}
+
+/// Determine the `uv` executable to resolve with.
+fn find_uv(uv: Option<PathBuf>) -> PathBuf {
+    if let Some(uv) = uv {
+        return uv;
+    }
+    if let Ok(current_exe) = std::env::current_exe() {
+        let sibling = current_exe.with_file_name(format!("uv{EXE_SUFFIX}"));
+        if sibling.is_file() {
+            return sibling;
+        }
+    }
+    PathBuf::from("uv")
+}
+
+/// Reduce the `stderr` of a failed `uv pip compile` to a single-line error message.
+fn failure_reason(stderr: &str) -> String {
+    stderr
+        .lines()
+        .map(|line| {
+            line.trim()
+                .trim_start_matches('×')
+                .trim_start_matches("├─▶")
+                .trim_start_matches("╰─▶")
+                .trim_start_matches('│')
+                .trim()
+        })
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+async fn resolve(
+    uv: &Path,
+    requirement: String,
+    args: &ResolveManyArgs,
+    timeout: Option<Duration>,
+) -> Result<Record> {
+    let mut command = Command::new(uv);
+    command
+        .args([
+            "pip",
+            "compile",
+            "-",
+            "--universal",
+            "--no-header",
+            "--no-annotate",
+        ])
+        .args(["--color", "never"]);
+    if let Some(python_version) = &args.python_version {
+        command.arg("--python-version").arg(python_version);
+    }
+    if let Some(exclude_newer) = &args.exclude_newer {
+        command.arg("--exclude-newer").arg(exclude_newer);
+    }
+    if args.cache_args.no_cache {
+        command.arg("--no-cache");
+    }
+    if let Some(cache_dir) = &args.cache_args.cache_dir {
+        command.arg("--cache-dir").arg(cache_dir);
+    }
+    command
+        .args(&args.extra_args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .kill_on_drop(true);
+
+    let start = Instant::now();
+    let mut child = command
+        .spawn()
+        .with_context(|| format!("Failed to spawn `{}`", uv.display()))?;
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin.write_all(requirement.as_bytes()).await?;
+    }
+
+    let output = if let Some(timeout) = timeout {
+        match tokio::time::timeout(timeout, child.wait_with_output()).await {
+            Ok(output) => output?,
+            Err(_) => {
+                return Ok(Record {
+                    requirement,
+                    status: Status::Timeout,
+                    packages: None,
+                    duration_ms: duration_ms(start.elapsed()),
+                    reason: Some(format!("Timed out after {}s", timeout.as_secs())),
+                });
+            }
+        }
+    } else {
+        child.wait_with_output().await?
+    };
+    let duration = start.elapsed();
+
+    let record = if output.status.success() {
+        let packages = String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .count();
+        Record {
+            requirement,
+            status: Status::Success,
+            packages: Some(packages),
+            duration_ms: duration_ms(duration),
+            reason: None,
+        }
+    } else {
+        Record {
+            requirement,
+            status: Status::Failure,
+            packages: None,
+            duration_ms: duration_ms(duration),
+            reason: Some(failure_reason(&String::from_utf8_lossy(&output.stderr))),
+        }
+    };
+    Ok(record)
+}
+
+fn duration_ms(duration: Duration) -> u64 {
+    u64::try_from(duration.as_millis()).unwrap_or(u64::MAX)
+}
+
+pub(crate) async fn resolve_many(args: ResolveManyArgs) -> Result<()> {
+    let contents = fs_err::read_to_string(&args.list)?;
+    let requirements = contents
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(ToString::to_string)
+        .take(args.limit.unwrap_or(usize::MAX))
+        .collect::<Vec<_>>();
+
+    let uv = find_uv(args.uv.clone());
+    let timeout = args.timeout.map(Duration::from_secs);
+    debug!(
+        "Resolving {} requirements with `{}`",
+        requirements.len(),
+        uv.display()
+    );
+
+    let start = Instant::now();
+    let mut results = futures::stream::iter(requirements)
+        .map(|requirement| resolve(&uv, requirement, &args, timeout))
+        .buffer_unordered(args.concurrency.max(1));
+
+    let mut records = Vec::new();
+    while let Some(record) = results.next().await {
+        let record = record?;
+        match record.status {
+            Status::Success => eprintln!(
+                "{} {} ({}ms)",
+                "✓".green(),
+                record.requirement,
+                record.duration_ms
+            ),
+            Status::Failure | Status::Timeout => eprintln!(
+                "{} {} ({}ms): {}",
+                "✗".red(),
+                record.requirement,
+                record.duration_ms,
+                record.reason.as_deref().unwrap_or_default()
+            ),
+        }
+        records.push(record);
+    }
+    drop(results);
+
+    records.sort_by(|a, b| a.requirement.cmp(&b.requirement));
+
+    let successes = records
+        .iter()
+        .filter(|record| record.status == Status::Success)
+        .count();
+    println!(
+        "Resolved {successes}/{} requirements in {:.2}s",
+        records.len(),
+        start.elapsed().as_secs_f64()
+    );
+
+    if let Some(report) = &args.report {
+        write_report(report, &records)?;
+        println!("Wrote report to `{}`", report.display());
+    }
+
+    Ok(())
+}
+
+pub(crate) fn resolve_many_diff(args: &ResolveManyDiffArgs) -> Result<()> {
+    let baseline = read_report(&args.baseline)?
+        .into_iter()
+        .map(|record| (record.requirement.clone(), record))
+        .collect::<BTreeMap<_, _>>();
+    let candidate = read_report(&args.candidate)?
+        .into_iter()
+        .map(|record| (record.requirement.clone(), record))
+        .collect::<BTreeMap<_, _>>();
+
+    let mut regressions = 0;
+    let mut fixes = 0;
+    for (requirement, after) in &candidate {
+        let Some(before) = baseline.get(requirement) else {
+            println!("{} {requirement}: {} (new)", "+".cyan(), after.status);
+            continue;
+        };
+        if before.status == after.status {
+            if before.packages != after.packages {
+                println!(
+                    "{} {requirement}: {} -> {} packages",
+                    "~".yellow(),
+                    before.packages.unwrap_or_default(),
+                    after.packages.unwrap_or_default()
+                );
+            }
+            continue;
+        }
+        if after.status == Status::Success {
+            fixes += 1;
+            println!(
+                "{} {requirement}: {} -> {}",
+                "✓".green(),
+                before.status,
+                after.status
+            );
+        } else {
+            regressions += 1;
+            println!(
+                "{} {requirement}: {} -> {}: {}",
+                "✗".red(),
+                before.status,
+                after.status,
+                after.reason.as_deref().unwrap_or_default()
+            );
+        }
+    }
+    for (requirement, before) in &baseline {
+        if !candidate.contains_key(requirement) {
+            println!("{} {requirement}: {} (removed)", "-".cyan(), before.status);
+        }
+    }
+
+    let total_duration = |records: &BTreeMap<String, Record>| {
+        records
+            .values()
+            .map(|record| record.duration_ms)
+            .sum::<u64>()
+    };
+    println!(
+        "{regressions} regression(s), {fixes} fix(es); total duration {}ms -> {}ms",
+        total_duration(&baseline),
+        total_duration(&candidate)
+    );
+
+    if regressions > 0 {
+        bail!("{regressions} requirement(s) failed to resolve that previously succeeded");
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::failure_reason;
+
+    #[test]
+    fn failure_reason_single_line() {
+        let stderr = "  × No solution found when resolving dependencies:\n  ╰─▶ Because there are no versions of foo and you require foo, we can conclude that your requirements are unsatisfiable.\n";
+        assert_eq!(
+            failure_reason(stderr),
+            "No solution found when resolving dependencies: Because there are no versions of foo and you require foo, we can conclude that your requirements are unsatisfiable."
+        );
+    }
+}
@@ -0,0 +1,369 @@
+use std::collections::BTreeMap;
+use std::net::SocketAddr;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
+
+use anstream::eprintln;
+use anyhow::{Context, Result};
+use base64::Engine;
+use base64::prelude::BASE64_STANDARD;
+use clap::Parser;
+use http_body_util::Full;
+use hyper::body::{Bytes, Incoming};
+use hyper::header::{ACCEPT, AUTHORIZATION, CONTENT_TYPE, LOCATION, WWW_AUTHENTICATE};
+use hyper::server::conn::http1;
+use hyper::service::service_fn;
+use hyper::{Method, Request, Response, StatusCode};
+use hyper_util::rt::TokioIo;
+use owo_colors::OwoColorize;
+use serde_json::json;
+use sha2::{Digest, Sha256};
+use tokio::net::TcpListener;
+use tracing::{debug, warn};
+
+use uv_client::{SimpleHtmlFile, simple_detail_html, simple_index_html};
+use uv_distribution_filename::DistFilename;
+use uv_normalize::PackageName;
+
+/// The media type for the JSON-based Simple API (PEP 691).
+const JSON_CONTENT_TYPE: &str = "application/vnd.pypi.simple.v1+json";
+
+/// The media type for the HTML-based Simple API (PEP 503).
+const HTML_CONTENT_TYPE: &str = "text/html";
+
+#[derive(Parser)]
+pub(crate) struct ServeIndexArgs {
+    /// The directory containing the wheels and source distributions to serve.
+    directory: PathBuf,
+    /// The address to listen on.
+    #[arg(long, default_value = "127.0.0.1:8000")]
+    bind: SocketAddr,
+    /// Require HTTP basic authentication with the given username.
+    #[arg(long, requires = "password")]
+    username: Option<String>,
+    /// Require HTTP basic authentication with the given password.
+    #[arg(long, requires = "username")]
+    password: Option<String>,
+    /// Delay every response by the given number of milliseconds.
+    #[arg(long, default_value_t = 0)]
+    latency: u64,
+}
+
+pub(crate) async fn serve_index(args: ServeIndexArgs) -> Result<()> {
+    let index = Arc::new(LocalIndex::from_directory(
+        &args.directory,
+        args.username.zip(args.password),
+        Duration::from_millis(args.latency),
+    )?);
+
+    let listener = TcpListener::bind(args.bind)
+        .await
+        .with_context(|| format!("Failed to bind to `{}`", args.bind))?;
+    eprintln!(
+        "Serving {} packages at {}",
+        index.packages.len(),
+        format!("http://{}/simple/", listener.local_addr()?).cyan()
+    );
+
+    serve(listener, index).await
+}
+
+/// Accept connections on the listener and serve the index until the task is cancelled.
+async fn serve(listener: TcpListener, index: Arc<LocalIndex>) -> Result<()> {
+    loop {
+        let (stream, _) = listener.accept().await?;
+        let index = index.clone();
+        tokio::spawn(async move {
+            let service = service_fn(move |request| {
+                let index = index.clone();
+                async move { index.respond(&request).await }
+            });
+            if let Err(err) = http1::Builder::new()
+                .serve_connection(TokioIo::new(stream), service)
+                .await
+            {
+                debug!("Failed to serve connection: {err}");
+            }
+        });
+    }
+}
+
+/// A distribution in the served directory.
+struct LocalFile {
+    filename: String,
+    path: PathBuf,
+    sha256: String,
+}
+
+/// A directory of distributions, grouped by package name.
+struct LocalIndex {
+    packages: BTreeMap<PackageName, Vec<LocalFile>>,
+    /// The expected `Authorization` header, if authentication is required.
+    authorization: Option<String>,
+    latency: Duration,
+}
+
+impl LocalIndex {
+    /// Collect the wheels and source distributions at the top level of the given directory.
+    fn from_directory(
+        directory: &Path,
+        credentials: Option<(String, String)>,
+        latency: Duration,
+    ) -> Result<Self> {
+        let mut packages: BTreeMap<PackageName, Vec<LocalFile>> = BTreeMap::new();
+        for entry in fs_err::read_dir(directory)? {
+            let entry = entry?;
+            let Some(filename) = entry.file_name().to_str().map(ToString::to_string) else {
+                continue;
+            };
+            let Some(dist_filename) = DistFilename::try_from_normalized_filename(&filename) else {
+                debug!("Ignoring non-distribution file: {filename}");
+                continue;
+            };
+            let path = entry.path();
+            let sha256 = format!("{:x}", Sha256::digest(fs_err::read(&path)?));
+            packages
+                .entry(dist_filename.name().clone())
+                .or_default()
+                .push(LocalFile {
+                    filename,
+                    path,
+                    sha256,
+                });
+        }
+        for files in packages.values_mut() {
+            files.sort_by(|a, b| a.filename.cmp(&b.filename));
+        }
+        if packages.is_empty() {
+            warn!("No distributions found in `{}`", directory.display());
+        }
+
+        let authorization = credentials.map(|(username, password)| {
+            format!(
+                "Basic {}",
+                BASE64_STANDARD.encode(format!("{username}:{password}"))
+            )
+        });
+
+        Ok(Self {
+            packages,
+            authorization,
+            latency,
+        })
+    }
+
+    async fn respond(&self, request: &Request<Incoming>) -> Result<Response<Full<Bytes>>> {
+        if !self.latency.is_zero() {
+            tokio::time::sleep(self.latency).await;
+        }
+
+        if let Some(expected) = &self.authorization {
+            let provided = request
+                .headers()
+                .get(AUTHORIZATION)
+                .and_then(|value| value.to_str().ok());
+            if provided != Some(expected.as_str()) {
+                return Ok(Response::builder()
+                    .status(StatusCode::UNAUTHORIZED)
+                    .header(WWW_AUTHENTICATE, "Basic realm=\"uv\"")
+                    .body(Full::default())?);
+            }
+        }
+
+        if request.method() != Method::GET && request.method() != Method::HEAD {
+            return status(StatusCode::METHOD_NOT_ALLOWED);
+        }
+
+        let json = request
+            .headers()
+            .get(ACCEPT)
+            .and_then(|value| value.to_str().ok())
+            .is_some_and(|accept| accept.contains(JSON_CONTENT_TYPE));
+
+        let path = request.uri().path();
+        if path == "/simple" || path == "/simple/" {
+            return self.project_list(json);
+        }
+        if let Some(project) = path.strip_prefix("/simple/") {
+            return self.project_detail(project.trim_end_matches('/'), json);
+        }
+        if let Some(filename) = path.strip_prefix("/files/") {
+            return self.file(filename).await;
+        }
+        status(StatusCode::NOT_FOUND)
+    }
+
+    /// Render the list of all projects (the root of the Simple API).
+    fn project_list(&self, json: bool) -> Result<Response<Full<Bytes>>> {
+        if json {
+            let projects = self
+                .packages
+                .keys()
+                .map(|name| json!({ "name": name }))
+                .collect::<Vec<_>>();
+            let body = json!({ "meta": { "api-version": "1.1" }, "projects": projects });
+            return content(JSON_CONTENT_TYPE, body.to_string());
+        }
+
+        content(HTML_CONTENT_TYPE, simple_index_html(self.packages.keys()))
+    }
+
+    /// Render the files for a single project.
+    fn project_detail(&self, project: &str, json: bool) -> Result<Response<Full<Bytes>>> {
+        let Ok(name) = PackageName::from_str(project) else {
+            return status(StatusCode::NOT_FOUND);
+        };
+
+        // Per PEP 503, redirect non-normalized names to the normalized URL.
+        if name.as_str() != project {
+            return Ok(Response::builder()
+                .status(StatusCode::MOVED_PERMANENTLY)
+                .header(LOCATION, format!("/simple/{name}/"))
+                .body(Full::default())?);
+        }
+
+        let Some(files) = self.packages.get(&name) else {
+            return status(StatusCode::NOT_FOUND);
+        };
+
+        if json {
+            let files = files
+                .iter()
+                .map(|file| {
+                    json!({
+                        "filename": file.filename,
+                        "url": format!("../../files/{}", file.filename),
+                        "hashes": { "sha256": file.sha256 },
+                    })
+                })
+                .collect::<Vec<_>>();
+            let body = json!({ "meta": { "api-version": "1.1" }, "name": name, "files": files });
+            return content(JSON_CONTENT_TYPE, body.to_string());
+        }
+
+        let urls = files
+            .iter()
+            .map(|file| format!("../../files/{}", file.filename))
+            .collect::<Vec<_>>();
+        let body = simple_detail_html(
+            &name,
+            files.iter().zip(&urls).map(|(file, url)| SimpleHtmlFile {
+                filename: &file.filename,
+                url,
+                sha256: &file.sha256,
+            }),
+        );
+        content(HTML_CONTENT_TYPE, body)
+    }
+
+    /// Serve the contents of a distribution.
+    async fn file(&self, filename: &str) -> Result<Response<Full<Bytes>>> {
+        let Some(file) = self
+            .packages
+            .values()
+            .flatten()
+            .find(|file| file.filename == filename)
+        else {
+            return status(StatusCode::NOT_FOUND);
+        };
+        let contents = fs_err::tokio::read(&file.path).await?;
+        Ok(Response::builder()
+            .header(CONTENT_TYPE, "application/octet-stream")
+            .body(Full::new(Bytes::from(contents)))?)
+    }
+}
+
+fn content(content_type: &str, body: String) -> Result<Response<Full<Bytes>>> {
+    Ok(Response::builder()
+        .header(CONTENT_TYPE, content_type)
+        .body(Full::new(Bytes::from(body)))?)
+}
+
+fn status(status: StatusCode) -> Result<Response<Full<Bytes>>> {
+    Ok(Response::builder().status(status).body(Full::default())?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Start a server for a directory with a single wheel, returning its base URL.
+    async fn start(credentials: Option<(String, String)>) -> Result<(tempfile::TempDir, String)> {
+        let directory = tempfile::tempdir()?;
+        fs_err::write(
+            directory.path().join("iniconfig-2.0.0-py3-none-any.whl"),
+            b"wheel",
+        )?;
+        fs_err::write(directory.path().join("README.md"), b"readme")?;
+
+        let index = LocalIndex::from_directory(directory.path(), credentials, Duration::ZERO)?;
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let url = format!("http://{}", listener.local_addr()?);
+        tokio::spawn(serve(listener, Arc::new(index)));
+        Ok((directory, url))
+    }
+
+    #[tokio::test]
+    async fn serve_simple_index() -> Result<()> {
+        let (_directory, url) = start(None).await?;
+        let client = reqwest::Client::new();
+
+        let root = client.get(format!("{url}/simple/")).send().await?;
+        assert_eq!(root.status(), StatusCode::OK);
+        assert!(
+            root.text()
+                .await?
+                .contains("<a href=\"iniconfig/\">iniconfig</a>")
+        );
+
+        let detail = client
+            .get(format!("{url}/simple/iniconfig/"))
+            .header(ACCEPT, JSON_CONTENT_TYPE)
+            .send()
+            .await?;
+        let detail: serde_json::Value = detail.json().await?;
+        assert_eq!(
+            detail["files"][0]["url"],
+            "../../files/iniconfig-2.0.0-py3-none-any.whl"
+        );
+        assert_eq!(
+            detail["files"][0]["hashes"]["sha256"],
+            format!("{:x}", Sha256::digest(b"wheel"))
+        );
+
+        let redirect = client
+            .get(format!("{url}/simple/IniConfig/"))
+            .send()
+            .await?;
+        assert_eq!(redirect.url().path(), "/simple/iniconfig/");
+
+        let file = client
+            .get(format!("{url}/files/iniconfig-2.0.0-py3-none-any.whl"))
+            .send()
+            .await?;
+        assert_eq!(file.bytes().await?.as_ref(), b"wheel");
+
+        let missing = client.get(format!("{url}/files/README.md")).send().await?;
+        assert_eq!(missing.status(), StatusCode::NOT_FOUND);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn serve_simple_index_with_authentication() -> Result<()> {
+        let (_directory, url) = start(Some(("user".to_string(), "pass".to_string()))).await?;
+        let client = reqwest::Client::new();
+
+        let anonymous = client.get(format!("{url}/simple/")).send().await?;
+        assert_eq!(anonymous.status(), StatusCode::UNAUTHORIZED);
+
+        let authenticated = client
+            .get(format!("{url}/simple/"))
+            .basic_auth("user", Some("pass"))
+            .send()
+            .await?;
+        assert_eq!(authenticated.status(), StatusCode::OK);
+        Ok(())
+    }
+}
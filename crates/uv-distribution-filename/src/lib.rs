@@ -1,5 +1,6 @@
+use std::borrow::Cow;
 use std::fmt::{Display, Formatter};
-use std::str::FromStr;
+use std::str::{FromStr, Utf8Error};
 
 use uv_normalize::PackageName;
 use uv_pep440::Version;
@@ -89,6 +90,27 @@ impl DistFilename {
     }
 }
 
+/// Normalize a distribution filename as served by a remote index.
+///
+/// Some mirrors serve filenames that are still percent-encoded (e.g., `%2B` in place of `+`), or
+/// that were form-decoded such that the `+` in a local version (e.g., `2.5.1+cpu`) became a space.
+/// Since neither `%` nor a space can appear in a valid wheel or source distribution filename, the
+/// filename is percent-decoded and any spaces are replaced with `+`.
+///
+/// Returns an error if the percent-decoded filename is not valid UTF-8.
+pub fn normalize_remote_filename(filename: &str) -> Result<Cow<'_, str>, Utf8Error> {
+    let filename = if filename.contains('%') {
+        percent_encoding::percent_decode_str(filename).decode_utf8()?
+    } else {
+        Cow::Borrowed(filename)
+    };
+    if filename.contains(' ') {
+        Ok(Cow::Owned(filename.replace(' ', "+")))
+    } else {
+        Ok(filename)
+    }
+}
+
 impl Display for DistFilename {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -100,10 +122,46 @@ impl Display for DistFilename {
 
 #[cfg(test)]
 mod tests {
-    use crate::WheelFilename;
+    use std::str::FromStr;
+
+    use crate::{WheelFilename, normalize_remote_filename};
 
     #[test]
     fn wheel_filename_size() {
         assert_eq!(size_of::<WheelFilename>(), 48);
     }
+
+    #[test]
+    fn normalize_remote_filename_unchanged() {
+        let filename = "torch-2.5.1+cpu-cp312-cp312-linux_x86_64.whl";
+        assert_eq!(normalize_remote_filename(filename).unwrap(), filename);
+    }
+
+    #[test]
+    fn normalize_remote_filename_percent_encoded() {
+        let filename =
+            normalize_remote_filename("torch-2.5.1%2Bcpu-cp312-cp312-linux_x86_64.whl").unwrap();
+        assert_eq!(filename, "torch-2.5.1+cpu-cp312-cp312-linux_x86_64.whl");
+        let filename = WheelFilename::from_str(&filename).unwrap();
+        assert_eq!(filename.version.to_string(), "2.5.1+cpu");
+    }
+
+    #[test]
+    fn normalize_remote_filename_double_encoded() {
+        let filename =
+            normalize_remote_filename("torch-2.5.1%252Bcpu-cp312-cp312-linux_x86_64.whl").unwrap();
+        assert_eq!(filename, "torch-2.5.1%2Bcpu-cp312-cp312-linux_x86_64.whl");
+    }
+
+    #[test]
+    fn normalize_remote_filename_space() {
+        let filename =
+            normalize_remote_filename("torch-2.5.1 cpu-cp312-cp312-linux_x86_64.whl").unwrap();
+        assert_eq!(filename, "torch-2.5.1+cpu-cp312-cp312-linux_x86_64.whl");
+    }
+
+    #[test]
+    fn normalize_remote_filename_invalid_utf8() {
+        assert!(normalize_remote_filename("torch-2.5.1%FF-cp312-cp312-linux_x86_64.whl").is_err());
+    }
 }
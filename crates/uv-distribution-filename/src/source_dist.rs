@@ -47,32 +47,24 @@ impl SourceDistFilename {
 
         let stem = &filename[..(filename.len() - (extension.name().len() + 1))];
 
-        if stem.len() <= package_name.as_ref().len() + "-".len() {
+        // Split the stem at the first `-` that's preceded by the package name. The name in the
+        // filename isn't necessarily normalized (e.g., `Foo__Bar-1.0.tar.gz` for `foo-bar`), so it
+        // may differ in length from the package name.
+        let Some(version) = stem.match_indices('-').find_map(|(index, _)| {
+            PackageName::from_str(&stem[..index])
+                .is_ok_and(|name| name == *package_name)
+                .then(|| &stem[index + "-".len()..])
+        }) else {
             return Err(SourceDistFilenameError {
                 filename: filename.to_string(),
                 kind: SourceDistFilenameErrorKind::Filename(package_name.clone()),
             });
-        }
-        let actual_package_name = PackageName::from_str(&stem[..package_name.as_ref().len()])
-            .map_err(|err| SourceDistFilenameError {
-                filename: filename.to_string(),
-                kind: SourceDistFilenameErrorKind::PackageName(err),
-            })?;
-        if actual_package_name != *package_name {
-            return Err(SourceDistFilenameError {
-                filename: filename.to_string(),
-                kind: SourceDistFilenameErrorKind::Filename(package_name.clone()),
-            });
-        }
+        };
 
-        // We checked the length above
-        let version =
-            Version::from_str(&stem[package_name.as_ref().len() + "-".len()..]).map_err(|err| {
-                SourceDistFilenameError {
-                    filename: filename.to_string(),
-                    kind: SourceDistFilenameErrorKind::Version(err),
-                }
-            })?;
+        let version = Version::from_str(version).map_err(|err| SourceDistFilenameError {
+            filename: filename.to_string(),
+            kind: SourceDistFilenameErrorKind::Version(err),
+        })?;
 
         Ok(Self {
             name: package_name.clone(),
@@ -224,6 +216,38 @@ mod tests {
         }
     }
 
+    #[test]
+    fn non_normalized_name() {
+        for filename in [
+            "Foo_Lib-1.2.3.tar.gz",
+            "foo__lib-1.2.3.tar.gz",
+            "foo.-lib-1.2.3.tar.gz",
+            "FOO-LIB-1.2.3.tar.gz",
+        ] {
+            let ext = SourceDistExtension::from_path(filename).unwrap();
+            let parsed = SourceDistFilename::parse(
+                filename,
+                ext,
+                &PackageName::from_str("foo-lib").unwrap(),
+            )
+            .unwrap();
+            assert_eq!(parsed.to_string(), "foo_lib-1.2.3.tar.gz");
+        }
+    }
+
+    #[test]
+    fn non_ascii_name() {
+        // The name must not be sliced within a multi-byte character.
+        assert!(
+            SourceDistFilename::parse(
+                "fóo-1.2.3.tar.gz",
+                SourceDistExtension::TarGz,
+                &PackageName::from_str("fo").unwrap()
+            )
+            .is_err()
+        );
+    }
+
     #[test]
     fn name_too_long() {
         assert!(
@@ -1,4 +1,5 @@
 use std::collections::BTreeMap;
+use std::str::FromStr;
 
 use serde::{Deserialize, Serialize};
 
@@ -174,6 +175,19 @@ impl FromIterator<(PackageName, BuildVariables)> for ExtraBuildVariables {
     }
 }
 
+impl FromIterator<ExtraBuildVariableEntry> for ExtraBuildVariables {
+    fn from_iter<T: IntoIterator<Item = ExtraBuildVariableEntry>>(iter: T) -> Self {
+        let mut variables = Self::default();
+        for entry in iter {
+            variables
+                .entry(entry.package)
+                .or_default()
+                .insert(entry.key, entry.value);
+        }
+        variables
+    }
+}
+
 impl CacheKey for ExtraBuildVariables {
     fn cache_key(&self, state: &mut CacheKeyHasher) {
         for (package, vars) in &self.0 {
@@ -185,3 +199,85 @@ impl CacheKey for ExtraBuildVariables {
         }
     }
 }
+
+/// An extra environment variable to set when building a specific package, specified as
+/// `PACKAGE:VAR=VALUE`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExtraBuildVariableEntry {
+    /// The package name to apply the variable to.
+    package: PackageName,
+    /// The name of the environment variable.
+    key: String,
+    /// The value of the environment variable.
+    value: String,
+}
+
+impl FromStr for ExtraBuildVariableEntry {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some((package_str, variable_str)) = s.split_once(':') else {
+            return Err(format!(
+                "Invalid build variable: {s} (expected `PACKAGE:VAR=VALUE`)"
+            ));
+        };
+
+        let package = PackageName::from_str(package_str.trim())
+            .map_err(|err| format!("Invalid package name: {err}"))?;
+
+        let Some((key, value)) = variable_str.split_once('=') else {
+            return Err(format!(
+                "Invalid build variable: {s} (expected `PACKAGE:VAR=VALUE`)"
+            ));
+        };
+
+        let key = key.trim();
+        if key.is_empty() {
+            return Err(format!(
+                "Invalid build variable: {s} (variable name cannot be empty)"
+            ));
+        }
+
+        Ok(Self {
+            package,
+            key: key.to_string(),
+            value: value.to_string(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn collect_extra_build_variables() {
+        let variables: ExtraBuildVariables = [
+            "pycurl:PYCURL_SSL_LIBRARY=openssl",
+            "mysqlclient:MYSQLCLIENT_CFLAGS=-I/usr/include/mysql",
+            "pycurl:PYCURL_CURL_CONFIG=/usr/bin/curl-config",
+            "pycurl:PYCURL_SSL_LIBRARY=gnutls",
+        ]
+        .into_iter()
+        .map(|entry| ExtraBuildVariableEntry::from_str(entry).unwrap())
+        .collect();
+
+        let pycurl = &variables[&PackageName::from_str("pycurl").unwrap()];
+        assert_eq!(pycurl["PYCURL_SSL_LIBRARY"], "gnutls");
+        assert_eq!(pycurl["PYCURL_CURL_CONFIG"], "/usr/bin/curl-config");
+
+        let mysqlclient = &variables[&PackageName::from_str("mysqlclient").unwrap()];
+        assert_eq!(mysqlclient["MYSQLCLIENT_CFLAGS"], "-I/usr/include/mysql");
+    }
+
+    #[test]
+    fn parse_extra_build_variable_entry() {
+        let entry = ExtraBuildVariableEntry::from_str("pycurl:OPTS=a=b").unwrap();
+        assert_eq!(entry.key, "OPTS");
+        assert_eq!(entry.value, "a=b");
+
+        assert!(ExtraBuildVariableEntry::from_str("PYCURL_SSL_LIBRARY=openssl").is_err());
+        assert!(ExtraBuildVariableEntry::from_str("pycurl:PYCURL_SSL_LIBRARY").is_err());
+        assert!(ExtraBuildVariableEntry::from_str("pycurl:=openssl").is_err());
+    }
+}
@@ -8,6 +8,7 @@ use version_ranges::Ranges;
 
 use uv_normalize::{ExtraName, GroupName, PackageName};
 use uv_pep440::Version;
+use uv_pep508::MarkerTree;
 
 use crate::{
     BuiltDist, Dist, DistRef, Edge, Name, Node, RequestedDist, Resolution, ResolvedDist, SourceDist,
@@ -72,11 +73,20 @@ impl Display for DistErrorKind {
 /// A chain of derivation steps from the root package to the current package, to explain why a
 /// package is included in the resolution.
 #[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
-pub struct DerivationChain(Vec<DerivationStep>);
+pub struct DerivationChain {
+    /// The steps from the root package to the current package.
+    steps: Vec<DerivationStep>,
+    /// The markers under which the current package was resolved (e.g., the markers of the
+    /// resolver fork, combined with the markers on the requirements along the chain).
+    marker: MarkerTree,
+}
 
 impl FromIterator<DerivationStep> for DerivationChain {
     fn from_iter<T: IntoIterator<Item = DerivationStep>>(iter: T) -> Self {
-        Self(iter.into_iter().collect())
+        Self {
+            steps: iter.into_iter().collect(),
+            marker: MarkerTree::TRUE,
+        }
     }
 }
 
@@ -142,19 +152,31 @@ impl DerivationChain {
         None
     }
 
+    /// Set the markers under which the current package was resolved.
+    #[must_use]
+    pub fn with_marker(mut self, marker: MarkerTree) -> Self {
+        self.marker = marker;
+        self
+    }
+
+    /// Returns the markers under which the current package was resolved.
+    pub fn marker(&self) -> MarkerTree {
+        self.marker
+    }
+
     /// Returns the length of the derivation chain.
     pub fn len(&self) -> usize {
-        self.0.len()
+        self.steps.len()
     }
 
     /// Returns `true` if the derivation chain is empty.
     pub fn is_empty(&self) -> bool {
-        self.0.is_empty()
+        self.steps.is_empty()
     }
 
     /// Returns an iterator over the steps in the derivation chain.
     pub fn iter(&self) -> std::slice::Iter<'_, DerivationStep> {
-        self.0.iter()
+        self.steps.iter()
     }
 }
 
@@ -163,7 +185,7 @@ impl<'chain> IntoIterator for &'chain DerivationChain {
     type IntoIter = std::slice::Iter<'chain, DerivationStep>;
 
     fn into_iter(self) -> Self::IntoIter {
-        self.0.iter()
+        self.steps.iter()
     }
 }
 
@@ -172,7 +194,7 @@ impl IntoIterator for DerivationChain {
     type IntoIter = std::vec::IntoIter<DerivationStep>;
 
     fn into_iter(self) -> Self::IntoIter {
-        self.0.into_iter()
+        self.steps.into_iter()
     }
 }
 
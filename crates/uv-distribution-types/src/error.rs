@@ -12,6 +12,9 @@ pub enum Error {
     #[error(transparent)]
     WheelFilename(#[from] uv_distribution_filename::WheelFilenameError),
 
+    #[error("Failed to parse the wheel filename in URL: {0}")]
+    WheelFilenameUrl(String, #[source] uv_distribution_filename::WheelFilenameError),
+
     #[error("Could not extract path segments from URL: {0}")]
     MissingPathSegments(String),
 
@@ -29,8 +29,14 @@ pub enum FileConversionError {
 #[rkyv(derive(Debug))]
 pub struct File {
     pub dist_info_metadata: bool,
+    /// The hashes of the [PEP 658](https://peps.python.org/pep-0658/) metadata file, if provided by
+    /// the index.
+    pub dist_info_metadata_hashes: HashDigests,
     pub filename: SmallString,
     pub hashes: HashDigests,
+    /// The location of the [PEP 740](https://peps.python.org/pep-0740/) provenance object for the
+    /// file, if provided by the index.
+    pub provenance: Option<Box<FileLocation>>,
     pub requires_python: Option<VersionSpecifiers>,
     pub size: Option<u64>,
     // N.B. We don't use a Jiff timestamp here because it's a little
@@ -54,8 +60,18 @@ impl File {
                 .core_metadata
                 .as_ref()
                 .is_some_and(CoreMetadata::is_available),
+            dist_info_metadata_hashes: file
+                .core_metadata
+                .as_ref()
+                .and_then(CoreMetadata::hashes)
+                .cloned()
+                .map(HashDigests::from)
+                .unwrap_or_else(HashDigests::empty),
             filename: file.filename,
             hashes: HashDigests::from(file.hashes),
+            provenance: file
+                .provenance
+                .map(|provenance| Box::new(FileLocation::new(provenance, base))),
             requires_python: file
                 .requires_python
                 .transpose()
@@ -101,7 +117,15 @@ impl File {
                 .core_metadata
                 .as_ref()
                 .is_some_and(CoreMetadata::is_available),
+            dist_info_metadata_hashes: file
+                .core_metadata
+                .as_ref()
+                .and_then(CoreMetadata::hashes)
+                .cloned()
+                .map(HashDigests::from)
+                .unwrap_or_else(HashDigests::empty),
             hashes: HashDigests::from(file.hashes),
+            provenance: None,
             requires_python: file
                 .requires_python
                 .transpose()
@@ -1,13 +1,15 @@
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 use url::Url;
 
-use uv_auth::{AuthPolicy, Credentials};
+use uv_auth::{AuthPolicy, Credentials, IndexCredentials};
+use uv_fs::Simplified;
 use uv_redacted::DisplaySafeUrl;
 use uv_small_str::SmallString;
+use uv_warnings::warn_user_once;
 
 use crate::index_name::{IndexName, IndexNameError};
 use crate::origin::Origin;
@@ -133,6 +135,44 @@ pub struct Index {
     /// ```
     #[serde(default)]
     pub authenticate: AuthPolicy,
+    /// The credentials to use for requests to the index.
+    ///
+    /// Supports a `username` and `password` for HTTP basic authentication, a `token` for bearer
+    /// authentication, or a `keyring-service` to query the keyring for a password under a given
+    /// service name. Values may reference environment variables with the `${NAME}` syntax, which
+    /// are expanded when the credentials are read.
+    ///
+    /// Credentials provided via the `UV_INDEX_{name}_USERNAME` and `UV_INDEX_{name}_PASSWORD`
+    /// environment variables take precedence over those configured here, which in turn take
+    /// precedence over credentials embedded in the index URL.
+    ///
+    /// For security, credentials are ignored in project-level configuration (i.e., a `uv.toml`
+    /// or `pyproject.toml` file in a project, or script metadata); configure them in a user-level
+    /// or system-level `uv.toml` file, or in a file passed via `--config-file`.
+    ///
+    /// ```toml
+    /// [[index]]
+    /// name = "my-index"
+    /// url = "https://<omitted>/simple"
+    /// credentials = { username = "ci", password = "${MY_INDEX_PASSWORD}" }
+    /// ```
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub credentials: Option<IndexCredentials>,
+    /// A bundle of certificate authority (CA) certificates to trust for requests to the index.
+    ///
+    /// Expects a path to a PEM file, which may contain multiple certificates. The certificates are
+    /// trusted in addition to the default trust roots for all requests to the index's host,
+    /// including distribution downloads. Useful for indexes that are served with a certificate
+    /// signed by a private CA. Relative paths are resolved relative to the project root.
+    ///
+    /// ```toml
+    /// [[tool.uv.index]]
+    /// name = "my-index"
+    /// url = "https://<omitted>/simple"
+    /// ca-bundle = "certs/internal-ca.pem"
+    /// ```
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ca_bundle: Option<PathBuf>,
     /// Status codes that uv should ignore when deciding whether
     /// to continue searching in the next index after a failure.
     ///
@@ -157,6 +197,23 @@ pub struct Index {
     /// ```
     #[serde(default)]
     pub cache_control: Option<IndexCacheControl>,
+    /// Alternate URLs that serve the same content as the index, in order of preference.
+    ///
+    /// If a request to the index URL fails due to a network error, a timeout, or a server error
+    /// (e.g., a `503 Service Unavailable`), uv will retry the request against each mirror in turn.
+    /// This applies to Simple API pages and to distributions hosted under the index's root URL.
+    /// URLs that fail repeatedly are skipped for a short period, to avoid waiting on an outage.
+    /// Mirrors share the index's cache and credentials, and are not consulted for packages that
+    /// the index reports as missing.
+    ///
+    /// ```toml
+    /// [[tool.uv.index]]
+    /// name = "pypi"
+    /// url = "https://pypi.org/simple"
+    /// mirrors = ["https://pypi-mirror.example.com/simple"]
+    /// ```
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub mirrors: Vec<IndexUrl>,
 }
 
 impl PartialEq for Index {
@@ -170,8 +227,11 @@ impl PartialEq for Index {
             format,
             publish_url,
             authenticate,
+            credentials,
+            ca_bundle,
             ignore_error_codes,
             cache_control,
+            mirrors,
         } = self;
         *url == other.url
             && *name == other.name
@@ -180,8 +240,11 @@ impl PartialEq for Index {
             && *format == other.format
             && *publish_url == other.publish_url
             && *authenticate == other.authenticate
+            && *credentials == other.credentials
+            && *ca_bundle == other.ca_bundle
             && *ignore_error_codes == other.ignore_error_codes
             && *cache_control == other.cache_control
+            && *mirrors == other.mirrors
     }
 }
 
@@ -204,8 +267,11 @@ impl Ord for Index {
             format,
             publish_url,
             authenticate,
+            credentials,
+            ca_bundle,
             ignore_error_codes,
             cache_control,
+            mirrors,
         } = self;
         url.cmp(&other.url)
             .then_with(|| name.cmp(&other.name))
@@ -214,8 +280,11 @@ impl Ord for Index {
             .then_with(|| format.cmp(&other.format))
             .then_with(|| publish_url.cmp(&other.publish_url))
             .then_with(|| authenticate.cmp(&other.authenticate))
+            .then_with(|| credentials.cmp(&other.credentials))
+            .then_with(|| ca_bundle.cmp(&other.ca_bundle))
             .then_with(|| ignore_error_codes.cmp(&other.ignore_error_codes))
             .then_with(|| cache_control.cmp(&other.cache_control))
+            .then_with(|| mirrors.cmp(&other.mirrors))
     }
 }
 
@@ -230,8 +299,11 @@ impl std::hash::Hash for Index {
             format,
             publish_url,
             authenticate,
+            credentials,
+            ca_bundle,
             ignore_error_codes,
             cache_control,
+            mirrors,
         } = self;
         url.hash(state);
         name.hash(state);
@@ -240,8 +312,11 @@ impl std::hash::Hash for Index {
         format.hash(state);
         publish_url.hash(state);
         authenticate.hash(state);
+        credentials.hash(state);
+        ca_bundle.hash(state);
         ignore_error_codes.hash(state);
         cache_control.hash(state);
+        mirrors.hash(state);
     }
 }
 
@@ -280,8 +355,11 @@ impl Index {
             format: IndexFormat::Simple,
             publish_url: None,
             authenticate: AuthPolicy::default(),
+            credentials: None,
+            ca_bundle: None,
             ignore_error_codes: None,
             cache_control: None,
+            mirrors: Vec::new(),
         }
     }
 
@@ -296,8 +374,11 @@ impl Index {
             format: IndexFormat::Simple,
             publish_url: None,
             authenticate: AuthPolicy::default(),
+            credentials: None,
+            ca_bundle: None,
             ignore_error_codes: None,
             cache_control: None,
+            mirrors: Vec::new(),
         }
     }
 
@@ -312,8 +393,11 @@ impl Index {
             format: IndexFormat::Flat,
             publish_url: None,
             authenticate: AuthPolicy::default(),
+            credentials: None,
+            ca_bundle: None,
             ignore_error_codes: None,
             cache_control: None,
+            mirrors: Vec::new(),
         }
     }
 
@@ -359,7 +443,33 @@ impl Index {
         self
     }
 
-    /// Retrieve the credentials for the index, either from the environment, or from the URL itself.
+    /// Ignore any `credentials` configured for the index, warning if present.
+    ///
+    /// Configured credentials may reference environment variables or keyring entries, so
+    /// respecting them in project-level configuration (e.g., a `pyproject.toml` file or script
+    /// metadata) would allow an untrusted project to send the user's secrets to an index of its
+    /// choosing. The `source` is the file that defined the index, if any.
+    #[must_use]
+    pub fn without_credentials(mut self, source: Option<&Path>) -> Self {
+        if self.credentials.take().is_some() {
+            if let Some(source) = source {
+                warn_user_once!(
+                    "Ignoring `credentials` for index `{}` in `{}`; index credentials can only be configured in a user-level or system-level `uv.toml`, or via `--config-file`",
+                    self.url,
+                    source.user_display().cyan()
+                );
+            } else {
+                warn_user_once!(
+                    "Ignoring `credentials` for index `{}` in script metadata; index credentials can only be configured in a user-level or system-level `uv.toml`, or via `--config-file`",
+                    self.url
+                );
+            }
+        }
+        self
+    }
+
+    /// Retrieve the credentials for the index, either from the environment, the index
+    /// configuration, or from the URL itself.
     pub fn credentials(&self) -> Option<Credentials> {
         // If the index is named, and credentials are provided via the environment, prefer those.
         if let Some(name) = self.name.as_ref() {
@@ -368,6 +478,18 @@ impl Index {
             }
         }
 
+        // Next, use the credentials configured for the index.
+        if let Some(credentials) = self.credentials.as_ref() {
+            match credentials.resolve() {
+                Ok(Some(credentials)) => return Some(credentials),
+                Ok(None) => {}
+                Err(err) => {
+                    warn_user_once!("Ignoring credentials for index `{}`: {err}", self.url);
+                    return None;
+                }
+            }
+        }
+
         // Otherwise, extract the credentials from the URL.
         Credentials::from_url(self.url.url())
     }
@@ -379,9 +501,19 @@ impl Index {
                 self.url = IndexUrl::parse(given, Some(root_dir))?;
             }
         }
+        if let Some(ca_bundle) = self.ca_bundle.as_mut() {
+            if ca_bundle.is_relative() {
+                *ca_bundle = root_dir.join(&*ca_bundle);
+            }
+        }
         Ok(self)
     }
 
+    /// Return the mirrors of the index, in order of preference.
+    pub fn mirrors(&self) -> &[IndexUrl] {
+        &self.mirrors
+    }
+
     /// Return the [`IndexStatusCodeStrategy`] for this index.
     pub fn status_code_strategy(&self) -> IndexStatusCodeStrategy {
         if let Some(ignore_error_codes) = &self.ignore_error_codes {
@@ -429,8 +561,11 @@ impl From<IndexUrl> for Index {
             format: IndexFormat::Simple,
             publish_url: None,
             authenticate: AuthPolicy::default(),
+            credentials: None,
+            ca_bundle: None,
             ignore_error_codes: None,
             cache_control: None,
+            mirrors: Vec::new(),
         }
     }
 }
@@ -453,8 +588,11 @@ impl FromStr for Index {
                     format: IndexFormat::Simple,
                     publish_url: None,
                     authenticate: AuthPolicy::default(),
+                    credentials: None,
+                    ca_bundle: None,
                     ignore_error_codes: None,
                     cache_control: None,
+                    mirrors: Vec::new(),
                 });
             }
         }
@@ -470,8 +608,11 @@ impl FromStr for Index {
             format: IndexFormat::Simple,
             publish_url: None,
             authenticate: AuthPolicy::default(),
+            credentials: None,
+            ca_bundle: None,
             ignore_error_codes: None,
             cache_control: None,
+            mirrors: Vec::new(),
         })
     }
 }
@@ -573,9 +714,15 @@ struct IndexWire {
     #[serde(default)]
     authenticate: AuthPolicy,
     #[serde(default)]
+    credentials: Option<IndexCredentials>,
+    #[serde(default)]
+    ca_bundle: Option<PathBuf>,
+    #[serde(default)]
     ignore_error_codes: Option<Vec<SerializableStatusCode>>,
     #[serde(default)]
     cache_control: Option<IndexCacheControl>,
+    #[serde(default)]
+    mirrors: Vec<IndexUrl>,
 }
 
 impl<'de> Deserialize<'de> for Index {
@@ -592,6 +739,15 @@ impl<'de> Deserialize<'de> for Index {
             )));
         }
 
+        if let Some(credentials) = wire.credentials.as_ref() {
+            credentials.validate().map_err(|err| {
+                serde::de::Error::custom(format!(
+                    "Invalid `credentials` for index `{}`: {err}",
+                    wire.url
+                ))
+            })?;
+        }
+
         Ok(Self {
             name: wire.name,
             url: wire.url,
@@ -601,8 +757,11 @@ impl<'de> Deserialize<'de> for Index {
             format: wire.format,
             publish_url: wire.publish_url,
             authenticate: wire.authenticate,
+            credentials: wire.credentials,
+            ca_bundle: wire.ca_bundle,
             ignore_error_codes: wire.ignore_error_codes,
             cache_control: wire.cache_control,
+            mirrors: wire.mirrors,
         })
     }
 }
@@ -668,4 +827,59 @@ mod tests {
         assert_eq!(cache_control.api.as_deref(), Some("max-age=300"));
         assert_eq!(cache_control.files, None);
     }
+
+    #[test]
+    fn test_index_credentials() {
+        let toml_str = r#"
+            name = "test-index"
+            url = "https://test.example.com/simple"
+            credentials = { username = "user", password = "${TEST_INDEX_PASSWORD}" }
+        "#;
+
+        let index: Index = toml::from_str(toml_str).unwrap();
+        let credentials = index.credentials.as_ref().unwrap();
+        assert_eq!(credentials.username.as_deref(), Some("user"));
+        assert_eq!(
+            credentials.password.as_deref(),
+            Some("${TEST_INDEX_PASSWORD}")
+        );
+    }
+
+    #[test]
+    fn test_index_credentials_token_with_username() {
+        let toml_str = r#"
+            name = "test-index"
+            url = "https://test.example.com/simple"
+            credentials = { username = "user", token = "${TEST_INDEX_TOKEN}" }
+        "#;
+
+        let err = toml::from_str::<Index>(toml_str).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("`token` cannot be provided with `username` or `password`")
+        );
+    }
+
+    #[test]
+    fn test_index_mirrors() {
+        let toml_str = r#"
+            name = "test-index"
+            url = "https://test.example.com/simple"
+            mirrors = ["https://mirror-1.example.com/simple", "https://mirror-2.example.com/simple"]
+        "#;
+
+        let index: Index = toml::from_str(toml_str).unwrap();
+        let mirrors = index
+            .mirrors()
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            mirrors,
+            [
+                "https://mirror-1.example.com/simple",
+                "https://mirror-2.example.com/simple"
+            ]
+        );
+    }
 }
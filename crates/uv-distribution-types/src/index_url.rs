@@ -1,7 +1,7 @@
 use std::borrow::Cow;
 use std::fmt::{Display, Formatter};
 use std::ops::Deref;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 use std::sync::{Arc, LazyLock, RwLock};
 
@@ -12,8 +12,9 @@ use url::{ParseError, Url};
 use uv_auth::RealmRef;
 use uv_cache_key::CanonicalUrl;
 use uv_pep508::{Scheme, VerbatimUrl, VerbatimUrlError, split_scheme};
+use uv_pypi_types::HashDigest;
 use uv_redacted::DisplaySafeUrl;
-use uv_warnings::warn_user;
+use uv_warnings::{warn_user, warn_user_once};
 
 use crate::{Index, IndexStatusCodeStrategy, Verbatim};
 
@@ -419,6 +420,17 @@ impl<'a> IndexLocations {
         }
     }
 
+    /// Return the CA bundles configured for the allowed indexes, along with the index URL.
+    pub fn ca_bundles(&'a self) -> Vec<(DisplaySafeUrl, PathBuf)> {
+        self.allowed_indexes()
+            .into_iter()
+            .filter_map(|index| {
+                let ca_bundle = index.ca_bundle.clone()?;
+                Some((index.url.url().clone(), ca_bundle))
+            })
+            .collect()
+    }
+
     /// Return a vector containing all known [`Index`] entries.
     ///
     /// This includes explicit indexes, implicit indexes, flat indexes, and default indexes;
@@ -462,19 +474,42 @@ impl<'a> IndexLocations {
 
 impl From<&IndexLocations> for uv_auth::Indexes {
     fn from(index_locations: &IndexLocations) -> Self {
-        Self::from_indexes(index_locations.allowed_indexes().into_iter().map(|index| {
-            let mut url = index.url().url().clone();
-            url.set_username("").ok();
-            url.set_password(None).ok();
-            let mut root_url = index.url().root().unwrap_or_else(|| url.clone());
-            root_url.set_username("").ok();
-            root_url.set_password(None).ok();
-            uv_auth::Index {
-                url,
-                root_url,
-                auth_policy: index.authenticate,
-            }
-        }))
+        Self::from_indexes(
+            index_locations
+                .allowed_indexes()
+                .into_iter()
+                .flat_map(|index| {
+                    let keyring_service = index.credentials.as_ref().and_then(|credentials| {
+                        match credentials.keyring_service() {
+                            Ok(service) => service,
+                            Err(err) => {
+                                warn_user_once!(
+                                    "Ignoring credentials for index `{}`: {err}",
+                                    index.url
+                                );
+                                None
+                            }
+                        }
+                    });
+                    // Mirrors serve the same content as the index, and so share its authentication.
+                    std::iter::once(index.url())
+                        .chain(index.mirrors())
+                        .map(move |index_url| {
+                            let mut url = index_url.url().clone();
+                            url.set_username("").ok();
+                            url.set_password(None).ok();
+                            let mut root_url = index_url.root().unwrap_or_else(|| url.clone());
+                            root_url.set_username("").ok();
+                            root_url.set_password(None).ok();
+                            uv_auth::Index {
+                                url,
+                                root_url,
+                                auth_policy: index.authenticate,
+                                keyring_service: keyring_service.clone(),
+                            }
+                        })
+                }),
+        )
     }
 }
 
@@ -514,6 +549,12 @@ impl<'a> IndexUrls {
         }
     }
 
+    /// Returns `true` if the given [`IndexUrl`] is the default index.
+    pub fn is_default_index(&'a self, url: &IndexUrl) -> bool {
+        self.default_index()
+            .is_some_and(|index| is_same_index(&index.url, url))
+    }
+
     /// Return an iterator over the implicit [`Index`] entries.
     ///
     /// Default and explicit indexes are excluded.
@@ -622,6 +663,8 @@ bitflags::bitflags! {
         const UNAUTHORIZED      = 1 << 2;
         /// Whether the index returned a `403 Forbidden` status code.
         const FORBIDDEN         = 1 << 1;
+        /// Whether the index was unreachable, and treated as empty.
+        const UNREACHABLE       = 1 << 3;
     }
 }
 
@@ -631,13 +674,17 @@ bitflags::bitflags! {
 /// authorized). The benefit is that the map is almost always empty, so validating capabilities is
 /// extremely cheap.
 #[derive(Debug, Default, Clone)]
-pub struct IndexCapabilities(Arc<RwLock<FxHashMap<IndexUrl, Flags>>>);
+pub struct IndexCapabilities {
+    flags: Arc<RwLock<FxHashMap<IndexUrl, Flags>>>,
+    /// The files for which an index served stale metadata, by index.
+    stale_metadata: Arc<RwLock<FxHashSet<(IndexUrl, String)>>>,
+}
 
 impl IndexCapabilities {
     /// Returns `true` if the given [`IndexUrl`] supports range requests.
     pub fn supports_range_requests(&self, index_url: &IndexUrl) -> bool {
         !self
-            .0
+            .flags
             .read()
             .unwrap()
             .get(index_url)
@@ -646,7 +693,7 @@ impl IndexCapabilities {
 
     /// Mark an [`IndexUrl`] as not supporting range requests.
     pub fn set_no_range_requests(&self, index_url: IndexUrl) {
-        self.0
+        self.flags
             .write()
             .unwrap()
             .entry(index_url)
@@ -656,7 +703,7 @@ impl IndexCapabilities {
 
     /// Returns `true` if the given [`IndexUrl`] returns a `401 Unauthorized` status code.
     pub fn unauthorized(&self, index_url: &IndexUrl) -> bool {
-        self.0
+        self.flags
             .read()
             .unwrap()
             .get(index_url)
@@ -665,7 +712,7 @@ impl IndexCapabilities {
 
     /// Mark an [`IndexUrl`] as returning a `401 Unauthorized` status code.
     pub fn set_unauthorized(&self, index_url: IndexUrl) {
-        self.0
+        self.flags
             .write()
             .unwrap()
             .entry(index_url)
@@ -675,7 +722,7 @@ impl IndexCapabilities {
 
     /// Returns `true` if the given [`IndexUrl`] returns a `403 Forbidden` status code.
     pub fn forbidden(&self, index_url: &IndexUrl) -> bool {
-        self.0
+        self.flags
             .read()
             .unwrap()
             .get(index_url)
@@ -684,13 +731,117 @@ impl IndexCapabilities {
 
     /// Mark an [`IndexUrl`] as returning a `403 Forbidden` status code.
     pub fn set_forbidden(&self, index_url: IndexUrl) {
-        self.0
+        self.flags
             .write()
             .unwrap()
             .entry(index_url)
             .or_insert(Flags::empty())
             .insert(Flags::FORBIDDEN);
     }
+
+    /// Returns `true` if the given [`IndexUrl`] was unreachable, and treated as empty.
+    pub fn unreachable(&self, index_url: &IndexUrl) -> bool {
+        self.flags
+            .read()
+            .unwrap()
+            .get(index_url)
+            .is_some_and(|flags| flags.intersects(Flags::UNREACHABLE))
+    }
+
+    /// Mark an [`IndexUrl`] as unreachable, and treated as empty.
+    pub fn set_unreachable(&self, index_url: IndexUrl) {
+        self.flags
+            .write()
+            .unwrap()
+            .entry(index_url)
+            .or_insert(Flags::empty())
+            .insert(Flags::UNREACHABLE);
+    }
+
+    /// Return the [`IndexUrl`]s that were unreachable, and treated as empty, in sorted order.
+    pub fn unreachable_indexes(&self) -> Vec<IndexUrl> {
+        let mut indexes = self
+            .flags
+            .read()
+            .unwrap()
+            .iter()
+            .filter(|(_, flags)| flags.intersects(Flags::UNREACHABLE))
+            .map(|(index_url, _)| index_url.clone())
+            .collect::<Vec<_>>();
+        indexes.sort();
+        indexes
+    }
+
+    /// Record that an index served stale metadata for a file, i.e., a [PEP 658] metadata file
+    /// that doesn't match the hash advertised by the index, such that the metadata is read from
+    /// the file itself for the remainder of the invocation.
+    ///
+    /// [PEP 658]: https://peps.python.org/pep-0658/
+    pub fn set_stale_metadata(&self, stale_metadata: &StaleMetadata) {
+        self.stale_metadata.write().unwrap().insert((
+            stale_metadata.index.clone(),
+            stale_metadata.filename.clone(),
+        ));
+    }
+
+    /// Returns `true` if the given [`IndexUrl`] served stale metadata for the given file.
+    pub fn has_stale_metadata(&self, index_url: &IndexUrl, filename: &str) -> bool {
+        let stale_metadata = self.stale_metadata.read().unwrap();
+        !stale_metadata.is_empty()
+            && stale_metadata.contains(&(index_url.clone(), filename.to_string()))
+    }
+}
+
+/// A [PEP 658] metadata file that didn't match the hash advertised by the index.
+///
+/// Typically the result of an index (or a mirror) that serves metadata files from a stale cache,
+/// or that rewrote the metadata without updating the hash. Displayed as a warning, and serialized
+/// (without credentials) for reporting to the index operator.
+///
+/// [PEP 658]: https://peps.python.org/pep-0658/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StaleMetadata {
+    /// The index that served the metadata.
+    pub index: IndexUrl,
+    /// The filename of the distribution.
+    pub filename: String,
+    /// The URL of the metadata file.
+    pub url: DisplaySafeUrl,
+    /// The hash advertised by the index.
+    pub expected: HashDigest,
+    /// The hash of the metadata file that was served.
+    pub actual: HashDigest,
+}
+
+impl Display for StaleMetadata {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Index `{}` served stale metadata for `{}`: expected `{}`, but `{}` has `{}`",
+            self.index.without_credentials(),
+            self.filename,
+            self.expected,
+            self.url,
+            self.actual
+        )
+    }
+}
+
+impl serde::Serialize for StaleMetadata {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+
+        let mut state = serializer.serialize_struct("StaleMetadata", 5)?;
+        state.serialize_field("index", &self.index.without_credentials().to_string())?;
+        state.serialize_field("filename", &self.filename)?;
+        state.serialize_field("url", &self.url.to_string())?;
+        state.serialize_field("expected", &self.expected.to_string())?;
+        state.serialize_field("actual", &self.actual.to_string())?;
+        state.end()
+    }
 }
 
 #[cfg(test)]
@@ -749,24 +900,30 @@ mod tests {
                     api: Some(SmallString::from("max-age=300")),
                     files: Some(SmallString::from("max-age=1800")),
                 }),
+                mirrors: Vec::new(),
                 explicit: false,
                 default: false,
                 origin: None,
                 format: IndexFormat::Simple,
                 publish_url: None,
                 authenticate: uv_auth::AuthPolicy::default(),
+                credentials: None,
+                ca_bundle: None,
                 ignore_error_codes: None,
             },
             Index {
                 name: Some(IndexName::from_str("index2").unwrap()),
                 url: IndexUrl::from_str("https://index2.example.com/simple").unwrap(),
                 cache_control: None,
+                mirrors: Vec::new(),
                 explicit: false,
                 default: false,
                 origin: None,
                 format: IndexFormat::Simple,
                 publish_url: None,
                 authenticate: uv_auth::AuthPolicy::default(),
+                credentials: None,
+                ca_bundle: None,
                 ignore_error_codes: None,
             },
         ];
@@ -799,12 +956,15 @@ mod tests {
             name: Some(IndexName::from_str("pytorch").unwrap()),
             url: IndexUrl::from_str("https://download.pytorch.org/whl/cu118").unwrap(),
             cache_control: None, // No explicit cache control
+            mirrors: Vec::new(),
             explicit: false,
             default: false,
             origin: None,
             format: IndexFormat::Simple,
             publish_url: None,
             authenticate: uv_auth::AuthPolicy::default(),
+            credentials: None,
+            ca_bundle: None,
             ignore_error_codes: None,
         }];
 
@@ -841,12 +1001,15 @@ mod tests {
                 api: Some(SmallString::from("no-cache")),
                 files: Some(SmallString::from("max-age=3600")),
             }),
+            mirrors: Vec::new(),
             explicit: false,
             default: false,
             origin: None,
             format: IndexFormat::Simple,
             publish_url: None,
             authenticate: uv_auth::AuthPolicy::default(),
+            credentials: None,
+            ca_bundle: None,
             ignore_error_codes: None,
         }];
 
@@ -883,12 +1046,15 @@ mod tests {
             name: Some(IndexName::from_str("nvidia").unwrap()),
             url: IndexUrl::from_str("https://pypi.nvidia.com").unwrap(),
             cache_control: None, // No explicit cache control
+            mirrors: Vec::new(),
             explicit: false,
             default: false,
             origin: None,
             format: IndexFormat::Simple,
             publish_url: None,
             authenticate: uv_auth::AuthPolicy::default(),
+            credentials: None,
+            ca_bundle: None,
             ignore_error_codes: None,
         }];
 
@@ -19,7 +19,8 @@ use uv_pypi_types::{DirectUrl, MetadataError};
 use uv_redacted::DisplaySafeUrl;
 
 use crate::{
-    BuildInfo, DistributionMetadata, InstalledMetadata, InstalledVersion, Name, VersionOrUrlRef,
+    BuildInfo, DistributionMetadata, InstalledMetadata, InstalledVersion, InterpreterFingerprint,
+    Name, VersionOrUrlRef,
 };
 
 #[derive(Error, Debug)]
@@ -429,6 +430,36 @@ impl InstalledDist {
         Ok(Some(build_info))
     }
 
+    /// Read the `uv_interpreter.json` file from a `.dist-info` directory.
+    pub fn read_interpreter_fingerprint(
+        path: &Path,
+    ) -> Result<Option<InterpreterFingerprint>, InstalledDistError> {
+        let path = path.join("uv_interpreter.json");
+        let file = match fs_err::File::open(&path) {
+            Ok(file) => file,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(err) => return Err(err.into()),
+        };
+        let fingerprint = serde_json::from_reader::<BufReader<fs_err::File>, InterpreterFingerprint>(
+            BufReader::new(file),
+        )?;
+        Ok(Some(fingerprint))
+    }
+
+    /// Return the [`InterpreterFingerprint`] recorded when the distribution was installed, if
+    /// it was built from source by uv.
+    pub fn interpreter_fingerprint(
+        &self,
+    ) -> Result<Option<InterpreterFingerprint>, InstalledDistError> {
+        match &self.kind {
+            InstalledDistKind::Registry(dist) => Self::read_interpreter_fingerprint(&dist.path),
+            InstalledDistKind::Url(dist) => Self::read_interpreter_fingerprint(&dist.path),
+            InstalledDistKind::EggInfoDirectory(..)
+            | InstalledDistKind::EggInfoFile(..)
+            | InstalledDistKind::LegacyEditable(..) => Ok(None),
+        }
+    }
+
     /// Read the `METADATA` file from a `.dist-info` directory.
     pub fn read_metadata(&self) -> Result<&uv_pypi_types::ResolutionMetadata, InstalledDistError> {
         if let Some(metadata) = self.metadata_cache.get() {
@@ -484,6 +515,15 @@ impl InstalledDist {
         }
     }
 
+    /// Return `true` if the distribution was installed by uv, as recorded in its `INSTALLER` file.
+    ///
+    /// Distributions without an `INSTALLER` file (e.g., those installed with
+    /// `--no-installer-metadata`, or by tools that don't write one) are not considered to be
+    /// managed by uv.
+    pub fn is_managed_by_uv(&self) -> Result<bool, InstalledDistError> {
+        Ok(self.read_installer()?.as_deref() == Some("uv"))
+    }
+
     /// Return the supported wheel tags for the distribution from the `WHEEL` file, if available.
     pub fn read_tags(&self) -> Result<Option<&ExpandedTags>, InstalledDistError> {
         if let Some(tags) = self.tags_cache.get() {
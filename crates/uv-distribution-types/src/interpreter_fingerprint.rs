@@ -0,0 +1,50 @@
+use std::fmt::{Display, Formatter};
+
+/// A fingerprint of the Python interpreter that a distribution was built against.
+///
+/// Recorded in the `uv_interpreter.json` file of distributions that were built from source
+/// (including editables), such that they can be reinstalled when the environment's interpreter
+/// changes.
+#[derive(Debug, Clone, Hash, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub struct InterpreterFingerprint {
+    /// The implementation name, e.g., `cpython`.
+    implementation: String,
+    /// The full Python version, e.g., `3.12.4`.
+    version: String,
+    /// Whether the interpreter is a free-threaded build.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    gil_disabled: bool,
+    /// Whether the interpreter is a debug build.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    debug_enabled: bool,
+}
+
+impl InterpreterFingerprint {
+    /// Create an [`InterpreterFingerprint`] from the properties of an interpreter.
+    pub fn new(
+        implementation: impl Into<String>,
+        version: impl Into<String>,
+        gil_disabled: bool,
+        debug_enabled: bool,
+    ) -> Self {
+        Self {
+            implementation: implementation.into(),
+            version: version.into(),
+            gil_disabled,
+            debug_enabled,
+        }
+    }
+}
+
+impl Display for InterpreterFingerprint {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.implementation, self.version)?;
+        if self.gil_disabled {
+            write!(f, " (free-threaded)")?;
+        }
+        if self.debug_enabled {
+            write!(f, " (debug)")?;
+        }
+        Ok(())
+    }
+}
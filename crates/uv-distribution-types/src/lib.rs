@@ -70,6 +70,7 @@ pub use crate::index::*;
 pub use crate::index_name::*;
 pub use crate::index_url::*;
 pub use crate::installed::*;
+pub use crate::interpreter_fingerprint::*;
 pub use crate::known_platform::*;
 pub use crate::origin::*;
 pub use crate::pip_index::*;
@@ -101,6 +102,7 @@ mod index;
 mod index_name;
 mod index_url;
 mod installed;
+mod interpreter_fingerprint;
 mod known_platform;
 mod origin;
 mod pip_index;
@@ -367,7 +369,8 @@ impl Dist {
         match ext {
             DistExtension::Wheel => {
                 // Validate that the name in the wheel matches that of the requirement.
-                let filename = WheelFilename::from_str(&url.filename()?)?;
+                let filename = WheelFilename::from_str(&url.filename()?)
+                    .map_err(|err| Error::WheelFilenameUrl(url.verbatim().to_string(), err))?;
                 if filename.name != name {
                     return Err(Error::PackageNameMismatch(
                         name,
@@ -416,7 +419,8 @@ impl Dist {
         match ext {
             DistExtension::Wheel => {
                 // Validate that the name in the wheel matches that of the requirement.
-                let filename = WheelFilename::from_str(&url.filename()?)?;
+                let filename = WheelFilename::from_str(&url.filename()?)
+                    .map_err(|err| Error::WheelFilenameUrl(url.verbatim().to_string(), err))?;
                 if filename.name != name {
                     return Err(Error::PackageNameMismatch(
                         name,
@@ -339,6 +339,31 @@ pub enum HashComparison {
     Matched,
 }
 
+impl HashComparison {
+    /// Compare the hashes reported for a distribution against the required hashes.
+    ///
+    /// Any one of the required hashes is sufficient to match. A distribution is only considered
+    /// mismatched if it reports a digest for every required algorithm, and none of them match: the
+    /// required hashes may cover several distributions (e.g., `--hash=sha256:<sdist>` and
+    /// `--hash=sha512:<wheel>`), so if the index only reports a SHA-256 digest for the wheel, the
+    /// hash is considered missing, and will be computed and validated on download.
+    pub fn compare(required: &[HashDigest], hashes: &[HashDigest]) -> Self {
+        if required.is_empty() || hashes.iter().any(|hash| required.contains(hash)) {
+            Self::Matched
+        } else if !hashes.is_empty()
+            && required.iter().all(|required| {
+                hashes
+                    .iter()
+                    .any(|hash| hash.algorithm == required.algorithm)
+            })
+        {
+            Self::Mismatched
+        } else {
+            Self::Missing
+        }
+    }
+}
+
 impl PrioritizedDist {
     /// Create a new [`PrioritizedDist`] from the given wheel distribution.
     pub fn from_built(
@@ -599,6 +624,33 @@ impl PrioritizedDist {
             .flat_map(|(wheel, _)| wheel.filename.abi_tags().iter().copied())
     }
 
+    /// Returns an iterator over the filenames of all wheels for the distribution, excluding any
+    /// wheels that were published after the exclude newer time.
+    pub fn wheel_filenames(&self) -> impl Iterator<Item = &WheelFilename> {
+        self.0
+            .wheels
+            .iter()
+            .filter(|(_, compatibility)| !compatibility.is_excluded())
+            .map(|(wheel, _)| &wheel.filename)
+    }
+
+    /// Returns an iterator over all platform tags for the distribution, if none of them are
+    /// compatible with the given tags (e.g., if the distribution only includes Windows wheels, and
+    /// the current platform is Linux).
+    pub fn incompatible_platform_tags<'a>(
+        &'a self,
+        tags: &'a Tags,
+    ) -> impl Iterator<Item = &'a PlatformTag> + 'a {
+        let platform_tags = || {
+            self.0
+                .wheels
+                .iter()
+                .flat_map(|(wheel, _)| wheel.filename.platform_tags().iter())
+        };
+        let compatible = platform_tags().any(|tag| tags.is_compatible_platform(tag));
+        platform_tags().filter(move |_| !compatible)
+    }
+
     /// Returns the set of platform tags for the distribution that are ABI-compatible with the given
     /// tags.
     pub fn platform_tags<'a>(
@@ -1148,4 +1200,68 @@ mod tests {
             "python_full_version >= '3' and python_full_version < '4'",
         );
     }
+
+    #[test]
+    fn hash_comparison() {
+        let digest = |s: &str| HashDigest::from_str(s).unwrap();
+        let sha256 =
+            digest("sha256:f7ed51751b2c2add651e5747c891b47e26d2a21be5d32d9311dfe9692f3e5d7a");
+        let sha512 = digest(
+            "sha512:f30761c1e8725b49c498273b90dba4b05c0fd157811994c806183062cb6647e773364ce45f0e1ff0b10e32fe6d0232ea5ad39476ccf37109d6b49603a09c11c2",
+        );
+        let blake2b =
+            digest("blake2b:324dcf027dd4a30a932c441f365a25e86b173defa4b8e58948253471b81b72cf");
+        let other =
+            digest("sha256:0000000000000000000000000000000000000000000000000000000000000000");
+
+        // No hashes are required.
+        assert_eq!(
+            HashComparison::compare(&[], std::slice::from_ref(&sha256)),
+            HashComparison::Matched
+        );
+
+        // Any one of the required hashes is sufficient.
+        assert_eq!(
+            HashComparison::compare(
+                &[sha512.clone(), blake2b.clone()],
+                &[sha256.clone(), blake2b.clone()]
+            ),
+            HashComparison::Matched
+        );
+
+        // A digest for a required algorithm differs.
+        assert_eq!(
+            HashComparison::compare(std::slice::from_ref(&other), std::slice::from_ref(&sha256)),
+            HashComparison::Mismatched
+        );
+
+        // A digest is only available for some of the required algorithms, e.g., the requirement
+        // includes a SHA-256 digest for the source distribution and a SHA-512 digest for the
+        // wheel, but the index only reports a SHA-256 digest for the wheel.
+        assert_eq!(
+            HashComparison::compare(
+                &[other.clone(), sha512.clone()],
+                std::slice::from_ref(&sha256)
+            ),
+            HashComparison::Missing
+        );
+
+        assert_eq!(
+            HashComparison::compare(
+                &[other.clone(), blake2b.clone()],
+                &[sha256.clone(), sha512.clone()]
+            ),
+            HashComparison::Missing
+        );
+
+        // No digests are available for any of the required algorithms.
+        assert_eq!(
+            HashComparison::compare(&[sha512.clone(), blake2b], &[sha256]),
+            HashComparison::Missing
+        );
+        assert_eq!(
+            HashComparison::compare(&[sha512], &[]),
+            HashComparison::Missing
+        );
+    }
 }
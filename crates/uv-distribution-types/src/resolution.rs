@@ -1,9 +1,20 @@
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+use std::collections::hash_map::Entry;
+use std::fmt::{Display, Formatter};
+
+use itertools::Itertools;
+use petgraph::visit::EdgeRef;
+use rustc_hash::FxHashMap;
+
 use uv_distribution_filename::DistExtension;
 use uv_normalize::{ExtraName, GroupName, PackageName};
+use uv_pep440::Version;
 use uv_pypi_types::{HashDigest, HashDigests};
 
 use crate::{
-    BuiltDist, Diagnostic, Dist, IndexMetadata, Name, RequirementSource, ResolvedDist, SourceDist,
+    BuiltDist, Diagnostic, Dist, DistributionMetadata, IndexMetadata, Name, RequirementSource,
+    ResolvedDist, SourceDist, VersionId,
 };
 
 /// A set of packages pinned at specific versions.
@@ -92,6 +103,114 @@ impl Resolution {
         self
     }
 
+    /// Merge a set of [`Resolution`]s, each labeled with the group that produced it (e.g., `main`,
+    /// `dev`, or `docs`), into a single resolution.
+    ///
+    /// The graphs are unioned, such that a package selected by multiple resolutions is represented
+    /// by a single node. Returns an error if the resolutions select different versions of the same
+    /// package for installation.
+    pub fn merge<'a>(
+        resolutions: impl IntoIterator<Item = (impl Display, &'a Self)>,
+    ) -> Result<Self, ResolutionMergeError> {
+        let mut graph = petgraph::graph::DiGraph::new();
+        let root = graph.add_node(Node::Root);
+        let mut diagnostics = Vec::new();
+
+        // The node for each distribution in the merged graph.
+        let mut nodes = FxHashMap::default();
+
+        // The distributions selected for installation, along with the groups that selected them.
+        let mut selections: BTreeMap<PackageName, Vec<(VersionId, ResolvedDist, Vec<String>)>> =
+            BTreeMap::new();
+
+        for (group, resolution) in resolutions {
+            let group = group.to_string();
+
+            // Map each node in the resolution to its node in the merged graph.
+            let mut indices = Vec::with_capacity(resolution.graph.node_count());
+            for node in resolution.graph.node_weights() {
+                let Node::Dist {
+                    dist,
+                    hashes,
+                    install,
+                } = node
+                else {
+                    indices.push(root);
+                    continue;
+                };
+
+                let version_id = dist.version_id();
+                let index = match nodes.entry(version_id.clone()) {
+                    Entry::Occupied(entry) => {
+                        let index = *entry.get();
+                        if let Node::Dist {
+                            hashes: existing_hashes,
+                            install: existing_install,
+                            ..
+                        } = &mut graph[index]
+                        {
+                            *existing_install |= *install;
+                            let mut merged = existing_hashes.to_vec();
+                            for hash in hashes.iter() {
+                                if !merged.contains(hash) {
+                                    merged.push(hash.clone());
+                                }
+                            }
+                            *existing_hashes = HashDigests::from(merged);
+                        }
+                        index
+                    }
+                    Entry::Vacant(entry) => *entry.insert(graph.add_node(node.clone())),
+                };
+                indices.push(index);
+
+                if *install {
+                    let selected = selections.entry(dist.name().clone()).or_default();
+                    if let Some((.., groups)) = selected
+                        .iter_mut()
+                        .find(|(existing, ..)| *existing == version_id)
+                    {
+                        if !groups.contains(&group) {
+                            groups.push(group.clone());
+                        }
+                    } else {
+                        selected.push((version_id, dist.clone(), vec![group.clone()]));
+                    }
+                }
+            }
+
+            for edge in resolution.graph.edge_references() {
+                let source = indices[edge.source().index()];
+                let target = indices[edge.target().index()];
+                if !graph
+                    .edges_connecting(source, target)
+                    .any(|existing| existing.weight() == edge.weight())
+                {
+                    graph.add_edge(source, target, edge.weight().clone());
+                }
+            }
+
+            diagnostics.extend(resolution.diagnostics.iter().cloned());
+        }
+
+        let conflicts = selections
+            .into_iter()
+            .filter(|(_, selected)| selected.len() > 1)
+            .map(|(package, selected)| ResolutionConflict {
+                package,
+                dists: selected
+                    .into_iter()
+                    .map(|(_, dist, groups)| (dist, groups))
+                    .collect(),
+            })
+            .collect::<Vec<_>>();
+        if !conflicts.is_empty() {
+            return Err(ResolutionMergeError { conflicts });
+        }
+
+        Ok(Self { graph, diagnostics })
+    }
+
     /// Map over the resolved distributions in this resolution.
     ///
     /// For efficiency, the map function should return `None` if the resolved distribution is
@@ -107,6 +226,156 @@ impl Resolution {
         }
         self
     }
+
+    /// Compare this resolution against a newer resolution, returning the packages that were added,
+    /// removed, upgraded, or downgraded.
+    ///
+    /// Only packages that are marked for installation are considered. Packages whose version is
+    /// unchanged, or unknown in either resolution, are omitted.
+    pub fn diff(&self, other: &Self) -> ResolutionDiff {
+        let versions = |resolution: &Self| {
+            resolution
+                .distributions()
+                .map(|dist| (dist.name().clone(), dist.version().cloned()))
+                .collect::<BTreeMap<_, _>>()
+        };
+        let before = versions(self);
+        let after = versions(other);
+
+        let mut diff = ResolutionDiff::default();
+        for (name, version) in &before {
+            match after.get(name) {
+                None => diff.removed.push(ResolutionDiffPackage {
+                    name: name.clone(),
+                    version: version.clone(),
+                }),
+                Some(Some(to)) => {
+                    let Some(from) = version else {
+                        continue;
+                    };
+                    let update = ResolutionDiffUpdate {
+                        name: name.clone(),
+                        from: from.clone(),
+                        to: to.clone(),
+                    };
+                    match from.cmp(to) {
+                        Ordering::Less => diff.upgraded.push(update),
+                        Ordering::Greater => diff.downgraded.push(update),
+                        Ordering::Equal => {}
+                    }
+                }
+                Some(None) => {}
+            }
+        }
+        for (name, version) in after {
+            if !before.contains_key(&name) {
+                diff.added.push(ResolutionDiffPackage { name, version });
+            }
+        }
+        diff
+    }
+}
+
+/// The difference between two [`Resolution`]s, as computed by [`Resolution::diff`].
+///
+/// Each list is sorted by package name.
+#[derive(Debug, Default, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct ResolutionDiff {
+    /// The packages that are only present in the newer resolution.
+    pub added: Vec<ResolutionDiffPackage>,
+    /// The packages that are only present in the older resolution.
+    pub removed: Vec<ResolutionDiffPackage>,
+    /// The packages that were resolved to a newer version.
+    pub upgraded: Vec<ResolutionDiffUpdate>,
+    /// The packages that were resolved to an older version.
+    pub downgraded: Vec<ResolutionDiffUpdate>,
+}
+
+impl ResolutionDiff {
+    /// Returns `true` if the resolutions are equivalent.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty()
+            && self.removed.is_empty()
+            && self.upgraded.is_empty()
+            && self.downgraded.is_empty()
+    }
+}
+
+/// A package that was added to or removed from a [`Resolution`].
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct ResolutionDiffPackage {
+    pub name: PackageName,
+    pub version: Option<Version>,
+}
+
+/// A package that was resolved to a different version in a newer [`Resolution`].
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct ResolutionDiffUpdate {
+    pub name: PackageName,
+    pub from: Version,
+    pub to: Version,
+}
+
+/// An error that occurs when merging [`Resolution`]s that select different versions of the same
+/// package.
+#[derive(Debug, thiserror::Error)]
+#[error("{}", self.message())]
+pub struct ResolutionMergeError {
+    conflicts: Vec<ResolutionConflict>,
+}
+
+impl ResolutionMergeError {
+    /// Return the packages for which the resolutions disagree.
+    pub fn conflicts(&self) -> &[ResolutionConflict] {
+        &self.conflicts
+    }
+
+    fn message(&self) -> String {
+        if let [conflict] = self.conflicts.as_slice() {
+            format!("Failed to merge resolutions: {conflict}")
+        } else {
+            format!(
+                "Failed to merge resolutions:\n{}",
+                self.conflicts
+                    .iter()
+                    .map(|conflict| format!("  - {conflict}"))
+                    .join("\n")
+            )
+        }
+    }
+}
+
+/// A package for which multiple resolutions selected different versions.
+#[derive(Debug, Clone)]
+pub struct ResolutionConflict {
+    package: PackageName,
+    dists: Vec<(ResolvedDist, Vec<String>)>,
+}
+
+impl ResolutionConflict {
+    /// The name of the conflicting package.
+    pub fn package(&self) -> &PackageName {
+        &self.package
+    }
+
+    /// The distributions selected for the package, along with the groups that selected each one.
+    pub fn dists(&self) -> &[(ResolvedDist, Vec<String>)] {
+        &self.dists
+    }
+}
+
+impl Display for ResolutionConflict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "`{}` was resolved to {}",
+            self.package,
+            self.dists
+                .iter()
+                .map(|(dist, groups)| format!("`{dist}` (in {})", groups.join(", ")))
+                .join(" and ")
+        )
+    }
 }
 
 #[derive(Debug, Clone, Hash)]
@@ -202,7 +471,7 @@ impl Node {
 }
 
 /// An edge in the resolution graph.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Edge {
     Prod,
     Optional(ExtraName),
@@ -284,3 +553,65 @@ impl From<&ResolvedDist> for RequirementSource {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+    use std::sync::Arc;
+
+    use uv_normalize::PackageName;
+    use uv_pep508::Pep508Url;
+    use uv_pypi_types::{HashDigests, VerbatimParsedUrl};
+
+    use crate::{Dist, ResolvedDist};
+
+    use super::{Edge, Node, Resolution};
+
+    /// Create a [`Resolution`] with the given direct URL wheels, all dependencies of the root.
+    fn resolution(wheels: &[&str]) -> Resolution {
+        let mut graph = petgraph::graph::DiGraph::new();
+        let root = graph.add_node(Node::Root);
+        for wheel in wheels {
+            let (name, _) = wheel.split_once('-').unwrap();
+            let url = VerbatimParsedUrl::parse_url(&format!("https://example.com/{wheel}"), None)
+                .unwrap();
+            let dist = Dist::from_url(PackageName::from_str(name).unwrap(), url).unwrap();
+            let node = graph.add_node(Node::Dist {
+                dist: ResolvedDist::Installable {
+                    dist: Arc::new(dist),
+                    version: None,
+                },
+                hashes: HashDigests::empty(),
+                install: true,
+            });
+            graph.add_edge(root, node, Edge::Prod);
+        }
+        Resolution::new(graph)
+    }
+
+    #[test]
+    fn merge() {
+        let main = resolution(&["anyio-4.0.0-py3-none-any.whl", "idna-3.6-py3-none-any.whl"]);
+        let dev = resolution(&["idna-3.6-py3-none-any.whl", "pytest-8.0.0-py3-none-any.whl"]);
+
+        let merged = Resolution::merge([("main", &main), ("dev", &dev)]).unwrap();
+        assert_eq!(merged.len(), 3);
+        assert_eq!(merged.graph().edge_count(), 3);
+    }
+
+    #[test]
+    fn merge_conflict() {
+        let main = resolution(&["anyio-4.0.0-py3-none-any.whl", "idna-3.6-py3-none-any.whl"]);
+        let dev = resolution(&["anyio-3.7.1-py3-none-any.whl"]);
+        let docs = resolution(&["anyio-4.0.0-py3-none-any.whl"]);
+
+        let err = Resolution::merge([("main", &main), ("dev", &dev), ("docs", &docs)]).unwrap_err();
+        assert_eq!(err.conflicts().len(), 1);
+        assert_eq!(
+            err.to_string(),
+            "Failed to merge resolutions: `anyio` was resolved to \
+             `anyio @ https://example.com/anyio-4.0.0-py3-none-any.whl` (in main, docs) and \
+             `anyio @ https://example.com/anyio-3.7.1-py3-none-any.whl` (in dev)"
+        );
+    }
+}
@@ -14,6 +14,10 @@ pub struct Archive {
     pub filename: WheelFilename,
     /// The version of the archive bucket.
     pub version: u8,
+    /// Whether the attestations for the archive were checked when it was downloaded, such that it
+    /// can be reused without checking them again.
+    #[serde(default)]
+    pub attestations_checked: bool,
 }
 
 impl Archive {
@@ -24,9 +28,17 @@ impl Archive {
             hashes,
             filename,
             version: ARCHIVE_VERSION,
+            attestations_checked: false,
         }
     }
 
+    /// Mark whether the attestations for the archive were checked when it was downloaded.
+    #[must_use]
+    pub(crate) fn with_attestations_checked(mut self, attestations_checked: bool) -> Self {
+        self.attestations_checked = attestations_checked;
+        self
+    }
+
     /// Returns `true` if the archive exists in the cache.
     pub(crate) fn exists(&self, cache: &Cache) -> bool {
         self.version == ARCHIVE_VERSION && cache.archive(&self.id).exists()
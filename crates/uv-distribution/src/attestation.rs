@@ -0,0 +1,120 @@
+use tracing::debug;
+use url::Url;
+
+use uv_attestation::{Provenance, TrustedRoot};
+use uv_client::RegistryClient;
+use uv_distribution_types::File;
+use uv_normalize::PackageName;
+use uv_preview::PreviewFeature;
+use uv_pypi_types::{HashAlgorithm, HashDigest};
+
+use crate::Error;
+
+/// Returns `true` if the `attestations` preview feature is enabled, in which case the
+/// attestations for registry distributions are verified upon download.
+pub(crate) fn is_enabled() -> bool {
+    uv_preview::is_enabled(PreviewFeature::Attestations)
+}
+
+/// Fetch and verify the [PEP 740](https://peps.python.org/pep-0740/) attestations for a registry
+/// file, if the `attestations` preview feature is enabled.
+///
+/// This is called upon download, while the caller holds a download permit, so the request is
+/// sent through the unmanaged client.
+///
+/// The attestations must have been published by one of the trusted publishers pinned for the
+/// package in the client's [`AttestationPolicy`](uv_attestation::AttestationPolicy), if any. If the
+/// policy requires attestations, files without attestations are rejected.
+///
+/// Returns the SHA-256 digest to which the attestations attest, or `None` if verification is
+/// disabled or the index doesn't serve attestations for the file.
+pub(crate) async fn verify_attestations(
+    name: Option<&PackageName>,
+    file: &File,
+    client: &RegistryClient,
+) -> Result<Option<HashDigest>, Error> {
+    if !is_enabled() {
+        return Ok(None);
+    }
+
+    let policy = client.attestation_policy();
+    let trusted_publishers = name
+        .map(|name| policy.trusted_publishers(name))
+        .unwrap_or_default();
+
+    let Some(provenance) = file.provenance.as_deref() else {
+        if policy.is_required() || !trusted_publishers.is_empty() {
+            return Err(Error::MissingAttestations(file.filename.to_string()));
+        }
+        debug!("No attestations available for: {}", file.filename);
+        return Ok(None);
+    };
+
+    let url = provenance.to_url()?;
+    debug!("Fetching attestations for {} from: {url}", file.filename);
+    let response = client
+        .uncached_client(&url)
+        .get(Url::from(url.clone()))
+        .send()
+        .await?;
+    let bytes = response.error_for_status()?.bytes().await?;
+
+    let attestation_error = |err| Error::Attestation(file.filename.to_string(), err);
+    let provenance = Provenance::from_slice(&bytes).map_err(attestation_error)?;
+    let root = TrustedRoot::from_env().map_err(attestation_error)?;
+    let digest = uv_attestation::verify(&provenance, &file.filename, root, trusted_publishers)
+        .map_err(attestation_error)?;
+    let attested = HashDigest {
+        algorithm: HashAlgorithm::Sha256,
+        digest: digest.into(),
+    };
+
+    // The attestations must agree with the hash reported by the index.
+    if let Some(reported) = file
+        .hashes
+        .iter()
+        .find(|digest| digest.algorithm == HashAlgorithm::Sha256)
+    {
+        if *reported != attested {
+            return Err(Error::AttestationMismatch {
+                distribution: file.filename.to_string(),
+                attested: attested.digest.to_string(),
+                reported: reported.digest.to_string(),
+            });
+        }
+    }
+
+    debug!("Verified attestations for: {}", file.filename);
+    Ok(Some(attested))
+}
+
+/// Ensure that a downloaded archive matches the digest to which its attestations attest, if any.
+pub(crate) fn check_attested(
+    distribution: &str,
+    attested: Option<&HashDigest>,
+    computed: &[HashDigest],
+) -> Result<(), Error> {
+    let Some(attested) = attested else {
+        return Ok(());
+    };
+    if computed.contains(attested) {
+        return Ok(());
+    }
+    Err(Error::hash_mismatch(
+        distribution.to_string(),
+        std::slice::from_ref(attested),
+        computed,
+    ))
+}
+
+/// Add SHA-256 to the hash algorithms to compute for a download, if its attestations were
+/// verified.
+pub(crate) fn with_attested_algorithm(
+    mut algorithms: Vec<HashAlgorithm>,
+    attested: Option<&HashDigest>,
+) -> Vec<HashAlgorithm> {
+    if attested.is_some() && !algorithms.contains(&HashAlgorithm::Sha256) {
+        algorithms.push(HashAlgorithm::Sha256);
+    }
+    algorithms
+}
@@ -1,22 +1,23 @@
 use std::future::Future;
 use std::io;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::pin::Pin;
 use std::sync::Arc;
 use std::task::{Context, Poll};
 
 use futures::{FutureExt, TryStreamExt};
 use tempfile::TempDir;
-use tokio::io::{AsyncRead, AsyncSeekExt, ReadBuf};
+use tokio::io::{AsyncRead, AsyncSeekExt, AsyncWriteExt, ReadBuf};
 use tokio::sync::Semaphore;
 use tokio_util::compat::FuturesAsyncReadCompatExt;
-use tracing::{Instrument, info_span, instrument, warn};
+use tracing::{Instrument, debug, info_span, instrument, warn};
 use url::Url;
 
 use uv_cache::{ArchiveId, CacheBucket, CacheEntry, WheelCache};
 use uv_cache_info::{CacheInfo, Timestamp};
 use uv_client::{
     CacheControl, CachedClientError, Connectivity, DataWithCachePolicy, RegistryClient,
+    StallDetector,
 };
 use uv_distribution_filename::WheelFilename;
 use uv_distribution_types::{
@@ -24,15 +25,19 @@ use uv_distribution_types::{
     Name, SourceDist, ToUrlError,
 };
 use uv_extract::hash::Hasher;
-use uv_fs::write_atomic;
+use uv_fs::{Simplified, write_atomic};
 use uv_platform_tags::Tags;
 use uv_pypi_types::{HashDigest, HashDigests, PyProjectToml};
 use uv_redacted::DisplaySafeUrl;
 use uv_types::{BuildContext, BuildStack};
 
 use crate::archive::Archive;
+use crate::attestation;
+use crate::integrity::{from_sealed_slice, to_sealed_vec};
 use crate::metadata::{ArchiveMetadata, Metadata};
+use crate::partial::{PartialDownload, resumes_at};
 use crate::source::SourceDistributionBuilder;
+use crate::space::{WHEEL_SPACE_FACTOR, check_available_space};
 use crate::{Error, LocalWheel, Reporter, RequiresDist};
 
 /// A cached high-level interface to convert distributions (a requirement resolved to a location)
@@ -122,6 +127,230 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
         }
     }
 
+    /// Fetch and build a source distribution, returning the filename of the built wheel and the
+    /// path to the wheel archive (i.e., the `.whl` file) in the cache.
+    ///
+    /// Unlike [`DistributionDatabase::get_or_build_wheel`], the wheel is not unzipped.
+    #[instrument(skip_all, fields(%dist))]
+    pub async fn build_wheel_archive(
+        &self,
+        dist: &SourceDist,
+        tags: &Tags,
+        hashes: HashPolicy<'_>,
+    ) -> Result<(WheelFilename, PathBuf), Error> {
+        let built_wheel = self
+            .builder
+            .download_and_build(&BuildableSource::Dist(dist), tags, hashes, &self.client)
+            .boxed_local()
+            .await?;
+        Ok((built_wheel.filename, built_wheel.path.into_path_buf()))
+    }
+
+    /// Download the archive for a distribution (i.e., the wheel or source distribution file, as-is)
+    /// to the given path, without unzipping or building it.
+    ///
+    /// The archive is validated against the given hashes. Remote archives are retained in the
+    /// cache, such that subsequent downloads of the same archive respect the HTTP cache policy.
+    #[instrument(skip_all, fields(%dist))]
+    pub async fn download_archive(
+        &self,
+        dist: &Dist,
+        hashes: HashPolicy<'_>,
+        target: &Path,
+    ) -> Result<(), Error> {
+        let cache = self.build_context.cache();
+        let (url, index, archive_entry) = match dist {
+            Dist::Built(BuiltDist::Registry(wheels)) => {
+                let wheel = wheels.best_wheel();
+                (
+                    wheel.file.url.to_url()?,
+                    Some(&wheel.index),
+                    cache.entry(
+                        CacheBucket::Wheels,
+                        WheelCache::Index(&wheel.index).wheel_dir(wheel.name().as_ref()),
+                        wheel.filename.to_string(),
+                    ),
+                )
+            }
+            Dist::Built(BuiltDist::DirectUrl(wheel)) => (
+                (*wheel.location).clone(),
+                None,
+                cache.entry(
+                    CacheBucket::Wheels,
+                    WheelCache::Url(&wheel.url).wheel_dir(wheel.name().as_ref()),
+                    wheel.filename.to_string(),
+                ),
+            ),
+            Dist::Built(BuiltDist::Path(wheel)) => {
+                return copy_archive(dist, &wheel.install_path, hashes, target).await;
+            }
+            Dist::Source(SourceDist::Registry(sdist)) => (
+                sdist.file.url.to_url()?,
+                Some(&sdist.index),
+                cache.entry(
+                    CacheBucket::SourceDistributions,
+                    WheelCache::Index(&sdist.index).wheel_dir(sdist.name.as_ref()),
+                    sdist.file.filename.to_string(),
+                ),
+            ),
+            Dist::Source(SourceDist::DirectUrl(sdist)) => (
+                (*sdist.location).clone(),
+                None,
+                cache.entry(
+                    CacheBucket::SourceDistributions,
+                    WheelCache::Url(&sdist.url).wheel_dir(sdist.name.as_ref()),
+                    format!("{}.{}", sdist.name, sdist.ext),
+                ),
+            ),
+            Dist::Source(SourceDist::Path(sdist)) => {
+                return copy_archive(dist, &sdist.install_path, hashes, target).await;
+            }
+            Dist::Source(SourceDist::Git(_) | SourceDist::Directory(_)) => {
+                return Err(Error::NotAnArchive(dist.to_string()));
+            }
+        };
+
+        // Flat indexes and registries may refer to files on the local filesystem.
+        if url.scheme() == "file" {
+            let path = url
+                .to_file_path()
+                .map_err(|()| Error::NonFileUrl(url.clone()))?;
+            return copy_archive(dist, &path, hashes, target).await;
+        }
+
+        let http_entry = archive_entry.with_file(format!(
+            "{}.http",
+            archive_entry
+                .path()
+                .file_name()
+                .unwrap_or_default()
+                .display()
+        ));
+
+        let download = |response: reqwest::Response| {
+            async {
+                // Stream the archive to a temporary file in the cache, computing its hashes.
+                let temp_file =
+                    tempfile::NamedTempFile::new_in(cache.root()).map_err(Error::CacheWrite)?;
+                let file = fs_err::tokio::File::create(temp_file.path())
+                    .await
+                    .map_err(Error::CacheWrite)?;
+                let mut writer = tokio::io::BufWriter::new(file);
+                let mut hashers = hashes
+                    .algorithms()
+                    .into_iter()
+                    .map(Hasher::from)
+                    .collect::<Vec<_>>();
+                {
+                    let stream = response
+                        .bytes_stream()
+                        .map_err(|err| self.handle_response_errors(err));
+                    let reader = StallDetector::new(
+                        stream,
+                        url.clone(),
+                        self.client.unmanaged.stall_timeout(),
+                    )
+                    .into_async_read();
+                    let mut reader =
+                        uv_extract::hash::HashReader::new(reader.compat(), &mut hashers);
+                    tokio::io::copy(&mut reader, &mut writer)
+                        .await
+                        .map_err(Error::CacheWrite)?;
+                }
+                writer.flush().await.map_err(Error::CacheWrite)?;
+
+                // Never cache an archive that doesn't match the expected hashes.
+                let digests = hashers
+                    .into_iter()
+                    .map(HashDigest::from)
+                    .collect::<HashDigests>();
+                validate_hashes(dist, hashes, digests.as_slice())?;
+
+                fs_err::tokio::create_dir_all(archive_entry.dir())
+                    .await
+                    .map_err(Error::CacheWrite)?;
+                uv_fs::persist_with_retry(temp_file, archive_entry.path())
+                    .await
+                    .map_err(Error::CacheWrite)?;
+                Ok(digests)
+            }
+            .instrument(info_span!("archive", archive = %dist))
+        };
+
+        // Determine the cache control policy for the URL.
+        let cache_control = match self.client.unmanaged.connectivity() {
+            Connectivity::Online => {
+                if let Some(header) = index.and_then(|index| {
+                    self.build_context
+                        .locations()
+                        .artifact_cache_control_for(index)
+                }) {
+                    CacheControl::Override(header)
+                } else {
+                    CacheControl::from(
+                        cache
+                            .freshness(&http_entry, Some(dist.name()), None)
+                            .map_err(Error::CacheRead)?,
+                    )
+                }
+            }
+            Connectivity::Offline => CacheControl::AllowStale,
+        };
+
+        // Fetch the archive from the cache, or download it if necessary.
+        let req = self.request(url.clone())?;
+        let digests = self
+            .client
+            .managed(|client| {
+                client.cached_client().get_serde_with_retry(
+                    req,
+                    &http_entry,
+                    cache_control,
+                    download,
+                )
+            })
+            .await
+            .map_err(|err| match err {
+                CachedClientError::Callback { err, .. } => err,
+                CachedClientError::Client(err) => Error::Client(err),
+            })?;
+
+        // If the cached archive is missing the required hashes, or has since been removed, force
+        // a refresh.
+        let digests =
+            if has_algorithms(digests.as_slice(), hashes) && archive_entry.path().is_file() {
+                digests
+            } else {
+                self.client
+                    .managed(async |client| {
+                        client
+                            .cached_client()
+                            .skip_cache_with_retry(
+                                self.request(url.clone())?,
+                                &http_entry,
+                                cache_control,
+                                download,
+                            )
+                            .await
+                            .map_err(|err| match err {
+                                CachedClientError::Callback { err, .. } => err,
+                                CachedClientError::Client(err) => Error::Client(err),
+                            })
+                    })
+                    .await?
+            };
+        validate_hashes(dist, hashes, digests.as_slice())?;
+
+        debug!(
+            "Copying `{}` to `{}`",
+            archive_entry.path().user_display(),
+            target.user_display()
+        );
+        uv_fs::copy_atomic(archive_entry.path(), target)
+            .await
+            .map_err(|err| Error::WriteArchive(target.to_path_buf(), err))
+    }
+
     /// Either fetch the only wheel metadata (directly from the index or with range requests) or
     /// fetch and build the source distribution.
     ///
@@ -210,6 +439,9 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
                         .await;
                 }
 
+                // If enabled, verify the wheel's attestations upon download.
+                let attestations = Some(&*wheel.file).filter(|_| attestation::is_enabled());
+
                 // Download and unzip.
                 match self
                     .stream_wheel(
@@ -221,6 +453,7 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
                         &wheel_entry,
                         dist,
                         hashes,
+                        attestations,
                     )
                     .await
                 {
@@ -259,6 +492,7 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
                                 &wheel_entry,
                                 dist,
                                 hashes,
+                                attestations,
                             )
                             .await?;
 
@@ -298,6 +532,7 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
                         &wheel_entry,
                         dist,
                         hashes,
+                        None,
                     )
                     .await
                 {
@@ -330,6 +565,7 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
                                 &wheel_entry,
                                 dist,
                                 hashes,
+                                None,
                             )
                             .await?;
                         Ok(LocalWheel {
@@ -374,6 +610,7 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
     ///
     /// The returned wheel is guaranteed to come from a distribution with a matching hash, and
     /// no build processes will be executed for distributions with mismatched hashes.
+    #[instrument(skip_all, fields(%dist))]
     async fn build_wheel(
         &self,
         dist: &SourceDist,
@@ -585,6 +822,7 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
     }
 
     /// Stream a wheel from a URL, unzipping it into the cache as it's downloaded.
+    #[instrument(skip_all, fields(%filename))]
     async fn stream_wheel(
         &self,
         url: DisplaySafeUrl,
@@ -595,6 +833,7 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
         wheel_entry: &CacheEntry,
         dist: &BuiltDist,
         hashes: HashPolicy<'_>,
+        attestations: Option<&File>,
     ) -> Result<Archive, Error> {
         // Acquire an advisory lock, to guard against concurrent writes.
         #[cfg(windows)]
@@ -612,18 +851,46 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
             async {
                 let size = size.or_else(|| content_length(&response));
 
+                // Fail early if the cache can't hold the unpacked wheel.
+                if let Some(size) = size {
+                    check_available_space(
+                        self.build_context.cache().root(),
+                        size,
+                        WHEEL_SPACE_FACTOR,
+                    )?;
+                }
+
+                // If enabled, verify the wheel's attestations before downloading it.
+                let attested = match attestations {
+                    Some(file) => {
+                        attestation::verify_attestations(
+                            Some(dist.name()),
+                            file,
+                            self.client.unmanaged,
+                        )
+                        .await?
+                    }
+                    None => None,
+                };
+
                 let progress = self
                     .reporter
                     .as_ref()
                     .map(|reporter| (reporter, reporter.on_download_start(dist.name(), size)));
 
-                let reader = response
+                let stream = response
                     .bytes_stream()
-                    .map_err(|err| self.handle_response_errors(err))
-                    .into_async_read();
+                    .map_err(|err| self.handle_response_errors(err));
+                let reader = StallDetector::new(
+                    stream,
+                    query_url.clone(),
+                    self.client.unmanaged.stall_timeout(),
+                )
+                .into_async_read();
 
                 // Create a hasher for each hash algorithm.
-                let algorithms = hashes.algorithms();
+                let algorithms =
+                    attestation::with_attested_algorithm(hashes.algorithms(), attested.as_ref());
                 let mut hashers = algorithms.into_iter().map(Hasher::from).collect::<Vec<_>>();
                 let mut hasher = uv_extract::hash::HashReader::new(reader.compat(), &mut hashers);
 
@@ -662,10 +929,17 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
                 }
 
                 // If necessary, exhaust the reader to compute the hash.
-                if !hashes.is_none() {
+                if !hashes.is_none() || attested.is_some() {
                     hasher.finish().await.map_err(Error::HashExhaustion)?;
                 }
 
+                // Ensure that the wheel matches the attested digest.
+                let digests = hashers
+                    .into_iter()
+                    .map(HashDigest::from)
+                    .collect::<Vec<_>>();
+                attestation::check_attested(&dist.to_string(), attested.as_ref(), &digests)?;
+
                 // Persist the temporary directory to the directory store.
                 let id = self
                     .build_context
@@ -678,11 +952,10 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
                     reporter.on_download_complete(dist.name(), progress);
                 }
 
-                Ok(Archive::new(
-                    id,
-                    hashers.into_iter().map(HashDigest::from).collect(),
-                    filename.clone(),
-                ))
+                Ok(
+                    Archive::new(id, HashDigests::from(digests), filename.clone())
+                        .with_attestations_checked(attestations.is_some()),
+                )
             }
             .instrument(info_span!("wheel", wheel = %dist))
         };
@@ -728,9 +1001,16 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
             })?;
 
         // If the archive is missing the required hashes, or has since been removed, force a refresh.
+        // Likewise if its attestations weren't checked when it was downloaded, unless offline, in
+        // which case they can't be fetched.
         let archive = Some(archive)
             .filter(|archive| archive.has_digests(hashes))
-            .filter(|archive| archive.exists(self.build_context.cache()));
+            .filter(|archive| archive.exists(self.build_context.cache()))
+            .filter(|archive| {
+                archive.attestations_checked
+                    || attestations.is_none()
+                    || matches!(self.client.unmanaged.connectivity(), Connectivity::Offline)
+            });
 
         let archive = if let Some(archive) = archive {
             archive
@@ -758,6 +1038,7 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
     }
 
     /// Download a wheel from a URL, then unzip it into the cache.
+    #[instrument(skip_all, fields(%filename))]
     async fn download_wheel(
         &self,
         url: DisplaySafeUrl,
@@ -768,6 +1049,7 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
         wheel_entry: &CacheEntry,
         dist: &BuiltDist,
         hashes: HashPolicy<'_>,
+        attestations: Option<&File>,
     ) -> Result<Archive, Error> {
         // Acquire an advisory lock, to guard against concurrent writes.
         #[cfg(windows)]
@@ -785,23 +1067,70 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
             async {
                 let size = size.or_else(|| content_length(&response));
 
+                // Fail early if the cache can't hold both the downloaded and the unpacked wheel.
+                if let Some(size) = size {
+                    check_available_space(
+                        self.build_context.cache().root(),
+                        size,
+                        WHEEL_SPACE_FACTOR + 1,
+                    )?;
+                }
+
+                // If enabled, verify the wheel's attestations before downloading it.
+                let attested = match attestations {
+                    Some(file) => {
+                        attestation::verify_attestations(
+                            Some(dist.name()),
+                            file,
+                            self.client.unmanaged,
+                        )
+                        .await?
+                    }
+                    None => None,
+                };
+
                 let progress = self
                     .reporter
                     .as_ref()
                     .map(|reporter| (reporter, reporter.on_download_start(dist.name(), size)));
 
-                let reader = response
-                    .bytes_stream()
-                    .map_err(|err| self.handle_response_errors(err))
-                    .into_async_read();
+                // If the server supports range requests, download the wheel to a file that's
+                // retained across attempts, such that an interrupted download can be resumed.
+                // Otherwise, download the wheel to a temporary file.
+                let partial = PartialDownload::from_response(
+                    self.build_context.cache(),
+                    query_url,
+                    filename,
+                    &response,
+                );
+                let (file, response, _lock) = if let Some(partial) = &partial {
+                    let lock = partial.lock().await.map_err(Error::CacheLock)?;
+                    let (file, response, offset) =
+                        self.resume_download(partial, query_url, response).await?;
+                    if let Some((reporter, progress)) = progress {
+                        reporter.on_download_progress(progress, offset);
+                    }
+                    (file, response, Some(lock))
+                } else {
+                    let temp_file = tempfile::tempfile_in(self.build_context.cache().root())
+                        .map_err(Error::CacheWrite)?;
+                    let file = fs_err::tokio::File::from_std(
+                        // It's an unnamed file on Linux so that's the best approximation.
+                        fs_err::File::from_parts(temp_file, self.build_context.cache().root()),
+                    );
+                    (file, response, None)
+                };
 
-                // Download the wheel to a temporary file.
-                let temp_file = tempfile::tempfile_in(self.build_context.cache().root())
-                    .map_err(Error::CacheWrite)?;
-                let mut writer = tokio::io::BufWriter::new(fs_err::tokio::File::from_std(
-                    // It's an unnamed file on Linux so that's the best approximation.
-                    fs_err::File::from_parts(temp_file, self.build_context.cache().root()),
-                ));
+                let stream = response
+                    .bytes_stream()
+                    .map_err(|err| self.handle_response_errors(err));
+                let reader = StallDetector::new(
+                    stream,
+                    query_url.clone(),
+                    self.client.unmanaged.stall_timeout(),
+                )
+                .into_async_read();
+                let mut writer = tokio::io::BufWriter::new(file);
 
                 match progress {
                     Some((reporter, progress)) => {
@@ -831,51 +1160,72 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
                     .map_err(Error::CacheWrite)?;
 
                 // If no hashes are required, parallelize the unzip operation.
-                let hashes = if hashes.is_none() {
-                    let file = file.into_std().await;
-                    tokio::task::spawn_blocking({
-                        let target = temp_dir.path().to_owned();
-                        move || -> Result<(), uv_extract::Error> {
-                            // Unzip the wheel into a temporary directory.
-                            match extension {
-                                WheelExtension::Whl => {
-                                    uv_extract::unzip(file, &target)?;
-                                }
-                                WheelExtension::WhlZst => {
-                                    uv_extract::stream::untar_zst_file(file, &target)?;
+                let hashes: Result<HashDigests, Error> = async {
+                    if hashes.is_none() && attested.is_none() {
+                        let file = file.into_std().await;
+                        tokio::task::spawn_blocking({
+                            let target = temp_dir.path().to_owned();
+                            move || -> Result<(), uv_extract::Error> {
+                                // Unzip the wheel into a temporary directory.
+                                match extension {
+                                    WheelExtension::Whl => {
+                                        uv_extract::unzip(file, &target)?;
+                                    }
+                                    WheelExtension::WhlZst => {
+                                        uv_extract::stream::untar_zst_file(file, &target)?;
+                                    }
                                 }
+                                Ok(())
                             }
-                            Ok(())
-                        }
-                    })
-                    .await?
-                    .map_err(|err| Error::Extract(filename.to_string(), err))?;
+                        })
+                        .await?
+                        .map_err(|err| Error::Extract(filename.to_string(), err))?;
 
-                    HashDigests::empty()
-                } else {
-                    // Create a hasher for each hash algorithm.
-                    let algorithms = hashes.algorithms();
-                    let mut hashers = algorithms.into_iter().map(Hasher::from).collect::<Vec<_>>();
-                    let mut hasher = uv_extract::hash::HashReader::new(file, &mut hashers);
+                        Ok(HashDigests::empty())
+                    } else {
+                        // Create a hasher for each hash algorithm.
+                        let algorithms = attestation::with_attested_algorithm(
+                            hashes.algorithms(),
+                            attested.as_ref(),
+                        );
+                        let mut hashers =
+                            algorithms.into_iter().map(Hasher::from).collect::<Vec<_>>();
+                        let mut hasher = uv_extract::hash::HashReader::new(file, &mut hashers);
 
-                    match extension {
-                        WheelExtension::Whl => {
-                            uv_extract::stream::unzip(query_url, &mut hasher, temp_dir.path())
-                                .await
-                                .map_err(|err| Error::Extract(filename.to_string(), err))?;
-                        }
-                        WheelExtension::WhlZst => {
-                            uv_extract::stream::untar_zst(&mut hasher, temp_dir.path())
-                                .await
-                                .map_err(|err| Error::Extract(filename.to_string(), err))?;
+                        match extension {
+                            WheelExtension::Whl => {
+                                uv_extract::stream::unzip(query_url, &mut hasher, temp_dir.path())
+                                    .await
+                                    .map_err(|err| Error::Extract(filename.to_string(), err))?;
+                            }
+                            WheelExtension::WhlZst => {
+                                uv_extract::stream::untar_zst(&mut hasher, temp_dir.path())
+                                    .await
+                                    .map_err(|err| Error::Extract(filename.to_string(), err))?;
+                            }
                         }
+
+                        // If necessary, exhaust the reader to compute the hash.
+                        hasher.finish().await.map_err(Error::HashExhaustion)?;
+
+                        Ok(hashers.into_iter().map(HashDigest::from).collect())
                     }
+                }
+                .await;
 
-                    // If necessary, exhaust the reader to compute the hash.
-                    hasher.finish().await.map_err(Error::HashExhaustion)?;
+                // Once the download has been unpacked (or failed to unpack), there's nothing left to
+                // resume.
+                if let Some(partial) = &partial {
+                    partial.remove().await.map_err(Error::CacheWrite)?;
+                }
+                let hashes = hashes?;
 
-                    hashers.into_iter().map(HashDigest::from).collect()
-                };
+                // Ensure that the wheel matches the attested digest.
+                attestation::check_attested(
+                    &dist.to_string(),
+                    attested.as_ref(),
+                    hashes.as_slice(),
+                )?;
 
                 // Persist the temporary directory to the directory store.
                 let id = self
@@ -889,7 +1239,8 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
                     reporter.on_download_complete(dist.name(), progress);
                 }
 
-                Ok(Archive::new(id, hashes, filename.clone()))
+                Ok(Archive::new(id, hashes, filename.clone())
+                    .with_attestations_checked(attestations.is_some()))
             }
             .instrument(info_span!("wheel", wheel = %dist))
         };
@@ -935,9 +1286,16 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
             })?;
 
         // If the archive is missing the required hashes, or has since been removed, force a refresh.
+        // Likewise if its attestations weren't checked when it was downloaded, unless offline, in
+        // which case they can't be fetched.
         let archive = Some(archive)
             .filter(|archive| archive.has_digests(hashes))
-            .filter(|archive| archive.exists(self.build_context.cache()));
+            .filter(|archive| archive.exists(self.build_context.cache()))
+            .filter(|archive| {
+                archive.attestations_checked
+                    || attestations.is_none()
+                    || matches!(self.client.unmanaged.connectivity(), Connectivity::Offline)
+            });
 
         let archive = if let Some(archive) = archive {
             archive
@@ -1142,12 +1500,147 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
             .build()
     }
 
+    /// Open a [`PartialDownload`], resuming it with a range request if a previous attempt was
+    /// interrupted.
+    ///
+    /// Returns the file to write the download to, the response to read the remainder of the
+    /// download from, and the number of bytes that were already downloaded. If the download can't
+    /// be resumed, the file is truncated and the download restarts from the first byte.
+    async fn resume_download(
+        &self,
+        partial: &PartialDownload,
+        url: &DisplaySafeUrl,
+        response: reqwest::Response,
+    ) -> Result<(fs_err::tokio::File, reqwest::Response, u64), Error> {
+        let offset = partial.len().await;
+        if offset == 0 {
+            let file = partial.create().await.map_err(Error::CacheWrite)?;
+            return Ok((file, response, 0));
+        }
+
+        // Release the original response before sending the range request. Otherwise, the range
+        // request could wait on the connection slot held by the original response (e.g., with
+        // `--max-connections-per-host 1`).
+        drop(response);
+
+        let request = self
+            .client
+            .unmanaged
+            .uncached_client(url)
+            .get(Url::from(url.clone()))
+            .header(
+                "accept-encoding",
+                reqwest::header::HeaderValue::from_static("identity"),
+            )
+            .header(reqwest::header::RANGE, format!("bytes={offset}-"))
+            .header(reqwest::header::IF_RANGE, partial.validator().clone());
+        let response = match request.send().await {
+            Ok(resumed) if resumes_at(&resumed, offset) => {
+                debug!("Resuming download of `{url}` at byte {offset}");
+                let file = partial.append().await.map_err(Error::CacheWrite)?;
+                return Ok((file, resumed, offset));
+            }
+            // If the file changed since the previous attempt, the server ignores the range and
+            // responds with the full file.
+            Ok(resumed) if resumed.status() == reqwest::StatusCode::OK => {
+                debug!("Unable to resume download of `{url}` (file changed); restarting");
+                resumed
+            }
+            Ok(resumed) => {
+                debug!(
+                    "Unable to resume download of `{url}` (status: {}); restarting",
+                    resumed.status()
+                );
+                drop(resumed);
+                self.restart_download(url).await?
+            }
+            Err(err) => {
+                debug!("Unable to resume download of `{url}`; restarting: {err}");
+                self.restart_download(url).await?
+            }
+        };
+        let file = partial.create().await.map_err(Error::CacheWrite)?;
+        Ok((file, response, 0))
+    }
+
+    /// Request the full file, to restart a download that couldn't be resumed.
+    async fn restart_download(&self, url: &DisplaySafeUrl) -> Result<reqwest::Response, Error> {
+        let response = self
+            .client
+            .unmanaged
+            .uncached_client(url)
+            .execute(self.request(url.clone())?)
+            .await?
+            .error_for_status()?;
+        Ok(response)
+    }
+
     /// Return the [`ManagedClient`] used by this resolver.
     pub fn client(&self) -> &ManagedClient<'a> {
         &self.client
     }
 }
 
+/// Copy a local archive to the given path, validating it against the given hashes.
+async fn copy_archive(
+    dist: &Dist,
+    source: &Path,
+    hashes: HashPolicy<'_>,
+    target: &Path,
+) -> Result<(), Error> {
+    if hashes.is_validate() {
+        let mut hashers = hashes
+            .algorithms()
+            .into_iter()
+            .map(Hasher::from)
+            .collect::<Vec<_>>();
+        let file = fs_err::tokio::File::open(source)
+            .await
+            .map_err(Error::CacheRead)?;
+        uv_extract::hash::HashReader::new(tokio::io::BufReader::new(file), &mut hashers)
+            .finish()
+            .await
+            .map_err(Error::HashExhaustion)?;
+        let digests = hashers
+            .into_iter()
+            .map(HashDigest::from)
+            .collect::<Vec<_>>();
+        validate_hashes(dist, hashes, &digests)?;
+    }
+
+    debug!(
+        "Copying `{}` to `{}`",
+        source.user_display(),
+        target.user_display()
+    );
+    uv_fs::copy_atomic(source, target)
+        .await
+        .map_err(|err| Error::WriteArchive(target.to_path_buf(), err))
+}
+
+/// Validate the computed digests of an archive against the given hash policy.
+fn validate_hashes(
+    dist: &Dist,
+    hashes: HashPolicy<'_>,
+    digests: &[HashDigest],
+) -> Result<(), Error> {
+    let HashPolicy::Validate(expected) = hashes else {
+        return Ok(());
+    };
+    if digests.iter().any(|digest| expected.contains(digest)) {
+        return Ok(());
+    }
+    Err(Error::hash_mismatch(dist.to_string(), expected, digests))
+}
+
+/// Returns `true` if the digests include every algorithm required by the given hash policy.
+fn has_algorithms(digests: &[HashDigest], hashes: HashPolicy<'_>) -> bool {
+    hashes
+        .algorithms()
+        .iter()
+        .all(|algorithm| digests.iter().any(|digest| digest.algorithm == *algorithm))
+}
+
 /// A wrapper around `RegistryClient` that manages a concurrency limit.
 pub struct ManagedClient<'a> {
     pub unmanaged: &'a RegistryClient,
@@ -1191,7 +1684,7 @@ impl<'a> ManagedClient<'a> {
 }
 
 /// Returns the value of the `Content-Length` header from the [`reqwest::Response`], if present.
-fn content_length(response: &reqwest::Response) -> Option<u64> {
+pub(crate) fn content_length(response: &reqwest::Response) -> Option<u64> {
     response
         .headers()
         .get(reqwest::header::CONTENT_LENGTH)
@@ -1249,8 +1742,8 @@ impl HttpArchivePointer {
         match fs_err::File::open(path.as_ref()) {
             Ok(file) => {
                 let data = DataWithCachePolicy::from_reader(file)?.data;
-                let archive = rmp_serde::from_slice::<Archive>(&data)?;
-                Ok(Some(Self { archive }))
+                let archive = from_sealed_slice::<Archive>(path.as_ref(), &data)?;
+                Ok(archive.map(|archive| Self { archive }))
             }
             Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
             Err(err) => Err(Error::CacheRead(err)),
@@ -1285,8 +1778,8 @@ pub struct LocalArchivePointer {
 impl LocalArchivePointer {
     /// Read an [`LocalArchivePointer`] from the cache.
     pub fn read_from(path: impl AsRef<Path>) -> Result<Option<Self>, Error> {
-        match fs_err::read(path) {
-            Ok(cached) => Ok(Some(rmp_serde::from_slice::<Self>(&cached)?)),
+        match fs_err::read(path.as_ref()) {
+            Ok(cached) => from_sealed_slice::<Self>(path.as_ref(), &cached),
             Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
             Err(err) => Err(Error::CacheRead(err)),
         }
@@ -1294,7 +1787,7 @@ impl LocalArchivePointer {
 
     /// Write an [`LocalArchivePointer`] to the cache.
     pub async fn write_to(&self, entry: &CacheEntry) -> Result<(), Error> {
-        write_atomic(entry.path(), rmp_serde::to_vec(&self)?)
+        write_atomic(entry.path(), to_sealed_vec(&self)?)
             .await
             .map_err(Error::CacheWrite)
     }
@@ -7,6 +7,7 @@ use zip::result::ZipError;
 use crate::metadata::MetadataError;
 use uv_cache::Error as CacheError;
 use uv_client::WrappedReqwestError;
+use uv_console::human_readable_bytes;
 use uv_distribution_filename::{WheelFilename, WheelFilenameError};
 use uv_distribution_types::{InstalledDist, InstalledDistError, IsBuildBackendError};
 use uv_fs::Simplified;
@@ -130,6 +131,10 @@ pub enum Error {
     PyprojectToml(#[source] uv_pypi_types::MetadataError),
     #[error(transparent)]
     MetadataLowering(#[from] MetadataError),
+    #[error("`{0}` is not a wheel or source distribution archive")]
+    NotAnArchive(String),
+    #[error("Failed to write `{}`", _0.user_display())]
+    WriteArchive(PathBuf, #[source] std::io::Error),
     #[error("Distribution not found at: {0}")]
     NotFound(DisplaySafeUrl),
     #[error("Attempted to re-extract the source distribution for `{}`, but the {} hash didn't match. Run `{}` to clear the cache.", _0, _1, "uv cache clean".green())]
@@ -138,6 +143,18 @@ pub enum Error {
     RequiresPython(VersionSpecifiers, Version),
     #[error("Failed to identify base Python interpreter")]
     BaseInterpreter(#[source] std::io::Error),
+    #[error(
+        "Not enough free space in `{}` to unpack the distribution ({} available, at least {} required). Free up space, or use `{}` to move the cache to a larger filesystem.",
+        path.user_display(),
+        format_bytes(*available),
+        format_bytes(*required),
+        "--cache-dir".green(),
+    )]
+    InsufficientSpace {
+        path: PathBuf,
+        required: u64,
+        available: u64,
+    },
 
     /// A generic request middleware error happened while making a request.
     /// Refer to the error message for more details.
@@ -180,6 +197,23 @@ pub enum Error {
         actual: String,
     },
 
+    #[error("Failed to verify attestations for `{0}`")]
+    Attestation(String, #[source] uv_attestation::Error),
+
+    #[error(
+        "The index does not provide attestations for `{0}`, but attestations are required (via `require-attestations` or `trusted-publishers`)"
+    )]
+    MissingAttestations(String),
+
+    #[error(
+        "The attestations for `{distribution}` attest to a SHA-256 digest of `{attested}`, but the index reports `{reported}`"
+    )]
+    AttestationMismatch {
+        distribution: String,
+        attested: String,
+        reported: String,
+    },
+
     #[error("Hash-checking is not supported for local directories: `{0}`")]
     HashesNotSupportedSourceTree(String),
 
@@ -187,6 +221,12 @@ pub enum Error {
     HashesNotSupportedGit(String),
 }
 
+/// Format a number of bytes for display in an error message.
+fn format_bytes(bytes: u64) -> String {
+    let (quantity, unit) = human_readable_bytes(bytes);
+    format!("{quantity:.1}{unit}")
+}
+
 impl From<reqwest::Error> for Error {
     fn from(error: reqwest::Error) -> Self {
         Self::Reqwest(WrappedReqwestError::from(error))
@@ -10,6 +10,7 @@ use uv_distribution_types::{
 use uv_pypi_types::{HashDigest, HashDigests, VerbatimParsedUrl};
 
 use crate::archive::Archive;
+use crate::attestation;
 use crate::{HttpArchivePointer, LocalArchivePointer};
 
 #[derive(Debug, Clone)]
@@ -82,6 +83,12 @@ impl CachedWheel {
             return None;
         }
 
+        // Ignore archives whose attestations weren't checked when they were downloaded, if
+        // attestations are enabled, such that they're downloaded (and verified) again.
+        if !archive.attestations_checked && attestation::is_enabled() {
+            return None;
+        }
+
         let Archive { id, hashes, .. } = archive;
         let entry = cache.entry(CacheBucket::Archive, "", id);
 
@@ -110,6 +117,12 @@ impl CachedWheel {
             return None;
         }
 
+        // Ignore archives whose attestations weren't checked when they were downloaded, if
+        // attestations are enabled, such that they're downloaded (and verified) again.
+        if !archive.attestations_checked && attestation::is_enabled() {
+            return None;
+        }
+
         let Archive { id, hashes, .. } = archive;
         let entry = cache.entry(CacheBucket::Archive, "", id);
 
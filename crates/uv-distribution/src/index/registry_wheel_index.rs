@@ -15,6 +15,7 @@ use uv_normalize::PackageName;
 use uv_platform_tags::Tags;
 use uv_types::HashStrategy;
 
+use crate::attestation;
 use crate::index::cached_wheel::{CachedWheel, ResolvedWheel};
 use crate::source::{HTTP_REVISION, HttpRevisionPointer, LOCAL_REVISION, LocalRevisionPointer};
 
@@ -197,7 +198,11 @@ impl<'a> RegistryWheelIndex<'a> {
                     IndexUrl::Pypi(_) | IndexUrl::Url(_) => {
                         let revision_entry = cache_shard.entry(HTTP_REVISION);
                         if let Ok(Some(pointer)) = HttpRevisionPointer::read_from(revision_entry) {
-                            Some(pointer.into_revision())
+                            // Ignore revisions whose attestations weren't checked when they were
+                            // downloaded, if attestations are enabled.
+                            Some(pointer.into_revision()).filter(|revision| {
+                                revision.attestations_checked() || !attestation::is_enabled()
+                            })
                         } else {
                             None
                         }
@@ -0,0 +1,35 @@
+use std::path::Path;
+
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use tracing::warn;
+
+use uv_cache::integrity;
+use uv_fs::Simplified;
+
+use crate::Error;
+
+/// Serialize a cache entry with `MsgPack`, sealed with integrity metadata.
+pub(crate) fn to_sealed_vec<T: Serialize>(value: &T) -> Result<Vec<u8>, Error> {
+    Ok(integrity::seal(&rmp_serde::to_vec(value)?))
+}
+
+/// Deserialize a `MsgPack` cache entry read from `path`, validating its integrity metadata.
+///
+/// Returns `None` if the entry fails validation (e.g., it was written by an incompatible version of
+/// uv, or was corrupted on disk), such that it's treated as a cache miss.
+pub(crate) fn from_sealed_slice<T: DeserializeOwned>(
+    path: &Path,
+    bytes: &[u8],
+) -> Result<Option<T>, Error> {
+    match integrity::unseal(bytes) {
+        Ok(payload) => Ok(Some(rmp_serde::from_slice(payload)?)),
+        Err(err) => {
+            warn!(
+                "Ignoring invalid cache entry at `{}`: {err}",
+                path.user_display()
+            );
+            Ok(None)
+        }
+    }
+}
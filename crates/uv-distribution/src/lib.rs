@@ -7,14 +7,20 @@ pub use metadata::{
     LoweredRequirement, LoweringError, Metadata, MetadataError, RequiresDist,
     SourcedDependencyGroups,
 };
+pub use offline::missing_from_cache;
 pub use reporter::Reporter;
 pub use source::prune;
 
 mod archive;
+mod attestation;
 mod distribution_database;
 mod download;
 mod error;
 mod index;
+mod integrity;
 mod metadata;
+mod offline;
+mod partial;
 mod reporter;
 mod source;
+mod space;
@@ -6,9 +6,11 @@ use either::Either;
 use owo_colors::OwoColorize;
 use thiserror::Error;
 use uv_auth::CredentialsCache;
+use uv_cache_key::CanonicalUrl;
 use uv_distribution_filename::DistExtension;
 use uv_distribution_types::{
-    Index, IndexLocations, IndexMetadata, IndexName, Origin, Requirement, RequirementSource,
+    Index, IndexLocations, IndexMetadata, IndexName, IndexUrl, Origin, Requirement,
+    RequirementSource,
 };
 use uv_git_types::{GitLfs, GitReference, GitUrl, GitUrlParseError};
 use uv_normalize::{ExtraName, GroupName, PackageName};
@@ -17,7 +19,7 @@ use uv_pep508::{MarkerTree, VerbatimUrl, VersionOrUrl, looks_like_git_repository
 use uv_pypi_types::{ConflictItem, ParsedGitUrl, ParsedUrlError, VerbatimParsedUrl};
 use uv_redacted::{DisplaySafeUrl, DisplaySafeUrlError};
 use uv_workspace::Workspace;
-use uv_workspace::pyproject::{PyProjectToml, Source, Sources};
+use uv_workspace::pyproject::{PyProjectToml, Source, SourceIndex, Sources};
 
 use crate::metadata::GitWorkspaceMember;
 
@@ -216,30 +218,37 @@ impl LoweredRequirement {
                             extra,
                             group,
                         } => {
-                            // Identify the named index from either the project indexes or the workspace indexes,
-                            // in that order.
-                            let Some(index) = locations
+                            // Identify the index from either the project indexes or the workspace
+                            // indexes, in that order.
+                            let mut candidates = locations
                                 .indexes()
                                 .filter(|index| matches!(index.origin, Some(Origin::Cli)))
                                 .chain(project_indexes.iter())
-                                .chain(workspace.indexes().iter())
-                                .find(|Index { name, .. }| {
-                                    name.as_ref().is_some_and(|name| *name == index)
-                                })
-                            else {
-                                let hint = missing_index_hint(locations, &index);
-                                return Err(LoweringError::MissingIndex {
-                                    package: requirement.name.clone(),
-                                    index,
-                                    hint,
-                                });
-                            };
-                            if let Some(credentials) = index.credentials() {
-                                credentials_cache.store_credentials(index.raw_url(), credentials);
-                            }
-                            let index = IndexMetadata {
-                                url: index.url.clone(),
-                                format: index.format,
+                                .chain(workspace.indexes().iter());
+                            let index = match index {
+                                SourceIndex::Name(index) => {
+                                    let Some(index) = candidates.find(|Index { name, .. }| {
+                                        name.as_ref().is_some_and(|name| *name == index)
+                                    }) else {
+                                        let hint = missing_index_hint(locations, &index);
+                                        return Err(LoweringError::MissingIndex {
+                                            package: requirement.name.clone(),
+                                            index,
+                                            hint,
+                                        });
+                                    };
+                                    if let Some(credentials) = index.credentials() {
+                                        credentials_cache
+                                            .store_credentials(index.raw_url(), credentials);
+                                    }
+                                    IndexMetadata {
+                                        url: index.url.clone(),
+                                        format: index.format,
+                                    }
+                                }
+                                SourceIndex::Url(url) => {
+                                    pinned_index(&url, candidates, credentials_cache)
+                                }
                             };
                             let conflict = project_name.and_then(|project_name| {
                                 if let Some(extra) = extra {
@@ -457,27 +466,34 @@ impl LoweredRequirement {
                             (source, marker)
                         }
                         Source::Registry { index, marker, .. } => {
-                            let Some(index) = locations
+                            let mut candidates = locations
                                 .indexes()
                                 .filter(|index| matches!(index.origin, Some(Origin::Cli)))
-                                .chain(indexes.iter())
-                                .find(|Index { name, .. }| {
-                                    name.as_ref().is_some_and(|name| *name == index)
-                                })
-                            else {
-                                let hint = missing_index_hint(locations, &index);
-                                return Err(LoweringError::MissingIndex {
-                                    package: requirement.name.clone(),
-                                    index,
-                                    hint,
-                                });
-                            };
-                            if let Some(credentials) = index.credentials() {
-                                credentials_cache.store_credentials(index.raw_url(), credentials);
-                            }
-                            let index = IndexMetadata {
-                                url: index.url.clone(),
-                                format: index.format,
+                                .chain(indexes.iter());
+                            let index = match index {
+                                SourceIndex::Name(index) => {
+                                    let Some(index) = candidates.find(|Index { name, .. }| {
+                                        name.as_ref().is_some_and(|name| *name == index)
+                                    }) else {
+                                        let hint = missing_index_hint(locations, &index);
+                                        return Err(LoweringError::MissingIndex {
+                                            package: requirement.name.clone(),
+                                            index,
+                                            hint,
+                                        });
+                                    };
+                                    if let Some(credentials) = index.credentials() {
+                                        credentials_cache
+                                            .store_credentials(index.raw_url(), credentials);
+                                    }
+                                    IndexMetadata {
+                                        url: index.url.clone(),
+                                        format: index.format,
+                                    }
+                                }
+                                SourceIndex::Url(url) => {
+                                    pinned_index(&url, candidates, credentials_cache)
+                                }
                             };
                             let conflict = None;
                             let source = registry_source(&requirement, index, conflict);
@@ -611,6 +627,30 @@ fn missing_index_hint(locations: &IndexLocations, index: &IndexName) -> Option<S
     })
 }
 
+/// Resolve an index that was pinned by URL in `tool.uv.sources`.
+///
+/// If the URL matches a configured index (ignoring credentials and a trailing slash), that index's
+/// format and credentials are respected. Otherwise, the URL is treated as a Simple API index that's
+/// only used for the pinned package.
+fn pinned_index<'a>(
+    url: &DisplaySafeUrl,
+    mut indexes: impl Iterator<Item = &'a Index>,
+    credentials_cache: &CredentialsCache,
+) -> IndexMetadata {
+    let canonical_url = CanonicalUrl::new(url);
+    if let Some(index) = indexes.find(|index| CanonicalUrl::new(index.raw_url()) == canonical_url) {
+        if let Some(credentials) = index.credentials() {
+            credentials_cache.store_credentials(index.raw_url(), credentials);
+        }
+        return IndexMetadata {
+            url: index.url.clone(),
+            format: index.format,
+        };
+    }
+    credentials_cache.store_credentials_from_url(url);
+    IndexMetadata::from(IndexUrl::from(VerbatimUrl::from_url(url.clone())))
+}
+
 /// Convert a Git source into a [`RequirementSource`].
 fn git_source(
     git: &DisplaySafeUrl,
@@ -825,3 +865,33 @@ fn path_source(
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use uv_auth::CredentialsCache;
+    use uv_distribution_types::{Index, IndexFormat, IndexUrl};
+    use uv_redacted::DisplaySafeUrl;
+
+    use super::pinned_index;
+
+    #[test]
+    fn pinned_index_canonical_url() {
+        let index =
+            Index::from_find_links(IndexUrl::from_str("https://example.com/wheels").unwrap());
+        let credentials_cache = CredentialsCache::default();
+
+        // A URL that only differs by a trailing slash and credentials matches the configured index.
+        let url = DisplaySafeUrl::parse("https://user@example.com/wheels/").unwrap();
+        let metadata = pinned_index(&url, std::iter::once(&index), &credentials_cache);
+        assert_eq!(metadata.format, IndexFormat::Flat);
+        assert_eq!(metadata.url, index.url);
+
+        // Any other URL is treated as a Simple API index.
+        let url = DisplaySafeUrl::parse("https://example.com/simple").unwrap();
+        let metadata = pinned_index(&url, std::iter::once(&index), &credentials_cache);
+        assert_eq!(metadata.format, IndexFormat::Simple);
+        assert_eq!(metadata.url.url(), &url);
+    }
+}
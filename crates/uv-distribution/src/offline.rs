@@ -0,0 +1,74 @@
+use uv_cache::{Cache, CacheBucket, WheelCache};
+use uv_cache_key::{RepositoryUrl, cache_digest};
+use uv_distribution_types::{BuiltDist, Dist, FileLocation, Name, SourceDist};
+
+use crate::source::HTTP_REVISION;
+
+/// Returns the [`CacheBucket`] that must be populated to fetch a distribution without network
+/// access, or `None` if the distribution is available locally or already present in the cache.
+///
+/// This mirrors the cache entries read by the [`crate::DistributionDatabase`] when network access
+/// is disabled: the HTTP cache entry for remote wheels, the revision for remote source
+/// distributions, and the Git database for Git dependencies. Note that source distributions may
+/// require additional build dependencies, which aren't considered here.
+pub fn missing_from_cache(dist: &Dist, cache: &Cache) -> Option<CacheBucket> {
+    let (bucket, path) = match dist {
+        Dist::Built(BuiltDist::Registry(wheels)) => {
+            let wheel = wheels.best_wheel();
+            if is_file_url(&wheel.file.url) {
+                return None;
+            }
+            let entry = cache.entry(
+                CacheBucket::Wheels,
+                WheelCache::Index(&wheel.index).wheel_dir(wheel.name().as_ref()),
+                format!("{}.http", wheel.filename.cache_key()),
+            );
+            (CacheBucket::Wheels, entry.into_path_buf())
+        }
+        Dist::Built(BuiltDist::DirectUrl(wheel)) => {
+            let entry = cache.entry(
+                CacheBucket::Wheels,
+                WheelCache::Url(&wheel.url).wheel_dir(wheel.name().as_ref()),
+                format!("{}.http", wheel.filename.cache_key()),
+            );
+            (CacheBucket::Wheels, entry.into_path_buf())
+        }
+        Dist::Source(SourceDist::Registry(dist)) => {
+            if is_file_url(&dist.file.url) {
+                return None;
+            }
+            let entry = cache.entry(
+                CacheBucket::SourceDistributions,
+                WheelCache::Index(&dist.index)
+                    .wheel_dir(dist.name.as_ref())
+                    .join(dist.version.to_string()),
+                HTTP_REVISION,
+            );
+            (CacheBucket::SourceDistributions, entry.into_path_buf())
+        }
+        Dist::Source(SourceDist::DirectUrl(dist)) => {
+            let entry = cache.entry(
+                CacheBucket::SourceDistributions,
+                WheelCache::Url(&dist.url).root(),
+                HTTP_REVISION,
+            );
+            (CacheBucket::SourceDistributions, entry.into_path_buf())
+        }
+        Dist::Source(SourceDist::Git(dist)) => {
+            let path = cache
+                .bucket(CacheBucket::Git)
+                .join("db")
+                .join(cache_digest(&RepositoryUrl::new(dist.git.repository())));
+            (CacheBucket::Git, path)
+        }
+        Dist::Built(BuiltDist::Path(_))
+        | Dist::Source(SourceDist::Path(_) | SourceDist::Directory(_)) => return None,
+    };
+
+    if path.exists() { None } else { Some(bucket) }
+}
+
+/// Returns `true` if the file is served from the local filesystem.
+fn is_file_url(location: &FileLocation) -> bool {
+    location.to_url().is_ok_and(|url| url.scheme() == "file")
+}
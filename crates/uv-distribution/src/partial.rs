@@ -0,0 +1,135 @@
+use std::io;
+
+use reqwest::header::{ACCEPT_RANGES, CONTENT_RANGE, ETAG, HeaderValue, LAST_MODIFIED};
+
+use uv_cache::{Cache, CacheBucket, CacheEntry};
+use uv_cache_key::cache_digest;
+use uv_distribution_filename::WheelFilename;
+use uv_fs::LockedFile;
+use uv_redacted::DisplaySafeUrl;
+
+/// A (possibly partial) download of a wheel in the [`CacheBucket::Downloads`] bucket.
+///
+/// If a download is interrupted, the bytes received so far are retained on disk, such that the
+/// next attempt can request the remainder of the file with an HTTP range request. Partial
+/// downloads are keyed by the URL and the validator (`ETag` or `Last-Modified`) of the response,
+/// and resumed with `If-Range`, such that bytes from a different revision of the file are never
+/// combined.
+#[derive(Debug)]
+pub(crate) struct PartialDownload {
+    entry: CacheEntry,
+    validator: HeaderValue,
+}
+
+impl PartialDownload {
+    /// Return the [`PartialDownload`] for a response, if the server supports range requests.
+    pub(crate) fn from_response(
+        cache: &Cache,
+        url: &DisplaySafeUrl,
+        filename: &WheelFilename,
+        response: &reqwest::Response,
+    ) -> Option<Self> {
+        let headers = response.headers();
+        if headers
+            .get(ACCEPT_RANGES)
+            .is_none_or(|accept_ranges| accept_ranges != "bytes")
+        {
+            return None;
+        }
+
+        // Weak validators can't be used with `If-Range`.
+        let validator = headers
+            .get(ETAG)
+            .filter(|etag| !etag.as_bytes().starts_with(b"W/"))
+            .or_else(|| headers.get(LAST_MODIFIED))?;
+
+        let entry = cache.entry(
+            CacheBucket::Downloads,
+            cache_digest(&(url.as_str(), validator.as_bytes())),
+            filename.to_string(),
+        );
+        Some(Self {
+            entry,
+            validator: validator.clone(),
+        })
+    }
+
+    /// The validator to send in an `If-Range` header when resuming the download.
+    pub(crate) fn validator(&self) -> &HeaderValue {
+        &self.validator
+    }
+
+    /// Acquire an exclusive lock on the download, to guard against concurrent writes.
+    pub(crate) async fn lock(&self) -> Result<LockedFile, uv_cache::Error> {
+        self.entry.shard().lock().await
+    }
+
+    /// The number of bytes downloaded by previous attempts.
+    pub(crate) async fn len(&self) -> u64 {
+        fs_err::tokio::metadata(self.entry.path())
+            .await
+            .map(|metadata| metadata.len())
+            .unwrap_or(0)
+    }
+
+    /// Open the download to append the remainder of the file.
+    pub(crate) async fn append(&self) -> io::Result<fs_err::tokio::File> {
+        fs_err::tokio::OpenOptions::new()
+            .read(true)
+            .append(true)
+            .open(self.entry.path())
+            .await
+    }
+
+    /// Create (or truncate) the download to write the file from the start.
+    pub(crate) async fn create(&self) -> io::Result<fs_err::tokio::File> {
+        fs_err::tokio::create_dir_all(self.entry.dir()).await?;
+        fs_err::tokio::OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(self.entry.path())
+            .await
+    }
+
+    /// Remove the download, e.g., once it has been unpacked into the cache.
+    pub(crate) async fn remove(&self) -> io::Result<()> {
+        match fs_err::tokio::remove_file(self.entry.path()).await {
+            Ok(()) => Ok(()),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(()),
+            Err(err) => Err(err),
+        }
+    }
+}
+
+/// Parse the first byte position from a `Content-Range` header (e.g., `bytes 100-999/1000`).
+pub(crate) fn content_range_start(content_range: &HeaderValue) -> Option<u64> {
+    let range = content_range.to_str().ok()?.strip_prefix("bytes ")?;
+    let (start, _) = range.split_once('-')?;
+    start.trim().parse().ok()
+}
+
+/// Returns `true` if the response resumes a download at the given byte offset.
+pub(crate) fn resumes_at(response: &reqwest::Response, offset: u64) -> bool {
+    response.status() == reqwest::StatusCode::PARTIAL_CONTENT
+        && response
+            .headers()
+            .get(CONTENT_RANGE)
+            .and_then(content_range_start)
+            == Some(offset)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_content_range() {
+        let start = |value: &'static str| content_range_start(&HeaderValue::from_static(value));
+        assert_eq!(start("bytes 100-999/1000"), Some(100));
+        assert_eq!(start("bytes 0-0/*"), Some(0));
+        assert_eq!(start("bytes */1000"), None);
+        assert_eq!(start("items 100-999/1000"), None);
+    }
+}
@@ -13,6 +13,7 @@ use std::ops::Bound;
 use std::path::Path;
 use std::str::FromStr;
 use std::sync::Arc;
+use std::time::Duration;
 
 use fs_err::tokio as fs;
 use futures::{FutureExt, TryStreamExt};
@@ -27,13 +28,14 @@ use uv_cache::{Cache, CacheBucket, CacheEntry, CacheShard, Removal, WheelCache};
 use uv_cache_info::CacheInfo;
 use uv_client::{
     CacheControl, CachedClientError, Connectivity, DataWithCachePolicy, RegistryClient,
+    StallDetector,
 };
 use uv_configuration::{BuildKind, BuildOutput, NoSources};
 use uv_distribution_filename::{SourceDistExtension, WheelFilename};
 use uv_distribution_types::{
     BuildInfo, BuildVariables, BuildableSource, ConfigSettings, DirectorySourceUrl,
-    ExtraBuildRequirement, GitSourceUrl, HashPolicy, Hashed, IndexUrl, PathSourceUrl, SourceDist,
-    SourceUrl,
+    ExtraBuildRequirement, File, GitSourceUrl, HashPolicy, Hashed, IndexUrl, PathSourceUrl,
+    SourceDist, SourceUrl,
 };
 use uv_extract::hash::Hasher;
 use uv_fs::{Simplified, rename_with_retry, write_atomic};
@@ -48,11 +50,14 @@ use uv_redacted::DisplaySafeUrl;
 use uv_types::{BuildContext, BuildKey, BuildStack, SourceBuildTrait};
 use uv_workspace::pyproject::ToolUvSources;
 
-use crate::distribution_database::ManagedClient;
+use crate::attestation;
+use crate::distribution_database::{ManagedClient, content_length};
 use crate::error::Error;
+use crate::integrity::{from_sealed_slice, to_sealed_vec};
 use crate::metadata::{ArchiveMetadata, GitWorkspaceMember, Metadata};
 use crate::source::built_wheel_metadata::{BuiltWheelFile, BuiltWheelMetadata};
 use crate::source::revision::Revision;
+use crate::space::{SDIST_SPACE_FACTOR, check_available_space};
 use crate::{Reporter, RequiresDist};
 
 mod built_wheel_metadata;
@@ -147,6 +152,9 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                         .await;
                 }
 
+                // If enabled, verify the source distribution's attestations upon download.
+                let attestations = Some(&*dist.file).filter(|_| attestation::is_enabled());
+
                 self.url(
                     source,
                     &url,
@@ -156,6 +164,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                     dist.ext,
                     tags,
                     hashes,
+                    attestations,
                     client,
                 )
                 .boxed_local()
@@ -177,6 +186,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                     dist.ext,
                     tags,
                     hashes,
+                    None,
                     client,
                 )
                 .boxed_local()
@@ -223,6 +233,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                     resource.ext,
                     tags,
                     hashes,
+                    None,
                     client,
                 )
                 .boxed_local()
@@ -293,6 +304,9 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                         .await;
                 }
 
+                // If enabled, verify the source distribution's attestations upon download.
+                let attestations = Some(&*dist.file).filter(|_| attestation::is_enabled());
+
                 self.url_metadata(
                     source,
                     &url,
@@ -301,6 +315,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                     None,
                     dist.ext,
                     hashes,
+                    attestations,
                     client,
                 )
                 .boxed_local()
@@ -321,6 +336,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                     dist.subdirectory.as_deref(),
                     dist.ext,
                     hashes,
+                    None,
                     client,
                 )
                 .boxed_local()
@@ -371,6 +387,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                     resource.subdirectory,
                     resource.ext,
                     hashes,
+                    None,
                     client,
                 )
                 .boxed_local()
@@ -455,13 +472,23 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         ext: SourceDistExtension,
         tags: &Tags,
         hashes: HashPolicy<'_>,
+        attestations: Option<&File>,
         client: &ManagedClient<'_>,
     ) -> Result<BuiltWheelMetadata, Error> {
         let _lock = cache_shard.lock().await.map_err(Error::CacheLock)?;
 
         // Fetch the revision for the source distribution.
         let revision = self
-            .url_revision(source, ext, url, index, cache_shard, hashes, client)
+            .url_revision(
+                source,
+                ext,
+                url,
+                index,
+                cache_shard,
+                hashes,
+                attestations,
+                client,
+            )
             .await?;
 
         // Before running the build, check that the hashes match.
@@ -558,7 +585,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
 
         // Store the metadata.
         let metadata_entry = cache_shard.entry(METADATA);
-        write_atomic(metadata_entry.path(), rmp_serde::to_vec(&metadata)?)
+        write_atomic(metadata_entry.path(), to_sealed_vec(&metadata)?)
             .await
             .map_err(Error::CacheWrite)?;
 
@@ -585,13 +612,23 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         subdirectory: Option<&'data Path>,
         ext: SourceDistExtension,
         hashes: HashPolicy<'_>,
+        attestations: Option<&File>,
         client: &ManagedClient<'_>,
     ) -> Result<ArchiveMetadata, Error> {
         let _lock = cache_shard.lock().await.map_err(Error::CacheLock)?;
 
         // Fetch the revision for the source distribution.
         let revision = self
-            .url_revision(source, ext, url, index, cache_shard, hashes, client)
+            .url_revision(
+                source,
+                ext,
+                url,
+                index,
+                cache_shard,
+                hashes,
+                attestations,
+                client,
+            )
             .await?;
 
         // Before running the build, check that the hashes match.
@@ -693,7 +730,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             fs::create_dir_all(metadata_entry.dir())
                 .await
                 .map_err(Error::CacheWrite)?;
-            write_atomic(metadata_entry.path(), rmp_serde::to_vec(&metadata)?)
+            write_atomic(metadata_entry.path(), to_sealed_vec(&metadata)?)
                 .await
                 .map_err(Error::CacheWrite)?;
 
@@ -747,7 +784,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         };
 
         // Store the metadata.
-        write_atomic(metadata_entry.path(), rmp_serde::to_vec(&metadata)?)
+        write_atomic(metadata_entry.path(), to_sealed_vec(&metadata)?)
             .await
             .map_err(Error::CacheWrite)?;
 
@@ -766,6 +803,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         index: Option<&IndexUrl>,
         cache_shard: &CacheShard,
         hashes: HashPolicy<'_>,
+        attestations: Option<&File>,
         client: &ManagedClient<'_>,
     ) -> Result<Revision, Error> {
         let cache_entry = cache_shard.entry(HTTP_REVISION);
@@ -795,6 +833,15 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             let query_url = url.clone();
 
             async {
+                // If enabled, verify the source distribution's attestations before downloading it.
+                let attested = match attestations {
+                    Some(file) => {
+                        attestation::verify_attestations(source.name(), file, client.unmanaged)
+                            .await?
+                    }
+                    None => None,
+                };
+
                 // At this point, we're seeing a new or updated source distribution. Initialize a
                 // new revision, to collect the source and built artifacts.
                 let revision = Revision::new();
@@ -802,12 +849,26 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                 // Download the source distribution.
                 debug!("Downloading source distribution: {source}");
                 let entry = cache_shard.shard(revision.id()).entry(SOURCE);
-                let algorithms = hashes.algorithms();
+                let algorithms =
+                    attestation::with_attested_algorithm(hashes.algorithms(), attested.as_ref());
                 let hashes = self
-                    .download_archive(query_url, response, source, ext, entry.path(), &algorithms)
+                    .download_archive(
+                        query_url,
+                        response,
+                        source,
+                        ext,
+                        entry.path(),
+                        &algorithms,
+                        client.unmanaged.stall_timeout(),
+                    )
                     .await?;
 
-                Ok(revision.with_hashes(HashDigests::from(hashes)))
+                // Ensure that the source distribution matches the attested digest.
+                attestation::check_attested(&source.to_string(), attested.as_ref(), &hashes)?;
+
+                Ok(revision
+                    .with_hashes(HashDigests::from(hashes))
+                    .with_attestations_checked(attestations.is_some()))
             }
             .boxed_local()
             .instrument(info_span!("download", source_dist = %source))
@@ -828,8 +889,14 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                 CachedClientError::Client(err) => Error::Client(err),
             })?;
 
-        // If the archive is missing the required hashes, force a refresh.
-        if revision.has_digests(hashes) {
+        // If the archive is missing the required hashes, force a refresh. Likewise if its
+        // attestations weren't checked when it was downloaded, unless offline, in which case they
+        // can't be fetched.
+        if revision.has_digests(hashes)
+            && (revision.attestations_checked()
+                || attestations.is_none()
+                || matches!(client.unmanaged.connectivity(), Connectivity::Offline))
+        {
             Ok(revision)
         } else {
             client
@@ -941,7 +1008,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
 
         // Store the metadata.
         let metadata_entry = cache_shard.entry(METADATA);
-        write_atomic(metadata_entry.path(), rmp_serde::to_vec(&metadata)?)
+        write_atomic(metadata_entry.path(), to_sealed_vec(&metadata)?)
             .await
             .map_err(Error::CacheWrite)?;
 
@@ -1046,7 +1113,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             fs::create_dir_all(metadata_entry.dir())
                 .await
                 .map_err(Error::CacheWrite)?;
-            write_atomic(metadata_entry.path(), rmp_serde::to_vec(&metadata)?)
+            write_atomic(metadata_entry.path(), to_sealed_vec(&metadata)?)
                 .await
                 .map_err(Error::CacheWrite)?;
 
@@ -1100,7 +1167,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         };
 
         // Store the metadata.
-        write_atomic(metadata_entry.path(), rmp_serde::to_vec(&metadata)?)
+        write_atomic(metadata_entry.path(), to_sealed_vec(&metadata)?)
             .await
             .map_err(Error::CacheWrite)?;
 
@@ -1250,7 +1317,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
 
         // Store the metadata.
         let metadata_entry = cache_shard.entry(METADATA);
-        write_atomic(metadata_entry.path(), rmp_serde::to_vec(&metadata)?)
+        write_atomic(metadata_entry.path(), to_sealed_vec(&metadata)?)
             .await
             .map_err(Error::CacheWrite)?;
 
@@ -1374,7 +1441,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             fs::create_dir_all(metadata_entry.dir())
                 .await
                 .map_err(Error::CacheWrite)?;
-            write_atomic(metadata_entry.path(), rmp_serde::to_vec(&metadata)?)
+            write_atomic(metadata_entry.path(), to_sealed_vec(&metadata)?)
                 .await
                 .map_err(Error::CacheWrite)?;
 
@@ -1436,7 +1503,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         }
 
         // Store the metadata.
-        write_atomic(metadata_entry.path(), rmp_serde::to_vec(&metadata)?)
+        write_atomic(metadata_entry.path(), to_sealed_vec(&metadata)?)
             .await
             .map_err(Error::CacheWrite)?;
 
@@ -1670,7 +1737,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         }
 
         // Store the metadata.
-        write_atomic(metadata_entry.path(), rmp_serde::to_vec(&metadata)?)
+        write_atomic(metadata_entry.path(), to_sealed_vec(&metadata)?)
             .await
             .map_err(Error::CacheWrite)?;
 
@@ -1931,7 +1998,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             fs::create_dir_all(metadata_entry.dir())
                 .await
                 .map_err(Error::CacheWrite)?;
-            write_atomic(metadata_entry.path(), rmp_serde::to_vec(&metadata)?)
+            write_atomic(metadata_entry.path(), to_sealed_vec(&metadata)?)
                 .await
                 .map_err(Error::CacheWrite)?;
 
@@ -1993,7 +2060,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         };
 
         // Store the metadata.
-        write_atomic(metadata_entry.path(), rmp_serde::to_vec(&metadata)?)
+        write_atomic(metadata_entry.path(), to_sealed_vec(&metadata)?)
             .await
             .map_err(Error::CacheWrite)?;
 
@@ -2259,7 +2326,15 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                 };
 
                 let hashes = self
-                    .download_archive(query_url, response, source, ext, entry.path(), &algorithms)
+                    .download_archive(
+                        query_url,
+                        response,
+                        source,
+                        ext,
+                        entry.path(),
+                        &algorithms,
+                        client.unmanaged.stall_timeout(),
+                    )
                     .await?;
                 for existing in revision.hashes() {
                     if !hashes.contains(existing) {
@@ -2299,7 +2374,13 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         ext: SourceDistExtension,
         target: &Path,
         algorithms: &[HashAlgorithm],
+        stall_timeout: Option<Duration>,
     ) -> Result<Vec<HashDigest>, Error> {
+        // Fail early if the cache can't hold the unpacked source distribution and its build.
+        if let Some(size) = content_length(&response) {
+            check_available_space(self.build_context.cache().root(), size, SDIST_SPACE_FACTOR)?;
+        }
+
         let temp_dir = tempfile::tempdir_in(
             self.build_context
                 .cache()
@@ -2307,10 +2388,8 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         )
         .map_err(Error::CacheWrite)?;
 
-        let reader = response
-            .bytes_stream()
-            .map_err(std::io::Error::other)
-            .into_async_read();
+        let stream = response.bytes_stream().map_err(std::io::Error::other);
+        let reader = StallDetector::new(stream, query_url.clone(), stall_timeout).into_async_read();
 
         // Create a hasher for each hash algorithm.
         let mut hashers = algorithms
@@ -2337,7 +2416,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         // Extract the top-level directory.
         let extracted = match uv_extract::strip_component(temp_dir.path()) {
             Ok(top_level) => top_level,
-            Err(uv_extract::Error::NonSingularArchive(_)) => temp_dir.keep(),
+            Err(uv_extract::Error::NonSingularArchive(_)) => temp_dir.path().to_path_buf(),
             Err(err) => {
                 return Err(Error::Extract(
                     temp_dir.path().to_string_lossy().into_owned(),
@@ -2372,6 +2451,13 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
     ) -> Result<Vec<HashDigest>, Error> {
         debug!("Unpacking for build: {}", path.display());
 
+        // Fail early if the cache can't hold the unpacked source distribution and its build.
+        let size = fs_err::tokio::metadata(path)
+            .await
+            .map_err(Error::CacheRead)?
+            .len();
+        check_available_space(self.build_context.cache().root(), size, SDIST_SPACE_FACTOR)?;
+
         let temp_dir = tempfile::tempdir_in(
             self.build_context
                 .cache()
@@ -2982,8 +3068,8 @@ impl HttpRevisionPointer {
         match fs_err::File::open(path.as_ref()) {
             Ok(file) => {
                 let data = DataWithCachePolicy::from_reader(file)?.data;
-                let revision = rmp_serde::from_slice::<Revision>(&data)?;
-                Ok(Some(Self { revision }))
+                let revision = from_sealed_slice::<Revision>(path.as_ref(), &data)?;
+                Ok(revision.map(|revision| Self { revision }))
             }
             Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(None),
             Err(err) => Err(Error::CacheRead(err)),
@@ -3008,8 +3094,8 @@ pub(crate) struct LocalRevisionPointer {
 impl LocalRevisionPointer {
     /// Read an [`LocalRevisionPointer`] from the cache.
     pub(crate) fn read_from(path: impl AsRef<Path>) -> Result<Option<Self>, Error> {
-        match fs_err::read(path) {
-            Ok(cached) => Ok(Some(rmp_serde::from_slice::<Self>(&cached)?)),
+        match fs_err::read(path.as_ref()) {
+            Ok(cached) => from_sealed_slice::<Self>(path.as_ref(), &cached),
             Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(None),
             Err(err) => Err(Error::CacheRead(err)),
         }
@@ -3020,7 +3106,7 @@ impl LocalRevisionPointer {
         fs::create_dir_all(&entry.dir())
             .await
             .map_err(Error::CacheWrite)?;
-        write_atomic(entry.path(), rmp_serde::to_vec(&self)?)
+        write_atomic(entry.path(), to_sealed_vec(&self)?)
             .await
             .map_err(Error::CacheWrite)
     }
@@ -3099,7 +3185,7 @@ impl CachedMetadata {
     /// Read an existing cached [`ResolutionMetadata`], if it exists.
     async fn read(cache_entry: &CacheEntry) -> Result<Option<Self>, Error> {
         match fs::read(&cache_entry.path()).await {
-            Ok(cached) => Ok(Some(Self(rmp_serde::from_slice(&cached)?))),
+            Ok(cached) => Ok(from_sealed_slice(cache_entry.path(), &cached)?.map(Self)),
             Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(None),
             Err(err) => Err(Error::CacheRead(err)),
         }
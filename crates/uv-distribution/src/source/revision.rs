@@ -14,6 +14,9 @@ use uv_pypi_types::{HashDigest, HashDigests};
 pub(crate) struct Revision {
     id: RevisionId,
     hashes: HashDigests,
+    /// Whether the attestations for the archive were checked when it was downloaded.
+    #[serde(default)]
+    attestations_checked: bool,
 }
 
 impl Revision {
@@ -22,6 +25,7 @@ impl Revision {
         Self {
             id: RevisionId::new(),
             hashes: HashDigests::empty(),
+            attestations_checked: false,
         }
     }
 
@@ -46,6 +50,18 @@ impl Revision {
         self.hashes = hashes;
         self
     }
+
+    /// Return `true` if the attestations for the archive were checked when it was downloaded.
+    pub(crate) fn attestations_checked(&self) -> bool {
+        self.attestations_checked
+    }
+
+    /// Mark whether the attestations for the archive were checked when it was downloaded.
+    #[must_use]
+    pub(crate) fn with_attestations_checked(mut self, attestations_checked: bool) -> Self {
+        self.attestations_checked = attestations_checked;
+        self
+    }
 }
 
 impl Hashed for Revision {
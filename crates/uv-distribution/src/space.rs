@@ -0,0 +1,51 @@
+use std::path::Path;
+
+use tracing::{debug, trace};
+
+use uv_fs::Simplified;
+
+use crate::Error;
+
+/// The amount of free space to require when unpacking a wheel, as a multiple of its compressed
+/// size.
+///
+/// Wheels are zip archives, which typically compress Python sources by a factor of two to three.
+pub(crate) const WHEEL_SPACE_FACTOR: u64 = 3;
+
+/// The amount of free space to require when unpacking a source distribution, as a multiple of its
+/// compressed size.
+///
+/// Source distributions tend to compress better than wheels, and are built in place, so we budget
+/// for both the unpacked sources and the build artifacts.
+pub(crate) const SDIST_SPACE_FACTOR: u64 = 5;
+
+/// Check that the filesystem containing `path` has enough free space to unpack an archive of
+/// `size` bytes, given the expected expansion `factor`.
+///
+/// Returns an error if the free space is known to be insufficient. If the free space can't be
+/// determined, the check is skipped, and any shortage will surface when writing.
+pub(crate) fn check_available_space(path: &Path, size: u64, factor: u64) -> Result<(), Error> {
+    let required = size.saturating_mul(factor);
+    match uv_fs::available_space(path) {
+        Ok(Some(available)) if available < required => Err(Error::InsufficientSpace {
+            path: path.to_path_buf(),
+            required,
+            available,
+        }),
+        Ok(Some(available)) => {
+            trace!(
+                "Found {available} bytes available in `{}` ({required} required)",
+                path.user_display()
+            );
+            Ok(())
+        }
+        Ok(None) => Ok(()),
+        Err(err) => {
+            debug!(
+                "Failed to determine available space in `{}`: {err}",
+                path.user_display()
+            );
+            Ok(())
+        }
+    }
+}
@@ -10,11 +10,13 @@ use tracing::warn;
 
 pub use crate::locked_file::*;
 pub use crate::path::*;
+pub use crate::space::available_space;
 
 pub mod cachedir;
 pub mod link;
 mod locked_file;
 mod path;
+mod space;
 pub mod which;
 
 /// Attempt to check if the two paths refer to the same file.
@@ -253,6 +255,14 @@ pub fn write_atomic_sync(path: impl AsRef<Path>, data: impl AsRef<[u8]>) -> std:
     persist_with_retry_sync(temp_file, path.as_ref())
 }
 
+/// Copy `from` to `to` atomically using a temporary file and atomic rename.
+#[cfg(feature = "tokio")]
+pub async fn copy_atomic(from: impl AsRef<Path>, to: impl AsRef<Path>) -> std::io::Result<()> {
+    let temp_file = tempfile_in(to.as_ref().parent().expect("Write path must have a parent"))?;
+    fs_err::tokio::copy(from.as_ref(), &temp_file).await?;
+    persist_with_retry(temp_file, to.as_ref()).await
+}
+
 /// Copy `from` to `to` atomically using a temporary file and atomic rename.
 pub fn copy_atomic_sync(from: impl AsRef<Path>, to: impl AsRef<Path>) -> std::io::Result<()> {
     let temp_file = tempfile_in(to.as_ref().parent().expect("Write path must have a parent"))?;
@@ -0,0 +1,60 @@
+use std::io;
+use std::path::Path;
+
+/// Return the number of bytes available to the current user on the filesystem containing `path`.
+///
+/// If `path` does not exist yet, the nearest existing ancestor is queried instead, such that the
+/// free space of a directory can be checked before it is created.
+///
+/// Returns `Ok(None)` if the free space can't be determined on the current platform.
+pub fn available_space(path: &Path) -> io::Result<Option<u64>> {
+    let Some(existing) = path.ancestors().find(|ancestor| ancestor.exists()) else {
+        return Ok(None);
+    };
+    available_space_impl(existing)
+}
+
+#[cfg(unix)]
+fn available_space_impl(path: &Path) -> io::Result<Option<u64>> {
+    let stat = rustix::fs::statvfs(path)?;
+    Ok(Some(stat.f_bavail.saturating_mul(stat.f_frsize)))
+}
+
+#[cfg(windows)]
+#[allow(unsafe_code)] // We need to do an FFI call through the windows-* crates.
+fn available_space_impl(path: &Path) -> io::Result<Option<u64>> {
+    use std::os::windows::ffi::OsStrExt;
+    use windows::Win32::Storage::FileSystem::GetDiskFreeSpaceExW;
+    use windows::core::PCWSTR;
+
+    let mut available = 0u64;
+    let name = path
+        .as_os_str()
+        .encode_wide()
+        .chain(Some(0))
+        .collect::<Vec<u16>>();
+    // SAFETY: winapi call
+    unsafe { GetDiskFreeSpaceExW(PCWSTR(name.as_ptr()), Some(&raw mut available), None, None)? };
+    Ok(Some(available))
+}
+
+#[cfg(not(any(unix, windows)))]
+fn available_space_impl(_path: &Path) -> io::Result<Option<u64>> {
+    Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::available_space;
+
+    #[test]
+    fn missing_path_uses_ancestor() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let existing = available_space(temp_dir.path()).unwrap();
+        let missing = available_space(&temp_dir.path().join("a").join("b")).unwrap();
+        assert_eq!(existing.is_some(), missing.is_some());
+        if cfg!(any(unix, windows)) {
+            assert!(existing.is_some());
+        }
+    }
+}
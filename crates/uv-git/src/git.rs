@@ -13,7 +13,7 @@ use tracing::{debug, instrument, warn};
 
 use uv_fs::Simplified;
 use uv_git_types::{GitOid, GitReference};
-use uv_redacted::DisplaySafeUrl;
+use uv_redacted::{DisplaySafeUrl, redact_credentials};
 use uv_static::EnvVars;
 use uv_warnings::warn_user_once;
 
@@ -733,7 +733,7 @@ fn fetch_with_cli(
         if msg.contains("transport '") && msg.contains("' not allowed") && offline {
             return GitError::TransportNotAllowed.into();
         }
-        err
+        redact_process_error(&err)
     })?;
 
     Ok(())
@@ -797,7 +797,8 @@ fn fetch_lfs(
         .env_remove(EnvVars::GIT_LFS_SKIP_SMUDGE)
         .cwd(&repo.path);
 
-    cmd.exec_with_output()?;
+    cmd.exec_with_output()
+        .map_err(|err| redact_process_error(&err))?;
 
     // We now validate the Git LFS objects explicitly (if supported). This is
     // needed to avoid issues with Git LFS not being installed or configured
@@ -811,6 +812,12 @@ fn fetch_lfs(
     Ok(validation_result)
 }
 
+/// Redact credentials from the error of a `git` command, which includes the command line (with the
+/// remote URL) and the output of the command.
+fn redact_process_error(err: &anyhow::Error) -> anyhow::Error {
+    anyhow!("{}", redact_credentials(&format!("{err:#}")))
+}
+
 /// Whether `rev` is a shorter hash of `oid`.
 fn is_short_hash_of(rev: &str, oid: GitOid) -> bool {
     let long_hash = oid.to_string();
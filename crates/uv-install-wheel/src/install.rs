@@ -26,7 +26,11 @@ use crate::{Error, Layout};
 ///
 /// Wheel 1.0: <https://www.python.org/dev/peps/pep-0427/>
 #[instrument(skip_all, fields(wheel = %filename))]
-pub fn install_wheel<Cache: serde::Serialize, Build: serde::Serialize>(
+pub fn install_wheel<
+    Cache: serde::Serialize,
+    Build: serde::Serialize,
+    Interpreter: serde::Serialize,
+>(
     layout: &Layout,
     relocatable: bool,
     wheel: impl AsRef<Path>,
@@ -34,6 +38,7 @@ pub fn install_wheel<Cache: serde::Serialize, Build: serde::Serialize>(
     direct_url: Option<&DirectUrl>,
     cache_info: Option<&Cache>,
     build_info: Option<&Build>,
+    interpreter_info: Option<&Interpreter>,
     installer: Option<&str>,
     installer_metadata: bool,
     link_mode: LinkMode,
@@ -143,6 +148,7 @@ pub fn install_wheel<Cache: serde::Serialize, Build: serde::Serialize>(
             direct_url,
             cache_info,
             build_info,
+            interpreter_info,
             installer,
             &mut record,
         )?;
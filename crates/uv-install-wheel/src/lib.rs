@@ -13,14 +13,19 @@ use uv_pypi_types::Scheme;
 
 pub use install::install_wheel;
 pub use linker::{InstallState, LinkMode, link_wheel_files};
+pub use script::read_entry_points;
+pub use stub::install_stub;
 pub use uninstall::{Uninstall, uninstall_egg, uninstall_legacy_editable, uninstall_wheel};
+pub use verify::{RecordMismatch, Verification, verify_wheel};
 pub use wheel::{LibKind, WheelFile, read_record_file};
 
 mod install;
 mod linker;
 mod record;
 mod script;
+mod stub;
 mod uninstall;
+mod verify;
 mod wheel;
 
 /// The layout of the target environment into which a wheel can be installed.
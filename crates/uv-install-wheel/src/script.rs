@@ -2,6 +2,7 @@ use configparser::ini::Ini;
 use regex::Regex;
 use rustc_hash::FxHashSet;
 use serde::Serialize;
+use std::collections::BTreeMap;
 use std::sync::LazyLock;
 
 use crate::{Error, wheel};
@@ -107,9 +108,31 @@ pub(crate) fn scripts_from_ini(
     Ok((console_scripts, gui_scripts))
 }
 
+/// Parses an `entry_points.txt` file into its groups (e.g., `console_scripts`), mapping the name of
+/// each entry point to its object reference (e.g., `black:patched_main`).
+///
+/// <https://packaging.python.org/en/latest/specifications/entry-points/#file-format>
+pub fn read_entry_points(ini: String) -> Result<BTreeMap<String, BTreeMap<String, String>>, Error> {
+    let entry_points_mapping = Ini::new_cs()
+        .read(ini)
+        .map_err(|err| Error::InvalidWheel(format!("entry_points.txt is invalid: {err}")))?;
+
+    Ok(entry_points_mapping
+        .into_iter()
+        .map(|(group, entry_points)| {
+            let entry_points = entry_points
+                .into_iter()
+                .filter_map(|(name, value)| Some((name, value?)))
+                .collect::<BTreeMap<_, _>>();
+            (group, entry_points)
+        })
+        .filter(|(_, entry_points)| !entry_points.is_empty())
+        .collect())
+}
+
 #[cfg(test)]
 mod test {
-    use crate::script::{Script, scripts_from_ini};
+    use crate::script::{Script, read_entry_points, scripts_from_ini};
 
     #[test]
     fn test_valid_script_names() {
@@ -218,4 +241,25 @@ memray3.11 = a:b7
             console_scripts.get(5)
         );
     }
+
+    #[test]
+    fn test_read_entry_points() {
+        let entry_points = read_entry_points(
+            "[console_scripts]\nblack = black:patched_main\nblackd = blackd:patched_main [d]\n\n[empty]\n\n[pygments.lexers]\nBlack = black.lexer:BlackLexer\n"
+                .to_string(),
+        )
+        .unwrap();
+        assert_eq!(
+            entry_points.keys().collect::<Vec<_>>(),
+            ["console_scripts", "pygments.lexers"]
+        );
+        assert_eq!(
+            entry_points["console_scripts"]["blackd"],
+            "blackd:patched_main [d]"
+        );
+        assert_eq!(
+            entry_points["pygments.lexers"]["Black"],
+            "black.lexer:BlackLexer"
+        );
+    }
 }
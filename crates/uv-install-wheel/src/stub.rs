@@ -0,0 +1,65 @@
+use std::path::{Path, PathBuf};
+
+use tracing::trace;
+
+use uv_normalize::PackageName;
+use uv_pep440::Version;
+
+use crate::Error;
+use crate::record::RecordEntry;
+use crate::wheel::write_file_recorded;
+
+/// Install a stub distribution: a `.dist-info` directory without any package files, recording
+/// that the package is provided externally (e.g., by a system SDK).
+///
+/// The stub includes a `METADATA`, `INSTALLER`, and `RECORD` file, such that it can be discovered,
+/// planned against, and uninstalled like any other installed wheel.
+pub fn install_stub(
+    site_packages: &Path,
+    name: &PackageName,
+    version: &Version,
+    installer: Option<&str>,
+) -> Result<(), Error> {
+    let dist_info_prefix = format!("{}-{version}", name.as_dist_info_name());
+    let dist_info_dir = PathBuf::from(format!("{dist_info_prefix}.dist-info"));
+    fs_err::create_dir_all(site_packages.join(&dist_info_dir))?;
+
+    let mut record = Vec::new();
+
+    let metadata = format!(
+        "Metadata-Version: 2.1\nName: {name}\nVersion: {version}\nSummary: A stub for a package that is provided externally\n"
+    );
+    write_file_recorded(
+        site_packages,
+        &dist_info_dir.join("METADATA"),
+        metadata,
+        &mut record,
+    )?;
+
+    if let Some(installer) = installer {
+        write_file_recorded(
+            site_packages,
+            &dist_info_dir.join("INSTALLER"),
+            installer,
+            &mut record,
+        )?;
+    }
+
+    record.push(RecordEntry {
+        path: format!("{dist_info_prefix}.dist-info/RECORD"),
+        hash: None,
+        size: None,
+    });
+
+    trace!(?name, "Writing record");
+    let mut record_writer = csv::WriterBuilder::new()
+        .has_headers(false)
+        .escape(b'"')
+        .from_path(site_packages.join(dist_info_dir.join("RECORD")))?;
+    record.sort();
+    for entry in record {
+        record_writer.serialize(entry)?;
+    }
+
+    Ok(())
+}
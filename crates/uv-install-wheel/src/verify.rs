@@ -0,0 +1,170 @@
+use std::io;
+use std::path::{Component, Path, PathBuf};
+
+use rustc_hash::FxHashSet;
+use tracing::trace;
+use walkdir::WalkDir;
+
+use uv_fs::Simplified;
+
+use crate::Error;
+use crate::wheel::{copy_and_hash, read_record_file};
+
+/// Verify the files of the wheel represented by the given `.dist-info` directory against the
+/// hashes in its `RECORD` file.
+///
+/// Entries without a hash (like the `RECORD` file itself) or with a hash algorithm other than
+/// SHA-256 are skipped.
+///
+/// Files that aren't listed in the `RECORD` are reported as extra if they're in a directory that
+/// contains a `RECORD` entry (like the package itself or its `.dist-info` directory). Bytecode in
+/// `__pycache__` directories is ignored. Directories may be shared with other distributions (as
+/// with namespace packages), so callers should filter out extra files that are listed in the
+/// `RECORD` of another distribution.
+pub fn verify_wheel(dist_info: &Path) -> Result<Verification, Error> {
+    let Some(site_packages) = dist_info.parent() else {
+        return Err(Error::BrokenVenv(
+            "dist-info directory is not in a site-packages directory".to_string(),
+        ));
+    };
+
+    // Read the RECORD file.
+    let record = {
+        let record_path = dist_info.join("RECORD");
+        let mut record_file = match fs_err::File::open(&record_path) {
+            Ok(record_file) => record_file,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                return Err(Error::MissingRecord(record_path));
+            }
+            Err(err) => return Err(err.into()),
+        };
+        read_record_file(&mut record_file)?
+    };
+
+    // Track the recorded paths, along with the top-level directories that contain them.
+    let mut recorded = FxHashSet::default();
+    let mut directories = FxHashSet::default();
+    for entry in &record {
+        let path = PathBuf::from(&entry.path);
+        let mut components = path.components();
+        if let (Some(Component::Normal(directory)), Some(_)) =
+            (components.next(), components.next())
+        {
+            directories.insert(PathBuf::from(directory));
+        }
+        recorded.insert(path);
+    }
+
+    let mut verification = Verification::default();
+    for entry in record {
+        let Some(expected) = entry.hash else {
+            continue;
+        };
+        if !expected.starts_with("sha256=") {
+            trace!("Skipping unsupported hash for: {}", entry.path);
+            continue;
+        }
+
+        let path = site_packages.join(&entry.path);
+        let mut file = match fs_err::File::open(&path) {
+            Ok(file) => file,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                verification
+                    .mismatches
+                    .push(RecordMismatch::Missing(PathBuf::from(entry.path)));
+                continue;
+            }
+            Err(err) => return Err(err.into()),
+        };
+        let (_, actual) = copy_and_hash(&mut file, &mut io::sink())?;
+        verification.file_count += 1;
+
+        if actual != expected {
+            verification.mismatches.push(RecordMismatch::Modified {
+                path: PathBuf::from(entry.path),
+                expected,
+                actual,
+            });
+        }
+    }
+
+    // Find any files in the recorded directories that aren't listed in the `RECORD`.
+    let mut extra = Vec::new();
+    for directory in directories {
+        let walker = WalkDir::new(site_packages.join(directory))
+            .into_iter()
+            .filter_entry(|entry| entry.file_name() != "__pycache__");
+        for entry in walker {
+            let entry = match entry {
+                Ok(entry) => entry,
+                Err(err)
+                    if err
+                        .io_error()
+                        .is_some_and(|err| err.kind() == io::ErrorKind::NotFound) =>
+                {
+                    continue;
+                }
+                Err(err) => return Err(err.into()),
+            };
+            if entry.file_type().is_dir() {
+                continue;
+            }
+            let Ok(relative) = entry.path().strip_prefix(site_packages) else {
+                return Err(Error::BrokenVenv(format!(
+                    "`{}` is not in the site-packages directory: `{}`",
+                    entry.path().user_display(),
+                    site_packages.user_display()
+                )));
+            };
+            if !recorded.contains(relative) {
+                extra.push(RecordMismatch::Extra(relative.to_path_buf()));
+            }
+        }
+    }
+    extra.sort_by(|a, b| a.path().cmp(b.path()));
+    verification.mismatches.extend(extra);
+
+    Ok(verification)
+}
+
+/// The result of verifying an installed wheel against its `RECORD` file.
+#[derive(Debug, Default)]
+pub struct Verification {
+    /// The number of files whose contents were hashed.
+    pub file_count: usize,
+    /// The files that are missing, extra, or don't match the `RECORD`.
+    pub mismatches: Vec<RecordMismatch>,
+}
+
+impl Verification {
+    /// Returns `true` if all files match the `RECORD`.
+    pub fn is_valid(&self) -> bool {
+        self.mismatches.is_empty()
+    }
+}
+
+/// A file that doesn't match its `RECORD` entry, with paths relative to `site-packages`.
+#[derive(Debug)]
+pub enum RecordMismatch {
+    /// The file listed in the `RECORD` doesn't exist.
+    Missing(PathBuf),
+    /// The file's contents don't match the hash listed in the `RECORD`.
+    Modified {
+        path: PathBuf,
+        expected: String,
+        actual: String,
+    },
+    /// The file isn't listed in the `RECORD`.
+    Extra(PathBuf),
+}
+
+impl RecordMismatch {
+    /// Return the path of the mismatched file, relative to `site-packages`.
+    pub fn path(&self) -> &Path {
+        match self {
+            Self::Missing(path) => path,
+            Self::Modified { path, .. } => path,
+            Self::Extra(path) => path,
+        }
+    }
+}
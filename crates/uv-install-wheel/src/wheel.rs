@@ -78,7 +78,10 @@ pub(crate) fn read_scripts_from_section(
 /// <https://github.com/richo/hashing-copy/blob/d8dd2fdb63c6faf198de0c9e5713d6249cbb5323/src/lib.rs#L10-L52>
 /// which in turn got it from std
 /// <https://doc.rust-lang.org/1.58.0/src/std/io/copy.rs.html#128-156>
-fn copy_and_hash(reader: &mut impl Read, writer: &mut impl Write) -> io::Result<(u64, String)> {
+pub(crate) fn copy_and_hash(
+    reader: &mut impl Read,
+    writer: &mut impl Write,
+) -> io::Result<(u64, String)> {
     // TODO: Do we need to support anything besides sha256?
     let mut hasher = Sha256::new();
     // Same buf size as std. Note that this number is important for performance
@@ -720,13 +723,18 @@ pub(crate) fn write_file_recorded(
 }
 
 /// Adds `INSTALLER`, `REQUESTED` and `direct_url.json` to the .dist-info dir
-pub(crate) fn write_installer_metadata<Cache: serde::Serialize, Build: serde::Serialize>(
+pub(crate) fn write_installer_metadata<
+    Cache: serde::Serialize,
+    Build: serde::Serialize,
+    Interpreter: serde::Serialize,
+>(
     site_packages: &Path,
     dist_info_prefix: &str,
     requested: bool,
     direct_url: Option<&DirectUrl>,
     cache_info: Option<&Cache>,
     build_info: Option<&Build>,
+    interpreter_info: Option<&Interpreter>,
     installer: Option<&str>,
     record: &mut Vec<RecordEntry>,
 ) -> Result<(), Error> {
@@ -758,6 +766,14 @@ pub(crate) fn write_installer_metadata<Cache: serde::Serialize, Build: serde::Se
             record,
         )?;
     }
+    if let Some(interpreter_info) = interpreter_info {
+        write_file_recorded(
+            site_packages,
+            &dist_info_dir.join("uv_interpreter.json"),
+            serde_json::to_string(interpreter_info)?.as_bytes(),
+            record,
+        )?;
+    }
     if let Some(installer) = installer {
         write_file_recorded(
             site_packages,
@@ -1243,13 +1259,14 @@ mod test {
             .child("foo-0.1.0.dist-info")
             .create_dir_all()
             .unwrap();
-        write_installer_metadata::<(), ()>(
+        write_installer_metadata::<(), (), ()>(
             site_packages,
             "foo-0.1.0",
             true,
             None,
             None,
             None,
+            None,
             Some("uv"),
             &mut record,
         )
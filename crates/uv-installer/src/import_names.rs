@@ -0,0 +1,347 @@
+use std::collections::BTreeSet;
+use std::io;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use fs_err::File;
+use rustc_hash::FxHashMap;
+use serde::{Deserialize, Serialize};
+use tracing::debug;
+
+use uv_cache::{Cache, CacheBucket, CacheEntry, integrity};
+use uv_cache_info::Timestamp;
+use uv_cache_key::cache_digest;
+use uv_distribution_types::{InstalledDistKind, Name};
+use uv_install_wheel::read_record_file;
+use uv_normalize::PackageName;
+
+use crate::SitePackages;
+
+/// Well-known import names that differ from the name of the distribution that provides them.
+///
+/// Entries are matched against the longest dotted prefix of the requested module, so namespace
+/// packages (like `google.protobuf`) can be listed alongside top-level modules.
+static SEED: &[(&str, &str)] = &[
+    ("Crypto", "pycryptodome"),
+    ("MySQLdb", "mysqlclient"),
+    ("OpenSSL", "pyopenssl"),
+    ("PIL", "pillow"),
+    ("attr", "attrs"),
+    ("bs4", "beautifulsoup4"),
+    ("cv2", "opencv-python"),
+    ("dateutil", "python-dateutil"),
+    ("docx", "python-docx"),
+    ("dotenv", "python-dotenv"),
+    ("fitz", "pymupdf"),
+    ("gi", "pygobject"),
+    ("git", "gitpython"),
+    ("google.protobuf", "protobuf"),
+    ("jose", "python-jose"),
+    ("jwt", "pyjwt"),
+    ("magic", "python-magic"),
+    ("multipart", "python-multipart"),
+    ("pkg_resources", "setuptools"),
+    ("pptx", "python-pptx"),
+    ("serial", "pyserial"),
+    ("skimage", "scikit-image"),
+    ("sklearn", "scikit-learn"),
+    ("slugify", "python-slugify"),
+    ("usb", "pyusb"),
+    ("win32api", "pywin32"),
+    ("wx", "wxpython"),
+    ("yaml", "pyyaml"),
+    ("zmq", "pyzmq"),
+];
+
+/// A mapping from import names (e.g., `yaml`) to the names of the distributions that provide
+/// them (e.g., `pyyaml`).
+///
+/// The mapping is derived from the `RECORD` files of the distributions installed in an
+/// environment, and falls back to a bundled set of well-known import names.
+#[derive(Debug, Default)]
+pub struct ImportNames {
+    /// Dotted module paths, mapped to the installed distributions that provide them.
+    installed: FxHashMap<String, BTreeSet<PackageName>>,
+}
+
+/// The `RECORD` file for each distribution installed in an environment, along with its
+/// modification time (if it exists).
+///
+/// Used to determine whether a cached [`ImportNames`] mapping is up-to-date.
+type RecordState = Vec<(PathBuf, Option<Timestamp>)>;
+
+/// An [`ImportNames`] mapping, as persisted in the cache.
+#[derive(Deserialize, Serialize)]
+struct CachedImportNames {
+    /// The state of the environment from which the mapping was derived.
+    records: RecordState,
+    /// Dotted module paths, mapped to the installed distributions that provide them.
+    installed: FxHashMap<String, BTreeSet<PackageName>>,
+}
+
+impl ImportNames {
+    /// Build the mapping from the distributions installed in the given environment.
+    ///
+    /// The mapping is persisted in the cache, and only rebuilt if a distribution has been
+    /// installed, removed, or modified since it was last built.
+    pub fn from_site_packages(site_packages: &SitePackages, cache: &Cache) -> Self {
+        // Only `.dist-info` directories include a `RECORD` file.
+        let mut dists = site_packages
+            .iter()
+            .filter(|dist| {
+                matches!(
+                    dist.kind,
+                    InstalledDistKind::Registry(_) | InstalledDistKind::Url(_)
+                )
+            })
+            .map(|dist| (dist.install_path().join("RECORD"), dist))
+            .collect::<Vec<_>>();
+        dists.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
+
+        let records = dists
+            .iter()
+            .map(|(path, _)| (path.clone(), Timestamp::from_path(path).ok()))
+            .collect::<RecordState>();
+
+        let site_packages_dirs = site_packages
+            .interpreter()
+            .site_packages()
+            .collect::<Vec<_>>();
+        let entry = cache.entry(
+            CacheBucket::ImportNames,
+            "",
+            format!("{}.msgpack", cache_digest(&site_packages_dirs)),
+        );
+        if let Some(cached) = read_entry(&entry)
+            && cached.records == records
+        {
+            debug!("Using cached import names for: {}", entry.path().display());
+            return Self {
+                installed: cached.installed,
+            };
+        }
+
+        let mut import_names = Self::default();
+        for (path, dist) in dists {
+            let record = match File::open(&path)
+                .map_err(uv_install_wheel::Error::from)
+                .and_then(|mut file| read_record_file(&mut file))
+            {
+                Ok(record) => record,
+                Err(err) => {
+                    debug!("Failed to read `RECORD` for `{}`: {err}", dist.name());
+                    continue;
+                }
+            };
+            for entry in record {
+                import_names.insert(dist.name(), &entry.path);
+            }
+        }
+
+        let cached = CachedImportNames {
+            records,
+            installed: import_names.installed,
+        };
+        if let Err(err) = write_entry(&entry, &cached) {
+            debug!("Failed to cache import names: {err}");
+        }
+        Self {
+            installed: cached.installed,
+        }
+    }
+
+    /// Register the module at the given `RECORD` path as provided by the given distribution.
+    fn insert(&mut self, name: &PackageName, path: &str) {
+        let mut components = path.split('/').collect::<Vec<_>>();
+        let Some(file_name) = components.pop() else {
+            return;
+        };
+
+        // Extract the module name from Python sources and extension modules, e.g., `foo.py` or
+        // `foo.cpython-312-x86_64-linux-gnu.so`.
+        let module = if let Some(stem) = file_name.strip_suffix(".py") {
+            stem
+        } else if let Some((stem, _)) = file_name.split_once('.')
+            && [".so", ".pyd"]
+                .iter()
+                .any(|extension| file_name.ends_with(extension))
+        {
+            stem
+        } else {
+            return;
+        };
+        if module != "__init__" {
+            components.push(module);
+        }
+
+        // Skip anything that isn't importable, like `.dist-info` and `.data` directories, scripts
+        // that were installed outside of `site-packages`, and bytecode caches.
+        if components.is_empty()
+            || !components
+                .iter()
+                .all(|component| is_identifier(component) && *component != "__pycache__")
+        {
+            return;
+        }
+
+        for depth in 1..=components.len() {
+            self.installed
+                .entry(components[..depth].join("."))
+                .or_default()
+                .insert(name.clone());
+        }
+    }
+
+    /// Determine the distribution that provides the given module (e.g., `numpy.typing`).
+    ///
+    /// Prefers distributions installed in the environment, then the bundled set of well-known
+    /// import names, and otherwise assumes that the distribution is named after the top-level
+    /// module.
+    ///
+    /// Returns `None` if the module is not a valid dotted module path.
+    pub fn resolve(&self, module: &str) -> Option<PackageName> {
+        let components = module.split('.').collect::<Vec<_>>();
+        if !components.iter().all(|component| is_identifier(component)) {
+            return None;
+        }
+
+        for depth in (1..=components.len()).rev() {
+            let prefix = components[..depth].join(".");
+            if let Some(names) = self.installed.get(&prefix)
+                && let [name] = names.iter().collect::<Vec<_>>()[..]
+            {
+                return Some(name.clone());
+            }
+            if let Some((_, name)) = SEED.iter().find(|(import, _)| *import == prefix) {
+                return PackageName::from_str(name).ok();
+            }
+        }
+
+        PackageName::from_str(components[0]).ok()
+    }
+}
+
+/// Read a cached [`ImportNames`] mapping, ignoring any missing or invalid entries.
+fn read_entry(entry: &CacheEntry) -> Option<CachedImportNames> {
+    let data = match fs_err::read(entry.path()) {
+        Ok(data) => data,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return None,
+        Err(err) => {
+            debug!("Failed to read cached import names: {err}");
+            return None;
+        }
+    };
+    match integrity::unseal(&data)
+        .map_err(|err| err.to_string())
+        .and_then(|data| rmp_serde::from_slice(data).map_err(|err| err.to_string()))
+    {
+        Ok(cached) => Some(cached),
+        Err(err) => {
+            debug!(
+                "Ignoring invalid cached import names at {}: {err}",
+                entry.path().display()
+            );
+            None
+        }
+    }
+}
+
+/// Persist an [`ImportNames`] mapping to the cache.
+fn write_entry(entry: &CacheEntry, cached: &CachedImportNames) -> io::Result<()> {
+    let data = rmp_serde::to_vec(cached).map_err(io::Error::other)?;
+    fs_err::create_dir_all(entry.dir())?;
+    uv_fs::write_atomic_sync(entry.path(), integrity::seal(&data))
+}
+
+/// Returns `true` if the string is a valid Python identifier (restricted to ASCII).
+fn is_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|char| char.is_ascii_alphabetic() || char == '_')
+        && chars.all(|char| char.is_ascii_alphanumeric() || char == '_')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn name(name: &str) -> PackageName {
+        PackageName::from_str(name).unwrap()
+    }
+
+    #[test]
+    fn resolve() {
+        let mut import_names = ImportNames::default();
+        import_names.insert(&name("pyyaml"), "yaml/__init__.py");
+        import_names.insert(
+            &name("pyyaml"),
+            "_yaml/_yaml.cpython-312-x86_64-linux-gnu.so",
+        );
+        import_names.insert(&name("pyyaml"), "PyYAML-6.0.1.dist-info/RECORD");
+        import_names.insert(&name("protobuf"), "google/protobuf/message.py");
+        import_names.insert(&name("googleapis-common-protos"), "google/api/http.py");
+        import_names.insert(&name("black"), "../../bin/black");
+
+        // Installed distributions take precedence.
+        assert_eq!(import_names.resolve("yaml").unwrap(), name("pyyaml"));
+        assert_eq!(import_names.resolve("_yaml").unwrap(), name("pyyaml"));
+        assert_eq!(
+            import_names.resolve("google.protobuf.message").unwrap(),
+            name("protobuf")
+        );
+        assert_eq!(
+            import_names.resolve("google.api").unwrap(),
+            name("googleapis-common-protos")
+        );
+
+        // Ambiguous namespace packages fall back to the top-level module.
+        assert_eq!(import_names.resolve("google").unwrap(), name("google"));
+
+        // Otherwise, fall back to the bundled set of well-known import names.
+        assert_eq!(import_names.resolve("PIL.Image").unwrap(), name("pillow"));
+        assert_eq!(
+            import_names.resolve("sklearn").unwrap(),
+            name("scikit-learn")
+        );
+
+        // Otherwise, assume that the distribution is named after the top-level module.
+        assert_eq!(import_names.resolve("numpy.typing").unwrap(), name("numpy"));
+        assert!(import_names.resolve("").is_none());
+        assert!(import_names.resolve("numpy..typing").is_none());
+    }
+
+    #[test]
+    fn cache_roundtrip() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let entry = CacheEntry::new(temp_dir.path().join("import-names"), "site.msgpack");
+        assert!(read_entry(&entry).is_none());
+
+        let record = temp_dir
+            .path()
+            .join("PyYAML-6.0.1.dist-info")
+            .join("RECORD");
+        let mut import_names = ImportNames::default();
+        import_names.insert(&name("pyyaml"), "yaml/__init__.py");
+        let records = vec![(record, Some(Timestamp::now()))];
+        write_entry(
+            &entry,
+            &CachedImportNames {
+                records: records.clone(),
+                installed: import_names.installed,
+            },
+        )
+        .unwrap();
+
+        let cached = read_entry(&entry).unwrap();
+        assert_eq!(cached.records, records);
+        let import_names = ImportNames {
+            installed: cached.installed,
+        };
+        assert_eq!(import_names.resolve("yaml").unwrap(), name("pyyaml"));
+
+        // Invalid entries are ignored.
+        fs_err::write(entry.path(), b"invalid").unwrap();
+        assert!(read_entry(&entry).is_none());
+    }
+}
@@ -8,7 +8,7 @@ use tracing::{instrument, warn};
 
 use uv_cache::Cache;
 use uv_configuration::RAYON_INITIALIZE;
-use uv_distribution_types::CachedDist;
+use uv_distribution_types::{CachedDist, InterpreterFingerprint};
 use uv_install_wheel::{Layout, LinkMode};
 use uv_preview::Preview;
 use uv_python::PythonEnvironment;
@@ -106,6 +106,7 @@ impl<'a> Installer<'a> {
         let (tx, rx) = oneshot::channel();
 
         let layout = venv.interpreter().layout();
+        let fingerprint = venv.interpreter().fingerprint();
         let relocatable = venv.relocatable();
         // Initialize the threadpool with the user settings.
         LazyLock::force(&RAYON_INITIALIZE);
@@ -113,6 +114,7 @@ impl<'a> Installer<'a> {
             let result = install(
                 wheels,
                 &layout,
+                &fingerprint,
                 installer_name.as_deref(),
                 link_mode,
                 reporter.as_ref(),
@@ -144,6 +146,7 @@ impl<'a> Installer<'a> {
         install(
             wheels,
             &self.venv.interpreter().layout(),
+            &self.venv.interpreter().fingerprint(),
             self.name.as_deref(),
             self.link_mode,
             self.reporter.as_ref(),
@@ -159,6 +162,7 @@ impl<'a> Installer<'a> {
 fn install(
     wheels: Vec<CachedDist>,
     layout: &Layout,
+    fingerprint: &InterpreterFingerprint,
     installer_name: Option<&str>,
     link_mode: LinkMode,
     reporter: Option<&Arc<dyn Reporter>>,
@@ -185,6 +189,8 @@ fn install(
                 Some(wheel.cache_info())
             },
             wheel.build_info(),
+            // Only wheels that were built from source are tied to the building interpreter.
+            wheel.build_info().and(Some(fingerprint)),
             installer_name,
             installer_metadata,
             link_mode,
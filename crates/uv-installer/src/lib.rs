@@ -1,13 +1,15 @@
 pub use compile::{CompileError, compile_tree};
+pub use import_names::ImportNames;
 pub use installer::{Installer, Reporter as InstallReporter};
 pub use plan::{Plan, Planner};
 pub use preparer::{Error as PrepareError, Preparer, Reporter as PrepareReporter};
 pub use site_packages::{
-    InstallationStrategy, SatisfiesResult, SitePackages, SitePackagesDiagnostic,
+    DiagnosticSeverity, InstallationStrategy, SatisfiesResult, SitePackages, SitePackagesDiagnostic,
 };
 pub use uninstall::{UninstallError, uninstall};
 
 mod compile;
+mod import_names;
 mod preparer;
 
 mod installer;
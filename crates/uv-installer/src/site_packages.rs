@@ -1,18 +1,21 @@
 use std::borrow::Cow;
 use std::collections::BTreeSet;
+use std::ffi::OsStr;
 use std::iter::Flatten;
-use std::path::PathBuf;
+use std::path::{Component, Path, PathBuf};
 
 use anyhow::{Context, Result};
 use fs_err as fs;
 use rustc_hash::{FxBuildHasher, FxHashMap, FxHashSet};
+use tracing::{debug, warn};
 
 use uv_distribution_types::{
     ConfigSettings, Diagnostic, ExtraBuildRequires, ExtraBuildVariables, InstalledDist,
-    InstalledDistKind, Name, NameRequirementSpecification, PackageConfigSettings, Requirement,
-    UnresolvedRequirement, UnresolvedRequirementSpecification,
+    InstalledDistKind, InterpreterFingerprint, Name, NameRequirementSpecification,
+    PackageConfigSettings, Requirement, UnresolvedRequirement, UnresolvedRequirementSpecification,
 };
 use uv_fs::Simplified;
+use uv_install_wheel::read_record_file;
 use uv_normalize::PackageName;
 use uv_pep440::{Version, VersionSpecifiers};
 use uv_pep508::VersionOrUrl;
@@ -153,6 +156,25 @@ impl SitePackages {
         self.distributions.iter().flatten()
     }
 
+    /// Returns the installed distributions that were built against an interpreter other than the
+    /// one used to install the packages, along with the interpreter they were built against.
+    ///
+    /// Only distributions that were built from source record the interpreter they were built
+    /// against; all other distributions are omitted.
+    pub fn interpreter_mismatches(&self) -> Vec<(&InstalledDist, InterpreterFingerprint)> {
+        let fingerprint = self.interpreter.fingerprint();
+        self.iter()
+            .filter_map(|dist| match dist.interpreter_fingerprint() {
+                Ok(Some(built_with)) if built_with != fingerprint => Some((dist, built_with)),
+                Ok(_) => None,
+                Err(err) => {
+                    warn!("Failed to read the interpreter fingerprint for `{dist}`: {err}");
+                    None
+                }
+            })
+            .collect()
+    }
+
     /// Returns the installed distributions for a given package.
     pub fn get_packages(&self, name: &PackageName) -> Vec<&InstalledDist> {
         let Some(indexes) = self.by_name.get(name) else {
@@ -308,6 +330,76 @@ impl SitePackages {
         Ok(diagnostics)
     }
 
+    /// Find the files and directories in `site-packages` that aren't listed in the `RECORD` of any
+    /// installed distribution.
+    ///
+    /// Directories that contain a distribution without a `RECORD` (e.g., a legacy `.egg-info`
+    /// install) are skipped, since the files owned by that distribution can't be determined.
+    pub fn orphaned_files(&self) -> Result<Vec<SitePackagesDiagnostic>> {
+        let mut diagnostics = Vec::new();
+
+        for site_packages in self.interpreter.site_packages() {
+            let site_packages = site_packages.as_ref();
+            let paths = match fs::read_dir(site_packages) {
+                Ok(read_dir) => read_dir
+                    .map(|entry| entry.map(|entry| entry.path()))
+                    .collect::<Result<BTreeSet<_>, std::io::Error>>()?,
+                Err(err) if err.kind() == std::io::ErrorKind::NotFound => continue,
+                Err(err) => return Err(err).context("Failed to read site-packages directory"),
+            };
+
+            // Collect the top-level entries owned by the distributions in this directory.
+            let mut owned = FxHashSet::default();
+            let mut complete = true;
+            for distribution in self.iter() {
+                // Legacy editables are linked from `site-packages`, but installed elsewhere.
+                if let InstalledDistKind::LegacyEditable(dist) = &distribution.kind {
+                    if dist.egg_link.parent() == Some(site_packages) {
+                        complete = false;
+                        break;
+                    }
+                }
+                let install_path = distribution.install_path();
+                if install_path.parent() != Some(site_packages) {
+                    continue;
+                }
+                if let Some(name) = install_path.file_name() {
+                    owned.insert(name.to_os_string());
+                }
+                let Ok(mut record) = fs::File::open(install_path.join("RECORD")) else {
+                    complete = false;
+                    break;
+                };
+                for entry in read_record_file(&mut record)? {
+                    if let Some(Component::Normal(name)) =
+                        Path::new(&entry.path).components().next()
+                    {
+                        owned.insert(name.to_os_string());
+                    }
+                }
+            }
+            if !complete {
+                debug!(
+                    "Skipping orphaned file check for `{}`, which contains a distribution without a `RECORD`",
+                    site_packages.user_display()
+                );
+                continue;
+            }
+
+            for path in paths {
+                let Some(name) = path.file_name() else {
+                    continue;
+                };
+                if owned.contains(name) || is_unowned_by_design(name) {
+                    continue;
+                }
+                diagnostics.push(SitePackagesDiagnostic::OrphanedFile { path });
+            }
+        }
+
+        Ok(diagnostics)
+    }
+
     /// Returns if the installed packages satisfy the given requirements.
     pub fn satisfies_spec(
         &self,
@@ -610,6 +702,28 @@ impl IntoIterator for SitePackages {
     }
 }
 
+/// Returns `true` if an entry in `site-packages` is expected to not be owned by any distribution,
+/// like the `README.txt` shipped with CPython, or the files created by virtual environment tools.
+fn is_unowned_by_design(name: &OsStr) -> bool {
+    let Some(name) = name.to_str() else {
+        return false;
+    };
+    name.starts_with('.')
+        || matches!(
+            name,
+            "__pycache__" | "README.txt" | "_virtualenv.py" | "_virtualenv.pth"
+        )
+}
+
+/// The severity of a [`SitePackagesDiagnostic`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum DiagnosticSeverity {
+    /// The environment is unusual, but the installed packages are compatible.
+    Warning,
+    /// The installed packages are broken or incompatible.
+    Error,
+}
+
 #[derive(Debug)]
 pub enum SitePackagesDiagnostic {
     MetadataUnavailable {
@@ -656,6 +770,27 @@ pub enum SitePackagesDiagnostic {
         /// The installed versions of the package.
         paths: Vec<PathBuf>,
     },
+    OrphanedFile {
+        /// The file or directory that isn't owned by any installed distribution.
+        path: PathBuf,
+    },
+}
+
+impl SitePackagesDiagnostic {
+    /// Returns the [`DiagnosticSeverity`] of the diagnostic.
+    pub fn severity(&self) -> DiagnosticSeverity {
+        match self {
+            Self::MetadataUnavailable { .. }
+            | Self::TagsUnavailable { .. }
+            | Self::IncompatiblePythonVersion { .. }
+            | Self::IncompatiblePlatform { .. }
+            | Self::MissingDependency { .. }
+            | Self::IncompatibleDependency { .. } => DiagnosticSeverity::Error,
+            Self::DuplicatePackage { .. } | Self::OrphanedFile { .. } => {
+                DiagnosticSeverity::Warning
+            }
+        }
+    }
 }
 
 impl Diagnostic for SitePackagesDiagnostic {
@@ -702,6 +837,10 @@ impl Diagnostic for SitePackagesDiagnostic {
                         + &format!("\n  - {}", path.display()))
                 )
             }
+            Self::OrphanedFile { path } => format!(
+                "The path `{}` isn't owned by any installed package",
+                path.display()
+            ),
         }
     }
 
@@ -719,6 +858,7 @@ impl Diagnostic for SitePackagesDiagnostic {
                 ..
             } => name == package || &requirement.name == name,
             Self::DuplicatePackage { package, .. } => name == package,
+            Self::OrphanedFile { .. } => false,
         }
     }
 }
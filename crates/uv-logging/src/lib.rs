@@ -1,4 +1,5 @@
 use std::fmt;
+use std::io;
 
 use jiff::Timestamp;
 use owo_colors::OwoColorize;
@@ -7,6 +8,8 @@ use tracing_subscriber::fmt::format::Writer;
 use tracing_subscriber::fmt::{FmtContext, FormatEvent, FormatFields};
 use tracing_subscriber::registry::LookupSpan;
 
+use uv_redacted::redact_credentials;
+
 /// The style of a uv logging line.
 pub struct UvFormat {
     pub display_timestamp: bool,
@@ -93,3 +96,24 @@ where
         writeln!(writer)
     }
 }
+
+/// A writer that redacts credentials from any URLs in the log output.
+///
+/// Log messages may include URLs that weren't formatted via `DisplaySafeUrl`, e.g., in errors from
+/// dependencies. Each formatted event is written in a single call, so URLs aren't split across
+/// writes.
+pub struct RedactingWriter<W>(pub W);
+
+impl<W: io::Write> io::Write for RedactingWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let Ok(text) = std::str::from_utf8(buf) else {
+            return self.0.write(buf);
+        };
+        self.0.write_all(redact_credentials(text).as_bytes())?;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.0.flush()
+    }
+}
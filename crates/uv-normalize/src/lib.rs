@@ -194,6 +194,23 @@ mod tests {
         }
     }
 
+    #[test]
+    fn lookalike() {
+        for input in [
+            "ｆriendly-bard",
+            "friendly‐bard",
+            "friendly\u{200B}-bard",
+            "ＦＲＩＥＮＤＬＹ＿ＢＡＲＤ",
+        ] {
+            assert_eq!(
+                PackageName::from_lookalike(input).unwrap().as_ref(),
+                "friendly-bard"
+            );
+        }
+        assert_eq!(PackageName::from_lookalike("friendly-bard"), None);
+        assert_eq!(PackageName::from_lookalike("friendly-bärd"), None);
+    }
+
     #[test]
     fn check() {
         let inputs = ["friendly-bard", "friendlybard"];
@@ -40,6 +40,33 @@ impl PackageName {
         validate_and_normalize_ref(&name).map(Self)
     }
 
+    /// Create a package name from a name that's only valid once common Unicode lookalikes are
+    /// replaced with their ASCII equivalents.
+    ///
+    /// Package names are restricted to ASCII, but indexes occasionally serve files with names that
+    /// contain fullwidth forms (e.g., `ｒequests`), Unicode dashes (e.g., `‐`), or invisible
+    /// characters (e.g., a zero-width space), typically as a result of copy-pasting. Returns `None`
+    /// if the name doesn't contain any such characters, or isn't valid after replacing them.
+    pub fn from_lookalike(name: &str) -> Option<Self> {
+        if name.is_ascii() {
+            return None;
+        }
+        let mut ascii = String::with_capacity(name.len());
+        for char in name.chars() {
+            match char {
+                // Fullwidth forms of the printable ASCII characters.
+                '\u{FF01}'..='\u{FF5E}' => ascii.push(char::from_u32(u32::from(char) - 0xFEE0)?),
+                // Hyphens, dashes, and minus signs.
+                '\u{2010}'..='\u{2015}' | '\u{2212}' | '\u{FE63}' => ascii.push('-'),
+                // Soft hyphens, zero-width characters, and byte order marks.
+                '\u{00AD}' | '\u{200B}'..='\u{200D}' | '\u{2060}' | '\u{FEFF}' => {}
+                char if char.is_ascii() => ascii.push(char),
+                _ => return None,
+            }
+        }
+        validate_and_normalize_ref(ascii).map(Self).ok()
+    }
+
     /// Escape this name with underscores (`_`) instead of dashes (`-`)
     ///
     /// See: <https://packaging.python.org/en/latest/specifications/recording-installed-packages/#recording-installed-packages>
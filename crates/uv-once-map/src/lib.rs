@@ -65,6 +65,8 @@ impl<K: Eq + Hash, V: Clone, H: BuildHasher + Clone> OnceMap<K, V, H> {
 
     /// Wait for the result of a job that is running.
     ///
+    /// Returns `None` if no job was registered for this key, or if its result was removed.
+    ///
     /// Will hang if [`OnceMap::done`] isn't called for this key.
     pub async fn wait(&self, key: &K) -> Option<V> {
         let notify = {
@@ -79,14 +81,15 @@ impl<K: Eq + Hash, V: Clone, H: BuildHasher + Clone> OnceMap<K, V, H> {
         let notification = notify.notified();
 
         // Make sure the value wasn't inserted in-between us checking the map and registering the waiter.
-        if let Value::Filled(value) = self.items.get(key).expect("map is append-only").value() {
+        // Pending jobs are never removed, but the result may be removed once it was inserted.
+        if let Value::Filled(value) = self.items.get(key)?.value() {
             return Some(value.clone());
         }
 
         // Wait until the value is inserted.
         notification.await;
 
-        let entry = self.items.get(key).expect("map is append-only");
+        let entry = self.items.get(key)?;
         match entry.value() {
             Value::Filled(value) => Some(value.clone()),
             Value::Waiting(_) => unreachable!("notify was called"),
@@ -113,14 +116,19 @@ impl<K: Eq + Hash, V: Clone, H: BuildHasher + Clone> OnceMap<K, V, H> {
     }
 
     /// Remove the result of a previous job, if any.
+    ///
+    /// Jobs that are still running are left in place, such that their waiters are notified once
+    /// they complete.
     pub fn remove<Q: ?Sized + Hash + Eq>(&self, key: &Q) -> Option<V>
     where
         K: Borrow<Q>,
     {
-        let entry = self.items.remove(key)?;
+        let (_, entry) = self
+            .items
+            .remove_if(key, |_, value| matches!(value, Value::Filled(_)))?;
         match entry {
-            (_, Value::Filled(value)) => Some(value),
-            (_, Value::Waiting(_)) => None,
+            Value::Filled(value) => Some(value),
+            Value::Waiting(_) => None,
         }
     }
 }
@@ -382,6 +382,15 @@ impl Tags {
             .unwrap_or(false)
     }
 
+    /// Returns `true` if the given platform tag is compatible with the current environment, for
+    /// any language and ABI tag.
+    pub fn is_compatible_platform(&self, platform_tag: &PlatformTag) -> bool {
+        self.map
+            .values()
+            .flat_map(|abis| abis.values())
+            .any(|platforms| platforms.contains_key(platform_tag))
+    }
+
     pub fn python_platform(&self) -> &Platform {
         &self.python_platform
     }
@@ -163,7 +163,7 @@ pub mod test {
 }
 
 #[bitflags]
-#[repr(u32)]
+#[repr(u64)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum PreviewFeature {
     PythonInstallDefault = 1 << 0,
@@ -193,6 +193,19 @@ pub enum PreviewFeature {
     RelocatableEnvsDefault = 1 << 24,
     PublishRequireNormalized = 1 << 25,
     Audit = 1 << 26,
+    WheelRetag = 1 << 27,
+    IndexBuild = 1 << 28,
+    Vendor = 1 << 29,
+    CacheWarm = 1 << 30,
+    CacheList = 1 << 31,
+    BuildEnvironmentCache = 1 << 32,
+    ResolutionHistory = 1 << 33,
+    IndexPing = 1 << 34,
+    Licenses = 1 << 35,
+    Attestations = 1 << 36,
+    CacheVerify = 1 << 37,
+    CacheBundles = 1 << 38,
+    CloudRegistryAuth = 1 << 39,
 }
 
 impl PreviewFeature {
@@ -226,6 +239,19 @@ impl PreviewFeature {
             Self::RelocatableEnvsDefault => "relocatable-envs-default",
             Self::PublishRequireNormalized => "publish-require-normalized",
             Self::Audit => "audit",
+            Self::WheelRetag => "wheel-retag",
+            Self::IndexBuild => "index-build",
+            Self::Vendor => "vendor",
+            Self::CacheWarm => "cache-warm",
+            Self::CacheList => "cache-list",
+            Self::BuildEnvironmentCache => "build-environment-cache",
+            Self::ResolutionHistory => "resolution-history",
+            Self::IndexPing => "index-ping",
+            Self::Licenses => "licenses",
+            Self::Attestations => "attestations",
+            Self::CacheVerify => "cache-verify",
+            Self::CacheBundles => "cache-bundles",
+            Self::CloudRegistryAuth => "cloud-registry-auth",
         }
     }
 }
@@ -272,6 +298,19 @@ impl FromStr for PreviewFeature {
             "relocatable-envs-default" => Self::RelocatableEnvsDefault,
             "publish-require-normalized" => Self::PublishRequireNormalized,
             "audit" => Self::Audit,
+            "wheel-retag" => Self::WheelRetag,
+            "index-build" => Self::IndexBuild,
+            "vendor" => Self::Vendor,
+            "cache-warm" => Self::CacheWarm,
+            "cache-list" => Self::CacheList,
+            "build-environment-cache" => Self::BuildEnvironmentCache,
+            "resolution-history" => Self::ResolutionHistory,
+            "index-ping" => Self::IndexPing,
+            "licenses" => Self::Licenses,
+            "attestations" => Self::Attestations,
+            "cache-verify" => Self::CacheVerify,
+            "cache-bundles" => Self::CacheBundles,
+            "cloud-registry-auth" => Self::CloudRegistryAuth,
             _ => return Err(PreviewFeatureParseError),
         })
     }
@@ -18,6 +18,12 @@ use crate::{ProjectStatus, VerbatimParsedUrl};
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 pub struct PypiSimpleDetail {
+    /// The name of the project, as served by the index.
+    ///
+    /// Per PEP 691, this should be the normalized name; however, indexes with legacy project names
+    /// may serve a non-normalized name instead.
+    #[serde(default)]
+    pub name: Option<String>,
     /// PEP 792 project status information.
     #[serde(default)]
     pub project_status: ProjectStatus,
@@ -51,6 +57,9 @@ pub struct PypiFile {
     pub core_metadata: Option<CoreMetadata>,
     pub filename: SmallString,
     pub hashes: Hashes,
+    /// The URL of the [PEP 740](https://peps.python.org/pep-0740/) provenance object for the file,
+    /// if the index serves attestations.
+    pub provenance: Option<SmallString>,
     pub requires_python: Option<Result<VersionSpecifiers, VersionSpecifiersParseError>>,
     pub size: Option<u64>,
     pub upload_time: Option<Timestamp>,
@@ -79,6 +88,7 @@ impl<'de> Deserialize<'de> for PypiFile {
                 let mut core_metadata = None;
                 let mut filename = None;
                 let mut hashes = None;
+                let mut provenance = None;
                 let mut requires_python = None;
                 let mut size = None;
                 let mut upload_time = None;
@@ -96,6 +106,7 @@ impl<'de> Deserialize<'de> for PypiFile {
                         }
                         "filename" => filename = Some(access.next_value()?),
                         "hashes" => hashes = Some(access.next_value()?),
+                        "provenance" => provenance = access.next_value()?,
                         "requires-python" => {
                             requires_python =
                                 access.next_value::<Option<Cow<'_, str>>>()?.map(|s| {
@@ -118,6 +129,7 @@ impl<'de> Deserialize<'de> for PypiFile {
                     filename: filename
                         .ok_or_else(|| serde::de::Error::missing_field("filename"))?,
                     hashes: hashes.ok_or_else(|| serde::de::Error::missing_field("hashes"))?,
+                    provenance,
                     requires_python,
                     size,
                     upload_time,
@@ -285,6 +297,14 @@ impl CoreMetadata {
             Self::Hashes(_) => true,
         }
     }
+
+    /// Returns the hashes of the metadata file, if provided.
+    pub fn hashes(&self) -> Option<&Hashes> {
+        match self {
+            Self::Bool(_) => None,
+            Self::Hashes(hashes) => Some(hashes),
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, rkyv::Archive, rkyv::Deserialize, rkyv::Serialize)]
@@ -15,9 +15,10 @@ use serde::{Deserialize, Serialize};
 use thiserror::Error;
 use tracing::{debug, trace, warn};
 
-use uv_cache::{Cache, CacheBucket, CachedByTimestamp, Freshness};
+use uv_cache::{Cache, CacheBucket, CachedByTimestamp, Freshness, integrity};
 use uv_cache_info::Timestamp;
 use uv_cache_key::cache_digest;
+use uv_distribution_types::InterpreterFingerprint;
 use uv_fs::{
     LockedFile, LockedFileError, LockedFileMode, PythonExt, Simplified, write_atomic_sync,
 };
@@ -539,6 +540,17 @@ impl Interpreter {
         self.debug_enabled
     }
 
+    /// Return the [`InterpreterFingerprint`] recorded for distributions built against this
+    /// interpreter.
+    pub fn fingerprint(&self) -> InterpreterFingerprint {
+        InterpreterFingerprint::new(
+            self.implementation_name(),
+            self.python_full_version().to_string(),
+            self.gil_disabled,
+            self.debug_enabled,
+        )
+    }
+
     /// Return the `--target` directory for this interpreter, if any.
     pub fn target(&self) -> Option<&Target> {
         self.target.as_ref()
@@ -1164,7 +1176,12 @@ impl InterpreterInfo {
             .is_ok_and(Freshness::is_fresh)
         {
             if let Ok(data) = fs::read(cache_entry.path()) {
-                match rmp_serde::from_slice::<CachedByTimestamp<Self>>(&data) {
+                match integrity::unseal(&data)
+                    .map_err(|err| err.to_string())
+                    .and_then(|data| {
+                        rmp_serde::from_slice::<CachedByTimestamp<Self>>(data)
+                            .map_err(|err| err.to_string())
+                    }) {
                     Ok(cached) => {
                         if cached.timestamp == modified {
                             trace!(
@@ -1204,10 +1221,10 @@ impl InterpreterInfo {
             fs::create_dir_all(cache_entry.dir())?;
             write_atomic_sync(
                 cache_entry.path(),
-                rmp_serde::to_vec(&CachedByTimestamp {
+                integrity::seal(&rmp_serde::to_vec(&CachedByTimestamp {
                     timestamp: modified,
                     data: info.clone(),
-                })?,
+                })?),
             )?;
         }
 
@@ -0,0 +1,55 @@
+//! Layered requirements, e.g., a frozen base lock with an overlay of extra development tools.
+//!
+//! The overlay is read into its own [`RequirementsSpecification`] and resolved separately from the
+//! base requirements, constrained by the packages pinned in the resolution of the base.
+
+use anyhow::Result;
+
+use uv_client::BaseClientBuilder;
+use uv_distribution_types::{
+    Name, NameRequirementSpecification, Requirement, RequirementSource, Resolution,
+};
+use uv_pep508::MarkerTree;
+
+use crate::{RequirementsSource, RequirementsSpecification};
+
+/// A layer of requirements, resolved on top of the layers beneath it.
+#[derive(Debug, Clone)]
+pub struct RequirementsLayer {
+    /// The requirements, constraints, and index settings for the layer.
+    pub specification: RequirementsSpecification,
+}
+
+impl RequirementsLayer {
+    /// Read a [`RequirementsLayer`] from a set of sources.
+    pub async fn from_sources(
+        sources: &[RequirementsSource],
+        client_builder: &BaseClientBuilder<'_>,
+    ) -> Result<Self> {
+        let specification =
+            RequirementsSpecification::from_sources(sources, &[], &[], &[], None, client_builder)
+                .await?;
+        Ok(Self { specification })
+    }
+
+    /// Constrain the layer to the distributions pinned by the resolution of a lower layer.
+    ///
+    /// Every package in the lower resolution is pinned to its resolved version (or URL), such that
+    /// resolving this layer can add packages, but never change those of the layers beneath it.
+    #[must_use]
+    pub fn constrained_by(mut self, resolution: &Resolution) -> Self {
+        self.specification
+            .constraints
+            .extend(resolution.distributions().map(|dist| {
+                NameRequirementSpecification::from(Requirement {
+                    name: dist.name().clone(),
+                    extras: Box::default(),
+                    groups: Box::default(),
+                    marker: MarkerTree::TRUE,
+                    source: RequirementSource::from(dist),
+                    origin: None,
+                })
+            }));
+        self
+    }
+}
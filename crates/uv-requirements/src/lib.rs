@@ -1,5 +1,7 @@
 pub use crate::extras::*;
+pub use crate::layer::*;
 pub use crate::lookahead::*;
+pub use crate::lowerer::*;
 pub use crate::source_tree::*;
 pub use crate::sources::*;
 pub use crate::specification::*;
@@ -10,7 +12,9 @@ use uv_distribution_types::{
 };
 
 mod extras;
+mod layer;
 mod lookahead;
+mod lowerer;
 mod source_tree;
 mod sources;
 mod specification;
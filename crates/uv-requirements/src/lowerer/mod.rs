@@ -0,0 +1,168 @@
+//! An extension point for requirements file formats that aren't natively supported by uv.
+//!
+//! Crates that embed uv can register a [`SourceLowerer`] for their own manifest formats in a set of
+//! [`SourceLowerers`], passed to [`RequirementsSource::from_requirements_file_with_lowerers`]. Any
+//! file claimed by a registered lowerer is read as a [`RequirementsSource::Lowered`] source, and
+//! its contents are lowered into requirements as part of
+//! [`RequirementsSpecification::from_source`].
+//!
+//! uv itself uses the same mechanism to read the lockfiles of other tools (i.e., `poetry.lock` and
+//! `Pipfile.lock`), which are consulted after any registered lowerers.
+//!
+//! [`RequirementsSource::from_requirements_file_with_lowerers`]: crate::RequirementsSource::from_requirements_file_with_lowerers
+//! [`RequirementsSource::Lowered`]: crate::RequirementsSource::Lowered
+//! [`RequirementsSpecification::from_source`]: crate::RequirementsSpecification::from_source
+
+use std::fmt::Debug;
+use std::path::Path;
+use std::sync::{Arc, LazyLock};
+
+use anyhow::{Context, Result};
+
+use uv_distribution_types::UnresolvedRequirementSpecification;
+use uv_fs::CWD;
+use uv_pep508::RequirementOrigin;
+use uv_requirements_txt::{RequirementEntry, RequirementsTxtRequirement};
+
+use crate::lowerer::pipenv::PipfileLock;
+use crate::lowerer::poetry::PoetryLock;
+
+mod pipenv;
+mod poetry;
+
+/// Lowers a requirements file in a custom format into a set of requirements.
+pub trait SourceLowerer: Debug + Send + Sync {
+    /// A human-readable name for the format (e.g., `manifest.json`), used in error messages.
+    fn name(&self) -> &str;
+
+    /// Returns `true` if the lowerer handles the file at the given path.
+    ///
+    /// Lowerers are consulted before the built-in formats, in the order in which they were
+    /// registered.
+    fn matches(&self, path: &Path) -> bool;
+
+    /// Lower the contents of the file at the given path into requirements.
+    fn lower(&self, path: &Path, content: &str) -> Result<Vec<UnresolvedRequirementSpecification>>;
+}
+
+/// The lowerers for the lockfile formats that uv supports out of the box.
+static BUILTIN_LOWERERS: LazyLock<[Arc<dyn SourceLowerer>; 2]> =
+    LazyLock::new(|| [Arc::new(PoetryLock), Arc::new(PipfileLock)]);
+
+/// The [`SourceLowerer`]s to consult when parsing a requirements file, in addition to the
+/// built-in lowerers.
+#[derive(Debug, Clone, Default)]
+pub struct SourceLowerers(Vec<Arc<dyn SourceLowerer>>);
+
+impl SourceLowerers {
+    /// Register a [`SourceLowerer`] for a custom requirements file format.
+    #[must_use]
+    pub fn with(mut self, lowerer: impl SourceLowerer + 'static) -> Self {
+        self.0.push(Arc::new(lowerer));
+        self
+    }
+
+    /// Return the first [`SourceLowerer`] that handles the file at the given path, preferring
+    /// those that were registered over the built-in lowerers.
+    pub(crate) fn find(&self, path: &Path) -> Option<Arc<dyn SourceLowerer>> {
+        self.0
+            .iter()
+            .chain(BUILTIN_LOWERERS.iter())
+            .find(|lowerer| lowerer.matches(path))
+            .cloned()
+    }
+}
+
+/// Parse a single requirement from a lockfile at the given path, as if it were a line in a
+/// `requirements.txt` located alongside the lockfile.
+fn parse_requirement(
+    requirement: &str,
+    path: &Path,
+    editable: bool,
+    hashes: Vec<String>,
+) -> Result<UnresolvedRequirementSpecification> {
+    let working_dir = CWD.join(path);
+    let working_dir = working_dir.parent().unwrap_or(&CWD);
+
+    let requirement = RequirementsTxtRequirement::parse(requirement, working_dir, editable)
+        .with_context(|| format!("Failed to parse: `{requirement}`"))?
+        .with_origin(RequirementOrigin::File(path.to_path_buf()));
+    let requirement = if editable {
+        requirement.into_editable()?
+    } else {
+        requirement
+    };
+
+    Ok(UnresolvedRequirementSpecification::from(RequirementEntry {
+        requirement,
+        hashes,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use anyhow::Result;
+
+    use uv_client::BaseClientBuilder;
+    use uv_distribution_types::UnresolvedRequirementSpecification;
+
+    use crate::{RequirementsSource, RequirementsSpecification};
+
+    use super::{SourceLowerer, SourceLowerers, parse_requirement};
+
+    /// A lowerer for a manifest that lists one requirement per line.
+    #[derive(Debug)]
+    struct Manifest;
+
+    impl SourceLowerer for Manifest {
+        fn name(&self) -> &'static str {
+            "manifest.lst"
+        }
+
+        fn matches(&self, path: &Path) -> bool {
+            path.ends_with("manifest.lst")
+        }
+
+        fn lower(
+            &self,
+            path: &Path,
+            content: &str,
+        ) -> Result<Vec<UnresolvedRequirementSpecification>> {
+            content
+                .lines()
+                .map(|line| parse_requirement(line, path, false, Vec::new()))
+                .collect()
+        }
+    }
+
+    #[tokio::test]
+    async fn lower_registered_source() -> Result<()> {
+        let temp_dir = tempfile::tempdir()?;
+        let path = temp_dir.path().join("manifest.lst");
+        fs_err::write(&path, "anyio==4.3.0\nidna>=3.6\n")?;
+
+        // Without the lowerer, the file is read as a `requirements.txt` file.
+        let source = RequirementsSource::from_requirements_file(path.clone())?;
+        assert!(matches!(source, RequirementsSource::RequirementsTxt(_)));
+
+        let lowerers = SourceLowerers::default().with(Manifest);
+        let source = RequirementsSource::from_requirements_file_with_lowerers(path, &lowerers)?;
+        let RequirementsSource::Lowered(_, lowerer) = &source else {
+            panic!("Expected a lowered source, found: {source:?}");
+        };
+        assert_eq!(lowerer.name(), "manifest.lst");
+
+        let spec =
+            RequirementsSpecification::from_source(&source, &BaseClientBuilder::default()).await?;
+        let requirements = spec
+            .requirements
+            .iter()
+            .map(|requirement| requirement.requirement.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(requirements, ["anyio==4.3.0", "idna>=3.6"]);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,108 @@
+//! Lowering of Pipenv's `Pipfile.lock` format.
+//!
+//! Only the `default` packages are included; the `develop` packages (which Pipenv installs only
+//! with `--dev`) are omitted.
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::path::Path;
+
+use anyhow::Result;
+use serde::Deserialize;
+
+use uv_distribution_types::UnresolvedRequirementSpecification;
+use uv_normalize::{ExtraName, PackageName};
+
+use crate::lowerer::{SourceLowerer, parse_requirement};
+
+#[derive(Debug)]
+pub(crate) struct PipfileLock;
+
+impl SourceLowerer for PipfileLock {
+    fn name(&self) -> &'static str {
+        "Pipfile.lock"
+    }
+
+    fn matches(&self, path: &Path) -> bool {
+        path.ends_with("Pipfile.lock")
+    }
+
+    fn lower(&self, path: &Path, content: &str) -> Result<Vec<UnresolvedRequirementSpecification>> {
+        let lock = serde_json::from_str::<LockFile>(content)?;
+
+        let mut requirements = Vec::new();
+        for (name, package) in lock.default {
+            let requirement = package.to_requirement(&name);
+            requirements.push(parse_requirement(
+                &requirement,
+                path,
+                package.editable,
+                package.hashes,
+            )?);
+        }
+
+        Ok(requirements)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct LockFile {
+    #[serde(default)]
+    default: BTreeMap<PackageName, Package>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Package {
+    /// The pinned version, including the operator (e.g., `==2.31.0`).
+    version: Option<String>,
+    #[serde(default)]
+    hashes: Vec<String>,
+    markers: Option<String>,
+    #[serde(default)]
+    extras: Vec<ExtraName>,
+    git: Option<String>,
+    r#ref: Option<String>,
+    subdirectory: Option<String>,
+    path: Option<String>,
+    file: Option<String>,
+    #[serde(default)]
+    editable: bool,
+}
+
+impl Package {
+    /// Render the package as a `requirements.txt`-style requirement.
+    fn to_requirement(&self, name: &PackageName) -> String {
+        let mut requirement = name.to_string();
+
+        if !self.extras.is_empty() {
+            let extras = self
+                .extras
+                .iter()
+                .map(ExtraName::as_str)
+                .collect::<Vec<_>>()
+                .join(",");
+            let _ = write!(requirement, "[{extras}]");
+        }
+
+        if let Some(git) = &self.git {
+            let git = git.strip_prefix("git+").unwrap_or(git);
+            let _ = write!(requirement, " @ git+{git}");
+            if let Some(reference) = &self.r#ref {
+                let _ = write!(requirement, "@{reference}");
+            }
+            if let Some(subdirectory) = &self.subdirectory {
+                let _ = write!(requirement, "#subdirectory={subdirectory}");
+            }
+        } else if let Some(path) = self.path.as_ref().or(self.file.as_ref()) {
+            let _ = write!(requirement, " @ {path}");
+        } else if let Some(version) = &self.version {
+            requirement.push_str(version);
+        }
+
+        if let Some(markers) = &self.markers {
+            let _ = write!(requirement, " ; {markers}");
+        }
+
+        requirement
+    }
+}
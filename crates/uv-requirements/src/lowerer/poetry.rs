@@ -0,0 +1,179 @@
+//! Lowering of Poetry's `poetry.lock` format.
+//!
+//! Only the packages required by the `main` group are included; optional packages (which Poetry
+//! installs only when the corresponding extra is requested) and packages that are exclusive to
+//! other groups (e.g., `dev`) are omitted.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+
+use uv_distribution_types::UnresolvedRequirementSpecification;
+use uv_normalize::PackageName;
+
+use crate::lowerer::{SourceLowerer, parse_requirement};
+
+/// The name of Poetry's default dependency group.
+const MAIN_GROUP: &str = "main";
+
+#[derive(Debug)]
+pub(crate) struct PoetryLock;
+
+impl SourceLowerer for PoetryLock {
+    fn name(&self) -> &'static str {
+        "poetry.lock"
+    }
+
+    fn matches(&self, path: &Path) -> bool {
+        path.ends_with("poetry.lock")
+    }
+
+    fn lower(&self, path: &Path, content: &str) -> Result<Vec<UnresolvedRequirementSpecification>> {
+        let lock = toml::from_str::<LockFile>(content)?;
+
+        let mut requirements = Vec::new();
+        for package in &lock.package {
+            if package.optional || !package.is_main() {
+                continue;
+            }
+
+            let requirement = package.to_requirement()?;
+
+            // Lockfiles prior to v2 store the hashes in a separate table.
+            let files = if package.files.is_empty() {
+                lock.metadata
+                    .files
+                    .get(&package.name)
+                    .map(Vec::as_slice)
+                    .unwrap_or_default()
+            } else {
+                package.files.as_slice()
+            };
+            let hashes = files.iter().map(|file| file.hash.clone()).collect();
+
+            let editable = package
+                .source
+                .as_ref()
+                .is_some_and(|source| source.r#type == "directory" && package.develop);
+
+            requirements.push(parse_requirement(&requirement, path, editable, hashes)?);
+        }
+
+        Ok(requirements)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct LockFile {
+    #[serde(default)]
+    package: Vec<Package>,
+    #[serde(default)]
+    metadata: Metadata,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct Metadata {
+    #[serde(default)]
+    files: BTreeMap<PackageName, Vec<File>>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct Package {
+    name: PackageName,
+    version: String,
+    #[serde(default)]
+    optional: bool,
+    /// The group to which the package belongs, in lockfiles prior to v2.1.
+    category: Option<String>,
+    /// The groups that require the package, in lockfiles since v2.1.
+    groups: Option<Vec<String>>,
+    markers: Option<Markers>,
+    #[serde(default)]
+    files: Vec<File>,
+    #[serde(default)]
+    develop: bool,
+    source: Option<Source>,
+}
+
+impl Package {
+    /// Returns `true` if the package is required by the `main` group.
+    fn is_main(&self) -> bool {
+        if let Some(groups) = &self.groups {
+            groups.iter().any(|group| group == MAIN_GROUP)
+        } else if let Some(category) = &self.category {
+            category == MAIN_GROUP
+        } else {
+            true
+        }
+    }
+
+    /// Render the package as a `requirements.txt`-style requirement.
+    fn to_requirement(&self) -> Result<String> {
+        let name = &self.name;
+        let version = &self.version;
+
+        let mut requirement = match &self.source {
+            Some(source) => match source.r#type.as_str() {
+                "git" => {
+                    let reference = source
+                        .resolved_reference
+                        .as_ref()
+                        .or(source.reference.as_ref())
+                        .with_context(|| format!("Missing Git reference for `{name}`"))?;
+                    format!("{name} @ git+{}@{reference}", source.url)
+                }
+                "directory" | "file" | "url" => format!("{name} @ {}", source.url),
+                // Any other source (e.g., `legacy`) refers to a package index.
+                _ => format!("{name}=={version}"),
+            },
+            None => format!("{name}=={version}"),
+        };
+
+        if let Some(subdirectory) = self
+            .source
+            .as_ref()
+            .and_then(|source| source.subdirectory.as_ref())
+        {
+            requirement.push_str("#subdirectory=");
+            requirement.push_str(subdirectory);
+        }
+
+        let marker = match &self.markers {
+            Some(Markers::Universal(marker)) => Some(marker),
+            Some(Markers::Groups(markers)) => markers.get(MAIN_GROUP),
+            None => None,
+        };
+        if let Some(marker) = marker {
+            requirement.push_str(" ; ");
+            requirement.push_str(marker);
+        }
+
+        Ok(requirement)
+    }
+}
+
+/// The markers under which a package is required, either for all groups or per group.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum Markers {
+    Universal(String),
+    Groups(BTreeMap<String, String>),
+}
+
+#[derive(Debug, Deserialize)]
+struct File {
+    hash: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "snake_case")]
+struct Source {
+    r#type: String,
+    url: String,
+    reference: Option<String>,
+    resolved_reference: Option<String>,
+    subdirectory: Option<String>,
+}
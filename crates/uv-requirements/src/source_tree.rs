@@ -192,7 +192,7 @@ impl<'a, Context: BuildContext> SourceTreeResolver<'a, Context> {
             HashStrategy::None => HashPolicy::None,
             HashStrategy::Generate(mode) => HashPolicy::Generate(*mode),
             HashStrategy::Verify(_) => HashPolicy::Generate(HashGeneration::All),
-            HashStrategy::Require(_) => {
+            HashStrategy::Require(..) => {
                 return Err(anyhow::anyhow!(
                     "Hash-checking is not supported for local directories: {}",
                     path.user_display()
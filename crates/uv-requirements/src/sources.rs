@@ -1,5 +1,6 @@
 use std::ffi::OsStr;
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
 
 use anyhow::{Context, Result};
 use console::Term;
@@ -7,6 +8,8 @@ use console::Term;
 use uv_fs::{CWD, Simplified};
 use uv_requirements_txt::RequirementsTxtRequirement;
 
+use crate::lowerer::{SourceLowerer, SourceLowerers};
+
 #[derive(Debug, Clone)]
 pub enum RequirementsSource {
     /// A package was provided on the command line (e.g., `pip install flask`).
@@ -30,13 +33,27 @@ pub enum RequirementsSource {
     /// An extensionless file that could be either a PEP 723 script or a requirements.txt file.
     /// We detect the format when reading the file.
     Extensionless(PathBuf),
+    /// Dependencies were provided via a file in a custom format, to be lowered by a registered
+    /// [`SourceLowerer`].
+    Lowered(PathBuf, Arc<dyn SourceLowerer>),
 }
 
 impl RequirementsSource {
     /// Parse a [`RequirementsSource`] from a [`PathBuf`]. The file type is determined by the file
     /// extension and, in some cases, the file contents.
     pub fn from_requirements_file(path: PathBuf) -> Result<Self> {
-        if path.ends_with("pyproject.toml") {
+        Self::from_requirements_file_with_lowerers(path, &SourceLowerers::default())
+    }
+
+    /// Parse a [`RequirementsSource`] from a [`PathBuf`], consulting the given [`SourceLowerers`]
+    /// before the built-in file types.
+    pub fn from_requirements_file_with_lowerers(
+        path: PathBuf,
+        lowerers: &SourceLowerers,
+    ) -> Result<Self> {
+        if let Some(lowerer) = lowerers.find(&path) {
+            Ok(Self::Lowered(path, lowerer))
+        } else if path.ends_with("pyproject.toml") {
             Ok(Self::PyprojectToml(path))
         } else if path.ends_with("setup.py") {
             Ok(Self::SetupPy(path))
@@ -326,7 +343,8 @@ impl std::fmt::Display for RequirementsSource {
             | Self::SetupPy(path)
             | Self::SetupCfg(path)
             | Self::EnvironmentYml(path)
-            | Self::Extensionless(path) => {
+            | Self::Extensionless(path)
+            | Self::Lowered(path, _) => {
                 write!(f, "{}", path.simplified_display())
             }
         }
@@ -37,7 +37,9 @@ use url::Url;
 
 use uv_cache_key::CanonicalUrl;
 use uv_client::BaseClientBuilder;
-use uv_configuration::{DependencyGroups, NoBinary, NoBuild};
+use uv_configuration::{
+    BuildOptionsOrigin, BuildOptionsOrigins, DependencyGroups, NoBinary, NoBuild,
+};
 use uv_distribution_types::{Index, Requirement};
 use uv_distribution_types::{
     IndexUrl, NameRequirementSpecification, UnresolvedRequirement,
@@ -85,6 +87,8 @@ pub struct RequirementsSpecification {
     pub no_binary: NoBinary,
     /// The `--no-build` flags to enforce when selecting distributions.
     pub no_build: NoBuild,
+    /// The origins of the `--no-binary` and `--no-build` flags, used to detect contradictions.
+    pub build_options_origins: BuildOptionsOrigins,
 }
 
 impl RequirementsSpecification {
@@ -196,7 +200,13 @@ impl RequirementsSpecification {
     }
 
     /// Create a [`RequirementsSpecification`] from a parsed `requirements.txt` file.
-    fn from_requirements_txt(requirements_txt: RequirementsTxt) -> Self {
+    fn from_requirements_txt(requirements_txt: RequirementsTxt, path: &Path) -> Self {
+        let mut build_options_origins = BuildOptionsOrigins::default();
+        build_options_origins.push(
+            requirements_txt.no_binary.clone(),
+            requirements_txt.only_binary.clone(),
+            BuildOptionsOrigin::File(path.to_path_buf()),
+        );
         Self {
             requirements: requirements_txt
                 .requirements
@@ -229,6 +239,7 @@ impl RequirementsSpecification {
                 .collect(),
             no_binary: requirements_txt.no_binary,
             no_build: requirements_txt.only_binary,
+            build_options_origins,
             ..Self::default()
         }
     }
@@ -268,7 +279,7 @@ impl RequirementsSpecification {
                     );
                 }
 
-                Self::from_requirements_txt(requirements_txt)
+                Self::from_requirements_txt(requirements_txt, path)
             }
             RequirementsSource::PyprojectToml(path) => {
                 let content = match fs_err::tokio::read_to_string(&path).await {
@@ -344,6 +355,35 @@ impl RequirementsSpecification {
                     ..Self::default()
                 }
             }
+            RequirementsSource::Lowered(path, lowerer) => {
+                let content = if let Some(content) = cache.get(path.as_path()) {
+                    content.clone()
+                } else {
+                    let content = read_file(path, client_builder).await?;
+                    cache.insert(path.clone(), content.clone());
+                    content
+                };
+
+                let requirements = lowerer.lower(path, &content).with_context(|| {
+                    format!(
+                        "Failed to lower `{}` as `{}`",
+                        path.user_display(),
+                        lowerer.name()
+                    )
+                })?;
+
+                if requirements.is_empty() {
+                    warn_user!(
+                        "Requirements file `{}` does not contain any dependencies",
+                        path.user_display()
+                    );
+                }
+
+                Self {
+                    requirements,
+                    ..Self::default()
+                }
+            }
             RequirementsSource::EnvironmentYml(path) => {
                 return Err(anyhow::anyhow!(
                     "Conda environment files (i.e., `{}`) are not supported",
@@ -379,7 +419,7 @@ impl RequirementsSpecification {
                         }
                     }
 
-                    Self::from_requirements_txt(requirements_txt)
+                    Self::from_requirements_txt(requirements_txt, path)
                 }
             }
         })
@@ -579,6 +619,8 @@ impl RequirementsSpecification {
             spec.find_links.extend(source.find_links);
             spec.no_binary.extend(source.no_binary);
             spec.no_build.extend(source.no_build);
+            spec.build_options_origins
+                .extend(source.build_options_origins);
         }
 
         // Read all constraints, treating both requirements _and_ constraints as constraints.
@@ -617,6 +659,8 @@ impl RequirementsSpecification {
             spec.find_links.extend(source.find_links);
             spec.no_binary.extend(source.no_binary);
             spec.no_build.extend(source.no_build);
+            spec.build_options_origins
+                .extend(source.build_options_origins);
         }
 
         // Read all overrides, treating both requirements _and_ overrides as overrides.
@@ -641,6 +685,8 @@ impl RequirementsSpecification {
             spec.find_links.extend(source.find_links);
             spec.no_binary.extend(source.no_binary);
             spec.no_build.extend(source.no_build);
+            spec.build_options_origins
+                .extend(source.build_options_origins);
         }
 
         // Collect excludes.
@@ -21,11 +21,11 @@ use crate::version_map::{VersionMap, VersionMapDistHandle};
 use crate::{Exclusions, Manifest, Options, ResolverEnvironment};
 
 #[derive(Debug, Clone)]
-#[expect(clippy::struct_field_names)]
 pub(crate) struct CandidateSelector {
     resolution_strategy: ResolutionStrategy,
     prerelease_strategy: PrereleaseStrategy,
     index_strategy: IndexStrategy,
+    minimal_update: bool,
 }
 
 impl CandidateSelector {
@@ -44,11 +44,13 @@ impl CandidateSelector {
             ),
             prerelease_strategy: PrereleaseStrategy::from_mode(
                 options.prerelease_mode,
+                &options.prerelease_packages,
                 manifest,
                 env,
                 options.dependency_mode,
             ),
             index_strategy: options.index_strategy,
+            minimal_update: options.minimal_update,
         }
     }
 
@@ -114,6 +116,20 @@ impl CandidateSelector {
             return Some(preferred);
         }
 
+        // If the preference is no longer compatible, and we're minimizing updates, prefer the
+        // nearest version above the preference over the best candidate.
+        if self.minimal_update && !upgrade {
+            if let Some(nearest) =
+                self.select_nearest_preference(package_name, range, version_maps, preferences, env)
+            {
+                debug!(
+                    "Using nearest version to preference {} {}",
+                    nearest.name, nearest.version
+                );
+                return Some(nearest);
+            }
+        }
+
         // If we don't have a preference, find an already-installed distribution that satisfies the
         // range.
         let installed = if reinstall {
@@ -363,6 +379,44 @@ impl CandidateSelector {
         None
     }
 
+    /// Select the lowest version at or above the preferred version(s) for a package, such that an
+    /// incompatible preference (e.g., from a lockfile) is updated as little as possible.
+    ///
+    /// Returns `None` if the package has no preferences, or if no such version satisfies the range.
+    fn select_nearest_preference<'a>(
+        &'a self,
+        package_name: &'a PackageName,
+        range: &Range<Version>,
+        version_maps: &'a [VersionMap],
+        preferences: &'a Preferences,
+        env: &ResolverEnvironment,
+    ) -> Option<Candidate<'a>> {
+        let preferred = preferences
+            .get(package_name)
+            .iter()
+            .map(|entry| entry.pin().version())
+            .max()?;
+        let range = range.intersection(&Range::higher_than(preferred.clone()));
+        if range.is_empty() {
+            return None;
+        }
+
+        let allow_prerelease = match self.prerelease_strategy.allows(package_name, env) {
+            AllowPrerelease::Yes => true,
+            AllowPrerelease::No => false,
+            AllowPrerelease::IfNecessary => !version_maps.iter().any(VersionMap::stable),
+        };
+
+        version_maps.iter().find_map(|version_map| {
+            Self::select_candidate(
+                version_map.iter(&range),
+                package_name,
+                &range,
+                allow_prerelease,
+            )
+        })
+    }
+
     /// Check for an installed distribution that satisfies the current range and is allowed.
     fn get_installed<'a, InstalledPackages: InstalledPackagesProvider>(
         package_name: &'a PackageName,
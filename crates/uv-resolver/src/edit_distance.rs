@@ -0,0 +1,116 @@
+use uv_normalize::PackageName;
+
+/// The maximum number of similarly-named packages to suggest.
+const MAX_SUGGESTIONS: usize = 3;
+
+/// Return the packages from `candidates` whose names are similar to `name`, ordered from most to
+/// least similar.
+///
+/// A candidate is considered similar if its edit distance from `name` is at most a third of the
+/// length of `name`, as in `rustc`'s suggestions for misspelled identifiers.
+pub(crate) fn similar_names<'a>(
+    name: &PackageName,
+    candidates: impl IntoIterator<Item = &'a PackageName>,
+) -> Vec<PackageName> {
+    let name = name.as_ref();
+    let threshold = name.len().max(3) / 3;
+
+    let mut matches = candidates
+        .into_iter()
+        .filter(|candidate| candidate.as_ref() != name)
+        // Skip any candidates whose lengths alone put them over the threshold.
+        .filter(|candidate| candidate.as_ref().len().abs_diff(name.len()) <= threshold)
+        .filter_map(|candidate| {
+            let distance = edit_distance(name, candidate.as_ref());
+            (distance <= threshold).then_some((distance, candidate))
+        })
+        .collect::<Vec<_>>();
+    matches.sort_unstable();
+    matches.dedup();
+    matches
+        .into_iter()
+        .take(MAX_SUGGESTIONS)
+        .map(|(_, candidate)| candidate.clone())
+        .collect()
+}
+
+/// Compute the optimal string alignment distance between two strings: the number of insertions,
+/// deletions, substitutions, and transpositions of adjacent characters required to turn `a` into
+/// `b`.
+pub(crate) fn edit_distance(a: &str, b: &str) -> usize {
+    let a = a.chars().collect::<Vec<_>>();
+    let b = b.chars().collect::<Vec<_>>();
+
+    // The distances for the previous two rows, and the current row.
+    let mut prev_prev = vec![0; b.len() + 1];
+    let mut prev = (0..=b.len()).collect::<Vec<_>>();
+    let mut curr = vec![0; b.len() + 1];
+
+    for i in 1..=a.len() {
+        curr[0] = i;
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            curr[j] = (prev[j] + 1).min(curr[j - 1] + 1).min(prev[j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                curr[j] = curr[j].min(prev_prev[j - 2] + 1);
+            }
+        }
+        std::mem::swap(&mut prev_prev, &mut prev);
+        std::mem::swap(&mut prev, &mut curr);
+    }
+
+    prev[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    #[test]
+    fn distance() {
+        assert_eq!(edit_distance("", ""), 0);
+        assert_eq!(edit_distance("flask", ""), 5);
+        assert_eq!(edit_distance("", "flask"), 5);
+        assert_eq!(edit_distance("flask", "flask"), 0);
+        assert_eq!(edit_distance("flask", "flasks"), 1);
+        assert_eq!(edit_distance("flask", "flak"), 1);
+        assert_eq!(edit_distance("flask", "glask"), 1);
+        assert_eq!(edit_distance("reqeusts", "requests"), 1);
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+    }
+
+    #[test]
+    fn similar() {
+        let candidates = [
+            "beautifulsoup4",
+            "beautifulsoup",
+            "bs4",
+            "requests",
+            "requests-oauthlib",
+            "flask",
+        ]
+        .into_iter()
+        .map(|name| PackageName::from_str(name).unwrap())
+        .collect::<Vec<_>>();
+
+        let name = PackageName::from_str("beautifulsoup5").unwrap();
+        assert_eq!(
+            similar_names(&name, &candidates),
+            vec![
+                PackageName::from_str("beautifulsoup").unwrap(),
+                PackageName::from_str("beautifulsoup4").unwrap(),
+            ]
+        );
+
+        let name = PackageName::from_str("reqeusts").unwrap();
+        assert_eq!(
+            similar_names(&name, &candidates),
+            vec![PackageName::from_str("requests").unwrap()]
+        );
+
+        let name = PackageName::from_str("django").unwrap();
+        assert!(similar_names(&name, &candidates).is_empty());
+    }
+}
@@ -12,7 +12,8 @@ use rustc_hash::FxHashMap;
 use tracing::trace;
 
 use uv_distribution_types::{
-    DerivationChain, DistErrorKind, IndexCapabilities, IndexLocations, IndexUrl, RequestedDist,
+    DerivationChain, DistErrorKind, IndexCapabilities, IndexLocations, IndexMetadata, IndexUrl,
+    RequestedDist,
 };
 use uv_normalize::{ExtraName, InvalidNameError, PackageName};
 use uv_pep440::{LocalVersionSlice, LowerBound, Version};
@@ -27,7 +28,7 @@ use crate::dependency_provider::UvDependencyProvider;
 use crate::fork_indexes::ForkIndexes;
 use crate::fork_urls::ForkUrls;
 use crate::prerelease::AllowPrerelease;
-use crate::pubgrub::{PubGrubPackage, PubGrubPackageInner, PubGrubReportFormatter};
+use crate::pubgrub::{PubGrubHint, PubGrubPackage, PubGrubPackageInner, PubGrubReportFormatter};
 use crate::python_requirement::PythonRequirement;
 use crate::resolution::ConflictingDistributionError;
 use crate::resolver::{
@@ -112,6 +113,9 @@ pub enum ResolveError {
     #[error(transparent)]
     NoSolution(#[from] Box<NoSolutionError>),
 
+    #[error(transparent)]
+    BacktrackLimit(#[from] Box<BacktrackLimitError>),
+
     #[error("Attempted to construct an invalid version specifier")]
     InvalidVersion(#[from] uv_pep440::VersionSpecifierBuildError),
 
@@ -156,6 +160,54 @@ impl<T> From<tokio::sync::mpsc::error::SendError<T>> for ResolveError {
     }
 }
 
+/// The resolver gave up after encountering more conflicts than allowed by `max-backtracks`.
+#[derive(Debug)]
+pub struct BacktrackLimitError {
+    /// The configured limit.
+    limit: usize,
+    /// The packages involved in the most conflicts, with the number of conflicts for each, in
+    /// decreasing order.
+    packages: Vec<(PackageName, usize)>,
+}
+
+impl BacktrackLimitError {
+    pub(crate) fn new(limit: usize, packages: Vec<(PackageName, usize)>) -> Self {
+        Self { limit, packages }
+    }
+}
+
+impl std::error::Error for BacktrackLimitError {}
+
+impl std::fmt::Display for BacktrackLimitError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let s = if self.limit == 1 { "" } else { "s" };
+        write!(
+            f,
+            "Resolution was aborted after exceeding the limit of {} backtrack{s} (`max-backtracks`)",
+            self.limit.cyan()
+        )?;
+        if self.packages.is_empty() {
+            return Ok(());
+        }
+
+        write!(
+            f,
+            "\n\nThe following packages were involved in the most conflicts:"
+        )?;
+        for (name, count) in &self.packages {
+            let s = if *count == 1 { "" } else { "s" };
+            write!(f, "\n  - {} ({count} conflict{s})", name.cyan())?;
+        }
+        write!(
+            f,
+            "\n\n{}{} Consider adding constraints on these packages (e.g., a lower bound such as `{}`) to narrow the search, listing them in `resolution-priority`, or raising `max-backtracks`",
+            "hint".bold().cyan(),
+            ":".bold(),
+            format!("{}>=...", self.packages[0].0).cyan(),
+        )
+    }
+}
+
 pub type ErrorTree = DerivationTree<PubGrubPackage, Range<Version>, UnavailableReason>;
 
 /// A wrapper around [`pubgrub::error::NoSolutionError`] that displays a resolution failure report.
@@ -177,6 +229,9 @@ pub struct NoSolutionError {
     tags: Option<Tags>,
     workspace_members: BTreeSet<PackageName>,
     options: Options,
+    /// Similarly-named packages for any packages that weren't found, populated once the
+    /// resolution has failed.
+    suggestions: FxHashMap<PackageName, Vec<PackageName>>,
 }
 
 impl NoSolutionError {
@@ -218,6 +273,7 @@ impl NoSolutionError {
             tags,
             workspace_members,
             options,
+            suggestions: FxHashMap::default(),
         }
     }
 
@@ -380,6 +436,23 @@ impl NoSolutionError {
             .filter_map(|p| p.name())
             .unique()
     }
+
+    /// Get the packages involved in this error that weren't found, along with the explicit index
+    /// that was queried for each, if any.
+    pub(crate) fn not_found(&self) -> impl Iterator<Item = (&PackageName, Option<&IndexMetadata>)> {
+        self.packages()
+            .filter_map(|name| match self.unavailable_packages.get(name) {
+                Some(UnavailablePackage::NotFound(index)) => Some((name, index.as_ref())),
+                _ => None,
+            })
+    }
+
+    /// Set the similarly-named packages to suggest for a package that wasn't found.
+    pub(crate) fn set_suggestions(&mut self, name: PackageName, suggestions: Vec<PackageName>) {
+        if !suggestions.is_empty() {
+            self.suggestions.insert(name, suggestions);
+        }
+    }
 }
 
 impl std::fmt::Debug for NoSolutionError {
@@ -403,6 +476,7 @@ impl std::fmt::Debug for NoSolutionError {
             tags,
             workspace_members,
             options,
+            suggestions,
         } = self;
         f.debug_struct("NoSolutionError")
             .field("error", error)
@@ -421,23 +495,26 @@ impl std::fmt::Debug for NoSolutionError {
             .field("tags", tags)
             .field("workspace_members", workspace_members)
             .field("options", options)
+            .field("suggestions", suggestions)
             .finish()
     }
 }
 
 impl std::error::Error for NoSolutionError {}
 
-impl std::fmt::Display for NoSolutionError {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        // Write the derivation report.
-        let formatter = PubGrubReportFormatter {
+impl NoSolutionError {
+    /// Return the [`PubGrubReportFormatter`] used to render this error.
+    fn formatter(&self) -> PubGrubReportFormatter<'_> {
+        PubGrubReportFormatter {
             available_versions: &self.available_versions,
             python_requirement: &self.python_requirement,
             workspace_members: &self.workspace_members,
             tags: self.tags.as_ref(),
-        };
+        }
+    }
 
-        // Transform the error tree for reporting
+    /// Transform the derivation tree for reporting, removing any redundant or internal nodes.
+    fn reduced_tree(&self) -> ErrorTree {
         let mut tree = self.error.clone();
         simplify_derivation_tree_markers(&self.python_requirement, &mut tree);
         let should_display_tree = std::env::var_os(EnvVars::UV_INTERNAL__SHOW_DERIVATION_TREE)
@@ -476,19 +553,21 @@ impl std::fmt::Display for NoSolutionError {
             display_tree(&tree, "Resolver derivation tree after reduction");
         }
 
-        let report = DefaultStringReporter::report_with_formatter(&tree, &formatter);
-        write!(f, "{report}")?;
+        tree
+    }
 
-        // Include any additional hints.
-        let mut additional_hints = IndexSet::default();
-        formatter.generate_hints(
-            &tree,
+    /// Generate any additional hints for the given (reduced) derivation tree.
+    fn hints(&self, tree: &ErrorTree) -> IndexSet<PubGrubHint> {
+        let mut hints = IndexSet::default();
+        self.formatter().generate_hints(
+            tree,
             &self.index,
             &self.selector,
             &self.index_locations,
             &self.index_capabilities,
             &self.available_indexes,
             &self.unavailable_packages,
+            &self.suggestions,
             &self.incomplete_packages,
             &self.fork_urls,
             &self.fork_indexes,
@@ -497,9 +576,35 @@ impl std::fmt::Display for NoSolutionError {
             self.tags.as_ref(),
             &self.workspace_members,
             &self.options,
-            &mut additional_hints,
+            &mut hints,
         );
-        for hint in additional_hints {
+        hints
+    }
+
+    /// Return a machine-readable report of this error.
+    pub fn report(&self) -> NoSolutionReport {
+        let tree = self.reduced_tree();
+        NoSolutionReport {
+            message: DefaultStringReporter::report_with_formatter(&tree, &self.formatter()),
+            hints: self
+                .hints(&tree)
+                .into_iter()
+                .map(|hint| hint.to_string())
+                .collect(),
+            derivation_tree: DerivationNode::from(&tree),
+        }
+    }
+}
+
+impl std::fmt::Display for NoSolutionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        // Write the derivation report.
+        let tree = self.reduced_tree();
+        let report = DefaultStringReporter::report_with_formatter(&tree, &self.formatter());
+        write!(f, "{report}")?;
+
+        // Include any additional hints.
+        for hint in self.hints(&tree) {
             write!(f, "\n\n{hint}")?;
         }
 
@@ -507,6 +612,115 @@ impl std::fmt::Display for NoSolutionError {
     }
 }
 
+/// A machine-readable report of a [`NoSolutionError`].
+#[derive(Debug, serde::Serialize)]
+pub struct NoSolutionReport {
+    /// The human-readable explanation of the conflict.
+    pub message: String,
+    /// Any additional hints for resolving the conflict.
+    pub hints: Vec<String>,
+    /// The derivation tree explaining the conflict.
+    pub derivation_tree: DerivationNode,
+}
+
+/// A node in the derivation tree of a [`NoSolutionReport`].
+#[derive(Debug, serde::Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum DerivationNode {
+    /// An incompatibility derived from two other incompatibilities.
+    Derived {
+        /// The package ranges that can't be selected together.
+        terms: Vec<DerivationTerm>,
+        cause1: Box<Self>,
+        cause2: Box<Self>,
+    },
+    /// The root package can't be selected at the given versions.
+    NotRoot { package: String, range: String },
+    /// There are no versions of the package in the given range.
+    NoVersions { package: String, range: String },
+    /// Versions of the package in the given range depend on a range of another package.
+    Dependency {
+        package: String,
+        range: String,
+        dependency: String,
+        dependency_range: String,
+    },
+    /// Versions of the package in the given range are unavailable.
+    Unavailable {
+        package: String,
+        range: String,
+        reason: String,
+    },
+}
+
+/// A term in a [`DerivationNode::Derived`] incompatibility.
+#[derive(Debug, serde::Serialize)]
+pub struct DerivationTerm {
+    /// The package to which the term applies.
+    pub package: String,
+    /// The range of versions of the package.
+    pub range: String,
+    /// Whether the term is negated, i.e., applies to the versions _outside_ the range.
+    pub negated: bool,
+}
+
+impl From<&ErrorTree> for DerivationNode {
+    fn from(tree: &ErrorTree) -> Self {
+        match tree {
+            DerivationTree::Derived(derived) => {
+                let terms = derived
+                    .terms
+                    .iter()
+                    .map(|(package, term)| match term {
+                        Term::Positive(range) => DerivationTerm {
+                            package: package.to_string(),
+                            range: range.to_string(),
+                            negated: false,
+                        },
+                        Term::Negative(range) => DerivationTerm {
+                            package: package.to_string(),
+                            range: range.to_string(),
+                            negated: true,
+                        },
+                    })
+                    .sorted_by(|a, b| a.package.cmp(&b.package))
+                    .collect();
+                Self::Derived {
+                    terms,
+                    cause1: Box::new(Self::from(derived.cause1.as_ref())),
+                    cause2: Box::new(Self::from(derived.cause2.as_ref())),
+                }
+            }
+            DerivationTree::External(External::NotRoot(package, version)) => Self::NotRoot {
+                package: package.to_string(),
+                range: version.to_string(),
+            },
+            DerivationTree::External(External::NoVersions(package, range)) => Self::NoVersions {
+                package: package.to_string(),
+                range: range.to_string(),
+            },
+            DerivationTree::External(External::FromDependencyOf(
+                package,
+                range,
+                dependency,
+                dependency_range,
+            )) => Self::Dependency {
+                package: package.to_string(),
+                range: range.to_string(),
+                dependency: dependency.to_string(),
+                dependency_range: dependency_range.to_string(),
+            },
+            DerivationTree::External(External::Custom(package, range, reason)) => {
+                Self::Unavailable {
+                    package: package.to_string(),
+                    range: range.to_string(),
+                    reason: reason.to_string(),
+                }
+            }
+        }
+    }
+}
+
 #[expect(clippy::print_stderr)]
 fn display_tree(
     error: &DerivationTree<PubGrubPackage, Range<Version>, UnavailableReason>,
@@ -1,3 +1,5 @@
+use dashmap::DashSet;
+
 use uv_configuration::{Reinstall, Upgrade};
 use uv_normalize::PackageName;
 
@@ -6,11 +8,18 @@ use uv_normalize::PackageName;
 pub struct Exclusions {
     reinstall: Reinstall,
     upgrade: Upgrade,
+    /// The dependencies of upgraded packages that should be upgraded, too, as discovered during
+    /// resolution with an eager upgrade strategy.
+    dependencies: DashSet<PackageName>,
 }
 
 impl Exclusions {
     pub fn new(reinstall: Reinstall, upgrade: Upgrade) -> Self {
-        Self { reinstall, upgrade }
+        Self {
+            reinstall,
+            upgrade,
+            dependencies: DashSet::default(),
+        }
     }
 
     pub fn reinstall(&self, package: &PackageName) -> bool {
@@ -18,10 +27,25 @@ impl Exclusions {
     }
 
     pub fn upgrade(&self, package: &PackageName) -> bool {
-        self.upgrade.contains(package)
+        self.upgrade.contains(package) || self.dependencies.contains(package)
     }
 
     pub fn contains(&self, package: &PackageName) -> bool {
         self.reinstall(package) || self.upgrade(package)
     }
+
+    /// Mark a dependency of the given package for upgrade, if the package is being upgraded and
+    /// the upgrade strategy is eager.
+    ///
+    /// Returns `true` if the dependency wasn't already marked for upgrade.
+    pub(crate) fn upgrade_dependency(
+        &self,
+        package: &PackageName,
+        dependency: &PackageName,
+    ) -> bool {
+        if !self.upgrade.is_eager() || !self.upgrade(package) || self.upgrade(dependency) {
+            return false;
+        }
+        self.dependencies.insert(dependency.clone())
+    }
 }
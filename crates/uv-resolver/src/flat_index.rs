@@ -8,9 +8,8 @@ use uv_client::{FlatIndexEntries, FlatIndexEntry};
 use uv_configuration::BuildOptions;
 use uv_distribution_filename::{DistFilename, SourceDistFilename, WheelFilename};
 use uv_distribution_types::{
-    File, HashComparison, HashPolicy, IncompatibleSource, IncompatibleWheel, IndexUrl,
-    PrioritizedDist, RegistryBuiltWheel, RegistrySourceDist, SourceDistCompatibility,
-    WheelCompatibility,
+    File, HashComparison, IncompatibleSource, IncompatibleWheel, IndexUrl, PrioritizedDist,
+    RegistryBuiltWheel, RegistrySourceDist, SourceDistCompatibility, WheelCompatibility,
 };
 use uv_normalize::PackageName;
 use uv_pep440::Version;
@@ -184,19 +183,12 @@ impl FlatDistributions {
         }
 
         // Check if hashes line up
-        let hash = if let HashPolicy::Validate(required) =
-            hasher.get_package(&filename.name, &filename.version)
-        {
-            if hashes.is_empty() {
-                HashComparison::Missing
-            } else if required.iter().any(|hash| hashes.contains(hash)) {
-                HashComparison::Matched
-            } else {
-                HashComparison::Mismatched
-            }
-        } else {
-            HashComparison::Matched
-        };
+        let hash = HashComparison::compare(
+            hasher
+                .get_package(&filename.name, &filename.version)
+                .digests(),
+            hashes,
+        );
 
         SourceDistCompatibility::Compatible(hash)
     }
@@ -225,19 +217,12 @@ impl FlatDistributions {
         };
 
         // Check if hashes line up.
-        let hash = if let HashPolicy::Validate(required) =
-            hasher.get_package(&filename.name, &filename.version)
-        {
-            if hashes.is_empty() {
-                HashComparison::Missing
-            } else if required.iter().any(|hash| hashes.contains(hash)) {
-                HashComparison::Matched
-            } else {
-                HashComparison::Mismatched
-            }
-        } else {
-            HashComparison::Matched
-        };
+        let hash = HashComparison::compare(
+            hasher
+                .get_package(&filename.name, &filename.version)
+                .digests(),
+            hashes,
+        );
 
         // Break ties with the build tag.
         let build_tag = filename.build_tag().cloned();
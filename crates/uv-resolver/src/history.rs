@@ -0,0 +1,145 @@
+use std::collections::BTreeMap;
+use std::io;
+
+use rustc_hash::FxHashMap;
+use tracing::debug;
+
+use uv_cache::{Cache, CacheBucket, CacheEntry, WheelCache, integrity};
+use uv_distribution_types::{IndexLocations, IndexUrl};
+use uv_normalize::PackageName;
+use uv_pep440::Version;
+
+use crate::ResolverOutput;
+use crate::resolution::ResolutionGraphNode;
+
+/// The number of previously selected versions to retain for each package.
+const HISTORY_LEN: usize = 3;
+
+/// The versions selected for each package on a single index, most recent first.
+type IndexHistory = BTreeMap<PackageName, Vec<Version>>;
+
+/// The versions selected for each package in prior resolutions.
+///
+/// The history is used to prefetch the metadata for the most likely candidates as soon as a
+/// package is discovered, which reduces the time to a solution when resolving a similar set of
+/// requirements repeatedly (e.g., in CI). It never affects which versions are selected.
+#[derive(Debug, Default)]
+pub(crate) struct SelectionHistory {
+    /// The cache in which the history is persisted, or `None` if the history is disabled.
+    cache: Option<Cache>,
+    /// The previously selected versions for each package, across all allowed indexes.
+    versions: FxHashMap<PackageName, Vec<Version>>,
+}
+
+impl SelectionHistory {
+    /// Read the history for all allowed indexes from the cache.
+    pub(crate) fn read(cache: &Cache, locations: &IndexLocations) -> Self {
+        let mut versions = FxHashMap::<PackageName, Vec<Version>>::default();
+        for index in locations.allowed_indexes() {
+            for (name, selected) in read_entry(&history_entry(cache, index.url())) {
+                let versions = versions.entry(name).or_default();
+                for version in selected {
+                    if !versions.contains(&version) {
+                        versions.push(version);
+                    }
+                }
+            }
+        }
+        Self {
+            cache: Some(cache.clone()),
+            versions,
+        }
+    }
+
+    /// Return the versions previously selected for the given package.
+    pub(crate) fn get(&self, name: &PackageName) -> &[Version] {
+        self.versions
+            .get(name)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// Record the versions selected in the given resolution, for each index.
+    pub(crate) fn write(&self, resolution: &ResolverOutput) -> io::Result<()> {
+        let Some(cache) = &self.cache else {
+            return Ok(());
+        };
+
+        // Group the selected versions by index.
+        let mut selected = FxHashMap::<&IndexUrl, IndexHistory>::default();
+        for node in resolution.graph.node_weights() {
+            let ResolutionGraphNode::Dist(dist) = node else {
+                continue;
+            };
+            if !dist.is_base() {
+                continue;
+            }
+            let Some(index) = dist.index() else {
+                continue;
+            };
+            selected
+                .entry(index)
+                .or_default()
+                .entry(dist.name.clone())
+                .or_default()
+                .push(dist.version.clone());
+        }
+
+        // Merge the selected versions into the existing history for each index, retaining the
+        // most recent selections.
+        for (index, selected) in selected {
+            let entry = history_entry(cache, index);
+            let mut history = read_entry(&entry);
+            for (name, mut versions) in selected {
+                versions.sort_unstable_by(|a, b| b.cmp(a));
+                for version in history.remove(&name).into_iter().flatten() {
+                    if !versions.contains(&version) {
+                        versions.push(version);
+                    }
+                }
+                versions.truncate(HISTORY_LEN);
+                history.insert(name, versions);
+            }
+
+            let data = rmp_serde::to_vec(&history).map_err(io::Error::other)?;
+            fs_err::create_dir_all(entry.dir())?;
+            uv_fs::write_atomic_sync(entry.path(), integrity::seal(&data))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Return the cache entry for the history of the given index.
+fn history_entry(cache: &Cache, index: &IndexUrl) -> CacheEntry {
+    cache.entry(
+        CacheBucket::SelectionHistory,
+        WheelCache::Index(index).root(),
+        "history.msgpack",
+    )
+}
+
+/// Read the history from the given cache entry, ignoring any missing or invalid entries.
+fn read_entry(entry: &CacheEntry) -> IndexHistory {
+    let data = match fs_err::read(entry.path()) {
+        Ok(data) => data,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return IndexHistory::default(),
+        Err(err) => {
+            debug!("Failed to read resolution history: {err}");
+            return IndexHistory::default();
+        }
+    };
+    match integrity::unseal(&data)
+        .map_err(|err| err.to_string())
+        .and_then(|data| rmp_serde::from_slice(data).map_err(|err| err.to_string()))
+    {
+        Ok(history) => history,
+        Err(err) => {
+            debug!(
+                "Ignoring invalid resolution history at {}: {err}",
+                entry.path().display()
+            );
+            IndexHistory::default()
+        }
+    }
+}
@@ -1,5 +1,8 @@
 pub use dependency_mode::DependencyMode;
-pub use error::{ErrorTree, NoSolutionError, NoSolutionHeader, ResolveError, SentinelRange};
+pub use error::{
+    BacktrackLimitError, DerivationNode, DerivationTerm, ErrorTree, NoSolutionError,
+    NoSolutionHeader, NoSolutionReport, ResolveError, SentinelRange,
+};
 pub use exclude_newer::{
     ExcludeNewer, ExcludeNewerChange, ExcludeNewerPackage, ExcludeNewerPackageChange,
     ExcludeNewerPackageEntry, ExcludeNewerValue, ExcludeNewerValueChange, PackageExcludeNewer,
@@ -9,9 +12,9 @@ pub use exclusions::Exclusions;
 pub use flat_index::{FlatDistributions, FlatIndex};
 pub use fork_strategy::ForkStrategy;
 pub use lock::{
-    Installable, Lock, LockError, LockVersion, Package, PackageMap, PylockToml,
-    PylockTomlErrorKind, RequirementsTxtExport, ResolverManifest, SatisfiesResult, TreeDisplay,
-    VERSION, cyclonedx_json,
+    DisplayLockExplanation, DotDisplay, Installable, Lock, LockError, LockVersion, Package,
+    PackageMap, PylockToml, PylockTomlErrorKind, RequirementsTxtExport, ResolverManifest,
+    SatisfiesResult, TreeDisplay, VERSION, cyclonedx_json, spdx_json,
 };
 pub use manifest::Manifest;
 pub use options::{Flexibility, Options, OptionsBuilder};
@@ -19,17 +22,18 @@ pub use preferences::{Preference, PreferenceError, Preferences};
 pub use prerelease::PrereleaseMode;
 pub use python_requirement::PythonRequirement;
 pub use resolution::{
-    AnnotationStyle, ConflictingDistributionError, DisplayResolutionGraph, ResolverOutput,
+    AnnotationStyle, ConflictingDistributionError, DisplayExplanation, DisplayResolutionDot,
+    DisplayResolutionGraph, ResolverOutput,
 };
 pub use resolution_mode::ResolutionMode;
 pub use resolver::{
-    BuildId, DefaultResolverProvider, DerivationChainBuilder, InMemoryIndex, MetadataResponse,
-    PackageVersionsResult, Reporter as ResolverReporter, Resolver, ResolverEnvironment,
-    ResolverProvider, VersionsResponse, WheelMetadataResult,
+    BuildId, DefaultResolverProvider, DerivationChainBuilder, InMemoryIndex, MemoryMetrics,
+    MetadataResponse, PackageVersionsResult, Reporter as ResolverReporter, Resolver,
+    ResolverEnvironment, ResolverProvider, VersionsResponse, WheelMetadataResult,
 };
 pub use universal_marker::{ConflictMarker, UniversalMarker};
 pub use version_map::VersionMap;
-pub use yanks::AllowedYanks;
+pub use yanks::{AllowYankedEntry, AllowedYanks};
 
 /// A custom `HashSet` using `hashbrown`.
 ///
@@ -43,6 +47,7 @@ type FxHashbrownMap<K, V> = hashbrown::HashMap<K, V, rustc_hash::FxBuildHasher>;
 mod candidate_selector;
 mod dependency_mode;
 mod dependency_provider;
+mod edit_distance;
 mod error;
 mod exclude_newer;
 mod exclusions;
@@ -51,6 +56,7 @@ mod fork_indexes;
 mod fork_strategy;
 mod fork_urls;
 mod graph_ops;
+mod history;
 mod lock;
 mod manifest;
 mod marker;
@@ -0,0 +1,169 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::{Display, Formatter};
+
+use itertools::Itertools;
+
+use uv_normalize::{ExtraName, GroupName};
+
+use crate::Lock;
+use crate::lock::{Dependency, Package, PackageId};
+use crate::resolution::quote;
+
+/// A [`std::fmt::Display`] implementation that renders a lockfile as a Graphviz DOT graph.
+///
+/// Each node represents a locked package. Each edge is labeled with the requirements that
+/// introduced it: for workspace members and other mutable sources, the declared requirements,
+/// including their specifiers and markers; for all other packages, the locked extras and markers.
+#[derive(Debug)]
+pub struct DotDisplay<'env> {
+    /// The underlying lockfile.
+    lock: &'env Lock,
+}
+
+impl<'env> From<&'env Lock> for DotDisplay<'env> {
+    fn from(lock: &'env Lock) -> Self {
+        Self { lock }
+    }
+}
+
+impl Display for DotDisplay<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut edges: BTreeMap<(String, String), BTreeSet<String>> = BTreeMap::new();
+        for package in &self.lock.packages {
+            let source = node_id(&package.id);
+            let dependencies =
+                package
+                    .dependencies
+                    .iter()
+                    .map(|dependency| (dependency, None))
+                    .chain(package.optional_dependencies.iter().flat_map(
+                        |(extra, dependencies)| {
+                            dependencies
+                                .iter()
+                                .map(move |dependency| (dependency, Some(Origin::Extra(extra))))
+                        },
+                    ))
+                    .chain(
+                        package
+                            .dependency_groups
+                            .iter()
+                            .flat_map(|(group, dependencies)| {
+                                dependencies
+                                    .iter()
+                                    .map(move |dependency| (dependency, Some(Origin::Group(group))))
+                            }),
+                    );
+            for (dependency, origin) in dependencies {
+                edges
+                    .entry((source.clone(), node_id(&dependency.package_id)))
+                    .or_default()
+                    .extend(labels(package, dependency, origin));
+            }
+        }
+
+        // Identify any workspace members, including the root package, which is omitted from the
+        // list of members for single-member workspaces.
+        let is_member = |package: &Package| {
+            if self.lock.members().is_empty() {
+                self.lock
+                    .root()
+                    .is_some_and(|root| root.id.name == package.id.name)
+            } else {
+                self.lock.members().contains(&package.id.name)
+            }
+        };
+
+        writeln!(f, "digraph {{")?;
+        for package in &self.lock.packages {
+            if is_member(package) {
+                writeln!(f, "    {} [shape=box];", quote(&node_id(&package.id)))?;
+            } else {
+                writeln!(f, "    {};", quote(&node_id(&package.id)))?;
+            }
+        }
+        for ((source, target), labels) in &edges {
+            writeln!(
+                f,
+                "    {} -> {} [label={}];",
+                quote(source),
+                quote(target),
+                quote(&labels.iter().join("\n"))
+            )?;
+        }
+        writeln!(f, "}}")?;
+
+        Ok(())
+    }
+}
+
+/// The extra or dependency group through which a package depends on another.
+#[derive(Debug, Clone, Copy)]
+enum Origin<'env> {
+    Extra(&'env ExtraName),
+    Group(&'env GroupName),
+}
+
+impl Display for Origin<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Extra(extra) => write!(f, "extra: {extra}"),
+            Self::Group(group) => write!(f, "group: {group}"),
+        }
+    }
+}
+
+/// Return the labels for the edge from `package` to `dependency`.
+fn labels(package: &Package, dependency: &Dependency, origin: Option<Origin>) -> BTreeSet<String> {
+    let name = &dependency.package_id.name;
+
+    // Prefer the declared requirements, if they were recorded in the lockfile.
+    let requirements = match origin {
+        None | Some(Origin::Extra(_)) => package
+            .metadata
+            .requires_dist
+            .iter()
+            .filter(|requirement| {
+                requirement.name == *name
+                    && requirement.marker.top_level_extra_name().as_deref()
+                        == origin.and_then(|origin| match origin {
+                            Origin::Extra(extra) => Some(extra),
+                            Origin::Group(_) => None,
+                        })
+            })
+            .map(ToString::to_string)
+            .collect::<BTreeSet<_>>(),
+        Some(Origin::Group(group)) => package
+            .metadata
+            .dependency_groups
+            .get(group)
+            .into_iter()
+            .flatten()
+            .filter(|requirement| requirement.name == *name)
+            .map(|requirement| format!("{requirement} ({})", Origin::Group(group)))
+            .collect::<BTreeSet<_>>(),
+    };
+    if !requirements.is_empty() {
+        return requirements;
+    }
+
+    let mut label = name.to_string();
+    if !dependency.extra.is_empty() {
+        label = format!("{label}[{}]", dependency.extra.iter().join(","));
+    }
+    if let Some(marker) = dependency.simplified_marker.try_to_string() {
+        label = format!("{label} ; {marker}");
+    }
+    if let Some(origin) = origin {
+        label = format!("{label} ({origin})");
+    }
+    BTreeSet::from([label])
+}
+
+/// Return the identifier of the node for a locked package, e.g., `flask==3.0.0`.
+fn node_id(id: &PackageId) -> String {
+    if let Some(version) = &id.version {
+        format!("{}=={version}", id.name)
+    } else {
+        id.name.to_string()
+    }
+}
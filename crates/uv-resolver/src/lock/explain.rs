@@ -0,0 +1,306 @@
+use std::collections::{BTreeSet, VecDeque};
+use std::fmt::{Display, Formatter};
+
+use itertools::Itertools;
+use owo_colors::OwoColorize;
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use uv_normalize::{ExtraName, GroupName, PackageName};
+
+use crate::Lock;
+use crate::lock::{Dependency, Package};
+
+/// A [`std::fmt::Display`] implementation that explains why a package was included in a lockfile.
+///
+/// For each locked version of the package, the explanation lists the dependencies that led to its
+/// inclusion, along with the shortest chain of dependencies from each dependent back to a
+/// workspace member or one of the lockfile's requirements, followed by any constraints and
+/// overrides that applied to it.
+///
+/// Declared requirements are only available for workspace members and other mutable sources; for
+/// all other packages, the locked dependency is shown instead.
+#[derive(Debug)]
+pub struct DisplayLockExplanation<'env> {
+    /// The underlying lockfile.
+    lock: &'env Lock,
+    /// The package to explain.
+    package: &'env PackageName,
+    /// The dependents of each package, by index, along with the extra or group that introduced
+    /// each edge.
+    dependents: FxHashMap<usize, Vec<(usize, Option<Origin<'env>>)>>,
+}
+
+impl<'env> DisplayLockExplanation<'env> {
+    /// Create a new [`DisplayLockExplanation`] for the given package, or `None` if the package is
+    /// not included in the lockfile.
+    pub fn new(lock: &'env Lock, package: &'env PackageName) -> Option<Self> {
+        if !lock
+            .packages
+            .iter()
+            .any(|locked| locked.id.name == *package)
+        {
+            return None;
+        }
+
+        let mut dependents: FxHashMap<usize, Vec<_>> = FxHashMap::default();
+        for (index, locked) in lock.packages.iter().enumerate() {
+            for (dependency, origin) in dependencies(locked) {
+                if let Some(child) = lock.by_id.get(&dependency.package_id) {
+                    dependents.entry(*child).or_default().push((index, origin));
+                }
+            }
+        }
+
+        Some(Self {
+            lock,
+            package,
+            dependents,
+        })
+    }
+
+    /// Returns `true` if the package is a workspace member or one of the lockfile's requirements,
+    /// such that it was included directly.
+    fn is_root(&self, package: &Package) -> bool {
+        let name = &package.id.name;
+        if self.lock.members().is_empty() {
+            if self.lock.root().is_some_and(|root| root.id.name == *name) {
+                return true;
+            }
+        } else if self.lock.members().contains(name) {
+            return true;
+        }
+        self.lock
+            .requirements()
+            .iter()
+            .chain(self.lock.dependency_groups().values().flatten())
+            .any(|requirement| requirement.name == *name)
+    }
+
+    /// Find the shortest path from the given package to a root, excluding the package itself.
+    fn path_to_root(&self, start: usize) -> Vec<(usize, Option<Origin<'env>>)> {
+        let mut queue = VecDeque::from([(start, Vec::new())]);
+        let mut seen = FxHashSet::default();
+        while let Some((index, path)) = queue.pop_front() {
+            if !seen.insert(index) {
+                continue;
+            }
+            if self.is_root(&self.lock.packages[index]) {
+                return path;
+            }
+            for (parent, origin) in self.dependents.get(&index).into_iter().flatten() {
+                let mut path = path.clone();
+                path.push((*parent, *origin));
+                queue.push_back((*parent, path));
+            }
+        }
+        Vec::new()
+    }
+
+    /// Write the requirements declared by `parent` on `child`, indented to the given depth.
+    fn write_requirements(
+        &self,
+        f: &mut Formatter<'_>,
+        child: usize,
+        parent: usize,
+        origin: Option<Origin<'_>>,
+        depth: usize,
+    ) -> std::fmt::Result {
+        let indent = "  ".repeat(depth);
+        let parent = &self.lock.packages[parent];
+        let Some(dependency) = dependencies(parent)
+            .filter(|(_, edge)| *edge == origin)
+            .map(|(dependency, _)| dependency)
+            .find(|dependency| self.lock.by_id.get(&dependency.package_id) == Some(&child))
+        else {
+            return Ok(());
+        };
+        for requirement in requirements(parent, dependency, origin) {
+            writeln!(
+                f,
+                "{indent}{} required by {}",
+                requirement.cyan(),
+                display_package(parent, origin).bold()
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Write the requirements in the lockfile that name the package directly, indented to the
+    /// given depth.
+    fn write_requested(
+        &self,
+        f: &mut Formatter<'_>,
+        name: &PackageName,
+        depth: usize,
+    ) -> std::fmt::Result {
+        let indent = "  ".repeat(depth);
+        for requirement in self
+            .lock
+            .requirements()
+            .iter()
+            .chain(self.lock.dependency_groups().values().flatten())
+            .filter(|requirement| requirement.name == *name)
+        {
+            writeln!(f, "{indent}{} requested", requirement.cyan())?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for DisplayLockExplanation<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let targets = self
+            .lock
+            .packages
+            .iter()
+            .enumerate()
+            .filter(|(_, package)| package.id.name == *self.package);
+
+        for (position, (target, package)) in targets.enumerate() {
+            if position > 0 {
+                writeln!(f)?;
+            }
+            writeln!(f, "{}", display_package(package, None).bold())?;
+            self.write_requested(f, self.package, 1)?;
+
+            // Ignore the edges between a package and its own extras.
+            let parents = self
+                .dependents
+                .get(&target)
+                .into_iter()
+                .flatten()
+                .filter(|(parent, _)| self.lock.packages[*parent].id.name != *self.package)
+                .copied()
+                .collect::<BTreeSet<_>>();
+
+            for (parent, origin) in parents {
+                self.write_requirements(f, target, parent, origin, 1)?;
+
+                // Walk back to a root along the shortest path.
+                let mut child = parent;
+                let mut depth = 2;
+                for (ancestor, origin) in self.path_to_root(parent) {
+                    if self.lock.packages[ancestor].id.name == self.lock.packages[child].id.name {
+                        child = ancestor;
+                        continue;
+                    }
+                    self.write_requirements(f, child, ancestor, origin, depth)?;
+                    child = ancestor;
+                    depth += 1;
+                }
+                self.write_requested(f, &self.lock.packages[child].id.name, depth)?;
+            }
+
+            for requirement in self
+                .lock
+                .manifest
+                .constraints
+                .iter()
+                .filter(|requirement| requirement.name == *self.package)
+            {
+                writeln!(f, "  {} constrained", requirement.cyan())?;
+            }
+            for requirement in self
+                .lock
+                .manifest
+                .overrides
+                .iter()
+                .filter(|requirement| requirement.name == *self.package)
+            {
+                writeln!(f, "  {} overridden", requirement.cyan())?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// The extra or dependency group through which a package depends on another.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Origin<'env> {
+    Extra(&'env ExtraName),
+    Group(&'env GroupName),
+}
+
+/// Return the dependencies of a package, along with the extra or group that introduced each.
+fn dependencies(package: &Package) -> impl Iterator<Item = (&Dependency, Option<Origin<'_>>)> {
+    package
+        .dependencies
+        .iter()
+        .map(|dependency| (dependency, None))
+        .chain(
+            package
+                .optional_dependencies
+                .iter()
+                .flat_map(|(extra, dependencies)| {
+                    dependencies
+                        .iter()
+                        .map(move |dependency| (dependency, Some(Origin::Extra(extra))))
+                }),
+        )
+        .chain(
+            package
+                .dependency_groups
+                .iter()
+                .flat_map(|(group, dependencies)| {
+                    dependencies
+                        .iter()
+                        .map(move |dependency| (dependency, Some(Origin::Group(group))))
+                }),
+        )
+}
+
+/// Return the requirements through which `package` depends on `dependency`.
+///
+/// Prefers the declared requirements, if they were recorded in the lockfile.
+fn requirements(package: &Package, dependency: &Dependency, origin: Option<Origin>) -> Vec<String> {
+    let name = &dependency.package_id.name;
+    let requirements = match origin {
+        None | Some(Origin::Extra(_)) => package
+            .metadata
+            .requires_dist
+            .iter()
+            .filter(|requirement| {
+                requirement.name == *name
+                    && requirement.marker.top_level_extra_name().as_deref()
+                        == origin.and_then(|origin| match origin {
+                            Origin::Extra(extra) => Some(extra),
+                            Origin::Group(_) => None,
+                        })
+            })
+            .map(ToString::to_string)
+            .collect::<Vec<_>>(),
+        Some(Origin::Group(group)) => package
+            .metadata
+            .dependency_groups
+            .get(group)
+            .into_iter()
+            .flatten()
+            .filter(|requirement| requirement.name == *name)
+            .map(ToString::to_string)
+            .collect::<Vec<_>>(),
+    };
+    if !requirements.is_empty() {
+        return requirements;
+    }
+
+    if dependency.extra.is_empty() {
+        vec![name.to_string()]
+    } else {
+        vec![format!("{name}[{}]", dependency.extra.iter().join(","))]
+    }
+}
+
+/// Format a locked package, e.g., `flask[dotenv]==3.0.0`.
+fn display_package(package: &Package, origin: Option<Origin>) -> String {
+    let mut display = package.id.name.to_string();
+    match origin {
+        Some(Origin::Extra(extra)) => display = format!("{display}[{extra}]"),
+        Some(Origin::Group(group)) => display = format!("{display}:{group}"),
+        None => {}
+    }
+    if let Some(version) = &package.id.version {
+        display = format!("{display}=={version}");
+    }
+    display
+}
@@ -1,28 +1,39 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 use std::path::Path;
 
+use cyclonedx_bom::external_models::uri::Uri;
 use cyclonedx_bom::models::component::Classification;
 use cyclonedx_bom::models::dependency::{Dependencies, Dependency};
+use cyclonedx_bom::models::external_reference::{
+    ExternalReference, ExternalReferenceType, ExternalReferences,
+};
+use cyclonedx_bom::models::hash::{
+    Hash, HashAlgorithm as CycloneDxHashAlgorithm, HashValue, Hashes,
+};
 use cyclonedx_bom::models::metadata::Metadata;
 use cyclonedx_bom::models::property::{Properties, Property};
 use cyclonedx_bom::models::tool::{Tool, Tools};
 use cyclonedx_bom::prelude::{Bom, Component, Components, NormalizedString};
 use itertools::Itertools;
 use percent_encoding::{AsciiSet, CONTROLS, percent_encode};
-use rustc_hash::FxHashSet;
+use petgraph::visit::EdgeRef;
+use rustc_hash::{FxHashMap, FxHashSet};
 
 use uv_configuration::{
     DependencyGroupsWithDefaults, ExtrasSpecificationWithDefaults, InstallOptions,
 };
+use uv_distribution_types::{BuiltDist, Dist, IndexUrl, ResolvedDist, SourceDist};
 use uv_fs::PortablePath;
 use uv_normalize::PackageName;
 use uv_pep508::MarkerTree;
 use uv_preview::{Preview, PreviewFeature};
+use uv_pypi_types::{HashAlgorithm, HashDigests};
 use uv_warnings::warn_user;
 
-use crate::lock::export::{ExportableRequirement, ExportableRequirements};
+use crate::lock::export::{ExportableRequirement, ExportableRequirements, registry_files};
 use crate::lock::{LockErrorKind, Package, PackageId, RegistrySource, Source};
-use crate::{Installable, LockError};
+use crate::resolution::{AnnotatedDist, ResolutionGraphNode};
+use crate::{Installable, LockError, ResolverOutput};
 
 /// Character set for percent-encoding PURL components, copied from packageurl.rs (<https://github.com/scm-rs/packageurl.rs/blob/a725aa0ab332934c350641508017eb09ddfa0813/src/purl.rs#L18>).
 const PURL_ENCODE_SET: &AsciiSet = &CONTROLS
@@ -49,7 +60,7 @@ const PURL_ENCODE_SET: &AsciiSet = &CONTROLS
 /// Creates `CycloneDX` components, registering them in a `HashMap` so that they can be retrieved by `PackageId`.
 /// Also ensures uniqueness when generating bom-refs by using a numeric prefix which is incremented for each component.
 #[derive(Default)]
-struct ComponentBuilder<'a> {
+pub(super) struct ComponentBuilder<'a> {
     id_counter: usize, // Used as prefix in bom-ref generation, to ensure uniqueness
     package_to_component_map: HashMap<&'a PackageId, Component>,
 }
@@ -81,7 +92,7 @@ impl<'a> ComponentBuilder<'a> {
     }
 
     /// Generate a Package URL (purl) from a package. Returns `None` for local sources.
-    fn create_purl(package: &Package) -> Option<String> {
+    pub(super) fn create_purl(package: &Package) -> Option<String> {
         let name = percent_encode(Self::get_package_name(package).as_bytes(), PURL_ENCODE_SET);
 
         let version = Self::get_version_string(package)
@@ -125,6 +136,74 @@ impl<'a> ComponentBuilder<'a> {
         Some(format!("pkg:{purl_type}/{name}{version}{qualifiers}"))
     }
 
+    /// Generate a Package URL (purl) from a resolved distribution. Returns `None` for local
+    /// sources.
+    pub(super) fn create_purl_from_dist(dist: &AnnotatedDist) -> Option<String> {
+        let ResolvedDist::Installable { dist: inner, .. } = &dist.dist else {
+            return None;
+        };
+
+        let name = percent_encode(dist.name.as_str().as_bytes(), PURL_ENCODE_SET);
+        let version = dist.version.to_string();
+        let version = percent_encode(version.as_bytes(), PURL_ENCODE_SET);
+
+        // As in `create_purl`, all Python packages use the "pypi" purl type, with a qualifier to
+        // indicate the source of packages from non-default repositories.
+        let qualifier = match &**inner {
+            Dist::Built(BuiltDist::Registry(_)) | Dist::Source(SourceDist::Registry(_)) => {
+                match dist.index() {
+                    Some(index @ IndexUrl::Url(_)) => {
+                        Some(("repository_url", index.url().to_string()))
+                    }
+                    Some(IndexUrl::Pypi(_) | IndexUrl::Path(_)) | None => None,
+                }
+            }
+            Dist::Built(BuiltDist::DirectUrl(dist)) => {
+                Some(("download_url", dist.location.to_string()))
+            }
+            Dist::Source(SourceDist::DirectUrl(dist)) => {
+                Some(("download_url", dist.location.to_string()))
+            }
+            Dist::Source(SourceDist::Git(dist)) => {
+                Some(("vcs_url", dist.git.repository().to_string()))
+            }
+            // No purl for local sources
+            Dist::Built(BuiltDist::Path(_))
+            | Dist::Source(SourceDist::Path(_) | SourceDist::Directory(_)) => {
+                return None;
+            }
+        };
+
+        let qualifiers = qualifier
+            .as_ref()
+            .map(|(key, value)| Self::format_qualifiers(&[(*key, value.as_str())]))
+            .unwrap_or_default();
+
+        Some(format!("pkg:pypi/{name}@{version}{qualifiers}"))
+    }
+
+    /// Convert the hashes of a resolved distribution to `CycloneDX` hashes.
+    fn create_hashes(hashes: &HashDigests) -> Option<Hashes> {
+        let hashes = hashes
+            .iter()
+            .map(|hash| Hash {
+                alg: match hash.algorithm() {
+                    HashAlgorithm::Md5 => CycloneDxHashAlgorithm::MD5,
+                    HashAlgorithm::Sha256 => CycloneDxHashAlgorithm::SHA_256,
+                    HashAlgorithm::Sha384 => CycloneDxHashAlgorithm::SHA_384,
+                    HashAlgorithm::Sha512 => CycloneDxHashAlgorithm::SHA_512,
+                    HashAlgorithm::Blake2b => CycloneDxHashAlgorithm::BLAKE2b_256,
+                },
+                content: HashValue(hash.digest.to_string()),
+            })
+            .collect::<Vec<_>>();
+        if hashes.is_empty() {
+            None
+        } else {
+            Some(Hashes(hashes))
+        }
+    }
+
     fn format_qualifiers(qualifiers: &[(&str, &str)]) -> String {
         let joined_qualifiers = qualifiers
             .iter()
@@ -150,8 +229,7 @@ impl<'a> ComponentBuilder<'a> {
         component
     }
 
-    fn create_synthetic_root_component(&mut self, root: Option<&Package>) -> Component {
-        let name = root.map(Self::get_package_name).unwrap_or("uv-workspace");
+    fn create_synthetic_root_component(&mut self, name: &str) -> Component {
         let bom_ref = self.create_bom_ref(name, None);
         let properties = Properties(vec![Property::new("uv:package:is_synthetic_root", "true")]);
 
@@ -255,6 +333,46 @@ impl<'a> ComponentBuilder<'a> {
     fn get_component(&self, id: &PackageId) -> Option<&Component> {
         self.package_to_component_map.get(id)
     }
+
+    fn create_component_from_dist(&mut self, dist: &AnnotatedDist) -> Component {
+        let name = dist.name.as_str();
+        let version = dist.version.to_string();
+        let bom_ref = self.create_bom_ref(name, Some(&version));
+
+        let mut component = Component::new(Classification::Library, name, &version, Some(bom_ref));
+        component.purl =
+            Self::create_purl_from_dist(dist).and_then(|purl_string| purl_string.parse().ok());
+        // The hashes of a distribution cover all of its artifacts. For registry distributions,
+        // attach the hashes to a reference for each artifact, rather than to the component itself.
+        if let ResolvedDist::Installable { dist: inner, .. } = &dist.dist
+            && let Some(files) = registry_files(inner)
+        {
+            let references = files
+                .into_iter()
+                .filter_map(|file| {
+                    let url = file.url.to_url().ok()?;
+                    let mut reference = ExternalReference::new(
+                        ExternalReferenceType::Distribution,
+                        Uri::new(url.as_str()),
+                    );
+                    reference.hashes = Self::create_hashes(&file.hashes);
+                    Some(reference)
+                })
+                .collect::<Vec<_>>();
+            if !references.is_empty() {
+                component.external_references = Some(ExternalReferences(references));
+            }
+        } else {
+            component.hashes = Self::create_hashes(&dist.hashes);
+        }
+        if let Some(marker_contents) = dist.marker.pep508().contents() {
+            component.properties = Some(Properties(vec![Property::new(
+                "uv:package:marker",
+                &marker_contents.to_string(),
+            )]));
+        }
+        component
+    }
 }
 
 pub fn from_lock<'lock>(
@@ -357,7 +475,10 @@ pub fn from_lock<'lock>(
     // 1. With `--all-packages`: ensures no dangling components from workspace packages not depended on by the workspace root.
     // 2. For virtual workspaces (no root project): provides an anchor for the dependency graph.
     if all_packages || metadata.component.is_none() {
-        let synthetic_root = component_builder.create_synthetic_root_component(root);
+        let synthetic_root = component_builder.create_synthetic_root_component(
+            root.map(ComponentBuilder::get_package_name)
+                .unwrap_or("uv-workspace"),
+        );
         let synthetic_root_bom_ref = synthetic_root
             .bom_ref
             .clone()
@@ -399,6 +520,109 @@ pub fn from_lock<'lock>(
     Ok(bom)
 }
 
+/// Export a [`ResolverOutput`], as produced by `uv pip compile`, in `CycloneDX` format.
+///
+/// Unlike [`from_lock`], a resolution has no project root, so a synthetic root component is used
+/// to anchor the direct requirements in the dependency graph.
+pub fn from_resolution(resolution: &ResolverOutput, omit: &[PackageName], preview: Preview) -> Bom {
+    if !preview.is_enabled(PreviewFeature::SbomExport) {
+        warn_user!(
+            "`uv pip compile --format=cyclonedx1.5` is experimental and may change without warning. Pass `--preview-features {}` to disable this warning.",
+            PreviewFeature::SbomExport
+        );
+    }
+
+    let mut component_builder = ComponentBuilder::default();
+
+    let synthetic_root = component_builder.create_synthetic_root_component("uv-resolution");
+    let synthetic_root_bom_ref = synthetic_root
+        .bom_ref
+        .clone()
+        .expect("bom-ref should always exist");
+
+    let metadata = Metadata {
+        component: Some(synthetic_root),
+        timestamp: cyclonedx_bom::prelude::DateTime::now().ok(),
+        tools: Some(Tools::List(vec![Tool {
+            vendor: Some(NormalizedString::new("Astral Software Inc.")),
+            name: Some(NormalizedString::new("uv")),
+            version: Some(NormalizedString::new(uv_version::version())),
+            hashes: None,
+            external_references: None,
+        }])),
+        ..Metadata::default()
+    };
+
+    // Create a component for each base distribution. Extras and dependency groups are keyed by
+    // the same name and version, such that they map onto the component of the base distribution.
+    let mut nodes = resolution
+        .graph
+        .node_weights()
+        .filter_map(|node| match node {
+            ResolutionGraphNode::Root => None,
+            ResolutionGraphNode::Dist(dist) => Some(dist),
+        })
+        .filter(|dist| dist.is_base() && !omit.contains(&dist.name))
+        .collect::<Vec<_>>();
+    nodes.sort_unstable_by(|a, b| (&a.name, &a.version).cmp(&(&b.name, &b.version)));
+
+    let mut bom_refs = FxHashMap::default();
+    let mut components = Vec::with_capacity(nodes.len());
+    for dist in nodes {
+        if bom_refs.contains_key(&(&dist.name, &dist.version)) {
+            continue;
+        }
+        let component = component_builder.create_component_from_dist(dist);
+        let bom_ref = component
+            .bom_ref
+            .clone()
+            .expect("bom-ref should always exist");
+        bom_refs.insert((&dist.name, &dist.version), bom_ref);
+        components.push(component);
+    }
+
+    // Collect the edges between components, attributing the direct requirements to the root.
+    let mut edges: BTreeMap<&str, BTreeSet<&str>> = BTreeMap::default();
+    edges.insert(synthetic_root_bom_ref.as_str(), BTreeSet::default());
+    for bom_ref in bom_refs.values() {
+        edges.insert(bom_ref.as_str(), BTreeSet::default());
+    }
+    for edge in resolution.graph.edge_references() {
+        let source = match &resolution.graph[edge.source()] {
+            ResolutionGraphNode::Root => Some(synthetic_root_bom_ref.as_str()),
+            ResolutionGraphNode::Dist(dist) => bom_refs
+                .get(&(&dist.name, &dist.version))
+                .map(String::as_str),
+        };
+        let ResolutionGraphNode::Dist(target) = &resolution.graph[edge.target()] else {
+            continue;
+        };
+        let Some(target) = bom_refs.get(&(&target.name, &target.version)) else {
+            continue;
+        };
+        if let Some(source) = source
+            && source != target.as_str()
+        {
+            edges.entry(source).or_default().insert(target.as_str());
+        }
+    }
+
+    let dependencies = edges
+        .into_iter()
+        .map(|(dependency_ref, dependencies)| Dependency {
+            dependency_ref: dependency_ref.to_string(),
+            dependencies: dependencies.into_iter().map(ToString::to_string).collect(),
+        })
+        .collect();
+
+    Bom {
+        metadata: Some(metadata),
+        components: Some(Components(components)),
+        dependencies: Some(Dependencies(dependencies)),
+        ..Bom::default()
+    }
+}
+
 fn create_dependencies(
     nodes: &[ExportableRequirement<'_>],
     component_builder: &ComponentBuilder,
@@ -11,6 +11,7 @@ use rustc_hash::{FxBuildHasher, FxHashMap, FxHashSet};
 use uv_configuration::{
     DependencyGroupsWithDefaults, ExtrasSpecificationWithDefaults, InstallOptions,
 };
+use uv_distribution_types::{BuiltDist, Dist, File, SourceDist};
 use uv_normalize::{ExtraName, GroupName, PackageName};
 use uv_pep508::MarkerTree;
 use uv_pypi_types::ConflictItem;
@@ -26,6 +27,31 @@ use crate::{Installable, LockError, Package};
 pub mod cyclonedx_json;
 mod pylock_toml;
 mod requirements_txt;
+pub mod spdx_json;
+
+/// Return the files (i.e., wheels and source distribution) of a registry distribution, each of
+/// which has its own hashes.
+///
+/// Returns `None` for distributions that aren't from a registry, which consist of a single artifact.
+fn registry_files(dist: &Dist) -> Option<Vec<&File>> {
+    match dist {
+        Dist::Built(BuiltDist::Registry(dist)) => Some(
+            dist.wheels
+                .iter()
+                .map(|wheel| &*wheel.file)
+                .chain(dist.sdist.iter().map(|sdist| &*sdist.file))
+                .collect(),
+        ),
+        Dist::Source(SourceDist::Registry(dist)) => Some(
+            dist.wheels
+                .iter()
+                .map(|wheel| &*wheel.file)
+                .chain(std::iter::once(&*dist.file))
+                .collect(),
+        ),
+        _ => None,
+    }
+}
 
 /// A flat requirement, with its associated marker.
 #[derive(Debug, Clone, PartialEq, Eq)]
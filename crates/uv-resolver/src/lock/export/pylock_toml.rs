@@ -1396,8 +1396,10 @@ impl PylockTomlWheel {
 
         let file = Box::new(uv_distribution_types::File {
             dist_info_metadata: false,
+            dist_info_metadata_hashes: HashDigests::empty(),
             filename: SmallString::from(filename.to_string()),
             hashes: HashDigests::from(self.hashes.clone()),
+            provenance: None,
             requires_python: None,
             size: self.size,
             upload_time_utc_ms: self.upload_time.map(Timestamp::as_millisecond),
@@ -1554,8 +1556,10 @@ impl PylockTomlSdist {
 
         let file = Box::new(uv_distribution_types::File {
             dist_info_metadata: false,
+            dist_info_metadata_hashes: HashDigests::empty(),
             filename,
             hashes: HashDigests::from(self.hashes.clone()),
+            provenance: None,
             requires_python: None,
             size: self.size,
             upload_time_utc_ms: self.upload_time.map(Timestamp::as_millisecond),
@@ -0,0 +1,443 @@
+//! Export to [SPDX](https://spdx.github.io/spdx-spec/v2.3/) v2.3 JSON documents.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use jiff::Timestamp;
+use petgraph::visit::EdgeRef;
+use rustc_hash::FxHashMap;
+use serde::Serialize;
+
+use uv_configuration::{
+    DependencyGroupsWithDefaults, ExtrasSpecificationWithDefaults, InstallOptions,
+};
+use uv_distribution_types::{BuiltDist, Dist, ResolvedDist, SourceDist};
+use uv_normalize::PackageName;
+use uv_preview::{Preview, PreviewFeature};
+use uv_pypi_types::{HashAlgorithm, HashDigest};
+use uv_warnings::warn_user;
+
+use crate::lock::export::cyclonedx_json::ComponentBuilder;
+use crate::lock::export::{ExportableRequirements, registry_files};
+use crate::lock::{Package, PackageId, Source};
+use crate::resolution::{AnnotatedDist, ResolutionGraphNode};
+use crate::{Installable, LockError, ResolverOutput};
+
+/// The identifier of the document itself, used as the source of `DESCRIBES` relationships.
+const DOCUMENT_ID: &str = "SPDXRef-DOCUMENT";
+
+/// The value used for fields whose value is unknown.
+const NOASSERTION: &str = "NOASSERTION";
+
+/// An SPDX v2.3 document, listing a set of packages and the relationships between them.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpdxDocument {
+    spdx_version: &'static str,
+    data_license: &'static str,
+    #[serde(rename = "SPDXID")]
+    spdx_id: &'static str,
+    name: String,
+    document_namespace: String,
+    creation_info: CreationInfo,
+    packages: Vec<SpdxPackage>,
+    relationships: Vec<Relationship>,
+}
+
+impl SpdxDocument {
+    /// Serialize the document to pretty-printed JSON.
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string_pretty(self)
+    }
+
+    fn new(name: &str, packages: Vec<SpdxPackage>, relationships: Vec<Relationship>) -> Self {
+        Self {
+            spdx_version: "SPDX-2.3",
+            data_license: "CC0-1.0",
+            spdx_id: DOCUMENT_ID,
+            name: name.to_string(),
+            document_namespace: format!(
+                "https://spdx.org/spdxdocs/{name}-{}",
+                uuid::Uuid::new_v4()
+            ),
+            creation_info: CreationInfo {
+                created: Timestamp::now().strftime("%Y-%m-%dT%H:%M:%SZ").to_string(),
+                creators: vec![format!("Tool: uv-{}", uv_version::version())],
+            },
+            packages,
+            relationships,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CreationInfo {
+    created: String,
+    creators: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SpdxPackage {
+    name: String,
+    #[serde(rename = "SPDXID")]
+    spdx_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    version_info: Option<String>,
+    download_location: String,
+    files_analyzed: bool,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    checksums: Vec<Checksum>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    external_refs: Vec<ExternalRef>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Checksum {
+    algorithm: &'static str,
+    checksum_value: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ExternalRef {
+    #[serde(rename = "referenceCategory")]
+    category: &'static str,
+    #[serde(rename = "referenceType")]
+    kind: &'static str,
+    #[serde(rename = "referenceLocator")]
+    locator: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Relationship {
+    spdx_element_id: String,
+    #[serde(rename = "relationshipType")]
+    kind: &'static str,
+    related_spdx_element: String,
+}
+
+impl Relationship {
+    fn new(source: &str, kind: &'static str, target: &str) -> Self {
+        Self {
+            spdx_element_id: source.to_string(),
+            kind,
+            related_spdx_element: target.to_string(),
+        }
+    }
+}
+
+/// Creates SPDX packages, ensuring that each is assigned a unique identifier.
+#[derive(Default)]
+struct PackageBuilder {
+    id_counter: usize,
+}
+
+impl PackageBuilder {
+    /// Create an identifier in the format `SPDXRef-{package_name}-{id}-{version}`, replacing any
+    /// characters that aren't allowed in SPDX identifiers.
+    fn create_spdx_id(&mut self, name: &str, version: Option<&str>) -> String {
+        self.id_counter += 1;
+        let id = self.id_counter;
+        let spdx_id = if let Some(version) = version {
+            format!("SPDXRef-{name}-{id}-{version}")
+        } else {
+            format!("SPDXRef-{name}-{id}")
+        };
+        spdx_id
+            .chars()
+            .map(|c| {
+                if c.is_ascii_alphanumeric() || c == '.' || c == '-' {
+                    c
+                } else {
+                    '-'
+                }
+            })
+            .collect()
+    }
+
+    fn create_package(
+        &mut self,
+        name: &str,
+        version: Option<String>,
+        download_location: Option<String>,
+        checksums: Vec<Checksum>,
+        purl: Option<String>,
+    ) -> SpdxPackage {
+        SpdxPackage {
+            name: name.to_string(),
+            spdx_id: self.create_spdx_id(name, version.as_deref()),
+            version_info: version,
+            download_location: download_location.unwrap_or_else(|| NOASSERTION.to_string()),
+            files_analyzed: false,
+            checksums,
+            external_refs: purl
+                .into_iter()
+                .map(|purl| ExternalRef {
+                    category: "PACKAGE-MANAGER",
+                    kind: "purl",
+                    locator: purl,
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Convert the hashes of a single artifact to SPDX checksums.
+fn create_checksums<'a>(hashes: impl IntoIterator<Item = &'a HashDigest>) -> Vec<Checksum> {
+    hashes
+        .into_iter()
+        .map(|hash| Checksum {
+            algorithm: match hash.algorithm() {
+                HashAlgorithm::Md5 => "MD5",
+                HashAlgorithm::Sha256 => "SHA256",
+                HashAlgorithm::Sha384 => "SHA384",
+                HashAlgorithm::Sha512 => "SHA512",
+                HashAlgorithm::Blake2b => "BLAKE2b-256",
+            },
+            checksum_value: hash.digest.to_string(),
+        })
+        .collect()
+}
+
+/// Return the download location and checksums of a resolved distribution.
+///
+/// SPDX checksums describe a single file, so they're omitted for registry distributions that
+/// consist of multiple artifacts (e.g., several wheels and a source distribution).
+fn dist_artifact(dist: &AnnotatedDist) -> (Option<String>, Vec<Checksum>) {
+    let ResolvedDist::Installable { dist: inner, .. } = &dist.dist else {
+        return (None, Vec::new());
+    };
+    if let Some(files) = registry_files(inner) {
+        return match files.as_slice() {
+            [file] => (
+                file.url.to_url().ok().map(|url| url.to_string()),
+                create_checksums(file.hashes.iter()),
+            ),
+            _ => (None, Vec::new()),
+        };
+    }
+    let location = match &**inner {
+        Dist::Built(BuiltDist::DirectUrl(dist)) => Some(dist.location.to_string()),
+        Dist::Source(SourceDist::DirectUrl(dist)) => Some(dist.location.to_string()),
+        Dist::Source(SourceDist::Git(dist)) => Some(format!("git+{}", dist.git.repository())),
+        _ => None,
+    };
+    (location, create_checksums(dist.hashes.iter()))
+}
+
+/// Return the download location and checksums of a package in the lockfile.
+///
+/// As in [`dist_artifact`], checksums are only included for packages with a single artifact.
+fn package_artifact(package: &Package) -> (Option<String>, Vec<Checksum>) {
+    let location = match &package.id.source {
+        Source::Direct(url, _) => Some(url.as_ref().to_string()),
+        Source::Git(url, _) => Some(format!("git+{}", url.as_ref())),
+        Source::Registry(_)
+        | Source::Path(_)
+        | Source::Directory(_)
+        | Source::Editable(_)
+        | Source::Virtual(_) => None,
+    };
+    let hashes = package
+        .sdist
+        .as_ref()
+        .and_then(|sdist| sdist.hash())
+        .into_iter()
+        .chain(
+            package
+                .wheels
+                .iter()
+                .filter_map(|wheel| wheel.hash.as_ref()),
+        )
+        .map(|hash| &hash.0)
+        .collect::<Vec<_>>();
+    let checksums = if hashes.len() == 1 {
+        create_checksums(hashes)
+    } else {
+        Vec::new()
+    };
+    (location, checksums)
+}
+
+/// Export a lockfile in SPDX format.
+///
+/// The document describes the project root, or each workspace member if there is no single
+/// root (or all packages are exported).
+pub fn from_lock<'lock>(
+    target: &impl Installable<'lock>,
+    prune: &[PackageName],
+    extras: &ExtrasSpecificationWithDefaults,
+    groups: &DependencyGroupsWithDefaults,
+    annotate: bool,
+    install_options: &'lock InstallOptions,
+    preview: Preview,
+    all_packages: bool,
+) -> Result<SpdxDocument, LockError> {
+    if !preview.is_enabled(PreviewFeature::SbomExport) {
+        warn_user!(
+            "`uv export --format=spdx2.3` is experimental and may change without warning. Pass `--preview-features {}` to disable this warning.",
+            PreviewFeature::SbomExport
+        );
+    }
+
+    let ExportableRequirements(mut nodes) = ExportableRequirements::from_lock(
+        target,
+        prune,
+        extras,
+        groups,
+        annotate,
+        install_options,
+    )?;
+    nodes.sort_unstable_by_key(|node| &node.package.id);
+
+    let root = match target.roots().collect::<Vec<_>>().as_slice() {
+        [single_root] => nodes
+            .iter()
+            .find(|node| &node.package.id.name == *single_root)
+            .map(|node| node.package),
+        _ => None,
+    }
+    .or_else(|| target.lock().root());
+
+    let mut builder = PackageBuilder::default();
+    let mut spdx_ids: FxHashMap<&PackageId, String> = FxHashMap::default();
+    let mut packages = Vec::with_capacity(nodes.len());
+    for node in &nodes {
+        let package = node.package;
+        let (download_location, checksums) = package_artifact(package);
+        let spdx_package = builder.create_package(
+            package.id.name.as_str(),
+            package.id.version.as_ref().map(ToString::to_string),
+            download_location,
+            checksums,
+            ComponentBuilder::create_purl(package),
+        );
+        spdx_ids.insert(&package.id, spdx_package.spdx_id.clone());
+        packages.push(spdx_package);
+    }
+
+    // The document describes the project root, or each workspace member in its absence.
+    let described = if let Some(root) = root.filter(|_| !all_packages) {
+        vec![&root.id]
+    } else {
+        nodes
+            .iter()
+            .map(|node| &node.package.id)
+            .filter(|id| target.lock().members().contains(&id.name))
+            .collect()
+    };
+    let mut relationships = described
+        .into_iter()
+        .filter_map(|id| spdx_ids.get(id))
+        .map(|spdx_id| Relationship::new(DOCUMENT_ID, "DESCRIBES", spdx_id))
+        .collect::<Vec<_>>();
+
+    for node in &nodes {
+        let source = &spdx_ids[&node.package.id];
+        let targets = node
+            .package
+            .dependencies
+            .iter()
+            .chain(node.package.optional_dependencies.values().flatten())
+            .chain(node.package.dependency_groups.values().flatten())
+            .filter_map(|dep| spdx_ids.get(&dep.package_id))
+            .filter(|target| *target != source)
+            .collect::<BTreeSet<_>>();
+        relationships.extend(
+            targets
+                .into_iter()
+                .map(|target| Relationship::new(source, "DEPENDS_ON", target)),
+        );
+    }
+
+    let name = root.map_or("uv-workspace", |root| root.id.name.as_str());
+    Ok(SpdxDocument::new(name, packages, relationships))
+}
+
+/// Export a [`ResolverOutput`], as produced by `uv pip compile`, in SPDX format.
+///
+/// The document describes each of the direct requirements of the resolution.
+pub fn from_resolution(
+    resolution: &ResolverOutput,
+    omit: &[PackageName],
+    preview: Preview,
+) -> SpdxDocument {
+    if !preview.is_enabled(PreviewFeature::SbomExport) {
+        warn_user!(
+            "`uv pip compile --format=spdx2.3` is experimental and may change without warning. Pass `--preview-features {}` to disable this warning.",
+            PreviewFeature::SbomExport
+        );
+    }
+
+    // Create a package for each base distribution. Extras and dependency groups are keyed by the
+    // same name and version, such that they map onto the package of the base distribution.
+    let mut nodes = resolution
+        .graph
+        .node_weights()
+        .filter_map(|node| match node {
+            ResolutionGraphNode::Root => None,
+            ResolutionGraphNode::Dist(dist) => Some(dist),
+        })
+        .filter(|dist| dist.is_base() && !omit.contains(&dist.name))
+        .collect::<Vec<_>>();
+    nodes.sort_unstable_by(|a, b| (&a.name, &a.version).cmp(&(&b.name, &b.version)));
+
+    let mut builder = PackageBuilder::default();
+    let mut spdx_ids = FxHashMap::default();
+    let mut packages = Vec::with_capacity(nodes.len());
+    for dist in nodes {
+        if spdx_ids.contains_key(&(&dist.name, &dist.version)) {
+            continue;
+        }
+        let (download_location, checksums) = dist_artifact(dist);
+        let package = builder.create_package(
+            dist.name.as_str(),
+            Some(dist.version.to_string()),
+            download_location,
+            checksums,
+            ComponentBuilder::create_purl_from_dist(dist),
+        );
+        spdx_ids.insert((&dist.name, &dist.version), package.spdx_id.clone());
+        packages.push(package);
+    }
+
+    // Collect the edges between packages, attributing the direct requirements to the document.
+    let mut described = BTreeSet::default();
+    let mut edges: BTreeMap<&str, BTreeSet<&str>> = BTreeMap::default();
+    for edge in resolution.graph.edge_references() {
+        let ResolutionGraphNode::Dist(target) = &resolution.graph[edge.target()] else {
+            continue;
+        };
+        let Some(target) = spdx_ids.get(&(&target.name, &target.version)) else {
+            continue;
+        };
+        match &resolution.graph[edge.source()] {
+            ResolutionGraphNode::Root => {
+                described.insert(target.as_str());
+            }
+            ResolutionGraphNode::Dist(dist) => {
+                let Some(source) = spdx_ids.get(&(&dist.name, &dist.version)) else {
+                    continue;
+                };
+                if source != target {
+                    edges.entry(source).or_default().insert(target.as_str());
+                }
+            }
+        }
+    }
+
+    let relationships = described
+        .into_iter()
+        .map(|target| Relationship::new(DOCUMENT_ID, "DESCRIBES", target))
+        .chain(edges.into_iter().flat_map(|(source, targets)| {
+            targets
+                .into_iter()
+                .map(move |target| Relationship::new(source, "DEPENDS_ON", target))
+        }))
+        .collect();
+
+    SpdxDocument::new("uv-resolution", packages, relationships)
+}
@@ -51,9 +51,11 @@ use uv_workspace::{Editability, WorkspaceMember};
 
 use crate::exclude_newer::ExcludeNewerSpan;
 use crate::fork_strategy::ForkStrategy;
+pub use crate::lock::dot::DotDisplay;
+pub use crate::lock::explain::DisplayLockExplanation;
 pub(crate) use crate::lock::export::PylockTomlPackage;
 pub use crate::lock::export::RequirementsTxtExport;
-pub use crate::lock::export::{PylockToml, PylockTomlErrorKind, cyclonedx_json};
+pub use crate::lock::export::{PylockToml, PylockTomlErrorKind, cyclonedx_json, spdx_json};
 pub use crate::lock::installable::Installable;
 pub use crate::lock::map::PackageMap;
 pub use crate::lock::tree::TreeDisplay;
@@ -64,6 +66,8 @@ use crate::{
     PackageExcludeNewer, PrereleaseMode, ResolutionMode, ResolverOutput,
 };
 
+mod dot;
+mod explain;
 mod export;
 mod installable;
 mod map;
@@ -437,6 +441,12 @@ impl Lock {
         let options = ResolverOptions {
             resolution_mode: resolution.options.resolution_mode,
             prerelease_mode: resolution.options.prerelease_mode,
+            prerelease_packages: resolution
+                .options
+                .prerelease_packages
+                .iter()
+                .cloned()
+                .collect(),
             fork_strategy: resolution.options.fork_strategy,
             exclude_newer: resolution.options.exclude_newer.clone().into(),
         };
@@ -735,6 +745,12 @@ impl Lock {
         self.options.prerelease_mode
     }
 
+    /// Returns the packages that were allowed to have pre-release versions in this lock,
+    /// regardless of the pre-release mode.
+    pub fn prerelease_packages(&self) -> &BTreeSet<PackageName> {
+        &self.options.prerelease_packages
+    }
+
     /// Returns the multi-version mode used to generate this lock.
     pub fn fork_strategy(&self) -> ForkStrategy {
         self.options.fork_strategy
@@ -985,6 +1001,18 @@ impl Lock {
                     value(self.options.prerelease_mode.to_string()),
                 );
             }
+            if !self.options.prerelease_packages.is_empty() {
+                options_table.insert(
+                    "prerelease-packages",
+                    value(
+                        self.options
+                            .prerelease_packages
+                            .iter()
+                            .map(ToString::to_string)
+                            .collect::<Array>(),
+                    ),
+                );
+            }
             if self.options.fork_strategy != ForkStrategy::default() {
                 options_table.insert(
                     "fork-strategy",
@@ -1228,7 +1256,21 @@ impl Lock {
 
         let mut packages = ArrayOfTables::new();
         for dist in &self.packages {
-            packages.push(dist.to_toml(&self.requires_python, &dist_count_by_name)?);
+            let mut table = dist.to_toml(&self.requires_python, &dist_count_by_name)?;
+
+            // Annotate any pre-releases that were allowed by a package-specific policy, as
+            // opposed to the pre-release strategy.
+            if self.options.prerelease_packages.contains(dist.name())
+                && dist.version().is_some_and(Version::any_prerelease)
+            {
+                if let Some(version) = table.get_mut("version").and_then(Item::as_value_mut) {
+                    version
+                        .decor_mut()
+                        .set_suffix(" # pre-release allowed by `prerelease-packages`");
+                }
+            }
+
+            packages.push(table);
         }
 
         doc.insert("package", Item::ArrayOfTables(packages));
@@ -2168,6 +2210,10 @@ struct ResolverOptions {
     /// The [`PrereleaseMode`] used to generate this lock.
     #[serde(default)]
     prerelease_mode: PrereleaseMode,
+    /// The packages that were allowed to have pre-release versions, regardless of the
+    /// [`PrereleaseMode`].
+    #[serde(default)]
+    prerelease_packages: BTreeSet<PackageName>,
     /// The [`ForkStrategy`] used to generate this lock.
     #[serde(default)]
     fork_strategy: ForkStrategy,
@@ -2924,10 +2970,12 @@ impl Package {
                 })?;
                 let file = Box::new(uv_distribution_types::File {
                     dist_info_metadata: false,
+                    dist_info_metadata_hashes: HashDigests::empty(),
                     filename: SmallString::from(filename),
                     hashes: sdist.hash().map_or(HashDigests::empty(), |hash| {
                         HashDigests::from(hash.0.clone())
                     }),
+                    provenance: None,
                     requires_python: None,
                     size: sdist.size(),
                     upload_time_utc_ms: sdist.upload_time().map(Timestamp::as_millisecond),
@@ -2999,10 +3047,12 @@ impl Package {
                 })?;
                 let file = Box::new(uv_distribution_types::File {
                     dist_info_metadata: false,
+                    dist_info_metadata_hashes: HashDigests::empty(),
                     filename: SmallString::from(filename),
                     hashes: sdist.hash().map_or(HashDigests::empty(), |hash| {
                         HashDigests::from(hash.0.clone())
                     }),
+                    provenance: None,
                     requires_python: None,
                     size: sdist.size(),
                     upload_time_utc_ms: sdist.upload_time().map(Timestamp::as_millisecond),
@@ -4697,8 +4747,10 @@ impl Wheel {
                 };
                 let file = Box::new(uv_distribution_types::File {
                     dist_info_metadata: false,
+                    dist_info_metadata_hashes: HashDigests::empty(),
                     filename: SmallString::from(filename.to_string()),
                     hashes: self.hash.iter().map(|h| h.0.clone()).collect(),
+                    provenance: None,
                     requires_python: None,
                     size: self.size,
                     upload_time_utc_ms: self.upload_time.map(Timestamp::as_millisecond),
@@ -4747,8 +4799,10 @@ impl Wheel {
                 };
                 let file = Box::new(uv_distribution_types::File {
                     dist_info_metadata: false,
+                    dist_info_metadata_hashes: HashDigests::empty(),
                     filename: SmallString::from(filename.to_string()),
                     hashes: self.hash.iter().map(|h| h.0.clone()).collect(),
+                    provenance: None,
                     requires_python: None,
                     size: self.size,
                     upload_time_utc_ms: self.upload_time.map(Timestamp::as_millisecond),
@@ -6775,6 +6829,45 @@ source = { directory = "path/to/dir" }
         insta::assert_debug_snapshot!(result);
     }
 
+    #[test]
+    fn prerelease_packages_annotation() {
+        let data = r#"
+version = 1
+requires-python = ">=3.12"
+
+[options]
+prerelease-packages = ["anyio"]
+
+[[package]]
+name = "anyio"
+version = "4.3.0rc1"
+source = { directory = "path/to/anyio" }
+
+[[package]]
+name = "idna"
+version = "3.7rc1"
+source = { directory = "path/to/idna" }
+"#;
+        let lock: Lock = toml::from_str(data).unwrap();
+        insta::assert_snapshot!(lock.to_toml().unwrap(), @r#"
+        version = 1
+        requires-python = ">=3.12"
+
+        [options]
+        prerelease-packages = ["anyio"]
+
+        [[package]]
+        name = "anyio"
+        version = "4.3.0rc1" # pre-release allowed by `prerelease-packages`
+        source = { directory = "path/to/anyio" }
+
+        [[package]]
+        name = "idna"
+        version = "3.7rc1"
+        source = { directory = "path/to/idna" }
+        "#);
+    }
+
     #[test]
     fn source_editable() {
         let data = r#"
@@ -1,15 +1,17 @@
 use uv_configuration::{BuildOptions, IndexStrategy};
+use uv_normalize::PackageName;
 use uv_pypi_types::SupportedEnvironments;
 use uv_torch::TorchStrategy;
 
 use crate::fork_strategy::ForkStrategy;
-use crate::{DependencyMode, ExcludeNewer, PrereleaseMode, ResolutionMode};
+use crate::{AllowYankedEntry, DependencyMode, ExcludeNewer, PrereleaseMode, ResolutionMode};
 
 /// Options for resolving a manifest.
 #[derive(Debug, Default, Clone, PartialEq, Eq)]
 pub struct Options {
     pub resolution_mode: ResolutionMode,
     pub prerelease_mode: PrereleaseMode,
+    pub prerelease_packages: Vec<PackageName>,
     pub dependency_mode: DependencyMode,
     pub fork_strategy: ForkStrategy,
     pub exclude_newer: ExcludeNewer,
@@ -18,6 +20,12 @@ pub struct Options {
     pub flexibility: Flexibility,
     pub build_options: BuildOptions,
     pub torch_backend: Option<TorchStrategy>,
+    pub excluded_packages: Vec<PackageName>,
+    pub allow_yanked: Vec<AllowYankedEntry>,
+    pub resolution_priority: Vec<PackageName>,
+    pub max_backtracks: Option<usize>,
+    pub memory_limit: Option<usize>,
+    pub minimal_update: bool,
 }
 
 /// Builder for [`Options`].
@@ -25,6 +33,7 @@ pub struct Options {
 pub struct OptionsBuilder {
     resolution_mode: ResolutionMode,
     prerelease_mode: PrereleaseMode,
+    prerelease_packages: Vec<PackageName>,
     dependency_mode: DependencyMode,
     fork_strategy: ForkStrategy,
     exclude_newer: ExcludeNewer,
@@ -33,6 +42,12 @@ pub struct OptionsBuilder {
     flexibility: Flexibility,
     build_options: BuildOptions,
     torch_backend: Option<TorchStrategy>,
+    excluded_packages: Vec<PackageName>,
+    allow_yanked: Vec<AllowYankedEntry>,
+    resolution_priority: Vec<PackageName>,
+    max_backtracks: Option<usize>,
+    memory_limit: Option<usize>,
+    minimal_update: bool,
 }
 
 impl OptionsBuilder {
@@ -55,6 +70,14 @@ impl OptionsBuilder {
         self
     }
 
+    /// Sets the packages that are allowed to have pre-release versions, regardless of the
+    /// [`PrereleaseMode`].
+    #[must_use]
+    pub fn prerelease_packages(mut self, prerelease_packages: Vec<PackageName>) -> Self {
+        self.prerelease_packages = prerelease_packages;
+        self
+    }
+
     /// Sets the dependency mode.
     #[must_use]
     pub fn dependency_mode(mut self, dependency_mode: DependencyMode) -> Self {
@@ -111,11 +134,56 @@ impl OptionsBuilder {
         self
     }
 
+    /// Sets the packages that must never be installed.
+    #[must_use]
+    pub fn excluded_packages(mut self, excluded_packages: Vec<PackageName>) -> Self {
+        self.excluded_packages = excluded_packages;
+        self
+    }
+
+    /// Sets the yanked versions that are explicitly allowed.
+    #[must_use]
+    pub fn allow_yanked(mut self, allow_yanked: Vec<AllowYankedEntry>) -> Self {
+        self.allow_yanked = allow_yanked;
+        self
+    }
+
+    /// Sets the packages whose versions should be decided first, in order.
+    #[must_use]
+    pub fn resolution_priority(mut self, resolution_priority: Vec<PackageName>) -> Self {
+        self.resolution_priority = resolution_priority;
+        self
+    }
+
+    /// Sets the maximum number of conflicts the resolver may backtrack from before giving up.
+    #[must_use]
+    pub fn max_backtracks(mut self, max_backtracks: Option<usize>) -> Self {
+        self.max_backtracks = max_backtracks;
+        self
+    }
+
+    /// Sets the approximate amount of memory (in bytes) to use for package metadata, evicting
+    /// the least recently used metadata when exceeded.
+    #[must_use]
+    pub fn memory_limit(mut self, memory_limit: Option<usize>) -> Self {
+        self.memory_limit = memory_limit;
+        self
+    }
+
+    /// Sets whether preferred versions that are no longer compatible should be updated as little
+    /// as possible.
+    #[must_use]
+    pub fn minimal_update(mut self, minimal_update: bool) -> Self {
+        self.minimal_update = minimal_update;
+        self
+    }
+
     /// Builds the options.
     pub fn build(self) -> Options {
         Options {
             resolution_mode: self.resolution_mode,
             prerelease_mode: self.prerelease_mode,
+            prerelease_packages: self.prerelease_packages,
             dependency_mode: self.dependency_mode,
             fork_strategy: self.fork_strategy,
             exclude_newer: self.exclude_newer,
@@ -124,6 +192,12 @@ impl OptionsBuilder {
             flexibility: self.flexibility,
             build_options: self.build_options,
             torch_backend: self.torch_backend,
+            excluded_packages: self.excluded_packages,
+            allow_yanked: self.allow_yanked,
+            resolution_priority: self.resolution_priority,
+            max_backtracks: self.max_backtracks,
+            memory_limit: self.memory_limit,
+            minimal_update: self.minimal_update,
         }
     }
 }
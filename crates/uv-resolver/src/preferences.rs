@@ -310,6 +310,14 @@ impl Preferences {
         });
     }
 
+    /// Remove the preferences for a package that stem from an existing lockfile, requirements
+    /// file, or environment, retaining any preferences from the current solve.
+    pub(crate) fn remove_pinned(&mut self, package_name: &PackageName) {
+        if let Some(entries) = self.0.get_mut(package_name) {
+            entries.retain(|entry| entry.source == PreferenceSource::Resolver);
+        }
+    }
+
     /// Returns an iterator over the preferences.
     pub fn iter(
         &self,
@@ -1,3 +1,5 @@
+use rustc_hash::FxHashSet;
+
 use uv_distribution_types::RequirementSource;
 use uv_normalize::PackageName;
 use uv_pep440::Operator;
@@ -44,7 +46,15 @@ impl std::fmt::Display for PrereleaseMode {
 /// Like [`PrereleaseMode`], but with any additional information required to select a candidate,
 /// like the set of direct dependencies.
 #[derive(Debug, Clone)]
-pub(crate) enum PrereleaseStrategy {
+pub(crate) struct PrereleaseStrategy {
+    /// The strategy for packages without a package-specific policy.
+    mode: ModeStrategy,
+    /// The packages that are allowed to have pre-release versions, regardless of the mode.
+    packages: FxHashSet<PackageName>,
+}
+
+#[derive(Debug, Clone)]
+enum ModeStrategy {
     /// Disallow all pre-release versions.
     Disallow,
 
@@ -66,16 +76,17 @@ pub(crate) enum PrereleaseStrategy {
 impl PrereleaseStrategy {
     pub(crate) fn from_mode(
         mode: PrereleaseMode,
+        prerelease_packages: &[PackageName],
         manifest: &Manifest,
         env: &ResolverEnvironment,
         dependencies: DependencyMode,
     ) -> Self {
         let mut packages = ForkSet::default();
 
-        match mode {
-            PrereleaseMode::Disallow => Self::Disallow,
-            PrereleaseMode::Allow => Self::Allow,
-            PrereleaseMode::IfNecessary => Self::IfNecessary,
+        let mode = match mode {
+            PrereleaseMode::Disallow => ModeStrategy::Disallow,
+            PrereleaseMode::Allow => ModeStrategy::Allow,
+            PrereleaseMode::IfNecessary => ModeStrategy::IfNecessary,
             _ => {
                 for requirement in manifest.requirements(env, dependencies) {
                     let RequirementSource::Registry { specifier, .. } = &requirement.source else {
@@ -94,11 +105,18 @@ impl PrereleaseStrategy {
                 }
 
                 match mode {
-                    PrereleaseMode::Explicit => Self::Explicit(packages),
-                    PrereleaseMode::IfNecessaryOrExplicit => Self::IfNecessaryOrExplicit(packages),
+                    PrereleaseMode::Explicit => ModeStrategy::Explicit(packages),
+                    PrereleaseMode::IfNecessaryOrExplicit => {
+                        ModeStrategy::IfNecessaryOrExplicit(packages)
+                    }
                     _ => unreachable!(),
                 }
             }
+        };
+
+        Self {
+            mode,
+            packages: prerelease_packages.iter().cloned().collect(),
         }
     }
 
@@ -108,18 +126,21 @@ impl PrereleaseStrategy {
         package_name: &PackageName,
         env: &ResolverEnvironment,
     ) -> AllowPrerelease {
-        match self {
-            Self::Disallow => AllowPrerelease::No,
-            Self::Allow => AllowPrerelease::Yes,
-            Self::IfNecessary => AllowPrerelease::IfNecessary,
-            Self::Explicit(packages) => {
+        if self.packages.contains(package_name) {
+            return AllowPrerelease::Yes;
+        }
+        match &self.mode {
+            ModeStrategy::Disallow => AllowPrerelease::No,
+            ModeStrategy::Allow => AllowPrerelease::Yes,
+            ModeStrategy::IfNecessary => AllowPrerelease::IfNecessary,
+            ModeStrategy::Explicit(packages) => {
                 if packages.contains(package_name, env) {
                     AllowPrerelease::Yes
                 } else {
                     AllowPrerelease::No
                 }
             }
-            Self::IfNecessaryOrExplicit(packages) => {
+            ModeStrategy::IfNecessaryOrExplicit(packages) => {
                 if packages.contains(package_name, env) {
                     AllowPrerelease::Yes
                 } else {
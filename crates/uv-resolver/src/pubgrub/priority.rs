@@ -1,8 +1,9 @@
 use std::cmp::Reverse;
+use std::sync::Arc;
 
 use hashbrown::hash_map::{EntryRef, OccupiedEntry};
 use pubgrub::{DependencyProvider, Range};
-use rustc_hash::FxBuildHasher;
+use rustc_hash::{FxBuildHasher, FxHashMap};
 
 use uv_normalize::PackageName;
 use uv_pep440::Version;
@@ -25,13 +26,32 @@ use crate::{FxHashbrownMap, SentinelRange};
 /// Our main priority is the package name, the earlier we encounter a package, the higher its
 /// priority. This way, all virtual packages of the same name will be applied in a batch. To ensure
 /// determinism, we also track the discovery order of virtual packages as secondary order.
+///
+/// Users can override the order for specific packages with `resolution-priority`, in which case
+/// those packages are decided before any package without an explicit version or URL.
 #[derive(Clone, Debug, Default)]
 pub(crate) struct PubGrubPriorities {
     package_priority: FxHashbrownMap<PackageName, PubGrubPriority>,
     virtual_package_tiebreaker: FxHashbrownMap<PubGrubPackage, PubGrubTiebreaker>,
+    /// The user-provided priority hints, mapping each package to its position in the hints.
+    hints: Arc<FxHashMap<PackageName, u32>>,
 }
 
 impl PubGrubPriorities {
+    /// Create a new [`PubGrubPriorities`] with the given user-provided priority hints, in order
+    /// of decreasing priority.
+    pub(crate) fn new(hints: &[PackageName]) -> Self {
+        let mut map = FxHashMap::default();
+        for (index, name) in hints.iter().enumerate() {
+            let index = u32::try_from(index).unwrap_or(u32::MAX);
+            map.entry(name.clone()).or_insert(index);
+        }
+        Self {
+            hints: Arc::new(map),
+            ..Self::default()
+        }
+    }
+
     /// Add a [`PubGrubPackage`] to the priority map.
     pub(crate) fn insert(
         &mut self,
@@ -65,6 +85,8 @@ impl PubGrubPriorities {
                     || SentinelRange::from(version).is_sentinel()
                 {
                     PubGrubPriority::Singleton(Reverse(index))
+                } else if let Some(hint) = self.hints.get(name) {
+                    PubGrubPriority::Hinted(Reverse(*hint), Reverse(index))
                 } else {
                     // Keep the conflict-causing packages to avoid loops where we seesaw between
                     // `Unspecified` and `Conflict*`.
@@ -90,6 +112,8 @@ impl PubGrubPriorities {
                     || SentinelRange::from(version).is_sentinel()
                 {
                     PubGrubPriority::Singleton(Reverse(len))
+                } else if let Some(hint) = self.hints.get(name) {
+                    PubGrubPriority::Hinted(Reverse(*hint), Reverse(len))
                 } else {
                     PubGrubPriority::Unspecified(Reverse(len))
                 };
@@ -107,6 +131,7 @@ impl PubGrubPriorities {
             PubGrubPriority::ConflictLate(Reverse(index))
             | PubGrubPriority::Unspecified(Reverse(index))
             | PubGrubPriority::ConflictEarly(Reverse(index))
+            | PubGrubPriority::Hinted(_, Reverse(index))
             | PubGrubPriority::Singleton(Reverse(index))
             | PubGrubPriority::DirectUrl(Reverse(index)) => Some(*index),
             PubGrubPriority::Root => None,
@@ -179,6 +204,11 @@ impl PubGrubPriorities {
             }
         };
 
+        // Respect the user-provided priority hints.
+        if self.hints.contains_key(name) {
+            return false;
+        }
+
         let len = self.package_priority.len();
         match self.package_priority.entry_ref(name) {
             EntryRef::Vacant(entry) => {
@@ -212,6 +242,11 @@ impl PubGrubPriorities {
             }
         };
 
+        // Respect the user-provided priority hints.
+        if self.hints.contains_key(name) {
+            return false;
+        }
+
         let len = self.package_priority.len();
         match self.package_priority.entry_ref(name) {
             EntryRef::Vacant(entry) => {
@@ -255,6 +290,12 @@ pub(crate) enum PubGrubPriority {
     /// `ConflictLate`.
     ConflictEarly(Reverse<usize>),
 
+    /// The package was listed in the user-provided `resolution-priority` hints.
+    ///
+    /// Hinted packages are ordered by their position in the hints, then by the order in which
+    /// they were added.
+    Hinted(Reverse<u32>, Reverse<usize>),
+
     /// The version range is constrained to a single version (e.g., with the `==` operator).
     Singleton(Reverse<usize>),
 
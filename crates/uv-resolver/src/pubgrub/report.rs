@@ -8,6 +8,7 @@ use owo_colors::OwoColorize;
 use pubgrub::{DerivationTree, Derived, External, Map, Range, Ranges, ReportFormatter, Term};
 use rustc_hash::FxHashMap;
 
+use uv_cache::CacheBucket;
 use uv_configuration::{IndexStrategy, NoBinary, NoBuild};
 use uv_distribution_types::{
     IncompatibleDist, IncompatibleSource, IncompatibleWheel, Index, IndexCapabilities,
@@ -534,6 +535,7 @@ impl PubGrubReportFormatter<'_> {
         index_capabilities: &IndexCapabilities,
         available_indexes: &FxHashMap<PackageName, BTreeSet<IndexUrl>>,
         unavailable_packages: &FxHashMap<PackageName, UnavailablePackage>,
+        suggestions: &FxHashMap<PackageName, Vec<PackageName>>,
         incomplete_packages: &FxHashMap<PackageName, BTreeMap<Version, MetadataUnavailable>>,
         fork_urls: &ForkUrls,
         fork_indexes: &ForkIndexes,
@@ -579,6 +581,7 @@ impl PubGrubReportFormatter<'_> {
                         index_capabilities,
                         available_indexes,
                         unavailable_packages,
+                        suggestions,
                         incomplete_packages,
                         output_hints,
                     );
@@ -599,6 +602,7 @@ impl PubGrubReportFormatter<'_> {
                                 output_hints.insert(PubGrubHint::NoBuild {
                                     package: name.clone(),
                                     option: options.build_options.no_build().clone(),
+                                    wheels: Self::wheel_tags(name, index, fork_indexes),
                                 });
                             }
                             // Check for unavailable versions due to incompatible tags.
@@ -637,6 +641,7 @@ impl PubGrubReportFormatter<'_> {
                         index_capabilities,
                         available_indexes,
                         unavailable_packages,
+                        suggestions,
                         incomplete_packages,
                         output_hints,
                     );
@@ -716,6 +721,7 @@ impl PubGrubReportFormatter<'_> {
                     index_capabilities,
                     available_indexes,
                     unavailable_packages,
+                    suggestions,
                     incomplete_packages,
                     fork_urls,
                     fork_indexes,
@@ -734,6 +740,7 @@ impl PubGrubReportFormatter<'_> {
                     index_capabilities,
                     available_indexes,
                     unavailable_packages,
+                    suggestions,
                     incomplete_packages,
                     fork_urls,
                     fork_indexes,
@@ -788,6 +795,15 @@ impl PubGrubReportFormatter<'_> {
                         version: candidate.version().clone(),
                         tags,
                         best,
+                        platforms: self
+                            .tags
+                            .map(|tags| {
+                                prioritized
+                                    .incompatible_platform_tags(tags)
+                                    .cloned()
+                                    .collect()
+                            })
+                            .unwrap_or_default(),
                     })
                 }
             }
@@ -815,6 +831,15 @@ impl PubGrubReportFormatter<'_> {
                         version: candidate.version().clone(),
                         tags,
                         best,
+                        platforms: self
+                            .tags
+                            .map(|tags| {
+                                prioritized
+                                    .incompatible_platform_tags(tags)
+                                    .cloned()
+                                    .collect()
+                            })
+                            .unwrap_or_default(),
                     })
                 }
             }
@@ -854,6 +879,7 @@ impl PubGrubReportFormatter<'_> {
         index_capabilities: &IndexCapabilities,
         available_indexes: &FxHashMap<PackageName, BTreeSet<IndexUrl>>,
         unavailable_packages: &FxHashMap<PackageName, UnavailablePackage>,
+        suggestions: &FxHashMap<PackageName, Vec<PackageName>>,
         incomplete_packages: &FxHashMap<PackageName, BTreeMap<Version, MetadataUnavailable>>,
         hints: &mut IndexSet<PubGrubHint>,
     ) {
@@ -881,7 +907,15 @@ impl PubGrubReportFormatter<'_> {
                     reason: reason.clone(),
                 });
             }
-            Some(UnavailablePackage::NotFound) => {}
+            Some(UnavailablePackage::NotFound(_)) => {
+                if let Some(suggestions) = suggestions.get(name) {
+                    hints.insert(PubGrubHint::SimilarPackages {
+                        package: name.clone(),
+                        suggestions: suggestions.clone(),
+                    });
+                }
+            }
+            Some(UnavailablePackage::Excluded) => {}
             None => {}
         }
 
@@ -1054,6 +1088,46 @@ impl PubGrubReportFormatter<'_> {
         }
     }
 
+    /// Return the wheel tags available for each version of a package in the index, omitting any
+    /// versions that only include source distributions.
+    fn wheel_tags(
+        name: &PackageName,
+        index: &InMemoryIndex,
+        fork_indexes: &ForkIndexes,
+    ) -> BTreeMap<Version, BTreeSet<String>> {
+        let response = if let Some(url) = fork_indexes.get(name).map(IndexMetadata::url) {
+            index.explicit().get(&(name.clone(), url.clone()))
+        } else {
+            index.implicit().get(name)
+        };
+
+        let Some(response) = response else {
+            return BTreeMap::new();
+        };
+
+        let VersionsResponse::Found(ref version_maps) = *response else {
+            return BTreeMap::new();
+        };
+
+        let mut wheels = BTreeMap::<Version, BTreeSet<String>>::new();
+        for version_map in version_maps {
+            for (version, dist) in version_map.iter(&Ranges::full()) {
+                let Some(prioritized) = dist.prioritized_dist() else {
+                    continue;
+                };
+                for filename in prioritized.wheel_filenames() {
+                    wheels.entry(version.clone()).or_default().insert(format!(
+                        "{}-{}-{}",
+                        filename.python_tags().iter().join("."),
+                        filename.abi_tags().iter().join("."),
+                        filename.platform_tags().iter().join("."),
+                    ));
+                }
+            }
+        }
+        wheels
+    }
+
     fn has_versions_in_index(
         name: &PackageName,
         index: &InMemoryIndex,
@@ -1115,6 +1189,12 @@ pub(crate) enum PubGrubHint {
     NoIndex,
     /// A package was not found in the registry, but network access was disabled.
     Offline,
+    /// A package was not found in the registry, but similarly-named packages were.
+    SimilarPackages {
+        package: PackageName,
+        // excluded from `PartialEq` and `Hash`
+        suggestions: Vec<PackageName>,
+    },
     /// Metadata for a package could not be parsed.
     InvalidPackageMetadata {
         package: PackageName,
@@ -1203,6 +1283,8 @@ pub(crate) enum PubGrubHint {
         package: PackageName,
         // excluded from `PartialEq` and `Hash`
         option: NoBuild,
+        // excluded from `PartialEq` and `Hash`
+        wheels: BTreeMap<Version, BTreeSet<String>>,
     },
     /// No source distributions are available for a package, and using pre-built wheels was disabled.
     NoBinary {
@@ -1224,6 +1306,8 @@ pub(crate) enum PubGrubHint {
         tags: BTreeSet<LanguageTag>,
         // excluded from `PartialEq` and `Hash`
         best: Option<LanguageTag>,
+        // excluded from `PartialEq` and `Hash`
+        platforms: BTreeSet<PlatformTag>,
     },
     /// None of the available wheels for a package have a compatible ABI tag (e.g., `abi3` in
     /// `cp310-abi3-manylinux_2_17_x86_64.whl`).
@@ -1235,6 +1319,8 @@ pub(crate) enum PubGrubHint {
         tags: BTreeSet<AbiTag>,
         // excluded from `PartialEq` and `Hash`
         best: Option<AbiTag>,
+        // excluded from `PartialEq` and `Hash`
+        platforms: BTreeSet<PlatformTag>,
     },
     /// None of the available wheels for a package have a compatible platform tag (e.g.,
     /// `manylinux_2_17_x86_64` in `cp310-abi3-manylinux_2_17_x86_64.whl`).
@@ -1261,6 +1347,72 @@ pub(crate) enum PubGrubHint {
     DisjointEnvironment,
 }
 
+/// Format the platforms for which wheels are available as a suffix for a tag hint, or an empty
+/// string if there are none.
+fn platforms_suffix(platforms: &BTreeSet<PlatformTag>) -> String {
+    if platforms.is_empty() {
+        return String::new();
+    }
+    let s = if platforms.len() == 1 { "" } else { "s" };
+    format!(
+        ", on the following platform{s}: {}",
+        platforms
+            .iter()
+            .map(|tag| format!("`{}`", tag.cyan()))
+            .join(", ")
+    )
+}
+
+/// Format the versions of a package for which wheels are available, along with their tags, as a
+/// suffix for a `--no-build` hint.
+///
+/// Only the most recent versions and the first few tags for each version are included.
+fn wheels_suffix(package: &PackageName, wheels: &BTreeMap<Version, BTreeSet<String>>) -> String {
+    const MAX_VERSIONS: usize = 3;
+    const MAX_TAGS: usize = 3;
+
+    /// Append a count of the omitted items, if any, to a list.
+    fn truncated(items: &[String], total: usize, noun: &str) -> String {
+        let omitted = total - items.len();
+        let items = items.join(", ");
+        if omitted == 0 {
+            items
+        } else {
+            let s = if omitted == 1 { "" } else { "s" };
+            format!("{items}, and {omitted} other {noun}{s}")
+        }
+    }
+
+    if wheels.is_empty() {
+        return format!(
+            ", but no wheels are available for any version of `{}`",
+            package.cyan()
+        );
+    }
+
+    let versions = wheels
+        .iter()
+        .rev()
+        .take(MAX_VERSIONS)
+        .map(|(version, tags)| {
+            let listed = tags
+                .iter()
+                .take(MAX_TAGS)
+                .map(|tag| format!("`{}`", tag.cyan()))
+                .collect::<Vec<_>>();
+            format!(
+                "`{}` ({})",
+                format!("{package}=={version}").cyan(),
+                truncated(&listed, tags.len(), "tag"),
+            )
+        })
+        .collect::<Vec<_>>();
+    format!(
+        "; wheels are only available for {}",
+        truncated(&versions, wheels.len(), "version")
+    )
+}
+
 /// This private enum mirrors [`PubGrubHint`] but only includes fields that should be
 /// used for `Eq` and `Hash` implementations. It is used to derive `PartialEq` and
 /// `Hash` implementations for [`PubGrubHint`].
@@ -1280,6 +1432,9 @@ enum PubGrubHintCore {
     },
     NoIndex,
     Offline,
+    SimilarPackages {
+        package: PackageName,
+    },
     InvalidPackageMetadata {
         package: PackageName,
     },
@@ -1361,6 +1516,7 @@ impl From<PubGrubHint> for PubGrubHintCore {
             }
             PubGrubHint::NoIndex => Self::NoIndex,
             PubGrubHint::Offline => Self::Offline,
+            PubGrubHint::SimilarPackages { package, .. } => Self::SimilarPackages { package },
             PubGrubHint::InvalidPackageMetadata { package, .. } => {
                 Self::InvalidPackageMetadata { package }
             }
@@ -1498,9 +1654,34 @@ impl std::fmt::Display for PubGrubHint {
             Self::Offline => {
                 write!(
                     f,
-                    "{}{} Packages were unavailable because the network was disabled. When the network is disabled, registry packages may only be read from the cache.",
+                    "{}{} Packages were unavailable because the network was disabled. When the network is disabled, registry packages may only be read from the cache (pre-populate the `{}` and `{}` cache buckets while online).",
+                    "hint".bold().cyan(),
+                    ":".bold(),
+                    CacheBucket::Simple,
+                    CacheBucket::Wheels,
+                )
+            }
+            Self::SimilarPackages {
+                package,
+                suggestions,
+            } => {
+                let suggestions = suggestions
+                    .iter()
+                    .map(|suggestion| format!("`{}`", suggestion.cyan()))
+                    .collect::<Vec<_>>();
+                let suggestions = match suggestions.as_slice() {
+                    [] => String::new(),
+                    [suggestion] => suggestion.clone(),
+                    [first, second] => format!("{first} or {second}"),
+                    [rest @ .., last] => format!("{}, or {last}", rest.join(", ")),
+                };
+                write!(
+                    f,
+                    "{}{} `{}` was not found in the package registry; did you mean {}?",
                     "hint".bold().cyan(),
                     ":".bold(),
+                    package.cyan(),
+                    suggestions,
                 )
             }
             Self::InvalidPackageMetadata { package, reason } => {
@@ -1717,7 +1898,11 @@ impl std::fmt::Display for PubGrubHint {
                     "403 Forbidden".red(),
                 )
             }
-            Self::NoBuild { package, option } => {
+            Self::NoBuild {
+                package,
+                option,
+                wheels,
+            } => {
                 let option = match option {
                     NoBuild::All => "for all packages (i.e., with `--no-build`)".to_string(),
                     NoBuild::Packages(_) => {
@@ -1727,10 +1912,11 @@ impl std::fmt::Display for PubGrubHint {
                 };
                 write!(
                     f,
-                    "{}{} Wheels are required for `{}` because building from source is disabled {option}",
+                    "{}{} Wheels are required for `{}` because building from source is disabled {option}{}",
                     "hint".bold().cyan(),
                     ":".bold(),
                     package.cyan(),
+                    wheels_suffix(package, wheels),
                 )
             }
             Self::NoBinary { package, option } => {
@@ -1754,7 +1940,9 @@ impl std::fmt::Display for PubGrubHint {
                 version,
                 tags,
                 best,
+                platforms,
             } => {
+                let platforms = platforms_suffix(platforms);
                 if let Some(best) = best {
                     let s = if tags.len() == 1 { "" } else { "s" };
                     let best = if let Some(pretty) = best.pretty() {
@@ -1764,7 +1952,7 @@ impl std::fmt::Display for PubGrubHint {
                     };
                     write!(
                         f,
-                        "{}{} You require {}, but we only found wheels for `{}` ({}) with the following Python implementation tag{s}: {}",
+                        "{}{} You require {}, but we only found wheels for `{}` ({}) with the following Python implementation tag{s}: {}{platforms}",
                         "hint".bold().cyan(),
                         ":".bold(),
                         best,
@@ -1778,7 +1966,7 @@ impl std::fmt::Display for PubGrubHint {
                     let s = if tags.len() == 1 { "" } else { "s" };
                     write!(
                         f,
-                        "{}{} Wheels are available for `{}` ({}) with the following Python implementation tag{s}: {}",
+                        "{}{} Wheels are available for `{}` ({}) with the following Python implementation tag{s}: {}{platforms}",
                         "hint".bold().cyan(),
                         ":".bold(),
                         package.cyan(),
@@ -1794,7 +1982,9 @@ impl std::fmt::Display for PubGrubHint {
                 version,
                 tags,
                 best,
+                platforms,
             } => {
+                let platforms = platforms_suffix(platforms);
                 if let Some(best) = best {
                     let s = if tags.len() == 1 { "" } else { "s" };
                     let best = if let Some(pretty) = best.pretty() {
@@ -1804,7 +1994,7 @@ impl std::fmt::Display for PubGrubHint {
                     };
                     write!(
                         f,
-                        "{}{} You require {}, but we only found wheels for `{}` ({}) with the following Python ABI tag{s}: {}",
+                        "{}{} You require {}, but we only found wheels for `{}` ({}) with the following Python ABI tag{s}: {}{platforms}",
                         "hint".bold().cyan(),
                         ":".bold(),
                         best,
@@ -1818,7 +2008,7 @@ impl std::fmt::Display for PubGrubHint {
                     let s = if tags.len() == 1 { "" } else { "s" };
                     write!(
                         f,
-                        "{}{} Wheels are available for `{}` ({}) with the following Python ABI tag{s}: {}",
+                        "{}{} Wheels are available for `{}` ({}) with the following Python ABI tag{s}: {}{platforms}",
                         "hint".bold().cyan(),
                         ":".bold(),
                         package.cyan(),
@@ -1,5 +1,3 @@
-use std::collections::BTreeSet;
-
 use owo_colors::OwoColorize;
 use petgraph::visit::EdgeRef;
 use petgraph::{Directed, Direction, Graph};
@@ -8,6 +6,7 @@ use rustc_hash::{FxBuildHasher, FxHashMap};
 use uv_distribution_types::{DistributionMetadata, Name, SourceAnnotation, SourceAnnotations};
 use uv_normalize::PackageName;
 use uv_pep508::MarkerTree;
+use uv_redacted::redact_credentials;
 
 use crate::resolution::{RequirementsTxtDist, ResolutionGraphNode};
 use crate::{ResolverEnvironment, ResolverOutput};
@@ -218,18 +217,21 @@ impl std::fmt::Display for DisplayResolutionGraph<'_> {
                     dependents
                 };
 
-                // Include all external sources (e.g., requirements files).
-                let default = BTreeSet::default();
-                let source = sources.get(node.name()).unwrap_or(&default);
+                // Include all external sources (e.g., requirements files), without any
+                // credentials (e.g., in the URL of a remote requirements file).
+                let source = sources
+                    .get(node.name())
+                    .into_iter()
+                    .flatten()
+                    .map(|source| redact_credentials(&source.to_string()).into_owned())
+                    .collect::<Vec<_>>();
 
                 match self.annotation_style {
                     AnnotationStyle::Line => match dependents.as_slice() {
                         [] if source.is_empty() => {}
                         [] if source.len() == 1 => {
                             let separator = if has_hashes { "\n    " } else { "  " };
-                            let comment = format!("# via {}", source.iter().next().unwrap())
-                                .green()
-                                .to_string();
+                            let comment = format!("# via {}", source[0]).green().to_string();
                             annotation = Some((separator, comment));
                         }
                         dependents => {
@@ -237,7 +239,7 @@ impl std::fmt::Display for DisplayResolutionGraph<'_> {
                             let dependents = dependents
                                 .iter()
                                 .map(ToString::to_string)
-                                .chain(source.iter().map(ToString::to_string))
+                                .chain(source.iter().cloned())
                                 .collect::<Vec<_>>()
                                 .join(", ");
                             let comment = format!("# via {dependents}").green().to_string();
@@ -248,9 +250,7 @@ impl std::fmt::Display for DisplayResolutionGraph<'_> {
                         [] if source.is_empty() => {}
                         [] if source.len() == 1 => {
                             let separator = "\n";
-                            let comment = format!("    # via {}", source.iter().next().unwrap())
-                                .green()
-                                .to_string();
+                            let comment = format!("    # via {}", source[0]).green().to_string();
                             annotation = Some((separator, comment));
                         }
                         [dependent] if source.is_empty() => {
@@ -262,7 +262,7 @@ impl std::fmt::Display for DisplayResolutionGraph<'_> {
                             let separator = "\n";
                             let dependent = source
                                 .iter()
-                                .map(ToString::to_string)
+                                .cloned()
                                 .chain(dependents.iter().map(ToString::to_string))
                                 .map(|name| format!("    #   {name}"))
                                 .collect::<Vec<_>>()
@@ -285,6 +285,23 @@ impl std::fmt::Display for DisplayResolutionGraph<'_> {
                 writeln!(f, "{line}")?;
             }
 
+            // If enabled, indicate any pre-releases that were allowed by a package-specific
+            // policy, as opposed to the pre-release strategy.
+            if self.include_annotations
+                && node.version.any_prerelease()
+                && self
+                    .resolution
+                    .options
+                    .prerelease_packages
+                    .contains(node.name())
+            {
+                writeln!(
+                    f,
+                    "{}",
+                    "    # pre-release allowed by `prerelease-packages`".green()
+                )?;
+            }
+
             // If enabled, include indexes to indicate which index was used for each package (e.g.,
             // `# from https://pypi.org/simple`).
             if self.include_index_annotation {
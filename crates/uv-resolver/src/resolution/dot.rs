@@ -0,0 +1,128 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::{Display, Formatter};
+
+use itertools::Itertools;
+use petgraph::visit::EdgeRef;
+
+use crate::ResolverOutput;
+use crate::resolution::{AnnotatedDist, ResolutionGraphNode};
+
+/// A [`std::fmt::Display`] implementation that renders a resolution as a Graphviz DOT graph.
+///
+/// Each node represents a selected package version, with its extras and dependency groups merged
+/// into the base package. Each edge is labeled with the requirements that introduced it,
+/// including their specifiers and markers.
+#[derive(Debug)]
+pub struct DisplayResolutionDot<'a> {
+    /// The underlying resolution.
+    resolution: &'a ResolverOutput,
+}
+
+impl<'a> From<&'a ResolverOutput> for DisplayResolutionDot<'a> {
+    fn from(resolution: &'a ResolverOutput) -> Self {
+        Self { resolution }
+    }
+}
+
+impl DisplayResolutionDot<'_> {
+    /// Return the labels for the edge from `parent` (or the root, if `None`) to `child`.
+    fn labels(&self, parent: Option<&AnnotatedDist>, child: &AnnotatedDist) -> BTreeSet<String> {
+        let labels = match parent {
+            None => self
+                .resolution
+                .requirements
+                .iter()
+                .filter(|requirement| requirement.name == child.name)
+                .map(ToString::to_string)
+                .collect::<BTreeSet<_>>(),
+            Some(parent) => parent
+                .metadata
+                .iter()
+                .flat_map(|metadata| metadata.requires_dist.iter())
+                .filter(|requirement| requirement.name == child.name)
+                .map(ToString::to_string)
+                .collect::<BTreeSet<_>>(),
+        };
+        if labels.is_empty() {
+            BTreeSet::from([child.name.to_string()])
+        } else {
+            labels
+        }
+    }
+}
+
+impl Display for DisplayResolutionDot<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let graph = &self.resolution.graph;
+
+        let mut nodes = BTreeSet::new();
+        let mut edges: BTreeMap<(String, String), BTreeSet<String>> = BTreeMap::new();
+        for index in graph.node_indices() {
+            if let ResolutionGraphNode::Dist(dist) = &graph[index] {
+                nodes.insert(node_id(dist));
+            }
+        }
+        for edge in graph.edge_references() {
+            let ResolutionGraphNode::Dist(child) = &graph[edge.target()] else {
+                continue;
+            };
+            let parent = match &graph[edge.source()] {
+                ResolutionGraphNode::Root => None,
+                ResolutionGraphNode::Dist(parent) => Some(parent),
+            };
+
+            // Skip the edges between a package and its own extras and groups.
+            if parent.is_some_and(|parent| parent.name == child.name) {
+                continue;
+            }
+
+            let source = parent.map_or_else(|| ROOT.to_string(), node_id);
+            edges
+                .entry((source, node_id(child)))
+                .or_default()
+                .extend(self.labels(parent, child));
+        }
+
+        writeln!(f, "digraph {{")?;
+        writeln!(f, "    {} [shape=box];", quote(ROOT))?;
+        for node in &nodes {
+            writeln!(f, "    {};", quote(node))?;
+        }
+        for ((source, target), labels) in &edges {
+            writeln!(
+                f,
+                "    {} -> {} [label={}];",
+                quote(source),
+                quote(target),
+                quote(&labels.iter().join("\n"))
+            )?;
+        }
+        writeln!(f, "}}")?;
+
+        Ok(())
+    }
+}
+
+/// The identifier of the node representing the user's requirements.
+const ROOT: &str = "requirements";
+
+/// Return the identifier of the node for a distribution, e.g., `flask==3.0.0`.
+fn node_id(dist: &AnnotatedDist) -> String {
+    format!("{}=={}", dist.name, dist.version)
+}
+
+/// Quote a string as a DOT identifier, escaping any quotes, backslashes, and newlines.
+pub(crate) fn quote(value: &str) -> String {
+    let mut quoted = String::with_capacity(value.len() + 2);
+    quoted.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
@@ -0,0 +1,236 @@
+use std::collections::{BTreeSet, VecDeque};
+use std::fmt::{Display, Formatter};
+
+use owo_colors::OwoColorize;
+use petgraph::Direction;
+use petgraph::graph::NodeIndex;
+use petgraph::visit::EdgeRef;
+use rustc_hash::FxHashSet;
+
+use uv_distribution_types::{Requirement, SourceAnnotation};
+use uv_normalize::PackageName;
+use uv_pep508::RequirementOrigin;
+
+use crate::ResolverOutput;
+use crate::resolution::{AnnotatedDist, ResolutionGraphNode};
+
+/// A [`std::fmt::Display`] implementation that explains why a package was selected in a
+/// resolution.
+///
+/// For each selected version of the package, the explanation lists the requirements that led to
+/// its inclusion, along with the shortest chain of requirements from each dependent back to the
+/// user's requirements, followed by any constraints and overrides that applied to it.
+#[derive(Debug)]
+pub struct DisplayExplanation<'a> {
+    /// The underlying resolution.
+    resolution: &'a ResolverOutput,
+    /// The package to explain.
+    package: &'a PackageName,
+}
+
+impl<'a> DisplayExplanation<'a> {
+    /// Create a new [`DisplayExplanation`] for the given package, or `None` if the package is not
+    /// included in the resolution.
+    pub fn new(resolution: &'a ResolverOutput, package: &'a PackageName) -> Option<Self> {
+        if resolution.contains(package) {
+            Some(Self {
+                resolution,
+                package,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Return the distribution at the given node, or `None` for the root.
+    fn dist(&self, index: NodeIndex) -> Option<&'a AnnotatedDist> {
+        match &self.resolution.graph[index] {
+            ResolutionGraphNode::Root => None,
+            ResolutionGraphNode::Dist(dist) => Some(dist),
+        }
+    }
+
+    /// Find the shortest path from the given node to the root, excluding the node itself.
+    fn path_to_root(&self, start: NodeIndex) -> Vec<NodeIndex> {
+        let graph = &self.resolution.graph;
+        let mut queue = VecDeque::from([(start, Vec::new())]);
+        let mut seen = FxHashSet::default();
+        while let Some((index, path)) = queue.pop_front() {
+            if !seen.insert(index) {
+                continue;
+            }
+            if matches!(graph[index], ResolutionGraphNode::Root) {
+                return path;
+            }
+            for edge in graph.edges_directed(index, Direction::Incoming) {
+                let mut path = path.clone();
+                path.push(edge.source());
+                queue.push_back((edge.source(), path));
+            }
+        }
+        Vec::new()
+    }
+
+    /// Write the requirements declared by `parent` on `child`, indented to the given depth.
+    fn write_requirements(
+        &self,
+        f: &mut Formatter<'_>,
+        child: &PackageName,
+        parent: NodeIndex,
+        depth: usize,
+    ) -> std::fmt::Result {
+        let indent = "  ".repeat(depth);
+        match self.dist(parent) {
+            None => {
+                for requirement in &self.resolution.requirements {
+                    if requirement.name != *child {
+                        continue;
+                    }
+                    if let Some(origin) = &requirement.origin {
+                        let source = SourceAnnotation::Requirement(origin.clone());
+                        writeln!(f, "{indent}{} required by {source}", requirement.cyan())?;
+                    } else {
+                        writeln!(f, "{indent}{} requested", requirement.cyan())?;
+                    }
+                }
+            }
+            Some(dist) => {
+                let requirements = dist
+                    .metadata
+                    .iter()
+                    .flat_map(|metadata| metadata.requires_dist.iter())
+                    .filter(|requirement| requirement.name == *child)
+                    .collect::<Vec<_>>();
+                if requirements.is_empty() {
+                    writeln!(
+                        f,
+                        "{indent}{} required by {}",
+                        child.cyan(),
+                        display_dist(dist).bold()
+                    )?;
+                }
+                for requirement in requirements {
+                    writeln!(
+                        f,
+                        "{indent}{} required by {}",
+                        requirement.cyan(),
+                        display_dist(dist).bold()
+                    )?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Write the constraints or overrides that applied to the package.
+    fn write_sources(
+        f: &mut Formatter<'_>,
+        requirements: Option<&Vec<Requirement>>,
+        verb: &str,
+        annotation: fn(RequirementOrigin) -> SourceAnnotation,
+    ) -> std::fmt::Result {
+        for requirement in requirements.into_iter().flatten() {
+            if let Some(origin) = &requirement.origin {
+                let source = annotation(origin.clone());
+                writeln!(f, "  {} {verb} by {source}", requirement.cyan())?;
+            } else {
+                writeln!(f, "  {} {verb}", requirement.cyan())?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Display for DisplayExplanation<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let graph = &self.resolution.graph;
+
+        // Identify each selected version of the package.
+        let versions = graph
+            .node_weights()
+            .filter_map(|node| match node {
+                ResolutionGraphNode::Dist(dist) if dist.name == *self.package && dist.is_base() => {
+                    Some(&dist.version)
+                }
+                _ => None,
+            })
+            .collect::<BTreeSet<_>>();
+
+        for (position, version) in versions.into_iter().enumerate() {
+            if position > 0 {
+                writeln!(f)?;
+            }
+            writeln!(f, "{}", format!("{}=={version}", self.package).bold())?;
+
+            // Find the dependents of any node for this version, including its extras and groups,
+            // ignoring the edges between a package and its own extras.
+            let mut parents = BTreeSet::new();
+            for index in graph.node_indices() {
+                let Some(dist) = self.dist(index) else {
+                    continue;
+                };
+                if dist.name != *self.package || dist.version != *version {
+                    continue;
+                }
+                for edge in graph.edges_directed(index, Direction::Incoming) {
+                    if self
+                        .dist(edge.source())
+                        .is_none_or(|parent| parent.name != *self.package)
+                    {
+                        parents.insert(edge.source());
+                    }
+                }
+            }
+
+            for parent in parents {
+                self.write_requirements(f, self.package, parent, 1)?;
+
+                // Walk back to the root along the shortest path, skipping the edges between a
+                // package and its own extras.
+                let mut child = parent;
+                let mut depth = 2;
+                for ancestor in self.path_to_root(parent) {
+                    let Some(name) = self.dist(child).map(|dist| &dist.name) else {
+                        break;
+                    };
+                    if self
+                        .dist(ancestor)
+                        .is_some_and(|ancestor| ancestor.name == *name)
+                    {
+                        child = ancestor;
+                        continue;
+                    }
+                    self.write_requirements(f, name, ancestor, depth)?;
+                    child = ancestor;
+                    depth += 1;
+                }
+            }
+
+            Self::write_sources(
+                f,
+                self.resolution.constraints.get(self.package),
+                "constrained",
+                SourceAnnotation::Constraint,
+            )?;
+            Self::write_sources(
+                f,
+                self.resolution.overrides.get(self.package),
+                "overridden",
+                SourceAnnotation::Override,
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Format a distribution in the resolution graph, e.g., `flask[dotenv]==3.0.0`.
+fn display_dist(dist: &AnnotatedDist) -> String {
+    if let Some(extra) = &dist.extra {
+        format!("{}[{extra}]=={}", dist.name, dist.version)
+    } else if let Some(group) = &dist.group {
+        format!("{}:{group}=={}", dist.name, dist.version)
+    } else {
+        format!("{}=={}", dist.name, dist.version)
+    }
+}
@@ -167,6 +167,19 @@ impl ResolverOutput {
             }
         }
 
+        // Omit warnings for any yanked versions that were explicitly allowed.
+        diagnostics.retain(|diagnostic| {
+            let ResolutionDiagnostic::YankedVersion { dist, .. } = diagnostic else {
+                return true;
+            };
+            !dist.version().is_some_and(|version| {
+                options
+                    .allow_yanked
+                    .iter()
+                    .any(|entry| entry.matches(dist.name(), version))
+            })
+        });
+
         let mut seen = FxHashSet::default();
         for resolution in resolutions {
             let marker = resolution.env.try_universal_markers().unwrap_or_default();
@@ -9,7 +9,9 @@ pub enum ResolutionMode {
     /// Resolve the highest compatible version of each package.
     #[default]
     Highest,
-    /// Resolve the lowest compatible version of each package.
+    /// Resolve the lowest compatible version of each package, including transitive dependencies.
+    #[serde(alias = "lowest-transitive")]
+    #[cfg_attr(feature = "clap", value(alias = "lowest-transitive"))]
     Lowest,
     /// Resolve the lowest compatible version of any direct dependencies, and the highest
     /// compatible version of any transitive dependencies.
@@ -2,7 +2,7 @@ use std::fmt::{Display, Formatter};
 use std::iter;
 use std::sync::Arc;
 
-use uv_distribution_types::IncompatibleDist;
+use uv_distribution_types::{IncompatibleDist, IndexMetadata};
 use uv_pep440::{Version, VersionSpecifiers};
 use uv_platform_tags::{AbiTag, Tags};
 
@@ -152,12 +152,15 @@ pub enum UnavailablePackage {
     NoIndex,
     /// Network requests were disabled (i.e., `--offline`), and the package was not found in the cache.
     Offline,
-    /// The package was not found in the registry.
-    NotFound,
+    /// The package was not found in the registry, along with the explicit index that was queried,
+    /// if any.
+    NotFound(Option<IndexMetadata>),
     /// The package metadata was found, but could not be parsed.
     InvalidMetadata(UnavailableErrorChain),
     /// The package has an invalid structure.
     InvalidStructure(UnavailableErrorChain),
+    /// The package was excluded by the `excluded-packages` setting.
+    Excluded,
 }
 
 impl UnavailablePackage {
@@ -165,9 +168,10 @@ impl UnavailablePackage {
         match self {
             Self::NoIndex => "not found in the provided package locations",
             Self::Offline => "not found in the cache",
-            Self::NotFound => "not found in the package registry",
+            Self::NotFound(_) => "not found in the package registry",
             Self::InvalidMetadata(_) => "invalid metadata",
             Self::InvalidStructure(_) => "an invalid package format",
+            Self::Excluded => "excluded by the `excluded-packages` setting",
         }
     }
 
@@ -175,9 +179,10 @@ impl UnavailablePackage {
         match self {
             Self::NoIndex => format!("was {self}"),
             Self::Offline => format!("was {self}"),
-            Self::NotFound => format!("was {self}"),
+            Self::NotFound(_) => format!("was {self}"),
             Self::InvalidMetadata(_) => format!("has {self}"),
             Self::InvalidStructure(_) => format!("has {self}"),
+            Self::Excluded => format!("was {self}"),
         }
     }
 }
@@ -34,7 +34,11 @@ enum BatchPrefetchStrategy {
     InOrder { previous: Version },
 }
 
-/// Prefetch a large number of versions if we already unsuccessfully tried many versions.
+/// The number of adjacent versions to prefetch once the resolver starts backtracking on a package.
+const BACKTRACKING_PREFETCH: usize = 5;
+
+/// Prefetch the adjacent versions once we start backtracking on a package, and a large number of
+/// versions if we already unsuccessfully tried many versions.
 ///
 /// This is an optimization specifically targeted at cold cache urllib3/boto3/botocore, where we
 /// have to fetch the metadata for a lot of versions.
@@ -77,7 +81,8 @@ impl BatchPrefetcher {
         }
     }
 
-    /// Prefetch a large number of versions if we already unsuccessfully tried many versions.
+    /// Prefetch the adjacent versions once we start backtracking on a package, and a large number
+    /// of versions if we already unsuccessfully tried many versions.
     pub(crate) fn prefetch_batches(
         &mut self,
         next: &PubGrubPackage,
@@ -99,11 +104,9 @@ impl BatchPrefetcher {
             return Ok(());
         };
 
-        let (num_tried, do_prefetch) = self.should_prefetch(next);
-        if !do_prefetch {
+        let Some((num_tried, total_prefetch)) = self.should_prefetch(next, version) else {
             return Ok(());
-        }
-        let total_prefetch = min(num_tried, 50);
+        };
 
         // This is immediate, we already fetched the version map.
         let versions_response = if let Some(index) = index {
@@ -111,13 +114,12 @@ impl BatchPrefetcher {
                 .index
                 .explicit()
                 .wait_blocking(&(name.clone(), index.url().clone()))
-                .ok_or_else(|| ResolveError::UnregisteredTask(name.to_string()))?
         } else {
-            self.prefetch_runner
-                .index
-                .implicit()
-                .wait_blocking(name)
-                .ok_or_else(|| ResolveError::UnregisteredTask(name.to_string()))?
+            self.prefetch_runner.index.implicit().wait_blocking(name)
+        };
+        // If the version map was evicted to stay within the memory budget, skip the prefetch.
+        let Some(versions_response) = versions_response else {
+            return Ok(());
         };
 
         let phase = BatchPrefetchStrategy::Compatible {
@@ -159,10 +161,15 @@ impl BatchPrefetcher {
             .insert(version.clone());
     }
 
+    /// Determine whether to prefetch versions of the package, returning the number of versions
+    /// tried so far and the number of versions to prefetch.
+    ///
+    /// As soon as we backtrack on a package (i.e., we're trying a different version than one we
+    /// already tried), prefetch the next few versions, since we'll likely have to try them, too.
     /// After 5, 10, 20, 40 tried versions, prefetch that many versions to start early but not
     /// too aggressive. Later we schedule the prefetch of 50 versions every 20 versions, this gives
     /// us a good buffer until we see prefetch again and is high enough to saturate the task pool.
-    fn should_prefetch(&self, next: &PubGrubPackage) -> (usize, bool) {
+    fn should_prefetch(&self, next: &PubGrubPackage, version: &Version) -> Option<(usize, usize)> {
         let PubGrubPackageInner::Package {
             name,
             extra: None,
@@ -170,16 +177,23 @@ impl BatchPrefetcher {
             marker: MarkerTree::TRUE,
         } = &**next
         else {
-            return (0, false);
+            return None;
         };
 
-        let num_tried = self.tried_versions.get(name).map_or(0, FxHashSet::len);
+        let tried = self.tried_versions.get(name);
+        let num_tried = tried.map_or(0, FxHashSet::len);
         let previous_prefetch = self.last_prefetch.get(name).copied().unwrap_or_default();
+
+        let backtracking = tried.is_some_and(|tried| !tried.contains(version));
+        if backtracking && previous_prefetch == 0 {
+            return Some((num_tried, BACKTRACKING_PREFETCH));
+        }
+
         let do_prefetch = (num_tried >= 5 && previous_prefetch < 5)
             || (num_tried >= 10 && previous_prefetch < 10)
             || (num_tried >= 20 && previous_prefetch < 20)
             || (num_tried >= 20 && num_tried - previous_prefetch >= 20);
-        (num_tried, do_prefetch)
+        do_prefetch.then(|| (num_tried, min(num_tried, 50)))
     }
 
     /// Log stats about how many versions we tried.
@@ -360,3 +374,50 @@ fn satisfies_python(dist: &CompatibleDist, python_requirement: &PythonRequiremen
 
     true
 }
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    #[test]
+    fn prefetch_on_backtracking() {
+        let (request_sink, _request_stream) = tokio::sync::mpsc::channel(1);
+        let mut prefetcher = BatchPrefetcher::new(
+            IndexCapabilities::default(),
+            InMemoryIndex::default(),
+            request_sink,
+        );
+        let name = PackageName::from_str("botocore").unwrap();
+        let package = PubGrubPackage::from_package(name.clone(), None, None, MarkerTree::TRUE);
+
+        // The first version isn't prefetched, nor is re-trying the same version.
+        let first = Version::new([1, 0, 10]);
+        assert_eq!(prefetcher.should_prefetch(&package, &first), None);
+        prefetcher.version_tried(&package, &first);
+        assert_eq!(prefetcher.should_prefetch(&package, &first), None);
+
+        // Once we backtrack to a different version, the adjacent versions are prefetched, once.
+        let second = Version::new([1, 0, 9]);
+        assert_eq!(
+            prefetcher.should_prefetch(&package, &second),
+            Some((1, BACKTRACKING_PREFETCH))
+        );
+        prefetcher.last_prefetch.insert(name, 1);
+        prefetcher.version_tried(&package, &second);
+        assert_eq!(
+            prefetcher.should_prefetch(&package, &Version::new([1, 0, 8])),
+            None
+        );
+
+        // After five versions, the batch prefetching kicks in.
+        for patch in (5..8).rev() {
+            prefetcher.version_tried(&package, &Version::new([1, 0, patch]));
+        }
+        assert_eq!(
+            prefetcher.should_prefetch(&package, &Version::new([1, 0, 4])),
+            Some((5, 5))
+        );
+    }
+}
@@ -3,9 +3,11 @@ use rustc_hash::FxHashMap;
 
 use uv_distribution_types::{DerivationChain, DerivationStep};
 use uv_pep440::Version;
+use uv_pep508::MarkerTree;
 
 use crate::dependency_provider::UvDependencyProvider;
 use crate::pubgrub::PubGrubPackage;
+use crate::resolver::ResolverEnvironment;
 
 /// Build a [`DerivationChain`] from the pubgrub state, which is available in `uv-resolver`, but not
 /// in `uv-distribution-types`.
@@ -15,11 +17,14 @@ pub struct DerivationChainBuilder;
 impl DerivationChainBuilder {
     /// Compute a [`DerivationChain`] from the current PubGrub state.
     ///
-    /// This is used to construct a derivation chain upon resolution failure.
+    /// This is used to construct a derivation chain upon resolution failure. The chain is annotated
+    /// with the markers under which the package was resolved: the markers of the current fork,
+    /// combined with the markers of each requirement along the chain.
     pub(crate) fn from_state(
         id: Id<PubGrubPackage>,
         version: &Version,
         state: &State<UvDependencyProvider>,
+        env: &ResolverEnvironment,
     ) -> Option<DerivationChain> {
         /// Find a path from the current package to the root package.
         fn find_path(
@@ -28,6 +33,7 @@ impl DerivationChainBuilder {
             state: &State<UvDependencyProvider>,
             solution: &FxHashMap<Id<PubGrubPackage>, Version>,
             path: &mut Vec<DerivationStep>,
+            markers: &mut Vec<MarkerTree>,
         ) -> bool {
             // Retrieve the incompatibilities for the current package.
             let Some(incompatibilities) = state.incompatibilities.get(&id) else {
@@ -44,10 +50,12 @@ impl DerivationChainBuilder {
                             let p2 = &state.package_store[*id2];
 
                             if p1.name_no_root() == p2.name_no_root() {
-                                // Skip proxied dependencies.
-                                if find_path(*id1, version, state, solution, path) {
+                                // Skip proxied dependencies, but retain their markers.
+                                markers.push(p1.marker());
+                                if find_path(*id1, version, state, solution, path, markers) {
                                     return true;
                                 }
+                                markers.pop();
                             } else if let Some(name) = p1.name_no_root() {
                                 // Add to the current path.
                                 path.push(DerivationStep::new(
@@ -57,14 +65,16 @@ impl DerivationChainBuilder {
                                     Some(version.clone()),
                                     v2.clone(),
                                 ));
+                                markers.push(p1.marker());
 
                                 // Recursively search the next package.
-                                if find_path(*id1, version, state, solution, path) {
+                                if find_path(*id1, version, state, solution, path, markers) {
                                     return true;
                                 }
 
                                 // Backtrack if the path didn't lead to the root.
                                 path.pop();
+                                markers.pop();
                             } else {
                                 // If we've reached the root, return.
                                 return true;
@@ -77,15 +87,27 @@ impl DerivationChainBuilder {
         }
 
         let solution: FxHashMap<_, _> = state.partial_solution.extract_solution().collect();
+        let mut markers = vec![state.package_store[id].marker()];
         let path = {
             let mut path = vec![];
-            if !find_path(id, version, state, &solution, &mut path) {
+            if !find_path(id, version, state, &solution, &mut path, &mut markers) {
                 return None;
             }
             path.reverse();
             path
         };
 
-        Some(path.into_iter().collect())
+        // In a universal resolution, combine the markers along the chain with those of the current
+        // fork. In a resolution for a specific environment, the markers are always satisfied.
+        let marker = if let Some(mut marker) = env.fork_markers() {
+            for step_marker in markers {
+                marker.and(step_marker);
+            }
+            marker
+        } else {
+            MarkerTree::TRUE
+        };
+
+        Some(DerivationChain::from_iter(path).with_marker(marker))
     }
 }
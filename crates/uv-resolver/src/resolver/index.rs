@@ -1,8 +1,11 @@
+use std::collections::BTreeMap;
 use std::hash::BuildHasherDefault;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 
-use rustc_hash::FxHasher;
-use uv_distribution_types::{DistributionId, IndexUrl};
+use rustc_hash::{FxHashMap, FxHashSet, FxHasher};
+use tracing::debug;
+
+use uv_distribution_types::{DistributionId, IndexMetadata, IndexUrl};
 use uv_normalize::PackageName;
 use uv_once_map::OnceMap;
 
@@ -22,6 +25,9 @@ struct SharedInMemoryIndex {
 
     /// A map from a concrete distribution to its metadata.
     distributions: FxOnceMap<DistributionId, Arc<MetadataResponse>>,
+
+    /// The memory budget for the package metadata.
+    budget: MemoryBudget,
 }
 
 pub(crate) type FxOnceMap<K, V> = OnceMap<K, V, BuildHasherDefault<FxHasher>>;
@@ -41,4 +47,207 @@ impl InMemoryIndex {
     pub fn distributions(&self) -> &FxOnceMap<DistributionId, Arc<MetadataResponse>> {
         &self.0.distributions
     }
+
+    /// Returns the metrics for the memory budget of the index.
+    pub fn memory_metrics(&self) -> MemoryMetrics {
+        self.0.budget.metrics()
+    }
+
+    /// Record an access to the package metadata for the given package and index, evicting the
+    /// metadata for the least recently used packages if the memory limit (in bytes) is exceeded.
+    ///
+    /// The metadata for the accessed package itself is never evicted.
+    pub(crate) fn touch_versions(
+        &self,
+        name: &PackageName,
+        index: Option<&IndexMetadata>,
+        response: &VersionsResponse,
+        limit: usize,
+    ) {
+        let key = VersionsKey {
+            name: name.clone(),
+            index: index.cloned(),
+        };
+        let evicted = self
+            .0
+            .budget
+            .touch(key, || response.approximate_size(), limit);
+        for key in evicted {
+            if let Some(index) = &key.index {
+                self.explicit().remove(&(key.name, index.url.clone()));
+            } else {
+                self.implicit().remove(&key.name);
+            }
+        }
+    }
+
+    /// Take the evicted package metadata entries for the packages that match the given predicate,
+    /// such that they can be re-loaded.
+    pub(crate) fn take_evicted(
+        &self,
+        predicate: impl Fn(&PackageName) -> bool,
+    ) -> Vec<(PackageName, Option<IndexMetadata>)> {
+        self.0.budget.take_evicted(predicate)
+    }
+}
+
+/// Metrics for the memory budget of an [`InMemoryIndex`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct MemoryMetrics {
+    /// The number of package metadata entries that were evicted.
+    pub evictions: usize,
+    /// The approximate number of bytes that were freed by evictions.
+    pub evicted_bytes: usize,
+    /// The number of evicted package metadata entries that were re-loaded.
+    pub reloads: usize,
+}
+
+/// The package metadata for a package on an index, as tracked by a [`MemoryBudget`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct VersionsKey {
+    name: PackageName,
+    index: Option<IndexMetadata>,
+}
+
+/// A memory budget for the package metadata in an [`InMemoryIndex`], which evicts the least
+/// recently used entries when exceeded.
+///
+/// Evicted entries can be re-loaded (e.g., from the HTTP cache) by registering them again.
+#[derive(Debug, Default)]
+struct MemoryBudget {
+    state: Mutex<MemoryBudgetState>,
+}
+
+#[derive(Debug, Default)]
+struct MemoryBudgetState {
+    /// A monotonically increasing counter, used to order accesses.
+    tick: u64,
+    /// The retained entries, along with the tick of their most recent access and their size.
+    entries: FxHashMap<VersionsKey, (u64, usize)>,
+    /// The retained entries, ordered by their most recent access.
+    order: BTreeMap<u64, VersionsKey>,
+    /// The approximate size of the retained entries, in bytes.
+    size: usize,
+    /// The entries that were evicted, and not yet re-loaded.
+    evicted: FxHashSet<VersionsKey>,
+    metrics: MemoryMetrics,
+}
+
+impl MemoryBudget {
+    /// Record an access to an entry, returning the entries to evict to stay within the budget.
+    fn touch(
+        &self,
+        key: VersionsKey,
+        size: impl FnOnce() -> usize,
+        limit: usize,
+    ) -> Vec<VersionsKey> {
+        let mut state = self.state.lock().unwrap();
+        state.tick += 1;
+        let tick = state.tick;
+
+        if let Some((previous, _)) = state.entries.get_mut(&key) {
+            let previous = std::mem::replace(previous, tick);
+            state.order.remove(&previous);
+            state.order.insert(tick, key);
+            return Vec::new();
+        }
+
+        if state.evicted.remove(&key) {
+            debug!("Re-loaded evicted metadata for: {}", key.name);
+            state.metrics.reloads += 1;
+        }
+        let size = size();
+        state.size += size;
+        state.entries.insert(key.clone(), (tick, size));
+        state.order.insert(tick, key);
+
+        // Evict the least recently used entries, other than the one that was just accessed.
+        let mut evicted = Vec::new();
+        while state.size > limit && state.order.len() > 1 {
+            let Some((_, key)) = state.order.pop_first() else {
+                break;
+            };
+            let Some((_, size)) = state.entries.remove(&key) else {
+                continue;
+            };
+            debug!(
+                "Evicting metadata for {} ({size} bytes) to stay within the memory limit",
+                key.name
+            );
+            state.size -= size;
+            state.metrics.evictions += 1;
+            state.metrics.evicted_bytes += size;
+            state.evicted.insert(key.clone());
+            evicted.push(key);
+        }
+        evicted
+    }
+
+    /// Take the evicted entries that match the given predicate.
+    fn take_evicted(
+        &self,
+        predicate: impl Fn(&PackageName) -> bool,
+    ) -> Vec<(PackageName, Option<IndexMetadata>)> {
+        let mut state = self.state.lock().unwrap();
+        let taken = state
+            .evicted
+            .iter()
+            .filter(|key| predicate(&key.name))
+            .cloned()
+            .collect::<Vec<_>>();
+        for key in &taken {
+            state.evicted.remove(key);
+        }
+        state.metrics.reloads += taken.len();
+        taken
+            .into_iter()
+            .map(|VersionsKey { name, index }| (name, index))
+            .collect()
+    }
+
+    /// Returns the metrics for the budget.
+    fn metrics(&self) -> MemoryMetrics {
+        self.state.lock().unwrap().metrics
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    fn key(name: &str) -> VersionsKey {
+        VersionsKey {
+            name: PackageName::from_str(name).unwrap(),
+            index: None,
+        }
+    }
+
+    #[test]
+    fn evict_least_recently_used() {
+        let budget = MemoryBudget::default();
+
+        assert!(budget.touch(key("a"), || 40, 100).is_empty());
+        assert!(budget.touch(key("b"), || 40, 100).is_empty());
+
+        // Accessing `a` again makes `b` the least recently used entry.
+        assert!(budget.touch(key("a"), || unreachable!(), 100).is_empty());
+        assert_eq!(budget.touch(key("c"), || 40, 100), vec![key("b")]);
+
+        // The most recently accessed entry is retained, even if it exceeds the budget on its own.
+        assert_eq!(
+            budget.touch(key("d"), || 200, 100),
+            vec![key("a"), key("c")]
+        );
+
+        let metrics = budget.metrics();
+        assert_eq!(metrics.evictions, 3);
+        assert_eq!(metrics.evicted_bytes, 120);
+
+        // Re-loading an evicted entry is tracked.
+        let reloaded = budget.take_evicted(|name| name.as_ref() == "b");
+        assert_eq!(reloaded, vec![(PackageName::from_str("b").unwrap(), None)]);
+        assert_eq!(budget.metrics().reloads, 1);
+    }
 }
@@ -35,6 +35,7 @@ use uv_pep508::{
     MarkerEnvironment, MarkerExpression, MarkerOperator, MarkerTree, MarkerValueString,
 };
 use uv_platform_tags::{IncompatibleTag, Tags};
+use uv_preview::PreviewFeature;
 use uv_pypi_types::{ConflictItem, ConflictItemRef, ConflictKindRef, Conflicts, VerbatimParsedUrl};
 use uv_torch::TorchStrategy;
 use uv_types::{BuildContext, HashStrategy, InstalledPackagesProvider};
@@ -42,10 +43,11 @@ use uv_warnings::warn_user_once;
 
 use crate::candidate_selector::{Candidate, CandidateDist, CandidateSelector};
 use crate::dependency_provider::UvDependencyProvider;
-use crate::error::{NoSolutionError, ResolveError};
+use crate::error::{BacktrackLimitError, NoSolutionError, ResolveError};
 use crate::fork_indexes::ForkIndexes;
 use crate::fork_strategy::ForkStrategy;
 use crate::fork_urls::ForkUrls;
+use crate::history::SelectionHistory;
 use crate::manifest::Manifest;
 use crate::pins::FilePins;
 use crate::preferences::{PreferenceSource, Preferences};
@@ -66,7 +68,7 @@ use crate::resolver::environment::{
     ForkingPossibility, fork_version_by_marker, fork_version_by_python_requirement,
 };
 pub(crate) use crate::resolver::fork_map::{ForkMap, ForkSet};
-pub use crate::resolver::index::InMemoryIndex;
+pub use crate::resolver::index::{InMemoryIndex, MemoryMetrics};
 use crate::resolver::indexes::Indexes;
 pub use crate::resolver::provider::{
     DefaultResolverProvider, MetadataResponse, PackageVersionsResult, ResolverProvider,
@@ -136,6 +138,8 @@ struct ResolverState<InstalledPackages: InstalledPackagesProvider> {
     incomplete_packages: DashMap<PackageName, DashMap<Version, MetadataUnavailable>>,
     /// The options that were used to configure this resolver.
     options: Options,
+    /// The versions selected in prior resolutions, used to prioritize metadata fetches.
+    history: SelectionHistory,
     /// The reporter to use for this resolver.
     reporter: Option<Arc<dyn Reporter>>,
 }
@@ -181,14 +185,19 @@ impl<'a, Context: BuildContext, InstalledPackages: InstalledPackagesProvider>
             flat_index,
             tags,
             python_requirement.target(),
-            AllowedYanks::from_manifest(&manifest, &env, options.dependency_mode),
+            AllowedYanks::from_manifest(
+                &manifest,
+                &env,
+                options.dependency_mode,
+                &options.allow_yanked,
+            ),
             hasher,
             options.exclude_newer.clone(),
             build_context.build_options(),
             build_context.capabilities(),
         );
 
-        Self::new_custom_io(
+        let mut resolver = Self::new_custom_io(
             manifest,
             options,
             hasher,
@@ -203,7 +212,14 @@ impl<'a, Context: BuildContext, InstalledPackages: InstalledPackagesProvider>
             build_context.locations(),
             provider,
             installed_packages,
-        )
+        )?;
+
+        if uv_preview::is_enabled(PreviewFeature::ResolutionHistory) {
+            resolver.state.history =
+                SelectionHistory::read(build_context.cache(), build_context.locations());
+        }
+
+        Ok(resolver)
     }
 }
 
@@ -254,6 +270,7 @@ impl<Provider: ResolverProvider, InstalledPackages: InstalledPackagesProvider>
             unavailable_packages: DashMap::default(),
             incomplete_packages: DashMap::default(),
             options,
+            history: SelectionHistory::default(),
             reporter: None,
         };
         Ok(Self { state, provider })
@@ -274,6 +291,7 @@ impl<Provider: ResolverProvider, InstalledPackages: InstalledPackagesProvider>
     }
 
     /// Resolve a set of requirements into a set of pinned versions.
+    #[instrument(skip_all)]
     pub async fn resolve(self) -> Result<ResolverOutput, ResolveError> {
         let state = Arc::new(self.state);
         let provider = Arc::new(self.provider);
@@ -305,7 +323,42 @@ impl<Provider: ResolverProvider, InstalledPackages: InstalledPackagesProvider>
         let ((), resolution) = tokio::try_join!(requests_fut, resolve_fut)?;
 
         state.on_complete();
-        resolution
+
+        let metrics = state.index.memory_metrics();
+        if metrics.evictions > 0 {
+            debug!(
+                "Evicted metadata {} time(s) ({} bytes) to stay within the memory limit, and re-loaded it {} time(s)",
+                metrics.evictions, metrics.evicted_bytes, metrics.reloads
+            );
+        }
+
+        // Record the selected versions, to prioritize them in subsequent resolutions.
+        if let Ok(resolution) = &resolution
+            && let Err(err) = state.history.write(resolution)
+        {
+            warn!("Failed to write resolution history: {err}");
+        }
+
+        // Suggest similarly-named packages for any packages that weren't found. This requires
+        // fetching the list of all projects from each index, so it's deferred until we know that
+        // the resolution failed.
+        match resolution {
+            Err(ResolveError::NoSolution(mut err)) => {
+                let not_found = err
+                    .not_found()
+                    .map(|(name, index)| (name.clone(), index.cloned()))
+                    .collect::<Vec<_>>();
+                for (name, index) in not_found {
+                    let suggestions = provider
+                        .similar_packages(&name, index.as_ref())
+                        .boxed_local()
+                        .await;
+                    err.set_suggestions(name, suggestions);
+                }
+                Err(ResolveError::NoSolution(err))
+            }
+            resolution => resolution,
+        }
     }
 }
 
@@ -341,6 +394,7 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
             self.env.clone(),
             self.python_requirement.clone(),
             prefetcher,
+            PubGrubPriorities::new(&self.options.resolution_priority),
         );
         let mut preferences = self.preferences.clone();
         let mut forked_states = self.env.initial_forked_states(state)?;
@@ -364,6 +418,15 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                         let result = state.pubgrub.unit_propagation(state.next);
                         match result {
                             Err(err) => {
+                                // Re-load any metadata that was evicted for the packages involved,
+                                // to report their available versions.
+                                self.reload_evicted(
+                                    err.packages()
+                                        .into_iter()
+                                        .filter_map(|package| package.name()),
+                                    request_sink,
+                                )?;
+
                                 // If unit propagation failed, there is no solution.
                                 return Err(self.convert_no_solution_err(
                                     err,
@@ -384,6 +447,19 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                             }
                         }
 
+                        // If we've exceeded the backtracking budget, report the packages that caused
+                        // the most conflicts rather than continuing the search.
+                        if let Some(limit) = self.options.max_backtracks {
+                            if state.conflict_tracker.conflicts > limit {
+                                return Err(ResolveError::BacktrackLimit(Box::new(
+                                    BacktrackLimitError::new(
+                                        limit,
+                                        state.conflict_tracker.most_conflicting(&state.pubgrub),
+                                    ),
+                                )));
+                            }
+                        }
+
                         // Pre-visit all candidate packages, to allow metadata to be fetched in parallel.
                         if self.dependency_mode.is_transitive() {
                             Self::pre_visit(
@@ -394,6 +470,8 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                                     .map(|(id, range)| (&state.pubgrub.package_store[id], range)),
                                 &self.urls,
                                 &self.indexes,
+                                &self.options.excluded_packages,
+                                &self.history,
                                 &state.python_requirement,
                                 request_sink,
                             )?;
@@ -515,7 +593,7 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                     let decision = self.choose_version(
                         next_package,
                         next_id,
-                        index.map(IndexMetadata::url),
+                        index,
                         term_intersection.unwrap_positive(),
                         &mut state.pins,
                         &preferences,
@@ -623,6 +701,9 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                     &state.pubgrub,
                 )?;
 
+                // With an eager upgrade strategy, upgrade the dependencies of upgraded packages.
+                self.upgrade_dependencies(next_package, &forked_deps, &mut preferences);
+
                 match forked_deps {
                     ForkedDependencies::Unavailable(reason) => {
                         // Then here, if we get a reason that we consider unrecoverable, we should
@@ -649,13 +730,25 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                                 self.selector.resolution_strategy(),
                             )
                             .map_err(|err| {
-                                enrich_dependency_error(err, next_id, &version, &state.pubgrub)
+                                enrich_dependency_error(
+                                    err,
+                                    next_id,
+                                    &version,
+                                    &state.pubgrub,
+                                    &state.env,
+                                )
                             })?;
 
                         // Emit a request to fetch the metadata for each registry package.
                         self.visit_dependencies(&dependencies, &state, request_sink)
                             .map_err(|err| {
-                                enrich_dependency_error(err, next_id, &version, &state.pubgrub)
+                                enrich_dependency_error(
+                                    err,
+                                    next_id,
+                                    &version,
+                                    &state.pubgrub,
+                                    &state.env,
+                                )
                             })?;
 
                         // Add the dependencies to the state.
@@ -731,6 +824,17 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
         for resolution in &resolutions {
             Self::trace_resolution(resolution);
         }
+
+        // Re-load any metadata that was evicted for the resolved packages, since it's required to
+        // determine their hashes.
+        self.reload_evicted(
+            resolutions
+                .iter()
+                .flat_map(|resolution| resolution.nodes.keys())
+                .map(|package| &package.name),
+            request_sink,
+        )?;
+
         ResolverOutput::from_state(
             &resolutions,
             &self.requirements,
@@ -898,13 +1002,25 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                         self.selector.resolution_strategy(),
                     )
                     .map_err(|err| {
-                        enrich_dependency_error(err, package, version, &forked_state.pubgrub)
+                        enrich_dependency_error(
+                            err,
+                            package,
+                            version,
+                            &forked_state.pubgrub,
+                            &forked_state.env,
+                        )
                     })?;
 
                 // Emit a request to fetch the metadata for each registry package.
                 self.visit_dependencies(&fork.dependencies, &forked_state, request_sink)
                     .map_err(|err| {
-                        enrich_dependency_error(err, package, version, &forked_state.pubgrub)
+                        enrich_dependency_error(
+                            err,
+                            package,
+                            version,
+                            &forked_state.pubgrub,
+                            &forked_state.env,
+                        )
                     })?;
 
                 // Add the dependencies to the state.
@@ -940,6 +1056,35 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
         })
     }
 
+    /// If the given package is being upgraded with an eager upgrade strategy, mark its
+    /// dependencies for upgrade, too, ignoring any existing preferences for them.
+    fn upgrade_dependencies(
+        &self,
+        package: &PubGrubPackage,
+        forked_deps: &ForkedDependencies,
+        preferences: &mut Preferences,
+    ) {
+        let Some(name) = package.name() else {
+            return;
+        };
+        let dependencies = match forked_deps {
+            ForkedDependencies::Unavailable(_) => return,
+            ForkedDependencies::Unforked(dependencies) => Either::Left(dependencies.iter()),
+            ForkedDependencies::Forked { forks, .. } => {
+                Either::Right(forks.iter().flat_map(|fork| fork.dependencies.iter()))
+            }
+        };
+        for dependency in dependencies {
+            let Some(dependency) = dependency.package.name() else {
+                continue;
+            };
+            if self.exclusions.upgrade_dependency(name, dependency) {
+                debug!("Allowing upgrades for {dependency}, a dependency of {name}");
+                preferences.remove_pinned(dependency);
+            }
+        }
+    }
+
     /// Visit a set of [`PubGrubDependency`] entities prior to selection.
     fn visit_dependencies(
         &self,
@@ -961,6 +1106,57 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
         Ok(())
     }
 
+    /// Re-load the package metadata for the given packages, if it was evicted to stay within the
+    /// memory budget of the index.
+    fn reload_evicted<'a>(
+        &self,
+        names: impl IntoIterator<Item = &'a PackageName>,
+        request_sink: &Sender<Request>,
+    ) -> Result<(), ResolveError> {
+        if self.options.memory_limit.is_none() {
+            return Ok(());
+        }
+        let names = names.into_iter().collect::<FxHashSet<_>>();
+        let evicted = self.index.take_evicted(|name| names.contains(name));
+        if evicted.is_empty() {
+            return Ok(());
+        }
+        debug!(
+            "Re-loading evicted metadata for {} package(s)",
+            evicted.len()
+        );
+
+        // Emit all requests before waiting, such that the metadata is fetched in parallel.
+        for (name, index) in &evicted {
+            if let Some(index) = index {
+                if self
+                    .index
+                    .explicit()
+                    .register((name.clone(), index.url().clone()))
+                {
+                    request_sink
+                        .blocking_send(Request::Package(name.clone(), Some(index.clone())))?;
+                }
+            } else if self.index.implicit().register(name.clone()) {
+                request_sink.blocking_send(Request::Package(name.clone(), None))?;
+            }
+        }
+        for (name, index) in evicted {
+            if let Some(index) = index {
+                self.index
+                    .explicit()
+                    .wait_blocking(&(name.clone(), index.into_url()))
+                    .ok_or_else(|| ResolveError::UnregisteredTask(name.to_string()))?;
+            } else {
+                self.index
+                    .implicit()
+                    .wait_blocking(&name)
+                    .ok_or_else(|| ResolveError::UnregisteredTask(name.to_string()))?;
+            }
+        }
+        Ok(())
+    }
+
     /// Visit a [`PubGrubPackage`] prior to selection. This should be called on a [`PubGrubPackage`]
     /// before it is selected, to allow metadata to be fetched in parallel.
     fn visit_package(
@@ -995,6 +1191,11 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
             return Ok(());
         };
 
+        // Don't fetch metadata for packages that can never be selected.
+        if self.options.excluded_packages.contains(name) {
+            return Ok(());
+        }
+
         if let Some(url) = url {
             // Verify that the package is allowed under the hash-checking policy.
             if !self.hasher.allows_url(&url.verbatim) {
@@ -1030,6 +1231,8 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
         packages: impl Iterator<Item = (&'data PubGrubPackage, &'data Range<Version>)>,
         urls: &Urls,
         indexes: &Indexes,
+        excluded_packages: &[PackageName],
+        history: &SelectionHistory,
         python_requirement: &PythonRequirement,
         request_sink: &Sender<Request>,
     ) -> Result<(), ResolveError> {
@@ -1054,6 +1257,21 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
             if indexes.contains_key(name) {
                 continue;
             }
+            // Avoid visiting packages that can never be selected.
+            if excluded_packages.contains(name) {
+                continue;
+            }
+            // Fetch the versions selected in prior resolutions first, as they're the most likely
+            // to be selected again.
+            for version in history.get(name) {
+                if range.contains(version) {
+                    request_sink.blocking_send(Request::Prefetch(
+                        name.clone(),
+                        Range::singleton(version.clone()),
+                        python_requirement.clone(),
+                    ))?;
+                }
+            }
             request_sink.blocking_send(Request::Prefetch(
                 name.clone(),
                 range.clone(),
@@ -1075,7 +1293,7 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
         &self,
         package: &PubGrubPackage,
         id: Id<PubGrubPackage>,
-        index: Option<&IndexUrl>,
+        index: Option<&IndexMetadata>,
         range: &Range<Version>,
         pins: &mut FilePins,
         preferences: &Preferences,
@@ -1110,6 +1328,13 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
             | PubGrubPackageInner::Extra { name, .. }
             | PubGrubPackageInner::Group { name, .. }
             | PubGrubPackageInner::Package { name, .. } => {
+                // Excluded packages are unavailable at any version.
+                if self.options.excluded_packages.contains(name) {
+                    self.unavailable_packages
+                        .insert(name.clone(), UnavailablePackage::Excluded);
+                    return Ok(None);
+                }
+
                 if let Some(url) = package.name().and_then(|name| fork_urls.get(name)) {
                     self.choose_version_url(id, name, range, url, env, python_requirement, pubgrub)
                 } else {
@@ -1254,7 +1479,7 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
         package: &PubGrubPackage,
         id: Id<PubGrubPackage>,
         name: &PackageName,
-        index: Option<&IndexUrl>,
+        index: Option<&IndexMetadata>,
         range: &Range<Version>,
         preferences: &Preferences,
         env: &ResolverEnvironment,
@@ -1264,11 +1489,14 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
         visited: &mut FxHashSet<PackageName>,
         request_sink: &Sender<Request>,
     ) -> Result<Option<ResolverVersion>, ResolveError> {
+        // If the metadata was evicted to stay within the memory budget, fetch it again.
+        self.reload_evicted(std::iter::once(name), request_sink)?;
+
         // Wait for the metadata to be available.
         let versions_response = if let Some(index) = index {
             self.index
                 .explicit()
-                .wait_blocking(&(name.clone(), index.clone()))
+                .wait_blocking(&(name.clone(), index.url().clone()))
                 .ok_or_else(|| ResolveError::UnregisteredTask(name.to_string()))?
         } else {
             self.index
@@ -1278,6 +1506,20 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
         };
         visited.insert(name.clone());
 
+        // If the package metadata exceeds the memory budget, evict the least recently used
+        // metadata for other packages.
+        if let Some(limit) = self.options.memory_limit {
+            self.index
+                .touch_versions(name, index, &versions_response, limit);
+        }
+
+        if matches!(*versions_response, VersionsResponse::NotFound) {
+            self.unavailable_packages
+                .insert(name.clone(), UnavailablePackage::NotFound(index.cloned()));
+        }
+
+        let index = index.map(IndexMetadata::url);
+
         let version_maps = match *versions_response {
             VersionsResponse::Found(ref version_maps) => version_maps.as_slice(),
             VersionsResponse::NoIndex => {
@@ -1290,11 +1532,7 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                     .insert(name.clone(), UnavailablePackage::Offline);
                 &[]
             }
-            VersionsResponse::NotFound => {
-                self.unavailable_packages
-                    .insert(name.clone(), UnavailablePackage::NotFound);
-                &[]
-            }
+            VersionsResponse::NotFound => &[],
         };
 
         debug!("Searching for a compatible version of {package} ({range})");
@@ -1866,7 +2104,7 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                         return Ok(Dependencies::Unavailable(unavailable_version));
                     }
                     MetadataResponse::Error(dist, err) => {
-                        let chain = DerivationChainBuilder::from_state(id, version, pubgrub)
+                        let chain = DerivationChainBuilder::from_state(id, version, pubgrub, env)
                             .unwrap_or_default();
                         return Err(ResolveError::Dist(
                             DistErrorKind::from_requested_dist(dist, &**err),
@@ -2507,13 +2745,12 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
 
             // Pre-fetch the package and distribution metadata.
             Request::Prefetch(package_name, range, python_requirement) => {
-                // Wait for the package metadata to become available.
-                let versions_response = self
-                    .index
-                    .implicit()
-                    .wait(&package_name)
-                    .await
-                    .ok_or_else(|| ResolveError::UnregisteredTask(package_name.to_string()))?;
+                // Wait for the package metadata to become available. If the metadata was evicted
+                // to stay within the memory budget in the interim, skip the prefetch.
+                let Some(versions_response) = self.index.implicit().wait(&package_name).await
+                else {
+                    return Ok(None);
+                };
 
                 let version_map = match *versions_response {
                     VersionsResponse::Found(ref version_map) => version_map,
@@ -2532,7 +2769,7 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                     }
                     VersionsResponse::NotFound => {
                         self.unavailable_packages
-                            .insert(package_name.clone(), UnavailablePackage::NotFound);
+                            .insert(package_name.clone(), UnavailablePackage::NotFound(None));
 
                         return Ok(None);
                     }
@@ -2914,6 +3151,7 @@ impl ForkState {
         env: ResolverEnvironment,
         python_requirement: PythonRequirement,
         prefetcher: BatchPrefetcher,
+        priorities: PubGrubPriorities,
     ) -> Self {
         Self {
             initial_id: None,
@@ -2923,7 +3161,7 @@ impl ForkState {
             pins: FilePins::default(),
             fork_urls: ForkUrls::default(),
             fork_indexes: ForkIndexes::default(),
-            priorities: PubGrubPriorities::default(),
+            priorities,
             added_dependencies: FxHashMap::default(),
             env,
             python_requirement,
@@ -3127,6 +3365,8 @@ impl ForkState {
                 }
             }
 
+            self.conflict_tracker.conflicts += 1;
+
             let affected_count = self.conflict_tracker.affected.entry(self.next).or_default();
             *affected_count += 1;
             if *affected_count == CONFLICT_THRESHOLD {
@@ -4120,11 +4360,12 @@ fn enrich_dependency_error(
     id: Id<PubGrubPackage>,
     version: &Version,
     pubgrub: &State<UvDependencyProvider>,
+    env: &ResolverEnvironment,
 ) -> ResolveError {
     let Some(name) = pubgrub.package_store[id].name_no_root() else {
         return error;
     };
-    let chain = DerivationChainBuilder::from_state(id, version, pubgrub).unwrap_or_default();
+    let chain = DerivationChainBuilder::from_state(id, version, pubgrub, env).unwrap_or_default();
     ResolveError::Dependencies(Box::new(error), name.clone(), version.clone(), chain)
 }
 
@@ -4171,4 +4412,30 @@ struct ConflictTracker {
     ///
     /// Distilled from `culprit` for fast checking in the hot loop.
     deprioritize: Vec<Id<PubGrubPackage>>,
+    /// The total number of conflicts encountered, i.e., the number of times the resolver had to
+    /// backtrack.
+    conflicts: usize,
+}
+
+impl ConflictTracker {
+    /// The number of packages to report when the backtracking budget is exceeded.
+    const REPORTED_PACKAGES: usize = 5;
+
+    /// Return the packages involved in the most conflicts (as either the affected package or the
+    /// culprit), along with their conflict counts, in decreasing order.
+    fn most_conflicting(&self, pubgrub: &State<UvDependencyProvider>) -> Vec<(PackageName, usize)> {
+        let mut counts: FxHashMap<PackageName, usize> = FxHashMap::default();
+        for (id, count) in self.affected.iter().chain(self.culprit.iter()) {
+            if let Some(name) = pubgrub.package_store[*id].name_no_root() {
+                *counts.entry(name.clone()).or_default() += count;
+            }
+        }
+        counts
+            .into_iter()
+            .sorted_unstable_by(|(a_name, a_count), (b_name, b_count)| {
+                b_count.cmp(a_count).then_with(|| a_name.cmp(b_name))
+            })
+            .take(Self::REPORTED_PACKAGES)
+            .collect()
+    }
 }
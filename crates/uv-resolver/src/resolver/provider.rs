@@ -13,6 +13,7 @@ use uv_platform_tags::Tags;
 use uv_types::{BuildContext, HashStrategy};
 
 use crate::ExcludeNewer;
+use crate::edit_distance::similar_names;
 use crate::flat_index::FlatIndex;
 use crate::version_map::VersionMap;
 use crate::yanks::AllowedYanks;
@@ -33,6 +34,18 @@ pub enum VersionsResponse {
     Offline,
 }
 
+impl VersionsResponse {
+    /// Returns the approximate size of the response in memory, in bytes.
+    pub(crate) fn approximate_size(&self) -> usize {
+        match self {
+            Self::Found(version_maps) => {
+                version_maps.iter().map(VersionMap::approximate_size).sum()
+            }
+            Self::NotFound | Self::NoIndex | Self::Offline => 0,
+        }
+    }
+}
+
 #[derive(Debug)]
 pub enum MetadataResponse {
     /// The wheel metadata was found and parsed successfully.
@@ -100,6 +113,17 @@ pub trait ResolverProvider {
         dist: &'io InstalledDist,
     ) -> impl Future<Output = WheelMetadataResult> + 'io;
 
+    /// Find the names of packages that are similar to a package that wasn't found, on the indexes
+    /// that were queried for it.
+    ///
+    /// Only called once a resolution has failed, since it may require fetching the list of all
+    /// projects from each index.
+    fn similar_packages<'io>(
+        &'io self,
+        package_name: &'io PackageName,
+        index: Option<&'io IndexMetadata>,
+    ) -> impl Future<Output = Vec<PackageName>> + 'io;
+
     /// Set the [`Reporter`] to use for this installer.
     #[must_use]
     fn with_reporter(self, reporter: Arc<dyn Reporter>) -> Self;
@@ -309,6 +333,25 @@ impl<Context: BuildContext> ResolverProvider for DefaultResolverProvider<'_, Con
         }
     }
 
+    /// Look for similarly-named packages on the indexes that were queried for a missing package.
+    async fn similar_packages<'io>(
+        &'io self,
+        package_name: &'io PackageName,
+        index: Option<&'io IndexMetadata>,
+    ) -> Vec<PackageName> {
+        let projects = self
+            .fetcher
+            .client()
+            .manual(|client, _| {
+                client.simple_index_projects(package_name, index.map(IndexMetadataRef::from))
+            })
+            .await;
+        similar_names(
+            package_name,
+            projects.iter().flat_map(|projects| projects.iter()),
+        )
+    }
+
     /// Set the [`Reporter`] to use for this installer.
     fn with_reporter(self, reporter: Arc<dyn Reporter>) -> Self {
         Self {
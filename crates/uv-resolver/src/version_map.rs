@@ -269,6 +269,28 @@ impl VersionMap {
         }
     }
 
+    /// Returns the approximate size of this map in memory, in bytes.
+    ///
+    /// Only accounts for the raw metadata and the per-version entries, and not for any
+    /// distributions that were materialized upon access.
+    pub(crate) fn approximate_size(&self) -> usize {
+        match self.inner {
+            VersionMapInner::Eager(VersionMapEager { ref map, .. }) => {
+                map.len() * size_of::<(Version, PrioritizedDist)>()
+            }
+            VersionMapInner::Lazy(VersionMapLazy {
+                ref map,
+                ref core_metadata,
+                ref simple_metadata,
+                ..
+            }) => {
+                OwnedArchive::as_bytes(simple_metadata).len()
+                    + map.len() * size_of::<(Version, LazyPrioritizedDist)>()
+                    + core_metadata.len() * size_of::<(Version, ResolutionMetadata)>()
+            }
+        }
+    }
+
     /// Returns `true` if the map contains at least one stable (non-pre-release) version.
     pub(crate) fn stable(&self) -> bool {
         match self.inner {
@@ -550,18 +572,7 @@ impl VersionMapLazy {
 
         // Check if hashes line up. If hashes aren't required, they're considered matching.
         let hash_policy = self.hasher.get_package(name, version);
-        let required_hashes = hash_policy.digests();
-        let hash = if required_hashes.is_empty() {
-            HashComparison::Matched
-        } else {
-            if hashes.is_empty() {
-                HashComparison::Missing
-            } else if hashes.iter().any(|hash| required_hashes.contains(hash)) {
-                HashComparison::Matched
-            } else {
-                HashComparison::Mismatched
-            }
-        };
+        let hash = HashComparison::compare(hash_policy.digests(), hashes);
 
         SourceDistCompatibility::Compatible(hash)
     }
@@ -614,18 +625,7 @@ impl VersionMapLazy {
 
         // Check if hashes line up. If hashes aren't required, they're considered matching.
         let hash_policy = self.hasher.get_package(name, version);
-        let required_hashes = hash_policy.digests();
-        let hash = if required_hashes.is_empty() {
-            HashComparison::Matched
-        } else {
-            if hashes.is_empty() {
-                HashComparison::Missing
-            } else if hashes.iter().any(|hash| required_hashes.contains(hash)) {
-                HashComparison::Matched
-            } else {
-                HashComparison::Mismatched
-            }
-        };
+        let hash = HashComparison::compare(hash_policy.digests(), hashes);
 
         // Break ties with the build tag.
         let build_tag = filename.build_tag().cloned();
@@ -1,3 +1,6 @@
+use std::borrow::Cow;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
 use std::sync::Arc;
 
 use rustc_hash::{FxHashMap, FxHashSet};
@@ -8,6 +11,80 @@ use uv_pep440::Version;
 
 use crate::{DependencyMode, Manifest, ResolverEnvironment};
 
+/// A package version that is explicitly permitted, even if it's marked as yanked, in the format
+/// `PACKAGE==VERSION` (e.g., `flask==2.0.0`).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AllowYankedEntry {
+    pub package: PackageName,
+    pub version: Version,
+}
+
+impl AllowYankedEntry {
+    /// Returns `true` if the entry permits the given package-version.
+    pub fn matches(&self, package_name: &PackageName, version: &Version) -> bool {
+        self.package == *package_name && self.version == *version
+    }
+}
+
+impl FromStr for AllowYankedEntry {
+    type Err = String;
+
+    /// Parses an [`AllowYankedEntry`] from a string in the format `PACKAGE==VERSION`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some((package, version)) = s.split_once("==") else {
+            return Err(format!(
+                "Invalid `allow-yanked` value `{s}`: expected format `PACKAGE==VERSION`"
+            ));
+        };
+
+        let package = PackageName::from_str(package.trim())
+            .map_err(|err| format!("Invalid `allow-yanked` package name `{package}`: {err}"))?;
+        let version = Version::from_str(version.trim())
+            .map_err(|err| format!("Invalid `allow-yanked` version `{version}`: {err}"))?;
+
+        Ok(Self { package, version })
+    }
+}
+
+impl Display for AllowYankedEntry {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}=={}", self.package, self.version)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for AllowYankedEntry {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = <Cow<'_, str>>::deserialize(deserializer)?;
+        Self::from_str(&s).map_err(serde::de::Error::custom)
+    }
+}
+
+impl serde::Serialize for AllowYankedEntry {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for AllowYankedEntry {
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Borrowed("AllowYankedEntry")
+    }
+
+    fn json_schema(_generator: &mut schemars::generate::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "type": "string",
+            "description": "A package version to allow, even if it's yanked, in the format `PACKAGE==VERSION` (e.g., `flask==2.0.0`).",
+        })
+    }
+}
+
 /// A set of package versions that are permitted, even if they're marked as yanked by the
 /// relevant index.
 #[derive(Debug, Default, Clone)]
@@ -18,9 +95,18 @@ impl AllowedYanks {
         manifest: &Manifest,
         env: &ResolverEnvironment,
         dependencies: DependencyMode,
+        allow_yanked: &[AllowYankedEntry],
     ) -> Self {
         let mut allowed_yanks = FxHashMap::<PackageName, FxHashSet<Version>>::default();
 
+        // Allow yanks for any versions that were explicitly permitted.
+        for entry in allow_yanked {
+            allowed_yanks
+                .entry(entry.package.clone())
+                .or_default()
+                .insert(entry.version.clone());
+        }
+
         // Allow yanks for any pinned input requirements.
         for requirement in manifest.requirements(env, dependencies) {
             let RequirementSource::Registry { specifier, .. } = &requirement.source else {
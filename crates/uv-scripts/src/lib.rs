@@ -407,9 +407,20 @@ impl FromStr for Pep723Metadata {
     type Err = toml::de::Error;
 
     /// Parse `Pep723Metadata` from a raw TOML string.
+    ///
+    /// Any `credentials` configured for the script's indexes are ignored, as they're only
+    /// respected in user-level configuration.
     #[instrument(name = "toml::from_str PEP 723 metadata", skip_all)]
     fn from_str(raw: &str) -> Result<Self, Self::Err> {
-        let metadata = toml::from_str(raw)?;
+        let mut metadata: Self = toml::from_str(raw)?;
+        if let Some(uv) = metadata.tool.as_mut().and_then(|tool| tool.uv.as_mut()) {
+            uv.top_level.index = uv.top_level.index.take().map(|indexes| {
+                indexes
+                    .into_iter()
+                    .map(|index| index.without_credentials(None))
+                    .collect()
+            });
+        }
         Ok(Self {
             raw: raw.to_string(),
             ..metadata
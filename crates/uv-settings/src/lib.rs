@@ -3,11 +3,14 @@ use std::ops::Deref;
 use std::path::{Path, PathBuf};
 use std::time::Duration;
 use tracing::info_span;
-use uv_client::{DEFAULT_CONNECT_TIMEOUT, DEFAULT_READ_TIMEOUT, DEFAULT_READ_TIMEOUT_UPLOAD};
+use uv_client::{
+    DEFAULT_CONNECT_TIMEOUT, DEFAULT_READ_TIMEOUT, DEFAULT_READ_TIMEOUT_UPLOAD,
+    DEFAULT_RETRY_MAX_DELAY, DEFAULT_STALL_TIMEOUT,
+};
 use uv_dirs::{system_config_file, user_config_dir};
 use uv_distribution_types::Origin;
 use uv_flags::EnvironmentFlags;
-use uv_fs::Simplified;
+use uv_fs::{CWD, Simplified};
 use uv_static::{EnvVars, InvalidEnvironmentVariable, parse_boolish_environment_variable};
 use uv_warnings::warn_user;
 
@@ -141,6 +144,7 @@ impl FilesystemOptions {
 
                 tracing::debug!("Found workspace configuration at `{}`", path.display());
                 validate_uv_toml(&path, &options)?;
+                let options = options.without_user_only_settings(Some(&path));
                 return Ok(Some(Self(options.with_origin(Origin::Project))));
             }
             Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
@@ -171,7 +175,9 @@ impl FilesystemOptions {
                     return Ok(None);
                 };
 
-                let options = options.relative_to(&std::path::absolute(dir)?)?;
+                let options = options
+                    .relative_to(&std::path::absolute(dir)?)?
+                    .without_user_only_settings(Some(&path));
 
                 tracing::debug!("Found workspace configuration at `{}`", path.display());
                 return Ok(Some(Self(options)));
@@ -192,6 +198,23 @@ impl FilesystemOptions {
         validate_uv_toml(path, &options)?;
         Ok(Self(options))
     }
+
+    /// Load a [`FilesystemOptions`] from the contents of a `uv.toml` file fetched from the given
+    /// URL.
+    ///
+    /// Relative paths are resolved against the current working directory. As with project-level
+    /// configuration, any user-only settings (e.g., `credential-helper`) are ignored.
+    pub fn from_remote(url: &str, content: &str) -> Result<Self, Error> {
+        tracing::debug!("Reading remote configuration from: `{url}`");
+        let path = PathBuf::from(url);
+
+        let options = toml::from_str::<Options>(content)
+            .map_err(|err| Error::UvToml(path.clone(), Box::new(err)))?
+            .relative_to(&CWD)?;
+        validate_uv_toml(&path, &options)?;
+        let options = options.without_user_only_settings(Some(&path));
+        Ok(Self(options))
+    }
 }
 
 impl From<Options> for FilesystemOptions {
@@ -309,6 +332,7 @@ fn warn_uv_toml_masked_fields(options: &Options) {
                 offline,
                 no_cache,
                 cache_dir,
+                scratch_dir,
                 preview,
                 python_preference,
                 python_downloads,
@@ -318,7 +342,11 @@ fn warn_uv_toml_masked_fields(options: &Options) {
                 allow_insecure_host,
                 http_proxy,
                 https_proxy,
+                all_proxy,
                 no_proxy,
+                credential_helper,
+                require_attestations,
+                trusted_publishers,
             },
         top_level:
             ResolverInstallerSchema {
@@ -331,6 +359,7 @@ fn warn_uv_toml_masked_fields(options: &Options) {
                 keyring_provider,
                 resolution,
                 prerelease,
+                prerelease_packages,
                 fork_strategy,
                 dependency_metadata,
                 config_settings,
@@ -341,10 +370,15 @@ fn warn_uv_toml_masked_fields(options: &Options) {
                 extra_build_variables,
                 exclude_newer,
                 exclude_newer_package,
+                allow_yanked,
                 link_mode,
                 compile_bytecode,
                 no_sources,
                 no_sources_package: _,
+                excluded_packages,
+                resolution_priority,
+                max_backtracks,
+                protected_packages,
                 upgrade,
                 upgrade_package,
                 reinstall,
@@ -404,6 +438,9 @@ fn warn_uv_toml_masked_fields(options: &Options) {
     if cache_dir.is_some() {
         masked_fields.push("cache-dir");
     }
+    if scratch_dir.is_some() {
+        masked_fields.push("scratch-dir");
+    }
     if preview.is_some() {
         masked_fields.push("preview");
     }
@@ -431,9 +468,21 @@ fn warn_uv_toml_masked_fields(options: &Options) {
     if https_proxy.is_some() {
         masked_fields.push("https-proxy");
     }
+    if all_proxy.is_some() {
+        masked_fields.push("all-proxy");
+    }
     if no_proxy.is_some() {
         masked_fields.push("no-proxy");
     }
+    if credential_helper.is_some() {
+        masked_fields.push("credential-helper");
+    }
+    if require_attestations.is_some() {
+        masked_fields.push("require-attestations");
+    }
+    if trusted_publishers.is_some() {
+        masked_fields.push("trusted-publishers");
+    }
     if index.is_some() {
         masked_fields.push("index");
     }
@@ -461,6 +510,9 @@ fn warn_uv_toml_masked_fields(options: &Options) {
     if prerelease.is_some() {
         masked_fields.push("prerelease");
     }
+    if prerelease_packages.is_some() {
+        masked_fields.push("prerelease-packages");
+    }
     if fork_strategy.is_some() {
         masked_fields.push("fork-strategy");
     }
@@ -491,6 +543,9 @@ fn warn_uv_toml_masked_fields(options: &Options) {
     if exclude_newer_package.is_some() {
         masked_fields.push("exclude-newer-package");
     }
+    if allow_yanked.is_some() {
+        masked_fields.push("allow-yanked");
+    }
     if link_mode.is_some() {
         masked_fields.push("link-mode");
     }
@@ -500,6 +555,18 @@ fn warn_uv_toml_masked_fields(options: &Options) {
     if no_sources.is_some() {
         masked_fields.push("no-sources");
     }
+    if excluded_packages.is_some() {
+        masked_fields.push("excluded-packages");
+    }
+    if resolution_priority.is_some() {
+        masked_fields.push("resolution-priority");
+    }
+    if max_backtracks.is_some() {
+        masked_fields.push("max-backtracks");
+    }
+    if protected_packages.is_some() {
+        masked_fields.push("protected-packages");
+    }
     if upgrade.is_some() {
         masked_fields.push("upgrade");
     }
@@ -638,11 +705,15 @@ pub struct EnvironmentOptions {
     pub lfs: Option<bool>,
     pub http_connect_timeout: Duration,
     pub http_read_timeout: Duration,
+    pub http_stall_timeout: Duration,
     /// There's no upload timeout in reqwest, instead we have to use a read timeout as upload
     /// timeout.
     pub http_read_timeout_upload: Duration,
     pub http_retries: u32,
+    pub http_retry_max_delay: Duration,
     pub concurrency: Concurrency,
+    /// The approximate amount of memory (in bytes) to use for package metadata during resolution.
+    pub resolver_memory_limit: Option<usize>,
     #[cfg(feature = "tracing-durations-export")]
     pub tracing_durations_file: Option<PathBuf>,
     pub frozen: EnvFlag,
@@ -733,8 +804,25 @@ impl EnvironmentOptions {
             )?
             .map(Duration::from_secs)
             .unwrap_or(DEFAULT_CONNECT_TIMEOUT),
+            http_stall_timeout: parse_integer_environment_variable(
+                EnvVars::UV_HTTP_STALL_TIMEOUT,
+                Some("value should be an integer number of seconds"),
+            )?
+            .map(Duration::from_secs)
+            .unwrap_or(DEFAULT_STALL_TIMEOUT),
             http_retries: parse_integer_environment_variable(EnvVars::UV_HTTP_RETRIES, None)?
                 .unwrap_or(uv_client::DEFAULT_RETRIES),
+            http_retry_max_delay: parse_integer_environment_variable(
+                EnvVars::UV_HTTP_RETRY_MAX_DELAY,
+                Some("value should be an integer number of seconds"),
+            )?
+            .map(Duration::from_secs)
+            .unwrap_or(DEFAULT_RETRY_MAX_DELAY),
+            resolver_memory_limit: parse_integer_environment_variable::<usize>(
+                EnvVars::UV_RESOLVER_MEMORY_LIMIT,
+                Some("value should be an integer number of megabytes"),
+            )?
+            .map(|megabytes| megabytes.saturating_mul(1024 * 1024)),
             #[cfg(feature = "tracing-durations-export")]
             tracing_durations_file: parse_path_environment_variable(
                 EnvVars::TRACING_DURATIONS_FILE,
@@ -60,6 +60,12 @@ impl EnvVars {
     #[attr_added_in("0.0.5")]
     pub const UV_CACHE_DIR: &'static str = "UV_CACHE_DIR";
 
+    /// Equivalent to the `--scratch-dir` command-line argument. If set, uv will create the
+    /// temporary directories used by build backends (e.g., `TMPDIR`) in this directory, instead
+    /// of in the cache directory.
+    #[attr_added_in("next version")]
+    pub const UV_SCRATCH_DIR: &'static str = "UV_SCRATCH_DIR";
+
     /// The directory for storage of credentials when using a plain text backend.
     #[attr_added_in("0.8.15")]
     pub const UV_CREDENTIALS_DIR: &'static str = "UV_CREDENTIALS_DIR";
@@ -169,7 +175,8 @@ impl EnvVars {
     pub const UV_KEYRING_PROVIDER: &'static str = "UV_KEYRING_PROVIDER";
 
     /// Equivalent to the `--config-file` command-line argument. Expects a path to a
-    /// local `uv.toml` file to use as the configuration file.
+    /// local `uv.toml` file, or an `https://` URL pointing to a remote `uv.toml` file, to use as
+    /// the configuration file.
     #[attr_added_in("0.1.34")]
     pub const UV_CONFIG_FILE: &'static str = "UV_CONFIG_FILE";
 
@@ -307,6 +314,12 @@ impl EnvVars {
     #[attr_added_in("0.9.12")]
     pub const UV_PUBLISH_NO_ATTESTATIONS: &'static str = "UV_PUBLISH_NO_ATTESTATIONS";
 
+    /// The path to a Sigstore trusted root (`trusted_root.json`) used to verify the attestations
+    /// of registry distributions when the `attestations` preview feature is enabled. Defaults to
+    /// the trusted root of the Sigstore public-good instance.
+    #[attr_added_in("next version")]
+    pub const UV_ATTESTATION_TRUSTED_ROOT: &'static str = "UV_ATTESTATION_TRUSTED_ROOT";
+
     /// Equivalent to the `--no-sync` command-line argument. If set, uv will skip updating
     /// the environment.
     #[attr_added_in("0.4.18")]
@@ -343,6 +356,46 @@ impl EnvVars {
     #[attr_added_in("0.3.5")]
     pub const UV_INSECURE_HOST: &'static str = "UV_INSECURE_HOST";
 
+    /// Equivalent to the `--allow-index-failure` argument. Treats unreachable indexes other than
+    /// the default index as empty, rather than failing.
+    #[attr_added_in("next version")]
+    pub const UV_ALLOW_INDEX_FAILURE: &'static str = "UV_ALLOW_INDEX_FAILURE";
+
+    /// Equivalent to the `--index-cache-ttl` argument. Considers cached Simple API responses fresh
+    /// for the given duration (e.g., `600` or `10m`).
+    #[attr_added_in("next version")]
+    pub const UV_INDEX_CACHE_TTL: &'static str = "UV_INDEX_CACHE_TTL";
+
+    /// Equivalent to the `--index-stale-if-error` argument. Uses stale cached Simple API
+    /// responses if an index is unreachable, rather than failing.
+    #[attr_added_in("next version")]
+    pub const UV_INDEX_STALE_IF_ERROR: &'static str = "UV_INDEX_STALE_IF_ERROR";
+
+    /// Equivalent to the `--stale-metadata-report` argument. Appends a JSON record to the given
+    /// file for each distribution for which an index served stale metadata.
+    #[attr_added_in("next version")]
+    pub const UV_STALE_METADATA_REPORT: &'static str = "UV_STALE_METADATA_REPORT";
+
+    /// Equivalent to the `--client-cert` argument. The path to a PEM file with a client
+    /// certificate to use for mTLS, overriding `SSL_CLIENT_CERT`.
+    #[attr_added_in("next version")]
+    pub const UV_CLIENT_CERT: &'static str = "UV_CLIENT_CERT";
+
+    /// Equivalent to the `--client-key` argument. The path to a PEM file with the private key for
+    /// the client certificate.
+    #[attr_added_in("next version")]
+    pub const UV_CLIENT_KEY: &'static str = "UV_CLIENT_KEY";
+
+    /// Equivalent to the `--limit-rate` argument. The maximum download rate across all requests,
+    /// in bytes per second (e.g., `500K` or `10M`).
+    #[attr_added_in("next version")]
+    pub const UV_LIMIT_RATE: &'static str = "UV_LIMIT_RATE";
+
+    /// Equivalent to the `--max-connections-per-host` argument. The maximum number of concurrent
+    /// connections to each host.
+    #[attr_added_in("next version")]
+    pub const UV_MAX_CONNECTIONS_PER_HOST: &'static str = "UV_MAX_CONNECTIONS_PER_HOST";
+
     /// Disable ZIP validation for streamed wheels and ZIP-based source distributions.
     ///
     /// WARNING: Disabling ZIP validation can expose your system to security risks by bypassing
@@ -367,6 +420,14 @@ impl EnvVars {
     #[attr_added_in("0.1.45")]
     pub const UV_CONCURRENT_INSTALLS: &'static str = "UV_CONCURRENT_INSTALLS";
 
+    /// The approximate amount of memory (in megabytes) to use for package metadata during
+    /// resolution.
+    ///
+    /// When exceeded, the metadata for the least recently used packages is evicted, and re-loaded
+    /// from the cache if needed again. By default, no limit is applied.
+    #[attr_added_in("next version")]
+    pub const UV_RESOLVER_MEMORY_LIMIT: &'static str = "UV_RESOLVER_MEMORY_LIMIT";
+
     /// Equivalent to the `--no-progress` command-line argument. Disables all progress output. For
     /// example, spinners and progress bars.
     #[attr_added_in("0.2.28")]
@@ -696,10 +757,23 @@ impl EnvVars {
     #[attr_added_in("0.10.0")]
     pub const UV_HTTP_CONNECT_TIMEOUT: &'static str = "UV_HTTP_CONNECT_TIMEOUT";
 
+    /// Timeout (in seconds) after which a download that has stopped receiving data is aborted
+    /// and retried, resuming from the bytes received so far when the server supports it.
+    /// (default: 10 s)
+    ///
+    /// Only takes effect if lower than `UV_HTTP_TIMEOUT`.
+    #[attr_added_in("next version")]
+    pub const UV_HTTP_STALL_TIMEOUT: &'static str = "UV_HTTP_STALL_TIMEOUT";
+
     /// The number of retries for HTTP requests. (default: 3)
     #[attr_added_in("0.7.21")]
     pub const UV_HTTP_RETRIES: &'static str = "UV_HTTP_RETRIES";
 
+    /// The maximum delay (in seconds) between two attempts of a retried HTTP request, which
+    /// otherwise backs off exponentially. (default: 30 s)
+    #[attr_added_in("next version")]
+    pub const UV_HTTP_RETRY_MAX_DELAY: &'static str = "UV_HTTP_RETRY_MAX_DELAY";
+
     /// Timeout (in seconds) for HTTP requests. Equivalent to `UV_HTTP_TIMEOUT`.
     #[attr_added_in("0.1.6")]
     pub const UV_REQUEST_TIMEOUT: &'static str = "UV_REQUEST_TIMEOUT";
@@ -927,6 +1001,21 @@ impl EnvVars {
     #[attr_added_in("0.4.18")]
     pub const PYTHONIOENCODING: &'static str = "PYTHONIOENCODING";
 
+    /// The directory in which to create temporary files on Unix, set for build backends.
+    #[attr_hidden]
+    #[attr_added_in("next version")]
+    pub const TMPDIR: &'static str = "TMPDIR";
+
+    /// The directory in which to create temporary files on Windows, set for build backends.
+    #[attr_hidden]
+    #[attr_added_in("next version")]
+    pub const TEMP: &'static str = "TEMP";
+
+    /// The directory in which to create temporary files on Windows, set for build backends.
+    #[attr_hidden]
+    #[attr_added_in("next version")]
+    pub const TMP: &'static str = "TMP";
+
     /// Forces unbuffered I/O streams, equivalent to `-u` in Python.
     #[attr_hidden]
     #[attr_added_in("0.1.15")]
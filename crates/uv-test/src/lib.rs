@@ -602,6 +602,24 @@ impl TestContext {
         self
     }
 
+    /// Adds filters for non-deterministic SPDX data
+    #[must_use]
+    pub fn with_spdx_filters(mut self) -> Self {
+        self.filters.push((
+            r#"-[0-9a-f]{8}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{12}""#.to_string(),
+            r#"-[UUID]""#.to_string(),
+        ));
+        self.filters.push((
+            r#""created": "[0-9]{4}-[0-9]{2}-[0-9]{2}T[0-9]{2}:[0-9]{2}:[0-9]{2}Z""#.to_string(),
+            r#""created": "[TIMESTAMP]""#.to_string(),
+        ));
+        self.filters.push((
+            r#""Tool: uv-\d+\.\d+\.\d+(-(alpha|beta|rc)\.\d+)?(\+\d+)?""#.to_string(),
+            r#""Tool: uv-[VERSION]""#.to_string(),
+        ));
+        self
+    }
+
     /// Add a filter that collapses duplicate whitespace.
     #[must_use]
     pub fn with_collapsed_whitespace(mut self) -> Self {
@@ -1048,6 +1066,12 @@ impl TestContext {
             "Activate with: source $1[BIN]/activate".to_string(),
         ));
 
+        // Filter the source digest in build log paths
+        filters.push((
+            r"(build-logs-v0/(?:[^/\s]+/)?)[0-9a-f]{16}\.log".to_string(),
+            "$1[HASH].log".to_string(),
+        ));
+
         // Filter non-deterministic temporary directory names
         // Note we apply this _after_ all the full paths to avoid breaking their matching
         filters.push((r"(\\|\/)\.tmp.*(\\|\/)".to_string(), "/[TMP]/".to_string()));
@@ -1268,6 +1292,14 @@ impl TestContext {
         command
     }
 
+    /// Create a `pip explain` command with options shared across scenarios.
+    pub fn pip_explain(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("pip").arg("explain");
+        self.add_shared_options(&mut command, true);
+        command
+    }
+
     /// Create a `pip compile` command for testing.
     pub fn pip_sync(&self) -> Command {
         let mut command = self.new_command();
@@ -1299,6 +1331,30 @@ impl TestContext {
         command
     }
 
+    /// Create a `pip verify` command with options shared across scenarios.
+    pub fn pip_verify(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("pip").arg("verify");
+        self.add_shared_options(&mut command, true);
+        command
+    }
+
+    /// Create a `uv pip index versions` command.
+    pub fn pip_index_versions(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("pip").arg("index").arg("versions");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
+    /// Create a `uv pip hash` command.
+    pub fn pip_hash(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("pip").arg("hash");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
     pub fn pip_list(&self) -> Command {
         let mut command = self.new_command();
         command.arg("pip").arg("list");
@@ -1322,6 +1378,22 @@ impl TestContext {
         command
     }
 
+    /// Create a `pip download` command with options shared across scenarios.
+    pub fn pip_download(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("pip").arg("download");
+        self.add_shared_options(&mut command, true);
+        command
+    }
+
+    /// Create a `pip wheel` command with options shared across scenarios.
+    pub fn pip_wheel(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("pip").arg("wheel");
+        self.add_shared_options(&mut command, true);
+        command
+    }
+
     /// Create a `pip uninstall` command with options shared across scenarios.
     pub fn pip_uninstall(&self) -> Command {
         let mut command = self.new_command();
@@ -1330,6 +1402,14 @@ impl TestContext {
         command
     }
 
+    /// Create a `pip stub` command with options shared across scenarios.
+    pub fn pip_stub(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("pip").arg("stub");
+        self.add_shared_options(&mut command, true);
+        command
+    }
+
     /// Create a `pip tree` command for testing.
     pub fn pip_tree(&self) -> Command {
         let mut command = self.new_command();
@@ -1403,6 +1483,30 @@ impl TestContext {
         command
     }
 
+    /// Create a `uv index build` command with options shared across scenarios.
+    pub fn index_build(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("index").arg("build");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
+    /// Create a `uv index ping` command with options shared across scenarios.
+    pub fn index_ping(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("index").arg("ping");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
+    /// Create a `uv licenses` command with options shared across scenarios.
+    pub fn licenses(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("licenses");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
     /// Create a `uv export` command with options shared across scenarios.
     pub fn export(&self) -> Command {
         let mut command = self.new_command();
@@ -1411,6 +1515,14 @@ impl TestContext {
         command
     }
 
+    /// Create a `uv vendor` command with options shared across scenarios.
+    pub fn vendor(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("vendor");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
     /// Create a `uv format` command with options shared across scenarios.
     pub fn format(&self) -> Command {
         let mut command = self.new_command();
@@ -1628,6 +1740,46 @@ impl TestContext {
         command
     }
 
+    /// Create a `uv cache list` command.
+    pub fn cache_list(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("cache").arg("list");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
+    /// Create a `uv cache warm` command.
+    pub fn cache_warm(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("cache").arg("warm");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
+    /// Create a `uv cache verify` command.
+    pub fn cache_verify(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("cache").arg("verify");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
+    /// Create a `uv cache export` command.
+    pub fn cache_export(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("cache").arg("export");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
+    /// Create a `uv cache import` command.
+    pub fn cache_import(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("cache").arg("import");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
     /// Create a `uv build_backend` command.
     ///
     /// Note that this command is hidden and only invoking it through a build frontend is supported.
@@ -1,7 +1,7 @@
 use std::str::FromStr;
 use std::sync::Arc;
 
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
 
 use uv_configuration::HashCheckingMode;
 use uv_distribution_types::{
@@ -10,7 +10,7 @@ use uv_distribution_types::{
 };
 use uv_normalize::PackageName;
 use uv_pep440::Version;
-use uv_pypi_types::{HashDigest, HashDigests, HashError, ResolverMarkerEnvironment};
+use uv_pypi_types::{HashDigest, HashDigests, HashError, ParsedUrl, ResolverMarkerEnvironment};
 use uv_redacted::DisplaySafeUrl;
 
 #[derive(Debug, Default, Clone)]
@@ -27,7 +27,13 @@ pub enum HashStrategy {
     /// Hashes should be validated against a pre-defined list of hashes.
     ///
     /// If necessary, hashes should be generated to ensure that the archive is valid.
-    Require(Arc<FxHashMap<VersionId, Vec<HashDigest>>>),
+    ///
+    /// Unhashable requirements that were exempted via `--allow-unhashed` are tracked separately,
+    /// and are not validated.
+    Require(
+        Arc<FxHashMap<VersionId, Vec<HashDigest>>>,
+        Arc<FxHashSet<VersionId>>,
+    ),
 }
 
 impl HashStrategy {
@@ -43,12 +49,14 @@ impl HashStrategy {
                     HashPolicy::None
                 }
             }
-            Self::Require(hashes) => HashPolicy::Validate(
-                hashes
-                    .get(&distribution.version_id())
-                    .map(Vec::as_slice)
-                    .unwrap_or_default(),
-            ),
+            Self::Require(hashes, exempt) => {
+                let id = distribution.version_id();
+                if exempt.contains(&id) {
+                    HashPolicy::None
+                } else {
+                    Self::require(hashes.get(&id))
+                }
+            }
         }
     }
 
@@ -66,12 +74,14 @@ impl HashStrategy {
                     HashPolicy::None
                 }
             }
-            Self::Require(hashes) => HashPolicy::Validate(
-                hashes
-                    .get(&VersionId::from_registry(name.clone(), version.clone()))
-                    .map(Vec::as_slice)
-                    .unwrap_or_default(),
-            ),
+            Self::Require(hashes, exempt) => {
+                let id = VersionId::from_registry(name.clone(), version.clone());
+                if exempt.contains(&id) {
+                    HashPolicy::None
+                } else {
+                    Self::require(hashes.get(&id))
+                }
+            }
         }
     }
 
@@ -87,12 +97,14 @@ impl HashStrategy {
                     HashPolicy::None
                 }
             }
-            Self::Require(hashes) => HashPolicy::Validate(
-                hashes
-                    .get(&VersionId::from_url(url))
-                    .map(Vec::as_slice)
-                    .unwrap_or_default(),
-            ),
+            Self::Require(hashes, exempt) => {
+                let id = VersionId::from_url(url);
+                if exempt.contains(&id) {
+                    HashPolicy::None
+                } else {
+                    Self::require(hashes.get(&id))
+                }
+            }
         }
     }
 
@@ -102,8 +114,9 @@ impl HashStrategy {
             Self::None => true,
             Self::Generate(_) => true,
             Self::Verify(_) => true,
-            Self::Require(hashes) => {
-                hashes.contains_key(&VersionId::from_registry(name.clone(), version.clone()))
+            Self::Require(hashes, exempt) => {
+                let id = VersionId::from_registry(name.clone(), version.clone());
+                hashes.contains_key(&id) || exempt.contains(&id)
             }
         }
     }
@@ -114,7 +127,27 @@ impl HashStrategy {
             Self::None => true,
             Self::Generate(_) => true,
             Self::Verify(_) => true,
-            Self::Require(hashes) => hashes.contains_key(&VersionId::from_url(url)),
+            Self::Require(hashes, exempt) => {
+                let id = VersionId::from_url(url);
+                hashes.contains_key(&id) || exempt.contains(&id)
+            }
+        }
+    }
+
+    /// Returns `true` if the given distribution was exempted from hash-checking via
+    /// `--allow-unhashed`.
+    pub fn is_exempt<T: DistributionMetadata>(&self, distribution: &T) -> bool {
+        match self {
+            Self::None | Self::Generate(_) | Self::Verify(_) => false,
+            Self::Require(_, exempt) => exempt.contains(&distribution.version_id()),
+        }
+    }
+
+    /// Return the [`HashPolicy`] for a package under `--require-hashes`, given its known hashes.
+    fn require(hashes: Option<&Vec<HashDigest>>) -> HashPolicy<'_> {
+        match hashes {
+            Some(hashes) => HashPolicy::Validate(hashes.as_slice()),
+            None => HashPolicy::Validate(&[]),
         }
     }
 
@@ -124,11 +157,16 @@ impl HashStrategy {
     /// that reference the environment as true. In other words, it does
     /// environment independent expression evaluation. (Which in turn devolves
     /// to "only evaluate marker expressions that reference an extra name.")
+    ///
+    /// Under `--require-hashes`, requirements that can't be verified against a hash (i.e.,
+    /// editables, local directories, and Git repositories) are rejected, unless they're named in
+    /// `allow_unhashed`, in which case they're installed without hash-checking.
     pub fn from_requirements<'a>(
         requirements: impl Iterator<Item = (&'a UnresolvedRequirement, &'a [String])>,
         constraints: impl Iterator<Item = (&'a Requirement, &'a [String])>,
         marker_env: Option<&ResolverMarkerEnvironment>,
         mode: HashCheckingMode,
+        allow_unhashed: &[PackageName],
     ) -> Result<Self, HashStrategyError> {
         let mut constraint_hashes = FxHashMap::<VersionId, Vec<HashDigest>>::default();
 
@@ -177,6 +215,8 @@ impl HashStrategy {
         // For each requirement, map from name to allowed hashes. We use the last entry for each
         // package.
         let mut requirement_hashes = FxHashMap::<VersionId, Vec<HashDigest>>::default();
+        let mut exempt = FxHashSet::<VersionId>::default();
+        let mut unhashable = Vec::new();
         for (requirement, digests) in requirements {
             if !requirement
                 .evaluate_markers(marker_env.map(ResolverMarkerEnvironment::markers), &[])
@@ -184,6 +224,22 @@ impl HashStrategy {
                 continue;
             }
 
+            // Under `--require-hashes`, reject any requirements that can't be hashed, unless
+            // they've been explicitly exempted.
+            if mode.is_require()
+                && let Some(kind) = Self::unhashable(requirement)
+            {
+                if let UnresolvedRequirement::Named(requirement) = requirement
+                    && allow_unhashed.contains(&requirement.name)
+                    && let Some(id) = Self::pin(requirement)
+                {
+                    exempt.insert(id);
+                } else {
+                    unhashable.push(format!("{requirement} ({kind})"));
+                }
+                continue;
+            }
+
             // Every requirement must be either a pinned version or a direct URL.
             let id = match &requirement {
                 UnresolvedRequirement::Named(requirement) => {
@@ -257,6 +313,10 @@ impl HashStrategy {
             requirement_hashes.insert(id, digests);
         }
 
+        if !unhashable.is_empty() {
+            return Err(HashStrategyError::UnhashableRequirements(unhashable, mode));
+        }
+
         // Merge the hashes, preferring requirements over constraints, since overlapping
         // requirements were already merged.
         let hashes: FxHashMap<VersionId, Vec<HashDigest>> = constraint_hashes
@@ -265,7 +325,7 @@ impl HashStrategy {
             .collect();
         match mode {
             HashCheckingMode::Verify => Ok(Self::Verify(Arc::new(hashes))),
-            HashCheckingMode::Require => Ok(Self::Require(Arc::new(hashes))),
+            HashCheckingMode::Require => Ok(Self::Require(Arc::new(hashes), Arc::new(exempt))),
         }
     }
 
@@ -292,7 +352,80 @@ impl HashStrategy {
 
         match mode {
             HashCheckingMode::Verify => Ok(Self::Verify(Arc::new(hashes))),
-            HashCheckingMode::Require => Ok(Self::Require(Arc::new(hashes))),
+            HashCheckingMode::Require => Ok(Self::Require(
+                Arc::new(hashes),
+                Arc::new(FxHashSet::default()),
+            )),
+        }
+    }
+
+    /// Combine two [`HashStrategy`]s, e.g., for requirements that are resolved in separate layers.
+    ///
+    /// The stricter of the two policies is used. If both strategies include hashes for the same
+    /// distribution, their intersection is used, as in [`HashStrategy::from_requirements`].
+    #[must_use]
+    pub fn merge(self, other: Self) -> Self {
+        match (self, other) {
+            (Self::None, other) => other,
+            (strategy, Self::None) => strategy,
+            (Self::Generate(mode), Self::Generate(_)) => Self::Generate(mode),
+            (Self::Generate(_), strategy) | (strategy, Self::Generate(_)) => strategy,
+            (Self::Verify(left), Self::Verify(right)) => {
+                Self::Verify(Arc::new(Self::merge_hashes(&left, &right)))
+            }
+            (Self::Require(left, exempt), Self::Verify(right))
+            | (Self::Verify(right), Self::Require(left, exempt)) => {
+                Self::Require(Arc::new(Self::merge_hashes(&left, &right)), exempt)
+            }
+            (Self::Require(left, left_exempt), Self::Require(right, right_exempt)) => {
+                Self::Require(
+                    Arc::new(Self::merge_hashes(&left, &right)),
+                    Arc::new(left_exempt.union(&right_exempt).cloned().collect()),
+                )
+            }
+        }
+    }
+
+    /// Combine the hashes for each distribution in two maps, intersecting the hashes for any
+    /// distribution that appears in both.
+    fn merge_hashes(
+        left: &FxHashMap<VersionId, Vec<HashDigest>>,
+        right: &FxHashMap<VersionId, Vec<HashDigest>>,
+    ) -> FxHashMap<VersionId, Vec<HashDigest>> {
+        let mut hashes = left.clone();
+        for (id, digests) in right {
+            if let Some(existing) = hashes.get_mut(id) {
+                existing.retain(|digest| digests.contains(digest));
+            } else {
+                hashes.insert(id.clone(), digests.clone());
+            }
+        }
+        hashes
+    }
+
+    /// If the requirement can't be verified against a hash (e.g., it's an editable), return a
+    /// description of its kind.
+    pub fn unhashable(requirement: &UnresolvedRequirement) -> Option<&'static str> {
+        match requirement {
+            UnresolvedRequirement::Named(requirement) => match &requirement.source {
+                RequirementSource::Directory {
+                    editable: Some(true),
+                    ..
+                } => Some("editable"),
+                RequirementSource::Directory { .. } => Some("local directory"),
+                RequirementSource::Git { .. } => Some("Git repository"),
+                RequirementSource::Registry { .. }
+                | RequirementSource::Url { .. }
+                | RequirementSource::Path { .. } => None,
+            },
+            UnresolvedRequirement::Unnamed(requirement) => match &requirement.url.parsed_url {
+                ParsedUrl::Directory(directory) if directory.editable == Some(true) => {
+                    Some("editable")
+                }
+                ParsedUrl::Directory(_) => Some("local directory"),
+                ParsedUrl::Git(_) => Some("Git repository"),
+                ParsedUrl::Path(_) | ParsedUrl::Archive(_) => None,
+            },
         }
     }
 
@@ -333,6 +466,12 @@ pub enum HashStrategyError {
     UnpinnedRequirement(String, HashCheckingMode),
     #[error("In `{1}` mode, all requirements must have a hash, but none were provided for: {0}")]
     MissingHashes(String, HashCheckingMode),
+    #[error(
+        "In `{mode}` mode, all requirements must be hashable, but found:\n- {requirements}\n\nhint: To install a named requirement without hash-checking, pass `--allow-unhashed <name>`",
+        mode = .1,
+        requirements = .0.join("\n- ")
+    )]
+    UnhashableRequirements(Vec<String>, HashCheckingMode),
     #[error(
         "In `{1}` mode, all requirements must have a hash, but there were no overlapping hashes between the requirements and constraints for: {0}"
     )]
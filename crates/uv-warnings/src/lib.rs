@@ -10,6 +10,8 @@ pub use anstream;
 pub use owo_colors;
 use owo_colors::{DynColor, OwoColorize};
 use rustc_hash::FxHashSet;
+#[doc(hidden)]
+pub use uv_redacted::redact_credentials;
 
 /// Whether user-facing warnings are enabled.
 pub static ENABLED: AtomicBool = AtomicBool::new(false);
@@ -25,6 +27,8 @@ pub fn disable() {
 }
 
 /// Warn a user, if warnings are enabled.
+///
+/// Any credentials embedded in URLs in the message are redacted.
 #[macro_export]
 macro_rules! warn_user {
     ($($arg:tt)*) => {{
@@ -32,7 +36,8 @@ macro_rules! warn_user {
         use $crate::owo_colors::OwoColorize;
 
         if $crate::ENABLED.load(std::sync::atomic::Ordering::Relaxed) {
-            let message = format!("{}", format_args!($($arg)*));
+            let message = $crate::redact_credentials(&format!("{}", format_args!($($arg)*)))
+                .into_owned();
             let formatted = message.bold();
             eprintln!("{}{} {formatted}", "warning".yellow().bold(), ":".bold());
         }
@@ -43,6 +48,8 @@ pub static WARNINGS: LazyLock<Mutex<FxHashSet<String>>> = LazyLock::new(Mutex::d
 
 /// Warn a user once, if warnings are enabled, with uniqueness determined by the content of the
 /// message.
+///
+/// Any credentials embedded in URLs in the message are redacted.
 #[macro_export]
 macro_rules! warn_user_once {
     ($($arg:tt)*) => {{
@@ -51,7 +58,8 @@ macro_rules! warn_user_once {
 
         if $crate::ENABLED.load(std::sync::atomic::Ordering::Relaxed) {
             if let Ok(mut states) = $crate::WARNINGS.lock() {
-                let message = format!("{}", format_args!($($arg)*));
+                let message = $crate::redact_credentials(&format!("{}", format_args!($($arg)*)))
+                    .into_owned();
                 if states.insert(message.clone()) {
                     eprintln!("{}{} {}", "warning".yellow().bold(), ":".bold(), message.bold());
                 }
@@ -121,9 +121,20 @@ pub struct PyProjectToml {
 
 impl PyProjectToml {
     /// Parse a `PyProjectToml` from a raw TOML string.
-    #[instrument("toml::from_str workspace", skip_all, fields(path = %_path.as_ref().display()))]
-    pub fn from_string(raw: String, _path: impl AsRef<Path>) -> Result<Self, PyprojectTomlError> {
-        let pyproject = toml::from_str(&raw).map_err(PyprojectTomlError::Toml)?;
+    #[instrument("toml::from_str workspace", skip_all, fields(path = %path.as_ref().display()))]
+    ///
+    /// Any `credentials` configured for the project's indexes are ignored, as they're only
+    /// respected in user-level configuration.
+    pub fn from_string(raw: String, path: impl AsRef<Path>) -> Result<Self, PyprojectTomlError> {
+        let mut pyproject: Self = toml::from_str(&raw).map_err(PyprojectTomlError::Toml)?;
+        if let Some(uv) = pyproject.tool.as_mut().and_then(|tool| tool.uv.as_mut()) {
+            uv.index = uv.index.take().map(|indexes| {
+                indexes
+                    .into_iter()
+                    .map(|index| index.without_credentials(Some(path.as_ref())))
+                    .collect()
+            });
+        }
         Ok(Self { raw, ..pyproject })
     }
 
@@ -1188,8 +1199,17 @@ pub enum Source {
         group: Option<GroupName>,
     },
     /// A dependency pinned to a specific index, e.g., `torch` after setting `torch` to `https://download.pytorch.org/whl/cu118`.
+    ///
+    /// The index may be referenced by the name of an index defined in `[[tool.uv.index]]`, or
+    /// by its URL.
+    ///
+    /// Example:
+    /// ```toml
+    /// torch = { index = "pytorch" }
+    /// internal = { index = "https://private.example.com/simple" }
+    /// ```
     Registry {
-        index: IndexName,
+        index: SourceIndex,
         #[serde(
             skip_serializing_if = "uv_pep508::marker::ser::is_empty",
             serialize_with = "uv_pep508::marker::ser::serialize",
@@ -1237,7 +1257,7 @@ impl<'de> Deserialize<'de> for Source {
             path: Option<PortablePathBuf>,
             editable: Option<bool>,
             package: Option<bool>,
-            index: Option<IndexName>,
+            index: Option<SourceIndex>,
             workspace: Option<bool>,
             #[serde(
                 skip_serializing_if = "uv_pep508::marker::ser::is_empty",
@@ -1568,6 +1588,49 @@ impl<'de> Deserialize<'de> for Source {
     }
 }
 
+/// The index to which a `tool.uv.sources` entry is pinned.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(untagged)]
+pub enum SourceIndex {
+    /// The name of an index defined in `[[tool.uv.index]]`.
+    Name(IndexName),
+    /// The URL of an index, e.g., `https://download.pytorch.org/whl/cu118`.
+    ///
+    /// The package will only ever be fetched from this index, regardless of whether the index is
+    /// defined in `[[tool.uv.index]]`.
+    Url(DisplaySafeUrl),
+}
+
+impl std::fmt::Display for SourceIndex {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Name(name) => write!(f, "{name}"),
+            Self::Url(url) => write!(f, "{url}"),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for SourceIndex {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+
+        // Index names can't contain a `:`, so anything that looks like a URL is treated as one.
+        if value.contains("://") {
+            DisplaySafeUrl::parse(&value)
+                .map(Self::Url)
+                .map_err(serde::de::Error::custom)
+        } else {
+            IndexName::from_str(&value)
+                .map(Self::Name)
+                .map_err(serde::de::Error::custom)
+        }
+    }
+}
+
 #[derive(Error, Debug)]
 pub enum SourceError {
     #[error("Failed to resolve Git reference: `{0}`")]
@@ -1715,7 +1778,7 @@ impl Source {
             RequirementSource::Registry { index: None, .. } => {
                 if let Some(index) = index {
                     Self::Registry {
-                        index,
+                        index: SourceIndex::Name(index),
                         marker: MarkerTree::TRUE,
                         extra: None,
                         group: None,
@@ -72,6 +72,11 @@ pub enum WorkspaceError {
         _0
     )]
     EditableConflict(PackageName),
+    #[error(
+        "Workspace member `{}` has sources with different `editable` values under different markers, but editability can't vary by platform",
+        _0
+    )]
+    EditableMarkerConflict(PackageName),
     #[error("Failed to find directories for glob: `{0}`")]
     Pattern(String, #[source] PatternError),
     // Syntax and other errors.
@@ -449,19 +454,30 @@ impl Workspace {
                     .flatten(),
             )
         {
-            for source in sources.iter() {
-                let Source::Workspace { editable, .. } = &source else {
-                    continue;
-                };
-                let existing = required_members.insert(package.clone(), *editable);
-                if let Some(Some(existing)) = existing {
-                    if let Some(editable) = editable {
-                        // If there are conflicting `editable` values, raise an error.
-                        if existing != *editable {
-                            return Err(WorkspaceError::EditableConflict(package.clone()));
-                        }
-                    }
+            let mut editables = sources.iter().filter_map(|source| match source {
+                Source::Workspace { editable, .. } => Some(*editable),
+                _ => None,
+            });
+            let Some(editable) = editables.next() else {
+                continue;
+            };
+
+            // Editability is a property of the member, rather than of a given environment, so it
+            // can't vary across marker-specific sources.
+            if editables.any(|other| other != editable) {
+                return Err(WorkspaceError::EditableMarkerConflict(package.clone()));
+            }
+
+            let existing = required_members.entry(package.clone()).or_insert(None);
+            match (*existing, editable) {
+                // If there are conflicting `editable` values, raise an error.
+                (Some(existing), Some(editable)) if existing != editable => {
+                    return Err(WorkspaceError::EditableConflict(package.clone()));
                 }
+                // An explicit `editable` value takes precedence over an unspecified one, even if
+                // the unspecified one is declared later (e.g., by a different member).
+                (None, Some(editable)) => *existing = Some(editable),
+                _ => {}
             }
         }
 
@@ -213,6 +213,7 @@ async fn build_impl(
         keyring_provider,
         resolution: _,
         prerelease: _,
+        prerelease_packages: _,
         fork_strategy: _,
         dependency_metadata,
         config_setting,
@@ -226,6 +227,11 @@ async fn build_impl(
         build_options,
         sources,
         torch_backend: _,
+        excluded_packages: _,
+        resolution_priority: _,
+        max_backtracks: _,
+        memory_limit: _,
+        allow_yanked: _,
     } = settings;
 
     // Determine the source to build.
@@ -568,6 +574,7 @@ async fn build_package(
                 .map(|entry| (&entry.requirement, entry.hashes.as_slice())),
             Some(&interpreter.resolver_marker_environment()),
             hash_checking,
+            &[],
         )?
     } else {
         HashStrategy::None
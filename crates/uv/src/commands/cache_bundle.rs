@@ -0,0 +1,388 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::path::{Component, Path, PathBuf};
+use std::str::FromStr;
+
+use anyhow::{Context, Result, bail};
+use itertools::Itertools;
+use owo_colors::OwoColorize;
+use serde::{Deserialize, Serialize};
+use tracing::debug;
+use walkdir::{DirEntry, WalkDir};
+
+use uv_cache::{ArchiveId, Cache, CacheBucket};
+use uv_client::BaseClientBuilder;
+use uv_distribution_types::{RequirementSource, UnresolvedRequirement};
+use uv_fs::Simplified;
+use uv_pep440::{Operator, Version, VersionSpecifiers};
+use uv_preview::{Preview, PreviewFeature};
+use uv_requirements::{RequirementsSource, RequirementsSpecification};
+use uv_warnings::warn_user;
+
+use crate::commands::{ExitStatus, human_readable_bytes};
+use crate::printer::Printer;
+
+/// The name of the manifest within a cache bundle.
+const MANIFEST: &str = "uv-cache-bundle.json";
+
+/// The version of the cache bundle format.
+const BUNDLE_VERSION: u32 = 1;
+
+/// The manifest of a cache bundle.
+#[derive(Debug, Serialize, Deserialize)]
+struct Manifest {
+    /// The version of the bundle format.
+    version: u32,
+    /// The links to unzipped wheels in the archive bucket, keyed by their path relative to the
+    /// cache root.
+    ///
+    /// On Unix, links are symlinks to absolute paths within the cache, so they're recreated on
+    /// import rather than included in the bundle directly.
+    links: BTreeMap<String, ArchiveId>,
+}
+
+/// Export the cache to a bundle, optionally limited to the packages in the given requirements.
+pub(crate) async fn cache_export(
+    output: &Path,
+    filter: &[RequirementsSource],
+    client_builder: &BaseClientBuilder<'_>,
+    cache: &Cache,
+    printer: Printer,
+    preview: Preview,
+) -> Result<ExitStatus> {
+    if !preview.is_enabled(PreviewFeature::CacheBundles) {
+        warn_user!(
+            "`uv cache export` is experimental and may change without warning. Pass `--preview-features {}` to disable this warning.",
+            PreviewFeature::CacheBundles
+        );
+    }
+
+    // Determine the cache entries to export.
+    let roots = if filter.is_empty() {
+        [
+            CacheBucket::Wheels,
+            CacheBucket::SourceDistributions,
+            CacheBucket::Simple,
+            CacheBucket::FlatIndex,
+            CacheBucket::Git,
+        ]
+        .into_iter()
+        .map(|bucket| cache.bucket(bucket))
+        .collect::<Vec<_>>()
+    } else {
+        let spec = RequirementsSpecification::from_simple_sources(filter, client_builder).await?;
+        if spec.pylock.is_some() {
+            bail!("`pylock.toml` is not a supported filter format for `uv cache export`");
+        }
+
+        // Only the pinned versions are exported, so the filter must be fully resolved (e.g., the
+        // output of `uv pip compile` or `uv export`), such that it includes every transitive
+        // dependency.
+        let mut packages = BTreeMap::new();
+        for entry in spec.requirements {
+            match entry.requirement {
+                UnresolvedRequirement::Named(requirement) => {
+                    let version = match &requirement.source {
+                        RequirementSource::Registry { specifier, .. } => {
+                            let Some(version) = pinned_version(specifier) else {
+                                bail!(
+                                    "`uv cache export` requires pinned requirements, but found: `{requirement}`\n\n{}{} Use `uv pip compile` or `uv export` to generate a filter with every package in the resolution pinned to an exact version",
+                                    "hint".bold().cyan(),
+                                    ":".bold(),
+                                );
+                            };
+                            Some(version)
+                        }
+                        // Direct URL, path, and Git dependencies aren't keyed by version.
+                        RequirementSource::Url { .. }
+                        | RequirementSource::Git { .. }
+                        | RequirementSource::Path { .. }
+                        | RequirementSource::Directory { .. } => None,
+                    };
+                    packages.insert(requirement.name, version);
+                }
+                UnresolvedRequirement::Unnamed(requirement) => {
+                    warn_user!("Ignoring unnamed requirement in filter: `{requirement}`");
+                }
+            }
+        }
+
+        let mut roots = Vec::new();
+        for (package, version) in &packages {
+            for bucket in [
+                CacheBucket::Wheels,
+                CacheBucket::SourceDistributions,
+                CacheBucket::Simple,
+            ] {
+                if let Some(version) = version {
+                    roots.extend(bucket.package_version_entries(cache, package, version)?);
+                } else {
+                    roots.extend(bucket.package_entries(cache, package)?);
+                }
+            }
+        }
+
+        // The flat index entries can't be attributed to a package, so they're always included.
+        roots.push(cache.bucket(CacheBucket::FlatIndex));
+        roots
+    };
+
+    // Collect the files to export, along with any links to unzipped wheels.
+    let archive_bucket = fs_err::canonicalize(cache.bucket(CacheBucket::Archive)).ok();
+    let mut files = Vec::new();
+    let mut links = BTreeMap::new();
+    for root in roots {
+        if !root.exists() {
+            continue;
+        }
+        for entry in WalkDir::new(&root) {
+            let entry = entry?;
+            if entry.file_type().is_dir() || is_lock_file(entry.path()) {
+                continue;
+            }
+            if let Some(id) = archive_link(cache, archive_bucket.as_deref(), &entry) {
+                links.insert(relative_path(cache, entry.path())?, id);
+            } else if entry.file_type().is_file() {
+                files.push(entry.into_path());
+            }
+        }
+    }
+
+    // Include the unzipped wheels referenced by the links.
+    for id in links.values().unique() {
+        for entry in WalkDir::new(cache.archive(id)) {
+            let entry = entry?;
+            if entry.file_type().is_file() {
+                files.push(entry.into_path());
+            }
+        }
+    }
+
+    // Write the bundle, starting with the manifest.
+    let manifest = serde_json::to_vec_pretty(&Manifest {
+        version: BUNDLE_VERSION,
+        links,
+    })?;
+    let file = fs_err::File::create(output)?;
+    let mut builder = tar::Builder::new(zstd::Encoder::new(file, 0)?);
+    let mut header = tar::Header::new_gnu();
+    header.set_size(manifest.len() as u64);
+    header.set_mode(0o644);
+    builder.append_data(&mut header, MANIFEST, manifest.as_slice())?;
+    for path in &files {
+        builder
+            .append_path_with_name(path, relative_path(cache, path)?)
+            .with_context(|| format!("Failed to add `{}` to bundle", path.user_display()))?;
+    }
+    builder.into_inner()?.finish()?;
+
+    let (bytes, unit) = human_readable_bytes(fs_err::metadata(output)?.len());
+    let s = if files.len() == 1 { "" } else { "s" };
+    writeln!(
+        printer.stderr(),
+        "Exported {} to {} ({})",
+        format!("{} file{s}", files.len()).bold(),
+        output.user_display().cyan(),
+        format!("{bytes:.1}{unit}").green()
+    )?;
+
+    Ok(ExitStatus::Success)
+}
+
+/// Import a bundle created by `uv cache export` into the cache.
+pub(crate) async fn cache_import(
+    bundle: &Path,
+    cache: Cache,
+    printer: Printer,
+    preview: Preview,
+) -> Result<ExitStatus> {
+    if !preview.is_enabled(PreviewFeature::CacheBundles) {
+        warn_user!(
+            "`uv cache import` is experimental and may change without warning. Pass `--preview-features {}` to disable this warning.",
+            PreviewFeature::CacheBundles
+        );
+    }
+
+    // Entries are moved into place (and links recreated) underneath any running uv processes, so
+    // wait for them to finish.
+    let cache = match cache.with_exclusive_lock_no_wait() {
+        Ok(cache) => cache,
+        Err(cache) => {
+            writeln!(
+                printer.stderr(),
+                "Cache is currently in-use, waiting for other uv processes to finish"
+            )?;
+            cache.with_exclusive_lock().await?
+        }
+    };
+
+    // Unpack the bundle into a temporary directory within the cache, such that its entries can be
+    // moved into place atomically.
+    let temp_dir = tempfile::tempdir_in(cache.root())?;
+    let file = fs_err::File::open(bundle)?;
+    let mut archive = tar::Archive::new(zstd::Decoder::new(file)?);
+    for entry in archive
+        .entries()
+        .with_context(|| format!("Failed to read bundle: `{}`", bundle.user_display()))?
+    {
+        let mut entry =
+            entry.with_context(|| format!("Failed to read bundle: `{}`", bundle.user_display()))?;
+        let path = entry.path()?.into_owned();
+        if !matches!(
+            entry.header().entry_type(),
+            tar::EntryType::Regular | tar::EntryType::Directory
+        ) {
+            bail!(
+                "Invalid cache bundle: `{}` is not a regular file",
+                path.display()
+            );
+        }
+        if !entry.unpack_in(temp_dir.path())? {
+            bail!(
+                "Invalid cache bundle: `{}` is outside the cache",
+                path.display()
+            );
+        }
+    }
+
+    // Read the manifest.
+    let manifest = match fs_err::read(temp_dir.path().join(MANIFEST)) {
+        Ok(manifest) => serde_json::from_slice::<Manifest>(&manifest)
+            .with_context(|| format!("Invalid cache bundle manifest: `{MANIFEST}`"))?,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+            bail!(
+                "Invalid cache bundle: `{}` is missing a `{MANIFEST}`",
+                bundle.user_display()
+            );
+        }
+        Err(err) => return Err(err.into()),
+    };
+    if manifest.version != BUNDLE_VERSION {
+        bail!(
+            "Unsupported cache bundle version: {} (expected {BUNDLE_VERSION})",
+            manifest.version
+        );
+    }
+
+    // Move the entries into the cache. Unzipped wheels are immutable, so they're only moved if
+    // they don't already exist.
+    let archive_bucket = cache.bucket(CacheBucket::Archive);
+    let archive_bucket = archive_bucket
+        .file_name()
+        .expect("archive bucket has a name");
+    let mut num_files = 0;
+    let mut walker = WalkDir::new(temp_dir.path()).min_depth(1).into_iter();
+    while let Some(entry) = walker.next() {
+        let entry = entry?;
+        let relative = entry
+            .path()
+            .strip_prefix(temp_dir.path())
+            .expect("walkdir starts with root");
+        let target = cache.root().join(relative);
+
+        if entry.depth() == 2 && entry.file_type().is_dir() && relative.starts_with(archive_bucket)
+        {
+            walker.skip_current_dir();
+            if target.exists() {
+                debug!("Skipping existing archive: {}", target.display());
+                continue;
+            }
+            fs_err::create_dir_all(target.parent().expect("archive has a parent"))?;
+            fs_err::rename(entry.path(), &target)?;
+            num_files += WalkDir::new(&target)
+                .into_iter()
+                .filter_ok(|entry| entry.file_type().is_file())
+                .count();
+            continue;
+        }
+
+        if !entry.file_type().is_file() || relative == Path::new(MANIFEST) {
+            continue;
+        }
+        fs_err::create_dir_all(target.parent().expect("entry has a parent"))?;
+        fs_err::rename(entry.path(), &target)?;
+        num_files += 1;
+    }
+
+    // Recreate the links to the unzipped wheels.
+    for (path, id) in manifest.links {
+        let relative = PathBuf::from(&path);
+        if !is_normal(&relative) || !is_normal(id.as_ref()) {
+            bail!("Invalid cache bundle: link `{path}` is outside the cache");
+        }
+        if !cache.archive(&id).is_dir() {
+            debug!("Skipping link to missing archive: {path}");
+            continue;
+        }
+        let target = cache.root().join(relative);
+        fs_err::create_dir_all(target.parent().expect("link has a parent"))?;
+        cache.create_link(&id, &target)?;
+    }
+
+    let s = if num_files == 1 { "" } else { "s" };
+    writeln!(
+        printer.stderr(),
+        "Imported {} into cache at: {}",
+        format!("{num_files} file{s}").bold(),
+        cache.root().user_display().cyan()
+    )?;
+
+    Ok(ExitStatus::Success)
+}
+
+/// If the entry is a link to an unzipped wheel in the archive bucket, return its [`ArchiveId`].
+fn archive_link(
+    cache: &Cache,
+    archive_bucket: Option<&Path>,
+    entry: &DirEntry,
+) -> Option<ArchiveId> {
+    // On Unix, archive references use symlinks. On Windows, they're files containing structured
+    // data, without an extension.
+    let is_link = if cfg!(windows) {
+        entry.file_type().is_file() && entry.path().extension().is_none()
+    } else {
+        entry.file_type().is_symlink()
+    };
+    if !is_link {
+        return None;
+    }
+    let target = cache.resolve_link(entry.path()).ok()?;
+    if target.parent() != archive_bucket {
+        return None;
+    }
+    ArchiveId::from_str(target.file_name()?.to_str()?).ok()
+}
+
+/// Return the version that the specifiers pin to, if they consist of a single `==` specifier.
+fn pinned_version(specifiers: &VersionSpecifiers) -> Option<Version> {
+    let specifier = specifiers.iter().exactly_one().ok()?;
+    matches!(specifier.operator(), Operator::Equal | Operator::ExactEqual)
+        .then(|| specifier.version().clone())
+}
+
+/// Returns `true` if the path is a lock file, which is specific to the local cache.
+fn is_lock_file(path: &Path) -> bool {
+    path.file_name().is_some_and(|name| name == ".lock")
+        || path
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("lock"))
+}
+
+/// Returns `true` if the path is relative and doesn't traverse outside its root.
+fn is_normal(path: &Path) -> bool {
+    path.components().next().is_some()
+        && path
+            .components()
+            .all(|component| matches!(component, Component::Normal(_)))
+}
+
+/// Return the path of a cache entry relative to the cache root, with `/` separators.
+fn relative_path(cache: &Cache, path: &Path) -> Result<String> {
+    let relative = path
+        .strip_prefix(cache.root())
+        .with_context(|| format!("`{}` is not in the cache", path.user_display()))?;
+    Ok(relative
+        .components()
+        .map(|component| component.as_os_str().to_string_lossy())
+        .join("/"))
+}
@@ -0,0 +1,97 @@
+use std::collections::BTreeSet;
+use std::fmt::Write;
+use std::path::Path;
+
+use anyhow::Result;
+use owo_colors::OwoColorize;
+use walkdir::WalkDir;
+
+use uv_cache::{Cache, CacheBucket};
+use uv_distribution_filename::WheelFilename;
+use uv_normalize::PackageName;
+use uv_preview::{Preview, PreviewFeature};
+use uv_warnings::warn_user;
+
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// The extensions of the pointer and metadata files stored alongside cached wheels.
+const POINTER_EXTENSIONS: &[&str] = &[".http", ".rev", ".msgpack", ".lock"];
+
+/// List the wheels in the cache for the given packages.
+pub(crate) fn cache_list(
+    cache: &Cache,
+    packages: &[PackageName],
+    printer: Printer,
+    preview: Preview,
+) -> Result<ExitStatus> {
+    if !preview.is_enabled(PreviewFeature::CacheList) {
+        warn_user!(
+            "`uv cache list` is experimental and may change without warning. Pass `--preview-features {}` to disable this warning.",
+            PreviewFeature::CacheList
+        );
+    }
+
+    for package in packages {
+        // Wheels downloaded from an index or URL are stored in a directory per package, named by
+        // their version and tags (e.g., `1.0.0-py3-none-any`).
+        let downloaded = find_wheels(cache, CacheBucket::Wheels, |path| {
+            let name = path.file_name()?.to_str()?;
+            if path.parent()?.file_name()?.to_str()? != package.as_str() {
+                return None;
+            }
+            WheelFilename::from_stem(&format!("{package}-{name}")).ok()
+        });
+
+        // Wheels built from source are stored alongside the source distribution, named by their
+        // full filename (e.g., `foo-1.0.0-py3-none-any.whl`).
+        let built = find_wheels(cache, CacheBucket::SourceDistributions, |path| {
+            let name = path.file_name()?.to_str()?;
+            let filename = if let Some(stem) = name.strip_suffix(".whl") {
+                WheelFilename::from_stem(stem).ok()?
+            } else {
+                WheelFilename::from_stem(name).ok()?
+            };
+            (filename.name == *package).then_some(filename)
+        });
+
+        if downloaded.is_empty() && built.is_empty() {
+            writeln!(
+                printer.stderr(),
+                "No wheels found in the cache for {}",
+                package.cyan()
+            )?;
+            continue;
+        }
+
+        for filename in &downloaded {
+            writeln!(printer.stdout(), "{filename}")?;
+        }
+        for filename in &built {
+            writeln!(printer.stdout(), "{filename} {}", "(built)".dimmed())?;
+        }
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Find the wheels in a cache bucket, using `parse` to extract a [`WheelFilename`] from each
+/// entry.
+fn find_wheels(
+    cache: &Cache,
+    bucket: CacheBucket,
+    parse: impl Fn(&Path) -> Option<WheelFilename>,
+) -> BTreeSet<WheelFilename> {
+    WalkDir::new(cache.bucket(bucket))
+        .max_depth(8)
+        .into_iter()
+        .filter_map(Result::ok)
+        .filter(|entry| {
+            let name = entry.file_name().to_string_lossy();
+            !POINTER_EXTENSIONS
+                .iter()
+                .any(|extension| name.ends_with(extension))
+        })
+        .filter_map(|entry| parse(entry.path()))
+        .collect()
+}
@@ -0,0 +1,308 @@
+use std::fmt::Write;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use owo_colors::OwoColorize;
+use tracing::debug;
+use walkdir::WalkDir;
+
+use uv_cache::{Cache, CacheBucket, Removal, integrity, rm_rf};
+use uv_client::DataWithCachePolicy;
+use uv_fs::Simplified;
+use uv_install_wheel::{RecordMismatch, verify_wheel};
+use uv_preview::{Preview, PreviewFeature};
+use uv_warnings::warn_user;
+
+use crate::commands::{ExitStatus, human_readable_bytes};
+use crate::printer::Printer;
+
+/// Verify the integrity of the cache, removing any corrupted entries.
+pub(crate) async fn cache_verify(
+    dry_run: bool,
+    force: bool,
+    cache: Cache,
+    printer: Printer,
+    preview: Preview,
+) -> Result<ExitStatus> {
+    if !preview.is_enabled(PreviewFeature::CacheVerify) {
+        warn_user!(
+            "`uv cache verify` is experimental and may change without warning. Pass `--preview-features {}` to disable this warning.",
+            PreviewFeature::CacheVerify
+        );
+    }
+
+    if !cache.root().exists() {
+        writeln!(
+            printer.stderr(),
+            "No cache found at: {}",
+            cache.root().user_display().cyan()
+        )?;
+        return Ok(ExitStatus::Success);
+    }
+
+    let cache = match cache.with_exclusive_lock_no_wait() {
+        Ok(cache) => cache,
+        Err(cache) if force => {
+            debug!("Cache is currently in use, proceeding due to `--force`");
+            cache
+        }
+        Err(cache) => {
+            writeln!(
+                printer.stderr(),
+                "Cache is currently in-use, waiting for other uv processes to finish (use `--force` to override)"
+            )?;
+            cache.with_exclusive_lock().await?
+        }
+    };
+
+    writeln!(
+        printer.stderr(),
+        "Verifying cache at: {}",
+        cache.root().user_display().cyan()
+    )?;
+
+    let corrupted = find_corrupted_entries(&cache)
+        .with_context(|| format!("Failed to verify cache at: {}", cache.root().user_display()))?;
+
+    if corrupted.is_empty() {
+        writeln!(printer.stderr(), "No corrupted entries found")?;
+        return Ok(ExitStatus::Success);
+    }
+
+    let s = if corrupted.len() == 1 { "y" } else { "ies" };
+    writeln!(
+        printer.stderr(),
+        "Found {} corrupted entr{s}:",
+        corrupted.len()
+    )?;
+    for entry in &corrupted {
+        let path = entry.path.strip_prefix(cache.root()).unwrap_or(&entry.path);
+        writeln!(
+            printer.stderr(),
+            " {} {}: {}",
+            "-".red(),
+            path.user_display().bold(),
+            entry.reason
+        )?;
+    }
+
+    if dry_run {
+        return Ok(ExitStatus::Failure);
+    }
+
+    // Remove the corrupted entries, along with any references to corrupted archives, such that
+    // they're fetched again the next time they're needed.
+    let references = cache.find_archive_references()?;
+    let mut summary = Removal::default();
+    for entry in corrupted {
+        if entry.archive {
+            let archive = fs_err::canonicalize(&entry.path)?;
+            for reference in references.get(&archive).into_iter().flatten() {
+                debug!(
+                    "Removing reference to corrupted archive: {}",
+                    reference.display()
+                );
+                summary += rm_rf(reference)?;
+            }
+        }
+        debug!("Removing corrupted cache entry: {}", entry.path.display());
+        summary += rm_rf(&entry.path)?;
+    }
+
+    // Write a summary of the number of files and directories removed.
+    match (summary.num_files, summary.num_dirs) {
+        (0, 0) => {
+            write!(printer.stderr(), "No entries removed")?;
+        }
+        (0, 1) => {
+            write!(printer.stderr(), "Removed 1 directory")?;
+        }
+        (0, num_dirs_removed) => {
+            write!(printer.stderr(), "Removed {num_dirs_removed} directories")?;
+        }
+        (1, _) => {
+            write!(printer.stderr(), "Removed 1 file")?;
+        }
+        (num_files_removed, _) => {
+            write!(printer.stderr(), "Removed {num_files_removed} files")?;
+        }
+    }
+
+    // If any, write a summary of the total byte count removed.
+    if summary.total_bytes > 0 {
+        let bytes = if summary.total_bytes < 1024 {
+            format!("{}B", summary.total_bytes)
+        } else {
+            let (bytes, unit) = human_readable_bytes(summary.total_bytes);
+            format!("{bytes:.1}{unit}")
+        };
+        write!(printer.stderr(), " ({})", bytes.green())?;
+    }
+
+    writeln!(printer.stderr())?;
+
+    Ok(ExitStatus::Success)
+}
+
+/// A corrupted entry in the cache.
+#[derive(Debug)]
+struct CorruptedEntry {
+    /// The path to the entry.
+    path: PathBuf,
+    /// Whether the entry is an unzipped wheel in the archive bucket.
+    archive: bool,
+    /// A description of the corruption.
+    reason: String,
+}
+
+/// Find the corrupted entries in the cache.
+fn find_corrupted_entries(cache: &Cache) -> Result<Vec<CorruptedEntry>> {
+    let mut corrupted = Vec::new();
+
+    // First, validate the integrity metadata of the archive pointers and cached metadata.
+    for bucket in [CacheBucket::Wheels, CacheBucket::SourceDistributions] {
+        let bucket_path = cache.bucket(bucket);
+        if !bucket_path.is_dir() {
+            continue;
+        }
+
+        // Ignore the `src` directories, which represent unpacked source distributions.
+        let walker = WalkDir::new(&bucket_path)
+            .into_iter()
+            .filter_entry(|entry| entry.file_name() != "src");
+        for entry in walker {
+            let entry = entry?;
+            if !entry.file_type().is_file() {
+                continue;
+            }
+            if let Some(reason) = verify_sealed_entry(entry.path())? {
+                corrupted.push(CorruptedEntry {
+                    path: entry.into_path(),
+                    archive: false,
+                    reason,
+                });
+            }
+        }
+    }
+
+    // Second, verify the contents of each unzipped wheel against its `RECORD`.
+    match fs_err::read_dir(cache.bucket(CacheBucket::Archive)) {
+        Ok(entries) => {
+            for entry in entries {
+                let entry = entry?;
+                if !entry.file_type()?.is_dir() {
+                    continue;
+                }
+                if let Some(reason) = verify_archive(&entry.path())? {
+                    corrupted.push(CorruptedEntry {
+                        path: entry.path(),
+                        archive: true,
+                        reason,
+                    });
+                }
+            }
+        }
+        Err(err) if err.kind() == io::ErrorKind::NotFound => (),
+        Err(err) => return Err(err.into()),
+    }
+
+    Ok(corrupted)
+}
+
+/// Validate the integrity metadata of a sealed cache entry.
+///
+/// Returns a description of the corruption, if any. Entries written by an incompatible version of
+/// uv are treated as cache misses on read, and so aren't considered corrupt.
+fn verify_sealed_entry(path: &Path) -> Result<Option<String>> {
+    let Some(extension) = path.extension().and_then(|ext| ext.to_str()) else {
+        return Ok(None);
+    };
+
+    // Archive pointers for local distributions (`.rev`) and built wheel metadata are sealed
+    // directly, while responses from the HTTP cache (`.http`, and registry metadata in
+    // `.msgpack`) are sealed within the cached response.
+    let http = match extension {
+        "rev" => false,
+        "msgpack" => path
+            .file_name()
+            .is_none_or(|file_name| file_name != "metadata.msgpack"),
+        "http" => true,
+        _ => return Ok(None),
+    };
+
+    let bytes = fs_err::read(path)?;
+    let result = if http {
+        match DataWithCachePolicy::from_reader(bytes.as_slice()) {
+            Ok(cached) => integrity::unseal(&cached.data).map(|_| ()),
+            Err(err) => return Ok(Some(format!("Invalid HTTP cache entry: {err}"))),
+        }
+    } else {
+        integrity::unseal(&bytes).map(|_| ())
+    };
+
+    match result {
+        Ok(()) => Ok(None),
+        Err(err) if err.is_corrupt() => Ok(Some(err.to_string())),
+        Err(err) => {
+            debug!("Ignoring stale cache entry at `{}`: {err}", path.display());
+            Ok(None)
+        }
+    }
+}
+
+/// Verify the files in an unzipped wheel against its `RECORD`.
+///
+/// Returns a description of the corruption, if any. Files that aren't listed in the `RECORD` are
+/// ignored, since many wheels omit entries from their `RECORD`.
+fn verify_archive(archive: &Path) -> Result<Option<String>> {
+    let mut dist_info = None;
+    for entry in fs_err::read_dir(archive)? {
+        let entry = entry?;
+        if entry
+            .path()
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("dist-info"))
+        {
+            dist_info = Some(entry.path());
+            break;
+        }
+    }
+
+    // If the archive doesn't contain a `.dist-info` directory, it isn't an unzipped wheel.
+    let Some(dist_info) = dist_info else {
+        return Ok(None);
+    };
+
+    match verify_wheel(&dist_info) {
+        Ok(verification) => {
+            let mismatch = verification
+                .mismatches
+                .into_iter()
+                .find(|mismatch| !matches!(mismatch, RecordMismatch::Extra(_)));
+            Ok(mismatch.map(|mismatch| match mismatch {
+                RecordMismatch::Missing(path) => {
+                    format!("`{}` is missing", path.simplified_display())
+                }
+                RecordMismatch::Modified {
+                    path,
+                    expected,
+                    actual,
+                } => format!(
+                    "`{}` has hash `{actual}`, but the `RECORD` lists `{expected}`",
+                    path.simplified_display()
+                ),
+                RecordMismatch::Extra(path) => {
+                    format!("`{}` is not listed", path.simplified_display())
+                }
+            }))
+        }
+        Err(uv_install_wheel::Error::MissingRecord(_)) => {
+            Ok(Some("The `RECORD` file is missing".to_string()))
+        }
+        Err(uv_install_wheel::Error::RecordCsv(_) | uv_install_wheel::Error::RecordFile(_)) => {
+            Ok(Some("The `RECORD` file is invalid".to_string()))
+        }
+        Err(err) => Err(err.into()),
+    }
+}
@@ -0,0 +1,422 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write;
+use std::time::Instant;
+
+use anyhow::{Context, Result, anyhow};
+use futures::{StreamExt, TryStreamExt};
+use owo_colors::OwoColorize;
+use tracing::debug;
+
+use uv_cache::Cache;
+use uv_client::{BaseClientBuilder, FlatIndexClient, RegistryClientBuilder};
+use uv_configuration::{
+    BuildIsolation, Concurrency, Constraints, Reinstall, TargetTriple, Upgrade,
+};
+use uv_dispatch::{BuildDispatch, SharedState};
+use uv_distribution::{DistributionDatabase, LoweredExtraBuildDependencies};
+use uv_distribution_types::{
+    Dist, HashPolicy, Identifier, Index, NameRequirementSpecification, Origin, Requirement,
+    Resolution, ResolvedDist,
+};
+use uv_fs::Simplified;
+use uv_preview::{Preview, PreviewFeature};
+use uv_pypi_types::Conflicts;
+use uv_python::{
+    EnvironmentPreference, PythonDownloads, PythonEnvironment, PythonInstallation,
+    PythonPreference, PythonRequest, PythonVersion,
+};
+use uv_requirements::{RequirementsSource, RequirementsSpecification};
+use uv_resolver::{
+    FlatIndex, InMemoryIndex, OptionsBuilder, PythonRequirement, ResolverEnvironment,
+};
+use uv_types::{EmptyInstalledPackages, HashStrategy};
+use uv_warnings::warn_user;
+use uv_workspace::WorkspaceCache;
+
+use crate::commands::pip::loggers::DefaultResolveLogger;
+use crate::commands::pip::{operations, resolution_markers, resolution_tags};
+use crate::commands::reporters::PythonDownloadReporter;
+use crate::commands::{ExitStatus, diagnostics, elapsed};
+use crate::printer::Printer;
+use crate::settings::PipSettings;
+
+/// Populate the cache with the distributions required by a set of requirements, for each of the
+/// given target environments.
+pub(crate) async fn cache_warm(
+    requirements: &[RequirementsSource],
+    constraints: &[RequirementsSource],
+    constraints_from_workspace: Vec<Requirement>,
+    build_wheels: bool,
+    python_versions: Vec<PythonVersion>,
+    python_platforms: Vec<TargetTriple>,
+    settings: PipSettings,
+    client_builder: &BaseClientBuilder<'_>,
+    python_downloads: PythonDownloads,
+    python_preference: PythonPreference,
+    concurrency: Concurrency,
+    cache: Cache,
+    workspace_cache: WorkspaceCache,
+    printer: Printer,
+    preview: Preview,
+) -> Result<ExitStatus> {
+    let start = Instant::now();
+
+    if !preview.is_enabled(PreviewFeature::CacheWarm) {
+        warn_user!(
+            "`uv cache warm` is experimental and may change without warning. Pass `--preview-features {}` to disable this warning.",
+            PreviewFeature::CacheWarm
+        );
+    }
+
+    let client_builder = client_builder.clone().keyring(settings.keyring_provider);
+
+    // Read all requirements from the provided sources.
+    let RequirementsSpecification {
+        project,
+        requirements,
+        constraints,
+        overrides,
+        excludes,
+        pylock,
+        source_trees,
+        groups,
+        index_url,
+        extra_index_urls,
+        no_index,
+        find_links,
+        no_binary,
+        no_build,
+        build_options_origins,
+        extras: _,
+    } = RequirementsSpecification::from_sources(
+        requirements,
+        constraints,
+        &[],
+        &[],
+        None,
+        &client_builder,
+    )
+    .await?;
+
+    // Reject `pylock.toml` files, which are installed without a resolution.
+    if pylock.is_some() {
+        return Err(anyhow!(
+            "`pylock.toml` is not a supported input format for `uv cache warm`"
+        ));
+    }
+
+    let constraints: Vec<NameRequirementSpecification> = constraints
+        .iter()
+        .cloned()
+        .chain(
+            constraints_from_workspace
+                .into_iter()
+                .map(NameRequirementSpecification::from),
+        )
+        .collect();
+
+    // Find an interpreter to use for resolution, and for building distributions.
+    let environment_preference = EnvironmentPreference::from_system_flag(settings.system, false);
+    let python_preference = python_preference.with_system_flag(settings.system);
+    let reporter = PythonDownloadReporter::single(printer);
+    let interpreter = PythonInstallation::find_best(
+        &settings
+            .python
+            .as_deref()
+            .map(PythonRequest::parse)
+            .unwrap_or_default(),
+        environment_preference,
+        python_preference,
+        python_downloads,
+        &client_builder,
+        &cache,
+        Some(&reporter),
+        settings.install_mirrors.python_install_mirror.as_deref(),
+        settings.install_mirrors.pypy_install_mirror.as_deref(),
+        settings
+            .install_mirrors
+            .python_downloads_json_url
+            .as_deref(),
+        preview,
+    )
+    .await?
+    .into_interpreter();
+
+    debug!(
+        "Using Python {} interpreter at {} for builds",
+        interpreter.python_version(),
+        interpreter.sys_executable().user_display().cyan()
+    );
+
+    // Determine the target environments, as the product of the requested Python versions and
+    // platforms. By default, the interpreter itself is the only target.
+    let python_versions = if python_versions.is_empty() {
+        vec![None]
+    } else {
+        python_versions.into_iter().map(Some).collect()
+    };
+    let python_platforms = if python_platforms.is_empty() {
+        vec![None]
+    } else {
+        python_platforms.into_iter().map(Some).collect()
+    };
+    let targets = python_versions
+        .iter()
+        .flat_map(|python_version| {
+            python_platforms
+                .iter()
+                .map(move |python_platform| (python_version.as_ref(), python_platform.as_ref()))
+        })
+        .collect::<Vec<_>>();
+
+    // Incorporate any index locations from the provided sources.
+    let index_locations = settings.index_locations.combine(
+        extra_index_urls
+            .into_iter()
+            .map(Index::from_extra_index_url)
+            .chain(index_url.map(Index::from_index_url))
+            .map(|index| index.with_origin(Origin::RequirementsTxt))
+            .collect(),
+        find_links
+            .into_iter()
+            .map(Index::from_find_links)
+            .map(|index| index.with_origin(Origin::RequirementsTxt))
+            .collect(),
+        no_index,
+    );
+
+    // Initialize the registry client.
+    let client = RegistryClientBuilder::new(client_builder.clone(), cache.clone())
+        .index_locations(index_locations.clone())
+        .index_strategy(settings.index_strategy)
+        .markers(interpreter.markers())
+        .platform(interpreter.platform())
+        .build();
+
+    // Combine the `--no-binary` and `--no-build` flags from the requirements files.
+    let build_options =
+        settings
+            .build_options
+            .try_combine(no_binary, no_build, build_options_origins)?;
+
+    // Hashes are verified against the index when downloading, rather than during resolution.
+    let hasher = HashStrategy::None;
+
+    // Fetch the flat indexes from `--find-links`, which are filtered for each target.
+    let flat_index_entries = {
+        let client = FlatIndexClient::new(client.cached_client(), client.connectivity(), &cache);
+        client
+            .fetch_all(index_locations.flat_indexes().map(Index::url))
+            .await?
+    };
+    let interpreter_tags = interpreter.tags()?;
+    let flat_index = FlatIndex::from_entries(
+        flat_index_entries.clone(),
+        Some(interpreter_tags),
+        &hasher,
+        &build_options,
+    );
+
+    // Determine whether to enable build isolation.
+    let environment;
+    let build_isolation = match &settings.build_isolation {
+        BuildIsolation::Isolate => uv_types::BuildIsolation::Isolated,
+        BuildIsolation::Shared => {
+            environment = PythonEnvironment::from_interpreter(interpreter.clone());
+            uv_types::BuildIsolation::Shared(&environment)
+        }
+        BuildIsolation::SharedPackage(packages) => {
+            environment = PythonEnvironment::from_interpreter(interpreter.clone());
+            uv_types::BuildIsolation::SharedPackage(&environment, packages)
+        }
+    };
+
+    // Lower the extra build dependencies, if any.
+    let extra_build_requires =
+        LoweredExtraBuildDependencies::from_non_lowered(settings.extra_build_dependencies.clone())
+            .into_inner();
+
+    // Create a build dispatch.
+    let state = SharedState::default();
+    let build_constraints = Constraints::default();
+    let build_dispatch = BuildDispatch::new(
+        &client,
+        &cache,
+        &build_constraints,
+        &interpreter,
+        &index_locations,
+        &flat_index,
+        &settings.dependency_metadata,
+        state.clone(),
+        settings.index_strategy,
+        &settings.config_setting,
+        &settings.config_settings_package,
+        build_isolation,
+        &extra_build_requires,
+        &settings.extra_build_variables,
+        settings.link_mode,
+        &build_options,
+        &hasher,
+        settings.exclude_newer.clone(),
+        settings.sources.clone(),
+        workspace_cache,
+        concurrency.clone(),
+        preview,
+    );
+    let database = DistributionDatabase::new(
+        &client,
+        &build_dispatch,
+        concurrency.downloads_semaphore.clone(),
+    );
+
+    // Resolve the requirements for each target, collecting the distinct distributions.
+    let mut dists = BTreeMap::new();
+    for (python_version, python_platform) in targets {
+        let target = match (python_version, python_platform) {
+            (Some(version), Some(platform)) => Some(format!(
+                "Python {version} on {} ({})",
+                platform.sys_platform(),
+                platform.platform_machine()
+            )),
+            (Some(version), None) => Some(format!("Python {version}")),
+            (None, Some(platform)) => Some(format!(
+                "{} ({})",
+                platform.sys_platform(),
+                platform.platform_machine()
+            )),
+            (None, None) => None,
+        };
+        if let Some(target) = &target {
+            writeln!(printer.stderr(), "Resolving for {}", target.cyan())?;
+        }
+
+        let python_requirement = if let Some(python_version) = python_version {
+            PythonRequirement::from_python_version(&interpreter, python_version)
+        } else {
+            PythonRequirement::from_interpreter(&interpreter)
+        };
+        let tags = resolution_tags(python_version, python_platform, &interpreter)?;
+        let marker_env = resolution_markers(python_version, python_platform, &interpreter);
+
+        let flat_index = FlatIndex::from_entries(
+            flat_index_entries.clone(),
+            Some(&tags),
+            &hasher,
+            &build_options,
+        );
+
+        // If we're resolving for a different environment, use a separate index, since the same
+        // packages may resolve to different distributions.
+        let top_level_index = if target.is_some() {
+            InMemoryIndex::default()
+        } else {
+            state.index().clone()
+        };
+
+        let options = OptionsBuilder::new()
+            .resolution_mode(settings.resolution)
+            .prerelease_mode(settings.prerelease)
+            .prerelease_packages(settings.prerelease_packages.clone())
+            .fork_strategy(settings.fork_strategy)
+            .dependency_mode(settings.dependency_mode)
+            .exclude_newer(settings.exclude_newer.clone())
+            .index_strategy(settings.index_strategy)
+            .build_options(build_options.clone())
+            .excluded_packages(settings.excluded_packages.clone())
+            .resolution_priority(settings.resolution_priority.clone())
+            .max_backtracks(settings.max_backtracks)
+            .memory_limit(settings.memory_limit)
+            .allow_yanked(settings.allow_yanked.clone())
+            .build();
+
+        let resolution = match operations::resolve(
+            requirements.clone(),
+            constraints.clone(),
+            overrides.clone(),
+            excludes.clone(),
+            source_trees.clone(),
+            project.clone(),
+            BTreeSet::default(),
+            &settings.extras,
+            &groups,
+            Vec::new(),
+            EmptyInstalledPackages,
+            &hasher,
+            &Reinstall::None,
+            &Upgrade::none(),
+            Some(&tags),
+            ResolverEnvironment::specific(marker_env),
+            python_requirement,
+            interpreter.markers(),
+            Conflicts::empty(),
+            &client,
+            &flat_index,
+            &top_level_index,
+            &build_dispatch,
+            &concurrency,
+            options,
+            Box::new(DefaultResolveLogger),
+            printer,
+        )
+        .await
+        {
+            Ok(graph) => Resolution::from(graph),
+            Err(err) => {
+                return diagnostics::OperationDiagnostic::native_tls(
+                    client_builder.is_native_tls(),
+                )
+                .report(err)
+                .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
+            }
+        };
+
+        for dist in resolution.distributions() {
+            if let ResolvedDist::Installable { dist, .. } = dist {
+                // Key by the distribution ID (e.g., the URL of the selected wheel) rather than the
+                // name and version, since each target may select a different wheel.
+                dists
+                    .entry(dist.distribution_id())
+                    .or_insert_with(|| dist.clone());
+            }
+        }
+    }
+
+    // Fetch the wheels into the cache, building any source distributions if requested. The
+    // metadata for source distributions was already cached during resolution.
+    let mut warmed = futures::stream::iter(
+        dists
+            .values()
+            .filter(|dist| build_wheels || matches!(dist.as_ref(), Dist::Built(_))),
+    )
+    .map(|dist| {
+        let database = &database;
+        async move {
+            let wheel = database
+                .get_or_build_wheel(dist, interpreter_tags, HashPolicy::None)
+                .await
+                .with_context(|| format!("Failed to fetch `{dist}`"))?;
+            Ok::<_, anyhow::Error>(wheel.filename().to_string())
+        }
+    })
+    .buffer_unordered(concurrency.downloads)
+    .try_collect::<Vec<_>>()
+    .await?;
+    warmed.sort_unstable();
+
+    let s = if warmed.len() == 1 { "" } else { "s" };
+    writeln!(
+        printer.stderr(),
+        "{}",
+        format!(
+            "Cached {} {}",
+            format!("{} wheel{s}", warmed.len()).bold(),
+            format!("in {}", elapsed(start.elapsed())).dimmed()
+        )
+        .dimmed()
+    )?;
+    for filename in &warmed {
+        writeln!(printer.stderr(), " {} {}", "+".green(), filename.bold())?;
+    }
+
+    Ok(ExitStatus::Success)
+}
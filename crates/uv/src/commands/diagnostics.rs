@@ -1,16 +1,19 @@
 use std::str::FromStr;
-use std::sync::{Arc, LazyLock};
+use std::sync::{Arc, LazyLock, OnceLock};
 
 use owo_colors::OwoColorize;
 use rustc_hash::FxHashMap;
+use serde::Serialize;
 use version_ranges::Ranges;
 
+use uv_cli::ErrorFormat;
 use uv_distribution_types::{
     DerivationChain, DerivationStep, Dist, DistErrorKind, Name, RequestedDist,
 };
 use uv_normalize::PackageName;
 use uv_pep440::Version;
-use uv_resolver::SentinelRange;
+use uv_redacted::redact_credentials;
+use uv_resolver::{NoSolutionError, NoSolutionHeader, NoSolutionReport, SentinelRange};
 
 use crate::commands::pip;
 
@@ -28,6 +31,14 @@ static SUGGESTIONS: LazyLock<FxHashMap<PackageName, PackageName>> = LazyLock::ne
         .collect()
 });
 
+/// The format in which to report resolution failures.
+static ERROR_FORMAT: OnceLock<ErrorFormat> = OnceLock::new();
+
+/// Set the format in which to report resolution failures.
+pub(crate) fn set_error_format(format: ErrorFormat) {
+    let _ = ERROR_FORMAT.set(format);
+}
+
 /// A rich reporter for operational diagnostics, i.e., errors that occur during resolution and
 /// installation.
 #[derive(Debug, Default)]
@@ -124,7 +135,7 @@ impl OperationDiagnostic {
                 if let Some(context) = self.context {
                     let err = miette::Report::msg(format!("{err}"))
                         .context(format!("Failed to resolve {context} requirement"));
-                    anstream::eprint!("{err:?}");
+                    anstream::eprint!("{}", redact_credentials(&format!("{err:?}")));
                     None
                 } else {
                     Some(pip::operations::Error::Requirements(err))
@@ -136,6 +147,10 @@ impl OperationDiagnostic {
                 native_tls_hint(err);
                 None
             }
+            err @ pip::operations::Error::MissingFromCache(_) => {
+                missing_from_cache_hint(err);
+                None
+            }
             pip::operations::Error::OutdatedEnvironment => {
                 anstream::eprintln!("{}", err);
                 None
@@ -192,7 +207,7 @@ pub(crate) fn dist_error(
         cause,
         help,
     });
-    anstream::eprint!("{report:?}");
+    anstream::eprint!("{}", redact_credentials(&format!("{report:?}")));
 }
 
 /// Render a requested distribution failure (read, download or build) with a help message.
@@ -242,7 +257,7 @@ pub(crate) fn requested_dist_error(
         cause,
         help,
     });
-    anstream::eprint!("{report:?}");
+    anstream::eprint!("{}", redact_credentials(&format!("{report:?}")));
 }
 
 /// Render an error in fetching a package's dependencies.
@@ -292,19 +307,73 @@ pub(crate) fn dependencies_error(
         cause: error,
         help,
     });
-    anstream::eprint!("{report:?}");
+    anstream::eprint!("{}", redact_credentials(&format!("{report:?}")));
+}
+
+/// A resolution failure, as rendered with `--error-format json`.
+#[derive(Serialize)]
+struct NoSolutionJson {
+    /// The kind of error, i.e., `no_solution`.
+    error: &'static str,
+    /// The header describing the failed resolution.
+    header: String,
+    /// The help message for the failure, if any.
+    help: Option<String>,
+    #[serde(flatten)]
+    report: NoSolutionReport,
+}
+
+/// Render a [`uv_resolver::NoSolutionError`] as JSON, if enabled via `--error-format json`.
+///
+/// Returns `true` if the error was rendered.
+fn no_solution_json(err: &NoSolutionError, header: &NoSolutionHeader, help: Option<&str>) -> bool {
+    if ERROR_FORMAT
+        .get()
+        .is_none_or(|format| *format != ErrorFormat::Json)
+    {
+        return false;
+    }
+
+    // Strip any styling, which is only meaningful when rendered in a terminal.
+    let strip = |text: &str| anstream::adapter::strip_str(text).to_string();
+    let mut report = err.report();
+    report.message = strip(&report.message);
+    report.hints = report.hints.iter().map(|hint| strip(hint)).collect();
+    let json = NoSolutionJson {
+        error: "no_solution",
+        header: strip(&header.to_string()),
+        help: help.map(strip),
+        report,
+    };
+
+    match serde_json::to_string(&json) {
+        Ok(json) => {
+            anstream::eprintln!("{json}");
+            true
+        }
+        Err(err) => {
+            tracing::warn!("Failed to serialize resolution failure: {err}");
+            false
+        }
+    }
 }
 
 /// Render a [`uv_resolver::NoSolutionError`].
 pub(crate) fn no_solution(err: &uv_resolver::NoSolutionError) {
+    if no_solution_json(err, &err.header(), None) {
+        return;
+    }
     let report = miette::Report::msg(format!("{err}")).context(err.header());
-    anstream::eprint!("{report:?}");
+    anstream::eprint!("{}", redact_credentials(&format!("{report:?}")));
 }
 
 /// Render a [`uv_resolver::NoSolutionError`] with dedicated context.
 pub(crate) fn no_solution_context(err: &uv_resolver::NoSolutionError, context: &'static str) {
+    if no_solution_json(err, &err.header().with_context(context), None) {
+        return;
+    }
     let report = miette::Report::msg(format!("{err}")).context(err.header().with_context(context));
-    anstream::eprint!("{report:?}");
+    anstream::eprint!("{}", redact_credentials(&format!("{report:?}")));
 }
 
 /// Render a [`uv_resolver::NoSolutionError`] with a help message.
@@ -328,8 +397,11 @@ pub(crate) fn no_solution_hint(err: Box<uv_resolver::NoSolutionError>, help: Str
     }
 
     let header = err.header();
+    if no_solution_json(&err, &header, Some(&help)) {
+        return;
+    }
     let report = miette::Report::new(Error { header, err, help });
-    anstream::eprint!("{report:?}");
+    anstream::eprint!("{}", redact_credentials(&format!("{report:?}")));
 }
 
 /// Render a [`uv_resolver::NoSolutionError`] with a help message.
@@ -366,7 +438,34 @@ pub(crate) fn native_tls_hint(err: uv_client::Error) {
             "--native-tls".green()
         ),
     });
-    anstream::eprint!("{report:?}");
+    anstream::eprint!("{}", redact_credentials(&format!("{report:?}")));
+}
+
+/// Render a [`pip::operations::Error::MissingFromCache`] error with a help message.
+// https://github.com/rust-lang/rust/issues/147648
+#[allow(unused_assignments)]
+pub(crate) fn missing_from_cache_hint(err: pip::operations::Error) {
+    #[derive(Debug, miette::Diagnostic, thiserror::Error)]
+    #[error("{err}")]
+    #[diagnostic()]
+    struct Error {
+        /// The underlying error.
+        err: pip::operations::Error,
+
+        /// The help message to display.
+        #[help]
+        help: String,
+    }
+
+    let help = format!(
+        "Pre-populate the listed cache buckets while online (e.g., with `{}` or `{}`), or run without `{}`",
+        "uv cache warm".green(),
+        "uv cache import".green(),
+        "--offline".green(),
+    );
+
+    let report = miette::Report::new(Error { err, help });
+    anstream::eprint!("{}", redact_credentials(&format!("{report:?}")));
 }
 
 /// Format a [`DerivationChain`] as a human-readable error message.
@@ -485,5 +584,8 @@ fn format_chain(name: &PackageName, version: Option<&Version>, chain: &Derivatio
     } else {
         message = format!("{message} `{}`", name.cyan());
     }
+    if let Some(marker) = chain.marker().contents() {
+        message = format!("{message} (resolved for `{}`)", marker.cyan());
+    }
     message
 }
@@ -0,0 +1,139 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use owo_colors::OwoColorize;
+use tracing::debug;
+
+use uv_client::{SimpleHtmlFile, simple_detail_html, simple_index_html};
+use uv_distribution_filename::DistFilename;
+use uv_extract::hash::Hasher;
+use uv_fs::{PortablePath, Simplified};
+use uv_normalize::PackageName;
+use uv_preview::{Preview, PreviewFeature};
+use uv_pypi_types::{HashAlgorithm, HashDigest};
+use uv_warnings::warn_user;
+
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// A distribution to include in the index.
+#[derive(Debug)]
+struct IndexFile {
+    filename: String,
+    path: PathBuf,
+    sha256: HashDigest,
+}
+
+/// Generate a Simple API index for a directory of distributions.
+pub(crate) fn index_build(
+    directory: &Path,
+    out_dir: Option<&Path>,
+    printer: Printer,
+    preview: Preview,
+) -> Result<ExitStatus> {
+    if !preview.is_enabled(PreviewFeature::IndexBuild) {
+        warn_user!(
+            "`uv index build` is experimental and may change without warning. Pass `--preview-features {}` to disable this warning.",
+            PreviewFeature::IndexBuild
+        );
+    }
+
+    let out_dir = out_dir.map_or_else(|| directory.join("simple"), Path::to_path_buf);
+
+    let projects = read_directory(directory).with_context(|| {
+        format!(
+            "Failed to read distributions from: {}",
+            directory.user_display()
+        )
+    })?;
+    if projects.is_empty() {
+        warn_user!(
+            "No wheels or source distributions found in: {}",
+            directory.user_display()
+        );
+    }
+
+    // Write the page for each project.
+    for (name, files) in &projects {
+        let project_dir = out_dir.join(name.as_str());
+        fs_err::create_dir_all(&project_dir)?;
+        let urls = files
+            .iter()
+            .map(|file| file_url(file, &project_dir))
+            .collect::<Result<Vec<_>>>()?;
+        let page = simple_detail_html(
+            name,
+            files.iter().zip(&urls).map(|(file, url)| SimpleHtmlFile {
+                filename: &file.filename,
+                url,
+                sha256: &file.sha256.digest,
+            }),
+        );
+        fs_err::write(project_dir.join("index.html"), page)?;
+    }
+
+    // Write the root page, listing all projects.
+    let page = simple_index_html(projects.keys());
+    fs_err::create_dir_all(&out_dir)?;
+    fs_err::write(out_dir.join("index.html"), page)?;
+
+    let file_count = projects.values().map(Vec::len).sum::<usize>();
+    let s = if projects.len() == 1 { "" } else { "s" };
+    let files_s = if file_count == 1 { "" } else { "s" };
+    writeln!(
+        printer.stderr(),
+        "Wrote index for {} ({}) to {}",
+        format!("{} project{s}", projects.len()).bold(),
+        format!("{file_count} file{files_s}").bold(),
+        out_dir.user_display().cyan()
+    )?;
+
+    Ok(ExitStatus::Success)
+}
+
+/// Collect the wheels and source distributions at the top level of a directory, grouped by
+/// package name.
+fn read_directory(directory: &Path) -> Result<BTreeMap<PackageName, Vec<IndexFile>>> {
+    let mut projects: BTreeMap<PackageName, Vec<IndexFile>> = BTreeMap::new();
+    for entry in fs_err::read_dir(directory)? {
+        let entry = entry?;
+        if !entry.file_type()?.is_file() {
+            continue;
+        }
+        let Some(filename) = entry.file_name().to_str().map(ToString::to_string) else {
+            continue;
+        };
+        let Some(dist_filename) = DistFilename::try_from_normalized_filename(&filename) else {
+            debug!("Ignoring non-distribution file: {filename}");
+            continue;
+        };
+
+        let path = entry.path();
+        let contents = fs_err::read(&path)?;
+        let mut hasher = Hasher::from(HashAlgorithm::Sha256);
+        hasher.update(&contents);
+
+        projects
+            .entry(dist_filename.name().clone())
+            .or_default()
+            .push(IndexFile {
+                filename,
+                path,
+                sha256: HashDigest::from(hasher),
+            });
+    }
+    for files in projects.values_mut() {
+        files.sort_by(|a, b| a.filename.cmp(&b.filename));
+    }
+    Ok(projects)
+}
+
+/// Return the URL of a distribution, relative to the page of its project.
+fn file_url(file: &IndexFile, project_dir: &Path) -> Result<String> {
+    let path = std::path::absolute(&file.path)?;
+    let project_dir = std::path::absolute(project_dir)?;
+    let relative = uv_fs::relative_to(&path, &project_dir)?;
+    Ok(PortablePath::from(&relative).to_string())
+}
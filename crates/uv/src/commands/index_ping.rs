@@ -0,0 +1,208 @@
+use std::fmt::Write;
+
+use anyhow::Result;
+use http::StatusCode;
+use owo_colors::OwoColorize;
+
+use uv_cache::Cache;
+use uv_client::{BaseClientBuilder, IndexPing, RegistryClient, RegistryClientBuilder};
+use uv_configuration::KeyringProviderType;
+use uv_distribution_types::{IndexFormat, IndexLocations, IndexUrl};
+use uv_fs::Simplified;
+use uv_normalize::PackageName;
+use uv_preview::{Preview, PreviewFeature};
+use uv_warnings::warn_user;
+
+use crate::commands::{ExitStatus, elapsed};
+use crate::printer::Printer;
+
+/// Check the health of the configured indexes.
+pub(crate) async fn index_ping(
+    package: &PackageName,
+    index_locations: &IndexLocations,
+    keyring_provider: KeyringProviderType,
+    client_builder: &BaseClientBuilder<'_>,
+    cache: Cache,
+    printer: Printer,
+    preview: Preview,
+) -> Result<ExitStatus> {
+    if !preview.is_enabled(PreviewFeature::IndexPing) {
+        warn_user!(
+            "`uv index ping` is experimental and may change without warning. Pass `--preview-features {}` to disable this warning.",
+            PreviewFeature::IndexPing
+        );
+    }
+
+    let client_builder = client_builder.clone().keyring(keyring_provider);
+    let client = RegistryClientBuilder::new(client_builder, cache)
+        .index_locations(index_locations.clone())
+        .build();
+
+    // Collect the Simple API indexes in order of priority, followed by their mirrors.
+    let indexes = index_locations
+        .allowed_indexes()
+        .into_iter()
+        .rev()
+        .filter(|index| matches!(index.format, IndexFormat::Simple))
+        .collect::<Vec<_>>();
+    if indexes.is_empty() {
+        writeln!(printer.stderr(), "No indexes to check")?;
+        return Ok(ExitStatus::Success);
+    }
+
+    let mut healthy = true;
+    for index in indexes {
+        match &index.name {
+            Some(name) => writeln!(
+                printer.stdout(),
+                "{} ({})",
+                name.bold(),
+                index.url.without_credentials().cyan()
+            )?,
+            None => writeln!(
+                printer.stdout(),
+                "{}",
+                index.url.without_credentials().bold()
+            )?,
+        }
+        healthy &= ping(&client, &index.url, package, printer).await?;
+
+        for mirror in index.mirrors() {
+            writeln!(
+                printer.stdout(),
+                "{} {}",
+                "Mirror:".bold(),
+                mirror.without_credentials().cyan()
+            )?;
+            healthy &= ping(&client, mirror, package, printer).await?;
+        }
+    }
+
+    if healthy {
+        Ok(ExitStatus::Success)
+    } else {
+        Ok(ExitStatus::Failure)
+    }
+}
+
+/// Check the health of a single index, returning `true` if the index is healthy.
+async fn ping(
+    client: &RegistryClient,
+    index: &IndexUrl,
+    package: &PackageName,
+    printer: Printer,
+) -> Result<bool> {
+    if let IndexUrl::Path(url) = index {
+        let Ok(path) = url.as_path() else {
+            writeln!(
+                printer.stdout(),
+                "  Local directory: {}",
+                "invalid path".red()
+            )?;
+            writeln!(printer.stdout())?;
+            return Ok(false);
+        };
+        let exists = path.is_dir();
+        if exists {
+            writeln!(
+                printer.stdout(),
+                "  Local directory: {}",
+                path.user_display()
+            )?;
+        } else {
+            writeln!(
+                printer.stdout(),
+                "  Local directory: {} ({})",
+                path.user_display(),
+                "not found".red()
+            )?;
+        }
+        writeln!(printer.stdout())?;
+        return Ok(exists);
+    }
+
+    let ping = match client.ping(index, package).await {
+        Ok(ping) => ping,
+        Err(err) => {
+            writeln!(
+                printer.stdout(),
+                "  Status: {} ({err})",
+                "unreachable".red()
+            )?;
+            writeln!(printer.stdout())?;
+            return Ok(false);
+        }
+    };
+
+    write_ping(&ping, package, printer)?;
+    writeln!(printer.stdout())?;
+
+    // A missing package doesn't indicate a problem with the index itself.
+    Ok((ping.status.is_success() && ping.simple_api) || ping.status == StatusCode::NOT_FOUND)
+}
+
+/// Write the report for an [`IndexPing`].
+fn write_ping(ping: &IndexPing, package: &PackageName, printer: Printer) -> Result<()> {
+    let status = format!("{} ({})", ping.status, elapsed(ping.latency));
+    if ping.status.is_success() {
+        writeln!(printer.stdout(), "  Status: {}", status.green())?;
+    } else if ping.is_auth_failure() {
+        writeln!(
+            printer.stdout(),
+            "  Status: {} (check the credentials for this index)",
+            status.red()
+        )?;
+        return Ok(());
+    } else if ping.status == StatusCode::NOT_FOUND {
+        writeln!(
+            printer.stdout(),
+            "  Status: {} (`{package}` was not found; use `--package` to check a package available on this index)",
+            status.yellow()
+        )?;
+        return Ok(());
+    } else {
+        writeln!(printer.stdout(), "  Status: {}", status.red())?;
+        return Ok(());
+    }
+
+    let content_type = ping.content_type.as_deref().unwrap_or("none");
+    if ping.simple_api {
+        writeln!(printer.stdout(), "  Content type: {content_type}")?;
+    } else {
+        writeln!(
+            printer.stdout(),
+            "  Content type: {content_type} ({})",
+            "not a Simple API response".red()
+        )?;
+        return Ok(());
+    }
+
+    writeln!(
+        printer.stdout(),
+        "  JSON API: {}",
+        yes_no(Some(ping.json_api))
+    )?;
+    writeln!(
+        printer.stdout(),
+        "  Metadata files: {} ({}/{} files)",
+        yes_no((ping.files > 0).then_some(ping.metadata_files > 0)),
+        ping.metadata_files,
+        ping.files
+    )?;
+    writeln!(
+        printer.stdout(),
+        "  Range requests: {}",
+        yes_no(ping.range_requests)
+    )?;
+
+    Ok(())
+}
+
+/// Format a detected feature, where `None` indicates that support could not be determined.
+fn yes_no(supported: Option<bool>) -> String {
+    match supported {
+        Some(true) => "yes".green().to_string(),
+        Some(false) => "no".yellow().to_string(),
+        None => "unknown".dimmed().to_string(),
+    }
+}
@@ -15,30 +15,45 @@ pub(crate) use auth::login::login as auth_login;
 pub(crate) use auth::logout::logout as auth_logout;
 pub(crate) use auth::token::token as auth_token;
 pub(crate) use build_frontend::build_frontend;
+pub(crate) use cache_bundle::{cache_export, cache_import};
 pub(crate) use cache_clean::cache_clean;
 pub(crate) use cache_dir::cache_dir;
+pub(crate) use cache_list::cache_list;
 pub(crate) use cache_prune::cache_prune;
 pub(crate) use cache_size::cache_size;
+pub(crate) use cache_verify::cache_verify;
+pub(crate) use cache_warm::cache_warm;
+pub(crate) use diagnostics::set_error_format;
 pub(crate) use help::help;
+pub(crate) use index_build::index_build;
+pub(crate) use index_ping::index_ping;
 pub(crate) use pip::check::pip_check;
 pub(crate) use pip::compile::pip_compile;
+pub(crate) use pip::download::{DownloadTarget, pip_download};
+pub(crate) use pip::explain::pip_explain_lock;
 pub(crate) use pip::freeze::pip_freeze;
+pub(crate) use pip::hash::pip_hash;
+pub(crate) use pip::index::pip_index_versions;
 pub(crate) use pip::install::pip_install;
 pub(crate) use pip::list::pip_list;
 pub(crate) use pip::show::pip_show;
+pub(crate) use pip::stub::pip_stub;
 pub(crate) use pip::sync::pip_sync;
 pub(crate) use pip::tree::pip_tree;
 pub(crate) use pip::uninstall::pip_uninstall;
+pub(crate) use pip::verify::pip_verify;
 pub(crate) use project::add::add;
 pub(crate) use project::audit::audit;
 pub(crate) use project::export::export;
 pub(crate) use project::format::format;
 pub(crate) use project::init::{InitKind, InitProjectKind, init};
+pub(crate) use project::licenses::licenses;
 pub(crate) use project::lock::lock;
 pub(crate) use project::remove::remove;
 pub(crate) use project::run::{RunCommand, run};
 pub(crate) use project::sync::sync;
 pub(crate) use project::tree::tree;
+pub(crate) use project::vendor::vendor;
 pub(crate) use project::version::{project_version, self_version};
 pub(crate) use publish::publish;
 pub(crate) use python::dir::dir as python_dir;
@@ -68,6 +83,7 @@ use uv_installer::compile_tree;
 use uv_python::PythonEnvironment;
 use uv_scripts::Pep723Script;
 pub(crate) use venv::venv;
+pub(crate) use wheel_retag::wheel_retag;
 pub(crate) use workspace::dir::dir;
 pub(crate) use workspace::list::list;
 pub(crate) use workspace::metadata::metadata;
@@ -78,12 +94,18 @@ use crate::printer::Printer;
 mod auth;
 pub(crate) mod build_backend;
 mod build_frontend;
+mod cache_bundle;
 mod cache_clean;
 mod cache_dir;
+mod cache_list;
 mod cache_prune;
 mod cache_size;
+mod cache_verify;
+mod cache_warm;
 mod diagnostics;
 mod help;
+mod index_build;
+mod index_ping;
 pub(crate) mod pip;
 mod project;
 mod publish;
@@ -93,6 +115,7 @@ pub(crate) mod reporters;
 mod self_update;
 mod tool;
 mod venv;
+mod wheel_retag;
 mod workspace;
 
 #[derive(Copy, Clone)]
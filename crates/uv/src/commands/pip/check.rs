@@ -3,11 +3,15 @@ use std::time::Instant;
 
 use anyhow::Result;
 use owo_colors::OwoColorize;
+use serde::Serialize;
 
 use uv_cache::Cache;
+use uv_cli::{PipCheckFormat, PipCheckSeverity};
 use uv_configuration::TargetTriple;
 use uv_distribution_types::{Diagnostic, InstalledDist};
-use uv_installer::{SitePackages, SitePackagesDiagnostic};
+use uv_fs::Simplified;
+use uv_installer::{DiagnosticSeverity, SitePackages, SitePackagesDiagnostic};
+use uv_normalize::PackageName;
 use uv_preview::Preview;
 use uv_python::{
     EnvironmentPreference, PythonEnvironment, PythonPreference, PythonRequest, PythonVersion,
@@ -20,6 +24,8 @@ use crate::printer::Printer;
 
 /// Check for incompatibilities in installed packages.
 pub(crate) fn pip_check(
+    format: PipCheckFormat,
+    severity: PipCheckSeverity,
     python: Option<&str>,
     system: bool,
     python_version: Option<&PythonVersion>,
@@ -61,40 +67,134 @@ pub(crate) fn pip_check(
     let markers = resolution_markers(python_version, python_platform, environment.interpreter());
     let tags = resolution_tags(python_version, python_platform, environment.interpreter())?;
 
-    // Run the diagnostics.
-    let diagnostics: Vec<SitePackagesDiagnostic> = site_packages
-        .diagnostics(&markers, &tags)?
+    // Run the diagnostics, including the orphaned file check if warnings are reported.
+    let mut diagnostics = site_packages.diagnostics(&markers, &tags)?;
+    if severity == PipCheckSeverity::Warning {
+        diagnostics.extend(site_packages.orphaned_files()?);
+    }
+    let min_severity = match severity {
+        PipCheckSeverity::Warning => DiagnosticSeverity::Warning,
+        PipCheckSeverity::Error => DiagnosticSeverity::Error,
+    };
+    let diagnostics: Vec<SitePackagesDiagnostic> = diagnostics
         .into_iter()
+        .filter(|diagnostic| diagnostic.severity() >= min_severity)
         .collect();
 
-    if diagnostics.is_empty() {
+    // Only errors fail the check; warnings are reported, but don't affect the exit status.
+    let valid = diagnostics
+        .iter()
+        .all(|diagnostic| diagnostic.severity() < DiagnosticSeverity::Error);
+
+    if matches!(format, PipCheckFormat::Json) {
+        let output = serde_json::to_string(&Output {
+            valid,
+            diagnostics: diagnostics.iter().map(Entry::from).collect(),
+        })?;
+        writeln!(printer.stdout_important(), "{output}")?;
+    } else if diagnostics.is_empty() {
         writeln!(
             printer.stderr(),
             "{}",
             "All installed packages are compatible".to_string().dimmed()
         )?;
-
-        Ok(ExitStatus::Success)
     } else {
-        let incompats = if diagnostics.len() == 1 {
+        let (errors, warnings): (Vec<_>, Vec<_>) = diagnostics
+            .iter()
+            .partition(|diagnostic| diagnostic.severity() == DiagnosticSeverity::Error);
+
+        let incompats = if errors.len() == 1 {
             "incompatibility"
         } else {
             "incompatibilities"
         };
-        writeln!(
-            printer.stderr(),
-            "{}",
-            format!(
-                "Found {}",
-                format!("{} {}", diagnostics.len(), incompats).bold()
-            )
-            .dimmed()
-        )?;
+        let s = if warnings.len() == 1 { "" } else { "s" };
+        let found = match (errors.len(), warnings.len()) {
+            (errors, 0) => format!("{errors} {incompats}").bold().to_string(),
+            (0, warnings) => format!("{warnings} warning{s}").bold().to_string(),
+            (errors, warnings) => format!(
+                "{} and {}",
+                format!("{errors} {incompats}").bold(),
+                format!("{warnings} warning{s}").bold()
+            ),
+        };
+        writeln!(printer.stderr(), "{}", format!("Found {found}").dimmed())?;
 
-        for diagnostic in &diagnostics {
+        for diagnostic in &errors {
             writeln!(printer.stderr(), "{}", diagnostic.message().bold())?;
         }
+        for diagnostic in &warnings {
+            writeln!(
+                printer.stderr(),
+                "{}{} {}",
+                "warning".yellow().bold(),
+                ":".bold(),
+                diagnostic.message().bold()
+            )?;
+        }
+    }
 
+    if valid {
+        Ok(ExitStatus::Success)
+    } else {
         Ok(ExitStatus::Failure)
     }
 }
+
+/// A diagnostic in the JSON output of `pip check`.
+#[derive(Debug, Serialize)]
+struct Entry<'a> {
+    severity: &'static str,
+    kind: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    package: Option<&'a PackageName>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    path: Option<String>,
+    message: String,
+}
+
+impl<'a> From<&'a SitePackagesDiagnostic> for Entry<'a> {
+    fn from(diagnostic: &'a SitePackagesDiagnostic) -> Self {
+        let (kind, package, path) = match diagnostic {
+            SitePackagesDiagnostic::MetadataUnavailable { package, path } => {
+                ("metadata-unavailable", Some(package), Some(path))
+            }
+            SitePackagesDiagnostic::TagsUnavailable { package, path } => {
+                ("tags-unavailable", Some(package), Some(path))
+            }
+            SitePackagesDiagnostic::IncompatiblePythonVersion { package, .. } => {
+                ("incompatible-python-version", Some(package), None)
+            }
+            SitePackagesDiagnostic::IncompatiblePlatform { package } => {
+                ("incompatible-platform", Some(package), None)
+            }
+            SitePackagesDiagnostic::MissingDependency { package, .. } => {
+                ("missing-dependency", Some(package), None)
+            }
+            SitePackagesDiagnostic::IncompatibleDependency { package, .. } => {
+                ("incompatible-dependency", Some(package), None)
+            }
+            SitePackagesDiagnostic::DuplicatePackage { package, .. } => {
+                ("duplicate-package", Some(package), None)
+            }
+            SitePackagesDiagnostic::OrphanedFile { path } => ("orphaned-file", None, Some(path)),
+        };
+        Entry {
+            severity: match diagnostic.severity() {
+                DiagnosticSeverity::Warning => "warning",
+                DiagnosticSeverity::Error => "error",
+            },
+            kind,
+            package,
+            path: path.map(|path| path.simplified_display().to_string()),
+            message: diagnostic.message(),
+        }
+    }
+}
+
+/// The JSON output for `pip check`.
+#[derive(Debug, Serialize)]
+struct Output<'a> {
+    valid: bool,
+    diagnostics: Vec<Entry<'a>>,
+}
@@ -1,6 +1,7 @@
 use std::collections::BTreeSet;
 use std::env;
 use std::ffi::OsStr;
+use std::fmt::Write as _;
 use std::io::Write;
 use std::path::Path;
 use std::str::FromStr;
@@ -35,20 +36,22 @@ use uv_python::{
     EnvironmentPreference, PythonDownloads, PythonEnvironment, PythonInstallation,
     PythonPreference, PythonRequest, PythonVersion, VersionRequest,
 };
+use uv_redacted::redact_credentials;
 use uv_requirements::upgrade::{LockedRequirements, read_pylock_toml_requirements};
 use uv_requirements::{
     GroupsSpecification, RequirementsSource, RequirementsSpecification, is_pylock_toml,
     upgrade::read_requirements_txt,
 };
 use uv_resolver::{
-    AnnotationStyle, DependencyMode, DisplayResolutionGraph, ExcludeNewer, FlatIndex, ForkStrategy,
-    InMemoryIndex, OptionsBuilder, PrereleaseMode, PylockToml, PythonRequirement, ResolutionMode,
-    ResolverEnvironment,
+    AllowYankedEntry, AnnotationStyle, DependencyMode, DisplayExplanation, DisplayResolutionDot,
+    DisplayResolutionGraph, ExcludeNewer, FlatIndex, ForkStrategy, InMemoryIndex, OptionsBuilder,
+    PrereleaseMode, PylockToml, PythonRequirement, ResolutionMode, ResolverEnvironment,
+    cyclonedx_json, spdx_json,
 };
 use uv_settings::PythonInstallMirrors;
 use uv_static::EnvVars;
 use uv_torch::{TorchMode, TorchSource, TorchStrategy};
-use uv_types::{EmptyInstalledPackages, HashStrategy};
+use uv_types::{BuildContext, EmptyInstalledPackages, HashStrategy};
 use uv_warnings::warn_user;
 use uv_workspace::WorkspaceCache;
 use uv_workspace::pyproject::ExtraBuildDependencies;
@@ -76,11 +79,15 @@ pub(crate) async fn pip_compile(
     groups: GroupsSpecification,
     output_file: Option<&Path>,
     format: Option<PipCompileFormat>,
+    emit_graph: Option<&Path>,
+    explain: Option<&PackageName>,
     resolution_mode: ResolutionMode,
     prerelease_mode: PrereleaseMode,
+    prerelease_packages: Vec<PackageName>,
     fork_strategy: ForkStrategy,
     dependency_mode: DependencyMode,
     upgrade: Upgrade,
+    minimal_update: bool,
     generate_hashes: bool,
     no_emit_packages: Vec<PackageName>,
     include_extras: bool,
@@ -112,6 +119,11 @@ pub(crate) async fn pip_compile(
     universal: bool,
     exclude_newer: ExcludeNewer,
     sources: NoSources,
+    excluded_packages: Vec<PackageName>,
+    resolution_priority: Vec<PackageName>,
+    max_backtracks: Option<usize>,
+    memory_limit: Option<usize>,
+    allow_yanked: Vec<AllowYankedEntry>,
     annotation_style: AnnotationStyle,
     link_mode: LinkMode,
     mut python: Option<String>,
@@ -144,6 +156,13 @@ pub(crate) async fn pip_compile(
         } else if extension.is_some_and(|ext| ext.eq_ignore_ascii_case("toml")) {
             PipCompileFormat::PylockToml
         } else {
+            // CycloneDX output must be requested explicitly, since other JSON formats exist.
+            if extension.is_some_and(|ext| ext.eq_ignore_ascii_case("json")) {
+                warn_user!(
+                    "Writing `requirements.txt`-style output to a `.json` file; pass `{}` to write a CycloneDX SBOM instead",
+                    "--format cyclonedx1.5".green()
+                );
+            }
             PipCompileFormat::RequirementsTxt
         }
     });
@@ -212,6 +231,7 @@ pub(crate) async fn pip_compile(
         find_links,
         no_binary,
         no_build,
+        build_options_origins,
     } = RequirementsSpecification::from_sources(
         requirements,
         constraints,
@@ -405,8 +425,14 @@ pub(crate) async fn pip_compile(
     };
 
     // Generate, but don't enforce hashes for the requirements. PEP 751 _requires_ a hash to be
-    // present, but otherwise, we omit them by default.
-    let hasher = if generate_hashes || matches!(format, PipCompileFormat::PylockToml) {
+    // present, and an SBOM should identify each artifact, but otherwise, we omit them by default.
+    let hasher = if generate_hashes
+        || matches!(
+            format,
+            PipCompileFormat::PylockToml
+                | PipCompileFormat::CycloneDX1_5
+                | PipCompileFormat::Spdx2_3
+        ) {
         HashStrategy::Generate(HashGeneration::All)
     } else {
         HashStrategy::None
@@ -469,6 +495,10 @@ pub(crate) async fn pip_compile(
                 PipCompileFormat::PylockToml => {
                     read_pylock_toml_requirements(output_file, &upgrade).await?
                 }
+                // An SBOM is not a lockfile, so it doesn't provide any preferences.
+                PipCompileFormat::CycloneDX1_5 | PipCompileFormat::Spdx2_3 => {
+                    LockedRequirements::default()
+                }
             }
         } else {
             LockedRequirements::default()
@@ -481,7 +511,7 @@ pub(crate) async fn pip_compile(
     }
 
     // Combine the `--no-binary` and `--no-build` flags from the requirements files.
-    let build_options = build_options.combine(no_binary, no_build);
+    let build_options = build_options.try_combine(no_binary, no_build, build_options_origins)?;
 
     // Resolve the flat indexes from `--find-links`.
     let flat_index = {
@@ -548,12 +578,19 @@ pub(crate) async fn pip_compile(
     let options = OptionsBuilder::new()
         .resolution_mode(resolution_mode)
         .prerelease_mode(prerelease_mode)
+        .prerelease_packages(prerelease_packages)
         .fork_strategy(fork_strategy)
         .dependency_mode(dependency_mode)
         .exclude_newer(exclude_newer.clone())
         .index_strategy(index_strategy)
         .torch_backend(torch_backend)
         .build_options(build_options.clone())
+        .excluded_packages(excluded_packages)
+        .resolution_priority(resolution_priority)
+        .max_backtracks(max_backtracks)
+        .memory_limit(memory_limit)
+        .allow_yanked(allow_yanked)
+        .minimal_update(minimal_update)
         .build();
 
     // Resolve the requirements.
@@ -596,10 +633,37 @@ pub(crate) async fn pip_compile(
         }
     };
 
+    // If requested, write the resolved dependency graph.
+    if let Some(emit_graph) = emit_graph {
+        uv_fs::write_atomic(
+            emit_graph,
+            DisplayResolutionDot::from(&resolution).to_string(),
+        )
+        .await?;
+    }
+
+    // If a package was provided, explain its selection rather than writing the resolution.
+    if let Some(package) = explain {
+        let Some(explanation) = DisplayExplanation::new(&resolution, package) else {
+            return Err(anyhow!(
+                "Package `{}` is not included in the resolution",
+                package.cyan()
+            ));
+        };
+        write!(printer.stdout_important(), "{explanation}")?;
+        return Ok(ExitStatus::Success);
+    }
+
     // Write the resolved dependencies to the output channel.
     let mut writer = OutputWriter::new(!quiet || output_file.is_none(), output_file);
 
-    if include_header {
+    // JSON output can't include comments.
+    if include_header
+        && !matches!(
+            format,
+            PipCompileFormat::CycloneDX1_5 | PipCompileFormat::Spdx2_3
+        )
+    {
         writeln!(
             writer,
             "{}",
@@ -618,6 +682,24 @@ pub(crate) async fn pip_compile(
             )
             .green()
         )?;
+
+        // Record any indexes that were skipped due to `--allow-index-failure`, since the
+        // resolution may differ from one performed with all indexes available.
+        let unreachable_indexes = build_dispatch.capabilities().unreachable_indexes();
+        if !unreachable_indexes.is_empty() {
+            writeln!(
+                writer,
+                "{}",
+                "# The following indexes were unreachable and treated as empty:".green()
+            )?;
+            for index in unreachable_indexes {
+                writeln!(
+                    writer,
+                    "{}",
+                    format!("#    {}", index.without_credentials()).green()
+                )?;
+            }
+        }
     }
 
     match format {
@@ -757,6 +839,47 @@ pub(crate) async fn pip_compile(
             )?;
             write!(writer, "{}", export.to_toml()?)?;
         }
+        PipCompileFormat::CycloneDX1_5 | PipCompileFormat::Spdx2_3 => {
+            let sbom_format = if format == PipCompileFormat::CycloneDX1_5 {
+                "CycloneDX"
+            } else {
+                "SPDX"
+            };
+            if include_marker_expression {
+                warn_user!(
+                    "The `--emit-marker-expression` option is not supported for {sbom_format} output"
+                );
+            }
+            if include_index_url {
+                warn_user!(
+                    "The `--emit-index-url` option is not supported for {sbom_format} output"
+                );
+            }
+            if include_find_links {
+                warn_user!(
+                    "The `--emit-find-links` option is not supported for {sbom_format} output"
+                );
+            }
+            if include_build_options {
+                warn_user!(
+                    "The `--emit-build-options` option is not supported for {sbom_format} output"
+                );
+            }
+            if include_index_annotation {
+                warn_user!(
+                    "The `--emit-index-annotation` option is not supported for {sbom_format} output"
+                );
+            }
+
+            if format == PipCompileFormat::CycloneDX1_5 {
+                let export =
+                    cyclonedx_json::from_resolution(&resolution, &no_emit_packages, preview);
+                export.output_as_json_v1_5(&mut writer)?;
+            } else {
+                let export = spdx_json::from_resolution(&resolution, &no_emit_packages, preview);
+                writeln!(writer, "{}", export.to_json()?)?;
+            }
+        }
     }
 
     // If any "unsafe" packages were excluded, notify the user.
@@ -764,7 +887,12 @@ pub(crate) async fn pip_compile(
         .into_iter()
         .filter(|name| resolution.contains(name))
         .collect::<Vec<_>>();
-    if !excluded.is_empty() {
+    if !excluded.is_empty()
+        && !matches!(
+            format,
+            PipCompileFormat::CycloneDX1_5 | PipCompileFormat::Spdx2_3
+        )
+    {
         writeln!(writer)?;
         writeln!(
             writer,
@@ -864,6 +992,25 @@ fn cmd(
                 return Some(None);
             }
 
+            // Always skip the `--upgrade-strategy` and mark the next item to be skipped
+            if arg == "--upgrade-strategy" {
+                *skip_next = Some(true);
+                return Some(None);
+            }
+
+            // Skip only this argument if option and value are together
+            if arg.starts_with("--upgrade-strategy=") {
+                // Reset state; skip this iteration.
+                *skip_next = None;
+                return Some(None);
+            }
+
+            // Always skip the `--minimal-update` flag.
+            if arg == "--minimal-update" {
+                *skip_next = None;
+                return Some(None);
+            }
+
             // Always skip the `--quiet` flag.
             if arg == "--quiet" || arg == "-q" {
                 *skip_next = None;
@@ -888,8 +1035,34 @@ fn cmd(
                 return Some(None);
             }
 
-            // Return the argument.
-            Some(Some(arg))
+            // Always skip the `--trace-file` and mark the next item to be skipped
+            if arg == "--trace-file" {
+                *skip_next = Some(true);
+                return Some(None);
+            }
+
+            // Skip only this argument if option and value are together
+            if arg.starts_with("--trace-file=") {
+                // Reset state; skip this iteration.
+                *skip_next = None;
+                return Some(None);
+            }
+
+            // Always skip the `--emit-graph` and mark the next item to be skipped
+            if arg == "--emit-graph" {
+                *skip_next = Some(true);
+                return Some(None);
+            }
+
+            // Skip only this argument if option and value are together
+            if arg.starts_with("--emit-graph=") {
+                // Reset state; skip this iteration.
+                *skip_next = None;
+                return Some(None);
+            }
+
+            // Return the argument, without any credentials (e.g., in a direct URL requirement).
+            Some(Some(redact_credentials(&arg).into_owned()))
         })
         .flatten()
         .join(" ");
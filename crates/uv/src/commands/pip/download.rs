@@ -0,0 +1,513 @@
+use std::collections::BTreeSet;
+use std::fmt::Write;
+use std::path::Path;
+use std::time::Instant;
+
+use anyhow::{Result, anyhow};
+use futures::{StreamExt, TryStreamExt};
+use owo_colors::OwoColorize;
+use tracing::debug;
+
+use uv_cache::Cache;
+use uv_client::{BaseClientBuilder, FlatIndexClient, RegistryClientBuilder};
+use uv_configuration::{
+    BuildIsolation, BuildOptions, Concurrency, Constraints, ExtrasSpecification, HashCheckingMode,
+    IndexStrategy, KeyringProviderType, NoSources, Reinstall, TargetTriple, Upgrade,
+};
+use uv_dispatch::{BuildDispatch, SharedState};
+use uv_distribution::{DistributionDatabase, LoweredExtraBuildDependencies};
+use uv_distribution_types::{
+    BuiltDist, ConfigSettings, DependencyMetadata, Dist, ExtraBuildVariables, HashPolicy, Index,
+    IndexLocations, NameRequirementSpecification, Origin, PackageConfigSettings, RemoteSource,
+    Requirement, Resolution, ResolvedDist, SourceDist, UnresolvedRequirementSpecification,
+};
+use uv_fs::Simplified;
+use uv_install_wheel::LinkMode;
+use uv_normalize::PackageName;
+use uv_preview::Preview;
+use uv_pypi_types::Conflicts;
+use uv_python::{
+    EnvironmentPreference, PythonDownloads, PythonEnvironment, PythonInstallation,
+    PythonPreference, PythonRequest, PythonVersion, VersionRequest,
+};
+use uv_requirements::{GroupsSpecification, RequirementsSource, RequirementsSpecification};
+use uv_resolver::{
+    AllowYankedEntry, DependencyMode, ExcludeNewer, FlatIndex, ForkStrategy, InMemoryIndex,
+    OptionsBuilder, PrereleaseMode, PythonRequirement, ResolutionMode, ResolverEnvironment,
+};
+use uv_settings::PythonInstallMirrors;
+use uv_types::{EmptyInstalledPackages, HashStrategy};
+use uv_warnings::warn_user;
+use uv_workspace::WorkspaceCache;
+use uv_workspace::pyproject::ExtraBuildDependencies;
+
+use crate::commands::pip::loggers::DefaultResolveLogger;
+use crate::commands::pip::wheel::collect_wheel;
+use crate::commands::pip::{operations, resolution_markers, resolution_tags};
+use crate::commands::reporters::PythonDownloadReporter;
+use crate::commands::{ExitStatus, diagnostics, elapsed};
+use crate::printer::Printer;
+
+/// The artifacts to save for each distribution in a resolution.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) enum DownloadTarget {
+    /// Save the distribution archives as-is, i.e., wheels and source distributions
+    /// (`uv pip download`).
+    Archives,
+    /// Save a wheel for each distribution, building any source distributions (`uv pip wheel`).
+    Wheels,
+}
+
+impl DownloadTarget {
+    /// The name of the command that downloads the given target.
+    fn command(self) -> &'static str {
+        match self {
+            Self::Archives => "uv pip download",
+            Self::Wheels => "uv pip wheel",
+        }
+    }
+}
+
+/// Resolve a set of requirements and save the resulting distributions into a directory, either as
+/// archives or as wheels, per the [`DownloadTarget`].
+pub(crate) async fn pip_download(
+    requirements: &[RequirementsSource],
+    constraints: &[RequirementsSource],
+    overrides: &[RequirementsSource],
+    build_constraints: &[RequirementsSource],
+    constraints_from_workspace: Vec<Requirement>,
+    overrides_from_workspace: Vec<Requirement>,
+    excludes_from_workspace: Vec<PackageName>,
+    build_constraints_from_workspace: Vec<Requirement>,
+    extras: &ExtrasSpecification,
+    groups: &GroupsSpecification,
+    dest: &Path,
+    target: DownloadTarget,
+    resolution_mode: ResolutionMode,
+    prerelease_mode: PrereleaseMode,
+    prerelease_packages: Vec<PackageName>,
+    fork_strategy: ForkStrategy,
+    dependency_mode: DependencyMode,
+    index_locations: IndexLocations,
+    index_strategy: IndexStrategy,
+    dependency_metadata: DependencyMetadata,
+    keyring_provider: KeyringProviderType,
+    client_builder: &BaseClientBuilder<'_>,
+    config_settings: &ConfigSettings,
+    config_settings_package: &PackageConfigSettings,
+    build_isolation: BuildIsolation,
+    extra_build_dependencies: &ExtraBuildDependencies,
+    extra_build_variables: &ExtraBuildVariables,
+    build_options: BuildOptions,
+    install_mirrors: PythonInstallMirrors,
+    python_version: Option<PythonVersion>,
+    python_platform: Option<TargetTriple>,
+    python_downloads: PythonDownloads,
+    exclude_newer: ExcludeNewer,
+    sources: NoSources,
+    excluded_packages: Vec<PackageName>,
+    resolution_priority: Vec<PackageName>,
+    max_backtracks: Option<usize>,
+    memory_limit: Option<usize>,
+    allow_yanked: Vec<AllowYankedEntry>,
+    hash_checking: Option<HashCheckingMode>,
+    link_mode: LinkMode,
+    python: Option<String>,
+    system: bool,
+    python_preference: PythonPreference,
+    concurrency: Concurrency,
+    cache: Cache,
+    workspace_cache: WorkspaceCache,
+    printer: Printer,
+    preview: Preview,
+) -> Result<ExitStatus> {
+    let start = Instant::now();
+
+    // If the user requests `extras` but does not provide a valid source (e.g., a `pyproject.toml`),
+    // return an error.
+    if !extras.is_empty() && !requirements.iter().any(RequirementsSource::allows_extras) {
+        return Err(anyhow!(
+            "Requesting extras requires a `pyproject.toml`, `setup.cfg`, or `setup.py` file."
+        ));
+    }
+
+    let client_builder = client_builder.clone().keyring(keyring_provider);
+
+    // Read all requirements from the provided sources.
+    let RequirementsSpecification {
+        project,
+        requirements,
+        constraints,
+        overrides,
+        excludes,
+        pylock,
+        source_trees,
+        groups,
+        index_url,
+        extra_index_urls,
+        no_index,
+        find_links,
+        no_binary,
+        no_build,
+        build_options_origins,
+        extras: _,
+    } = RequirementsSpecification::from_sources(
+        requirements,
+        constraints,
+        overrides,
+        &[],
+        Some(groups),
+        &client_builder,
+    )
+    .await?;
+
+    // Reject `pylock.toml` files, which already enumerate the distributions to download.
+    if pylock.is_some() {
+        return Err(anyhow!(
+            "`pylock.toml` is not a supported input format for `{}`",
+            target.command()
+        ));
+    }
+
+    let constraints: Vec<NameRequirementSpecification> = constraints
+        .iter()
+        .cloned()
+        .chain(
+            constraints_from_workspace
+                .into_iter()
+                .map(NameRequirementSpecification::from),
+        )
+        .collect();
+
+    let overrides: Vec<UnresolvedRequirementSpecification> = overrides
+        .iter()
+        .cloned()
+        .chain(
+            overrides_from_workspace
+                .into_iter()
+                .map(UnresolvedRequirementSpecification::from),
+        )
+        .collect();
+
+    let excludes: Vec<PackageName> = excludes
+        .into_iter()
+        .chain(excludes_from_workspace)
+        .collect();
+
+    // Read build constraints.
+    let build_constraints: Vec<NameRequirementSpecification> =
+        operations::read_constraints(build_constraints, &client_builder)
+            .await?
+            .into_iter()
+            .chain(
+                build_constraints_from_workspace
+                    .into_iter()
+                    .map(NameRequirementSpecification::from),
+            )
+            .collect();
+
+    // Find an interpreter to use for building distributions.
+    let environment_preference = EnvironmentPreference::from_system_flag(system, false);
+    let python_preference = python_preference.with_system_flag(system);
+    let reporter = PythonDownloadReporter::single(printer);
+    let request = if let Some(python) = python.as_ref() {
+        PythonRequest::parse(python)
+    } else if let Some(version) = python_version.as_ref() {
+        PythonRequest::Version(VersionRequest::from(version))
+    } else {
+        PythonRequest::default()
+    };
+    let interpreter = PythonInstallation::find_best(
+        &request,
+        environment_preference,
+        python_preference,
+        python_downloads,
+        &client_builder,
+        &cache,
+        Some(&reporter),
+        install_mirrors.python_install_mirror.as_deref(),
+        install_mirrors.pypy_install_mirror.as_deref(),
+        install_mirrors.python_downloads_json_url.as_deref(),
+        preview,
+    )
+    .await?
+    .into_interpreter();
+
+    debug!(
+        "Using Python {} interpreter at {} for builds",
+        interpreter.python_version(),
+        interpreter.sys_executable().user_display().cyan()
+    );
+
+    // Create the shared state.
+    let state = SharedState::default();
+
+    // If we're resolving against a different Python version, use a separate index. Source
+    // distributions will be built against the installed version, and so the index may contain
+    // different package priorities than in the top-level resolution.
+    let top_level_index = if python_version.is_some() {
+        InMemoryIndex::default()
+    } else {
+        state.index().clone()
+    };
+
+    // Determine the Python requirement and the environment for the resolution.
+    let python_requirement = if let Some(python_version) = python_version.as_ref() {
+        PythonRequirement::from_python_version(&interpreter, python_version)
+    } else {
+        PythonRequirement::from_interpreter(&interpreter)
+    };
+    let tags = resolution_tags(
+        python_version.as_ref(),
+        python_platform.as_ref(),
+        &interpreter,
+    )?;
+    let marker_env = resolution_markers(
+        python_version.as_ref(),
+        python_platform.as_ref(),
+        &interpreter,
+    );
+
+    // Incorporate any index locations from the provided sources.
+    let index_locations = index_locations.combine(
+        extra_index_urls
+            .into_iter()
+            .map(Index::from_extra_index_url)
+            .chain(index_url.map(Index::from_index_url))
+            .map(|index| index.with_origin(Origin::RequirementsTxt))
+            .collect(),
+        find_links
+            .into_iter()
+            .map(Index::from_find_links)
+            .map(|index| index.with_origin(Origin::RequirementsTxt))
+            .collect(),
+        no_index,
+    );
+
+    // Initialize the registry client.
+    let client = RegistryClientBuilder::new(client_builder.clone(), cache.clone())
+        .index_locations(index_locations.clone())
+        .index_strategy(index_strategy)
+        .markers(interpreter.markers())
+        .platform(interpreter.platform())
+        .build();
+
+    // Combine the `--no-binary` and `--no-build` flags from the requirements files.
+    let build_options = build_options.try_combine(no_binary, no_build, build_options_origins)?;
+
+    // Collect the set of required hashes.
+    let hasher = if let Some(hash_checking) = hash_checking {
+        HashStrategy::from_requirements(
+            requirements
+                .iter()
+                .chain(overrides.iter())
+                .map(|entry| (&entry.requirement, entry.hashes.as_slice())),
+            constraints
+                .iter()
+                .map(|entry| (&entry.requirement, entry.hashes.as_slice())),
+            Some(&marker_env),
+            hash_checking,
+            &[],
+        )?
+    } else {
+        HashStrategy::None
+    };
+
+    // Resolve the flat indexes from `--find-links`.
+    let flat_index = {
+        let client = FlatIndexClient::new(client.cached_client(), client.connectivity(), &cache);
+        let entries = client
+            .fetch_all(index_locations.flat_indexes().map(Index::url))
+            .await?;
+        FlatIndex::from_entries(entries, Some(&tags), &hasher, &build_options)
+    };
+
+    // Determine whether to enable build isolation.
+    let environment;
+    let types_build_isolation = match build_isolation {
+        BuildIsolation::Isolate => uv_types::BuildIsolation::Isolated,
+        BuildIsolation::Shared => {
+            environment = PythonEnvironment::from_interpreter(interpreter.clone());
+            uv_types::BuildIsolation::Shared(&environment)
+        }
+        BuildIsolation::SharedPackage(ref packages) => {
+            environment = PythonEnvironment::from_interpreter(interpreter.clone());
+            uv_types::BuildIsolation::SharedPackage(&environment, packages)
+        }
+    };
+
+    let build_constraints = Constraints::from_requirements(
+        build_constraints
+            .iter()
+            .map(|constraint| constraint.requirement.clone()),
+    );
+
+    // Lower the extra build dependencies, if any.
+    let extra_build_requires =
+        LoweredExtraBuildDependencies::from_non_lowered(extra_build_dependencies.clone())
+            .into_inner();
+
+    // Create a build dispatch.
+    let build_dispatch = BuildDispatch::new(
+        &client,
+        &cache,
+        &build_constraints,
+        &interpreter,
+        &index_locations,
+        &flat_index,
+        &dependency_metadata,
+        state,
+        index_strategy,
+        config_settings,
+        config_settings_package,
+        types_build_isolation,
+        &extra_build_requires,
+        extra_build_variables,
+        link_mode,
+        &build_options,
+        &hasher,
+        exclude_newer.clone(),
+        sources,
+        workspace_cache,
+        concurrency.clone(),
+        preview,
+    );
+
+    let options = OptionsBuilder::new()
+        .resolution_mode(resolution_mode)
+        .prerelease_mode(prerelease_mode)
+        .prerelease_packages(prerelease_packages)
+        .fork_strategy(fork_strategy)
+        .dependency_mode(dependency_mode)
+        .exclude_newer(exclude_newer)
+        .index_strategy(index_strategy)
+        .build_options(build_options.clone())
+        .excluded_packages(excluded_packages)
+        .resolution_priority(resolution_priority)
+        .max_backtracks(max_backtracks)
+        .memory_limit(memory_limit)
+        .allow_yanked(allow_yanked)
+        .build();
+
+    // Resolve the requirements.
+    let resolution = match operations::resolve(
+        requirements,
+        constraints,
+        overrides,
+        excludes,
+        source_trees,
+        project,
+        BTreeSet::default(),
+        extras,
+        &groups,
+        Vec::new(),
+        EmptyInstalledPackages,
+        &hasher,
+        &Reinstall::None,
+        &Upgrade::none(),
+        Some(&tags),
+        ResolverEnvironment::specific(marker_env.clone()),
+        python_requirement,
+        interpreter.markers(),
+        Conflicts::empty(),
+        &client,
+        &flat_index,
+        &top_level_index,
+        &build_dispatch,
+        &concurrency,
+        options,
+        Box::new(DefaultResolveLogger),
+        printer,
+    )
+    .await
+    {
+        Ok(graph) => Resolution::from(graph),
+        Err(err) => {
+            return diagnostics::OperationDiagnostic::native_tls(client_builder.is_native_tls())
+                .report(err)
+                .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
+        }
+    };
+
+    // Save the distributions into the destination directory.
+    fs_err::tokio::create_dir_all(dest).await?;
+    let database = DistributionDatabase::new(
+        &client,
+        &build_dispatch,
+        concurrency.downloads_semaphore.clone(),
+    );
+
+    let mut filenames =
+        futures::stream::iter(resolution.distributions().filter_map(|dist| match dist {
+            ResolvedDist::Installable { dist, .. } => Some(dist),
+            ResolvedDist::Installed { .. } => None,
+        }))
+        .map(async |dist| match target {
+            DownloadTarget::Archives => download_dist(dist, dest, &database, &hasher).await,
+            DownloadTarget::Wheels => collect_wheel(dist, dest, &tags, &database, &hasher).await,
+        })
+        .buffer_unordered(concurrency.downloads)
+        .try_filter_map(|filename| async move { Ok(filename) })
+        .try_collect::<Vec<_>>()
+        .await?;
+    filenames.sort_unstable();
+
+    let (verb, noun) = match target {
+        DownloadTarget::Archives => ("Downloaded", "package"),
+        DownloadTarget::Wheels => ("Collected", "wheel"),
+    };
+    let s = if filenames.len() == 1 { "" } else { "s" };
+    writeln!(
+        printer.stderr(),
+        "{}",
+        format!(
+            "{verb} {} {}",
+            format!("{} {noun}{s}", filenames.len()).bold(),
+            format!("in {}", elapsed(start.elapsed())).dimmed()
+        )
+        .dimmed()
+    )?;
+    for filename in &filenames {
+        writeln!(printer.stderr(), " {} {}", "+".green(), filename.bold())?;
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Save a distribution to the destination directory, returning the filename of the saved archive.
+///
+/// The archive is validated against the hashes required by the [`HashStrategy`], if any; otherwise,
+/// against the hashes provided by the index.
+///
+/// Returns `None` if the distribution is not backed by an archive (e.g., a Git repository or a
+/// local directory), and so can't be downloaded.
+pub(crate) async fn download_dist(
+    dist: &Dist,
+    dest: &Path,
+    database: &DistributionDatabase<'_, BuildDispatch<'_>>,
+    hasher: &HashStrategy,
+) -> Result<Option<String>> {
+    let index_hashes = match dist {
+        Dist::Built(BuiltDist::Registry(wheels)) => wheels.best_wheel().file.hashes.as_slice(),
+        Dist::Source(SourceDist::Registry(sdist)) => sdist.file.hashes.as_slice(),
+        Dist::Built(BuiltDist::DirectUrl(_) | BuiltDist::Path(_))
+        | Dist::Source(SourceDist::DirectUrl(_) | SourceDist::Path(_)) => [].as_slice(),
+        Dist::Source(SourceDist::Git(_) | SourceDist::Directory(_)) => {
+            warn_user!("Skipping `{dist}`, which is not a wheel or source distribution archive");
+            return Ok(None);
+        }
+    };
+
+    // Prefer the hashes from the requirements, falling back to those provided by the index.
+    let hashes = match hasher.get(dist) {
+        HashPolicy::Validate(hashes) => HashPolicy::Validate(hashes),
+        HashPolicy::None | HashPolicy::Generate(_) if index_hashes.is_empty() => HashPolicy::None,
+        HashPolicy::None | HashPolicy::Generate(_) => HashPolicy::Validate(index_hashes),
+    };
+
+    let filename = dist.filename()?.into_owned();
+    database
+        .download_archive(dist, hashes, &dest.join(&filename))
+        .await?;
+    Ok(Some(filename))
+}
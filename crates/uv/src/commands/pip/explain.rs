@@ -0,0 +1,80 @@
+use std::fmt::Write;
+use std::path::Path;
+
+use anyhow::{Context, Result, anyhow};
+use owo_colors::OwoColorize;
+use tracing::info_span;
+
+use uv_fs::Simplified;
+use uv_normalize::PackageName;
+use uv_requirements::is_pylock_toml;
+use uv_resolver::{DisplayLockExplanation, Lock, PylockToml};
+use uv_warnings::warn_user;
+
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// Explain why a package was included in an existing `uv.lock` or `pylock.toml` file, without
+/// performing a resolution.
+pub(crate) async fn pip_explain_lock(
+    path: &Path,
+    package: &PackageName,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let content = fs_err::tokio::read_to_string(path).await?;
+
+    if path
+        .file_name()
+        .and_then(|name| name.to_str())
+        .is_some_and(is_pylock_toml)
+    {
+        let lock = info_span!("toml::from_str pip explain", path = %path.display())
+            .in_scope(|| toml::from_str::<PylockToml>(&content))
+            .with_context(|| format!("Not a valid `pylock.toml` file: {}", path.user_display()))?;
+
+        let versions = lock
+            .packages
+            .iter()
+            .filter(|locked| locked.name == *package)
+            .collect::<Vec<_>>();
+        if versions.is_empty() {
+            return Err(anyhow!(
+                "Package `{}` is not included in the lockfile",
+                package.cyan()
+            ));
+        }
+
+        // `pylock.toml` files don't record the edges between packages, so the best we can do is
+        // to show the locked versions.
+        warn_user!(
+            "`{}` does not record the dependencies of each package; only the locked versions of `{}` can be shown",
+            path.user_display().cyan(),
+            package.cyan()
+        );
+        for locked in versions {
+            if let Some(version) = &locked.version {
+                writeln!(
+                    printer.stdout_important(),
+                    "{}",
+                    format!("{}=={version}", locked.name).bold()
+                )?;
+            } else {
+                writeln!(printer.stdout_important(), "{}", locked.name.bold())?;
+            }
+        }
+        return Ok(ExitStatus::Success);
+    }
+
+    let lock = info_span!("toml::from_str pip explain", path = %path.display())
+        .in_scope(|| toml::from_str::<Lock>(&content))
+        .with_context(|| format!("Not a valid `uv.lock` file: {}", path.user_display()))?;
+
+    let Some(explanation) = DisplayLockExplanation::new(&lock, package) else {
+        return Err(anyhow!(
+            "Package `{}` is not included in the lockfile",
+            package.cyan()
+        ));
+    };
+    write!(printer.stdout_important(), "{explanation}")?;
+    Ok(ExitStatus::Success)
+}
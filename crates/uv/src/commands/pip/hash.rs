@@ -0,0 +1,45 @@
+use std::fmt::Write;
+use std::path::PathBuf;
+
+use anyhow::{Context, Result};
+use tokio::io::BufReader;
+use tracing::debug;
+
+use uv_extract::hash::{HashReader, Hasher};
+use uv_fs::Simplified;
+use uv_pypi_types::{HashAlgorithm, HashDigest};
+
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// Compute the hashes of local distribution files, in `requirements.txt` `--hash` syntax.
+pub(crate) async fn pip_hash(
+    paths: &[PathBuf],
+    algorithms: &[HashAlgorithm],
+    printer: Printer,
+) -> Result<ExitStatus> {
+    for path in paths {
+        debug!("Hashing: {}", path.user_display());
+        let file = fs_err::tokio::File::open(path).await?;
+        let mut hashers = algorithms
+            .iter()
+            .copied()
+            .map(Hasher::from)
+            .collect::<Vec<_>>();
+        HashReader::new(BufReader::new(file), &mut hashers)
+            .finish()
+            .await
+            .with_context(|| format!("Failed to read: `{}`", path.user_display()))?;
+
+        writeln!(printer.stdout_important(), "{}:", path.user_display())?;
+        for hasher in hashers {
+            writeln!(
+                printer.stdout_important(),
+                "--hash={}",
+                HashDigest::from(hasher)
+            )?;
+        }
+    }
+
+    Ok(ExitStatus::Success)
+}
@@ -0,0 +1,242 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write;
+
+use anyhow::Result;
+use itertools::Itertools;
+use serde::Serialize;
+use tracing::debug;
+
+use uv_cache::{Cache, Refresh};
+use uv_cache_info::Timestamp;
+use uv_cli::PipIndexFormat;
+use uv_client::{
+    BaseClientBuilder, FlatIndexClient, MetadataFormat, RegistryClientBuilder, VersionFiles,
+};
+use uv_configuration::{Concurrency, IndexStrategy, KeyringProviderType};
+use uv_distribution_filename::DistFilename;
+use uv_distribution_types::{File, Index, IndexCapabilities, IndexLocations};
+use uv_normalize::PackageName;
+use uv_pep440::Version;
+use uv_resolver::ExcludeNewer;
+use uv_warnings::warn_user_once;
+
+use crate::commands::ExitStatus;
+use crate::commands::pip::list::{Column, MultiZip};
+use crate::printer::Printer;
+
+/// List the available versions of a package on the configured indexes.
+pub(crate) async fn pip_index_versions(
+    package: &PackageName,
+    format: PipIndexFormat,
+    index_locations: IndexLocations,
+    index_strategy: IndexStrategy,
+    keyring_provider: KeyringProviderType,
+    client_builder: &BaseClientBuilder<'_>,
+    concurrency: Concurrency,
+    exclude_newer: &ExcludeNewer,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let capabilities = IndexCapabilities::default();
+
+    // Always fetch the latest metadata from the index.
+    let cache = cache.clone().with_refresh(Refresh::All(Timestamp::now()));
+
+    // Initialize the registry client.
+    let client = RegistryClientBuilder::new(
+        client_builder.clone().keyring(keyring_provider),
+        cache.clone(),
+    )
+    .index_locations(index_locations.clone())
+    .index_strategy(index_strategy)
+    .build();
+
+    let mut versions = BTreeMap::<Version, VersionEntry>::new();
+
+    // Fetch the distributions from the registry.
+    match client
+        .simple_detail(
+            package,
+            None,
+            &capabilities,
+            &concurrency.downloads_semaphore,
+        )
+        .await
+    {
+        Ok(archives) => {
+            for (_, archive) in archives {
+                match archive {
+                    MetadataFormat::Simple(archive) => {
+                        for datum in archive.iter() {
+                            let files = rkyv::deserialize::<VersionFiles, rkyv::rancor::Error>(
+                                &datum.files,
+                            )
+                            .expect("archived version files always deserializes");
+                            for (filename, file) in files.all() {
+                                // Skip distributions uploaded after the cutoff.
+                                if let Some(cutoff) = exclude_newer.exclude_newer_package(package) {
+                                    match file.upload_time_utc_ms.as_ref() {
+                                        Some(&upload_time)
+                                            if upload_time >= cutoff.timestamp_millis() =>
+                                        {
+                                            continue;
+                                        }
+                                        None => {
+                                            warn_user_once!(
+                                                "{} is missing an upload date, but user provided: {exclude_newer}",
+                                                file.filename,
+                                            );
+                                        }
+                                        _ => {}
+                                    }
+                                }
+                                add_file(&mut versions, &filename, &file);
+                            }
+                        }
+                    }
+                    MetadataFormat::Flat(entries) => {
+                        for entry in entries {
+                            add_file(&mut versions, &entry.filename, &entry.file);
+                        }
+                    }
+                }
+            }
+        }
+        Err(err) => match err.kind() {
+            uv_client::ErrorKind::RemotePackageNotFound(_)
+            | uv_client::ErrorKind::NoIndex(_)
+            | uv_client::ErrorKind::Offline(_) => {
+                debug!("No registry distributions found for: `{package}`");
+            }
+            _ => return Err(err.into()),
+        },
+    }
+
+    // Fetch the distributions from any `--find-links` locations. As in resolution, the
+    // `--exclude-newer` cutoff only applies to registry distributions.
+    let flat_index = FlatIndexClient::new(client.cached_client(), client.connectivity(), &cache)
+        .fetch_all(index_locations.flat_indexes().map(Index::url))
+        .await?;
+    for entry in flat_index.entries {
+        if entry.filename.name() != package {
+            continue;
+        }
+        add_file(&mut versions, &entry.filename, &entry.file);
+    }
+
+    if versions.is_empty() {
+        anyhow::bail!("No versions found for `{package}`");
+    }
+
+    // Display the versions from newest to oldest.
+    let rows = versions
+        .into_iter()
+        .rev()
+        .map(|(version, entry)| Entry {
+            version,
+            wheel: entry.wheel,
+            sdist: entry.sdist,
+            yanked: entry.yanked == entry.files,
+            requires_python: if entry.requires_python.is_empty() {
+                None
+            } else {
+                Some(entry.requires_python.iter().join(" | "))
+            },
+        })
+        .collect_vec();
+
+    match format {
+        PipIndexFormat::Json => {
+            let output = serde_json::to_string(&Output {
+                name: package,
+                versions: rows,
+            })?;
+            writeln!(printer.stdout_important(), "{output}")?;
+        }
+        PipIndexFormat::Columns => {
+            let yes_no = |value: bool| if value { "yes" } else { "no" }.to_string();
+            let columns = [
+                Column {
+                    header: String::from("Version"),
+                    rows: rows.iter().map(|row| row.version.to_string()).collect(),
+                },
+                Column {
+                    header: String::from("Wheel"),
+                    rows: rows.iter().map(|row| yes_no(row.wheel)).collect(),
+                },
+                Column {
+                    header: String::from("Sdist"),
+                    rows: rows.iter().map(|row| yes_no(row.sdist)).collect(),
+                },
+                Column {
+                    header: String::from("Yanked"),
+                    rows: rows.iter().map(|row| yes_no(row.yanked)).collect(),
+                },
+                Column {
+                    header: String::from("Requires-Python"),
+                    rows: rows
+                        .iter()
+                        .map(|row| row.requires_python.clone().unwrap_or_default())
+                        .collect(),
+                },
+            ];
+            for elems in MultiZip(columns.iter().map(Column::fmt).collect_vec()) {
+                writeln!(printer.stdout_important(), "{}", elems.join(" ").trim_end())?;
+            }
+        }
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Record a distribution in the map of available versions.
+fn add_file(versions: &mut BTreeMap<Version, VersionEntry>, filename: &DistFilename, file: &File) {
+    let entry = versions.entry(filename.version().clone()).or_default();
+    match filename {
+        DistFilename::WheelFilename(_) => entry.wheel = true,
+        DistFilename::SourceDistFilename(_) => entry.sdist = true,
+    }
+    entry.files += 1;
+    if file
+        .yanked
+        .as_ref()
+        .is_some_and(|yanked| yanked.is_yanked())
+    {
+        entry.yanked += 1;
+    }
+    if let Some(requires_python) = &file.requires_python {
+        entry.requires_python.insert(requires_python.to_string());
+    }
+}
+
+/// The distributions available for a given version of a package.
+#[derive(Debug, Default)]
+struct VersionEntry {
+    /// Whether any wheels are available.
+    wheel: bool,
+    /// Whether any source distributions are available.
+    sdist: bool,
+    /// The number of distributions.
+    files: usize,
+    /// The number of yanked distributions.
+    yanked: usize,
+    /// The distinct `Requires-Python` specifiers across the distributions.
+    requires_python: BTreeSet<String>,
+}
+
+/// An entry in a JSON list of versions.
+#[derive(Debug, Serialize)]
+struct Entry {
+    version: Version,
+    wheel: bool,
+    sdist: bool,
+    yanked: bool,
+    requires_python: Option<String>,
+}
+
+/// The JSON output for `pip index versions`.
+#[derive(Debug, Serialize)]
+struct Output<'a> {
+    name: &'a PackageName,
+    versions: Vec<Entry>,
+}
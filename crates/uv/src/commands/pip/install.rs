@@ -1,4 +1,5 @@
 use std::collections::BTreeSet;
+use std::fmt::Write;
 
 use anyhow::Context;
 use itertools::Itertools;
@@ -16,13 +17,15 @@ use uv_dispatch::{BuildDispatch, SharedState};
 use uv_distribution::LoweredExtraBuildDependencies;
 use uv_distribution_types::{
     ConfigSettings, DependencyMetadata, ExtraBuildVariables, Index, IndexLocations,
-    NameRequirementSpecification, Origin, PackageConfigSettings, Requirement, Resolution,
-    UnresolvedRequirementSpecification,
+    NameRequirementSpecification, Origin, PackageConfigSettings, Requirement, RequirementSource,
+    Resolution, UnresolvedRequirementSpecification,
 };
 use uv_fs::Simplified;
 use uv_install_wheel::LinkMode;
-use uv_installer::{InstallationStrategy, SatisfiesResult, SitePackages};
+use uv_installer::{ImportNames, InstallationStrategy, SatisfiesResult, SitePackages};
 use uv_normalize::{DefaultExtras, DefaultGroups, PackageName};
+use uv_pep440::VersionSpecifiers;
+use uv_pep508::MarkerTree;
 use uv_preview::{Preview, PreviewFeature};
 use uv_pypi_types::Conflicts;
 use uv_python::{
@@ -31,8 +34,8 @@ use uv_python::{
 };
 use uv_requirements::{GroupsSpecification, RequirementsSource, RequirementsSpecification};
 use uv_resolver::{
-    DependencyMode, ExcludeNewer, FlatIndex, OptionsBuilder, PrereleaseMode, PylockToml,
-    PythonRequirement, ResolutionMode, ResolverEnvironment,
+    AllowYankedEntry, DependencyMode, ExcludeNewer, FlatIndex, OptionsBuilder, PrereleaseMode,
+    PylockToml, PythonRequirement, ResolutionMode, ResolverEnvironment,
 };
 use uv_settings::PythonInstallMirrors;
 use uv_torch::{TorchMode, TorchSource, TorchStrategy};
@@ -44,6 +47,7 @@ use uv_workspace::pyproject::ExtraBuildDependencies;
 use crate::commands::pip::loggers::{DefaultInstallLogger, DefaultResolveLogger, InstallLogger};
 use crate::commands::pip::operations::Modifications;
 use crate::commands::pip::operations::{report_interpreter, report_target_environment};
+use crate::commands::pip::verify::verify_changelog;
 use crate::commands::pip::{operations, resolution_markers, resolution_tags};
 use crate::commands::reporters::PythonDownloadReporter;
 use crate::commands::{ExitStatus, diagnostics};
@@ -53,6 +57,7 @@ use crate::printer::Printer;
 #[expect(clippy::fn_params_excessive_bools)]
 pub(crate) async fn pip_install(
     requirements: &[RequirementsSource],
+    for_import: &[String],
     constraints: &[RequirementsSource],
     overrides: &[RequirementsSource],
     excludes: &[RequirementsSource],
@@ -65,6 +70,7 @@ pub(crate) async fn pip_install(
     groups: &GroupsSpecification,
     resolution_mode: ResolutionMode,
     prerelease_mode: PrereleaseMode,
+    prerelease_packages: Vec<PackageName>,
     dependency_mode: DependencyMode,
     upgrade: Upgrade,
     index_locations: IndexLocations,
@@ -77,6 +83,7 @@ pub(crate) async fn pip_install(
     link_mode: LinkMode,
     compile: bool,
     hash_checking: Option<HashCheckingMode>,
+    allow_unhashed: Vec<PackageName>,
     installer_metadata: bool,
     config_settings: &ConfigSettings,
     config_settings_package: &PackageConfigSettings,
@@ -92,6 +99,12 @@ pub(crate) async fn pip_install(
     strict: bool,
     exclude_newer: ExcludeNewer,
     sources: NoSources,
+    excluded_packages: Vec<PackageName>,
+    resolution_priority: Vec<PackageName>,
+    max_backtracks: Option<usize>,
+    memory_limit: Option<usize>,
+    allow_yanked: Vec<AllowYankedEntry>,
+    protected_packages: Vec<PackageName>,
     python: Option<String>,
     system: bool,
     break_system_packages: bool,
@@ -102,6 +115,8 @@ pub(crate) async fn pip_install(
     cache: Cache,
     workspace_cache: WorkspaceCache,
     dry_run: DryRun,
+    only_uv_managed: bool,
+    verify_record: bool,
     printer: Printer,
     preview: Preview,
 ) -> anyhow::Result<ExitStatus> {
@@ -112,7 +127,7 @@ pub(crate) async fn pip_install(
     // Read all requirements from the provided sources.
     let RequirementsSpecification {
         project,
-        requirements,
+        mut requirements,
         constraints,
         overrides,
         excludes,
@@ -125,6 +140,7 @@ pub(crate) async fn pip_install(
         find_links,
         no_binary,
         no_build,
+        build_options_origins,
         extras: _,
     } = operations::read_requirements(
         requirements,
@@ -305,6 +321,34 @@ pub(crate) async fn pip_install(
     // Determine the set of installed packages.
     let site_packages = SitePackages::from_environment(&environment)?;
 
+    // Add the distributions that provide any requested imports.
+    if !for_import.is_empty() {
+        let import_names = ImportNames::from_site_packages(&site_packages, &cache);
+        for module in for_import {
+            let Some(name) = import_names.resolve(module) else {
+                return Err(anyhow::anyhow!("`{module}` is not a valid import name"));
+            };
+            writeln!(
+                printer.stderr(),
+                "Resolved import `{}` to `{}`",
+                module.cyan(),
+                name.cyan()
+            )?;
+            requirements.push(UnresolvedRequirementSpecification::from(Requirement {
+                name,
+                extras: Box::new([]),
+                groups: Box::new([]),
+                marker: MarkerTree::default(),
+                source: RequirementSource::Registry {
+                    specifier: VersionSpecifiers::empty(),
+                    index: None,
+                    conflict: None,
+                },
+                origin: None,
+            }));
+        }
+    }
+
     // Check if the current environment satisfies the requirements.
     // Ideally, the resolver would be fast enough to let us remove this check. But right now, for large environments,
     // it's an order of magnitude faster to validate the environment than to resolve the requirements.
@@ -369,11 +413,15 @@ pub(crate) async fn pip_install(
                 .map(|entry| (&entry.requirement, entry.hashes.as_slice())),
             Some(&marker_env),
             hash_checking,
+            &allow_unhashed,
         )?
     } else {
         HashStrategy::None
     };
 
+    // Report any unhashable requirements that are exempt from hash-checking.
+    operations::report_unhashed(&requirements, &allow_unhashed, hash_checking);
+
     // Incorporate any index locations from the provided sources.
     let index_locations = index_locations.combine(
         extra_index_urls
@@ -422,7 +470,7 @@ pub(crate) async fn pip_install(
         .build();
 
     // Combine the `--no-binary` and `--no-build` flags from the requirements files.
-    let build_options = build_options.combine(no_binary, no_build);
+    let build_options = build_options.try_combine(no_binary, no_build, build_options_origins)?;
 
     // Resolve the flat indexes from `--find-links`.
     let flat_index = {
@@ -452,6 +500,7 @@ pub(crate) async fn pip_install(
                 .map(|entry| (&entry.requirement, entry.hashes.as_slice())),
             Some(&marker_env),
             HashCheckingMode::Verify,
+            &[],
         )?
     } else {
         HashStrategy::None
@@ -566,11 +615,17 @@ pub(crate) async fn pip_install(
         let options = OptionsBuilder::new()
             .resolution_mode(resolution_mode)
             .prerelease_mode(prerelease_mode)
+            .prerelease_packages(prerelease_packages)
             .dependency_mode(dependency_mode)
             .exclude_newer(exclude_newer.clone())
             .index_strategy(index_strategy)
             .torch_backend(torch_backend)
             .build_options(build_options.clone())
+            .excluded_packages(excluded_packages.clone())
+            .resolution_priority(resolution_priority)
+            .max_backtracks(max_backtracks)
+            .memory_limit(memory_limit)
+            .allow_yanked(allow_yanked)
             .build();
 
         // Resolve the requirements.
@@ -618,6 +673,12 @@ pub(crate) async fn pip_install(
         (resolution, hasher)
     };
 
+    // When hash-checking is required, refuse to install any yanked versions that weren't
+    // explicitly allowed.
+    if hash_checking.is_some_and(|mode| mode.is_require()) {
+        operations::check_yanked(resolution.diagnostics())?;
+    }
+
     // Constrain any build requirements marked as `match-runtime = true`.
     let extra_build_requires = extra_build_requires.match_runtime(&resolution)?;
 
@@ -648,7 +709,7 @@ pub(crate) async fn pip_install(
     );
 
     // Sync the environment.
-    match operations::install(
+    let changelog = match operations::install(
         &resolution,
         site_packages,
         InstallationStrategy::Permissive,
@@ -667,19 +728,22 @@ pub(crate) async fn pip_install(
         &environment,
         Box::new(DefaultInstallLogger),
         installer_metadata,
+        only_uv_managed,
+        &excluded_packages,
+        &protected_packages,
         dry_run,
         printer,
         preview,
     )
     .await
     {
-        Ok(..) => {}
+        Ok(changelog) => changelog,
         Err(err) => {
             return diagnostics::OperationDiagnostic::native_tls(client_builder.is_native_tls())
                 .report(err)
                 .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
         }
-    }
+    };
 
     // Notify the user of any resolution diagnostics.
     operations::diagnose_resolution(resolution.diagnostics(), printer)?;
@@ -689,5 +753,11 @@ pub(crate) async fn pip_install(
         operations::diagnose_environment(&resolution, &environment, &marker_env, &tags, printer)?;
     }
 
+    // If requested, verify the installed files against their `RECORD`.
+    if verify_record && !dry_run.enabled() && !verify_changelog(&changelog, &environment, printer)?
+    {
+        return Ok(ExitStatus::Failure);
+    }
+
     Ok(ExitStatus::Success)
 }
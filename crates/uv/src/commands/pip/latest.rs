@@ -55,6 +55,7 @@ impl LatestClient<'_> {
         };
 
         let mut latest: Option<DistFilename> = None;
+        let mut latest_prerelease: Option<DistFilename> = None;
         for (_, archive) in archives {
             let MetadataFormat::Simple(archive) = archive else {
                 continue;
@@ -88,13 +89,6 @@ impl LatestClient<'_> {
                         }
                     }
 
-                    // Skip pre-release distributions.
-                    if !filename.version().is_stable() {
-                        if !matches!(self.prerelease, PrereleaseMode::Allow) {
-                            continue;
-                        }
-                    }
-
                     // Skip distributions that are yanked.
                     if file.yanked.is_some_and(|yanked| yanked.is_yanked()) {
                         continue;
@@ -136,19 +130,33 @@ impl LatestClient<'_> {
                     }
                 }
 
-                match (latest.as_ref(), best) {
-                    (Some(current), Some(best)) => {
-                        if best.version() > current.version() {
-                            latest = Some(best);
-                        }
-                    }
-                    (None, Some(best)) => {
-                        latest = Some(best);
-                    }
-                    _ => {}
+                let Some(best) = best else {
+                    continue;
+                };
+
+                // Pre-releases are only considered if allowed, or if there are no stable
+                // releases and the strategy permits falling back to pre-releases.
+                let latest = if best.version().is_stable()
+                    || matches!(self.prerelease, PrereleaseMode::Allow)
+                {
+                    &mut latest
+                } else if matches!(
+                    self.prerelease,
+                    PrereleaseMode::IfNecessary | PrereleaseMode::IfNecessaryOrExplicit
+                ) {
+                    &mut latest_prerelease
+                } else {
+                    continue;
+                };
+
+                if latest
+                    .as_ref()
+                    .is_none_or(|current| best.version() > current.version())
+                {
+                    *latest = Some(best);
                 }
             }
         }
-        Ok(latest)
+        Ok(latest.or(latest_prerelease))
     }
 }
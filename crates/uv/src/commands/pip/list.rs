@@ -35,12 +35,15 @@ use crate::commands::reporters::LatestVersionReporter;
 use crate::printer::Printer;
 
 /// Enumerate the installed packages in the current environment.
+#[expect(clippy::fn_params_excessive_bools)]
 pub(crate) async fn pip_list(
     editable: Option<bool>,
     exclude: &FxHashSet<PackageName>,
+    not_required: bool,
     format: &ListFormat,
     outdated: bool,
     prerelease: PrereleaseMode,
+    prerelease_packages: &[PackageName],
     index_locations: IndexLocations,
     index_strategy: IndexStrategy,
     keyring_provider: KeyringProviderType,
@@ -92,11 +95,29 @@ pub(crate) async fn pip_list(
     // Build the installed index.
     let site_packages = SitePackages::from_environment(&environment)?;
 
-    // Filter if `--editable` is specified; always sort by name.
+    // If `--not-required` is specified, determine the packages required by any installed package.
+    let required = if not_required {
+        let markers = environment.interpreter().resolver_marker_environment();
+        let mut required = FxHashSet::default();
+        for dist in site_packages.iter() {
+            let metadata = dist.read_metadata()?;
+            for requirement in &metadata.requires_dist {
+                if requirement.name != metadata.name && requirement.marker.evaluate(&markers, &[]) {
+                    required.insert(requirement.name.clone());
+                }
+            }
+        }
+        required
+    } else {
+        FxHashSet::default()
+    };
+
+    // Filter if `--editable` or `--not-required` is specified; always sort by name.
     let results = site_packages
         .iter()
         .filter(|dist| editable.is_none() || editable == Some(dist.is_editable()))
         .filter(|dist| !exclude.contains(dist.name()))
+        .filter(|dist| !required.contains(dist.name()))
         .sorted_unstable_by(|a, b| a.name().cmp(b.name()).then(a.version().cmp(b.version())))
         .collect_vec();
 
@@ -134,11 +155,28 @@ pub(crate) async fn pip_list(
             requires_python: Some(&requires_python),
         };
 
+        // Pre-releases are allowed for any packages passed to `--prerelease allow:<package>`. An
+        // installed pre-release is treated as an explicit opt-in to newer pre-releases, too.
+        let prerelease_client = LatestClient {
+            prerelease: PrereleaseMode::Allow,
+            ..client.clone()
+        };
+
         let reporter = LatestVersionReporter::from(printer).with_length(results.len() as u64);
 
         // Fetch the latest version for each package.
         let mut fetches = futures::stream::iter(&results)
             .map(async |dist| {
+                let client = if prerelease_packages.contains(dist.name())
+                    || !dist.version().is_stable()
+                        && matches!(
+                            prerelease,
+                            PrereleaseMode::Explicit | PrereleaseMode::IfNecessaryOrExplicit
+                        ) {
+                    &prerelease_client
+                } else {
+                    &client
+                };
                 let latest = client
                     .find_latest(dist.name(), None, &download_concurrency)
                     .await?;
@@ -361,11 +399,11 @@ struct Entry {
 
 /// A column in a table.
 #[derive(Debug)]
-struct Column {
+pub(crate) struct Column {
     /// The header of the column.
-    header: String,
+    pub(crate) header: String,
     /// The rows of the column.
-    rows: Vec<String>,
+    pub(crate) rows: Vec<String>,
 }
 
 impl<'a> Column {
@@ -378,7 +416,7 @@ impl<'a> Column {
     }
 
     /// Return an iterator of the column, with the header and rows formatted to the maximum width.
-    fn fmt(&'a self) -> impl Iterator<Item = String> + 'a {
+    pub(crate) fn fmt(&'a self) -> impl Iterator<Item = String> + 'a {
         let max_width = self.max_width();
         let header = vec![
             format!("{0:width$}", self.header, width = max_width),
@@ -395,7 +433,7 @@ impl<'a> Column {
 ///
 /// A combination of [`itertools::multizip`] and [`itertools::izip`].
 #[derive(Debug)]
-struct MultiZip<T>(Vec<T>);
+pub(crate) struct MultiZip<T>(pub(crate) Vec<T>);
 
 impl<T> Iterator for MultiZip<T>
 where
@@ -212,10 +212,11 @@ impl InstallLogger for DefaultInstallLogger {
                 ChangeEventKind::Added => {
                     writeln!(
                         printer.stderr(),
-                        " {} {}{}",
+                        " {} {}{}{}",
                         "+".green(),
                         event.dist.name().bold(),
-                        event.dist.long_specifier().dimmed()
+                        event.dist.long_specifier().dimmed(),
+                        unhashed(changelog, event.dist.name())
                     )?;
                 }
                 ChangeEventKind::Removed => {
@@ -230,10 +231,11 @@ impl InstallLogger for DefaultInstallLogger {
                 ChangeEventKind::Reinstalled => {
                     writeln!(
                         printer.stderr(),
-                        " {} {}{}",
+                        " {} {}{}{}",
                         "~".yellow(),
                         event.dist.name().bold(),
-                        event.dist.long_specifier().dimmed()
+                        event.dist.long_specifier().dimmed(),
+                        unhashed(changelog, event.dist.name())
                     )?;
                 }
             }
@@ -242,6 +244,16 @@ impl InstallLogger for DefaultInstallLogger {
     }
 }
 
+/// Return a suffix for a distribution in the [`Changelog`] that was installed without
+/// hash-checking.
+fn unhashed(changelog: &Changelog, name: &PackageName) -> impl fmt::Display {
+    if changelog.unhashed.contains(name) {
+        " (without hash-checking)".yellow().to_string()
+    } else {
+        String::new()
+    }
+}
+
 /// A logger that only shows installs and uninstalls, the minimal logging necessary to understand
 /// environment changes.
 #[derive(Debug, Default, Clone, Copy)]
@@ -7,16 +7,23 @@ use uv_python::{Interpreter, PythonVersion};
 
 pub(crate) mod check;
 pub(crate) mod compile;
+pub(crate) mod download;
+pub(crate) mod explain;
 pub(crate) mod freeze;
+pub(crate) mod hash;
+pub(crate) mod index;
 pub(crate) mod install;
 pub(crate) mod latest;
 pub(crate) mod list;
 pub(crate) mod loggers;
 pub(crate) mod operations;
 pub(crate) mod show;
+pub(crate) mod stub;
 pub(crate) mod sync;
 pub(crate) mod tree;
 pub(crate) mod uninstall;
+pub(crate) mod verify;
+pub(crate) mod wheel;
 
 pub(crate) fn resolution_markers(
     python_version: Option<&PythonVersion>,
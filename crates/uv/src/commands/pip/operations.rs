@@ -8,16 +8,16 @@ use std::sync::Arc;
 use anyhow::{Context, anyhow};
 use itertools::Itertools;
 use owo_colors::OwoColorize;
-use tracing::debug;
+use tracing::{debug, instrument};
 
-use uv_cache::Cache;
+use uv_cache::{Cache, CacheBucket};
 use uv_client::{BaseClientBuilder, RegistryClient};
 use uv_configuration::{
     BuildOptions, Concurrency, Constraints, DependencyGroups, DryRun, Excludes,
-    ExtrasSpecification, Overrides, Reinstall, Upgrade,
+    ExtrasSpecification, HashCheckingMode, Overrides, Reinstall, Upgrade,
 };
 use uv_dispatch::BuildDispatch;
-use uv_distribution::{DistributionDatabase, SourcedDependencyGroups};
+use uv_distribution::{DistributionDatabase, SourcedDependencyGroups, missing_from_cache};
 use uv_distribution_types::{
     CachedDist, Diagnostic, Dist, InstalledDist, InstalledVersion, LocalDist,
     NameRequirementSpecification, Requirement, ResolutionDiagnostic, UnresolvedRequirement,
@@ -104,6 +104,7 @@ pub(crate) async fn read_constraints(
 }
 
 /// Resolve a set of requirements, similar to running `pip compile`.
+#[instrument(skip_all)]
 pub(crate) async fn resolve<InstalledPackages: InstalledPackagesProvider>(
     requirements: Vec<UnresolvedRequirementSpecification>,
     constraints: Vec<NameRequirementSpecification>,
@@ -265,6 +266,16 @@ pub(crate) async fn resolve<InstalledPackages: InstalledPackagesProvider>(
         requirements
     };
 
+    // If only the direct requirements should be upgraded (`--upgrade-strategy only-if-needed`),
+    // ignore their preferences, but retain the preferences for all other packages.
+    let upgrade = upgrade
+        .clone()
+        .with_requested(requirements.iter().map(|requirement| &requirement.name));
+    let preferences = preferences
+        .into_iter()
+        .filter(|preference| !upgrade.contains(preference.name()))
+        .collect::<Vec<_>>();
+
     // Resolve the overrides from the provided sources.
     let overrides = {
         // Partition the overrides into named and unnamed requirements.
@@ -335,7 +346,7 @@ pub(crate) async fn resolve<InstalledPackages: InstalledPackagesProvider>(
     };
 
     // TODO(zanieb): Consider consuming these instead of cloning
-    let exclusions = Exclusions::new(reinstall.clone(), upgrade.clone());
+    let exclusions = Exclusions::new(reinstall.clone(), upgrade);
 
     // Create a manifest of the requirements.
     let manifest = Manifest::new(
@@ -493,6 +504,8 @@ pub(crate) struct Changelog {
     pub(crate) uninstalled: HashSet<ChangedDist>,
     /// The distributions that were reinstalled.
     pub(crate) reinstalled: HashSet<ChangedDist>,
+    /// The packages that were installed without hash-checking, as allowed by `--allow-unhashed`.
+    pub(crate) unhashed: BTreeSet<PackageName>,
 }
 
 impl Changelog {
@@ -515,6 +528,7 @@ impl Changelog {
             installed,
             uninstalled,
             reinstalled,
+            unhashed: BTreeSet::default(),
         }
     }
 
@@ -535,6 +549,17 @@ impl Changelog {
         Self::from_local(installed, Vec::new())
     }
 
+    /// Record the distributions in the [`Resolution`] that are exempt from hash-checking.
+    #[must_use]
+    pub(crate) fn with_unhashed(mut self, resolution: &Resolution, hasher: &HashStrategy) -> Self {
+        self.unhashed = resolution
+            .distributions()
+            .filter(|dist| hasher.is_exempt(*dist))
+            .map(|dist| dist.name().clone())
+            .collect();
+        self
+    }
+
     /// Returns `true` if the changelog includes a distribution with the given name, either via
     /// an installation or uninstallation.
     pub(crate) fn includes(&self, name: &PackageName) -> bool {
@@ -551,6 +576,7 @@ impl Changelog {
 /// Install a set of requirements into the current environment.
 ///
 /// Returns a [`Changelog`] summarizing the changes made to the environment.
+#[instrument(skip_all)]
 pub(crate) async fn install(
     resolution: &Resolution,
     site_packages: SitePackages,
@@ -570,6 +596,9 @@ pub(crate) async fn install(
     venv: &PythonEnvironment,
     logger: Box<dyn InstallLogger>,
     installer_metadata: bool,
+    only_uv_managed: bool,
+    excluded_packages: &[PackageName],
+    protected_packages: &[PackageName],
     dry_run: DryRun,
     printer: Printer,
     preview: Preview,
@@ -596,12 +625,46 @@ pub(crate) async fn install(
         )
         .context("Failed to determine installation plan")?;
 
+    // If requested, refuse to remove or replace any distributions installed by other tools.
+    if only_uv_managed {
+        let extraneous = match modifications {
+            Modifications::Sufficient => [].as_slice(),
+            Modifications::Exact => plan.extraneous.as_slice(),
+        };
+        check_uv_managed(plan.reinstalls.iter().chain(extraneous))?;
+    }
+
+    // Refuse to install any excluded packages, e.g., from a lockfile that predates the setting.
+    if let Some(name) = plan
+        .cached
+        .iter()
+        .map(Name::name)
+        .chain(plan.remote.iter().map(|dist| dist.name()))
+        .find(|name| excluded_packages.contains(name))
+    {
+        return Err(Error::ExcludedPackage { name: name.clone() });
+    }
+
+    // Refuse to remove or replace any protected packages.
+    let extraneous = match modifications {
+        Modifications::Sufficient => [].as_slice(),
+        Modifications::Exact => plan.extraneous.as_slice(),
+    };
+    check_protected(plan.reinstalls.iter().chain(extraneous), protected_packages)?;
+
+    // If network access is disabled, ensure that every distribution we need to fetch is available
+    // in the cache before modifying the environment.
+    if client.connectivity().is_offline() {
+        check_offline(&plan.remote, cache)?;
+    }
+
     if dry_run.enabled() {
         return report_dry_run(
             dry_run,
             resolution,
             plan,
             modifications,
+            hasher,
             start,
             logger.as_ref(),
             printer,
@@ -709,7 +772,7 @@ pub(crate) async fn install(
     }
 
     // Construct a summary of the changes made to the environment.
-    let changelog = Changelog::from_local(installs, uninstalls);
+    let changelog = Changelog::from_local(installs, uninstalls).with_unhashed(resolution, hasher);
 
     // Notify the user of any environment modifications.
     logger.on_complete(&changelog, printer, dry_run)?;
@@ -731,7 +794,106 @@ impl InstallPhase {
     }
 }
 
+/// Ensure that each of the given distributions was installed by uv.
+#[expect(clippy::result_large_err)]
+pub(crate) fn check_uv_managed<'a>(
+    dists: impl IntoIterator<Item = &'a InstalledDist>,
+) -> Result<(), Error> {
+    for dist in dists {
+        if dist.is_managed_by_uv().map_err(anyhow::Error::from)? {
+            continue;
+        }
+        let installer = dist.read_installer().map_err(anyhow::Error::from)?;
+        return Err(Error::NotUvManaged {
+            name: dist.name().clone(),
+            installer: installer.map_or_else(
+                || "an unknown tool".to_string(),
+                |installer| format!("`{installer}`"),
+            ),
+        });
+    }
+    Ok(())
+}
+
+/// Ensure that none of the given distributions are protected by the `protected-packages` setting.
+#[expect(clippy::result_large_err)]
+pub(crate) fn check_protected<'a>(
+    dists: impl IntoIterator<Item = &'a InstalledDist>,
+    protected_packages: &[PackageName],
+) -> Result<(), Error> {
+    if let Some(dist) = dists
+        .into_iter()
+        .find(|dist| protected_packages.contains(dist.name()))
+    {
+        return Err(Error::ProtectedPackage {
+            name: dist.name().clone(),
+        });
+    }
+    Ok(())
+}
+
+/// Ensure that all of the given distributions can be fetched from the cache, without network
+/// access.
+#[expect(clippy::result_large_err)]
+pub(crate) fn check_offline(dists: &[Arc<Dist>], cache: &Cache) -> Result<(), Error> {
+    let missing = dists
+        .iter()
+        .filter_map(|dist| {
+            let bucket = missing_from_cache(dist, cache)?;
+            Some((dist.clone(), bucket))
+        })
+        .collect::<Vec<_>>();
+    if missing.is_empty() {
+        Ok(())
+    } else {
+        Err(Error::MissingFromCache(missing))
+    }
+}
+
+/// Ensure that the resolution doesn't include any yanked versions.
+///
+/// Yanked versions that were explicitly allowed via `--allow-yanked` are omitted from the
+/// resolution diagnostics, and so are permitted.
+#[expect(clippy::result_large_err)]
+pub(crate) fn check_yanked(diagnostics: &[ResolutionDiagnostic]) -> Result<(), Error> {
+    for diagnostic in diagnostics {
+        if let ResolutionDiagnostic::YankedVersion { dist, .. } = diagnostic
+            && let Some(version) = dist.version()
+        {
+            return Err(Error::YankedVersion {
+                name: dist.name().clone(),
+                version: version.clone(),
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Warn about any unhashable requirements that are exempt from hash-checking via
+/// `--allow-unhashed`.
+pub(crate) fn report_unhashed(
+    requirements: &[UnresolvedRequirementSpecification],
+    allow_unhashed: &[PackageName],
+    hash_checking: Option<HashCheckingMode>,
+) {
+    if !hash_checking.is_some_and(|mode| mode.is_require()) {
+        return;
+    }
+    for entry in requirements {
+        if let UnresolvedRequirement::Named(requirement) = &entry.requirement
+            && allow_unhashed.contains(&requirement.name)
+            && let Some(kind) = HashStrategy::unhashable(&entry.requirement)
+        {
+            warn_user!(
+                "Installing `{}` ({kind}) without hash-checking, as allowed by `--allow-unhashed`",
+                requirement.name
+            );
+        }
+    }
+}
+
 /// Execute a [`Plan`] to install distributions into a Python environment.
+#[instrument(skip_all)]
 async fn execute_plan(
     plan: Plan,
     phase: Option<InstallPhase>,
@@ -981,6 +1143,7 @@ fn report_dry_run(
     resolution: &Resolution,
     plan: Plan,
     modifications: Modifications,
+    hasher: &HashStrategy,
     start: std::time::Instant,
     logger: &dyn InstallLogger,
     printer: Printer,
@@ -1036,7 +1199,7 @@ fn report_dry_run(
             .map(|dist| ChangedDist::Local(dist.into())),
     );
 
-    let changelog = Changelog::new(installed, uninstalled);
+    let changelog = Changelog::new(installed, uninstalled).with_unhashed(resolution, hasher);
 
     logger.on_complete(&changelog, printer, dry_run)?;
 
@@ -1121,4 +1284,29 @@ pub(crate) enum Error {
 
     #[error("The environment is outdated; run `{}` to update the environment", "uv sync".cyan())]
     OutdatedEnvironment,
+
+    #[error(
+        "Refusing to modify `{name}`, which was installed by {installer} rather than uv (omit `--only-uv-managed` to modify it anyway)"
+    )]
+    NotUvManaged {
+        name: PackageName,
+        installer: String,
+    },
+
+    #[error("Refusing to install `{name}`, which is excluded by the `excluded-packages` setting")]
+    ExcludedPackage { name: PackageName },
+
+    #[error("Refusing to remove `{name}`, which is protected by the `protected-packages` setting")]
+    ProtectedPackage { name: PackageName },
+
+    #[error(
+        "Refusing to install `{name}=={version}`, which is yanked, while hash-checking is required (pass `--allow-yanked {name}=={version}` to install it anyway)"
+    )]
+    YankedVersion { name: PackageName, version: Version },
+
+    #[error(
+        "Network connectivity is disabled, but the following distributions are missing from the cache:{}",
+        .0.iter().map(|(dist, bucket)| format!("\n- `{dist}` (`{bucket}`)")).join("")
+    )]
+    MissingFromCache(Vec<(Arc<Dist>, CacheBucket)>),
 }
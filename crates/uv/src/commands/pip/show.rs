@@ -1,3 +1,4 @@
+use std::collections::BTreeMap;
 use std::fmt::Write;
 
 use anyhow::Result;
@@ -5,14 +6,17 @@ use fs_err::File;
 use itertools::{Either, Itertools};
 use owo_colors::OwoColorize;
 use rustc_hash::FxHashMap;
+use serde::Serialize;
 use tracing::debug;
 
 use uv_cache::Cache;
+use uv_cli::PipShowFormat;
 use uv_distribution_types::{Diagnostic, Name};
 use uv_fs::Simplified;
-use uv_install_wheel::read_record_file;
+use uv_install_wheel::{read_entry_points, read_record_file};
 use uv_installer::SitePackages;
 use uv_normalize::PackageName;
+use uv_pep440::Version;
 use uv_preview::Preview;
 use uv_python::{
     EnvironmentPreference, Prefix, PythonEnvironment, PythonPreference, PythonRequest, Target,
@@ -23,6 +27,7 @@ use crate::commands::pip::operations::report_target_environment;
 use crate::printer::Printer;
 
 /// Show information about one or more installed packages.
+#[expect(clippy::fn_params_excessive_bools)]
 pub(crate) fn pip_show(
     mut packages: Vec<PackageName>,
     strict: bool,
@@ -31,6 +36,8 @@ pub(crate) fn pip_show(
     target: Option<Target>,
     prefix: Option<Prefix>,
     files: bool,
+    entry_points: bool,
+    format: PipShowFormat,
     cache: &Cache,
     printer: Printer,
     preview: Preview,
@@ -154,73 +161,127 @@ pub(crate) fn pip_show(
         }
     }
 
-    // Print the information for each package.
-    for (i, distribution) in distributions.iter().enumerate() {
-        if i > 0 {
-            // Print a separator between packages.
-            writeln!(printer.stdout(), "---")?;
-        }
-
-        // Print the name, version, and location (e.g., the `site-packages` directory).
-        writeln!(printer.stdout(), "Name: {}", distribution.name())?;
-        writeln!(printer.stdout(), "Version: {}", distribution.version())?;
-        writeln!(
-            printer.stdout(),
-            "Location: {}",
-            distribution
-                .install_path()
-                .parent()
-                .expect("package path is not root")
-                .simplified_display()
-        )?;
+    // Collect the information for each package.
+    let entries = distributions
+        .iter()
+        .map(|distribution| {
+            let requires = requires_map.get(distribution.name());
 
-        if let Some(path) = distribution
-            .as_editable()
-            .and_then(|url| url.to_file_path().ok())
-        {
-            writeln!(
-                printer.stdout(),
-                "Editable project location: {}",
-                path.simplified_display()
-            )?;
-        }
+            // Only determine the reverse dependencies if the metadata could be read.
+            let required_by = requires.map(|_| {
+                requires_map
+                    .iter()
+                    .filter(|(name, pkgs)| {
+                        **name != distribution.name()
+                            && pkgs.iter().any(|pkg| *pkg == distribution.name())
+                    })
+                    .map(|(name, _)| *name)
+                    .sorted_unstable()
+                    .dedup()
+                    .collect_vec()
+            });
 
-        // If available, print the requirements.
-        if let Some(requires) = requires_map.get(distribution.name()) {
-            if requires.is_empty() {
-                writeln!(printer.stdout(), "Requires:")?;
+            let entry_points = if entry_points {
+                let path = distribution.install_path().join("entry_points.txt");
+                match fs_err::read_to_string(path) {
+                    Ok(ini) => Some(read_entry_points(ini)?),
+                    Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+                        Some(BTreeMap::default())
+                    }
+                    Err(err) => return Err(err.into()),
+                }
             } else {
-                writeln!(printer.stdout(), "Requires: {}", requires.iter().join(", "))?;
-            }
+                None
+            };
 
-            let required_by = requires_map
-                .iter()
-                .filter(|(name, pkgs)| {
-                    **name != distribution.name()
-                        && pkgs.iter().any(|pkg| *pkg == distribution.name())
-                })
-                .map(|(name, _)| name)
-                .sorted_unstable()
-                .dedup()
-                .collect_vec();
-            if required_by.is_empty() {
-                writeln!(printer.stdout(), "Required-by:")?;
+            let files = if files {
+                let path = distribution.install_path().join("RECORD");
+                let record = read_record_file(&mut File::open(path)?)?;
+                Some(record.into_iter().map(|entry| entry.path).collect_vec())
             } else {
-                writeln!(
-                    printer.stdout(),
-                    "Required-by: {}",
-                    required_by.into_iter().join(", "),
-                )?;
-            }
+                None
+            };
+
+            Ok(PackageInfo {
+                name: distribution.name(),
+                version: distribution.version(),
+                location: distribution
+                    .install_path()
+                    .parent()
+                    .expect("package path is not root")
+                    .simplified_display()
+                    .to_string(),
+                editable_project_location: distribution
+                    .as_editable()
+                    .and_then(|url| url.to_file_path().ok())
+                    .map(|path| path.simplified_display().to_string()),
+                requires: requires.cloned(),
+                required_by,
+                entry_points,
+                files,
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    match format {
+        PipShowFormat::Json => {
+            let output = serde_json::to_string(&entries)?;
+            writeln!(printer.stdout_important(), "{output}")?;
         }
+        PipShowFormat::Text => {
+            for (i, entry) in entries.iter().enumerate() {
+                if i > 0 {
+                    // Print a separator between packages.
+                    writeln!(printer.stdout(), "---")?;
+                }
+
+                // Print the name, version, and location (e.g., the `site-packages` directory).
+                writeln!(printer.stdout(), "Name: {}", entry.name)?;
+                writeln!(printer.stdout(), "Version: {}", entry.version)?;
+                writeln!(printer.stdout(), "Location: {}", entry.location)?;
+
+                if let Some(path) = &entry.editable_project_location {
+                    writeln!(printer.stdout(), "Editable project location: {path}")?;
+                }
+
+                // If available, print the requirements.
+                if let Some(requires) = &entry.requires {
+                    if requires.is_empty() {
+                        writeln!(printer.stdout(), "Requires:")?;
+                    } else {
+                        writeln!(printer.stdout(), "Requires: {}", requires.iter().join(", "))?;
+                    }
+                }
+                if let Some(required_by) = &entry.required_by {
+                    if required_by.is_empty() {
+                        writeln!(printer.stdout(), "Required-by:")?;
+                    } else {
+                        writeln!(
+                            printer.stdout(),
+                            "Required-by: {}",
+                            required_by.iter().join(", "),
+                        )?;
+                    }
+                }
+
+                // If requested, show the entry points, grouped as in `entry_points.txt`.
+                if let Some(entry_points) = &entry.entry_points {
+                    writeln!(printer.stdout(), "Entry-points:")?;
+                    for (group, entry_points) in entry_points {
+                        writeln!(printer.stdout(), "  [{group}]")?;
+                        for (name, value) in entry_points {
+                            writeln!(printer.stdout(), "  {name} = {value}")?;
+                        }
+                    }
+                }
 
-        // If requests, show the list of installed files.
-        if files {
-            let path = distribution.install_path().join("RECORD");
-            let record = read_record_file(&mut File::open(path)?)?;
-            writeln!(printer.stdout(), "Files:")?;
-            for entry in record {
-                writeln!(printer.stdout(), "  {}", entry.path)?;
+                // If requested, show the list of installed files.
+                if let Some(files) = &entry.files {
+                    writeln!(printer.stdout(), "Files:")?;
+                    for path in files {
+                        writeln!(printer.stdout(), "  {path}")?;
+                    }
+                }
             }
         }
     }
@@ -240,3 +301,21 @@ pub(crate) fn pip_show(
 
     Ok(ExitStatus::Success)
 }
+
+/// The information about an installed package, as displayed by `pip show`.
+#[derive(Debug, Serialize)]
+struct PackageInfo<'a> {
+    name: &'a PackageName,
+    version: &'a Version,
+    location: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    editable_project_location: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    requires: Option<Vec<&'a PackageName>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    required_by: Option<Vec<&'a PackageName>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    entry_points: Option<BTreeMap<String, BTreeMap<String, String>>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    files: Option<Vec<String>>,
+}
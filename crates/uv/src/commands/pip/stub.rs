@@ -0,0 +1,196 @@
+use std::fmt::Write;
+
+use anyhow::{Result, bail};
+use itertools::Itertools;
+use owo_colors::OwoColorize;
+use tracing::{debug, warn};
+
+use uv_cache::Cache;
+use uv_cli::StubDist;
+use uv_configuration::DryRun;
+use uv_distribution_types::{InstalledMetadata, Name};
+use uv_fs::Simplified;
+use uv_installer::SitePackages;
+use uv_preview::Preview;
+use uv_python::{
+    EnvironmentPreference, Prefix, PythonEnvironment, PythonPreference, PythonRequest, Target,
+};
+
+use crate::commands::pip::operations::report_target_environment;
+use crate::commands::{ExitStatus, elapsed};
+use crate::printer::Printer;
+
+/// Install stub distributions for packages that are provided outside of uv.
+pub(crate) async fn pip_stub(
+    stubs: &[StubDist],
+    python: Option<String>,
+    system: bool,
+    break_system_packages: bool,
+    target: Option<Target>,
+    prefix: Option<Prefix>,
+    cache: &Cache,
+    dry_run: DryRun,
+    printer: Printer,
+    preview: Preview,
+) -> Result<ExitStatus> {
+    let start = std::time::Instant::now();
+
+    // Detect the current Python interpreter.
+    let environment = PythonEnvironment::find(
+        &python
+            .as_deref()
+            .map(PythonRequest::parse)
+            .unwrap_or_default(),
+        EnvironmentPreference::from_system_flag(system, true),
+        PythonPreference::default().with_system_flag(system),
+        cache,
+        preview,
+    )?;
+
+    report_target_environment(&environment, cache, printer)?;
+
+    // Apply any `--target` or `--prefix` directories.
+    let environment = if let Some(target) = target {
+        debug!(
+            "Using `--target` directory at {}",
+            target.root().user_display()
+        );
+        environment.with_target(target)?
+    } else if let Some(prefix) = prefix {
+        debug!(
+            "Using `--prefix` directory at {}",
+            prefix.root().user_display()
+        );
+        environment.with_prefix(prefix)?
+    } else {
+        environment
+    };
+
+    // If the environment is externally managed, abort.
+    if let Some(externally_managed) = environment.interpreter().is_externally_managed() {
+        if break_system_packages {
+            debug!("Ignoring externally managed environment due to `--break-system-packages`");
+        } else {
+            return if let Some(error) = externally_managed.into_error() {
+                Err(anyhow::anyhow!(
+                    "The interpreter at {} is externally managed, and indicates the following:\n\n{}\n\nConsider creating a virtual environment with `uv venv`.",
+                    environment.root().user_display().cyan(),
+                    textwrap::indent(&error, "  ").green(),
+                ))
+            } else {
+                Err(anyhow::anyhow!(
+                    "The interpreter at {} is externally managed. Instead, create a virtual environment with `uv venv`.",
+                    environment.root().user_display().cyan()
+                ))
+            };
+        }
+    }
+
+    let _lock = environment
+        .lock()
+        .await
+        .inspect_err(|err| {
+            warn!("Failed to acquire environment lock: {err}");
+        })
+        .ok();
+
+    // Index the current `site-packages` directory.
+    let site_packages = SitePackages::from_environment(&environment)?;
+
+    // Sort and deduplicate the stubs, skipping any that are already installed.
+    let stubs = {
+        let mut stubs = stubs.iter().collect::<Vec<_>>();
+        stubs.sort_unstable_by(|a, b| a.name.cmp(&b.name).then(a.version.cmp(&b.version)));
+        stubs.dedup();
+        if let Some((stub, _)) = stubs
+            .iter()
+            .tuple_windows()
+            .find(|(first, second)| first.name == second.name)
+        {
+            bail!(
+                "Requested multiple versions of {} to stub",
+                stub.name.as_ref().bold()
+            );
+        }
+
+        let mut pending = Vec::with_capacity(stubs.len());
+        for stub in stubs {
+            let installed = site_packages.get_packages(&stub.name);
+            if let Some(dist) = installed
+                .iter()
+                .find(|dist| dist.version() != &stub.version)
+            {
+                bail!(
+                    "Refusing to stub {} as {} is already installed (run `uv pip uninstall {}` first)",
+                    stub.to_string().bold(),
+                    format!("{}{}", dist.name(), dist.installed_version()).bold(),
+                    stub.name
+                );
+            }
+            if installed.is_empty() {
+                pending.push(stub);
+            } else if !dry_run.enabled() {
+                writeln!(
+                    printer.stderr(),
+                    "{}{} Skipping {} as it is already installed",
+                    "warning".yellow().bold(),
+                    ":".bold(),
+                    stub.to_string().bold()
+                )?;
+            }
+        }
+        pending
+    };
+
+    if stubs.is_empty() {
+        if dry_run.enabled() {
+            writeln!(printer.stderr(), "Would make no changes")?;
+        }
+        return Ok(ExitStatus::Success);
+    }
+
+    // Write a `.dist-info` directory for each stub.
+    if !dry_run.enabled() {
+        let site_packages = environment.interpreter().layout().scheme.purelib;
+        for stub in &stubs {
+            uv_install_wheel::install_stub(&site_packages, &stub.name, &stub.version, Some("uv"))?;
+            debug!(
+                "Installed stub for {stub} into: {}",
+                site_packages.user_display()
+            );
+        }
+    }
+
+    let installs = stubs.len();
+    let s = if installs == 1 { "" } else { "s" };
+    if dry_run.enabled() {
+        writeln!(
+            printer.stderr(),
+            "{}",
+            format!("Would stub {}", format!("{installs} package{s}").bold()).dimmed()
+        )?;
+    } else {
+        writeln!(
+            printer.stderr(),
+            "{}",
+            format!(
+                "Stubbed {} {}",
+                format!("{installs} package{s}").bold(),
+                format!("in {}", elapsed(start.elapsed())).dimmed(),
+            )
+            .dimmed()
+        )?;
+    }
+
+    for stub in stubs {
+        writeln!(
+            printer.stderr(),
+            " {} {}{}",
+            "+".green(),
+            stub.name.as_ref().bold(),
+            format!("=={}", stub.version).dimmed()
+        )?;
+    }
+
+    Ok(ExitStatus::Success)
+}
@@ -5,7 +5,7 @@ use anyhow::{Context, Result};
 use owo_colors::OwoColorize;
 use tracing::{debug, info_span, warn};
 
-use uv_cache::Cache;
+use uv_cache::{Cache, Refresh};
 use uv_client::{BaseClientBuilder, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{
     BuildIsolation, BuildOptions, Concurrency, Constraints, DryRun, ExtrasSpecification,
@@ -15,23 +15,25 @@ use uv_configuration::{KeyringProviderType, TargetTriple};
 use uv_dispatch::{BuildDispatch, SharedState};
 use uv_distribution::LoweredExtraBuildDependencies;
 use uv_distribution_types::{
-    ConfigSettings, DependencyMetadata, ExtraBuildVariables, Index, IndexLocations, Origin,
+    ConfigSettings, DependencyMetadata, ExtraBuildVariables, Index, IndexLocations, Name, Origin,
     PackageConfigSettings, Resolution,
 };
 use uv_fs::Simplified;
 use uv_install_wheel::LinkMode;
 use uv_installer::{InstallationStrategy, SitePackages};
-use uv_normalize::{DefaultExtras, DefaultGroups};
+use uv_normalize::{DefaultExtras, DefaultGroups, PackageName};
 use uv_preview::{Preview, PreviewFeature};
 use uv_pypi_types::Conflicts;
 use uv_python::{
     EnvironmentPreference, Prefix, PythonDownloads, PythonEnvironment, PythonInstallation,
     PythonPreference, PythonRequest, PythonVersion, Target,
 };
-use uv_requirements::{GroupsSpecification, RequirementsSource, RequirementsSpecification};
+use uv_requirements::{
+    GroupsSpecification, RequirementsLayer, RequirementsSource, RequirementsSpecification,
+};
 use uv_resolver::{
-    DependencyMode, ExcludeNewer, FlatIndex, OptionsBuilder, PrereleaseMode, PylockToml,
-    PythonRequirement, ResolutionMode, ResolverEnvironment,
+    AllowYankedEntry, DependencyMode, ExcludeNewer, FlatIndex, OptionsBuilder, PrereleaseMode,
+    PylockToml, PythonRequirement, ResolutionMode, ResolverEnvironment,
 };
 use uv_settings::PythonInstallMirrors;
 use uv_torch::{TorchMode, TorchSource, TorchStrategy};
@@ -43,6 +45,7 @@ use uv_workspace::pyproject::ExtraBuildDependencies;
 use crate::commands::pip::loggers::{DefaultInstallLogger, DefaultResolveLogger};
 use crate::commands::pip::operations::Modifications;
 use crate::commands::pip::operations::{report_interpreter, report_target_environment};
+use crate::commands::pip::verify::verify_changelog;
 use crate::commands::pip::{operations, resolution_markers, resolution_tags};
 use crate::commands::reporters::PythonDownloadReporter;
 use crate::commands::{ExitStatus, diagnostics};
@@ -53,6 +56,7 @@ use crate::printer::Printer;
 pub(crate) async fn pip_sync(
     requirements: &[RequirementsSource],
     constraints: &[RequirementsSource],
+    overlay: &[RequirementsSource],
     build_constraints: &[RequirementsSource],
     extras: &ExtrasSpecification,
     groups: &GroupsSpecification,
@@ -60,6 +64,7 @@ pub(crate) async fn pip_sync(
     link_mode: LinkMode,
     compile: bool,
     hash_checking: Option<HashCheckingMode>,
+    allow_unhashed: Vec<PackageName>,
     index_locations: IndexLocations,
     index_strategy: IndexStrategy,
     torch_backend: Option<TorchMode>,
@@ -86,11 +91,20 @@ pub(crate) async fn pip_sync(
     target: Option<Target>,
     prefix: Option<Prefix>,
     sources: NoSources,
+    excluded_packages: Vec<PackageName>,
+    resolution_priority: Vec<PackageName>,
+    max_backtracks: Option<usize>,
+    memory_limit: Option<usize>,
+    allow_yanked: Vec<AllowYankedEntry>,
+    protected_packages: Vec<PackageName>,
     python_preference: PythonPreference,
     concurrency: Concurrency,
     cache: Cache,
     workspace_cache: WorkspaceCache,
     dry_run: DryRun,
+    only_uv_managed: bool,
+    verify_record: bool,
+    reinstall_if_python_changed: bool,
     printer: Printer,
     preview: Preview,
 ) -> Result<ExitStatus> {
@@ -120,6 +134,7 @@ pub(crate) async fn pip_sync(
         find_links,
         no_binary,
         no_build,
+        build_options_origins,
         extras: _,
     } = operations::read_requirements(
         requirements,
@@ -141,6 +156,13 @@ pub(crate) async fn pip_sync(
         }
     }
 
+    // Read the overlay, if any, as a separate layer, to be resolved against the base requirements.
+    let overlay = if overlay.is_empty() {
+        None
+    } else {
+        Some(RequirementsLayer::from_sources(overlay, &client_builder).await?)
+    };
+
     // Read build constraints.
     let build_constraints =
         operations::read_constraints(build_constraints, &client_builder).await?;
@@ -239,6 +261,28 @@ pub(crate) async fn pip_sync(
         })
         .ok();
 
+    // Determine the set of installed packages.
+    let site_packages = SitePackages::from_environment(&environment)?;
+
+    // If requested, rebuild and reinstall any packages that were built against a different
+    // interpreter (e.g., if the environment's interpreter was upgraded in-place).
+    let (reinstall, cache) = if reinstall_if_python_changed {
+        let mut reinstall = reinstall;
+        let mut changed = Reinstall::None;
+        for (dist, built_with) in site_packages.interpreter_mismatches() {
+            debug!(
+                "Reinstalling `{}`, which was built against {built_with}",
+                dist.name()
+            );
+            reinstall = reinstall.with_package(dist.name().clone());
+            changed = changed.with_package(dist.name().clone());
+        }
+        let refresh = cache.refresh().clone().combine(Refresh::from(changed));
+        (reinstall, cache.with_refresh(refresh))
+    } else {
+        (reinstall, cache)
+    };
+
     let interpreter = environment.interpreter();
 
     // Determine the Python requirement, if the user requested a specific version.
@@ -260,32 +304,71 @@ pub(crate) async fn pip_sync(
         interpreter,
     )?;
 
-    // Collect the set of required hashes.
+    // Collect the set of required hashes, across the base requirements and the overlay.
     let hasher = if let Some(hash_checking) = hash_checking {
         HashStrategy::from_requirements(
             requirements
                 .iter()
+                .chain(
+                    overlay
+                        .iter()
+                        .flat_map(|layer| layer.specification.requirements.iter()),
+                )
                 .map(|entry| (&entry.requirement, entry.hashes.as_slice())),
             constraints
                 .iter()
+                .chain(
+                    overlay
+                        .iter()
+                        .flat_map(|layer| layer.specification.constraints.iter()),
+                )
                 .map(|entry| (&entry.requirement, entry.hashes.as_slice())),
             Some(&marker_env),
             hash_checking,
+            &allow_unhashed,
         )?
     } else {
         HashStrategy::None
     };
 
+    // Report any unhashable requirements that are exempt from hash-checking.
+    operations::report_unhashed(&requirements, &allow_unhashed, hash_checking);
+    if let Some(overlay) = overlay.as_ref() {
+        operations::report_unhashed(
+            &overlay.specification.requirements,
+            &allow_unhashed,
+            hash_checking,
+        );
+    }
+
     // Incorporate any index locations from the provided sources.
     let index_locations = index_locations.combine(
         extra_index_urls
             .into_iter()
+            .chain(
+                overlay
+                    .iter()
+                    .flat_map(|layer| layer.specification.extra_index_urls.iter().cloned()),
+            )
             .map(Index::from_extra_index_url)
-            .chain(index_url.map(Index::from_index_url))
+            .chain(
+                index_url
+                    .or_else(|| {
+                        overlay
+                            .as_ref()
+                            .and_then(|layer| layer.specification.index_url.clone())
+                    })
+                    .map(Index::from_index_url),
+            )
             .map(|index| index.with_origin(Origin::RequirementsTxt))
             .collect(),
         find_links
             .into_iter()
+            .chain(
+                overlay
+                    .iter()
+                    .flat_map(|layer| layer.specification.find_links.iter().cloned()),
+            )
             .map(Index::from_find_links)
             .map(|index| index.with_origin(Origin::RequirementsTxt))
             .collect(),
@@ -324,7 +407,7 @@ pub(crate) async fn pip_sync(
         .build();
 
     // Combine the `--no-binary` and `--no-build` flags from the requirements files.
-    let build_options = build_options.combine(no_binary, no_build);
+    let build_options = build_options.try_combine(no_binary, no_build, build_options_origins)?;
 
     // Resolve the flat indexes from `--find-links`.
     let flat_index = {
@@ -354,6 +437,7 @@ pub(crate) async fn pip_sync(
                 .map(|entry| (&entry.requirement, entry.hashes.as_slice())),
             Some(&marker_env),
             HashCheckingMode::Verify,
+            &[],
         )?
     } else {
         HashStrategy::None
@@ -398,9 +482,6 @@ pub(crate) async fn pip_sync(
         preview,
     );
 
-    // Determine the set of installed packages.
-    let site_packages = SitePackages::from_environment(&environment)?;
-
     let (resolution, hasher) = if let Some(pylock) = pylock {
         // Read the `pylock.toml` from disk, and deserialize it from TOML.
         let install_path = std::path::absolute(&pylock)?;
@@ -448,7 +529,9 @@ pub(crate) async fn pip_sync(
             &tags,
             &build_options,
         )?;
-        let hasher = HashStrategy::from_resolution(&resolution, HashCheckingMode::Verify)?;
+        // Verify the hashes in the lock, along with any hashes provided for the overlay.
+        let hasher =
+            HashStrategy::from_resolution(&resolution, HashCheckingMode::Verify)?.merge(hasher);
 
         (resolution, hasher)
     } else {
@@ -461,8 +544,13 @@ pub(crate) async fn pip_sync(
             .dependency_mode(dependency_mode)
             .exclude_newer(exclude_newer.clone())
             .index_strategy(index_strategy)
-            .torch_backend(torch_backend)
+            .torch_backend(torch_backend.clone())
             .build_options(build_options.clone())
+            .excluded_packages(excluded_packages.clone())
+            .resolution_priority(resolution_priority.clone())
+            .max_backtracks(max_backtracks)
+            .memory_limit(memory_limit)
+            .allow_yanked(allow_yanked.clone())
             .build();
 
         let resolution = match operations::resolve(
@@ -482,7 +570,7 @@ pub(crate) async fn pip_sync(
             &upgrade,
             Some(&tags),
             ResolverEnvironment::specific(marker_env.clone()),
-            python_requirement,
+            python_requirement.clone(),
             interpreter.markers(),
             Conflicts::empty(),
             &client,
@@ -509,6 +597,79 @@ pub(crate) async fn pip_sync(
         (resolution, hasher)
     };
 
+    // Resolve the overlay, pinning every package in the base resolution, such that the overlay can
+    // add packages without changing any of the base packages.
+    let resolution = if let Some(overlay) = overlay {
+        let RequirementsLayer { specification } = overlay.constrained_by(&resolution);
+
+        let options = OptionsBuilder::new()
+            .resolution_mode(resolution_mode)
+            .prerelease_mode(prerelease_mode)
+            .dependency_mode(DependencyMode::Transitive)
+            .exclude_newer(exclude_newer.clone())
+            .index_strategy(index_strategy)
+            .torch_backend(torch_backend)
+            .build_options(build_options.clone())
+            .excluded_packages(excluded_packages.clone())
+            .resolution_priority(resolution_priority)
+            .max_backtracks(max_backtracks)
+            .memory_limit(memory_limit)
+            .allow_yanked(allow_yanked)
+            .build();
+
+        let overlay_resolution = match operations::resolve(
+            specification.requirements,
+            specification.constraints,
+            specification.overrides,
+            specification.excludes,
+            specification.source_trees,
+            specification.project,
+            BTreeSet::default(),
+            &ExtrasSpecification::default(),
+            &specification.groups,
+            Vec::default(),
+            site_packages.clone(),
+            &hasher,
+            &reinstall,
+            &upgrade,
+            Some(&tags),
+            ResolverEnvironment::specific(marker_env.clone()),
+            python_requirement,
+            interpreter.markers(),
+            Conflicts::empty(),
+            &client,
+            &flat_index,
+            state.index(),
+            &build_dispatch,
+            &concurrency,
+            options,
+            Box::new(DefaultResolveLogger),
+            printer,
+        )
+        .await
+        {
+            Ok(resolution) => Resolution::from(resolution),
+            Err(err) => {
+                return diagnostics::OperationDiagnostic::native_tls(
+                    client_builder.is_native_tls(),
+                )
+                .with_context("overlay")
+                .report(err)
+                .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
+            }
+        };
+
+        Resolution::merge([("base", &resolution), ("overlay", &overlay_resolution)])?
+    } else {
+        resolution
+    };
+
+    // When hash-checking is required, refuse to install any yanked versions that weren't
+    // explicitly allowed.
+    if hash_checking.is_some_and(|mode| mode.is_require()) {
+        operations::check_yanked(resolution.diagnostics())?;
+    }
+
     // Constrain any build requirements marked as `match-runtime = true`.
     let extra_build_requires = extra_build_requires.match_runtime(&resolution)?;
 
@@ -539,7 +700,7 @@ pub(crate) async fn pip_sync(
     );
 
     // Sync the environment.
-    match operations::install(
+    let changelog = match operations::install(
         &resolution,
         site_packages,
         InstallationStrategy::Permissive,
@@ -558,19 +719,22 @@ pub(crate) async fn pip_sync(
         &environment,
         Box::new(DefaultInstallLogger),
         installer_metadata,
+        only_uv_managed,
+        &excluded_packages,
+        &protected_packages,
         dry_run,
         printer,
         preview,
     )
     .await
     {
-        Ok(_) => {}
+        Ok(changelog) => changelog,
         Err(err) => {
             return diagnostics::OperationDiagnostic::native_tls(client_builder.is_native_tls())
                 .report(err)
                 .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
         }
-    }
+    };
 
     // Notify the user of any resolution diagnostics.
     operations::diagnose_resolution(resolution.diagnostics(), printer)?;
@@ -580,5 +744,11 @@ pub(crate) async fn pip_sync(
         operations::diagnose_environment(&resolution, &environment, &marker_env, &tags, printer)?;
     }
 
+    // If requested, verify the installed files against their `RECORD`.
+    if verify_record && !dry_run.enabled() && !verify_changelog(&changelog, &environment, printer)?
+    {
+        return Ok(ExitStatus::Failure);
+    }
+
     Ok(ExitStatus::Success)
 }
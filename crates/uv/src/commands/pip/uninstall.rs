@@ -11,6 +11,7 @@ use uv_configuration::{DryRun, KeyringProviderType};
 use uv_distribution_types::Requirement;
 use uv_distribution_types::{InstalledMetadata, Name, UnresolvedRequirement};
 use uv_fs::Simplified;
+use uv_normalize::PackageName;
 use uv_pep508::UnnamedRequirement;
 use uv_preview::Preview;
 use uv_pypi_types::VerbatimParsedUrl;
@@ -19,7 +20,9 @@ use uv_python::{EnvironmentPreference, PythonPreference};
 use uv_python::{Prefix, PythonEnvironment, Target};
 use uv_requirements::{RequirementsSource, RequirementsSpecification};
 
-use crate::commands::pip::operations::report_target_environment;
+use crate::commands::pip::operations::{
+    check_protected, check_uv_managed, report_target_environment,
+};
 use crate::commands::{ExitStatus, elapsed};
 use crate::printer::Printer;
 
@@ -35,6 +38,8 @@ pub(crate) async fn pip_uninstall(
     keyring_provider: KeyringProviderType,
     client_builder: &BaseClientBuilder<'_>,
     dry_run: DryRun,
+    only_uv_managed: bool,
+    protected_packages: &[PackageName],
     printer: Printer,
     preview: Preview,
 ) -> Result<ExitStatus> {
@@ -199,6 +204,14 @@ pub(crate) async fn pip_uninstall(
         return Ok(ExitStatus::Success);
     }
 
+    // If requested, refuse to uninstall any packages installed by other tools.
+    if only_uv_managed {
+        check_uv_managed(distributions.iter().copied())?;
+    }
+
+    // Refuse to uninstall any protected packages.
+    check_protected(distributions.iter().copied(), protected_packages)?;
+
     // Uninstall each package.
     if !dry_run.enabled() {
         for distribution in &distributions {
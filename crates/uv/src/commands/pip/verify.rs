@@ -0,0 +1,352 @@
+use std::fmt::Write;
+use std::io;
+use std::path::PathBuf;
+use std::time::Instant;
+
+use anyhow::Result;
+use owo_colors::OwoColorize;
+use rustc_hash::FxHashSet;
+use serde::Serialize;
+use tracing::debug;
+
+use uv_cache::Cache;
+use uv_cli::PipVerifyFormat;
+use uv_distribution_types::{InstalledDist, InstalledDistKind, Name};
+use uv_fs::Simplified;
+use uv_install_wheel::{RecordMismatch, read_record_file, verify_wheel};
+use uv_installer::SitePackages;
+use uv_normalize::PackageName;
+use uv_pep440::Version;
+use uv_preview::Preview;
+use uv_python::{EnvironmentPreference, PythonEnvironment, PythonPreference, PythonRequest};
+
+use crate::commands::pip::operations::{Changelog, report_target_environment};
+use crate::commands::{ExitStatus, elapsed};
+use crate::printer::Printer;
+
+/// Verify that the files of installed packages match the hashes in their `RECORD` files.
+pub(crate) fn pip_verify(
+    packages: &[PackageName],
+    format: PipVerifyFormat,
+    python: Option<&str>,
+    system: bool,
+    cache: &Cache,
+    printer: Printer,
+    preview: Preview,
+) -> Result<ExitStatus> {
+    let start = Instant::now();
+
+    // Detect the current Python interpreter.
+    let environment = PythonEnvironment::find(
+        &python.map(PythonRequest::parse).unwrap_or_default(),
+        EnvironmentPreference::from_system_flag(system, false),
+        PythonPreference::default().with_system_flag(system),
+        cache,
+        preview,
+    )?;
+
+    report_target_environment(&environment, cache, printer)?;
+
+    // Build the installed index.
+    let site_packages = SitePackages::from_environment(&environment)?;
+
+    // Determine the packages to verify, defaulting to all installed packages.
+    let mut missing = false;
+    let dists: Vec<&InstalledDist> = if packages.is_empty() {
+        site_packages.iter().collect()
+    } else {
+        let mut dists = Vec::new();
+        for package in packages {
+            let installed = site_packages.get_packages(package);
+            if installed.is_empty() {
+                writeln!(
+                    printer.stderr(),
+                    "{}{} Package `{}` is not installed",
+                    "warning".yellow().bold(),
+                    ":".bold(),
+                    package
+                )?;
+                missing = true;
+            }
+            dists.extend(installed);
+        }
+        dists
+    };
+
+    let valid = verify_distributions(&dists, &site_packages, format, start, printer)?;
+
+    if valid && !missing {
+        Ok(ExitStatus::Success)
+    } else {
+        Ok(ExitStatus::Failure)
+    }
+}
+
+/// Verify the distributions installed or reinstalled by an operation against their `RECORD` files.
+///
+/// Returns `true` if all files match.
+pub(crate) fn verify_changelog(
+    changelog: &Changelog,
+    environment: &PythonEnvironment,
+    printer: Printer,
+) -> Result<bool> {
+    let start = Instant::now();
+
+    let site_packages = SitePackages::from_environment(environment)?;
+    let dists: Vec<&InstalledDist> = changelog
+        .installed
+        .iter()
+        .chain(&changelog.reinstalled)
+        .flat_map(|dist| site_packages.get_packages(dist.name()))
+        .collect();
+
+    verify_distributions(
+        &dists,
+        &site_packages,
+        PipVerifyFormat::Text,
+        start,
+        printer,
+    )
+}
+
+/// Verify the given installed distributions against their `RECORD` files, reporting any files
+/// that are missing, modified, or extra.
+///
+/// Returns `true` if all files match.
+pub(crate) fn verify_distributions(
+    dists: &[&InstalledDist],
+    site_packages: &SitePackages,
+    format: PipVerifyFormat,
+    start: Instant,
+    printer: Printer,
+) -> Result<bool> {
+    let mut file_count = 0;
+    let mut reports = Vec::new();
+    for dist in dists {
+        // Only wheel installations include a `RECORD` file.
+        if !matches!(
+            dist.kind,
+            InstalledDistKind::Registry(_) | InstalledDistKind::Url(_)
+        ) {
+            debug!(
+                "Skipping verification of non-wheel installation: {}",
+                dist.install_path().user_display()
+            );
+            continue;
+        }
+
+        match verify_wheel(dist.install_path()) {
+            Ok(verification) => {
+                file_count += verification.file_count;
+                reports.push(Report {
+                    dist,
+                    mismatches: verification.mismatches,
+                    missing_record: false,
+                });
+            }
+            Err(uv_install_wheel::Error::MissingRecord(_)) => {
+                reports.push(Report {
+                    dist,
+                    mismatches: Vec::new(),
+                    missing_record: true,
+                });
+            }
+            Err(err) => return Err(err.into()),
+        }
+    }
+
+    // Directories can be shared across distributions (e.g., in namespace packages), so ignore any
+    // extra files that are listed in the `RECORD` of another installed distribution.
+    if reports.iter().any(|report| {
+        report
+            .mismatches
+            .iter()
+            .any(|mismatch| matches!(mismatch, RecordMismatch::Extra(_)))
+    }) {
+        let claimed = recorded_paths(site_packages)?;
+        for report in &mut reports {
+            report.mismatches.retain(|mismatch| match mismatch {
+                RecordMismatch::Extra(path) => !claimed.contains(path),
+                _ => true,
+            });
+        }
+    }
+
+    let packages = if dists.len() == 1 { "" } else { "s" };
+    let files = if file_count == 1 { "" } else { "s" };
+    writeln!(
+        printer.stderr(),
+        "{}",
+        format!(
+            "Verified {} {}",
+            format!(
+                "{} file{files} in {} package{packages}",
+                file_count,
+                dists.len()
+            )
+            .bold(),
+            format!("in {}", elapsed(start.elapsed())).dimmed()
+        )
+        .dimmed()
+    )?;
+
+    let valid = reports.iter().all(Report::is_valid);
+
+    match format {
+        PipVerifyFormat::Json => {
+            let output = serde_json::to_string(&Output {
+                valid,
+                packages: reports.iter().map(Entry::from).collect(),
+            })?;
+            writeln!(printer.stdout_important(), "{output}")?;
+        }
+        PipVerifyFormat::Text => {
+            let problems = reports
+                .iter()
+                .flat_map(|report| {
+                    let record = report
+                        .missing_record
+                        .then(|| "the `RECORD` file is missing".to_string());
+                    let mismatches = report.mismatches.iter().map(|mismatch| match mismatch {
+                        RecordMismatch::Missing(path) => {
+                            format!("`{}` is missing", path.simplified_display())
+                        }
+                        RecordMismatch::Modified {
+                            path,
+                            expected,
+                            actual,
+                        } => format!(
+                            "`{}` has hash `{actual}`, but the `RECORD` lists `{expected}`",
+                            path.simplified_display()
+                        ),
+                        RecordMismatch::Extra(path) => {
+                            format!("`{}` is not listed", path.simplified_display())
+                        }
+                    });
+                    record
+                        .into_iter()
+                        .chain(mismatches)
+                        .map(|message| (report.dist.name(), message))
+                })
+                .collect::<Vec<_>>();
+
+            if problems.is_empty() {
+                writeln!(
+                    printer.stderr(),
+                    "{}",
+                    "All installed files match their `RECORD`"
+                        .to_string()
+                        .dimmed()
+                )?;
+            } else {
+                let s = if problems.len() == 1 { "" } else { "s" };
+                writeln!(
+                    printer.stderr(),
+                    "{}",
+                    format!("Found {}", format!("{} problem{s}", problems.len()).bold()).dimmed()
+                )?;
+                for (name, message) in problems {
+                    writeln!(
+                        printer.stderr(),
+                        "{}",
+                        format!("The package `{name}` does not match its `RECORD`: {message}")
+                            .bold()
+                    )?;
+                }
+            }
+        }
+    }
+
+    Ok(valid)
+}
+
+/// Collect the paths listed in the `RECORD` files of all installed distributions.
+fn recorded_paths(site_packages: &SitePackages) -> Result<FxHashSet<PathBuf>> {
+    let mut paths = FxHashSet::default();
+    for dist in site_packages.iter() {
+        let path = dist.install_path().join("RECORD");
+        let mut file = match fs_err::File::open(&path) {
+            Ok(file) => file,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => continue,
+            Err(err) => return Err(err.into()),
+        };
+        for entry in read_record_file(&mut file)? {
+            paths.insert(PathBuf::from(entry.path));
+        }
+    }
+    Ok(paths)
+}
+
+/// The result of verifying an installed distribution.
+#[derive(Debug)]
+struct Report<'a> {
+    dist: &'a InstalledDist,
+    mismatches: Vec<RecordMismatch>,
+    missing_record: bool,
+}
+
+impl Report<'_> {
+    /// Returns `true` if all files match the `RECORD`.
+    fn is_valid(&self) -> bool {
+        !self.missing_record && self.mismatches.is_empty()
+    }
+}
+
+/// An entry in a JSON list of verified packages.
+#[derive(Debug, Serialize)]
+struct Entry<'a> {
+    name: &'a PackageName,
+    version: &'a Version,
+    missing_record: bool,
+    missing: Vec<String>,
+    modified: Vec<ModifiedEntry<'a>>,
+    extra: Vec<String>,
+}
+
+/// A file whose contents don't match the hash listed in the `RECORD`.
+#[derive(Debug, Serialize)]
+struct ModifiedEntry<'a> {
+    path: String,
+    expected: &'a str,
+    actual: &'a str,
+}
+
+impl<'a> From<&'a Report<'a>> for Entry<'a> {
+    fn from(report: &'a Report<'a>) -> Self {
+        let mut entry = Entry {
+            name: report.dist.name(),
+            version: report.dist.version(),
+            missing_record: report.missing_record,
+            missing: Vec::new(),
+            modified: Vec::new(),
+            extra: Vec::new(),
+        };
+        for mismatch in &report.mismatches {
+            match mismatch {
+                RecordMismatch::Missing(path) => {
+                    entry.missing.push(path.portable_display().to_string());
+                }
+                RecordMismatch::Modified {
+                    path,
+                    expected,
+                    actual,
+                } => entry.modified.push(ModifiedEntry {
+                    path: path.portable_display().to_string(),
+                    expected,
+                    actual,
+                }),
+                RecordMismatch::Extra(path) => {
+                    entry.extra.push(path.portable_display().to_string());
+                }
+            }
+        }
+        entry
+    }
+}
+
+/// The JSON output for `pip verify`.
+#[derive(Debug, Serialize)]
+struct Output<'a> {
+    valid: bool,
+    packages: Vec<Entry<'a>>,
+}
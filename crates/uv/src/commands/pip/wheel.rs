@@ -0,0 +1,49 @@
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use tracing::debug;
+
+use uv_dispatch::BuildDispatch;
+use uv_distribution::DistributionDatabase;
+use uv_distribution_types::{Dist, SourceDist};
+use uv_fs::Simplified;
+use uv_platform_tags::Tags;
+use uv_types::HashStrategy;
+use uv_warnings::warn_user;
+
+use crate::commands::pip::download::download_dist;
+
+/// Save a wheel for a distribution to the wheel directory, returning the filename of the wheel.
+///
+/// Wheels are downloaded (or copied) as-is, while source distributions are built into wheels.
+/// Returns `None` if the distribution is a virtual project, which can't be built.
+pub(crate) async fn collect_wheel(
+    dist: &Dist,
+    wheel_dir: &Path,
+    tags: &Tags,
+    database: &DistributionDatabase<'_, BuildDispatch<'_>>,
+    hasher: &HashStrategy,
+) -> Result<Option<String>> {
+    let sdist = match dist {
+        Dist::Built(_) => return download_dist(dist, wheel_dir, database, hasher).await,
+        Dist::Source(SourceDist::Directory(directory)) if directory.r#virtual.unwrap_or(false) => {
+            warn_user!("Skipping `{dist}`, which is a virtual project and can't be built");
+            return Ok(None);
+        }
+        Dist::Source(sdist) => sdist,
+    };
+
+    let (filename, archive) = database
+        .build_wheel_archive(sdist, tags, hasher.get(sdist))
+        .await
+        .with_context(|| format!("Failed to build `{dist}`"))?;
+    let filename = filename.to_string();
+    let target = wheel_dir.join(&filename);
+    debug!(
+        "Copying `{}` to `{}`",
+        archive.user_display(),
+        target.user_display()
+    );
+    fs_err::tokio::copy(&archive, &target).await?;
+    Ok(Some(filename))
+}
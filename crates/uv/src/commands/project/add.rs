@@ -124,7 +124,8 @@ pub(crate) async fn add(
             | RequirementsSource::Editable(_)
             | RequirementsSource::RequirementsTxt(_)
             | RequirementsSource::Extensionless(_)
-            | RequirementsSource::EnvironmentYml(_) => {}
+            | RequirementsSource::EnvironmentYml(_)
+            | RequirementsSource::Lowered(..) => {}
         }
     }
 
@@ -17,7 +17,7 @@ use uv_normalize::{DefaultExtras, DefaultGroups, PackageName};
 use uv_preview::Preview;
 use uv_python::{PythonDownloads, PythonPreference, PythonRequest};
 use uv_requirements::is_pylock_toml;
-use uv_resolver::{PylockToml, RequirementsTxtExport, cyclonedx_json};
+use uv_resolver::{PylockToml, RequirementsTxtExport, cyclonedx_json, spdx_json};
 use uv_scripts::Pep723Script;
 use uv_settings::PythonInstallMirrors;
 use uv_workspace::{DiscoveryOptions, MemberDiscovery, VirtualProject, WorkspaceCache};
@@ -332,8 +332,8 @@ pub(crate) async fn export(
         }
     });
 
-    // Skip conflict detection for CycloneDX exports, as SBOMs are meant to document all dependencies including conflicts.
-    if !matches!(format, ExportFormat::CycloneDX1_5) {
+    // Skip conflict detection for SBOM exports, as SBOMs are meant to document all dependencies including conflicts.
+    if !matches!(format, ExportFormat::CycloneDX1_5 | ExportFormat::Spdx2_3) {
         detect_conflicts(&target, &extras, &groups)?;
     }
 
@@ -411,6 +411,20 @@ pub(crate) async fn export(
 
             export.output_as_json_v1_5(&mut writer)?;
         }
+        ExportFormat::Spdx2_3 => {
+            let export = spdx_json::from_lock(
+                &target,
+                &prune,
+                &extras,
+                &groups,
+                include_annotations,
+                &install_options,
+                preview,
+                all_packages,
+            )?;
+
+            writeln!(writer, "{}", export.to_json()?)?;
+        }
     }
 
     writer.commit().await?;
@@ -419,7 +433,7 @@ pub(crate) async fn export(
 }
 
 /// Format the uv command used to generate the output file.
-fn cmd() -> String {
+pub(super) fn cmd() -> String {
     let args = env::args_os()
         .skip(1)
         .map(|arg| arg.to_string_lossy().to_string())
@@ -0,0 +1,565 @@
+use std::fmt::Write;
+use std::path::{Component, Path};
+
+use anyhow::{Context, Result, anyhow};
+use futures::{StreamExt, TryStreamExt};
+use itertools::Itertools;
+use owo_colors::OwoColorize;
+use serde::Serialize;
+use tracing::debug;
+
+use uv_cache::Cache;
+use uv_cli::LicensesFormat;
+use uv_client::{BaseClientBuilder, FlatIndexClient, RegistryClientBuilder};
+use uv_configuration::{
+    Concurrency, DependencyGroups, ExtrasSpecification, HashCheckingMode, InstallOptions,
+};
+use uv_dispatch::BuildDispatch;
+use uv_distribution::{DistributionDatabase, LoweredExtraBuildDependencies};
+use uv_distribution_types::{Index, Name, ResolvedDist};
+use uv_fs::Simplified;
+use uv_normalize::{DefaultExtras, PackageName};
+use uv_pep440::Version;
+use uv_preview::{Preview, PreviewFeature};
+use uv_pypi_types::Metadata23;
+use uv_python::{PythonDownloads, PythonEnvironment, PythonPreference, PythonRequest};
+use uv_resolver::{FlatIndex, Installable};
+use uv_settings::PythonInstallMirrors;
+use uv_types::{BuildIsolation, HashStrategy};
+use uv_warnings::warn_user;
+use uv_workspace::{DiscoveryOptions, VirtualProject, WorkspaceCache};
+
+use crate::commands::pip::list::{Column, MultiZip};
+use crate::commands::pip::loggers::DefaultResolveLogger;
+use crate::commands::pip::{resolution_markers, resolution_tags};
+use crate::commands::project::install_target::InstallTarget;
+use crate::commands::project::lock::{LockMode, LockOperation};
+use crate::commands::project::sync::{apply_no_virtual_project, store_credentials_from_target};
+use crate::commands::project::{
+    PlatformState, ProjectError, ProjectInterpreter, UniversalState, default_dependency_groups,
+    detect_conflicts,
+};
+use crate::commands::{ExitStatus, diagnostics};
+use crate::printer::Printer;
+use crate::settings::{FrozenSource, LockCheck, ResolverSettings};
+
+/// The longest free-form `License` value that is reported as-is, rather than as the full text of
+/// the license.
+const MAX_LICENSE_LENGTH: usize = 64;
+
+/// The prefixes of files in a `.dist-info` directory that are treated as license files, for
+/// distributions that don't declare their license files.
+const LICENSE_FILE_PREFIXES: &[&str] = &["LICENSE", "LICENCE", "COPYING", "NOTICE", "AUTHORS"];
+
+/// Report the licenses of the project's locked dependencies.
+pub(crate) async fn licenses(
+    project_dir: &Path,
+    exclude: Vec<PackageName>,
+    format: LicensesFormat,
+    notice: Option<&Path>,
+    extras: ExtrasSpecification,
+    groups: DependencyGroups,
+    lock_check: LockCheck,
+    frozen: Option<FrozenSource>,
+    python: Option<String>,
+    install_mirrors: PythonInstallMirrors,
+    settings: ResolverSettings,
+    client_builder: BaseClientBuilder<'_>,
+    python_preference: PythonPreference,
+    python_downloads: PythonDownloads,
+    concurrency: Concurrency,
+    no_config: bool,
+    cache: &Cache,
+    printer: Printer,
+    preview: Preview,
+) -> Result<ExitStatus> {
+    if !preview.is_enabled(PreviewFeature::Licenses) {
+        warn_user!(
+            "`uv licenses` is experimental and may change without warning. Pass `--preview-features {}` to disable this warning.",
+            PreviewFeature::Licenses
+        );
+    }
+
+    // Identify the project.
+    let workspace_cache = WorkspaceCache::default();
+    let project =
+        VirtualProject::discover(project_dir, &DiscoveryOptions::default(), &workspace_cache)
+            .await?;
+
+    // Determine the groups and extras to include.
+    let groups = groups.with_defaults(default_dependency_groups(project.pyproject_toml())?);
+    let extras = extras.with_defaults(DefaultExtras::default());
+
+    // Find an interpreter for the project. Distributions are selected for the interpreter's
+    // platform, and any source distributions are built with it.
+    let interpreter = ProjectInterpreter::discover(
+        project.workspace(),
+        project_dir,
+        &groups,
+        python.as_deref().map(PythonRequest::parse),
+        &client_builder,
+        python_preference,
+        python_downloads,
+        &install_mirrors,
+        false,
+        no_config,
+        Some(false),
+        cache,
+        printer,
+        preview,
+    )
+    .await?
+    .into_interpreter();
+
+    // Determine the lock mode.
+    let mode = if let Some(frozen_source) = frozen {
+        LockMode::Frozen(frozen_source.into())
+    } else if let LockCheck::Enabled(lock_check) = lock_check {
+        LockMode::Locked(&interpreter, lock_check)
+    } else {
+        LockMode::Write(&interpreter)
+    };
+
+    // Lock the project.
+    let lock = match Box::pin(
+        LockOperation::new(
+            mode,
+            &settings,
+            &client_builder,
+            &UniversalState::default(),
+            Box::new(DefaultResolveLogger),
+            &concurrency,
+            cache,
+            &workspace_cache,
+            printer,
+            preview,
+        )
+        .execute(project.workspace().into()),
+    )
+    .await
+    {
+        Ok(result) => result.into_lock(),
+        Err(ProjectError::Operation(err)) => {
+            return diagnostics::OperationDiagnostic::native_tls(client_builder.is_native_tls())
+                .report(err)
+                .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
+        }
+        Err(err) => return Err(err.into()),
+    };
+
+    // Identify the installation target.
+    let target = match &project {
+        VirtualProject::Project(project) => InstallTarget::Project {
+            workspace: project.workspace(),
+            name: project.project_name(),
+            lock: &lock,
+        },
+        VirtualProject::NonProject(workspace) => InstallTarget::NonProjectWorkspace {
+            workspace,
+            lock: &lock,
+        },
+    };
+
+    // Validate that the set of requested extras and development groups are compatible, and
+    // defined in the lockfile.
+    detect_conflicts(&target, &extras, &groups)?;
+    target.validate_extras(&extras)?;
+    target.validate_groups(&groups)?;
+
+    // Select the distributions for the interpreter from the lockfile. Workspace members are the
+    // project itself, rather than third-party dependencies, and so are omitted.
+    let marker_env = resolution_markers(None, None, &interpreter);
+    let tags = resolution_tags(None, None, &interpreter)?;
+    let install_options =
+        InstallOptions::new(false, false, true, false, false, false, exclude, Vec::new());
+    let resolution = target.to_resolution(
+        &marker_env,
+        &tags,
+        &extras,
+        &groups,
+        &settings.build_options,
+        &install_options,
+    )?;
+    let resolution = apply_no_virtual_project(resolution);
+
+    let client_builder = client_builder.clone().keyring(settings.keyring_provider);
+
+    // Populate credentials from the target.
+    store_credentials_from_target(target, &client_builder);
+
+    // Initialize the registry client.
+    let client = RegistryClientBuilder::new(client_builder, cache.clone())
+        .index_locations(settings.index_locations.clone())
+        .index_strategy(settings.index_strategy)
+        .markers(interpreter.markers())
+        .platform(interpreter.platform())
+        .build();
+
+    // Lower the extra build dependencies, constraining any that match the runtime.
+    let extra_build_requires = LoweredExtraBuildDependencies::from_workspace(
+        settings.extra_build_dependencies.clone(),
+        project.workspace(),
+        &settings.index_locations,
+        &settings.sources,
+        client.credentials_cache(),
+    )?
+    .into_inner()
+    .match_runtime(&resolution)?;
+
+    // Determine whether to enable build isolation.
+    let environment = PythonEnvironment::from_interpreter(interpreter.clone());
+    let build_isolation = match &settings.build_isolation {
+        uv_configuration::BuildIsolation::Isolate => BuildIsolation::Isolated,
+        uv_configuration::BuildIsolation::Shared => BuildIsolation::Shared(&environment),
+        uv_configuration::BuildIsolation::SharedPackage(packages) => {
+            BuildIsolation::SharedPackage(&environment, packages)
+        }
+    };
+
+    // Read the build constraints from the lockfile.
+    let build_constraints = target.build_constraints();
+
+    // Build dependencies aren't locked, so they're never hash-checked.
+    let build_hasher = HashStrategy::default();
+
+    // Verify every distribution against the hashes in the lockfile.
+    let hasher = HashStrategy::from_resolution(&resolution, HashCheckingMode::Verify)?;
+
+    // Resolve the flat indexes from `--find-links`.
+    let flat_index = {
+        let client = FlatIndexClient::new(client.cached_client(), client.connectivity(), cache);
+        let entries = client
+            .fetch_all(settings.index_locations.flat_indexes().map(Index::url))
+            .await?;
+        FlatIndex::from_entries(entries, Some(&tags), &hasher, &settings.build_options)
+    };
+
+    // Create a build dispatch.
+    let build_dispatch = BuildDispatch::new(
+        &client,
+        cache,
+        &build_constraints,
+        &interpreter,
+        &settings.index_locations,
+        &flat_index,
+        &settings.dependency_metadata,
+        PlatformState::default().into_inner(),
+        settings.index_strategy,
+        &settings.config_setting,
+        &settings.config_settings_package,
+        build_isolation,
+        &extra_build_requires,
+        &settings.extra_build_variables,
+        settings.link_mode,
+        &settings.build_options,
+        &build_hasher,
+        settings.exclude_newer.clone(),
+        settings.sources.clone(),
+        workspace_cache.clone(),
+        concurrency.clone(),
+        preview,
+    );
+    let database = DistributionDatabase::new(
+        &client,
+        &build_dispatch,
+        concurrency.downloads_semaphore.clone(),
+    );
+
+    // Fetch (or build) the wheel for each distribution into the cache, and read the license
+    // information from its `.dist-info` directory.
+    let mut packages =
+        futures::stream::iter(resolution.distributions().filter_map(|dist| match dist {
+            ResolvedDist::Installable { dist, .. } => Some(dist),
+            ResolvedDist::Installed { .. } => None,
+        }))
+        .map(|dist| {
+            let database = &database;
+            let tags = &tags;
+            let hasher = &hasher;
+            async move {
+                let wheel = database
+                    .get_or_build_wheel(dist, tags, hasher.get(dist.as_ref()))
+                    .await
+                    .with_context(|| format!("Failed to fetch `{dist}`"))?;
+                let dist_info = uv_metadata::find_flat_dist_info(wheel.filename(), wheel.target())?;
+                PackageLicense::read(
+                    dist.name(),
+                    &wheel.filename().version,
+                    &wheel.target().join(format!("{dist_info}.dist-info")),
+                )
+                .with_context(|| format!("Failed to read the license of `{dist}`"))
+            }
+        })
+        .buffer_unordered(concurrency.downloads)
+        .try_collect::<Vec<_>>()
+        .await?;
+
+    // Always sort by name.
+    packages.sort_unstable_by(|a, b| a.name.cmp(&b.name).then(a.version.cmp(&b.version)));
+
+    match format {
+        LicensesFormat::Json => {
+            let rows = packages
+                .iter()
+                .map(|package| Entry {
+                    name: &package.name,
+                    version: &package.version,
+                    license: package.license.as_deref(),
+                    license_files: package
+                        .license_files
+                        .iter()
+                        .map(|file| file.path.as_str())
+                        .collect(),
+                })
+                .collect_vec();
+            let output = serde_json::to_string(&rows)?;
+            writeln!(printer.stdout_important(), "{output}")?;
+        }
+        LicensesFormat::Text if packages.is_empty() => {}
+        LicensesFormat::Text => {
+            let columns = [
+                Column {
+                    header: String::from("Package"),
+                    rows: packages
+                        .iter()
+                        .map(|package| package.name.to_string())
+                        .collect_vec(),
+                },
+                Column {
+                    header: String::from("Version"),
+                    rows: packages
+                        .iter()
+                        .map(|package| package.version.to_string())
+                        .collect_vec(),
+                },
+                Column {
+                    header: String::from("License"),
+                    rows: packages
+                        .iter()
+                        .map(|package| package.license.as_deref().unwrap_or("UNKNOWN").to_string())
+                        .collect_vec(),
+                },
+            ];
+            for elems in MultiZip(columns.iter().map(Column::fmt).collect_vec()) {
+                writeln!(printer.stdout_important(), "{}", elems.join(" ").trim_end())?;
+            }
+        }
+    }
+
+    // Write the NOTICE file, if requested.
+    if let Some(notice) = notice {
+        fs_err::write(notice, render_notice(&packages)?)?;
+        writeln!(
+            printer.stderr(),
+            "Wrote notices for {} to: {}",
+            format!(
+                "{} package{}",
+                packages.len(),
+                if packages.len() == 1 { "" } else { "s" }
+            )
+            .bold(),
+            notice.user_display().cyan()
+        )?;
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// The license information for a locked package.
+#[derive(Debug)]
+struct PackageLicense {
+    name: PackageName,
+    version: Version,
+    /// The license of the package, if it could be determined from the metadata.
+    license: Option<String>,
+    /// The license files included in the distribution.
+    license_files: Vec<LicenseFile>,
+}
+
+impl PackageLicense {
+    /// Read the license information from the `.dist-info` directory of an unzipped wheel.
+    fn read(name: &PackageName, version: &Version, dist_info: &Path) -> Result<Self> {
+        let metadata = Metadata23::parse(&fs_err::read(dist_info.join("METADATA"))?)?;
+        let license_files = read_license_files(dist_info, &metadata)?;
+        Ok(Self {
+            name: name.clone(),
+            version: version.clone(),
+            license: license(&metadata),
+            license_files,
+        })
+    }
+}
+
+/// A license file included in a distribution.
+#[derive(Debug)]
+struct LicenseFile {
+    /// The path to the file, relative to the `.dist-info` directory.
+    path: String,
+    /// The contents of the file.
+    contents: String,
+}
+
+/// An entry in a JSON list of package licenses.
+#[derive(Debug, Serialize)]
+struct Entry<'a> {
+    name: &'a PackageName,
+    version: &'a Version,
+    license: Option<&'a str>,
+    license_files: Vec<&'a str>,
+}
+
+/// Determine the license of a package from its metadata.
+///
+/// Prefers the SPDX license expression (PEP 639), then any license classifiers, then the
+/// free-form `License` field, unless it contains the full text of the license.
+fn license(metadata: &Metadata23) -> Option<String> {
+    if let Some(expression) = metadata
+        .license_expression
+        .as_deref()
+        .map(str::trim)
+        .filter(|expression| !expression.is_empty())
+    {
+        return Some(expression.to_string());
+    }
+
+    // Ex) `License :: OSI Approved :: MIT License`
+    let classifiers = metadata
+        .classifiers
+        .iter()
+        .filter_map(|classifier| classifier.strip_prefix("License :: "))
+        .filter_map(|classifier| classifier.rsplit(" :: ").next())
+        .filter(|license| *license != "OSI Approved")
+        .collect_vec();
+    if !classifiers.is_empty() {
+        return Some(classifiers.join("; "));
+    }
+
+    metadata
+        .license
+        .as_deref()
+        .map(str::trim)
+        .filter(|license| {
+            !license.is_empty()
+                && !license.contains('\n')
+                && license.len() <= MAX_LICENSE_LENGTH
+                && !license.eq_ignore_ascii_case("UNKNOWN")
+        })
+        .map(ToString::to_string)
+}
+
+/// Read the license files included in a distribution's `.dist-info` directory.
+///
+/// The `License-File` entries in the metadata are relative to the `licenses` subdirectory
+/// (PEP 639) or, for older distributions, to the `.dist-info` directory itself. If the metadata
+/// doesn't declare any license files, all files in the `licenses` subdirectory and any
+/// license-like files in the `.dist-info` directory are used instead.
+fn read_license_files(dist_info: &Path, metadata: &Metadata23) -> Result<Vec<LicenseFile>> {
+    let root = dist_info;
+    if !root.is_dir() {
+        return Ok(Vec::new());
+    }
+
+    let mut paths = Vec::new();
+    if metadata.license_files.is_empty() {
+        for entry in walkdir::WalkDir::new(root.join("licenses"))
+            .sort_by_file_name()
+            .into_iter()
+            .filter_map(Result::ok)
+        {
+            if entry.file_type().is_file() {
+                paths.push(entry.into_path());
+            }
+        }
+        for entry in fs_err::read_dir(root)?
+            .filter_map(Result::ok)
+            .sorted_by_key(fs_err::DirEntry::file_name)
+        {
+            let name = entry.file_name().to_string_lossy().to_uppercase();
+            if LICENSE_FILE_PREFIXES
+                .iter()
+                .any(|prefix| name.starts_with(prefix))
+                && entry.path().is_file()
+            {
+                paths.push(entry.path());
+            }
+        }
+    } else {
+        for file in &metadata.license_files {
+            // Reject any paths that could escape the `.dist-info` directory (e.g., `../RECORD`
+            // or `/etc/passwd`).
+            if !Path::new(file)
+                .components()
+                .all(|component| matches!(component, Component::Normal(_)))
+            {
+                return Err(anyhow!(
+                    "Invalid `License-File` entry `{file}` for `{}`: license files must be relative paths within the distribution",
+                    metadata.name
+                ));
+            }
+            let path = [root.join("licenses").join(file), root.join(file)]
+                .into_iter()
+                .find(|path| path.is_file());
+            if let Some(path) = path {
+                paths.push(path);
+            } else {
+                debug!("License file `{file}` not found for: {}", metadata.name);
+            }
+        }
+    }
+
+    paths
+        .into_iter()
+        .unique()
+        .map(|path| {
+            let contents = String::from_utf8_lossy(&fs_err::read(&path)?).into_owned();
+            let path = path
+                .strip_prefix(root)
+                .unwrap_or(&path)
+                .components()
+                .filter_map(|component| match component {
+                    Component::Normal(name) => Some(name.to_string_lossy()),
+                    _ => None,
+                })
+                .join("/");
+            Ok(LicenseFile { path, contents })
+        })
+        .collect()
+}
+
+/// Render a NOTICE file that attributes each package, followed by the text of its license files.
+fn render_notice(packages: &[PackageLicense]) -> Result<String, std::fmt::Error> {
+    let separator = "=".repeat(80);
+    let mut notice = String::new();
+    writeln!(notice, "THIRD-PARTY SOFTWARE NOTICES")?;
+    writeln!(notice)?;
+    writeln!(
+        notice,
+        "This file lists the third-party packages required by this project, along with their"
+    )?;
+    writeln!(notice, "licenses.")?;
+    for package in packages {
+        writeln!(notice)?;
+        writeln!(notice, "{separator}")?;
+        writeln!(notice, "{} {}", package.name, package.version)?;
+        writeln!(
+            notice,
+            "License: {}",
+            package.license.as_deref().unwrap_or("UNKNOWN")
+        )?;
+        writeln!(notice, "{separator}")?;
+        if package.license_files.is_empty() {
+            writeln!(notice)?;
+            writeln!(
+                notice,
+                "No license files were included in the distribution."
+            )?;
+        }
+        for file in &package.license_files {
+            writeln!(notice)?;
+            writeln!(notice, "--- {} ---", file.path)?;
+            writeln!(notice)?;
+            writeln!(notice, "{}", file.contents.trim_end())?;
+        }
+    }
+    Ok(notice)
+}
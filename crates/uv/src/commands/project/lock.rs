@@ -5,25 +5,27 @@ use std::fmt::Write;
 use std::path::Path;
 use std::sync::Arc;
 
+use itertools::Itertools;
 use owo_colors::OwoColorize;
 use rustc_hash::{FxBuildHasher, FxHashMap};
 use tracing::debug;
 
 use uv_cache::{Cache, Refresh};
+use uv_cli::LockDiffFormat;
 use uv_client::{BaseClientBuilder, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{
-    Concurrency, Constraints, DependencyGroupsWithDefaults, DryRun, ExtrasSpecification, Reinstall,
-    Upgrade,
+    BuildOptions, Concurrency, Constraints, DependencyGroups, DependencyGroupsWithDefaults, DryRun,
+    ExtrasSpecification, InstallOptions, Reinstall, Upgrade,
 };
 use uv_dispatch::BuildDispatch;
 use uv_distribution::{DistributionDatabase, LoweredExtraBuildDependencies};
 use uv_distribution_types::{
     DependencyMetadata, HashGeneration, Index, IndexLocations, NameRequirementSpecification,
-    Requirement, RequiresPython, UnresolvedRequirementSpecification,
+    Requirement, RequiresPython, ResolutionDiff, UnresolvedRequirementSpecification,
 };
 use uv_git::ResolvedRepositoryReference;
 use uv_git_types::GitOid;
-use uv_normalize::{GroupName, PackageName};
+use uv_normalize::{DefaultExtras, DefaultGroups, GroupName, PackageName};
 use uv_pep440::Version;
 use uv_preview::{Preview, PreviewFeature};
 use uv_pypi_types::{ConflictKind, Conflicts, SupportedEnvironments};
@@ -31,8 +33,8 @@ use uv_python::{Interpreter, PythonDownloads, PythonEnvironment, PythonPreferenc
 use uv_requirements::ExtrasResolver;
 use uv_requirements::upgrade::{LockedRequirements, read_lock_requirements};
 use uv_resolver::{
-    FlatIndex, InMemoryIndex, Lock, Options, OptionsBuilder, Package, PythonRequirement,
-    ResolverEnvironment, ResolverManifest, SatisfiesResult, UniversalMarker,
+    DotDisplay, FlatIndex, InMemoryIndex, Installable, Lock, Options, OptionsBuilder, Package,
+    PythonRequirement, ResolverEnvironment, ResolverManifest, SatisfiesResult, UniversalMarker,
 };
 use uv_scripts::Pep723Script;
 use uv_settings::PythonInstallMirrors;
@@ -41,10 +43,11 @@ use uv_warnings::{warn_user, warn_user_once};
 use uv_workspace::{DiscoveryOptions, Editability, Workspace, WorkspaceCache, WorkspaceMember};
 
 use crate::commands::pip::loggers::{DefaultResolveLogger, ResolveLogger, SummaryResolveLogger};
+use crate::commands::project::install_target::InstallTarget;
 use crate::commands::project::lock_target::LockTarget;
 use crate::commands::project::{
     MissingLockfileSource, ProjectError, ProjectInterpreter, ScriptInterpreter, UniversalState,
-    init_script_python_requirement, script_extra_build_requires,
+    default_dependency_groups, init_script_python_requirement, script_extra_build_requires,
 };
 use crate::commands::reporters::{PythonDownloadReporter, ResolverReporter};
 use crate::commands::{ExitStatus, ScriptPath, diagnostics, pip};
@@ -83,6 +86,9 @@ pub(crate) async fn lock(
     lock_check: LockCheck,
     frozen: Option<FrozenSource>,
     dry_run: DryRun,
+    minimal_update: bool,
+    diff: Option<LockDiffFormat>,
+    emit_graph: Option<&Path>,
     refresh: Refresh,
     python: Option<String>,
     install_mirrors: PythonInstallMirrors,
@@ -202,6 +208,7 @@ pub(crate) async fn lock(
             preview,
         )
         .with_refresh(&refresh)
+        .with_minimal_update(minimal_update)
         .execute(target),
     )
     .await
@@ -223,7 +230,21 @@ pub(crate) async fn lock(
                 }
             }
 
-            if dry_run.enabled() {
+            if let (Some(format), LockMode::DryRun(interpreter)) = (diff, mode) {
+                // In `--diff` mode, compare the resolutions for the current platform.
+                let (previous, current) = match &lock {
+                    LockResult::Unchanged(lock) => (Some(lock), lock),
+                    LockResult::Changed(previous, lock) => (previous.as_ref(), lock),
+                };
+                let diff = diff_locks(
+                    target,
+                    previous,
+                    current,
+                    interpreter,
+                    &settings.build_options,
+                )?;
+                report_diff(&diff, format, printer)?;
+            } else if dry_run.enabled() {
                 // In `--dry-run` mode, show all changes.
                 if let LockResult::Changed(previous, lock) = &lock {
                     let mut changed = false;
@@ -251,6 +272,11 @@ pub(crate) async fn lock(
                 }
             }
 
+            // If requested, write the locked dependency graph.
+            if let Some(emit_graph) = emit_graph {
+                uv_fs::write_atomic(emit_graph, DotDisplay::from(lock.lock()).to_string()).await?;
+            }
+
             Ok(ExitStatus::Success)
         }
         Err(err @ ProjectError::LockMismatch(..)) => {
@@ -266,6 +292,126 @@ pub(crate) async fn lock(
     }
 }
 
+/// Compare the resolutions of the existing and updated lockfiles for the given interpreter, with
+/// the default extras and dependency groups enabled for all workspace members.
+#[expect(clippy::result_large_err)]
+fn diff_locks(
+    target: LockTarget<'_>,
+    previous: Option<&Lock>,
+    lock: &Lock,
+    interpreter: &Interpreter,
+    build_options: &BuildOptions,
+) -> Result<ResolutionDiff, ProjectError> {
+    let default_groups = match target {
+        LockTarget::Workspace(workspace) => default_dependency_groups(workspace.pyproject_toml())?,
+        LockTarget::Script(_) => DefaultGroups::default(),
+    };
+    let groups = DependencyGroups::default().with_defaults(default_groups);
+    let extras = ExtrasSpecification::default().with_defaults(DefaultExtras::default());
+    let marker_env = pip::resolution_markers(None, None, interpreter);
+    let tags = pip::resolution_tags(None, None, interpreter)?;
+
+    let resolution = |lock| {
+        let target = match target {
+            LockTarget::Workspace(workspace) if workspace.is_non_project() => {
+                InstallTarget::NonProjectWorkspace { workspace, lock }
+            }
+            LockTarget::Workspace(workspace) => InstallTarget::Workspace { workspace, lock },
+            LockTarget::Script(script) => InstallTarget::Script { script, lock },
+        };
+        target.to_resolution(
+            &marker_env,
+            &tags,
+            &extras,
+            &groups,
+            build_options,
+            &InstallOptions::default(),
+        )
+    };
+
+    let previous = previous.map(resolution).transpose()?.unwrap_or_default();
+    let current = resolution(lock)?;
+    Ok(previous.diff(&current))
+}
+
+/// Report the changes between two resolutions, in the given format.
+fn report_diff(
+    diff: &ResolutionDiff,
+    format: LockDiffFormat,
+    printer: Printer,
+) -> anyhow::Result<()> {
+    match format {
+        LockDiffFormat::Text => {
+            if diff.is_empty() {
+                writeln!(printer.stderr(), "{}", "No changes detected".bold())?;
+                return Ok(());
+            }
+
+            let version = |version: Option<&Version>| {
+                version
+                    .map(|version| format!(" v{version}"))
+                    .unwrap_or_default()
+            };
+            let mut changes = Vec::new();
+            for package in &diff.added {
+                changes.push((
+                    &package.name,
+                    format!(
+                        "{} {}{}",
+                        "Add".green().bold(),
+                        package.name,
+                        version(package.version.as_ref())
+                    ),
+                ));
+            }
+            for package in &diff.removed {
+                changes.push((
+                    &package.name,
+                    format!(
+                        "{} {}{}",
+                        "Remove".red().bold(),
+                        package.name,
+                        version(package.version.as_ref())
+                    ),
+                ));
+            }
+            for update in &diff.upgraded {
+                changes.push((
+                    &update.name,
+                    format!(
+                        "{} {} v{} -> v{}",
+                        "Upgrade".green().bold(),
+                        update.name,
+                        update.from,
+                        update.to
+                    ),
+                ));
+            }
+            for update in &diff.downgraded {
+                changes.push((
+                    &update.name,
+                    format!(
+                        "{} {} v{} -> v{}",
+                        "Downgrade".yellow().bold(),
+                        update.name,
+                        update.from,
+                        update.to
+                    ),
+                ));
+            }
+            changes.sort_by(|(a, _), (b, _)| a.cmp(b));
+            for (_, change) in changes {
+                writeln!(printer.stdout(), "{change}")?;
+            }
+        }
+        LockDiffFormat::Json => {
+            let string = serde_json::to_string_pretty(diff)?;
+            writeln!(printer.stdout(), "{string}")?;
+        }
+    }
+    Ok(())
+}
+
 #[derive(Debug, Clone, Copy)]
 pub(super) enum LockMode<'env> {
     /// Write the lockfile to disk.
@@ -283,6 +429,7 @@ pub(super) struct LockOperation<'env> {
     mode: LockMode<'env>,
     constraints: Vec<NameRequirementSpecification>,
     refresh: Option<&'env Refresh>,
+    minimal_update: bool,
     settings: &'env ResolverSettings,
     client_builder: &'env BaseClientBuilder<'env>,
     state: &'env UniversalState,
@@ -312,6 +459,7 @@ impl<'env> LockOperation<'env> {
             mode,
             constraints: vec![],
             refresh: None,
+            minimal_update: false,
             settings,
             client_builder,
             state,
@@ -341,6 +489,13 @@ impl<'env> LockOperation<'env> {
         self
     }
 
+    /// Update incompatible versions in the existing lockfile as little as possible.
+    #[must_use]
+    pub(super) fn with_minimal_update(mut self, minimal_update: bool) -> Self {
+        self.minimal_update = minimal_update;
+        self
+    }
+
     /// Perform a [`LockOperation`].
     pub(super) async fn execute(self, target: LockTarget<'_>) -> Result<LockResult, ProjectError> {
         match self.mode {
@@ -378,6 +533,7 @@ impl<'env> LockOperation<'env> {
                     Some(existing),
                     self.constraints,
                     self.refresh,
+                    self.minimal_update,
                     self.settings,
                     self.client_builder,
                     self.state,
@@ -422,6 +578,7 @@ impl<'env> LockOperation<'env> {
                     existing,
                     self.constraints,
                     self.refresh,
+                    self.minimal_update,
                     self.settings,
                     self.client_builder,
                     self.state,
@@ -454,6 +611,7 @@ async fn do_lock(
     existing_lock: Option<Lock>,
     external: Vec<NameRequirementSpecification>,
     refresh: Option<&Refresh>,
+    minimal_update: bool,
     settings: &ResolverSettings,
     client_builder: &BaseClientBuilder<'_>,
     state: &UniversalState,
@@ -473,6 +631,7 @@ async fn do_lock(
         keyring_provider,
         resolution,
         prerelease,
+        prerelease_packages,
         fork_strategy,
         dependency_metadata,
         config_setting,
@@ -486,6 +645,11 @@ async fn do_lock(
         build_options,
         sources,
         torch_backend: _,
+        excluded_packages,
+        resolution_priority,
+        max_backtracks,
+        memory_limit,
+        allow_yanked,
     } = settings;
 
     // Collect the requirements, etc.
@@ -718,11 +882,18 @@ async fn do_lock(
     let options = OptionsBuilder::new()
         .resolution_mode(*resolution)
         .prerelease_mode(*prerelease)
+        .prerelease_packages(prerelease_packages.clone())
         .fork_strategy(*fork_strategy)
         .exclude_newer(exclude_newer.clone())
         .index_strategy(*index_strategy)
         .build_options(build_options.clone())
         .required_environments(required_environments.cloned().unwrap_or_default())
+        .excluded_packages(excluded_packages.clone())
+        .resolution_priority(resolution_priority.clone())
+        .max_backtracks(*max_backtracks)
+        .memory_limit(*memory_limit)
+        .allow_yanked(allow_yanked.clone())
+        .minimal_update(minimal_update)
         .build();
     let hasher = HashStrategy::Generate(HashGeneration::Url);
 
@@ -1076,6 +1247,18 @@ impl ValidatedLock {
                 return Ok(Self::Preferable(lock));
             }
         }
+        if let Some(package) = lock
+            .packages()
+            .iter()
+            .find(|package| options.excluded_packages.contains(package.name()))
+        {
+            let _ = writeln!(
+                printer.stderr(),
+                "Resolving despite existing lockfile due to excluded package: `{}`",
+                package.name().cyan()
+            );
+            return Ok(Self::Preferable(lock));
+        }
 
         if upgrade.is_all() {
             // If the user specified `--upgrade`, then we can't use the existing lockfile.
@@ -1192,6 +1375,23 @@ impl ValidatedLock {
             return Ok(Self::Preferable(lock));
         }
 
+        // If the packages that allow pre-releases have changed, we have to re-resolve, but can
+        // retain the existing versions and forks.
+        let prerelease_packages = options
+            .prerelease_packages
+            .iter()
+            .cloned()
+            .collect::<BTreeSet<_>>();
+        if *lock.prerelease_packages() != prerelease_packages {
+            let _ = writeln!(
+                printer.stderr(),
+                "Resolving despite existing lockfile due to change in pre-release packages: `{}` vs. `{}`",
+                lock.prerelease_packages().iter().join(", ").cyan(),
+                prerelease_packages.iter().join(", ").cyan()
+            );
+            return Ok(Self::Preferable(lock));
+        }
+
         // If the user specified `--upgrade-package`, then at best we can prefer some of
         // the existing versions.
         if !(upgrade.is_none() || upgrade.is_all()) {
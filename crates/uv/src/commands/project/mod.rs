@@ -70,12 +70,14 @@ pub(crate) mod export;
 pub(crate) mod format;
 pub(crate) mod init;
 mod install_target;
+pub(crate) mod licenses;
 pub(crate) mod lock;
 mod lock_target;
 pub(crate) mod remove;
 pub(crate) mod run;
 pub(crate) mod sync;
 pub(crate) mod tree;
+pub(crate) mod vendor;
 pub(crate) mod version;
 
 /// The source of a missing lockfile error.
@@ -1849,12 +1851,19 @@ pub(crate) async fn resolve_names(
                 extra_build_dependencies,
                 extra_build_variables,
                 prerelease: _,
+                prerelease_packages: _,
                 resolution: _,
                 sources,
                 torch_backend,
                 upgrade: _,
+                excluded_packages: _,
+                resolution_priority: _,
+                max_backtracks: _,
+                memory_limit: _,
+                allow_yanked: _,
             },
         compile_bytecode: _,
+        protected_packages: _,
         reinstall: _,
     } = settings;
 
@@ -2019,6 +2028,7 @@ pub(crate) async fn resolve_environment(
         keyring_provider,
         resolution,
         prerelease,
+        prerelease_packages,
         fork_strategy,
         dependency_metadata,
         config_setting,
@@ -2032,6 +2042,11 @@ pub(crate) async fn resolve_environment(
         build_options,
         sources,
         torch_backend,
+        excluded_packages,
+        resolution_priority,
+        max_backtracks,
+        memory_limit,
+        allow_yanked,
     } = settings;
 
     // Respect all requirements from the provided sources.
@@ -2100,10 +2115,16 @@ pub(crate) async fn resolve_environment(
     let options = OptionsBuilder::new()
         .resolution_mode(*resolution)
         .prerelease_mode(*prerelease)
+        .prerelease_packages(prerelease_packages.clone())
         .fork_strategy(*fork_strategy)
         .exclude_newer(exclude_newer.clone())
         .index_strategy(*index_strategy)
         .build_options(build_options.clone())
+        .excluded_packages(excluded_packages.clone())
+        .resolution_priority(resolution_priority.clone())
+        .max_backtracks(*max_backtracks)
+        .memory_limit(*memory_limit)
+        .allow_yanked(allow_yanked.clone())
         .build();
 
     // TODO(charlie): These are all default values. We should consider whether we want to make them
@@ -2241,6 +2262,8 @@ pub(crate) async fn sync_environment(
         reinstall,
         build_options,
         sources,
+        excluded_packages,
+        protected_packages,
     } = settings;
 
     let client_builder = client_builder.clone().keyring(keyring_provider);
@@ -2335,6 +2358,9 @@ pub(crate) async fn sync_environment(
         &venv,
         logger,
         installer_metadata,
+        false,
+        excluded_packages,
+        protected_packages,
         dry_run,
         printer,
         preview,
@@ -2403,12 +2429,19 @@ pub(crate) async fn update_environment(
                 extra_build_dependencies: _,
                 extra_build_variables,
                 prerelease,
+                prerelease_packages,
                 resolution,
                 sources,
                 torch_backend,
                 upgrade,
+                excluded_packages,
+                resolution_priority,
+                max_backtracks,
+                memory_limit,
+                allow_yanked,
             },
         compile_bytecode,
+        protected_packages,
         reinstall,
     } = settings;
 
@@ -2519,10 +2552,16 @@ pub(crate) async fn update_environment(
     let options = OptionsBuilder::new()
         .resolution_mode(*resolution)
         .prerelease_mode(*prerelease)
+        .prerelease_packages(prerelease_packages.clone())
         .fork_strategy(*fork_strategy)
         .exclude_newer(exclude_newer.clone())
         .index_strategy(*index_strategy)
         .build_options(build_options.clone())
+        .excluded_packages(excluded_packages.clone())
+        .resolution_priority(resolution_priority.clone())
+        .max_backtracks(*max_backtracks)
+        .memory_limit(*memory_limit)
+        .allow_yanked(allow_yanked.clone())
         .build();
 
     // TODO(charlie): These are all default values. We should consider whether we want to make them
@@ -2627,6 +2666,9 @@ pub(crate) async fn update_environment(
         &venv,
         install,
         installer_metadata,
+        false,
+        excluded_packages,
+        protected_packages,
         dry_run,
         printer,
         preview,
@@ -646,6 +646,8 @@ pub(super) async fn do_sync(
         reinstall,
         build_options,
         sources,
+        excluded_packages,
+        protected_packages,
     } = settings;
 
     // Lower the extra build dependencies with source resolution.
@@ -676,9 +678,15 @@ pub(super) async fn do_sync(
                 keyring_provider,
                 link_mode,
                 build_isolation: build_isolation.clone(),
+                excluded_packages: excluded_packages.to_vec(),
+                resolution_priority: Vec::new(),
+                max_backtracks: None,
+                memory_limit: None,
+                allow_yanked: Vec::new(),
                 extra_build_dependencies: extra_build_dependencies.clone(),
                 extra_build_variables: extra_build_variables.clone(),
                 prerelease: PrereleaseMode::default(),
+                prerelease_packages: Vec::new(),
                 resolution: ResolutionMode::default(),
                 sources: sources.clone(),
                 torch_backend: None,
@@ -850,6 +858,9 @@ pub(super) async fn do_sync(
         venv,
         logger,
         installer_metadata,
+        false,
+        excluded_packages,
+        protected_packages,
         dry_run,
         printer,
         preview,
@@ -860,7 +871,7 @@ pub(super) async fn do_sync(
 }
 
 /// Filter out any virtual workspace members.
-fn apply_no_virtual_project(resolution: Resolution) -> Resolution {
+pub(super) fn apply_no_virtual_project(resolution: Resolution) -> Resolution {
     resolution.filter(|dist| {
         let ResolvedDist::Installable { dist, .. } = dist else {
             return true;
@@ -948,7 +959,10 @@ fn apply_editable_mode(resolution: Resolution, editable: Option<EditableMode>) -
 ///
 /// These credentials can come from any of `tool.uv.sources`, `tool.uv.dev-dependencies`,
 /// `project.dependencies`, and `project.optional-dependencies`.
-fn store_credentials_from_target(target: InstallTarget<'_>, client_builder: &BaseClientBuilder) {
+pub(super) fn store_credentials_from_target(
+    target: InstallTarget<'_>,
+    client_builder: &BaseClientBuilder,
+) {
     // Iterate over any indexes in the target.
     for index in target.indexes() {
         if let Some(credentials) = index.credentials() {
@@ -200,6 +200,7 @@ pub(crate) async fn tree(
                 keyring_provider,
                 resolution: _,
                 prerelease: _,
+                prerelease_packages: _,
                 fork_strategy: _,
                 dependency_metadata: _,
                 config_setting: _,
@@ -213,6 +214,11 @@ pub(crate) async fn tree(
                 build_options: _,
                 sources: _,
                 torch_backend: _,
+                excluded_packages: _,
+                resolution_priority: _,
+                max_backtracks: _,
+                memory_limit: _,
+                allow_yanked: _,
             } = &settings;
 
             let capabilities = IndexCapabilities::default();
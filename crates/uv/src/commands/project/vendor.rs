@@ -0,0 +1,463 @@
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+use anyhow::{Context, Result, anyhow, bail};
+use futures::{StreamExt, TryStreamExt};
+use itertools::Itertools;
+use owo_colors::OwoColorize;
+use tracing::debug;
+
+use uv_cache::Cache;
+use uv_client::{BaseClientBuilder, FlatIndexClient, RegistryClientBuilder};
+use uv_configuration::{
+    Concurrency, DependencyGroups, ExtrasSpecification, HashCheckingMode, InstallOptions,
+};
+use uv_dispatch::BuildDispatch;
+use uv_distribution::{DistributionDatabase, LoweredExtraBuildDependencies};
+use uv_distribution_types::{Dist, Index, Name, ResolvedDist, SourceDist};
+use uv_extract::hash::{HashReader, Hasher};
+use uv_fs::{PortablePath, Simplified};
+use uv_normalize::{DefaultExtras, PackageName};
+use uv_preview::{Preview, PreviewFeature};
+use uv_pypi_types::{HashAlgorithm, HashDigest};
+use uv_python::{PythonDownloads, PythonEnvironment, PythonPreference, PythonRequest};
+use uv_resolver::{FlatIndex, Installable};
+use uv_settings::PythonInstallMirrors;
+use uv_types::{BuildIsolation, HashStrategy};
+use uv_warnings::warn_user;
+use uv_workspace::{DiscoveryOptions, VirtualProject, WorkspaceCache};
+
+use crate::commands::pip::download::download_dist;
+use crate::commands::pip::loggers::DefaultResolveLogger;
+use crate::commands::pip::wheel::collect_wheel;
+use crate::commands::pip::{resolution_markers, resolution_tags};
+use crate::commands::project::export::cmd;
+use crate::commands::project::install_target::InstallTarget;
+use crate::commands::project::lock::{LockMode, LockOperation};
+use crate::commands::project::sync::{apply_no_virtual_project, store_credentials_from_target};
+use crate::commands::project::{
+    PlatformState, ProjectError, ProjectInterpreter, UniversalState, default_dependency_groups,
+    detect_conflicts,
+};
+use crate::commands::{ExitStatus, diagnostics, elapsed};
+use crate::printer::Printer;
+use crate::settings::{FrozenSource, LockCheck, ResolverSettings};
+
+/// Download the project's locked dependencies into a directory, along with a requirements file
+/// that refers to the downloaded distributions.
+#[expect(clippy::fn_params_excessive_bools)]
+pub(crate) async fn vendor(
+    project_dir: &Path,
+    vendor_dir: Option<PathBuf>,
+    output_file: Option<PathBuf>,
+    build_wheels: bool,
+    all_packages: bool,
+    package: Vec<PackageName>,
+    extras: ExtrasSpecification,
+    groups: DependencyGroups,
+    include_header: bool,
+    lock_check: LockCheck,
+    frozen: Option<FrozenSource>,
+    python: Option<String>,
+    install_mirrors: PythonInstallMirrors,
+    settings: ResolverSettings,
+    client_builder: BaseClientBuilder<'_>,
+    python_preference: PythonPreference,
+    python_downloads: PythonDownloads,
+    concurrency: Concurrency,
+    no_config: bool,
+    cache: &Cache,
+    printer: Printer,
+    preview: Preview,
+) -> Result<ExitStatus> {
+    let start = Instant::now();
+
+    if !preview.is_enabled(PreviewFeature::Vendor) {
+        warn_user!(
+            "`uv vendor` is experimental and may change without warning. Pass `--preview-features {}` to disable this warning.",
+            PreviewFeature::Vendor
+        );
+    }
+
+    // Identify the project.
+    let workspace_cache = WorkspaceCache::default();
+    let project = if let [name] = package.as_slice() {
+        VirtualProject::discover_with_package(
+            project_dir,
+            &DiscoveryOptions::default(),
+            &workspace_cache,
+            name.clone(),
+        )
+        .await?
+    } else {
+        let project =
+            VirtualProject::discover(project_dir, &DiscoveryOptions::default(), &workspace_cache)
+                .await?;
+
+        for name in &package {
+            if !project.workspace().packages().contains_key(name) {
+                return Err(anyhow!("Package `{name}` not found in workspace"));
+            }
+        }
+
+        project
+    };
+
+    // Determine the groups and extras to include.
+    let groups = groups.with_defaults(default_dependency_groups(project.pyproject_toml())?);
+    let extras = extras.with_defaults(DefaultExtras::default());
+
+    // Find an interpreter for the project. Distributions are selected for the interpreter's
+    // platform, and any source distributions are built with it.
+    let interpreter = ProjectInterpreter::discover(
+        project.workspace(),
+        project_dir,
+        &groups,
+        python.as_deref().map(PythonRequest::parse),
+        &client_builder,
+        python_preference,
+        python_downloads,
+        &install_mirrors,
+        false,
+        no_config,
+        Some(false),
+        cache,
+        printer,
+        preview,
+    )
+    .await?
+    .into_interpreter();
+
+    // Determine the lock mode.
+    let mode = if let Some(frozen_source) = frozen {
+        LockMode::Frozen(frozen_source.into())
+    } else if let LockCheck::Enabled(lock_check) = lock_check {
+        LockMode::Locked(&interpreter, lock_check)
+    } else {
+        LockMode::Write(&interpreter)
+    };
+
+    // Lock the project.
+    let lock = match Box::pin(
+        LockOperation::new(
+            mode,
+            &settings,
+            &client_builder,
+            &UniversalState::default(),
+            Box::new(DefaultResolveLogger),
+            &concurrency,
+            cache,
+            &workspace_cache,
+            printer,
+            preview,
+        )
+        .execute(project.workspace().into()),
+    )
+    .await
+    {
+        Ok(result) => result.into_lock(),
+        Err(ProjectError::Operation(err)) => {
+            return diagnostics::OperationDiagnostic::native_tls(client_builder.is_native_tls())
+                .report(err)
+                .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
+        }
+        Err(err) => return Err(err.into()),
+    };
+
+    // Identify the installation target.
+    let target = match &project {
+        VirtualProject::Project(project) => {
+            if all_packages {
+                InstallTarget::Workspace {
+                    workspace: project.workspace(),
+                    lock: &lock,
+                }
+            } else {
+                match package.as_slice() {
+                    // By default, vendor the dependencies of the root project.
+                    [] => InstallTarget::Project {
+                        workspace: project.workspace(),
+                        name: project.project_name(),
+                        lock: &lock,
+                    },
+                    [name] => InstallTarget::Project {
+                        workspace: project.workspace(),
+                        name,
+                        lock: &lock,
+                    },
+                    names => InstallTarget::Projects {
+                        workspace: project.workspace(),
+                        names,
+                        lock: &lock,
+                    },
+                }
+            }
+        }
+        VirtualProject::NonProject(workspace) => {
+            if all_packages {
+                InstallTarget::NonProjectWorkspace {
+                    workspace,
+                    lock: &lock,
+                }
+            } else {
+                match package.as_slice() {
+                    // By default, vendor the dependencies of the entire workspace.
+                    [] => InstallTarget::NonProjectWorkspace {
+                        workspace,
+                        lock: &lock,
+                    },
+                    [name] => InstallTarget::Project {
+                        workspace,
+                        name,
+                        lock: &lock,
+                    },
+                    names => InstallTarget::Projects {
+                        workspace,
+                        names,
+                        lock: &lock,
+                    },
+                }
+            }
+        }
+    };
+
+    // Validate that the set of requested extras and development groups are compatible, and
+    // defined in the lockfile.
+    detect_conflicts(&target, &extras, &groups)?;
+    target.validate_extras(&extras)?;
+    target.validate_groups(&groups)?;
+
+    // Select the distributions for the interpreter from the lockfile. Workspace members are part of
+    // the repository, and so are never vendored.
+    let marker_env = resolution_markers(None, None, &interpreter);
+    let tags = resolution_tags(None, None, &interpreter)?;
+    let install_options = InstallOptions::new(
+        false,
+        false,
+        true,
+        false,
+        false,
+        false,
+        Vec::new(),
+        Vec::new(),
+    );
+    let resolution = target.to_resolution(
+        &marker_env,
+        &tags,
+        &extras,
+        &groups,
+        &settings.build_options,
+        &install_options,
+    )?;
+    let resolution = apply_no_virtual_project(resolution);
+
+    let dists = resolution
+        .distributions()
+        .filter_map(|dist| match dist {
+            ResolvedDist::Installable { dist, .. } => Some(dist),
+            ResolvedDist::Installed { .. } => None,
+        })
+        .collect::<Vec<_>>();
+
+    // Without `--build-wheels`, only distributions that are backed by an archive can be vendored.
+    if !build_wheels {
+        let unarchived = dists
+            .iter()
+            .filter(|dist| {
+                matches!(
+                    dist.as_ref(),
+                    Dist::Source(SourceDist::Git(_) | SourceDist::Directory(_))
+                )
+            })
+            .map(|dist| format!("`{}`", dist.name()))
+            .collect::<Vec<_>>();
+        if !unarchived.is_empty() {
+            bail!(
+                "Unable to vendor {} without building, since they're not available as a wheel or source distribution archive; use `{}` to vendor them as wheels",
+                unarchived.join(", "),
+                "--build-wheels".green(),
+            );
+        }
+    }
+
+    let client_builder = client_builder.clone().keyring(settings.keyring_provider);
+
+    // Populate credentials from the target.
+    store_credentials_from_target(target, &client_builder);
+
+    // Initialize the registry client.
+    let client = RegistryClientBuilder::new(client_builder, cache.clone())
+        .index_locations(settings.index_locations.clone())
+        .index_strategy(settings.index_strategy)
+        .markers(interpreter.markers())
+        .platform(interpreter.platform())
+        .build();
+
+    // Lower the extra build dependencies, constraining any that match the runtime.
+    let extra_build_requires = LoweredExtraBuildDependencies::from_workspace(
+        settings.extra_build_dependencies.clone(),
+        project.workspace(),
+        &settings.index_locations,
+        &settings.sources,
+        client.credentials_cache(),
+    )?
+    .into_inner()
+    .match_runtime(&resolution)?;
+
+    // Determine whether to enable build isolation.
+    let environment = PythonEnvironment::from_interpreter(interpreter.clone());
+    let build_isolation = match &settings.build_isolation {
+        uv_configuration::BuildIsolation::Isolate => BuildIsolation::Isolated,
+        uv_configuration::BuildIsolation::Shared => BuildIsolation::Shared(&environment),
+        uv_configuration::BuildIsolation::SharedPackage(packages) => {
+            BuildIsolation::SharedPackage(&environment, packages)
+        }
+    };
+
+    // Read the build constraints from the lockfile.
+    let build_constraints = target.build_constraints();
+
+    // Build dependencies aren't locked, so they're never hash-checked.
+    let build_hasher = HashStrategy::default();
+
+    // Verify every vendored distribution against the hashes in the lockfile.
+    let hasher = HashStrategy::from_resolution(&resolution, HashCheckingMode::Verify)?;
+
+    // Resolve the flat indexes from `--find-links`.
+    let flat_index = {
+        let client = FlatIndexClient::new(client.cached_client(), client.connectivity(), cache);
+        let entries = client
+            .fetch_all(settings.index_locations.flat_indexes().map(Index::url))
+            .await?;
+        FlatIndex::from_entries(entries, Some(&tags), &hasher, &settings.build_options)
+    };
+
+    // Create a build dispatch.
+    let build_dispatch = BuildDispatch::new(
+        &client,
+        cache,
+        &build_constraints,
+        &interpreter,
+        &settings.index_locations,
+        &flat_index,
+        &settings.dependency_metadata,
+        PlatformState::default().into_inner(),
+        settings.index_strategy,
+        &settings.config_setting,
+        &settings.config_settings_package,
+        build_isolation,
+        &extra_build_requires,
+        &settings.extra_build_variables,
+        settings.link_mode,
+        &settings.build_options,
+        &build_hasher,
+        settings.exclude_newer.clone(),
+        settings.sources.clone(),
+        workspace_cache.clone(),
+        concurrency.clone(),
+        preview,
+    );
+    let database = DistributionDatabase::new(
+        &client,
+        &build_dispatch,
+        concurrency.downloads_semaphore.clone(),
+    );
+
+    // Download (or build) the distributions into the vendor directory.
+    let vendor_dir = vendor_dir.unwrap_or_else(|| project.root().join("vendor"));
+    fs_err::tokio::create_dir_all(&vendor_dir).await?;
+
+    let vendored = futures::stream::iter(&dists)
+        .map(|dist| async {
+            let filename = if build_wheels {
+                collect_wheel(dist, &vendor_dir, &tags, &database, &hasher).await?
+            } else {
+                download_dist(dist, &vendor_dir, &database, &hasher).await?
+            };
+            Ok::<_, anyhow::Error>((dist.name().clone(), filename))
+        })
+        .buffer_unordered(concurrency.downloads)
+        .try_collect::<Vec<_>>()
+        .await?;
+
+    // Verify that every distribution was vendored.
+    let mut vendored = vendored
+        .into_iter()
+        .map(|(name, filename)| {
+            let Some(filename) = filename.filter(|filename| vendor_dir.join(filename).is_file())
+            else {
+                bail!("Failed to vendor `{name}`");
+            };
+            Ok((name, filename))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    vendored.sort_unstable();
+
+    // Hash the vendored archives, such that they're verified when installed from the
+    // requirements file.
+    let digests = futures::stream::iter(&vendored)
+        .map(|(_, filename)| hash_archive(vendor_dir.join(filename)))
+        .buffered(concurrency.downloads)
+        .try_collect::<Vec<_>>()
+        .await?;
+
+    // Write a requirements file that refers to the vendored distributions, relative to the
+    // workspace root.
+    let output_file = output_file.unwrap_or_else(|| vendor_dir.join("requirements.txt"));
+    let mut requirements = String::new();
+    if include_header {
+        writeln!(
+            requirements,
+            "# This file was autogenerated by uv via the following command:"
+        )?;
+        writeln!(requirements, "#    {}", cmd())?;
+    }
+    writeln!(requirements, "--no-index")?;
+    for ((name, filename), digest) in vendored.iter().zip(&digests) {
+        let path = uv_fs::relative_to(
+            std::path::absolute(vendor_dir.join(filename))?,
+            project.root(),
+        )?;
+        let path = PortablePath::from(&path).to_string();
+        if path.starts_with("..") {
+            writeln!(requirements, "{name} @ file:{path} \\")?;
+        } else {
+            writeln!(requirements, "{name} @ file:./{path} \\")?;
+        }
+        writeln!(requirements, "    --hash={digest}")?;
+    }
+    if let Some(parent) = output_file.parent() {
+        fs_err::tokio::create_dir_all(parent).await?;
+    }
+    debug!("Writing requirements to: {}", output_file.user_display());
+    fs_err::tokio::write(&output_file, requirements).await?;
+
+    let s = if vendored.len() == 1 { "" } else { "s" };
+    writeln!(
+        printer.stderr(),
+        "{}",
+        format!(
+            "Vendored {} {}",
+            format!("{} package{s}", vendored.len()).bold(),
+            format!("in {}", elapsed(start.elapsed())).dimmed()
+        )
+        .dimmed()
+    )?;
+    for filename in vendored.iter().map(|(_, filename)| filename).sorted() {
+        writeln!(printer.stderr(), " {} {}", "+".green(), filename.bold())?;
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Compute the SHA-256 hash of a vendored archive.
+async fn hash_archive(path: PathBuf) -> Result<HashDigest> {
+    let file = fs_err::tokio::File::open(&path).await?;
+    let mut hashers = vec![Hasher::from(HashAlgorithm::Sha256)];
+    HashReader::new(tokio::io::BufReader::new(file), &mut hashers)
+        .finish()
+        .await
+        .with_context(|| format!("Failed to read: `{}`", path.user_display()))?;
+    Ok(HashDigest::from(hashers.remove(0)))
+}
@@ -6,7 +6,7 @@ use console::Term;
 use owo_colors::{AnsiColors, OwoColorize};
 use tokio::sync::Semaphore;
 use tracing::{debug, info, trace};
-use uv_auth::{Credentials, PyxTokenStore};
+use uv_auth::{Credentials, Pypirc, PyxTokenStore};
 use uv_cache::Cache;
 use uv_client::{
     AuthIntegration, BaseClient, BaseClientBuilder, RedirectPolicy, RegistryClientBuilder,
@@ -59,43 +59,53 @@ pub(crate) async fn publish(
 
     let token_store = PyxTokenStore::from_settings()?;
 
+    // Read the `.pypirc` file, for compatibility with `twine`.
+    let pypirc = Pypirc::from_default_path().unwrap_or_else(|err| {
+        warn_user_once!("{err}");
+        None
+    });
+
     let (publish_url, check_url) = if let Some(index_name) = index {
         // If the user provided an index by name, look it up.
         debug!("Publishing with index {index_name}");
-        let index = index_locations
-            .simple_indexes()
-            .find(|index| {
-                index
-                    .name
-                    .as_ref()
-                    .is_some_and(|name| name.as_ref() == index_name)
-            })
-            .with_context(|| {
-                let mut index_names: Vec<String> = index_locations
-                    .simple_indexes()
-                    .filter_map(|index| index.name.as_ref())
-                    .map(ToString::to_string)
-                    .collect();
-                index_names.sort();
-                if index_names.is_empty() {
-                    format!("No indexes were found, can't use index: `{index_name}`")
-                } else {
-                    let index_names = index_names.join("`, `");
-                    format!("Index not found: `{index_name}`. Found indexes: `{index_names}`")
-                }
-            })?;
-        let publish_url = index
-            .publish_url
-            .clone()
-            .with_context(|| format!("Index is missing a publish URL: `{index_name}`"))?;
-
-        // pyx has the same behavior as PyPI where uploads of identical
-        // files + contents are idempotent, so we don't need to pre-check.
-        if token_store.is_known_url(&publish_url) {
-            (publish_url, None)
+        if let Some(index) = index_locations.simple_indexes().find(|index| {
+            index
+                .name
+                .as_ref()
+                .is_some_and(|name| name.as_ref() == index_name)
+        }) {
+            let publish_url = index
+                .publish_url
+                .clone()
+                .with_context(|| format!("Index is missing a publish URL: `{index_name}`"))?;
+
+            // pyx has the same behavior as PyPI where uploads of identical
+            // files + contents are idempotent, so we don't need to pre-check.
+            if token_store.is_known_url(&publish_url) {
+                (publish_url, None)
+            } else {
+                let check_url = index.url.clone();
+                (publish_url, Some(check_url))
+            }
+        } else if let Some(repository) = pypirc
+            .as_ref()
+            .and_then(|pypirc| pypirc.repository(&index_name))
+        {
+            // Fall back to the repositories defined in the `.pypirc` file.
+            debug!("Using `.pypirc` repository {index_name}");
+            (repository.url.clone(), check_url)
         } else {
-            let check_url = index.url.clone();
-            (publish_url, Some(check_url))
+            let mut index_names: Vec<String> = index_locations
+                .simple_indexes()
+                .filter_map(|index| index.name.as_ref())
+                .map(ToString::to_string)
+                .collect();
+            index_names.sort();
+            if index_names.is_empty() {
+                bail!("No indexes were found, can't use index: `{index_name}`");
+            }
+            let index_names = index_names.join("`, `");
+            bail!("Index not found: `{index_name}`. Found indexes: `{index_names}`");
         }
     } else {
         (publish_url, check_url)
@@ -174,6 +184,7 @@ pub(crate) async fn publish(
         &token_store,
         &oidc_client,
         check_url.as_ref(),
+        pypirc.as_ref(),
         Prompt::Enabled,
         printer,
     )
@@ -427,6 +438,7 @@ enum Prompt {
 ///   - The username field in the publish URL
 ///   - If `--token` or `UV_PUBLISH_TOKEN` are used, it is `__token__`. The CLI option
 ///     overrides the environment variable
+/// - The username in the `.pypirc` file for the publish URL, if it also has a password
 /// - If trusted publishing is available, it is `__token__`
 /// - (We currently do not read the username from the keyring)
 /// - If stderr is a tty, prompt the user
@@ -438,6 +450,7 @@ enum Prompt {
 ///   - The password field in the publish URL
 ///   - If `--token` or `UV_PUBLISH_TOKEN` are used, it is the token value. The CLI option overrides
 ///     the environment variable
+/// - The password in the `.pypirc` file for the publish URL and username
 /// - If the keyring is enabled, the keyring entry for the URL and username
 /// - If trusted publishing is available, the trusted publishing token
 /// - If stderr is a tty, prompt the user
@@ -455,6 +468,7 @@ async fn gather_credentials(
     token_store: &PyxTokenStore,
     oidc_client: &BaseClient,
     check_url: Option<&IndexUrl>,
+    pypirc: Option<&Pypirc>,
     prompt: Prompt,
     printer: Printer,
 ) -> Result<(DisplaySafeUrl, Credentials)> {
@@ -479,6 +493,17 @@ async fn gather_credentials(
             .expect("Failed to clear publish URL username");
     }
 
+    // Read the credentials from the `.pypirc` file, as `twine` does.
+    if password.is_none() {
+        if let Some(credentials) =
+            pypirc.and_then(|pypirc| pypirc.credentials_for(&publish_url, username.as_deref()))
+        {
+            debug!("Using credentials from `.pypirc` for {publish_url}");
+            username = credentials.username().map(ToString::to_string);
+            password = credentials.password().map(ToString::to_string);
+        }
+    }
+
     // If applicable, attempt obtaining a token for trusted publishing.
     let trusted_publishing_token = check_trusted_publishing(
         username.as_deref(),
@@ -619,6 +644,7 @@ mod tests {
             &token_store,
             &client,
             None,
+            None,
             Prompt::Disabled,
             Printer::Quiet,
         )
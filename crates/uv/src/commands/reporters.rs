@@ -18,7 +18,7 @@ use uv_distribution_types::{
 use uv_normalize::PackageName;
 use uv_pep440::Version;
 use uv_python::PythonInstallationKey;
-use uv_redacted::DisplaySafeUrl;
+use uv_redacted::{DisplaySafeUrl, redact_credentials};
 use uv_static::EnvVars;
 
 /// Since downloads, fetches and builds run in parallel, their message output order is
@@ -168,11 +168,12 @@ impl ProgressReporter {
         );
 
         progress.set_style(ProgressStyle::with_template("{wide_msg}").unwrap());
-        let message = format!(
+        let message = redact_credentials(&format!(
             "   {} {}",
             "Building".bold().cyan(),
             source.to_color_string()
-        );
+        ))
+        .into_owned();
         if multi_progress.is_hidden() && !*HAS_UV_TEST_NO_CLI_PROGRESS {
             let _ = writeln!(self.printer.stderr(), "{message}");
         }
@@ -198,11 +199,12 @@ impl ProgressReporter {
             state.bars.remove(&id).unwrap()
         };
 
-        let message = format!(
+        let message = redact_credentials(&format!(
             "      {} {}",
             "Built".bold().green(),
             source.to_color_string()
-        );
+        ))
+        .into_owned();
         if multi_progress.is_hidden() && !*HAS_UV_TEST_NO_CLI_PROGRESS {
             let _ = writeln!(self.printer.stderr(), "{message}");
         }
@@ -530,7 +532,9 @@ impl uv_resolver::ResolverReporter for ResolverReporter {
                 self.reporter.root.set_message(format!("{name}=={version}"));
             }
             VersionOrUrlRef::Url(url) => {
-                self.reporter.root.set_message(format!("{name} @ {url}"));
+                self.reporter
+                    .root
+                    .set_message(redact_credentials(&format!("{name} @ {url}")).into_owned());
             }
         }
     }
@@ -625,7 +629,8 @@ impl InstallReporter {
 
 impl uv_installer::InstallReporter for InstallReporter {
     fn on_install_progress(&self, wheel: &CachedDist) {
-        self.progress.set_message(format!("{wheel}"));
+        self.progress
+            .set_message(redact_credentials(&wheel.to_string()).into_owned());
         self.progress.inc(1);
     }
 
@@ -0,0 +1,76 @@
+use std::fmt::Write;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use owo_colors::OwoColorize;
+
+use uv_fs::Simplified;
+use uv_platform_tags::{AbiTag, LanguageTag, PlatformTag};
+use uv_preview::{Preview, PreviewFeature};
+use uv_warnings::warn_user;
+
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// Rewrite the tags of a wheel.
+pub(crate) fn wheel_retag(
+    wheel: &Path,
+    python_tags: Option<&[LanguageTag]>,
+    abi_tags: Option<&[AbiTag]>,
+    platform_tags: Option<&[PlatformTag]>,
+    out_dir: Option<&Path>,
+    remove: bool,
+    force: bool,
+    printer: Printer,
+    preview: Preview,
+) -> Result<ExitStatus> {
+    if !preview.is_enabled(PreviewFeature::WheelRetag) {
+        warn_user!(
+            "`uv wheel retag` is experimental and may change without warning. Pass `--preview-features {}` to disable this warning.",
+            PreviewFeature::WheelRetag
+        );
+    }
+
+    let output_dir = match out_dir {
+        Some(out_dir) => out_dir,
+        None => wheel.parent().unwrap_or(Path::new(".")),
+    };
+    // Support relative paths such as `foo.whl`, which have an empty parent.
+    let output_dir = if output_dir.as_os_str().is_empty() {
+        Path::new(".")
+    } else {
+        output_dir
+    };
+    fs_err::create_dir_all(output_dir)?;
+
+    let filename = uv_build_backend::retag_wheel(
+        wheel,
+        output_dir,
+        python_tags,
+        abi_tags,
+        platform_tags,
+        force,
+    )
+    .with_context(|| format!("Failed to retag wheel: {}", wheel.user_display()))?;
+
+    let retagged = output_dir.join(filename.to_string());
+    if remove && !same_file(wheel, &retagged) {
+        fs_err::remove_file(wheel)?;
+    }
+
+    writeln!(
+        printer.stderr(),
+        "Successfully retagged {}",
+        retagged.user_display().bold().cyan()
+    )?;
+
+    Ok(ExitStatus::Success)
+}
+
+/// Returns `true` if both paths refer to the same existing file.
+fn same_file(left: &Path, right: &Path) -> bool {
+    match (fs_err::canonicalize(left), fs_err::canonicalize(right)) {
+        (Ok(left), Ok(right)) => left == right,
+        _ => false,
+    }
+}
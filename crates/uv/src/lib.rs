@@ -12,7 +12,7 @@ use std::str::FromStr;
 use std::sync::atomic::Ordering;
 
 use anstream::eprintln;
-use anyhow::{Result, anyhow, bail};
+use anyhow::{Context, Result, anyhow, bail};
 use clap::error::{ContextKind, ContextValue};
 use clap::{CommandFactory, Parser};
 use futures::FutureExt;
@@ -20,30 +20,35 @@ use owo_colors::OwoColorize;
 use settings::PipTreeSettings;
 use tokio::task::spawn_blocking;
 use tracing::{debug, instrument, trace};
+use url::Url;
 
 #[cfg(not(feature = "self-update"))]
 use crate::install_source::InstallSource;
-use uv_cache::{Cache, Refresh};
+use uv_cache::{Cache, CacheBucket, CacheEntry, Refresh};
 use uv_cache_info::Timestamp;
+use uv_cache_key::{CanonicalUrl, cache_digest};
 #[cfg(feature = "self-update")]
 use uv_cli::SelfUpdateArgs;
 use uv_cli::{
     AuthCommand, AuthHelperCommand, AuthNamespace, BuildBackendCommand, CacheCommand,
-    CacheNamespace, Cli, Commands, PipCommand, PipNamespace, ProjectCommand, PythonCommand,
+    CacheNamespace, Cli, Commands, IndexCommand, IndexNamespace, PipCommand, PipExplainArgs,
+    PipIndexCommand, PipIndexNamespace, PipNamespace, ProjectCommand, PythonCommand,
     PythonNamespace, SelfCommand, SelfNamespace, ToolCommand, ToolNamespace, TopLevelArgs,
-    WorkspaceCommand, WorkspaceNamespace, compat::CompatArgs,
+    WheelCommand, WheelNamespace, WorkspaceCommand, WorkspaceNamespace, compat::CompatArgs,
 };
 use uv_client::BaseClientBuilder;
 use uv_configuration::min_stack_size;
 use uv_flags::EnvironmentFlags;
 use uv_fs::{CWD, Simplified};
+use uv_normalize::PackageName;
 #[cfg(feature = "self-update")]
 use uv_pep440::release_specifiers_to_ranges;
 use uv_pep508::VersionOrUrl;
 use uv_preview::PreviewFeature;
-use uv_pypi_types::{ParsedDirectoryUrl, ParsedUrl};
+use uv_pypi_types::{HashAlgorithm, ParsedDirectoryUrl, ParsedUrl};
 use uv_python::PythonRequest;
-use uv_requirements::{GroupsSpecification, RequirementsSource};
+use uv_redacted::{DisplaySafeUrl, redact_credentials};
+use uv_requirements::{GroupsSpecification, RequirementsSource, is_pylock_toml};
 use uv_requirements_txt::RequirementsTxtRequirement;
 use uv_scripts::{Pep723Error, Pep723Item, Pep723Metadata, Pep723Script};
 use uv_settings::{Combine, EnvironmentOptions, FilesystemOptions, Options};
@@ -51,12 +56,13 @@ use uv_static::EnvVars;
 use uv_warnings::{warn_user, warn_user_once};
 use uv_workspace::{DiscoveryOptions, Workspace, WorkspaceCache};
 
-use crate::commands::{ExitStatus, RunCommand, ScriptPath, ToolRunCommand};
+use crate::commands::{DownloadTarget, ExitStatus, RunCommand, ScriptPath, ToolRunCommand};
 use crate::printer::Printer;
 use crate::settings::{
-    CacheSettings, GlobalSettings, PipCheckSettings, PipCompileSettings, PipFreezeSettings,
-    PipInstallSettings, PipListSettings, PipShowSettings, PipSyncSettings, PipUninstallSettings,
-    PublishSettings,
+    CacheSettings, GlobalSettings, PipCheckSettings, PipCompileSettings, PipDownloadSettings,
+    PipFreezeSettings, PipIndexVersionsSettings, PipInstallSettings, PipListSettings,
+    PipShowSettings, PipStubSettings, PipSyncSettings, PipUninstallSettings, PipVerifySettings,
+    PipWheelSettings, PublishSettings,
 };
 
 pub(crate) mod child;
@@ -149,15 +155,29 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
     //    starting from the current directory.
     let workspace_cache = WorkspaceCache::default();
     let filesystem = if let Some(config_file) = cli.top_level.config_file.as_ref() {
-        if config_file
-            .file_name()
-            .is_some_and(|file_name| file_name == "pyproject.toml")
+        if let Some(url) = config_file
+            .to_str()
+            .filter(|url| url.starts_with("https://") || url.starts_with("http://"))
         {
-            warn_user!(
-                "The `--config-file` argument expects to receive a `uv.toml` file, not a `pyproject.toml`. If you're trying to run a command from another project, use the `--project` argument instead."
-            );
+            // Fetch shared configuration using the network settings from the command line and
+            // environment alone, since there's no other configuration to respect.
+            let settings = GlobalSettings::resolve(&cli.top_level.global_args, None, &environment);
+            let cache = Cache::from_settings(
+                cli.top_level.cache_args.no_cache,
+                cli.top_level.cache_args.cache_dir.clone(),
+            )?;
+            Some(read_remote_config_file(url, settings, &cache).await?)
+        } else {
+            if config_file
+                .file_name()
+                .is_some_and(|file_name| file_name == "pyproject.toml")
+            {
+                warn_user!(
+                    "The `--config-file` argument expects to receive a `uv.toml` file, not a `pyproject.toml`. If you're trying to run a command from another project, use the `--project` argument instead."
+                );
+            }
+            Some(FilesystemOptions::from_file(config_file)?)
         }
-        Some(FilesystemOptions::from_file(config_file)?)
     } else if deprecated_isolated || cli.top_level.no_config {
         None
     } else if matches!(&*cli.command, Commands::Tool(_) | Commands::Self_(_)) {
@@ -201,9 +221,24 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 settings.network_settings.connect_timeout,
                 settings.network_settings.retries,
             )
+            .stall_timeout(settings.network_settings.stall_timeout)
+            .retry_max_delay(settings.network_settings.retry_max_delay)
+            .allow_index_failure(settings.network_settings.allow_index_failure)
+            .index_cache_ttl(settings.network_settings.index_cache_ttl)
+            .index_stale_if_error(settings.network_settings.index_stale_if_error)
+            .stale_metadata_report(settings.network_settings.stale_metadata_report.clone())
             .http_proxy(settings.network_settings.http_proxy)
             .https_proxy(settings.network_settings.https_proxy)
-            .no_proxy(settings.network_settings.no_proxy);
+            .all_proxy(settings.network_settings.all_proxy)
+            .no_proxy(settings.network_settings.no_proxy)
+            .credential_helper(settings.network_settings.credential_helper)
+            .attestation_policy(settings.network_settings.attestation_policy)
+            .client_cert(
+                settings.network_settings.client_cert.as_deref(),
+                settings.network_settings.client_key,
+            )?
+            .limit_rate(settings.network_settings.limit_rate)
+            .max_connections_per_host(settings.network_settings.max_connections_per_host);
             Some(
                 RunCommand::from_args(command, client_builder, *module, *script, *gui_script)
                     .await?,
@@ -328,7 +363,10 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
         .map(Pep723Item::metadata)
         .and_then(|metadata| metadata.tool.as_ref())
         .and_then(|tool| tool.uv.as_ref())
-        .map(|uv| Options::simple(uv.globals.clone(), uv.top_level.clone()))
+        .map(|uv| {
+            Options::simple(uv.globals.clone(), uv.top_level.clone())
+                .without_user_only_settings(script.as_ref().and_then(Pep723Item::path))
+        })
         .map(FilesystemOptions::from)
         .combine(filesystem);
 
@@ -349,7 +387,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
         logging::setup_durations(environment.tracing_durations_file.as_ref())?;
     #[cfg(not(feature = "tracing-durations-export"))]
     let durations_layer = None::<tracing_subscriber::layer::Identity>;
-    logging::setup_logging(
+    let _trace_guard = logging::setup_logging(
         match globals.verbose {
             0 => logging::Level::Off,
             1 => logging::Level::DebugUv,
@@ -357,6 +395,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
             3.. => logging::Level::TraceAll,
         },
         durations_layer,
+        cli.top_level.global_args.trace_file.as_deref(),
         globals.color,
         environment.log_context.unwrap_or_default(),
     )?;
@@ -452,6 +491,11 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
 
     anstream::ColorChoice::write_global(globals.color.into());
 
+    // Configure the format in which resolution failures are reported.
+    if let Some(error_format) = cli.top_level.global_args.error_format {
+        commands::set_error_format(error_format);
+    }
+
     miette::set_hook(Box::new(|_| {
         Box::new(
             miette::MietteHandlerOpts::new()
@@ -491,7 +535,8 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
     if cache_settings.no_cache {
         debug!("Disabling the uv cache due to `--no-cache`");
     }
-    let cache = Cache::from_settings(cache_settings.no_cache, cache_settings.cache_dir)?;
+    let cache = Cache::from_settings(cache_settings.no_cache, cache_settings.cache_dir)?
+        .with_scratch_dir(cache_settings.scratch_dir);
 
     // Configure the global network settings.
     let client_builder = BaseClientBuilder::new(
@@ -503,9 +548,24 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
         globals.network_settings.connect_timeout,
         globals.network_settings.retries,
     )
+    .stall_timeout(globals.network_settings.stall_timeout)
+    .retry_max_delay(globals.network_settings.retry_max_delay)
+    .allow_index_failure(globals.network_settings.allow_index_failure)
+    .index_cache_ttl(globals.network_settings.index_cache_ttl)
+    .index_stale_if_error(globals.network_settings.index_stale_if_error)
+    .stale_metadata_report(globals.network_settings.stale_metadata_report.clone())
     .http_proxy(globals.network_settings.http_proxy.clone())
     .https_proxy(globals.network_settings.https_proxy.clone())
-    .no_proxy(globals.network_settings.no_proxy.clone());
+    .all_proxy(globals.network_settings.all_proxy.clone())
+    .no_proxy(globals.network_settings.no_proxy.clone())
+    .credential_helper(globals.network_settings.credential_helper.clone())
+    .attestation_policy(globals.network_settings.attestation_policy.clone())
+    .client_cert(
+        globals.network_settings.client_cert.as_deref(),
+        globals.network_settings.client_key.clone(),
+    )?
+    .limit_rate(globals.network_settings.limit_rate)
+    .max_connections_per_host(globals.network_settings.max_connections_per_host);
 
     match *cli.command {
         Commands::Auth(AuthNamespace {
@@ -591,114 +651,55 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
             args.compat_args.validate()?;
 
             // Resolve the settings from the command-line arguments and workspace configuration.
-            let args = PipCompileSettings::resolve(args, filesystem, environment);
+            let args = PipCompileSettings::resolve(args, filesystem, environment)?;
             show_settings!(args);
 
-            // Check for conflicts between offline and refresh.
-            globals
-                .network_settings
-                .check_refresh_conflict(&args.refresh);
+            Box::pin(run_pip_compile(
+                args,
+                None,
+                &project_dir,
+                globals,
+                client_builder,
+                cache,
+                workspace_cache,
+                printer,
+            ))
+            .await
+        }
+        Commands::Pip(PipNamespace {
+            command:
+                PipCommand::Explain(PipExplainArgs {
+                    package,
+                    compile: args,
+                }),
+        }) => {
+            args.compat_args.validate()?;
 
-            // Initialize the cache.
-            let cache = cache.init().await?.with_refresh(
-                args.refresh
-                    .combine(Refresh::from(args.settings.reinstall.clone()))
-                    .combine(Refresh::from(args.settings.upgrade.clone())),
-            );
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = PipCompileSettings::resolve(args, filesystem, environment)?;
+            show_settings!(args);
 
-            let requirements = args
-                .src_file
-                .into_iter()
-                .map(RequirementsSource::from_requirements_file)
-                .collect::<Result<Vec<_>, _>>()?;
-            let constraints = args
-                .constraints
-                .into_iter()
-                .map(RequirementsSource::from_constraints_txt)
-                .collect::<Result<Vec<_>, _>>()?;
-            let overrides = args
-                .overrides
-                .into_iter()
-                .map(RequirementsSource::from_overrides_txt)
-                .collect::<Result<Vec<_>, _>>()?;
-            let excludes = args
-                .excludes
-                .into_iter()
-                .map(RequirementsSource::from_requirements_txt)
-                .collect::<Result<Vec<_>, _>>()?;
-            let build_constraints = args
-                .build_constraints
-                .into_iter()
-                .map(RequirementsSource::from_constraints_txt)
-                .collect::<Result<Vec<_>, _>>()?;
-            let groups = GroupsSpecification {
-                root: project_dir.to_path_buf(),
-                groups: args.settings.groups,
-            };
+            // A lockfile already records a resolution, so explain it as-is rather than resolving.
+            if let [src_file] = args.src_file.as_slice() {
+                if src_file
+                    .file_name()
+                    .and_then(|name| name.to_str())
+                    .is_some_and(|name| name == "uv.lock" || is_pylock_toml(name))
+                {
+                    return commands::pip_explain_lock(src_file, &package, printer).await;
+                }
+            }
 
-            commands::pip_compile(
-                &requirements,
-                &constraints,
-                &overrides,
-                &excludes,
-                &build_constraints,
-                args.constraints_from_workspace,
-                args.overrides_from_workspace,
-                args.excludes_from_workspace,
-                args.build_constraints_from_workspace,
-                args.environments,
-                args.settings.extras,
-                groups,
-                args.settings.output_file.as_deref(),
-                args.format,
-                args.settings.resolution,
-                args.settings.prerelease,
-                args.settings.fork_strategy,
-                args.settings.dependency_mode,
-                args.settings.upgrade,
-                args.settings.generate_hashes,
-                args.settings.no_emit_package,
-                args.settings.no_strip_extras,
-                args.settings.no_strip_markers,
-                !args.settings.no_annotate,
-                !args.settings.no_header,
-                args.settings.custom_compile_command,
-                args.settings.emit_index_url,
-                args.settings.emit_find_links,
-                args.settings.emit_build_options,
-                args.settings.emit_marker_expression,
-                args.settings.emit_index_annotation,
-                args.settings.index_locations,
-                args.settings.index_strategy,
-                args.settings.torch_backend,
-                args.settings.dependency_metadata,
-                args.settings.keyring_provider,
-                &client_builder.subcommand(vec!["pip".to_owned(), "compile".to_owned()]),
-                args.settings.config_setting,
-                args.settings.config_settings_package,
-                args.settings.build_isolation.clone(),
-                &args.settings.extra_build_dependencies,
-                &args.settings.extra_build_variables,
-                args.settings.build_options,
-                args.settings.install_mirrors,
-                args.settings.python_version,
-                args.settings.python_platform,
-                globals.python_downloads,
-                args.settings.universal,
-                args.settings.exclude_newer,
-                args.settings.sources,
-                args.settings.annotation_style,
-                args.settings.link_mode,
-                args.settings.python,
-                args.settings.system,
-                globals.python_preference,
-                globals.concurrency,
-                globals.quiet > 0,
+            Box::pin(run_pip_compile(
+                args,
+                Some(&package),
+                &project_dir,
+                globals,
+                client_builder,
                 cache,
                 workspace_cache,
                 printer,
-                globals.preview,
-            )
+            ))
             .await
         }
         Commands::Pip(PipNamespace {
@@ -732,6 +733,11 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 .into_iter()
                 .map(RequirementsSource::from_constraints_txt)
                 .collect::<Result<Vec<_>, _>>()?;
+            let overlay = args
+                .overlay
+                .into_iter()
+                .map(RequirementsSource::from_requirements_file)
+                .collect::<Result<Vec<_>, _>>()?;
             let build_constraints = args
                 .build_constraints
                 .into_iter()
@@ -742,9 +748,10 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 groups: args.settings.groups,
             };
 
-            commands::pip_sync(
+            Box::pin(commands::pip_sync(
                 &requirements,
                 &constraints,
+                &overlay,
                 &build_constraints,
                 &args.settings.extras,
                 &groups,
@@ -752,6 +759,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.settings.link_mode,
                 args.settings.compile_bytecode,
                 args.settings.hash_checking,
+                args.allow_unhashed,
                 args.settings.index_locations,
                 args.settings.index_strategy,
                 args.settings.torch_backend,
@@ -778,14 +786,23 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.settings.target,
                 args.settings.prefix,
                 args.settings.sources,
+                args.settings.excluded_packages,
+                args.settings.resolution_priority,
+                args.settings.max_backtracks,
+                args.settings.memory_limit,
+                args.settings.allow_yanked,
+                args.settings.protected_packages,
                 globals.python_preference,
                 globals.concurrency,
                 cache,
                 workspace_cache,
                 args.dry_run,
+                args.only_uv_managed,
+                args.verify_record,
+                args.reinstall_if_python_changed,
                 printer,
                 globals.preview,
-            )
+            ))
             .await
         }
         Commands::Pip(PipNamespace {
@@ -893,6 +910,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
 
             Box::pin(commands::pip_install(
                 &requirements,
+                &args.for_import,
                 &constraints,
                 &overrides,
                 &excludes,
@@ -905,6 +923,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 &groups,
                 args.settings.resolution,
                 args.settings.prerelease,
+                args.settings.prerelease_packages,
                 args.settings.dependency_mode,
                 args.settings.upgrade,
                 args.settings.index_locations,
@@ -917,6 +936,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.settings.link_mode,
                 args.settings.compile_bytecode,
                 args.settings.hash_checking,
+                args.allow_unhashed,
                 globals.installer_metadata,
                 &args.settings.config_setting,
                 &args.settings.config_settings_package,
@@ -932,6 +952,12 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.settings.strict,
                 args.settings.exclude_newer,
                 args.settings.sources,
+                args.settings.excluded_packages,
+                args.settings.resolution_priority,
+                args.settings.max_backtracks,
+                args.settings.memory_limit,
+                args.settings.allow_yanked,
+                args.settings.protected_packages,
                 args.settings.python,
                 args.settings.system,
                 args.settings.break_system_packages,
@@ -942,6 +968,214 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 cache,
                 workspace_cache,
                 args.dry_run,
+                args.only_uv_managed,
+                args.verify_record,
+                printer,
+                globals.preview,
+            ))
+            .await
+        }
+        Commands::Pip(PipNamespace {
+            command: PipCommand::Download(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = PipDownloadSettings::resolve(args, filesystem, environment);
+            show_settings!(args);
+
+            // Check for conflicts between offline and refresh.
+            globals
+                .network_settings
+                .check_refresh_conflict(&args.refresh);
+
+            // Initialize the cache.
+            let cache = cache.init().await?.with_refresh(
+                args.refresh
+                    .combine(Refresh::from(args.settings.reinstall.clone()))
+                    .combine(Refresh::from(args.settings.upgrade.clone())),
+            );
+
+            let mut requirements = Vec::with_capacity(args.package.len() + args.requirements.len());
+            for package in args.package {
+                requirements.push(RequirementsSource::from_package_argument(&package)?);
+            }
+            requirements.extend(
+                args.requirements
+                    .into_iter()
+                    .map(RequirementsSource::from_requirements_file)
+                    .collect::<Result<Vec<_>, _>>()?,
+            );
+            let constraints = args
+                .constraints
+                .into_iter()
+                .map(RequirementsSource::from_constraints_txt)
+                .collect::<Result<Vec<_>, _>>()?;
+            let overrides = args
+                .overrides
+                .into_iter()
+                .map(RequirementsSource::from_overrides_txt)
+                .collect::<Result<Vec<_>, _>>()?;
+            let build_constraints = args
+                .build_constraints
+                .into_iter()
+                .map(RequirementsSource::from_constraints_txt)
+                .collect::<Result<Vec<_>, _>>()?;
+            let groups = GroupsSpecification {
+                root: project_dir.to_path_buf(),
+                groups: args.settings.groups,
+            };
+            let dest = args.dest.unwrap_or_else(|| CWD.to_path_buf());
+
+            Box::pin(commands::pip_download(
+                &requirements,
+                &constraints,
+                &overrides,
+                &build_constraints,
+                args.constraints_from_workspace,
+                args.overrides_from_workspace,
+                args.excludes_from_workspace,
+                args.build_constraints_from_workspace,
+                &args.settings.extras,
+                &groups,
+                &dest,
+                DownloadTarget::Archives,
+                args.settings.resolution,
+                args.settings.prerelease,
+                args.settings.prerelease_packages,
+                args.settings.fork_strategy,
+                args.settings.dependency_mode,
+                args.settings.index_locations,
+                args.settings.index_strategy,
+                args.settings.dependency_metadata,
+                args.settings.keyring_provider,
+                &client_builder.subcommand(vec!["pip".to_owned(), "download".to_owned()]),
+                &args.settings.config_setting,
+                &args.settings.config_settings_package,
+                args.settings.build_isolation.clone(),
+                &args.settings.extra_build_dependencies,
+                &args.settings.extra_build_variables,
+                args.settings.build_options,
+                args.settings.install_mirrors,
+                args.settings.python_version,
+                args.settings.python_platform,
+                globals.python_downloads,
+                args.settings.exclude_newer,
+                args.settings.sources,
+                args.settings.excluded_packages,
+                args.settings.resolution_priority,
+                args.settings.max_backtracks,
+                args.settings.memory_limit,
+                args.settings.allow_yanked,
+                args.settings.hash_checking,
+                args.settings.link_mode,
+                args.settings.python,
+                args.settings.system,
+                globals.python_preference,
+                globals.concurrency,
+                cache,
+                workspace_cache,
+                printer,
+                globals.preview,
+            ))
+            .await
+        }
+        Commands::Pip(PipNamespace {
+            command: PipCommand::Wheel(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = PipWheelSettings::resolve(args, filesystem, environment);
+            show_settings!(args);
+
+            // Check for conflicts between offline and refresh.
+            globals
+                .network_settings
+                .check_refresh_conflict(&args.refresh);
+
+            // Initialize the cache.
+            let cache = cache.init().await?.with_refresh(
+                args.refresh
+                    .combine(Refresh::from(args.settings.reinstall.clone()))
+                    .combine(Refresh::from(args.settings.upgrade.clone())),
+            );
+
+            let mut requirements = Vec::with_capacity(args.package.len() + args.requirements.len());
+            for package in args.package {
+                requirements.push(RequirementsSource::from_package_argument(&package)?);
+            }
+            requirements.extend(
+                args.requirements
+                    .into_iter()
+                    .map(RequirementsSource::from_requirements_file)
+                    .collect::<Result<Vec<_>, _>>()?,
+            );
+            let constraints = args
+                .constraints
+                .into_iter()
+                .map(RequirementsSource::from_constraints_txt)
+                .collect::<Result<Vec<_>, _>>()?;
+            let overrides = args
+                .overrides
+                .into_iter()
+                .map(RequirementsSource::from_overrides_txt)
+                .collect::<Result<Vec<_>, _>>()?;
+            let build_constraints = args
+                .build_constraints
+                .into_iter()
+                .map(RequirementsSource::from_constraints_txt)
+                .collect::<Result<Vec<_>, _>>()?;
+            let groups = GroupsSpecification {
+                root: project_dir.to_path_buf(),
+                groups: args.settings.groups,
+            };
+            let wheel_dir = args.wheel_dir.unwrap_or_else(|| CWD.to_path_buf());
+
+            Box::pin(commands::pip_download(
+                &requirements,
+                &constraints,
+                &overrides,
+                &build_constraints,
+                args.constraints_from_workspace,
+                args.overrides_from_workspace,
+                args.excludes_from_workspace,
+                args.build_constraints_from_workspace,
+                &args.settings.extras,
+                &groups,
+                &wheel_dir,
+                DownloadTarget::Wheels,
+                args.settings.resolution,
+                args.settings.prerelease,
+                args.settings.prerelease_packages,
+                args.settings.fork_strategy,
+                args.settings.dependency_mode,
+                args.settings.index_locations,
+                args.settings.index_strategy,
+                args.settings.dependency_metadata,
+                args.settings.keyring_provider,
+                &client_builder.subcommand(vec!["pip".to_owned(), "wheel".to_owned()]),
+                &args.settings.config_setting,
+                &args.settings.config_settings_package,
+                args.settings.build_isolation.clone(),
+                &args.settings.extra_build_dependencies,
+                &args.settings.extra_build_variables,
+                args.settings.build_options,
+                args.settings.install_mirrors,
+                args.settings.python_version,
+                args.settings.python_platform,
+                globals.python_downloads,
+                args.settings.exclude_newer,
+                args.settings.sources,
+                args.settings.excluded_packages,
+                args.settings.resolution_priority,
+                args.settings.max_backtracks,
+                args.settings.memory_limit,
+                args.settings.allow_yanked,
+                args.settings.hash_checking,
+                args.settings.link_mode,
+                args.settings.python,
+                args.settings.system,
+                globals.python_preference,
+                globals.concurrency,
+                cache,
+                workspace_cache,
                 printer,
                 globals.preview,
             ))
@@ -978,6 +1212,32 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.settings.keyring_provider,
                 &client_builder.subcommand(vec!["pip".to_owned(), "uninstall".to_owned()]),
                 args.dry_run,
+                args.only_uv_managed,
+                &args.settings.protected_packages,
+                printer,
+                globals.preview,
+            )
+            .await
+        }
+        Commands::Pip(PipNamespace {
+            command: PipCommand::Stub(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = PipStubSettings::resolve(args, filesystem, environment);
+            show_settings!(args);
+
+            // Initialize the cache.
+            let cache = cache.init().await?;
+
+            commands::pip_stub(
+                &args.package,
+                args.settings.python,
+                args.settings.system,
+                args.settings.break_system_packages,
+                args.settings.target,
+                args.settings.prefix,
+                &cache,
+                args.dry_run,
                 printer,
                 globals.preview,
             )
@@ -1022,9 +1282,11 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
             commands::pip_list(
                 args.editable,
                 &args.exclude,
+                args.not_required,
                 &args.format,
                 args.outdated,
                 args.settings.prerelease,
+                &args.settings.prerelease_packages,
                 args.settings.index_locations,
                 args.settings.index_strategy,
                 args.settings.keyring_provider,
@@ -1060,6 +1322,8 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.settings.target,
                 args.settings.prefix,
                 args.files,
+                args.entry_points,
+                args.format,
                 &cache,
                 printer,
                 globals.preview,
@@ -1109,6 +1373,8 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
             let cache = cache.init().await?;
 
             commands::pip_check(
+                args.format,
+                args.severity,
                 args.settings.python.as_deref(),
                 args.settings.system,
                 args.settings.python_version.as_ref(),
@@ -1118,6 +1384,69 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 globals.preview,
             )
         }
+        Commands::Pip(PipNamespace {
+            command: PipCommand::Verify(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = PipVerifySettings::resolve(args, filesystem, environment);
+            show_settings!(args);
+
+            // Initialize the cache.
+            let cache = cache.init().await?;
+
+            commands::pip_verify(
+                &args.package,
+                args.format,
+                args.settings.python.as_deref(),
+                args.settings.system,
+                &cache,
+                printer,
+                globals.preview,
+            )
+        }
+        Commands::Pip(PipNamespace {
+            command:
+                PipCommand::Index(PipIndexNamespace {
+                    command: PipIndexCommand::Versions(args),
+                }),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = PipIndexVersionsSettings::resolve(args, filesystem, environment);
+            show_settings!(args);
+
+            // Initialize the cache.
+            let cache = cache.init().await?;
+
+            commands::pip_index_versions(
+                &args.package,
+                args.format,
+                args.settings.index_locations,
+                args.settings.index_strategy,
+                args.settings.keyring_provider,
+                &client_builder.subcommand(vec![
+                    "pip".to_owned(),
+                    "index".to_owned(),
+                    "versions".to_owned(),
+                ]),
+                globals.concurrency,
+                &args.settings.exclude_newer,
+                &cache,
+                printer,
+            )
+            .await
+        }
+        Commands::Pip(PipNamespace {
+            command: PipCommand::Hash(args),
+        }) => {
+            show_settings!(args);
+
+            let algorithms = args
+                .algorithm
+                .into_iter()
+                .map(HashAlgorithm::from)
+                .collect::<Vec<_>>();
+            commands::pip_hash(&args.paths, &algorithms, printer).await
+        }
         Commands::Pip(PipNamespace {
             command: PipCommand::Debug(_),
         }) => Err(anyhow!(
@@ -1136,12 +1465,95 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
             show_settings!(args);
             commands::cache_prune(args.ci, args.force, cache, printer).await
         }
+        Commands::Cache(CacheNamespace {
+            command: CacheCommand::Verify(args),
+        }) => {
+            show_settings!(args);
+            commands::cache_verify(args.dry_run, args.force, cache, printer, globals.preview).await
+        }
+        Commands::Cache(CacheNamespace {
+            command: CacheCommand::Export(args),
+        }) => {
+            show_settings!(args);
+
+            // Initialize the cache.
+            let cache = cache.init().await?;
+
+            let filter = args
+                .filter
+                .into_iter()
+                .map(RequirementsSource::from_requirements_file)
+                .collect::<Result<Vec<_>, _>>()?;
+
+            commands::cache_export(
+                &args.output,
+                &filter,
+                &client_builder.subcommand(vec!["cache".to_owned(), "export".to_owned()]),
+                &cache,
+                printer,
+                globals.preview,
+            )
+            .await
+        }
+        Commands::Cache(CacheNamespace {
+            command: CacheCommand::Import(args),
+        }) => {
+            show_settings!(args);
+
+            // Initialize the cache.
+            let cache = cache.init().await?;
+
+            commands::cache_import(&args.bundle, cache, printer, globals.preview).await
+        }
         Commands::Cache(CacheNamespace {
             command: CacheCommand::Dir,
         }) => commands::cache_dir(&cache, printer),
         Commands::Cache(CacheNamespace {
             command: CacheCommand::Size(args),
         }) => commands::cache_size(&cache, args.human, printer, globals.preview),
+        Commands::Cache(CacheNamespace {
+            command: CacheCommand::List(args),
+        }) => commands::cache_list(&cache, &args.package, printer, globals.preview),
+        Commands::Cache(CacheNamespace {
+            command: CacheCommand::Warm(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::CacheWarmSettings::resolve(*args, filesystem, environment);
+            show_settings!(args);
+
+            // Initialize the cache.
+            let cache = cache.init().await?;
+
+            let requirements = args
+                .requirements
+                .into_iter()
+                .map(RequirementsSource::from_requirements_file)
+                .collect::<Result<Vec<_>, _>>()?;
+            let constraints = args
+                .constraints
+                .into_iter()
+                .map(RequirementsSource::from_constraints_txt)
+                .collect::<Result<Vec<_>, _>>()?;
+
+            Box::pin(commands::cache_warm(
+                &requirements,
+                &constraints,
+                args.constraints_from_workspace,
+                args.build_wheels,
+                args.python_versions,
+                args.python_platforms,
+                args.settings,
+                &client_builder.subcommand(vec!["cache".to_owned(), "warm".to_owned()]),
+                globals.python_downloads,
+                globals.python_preference,
+                globals.concurrency,
+                cache,
+                workspace_cache,
+                printer,
+                globals.preview,
+            ))
+            .await
+        }
         Commands::Build(args) => {
             // Resolve the settings from the command-line arguments and workspace configuration.
             let args = settings::BuildSettings::resolve(args, filesystem, environment);
@@ -1910,6 +2322,48 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 commands::list(&project_dir, args.paths, &workspace_cache, printer).await
             }
         },
+        Commands::Wheel(WheelNamespace {
+            command: WheelCommand::Retag(args),
+        }) => commands::wheel_retag(
+            &args.wheel,
+            args.python_tag.as_deref(),
+            args.abi_tag.as_deref(),
+            args.platform_tag.as_deref(),
+            args.out_dir.as_deref(),
+            args.remove,
+            args.force,
+            printer,
+            globals.preview,
+        ),
+        Commands::Index(IndexNamespace {
+            command: IndexCommand::Build(args),
+        }) => commands::index_build(
+            &args.path,
+            args.out_dir.as_deref(),
+            printer,
+            globals.preview,
+        ),
+        Commands::Index(IndexNamespace {
+            command: IndexCommand::Ping(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::IndexPingSettings::resolve(*args, filesystem, environment);
+            show_settings!(args);
+
+            // Initialize the cache.
+            let cache = cache.init().await?;
+
+            commands::index_ping(
+                &args.package,
+                &args.settings.index_locations,
+                args.settings.keyring_provider,
+                &client_builder.subcommand(vec!["index".to_owned(), "ping".to_owned()]),
+                cache,
+                printer,
+                globals.preview,
+            )
+            .await
+        }
         Commands::BuildBackend { command } => spawn_blocking(move || match command {
             BuildBackendCommand::BuildSdist { sdist_directory } => {
                 commands::build_backend::build_sdist(&sdist_directory)
@@ -1949,6 +2403,141 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
     }
 }
 
+/// Run a `pip compile` or, if a package is provided, a `pip explain` command.
+async fn run_pip_compile(
+    args: PipCompileSettings,
+    explain: Option<&PackageName>,
+    project_dir: &Path,
+    globals: GlobalSettings,
+    client_builder: BaseClientBuilder<'_>,
+    cache: Cache,
+    workspace_cache: WorkspaceCache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    // Explanations are written in lieu of the resolution, so there's no graph to emit.
+    let (emit_graph, subcommand) = if explain.is_some() {
+        (None, "explain")
+    } else {
+        (args.emit_graph.as_deref(), "compile")
+    };
+
+    // Check for conflicts between offline and refresh.
+    globals
+        .network_settings
+        .check_refresh_conflict(&args.refresh);
+
+    // Initialize the cache.
+    let cache = cache.init().await?.with_refresh(
+        args.refresh
+            .combine(Refresh::from(args.settings.reinstall.clone()))
+            .combine(Refresh::from(args.settings.upgrade.clone())),
+    );
+
+    let requirements = args
+        .src_file
+        .into_iter()
+        .map(RequirementsSource::from_requirements_file)
+        .collect::<Result<Vec<_>, _>>()?;
+    let constraints = args
+        .constraints
+        .into_iter()
+        .map(RequirementsSource::from_constraints_txt)
+        .collect::<Result<Vec<_>, _>>()?;
+    let overrides = args
+        .overrides
+        .into_iter()
+        .map(RequirementsSource::from_overrides_txt)
+        .collect::<Result<Vec<_>, _>>()?;
+    let excludes = args
+        .excludes
+        .into_iter()
+        .map(RequirementsSource::from_requirements_txt)
+        .collect::<Result<Vec<_>, _>>()?;
+    let build_constraints = args
+        .build_constraints
+        .into_iter()
+        .map(RequirementsSource::from_constraints_txt)
+        .collect::<Result<Vec<_>, _>>()?;
+    let groups = GroupsSpecification {
+        root: project_dir.to_path_buf(),
+        groups: args.settings.groups,
+    };
+
+    commands::pip_compile(
+        &requirements,
+        &constraints,
+        &overrides,
+        &excludes,
+        &build_constraints,
+        args.constraints_from_workspace,
+        args.overrides_from_workspace,
+        args.excludes_from_workspace,
+        args.build_constraints_from_workspace,
+        args.environments,
+        args.settings.extras,
+        groups,
+        args.settings.output_file.as_deref(),
+        args.format,
+        emit_graph,
+        explain,
+        args.settings.resolution,
+        args.settings.prerelease,
+        args.settings.prerelease_packages,
+        args.settings.fork_strategy,
+        args.settings.dependency_mode,
+        args.settings.upgrade,
+        args.minimal_update,
+        args.settings.generate_hashes,
+        args.settings.no_emit_package,
+        args.settings.no_strip_extras,
+        args.settings.no_strip_markers,
+        !args.settings.no_annotate,
+        !args.settings.no_header,
+        args.settings.custom_compile_command,
+        args.settings.emit_index_url,
+        args.settings.emit_find_links,
+        args.settings.emit_build_options,
+        args.settings.emit_marker_expression,
+        args.settings.emit_index_annotation,
+        args.settings.index_locations,
+        args.settings.index_strategy,
+        args.settings.torch_backend,
+        args.settings.dependency_metadata,
+        args.settings.keyring_provider,
+        &client_builder.subcommand(vec!["pip".to_owned(), subcommand.to_owned()]),
+        args.settings.config_setting,
+        args.settings.config_settings_package,
+        args.settings.build_isolation.clone(),
+        &args.settings.extra_build_dependencies,
+        &args.settings.extra_build_variables,
+        args.settings.build_options,
+        args.settings.install_mirrors,
+        args.settings.python_version,
+        args.settings.python_platform,
+        globals.python_downloads,
+        args.settings.universal,
+        args.settings.exclude_newer,
+        args.settings.sources,
+        args.settings.excluded_packages,
+        args.settings.resolution_priority,
+        args.settings.max_backtracks,
+        args.settings.memory_limit,
+        args.settings.allow_yanked,
+        args.settings.annotation_style,
+        args.settings.link_mode,
+        args.settings.python,
+        args.settings.system,
+        globals.python_preference,
+        globals.concurrency,
+        globals.quiet > 0,
+        cache,
+        workspace_cache,
+        printer,
+        globals.preview,
+    )
+    .await
+}
+
 /// Run a [`ProjectCommand`].
 async fn run_project(
     project_command: Box<ProjectCommand>,
@@ -2198,6 +2787,9 @@ async fn run_project(
                 args.lock_check,
                 args.frozen,
                 args.dry_run,
+                args.minimal_update,
+                args.diff,
+                args.emit_graph.as_deref(),
                 args.refresh,
                 args.python,
                 args.install_mirrors,
@@ -2537,6 +3129,87 @@ async fn run_project(
             .boxed_local()
             .await
         }
+        ProjectCommand::Vendor(args) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::VendorSettings::resolve(args, filesystem, environment);
+            show_settings!(args);
+
+            // Check for conflicts between offline and refresh.
+            globals
+                .network_settings
+                .check_refresh_conflict(&args.refresh);
+
+            // Initialize the cache.
+            let cache = cache.init().await?.with_refresh(
+                args.refresh
+                    .combine(Refresh::from(args.settings.upgrade.clone())),
+            );
+
+            Box::pin(commands::vendor(
+                project_dir,
+                args.vendor_dir,
+                args.output_file,
+                args.build_wheels,
+                args.all_packages,
+                args.package,
+                args.extras,
+                args.groups,
+                args.include_header,
+                args.lock_check,
+                args.frozen,
+                args.python,
+                args.install_mirrors,
+                args.settings,
+                client_builder.subcommand(vec!["vendor".to_owned()]),
+                globals.python_preference,
+                globals.python_downloads,
+                globals.concurrency,
+                no_config,
+                &cache,
+                printer,
+                globals.preview,
+            ))
+            .await
+        }
+        ProjectCommand::Licenses(args) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::LicensesSettings::resolve(args, filesystem, environment);
+            show_settings!(args);
+
+            // Check for conflicts between offline and refresh.
+            globals
+                .network_settings
+                .check_refresh_conflict(&args.refresh);
+
+            // Initialize the cache.
+            let cache = cache.init().await?.with_refresh(
+                args.refresh
+                    .combine(Refresh::from(args.settings.upgrade.clone())),
+            );
+
+            Box::pin(commands::licenses(
+                project_dir,
+                args.exclude,
+                args.format,
+                args.notice.as_deref(),
+                args.extras,
+                args.groups,
+                args.lock_check,
+                args.frozen,
+                args.python,
+                args.install_mirrors,
+                args.settings,
+                client_builder.subcommand(vec!["licenses".to_owned()]),
+                globals.python_preference,
+                globals.python_downloads,
+                globals.concurrency,
+                no_config,
+                &cache,
+                printer,
+                globals.preview,
+            ))
+            .await
+        }
         ProjectCommand::Format(args) => {
             // Resolve the settings from the command-line arguments and workspace configuration.
             let args = settings::FormatSettings::resolve(args, filesystem);
@@ -2719,12 +3392,153 @@ where
             eprintln!(
                 "{}: {}",
                 "error".red().bold(),
-                causes.next().unwrap().to_string().trim()
+                redact_credentials(&causes.next().unwrap().to_string()).trim()
             );
             for err in causes {
-                eprintln!("  {}: {}", "Caused by".red().bold(), err.to_string().trim());
+                eprintln!(
+                    "  {}: {}",
+                    "Caused by".red().bold(),
+                    redact_credentials(&err.to_string()).trim()
+                );
             }
             ExitStatus::Error.into()
         }
     }
 }
+
+/// Fetch and parse a `uv.toml` file from a remote URL, as provided via `--config-file`.
+///
+/// The most recently fetched copy of the file is cached, and used in lieu of fetching the file when
+/// offline or when the URL is unreachable.
+async fn read_remote_config_file(
+    url: &str,
+    settings: GlobalSettings,
+    cache: &Cache,
+) -> Result<FilesystemOptions> {
+    let parsed = DisplaySafeUrl::parse(url)?;
+    if parsed.scheme() != "https" {
+        bail!("Remote configuration files must be served over HTTPS: `{parsed}`");
+    }
+
+    let cache_entry = cache.entry(
+        CacheBucket::RemoteConfig,
+        "",
+        format!("{}.toml", cache_digest(&CanonicalUrl::new(&parsed))),
+    );
+
+    if settings.network_settings.connectivity.is_offline() {
+        let content = match fs_err::tokio::read_to_string(cache_entry.path()).await {
+            Ok(content) => content,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+                bail!(
+                    "Configuration file `{parsed}` is not available in the cache, and network access is disabled (i.e., `--offline`)"
+                );
+            }
+            Err(err) => return Err(err.into()),
+        };
+        return Ok(FilesystemOptions::from_remote(url, &content)?);
+    }
+
+    let client = BaseClientBuilder::new(
+        settings.network_settings.connectivity,
+        settings.network_settings.native_tls,
+        settings.network_settings.allow_insecure_host,
+        settings.preview,
+        settings.network_settings.read_timeout,
+        settings.network_settings.connect_timeout,
+        settings.network_settings.retries,
+    )
+    .stall_timeout(settings.network_settings.stall_timeout)
+    .retry_max_delay(settings.network_settings.retry_max_delay)
+    .http_proxy(settings.network_settings.http_proxy)
+    .https_proxy(settings.network_settings.https_proxy)
+    .all_proxy(settings.network_settings.all_proxy)
+    .no_proxy(settings.network_settings.no_proxy)
+    .client_cert(
+        settings.network_settings.client_cert.as_deref(),
+        settings.network_settings.client_key,
+    )?
+    .limit_rate(settings.network_settings.limit_rate)
+    .max_connections_per_host(settings.network_settings.max_connections_per_host)
+    .build();
+
+    let response = client
+        .for_host(&parsed)
+        .get(Url::from(parsed.clone()))
+        .send()
+        .await;
+
+    // If the URL is unreachable, or the server failed to serve the file, fall back to the cached
+    // copy, if any.
+    if should_use_cached_config_file(&response) {
+        if let Ok(content) = fs_err::tokio::read_to_string(cache_entry.path()).await {
+            match &response {
+                Ok(response) => debug!(
+                    "Failed to fetch configuration file `{parsed}`: {}",
+                    response.status()
+                ),
+                Err(err) => debug!("Failed to fetch configuration file `{parsed}`: {err}"),
+            }
+            warn_user!(
+                "Failed to fetch configuration file `{parsed}`; using the previously fetched copy"
+            );
+            return Ok(FilesystemOptions::from_remote(url, &content)?);
+        }
+    }
+
+    let content = response
+        .map_err(anyhow::Error::from)
+        .and_then(|response| response.error_for_status().map_err(anyhow::Error::from))
+        .with_context(|| format!("Failed to fetch configuration file: `{parsed}`"))?
+        .text()
+        .await
+        .with_context(|| format!("Failed to read configuration file: `{parsed}`"))?;
+
+    // Validate the file before caching it.
+    let options = FilesystemOptions::from_remote(url, &content)?;
+    if let Err(err) = write_remote_config_file(&cache_entry, &content).await {
+        debug!("Failed to cache configuration file `{parsed}`: {err}");
+    }
+
+    Ok(options)
+}
+
+/// Returns `true` if a request for a remote configuration file failed in a way that warrants using
+/// the previously fetched copy, i.e., if the server was unreachable or returned a server error.
+fn should_use_cached_config_file<E>(response: &Result<reqwest::Response, E>) -> bool {
+    match response {
+        Ok(response) => response.status().is_server_error(),
+        Err(_) => true,
+    }
+}
+
+/// Write a fetched configuration file to the cache.
+async fn write_remote_config_file(cache_entry: &CacheEntry, content: &str) -> std::io::Result<()> {
+    fs_err::tokio::create_dir_all(cache_entry.dir()).await?;
+    uv_fs::write_atomic(cache_entry.path(), content).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::should_use_cached_config_file;
+
+    fn response(status: u16) -> Result<reqwest::Response, ()> {
+        http::Response::builder()
+            .status(status)
+            .body(String::new())
+            .map(reqwest::Response::from)
+            .map_err(|_| ())
+    }
+
+    #[test]
+    fn cached_config_file_fallback() {
+        // Connection failures and server errors fall back to the cached copy.
+        assert!(should_use_cached_config_file::<()>(&Err(())));
+        assert!(should_use_cached_config_file(&response(500)));
+        assert!(should_use_cached_config_file(&response(503)));
+
+        // Successful responses and client errors do not.
+        assert!(!should_use_cached_config_file(&response(200)));
+        assert!(!should_use_cached_config_file(&response(404)));
+    }
+}
@@ -1,6 +1,8 @@
+use std::path::Path;
 use std::str::FromStr;
 
 use anyhow::Context;
+use tracing_chrome::{ChromeLayerBuilder, FlushGuard, TraceStyle};
 #[cfg(feature = "tracing-durations-export")]
 use tracing_durations_export::{
     DurationsLayer, DurationsLayerBuilder, DurationsLayerDropGuard, plot::PlotConfig,
@@ -13,7 +15,7 @@ use tracing_tree::HierarchicalLayer;
 use tracing_tree::time::Uptime;
 
 use uv_cli::ColorChoice;
-use uv_logging::UvFormat;
+use uv_logging::{RedactingWriter, UvFormat};
 
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
 pub(crate) enum Level {
@@ -30,12 +32,16 @@ pub(crate) enum Level {
 /// The [`Level`] is used to dictate the default filters (which can be overridden by the `RUST_LOG`
 /// environment variable) along with the formatting of the output. For example, [`Level::Verbose`]
 /// includes targets and timestamps, along with all `uv=debug` messages by default.
+///
+/// If a `trace_file` is provided, the spans recorded during the run are also written to it in the
+/// Chrome trace event format. The returned [`FlushGuard`] must be held until the end of the run.
 pub(crate) fn setup_logging(
     level: Level,
     durations_layer: Option<impl Layer<Registry> + Send + Sync>,
+    trace_file: Option<&Path>,
     color: ColorChoice,
     detailed_logging: bool,
-) -> anyhow::Result<()> {
+) -> anyhow::Result<Option<FlushGuard>> {
     // We use directives here to ensure `RUST_LOG` can override them
     let default_directive = match level {
         Level::Off => {
@@ -64,6 +70,28 @@ pub(crate) fn setup_logging(
                 .with_target("", tracing::level_filters::LevelFilter::INFO),
         )
     });
+
+    // Write the spans to a Chrome trace, if requested.
+    let (trace_layer, trace_guard) = if let Some(trace_file) = trace_file {
+        if let Some(parent) = trace_file.parent() {
+            fs_err::create_dir_all(parent).context("Failed to create parent of trace file")?;
+        }
+        let writer = fs_err::File::create(trace_file).context("Failed to create trace file")?;
+        let (layer, guard) = ChromeLayerBuilder::new()
+            .writer(writer)
+            .include_args(true)
+            .trace_style(TraceStyle::Async)
+            .build();
+        let layer = layer.with_filter(
+            // As with the durations, only record spans at the `INFO` level and above.
+            tracing_subscriber::filter::Targets::new()
+                .with_target("", tracing::level_filters::LevelFilter::INFO),
+        );
+        (Some(layer), Some(guard))
+    } else {
+        (None, None)
+    };
+
     let filter = EnvFilter::builder()
         .with_default_directive(default_directive)
         .from_env()
@@ -85,12 +113,16 @@ pub(crate) fn setup_logging(
             ColorChoice::Never => (false, anstream::ColorChoice::Never),
             ColorChoice::Auto => unreachable!("anstream can't return auto as choice"),
         };
-    let writer = std::sync::Mutex::new(anstream::AutoStream::new(std::io::stderr(), color_choice));
+    let writer = std::sync::Mutex::new(RedactingWriter(anstream::AutoStream::new(
+        std::io::stderr(),
+        color_choice,
+    )));
 
     if detailed_logging {
         // Regardless of the tracing level, include the uptime and target for each message.
         tracing_subscriber::registry()
             .with(durations_layer)
+            .with(trace_layer)
             .with(
                 HierarchicalLayer::default()
                     .with_targets(true)
@@ -103,6 +135,7 @@ pub(crate) fn setup_logging(
     } else {
         tracing_subscriber::registry()
             .with(durations_layer)
+            .with(trace_layer)
             .with(
                 tracing_subscriber::fmt::layer()
                     .event_format(UvFormat::default())
@@ -113,7 +146,7 @@ pub(crate) fn setup_logging(
             .init();
     }
 
-    Ok(())
+    Ok(trace_guard)
 }
 
 /// Setup the `TRACING_DURATIONS_FILE` environment variable to enable tracing durations.
@@ -1,5 +1,5 @@
 use std::env::VarError;
-use std::num::NonZeroUsize;
+use std::num::{NonZeroU64, NonZeroUsize};
 use std::path::PathBuf;
 use std::process;
 use std::str::FromStr;
@@ -8,21 +8,25 @@ use std::time::Duration;
 use rustc_hash::FxHashSet;
 
 use crate::commands::{PythonUpgrade, PythonUpgradeSource};
+use uv_attestation::AttestationPolicy;
 use uv_auth::Service;
 use uv_cache::{CacheArgs, Refresh};
 use uv_cli::comma::CommaSeparatedRequirements;
 use uv_cli::{
-    AddArgs, AuditArgs, AuthLoginArgs, AuthLogoutArgs, AuthTokenArgs, ColorChoice, ExternalCommand,
-    GlobalArgs, InitArgs, ListFormat, LockArgs, Maybe, PipCheckArgs, PipCompileArgs, PipFreezeArgs,
-    PipInstallArgs, PipListArgs, PipShowArgs, PipSyncArgs, PipTreeArgs, PipUninstallArgs,
-    PythonFindArgs, PythonInstallArgs, PythonListArgs, PythonListFormat, PythonPinArgs,
-    PythonUninstallArgs, PythonUpgradeArgs, RemoveArgs, RunArgs, SyncArgs, SyncFormat, ToolDirArgs,
-    ToolInstallArgs, ToolListArgs, ToolRunArgs, ToolUninstallArgs, TreeArgs, VenvArgs, VersionArgs,
+    AddArgs, AuditArgs, AuthLoginArgs, AuthLogoutArgs, AuthTokenArgs, CacheWarmArgs, ColorChoice,
+    ExternalCommand, GlobalArgs, IndexPingArgs, InitArgs, LicensesArgs, LicensesFormat, ListFormat,
+    LockArgs, LockDiffFormat, Maybe, PipCheckArgs, PipCheckFormat, PipCheckSeverity,
+    PipCompileArgs, PipDownloadArgs, PipFreezeArgs, PipIndexFormat, PipIndexVersionsArgs,
+    PipInstallArgs, PipListArgs, PipShowArgs, PipShowFormat, PipStubArgs, PipSyncArgs, PipTreeArgs,
+    PipUninstallArgs, PipVerifyArgs, PipVerifyFormat, PipWheelArgs, PrereleaseSpec, PythonFindArgs,
+    PythonInstallArgs, PythonListArgs, PythonListFormat, PythonPinArgs, PythonUninstallArgs,
+    PythonUpgradeArgs, RemoveArgs, RunArgs, SyncArgs, SyncFormat, ToolDirArgs, ToolInstallArgs,
+    ToolListArgs, ToolRunArgs, ToolUninstallArgs, TreeArgs, VendorArgs, VenvArgs, VersionArgs,
     VersionBumpSpec, VersionFormat,
 };
 use uv_cli::{
     AuthorFrom, BuildArgs, ExportArgs, FormatArgs, PublishArgs, PythonDirArgs,
-    ResolverInstallerArgs, ToolUpgradeArgs,
+    ResolverInstallerArgs, StubDist, ToolUpgradeArgs,
     options::{
         Flag, FlagSource, check_conflicts, flag, resolve_flag, resolver_installer_options,
         resolver_options,
@@ -34,7 +38,7 @@ use uv_configuration::{
     ExportFormat, ExtrasSpecification, GitLfsSetting, HashCheckingMode, IndexStrategy,
     InstallOptions, KeyringProviderType, NoBinary, NoBuild, NoSources, PipCompileFormat,
     ProjectBuildBackend, ProxyUrl, Reinstall, RequiredVersion, TargetTriple, TrustedHost,
-    TrustedPublishing, Upgrade, VersionControlSystem,
+    TrustedPublishing, Upgrade, UpgradeStrategy, VersionControlSystem,
 };
 use uv_distribution_types::{
     ConfigSettings, DependencyMetadata, ExtraBuildVariables, Index, IndexLocations, IndexUrl,
@@ -42,14 +46,15 @@ use uv_distribution_types::{
 };
 use uv_install_wheel::LinkMode;
 use uv_normalize::{ExtraName, PackageName, PipGroupName};
-use uv_pep508::{MarkerTree, RequirementOrigin};
+use uv_pep440::VersionSpecifier;
+use uv_pep508::{MarkerExpression, MarkerTree, MarkerValueVersion, RequirementOrigin};
 use uv_preview::Preview;
 use uv_pypi_types::SupportedEnvironments;
 use uv_python::{Prefix, PythonDownloads, PythonPreference, PythonVersion, Target};
 use uv_redacted::DisplaySafeUrl;
 use uv_resolver::{
-    AnnotationStyle, DependencyMode, ExcludeNewer, ExcludeNewerPackage, ForkStrategy,
-    PrereleaseMode, ResolutionMode,
+    AllowYankedEntry, AnnotationStyle, DependencyMode, ExcludeNewer, ExcludeNewerPackage,
+    ForkStrategy, PrereleaseMode, ResolutionMode,
 };
 use uv_settings::{
     Combine, EnvironmentOptions, FilesystemOptions, Options, PipOptions, PublishOptions,
@@ -243,11 +248,24 @@ pub(crate) struct NetworkSettings {
     pub(crate) native_tls: bool,
     pub(crate) http_proxy: Option<ProxyUrl>,
     pub(crate) https_proxy: Option<ProxyUrl>,
+    pub(crate) all_proxy: Option<ProxyUrl>,
     pub(crate) no_proxy: Option<Vec<String>>,
     pub(crate) allow_insecure_host: Vec<TrustedHost>,
     pub(crate) read_timeout: Duration,
     pub(crate) connect_timeout: Duration,
+    pub(crate) stall_timeout: Duration,
     pub(crate) retries: u32,
+    pub(crate) retry_max_delay: Duration,
+    pub(crate) allow_index_failure: bool,
+    pub(crate) index_cache_ttl: Option<Duration>,
+    pub(crate) index_stale_if_error: bool,
+    pub(crate) stale_metadata_report: Option<PathBuf>,
+    pub(crate) credential_helper: Option<String>,
+    pub(crate) attestation_policy: AttestationPolicy,
+    pub(crate) client_cert: Option<PathBuf>,
+    pub(crate) client_key: Option<PathBuf>,
+    pub(crate) limit_rate: Option<NonZeroU64>,
+    pub(crate) max_connections_per_host: Option<NonZeroUsize>,
 }
 
 impl NetworkSettings {
@@ -314,7 +332,18 @@ impl NetworkSettings {
             .collect();
         let http_proxy = workspace.and_then(|workspace| workspace.globals.http_proxy.clone());
         let https_proxy = workspace.and_then(|workspace| workspace.globals.https_proxy.clone());
+        let all_proxy = workspace.and_then(|workspace| workspace.globals.all_proxy.clone());
         let no_proxy = workspace.and_then(|workspace| workspace.globals.no_proxy.clone());
+        let credential_helper =
+            workspace.and_then(|workspace| workspace.globals.credential_helper.clone());
+        let attestation_policy = AttestationPolicy::new(
+            workspace
+                .and_then(|workspace| workspace.globals.require_attestations)
+                .unwrap_or(false),
+            workspace
+                .and_then(|workspace| workspace.globals.trusted_publishers.clone())
+                .unwrap_or_default(),
+        );
 
         Self {
             connectivity,
@@ -322,11 +351,24 @@ impl NetworkSettings {
             native_tls,
             http_proxy,
             https_proxy,
+            all_proxy,
             no_proxy,
             allow_insecure_host,
             read_timeout: environment.http_read_timeout,
             connect_timeout: environment.http_connect_timeout,
+            stall_timeout: environment.http_stall_timeout,
             retries: environment.http_retries,
+            retry_max_delay: environment.http_retry_max_delay,
+            allow_index_failure: args.allow_index_failure,
+            index_cache_ttl: args.index_cache_ttl,
+            index_stale_if_error: args.index_stale_if_error,
+            stale_metadata_report: args.stale_metadata_report.clone(),
+            credential_helper,
+            attestation_policy,
+            client_cert: args.client_cert.clone(),
+            client_key: args.client_key.clone(),
+            limit_rate: args.limit_rate,
+            max_connections_per_host: args.max_connections_per_host,
         }
     }
 
@@ -349,6 +391,7 @@ impl NetworkSettings {
 pub(crate) struct CacheSettings {
     pub(crate) no_cache: bool,
     pub(crate) cache_dir: Option<PathBuf>,
+    pub(crate) scratch_dir: Option<PathBuf>,
 }
 
 impl CacheSettings {
@@ -362,6 +405,9 @@ impl CacheSettings {
             cache_dir: args
                 .cache_dir
                 .or_else(|| workspace.and_then(|workspace| workspace.globals.cache_dir.clone())),
+            scratch_dir: args
+                .scratch_dir
+                .or_else(|| workspace.and_then(|workspace| workspace.globals.scratch_dir.clone())),
         }
     }
 }
@@ -690,6 +736,7 @@ impl RunSettings {
             settings: ResolverInstallerSettings::combine(
                 resolver_installer_options(installer, build),
                 filesystem,
+                &environment,
             ),
             env_file: EnvFile::from_args(env_file, no_env_file),
             install_mirrors: environment
@@ -796,6 +843,7 @@ impl ToolRunSettings {
             .unwrap_or_default();
 
         let mut settings = ResolverInstallerSettings::from(options.clone());
+        settings.resolver.memory_limit = environment.resolver_memory_limit;
         if torch_backend.is_some() {
             settings.resolver.torch_backend = torch_backend;
         }
@@ -918,6 +966,7 @@ impl ToolInstallSettings {
             .unwrap_or_default();
 
         let mut settings = ResolverInstallerSettings::from(options.clone());
+        settings.resolver.memory_limit = environment.resolver_memory_limit;
         if torch_backend.is_some() {
             settings.resolver.torch_backend = torch_backend;
         }
@@ -1009,6 +1058,7 @@ impl ToolUpgradeSettings {
             fork_strategy,
             config_setting,
             config_setting_package: config_settings_package,
+            extra_build_variable,
             no_build_isolation,
             no_build_isolation_package,
             build_isolation,
@@ -1019,6 +1069,7 @@ impl ToolUpgradeSettings {
             no_sources,
             no_sources_package,
             exclude_newer_package,
+            allow_yanked,
             build,
         } = args;
 
@@ -1046,11 +1097,13 @@ impl ToolUpgradeSettings {
             fork_strategy,
             config_setting,
             config_settings_package,
+            extra_build_variable,
             no_build_isolation,
             no_build_isolation_package,
             build_isolation,
             exclude_newer,
             exclude_newer_package,
+            allow_yanked,
             link_mode,
             compile_bytecode,
             no_compile_bytecode,
@@ -1640,6 +1693,7 @@ impl SyncSettings {
         let settings = ResolverInstallerSettings::combine(
             resolver_installer_options(installer, build),
             filesystem,
+            &environment,
         );
 
         let check = flag(check, no_check, "check").unwrap_or_default();
@@ -1721,6 +1775,9 @@ pub(crate) struct LockSettings {
     pub(crate) lock_check: LockCheck,
     pub(crate) frozen: Option<FrozenSource>,
     pub(crate) dry_run: DryRun,
+    pub(crate) minimal_update: bool,
+    pub(crate) diff: Option<LockDiffFormat>,
+    pub(crate) emit_graph: Option<PathBuf>,
     pub(crate) script: Option<PathBuf>,
     pub(crate) python: Option<String>,
     pub(crate) install_mirrors: PythonInstallMirrors,
@@ -1740,6 +1797,10 @@ impl LockSettings {
             locked,
             check_exists,
             dry_run,
+            minimal_update,
+            diff,
+            output_format,
+            emit_graph,
             script,
             resolver,
             build,
@@ -1768,11 +1829,18 @@ impl LockSettings {
         Self {
             lock_check,
             frozen: resolve_frozen(frozen),
-            dry_run: DryRun::from_args(dry_run),
+            dry_run: DryRun::from_args(dry_run || diff),
+            minimal_update,
+            diff: diff.then_some(output_format),
+            emit_graph,
             script,
             python: python.and_then(Maybe::into_option),
             refresh: Refresh::from(refresh),
-            settings: ResolverSettings::combine(resolver_options(resolver, build), filesystem),
+            settings: ResolverSettings::combine(
+                resolver_options(resolver, build),
+                filesystem,
+                &environment,
+            ),
             install_mirrors: environment
                 .install_mirrors
                 .combine(filesystem_install_mirrors),
@@ -1997,6 +2065,7 @@ impl AddSettings {
             settings: ResolverInstallerSettings::combine(
                 resolver_installer_options(installer, build),
                 filesystem,
+                &environment,
             ),
             install_mirrors: environment
                 .install_mirrors
@@ -2096,6 +2165,7 @@ impl RemoveSettings {
             settings: ResolverInstallerSettings::combine(
                 resolver_installer_options(installer, build),
                 filesystem,
+                &environment,
             ),
             install_mirrors: environment
                 .install_mirrors
@@ -2180,6 +2250,7 @@ impl VersionSettings {
             settings: ResolverInstallerSettings::combine(
                 resolver_installer_options(installer, build),
                 filesystem,
+                &environment,
             ),
             install_mirrors: environment
                 .install_mirrors
@@ -2279,7 +2350,11 @@ impl TreeSettings {
             python_version,
             python_platform,
             python: python.and_then(Maybe::into_option),
-            resolver: ResolverSettings::combine(resolver_options(resolver, build), filesystem),
+            resolver: ResolverSettings::combine(
+                resolver_options(resolver, build),
+                filesystem,
+                &environment,
+            ),
             install_mirrors: environment
                 .install_mirrors
                 .combine(filesystem_install_mirrors),
@@ -2321,6 +2396,7 @@ impl ExportSettings {
     ) -> Self {
         let ExportArgs {
             format,
+            sbom,
             all_packages,
             package,
             prune,
@@ -2378,7 +2454,11 @@ impl ExportSettings {
         let no_editable = no_editable || environment.no_editable.value == Some(true);
 
         Self {
-            format,
+            format: if sbom {
+                Some(ExportFormat::CycloneDX1_5)
+            } else {
+                format
+            },
             all_packages,
             package,
             prune,
@@ -2421,7 +2501,217 @@ impl ExportSettings {
             script,
             python: python.and_then(Maybe::into_option),
             refresh: Refresh::from(refresh),
-            settings: ResolverSettings::combine(resolver_options(resolver, build), filesystem),
+            settings: ResolverSettings::combine(
+                resolver_options(resolver, build),
+                filesystem,
+                &environment,
+            ),
+            install_mirrors: environment
+                .install_mirrors
+                .combine(filesystem_install_mirrors),
+        }
+    }
+}
+
+/// The resolved settings to use for a `vendor` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct VendorSettings {
+    pub(crate) vendor_dir: Option<PathBuf>,
+    pub(crate) output_file: Option<PathBuf>,
+    pub(crate) build_wheels: bool,
+    pub(crate) all_packages: bool,
+    pub(crate) package: Vec<PackageName>,
+    pub(crate) extras: ExtrasSpecification,
+    pub(crate) groups: DependencyGroups,
+    pub(crate) include_header: bool,
+    pub(crate) lock_check: LockCheck,
+    pub(crate) frozen: Option<FrozenSource>,
+    pub(crate) python: Option<String>,
+    pub(crate) install_mirrors: PythonInstallMirrors,
+    pub(crate) refresh: Refresh,
+    pub(crate) settings: ResolverSettings,
+}
+
+impl VendorSettings {
+    /// Resolve the [`VendorSettings`] from the CLI and filesystem configuration.
+    pub(crate) fn resolve(
+        args: VendorArgs,
+        filesystem: Option<FilesystemOptions>,
+        environment: EnvironmentOptions,
+    ) -> Self {
+        let VendorArgs {
+            vendor_dir,
+            output_file,
+            build_wheels,
+            all_packages,
+            package,
+            extra,
+            all_extras,
+            no_extra,
+            no_all_extras,
+            dev,
+            no_dev,
+            only_dev,
+            group,
+            no_group,
+            no_default_groups,
+            only_group,
+            all_groups,
+            header,
+            no_header,
+            locked,
+            frozen: frozen_cli,
+            resolver,
+            build_options,
+            refresh,
+            python,
+        } = args;
+        let filesystem_install_mirrors = filesystem
+            .clone()
+            .map(|fs| fs.install_mirrors.clone())
+            .unwrap_or_default();
+
+        // Resolve flags from CLI and environment variables.
+        let locked = resolve_flag(locked, "locked", environment.locked);
+        let frozen = resolve_flag(frozen_cli, "frozen", environment.frozen);
+
+        // Check for conflicts between locked and frozen.
+        check_conflicts(locked, frozen);
+
+        let dev = dev || environment.dev.value == Some(true);
+        let no_dev = no_dev || environment.no_dev.value == Some(true);
+
+        Self {
+            vendor_dir,
+            output_file,
+            build_wheels,
+            all_packages,
+            package,
+            extras: ExtrasSpecification::from_args(
+                extra.unwrap_or_default(),
+                no_extra,
+                false,
+                vec![],
+                flag(all_extras, no_all_extras, "all-extras").unwrap_or_default(),
+            ),
+            groups: DependencyGroups::from_args(
+                dev,
+                no_dev,
+                only_dev,
+                group,
+                no_group,
+                no_default_groups,
+                only_group,
+                all_groups,
+            ),
+            include_header: flag(header, no_header, "header").unwrap_or(true),
+            lock_check: resolve_lock_check(locked),
+            frozen: resolve_frozen(frozen),
+            python: python.and_then(Maybe::into_option),
+            refresh: Refresh::from(refresh),
+            settings: ResolverSettings::combine(
+                resolver_options(resolver, build_options),
+                filesystem,
+                &environment,
+            ),
+            install_mirrors: environment
+                .install_mirrors
+                .combine(filesystem_install_mirrors),
+        }
+    }
+}
+
+/// The resolved settings to use for a `licenses` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct LicensesSettings {
+    pub(crate) exclude: Vec<PackageName>,
+    pub(crate) format: LicensesFormat,
+    pub(crate) notice: Option<PathBuf>,
+    pub(crate) extras: ExtrasSpecification,
+    pub(crate) groups: DependencyGroups,
+    pub(crate) lock_check: LockCheck,
+    pub(crate) frozen: Option<FrozenSource>,
+    pub(crate) python: Option<String>,
+    pub(crate) install_mirrors: PythonInstallMirrors,
+    pub(crate) refresh: Refresh,
+    pub(crate) settings: ResolverSettings,
+}
+
+impl LicensesSettings {
+    /// Resolve the [`LicensesSettings`] from the CLI and filesystem configuration.
+    pub(crate) fn resolve(
+        args: LicensesArgs,
+        filesystem: Option<FilesystemOptions>,
+        environment: EnvironmentOptions,
+    ) -> Self {
+        let LicensesArgs {
+            exclude,
+            format,
+            notice,
+            extra,
+            all_extras,
+            no_extra,
+            no_all_extras,
+            dev,
+            no_dev,
+            only_dev,
+            group,
+            no_group,
+            no_default_groups,
+            only_group,
+            all_groups,
+            locked,
+            frozen: frozen_cli,
+            resolver,
+            build_options,
+            refresh,
+            python,
+        } = args;
+        let filesystem_install_mirrors = filesystem
+            .clone()
+            .map(|fs| fs.install_mirrors.clone())
+            .unwrap_or_default();
+
+        // Resolve flags from CLI and environment variables.
+        let locked = resolve_flag(locked, "locked", environment.locked);
+        let frozen = resolve_flag(frozen_cli, "frozen", environment.frozen);
+
+        // Check for conflicts between locked and frozen.
+        check_conflicts(locked, frozen);
+
+        let dev = dev || environment.dev.value == Some(true);
+        let no_dev = no_dev || environment.no_dev.value == Some(true);
+
+        Self {
+            exclude,
+            format,
+            notice,
+            extras: ExtrasSpecification::from_args(
+                extra.unwrap_or_default(),
+                no_extra,
+                false,
+                vec![],
+                flag(all_extras, no_all_extras, "all-extras").unwrap_or_default(),
+            ),
+            groups: DependencyGroups::from_args(
+                dev,
+                no_dev,
+                only_dev,
+                group,
+                no_group,
+                no_default_groups,
+                only_group,
+                all_groups,
+            ),
+            lock_check: resolve_lock_check(locked),
+            frozen: resolve_frozen(frozen),
+            python: python.and_then(Maybe::into_option),
+            refresh: Refresh::from(refresh),
+            settings: ResolverSettings::combine(
+                resolver_options(resolver, build_options),
+                filesystem,
+                &environment,
+            ),
             install_mirrors: environment
                 .install_mirrors
                 .combine(filesystem_install_mirrors),
@@ -2429,7 +2719,6 @@ impl ExportSettings {
     }
 }
 
-/// The resolved settings to use for a `format` invocation.
 #[derive(Debug, Clone)]
 pub(crate) struct FormatSettings {
     pub(crate) check: bool,
@@ -2547,10 +2836,14 @@ impl AuditSettings {
             frozen: resolve_frozen(frozen),
             python_version,
             python_platform,
+            settings: ResolverSettings::combine(
+                resolver_options(resolver, build),
+                filesystem,
+                &environment,
+            ),
             install_mirrors: environment
                 .install_mirrors
                 .combine(filesystem_install_mirrors),
-            settings: ResolverSettings::combine(resolver_options(resolver, build), filesystem),
         }
     }
 }
@@ -2569,6 +2862,8 @@ pub(crate) struct PipCompileSettings {
     pub(crate) excludes_from_workspace: Vec<PackageName>,
     pub(crate) build_constraints_from_workspace: Vec<Requirement>,
     pub(crate) environments: SupportedEnvironments,
+    pub(crate) minimal_update: bool,
+    pub(crate) emit_graph: Option<PathBuf>,
     pub(crate) refresh: Refresh,
     pub(crate) settings: PipSettings,
 }
@@ -2576,10 +2871,10 @@ pub(crate) struct PipCompileSettings {
 impl PipCompileSettings {
     /// Resolve the [`PipCompileSettings`] from the CLI and filesystem configuration.
     pub(crate) fn resolve(
-        args: PipCompileArgs,
+        args: Box<PipCompileArgs>,
         filesystem: Option<FilesystemOptions>,
         environment: EnvironmentOptions,
-    ) -> Self {
+    ) -> anyhow::Result<Self> {
         let PipCompileArgs {
             src_file,
             constraints,
@@ -2595,6 +2890,7 @@ impl PipCompileSettings {
             group,
             output_file,
             format,
+            emit_graph,
             no_strip_extras,
             strip_extras,
             no_strip_markers,
@@ -2606,6 +2902,8 @@ impl PipCompileSettings {
             annotation_style,
             custom_compile_command,
             resolver,
+            upgrade_strategy,
+            minimal_update,
             python,
             system,
             no_system,
@@ -2616,6 +2914,7 @@ impl PipCompileSettings {
             no_binary,
             only_binary,
             python_version,
+            python_versions,
             python_platform,
             universal,
             no_universal,
@@ -2632,7 +2931,7 @@ impl PipCompileSettings {
             no_emit_index_annotation,
             torch_backend,
             compat_args: _,
-        } = args;
+        } = *args;
 
         let constraints_from_workspace = if let Some(configuration) = &filesystem {
             configuration
@@ -2691,7 +2990,47 @@ impl PipCompileSettings {
             SupportedEnvironments::default()
         };
 
-        Self {
+        // If multiple Python versions were requested, perform a universal resolution limited to
+        // those versions, using the lowest as the lower bound.
+        let mut universal = flag(universal, no_universal, "universal");
+        let (python_version, environments) = if python_versions.is_empty() {
+            (python_version, environments)
+        } else {
+            if !environments.is_empty() {
+                anyhow::bail!(
+                    "`--python-versions` cannot be used with the `environments` setting"
+                );
+            }
+            universal = Some(true);
+            let markers = python_versions
+                .iter()
+                .map(|python_version| {
+                    let (key, version) = if python_version.patch().is_some() {
+                        (
+                            MarkerValueVersion::PythonFullVersion,
+                            python_version.python_full_version(),
+                        )
+                    } else {
+                        (
+                            MarkerValueVersion::PythonVersion,
+                            python_version.python_version(),
+                        )
+                    };
+                    MarkerTree::expression(MarkerExpression::Version {
+                        key,
+                        specifier: VersionSpecifier::equals_version(version),
+                    })
+                })
+                .collect();
+            (
+                python_versions
+                    .into_iter()
+                    .min_by(|left, right| left.version().cmp(right.version())),
+                SupportedEnvironments::from_markers(markers),
+            )
+        };
+
+        Ok(Self {
             format,
             src_file,
             constraints: constraints
@@ -2715,6 +3054,8 @@ impl PipCompileSettings {
             excludes_from_workspace,
             build_constraints_from_workspace,
             environments,
+            minimal_update,
+            emit_graph,
             refresh: Refresh::from(refresh),
             settings: PipSettings::combine(
                 PipOptions {
@@ -2736,7 +3077,7 @@ impl PipCompileSettings {
                     generate_hashes: flag(generate_hashes, no_generate_hashes, "generate-hashes"),
                     python_version,
                     python_platform,
-                    universal: flag(universal, no_universal, "universal"),
+                    universal,
                     no_emit_package,
                     emit_index_url: flag(emit_index_url, no_emit_index_url, "emit-index-url"),
                     emit_find_links: flag(emit_find_links, no_emit_find_links, "emit-find-links"),
@@ -2761,8 +3102,9 @@ impl PipCompileSettings {
                 },
                 filesystem,
                 environment,
-            ),
-        }
+            )
+            .with_upgrade_strategy(upgrade_strategy),
+        })
     }
 }
 
@@ -2771,8 +3113,13 @@ impl PipCompileSettings {
 pub(crate) struct PipSyncSettings {
     pub(crate) src_file: Vec<PathBuf>,
     pub(crate) constraints: Vec<PathBuf>,
+    pub(crate) overlay: Vec<PathBuf>,
     pub(crate) build_constraints: Vec<PathBuf>,
     pub(crate) dry_run: DryRun,
+    pub(crate) only_uv_managed: bool,
+    pub(crate) verify_record: bool,
+    pub(crate) reinstall_if_python_changed: bool,
+    pub(crate) allow_unhashed: Vec<PackageName>,
     pub(crate) refresh: Refresh,
     pub(crate) settings: PipSettings,
 }
@@ -2787,6 +3134,7 @@ impl PipSyncSettings {
         let PipSyncArgs {
             src_file,
             constraints,
+            overlay,
             build_constraints,
             extra,
             all_extras,
@@ -2796,6 +3144,7 @@ impl PipSyncSettings {
             refresh,
             require_hashes,
             no_require_hashes,
+            allow_unhashed,
             verify_hashes,
             no_verify_hashes,
             python,
@@ -2816,6 +3165,9 @@ impl PipSyncSettings {
             strict,
             no_strict,
             dry_run,
+            only_uv_managed,
+            verify_record,
+            reinstall_if_python_changed,
             torch_backend,
             compat_args: _,
         } = *args;
@@ -2826,11 +3178,16 @@ impl PipSyncSettings {
                 .into_iter()
                 .filter_map(Maybe::into_option)
                 .collect(),
+            overlay,
             build_constraints: build_constraints
                 .into_iter()
                 .filter_map(Maybe::into_option)
                 .collect(),
             dry_run: DryRun::from_args(dry_run),
+            only_uv_managed,
+            verify_record,
+            reinstall_if_python_changed,
+            allow_unhashed,
             refresh: Refresh::from(refresh),
             settings: PipSettings::combine(
                 PipOptions {
@@ -2875,11 +3232,15 @@ pub(crate) struct PipInstallSettings {
     pub(crate) package: Vec<String>,
     pub(crate) requirements: Vec<PathBuf>,
     pub(crate) editables: Vec<String>,
+    pub(crate) for_import: Vec<String>,
     pub(crate) constraints: Vec<PathBuf>,
     pub(crate) overrides: Vec<PathBuf>,
     pub(crate) excludes: Vec<PathBuf>,
     pub(crate) build_constraints: Vec<PathBuf>,
     pub(crate) dry_run: DryRun,
+    pub(crate) only_uv_managed: bool,
+    pub(crate) verify_record: bool,
+    pub(crate) allow_unhashed: Vec<PackageName>,
     pub(crate) constraints_from_workspace: Vec<Requirement>,
     pub(crate) overrides_from_workspace: Vec<Requirement>,
     pub(crate) excludes_from_workspace: Vec<PackageName>,
@@ -2900,6 +3261,7 @@ impl PipInstallSettings {
             package,
             requirements,
             editable,
+            for_import,
             constraints,
             overrides,
             excludes,
@@ -2908,12 +3270,14 @@ impl PipInstallSettings {
             all_extras,
             no_all_extras,
             installer,
+            upgrade_strategy,
             refresh,
             no_deps,
             deps,
             group,
             require_hashes,
             no_require_hashes,
+            allow_unhashed,
             verify_hashes,
             no_verify_hashes,
             python,
@@ -2934,6 +3298,8 @@ impl PipInstallSettings {
             strict,
             no_strict,
             dry_run,
+            only_uv_managed,
+            verify_record,
             torch_backend,
             compat_args: _,
         } = args;
@@ -2993,6 +3359,7 @@ impl PipInstallSettings {
             package,
             requirements,
             editables: editable,
+            for_import,
             constraints: constraints
                 .into_iter()
                 .filter_map(Maybe::into_option)
@@ -3010,6 +3377,9 @@ impl PipInstallSettings {
                 .filter_map(Maybe::into_option)
                 .collect(),
             dry_run: DryRun::from_args(dry_run),
+            only_uv_managed,
+            verify_record,
+            allow_unhashed,
             constraints_from_workspace,
             overrides_from_workspace,
             excludes_from_workspace,
@@ -3048,46 +3418,452 @@ impl PipInstallSettings {
                 },
                 filesystem,
                 environment,
-            ),
+            )
+            .with_upgrade_strategy(upgrade_strategy),
         }
     }
 }
 
-/// The resolved settings to use for a `pip uninstall` invocation.
+/// The resolved settings to use for a `pip download` invocation.
 #[derive(Debug, Clone)]
-pub(crate) struct PipUninstallSettings {
+pub(crate) struct PipDownloadSettings {
     pub(crate) package: Vec<String>,
     pub(crate) requirements: Vec<PathBuf>,
-    pub(crate) dry_run: DryRun,
+    pub(crate) constraints: Vec<PathBuf>,
+    pub(crate) overrides: Vec<PathBuf>,
+    pub(crate) build_constraints: Vec<PathBuf>,
+    pub(crate) dest: Option<PathBuf>,
+    pub(crate) constraints_from_workspace: Vec<Requirement>,
+    pub(crate) overrides_from_workspace: Vec<Requirement>,
+    pub(crate) excludes_from_workspace: Vec<PackageName>,
+    pub(crate) build_constraints_from_workspace: Vec<Requirement>,
+    pub(crate) refresh: Refresh,
     pub(crate) settings: PipSettings,
 }
 
-impl PipUninstallSettings {
-    /// Resolve the [`PipUninstallSettings`] from the CLI and filesystem configuration.
+impl PipDownloadSettings {
+    /// Resolve the [`PipDownloadSettings`] from the CLI and filesystem configuration.
     pub(crate) fn resolve(
-        args: PipUninstallArgs,
+        args: PipDownloadArgs,
         filesystem: Option<FilesystemOptions>,
         environment: EnvironmentOptions,
     ) -> Self {
-        let PipUninstallArgs {
+        let PipDownloadArgs {
             package,
             requirements,
+            constraints,
+            overrides,
+            build_constraints,
+            extra,
+            all_extras,
+            no_all_extras,
+            group,
+            dest,
+            resolver,
+            refresh,
+            no_deps,
+            deps,
+            require_hashes,
+            no_require_hashes,
+            verify_hashes,
+            no_verify_hashes,
             python,
-            keyring_provider,
             system,
             no_system,
-            break_system_packages,
-            no_break_system_packages,
-            target,
-            prefix,
-            dry_run,
-            compat_args: _,
+            no_build,
+            build,
+            no_binary,
+            only_binary,
+            python_version,
+            python_platform,
         } = args;
 
-        Self {
-            package,
-            requirements,
-            dry_run: DryRun::from_args(dry_run),
+        let constraints_from_workspace = if let Some(configuration) = &filesystem {
+            configuration
+                .constraint_dependencies
+                .clone()
+                .unwrap_or_default()
+                .into_iter()
+                .map(|requirement| {
+                    Requirement::from(requirement.with_origin(RequirementOrigin::Workspace))
+                })
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        let overrides_from_workspace = if let Some(configuration) = &filesystem {
+            configuration
+                .override_dependencies
+                .clone()
+                .unwrap_or_default()
+                .into_iter()
+                .map(|requirement| {
+                    Requirement::from(requirement.with_origin(RequirementOrigin::Workspace))
+                })
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        let excludes_from_workspace = if let Some(configuration) = &filesystem {
+            configuration
+                .exclude_dependencies
+                .clone()
+                .unwrap_or_default()
+        } else {
+            Vec::new()
+        };
+
+        let build_constraints_from_workspace = if let Some(configuration) = &filesystem {
+            configuration
+                .build_constraint_dependencies
+                .clone()
+                .unwrap_or_default()
+                .into_iter()
+                .map(|requirement| {
+                    Requirement::from(requirement.with_origin(RequirementOrigin::Workspace))
+                })
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        Self {
+            package,
+            requirements,
+            constraints: constraints
+                .into_iter()
+                .filter_map(Maybe::into_option)
+                .collect(),
+            overrides: overrides
+                .into_iter()
+                .filter_map(Maybe::into_option)
+                .collect(),
+            build_constraints: build_constraints
+                .into_iter()
+                .filter_map(Maybe::into_option)
+                .collect(),
+            dest,
+            constraints_from_workspace,
+            overrides_from_workspace,
+            excludes_from_workspace,
+            build_constraints_from_workspace,
+            refresh: Refresh::from(refresh),
+            settings: PipSettings::combine(
+                PipOptions {
+                    python: python.and_then(Maybe::into_option),
+                    system: flag(system, no_system, "system"),
+                    no_build: flag(no_build, build, "build"),
+                    no_binary,
+                    only_binary,
+                    extra,
+                    all_extras: flag(all_extras, no_all_extras, "all-extras"),
+                    group: Some(group),
+                    no_deps: flag(no_deps, deps, "deps"),
+                    require_hashes: flag(require_hashes, no_require_hashes, "require-hashes"),
+                    verify_hashes: flag(verify_hashes, no_verify_hashes, "verify-hashes"),
+                    python_version,
+                    python_platform,
+                    ..PipOptions::from(resolver)
+                },
+                filesystem,
+                environment,
+            ),
+        }
+    }
+}
+
+/// The resolved settings to use for a `cache warm` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct CacheWarmSettings {
+    pub(crate) requirements: Vec<PathBuf>,
+    pub(crate) constraints: Vec<PathBuf>,
+    pub(crate) build_wheels: bool,
+    pub(crate) python_versions: Vec<PythonVersion>,
+    pub(crate) python_platforms: Vec<TargetTriple>,
+    pub(crate) constraints_from_workspace: Vec<Requirement>,
+    pub(crate) settings: PipSettings,
+}
+
+impl CacheWarmSettings {
+    /// Resolve the [`CacheWarmSettings`] from the CLI and filesystem configuration.
+    pub(crate) fn resolve(
+        args: CacheWarmArgs,
+        filesystem: Option<FilesystemOptions>,
+        environment: EnvironmentOptions,
+    ) -> Self {
+        let CacheWarmArgs {
+            requirements,
+            constraints,
+            build_wheels,
+            resolver,
+            python,
+            system,
+            no_system,
+            python_version,
+            python_platform,
+        } = args;
+
+        let constraints_from_workspace = if let Some(configuration) = &filesystem {
+            configuration
+                .constraint_dependencies
+                .clone()
+                .unwrap_or_default()
+                .into_iter()
+                .map(|requirement| {
+                    Requirement::from(requirement.with_origin(RequirementOrigin::Workspace))
+                })
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        Self {
+            requirements,
+            constraints: constraints
+                .into_iter()
+                .filter_map(Maybe::into_option)
+                .collect(),
+            build_wheels,
+            python_versions: python_version,
+            python_platforms: python_platform,
+            constraints_from_workspace,
+            settings: PipSettings::combine(
+                PipOptions {
+                    python: python.and_then(Maybe::into_option),
+                    system: flag(system, no_system, "system"),
+                    ..PipOptions::from(resolver)
+                },
+                filesystem,
+                environment,
+            ),
+        }
+    }
+}
+
+/// The resolved settings to use for a `index ping` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct IndexPingSettings {
+    pub(crate) package: PackageName,
+    pub(crate) settings: PipSettings,
+}
+
+impl IndexPingSettings {
+    /// Resolve the [`IndexPingSettings`] from the CLI and filesystem configuration.
+    pub(crate) fn resolve(
+        args: IndexPingArgs,
+        filesystem: Option<FilesystemOptions>,
+        environment: EnvironmentOptions,
+    ) -> Self {
+        let IndexPingArgs {
+            package,
+            index_args,
+            keyring_provider,
+        } = args;
+
+        Self {
+            package,
+            settings: PipSettings::combine(
+                PipOptions {
+                    keyring_provider,
+                    ..PipOptions::from(index_args)
+                },
+                filesystem,
+                environment,
+            ),
+        }
+    }
+}
+
+/// The resolved settings to use for a `pip wheel` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct PipWheelSettings {
+    pub(crate) package: Vec<String>,
+    pub(crate) requirements: Vec<PathBuf>,
+    pub(crate) constraints: Vec<PathBuf>,
+    pub(crate) overrides: Vec<PathBuf>,
+    pub(crate) build_constraints: Vec<PathBuf>,
+    pub(crate) wheel_dir: Option<PathBuf>,
+    pub(crate) constraints_from_workspace: Vec<Requirement>,
+    pub(crate) overrides_from_workspace: Vec<Requirement>,
+    pub(crate) excludes_from_workspace: Vec<PackageName>,
+    pub(crate) build_constraints_from_workspace: Vec<Requirement>,
+    pub(crate) refresh: Refresh,
+    pub(crate) settings: PipSettings,
+}
+
+impl PipWheelSettings {
+    /// Resolve the [`PipWheelSettings`] from the CLI and filesystem configuration.
+    pub(crate) fn resolve(
+        args: PipWheelArgs,
+        filesystem: Option<FilesystemOptions>,
+        environment: EnvironmentOptions,
+    ) -> Self {
+        let PipWheelArgs {
+            package,
+            requirements,
+            constraints,
+            overrides,
+            build_constraints,
+            extra,
+            all_extras,
+            no_all_extras,
+            group,
+            wheel_dir,
+            resolver,
+            refresh,
+            no_deps,
+            deps,
+            require_hashes,
+            no_require_hashes,
+            verify_hashes,
+            no_verify_hashes,
+            python,
+            system,
+            no_system,
+            no_build,
+            build,
+            no_binary,
+            only_binary,
+            python_version,
+            python_platform,
+        } = args;
+
+        let constraints_from_workspace = if let Some(configuration) = &filesystem {
+            configuration
+                .constraint_dependencies
+                .clone()
+                .unwrap_or_default()
+                .into_iter()
+                .map(|requirement| {
+                    Requirement::from(requirement.with_origin(RequirementOrigin::Workspace))
+                })
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        let overrides_from_workspace = if let Some(configuration) = &filesystem {
+            configuration
+                .override_dependencies
+                .clone()
+                .unwrap_or_default()
+                .into_iter()
+                .map(|requirement| {
+                    Requirement::from(requirement.with_origin(RequirementOrigin::Workspace))
+                })
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        let excludes_from_workspace = if let Some(configuration) = &filesystem {
+            configuration
+                .exclude_dependencies
+                .clone()
+                .unwrap_or_default()
+        } else {
+            Vec::new()
+        };
+
+        let build_constraints_from_workspace = if let Some(configuration) = &filesystem {
+            configuration
+                .build_constraint_dependencies
+                .clone()
+                .unwrap_or_default()
+                .into_iter()
+                .map(|requirement| {
+                    Requirement::from(requirement.with_origin(RequirementOrigin::Workspace))
+                })
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        Self {
+            package,
+            requirements,
+            constraints: constraints
+                .into_iter()
+                .filter_map(Maybe::into_option)
+                .collect(),
+            overrides: overrides
+                .into_iter()
+                .filter_map(Maybe::into_option)
+                .collect(),
+            build_constraints: build_constraints
+                .into_iter()
+                .filter_map(Maybe::into_option)
+                .collect(),
+            wheel_dir,
+            constraints_from_workspace,
+            overrides_from_workspace,
+            excludes_from_workspace,
+            build_constraints_from_workspace,
+            refresh: Refresh::from(refresh),
+            settings: PipSettings::combine(
+                PipOptions {
+                    python: python.and_then(Maybe::into_option),
+                    system: flag(system, no_system, "system"),
+                    no_build: flag(no_build, build, "build"),
+                    no_binary,
+                    only_binary,
+                    extra,
+                    all_extras: flag(all_extras, no_all_extras, "all-extras"),
+                    group: Some(group),
+                    no_deps: flag(no_deps, deps, "deps"),
+                    require_hashes: flag(require_hashes, no_require_hashes, "require-hashes"),
+                    verify_hashes: flag(verify_hashes, no_verify_hashes, "verify-hashes"),
+                    python_version,
+                    python_platform,
+                    ..PipOptions::from(resolver)
+                },
+                filesystem,
+                environment,
+            ),
+        }
+    }
+}
+
+/// The resolved settings to use for a `pip uninstall` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct PipUninstallSettings {
+    pub(crate) package: Vec<String>,
+    pub(crate) requirements: Vec<PathBuf>,
+    pub(crate) dry_run: DryRun,
+    pub(crate) only_uv_managed: bool,
+    pub(crate) settings: PipSettings,
+}
+
+impl PipUninstallSettings {
+    /// Resolve the [`PipUninstallSettings`] from the CLI and filesystem configuration.
+    pub(crate) fn resolve(
+        args: PipUninstallArgs,
+        filesystem: Option<FilesystemOptions>,
+        environment: EnvironmentOptions,
+    ) -> Self {
+        let PipUninstallArgs {
+            package,
+            requirements,
+            python,
+            keyring_provider,
+            system,
+            no_system,
+            break_system_packages,
+            no_break_system_packages,
+            target,
+            prefix,
+            dry_run,
+            only_uv_managed,
+            compat_args: _,
+        } = args;
+
+        Self {
+            package,
+            requirements,
+            dry_run: DryRun::from_args(dry_run),
+            only_uv_managed,
             settings: PipSettings::combine(
                 PipOptions {
                     python: python.and_then(Maybe::into_option),
@@ -3109,6 +3885,56 @@ impl PipUninstallSettings {
     }
 }
 
+/// The resolved settings to use for a `pip stub` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct PipStubSettings {
+    pub(crate) package: Vec<StubDist>,
+    pub(crate) dry_run: DryRun,
+    pub(crate) settings: PipSettings,
+}
+
+impl PipStubSettings {
+    /// Resolve the [`PipStubSettings`] from the CLI and filesystem configuration.
+    pub(crate) fn resolve(
+        args: PipStubArgs,
+        filesystem: Option<FilesystemOptions>,
+        environment: EnvironmentOptions,
+    ) -> Self {
+        let PipStubArgs {
+            package,
+            python,
+            system,
+            no_system,
+            break_system_packages,
+            no_break_system_packages,
+            target,
+            prefix,
+            dry_run,
+        } = args;
+
+        Self {
+            package,
+            dry_run: DryRun::from_args(dry_run),
+            settings: PipSettings::combine(
+                PipOptions {
+                    python: python.and_then(Maybe::into_option),
+                    system: flag(system, no_system, "system"),
+                    break_system_packages: flag(
+                        break_system_packages,
+                        no_break_system_packages,
+                        "break-system-packages",
+                    ),
+                    target,
+                    prefix,
+                    ..PipOptions::default()
+                },
+                filesystem,
+                environment,
+            ),
+        }
+    }
+}
+
 /// The resolved settings to use for a `pip freeze` invocation.
 #[derive(Debug, Clone)]
 pub(crate) struct PipFreezeSettings {
@@ -3164,6 +3990,7 @@ impl PipFreezeSettings {
 pub(crate) struct PipListSettings {
     pub(crate) editable: Option<bool>,
     pub(crate) exclude: FxHashSet<PackageName>,
+    pub(crate) not_required: bool,
     pub(crate) format: ListFormat,
     pub(crate) outdated: bool,
     pub(crate) settings: PipSettings,
@@ -3180,9 +4007,12 @@ impl PipListSettings {
             editable,
             exclude_editable,
             exclude,
+            not_required,
             format,
             outdated,
             no_outdated,
+            prerelease,
+            pre,
             strict,
             no_strict,
             fetch,
@@ -3197,6 +4027,7 @@ impl PipListSettings {
         Self {
             editable: flag(editable, exclude_editable, "exclude-editable"),
             exclude: exclude.into_iter().collect(),
+            not_required,
             format,
             outdated: flag(outdated, no_outdated, "outdated").unwrap_or(false),
             settings: PipSettings::combine(
@@ -3206,6 +4037,12 @@ impl PipListSettings {
                     strict: flag(strict, no_strict, "strict"),
                     target,
                     prefix,
+                    prerelease: if pre {
+                        Some(PrereleaseMode::Allow)
+                    } else {
+                        prerelease.as_ref().and_then(PrereleaseSpec::mode)
+                    },
+                    prerelease_packages: prerelease.and_then(PrereleaseSpec::into_packages),
                     ..PipOptions::from(fetch)
                 },
                 filesystem,
@@ -3220,6 +4057,8 @@ impl PipListSettings {
 pub(crate) struct PipShowSettings {
     pub(crate) package: Vec<PackageName>,
     pub(crate) files: bool,
+    pub(crate) entry_points: bool,
+    pub(crate) format: PipShowFormat,
     pub(crate) settings: PipSettings,
 }
 
@@ -3235,6 +4074,8 @@ impl PipShowSettings {
             strict,
             no_strict,
             files,
+            entry_points,
+            format,
             python,
             system,
             no_system,
@@ -3246,6 +4087,8 @@ impl PipShowSettings {
         Self {
             package,
             files,
+            entry_points,
+            format,
             settings: PipSettings::combine(
                 PipOptions {
                     python: python.and_then(Maybe::into_option),
@@ -3319,6 +4162,8 @@ impl PipTreeSettings {
 /// The resolved settings to use for a `pip check` invocation.
 #[derive(Debug, Clone)]
 pub(crate) struct PipCheckSettings {
+    pub(crate) format: PipCheckFormat,
+    pub(crate) severity: PipCheckSeverity,
     pub(crate) settings: PipSettings,
 }
 
@@ -3330,6 +4175,8 @@ impl PipCheckSettings {
         environment: EnvironmentOptions,
     ) -> Self {
         let PipCheckArgs {
+            format,
+            severity,
             python,
             system,
             no_system,
@@ -3338,6 +4185,8 @@ impl PipCheckSettings {
         } = args;
 
         Self {
+            format,
+            severity,
             settings: PipSettings::combine(
                 PipOptions {
                     python: python.and_then(Maybe::into_option),
@@ -3353,6 +4202,74 @@ impl PipCheckSettings {
     }
 }
 
+/// The resolved settings to use for a `pip verify` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct PipVerifySettings {
+    pub(crate) package: Vec<PackageName>,
+    pub(crate) format: PipVerifyFormat,
+    pub(crate) settings: PipSettings,
+}
+
+impl PipVerifySettings {
+    /// Resolve the [`PipVerifySettings`] from the CLI and filesystem configuration.
+    pub(crate) fn resolve(
+        args: PipVerifyArgs,
+        filesystem: Option<FilesystemOptions>,
+        environment: EnvironmentOptions,
+    ) -> Self {
+        let PipVerifyArgs {
+            package,
+            format,
+            python,
+            system,
+            no_system,
+        } = args;
+
+        Self {
+            package,
+            format,
+            settings: PipSettings::combine(
+                PipOptions {
+                    python: python.and_then(Maybe::into_option),
+                    system: flag(system, no_system, "system"),
+                    ..PipOptions::default()
+                },
+                filesystem,
+                environment,
+            ),
+        }
+    }
+}
+
+/// The resolved settings to use for a `pip index versions` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct PipIndexVersionsSettings {
+    pub(crate) package: PackageName,
+    pub(crate) format: PipIndexFormat,
+    pub(crate) settings: PipSettings,
+}
+
+impl PipIndexVersionsSettings {
+    /// Resolve the [`PipIndexVersionsSettings`] from the CLI and filesystem configuration.
+    pub(crate) fn resolve(
+        args: PipIndexVersionsArgs,
+        filesystem: Option<FilesystemOptions>,
+        environment: EnvironmentOptions,
+    ) -> Self {
+        let PipIndexVersionsArgs {
+            package,
+            format,
+            fetch,
+        } = args;
+
+        Self {
+            package,
+            format,
+            settings: PipSettings::combine(PipOptions::from(fetch), filesystem, environment),
+        }
+    }
+}
+
 /// The resolved settings to use for a `build` invocation.
 #[derive(Debug, Clone)]
 pub(crate) struct BuildSettings {
@@ -3449,7 +4366,11 @@ impl BuildSettings {
             ),
             python: python.and_then(Maybe::into_option),
             refresh: Refresh::from(refresh),
-            settings: ResolverSettings::combine(resolver_options(resolver, build), filesystem),
+            settings: ResolverSettings::combine(
+                resolver_options(resolver, build),
+                filesystem,
+                &environment,
+            ),
             install_mirrors: environment
                 .install_mirrors
                 .combine(filesystem_install_mirrors),
@@ -3562,6 +4483,8 @@ pub(crate) struct InstallerSettingsRef<'a> {
     pub(crate) reinstall: &'a Reinstall,
     pub(crate) build_options: &'a BuildOptions,
     pub(crate) sources: NoSources,
+    pub(crate) excluded_packages: &'a [PackageName],
+    pub(crate) protected_packages: &'a [PackageName],
 }
 
 /// The resolved settings to use for an invocation of the uv CLI when resolving dependencies.
@@ -3581,9 +4504,15 @@ pub(crate) struct ResolverSettings {
     pub(crate) keyring_provider: KeyringProviderType,
     pub(crate) link_mode: LinkMode,
     pub(crate) build_isolation: BuildIsolation,
+    pub(crate) excluded_packages: Vec<PackageName>,
+    pub(crate) resolution_priority: Vec<PackageName>,
+    pub(crate) max_backtracks: Option<usize>,
+    pub(crate) memory_limit: Option<usize>,
+    pub(crate) allow_yanked: Vec<AllowYankedEntry>,
     pub(crate) extra_build_dependencies: ExtraBuildDependencies,
     pub(crate) extra_build_variables: ExtraBuildVariables,
     pub(crate) prerelease: PrereleaseMode,
+    pub(crate) prerelease_packages: Vec<PackageName>,
     pub(crate) resolution: ResolutionMode,
     pub(crate) sources: NoSources,
     pub(crate) torch_backend: Option<TorchMode>,
@@ -3591,8 +4520,12 @@ pub(crate) struct ResolverSettings {
 }
 
 impl ResolverSettings {
-    /// Resolve the [`ResolverSettings`] from the CLI and filesystem configuration.
-    pub(crate) fn combine(args: ResolverOptions, filesystem: Option<FilesystemOptions>) -> Self {
+    /// Resolve the [`ResolverSettings`] from the CLI, filesystem, and environment configuration.
+    pub(crate) fn combine(
+        args: ResolverOptions,
+        filesystem: Option<FilesystemOptions>,
+        environment: &EnvironmentOptions,
+    ) -> Self {
         // The problem is that for `upgrade`... we want to combine the two `Upgrade` structs,
         // not the individual fields.
         let options = args.combine(ResolverOptions::from(
@@ -3602,7 +4535,10 @@ impl ResolverSettings {
                 .unwrap_or_default(),
         ));
 
-        Self::from(options)
+        Self {
+            memory_limit: environment.resolver_memory_limit,
+            ..Self::from(options)
+        }
     }
 }
 
@@ -3628,6 +4564,7 @@ impl From<ResolverOptions> for ResolverSettings {
             index_locations,
             resolution: value.resolution.unwrap_or_default(),
             prerelease: value.prerelease.unwrap_or_default(),
+            prerelease_packages: value.prerelease_packages.unwrap_or_default(),
             fork_strategy: value.fork_strategy.unwrap_or_default(),
             dependency_metadata: DependencyMetadata::from_entries(
                 value.dependency_metadata.into_iter().flatten(),
@@ -3637,6 +4574,11 @@ impl From<ResolverOptions> for ResolverSettings {
             config_setting: value.config_settings.unwrap_or_default(),
             config_settings_package: value.config_settings_package.unwrap_or_default(),
             build_isolation: value.build_isolation.unwrap_or_default(),
+            excluded_packages: value.excluded_packages.unwrap_or_default(),
+            resolution_priority: value.resolution_priority.unwrap_or_default(),
+            max_backtracks: value.max_backtracks.map(NonZeroUsize::get),
+            memory_limit: None,
+            allow_yanked: value.allow_yanked.unwrap_or_default(),
             extra_build_dependencies: value.extra_build_dependencies.unwrap_or_default(),
             extra_build_variables: value.extra_build_variables.unwrap_or_default(),
             exclude_newer: value.exclude_newer,
@@ -3664,14 +4606,17 @@ impl From<ResolverOptions> for ResolverSettings {
 pub(crate) struct ResolverInstallerSettings {
     pub(crate) resolver: ResolverSettings,
     pub(crate) compile_bytecode: bool,
+    pub(crate) protected_packages: Vec<PackageName>,
     pub(crate) reinstall: Reinstall,
 }
 
 impl ResolverInstallerSettings {
-    /// Reconcile the [`ResolverInstallerSettings`] from the CLI and filesystem configuration.
+    /// Reconcile the [`ResolverInstallerSettings`] from the CLI, filesystem, and environment
+    /// configuration.
     pub(crate) fn combine(
         args: ResolverInstallerOptions,
         filesystem: Option<FilesystemOptions>,
+        environment: &EnvironmentOptions,
     ) -> Self {
         let options = args.combine(ResolverInstallerOptions::from(
             filesystem
@@ -3680,7 +4625,9 @@ impl ResolverInstallerSettings {
                 .unwrap_or_default(),
         ));
 
-        Self::from(options)
+        let mut settings = Self::from(options);
+        settings.resolver.memory_limit = environment.resolver_memory_limit;
+        settings
     }
 }
 
@@ -3731,9 +4678,15 @@ impl From<ResolverInstallerOptions> for ResolverInstallerSettings {
                 keyring_provider: value.keyring_provider.unwrap_or_default(),
                 link_mode: value.link_mode.unwrap_or_default(),
                 build_isolation: value.build_isolation.unwrap_or_default(),
+                excluded_packages: value.excluded_packages.unwrap_or_default(),
+                resolution_priority: value.resolution_priority.unwrap_or_default(),
+                max_backtracks: value.max_backtracks.map(NonZeroUsize::get),
+                memory_limit: None,
+                allow_yanked: value.allow_yanked.unwrap_or_default(),
                 extra_build_dependencies: value.extra_build_dependencies.unwrap_or_default(),
                 extra_build_variables: value.extra_build_variables.unwrap_or_default(),
                 prerelease: value.prerelease.unwrap_or_default(),
+                prerelease_packages: value.prerelease_packages.unwrap_or_default(),
                 resolution: value.resolution.unwrap_or_default(),
                 sources: NoSources::from_args(
                     value.no_sources,
@@ -3743,6 +4696,7 @@ impl From<ResolverInstallerOptions> for ResolverInstallerSettings {
                 upgrade: value.upgrade.unwrap_or_default(),
             },
             compile_bytecode: value.compile_bytecode.unwrap_or_default(),
+            protected_packages: value.protected_packages.unwrap_or_default(),
             reinstall: value.reinstall.unwrap_or_default(),
         }
     }
@@ -3767,6 +4721,12 @@ pub(crate) struct PipSettings {
     pub(crate) keyring_provider: KeyringProviderType,
     pub(crate) torch_backend: Option<TorchMode>,
     pub(crate) build_isolation: BuildIsolation,
+    pub(crate) excluded_packages: Vec<PackageName>,
+    pub(crate) resolution_priority: Vec<PackageName>,
+    pub(crate) max_backtracks: Option<usize>,
+    pub(crate) memory_limit: Option<usize>,
+    pub(crate) protected_packages: Vec<PackageName>,
+    pub(crate) allow_yanked: Vec<AllowYankedEntry>,
     pub(crate) extra_build_dependencies: ExtraBuildDependencies,
     pub(crate) extra_build_variables: ExtraBuildVariables,
     pub(crate) build_options: BuildOptions,
@@ -3775,6 +4735,7 @@ pub(crate) struct PipSettings {
     pub(crate) dependency_mode: DependencyMode,
     pub(crate) resolution: ResolutionMode,
     pub(crate) prerelease: PrereleaseMode,
+    pub(crate) prerelease_packages: Vec<PackageName>,
     pub(crate) fork_strategy: ForkStrategy,
     pub(crate) dependency_metadata: DependencyMetadata,
     pub(crate) output_file: Option<PathBuf>,
@@ -3806,6 +4767,18 @@ pub(crate) struct PipSettings {
 }
 
 impl PipSettings {
+    /// Apply the `--upgrade-strategy` to the selection of packages to upgrade.
+    #[must_use]
+    fn with_upgrade_strategy(self, upgrade_strategy: Option<UpgradeStrategy>) -> Self {
+        let Some(upgrade_strategy) = upgrade_strategy else {
+            return self;
+        };
+        Self {
+            upgrade: self.upgrade.with_strategy(upgrade_strategy),
+            ..self
+        }
+    }
+
     /// Resolve the [`PipSettings`] from the CLI and filesystem configuration.
     pub(crate) fn combine(
         args: PipOptions,
@@ -3851,6 +4824,7 @@ impl PipSettings {
             allow_empty_requirements,
             resolution,
             prerelease,
+            prerelease_packages,
             fork_strategy,
             dependency_metadata,
             output_file,
@@ -3884,6 +4858,7 @@ impl PipSettings {
             reinstall,
             reinstall_package,
             exclude_newer_package,
+            allow_yanked,
         } = pip.unwrap_or_default();
 
         let ResolverInstallerSchema {
@@ -3896,6 +4871,7 @@ impl PipSettings {
             keyring_provider: top_level_keyring_provider,
             resolution: top_level_resolution,
             prerelease: top_level_prerelease,
+            prerelease_packages: top_level_prerelease_packages,
             fork_strategy: top_level_fork_strategy,
             dependency_metadata: top_level_dependency_metadata,
             config_settings: top_level_config_settings,
@@ -3909,6 +4885,10 @@ impl PipSettings {
             compile_bytecode: top_level_compile_bytecode,
             no_sources: top_level_no_sources,
             no_sources_package: top_level_no_sources_package,
+            excluded_packages: top_level_excluded_packages,
+            resolution_priority: top_level_resolution_priority,
+            max_backtracks: top_level_max_backtracks,
+            protected_packages: top_level_protected_packages,
             upgrade: top_level_upgrade,
             upgrade_package: top_level_upgrade_package,
             reinstall: top_level_reinstall,
@@ -3918,6 +4898,7 @@ impl PipSettings {
             no_binary: top_level_no_binary,
             no_binary_package: top_level_no_binary_package,
             exclude_newer_package: top_level_exclude_newer_package,
+            allow_yanked: top_level_allow_yanked,
             torch_backend: top_level_torch_backend,
         } = top_level;
 
@@ -3934,6 +4915,7 @@ impl PipSettings {
         let keyring_provider = keyring_provider.combine(top_level_keyring_provider);
         let resolution = resolution.combine(top_level_resolution);
         let prerelease = prerelease.combine(top_level_prerelease);
+        let prerelease_packages = prerelease_packages.combine(top_level_prerelease_packages);
         let fork_strategy = fork_strategy.combine(top_level_fork_strategy);
         let dependency_metadata = dependency_metadata.combine(top_level_dependency_metadata);
         let config_settings = config_settings.combine(top_level_config_settings);
@@ -3954,6 +4936,7 @@ impl PipSettings {
             .combine(exclude_newer_package)
             .combine(top_level_exclude_newer_package)
             .unwrap_or_default();
+        let allow_yanked = allow_yanked.combine(top_level_allow_yanked);
         let link_mode = link_mode.combine(top_level_link_mode);
         let compile_bytecode = compile_bytecode.combine(top_level_compile_bytecode);
         let no_sources = no_sources.combine(top_level_no_sources);
@@ -4001,6 +4984,10 @@ impl PipSettings {
             },
             resolution: args.resolution.combine(resolution).unwrap_or_default(),
             prerelease: args.prerelease.combine(prerelease).unwrap_or_default(),
+            prerelease_packages: args
+                .prerelease_packages
+                .combine(prerelease_packages)
+                .unwrap_or_default(),
             fork_strategy: args
                 .fork_strategy
                 .combine(fork_strategy)
@@ -4051,6 +5038,12 @@ impl PipSettings {
                 no_build_isolation_package.unwrap_or_default(),
             ))
             .unwrap_or_default(),
+            excluded_packages: top_level_excluded_packages.unwrap_or_default(),
+            resolution_priority: top_level_resolution_priority.unwrap_or_default(),
+            max_backtracks: top_level_max_backtracks.map(NonZeroUsize::get),
+            memory_limit: environment.resolver_memory_limit,
+            protected_packages: top_level_protected_packages.unwrap_or_default(),
+            allow_yanked: args.allow_yanked.combine(allow_yanked).unwrap_or_default(),
             extra_build_dependencies: args
                 .extra_build_dependencies
                 .combine(extra_build_dependencies)
@@ -4189,6 +5182,8 @@ impl<'a> From<&'a ResolverInstallerSettings> for InstallerSettingsRef<'a> {
             reinstall: &settings.reinstall,
             build_options: &settings.resolver.build_options,
             sources: settings.resolver.sources.clone(),
+            excluded_packages: &settings.resolver.excluded_packages,
+            protected_packages: &settings.protected_packages,
         }
     }
 }
@@ -2316,3 +2316,65 @@ fn login_pyx_staging_with_env_var() {
     "
     );
 }
+
+/// A `credential-helper` in project-level configuration is ignored, since it would allow an
+/// untrusted project to run arbitrary commands.
+#[tokio::test]
+async fn credential_helper_project_config() -> Result<()> {
+    let context = uv_test::test_context_with_versions!(&[]);
+
+    let server = wiremock::MockServer::start().await;
+    wiremock::Mock::given(wiremock::matchers::method("GET"))
+        .respond_with(wiremock::ResponseTemplate::new(401))
+        .mount(&server)
+        .await;
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc::indoc! { r#"
+        [project]
+        name = "foo"
+        version = "1.0.0"
+
+        [tool.uv]
+        credential-helper = "touch helper-ran"
+    "#
+    })?;
+
+    uv_snapshot!(context.filters(), context.index_ping()
+        .arg("--default-index")
+        .arg(format!("{}/simple", server.uri()))
+        .arg("--preview-features")
+        .arg("index-ping"), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    http://[LOCALHOST]/simple
+      Status: 401 Unauthorized ([TIME]) (check the credentials for this index)
+
+
+    ----- stderr -----
+    warning: Ignoring `credential-helper` in `pyproject.toml`; credential helpers can only be configured in a user-level or system-level `uv.toml`, or via `--config-file`
+    "
+    );
+
+    assert!(!context.temp_dir.child("helper-ran").exists());
+
+    // The same helper is respected when provided via `--config-file`.
+    let uv_toml = context.temp_dir.child("config").child("uv.toml");
+    uv_toml.write_str(r#"credential-helper = "touch helper-ran""#)?;
+
+    context
+        .index_ping()
+        .arg("--default-index")
+        .arg(format!("{}/simple", server.uri()))
+        .arg("--preview-features")
+        .arg("index-ping")
+        .arg("--config-file")
+        .arg(uv_toml.path())
+        .assert()
+        .failure();
+
+    assert!(context.temp_dir.child("helper-ran").exists());
+
+    Ok(())
+}
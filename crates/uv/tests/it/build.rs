@@ -476,6 +476,9 @@ fn build_fail() -> Result<()> {
       × Failed to build `[TEMP_DIR]/project`
       ├─▶ The build backend returned an error
       ╰─▶ Call to `setuptools.build_meta.build_sdist` failed (exit status: 1)
+
+          The full build output was written to: [CACHE_DIR]/build-logs-v0/project/[HASH].log
+
           hint: This usually indicates a problem with the package or the build environment.
     "#);
 
@@ -806,6 +809,9 @@ fn build_all_with_failure() -> Result<()> {
       × Failed to build `member-b @ [TEMP_DIR]/project/packages/member_b`
       ├─▶ The build backend returned an error
       ╰─▶ Call to `setuptools.build_meta.build_sdist` failed (exit status: 1)
+
+          The full build output was written to: [CACHE_DIR]/build-logs-v0/member-b/[HASH].log
+
           hint: This usually indicates a problem with the package or the build environment.
     Successfully built dist/project-0.1.0.tar.gz
     Successfully built dist/project-0.1.0-py3-none-any.whl
@@ -1454,6 +1460,9 @@ fn build_hide_build_output_on_failure() -> Result<()> {
       × Failed to build `[TEMP_DIR]/project`
       ├─▶ The build backend returned an error
       ╰─▶ Call to `setuptools.build_meta.build_sdist` failed (exit status: 1)
+
+          The full build output was written to: [CACHE_DIR]/build-logs-v0/project/[HASH].log
+
           hint: This usually indicates a problem with the package or the build environment.
     ");
 
@@ -2298,6 +2307,9 @@ fn force_pep517() -> Result<()> {
       × Failed to build `[TEMP_DIR]/`
       ├─▶ The build backend returned an error
       ╰─▶ Call to `uv_build.build_sdist` failed (exit status: 1)
+
+          The full build output was written to: [CACHE_DIR]/build-logs-v0/project/[HASH].log
+
           hint: This usually indicates a problem with the package or the build environment.
     ");
 
@@ -2355,7 +2367,7 @@ fn venv_included_in_sdist() -> Result<()> {
     Building source distribution...
       × Failed to build `[TEMP_DIR]/`
       ├─▶ Invalid tar file
-      ├─▶ failed to unpack `[CACHE_DIR]/sdists-v9/[TMP]/python`
+      ├─▶ failed to unpack `[CACHE_DIR]/sdists-v10/[TMP]/python`
       ╰─▶ symlink path `[PYTHON-3.12]` is absolute, but external symlinks are not allowed
       help: This file seems to be part of a virtual environment. Virtual environments must be excluded from source distributions.
     ");
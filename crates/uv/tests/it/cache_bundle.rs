@@ -0,0 +1,129 @@
+use anyhow::Result;
+use assert_cmd::assert::OutputAssertExt;
+use assert_fs::prelude::*;
+
+use uv_test::uv_snapshot;
+
+/// Export the cache entries for a set of requirements, then import them into an empty cache and
+/// install offline.
+#[test]
+fn cache_export_import() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("iniconfig==2.0.0")?;
+
+    context
+        .pip_install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .assert()
+        .success();
+
+    let filters: Vec<_> = context
+        .filters()
+        .into_iter()
+        .chain([
+            (r"\d+ files", "[N] files"),
+            (r"\(\d+\.\d+[KMG]?i?B\)", "([SIZE])"),
+        ])
+        .collect();
+
+    uv_snapshot!(&filters, context.cache_export()
+        .arg("bundle.tar.zst")
+        .arg("--filter")
+        .arg("requirements.txt")
+        .arg("--preview-features")
+        .arg("cache-bundles"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Exported [N] files to bundle.tar.zst ([SIZE])
+    ");
+
+    // Import the bundle into a separate, empty cache.
+    let offline = uv_test::test_context!("3.12");
+    let bundle = context.temp_dir.child("bundle.tar.zst");
+
+    uv_snapshot!(&filters, offline.cache_import()
+        .arg(bundle.path())
+        .arg("--preview-features")
+        .arg("cache-bundles"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Imported [N] files into cache at: [CACHE_DIR]/
+    ");
+
+    uv_snapshot!(offline.filters(), offline.pip_install()
+        .arg("iniconfig==2.0.0")
+        .arg("--offline"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    ");
+
+    Ok(())
+}
+
+/// Filters must pin every package to an exact version.
+#[test]
+fn cache_export_unpinned() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("iniconfig>=2")?;
+
+    uv_snapshot!(context.filters(), context.cache_export()
+        .arg("bundle.tar.zst")
+        .arg("--filter")
+        .arg("requirements.txt")
+        .arg("--preview-features")
+        .arg("cache-bundles"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: `uv cache export` requires pinned requirements, but found: `iniconfig>=2`
+
+    hint: Use `uv pip compile` or `uv export` to generate a filter with every package in the resolution pinned to an exact version
+    ");
+
+    Ok(())
+}
+
+/// Files that aren't cache bundles should be rejected.
+#[test]
+fn cache_import_invalid() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    context
+        .temp_dir
+        .child("bundle.tar.zst")
+        .write_str("bundle")?;
+
+    uv_snapshot!(context.filters(), context.cache_import()
+        .arg("bundle.tar.zst")
+        .arg("--preview-features")
+        .arg("cache-bundles"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Failed to read bundle: `bundle.tar.zst`
+      Caused by: Unknown frame descriptor
+    ");
+
+    Ok(())
+}
@@ -0,0 +1,47 @@
+use assert_cmd::assert::OutputAssertExt;
+
+use uv_test::uv_snapshot;
+
+/// List the wheels in the cache for a package that was downloaded.
+#[test]
+fn cache_list_downloaded() {
+    let context = uv_test::test_context!("3.12");
+
+    context
+        .pip_install()
+        .arg("iniconfig==2.0.0")
+        .assert()
+        .success();
+
+    uv_snapshot!(context.filters(), context.cache_list()
+        .arg("--package")
+        .arg("iniconfig")
+        .arg("--preview-features")
+        .arg("cache-list"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    iniconfig-2.0.0-py3-none-any.whl
+
+    ----- stderr -----
+    ");
+}
+
+/// List the wheels in the cache for a package that isn't cached.
+#[test]
+fn cache_list_missing() {
+    let context = uv_test::test_context!("3.12");
+
+    uv_snapshot!(context.filters(), context.cache_list()
+        .arg("--package")
+        .arg("iniconfig")
+        .arg("--preview-features")
+        .arg("cache-list"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    No wheels found in the cache for iniconfig
+    ");
+}
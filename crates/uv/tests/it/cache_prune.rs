@@ -157,7 +157,7 @@ fn prune_stale_symlink() -> Result<()> {
         .success();
 
     // Remove the wheels directory, causing the symlink to become stale.
-    let wheels = context.cache_dir.child("wheels-v6");
+    let wheels = context.cache_dir.child("wheels-v8");
     fs_err::remove_dir_all(wheels)?;
 
     let filters: Vec<_> = context
@@ -311,7 +311,7 @@ fn prune_unzipped() -> Result<()> {
 
           hint: Pre-releases are available for `iniconfig` in the requested range (e.g., 0.2.dev0), but pre-releases weren't enabled (try: `--prerelease=allow`)
 
-          hint: Packages were unavailable because the network was disabled. When the network is disabled, registry packages may only be read from the cache.
+          hint: Packages were unavailable because the network was disabled. When the network is disabled, registry packages may only be read from the cache (pre-populate the `simple-v22` and `wheels-v8` cache buckets while online).
     ");
 
     Ok(())
@@ -403,7 +403,7 @@ fn prune_stale_revision() -> Result<()> {
     ----- stderr -----
     DEBUG uv [VERSION] ([COMMIT] DATE)
     Pruning cache at: [CACHE_DIR]/
-    DEBUG Removing dangling source revision: [CACHE_DIR]/sdists-v9/[ENTRY]
+    DEBUG Removing dangling source revision: [CACHE_DIR]/sdists-v10/[ENTRY]
     DEBUG Removing dangling cache archive: [CACHE_DIR]/archive-v0/[ENTRY]
     Removed [N] files ([SIZE])
     ");
@@ -0,0 +1,106 @@
+use anyhow::Result;
+use assert_cmd::assert::OutputAssertExt;
+
+use uv_test::uv_snapshot;
+
+/// `cache verify` should succeed if no entries in the cache are corrupted.
+#[test]
+fn cache_verify_no_op() {
+    let context = uv_test::test_context!("3.12");
+
+    context
+        .pip_install()
+        .arg("iniconfig==2.0.0")
+        .assert()
+        .success();
+
+    uv_snapshot!(context.filters(), context.cache_verify()
+        .arg("--preview-features")
+        .arg("cache-verify"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Verifying cache at: [CACHE_DIR]/
+    No corrupted entries found
+    ");
+}
+
+/// `cache verify` should remove unzipped wheels that don't match their `RECORD`, such that they're
+/// downloaded again.
+#[test]
+fn cache_verify_corrupted_archive() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    context
+        .pip_install()
+        .arg("iniconfig==2.0.0")
+        .assert()
+        .success();
+
+    // Modify a file in the unzipped wheel.
+    let archive = fs_err::read_dir(context.cache_dir.join("archive-v0"))?
+        .next()
+        .expect("archive should exist")?
+        .path();
+    fs_err::write(archive.join("iniconfig").join("__init__.py"), "")?;
+
+    let filters: Vec<_> = context
+        .filters()
+        .into_iter()
+        .chain([
+            (r"archive-v0/[A-Za-z0-9_-]+", "archive-v0/[ARCHIVE]"),
+            (r"Removed \d+ files \(.*\)", "Removed [N] files ([SIZE])"),
+        ])
+        .collect();
+
+    // With `--dry-run`, the corrupted entry is reported, but not removed.
+    uv_snapshot!(&filters, context.cache_verify()
+        .arg("--dry-run")
+        .arg("--preview-features")
+        .arg("cache-verify"), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Verifying cache at: [CACHE_DIR]/
+    Found 1 corrupted entry:
+     - archive-v0/[ARCHIVE]: `iniconfig/__init__.py` has hash `sha256=47DEQpj8HBSa-_TImW-5JCeuQeRkm5NMpJWZG3hSuFU`, but the `RECORD` lists `sha256=ybOjxVqlW5IunfNpvx-Jsb7-j0w6ZgrehwWH_ps_ybc`
+    ");
+    assert!(archive.exists());
+
+    uv_snapshot!(&filters, context.cache_verify()
+        .arg("--preview-features")
+        .arg("cache-verify"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Verifying cache at: [CACHE_DIR]/
+    Found 1 corrupted entry:
+     - archive-v0/[ARCHIVE]: `iniconfig/__init__.py` has hash `sha256=47DEQpj8HBSa-_TImW-5JCeuQeRkm5NMpJWZG3hSuFU`, but the `RECORD` lists `sha256=ybOjxVqlW5IunfNpvx-Jsb7-j0w6ZgrehwWH_ps_ybc`
+    Removed [N] files ([SIZE])
+    ");
+    assert!(!archive.exists());
+
+    // The wheel should be downloaded again.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("iniconfig==2.0.0")
+        .arg("--reinstall"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     ~ iniconfig==2.0.0
+    ");
+
+    Ok(())
+}
@@ -0,0 +1,83 @@
+use anyhow::Result;
+use assert_cmd::assert::OutputAssertExt;
+use assert_fs::prelude::*;
+
+use uv_test::uv_snapshot;
+
+/// Warm the cache from a requirements file, then install from the cache while offline.
+#[test]
+fn cache_warm() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("iniconfig==2.0.0")?;
+
+    uv_snapshot!(context.filters(), context.cache_warm()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--preview-features")
+        .arg("cache-warm"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Cached 1 wheel in [TIME]
+     + iniconfig-2.0.0-py3-none-any.whl
+    ");
+
+    context.venv().assert().success();
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--offline"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    ");
+
+    Ok(())
+}
+
+/// Warm the cache for multiple target environments.
+#[test]
+fn cache_warm_multiple_targets() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("MarkupSafe==2.1.5")?;
+
+    uv_snapshot!(context.filters(), context.cache_warm()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--python-version")
+        .arg("3.11")
+        .arg("--python-version")
+        .arg("3.12")
+        .arg("--python-platform")
+        .arg("x86_64-unknown-linux-gnu")
+        .arg("--preview-features")
+        .arg("cache-warm"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolving for Python 3.11 on linux (x86_64)
+    Resolved 1 package in [TIME]
+    Resolving for Python 3.12 on linux (x86_64)
+    Resolved 1 package in [TIME]
+    Cached 2 wheels in [TIME]
+     + markupsafe-2.1.5-cp311-cp311-manylinux_2_17_x86_64.manylinux2014_x86_64.whl
+     + markupsafe-2.1.5-cp312-cp312-manylinux_2_17_x86_64.manylinux2014_x86_64.whl
+    ");
+
+    Ok(())
+}
@@ -4626,7 +4626,7 @@ fn pep_751_infer_output_format() -> Result<()> {
 
     ----- stderr -----
     Resolved 4 packages in [TIME]
-    error: `pyproject.toml` is not a supported output format for `uv export` (supported formats: requirements.txt, pylock.toml, cyclonedx1.5)
+    error: `pyproject.toml` is not a supported output format for `uv export` (supported formats: requirements.txt, pylock.toml, cyclonedx1.5, spdx2.3)
     ");
 
     Ok(())
@@ -5096,6 +5096,87 @@ fn cyclonedx_export_basic() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn spdx_export_basic() -> Result<()> {
+    let context = uv_test::test_context!("3.12").with_spdx_filters();
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["urllib3==2.2.0"]
+
+        [build-system]
+        requires = ["uv_build>=0.7,<10000"]
+        build-backend = "uv_build"
+        "#,
+    )?;
+
+    context.lock().assert().success();
+
+    uv_snapshot!(context.filters(), context.export().arg("--format").arg("spdx2.3"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    {
+      "spdxVersion": "SPDX-2.3",
+      "dataLicense": "CC0-1.0",
+      "SPDXID": "SPDXRef-DOCUMENT",
+      "name": "project",
+      "documentNamespace": "https://spdx.org/spdxdocs/project-[UUID]",
+      "creationInfo": {
+        "created": "[TIMESTAMP]",
+        "creators": [
+          "Tool: uv-[VERSION]"
+        ]
+      },
+      "packages": [
+        {
+          "name": "project",
+          "SPDXID": "SPDXRef-project-1-0.1.0",
+          "versionInfo": "0.1.0",
+          "downloadLocation": "NOASSERTION",
+          "filesAnalyzed": false
+        },
+        {
+          "name": "urllib3",
+          "SPDXID": "SPDXRef-urllib3-2-2.2.0",
+          "versionInfo": "2.2.0",
+          "downloadLocation": "NOASSERTION",
+          "filesAnalyzed": false,
+          "externalRefs": [
+            {
+              "referenceCategory": "PACKAGE-MANAGER",
+              "referenceType": "purl",
+              "referenceLocator": "pkg:pypi/urllib3@2.2.0"
+            }
+          ]
+        }
+      ],
+      "relationships": [
+        {
+          "spdxElementId": "SPDXRef-DOCUMENT",
+          "relationshipType": "DESCRIBES",
+          "relatedSpdxElement": "SPDXRef-project-1-0.1.0"
+        },
+        {
+          "spdxElementId": "SPDXRef-project-1-0.1.0",
+          "relationshipType": "DEPENDS_ON",
+          "relatedSpdxElement": "SPDXRef-urllib3-2-2.2.0"
+        }
+      ]
+    }
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    warning: `uv export --format=spdx2.3` is experimental and may change without warning. Pass `--preview-features sbom-export` to disable this warning.
+    "#);
+
+    Ok(())
+}
+
 #[test]
 fn cyclonedx_export_direct_url() -> Result<()> {
     let context = uv_test::test_context!("3.12").with_cyclonedx_filters();
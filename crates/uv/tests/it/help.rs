@@ -39,9 +39,12 @@ fn help() {
       help                       Display documentation for a command
 
     Cache options:
-      -n, --no-cache               Avoid reading from or writing to the cache, instead using a temporary
-                                   directory for the duration of the operation [env: UV_NO_CACHE=]
-          --cache-dir [CACHE_DIR]  Path to the cache directory [env: UV_CACHE_DIR=]
+      -n, --no-cache                   Avoid reading from or writing to the cache, instead using a
+                                       temporary directory for the duration of the operation [env:
+                                       UV_NO_CACHE=]
+          --cache-dir [CACHE_DIR]      Path to the cache directory [env: UV_CACHE_DIR=]
+          --scratch-dir <SCRATCH_DIR>  Path to the directory in which build backends should create
+                                       temporary files [env: UV_SCRATCH_DIR=]
 
     Python options:
           --managed-python       Require use of uv-managed Python versions [env: UV_MANAGED_PYTHON=]
@@ -56,12 +59,33 @@ fn help() {
               Use verbose output
           --color <COLOR_CHOICE>
               Control the use of color in output [possible values: auto, always, never]
+          --error-format <ERROR_FORMAT>
+              The format in which to report resolution failures [possible values: text, json]
           --native-tls
               Whether to load TLS certificates from the platform's native store [env: UV_NATIVE_TLS=]
           --offline
               Disable network access [env: UV_OFFLINE=]
           --allow-insecure-host <ALLOW_INSECURE_HOST>
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
+          --allow-index-failure
+              Treat unreachable indexes as empty, rather than failing [env: UV_ALLOW_INDEX_FAILURE=]
+          --index-cache-ttl <DURATION>
+              How long to consider cached index responses fresh [env: UV_INDEX_CACHE_TTL=]
+          --index-stale-if-error
+              Use stale cached responses for unreachable indexes, rather than failing [env:
+              UV_INDEX_STALE_IF_ERROR=]
+          --stale-metadata-report <STALE_METADATA_REPORT>
+              Append a JSON report of stale index metadata to the given file [env:
+              UV_STALE_METADATA_REPORT=]
+          --client-cert <CLIENT_CERT>
+              The path to a client certificate to use for mTLS [env: UV_CLIENT_CERT=]
+          --client-key <CLIENT_KEY>
+              The path to the private key for the client certificate [env: UV_CLIENT_KEY=]
+          --limit-rate <RATE>
+              The maximum download rate, in bytes per second, across all requests [env: UV_LIMIT_RATE=]
+          --max-connections-per-host <MAX_CONNECTIONS_PER_HOST>
+              The maximum number of concurrent connections to each host [env:
+              UV_MAX_CONNECTIONS_PER_HOST=]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
           --directory <DIRECTORY>
@@ -119,9 +143,12 @@ fn help_flag() {
       help     Display documentation for a command
 
     Cache options:
-      -n, --no-cache               Avoid reading from or writing to the cache, instead using a temporary
-                                   directory for the duration of the operation [env: UV_NO_CACHE=]
-          --cache-dir [CACHE_DIR]  Path to the cache directory [env: UV_CACHE_DIR=]
+      -n, --no-cache                   Avoid reading from or writing to the cache, instead using a
+                                       temporary directory for the duration of the operation [env:
+                                       UV_NO_CACHE=]
+          --cache-dir [CACHE_DIR]      Path to the cache directory [env: UV_CACHE_DIR=]
+          --scratch-dir <SCRATCH_DIR>  Path to the directory in which build backends should create
+                                       temporary files [env: UV_SCRATCH_DIR=]
 
     Python options:
           --managed-python       Require use of uv-managed Python versions [env: UV_MANAGED_PYTHON=]
@@ -136,12 +163,33 @@ fn help_flag() {
               Use verbose output
           --color <COLOR_CHOICE>
               Control the use of color in output [possible values: auto, always, never]
+          --error-format <ERROR_FORMAT>
+              The format in which to report resolution failures [possible values: text, json]
           --native-tls
               Whether to load TLS certificates from the platform's native store [env: UV_NATIVE_TLS=]
           --offline
               Disable network access [env: UV_OFFLINE=]
           --allow-insecure-host <ALLOW_INSECURE_HOST>
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
+          --allow-index-failure
+              Treat unreachable indexes as empty, rather than failing [env: UV_ALLOW_INDEX_FAILURE=]
+          --index-cache-ttl <DURATION>
+              How long to consider cached index responses fresh [env: UV_INDEX_CACHE_TTL=]
+          --index-stale-if-error
+              Use stale cached responses for unreachable indexes, rather than failing [env:
+              UV_INDEX_STALE_IF_ERROR=]
+          --stale-metadata-report <STALE_METADATA_REPORT>
+              Append a JSON report of stale index metadata to the given file [env:
+              UV_STALE_METADATA_REPORT=]
+          --client-cert <CLIENT_CERT>
+              The path to a client certificate to use for mTLS [env: UV_CLIENT_CERT=]
+          --client-key <CLIENT_KEY>
+              The path to the private key for the client certificate [env: UV_CLIENT_KEY=]
+          --limit-rate <RATE>
+              The maximum download rate, in bytes per second, across all requests [env: UV_LIMIT_RATE=]
+          --max-connections-per-host <MAX_CONNECTIONS_PER_HOST>
+              The maximum number of concurrent connections to each host [env:
+              UV_MAX_CONNECTIONS_PER_HOST=]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
           --directory <DIRECTORY>
@@ -198,9 +246,12 @@ fn help_short_flag() {
       help     Display documentation for a command
 
     Cache options:
-      -n, --no-cache               Avoid reading from or writing to the cache, instead using a temporary
-                                   directory for the duration of the operation [env: UV_NO_CACHE=]
-          --cache-dir [CACHE_DIR]  Path to the cache directory [env: UV_CACHE_DIR=]
+      -n, --no-cache                   Avoid reading from or writing to the cache, instead using a
+                                       temporary directory for the duration of the operation [env:
+                                       UV_NO_CACHE=]
+          --cache-dir [CACHE_DIR]      Path to the cache directory [env: UV_CACHE_DIR=]
+          --scratch-dir <SCRATCH_DIR>  Path to the directory in which build backends should create
+                                       temporary files [env: UV_SCRATCH_DIR=]
 
     Python options:
           --managed-python       Require use of uv-managed Python versions [env: UV_MANAGED_PYTHON=]
@@ -215,12 +266,33 @@ fn help_short_flag() {
               Use verbose output
           --color <COLOR_CHOICE>
               Control the use of color in output [possible values: auto, always, never]
+          --error-format <ERROR_FORMAT>
+              The format in which to report resolution failures [possible values: text, json]
           --native-tls
               Whether to load TLS certificates from the platform's native store [env: UV_NATIVE_TLS=]
           --offline
               Disable network access [env: UV_OFFLINE=]
           --allow-insecure-host <ALLOW_INSECURE_HOST>
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
+          --allow-index-failure
+              Treat unreachable indexes as empty, rather than failing [env: UV_ALLOW_INDEX_FAILURE=]
+          --index-cache-ttl <DURATION>
+              How long to consider cached index responses fresh [env: UV_INDEX_CACHE_TTL=]
+          --index-stale-if-error
+              Use stale cached responses for unreachable indexes, rather than failing [env:
+              UV_INDEX_STALE_IF_ERROR=]
+          --stale-metadata-report <STALE_METADATA_REPORT>
+              Append a JSON report of stale index metadata to the given file [env:
+              UV_STALE_METADATA_REPORT=]
+          --client-cert <CLIENT_CERT>
+              The path to a client certificate to use for mTLS [env: UV_CLIENT_CERT=]
+          --client-key <CLIENT_KEY>
+              The path to the private key for the client certificate [env: UV_CLIENT_KEY=]
+          --limit-rate <RATE>
+              The maximum download rate, in bytes per second, across all requests [env: UV_LIMIT_RATE=]
+          --max-connections-per-host <MAX_CONNECTIONS_PER_HOST>
+              The maximum number of concurrent connections to each host [env:
+              UV_MAX_CONNECTIONS_PER_HOST=]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
           --directory <DIRECTORY>
@@ -321,6 +393,17 @@ fn help_subcommand() {
               
               [env: UV_CACHE_DIR=]
 
+          --scratch-dir <SCRATCH_DIR>
+              Path to the directory in which build backends should create temporary files.
+              
+              Build backends are run with `TMPDIR`, `TEMP`, and `TMP` pointing to a fresh directory
+              within the scratch directory, which is removed once the build completes, whether or not it
+              succeeds.
+              
+              Defaults to a directory within the cache directory.
+              
+              [env: UV_SCRATCH_DIR=]
+
     Python options:
           --managed-python
               Require use of uv-managed Python versions.
@@ -365,6 +448,17 @@ fn help_subcommand() {
               - always: Enables colored output regardless of the detected environment
               - never:  Disables colored output
 
+          --error-format <ERROR_FORMAT>
+              The format in which to report resolution failures.
+              
+              With `json`, a failure to find a solution is reported as a JSON object on stderr,
+              including the derivation tree of the conflict (the packages, version ranges, and causes
+              involved), rather than as prose. All other errors are reported as text.
+
+              Possible values:
+              - text: Report errors as human-readable text
+              - json: Report resolution failures as JSON
+
           --native-tls
               Whether to load TLS certificates from the platform's native store.
               
@@ -399,6 +493,88 @@ fn help_subcommand() {
               
               [env: UV_INSECURE_HOST=]
 
+          --allow-index-failure
+              Treat unreachable indexes as empty, rather than failing.
+              
+              When enabled, if an index other than the default index can't be reached (e.g., due to a
+              connection error, a timeout, or a `5xx` status code), uv will emit a warning and continue
+              as if the index contained no packages. The default index (e.g., PyPI or `--index-url`) is
+              always required.
+              
+              Resolutions performed while an index is unreachable may differ from those performed when
+              all indexes are available; `uv pip compile` records any unreachable indexes in the output
+              file header.
+              
+              [env: UV_ALLOW_INDEX_FAILURE=]
+
+          --index-cache-ttl <DURATION>
+              How long to consider cached index responses fresh.
+              
+              By default, uv respects the `Cache-Control` header returned by the index (e.g., PyPI
+              allows Simple API responses to be cached for ten minutes). When set, cached Simple API
+              responses are instead considered fresh for the given duration, avoiding revalidation
+              requests. A `cache-control` setting on an index takes precedence over this option.
+              
+              Accepts a number of seconds, optionally followed by a unit (`s`, `m`, `h`, or `d`), e.g.,
+              `600` or `10m`.
+              
+              [env: UV_INDEX_CACHE_TTL=]
+
+          --index-stale-if-error
+              Use stale cached responses for unreachable indexes, rather than failing.
+              
+              When enabled, if an index can't be reached (e.g., due to a connection error, a timeout, or
+              a `5xx` status code) while revalidating a cached Simple API response, uv will emit a
+              warning and use the cached response, regardless of its age.
+              
+              [env: UV_INDEX_STALE_IF_ERROR=]
+
+          --stale-metadata-report <STALE_METADATA_REPORT>
+              Append a JSON report of stale index metadata to the given file.
+              
+              When an index serves a PEP 658 metadata file that doesn't match the hash it advertises, uv
+              falls back to reading the metadata from the distribution itself. When provided, each such
+              distribution is also recorded as a JSON object on its own line, with the `index`,
+              `filename`, `url`, `expected`, and `actual` fields, e.g., for reporting to the index
+              operator.
+              
+              [env: UV_STALE_METADATA_REPORT=]
+
+          --client-cert <CLIENT_CERT>
+              The path to a client certificate to use for mTLS.
+              
+              Expects a PEM file containing the certificate and, unless `--client-key` is provided, the
+              private key. Takes precedence over the `SSL_CLIENT_CERT` environment variable.
+              
+              [env: UV_CLIENT_CERT=]
+
+          --client-key <CLIENT_KEY>
+              The path to the private key for the client certificate.
+              
+              Expects a PEM file. Only necessary if the client certificate file doesn't include the
+              private key.
+              
+              [env: UV_CLIENT_KEY=]
+
+          --limit-rate <RATE>
+              The maximum download rate, in bytes per second, across all requests.
+              
+              Accepts a number of bytes, optionally followed by a unit (`K`, `M`, or `G`, in multiples
+              of 1024), e.g., `500K` or `10M`. Defaults to no limit.
+              
+              [env: UV_LIMIT_RATE=]
+
+          --max-connections-per-host <MAX_CONNECTIONS_PER_HOST>
+              The maximum number of concurrent connections to each host.
+              
+              Requests beyond the limit wait until a response from the same host has been received.
+              Archive downloads (i.e., wheels and source distributions) hold their connection until the
+              body has been fully received; all other requests (e.g., for Simple API pages and metadata)
+              release it once the response headers arrive. Defaults to no limit, beyond the overall
+              `UV_CONCURRENT_DOWNLOADS`.
+              
+              [env: UV_MAX_CONNECTIONS_PER_HOST=]
+
           --no-progress
               Hide all progress outputs.
               
@@ -434,6 +610,10 @@ fn help_subcommand() {
           --config-file <CONFIG_FILE>
               The path to a `uv.toml` file to use for configuration.
               
+              May also be an `https://` URL, e.g., to share a configuration file across an organization.
+              Relative paths in a remote configuration file are resolved against the current working
+              directory.
+              
               While uv configuration can be included in a `pyproject.toml` file, it is not allowed in
               this context.
               
@@ -607,6 +787,17 @@ fn help_subsubcommand() {
               
               [env: UV_CACHE_DIR=]
 
+          --scratch-dir <SCRATCH_DIR>
+              Path to the directory in which build backends should create temporary files.
+              
+              Build backends are run with `TMPDIR`, `TEMP`, and `TMP` pointing to a fresh directory
+              within the scratch directory, which is removed once the build completes, whether or not it
+              succeeds.
+              
+              Defaults to a directory within the cache directory.
+              
+              [env: UV_SCRATCH_DIR=]
+
     Python options:
           --managed-python
               Require use of uv-managed Python versions.
@@ -651,6 +842,17 @@ fn help_subsubcommand() {
               - always: Enables colored output regardless of the detected environment
               - never:  Disables colored output
 
+          --error-format <ERROR_FORMAT>
+              The format in which to report resolution failures.
+              
+              With `json`, a failure to find a solution is reported as a JSON object on stderr,
+              including the derivation tree of the conflict (the packages, version ranges, and causes
+              involved), rather than as prose. All other errors are reported as text.
+
+              Possible values:
+              - text: Report errors as human-readable text
+              - json: Report resolution failures as JSON
+
           --native-tls
               Whether to load TLS certificates from the platform's native store.
               
@@ -685,6 +887,88 @@ fn help_subsubcommand() {
               
               [env: UV_INSECURE_HOST=]
 
+          --allow-index-failure
+              Treat unreachable indexes as empty, rather than failing.
+              
+              When enabled, if an index other than the default index can't be reached (e.g., due to a
+              connection error, a timeout, or a `5xx` status code), uv will emit a warning and continue
+              as if the index contained no packages. The default index (e.g., PyPI or `--index-url`) is
+              always required.
+              
+              Resolutions performed while an index is unreachable may differ from those performed when
+              all indexes are available; `uv pip compile` records any unreachable indexes in the output
+              file header.
+              
+              [env: UV_ALLOW_INDEX_FAILURE=]
+
+          --index-cache-ttl <DURATION>
+              How long to consider cached index responses fresh.
+              
+              By default, uv respects the `Cache-Control` header returned by the index (e.g., PyPI
+              allows Simple API responses to be cached for ten minutes). When set, cached Simple API
+              responses are instead considered fresh for the given duration, avoiding revalidation
+              requests. A `cache-control` setting on an index takes precedence over this option.
+              
+              Accepts a number of seconds, optionally followed by a unit (`s`, `m`, `h`, or `d`), e.g.,
+              `600` or `10m`.
+              
+              [env: UV_INDEX_CACHE_TTL=]
+
+          --index-stale-if-error
+              Use stale cached responses for unreachable indexes, rather than failing.
+              
+              When enabled, if an index can't be reached (e.g., due to a connection error, a timeout, or
+              a `5xx` status code) while revalidating a cached Simple API response, uv will emit a
+              warning and use the cached response, regardless of its age.
+              
+              [env: UV_INDEX_STALE_IF_ERROR=]
+
+          --stale-metadata-report <STALE_METADATA_REPORT>
+              Append a JSON report of stale index metadata to the given file.
+              
+              When an index serves a PEP 658 metadata file that doesn't match the hash it advertises, uv
+              falls back to reading the metadata from the distribution itself. When provided, each such
+              distribution is also recorded as a JSON object on its own line, with the `index`,
+              `filename`, `url`, `expected`, and `actual` fields, e.g., for reporting to the index
+              operator.
+              
+              [env: UV_STALE_METADATA_REPORT=]
+
+          --client-cert <CLIENT_CERT>
+              The path to a client certificate to use for mTLS.
+              
+              Expects a PEM file containing the certificate and, unless `--client-key` is provided, the
+              private key. Takes precedence over the `SSL_CLIENT_CERT` environment variable.
+              
+              [env: UV_CLIENT_CERT=]
+
+          --client-key <CLIENT_KEY>
+              The path to the private key for the client certificate.
+              
+              Expects a PEM file. Only necessary if the client certificate file doesn't include the
+              private key.
+              
+              [env: UV_CLIENT_KEY=]
+
+          --limit-rate <RATE>
+              The maximum download rate, in bytes per second, across all requests.
+              
+              Accepts a number of bytes, optionally followed by a unit (`K`, `M`, or `G`, in multiples
+              of 1024), e.g., `500K` or `10M`. Defaults to no limit.
+              
+              [env: UV_LIMIT_RATE=]
+
+          --max-connections-per-host <MAX_CONNECTIONS_PER_HOST>
+              The maximum number of concurrent connections to each host.
+              
+              Requests beyond the limit wait until a response from the same host has been received.
+              Archive downloads (i.e., wheels and source distributions) hold their connection until the
+              body has been fully received; all other requests (e.g., for Simple API pages and metadata)
+              release it once the response headers arrive. Defaults to no limit, beyond the overall
+              `UV_CONCURRENT_DOWNLOADS`.
+              
+              [env: UV_MAX_CONNECTIONS_PER_HOST=]
+
           --no-progress
               Hide all progress outputs.
               
@@ -720,6 +1004,10 @@ fn help_subsubcommand() {
           --config-file <CONFIG_FILE>
               The path to a `uv.toml` file to use for configuration.
               
+              May also be an `https://` URL, e.g., to share a configuration file across an organization.
+              Relative paths in a remote configuration file are resolved against the current working
+              directory.
+              
               While uv configuration can be included in a `pyproject.toml` file, it is not allowed in
               this context.
               
@@ -763,9 +1051,12 @@ fn help_flag_subcommand() {
       update-shell  Ensure that the Python executable directory is on the `PATH`
 
     Cache options:
-      -n, --no-cache               Avoid reading from or writing to the cache, instead using a temporary
-                                   directory for the duration of the operation [env: UV_NO_CACHE=]
-          --cache-dir [CACHE_DIR]  Path to the cache directory [env: UV_CACHE_DIR=]
+      -n, --no-cache                   Avoid reading from or writing to the cache, instead using a
+                                       temporary directory for the duration of the operation [env:
+                                       UV_NO_CACHE=]
+          --cache-dir [CACHE_DIR]      Path to the cache directory [env: UV_CACHE_DIR=]
+          --scratch-dir <SCRATCH_DIR>  Path to the directory in which build backends should create
+                                       temporary files [env: UV_SCRATCH_DIR=]
 
     Python options:
           --managed-python       Require use of uv-managed Python versions [env: UV_MANAGED_PYTHON=]
@@ -780,12 +1071,33 @@ fn help_flag_subcommand() {
               Use verbose output
           --color <COLOR_CHOICE>
               Control the use of color in output [possible values: auto, always, never]
+          --error-format <ERROR_FORMAT>
+              The format in which to report resolution failures [possible values: text, json]
           --native-tls
               Whether to load TLS certificates from the platform's native store [env: UV_NATIVE_TLS=]
           --offline
               Disable network access [env: UV_OFFLINE=]
           --allow-insecure-host <ALLOW_INSECURE_HOST>
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
+          --allow-index-failure
+              Treat unreachable indexes as empty, rather than failing [env: UV_ALLOW_INDEX_FAILURE=]
+          --index-cache-ttl <DURATION>
+              How long to consider cached index responses fresh [env: UV_INDEX_CACHE_TTL=]
+          --index-stale-if-error
+              Use stale cached responses for unreachable indexes, rather than failing [env:
+              UV_INDEX_STALE_IF_ERROR=]
+          --stale-metadata-report <STALE_METADATA_REPORT>
+              Append a JSON report of stale index metadata to the given file [env:
+              UV_STALE_METADATA_REPORT=]
+          --client-cert <CLIENT_CERT>
+              The path to a client certificate to use for mTLS [env: UV_CLIENT_CERT=]
+          --client-key <CLIENT_KEY>
+              The path to the private key for the client certificate [env: UV_CLIENT_KEY=]
+          --limit-rate <RATE>
+              The maximum download rate, in bytes per second, across all requests [env: UV_LIMIT_RATE=]
+          --max-connections-per-host <MAX_CONNECTIONS_PER_HOST>
+              The maximum number of concurrent connections to each host [env:
+              UV_MAX_CONNECTIONS_PER_HOST=]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
           --directory <DIRECTORY>
@@ -846,9 +1158,12 @@ fn help_flag_subsubcommand() {
               UV_COMPILE_BYTECODE=]
 
     Cache options:
-      -n, --no-cache               Avoid reading from or writing to the cache, instead using a temporary
-                                   directory for the duration of the operation [env: UV_NO_CACHE=]
-          --cache-dir [CACHE_DIR]  Path to the cache directory [env: UV_CACHE_DIR=]
+      -n, --no-cache                   Avoid reading from or writing to the cache, instead using a
+                                       temporary directory for the duration of the operation [env:
+                                       UV_NO_CACHE=]
+          --cache-dir [CACHE_DIR]      Path to the cache directory [env: UV_CACHE_DIR=]
+          --scratch-dir <SCRATCH_DIR>  Path to the directory in which build backends should create
+                                       temporary files [env: UV_SCRATCH_DIR=]
 
     Python options:
           --managed-python       Require use of uv-managed Python versions [env: UV_MANAGED_PYTHON=]
@@ -863,12 +1178,33 @@ fn help_flag_subsubcommand() {
               Use verbose output
           --color <COLOR_CHOICE>
               Control the use of color in output [possible values: auto, always, never]
+          --error-format <ERROR_FORMAT>
+              The format in which to report resolution failures [possible values: text, json]
           --native-tls
               Whether to load TLS certificates from the platform's native store [env: UV_NATIVE_TLS=]
           --offline
               Disable network access [env: UV_OFFLINE=]
           --allow-insecure-host <ALLOW_INSECURE_HOST>
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
+          --allow-index-failure
+              Treat unreachable indexes as empty, rather than failing [env: UV_ALLOW_INDEX_FAILURE=]
+          --index-cache-ttl <DURATION>
+              How long to consider cached index responses fresh [env: UV_INDEX_CACHE_TTL=]
+          --index-stale-if-error
+              Use stale cached responses for unreachable indexes, rather than failing [env:
+              UV_INDEX_STALE_IF_ERROR=]
+          --stale-metadata-report <STALE_METADATA_REPORT>
+              Append a JSON report of stale index metadata to the given file [env:
+              UV_STALE_METADATA_REPORT=]
+          --client-cert <CLIENT_CERT>
+              The path to a client certificate to use for mTLS [env: UV_CLIENT_CERT=]
+          --client-key <CLIENT_KEY>
+              The path to the private key for the client certificate [env: UV_CLIENT_KEY=]
+          --limit-rate <RATE>
+              The maximum download rate, in bytes per second, across all requests [env: UV_LIMIT_RATE=]
+          --max-connections-per-host <MAX_CONNECTIONS_PER_HOST>
+              The maximum number of concurrent connections to each host [env:
+              UV_MAX_CONNECTIONS_PER_HOST=]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
           --directory <DIRECTORY>
@@ -1007,9 +1343,12 @@ fn help_with_global_option() {
       help                       Display documentation for a command
 
     Cache options:
-      -n, --no-cache               Avoid reading from or writing to the cache, instead using a temporary
-                                   directory for the duration of the operation [env: UV_NO_CACHE=]
-          --cache-dir [CACHE_DIR]  Path to the cache directory [env: UV_CACHE_DIR=]
+      -n, --no-cache                   Avoid reading from or writing to the cache, instead using a
+                                       temporary directory for the duration of the operation [env:
+                                       UV_NO_CACHE=]
+          --cache-dir [CACHE_DIR]      Path to the cache directory [env: UV_CACHE_DIR=]
+          --scratch-dir <SCRATCH_DIR>  Path to the directory in which build backends should create
+                                       temporary files [env: UV_SCRATCH_DIR=]
 
     Python options:
           --managed-python       Require use of uv-managed Python versions [env: UV_MANAGED_PYTHON=]
@@ -1024,12 +1363,33 @@ fn help_with_global_option() {
               Use verbose output
           --color <COLOR_CHOICE>
               Control the use of color in output [possible values: auto, always, never]
+          --error-format <ERROR_FORMAT>
+              The format in which to report resolution failures [possible values: text, json]
           --native-tls
               Whether to load TLS certificates from the platform's native store [env: UV_NATIVE_TLS=]
           --offline
               Disable network access [env: UV_OFFLINE=]
           --allow-insecure-host <ALLOW_INSECURE_HOST>
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
+          --allow-index-failure
+              Treat unreachable indexes as empty, rather than failing [env: UV_ALLOW_INDEX_FAILURE=]
+          --index-cache-ttl <DURATION>
+              How long to consider cached index responses fresh [env: UV_INDEX_CACHE_TTL=]
+          --index-stale-if-error
+              Use stale cached responses for unreachable indexes, rather than failing [env:
+              UV_INDEX_STALE_IF_ERROR=]
+          --stale-metadata-report <STALE_METADATA_REPORT>
+              Append a JSON report of stale index metadata to the given file [env:
+              UV_STALE_METADATA_REPORT=]
+          --client-cert <CLIENT_CERT>
+              The path to a client certificate to use for mTLS [env: UV_CLIENT_CERT=]
+          --client-key <CLIENT_KEY>
+              The path to the private key for the client certificate [env: UV_CLIENT_KEY=]
+          --limit-rate <RATE>
+              The maximum download rate, in bytes per second, across all requests [env: UV_LIMIT_RATE=]
+          --max-connections-per-host <MAX_CONNECTIONS_PER_HOST>
+              The maximum number of concurrent connections to each host [env:
+              UV_MAX_CONNECTIONS_PER_HOST=]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
           --directory <DIRECTORY>
@@ -1129,9 +1489,12 @@ fn help_with_no_pager() {
       help                       Display documentation for a command
 
     Cache options:
-      -n, --no-cache               Avoid reading from or writing to the cache, instead using a temporary
-                                   directory for the duration of the operation [env: UV_NO_CACHE=]
-          --cache-dir [CACHE_DIR]  Path to the cache directory [env: UV_CACHE_DIR=]
+      -n, --no-cache                   Avoid reading from or writing to the cache, instead using a
+                                       temporary directory for the duration of the operation [env:
+                                       UV_NO_CACHE=]
+          --cache-dir [CACHE_DIR]      Path to the cache directory [env: UV_CACHE_DIR=]
+          --scratch-dir <SCRATCH_DIR>  Path to the directory in which build backends should create
+                                       temporary files [env: UV_SCRATCH_DIR=]
 
     Python options:
           --managed-python       Require use of uv-managed Python versions [env: UV_MANAGED_PYTHON=]
@@ -1146,12 +1509,33 @@ fn help_with_no_pager() {
               Use verbose output
           --color <COLOR_CHOICE>
               Control the use of color in output [possible values: auto, always, never]
+          --error-format <ERROR_FORMAT>
+              The format in which to report resolution failures [possible values: text, json]
           --native-tls
               Whether to load TLS certificates from the platform's native store [env: UV_NATIVE_TLS=]
           --offline
               Disable network access [env: UV_OFFLINE=]
           --allow-insecure-host <ALLOW_INSECURE_HOST>
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
+          --allow-index-failure
+              Treat unreachable indexes as empty, rather than failing [env: UV_ALLOW_INDEX_FAILURE=]
+          --index-cache-ttl <DURATION>
+              How long to consider cached index responses fresh [env: UV_INDEX_CACHE_TTL=]
+          --index-stale-if-error
+              Use stale cached responses for unreachable indexes, rather than failing [env:
+              UV_INDEX_STALE_IF_ERROR=]
+          --stale-metadata-report <STALE_METADATA_REPORT>
+              Append a JSON report of stale index metadata to the given file [env:
+              UV_STALE_METADATA_REPORT=]
+          --client-cert <CLIENT_CERT>
+              The path to a client certificate to use for mTLS [env: UV_CLIENT_CERT=]
+          --client-key <CLIENT_KEY>
+              The path to the private key for the client certificate [env: UV_CLIENT_KEY=]
+          --limit-rate <RATE>
+              The maximum download rate, in bytes per second, across all requests [env: UV_LIMIT_RATE=]
+          --max-connections-per-host <MAX_CONNECTIONS_PER_HOST>
+              The maximum number of concurrent connections to each host [env:
+              UV_MAX_CONNECTIONS_PER_HOST=]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
           --directory <DIRECTORY>
@@ -0,0 +1,106 @@
+use anyhow::Result;
+use assert_fs::prelude::*;
+use insta::assert_snapshot;
+
+use uv_test::uv_snapshot;
+
+/// Generate an HTML index for a directory of distributions.
+#[test]
+fn index_build_html() -> Result<()> {
+    let context = uv_test::test_context_with_versions!(&[]);
+
+    let dist = context.temp_dir.child("dist");
+    dist.child("iniconfig-2.0.0-py3-none-any.whl")
+        .write_binary(b"wheel")?;
+    dist.child("iniconfig-2.0.0.tar.gz")
+        .write_binary(b"sdist")?;
+    dist.child("Foo_Bar-1.0-py3-none-any.whl")
+        .write_binary(b"wheel")?;
+    dist.child("README.md").write_str("Not a distribution")?;
+
+    uv_snapshot!(context.filters(), context.index_build()
+        .arg("dist")
+        .arg("--preview-features")
+        .arg("index-build"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Wrote index for 2 projects (3 files) to dist/simple
+    "
+    );
+
+    let simple = dist.child("simple");
+    assert_snapshot!(fs_err::read_to_string(simple.child("index.html"))?, @r#"
+    <!DOCTYPE html>
+    <html>
+      <head>
+        <meta name="pypi:repository-version" content="1.1">
+        <title>Simple index</title>
+      </head>
+      <body>
+        <a href="foo-bar/">foo-bar</a><br />
+        <a href="iniconfig/">iniconfig</a><br />
+      </body>
+    </html>
+    "#);
+    assert_snapshot!(fs_err::read_to_string(simple.child("iniconfig").child("index.html"))?, @r#"
+    <!DOCTYPE html>
+    <html>
+      <head>
+        <meta name="pypi:repository-version" content="1.1">
+        <title>Links for iniconfig</title>
+      </head>
+      <body>
+        <h1>Links for iniconfig</h1>
+        <a href="../../iniconfig-2.0.0-py3-none-any.whl#sha256=ba59926159d2aa256eb8739b8da7e2b574b960e1202c6d624cbe981cef996c91">iniconfig-2.0.0-py3-none-any.whl</a><br />
+        <a href="../../iniconfig-2.0.0.tar.gz#sha256=714772a9f82b2aeb4fa5f7092d00fe4ac4c9cdeb6800840b6ed39ea64c4d785a">iniconfig-2.0.0.tar.gz</a><br />
+      </body>
+    </html>
+    "#);
+
+    Ok(())
+}
+
+/// Generate an index in a separate output directory.
+#[test]
+fn index_build_out_dir() -> Result<()> {
+    let context = uv_test::test_context_with_versions!(&[]);
+
+    let dist = context.temp_dir.child("dist");
+    dist.child("iniconfig-2.0.0-py3-none-any.whl")
+        .write_binary(b"wheel")?;
+
+    uv_snapshot!(context.filters(), context.index_build()
+        .arg("dist")
+        .arg("--out-dir")
+        .arg("index")
+        .arg("--preview-features")
+        .arg("index-build"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Wrote index for 1 project (1 file) to index
+    "
+    );
+
+    let index = context.temp_dir.child("index");
+    assert_snapshot!(fs_err::read_to_string(index.child("iniconfig").child("index.html"))?, @r#"
+    <!DOCTYPE html>
+    <html>
+      <head>
+        <meta name="pypi:repository-version" content="1.1">
+        <title>Links for iniconfig</title>
+      </head>
+      <body>
+        <h1>Links for iniconfig</h1>
+        <a href="../../dist/iniconfig-2.0.0-py3-none-any.whl#sha256=ba59926159d2aa256eb8739b8da7e2b574b960e1202c6d624cbe981cef996c91">iniconfig-2.0.0-py3-none-any.whl</a><br />
+      </body>
+    </html>
+    "#);
+
+    Ok(())
+}
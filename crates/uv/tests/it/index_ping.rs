@@ -0,0 +1,94 @@
+use anyhow::Result;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use uv_test::uv_snapshot;
+
+/// Check an index that serves the JSON API, metadata files, and range requests.
+#[tokio::test]
+async fn index_ping_json() -> Result<()> {
+    let context = uv_test::test_context_with_versions!(&[]);
+
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/simple/pip/"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(
+            r#"{
+                "meta": {"api-version": "1.1"},
+                "name": "pip",
+                "files": [
+                    {
+                        "filename": "pip-24.0-py3-none-any.whl",
+                        "url": "../../files/pip-24.0-py3-none-any.whl",
+                        "hashes": {},
+                        "core-metadata": true
+                    },
+                    {
+                        "filename": "pip-24.0.tar.gz",
+                        "url": "../../files/pip-24.0.tar.gz",
+                        "hashes": {}
+                    }
+                ]
+            }"#,
+            "application/vnd.pypi.simple.v1+json",
+        ))
+        .mount(&server)
+        .await;
+    Mock::given(method("HEAD"))
+        .and(path("/files/pip-24.0-py3-none-any.whl"))
+        .respond_with(ResponseTemplate::new(200).insert_header("Accept-Ranges", "bytes"))
+        .mount(&server)
+        .await;
+
+    uv_snapshot!(context.filters(), context.index_ping()
+        .arg("--default-index")
+        .arg(format!("{}/simple", server.uri()))
+        .arg("--preview-features")
+        .arg("index-ping"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    http://[LOCALHOST]/simple
+      Status: 200 OK ([TIME])
+      Content type: application/vnd.pypi.simple.v1+json
+      JSON API: yes
+      Metadata files: yes (1/2 files)
+      Range requests: yes
+
+
+    ----- stderr -----
+    "
+    );
+
+    Ok(())
+}
+
+/// Check an index that rejects the request for lack of credentials.
+#[tokio::test]
+async fn index_ping_unauthorized() -> Result<()> {
+    let context = uv_test::test_context_with_versions!(&[]);
+
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .respond_with(ResponseTemplate::new(401))
+        .mount(&server)
+        .await;
+
+    uv_snapshot!(context.filters(), context.index_ping()
+        .arg("--default-index")
+        .arg(format!("{}/simple", server.uri()))
+        .arg("--preview-features")
+        .arg("index-ping"), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    http://[LOCALHOST]/simple
+      Status: 401 Unauthorized ([TIME]) (check the credentials for this index)
+
+
+    ----- stderr -----
+    "
+    );
+
+    Ok(())
+}
@@ -0,0 +1,330 @@
+use std::io::Write;
+
+use anyhow::Result;
+use assert_fs::fixture::ChildPath;
+use assert_fs::prelude::*;
+use fs_err::File;
+use insta::assert_snapshot;
+
+use uv_test::uv_snapshot;
+
+/// Write a minimal wheel to a `--find-links` directory, with the given core metadata fields and
+/// files in its `.dist-info` directory.
+fn write_wheel(
+    dir: &ChildPath,
+    name: &str,
+    version: &str,
+    metadata_version: &str,
+    metadata: &[&str],
+    files: &[(&str, &str)],
+) -> Result<()> {
+    dir.create_dir_all()?;
+    let file = File::create(
+        dir.child(format!("{name}-{version}-py3-none-any.whl"))
+            .path(),
+    )?;
+    let mut writer = zip::ZipWriter::new(file);
+    let options = zip::write::SimpleFileOptions::default();
+
+    let dist_info = format!("{name}-{version}.dist-info");
+    writer.start_file(format!("{dist_info}/METADATA"), options)?;
+    writeln!(
+        writer,
+        "Metadata-Version: {metadata_version}\nName: {name}\nVersion: {version}"
+    )?;
+    for line in metadata {
+        writeln!(writer, "{line}")?;
+    }
+    for (path, contents) in files {
+        writer.start_file(format!("{dist_info}/{path}"), options)?;
+        writer.write_all(contents.as_bytes())?;
+    }
+    writer.start_file(format!("{dist_info}/WHEEL"), options)?;
+    writer.write_all(b"Wheel-Version: 1.0\nRoot-Is-Purelib: true\nTag: py3-none-any\n")?;
+    writer.start_file(format!("{dist_info}/RECORD"), options)?;
+    writer.finish()?;
+    Ok(())
+}
+
+/// Create a project that depends on a few hand-crafted distributions with different kinds of
+/// license metadata.
+fn write_project(context: &uv_test::TestContext) -> Result<()> {
+    let links = context.temp_dir.child("links");
+
+    // A PEP 639 distribution, with a license expression and a declared license file.
+    write_wheel(
+        &links,
+        "foo",
+        "1.0.0",
+        "2.4",
+        &["License-Expression: MIT", "License-File: LICENSE"],
+        &[("licenses/LICENSE", "MIT License\n\nCopyright (c) foo\n")],
+    )?;
+
+    // A legacy distribution, with a license classifier and an undeclared license file.
+    write_wheel(
+        &links,
+        "bar",
+        "2.0.0",
+        "2.1",
+        &["Classifier: License :: OSI Approved :: BSD License"],
+        &[("LICENSE.txt", "BSD 3-Clause License\n\nCopyright (c) bar\n")],
+    )?;
+
+    // A distribution without any license information.
+    write_wheel(&links, "baz", "0.1.0", "2.1", &[], &[])?;
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["foo", "bar", "baz"]
+
+        [tool.uv]
+        no-index = true
+        find-links = ["links"]
+        "#,
+    )?;
+
+    Ok(())
+}
+
+#[test]
+fn licenses_empty() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+        "#,
+    )?;
+
+    // The project itself should be omitted.
+    uv_snapshot!(context.filters(), context.licenses().arg("--preview-features").arg("licenses"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    "
+    );
+
+    Ok(())
+}
+
+#[test]
+fn licenses_text() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+    write_project(&context)?;
+
+    uv_snapshot!(context.filters(), context.licenses().arg("--preview-features").arg("licenses"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Package Version License
+    ------- ------- -----------
+    bar     2.0.0   BSD License
+    baz     0.1.0   UNKNOWN
+    foo     1.0.0   MIT
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    "
+    );
+
+    // The licenses should be reported for the locked versions, without updating the lockfile.
+    uv_snapshot!(context.filters(), context.licenses()
+        .arg("--locked")
+        .arg("--preview-features")
+        .arg("licenses"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Package Version License
+    ------- ------- -----------
+    bar     2.0.0   BSD License
+    baz     0.1.0   UNKNOWN
+    foo     1.0.0   MIT
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    "
+    );
+
+    Ok(())
+}
+
+#[test]
+fn licenses_json() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+    write_project(&context)?;
+
+    uv_snapshot!(context.filters(), context.licenses()
+        .arg("--format")
+        .arg("json")
+        .arg("--exclude")
+        .arg("baz")
+        .arg("--preview-features")
+        .arg("licenses"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [{"name":"bar","version":"2.0.0","license":"BSD License","license_files":["LICENSE.txt"]},{"name":"foo","version":"1.0.0","license":"MIT","license_files":["licenses/LICENSE"]}]
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    "#
+    );
+
+    Ok(())
+}
+
+#[test]
+fn licenses_notice() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+    write_project(&context)?;
+
+    uv_snapshot!(context.filters(), context.licenses()
+        .arg("--notice")
+        .arg("NOTICE")
+        .arg("--preview-features")
+        .arg("licenses"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Package Version License
+    ------- ------- -----------
+    bar     2.0.0   BSD License
+    baz     0.1.0   UNKNOWN
+    foo     1.0.0   MIT
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    Wrote notices for 3 packages to: NOTICE
+    "
+    );
+
+    let notice = fs_err::read_to_string(context.temp_dir.child("NOTICE"))?;
+    assert_snapshot!(notice, @"
+    THIRD-PARTY SOFTWARE NOTICES
+
+    This file lists the third-party packages required by this project, along with their
+    licenses.
+
+    ================================================================================
+    bar 2.0.0
+    License: BSD License
+    ================================================================================
+
+    --- LICENSE.txt ---
+
+    BSD 3-Clause License
+
+    Copyright (c) bar
+
+    ================================================================================
+    baz 0.1.0
+    License: UNKNOWN
+    ================================================================================
+
+    No license files were included in the distribution.
+
+    ================================================================================
+    foo 1.0.0
+    License: MIT
+    ================================================================================
+
+    --- licenses/LICENSE ---
+
+    MIT License
+
+    Copyright (c) foo
+    ");
+
+    Ok(())
+}
+
+/// `License-File` entries that escape the `.dist-info` directory should be rejected.
+#[test]
+fn licenses_invalid_license_file() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    write_wheel(
+        &context.temp_dir.child("links"),
+        "foo",
+        "1.0.0",
+        "2.4",
+        &[
+            "License-Expression: MIT",
+            "License-File: ../../../../etc/passwd",
+        ],
+        &[],
+    )?;
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["foo"]
+
+        [tool.uv]
+        no-index = true
+        find-links = ["links"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.licenses().arg("--preview-features").arg("licenses"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    error: Failed to read the license of `foo==1.0.0`
+      Caused by: Invalid `License-File` entry `../../../../etc/passwd` for `foo`: license files must be relative paths within the distribution
+    "
+    );
+
+    Ok(())
+}
+
+#[test]
+fn licenses_preview_warning() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.licenses(), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv licenses` is experimental and may change without warning. Pass `--preview-features licenses` to disable this warning.
+    Resolved 1 package in [TIME]
+    "
+    );
+
+    Ok(())
+}
@@ -2167,6 +2167,43 @@ fn lock_project_with_build_constraints() -> Result<()> {
     Ok(())
 }
 
+/// When a dependency that is only required under a marker fails to build during resolution, the
+/// error should include the markers under which it was resolved.
+#[test]
+fn lock_build_failure_marker_context() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.8"
+        dependencies = ["requests==1.2 ; python_version < '3.12'"]
+
+        # This should fail the operation, since `requests` depends on `setuptools` at build time.
+        [tool.uv]
+        build-constraint-dependencies = ["setuptools==1"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+      × Failed to download and build `requests==1.2.0`
+      ├─▶ Failed to resolve requirements from `setup.py` build
+      ├─▶ No solution found when resolving: `setuptools>=40.8.0`
+      ╰─▶ Because you require setuptools>=40.8.0 and setuptools==1, we can conclude that your requirements are unsatisfiable.
+      help: `requests` (v1.2.0) was included because `project` (v0.1.0) depends on `requests==1.2` (resolved for `python_full_version < '3.12'`)
+    ");
+
+    Ok(())
+}
+
 /// Lock a project with `uv.tool.build-constraint-dependencies` that reference `tool.uv.sources`.
 #[test]
 fn lock_project_with_build_constraint_sources() -> Result<()> {
@@ -8831,6 +8868,104 @@ fn lock_resolution_mode() -> Result<()> {
     Ok(())
 }
 
+/// Record the packages that allow pre-releases in the lockfile, and re-resolve if they change.
+#[test]
+fn lock_prerelease_packages() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [tool.uv]
+        prerelease-packages = ["flask"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    ");
+
+    let lock = context.read("uv.lock");
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            lock, @r#"
+        version = 1
+        revision = 3
+        requires-python = ">=3.12"
+
+        [options]
+        prerelease-packages = ["flask"]
+        exclude-newer = "2024-03-25T00:00:00Z"
+
+        [[package]]
+        name = "project"
+        version = "0.1.0"
+        source = { virtual = "." }
+        "#
+        );
+    });
+
+    // Re-run with `--locked`.
+    uv_snapshot!(context.filters(), context.lock().arg("--locked"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    ");
+
+    // Allowing pre-releases for additional packages should re-resolve.
+    uv_snapshot!(context.filters(), context.lock().arg("--prerelease").arg("allow:anyio"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolving despite existing lockfile due to change in pre-release packages: `flask` vs. `anyio, flask`
+    Resolved 1 package in [TIME]
+    ");
+
+    let lock = context.read("uv.lock");
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            lock, @r#"
+        version = 1
+        revision = 3
+        requires-python = ">=3.12"
+
+        [options]
+        prerelease-packages = ["anyio", "flask"]
+        exclude-newer = "2024-03-25T00:00:00Z"
+
+        [[package]]
+        name = "project"
+        version = "0.1.0"
+        source = { virtual = "." }
+        "#
+        );
+    });
+
+    Ok(())
+}
+
 /// Lock a requirement from PyPI, filtering out wheels that target an ABI that is non-overlapping
 /// with the `Requires-Python` constraint.
 #[test]
@@ -10621,6 +10756,148 @@ async fn lock_env_credentials() -> Result<()> {
     Ok(())
 }
 
+/// Configure credentials for an index in `pyproject.toml`, referencing environment variables.
+#[tokio::test]
+async fn lock_index_credentials_config() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+    let proxy = crate::pypi_proxy::start().await;
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(&format!(
+        r#"
+        [project]
+        name = "foo"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+
+        [[tool.uv.index]]
+        name = "internal-proxy"
+        url = "{proxy_uri}/basic-auth/simple"
+        default = true
+        credentials = {{ username = "public", password = "${{INTERNAL_PROXY_PASSWORD}}" }}
+        "#,
+        proxy_uri = proxy.uri()
+    ))?;
+
+    // Credentials in project-level configuration should be ignored.
+    uv_snapshot!(context.filters(), context.lock()
+        .env("INTERNAL_PROXY_PASSWORD", "heron"), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: Ignoring `credentials` for index `http://[LOCALHOST]/basic-auth/simple` in `pyproject.toml`; index credentials can only be configured in a user-level or system-level `uv.toml`, or via `--config-file`
+      × No solution found when resolving dependencies:
+      ╰─▶ Because iniconfig was not found in the package registry and your project depends on iniconfig, we can conclude that your project's requirements are unsatisfiable.
+
+          hint: An index URL (http://[LOCALHOST]/basic-auth/simple) could not be queried due to a lack of valid authentication credentials (401 Unauthorized).
+    ");
+
+    // Configure the credentials in a user-provided configuration file instead.
+    pyproject_toml.write_str(&format!(
+        r#"
+        [project]
+        name = "foo"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+
+        [[tool.uv.index]]
+        name = "internal-proxy"
+        url = "{proxy_uri}/basic-auth/simple"
+        default = true
+        "#,
+        proxy_uri = proxy.uri()
+    ))?;
+
+    let config = context.temp_dir.child("config").child("uv.toml");
+    config.write_str(&format!(
+        r#"
+        [[index]]
+        name = "internal-proxy"
+        url = "{proxy_uri}/basic-auth/simple"
+        credentials = {{ username = "public", password = "${{INTERNAL_PROXY_PASSWORD}}" }}
+        "#,
+        proxy_uri = proxy.uri()
+    ))?;
+
+    // If the referenced environment variable is missing, the credentials should be ignored.
+    uv_snapshot!(context.filters(), context.lock()
+        .arg("--config-file")
+        .arg(config.path()), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: Ignoring credentials for index `http://[LOCALHOST]/basic-auth/simple`: Environment variable `INTERNAL_PROXY_PASSWORD` is not set
+      × No solution found when resolving dependencies:
+      ╰─▶ Because iniconfig was not found in the package registry and your project depends on iniconfig, we can conclude that your project's requirements are unsatisfiable.
+
+          hint: An index URL (http://[LOCALHOST]/basic-auth/simple) could not be queried due to a lack of valid authentication credentials (401 Unauthorized).
+    ");
+
+    // Provide the password via the environment variable.
+    uv_snapshot!(context.filters(), context.lock()
+        .arg("--config-file")
+        .arg(config.path())
+        .env("INTERNAL_PROXY_PASSWORD", "heron"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    ");
+
+    // The lockfile should omit the credentials.
+    let lock = fs_err::read_to_string(context.temp_dir.join("uv.lock")).unwrap();
+    assert!(!lock.contains("heron"));
+
+    // A token can't be combined with a username.
+    pyproject_toml.write_str(&format!(
+        r#"
+        [project]
+        name = "foo"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+
+        [[tool.uv.index]]
+        name = "internal-proxy"
+        url = "{proxy_uri}/basic-auth/simple"
+        default = true
+        credentials = {{ username = "public", token = "${{INTERNAL_PROXY_TOKEN}}" }}
+        "#,
+        proxy_uri = proxy.uri()
+    ))?;
+
+    uv_snapshot!(context.filters(), context.lock(), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: Failed to parse `pyproject.toml` during settings discovery:
+      TOML parse error at line 8, column 9
+        |
+      8 |         [[tool.uv.index]]
+        |         ^^^^^^^^^^^^^^^^^
+      Invalid `credentials` for index `http://[LOCALHOST]/basic-auth/simple`: `token` cannot be provided with `username` or `password`
+
+    error: Failed to parse: `pyproject.toml`
+      Caused by: TOML parse error at line 8, column 9
+      |
+    8 |         [[tool.uv.index]]
+      |         ^^^^^^^^^^^^^^^^^
+    Invalid `credentials` for index `http://[LOCALHOST]/basic-auth/simple`: `token` cannot be provided with `username` or `password`
+    ");
+
+    Ok(())
+}
+
 /// Test solving for packages that are pinned to separate indexes in the same realm.
 /// This requires the credentials to be cached at the URL-level instead of the realm-level, or
 /// credentials for one index will be used for both indexes and the request will fail.
@@ -11468,6 +11745,67 @@ fn lock_upgrade_package() -> Result<()> {
     Ok(())
 }
 
+/// Upgrade a specific package within a range with `--upgrade-package`.
+#[test]
+fn lock_upgrade_package_constraint() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    // Constrain `anyio`.
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio<=2"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    ");
+
+    // Remove the constraint.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio"]
+        "#,
+    )?;
+
+    // Upgrade `anyio`, but only within the requested bound.
+    uv_snapshot!(context.filters(), context.lock().arg("--upgrade-package").arg("anyio<4"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    Updated anyio v2.0.0 -> v3.7.1
+    ");
+
+    // The bound isn't persisted, so the lockfile is still up-to-date.
+    uv_snapshot!(context.filters(), context.lock().arg("--locked"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    ");
+
+    Ok(())
+}
+
 /// Check that we discard the fork marker from the lockfile when using `--upgrade`.
 #[test]
 fn lock_upgrade_drop_fork_markers() -> Result<()> {
@@ -13790,6 +14128,60 @@ fn lock_mismatched_versions() -> Result<()> {
     Ok(())
 }
 
+/// Re-resolve an existing lockfile that contains a package listed in `excluded-packages`, and
+/// refuse to install such a package from a frozen lockfile.
+#[test]
+fn lock_excluded_packages() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    ");
+
+    let uv_toml = context.temp_dir.child("uv.toml");
+    uv_toml.write_str(r#"excluded-packages = ["iniconfig"]"#)?;
+
+    uv_snapshot!(context.filters(), context.lock(), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolving despite existing lockfile due to excluded package: `iniconfig`
+      × No solution found when resolving dependencies:
+      ╰─▶ Because iniconfig was excluded by the `excluded-packages` setting and
+          your project depends on iniconfig, we can conclude that your project's
+          requirements are unsatisfiable.
+    ");
+
+    uv_snapshot!(context.filters(), context.sync().arg("--frozen"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Refusing to install `iniconfig`, which is excluded by the `excluded-packages` setting
+    ");
+
+    Ok(())
+}
+
 /// Test that `--no-sources-package` allows selectively disabling sources for specific packages.
 #[test]
 #[cfg(feature = "test-git")]
@@ -15041,7 +15433,7 @@ fn lock_add_member_with_build_system() -> Result<()> {
       ╰─▶ Because anyio was not found in the cache and leaf depends on anyio>3, we can conclude that leaf's requirements are unsatisfiable.
           And because your workspace requires leaf, we can conclude that your workspace's requirements are unsatisfiable.
 
-          hint: Packages were unavailable because the network was disabled. When the network is disabled, registry packages may only be read from the cache.
+          hint: Packages were unavailable because the network was disabled. When the network is disabled, registry packages may only be read from the cache (pre-populate the `simple-v22` and `wheels-v8` cache buckets while online).
     ");
 
     // Re-run without `--locked`.
@@ -15249,7 +15641,7 @@ fn lock_add_member_without_build_system() -> Result<()> {
       ╰─▶ Because anyio was not found in the cache and leaf depends on anyio>3, we can conclude that leaf's requirements are unsatisfiable.
           And because your workspace requires leaf, we can conclude that your workspace's requirements are unsatisfiable.
 
-          hint: Packages were unavailable because the network was disabled. When the network is disabled, registry packages may only be read from the cache.
+          hint: Packages were unavailable because the network was disabled. When the network is disabled, registry packages may only be read from the cache (pre-populate the `simple-v22` and `wheels-v8` cache buckets while online).
     ");
 
     // Re-run without `--locked`.
@@ -18833,6 +19225,84 @@ fn lock_explicit_index() -> Result<()> {
     Ok(())
 }
 
+/// Pin a package to an index by URL, without declaring a named index.
+#[test]
+fn lock_url_pinned_index() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig==2.0.0"]
+
+        [tool.uv.sources]
+        iniconfig = { index = "https://test.pypi.org/simple" }
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    ");
+
+    let lock = fs_err::read_to_string(context.temp_dir.join("uv.lock")).unwrap();
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            lock, @r#"
+        version = 1
+        revision = 3
+        requires-python = ">=3.12"
+
+        [options]
+        exclude-newer = "2024-03-25T00:00:00Z"
+
+        [[package]]
+        name = "iniconfig"
+        version = "2.0.0"
+        source = { registry = "https://test.pypi.org/simple" }
+        sdist = { url = "https://test-files.pythonhosted.org/packages/d7/4b/cbd8e699e64a6f16ca3a8220661b5f83792b3017d0f79807cb8708d33913/iniconfig-2.0.0.tar.gz", hash = "sha256:2d91e135bf72d31a410b17c16da610a82cb55f6b0477d1a902134b24a455b8b3", size = 4646, upload-time = "2023-01-07T11:08:16.826Z" }
+        wheels = [
+            { url = "https://test-files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl", hash = "sha256:b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374", size = 5892, upload-time = "2023-01-07T11:08:14.843Z" },
+        ]
+
+        [[package]]
+        name = "project"
+        version = "0.1.0"
+        source = { virtual = "." }
+        dependencies = [
+            { name = "iniconfig" },
+        ]
+
+        [package.metadata]
+        requires-dist = [{ name = "iniconfig", specifier = "==2.0.0", index = "https://test.pypi.org/simple" }]
+        "#
+        );
+    });
+
+    // Re-run with `--locked`.
+    uv_snapshot!(context.filters(), context.lock().arg("--locked"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    ");
+
+    Ok(())
+}
+
 #[test]
 fn lock_explicit_default_index() -> Result<()> {
     let context = uv_test::test_context!("3.12");
@@ -23712,6 +24182,131 @@ fn lock_multiple_index_with_absent_group() -> Result<()> {
     Ok(())
 }
 
+/// Write the locked dependency graph with `--emit-graph`.
+#[test]
+fn lock_emit_graph() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==3.7.0"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--emit-graph").arg("graph.dot"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    ");
+
+    // The project's own requirements include their specifiers, while the dependencies of
+    // registry packages are labeled with their locked markers, if any.
+    let graph = context.read("graph.dot");
+    assert_snapshot!(graph, @r#"
+    digraph {
+        "anyio==3.7.0";
+        "idna==3.6";
+        "project==0.1.0" [shape=box];
+        "sniffio==1.3.1";
+        "anyio==3.7.0" -> "idna==3.6" [label="idna"];
+        "anyio==3.7.0" -> "sniffio==1.3.1" [label="sniffio"];
+        "project==0.1.0" -> "anyio==3.7.0" [label="anyio==3.7.0"];
+    }
+    "#);
+
+    Ok(())
+}
+
+/// Compare the existing lockfile against a fresh resolution with `--diff`.
+#[test]
+fn lock_diff() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==3.7.0", "iniconfig"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    ");
+
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==3.0.0", "typing-extensions"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--diff"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Downgrade anyio v3.7.0 -> v3.0.0
+    Remove iniconfig v2.0.0
+    Add typing-extensions v4.10.0
+
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    ");
+
+    // The lockfile should be left unchanged.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio", "iniconfig"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--diff").arg("--output-format").arg("json"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    {
+      "added": [],
+      "removed": [],
+      "upgraded": [
+        {
+          "name": "anyio",
+          "from": "3.7.0",
+          "to": "4.3.0"
+        }
+      ],
+      "downgraded": []
+    }
+
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    "#);
+
+    Ok(())
+}
+
 #[test]
 fn lock_dry_run() -> Result<()> {
     let context = uv_test::test_context!("3.12");
@@ -14,15 +14,27 @@ mod build_backend;
 #[cfg(all(feature = "test-python", feature = "test-pypi"))]
 mod cache;
 
+#[cfg(all(feature = "test-python", feature = "test-pypi"))]
+mod cache_bundle;
+
 #[cfg(all(feature = "test-python", feature = "test-pypi"))]
 mod cache_clean;
 
+#[cfg(all(feature = "test-python", feature = "test-pypi"))]
+mod cache_list;
+
 #[cfg(all(feature = "test-python", feature = "test-pypi"))]
 mod cache_prune;
 
 #[cfg(all(feature = "test-python", feature = "test-pypi"))]
 mod cache_size;
 
+#[cfg(all(feature = "test-python", feature = "test-pypi"))]
+mod cache_verify;
+
+#[cfg(all(feature = "test-python", feature = "test-pypi"))]
+mod cache_warm;
+
 #[cfg(all(
     feature = "test-python",
     feature = "test-pypi",
@@ -41,6 +53,13 @@ mod format;
 
 mod help;
 
+mod index_build;
+
+mod index_ping;
+
+#[cfg(feature = "test-python")]
+mod licenses;
+
 #[cfg(all(feature = "test-python", feature = "test-pypi", feature = "test-git"))]
 mod init;
 
@@ -65,9 +84,19 @@ mod pip_compile;
 
 mod pip_compile_scenarios;
 
+#[cfg(all(feature = "test-python", feature = "test-pypi"))]
+mod pip_download;
+
+#[cfg(all(feature = "test-python", feature = "test-pypi"))]
+mod pip_explain;
+
 #[cfg(all(feature = "test-python", feature = "test-pypi"))]
 mod pip_freeze;
 
+mod pip_hash;
+
+mod pip_index;
+
 #[cfg(all(feature = "test-python", feature = "test-pypi"))]
 mod pip_install;
 
@@ -77,6 +106,9 @@ mod pip_list;
 
 mod pip_show;
 
+#[cfg(all(feature = "test-python", feature = "test-pypi"))]
+mod pip_stub;
+
 #[cfg(all(feature = "test-python", feature = "test-pypi"))]
 mod pip_sync;
 
@@ -84,6 +116,12 @@ mod pip_debug;
 mod pip_tree;
 mod pip_uninstall;
 
+#[cfg(all(feature = "test-python", feature = "test-pypi"))]
+mod pip_verify;
+
+#[cfg(all(feature = "test-python", feature = "test-pypi"))]
+mod pip_wheel;
+
 #[cfg(feature = "test-pypi")]
 mod publish;
 
@@ -140,6 +178,9 @@ mod tool_upgrade;
 #[cfg(all(feature = "test-python", feature = "test-pypi"))]
 mod tree;
 
+#[cfg(all(feature = "test-python", feature = "test-pypi"))]
+mod vendor;
+
 #[cfg(feature = "test-python")]
 mod venv;
 
@@ -1,18 +1,21 @@
 use std::convert::Infallible;
 use std::io;
+use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
 
+use anyhow::Result;
+use assert_cmd::assert::OutputAssertExt;
 use assert_fs::fixture::{ChildPath, FileWriteStr, PathChild};
 use bytes::Bytes;
 use http::StatusCode;
 use http_body_util::combinators::BoxBody;
-use http_body_util::{BodyExt, StreamBody};
+use http_body_util::{BodyExt, Full, StreamBody};
 use hyper::body::Frame;
 use hyper::service::service_fn;
 use hyper_util::rt::TokioIo;
 use serde_json::json;
 use tokio_stream::wrappers::ReceiverStream;
-use wiremock::matchers::{any, method};
+use wiremock::matchers::{any, method, path};
 use wiremock::{Mock, MockServer, Request, ResponseTemplate};
 
 use uv_static::EnvVars;
@@ -283,6 +286,210 @@ async fn simple_io_err() {
     ");
 }
 
+/// With `--allow-index-failure`, an extra index that returns HTTP status 500 is treated as empty,
+/// and recorded in the output header.
+#[tokio::test]
+async fn simple_http_500_allow_index_failure() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("iniconfig==2.0.0")?;
+
+    let (_server_drop_guard, mock_server_uri) = http_error_server().await;
+
+    uv_snapshot!(context.filters(), context
+        .pip_compile()
+        .arg("requirements.in")
+        .arg("--extra-index-url")
+        .arg(&mock_server_uri)
+        .arg("--allow-index-failure")
+        .env(EnvVars::UV_TEST_NO_HTTP_RETRY_DELAY, "true"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --allow-index-failure
+    # The following indexes were unreachable and treated as empty:
+    #    http://[LOCALHOST]/
+    iniconfig==2.0.0
+        # via -r requirements.in
+
+    ----- stderr -----
+    warning: Index `http://[LOCALHOST]/` is unreachable and will be treated as empty
+    Resolved 1 package in [TIME]
+    ");
+
+    // The default index is always required.
+    uv_snapshot!(context.filters(), context
+        .pip_compile()
+        .arg("requirements.in")
+        .arg("--index-url")
+        .arg(&mock_server_uri)
+        .arg("--allow-index-failure")
+        .env(EnvVars::UV_TEST_NO_HTTP_RETRY_DELAY, "true"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Request failed after 3 retries in [TIME]
+      Caused by: Failed to fetch: `http://[LOCALHOST]/iniconfig/`
+      Caused by: HTTP status server error (500 Internal Server Error) for url (http://[LOCALHOST]/iniconfig/)
+    ");
+
+    Ok(())
+}
+
+/// Serves a Simple API page for `iniconfig` once, which must be revalidated on every subsequent
+/// request, then answers with HTTP status 500.
+async fn flaky_simple_server() -> (MockServer, String) {
+    let server = MockServer::start().await;
+    let page = r#"<!DOCTYPE html>
+<html>
+  <body>
+    <a href="https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl#sha256=b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374">iniconfig-2.0.0-py3-none-any.whl</a>
+  </body>
+</html>
+"#;
+    Mock::given(method("GET"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_raw(page, "text/html")
+                .insert_header("Cache-Control", "max-age=0"),
+        )
+        .up_to_n_times(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .respond_with(ResponseTemplate::new(StatusCode::INTERNAL_SERVER_ERROR))
+        .mount(&server)
+        .await;
+
+    let mock_server_uri = server.uri();
+    (server, mock_server_uri)
+}
+
+/// With `--index-stale-if-error`, a stale cached response is used if the index becomes
+/// unreachable.
+#[tokio::test]
+async fn simple_http_500_stale_if_error() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("iniconfig==2.0.0")?;
+
+    let (_server_drop_guard, mock_server_uri) = flaky_simple_server().await;
+
+    context
+        .pip_compile()
+        .arg("requirements.in")
+        .arg("--index-url")
+        .arg(&mock_server_uri)
+        .assert()
+        .success();
+
+    // The cached response is stale, and revalidation fails.
+    uv_snapshot!(context.filters(), context
+        .pip_compile()
+        .arg("requirements.in")
+        .arg("--index-url")
+        .arg(&mock_server_uri)
+        .env(EnvVars::UV_TEST_NO_HTTP_RETRY_DELAY, "true"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Request failed after 3 retries in [TIME]
+      Caused by: Failed to fetch: `http://[LOCALHOST]/iniconfig/`
+      Caused by: HTTP status server error (500 Internal Server Error) for url (http://[LOCALHOST]/iniconfig/)
+    ");
+
+    uv_snapshot!(context.filters(), context
+        .pip_compile()
+        .arg("requirements.in")
+        .arg("--index-url")
+        .arg(&mock_server_uri)
+        .arg("--index-stale-if-error")
+        .env(EnvVars::UV_TEST_NO_HTTP_RETRY_DELAY, "true"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --index-stale-if-error
+    iniconfig==2.0.0
+        # via -r requirements.in
+
+    ----- stderr -----
+    warning: Index `http://[LOCALHOST]/` is unreachable; using stale cached responses
+    Resolved 1 package in [TIME]
+    ");
+
+    Ok(())
+}
+
+/// With `--index-cache-ttl`, cached responses are considered fresh for the given duration,
+/// regardless of the `Cache-Control` header returned by the index.
+#[tokio::test]
+async fn simple_index_cache_ttl() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("iniconfig==2.0.0")?;
+
+    let (_server_drop_guard, mock_server_uri) = flaky_simple_server().await;
+
+    context
+        .pip_compile()
+        .arg("requirements.in")
+        .arg("--index-url")
+        .arg(&mock_server_uri)
+        .arg("--index-cache-ttl")
+        .arg("10m")
+        .assert()
+        .success();
+
+    // The cached response is still fresh, so the index isn't queried.
+    uv_snapshot!(context.filters(), context
+        .pip_compile()
+        .arg("requirements.in")
+        .arg("--index-url")
+        .arg(&mock_server_uri)
+        .env(EnvVars::UV_INDEX_CACHE_TTL, "10m")
+        .env(EnvVars::UV_TEST_NO_HTTP_RETRY_DELAY, "true"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in
+    iniconfig==2.0.0
+        # via -r requirements.in
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    ");
+
+    // With `--refresh`, the TTL is ignored.
+    uv_snapshot!(context.filters(), context
+        .pip_compile()
+        .arg("requirements.in")
+        .arg("--index-url")
+        .arg(&mock_server_uri)
+        .arg("--index-cache-ttl")
+        .arg("10m")
+        .arg("--refresh-package")
+        .arg("iniconfig")
+        .env(EnvVars::UV_TEST_NO_HTTP_RETRY_DELAY, "true"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Request failed after 3 retries in [TIME]
+      Caused by: Failed to fetch: `http://[LOCALHOST]/iniconfig/`
+      Caused by: HTTP status server error (500 Internal Server Error) for url (http://[LOCALHOST]/iniconfig/)
+    ");
+
+    Ok(())
+}
+
 /// Check the find links error message when the server returns HTTP status 500, a retryable error.
 #[tokio::test]
 async fn find_links_http_500() {
@@ -980,6 +1187,147 @@ async fn proxy_schemeless_url_in_uv_toml() {
     );
 }
 
+/// Test that all-proxy in uv.toml routes requests through the proxy, with Basic authentication.
+#[cfg(feature = "test-pypi")]
+#[tokio::test]
+async fn proxy_all_proxy_with_auth_in_uv_toml() {
+    let context = uv_test::test_context!("3.12");
+
+    let target_server = MockServer::start().await;
+    Mock::given(any())
+        .respond_with(ResponseTemplate::new(200))
+        .mount(&target_server)
+        .await;
+
+    let proxy_server = MockServer::start().await;
+    mock_simple_api(&proxy_server).await;
+
+    let target_uri = target_server.uri();
+    let proxy_uri = proxy_server.uri();
+    let proxy_host = proxy_uri
+        .strip_prefix("http://")
+        .unwrap_or(proxy_uri.as_str());
+
+    let context = context
+        .with_filter((target_uri.clone(), "[TARGET]"))
+        .with_filter((proxy_uri.clone(), "[PROXY]"));
+
+    let uv_toml = context.temp_dir.child("uv.toml");
+    uv_toml
+        .write_str(&format!(
+            r#"
+all-proxy = "http://user:pa%40ss@{proxy_host}"
+# The mock proxy can't tunnel HTTPS requests for the wheel itself.
+no-proxy = ["files.pythonhosted.org"]
+"#
+        ))
+        .unwrap();
+
+    uv_snapshot!(context.filters(), context
+        .pip_install()
+        .arg("iniconfig")
+        .arg("--index-url")
+        .arg(&target_uri)
+        .arg("--config-file")
+        .arg(uv_toml.path())
+        .env_remove(EnvVars::HTTP_PROXY)
+        .env_remove(EnvVars::HTTPS_PROXY)
+        .env_remove(EnvVars::ALL_PROXY)
+        .env_remove(EnvVars::NO_PROXY), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    ");
+
+    // The credentials are percent-decoded and sent as `Proxy-Authorization` (`user:pa@ss`).
+    let requests = proxy_server.received_requests().await.unwrap();
+    assert!(
+        !requests.is_empty(),
+        "Proxy should have received the request"
+    );
+    assert!(
+        requests.iter().all(|request| {
+            request
+                .headers
+                .get(http::header::PROXY_AUTHORIZATION)
+                .is_some_and(|value| value == "Basic dXNlcjpwYUBzcw==")
+        }),
+        "Proxy requests should include the proxy credentials"
+    );
+    assert!(
+        !has_received_requests(&target_server).await,
+        "Target should NOT have been called directly when proxy is configured"
+    );
+}
+
+/// Test that `NO_PROXY` is respected for proxies configured in uv.toml, if `no-proxy` is unset.
+#[cfg(feature = "test-pypi")]
+#[tokio::test]
+async fn proxy_no_proxy_env_with_uv_toml() {
+    let context = uv_test::test_context!("3.12");
+
+    let target_server = MockServer::start().await;
+    mock_simple_api(&target_server).await;
+
+    let proxy_server = MockServer::start().await;
+    Mock::given(any())
+        .respond_with(ResponseTemplate::new(200))
+        .mount(&proxy_server)
+        .await;
+
+    let target_uri = target_server.uri();
+    let proxy_uri = proxy_server.uri();
+
+    let target_url = url::Url::parse(&target_uri).unwrap();
+    let target_host = target_url.host_str().unwrap();
+
+    let context = context
+        .with_filter((target_uri.clone(), "[TARGET]"))
+        .with_filter((proxy_uri.clone(), "[PROXY]"));
+
+    let uv_toml = context.temp_dir.child("uv.toml");
+    uv_toml
+        .write_str(&format!(r#"http-proxy = "{proxy_uri}""#))
+        .unwrap();
+
+    uv_snapshot!(context.filters(), context
+        .pip_install()
+        .arg("iniconfig")
+        .arg("--index-url")
+        .arg(&target_uri)
+        .arg("--config-file")
+        .arg(uv_toml.path())
+        .env_remove(EnvVars::HTTP_PROXY)
+        .env_remove(EnvVars::HTTPS_PROXY)
+        .env_remove(EnvVars::ALL_PROXY)
+        .env(EnvVars::NO_PROXY, target_host), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    ");
+
+    assert!(
+        has_received_requests(&target_server).await,
+        "Target should have received the request directly when in `NO_PROXY`"
+    );
+    assert!(
+        !has_received_requests(&proxy_server).await,
+        "Proxy should NOT have received requests when target is in `NO_PROXY`"
+    );
+}
+
 #[test]
 fn connect_timeout_index() {
     let context = uv_test::test_context!("3.12");
@@ -1101,3 +1449,308 @@ async fn retry_read_timeout_stream() {
       ╰─▶ Failed to download distribution due to network timeout. Try increasing UV_HTTP_TIMEOUT (current value: [TIME]).
     ");
 }
+
+#[tokio::test]
+async fn retry_stall_timeout_stream() {
+    let context = uv_test::test_context!("3.12");
+
+    let (server, _guard) = read_timeout_server();
+
+    // The stall timeout aborts the download before the read timeout.
+    let start = Instant::now();
+    uv_snapshot!(context.filters(), context
+        .pip_install()
+        .arg(format!("{server}/tqdm-0.1-py3-none-any.whl"))
+        .env(EnvVars::UV_HTTP_TIMEOUT, "30")
+        .env(EnvVars::UV_HTTP_STALL_TIMEOUT, "1")
+        .env(EnvVars::UV_HTTP_RETRIES, "1"), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+      × Failed to download `tqdm @ http://[LOCALHOST]/tqdm-0.1-py3-none-any.whl`
+      ├─▶ Request failed after 1 retry in [TIME]
+      ├─▶ Failed to read metadata: `http://[LOCALHOST]/tqdm-0.1-py3-none-any.whl`
+      ├─▶ Failed to read from zip file
+      ├─▶ an upstream reader returned an error: Download stalled: no data received for [TIME]. Try increasing UV_HTTP_STALL_TIMEOUT (current value: [TIME]).
+      ╰─▶ Download stalled: no data received for [TIME]. Try increasing UV_HTTP_STALL_TIMEOUT (current value: [TIME]).
+    ");
+
+    // Assumption: There's less than 10s overhead for two stalled attempts and startup.
+    let elapsed = start.elapsed();
+    assert!(
+        elapsed < Duration::from_secs(12),
+        "Test with 1s stall timeout took too long"
+    );
+}
+
+/// A wheel for `resumable==1.0.0`, large enough that an interrupted download retains some of it.
+fn resumable_wheel() -> Result<Vec<u8>> {
+    use std::io::Write;
+
+    let mut writer = zip::ZipWriter::new(io::Cursor::new(Vec::new()));
+    let options =
+        zip::write::SimpleFileOptions::default().compression_method(zip::CompressionMethod::Stored);
+
+    // Incompressible data, such that the size of the wheel is predictable.
+    writer.start_file("resumable/data.bin", options)?;
+    let mut state = 0x2545_f491_4f6c_dd1d_u64;
+    for _ in 0..(256 * 1024) {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        writer.write_all(&state.to_le_bytes()[..4])?;
+    }
+    writer.start_file("resumable-1.0.0.dist-info/METADATA", options)?;
+    writer.write_all(b"Metadata-Version: 2.1\nName: resumable\nVersion: 1.0.0\n")?;
+    writer.start_file("resumable-1.0.0.dist-info/WHEEL", options)?;
+    writer.write_all(b"Wheel-Version: 1.0\nRoot-Is-Purelib: true\nTag: py3-none-any\n")?;
+    writer.start_file("resumable-1.0.0.dist-info/RECORD", options)?;
+    Ok(writer.finish()?.into_inner())
+}
+
+/// Serves a wheel (and its metadata) that supports range requests, but whose full download is
+/// always interrupted halfway through.
+#[derive(Clone)]
+struct InterruptedWheel {
+    wheel: Bytes,
+    /// The current `ETag` of the wheel.
+    etag: &'static str,
+    /// The `ETag` reported by responses for the full file, which is stale if the wheel changed
+    /// in between requests.
+    full_etag: &'static str,
+    /// The `Range` and `If-Range` headers of the received range requests.
+    ranges: Arc<Mutex<Vec<(String, String)>>>,
+}
+
+impl InterruptedWheel {
+    fn new(wheel: Vec<u8>, etag: &'static str, full_etag: &'static str) -> Self {
+        Self {
+            wheel: Bytes::from(wheel),
+            etag,
+            full_etag,
+            ranges: Arc::default(),
+        }
+    }
+
+    fn respond(
+        &self,
+        request: &hyper::Request<hyper::body::Incoming>,
+    ) -> hyper::Response<BoxBody<Bytes, Infallible>> {
+        if request.uri().path().ends_with(".metadata") {
+            return hyper::Response::builder()
+                .body(
+                    Full::new(Bytes::from_static(
+                        b"Metadata-Version: 2.1\nName: resumable\nVersion: 1.0.0\n",
+                    ))
+                    .boxed(),
+                )
+                .unwrap();
+        }
+
+        let len = self.wheel.len();
+        let header = |name| {
+            request
+                .headers()
+                .get(name)
+                .map(|value: &http::HeaderValue| value.to_str().unwrap().to_string())
+        };
+        let Some(range) = header("range") else {
+            // Report the full length, but close the connection halfway through the body.
+            let (tx, rx) = tokio::sync::mpsc::channel(1);
+            let half = self.wheel.slice(..len / 2);
+            tokio::spawn(async move {
+                let _ = tx.send(Ok(Frame::data(half))).await;
+            });
+            return hyper::Response::builder()
+                .header("accept-ranges", "bytes")
+                .header("etag", self.full_etag)
+                .header("content-length", len)
+                .body(StreamBody::new(ReceiverStream::new(rx)).boxed())
+                .unwrap();
+        };
+        let if_range = header("if-range").unwrap_or_default();
+        self.ranges
+            .lock()
+            .unwrap()
+            .push((range.clone(), if_range.clone()));
+
+        // If the wheel changed, respond with the full file.
+        if if_range != self.etag {
+            return hyper::Response::builder()
+                .header("accept-ranges", "bytes")
+                .header("etag", self.etag)
+                .body(Full::new(self.wheel.clone()).boxed())
+                .unwrap();
+        }
+
+        let start = range
+            .strip_prefix("bytes=")
+            .and_then(|range| range.strip_suffix('-'))
+            .and_then(|start| start.parse::<usize>().ok())
+            .unwrap();
+        hyper::Response::builder()
+            .status(StatusCode::PARTIAL_CONTENT)
+            .header("accept-ranges", "bytes")
+            .header("etag", self.etag)
+            .header("content-range", format!("bytes {start}-{}/{len}", len - 1))
+            .body(Full::new(self.wheel.slice(start..)).boxed())
+            .unwrap()
+    }
+}
+
+/// Serve the [`InterruptedWheel`], returning the URL of the wheel and a drop guard that shuts
+/// down the server.
+fn interrupted_wheel_server(wheel: InterruptedWheel) -> (String, impl Drop) {
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    listener.set_nonblocking(true).unwrap();
+    let url = format!(
+        "http://{}/files/resumable-1.0.0-py3-none-any.whl",
+        listener.local_addr().unwrap()
+    );
+
+    let (shutdown_tx, shutdown_rx) = tokio::sync::oneshot::channel::<()>();
+
+    std::thread::spawn(move || {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+        runtime.block_on(async move {
+            let listener = tokio::net::TcpListener::from_std(listener).unwrap();
+            tokio::select! {
+                _ = async {
+                    loop {
+                        let (stream, _) = listener.accept().await.unwrap();
+                        let io = TokioIo::new(stream);
+                        let wheel = wheel.clone();
+
+                        tokio::spawn(async move {
+                            let _ = hyper_util::server::conn::auto::Builder::new(
+                                hyper_util::rt::TokioExecutor::new(),
+                            )
+                            .serve_connection(
+                                io,
+                                service_fn(move |request| {
+                                    std::future::ready(Ok::<_, Infallible>(wheel.respond(&request)))
+                                }),
+                            )
+                            .await;
+                        });
+                    }
+                } => {}
+                _ = shutdown_rx => {}
+            }
+        });
+    });
+
+    (url, shutdown_tx)
+}
+
+/// Serve an index for `resumable==1.0.0`, with the wheel itself at the given URL.
+async fn resumable_index(wheel_url: &str) -> MockServer {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/simple/resumable/"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_raw(
+                json!({
+                    "meta": { "api-version": "1.1" },
+                    "name": "resumable",
+                    "files": [{
+                        "filename": "resumable-1.0.0-py3-none-any.whl",
+                        "url": wheel_url,
+                        "hashes": {},
+                        "core-metadata": true,
+                        "upload-time": "2024-01-01T00:00:00Z",
+                    }],
+                })
+                .to_string(),
+                "application/vnd.pypi.simple.v1+json",
+            ),
+        )
+        .mount(&server)
+        .await;
+    server
+}
+
+/// Resume an interrupted download with a range request, rather than restarting it.
+#[tokio::test]
+async fn resume_interrupted_download() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let wheel = InterruptedWheel::new(resumable_wheel()?, "\"v1\"", "\"v1\"");
+    let ranges = wheel.ranges.clone();
+    let (wheel_url, _guard) = interrupted_wheel_server(wheel);
+    let server = resumable_index(&wheel_url).await;
+
+    // With a single connection per host, the range request must not wait on the connection held
+    // by the interrupted response.
+    uv_snapshot!(context.filters(), context
+        .pip_install()
+        .arg("resumable")
+        .arg("--index-url")
+        .arg(format!("{}/simple", server.uri()))
+        .arg("--max-connections-per-host")
+        .arg("1")
+        .env(EnvVars::UV_HTTP_RETRIES, "1")
+        .env(EnvVars::UV_TEST_NO_HTTP_RETRY_DELAY, "1"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + resumable==1.0.0
+    ");
+
+    // The remainder of the wheel was requested with `Range` and `If-Range`.
+    let ranges = ranges.lock().unwrap();
+    assert!(!ranges.is_empty());
+    assert!(
+        ranges
+            .iter()
+            .all(|(range, if_range)| range.starts_with("bytes=") && if_range == "\"v1\"")
+    );
+
+    Ok(())
+}
+
+/// Restart an interrupted download if the file changed since the previous attempt.
+#[tokio::test]
+async fn resume_interrupted_download_changed() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let wheel = InterruptedWheel::new(resumable_wheel()?, "\"v2\"", "\"v1\"");
+    let ranges = wheel.ranges.clone();
+    let (wheel_url, _guard) = interrupted_wheel_server(wheel);
+    let server = resumable_index(&wheel_url).await;
+
+    uv_snapshot!(context.filters(), context
+        .pip_install()
+        .arg("resumable")
+        .arg("--index-url")
+        .arg(format!("{}/simple", server.uri()))
+        .arg("--max-connections-per-host")
+        .arg("1")
+        .env(EnvVars::UV_HTTP_RETRIES, "1")
+        .env(EnvVars::UV_TEST_NO_HTTP_RETRY_DELAY, "1"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + resumable==1.0.0
+    ");
+
+    // The range request was answered with the full, changed wheel.
+    assert!(!ranges.lock().unwrap().is_empty());
+
+    Ok(())
+}
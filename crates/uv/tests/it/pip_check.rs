@@ -1,4 +1,5 @@
 use anyhow::Result;
+use assert_cmd::prelude::*;
 use assert_fs::fixture::FileWriteStr;
 use assert_fs::fixture::PathChild;
 
@@ -219,3 +220,80 @@ fn check_python_version() {
     "
     );
 }
+
+// Files in `site-packages` that aren't listed in any `RECORD` are reported as warnings.
+#[test]
+fn check_orphaned_files() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    context
+        .pip_install()
+        .arg("requests==2.31.0")
+        .assert()
+        .success();
+
+    fs_err::write(context.site_packages().join("stray.py"), "")?;
+
+    // Warnings are reported, but don't fail the check.
+    uv_snapshot!(context.filters(), context.pip_check(), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Checked 5 packages in [TIME]
+    Found 1 warning
+    warning: The path `[SITE_PACKAGES]/stray.py` isn't owned by any installed package
+    "
+    );
+
+    // Warnings are omitted when only errors are requested.
+    uv_snapshot!(context.filters(), context.pip_check().arg("--severity").arg("error"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Checked 5 packages in [TIME]
+    All installed packages are compatible
+    "
+    );
+
+    Ok(())
+}
+
+#[test]
+fn check_format_json() {
+    let context = uv_test::test_context!("3.12");
+
+    context
+        .pip_install()
+        .arg("requests==2.31.0")
+        .assert()
+        .success();
+
+    uv_snapshot!(context.filters(), context.pip_check().arg("--format").arg("json"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    {"valid":true,"diagnostics":[]}
+
+    ----- stderr -----
+    Checked 5 packages in [TIME]
+    "#
+    );
+
+    // Force-install an incompatible version of `idna`.
+    context.pip_install().arg("idna==2.4").assert().success();
+
+    uv_snapshot!(context.filters(), context.pip_check().arg("--format").arg("json"), @r#"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    {"valid":false,"diagnostics":[{"severity":"error","kind":"incompatible-dependency","package":"requests","message":"The package `requests` requires `idna>=2.5,<4`, but `2.4` is installed"}]}
+
+    ----- stderr -----
+    Checked 5 packages in [TIME]
+    "#
+    );
+}
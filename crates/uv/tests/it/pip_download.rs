@@ -0,0 +1,278 @@
+use anyhow::Result;
+use assert_fs::prelude::*;
+use url::Url;
+
+use uv_test::uv_snapshot;
+
+/// Download a package and its dependencies, then install them from the wheelhouse without an
+/// index.
+#[test]
+fn download() {
+    let context = uv_test::test_context!("3.12");
+
+    uv_snapshot!(context.filters(), context.pip_download()
+        .arg("anyio==4.3.0")
+        .arg("--dest")
+        .arg("wheelhouse"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Downloaded 3 packages in [TIME]
+     + anyio-4.3.0-py3-none-any.whl
+     + idna-3.6-py3-none-any.whl
+     + sniffio-1.3.1-py3-none-any.whl
+    "
+    );
+
+    let wheelhouse = context.temp_dir.child("wheelhouse");
+    wheelhouse
+        .child("anyio-4.3.0-py3-none-any.whl")
+        .assert(predicates::path::is_file());
+
+    // Nothing should be installed into the environment.
+    context.assert_command("import anyio").failure();
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("anyio==4.3.0")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(wheelhouse.path()), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Prepared 3 packages in [TIME]
+    Installed 3 packages in [TIME]
+     + anyio==4.3.0
+     + idna==3.6
+     + sniffio==1.3.1
+    "
+    );
+}
+
+/// Downloaded archives are retained in the cache, such that they can be downloaded again offline.
+#[test]
+fn download_cached() {
+    let context = uv_test::test_context!("3.12");
+
+    uv_snapshot!(context.filters(), context.pip_download()
+        .arg("iniconfig==2.0.0")
+        .arg("--dest")
+        .arg("wheelhouse"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Downloaded 1 package in [TIME]
+     + iniconfig-2.0.0-py3-none-any.whl
+    "
+    );
+
+    uv_snapshot!(context.filters(), context.pip_download()
+        .arg("iniconfig==2.0.0")
+        .arg("--dest")
+        .arg("offline")
+        .arg("--offline"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Downloaded 1 package in [TIME]
+     + iniconfig-2.0.0-py3-none-any.whl
+    "
+    );
+
+    context
+        .temp_dir
+        .child("offline")
+        .child("iniconfig-2.0.0-py3-none-any.whl")
+        .assert(predicates::path::is_file());
+}
+
+/// Download a source distribution, rather than a wheel, with `--no-binary`.
+#[test]
+fn download_no_binary() {
+    let context = uv_test::test_context!("3.12");
+
+    uv_snapshot!(context.filters(), context.pip_download()
+        .arg("iniconfig==2.0.0")
+        .arg("--no-binary")
+        .arg("iniconfig")
+        .arg("-d")
+        .arg("wheelhouse"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Downloaded 1 package in [TIME]
+     + iniconfig-2.0.0.tar.gz
+    "
+    );
+
+    context
+        .temp_dir
+        .child("wheelhouse")
+        .child("iniconfig-2.0.0.tar.gz")
+        .assert(predicates::path::is_file());
+}
+
+/// Download only the requested packages with `--no-deps`.
+#[test]
+fn download_no_deps() {
+    let context = uv_test::test_context!("3.12");
+
+    uv_snapshot!(context.filters(), context.pip_download()
+        .arg("anyio==4.3.0")
+        .arg("--no-deps")
+        .arg("-d")
+        .arg("wheelhouse"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Downloaded 1 package in [TIME]
+     + anyio-4.3.0-py3-none-any.whl
+    "
+    );
+}
+
+/// Download the wheels for a different platform and Python version.
+#[test]
+fn download_python_platform() {
+    let context = uv_test::test_context!("3.12");
+
+    uv_snapshot!(context.filters(), context.pip_download()
+        .arg("markupsafe==2.1.3")
+        .arg("--python-platform")
+        .arg("windows")
+        .arg("--python-version")
+        .arg("3.11")
+        .arg("-d")
+        .arg("wheelhouse"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Downloaded 1 package in [TIME]
+     + MarkupSafe-2.1.3-cp311-cp311-win_amd64.whl
+    "
+    );
+}
+
+/// Validate downloaded archives against the hashes in the requirements, including for direct URL
+/// requirements.
+#[test]
+fn download_hash_mismatch() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let wheel = context.temp_dir.child("tqdm-1000.0.0-py3-none-any.whl");
+    fs_err::copy(
+        context
+            .workspace_root
+            .join("test/links/tqdm-1000.0.0-py3-none-any.whl"),
+        &wheel,
+    )?;
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(&format!(
+        "tqdm @ {} --hash=sha256:0000000000000000000000000000000000000000000000000000000000000000",
+        Url::from_file_path(wheel.path()).unwrap()
+    ))?;
+
+    uv_snapshot!(context.filters(), context.pip_download()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--no-deps")
+        .arg("-d")
+        .arg("wheelhouse"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    error: Hash mismatch for `tqdm @ file://[TEMP_DIR]/tqdm-1000.0.0-py3-none-any.whl`
+
+    Expected:
+      sha256:0000000000000000000000000000000000000000000000000000000000000000
+
+    Computed:
+      sha256:a34996d4bd5abb2336e14ff0a2d22b92cfd0f0ed344e6883041ce01953276a13
+    "
+    );
+
+    // Nothing should be written to the destination.
+    context
+        .temp_dir
+        .child("wheelhouse")
+        .child("tqdm-1000.0.0-py3-none-any.whl")
+        .assert(predicates::path::missing());
+
+    // With the correct hash, the download succeeds.
+    requirements_txt.write_str(&format!(
+        "tqdm @ {} --hash=sha256:a34996d4bd5abb2336e14ff0a2d22b92cfd0f0ed344e6883041ce01953276a13",
+        Url::from_file_path(wheel.path()).unwrap()
+    ))?;
+
+    uv_snapshot!(context.filters(), context.pip_download()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--no-deps")
+        .arg("--require-hashes")
+        .arg("-d")
+        .arg("wheelhouse"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Downloaded 1 package in [TIME]
+     + tqdm-1000.0.0-py3-none-any.whl
+    "
+    );
+
+    Ok(())
+}
+
+/// In `--require-hashes` mode, every requirement must have a hash.
+#[test]
+fn download_require_hashes_missing() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("anyio==4.0.0")?;
+
+    uv_snapshot!(context.filters(), context.pip_download()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--no-deps")
+        .arg("--require-hashes")
+        .arg("-d")
+        .arg("wheelhouse"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: In `--require-hashes` mode, all requirements must have a hash, but none were provided for: anyio==4.0.0
+    "
+    );
+
+    Ok(())
+}
@@ -0,0 +1,184 @@
+use anyhow::Result;
+use assert_fs::prelude::*;
+
+use uv_test::uv_snapshot;
+
+/// Explain the selection of a transitive dependency, including the constraints that applied.
+#[test]
+fn explain_transitive() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("flask==3.0.0")?;
+    let constraints_txt = context.temp_dir.child("constraints.txt");
+    constraints_txt.write_str("werkzeug<3.0.1")?;
+
+    uv_snapshot!(context.filters(), context.pip_explain()
+        .arg("werkzeug")
+        .arg("requirements.in")
+        .arg("--constraint")
+        .arg("constraints.txt"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    werkzeug==3.0.0
+      werkzeug>=3.0.0 required by flask==3.0.0
+        flask==3.0.0 required by -r requirements.in
+      werkzeug<3.0.1 constrained by -c constraints.txt
+
+    ----- stderr -----
+    Resolved 7 packages in [TIME]
+    "
+    );
+
+    Ok(())
+}
+
+/// Explain the selection of a direct dependency.
+#[test]
+fn explain_direct() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("iniconfig")?;
+
+    uv_snapshot!(context.filters(), context.pip_explain()
+        .arg("iniconfig")
+        .arg("requirements.in"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    iniconfig==2.0.0
+      iniconfig required by -r requirements.in
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    "
+    );
+
+    Ok(())
+}
+
+/// Explaining a package that isn't in the resolution is an error.
+#[test]
+fn explain_missing() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("iniconfig")?;
+
+    uv_snapshot!(context.filters(), context.pip_explain()
+        .arg("flask")
+        .arg("requirements.in"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    error: Package `flask` is not included in the resolution
+    "
+    );
+
+    Ok(())
+}
+
+/// Explain a package from an existing `uv.lock`, without resolving.
+#[test]
+fn explain_uv_lock() -> Result<()> {
+    let context = uv_test::test_context_with_versions!(&[]);
+    let uv_lock = context.temp_dir.child("uv.lock");
+    uv_lock.write_str(indoc::indoc! {r#"
+        version = 1
+        revision = 3
+        requires-python = ">=3.12"
+
+        [manifest]
+        constraints = [{ name = "werkzeug", specifier = "<3.0.1" }]
+
+        [[package]]
+        name = "flask"
+        version = "3.0.0"
+        source = { registry = "https://pypi.org/simple" }
+        dependencies = [
+            { name = "werkzeug" },
+        ]
+        sdist = { url = "https://files.pythonhosted.org/packages/flask-3.0.0.tar.gz", hash = "sha256:cfadcdb638b609361d29ec22360d6070a77d7463dcb3ab08d2c2f2f168845f58", size = 674171 }
+
+        [[package]]
+        name = "project"
+        version = "0.1.0"
+        source = { virtual = "." }
+        dependencies = [
+            { name = "flask" },
+        ]
+
+        [package.metadata]
+        requires-dist = [{ name = "flask", specifier = ">=3" }]
+
+        [[package]]
+        name = "werkzeug"
+        version = "3.0.0"
+        source = { registry = "https://pypi.org/simple" }
+        sdist = { url = "https://files.pythonhosted.org/packages/werkzeug-3.0.0.tar.gz", hash = "sha256:3ffff4dcc32db52ef3cc94dff3000a3c2846890f3a5a51800a27b909c5e770f0", size = 819299 }
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.pip_explain()
+        .arg("werkzeug")
+        .arg("uv.lock"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    werkzeug==3.0.0
+      werkzeug required by flask==3.0.0
+        flask>=3 required by project==0.1.0
+      werkzeug<3.0.1 constrained
+
+    ----- stderr -----
+    "
+    );
+
+    uv_snapshot!(context.filters(), context.pip_explain()
+        .arg("iniconfig")
+        .arg("uv.lock"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Package `iniconfig` is not included in the lockfile
+    "
+    );
+
+    Ok(())
+}
+
+/// Explain a package from an existing `pylock.toml`, which doesn't record dependencies.
+#[test]
+fn explain_pylock_toml() -> Result<()> {
+    let context = uv_test::test_context_with_versions!(&[]);
+    let pylock_toml = context.temp_dir.child("pylock.toml");
+    pylock_toml.write_str(indoc::indoc! {r#"
+        lock-version = "1.0"
+        created-by = "uv"
+        requires-python = ">=3.12"
+
+        [[packages]]
+        name = "werkzeug"
+        version = "3.0.0"
+        index = "https://pypi.org/simple"
+        sdist = { url = "https://files.pythonhosted.org/packages/werkzeug-3.0.0.tar.gz", upload-time = 2023-09-30T14:00:00Z, size = 819299, hashes = { sha256 = "3ffff4dcc32db52ef3cc94dff3000a3c2846890f3a5a51800a27b909c5e770f0" } }
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.pip_explain()
+        .arg("werkzeug")
+        .arg("pylock.toml"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    werkzeug==3.0.0
+
+    ----- stderr -----
+    warning: `pylock.toml` does not record the dependencies of each package; only the locked versions of `werkzeug` can be shown
+    "
+    );
+
+    Ok(())
+}
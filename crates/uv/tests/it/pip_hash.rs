@@ -0,0 +1,82 @@
+use anyhow::Result;
+use assert_fs::prelude::*;
+
+use uv_test::uv_snapshot;
+
+#[test]
+fn hash() -> Result<()> {
+    let context = uv_test::test_context_with_versions!(&[]);
+
+    context
+        .temp_dir
+        .child("foo-1.0-py3-none-any.whl")
+        .write_str("hello")?;
+
+    uv_snapshot!(context.filters(), context
+        .pip_hash()
+        .arg("foo-1.0-py3-none-any.whl"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    foo-1.0-py3-none-any.whl:
+    --hash=sha256:2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824
+
+    ----- stderr -----
+    "
+    );
+
+    Ok(())
+}
+
+#[test]
+fn hash_algorithms() -> Result<()> {
+    let context = uv_test::test_context_with_versions!(&[]);
+
+    context
+        .temp_dir
+        .child("foo-1.0-py3-none-any.whl")
+        .write_str("hello")?;
+    context.temp_dir.child("foo-1.0.tar.gz").write_str("")?;
+
+    uv_snapshot!(context.filters(), context
+        .pip_hash()
+        .arg("foo-1.0-py3-none-any.whl")
+        .arg("foo-1.0.tar.gz")
+        .arg("--algorithm")
+        .arg("sha512")
+        .arg("-a")
+        .arg("blake2b"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    foo-1.0-py3-none-any.whl:
+    --hash=sha512:9b71d224bd62f3785d96d46ad3ea3d73319bfbc2890caadae2dff72519673ca72323c3d99ba5c11d7c7acc6e14b8c5da0c4663475c2e5c3adef46f73bcdec043
+    --hash=blake2b:324dcf027dd4a30a932c441f365a25e86b173defa4b8e58948253471b81b72cf
+    foo-1.0.tar.gz:
+    --hash=sha512:cf83e1357eefb8bdf1542850d66d8007d620e4050b5715dc83f4a921d36ce9ce47d0d13c5d85f2b0ff8318d2877eec2f63b931bd47417a81a538327af927da3e
+    --hash=blake2b:0e5751c026e543b2e8ab2eb06099daa1d1e5df47778f7787faab45cdf12fe3a8
+
+    ----- stderr -----
+    "
+    );
+
+    Ok(())
+}
+
+#[test]
+#[cfg(unix)]
+fn hash_missing() {
+    let context = uv_test::test_context_with_versions!(&[]);
+
+    uv_snapshot!(context.filters(), context
+        .pip_hash()
+        .arg("missing.whl"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: failed to open file `missing.whl`: No such file or directory (os error 2)
+    "
+    );
+}
@@ -0,0 +1,92 @@
+use uv_test::uv_snapshot;
+
+#[test]
+fn versions_find_links() {
+    let context = uv_test::test_context_with_versions!(&[]);
+
+    uv_snapshot!(context.filters(), context
+        .pip_index_versions()
+        .arg("ok")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(context.workspace_root.join("test/links")), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Version Wheel Sdist Yanked Requires-Python
+    ------- ----- ----- ------ ---------------
+    2.0.0   yes   no    no
+    1.0.0   yes   no    no
+
+    ----- stderr -----
+    "
+    );
+}
+
+#[test]
+fn versions_json() {
+    let context = uv_test::test_context_with_versions!(&[]);
+
+    uv_snapshot!(context.filters(), context
+        .pip_index_versions()
+        .arg("ok")
+        .arg("--format")
+        .arg("json")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(context.workspace_root.join("test/links")), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    {"name":"ok","versions":[{"version":"2.0.0","wheel":true,"sdist":false,"yanked":false,"requires_python":null},{"version":"1.0.0","wheel":true,"sdist":false,"yanked":false,"requires_python":null}]}
+
+    ----- stderr -----
+    "#
+    );
+}
+
+#[test]
+fn versions_not_found() {
+    let context = uv_test::test_context_with_versions!(&[]);
+
+    uv_snapshot!(context.filters(), context
+        .pip_index_versions()
+        .arg("missing")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(context.workspace_root.join("test/links")), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: No versions found for `missing`
+    "
+    );
+}
+
+#[test]
+#[cfg(feature = "test-pypi")]
+fn versions_exclude_newer() {
+    let context = uv_test::test_context_with_versions!(&[]);
+
+    uv_snapshot!(context.filters(), context
+        .pip_index_versions()
+        .arg("iniconfig")
+        .arg("--exclude-newer")
+        .arg("2023-01-01T00:00:00Z"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Version Wheel Sdist Yanked Requires-Python
+    ------- ----- ----- ------ ---------------
+    1.1.1   yes   yes   no
+    1.1.0   yes   yes   no
+    1.0.1   yes   yes   no
+    1.0.0   no    yes   no
+    0.1     no    yes   no
+
+    ----- stderr -----
+    "
+    );
+}
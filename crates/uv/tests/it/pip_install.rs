@@ -526,6 +526,58 @@ fn install_package() {
     context.assert_command("import flask").success();
 }
 
+/// Install the package that provides an import name.
+#[test]
+fn install_for_import() {
+    let context = uv_test::test_context!("3.12");
+
+    // `yaml` is provided by `pyyaml`.
+    uv_snapshot!(context.pip_install()
+        .arg("--for-import")
+        .arg("yaml"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved import `yaml` to `pyyaml`
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + pyyaml==6.0.1
+    "
+    );
+
+    context.assert_command("import yaml").success();
+
+    // Submodules are resolved via the installed packages.
+    uv_snapshot!(context.pip_install()
+        .arg("--for-import")
+        .arg("yaml.constructor"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved import `yaml.constructor` to `pyyaml`
+    Audited 1 package in [TIME]
+    "
+    );
+
+    // Import names must be valid Python identifiers.
+    uv_snapshot!(context.pip_install()
+        .arg("--for-import")
+        .arg("py-yaml"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: `py-yaml` is not a valid import name
+    "
+    );
+}
+
 /// Install a package from a `requirements.txt` into a virtual environment.
 #[test]
 fn install_requirements_txt() -> Result<()> {
@@ -2937,7 +2989,6 @@ fn install_only_binary_comma_separated() {
 }
 
 /// Overlapping usage of `--no-binary` and `--only-binary`
-// TODO(zanieb): We should have a better error message here
 #[test]
 fn install_only_binary_all_and_no_binary_all() {
     let context = uv_test::test_context!("3.12");
@@ -2955,16 +3006,11 @@ fn install_only_binary_all_and_no_binary_all() {
         command,
         @"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
-      × No solution found when resolving dependencies:
-      ╰─▶ Because all versions of anyio have no usable wheels and you require anyio, we can conclude that your requirements are unsatisfiable.
-
-          hint: Pre-releases are available for `anyio` in the requested range (e.g., 4.0.0rc1), but pre-releases weren't enabled (try: `--prerelease=allow`)
-
-          hint: Wheels are required for `anyio` because building from source is disabled for all packages (i.e., with `--no-build`)
+    error: Conflicting build options: `--no-binary :all:` (from command-line arguments or settings) and `--only-binary :all:` (from command-line arguments or settings) disallow both wheels and source distributions
     "
     );
 
@@ -3057,7 +3103,58 @@ fn only_binary_requirements_txt() {
       × No solution found when resolving dependencies:
       ╰─▶ Because django-allauth==0.51.0 has no usable wheels and you require django-allauth==0.51.0, we can conclude that your requirements are unsatisfiable.
 
-          hint: Wheels are required for `django-allauth` because building from source is disabled for `django-allauth` (i.e., with `--no-build-package django-allauth`)
+          hint: Wheels are required for `django-allauth` because building from source is disabled for `django-allauth` (i.e., with `--no-build-package django-allauth`), but no wheels are available for any version of `django-allauth`
+    "
+    );
+}
+
+/// Reject `--no-binary` on the command line for a package marked `--only-binary` in a
+/// `requirements.txt`, reporting both origins.
+#[test]
+fn only_binary_requirements_txt_no_binary_conflict() {
+    let context = uv_test::test_context!("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt
+        .write_str(indoc! {r"
+        anyio==4.3.0
+        --only-binary anyio
+        "
+        })
+        .unwrap();
+
+    uv_snapshot!(context.pip_install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--no-binary")
+        .arg("anyio"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Conflicting build options for `anyio`: `--no-binary` (from command-line arguments or settings) and `--only-binary` (from `requirements.txt`) disallow both wheels and source distributions
+    "
+    );
+
+    // A package-specific flag takes precedence over `:all:`.
+    uv_snapshot!(context.pip_install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--no-binary")
+        .arg(":all:")
+        .arg("--strict"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Prepared 3 packages in [TIME]
+    Installed 3 packages in [TIME]
+     + anyio==4.3.0
+     + idna==3.6
+     + sniffio==1.3.1
     "
     );
 }
@@ -3183,6 +3280,188 @@ fn no_prerelease_hint_source_builds() -> Result<()> {
     Ok(())
 }
 
+/// Reuse a cached build environment across source distribution builds with the same build
+/// requirements.
+#[test]
+fn build_environment_cache() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    for name in ["foo", "bar"] {
+        let pyproject_toml = context.temp_dir.child(name).child("pyproject.toml");
+        pyproject_toml.write_str(&formatdoc! {r#"
+            [project]
+            name = "{name}"
+            version = "0.1.0"
+            requires-python = ">=3.12"
+            dependencies = []
+
+            [build-system]
+            requires = ["hatchling==1.22.4"]
+            build-backend = "hatchling.build"
+        "#})?;
+        context
+            .temp_dir
+            .child(name)
+            .child("src")
+            .child(name)
+            .child("__init__.py")
+            .touch()?;
+    }
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("./foo")
+        .arg("--preview-features")
+        .arg("build-environment-cache"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + foo==0.1.0 (from file://[TEMP_DIR]/foo)
+    "
+    );
+
+    // The build environment should be persisted in the cache.
+    let environments = context.cache_dir.child("environments-v2").child("build");
+    let count = || {
+        fs::read_dir(environments.path())?
+            .map(|interpreter| Ok(fs::read_dir(interpreter?.path())?.count()))
+            .sum::<std::io::Result<usize>>()
+    };
+    assert_eq!(count()?, 1);
+
+    // Building a package with the same build requirements should reuse the environment.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("./bar")
+        .arg("--preview-features")
+        .arg("build-environment-cache"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + bar==0.1.0 (from file://[TEMP_DIR]/bar)
+    "
+    );
+    assert_eq!(count()?, 1);
+
+    Ok(())
+}
+
+/// When a build fails with a lot of output, only the tail is shown, and the full output is
+/// written to a log file in the cache.
+#[test]
+fn build_failure_log() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+
+        [build-system]
+        requires = ["setuptools>=42"]
+        build-backend = "setuptools.build_meta"
+    "#})?;
+    context.temp_dir.child("setup.py").write_str(indoc! {r#"
+        import sys
+
+        for i in range(60):
+            print(f"line {i}", file=sys.stderr)
+        sys.exit("The build failed")
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("."), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+      × Failed to build `project @ file://[TEMP_DIR]/`
+      ├─▶ The build backend returned an error
+      ╰─▶ Call to `setuptools.build_meta.build_wheel` failed (exit status: 1)
+
+          [stderr]
+          [11 earlier lines omitted]
+          line 11
+          line 12
+          line 13
+          line 14
+          line 15
+          line 16
+          line 17
+          line 18
+          line 19
+          line 20
+          line 21
+          line 22
+          line 23
+          line 24
+          line 25
+          line 26
+          line 27
+          line 28
+          line 29
+          line 30
+          line 31
+          line 32
+          line 33
+          line 34
+          line 35
+          line 36
+          line 37
+          line 38
+          line 39
+          line 40
+          line 41
+          line 42
+          line 43
+          line 44
+          line 45
+          line 46
+          line 47
+          line 48
+          line 49
+          line 50
+          line 51
+          line 52
+          line 53
+          line 54
+          line 55
+          line 56
+          line 57
+          line 58
+          line 59
+          The build failed
+
+          The full build output was written to: [CACHE_DIR]/build-logs-v0/project/[HASH].log
+
+          hint: This usually indicates a problem with the package or the build environment.
+    "
+    );
+
+    // The log contains the full output.
+    let logs = context.cache_dir.child("build-logs-v0").child("project");
+    let log = fs::read_dir(logs.path())?
+        .next()
+        .expect("a build log should be written")?;
+    let contents = fs::read_to_string(log.path())?;
+    assert!(contents.contains("line 0\n"));
+    assert!(contents.contains("The build failed\n"));
+
+    Ok(())
+}
+
 #[test]
 fn cache_priority() {
     let context = uv_test::test_context!("3.12");
@@ -3649,6 +3928,69 @@ fn install_upgrade() {
     );
 }
 
+/// Upgrade a package with `--upgrade-strategy only-if-needed`, retaining the installed versions of
+/// its dependencies.
+#[test]
+fn install_upgrade_only_if_needed() {
+    let context = uv_test::test_context!("3.12");
+
+    // Install an old version of anyio and idna.
+    uv_snapshot!(context.pip_install()
+        .arg("anyio==3.6.2")
+        .arg("idna==3.4"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Prepared 3 packages in [TIME]
+    Installed 3 packages in [TIME]
+     + anyio==3.6.2
+     + idna==3.4
+     + sniffio==1.3.1
+    "
+    );
+
+    // Upgrade anyio, but not idna.
+    uv_snapshot!(context.pip_install()
+        .arg("anyio")
+        .arg("--upgrade")
+        .arg("--upgrade-strategy")
+        .arg("only-if-needed"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     - anyio==3.6.2
+     + anyio==4.3.0
+    "
+    );
+
+    // With the default (`eager`) strategy, idna is upgraded too.
+    uv_snapshot!(context.pip_install()
+        .arg("anyio")
+        .arg("--upgrade"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     - idna==3.4
+     + idna==3.6
+    "
+    );
+}
+
 /// Install a package from a `requirements.txt` file, with a `constraints.txt` file.
 #[test]
 fn install_constraints_txt() -> Result<()> {
@@ -7245,46 +7587,467 @@ fn require_hashes_editable() -> Result<()> {
     ----- stdout -----
 
     ----- stderr -----
-    error: In `--require-hashes` mode, all requirements must have a hash, but none were provided for: file://[WORKSPACE]/test/packages/black_editable[d]
+    error: In `--require-hashes` mode, all requirements must be hashable, but found:
+    - file://[WORKSPACE]/test/packages/black_editable[d] (editable)
+
+    hint: To install a named requirement without hash-checking, pass `--allow-unhashed <name>`
     "
     );
 
     Ok(())
 }
 
-/// If a hash is only included as a constraint, that's good enough for `--require-hashes`.
+/// Named editables can be exempted from `--require-hashes` via `--allow-unhashed`.
 #[test]
-fn require_hashes_constraint() -> Result<()> {
+fn require_hashes_allow_unhashed() -> Result<()> {
     let context = uv_test::test_context!("3.12");
 
-    // Include the hash in the constraint file.
     let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt.write_str("anyio==4.0.0")?;
+    requirements_txt.write_str(&indoc::formatdoc! {r"
+        -e black @ file://{workspace_root}/test/packages/black_editable
+        ",
+        workspace_root = context.workspace_root.simplified_display(),
+    })?;
 
-    let constraints_txt = context.temp_dir.child("constraints.txt");
-    constraints_txt.write_str("anyio==4.0.0 --hash=sha256:cfdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f")?;
+    // Exempting a different package isn't sufficient.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("-r")
+        .arg(requirements_txt.path())
+        .arg("--require-hashes")
+        .arg("--allow-unhashed")
+        .arg("anyio"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
 
-    // Install the editable packages.
-    uv_snapshot!(context.pip_install()
+    ----- stderr -----
+    error: In `--require-hashes` mode, all requirements must be hashable, but found:
+    - black @ file://[WORKSPACE]/test/packages/black_editable (editable)
+
+    hint: To install a named requirement without hash-checking, pass `--allow-unhashed <name>`
+    "
+    );
+
+    uv_snapshot!(context.filters(), context.pip_install()
         .arg("-r")
         .arg(requirements_txt.path())
-        .arg("--no-deps")
         .arg("--require-hashes")
-        .arg("-c")
-        .arg(constraints_txt.path()), @"
+        .arg("--allow-unhashed")
+        .arg("black"), @"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
+    warning: Installing `black` (editable) without hash-checking, as allowed by `--allow-unhashed`
     Resolved 1 package in [TIME]
     Prepared 1 package in [TIME]
     Installed 1 package in [TIME]
-     + anyio==4.0.0
+     + black==0.1.0 (from file://[WORKSPACE]/test/packages/black_editable) (without hash-checking)
     "
     );
 
-    // Include the hash in the requirements file, but pin the version in the constraint file.
+    Ok(())
+}
+
+/// Refuse to install a yanked version in `--require-hashes` mode, unless it's explicitly allowed
+/// via `--allow-yanked`.
+#[tokio::test]
+async fn require_hashes_yanked() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    // Serve an index on which `iniconfig==2.0.0` is yanked.
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/simple/iniconfig/"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(
+            serde_json::json!({
+                "meta": { "api-version": "1.1" },
+                "name": "iniconfig",
+                "files": [{
+                    "filename": "iniconfig-2.0.0-py3-none-any.whl",
+                    "url": "https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl",
+                    "hashes": { "sha256": "b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374" },
+                    "requires-python": ">=3.7",
+                    "yanked": "Broken",
+                }],
+            })
+            .to_string(),
+            "application/vnd.pypi.simple.v1+json",
+        ))
+        .mount(&server)
+        .await;
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(
+        "iniconfig==2.0.0 --hash=sha256:b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374",
+    )?;
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--require-hashes")
+        .arg("--index-url")
+        .arg(format!("{}/simple", server.uri())), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    error: Refusing to install `iniconfig==2.0.0`, which is yanked, while hash-checking is required (pass `--allow-yanked iniconfig==2.0.0` to install it anyway)
+    "
+    );
+
+    // Without `--require-hashes`, the yanked version is installed with a warning.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--index-url")
+        .arg(format!("{}/simple", server.uri())), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    warning: `iniconfig==2.0.0` is yanked (reason: "Broken")
+    "#
+    );
+
+    // Explicitly allowing the yanked version installs it without a warning.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--require-hashes")
+        .arg("--reinstall")
+        .arg("--allow-yanked")
+        .arg("iniconfig==2.0.0")
+        .arg("--index-url")
+        .arg(format!("{}/simple", server.uri())), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     ~ iniconfig==2.0.0
+    "
+    );
+
+    Ok(())
+}
+
+/// Serve `ok==1.0.0` from a mock index, along with attestations that attest to `digest`.
+async fn attested_index(
+    context: &TestContext,
+    sigstore: &uv_attestation::testing::Sigstore,
+    digest: &str,
+) -> MockServer {
+    const FILENAME: &str = "ok-1.0.0-py3-none-any.whl";
+
+    let (certificate, key) = sigstore.issue_github("release.yml");
+    let provenance = sigstore.provenance(
+        &uv_attestation::testing::github("release.yml"),
+        &certificate,
+        &key,
+        FILENAME,
+        digest,
+    );
+
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/simple/ok/"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_raw(
+                serde_json::json!({
+                    "meta": { "api-version": "1.1" },
+                    "name": "ok",
+                    "files": [{
+                        "filename": FILENAME,
+                        "url": format!("{}/files/{FILENAME}", server.uri()),
+                        "hashes": {},
+                        "provenance": format!("{}/provenance/{FILENAME}", server.uri()),
+                    "upload-time": "2024-01-01T00:00:00Z",
+                    }],
+                })
+                .to_string(),
+                "application/vnd.pypi.simple.v1+json",
+            ),
+        )
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(format!("/files/{FILENAME}")))
+        .respond_with(ResponseTemplate::new(200).set_body_bytes(
+            fs::read(context.workspace_root.join("test/links").join(FILENAME)).unwrap(),
+        ))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(format!("/provenance/{FILENAME}")))
+        .respond_with(ResponseTemplate::new(200).set_body_bytes(provenance))
+        .mount(&server)
+        .await;
+    server
+}
+
+/// Verify the attestations for a distribution upon download, and reject a distribution that
+/// doesn't match the attested digest.
+#[tokio::test]
+async fn install_attestations() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let sigstore = uv_attestation::testing::Sigstore::new();
+    let trusted_root = context.temp_dir.child("trusted_root.json");
+    trusted_root.write_binary(&sigstore.trusted_root())?;
+
+    // The attestations attest to a different digest than that of the served wheel.
+    let server = attested_index(
+        &context,
+        &sigstore,
+        "0000000000000000000000000000000000000000000000000000000000000000",
+    )
+    .await;
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("ok")
+        .arg("--preview-features")
+        .arg("attestations")
+        .arg("--index-url")
+        .arg(format!("{}/simple", server.uri()))
+        .env(EnvVars::UV_ATTESTATION_TRUSTED_ROOT, trusted_root.path()), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+      × Failed to download `ok==1.0.0`
+      ╰─▶ Hash mismatch for `ok==1.0.0`
+
+          Expected:
+            sha256:0000000000000000000000000000000000000000000000000000000000000000
+
+          Computed:
+            sha256:79f0b33e6ce1e09eaa1784c8eee275dfe84d215d9c65c652f07c18e85fdaac5f
+    "
+    );
+
+    // The attestations attest to the digest of the served wheel.
+    let server = attested_index(
+        &context,
+        &sigstore,
+        "79f0b33e6ce1e09eaa1784c8eee275dfe84d215d9c65c652f07c18e85fdaac5f",
+    )
+    .await;
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("ok")
+        .arg("--preview-features")
+        .arg("attestations")
+        .arg("--index-url")
+        .arg(format!("{}/simple", server.uri()))
+        .env(EnvVars::UV_ATTESTATION_TRUSTED_ROOT, trusted_root.path()), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + ok==1.0.0
+    "
+    );
+
+    // The verification is cached with the wheel, so it can be reinstalled offline.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("ok")
+        .arg("--reinstall")
+        .arg("--offline")
+        .arg("--preview-features")
+        .arg("attestations")
+        .arg("--index-url")
+        .arg(format!("{}/simple", server.uri()))
+        .env(EnvVars::UV_ATTESTATION_TRUSTED_ROOT, trusted_root.path()), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     ~ ok==1.0.0
+    "
+    );
+
+    Ok(())
+}
+
+/// Reject attestations that weren't signed by a publisher pinned in user configuration.
+#[tokio::test]
+async fn install_attestations_trusted_publishers() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let sigstore = uv_attestation::testing::Sigstore::new();
+    let trusted_root = context.temp_dir.child("trusted_root.json");
+    trusted_root.write_binary(&sigstore.trusted_root())?;
+
+    let server = attested_index(
+        &context,
+        &sigstore,
+        "79f0b33e6ce1e09eaa1784c8eee275dfe84d215d9c65c652f07c18e85fdaac5f",
+    )
+    .await;
+
+    // The index serves valid attestations, but from a publisher that isn't trusted for `ok`.
+    let config = context.temp_dir.child("config").child("uv.toml");
+    config.write_str(indoc! {r#"
+        [trusted-publishers]
+        ok = ["github:astral-sh/ok"]
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("ok")
+        .arg("--preview-features")
+        .arg("attestations")
+        .arg("--config-file")
+        .arg(config.path())
+        .arg("--index-url")
+        .arg(format!("{}/simple", server.uri()))
+        .env(EnvVars::UV_ATTESTATION_TRUSTED_ROOT, trusted_root.path()), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+      × Failed to download `ok==1.0.0`
+      ├─▶ Failed to verify attestations for `ok-1.0.0-py3-none-any.whl`
+      ╰─▶ The attestations were published by GitHub repository `astral-sh/example` (workflow `release.yml`), which is not a trusted publisher for the package (expected: `github:astral-sh/ok`)
+    "
+    );
+
+    // Pin the publisher that signed the attestations.
+    config.write_str(indoc! {r#"
+        [trusted-publishers]
+        ok = ["github:astral-sh/example@release.yml"]
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("ok")
+        .arg("--preview-features")
+        .arg("attestations")
+        .arg("--config-file")
+        .arg(config.path())
+        .arg("--index-url")
+        .arg(format!("{}/simple", server.uri()))
+        .env(EnvVars::UV_ATTESTATION_TRUSTED_ROOT, trusted_root.path()), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + ok==1.0.0
+    "
+    );
+
+    Ok(())
+}
+
+/// With `require-attestations`, reject distributions for which the index serves no attestations.
+#[test]
+fn install_require_attestations() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let config = context.temp_dir.child("config").child("uv.toml");
+    config.write_str("require-attestations = true")?;
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("iniconfig==2.0.0")
+        .arg("--preview-features")
+        .arg("attestations")
+        .arg("--config-file")
+        .arg(config.path()), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+      × Failed to download `iniconfig==2.0.0`
+      ╰─▶ The index does not provide attestations for `iniconfig-2.0.0-py3-none-any.whl`, but attestations are required (via `require-attestations` or `trusted-publishers`)
+    "
+    );
+
+    // The setting is ignored in project-level configuration.
+    fs_err::rename(config.path(), context.temp_dir.child("uv.toml").path())?;
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("iniconfig==2.0.0")
+        .arg("--preview-features")
+        .arg("attestations"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: Ignoring `require-attestations` in `uv.toml`; attestation policies can only be configured in a user-level or system-level `uv.toml`, or via `--config-file`
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    "
+    );
+
+    Ok(())
+}
+
+/// If a hash is only included as a constraint, that's good enough for `--require-hashes`.
+#[test]
+fn require_hashes_constraint() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    // Include the hash in the constraint file.
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("anyio==4.0.0")?;
+
+    let constraints_txt = context.temp_dir.child("constraints.txt");
+    constraints_txt.write_str("anyio==4.0.0 --hash=sha256:cfdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f")?;
+
+    // Install the editable packages.
+    uv_snapshot!(context.pip_install()
+        .arg("-r")
+        .arg(requirements_txt.path())
+        .arg("--no-deps")
+        .arg("--require-hashes")
+        .arg("-c")
+        .arg(constraints_txt.path()), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + anyio==4.0.0
+    "
+    );
+
+    // Include the hash in the requirements file, but pin the version in the constraint file.
     let context = uv_test::test_context!("3.12");
 
     let requirements_txt = context.temp_dir.child("requirements.txt");
@@ -11487,6 +12250,89 @@ fn pep_751_install_registry_sdist() -> Result<()> {
     Ok(())
 }
 
+/// When the network is disabled, distributions that are missing from the cache should be reported
+/// up front, before the environment is modified.
+#[test]
+fn pep_751_install_offline_missing_from_cache() -> Result<()> {
+    let context = uv_test::test_context!("3.12").with_exclude_newer("2025-01-29T00:00:00Z");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig", "source-distribution"]
+        "#,
+    )?;
+
+    context
+        .export()
+        .arg("-o")
+        .arg("pylock.toml")
+        .assert()
+        .success();
+
+    // Neither distribution has been downloaded.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("--preview")
+        .arg("--offline")
+        .arg("-r")
+        .arg("pylock.toml"), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+      × Network connectivity is disabled, but the following distributions are missing from the cache:
+      │ - `iniconfig==2.0.0` (`wheels-v8`)
+      │ - `source-distribution==0.0.3` (`sdists-v10`)
+      help: Pre-populate the listed cache buckets while online (e.g., with `uv cache warm` or `uv cache import`), or run without `--offline`
+    "
+    );
+
+    context.assert_not_installed("iniconfig");
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("--preview")
+        .arg("-r")
+        .arg("pylock.toml"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + iniconfig==2.0.0
+     + source-distribution==0.0.3
+    "
+    );
+
+    // Once cached, the distributions can be reinstalled without network access.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("--preview")
+        .arg("--offline")
+        .arg("--reinstall")
+        .arg("-r")
+        .arg("pylock.toml"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Prepared 2 packages in [TIME]
+    Uninstalled 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     ~ iniconfig==2.0.0
+     ~ source-distribution==0.0.3
+    "
+    );
+
+    Ok(())
+}
+
 #[test]
 fn pep_751_install_directory() -> Result<()> {
     let context = uv_test::test_context!("3.12");
@@ -3876,7 +3876,7 @@ fn no_wheels_no_build() {
       ╰─▶ Because only package-a==1.0.0 is available and package-a==1.0.0 has no usable wheels, we can conclude that all versions of package-a cannot be used.
           And because you require package-a, we can conclude that your requirements are unsatisfiable.
 
-          hint: Wheels are required for `package-a` because building from source is disabled for `package-a` (i.e., with `--no-build-package package-a`)
+          hint: Wheels are required for `package-a` because building from source is disabled for `package-a` (i.e., with `--no-build-package package-a`), but no wheels are available for any version of `package-a`
     ");
 
     context.assert_not_installed("no_wheels_no_build_a");
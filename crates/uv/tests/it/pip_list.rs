@@ -5,7 +5,8 @@ use assert_fs::fixture::FileWriteStr;
 use assert_fs::fixture::PathChild;
 use assert_fs::prelude::*;
 
-use uv_test::uv_snapshot;
+use uv_static::EnvVars;
+use uv_test::{packse_index_url, uv_snapshot};
 
 #[test]
 fn list_empty_columns() {
@@ -279,6 +280,98 @@ fn list_outdated_index() -> Result<()> {
     Ok(())
 }
 
+/// An installed pre-release is compared against newer pre-releases, unless pre-releases are
+/// disallowed.
+#[test]
+#[cfg(feature = "test-pypi")]
+fn list_outdated_prerelease() {
+    let context = uv_test::test_context!("3.12");
+
+    let mut filters = context.filters();
+    filters.push((r"package-prerelease-specified-mixed-available-", "package-"));
+
+    uv_snapshot!(filters, context.pip_install()
+        .arg("package-prerelease-specified-mixed-available-a==0.2.0a1")
+        .arg("--index-url")
+        .arg(packse_index_url())
+        .env_remove(EnvVars::UV_EXCLUDE_NEWER), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + package-a==0.2.0a1
+    "
+    );
+
+    let columns_filters = filters
+        .iter()
+        .copied()
+        .chain(vec![(r"\-\-\-\-\-\-+.*", "[UNDERLINE]"), ("  +", " ")])
+        .collect::<Vec<_>>();
+
+    uv_snapshot!(columns_filters, context.pip_list()
+        .arg("--outdated")
+        .arg("--index-url")
+        .arg(packse_index_url())
+        .env_remove(EnvVars::UV_EXCLUDE_NEWER), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Package Version Latest Type
+    [UNDERLINE]
+    package-a 0.2.0a1 1.0.0a1 wheel
+
+    ----- stderr -----
+    "
+    );
+
+    uv_snapshot!(filters, context.pip_list()
+        .arg("--outdated")
+        .arg("--format")
+        .arg("json")
+        .arg("--prerelease")
+        .arg("disallow")
+        .arg("--index-url")
+        .arg(packse_index_url())
+        .env_remove(EnvVars::UV_EXCLUDE_NEWER), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [{"name":"package-a","version":"0.2.0a1","latest_version":"0.3.0","latest_filetype":"wheel"}]
+
+    ----- stderr -----
+    "#
+    );
+}
+
+/// `--pre` is an alias for `--prerelease allow`, so the two can't be combined.
+#[test]
+fn list_outdated_pre_conflicts_with_prerelease() {
+    let context = uv_test::test_context_with_versions!(&[]);
+
+    uv_snapshot!(context.filters(), context.pip_list()
+        .arg("--outdated")
+        .arg("--pre")
+        .arg("--prerelease")
+        .arg("disallow"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: the argument '--pre' cannot be used with '--prerelease <PRERELEASE>'
+
+    Usage: uv pip list --cache-dir [CACHE_DIR] --outdated --exclude-newer <EXCLUDE_NEWER>
+
+    For more information, try '--help'.
+    "
+    );
+}
+
 #[test]
 #[cfg(feature = "test-pypi")]
 fn list_editable() {
@@ -482,6 +575,59 @@ fn list_exclude() {
     );
 }
 
+#[test]
+#[cfg(feature = "test-pypi")]
+fn list_not_required() {
+    let context = uv_test::test_context!("3.12");
+
+    // Install the editable package.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("-e")
+        .arg(context.workspace_root.join("test/packages/poetry_editable")), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    Prepared 4 packages in [TIME]
+    Installed 4 packages in [TIME]
+     + anyio==4.3.0
+     + idna==3.6
+     + poetry-editable==0.1.0 (from file://[WORKSPACE]/test/packages/poetry_editable)
+     + sniffio==1.3.1
+    "
+    );
+
+    // `anyio`, `idna`, and `sniffio` are all dependencies of `poetry-editable`.
+    uv_snapshot!(context.filters(), context.pip_list()
+    .arg("--not-required")
+    .arg("--format")
+    .arg("freeze"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    poetry-editable==0.1.0
+
+    ----- stderr -----
+    "
+    );
+
+    uv_snapshot!(context.filters(), context.pip_list()
+    .arg("--not-required")
+    .arg("--format")
+    .arg("freeze")
+    .arg("--exclude")
+    .arg("poetry-editable"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    "
+    );
+}
+
 #[test]
 #[cfg(feature = "test-pypi")]
 #[cfg(not(windows))]
@@ -535,6 +535,78 @@ fn show_files() {
     ");
 }
 
+#[test]
+#[cfg(feature = "test-pypi")]
+fn show_entry_points() {
+    let context = uv_test::test_context!("3.12");
+
+    context
+        .pip_install()
+        .arg(context.workspace_root.join("test/packages/flit_editable"))
+        .assert()
+        .success();
+
+    uv_snapshot!(context.filters(), context.pip_show().arg("flit-editable").arg("--entry-points"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Name: flit-editable
+    Version: 0.1.0
+    Location: [SITE_PACKAGES]/
+    Requires:
+    Required-by:
+    Entry-points:
+      [console_scripts]
+      flit-editable = flit_editable:main
+
+    ----- stderr -----
+    ");
+
+    uv_snapshot!(context.filters(), context.pip_show()
+        .arg("flit-editable")
+        .arg("--entry-points")
+        .arg("--format")
+        .arg("json"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [{"name":"flit-editable","version":"0.1.0","location":"[SITE_PACKAGES]/","requires":[],"required_by":[],"entry_points":{"console_scripts":{"flit-editable":"flit_editable:main"}}}]
+
+    ----- stderr -----
+    "#);
+}
+
+#[test]
+#[cfg(feature = "test-pypi")]
+fn show_format_json() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("requests==2.31.0")?;
+
+    context
+        .pip_install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .assert()
+        .success();
+
+    uv_snapshot!(context.filters(), context.pip_show()
+        .arg("idna")
+        .arg("requests")
+        .arg("--format")
+        .arg("json"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [{"name":"idna","version":"3.6","location":"[SITE_PACKAGES]/","requires":[],"required_by":["requests"]},{"name":"requests","version":"2.31.0","location":"[SITE_PACKAGES]/","requires":["certifi","charset-normalizer","idna","urllib3"],"required_by":[]}]
+
+    ----- stderr -----
+    "#);
+
+    Ok(())
+}
+
 #[test]
 #[cfg(feature = "test-pypi")]
 fn show_target() -> Result<()> {
@@ -0,0 +1,109 @@
+use assert_cmd::assert::OutputAssertExt;
+
+use uv_test::uv_snapshot;
+
+#[test]
+fn stub_satisfies_install() {
+    let context = uv_test::test_context!("3.12");
+
+    uv_snapshot!(context.filters(), context.pip_stub().arg("iniconfig==2.0.0"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Stubbed 1 package in [TIME]
+     + iniconfig==2.0.0
+    "
+    );
+
+    // The stub is reported as installed, and satisfies subsequent installs.
+    uv_snapshot!(context.filters(), context.pip_list(), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Package   Version
+    --------- -------
+    iniconfig 2.0.0
+
+    ----- stderr -----
+    "
+    );
+
+    uv_snapshot!(context.filters(), context.pip_install().arg("iniconfig"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Audited 1 package in [TIME]
+    "
+    );
+
+    // Stubs are removed like any other package.
+    uv_snapshot!(context.filters(), context.pip_uninstall().arg("iniconfig"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Uninstalled 1 package in [TIME]
+     - iniconfig==2.0.0
+    "
+    );
+
+    assert!(
+        !context
+            .site_packages()
+            .join("iniconfig-2.0.0.dist-info")
+            .exists()
+    );
+}
+
+#[test]
+fn stub_already_installed() {
+    let context = uv_test::test_context!("3.12");
+
+    context
+        .pip_install()
+        .arg("iniconfig==2.0.0")
+        .assert()
+        .success();
+
+    uv_snapshot!(context.filters(), context.pip_stub().arg("iniconfig==2.0.0"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: Skipping iniconfig==2.0.0 as it is already installed
+    "
+    );
+
+    uv_snapshot!(context.filters(), context.pip_stub().arg("iniconfig==1.1.1"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Refusing to stub iniconfig==1.1.1 as iniconfig==2.0.0 is already installed (run `uv pip uninstall iniconfig` first)
+    "
+    );
+}
+
+#[test]
+fn stub_invalid() {
+    let context = uv_test::test_context!("3.12");
+
+    uv_snapshot!(context.filters(), context.pip_stub().arg("iniconfig"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: invalid value 'iniconfig' for '<PACKAGE==VERSION>...': Invalid stub `iniconfig`; expected a package name and version, e.g., `numpy==2.0.0`
+
+    For more information, try '--help'.
+    "
+    );
+}
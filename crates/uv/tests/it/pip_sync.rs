@@ -2513,6 +2513,108 @@ fn sync_editable() -> Result<()> {
     Ok(())
 }
 
+/// Reinstall packages that were built against a different interpreter with
+/// `--reinstall-if-python-changed`.
+#[test]
+fn sync_reinstall_if_python_changed() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+    let poetry_editable = context.temp_dir.child("poetry_editable");
+
+    copy_dir_all(
+        context.workspace_root.join("test/packages/poetry_editable"),
+        &poetry_editable,
+    )?;
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(&indoc::formatdoc! {r"
+        anyio==3.7.0
+        -e file://{poetry_editable}
+        ",
+        poetry_editable = poetry_editable.display()
+    })?;
+
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg(requirements_txt.path()), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + anyio==3.7.0
+     + poetry-editable==0.1.0 (from file://[TEMP_DIR]/poetry_editable)
+    "
+    );
+
+    // The interpreter is only recorded for packages that were built from source.
+    let fingerprint = context
+        .site_packages()
+        .join("poetry_editable-0.1.0.dist-info")
+        .join("uv_interpreter.json");
+    assert!(fingerprint.is_file());
+    assert!(
+        !context
+            .site_packages()
+            .join("anyio-3.7.0.dist-info")
+            .join("uv_interpreter.json")
+            .exists()
+    );
+
+    // The interpreter is unchanged, so this is a no-op.
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg(requirements_txt.path())
+        .arg("--reinstall-if-python-changed"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Audited 2 packages in [TIME]
+    "
+    );
+
+    // Simulate a package that was built against a different interpreter.
+    fs_err::write(
+        &fingerprint,
+        r#"{"implementation":"cpython","version":"3.11.0"}"#,
+    )?;
+
+    // Without the flag, the package is left as-is.
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg(requirements_txt.path()), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Audited 2 packages in [TIME]
+    "
+    );
+
+    // With the flag, only the affected package is reinstalled.
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg(requirements_txt.path())
+        .arg("--reinstall-if-python-changed"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     ~ poetry-editable==0.1.0 (from file://[TEMP_DIR]/poetry_editable)
+    "
+    );
+
+    Ok(())
+}
+
 #[test]
 fn sync_editable_and_registry() -> Result<()> {
     let context = uv_test::test_context!("3.12");
@@ -2876,7 +2978,7 @@ fn find_links_offline_no_match() -> Result<()> {
       × No solution found when resolving dependencies:
       ╰─▶ Because numpy was not found in the cache and you require numpy, we can conclude that your requirements are unsatisfiable.
 
-          hint: Packages were unavailable because the network was disabled. When the network is disabled, registry packages may only be read from the cache.
+          hint: Packages were unavailable because the network was disabled. When the network is disabled, registry packages may only be read from the cache (pre-populate the `simple-v22` and `wheels-v8` cache buckets while online).
     "
     );
 
@@ -3001,7 +3103,7 @@ fn offline() -> Result<()> {
       × No solution found when resolving dependencies:
       ╰─▶ Because black was not found in the cache and you require black==23.10.1, we can conclude that your requirements are unsatisfiable.
 
-          hint: Packages were unavailable because the network was disabled. When the network is disabled, registry packages may only be read from the cache.
+          hint: Packages were unavailable because the network was disabled. When the network is disabled, registry packages may only be read from the cache (pre-populate the `simple-v22` and `wheels-v8` cache buckets while online).
     "
     );
 
@@ -3869,6 +3971,33 @@ fn require_hashes_wrong_algorithm() -> Result<()> {
     Ok(())
 }
 
+/// Include a hash for an algorithm other than the one provided by the registry.
+#[test]
+fn require_hashes_sha512() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt
+        .write_str("anyio==4.0.0 --hash=sha512:f30761c1e8725b49c498273b90dba4b05c0fd157811994c806183062cb6647e773364ce45f0e1ff0b10e32fe6d0232ea5ad39476ccf37109d6b49603a09c11c2")?;
+
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--require-hashes"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + anyio==4.0.0
+    "
+    );
+
+    Ok(())
+}
+
 /// Include the hash for a source distribution specified as a direct URL dependency.
 #[test]
 fn require_hashes_source_url() -> Result<()> {
@@ -4109,12 +4238,14 @@ fn require_hashes_git() -> Result<()> {
         .arg("requirements.txt")
         .arg("--require-hashes"), @"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
-      × Failed to download and build `anyio @ git+https://github.com/agronholm/anyio@4a23745badf5bf5ef7928f1e346e9986bd696d82`
-      ╰─▶ Hash-checking is not supported for Git repositories: `anyio @ git+https://github.com/agronholm/anyio@4a23745badf5bf5ef7928f1e346e9986bd696d82`
+    error: In `--require-hashes` mode, all requirements must be hashable, but found:
+    - anyio @ git+https://github.com/agronholm/anyio@4a23745badf5bf5ef7928f1e346e9986bd696d82 (Git repository)
+
+    hint: To install a named requirement without hash-checking, pass `--allow-unhashed <name>`
     "
     );
 
@@ -4139,12 +4270,14 @@ fn require_hashes_source_tree() -> Result<()> {
         .arg("requirements.txt")
         .arg("--require-hashes"), @"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
-      × Failed to build `black @ file://[WORKSPACE]/test/packages/black_editable`
-      ╰─▶ Hash-checking is not supported for local directories: `black @ file://[WORKSPACE]/test/packages/black_editable`
+    error: In `--require-hashes` mode, all requirements must be hashable, but found:
+    - black @ file://[WORKSPACE]/test/packages/black_editable (local directory)
+
+    hint: To install a named requirement without hash-checking, pass `--allow-unhashed <name>`
     "
     );
 
@@ -4412,7 +4545,10 @@ fn require_hashes_editable() -> Result<()> {
     ----- stdout -----
 
     ----- stderr -----
-    error: In `--require-hashes` mode, all requirements must have a hash, but none were provided for: file://[WORKSPACE]/test/packages/black_editable[d]
+    error: In `--require-hashes` mode, all requirements must be hashable, but found:
+    - file://[WORKSPACE]/test/packages/black_editable[d] (editable)
+
+    hint: To install a named requirement without hash-checking, pass `--allow-unhashed <name>`
     "
     );
 
@@ -6307,3 +6443,228 @@ fn sync_with_target_installs_missing_python() -> Result<()> {
     );
     Ok(())
 }
+
+/// Sync the `main` group of a `poetry.lock`.
+#[test]
+fn sync_poetry_lock() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let poetry_lock = context.temp_dir.child("poetry.lock");
+    poetry_lock.write_str(indoc! {r#"
+        [[package]]
+        name = "iniconfig"
+        version = "2.0.0"
+        description = "brain-dead simple config-ini parsing"
+        optional = false
+        python-versions = ">=3.7"
+        groups = ["main"]
+        files = [
+            {file = "iniconfig-2.0.0-py3-none-any.whl", hash = "sha256:b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374"},
+        ]
+
+        [[package]]
+        name = "typing-extensions"
+        version = "4.10.0"
+        description = "Backported and Experimental Type Hints for Python 3.8+"
+        optional = false
+        python-versions = ">=3.8"
+        groups = ["main"]
+        markers = "python_version < \"3.11\""
+        files = []
+
+        [[package]]
+        name = "anyio"
+        version = "4.3.0"
+        description = "High level compatibility layer for multiple asynchronous event loop implementations"
+        optional = false
+        python-versions = ">=3.8"
+        groups = ["dev"]
+        files = []
+
+        [[package]]
+        name = "idna"
+        version = "3.6"
+        description = "Internationalized Domain Names in Applications (IDNA)"
+        optional = true
+        python-versions = ">=3.5"
+        groups = ["main"]
+        files = []
+
+        [metadata]
+        lock-version = "2.1"
+        python-versions = ">=3.12"
+        content-hash = "0000000000000000000000000000000000000000000000000000000000000000"
+    "#})?;
+
+    uv_snapshot!(context.pip_sync()
+        .arg("poetry.lock")
+        .arg("--require-hashes"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    "
+    );
+
+    Ok(())
+}
+
+/// Sync the `default` packages of a `Pipfile.lock`.
+#[test]
+fn sync_pipfile_lock() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pipfile_lock = context.temp_dir.child("Pipfile.lock");
+    pipfile_lock.write_str(indoc! {r#"
+        {
+            "_meta": {
+                "hash": {
+                    "sha256": "0000000000000000000000000000000000000000000000000000000000000000"
+                },
+                "pipfile-spec": 6,
+                "requires": {
+                    "python_version": "3.12"
+                },
+                "sources": [
+                    {
+                        "name": "pypi",
+                        "url": "https://pypi.org/simple",
+                        "verify_ssl": true
+                    }
+                ]
+            },
+            "default": {
+                "iniconfig": {
+                    "hashes": [
+                        "sha256:b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374"
+                    ],
+                    "index": "pypi",
+                    "markers": "python_version >= '3.7'",
+                    "version": "==2.0.0"
+                }
+            },
+            "develop": {
+                "anyio": {
+                    "index": "pypi",
+                    "version": "==4.3.0"
+                }
+            }
+        }
+    "#})?;
+
+    uv_snapshot!(context.pip_sync()
+        .arg("Pipfile.lock")
+        .arg("--require-hashes"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    "
+    );
+
+    Ok(())
+}
+
+/// Install an overlay of extra tools on top of a frozen base, resolved against the base pins.
+#[test]
+fn sync_overlay() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(indoc::indoc! {r"
+        anyio==3.7.0
+        idna==3.6
+        sniffio==1.3.1
+    "})?;
+
+    // `idna` is pinned by the base, so the overlay can't select a different version.
+    let overlay_in = context.temp_dir.child("overlay.in");
+    overlay_in.write_str(indoc::indoc! {r"
+        iniconfig
+        idna
+    "})?;
+
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg(requirements_txt.path())
+        .arg("--overlay")
+        .arg(overlay_in.path()), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Resolved 2 packages in [TIME]
+    Prepared 4 packages in [TIME]
+    Installed 4 packages in [TIME]
+     + anyio==3.7.0
+     + idna==3.6
+     + iniconfig==2.0.0
+     + sniffio==1.3.1
+    "
+    );
+
+    // An overlay that conflicts with the base pins is rejected.
+    overlay_in.write_str("idna>=3.7")?;
+
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg(requirements_txt.path())
+        .arg("--overlay")
+        .arg(overlay_in.path()), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+      × No solution found when resolving overlay dependencies:
+      ╰─▶ Because you require idna>=3.7 and idna==3.6, we can conclude that your requirements are unsatisfiable.
+    "
+    );
+
+    // Syncing without the overlay removes only the overlay packages.
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg(requirements_txt.path()), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Uninstalled 1 package in [TIME]
+     - iniconfig==2.0.0
+    "
+    );
+
+    // Hashes are required for the overlay requirements, too.
+    requirements_txt.write_str(
+        "iniconfig==2.0.0 --hash=sha256:b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374",
+    )?;
+    overlay_in.write_str("anyio==4.0.0")?;
+
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg(requirements_txt.path())
+        .arg("--overlay")
+        .arg(overlay_in.path())
+        .arg("--require-hashes"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: In `--require-hashes` mode, all requirements must have a hash, but none were provided for: anyio==4.0.0
+    "
+    );
+
+    Ok(())
+}
@@ -328,6 +328,127 @@ fn uninstall_duplicate() -> Result<()> {
     Ok(())
 }
 
+/// Refuse to uninstall a package that was installed by another tool with `--only-uv-managed`.
+#[test]
+#[cfg(feature = "test-pypi")]
+fn uninstall_only_uv_managed() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    context
+        .pip_install()
+        .arg("iniconfig==2.0.0")
+        .arg("MarkupSafe==2.1.3")
+        .assert()
+        .success();
+
+    // Mark `iniconfig` as having been installed by another tool.
+    ChildPath::new(context.site_packages())
+        .child("iniconfig-2.0.0.dist-info")
+        .child("INSTALLER")
+        .write_str("conda")?;
+
+    uv_snapshot!(context.filters(), context.pip_uninstall()
+        .arg("--only-uv-managed")
+        .arg("iniconfig"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Refusing to modify `iniconfig`, which was installed by `conda` rather than uv (omit `--only-uv-managed` to modify it anyway)
+    "
+    );
+
+    // Packages installed by uv can still be uninstalled.
+    uv_snapshot!(context.filters(), context.pip_uninstall()
+        .arg("--only-uv-managed")
+        .arg("markupsafe"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Uninstalled 1 package in [TIME]
+     - markupsafe==2.1.3
+    "
+    );
+
+    // Without the flag, the package is uninstalled as usual.
+    uv_snapshot!(context.filters(), context.pip_uninstall()
+        .arg("iniconfig"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Uninstalled 1 package in [TIME]
+     - iniconfig==2.0.0
+    "
+    );
+
+    Ok(())
+}
+
+/// Refuse to uninstall a package listed in `protected-packages`.
+#[test]
+#[cfg(feature = "test-pypi")]
+fn uninstall_protected_packages() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    context
+        .pip_install()
+        .arg("iniconfig==2.0.0")
+        .arg("MarkupSafe==2.1.3")
+        .assert()
+        .success();
+
+    let uv_toml = context.temp_dir.child("uv.toml");
+    uv_toml.write_str("protected-packages = [\"iniconfig\"]")?;
+
+    uv_snapshot!(context.filters(), context.pip_uninstall()
+        .arg("iniconfig")
+        .arg("markupsafe"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Refusing to remove `iniconfig`, which is protected by the `protected-packages` setting
+    "
+    );
+
+    // Unprotected packages can still be uninstalled.
+    uv_snapshot!(context.filters(), context.pip_uninstall()
+        .arg("markupsafe"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Uninstalled 1 package in [TIME]
+     - markupsafe==2.1.3
+    "
+    );
+
+    // Protected packages can't be removed by `uv pip sync` either.
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("MarkupSafe==2.1.3")?;
+
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("requirements.txt"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    error: Refusing to remove `iniconfig`, which is protected by the `protected-packages` setting
+    "
+    );
+
+    Ok(())
+}
+
 /// Uninstall a `.egg-info` package in a virtual environment.
 #[test]
 fn uninstall_egg_info() -> Result<()> {
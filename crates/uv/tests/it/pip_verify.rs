@@ -0,0 +1,134 @@
+use anyhow::Result;
+use assert_cmd::assert::OutputAssertExt;
+
+use uv_test::uv_snapshot;
+
+#[test]
+fn verify_installed_packages() {
+    let context = uv_test::test_context!("3.12");
+
+    uv_snapshot!(context.filters(), context
+        .pip_install()
+        .arg("iniconfig==2.0.0")
+        .arg("--verify-record"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    Verified 11 files in 1 package in [TIME]
+    All installed files match their `RECORD`
+    "
+    );
+
+    uv_snapshot!(context.filters(), context.pip_verify(), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Verified 11 files in 1 package in [TIME]
+    All installed files match their `RECORD`
+    "
+    );
+}
+
+#[test]
+fn verify_modified_packages() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    context
+        .pip_install()
+        .arg("iniconfig==2.0.0")
+        .assert()
+        .success();
+
+    // Modify one file and remove another.
+    fs_err::write(
+        context
+            .site_packages()
+            .join("iniconfig")
+            .join("__init__.py"),
+        "",
+    )?;
+    fs_err::remove_file(context.site_packages().join("iniconfig").join("py.typed"))?;
+
+    uv_snapshot!(context.filters(), context.pip_verify().arg("iniconfig"), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Verified 10 files in 1 package in [TIME]
+    Found 2 problems
+    The package `iniconfig` does not match its `RECORD`: `iniconfig/__init__.py` has hash `sha256=47DEQpj8HBSa-_TImW-5JCeuQeRkm5NMpJWZG3hSuFU`, but the `RECORD` lists `sha256=ybOjxVqlW5IunfNpvx-Jsb7-j0w6ZgrehwWH_ps_ybc`
+    The package `iniconfig` does not match its `RECORD`: `iniconfig/py.typed` is missing
+    "
+    );
+
+    // Packages that aren't installed are reported.
+    uv_snapshot!(context.filters(), context.pip_verify().arg("flask"), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: Package `flask` is not installed
+    Verified 0 files in 0 packages in [TIME]
+    All installed files match their `RECORD`
+    "
+    );
+
+    Ok(())
+}
+
+#[test]
+fn verify_extra_files() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    context
+        .pip_install()
+        .arg("iniconfig==2.0.0")
+        .assert()
+        .success();
+
+    // Add a file that isn't listed in the `RECORD`, along with some bytecode, which is ignored.
+    let package = context.site_packages().join("iniconfig");
+    fs_err::write(package.join("backdoor.py"), "import os")?;
+    fs_err::create_dir_all(package.join("__pycache__"))?;
+    fs_err::write(
+        package.join("__pycache__").join("backdoor.cpython-312.pyc"),
+        "",
+    )?;
+    fs_err::remove_file(package.join("py.typed"))?;
+
+    uv_snapshot!(context.filters(), context.pip_verify(), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Verified 10 files in 1 package in [TIME]
+    Found 2 problems
+    The package `iniconfig` does not match its `RECORD`: `iniconfig/py.typed` is missing
+    The package `iniconfig` does not match its `RECORD`: `iniconfig/backdoor.py` is not listed
+    "
+    );
+
+    uv_snapshot!(context.filters(), context.pip_verify().arg("--format").arg("json"), @r#"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    {"valid":false,"packages":[{"name":"iniconfig","version":"2.0.0","missing_record":false,"missing":["iniconfig/py.typed"],"modified":[],"extra":["iniconfig/backdoor.py"]}]}
+
+    ----- stderr -----
+    Verified 10 files in 1 package in [TIME]
+    "#
+    );
+
+    Ok(())
+}
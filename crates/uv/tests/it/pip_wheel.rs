@@ -0,0 +1,274 @@
+use anyhow::Result;
+use assert_fs::prelude::*;
+use indoc::indoc;
+use url::Url;
+
+use uv_test::uv_snapshot;
+
+/// Collect the wheels for a package and its dependencies, then install them from the wheel
+/// directory without an index.
+#[test]
+fn wheel() {
+    let context = uv_test::test_context!("3.12");
+
+    uv_snapshot!(context.filters(), context.pip_wheel()
+        .arg("anyio==4.3.0")
+        .arg("--wheel-dir")
+        .arg("wheels"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Collected 3 wheels in [TIME]
+     + anyio-4.3.0-py3-none-any.whl
+     + idna-3.6-py3-none-any.whl
+     + sniffio-1.3.1-py3-none-any.whl
+    "
+    );
+
+    // Nothing should be installed into the environment.
+    context.assert_command("import anyio").failure();
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("anyio==4.3.0")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(context.temp_dir.child("wheels").path()), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Prepared 3 packages in [TIME]
+    Installed 3 packages in [TIME]
+     + anyio==4.3.0
+     + idna==3.6
+     + sniffio==1.3.1
+    "
+    );
+}
+
+/// Build a wheel from a source distribution with `--no-binary`.
+#[test]
+fn wheel_no_binary() {
+    let context = uv_test::test_context!("3.12");
+
+    uv_snapshot!(context.filters(), context.pip_wheel()
+        .arg("iniconfig==2.0.0")
+        .arg("--no-binary")
+        .arg("iniconfig")
+        .arg("-w")
+        .arg("wheels"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Collected 1 wheel in [TIME]
+     + iniconfig-2.0.0-py3-none-any.whl
+    "
+    );
+
+    // The source distribution itself should not be saved.
+    let wheels = context.temp_dir.child("wheels");
+    wheels
+        .child("iniconfig-2.0.0-py3-none-any.whl")
+        .assert(predicates::path::is_file());
+    wheels
+        .child("iniconfig-2.0.0.tar.gz")
+        .assert(predicates::path::missing());
+}
+
+/// Build a wheel for a local project, along with its dependencies.
+#[test]
+fn wheel_local_project() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("project").child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig==2.0.0"]
+
+        [build-system]
+        requires = ["hatchling"]
+        build-backend = "hatchling.build"
+    "#})?;
+    context
+        .temp_dir
+        .child("project")
+        .child("src")
+        .child("project")
+        .child("__init__.py")
+        .touch()?;
+
+    uv_snapshot!(context.filters(), context.pip_wheel()
+        .arg("./project")
+        .arg("-w")
+        .arg("wheels"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Collected 2 wheels in [TIME]
+     + iniconfig-2.0.0-py3-none-any.whl
+     + project-0.1.0-py3-none-any.whl
+    "
+    );
+
+    Ok(())
+}
+
+/// Validate the hashes in the requirements file for downloaded wheels.
+#[test]
+fn wheel_hash_mismatch() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let wheel = context.temp_dir.child("tqdm-1000.0.0-py3-none-any.whl");
+    fs_err::copy(
+        context
+            .workspace_root
+            .join("test/links/tqdm-1000.0.0-py3-none-any.whl"),
+        &wheel,
+    )?;
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(&format!(
+        "tqdm @ {} --hash=sha256:0000000000000000000000000000000000000000000000000000000000000000",
+        Url::from_file_path(wheel.path()).unwrap()
+    ))?;
+
+    uv_snapshot!(context.filters(), context.pip_wheel()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--no-deps")
+        .arg("-w")
+        .arg("wheels"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    error: Hash mismatch for `tqdm @ file://[TEMP_DIR]/tqdm-1000.0.0-py3-none-any.whl`
+
+    Expected:
+      sha256:0000000000000000000000000000000000000000000000000000000000000000
+
+    Computed:
+      sha256:a34996d4bd5abb2336e14ff0a2d22b92cfd0f0ed344e6883041ce01953276a13
+    "
+    );
+
+    // Nothing should be written to the wheel directory.
+    context
+        .temp_dir
+        .child("wheels")
+        .child("tqdm-1000.0.0-py3-none-any.whl")
+        .assert(predicates::path::missing());
+
+    // With the correct hash, the wheel is collected.
+    requirements_txt.write_str(&format!(
+        "tqdm @ {} --hash=sha256:a34996d4bd5abb2336e14ff0a2d22b92cfd0f0ed344e6883041ce01953276a13",
+        Url::from_file_path(wheel.path()).unwrap()
+    ))?;
+
+    uv_snapshot!(context.filters(), context.pip_wheel()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--no-deps")
+        .arg("--require-hashes")
+        .arg("-w")
+        .arg("wheels"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Collected 1 wheel in [TIME]
+     + tqdm-1000.0.0-py3-none-any.whl
+    "
+    );
+
+    Ok(())
+}
+
+/// Validate the hashes in the requirements file for source distributions before building them.
+#[test]
+fn wheel_sdist_hash_mismatch() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let sdist = context.temp_dir.child("tqdm-999.0.0.tar.gz");
+    fs_err::copy(
+        context
+            .workspace_root
+            .join("test/links/tqdm-999.0.0.tar.gz"),
+        &sdist,
+    )?;
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(&format!(
+        "tqdm @ {} --hash=sha256:0000000000000000000000000000000000000000000000000000000000000000",
+        Url::from_file_path(sdist.path()).unwrap()
+    ))?;
+
+    uv_snapshot!(context.filters(), context.pip_wheel()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--no-deps")
+        .arg("-w")
+        .arg("wheels"), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+      × Failed to build `tqdm @ file://[TEMP_DIR]/tqdm-999.0.0.tar.gz`
+      ╰─▶ Hash mismatch for `tqdm @ file://[TEMP_DIR]/tqdm-999.0.0.tar.gz`
+
+          Expected:
+            sha256:0000000000000000000000000000000000000000000000000000000000000000
+
+          Computed:
+            sha256:89fa05cffa7f457658373b85de302d24d0c205ceda2819a8739e324b75e9430b
+    "
+    );
+
+    Ok(())
+}
+
+/// In `--require-hashes` mode, every requirement must have a hash.
+#[test]
+fn wheel_require_hashes_missing() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("anyio==4.0.0")?;
+
+    uv_snapshot!(context.filters(), context.pip_wheel()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--no-deps")
+        .arg("--require-hashes")
+        .arg("-w")
+        .arg("wheels"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: In `--require-hashes` mode, all requirements must have a hash, but none were provided for: anyio==4.0.0
+    "
+    );
+
+    Ok(())
+}
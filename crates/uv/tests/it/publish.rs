@@ -685,6 +685,84 @@ async fn upload_error_problem_details() {
     );
 }
 
+/// `--repository-url` is accepted as an alias for `--publish-url`, for compatibility with `twine`.
+#[tokio::test]
+async fn repository_url_alias() {
+    let context = uv_test::test_context!("3.12");
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/upload"))
+        .and(basic_auth("__token__", "dummy"))
+        .respond_with(ResponseTemplate::new(200))
+        .mount(&server)
+        .await;
+
+    uv_snapshot!(context.filters(), context.publish()
+        .arg("--token")
+        .arg("dummy")
+        .arg("--repository-url")
+        .arg(format!("{}/upload", server.uri()))
+        .arg(dummy_wheel()), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Publishing 1 file to http://[LOCALHOST]/upload
+    Uploading ok-1.0.0-py3-none-any.whl ([SIZE])
+    "
+    );
+}
+
+/// Repositories and credentials are read from `~/.pypirc`, for compatibility with `twine`.
+#[tokio::test]
+async fn pypirc_repository() {
+    let context = uv_test::test_context!("3.12");
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/upload"))
+        .and(basic_auth("ci", "secret"))
+        .respond_with(ResponseTemplate::new(200))
+        .mount(&server)
+        .await;
+
+    context
+        .home_dir
+        .child(".pypirc")
+        .write_str(&formatdoc! {
+            r"
+            [distutils]
+            index-servers =
+                private
+
+            [private]
+            repository = {server_uri}/upload
+            username = ci
+            password = secret
+            ",
+            server_uri = server.uri()
+        })
+        .unwrap();
+
+    uv_snapshot!(context.filters(), context.publish()
+        .arg("--index")
+        .arg("private")
+        .arg("--trusted-publishing")
+        .arg("never")
+        .arg(dummy_wheel()), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Publishing 1 file to http://[LOCALHOST]/upload
+    Uploading ok-1.0.0-py3-none-any.whl ([SIZE])
+    "
+    );
+}
+
 /// Test that `--dry-run` checks all files and reports all errors instead of
 /// stopping at the first failure.
 #[test]
@@ -64,11 +64,27 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             native_tls: false,
             http_proxy: None,
             https_proxy: None,
+            all_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
             read_timeout: [TIME],
             connect_timeout: [TIME],
+            stall_timeout: [TIME],
             retries: 3,
+            retry_max_delay: [TIME],
+            allow_index_failure: false,
+            index_cache_ttl: None,
+            index_stale_if_error: false,
+            stale_metadata_report: None,
+            credential_helper: None,
+            attestation_policy: AttestationPolicy {
+                required: false,
+                trusted_publishers: {},
+            },
+            client_cert: None,
+            client_key: None,
+            limit_rate: None,
+            max_connections_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -89,6 +105,7 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        scratch_dir: None,
     }
     PipCompileSettings {
         format: None,
@@ -106,6 +123,8 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        minimal_update: false,
+        emit_graph: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -149,8 +168,11 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
                         format: Simple,
                         publish_url: None,
                         authenticate: Auto,
+                        credentials: None,
+                        ca_bundle: None,
                         ignore_error_codes: None,
                         cache_control: None,
+                        mirrors: [],
                     },
                 ],
                 flat_index: [],
@@ -190,6 +212,12 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             keyring_provider: Disabled,
             torch_backend: None,
             build_isolation: Isolate,
+            excluded_packages: [],
+            resolution_priority: [],
+            max_backtracks: None,
+            memory_limit: None,
+            protected_packages: [],
+            allow_yanked: [],
             extra_build_dependencies: ExtraBuildDependencies(
                 {},
             ),
@@ -205,6 +233,7 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             dependency_mode: Transitive,
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
+            prerelease_packages: [],
             fork_strategy: RequiresPython,
             dependency_metadata: DependencyMetadata(
                 {},
@@ -247,6 +276,7 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             upgrade: Upgrade {
                 strategy: None,
                 constraints: {},
+                eager: false,
             },
             reinstall: None,
         },
@@ -275,11 +305,27 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             native_tls: false,
             http_proxy: None,
             https_proxy: None,
+            all_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
             read_timeout: [TIME],
             connect_timeout: [TIME],
+            stall_timeout: [TIME],
             retries: 3,
+            retry_max_delay: [TIME],
+            allow_index_failure: false,
+            index_cache_ttl: None,
+            index_stale_if_error: false,
+            stale_metadata_report: None,
+            credential_helper: None,
+            attestation_policy: AttestationPolicy {
+                required: false,
+                trusted_publishers: {},
+            },
+            client_cert: None,
+            client_key: None,
+            limit_rate: None,
+            max_connections_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -300,6 +346,7 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        scratch_dir: None,
     }
     PipCompileSettings {
         format: None,
@@ -317,6 +364,8 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        minimal_update: false,
+        emit_graph: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -360,8 +409,11 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
                         format: Simple,
                         publish_url: None,
                         authenticate: Auto,
+                        credentials: None,
+                        ca_bundle: None,
                         ignore_error_codes: None,
                         cache_control: None,
+                        mirrors: [],
                     },
                 ],
                 flat_index: [],
@@ -401,6 +453,12 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             keyring_provider: Disabled,
             torch_backend: None,
             build_isolation: Isolate,
+            excluded_packages: [],
+            resolution_priority: [],
+            max_backtracks: None,
+            memory_limit: None,
+            protected_packages: [],
+            allow_yanked: [],
             extra_build_dependencies: ExtraBuildDependencies(
                 {},
             ),
@@ -416,6 +474,7 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             dependency_mode: Transitive,
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
+            prerelease_packages: [],
             fork_strategy: RequiresPython,
             dependency_metadata: DependencyMetadata(
                 {},
@@ -458,6 +517,7 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             upgrade: Upgrade {
                 strategy: None,
                 constraints: {},
+                eager: false,
             },
             reinstall: None,
         },
@@ -487,11 +547,27 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             native_tls: false,
             http_proxy: None,
             https_proxy: None,
+            all_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
             read_timeout: [TIME],
             connect_timeout: [TIME],
+            stall_timeout: [TIME],
             retries: 3,
+            retry_max_delay: [TIME],
+            allow_index_failure: false,
+            index_cache_ttl: None,
+            index_stale_if_error: false,
+            stale_metadata_report: None,
+            credential_helper: None,
+            attestation_policy: AttestationPolicy {
+                required: false,
+                trusted_publishers: {},
+            },
+            client_cert: None,
+            client_key: None,
+            limit_rate: None,
+            max_connections_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -512,6 +588,7 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        scratch_dir: None,
     }
     PipCompileSettings {
         format: None,
@@ -529,6 +606,8 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        minimal_update: false,
+        emit_graph: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -572,8 +651,11 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
                         format: Simple,
                         publish_url: None,
                         authenticate: Auto,
+                        credentials: None,
+                        ca_bundle: None,
                         ignore_error_codes: None,
                         cache_control: None,
+                        mirrors: [],
                     },
                 ],
                 flat_index: [],
@@ -613,6 +695,12 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             keyring_provider: Disabled,
             torch_backend: None,
             build_isolation: Isolate,
+            excluded_packages: [],
+            resolution_priority: [],
+            max_backtracks: None,
+            memory_limit: None,
+            protected_packages: [],
+            allow_yanked: [],
             extra_build_dependencies: ExtraBuildDependencies(
                 {},
             ),
@@ -628,6 +716,7 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             dependency_mode: Transitive,
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
+            prerelease_packages: [],
             fork_strategy: RequiresPython,
             dependency_metadata: DependencyMetadata(
                 {},
@@ -670,6 +759,7 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             upgrade: Upgrade {
                 strategy: None,
                 constraints: {},
+                eager: false,
             },
             reinstall: None,
         },
@@ -731,11 +821,27 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             native_tls: false,
             http_proxy: None,
             https_proxy: None,
+            all_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
             read_timeout: [TIME],
             connect_timeout: [TIME],
+            stall_timeout: [TIME],
             retries: 3,
+            retry_max_delay: [TIME],
+            allow_index_failure: false,
+            index_cache_ttl: None,
+            index_stale_if_error: false,
+            stale_metadata_report: None,
+            credential_helper: None,
+            attestation_policy: AttestationPolicy {
+                required: false,
+                trusted_publishers: {},
+            },
+            client_cert: None,
+            client_key: None,
+            limit_rate: None,
+            max_connections_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -756,6 +862,7 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        scratch_dir: None,
     }
     PipCompileSettings {
         format: None,
@@ -773,6 +880,8 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        minimal_update: false,
+        emit_graph: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -816,8 +925,11 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
                         format: Simple,
                         publish_url: None,
                         authenticate: Auto,
+                        credentials: None,
+                        ca_bundle: None,
                         ignore_error_codes: None,
                         cache_control: None,
+                        mirrors: [],
                     },
                 ],
                 flat_index: [],
@@ -857,6 +969,12 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             keyring_provider: Disabled,
             torch_backend: None,
             build_isolation: Isolate,
+            excluded_packages: [],
+            resolution_priority: [],
+            max_backtracks: None,
+            memory_limit: None,
+            protected_packages: [],
+            allow_yanked: [],
             extra_build_dependencies: ExtraBuildDependencies(
                 {},
             ),
@@ -872,6 +990,7 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             dependency_mode: Transitive,
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
+            prerelease_packages: [],
             fork_strategy: RequiresPython,
             dependency_metadata: DependencyMetadata(
                 {},
@@ -914,6 +1033,7 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             upgrade: Upgrade {
                 strategy: None,
                 constraints: {},
+                eager: false,
             },
             reinstall: None,
         },
@@ -944,11 +1064,27 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             native_tls: false,
             http_proxy: None,
             https_proxy: None,
+            all_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
             read_timeout: [TIME],
             connect_timeout: [TIME],
+            stall_timeout: [TIME],
             retries: 3,
+            retry_max_delay: [TIME],
+            allow_index_failure: false,
+            index_cache_ttl: None,
+            index_stale_if_error: false,
+            stale_metadata_report: None,
+            credential_helper: None,
+            attestation_policy: AttestationPolicy {
+                required: false,
+                trusted_publishers: {},
+            },
+            client_cert: None,
+            client_key: None,
+            limit_rate: None,
+            max_connections_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -969,6 +1105,7 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        scratch_dir: None,
     }
     PipCompileSettings {
         format: None,
@@ -986,6 +1123,8 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        minimal_update: false,
+        emit_graph: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -1034,6 +1173,12 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             keyring_provider: Disabled,
             torch_backend: None,
             build_isolation: Isolate,
+            excluded_packages: [],
+            resolution_priority: [],
+            max_backtracks: None,
+            memory_limit: None,
+            protected_packages: [],
+            allow_yanked: [],
             extra_build_dependencies: ExtraBuildDependencies(
                 {},
             ),
@@ -1049,6 +1194,7 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             dependency_mode: Transitive,
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
+            prerelease_packages: [],
             fork_strategy: RequiresPython,
             dependency_metadata: DependencyMetadata(
                 {},
@@ -1091,6 +1237,7 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             upgrade: Upgrade {
                 strategy: None,
                 constraints: {},
+                eager: false,
             },
             reinstall: None,
         },
@@ -1131,11 +1278,27 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             native_tls: false,
             http_proxy: None,
             https_proxy: None,
+            all_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
             read_timeout: [TIME],
             connect_timeout: [TIME],
+            stall_timeout: [TIME],
             retries: 3,
+            retry_max_delay: [TIME],
+            allow_index_failure: false,
+            index_cache_ttl: None,
+            index_stale_if_error: false,
+            stale_metadata_report: None,
+            credential_helper: None,
+            attestation_policy: AttestationPolicy {
+                required: false,
+                trusted_publishers: {},
+            },
+            client_cert: None,
+            client_key: None,
+            limit_rate: None,
+            max_connections_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -1156,6 +1319,7 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        scratch_dir: None,
     }
     PipCompileSettings {
         format: None,
@@ -1173,6 +1337,8 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        minimal_update: false,
+        emit_graph: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -1214,8 +1380,11 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
                         format: Simple,
                         publish_url: None,
                         authenticate: Auto,
+                        credentials: None,
+                        ca_bundle: None,
                         ignore_error_codes: None,
                         cache_control: None,
+                        mirrors: [],
                     },
                 ],
                 flat_index: [],
@@ -1255,6 +1424,12 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             keyring_provider: Disabled,
             torch_backend: None,
             build_isolation: Isolate,
+            excluded_packages: [],
+            resolution_priority: [],
+            max_backtracks: None,
+            memory_limit: None,
+            protected_packages: [],
+            allow_yanked: [],
             extra_build_dependencies: ExtraBuildDependencies(
                 {},
             ),
@@ -1270,6 +1445,7 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             dependency_mode: Transitive,
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
+            prerelease_packages: [],
             fork_strategy: RequiresPython,
             dependency_metadata: DependencyMetadata(
                 {},
@@ -1314,6 +1490,7 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             upgrade: Upgrade {
                 strategy: None,
                 constraints: {},
+                eager: false,
             },
             reinstall: None,
         },
@@ -1367,11 +1544,27 @@ fn resolve_index_url() -> anyhow::Result<()> {
             native_tls: false,
             http_proxy: None,
             https_proxy: None,
+            all_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
             read_timeout: [TIME],
             connect_timeout: [TIME],
+            stall_timeout: [TIME],
             retries: 3,
+            retry_max_delay: [TIME],
+            allow_index_failure: false,
+            index_cache_ttl: None,
+            index_stale_if_error: false,
+            stale_metadata_report: None,
+            credential_helper: None,
+            attestation_policy: AttestationPolicy {
+                required: false,
+                trusted_publishers: {},
+            },
+            client_cert: None,
+            client_key: None,
+            limit_rate: None,
+            max_connections_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -1392,6 +1585,7 @@ fn resolve_index_url() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        scratch_dir: None,
     }
     PipCompileSettings {
         format: None,
@@ -1409,6 +1603,8 @@ fn resolve_index_url() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        minimal_update: false,
+        emit_graph: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -1450,8 +1646,11 @@ fn resolve_index_url() -> anyhow::Result<()> {
                         format: Simple,
                         publish_url: None,
                         authenticate: Auto,
+                        credentials: None,
+                        ca_bundle: None,
                         ignore_error_codes: None,
                         cache_control: None,
+                        mirrors: [],
                     },
                     Index {
                         name: None,
@@ -1483,8 +1682,11 @@ fn resolve_index_url() -> anyhow::Result<()> {
                         format: Simple,
                         publish_url: None,
                         authenticate: Auto,
+                        credentials: None,
+                        ca_bundle: None,
                         ignore_error_codes: None,
                         cache_control: None,
+                        mirrors: [],
                     },
                 ],
                 flat_index: [],
@@ -1524,6 +1726,12 @@ fn resolve_index_url() -> anyhow::Result<()> {
             keyring_provider: Disabled,
             torch_backend: None,
             build_isolation: Isolate,
+            excluded_packages: [],
+            resolution_priority: [],
+            max_backtracks: None,
+            memory_limit: None,
+            protected_packages: [],
+            allow_yanked: [],
             extra_build_dependencies: ExtraBuildDependencies(
                 {},
             ),
@@ -1539,6 +1747,7 @@ fn resolve_index_url() -> anyhow::Result<()> {
             dependency_mode: Transitive,
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
+            prerelease_packages: [],
             fork_strategy: RequiresPython,
             dependency_metadata: DependencyMetadata(
                 {},
@@ -1581,6 +1790,7 @@ fn resolve_index_url() -> anyhow::Result<()> {
             upgrade: Upgrade {
                 strategy: None,
                 constraints: {},
+                eager: false,
             },
             reinstall: None,
         },
@@ -1611,11 +1821,27 @@ fn resolve_index_url() -> anyhow::Result<()> {
             native_tls: false,
             http_proxy: None,
             https_proxy: None,
+            all_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
             read_timeout: [TIME],
             connect_timeout: [TIME],
+            stall_timeout: [TIME],
             retries: 3,
+            retry_max_delay: [TIME],
+            allow_index_failure: false,
+            index_cache_ttl: None,
+            index_stale_if_error: false,
+            stale_metadata_report: None,
+            credential_helper: None,
+            attestation_policy: AttestationPolicy {
+                required: false,
+                trusted_publishers: {},
+            },
+            client_cert: None,
+            client_key: None,
+            limit_rate: None,
+            max_connections_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -1636,6 +1862,7 @@ fn resolve_index_url() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        scratch_dir: None,
     }
     PipCompileSettings {
         format: None,
@@ -1653,6 +1880,8 @@ fn resolve_index_url() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        minimal_update: false,
+        emit_graph: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -1696,8 +1925,11 @@ fn resolve_index_url() -> anyhow::Result<()> {
                         format: Simple,
                         publish_url: None,
                         authenticate: Auto,
+                        credentials: None,
+                        ca_bundle: None,
                         ignore_error_codes: None,
                         cache_control: None,
+                        mirrors: [],
                     },
                     Index {
                         name: None,
@@ -1729,8 +1961,11 @@ fn resolve_index_url() -> anyhow::Result<()> {
                         format: Simple,
                         publish_url: None,
                         authenticate: Auto,
+                        credentials: None,
+                        ca_bundle: None,
                         ignore_error_codes: None,
                         cache_control: None,
+                        mirrors: [],
                     },
                     Index {
                         name: None,
@@ -1762,8 +1997,11 @@ fn resolve_index_url() -> anyhow::Result<()> {
                         format: Simple,
                         publish_url: None,
                         authenticate: Auto,
+                        credentials: None,
+                        ca_bundle: None,
                         ignore_error_codes: None,
                         cache_control: None,
+                        mirrors: [],
                     },
                 ],
                 flat_index: [],
@@ -1803,6 +2041,12 @@ fn resolve_index_url() -> anyhow::Result<()> {
             keyring_provider: Disabled,
             torch_backend: None,
             build_isolation: Isolate,
+            excluded_packages: [],
+            resolution_priority: [],
+            max_backtracks: None,
+            memory_limit: None,
+            protected_packages: [],
+            allow_yanked: [],
             extra_build_dependencies: ExtraBuildDependencies(
                 {},
             ),
@@ -1818,6 +2062,7 @@ fn resolve_index_url() -> anyhow::Result<()> {
             dependency_mode: Transitive,
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
+            prerelease_packages: [],
             fork_strategy: RequiresPython,
             dependency_metadata: DependencyMetadata(
                 {},
@@ -1860,6 +2105,7 @@ fn resolve_index_url() -> anyhow::Result<()> {
             upgrade: Upgrade {
                 strategy: None,
                 constraints: {},
+                eager: false,
             },
             reinstall: None,
         },
@@ -1913,11 +2159,27 @@ fn resolve_find_links() -> anyhow::Result<()> {
             native_tls: false,
             http_proxy: None,
             https_proxy: None,
+            all_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
             read_timeout: [TIME],
             connect_timeout: [TIME],
+            stall_timeout: [TIME],
             retries: 3,
+            retry_max_delay: [TIME],
+            allow_index_failure: false,
+            index_cache_ttl: None,
+            index_stale_if_error: false,
+            stale_metadata_report: None,
+            credential_helper: None,
+            attestation_policy: AttestationPolicy {
+                required: false,
+                trusted_publishers: {},
+            },
+            client_cert: None,
+            client_key: None,
+            limit_rate: None,
+            max_connections_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -1938,6 +2200,7 @@ fn resolve_find_links() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        scratch_dir: None,
     }
     PipCompileSettings {
         format: None,
@@ -1955,6 +2218,8 @@ fn resolve_find_links() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        minimal_update: false,
+        emit_graph: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -1997,8 +2262,11 @@ fn resolve_find_links() -> anyhow::Result<()> {
                         format: Flat,
                         publish_url: None,
                         authenticate: Auto,
+                        credentials: None,
+                        ca_bundle: None,
                         ignore_error_codes: None,
                         cache_control: None,
+                        mirrors: [],
                     },
                 ],
                 no_index: true,
@@ -2037,6 +2305,12 @@ fn resolve_find_links() -> anyhow::Result<()> {
             keyring_provider: Disabled,
             torch_backend: None,
             build_isolation: Isolate,
+            excluded_packages: [],
+            resolution_priority: [],
+            max_backtracks: None,
+            memory_limit: None,
+            protected_packages: [],
+            allow_yanked: [],
             extra_build_dependencies: ExtraBuildDependencies(
                 {},
             ),
@@ -2052,6 +2326,7 @@ fn resolve_find_links() -> anyhow::Result<()> {
             dependency_mode: Transitive,
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
+            prerelease_packages: [],
             fork_strategy: RequiresPython,
             dependency_metadata: DependencyMetadata(
                 {},
@@ -2094,6 +2369,7 @@ fn resolve_find_links() -> anyhow::Result<()> {
             upgrade: Upgrade {
                 strategy: None,
                 constraints: {},
+                eager: false,
             },
             reinstall: None,
         },
@@ -2146,11 +2422,27 @@ fn resolve_top_level() -> anyhow::Result<()> {
             native_tls: false,
             http_proxy: None,
             https_proxy: None,
+            all_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
             read_timeout: [TIME],
             connect_timeout: [TIME],
+            stall_timeout: [TIME],
             retries: 3,
+            retry_max_delay: [TIME],
+            allow_index_failure: false,
+            index_cache_ttl: None,
+            index_stale_if_error: false,
+            stale_metadata_report: None,
+            credential_helper: None,
+            attestation_policy: AttestationPolicy {
+                required: false,
+                trusted_publishers: {},
+            },
+            client_cert: None,
+            client_key: None,
+            limit_rate: None,
+            max_connections_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -2171,6 +2463,7 @@ fn resolve_top_level() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        scratch_dir: None,
     }
     PipCompileSettings {
         format: None,
@@ -2188,6 +2481,8 @@ fn resolve_top_level() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        minimal_update: false,
+        emit_graph: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -2236,6 +2531,12 @@ fn resolve_top_level() -> anyhow::Result<()> {
             keyring_provider: Disabled,
             torch_backend: None,
             build_isolation: Isolate,
+            excluded_packages: [],
+            resolution_priority: [],
+            max_backtracks: None,
+            memory_limit: None,
+            protected_packages: [],
+            allow_yanked: [],
             extra_build_dependencies: ExtraBuildDependencies(
                 {},
             ),
@@ -2251,6 +2552,7 @@ fn resolve_top_level() -> anyhow::Result<()> {
             dependency_mode: Transitive,
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
+            prerelease_packages: [],
             fork_strategy: RequiresPython,
             dependency_metadata: DependencyMetadata(
                 {},
@@ -2293,6 +2595,7 @@ fn resolve_top_level() -> anyhow::Result<()> {
             upgrade: Upgrade {
                 strategy: None,
                 constraints: {},
+                eager: false,
             },
             reinstall: None,
         },
@@ -2338,11 +2641,27 @@ fn resolve_top_level() -> anyhow::Result<()> {
             native_tls: false,
             http_proxy: None,
             https_proxy: None,
+            all_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
             read_timeout: [TIME],
             connect_timeout: [TIME],
+            stall_timeout: [TIME],
             retries: 3,
+            retry_max_delay: [TIME],
+            allow_index_failure: false,
+            index_cache_ttl: None,
+            index_stale_if_error: false,
+            stale_metadata_report: None,
+            credential_helper: None,
+            attestation_policy: AttestationPolicy {
+                required: false,
+                trusted_publishers: {},
+            },
+            client_cert: None,
+            client_key: None,
+            limit_rate: None,
+            max_connections_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -2363,6 +2682,7 @@ fn resolve_top_level() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        scratch_dir: None,
     }
     PipCompileSettings {
         format: None,
@@ -2380,6 +2700,8 @@ fn resolve_top_level() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        minimal_update: false,
+        emit_graph: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -2421,8 +2743,11 @@ fn resolve_top_level() -> anyhow::Result<()> {
                         format: Simple,
                         publish_url: None,
                         authenticate: Auto,
+                        credentials: None,
+                        ca_bundle: None,
                         ignore_error_codes: None,
                         cache_control: None,
+                        mirrors: [],
                     },
                     Index {
                         name: None,
@@ -2454,8 +2779,11 @@ fn resolve_top_level() -> anyhow::Result<()> {
                         format: Simple,
                         publish_url: None,
                         authenticate: Auto,
+                        credentials: None,
+                        ca_bundle: None,
                         ignore_error_codes: None,
                         cache_control: None,
+                        mirrors: [],
                     },
                 ],
                 flat_index: [],
@@ -2495,6 +2823,12 @@ fn resolve_top_level() -> anyhow::Result<()> {
             keyring_provider: Disabled,
             torch_backend: None,
             build_isolation: Isolate,
+            excluded_packages: [],
+            resolution_priority: [],
+            max_backtracks: None,
+            memory_limit: None,
+            protected_packages: [],
+            allow_yanked: [],
             extra_build_dependencies: ExtraBuildDependencies(
                 {},
             ),
@@ -2510,6 +2844,7 @@ fn resolve_top_level() -> anyhow::Result<()> {
             dependency_mode: Transitive,
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
+            prerelease_packages: [],
             fork_strategy: RequiresPython,
             dependency_metadata: DependencyMetadata(
                 {},
@@ -2552,6 +2887,7 @@ fn resolve_top_level() -> anyhow::Result<()> {
             upgrade: Upgrade {
                 strategy: None,
                 constraints: {},
+                eager: false,
             },
             reinstall: None,
         },
@@ -2580,11 +2916,27 @@ fn resolve_top_level() -> anyhow::Result<()> {
             native_tls: false,
             http_proxy: None,
             https_proxy: None,
+            all_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
             read_timeout: [TIME],
             connect_timeout: [TIME],
+            stall_timeout: [TIME],
             retries: 3,
+            retry_max_delay: [TIME],
+            allow_index_failure: false,
+            index_cache_ttl: None,
+            index_stale_if_error: false,
+            stale_metadata_report: None,
+            credential_helper: None,
+            attestation_policy: AttestationPolicy {
+                required: false,
+                trusted_publishers: {},
+            },
+            client_cert: None,
+            client_key: None,
+            limit_rate: None,
+            max_connections_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -2605,6 +2957,7 @@ fn resolve_top_level() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        scratch_dir: None,
     }
     PipCompileSettings {
         format: None,
@@ -2622,6 +2975,8 @@ fn resolve_top_level() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        minimal_update: false,
+        emit_graph: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -2663,8 +3018,11 @@ fn resolve_top_level() -> anyhow::Result<()> {
                         format: Simple,
                         publish_url: None,
                         authenticate: Auto,
+                        credentials: None,
+                        ca_bundle: None,
                         ignore_error_codes: None,
                         cache_control: None,
+                        mirrors: [],
                     },
                     Index {
                         name: None,
@@ -2696,8 +3054,11 @@ fn resolve_top_level() -> anyhow::Result<()> {
                         format: Simple,
                         publish_url: None,
                         authenticate: Auto,
+                        credentials: None,
+                        ca_bundle: None,
                         ignore_error_codes: None,
                         cache_control: None,
+                        mirrors: [],
                     },
                 ],
                 flat_index: [],
@@ -2737,6 +3098,12 @@ fn resolve_top_level() -> anyhow::Result<()> {
             keyring_provider: Disabled,
             torch_backend: None,
             build_isolation: Isolate,
+            excluded_packages: [],
+            resolution_priority: [],
+            max_backtracks: None,
+            memory_limit: None,
+            protected_packages: [],
+            allow_yanked: [],
             extra_build_dependencies: ExtraBuildDependencies(
                 {},
             ),
@@ -2752,6 +3119,7 @@ fn resolve_top_level() -> anyhow::Result<()> {
             dependency_mode: Transitive,
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
+            prerelease_packages: [],
             fork_strategy: RequiresPython,
             dependency_metadata: DependencyMetadata(
                 {},
@@ -2794,6 +3162,7 @@ fn resolve_top_level() -> anyhow::Result<()> {
             upgrade: Upgrade {
                 strategy: None,
                 constraints: {},
+                eager: false,
             },
             reinstall: None,
         },
@@ -2845,11 +3214,27 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             native_tls: false,
             http_proxy: None,
             https_proxy: None,
+            all_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
             read_timeout: [TIME],
             connect_timeout: [TIME],
+            stall_timeout: [TIME],
             retries: 3,
+            retry_max_delay: [TIME],
+            allow_index_failure: false,
+            index_cache_ttl: None,
+            index_stale_if_error: false,
+            stale_metadata_report: None,
+            credential_helper: None,
+            attestation_policy: AttestationPolicy {
+                required: false,
+                trusted_publishers: {},
+            },
+            client_cert: None,
+            client_key: None,
+            limit_rate: None,
+            max_connections_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -2870,6 +3255,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        scratch_dir: None,
     }
     PipCompileSettings {
         format: None,
@@ -2887,6 +3273,8 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        minimal_update: false,
+        emit_graph: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -2935,6 +3323,12 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             keyring_provider: Disabled,
             torch_backend: None,
             build_isolation: Isolate,
+            excluded_packages: [],
+            resolution_priority: [],
+            max_backtracks: None,
+            memory_limit: None,
+            protected_packages: [],
+            allow_yanked: [],
             extra_build_dependencies: ExtraBuildDependencies(
                 {},
             ),
@@ -2950,6 +3344,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             dependency_mode: Transitive,
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
+            prerelease_packages: [],
             fork_strategy: RequiresPython,
             dependency_metadata: DependencyMetadata(
                 {},
@@ -2992,6 +3387,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             upgrade: Upgrade {
                 strategy: None,
                 constraints: {},
+                eager: false,
             },
             reinstall: None,
         },
@@ -3027,11 +3423,27 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             native_tls: false,
             http_proxy: None,
             https_proxy: None,
+            all_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
             read_timeout: [TIME],
             connect_timeout: [TIME],
+            stall_timeout: [TIME],
             retries: 3,
+            retry_max_delay: [TIME],
+            allow_index_failure: false,
+            index_cache_ttl: None,
+            index_stale_if_error: false,
+            stale_metadata_report: None,
+            credential_helper: None,
+            attestation_policy: AttestationPolicy {
+                required: false,
+                trusted_publishers: {},
+            },
+            client_cert: None,
+            client_key: None,
+            limit_rate: None,
+            max_connections_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -3052,6 +3464,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        scratch_dir: None,
     }
     PipCompileSettings {
         format: None,
@@ -3069,6 +3482,8 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        minimal_update: false,
+        emit_graph: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -3117,6 +3532,12 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             keyring_provider: Disabled,
             torch_backend: None,
             build_isolation: Isolate,
+            excluded_packages: [],
+            resolution_priority: [],
+            max_backtracks: None,
+            memory_limit: None,
+            protected_packages: [],
+            allow_yanked: [],
             extra_build_dependencies: ExtraBuildDependencies(
                 {},
             ),
@@ -3132,6 +3553,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             dependency_mode: Transitive,
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
+            prerelease_packages: [],
             fork_strategy: RequiresPython,
             dependency_metadata: DependencyMetadata(
                 {},
@@ -3174,6 +3596,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             upgrade: Upgrade {
                 strategy: None,
                 constraints: {},
+                eager: false,
             },
             reinstall: None,
         },
@@ -3209,11 +3632,27 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             native_tls: false,
             http_proxy: None,
             https_proxy: None,
+            all_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
             read_timeout: [TIME],
             connect_timeout: [TIME],
+            stall_timeout: [TIME],
             retries: 3,
+            retry_max_delay: [TIME],
+            allow_index_failure: false,
+            index_cache_ttl: None,
+            index_stale_if_error: false,
+            stale_metadata_report: None,
+            credential_helper: None,
+            attestation_policy: AttestationPolicy {
+                required: false,
+                trusted_publishers: {},
+            },
+            client_cert: None,
+            client_key: None,
+            limit_rate: None,
+            max_connections_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -3234,6 +3673,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        scratch_dir: None,
     }
     PipCompileSettings {
         format: None,
@@ -3251,6 +3691,8 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        minimal_update: false,
+        emit_graph: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -3299,6 +3741,12 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             keyring_provider: Disabled,
             torch_backend: None,
             build_isolation: Isolate,
+            excluded_packages: [],
+            resolution_priority: [],
+            max_backtracks: None,
+            memory_limit: None,
+            protected_packages: [],
+            allow_yanked: [],
             extra_build_dependencies: ExtraBuildDependencies(
                 {},
             ),
@@ -3314,6 +3762,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             dependency_mode: Transitive,
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
+            prerelease_packages: [],
             fork_strategy: RequiresPython,
             dependency_metadata: DependencyMetadata(
                 {},
@@ -3356,6 +3805,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             upgrade: Upgrade {
                 strategy: None,
                 constraints: {},
+                eager: false,
             },
             reinstall: None,
         },
@@ -3393,11 +3843,27 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             native_tls: false,
             http_proxy: None,
             https_proxy: None,
+            all_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
             read_timeout: [TIME],
             connect_timeout: [TIME],
+            stall_timeout: [TIME],
             retries: 3,
+            retry_max_delay: [TIME],
+            allow_index_failure: false,
+            index_cache_ttl: None,
+            index_stale_if_error: false,
+            stale_metadata_report: None,
+            credential_helper: None,
+            attestation_policy: AttestationPolicy {
+                required: false,
+                trusted_publishers: {},
+            },
+            client_cert: None,
+            client_key: None,
+            limit_rate: None,
+            max_connections_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -3418,6 +3884,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        scratch_dir: None,
     }
     PipCompileSettings {
         format: None,
@@ -3435,6 +3902,8 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        minimal_update: false,
+        emit_graph: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -3483,6 +3952,12 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             keyring_provider: Disabled,
             torch_backend: None,
             build_isolation: Isolate,
+            excluded_packages: [],
+            resolution_priority: [],
+            max_backtracks: None,
+            memory_limit: None,
+            protected_packages: [],
+            allow_yanked: [],
             extra_build_dependencies: ExtraBuildDependencies(
                 {},
             ),
@@ -3498,6 +3973,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             dependency_mode: Transitive,
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
+            prerelease_packages: [],
             fork_strategy: RequiresPython,
             dependency_metadata: DependencyMetadata(
                 {},
@@ -3540,6 +4016,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             upgrade: Upgrade {
                 strategy: None,
                 constraints: {},
+                eager: false,
             },
             reinstall: None,
         },
@@ -3596,11 +4073,27 @@ fn resolve_tool() -> anyhow::Result<()> {
             native_tls: false,
             http_proxy: None,
             https_proxy: None,
+            all_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
             read_timeout: [TIME],
             connect_timeout: [TIME],
+            stall_timeout: [TIME],
             retries: 3,
+            retry_max_delay: [TIME],
+            allow_index_failure: false,
+            index_cache_ttl: None,
+            index_stale_if_error: false,
+            stale_metadata_report: None,
+            credential_helper: None,
+            attestation_policy: AttestationPolicy {
+                required: false,
+                trusted_publishers: {},
+            },
+            client_cert: None,
+            client_key: None,
+            limit_rate: None,
+            max_connections_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -3621,6 +4114,7 @@ fn resolve_tool() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        scratch_dir: None,
     }
     ToolInstallSettings {
         package: "requirements.in",
@@ -3656,6 +4150,7 @@ fn resolve_tool() -> anyhow::Result<()> {
                 LowestDirect,
             ),
             prerelease: None,
+            prerelease_packages: None,
             fork_strategy: None,
             dependency_metadata: None,
             config_settings: None,
@@ -3665,6 +4160,7 @@ fn resolve_tool() -> anyhow::Result<()> {
             extra_build_variables: None,
             exclude_newer: None,
             exclude_newer_package: None,
+            allow_yanked: None,
             link_mode: Some(
                 Clone,
             ),
@@ -3672,6 +4168,10 @@ fn resolve_tool() -> anyhow::Result<()> {
             compile_bytecode: None,
             no_sources: None,
             no_sources_package: None,
+            excluded_packages: None,
+            resolution_priority: None,
+            max_backtracks: None,
+            protected_packages: None,
             upgrade: None,
             reinstall: None,
             no_build: None,
@@ -3710,6 +4210,11 @@ fn resolve_tool() -> anyhow::Result<()> {
                 keyring_provider: Disabled,
                 link_mode: Clone,
                 build_isolation: Isolate,
+                excluded_packages: [],
+                resolution_priority: [],
+                max_backtracks: None,
+                memory_limit: None,
+                allow_yanked: [],
                 extra_build_dependencies: ExtraBuildDependencies(
                     {},
                 ),
@@ -3717,15 +4222,18 @@ fn resolve_tool() -> anyhow::Result<()> {
                     {},
                 ),
                 prerelease: IfNecessaryOrExplicit,
+                prerelease_packages: [],
                 resolution: LowestDirect,
                 sources: None,
                 torch_backend: None,
                 upgrade: Upgrade {
                     strategy: None,
                     constraints: {},
+                    eager: false,
                 },
             },
             compile_bytecode: false,
+            protected_packages: [],
             reinstall: None,
         },
         force: false,
@@ -3794,11 +4302,27 @@ fn resolve_poetry_toml() -> anyhow::Result<()> {
             native_tls: false,
             http_proxy: None,
             https_proxy: None,
+            all_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
             read_timeout: [TIME],
             connect_timeout: [TIME],
+            stall_timeout: [TIME],
             retries: 3,
+            retry_max_delay: [TIME],
+            allow_index_failure: false,
+            index_cache_ttl: None,
+            index_stale_if_error: false,
+            stale_metadata_report: None,
+            credential_helper: None,
+            attestation_policy: AttestationPolicy {
+                required: false,
+                trusted_publishers: {},
+            },
+            client_cert: None,
+            client_key: None,
+            limit_rate: None,
+            max_connections_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -3819,6 +4343,7 @@ fn resolve_poetry_toml() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        scratch_dir: None,
     }
     PipCompileSettings {
         format: None,
@@ -3836,6 +4361,8 @@ fn resolve_poetry_toml() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        minimal_update: false,
+        emit_graph: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -3884,6 +4411,12 @@ fn resolve_poetry_toml() -> anyhow::Result<()> {
             keyring_provider: Disabled,
             torch_backend: None,
             build_isolation: Isolate,
+            excluded_packages: [],
+            resolution_priority: [],
+            max_backtracks: None,
+            memory_limit: None,
+            protected_packages: [],
+            allow_yanked: [],
             extra_build_dependencies: ExtraBuildDependencies(
                 {},
             ),
@@ -3899,6 +4432,7 @@ fn resolve_poetry_toml() -> anyhow::Result<()> {
             dependency_mode: Transitive,
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
+            prerelease_packages: [],
             fork_strategy: RequiresPython,
             dependency_metadata: DependencyMetadata(
                 {},
@@ -3941,6 +4475,7 @@ fn resolve_poetry_toml() -> anyhow::Result<()> {
             upgrade: Upgrade {
                 strategy: None,
                 constraints: {},
+                eager: false,
             },
             reinstall: None,
         },
@@ -4010,11 +4545,27 @@ fn resolve_both() -> anyhow::Result<()> {
             native_tls: false,
             http_proxy: None,
             https_proxy: None,
+            all_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
             read_timeout: [TIME],
             connect_timeout: [TIME],
+            stall_timeout: [TIME],
             retries: 3,
+            retry_max_delay: [TIME],
+            allow_index_failure: false,
+            index_cache_ttl: None,
+            index_stale_if_error: false,
+            stale_metadata_report: None,
+            credential_helper: None,
+            attestation_policy: AttestationPolicy {
+                required: false,
+                trusted_publishers: {},
+            },
+            client_cert: None,
+            client_key: None,
+            limit_rate: None,
+            max_connections_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -4035,6 +4586,7 @@ fn resolve_both() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        scratch_dir: None,
     }
     PipCompileSettings {
         format: None,
@@ -4052,6 +4604,8 @@ fn resolve_both() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        minimal_update: false,
+        emit_graph: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -4095,8 +4649,11 @@ fn resolve_both() -> anyhow::Result<()> {
                         format: Simple,
                         publish_url: None,
                         authenticate: Auto,
+                        credentials: None,
+                        ca_bundle: None,
                         ignore_error_codes: None,
                         cache_control: None,
+                        mirrors: [],
                     },
                 ],
                 flat_index: [],
@@ -4136,6 +4693,12 @@ fn resolve_both() -> anyhow::Result<()> {
             keyring_provider: Disabled,
             torch_backend: None,
             build_isolation: Isolate,
+            excluded_packages: [],
+            resolution_priority: [],
+            max_backtracks: None,
+            memory_limit: None,
+            protected_packages: [],
+            allow_yanked: [],
             extra_build_dependencies: ExtraBuildDependencies(
                 {},
             ),
@@ -4151,6 +4714,7 @@ fn resolve_both() -> anyhow::Result<()> {
             dependency_mode: Transitive,
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
+            prerelease_packages: [],
             fork_strategy: RequiresPython,
             dependency_metadata: DependencyMetadata(
                 {},
@@ -4193,6 +4757,7 @@ fn resolve_both() -> anyhow::Result<()> {
             upgrade: Upgrade {
                 strategy: None,
                 constraints: {},
+                eager: false,
             },
             reinstall: None,
         },
@@ -4267,11 +4832,27 @@ fn resolve_both_special_fields() -> anyhow::Result<()> {
             native_tls: false,
             http_proxy: None,
             https_proxy: None,
+            all_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
             read_timeout: [TIME],
             connect_timeout: [TIME],
+            stall_timeout: [TIME],
             retries: 3,
+            retry_max_delay: [TIME],
+            allow_index_failure: false,
+            index_cache_ttl: None,
+            index_stale_if_error: false,
+            stale_metadata_report: None,
+            credential_helper: None,
+            attestation_policy: AttestationPolicy {
+                required: false,
+                trusted_publishers: {},
+            },
+            client_cert: None,
+            client_key: None,
+            limit_rate: None,
+            max_connections_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -4292,6 +4873,7 @@ fn resolve_both_special_fields() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        scratch_dir: None,
     }
     PipCompileSettings {
         format: None,
@@ -4309,6 +4891,8 @@ fn resolve_both_special_fields() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        minimal_update: false,
+        emit_graph: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -4352,8 +4936,11 @@ fn resolve_both_special_fields() -> anyhow::Result<()> {
                         format: Simple,
                         publish_url: None,
                         authenticate: Auto,
+                        credentials: None,
+                        ca_bundle: None,
                         ignore_error_codes: None,
                         cache_control: None,
+                        mirrors: [],
                     },
                 ],
                 flat_index: [],
@@ -4393,6 +4980,12 @@ fn resolve_both_special_fields() -> anyhow::Result<()> {
             keyring_provider: Disabled,
             torch_backend: None,
             build_isolation: Isolate,
+            excluded_packages: [],
+            resolution_priority: [],
+            max_backtracks: None,
+            memory_limit: None,
+            protected_packages: [],
+            allow_yanked: [],
             extra_build_dependencies: ExtraBuildDependencies(
                 {},
             ),
@@ -4408,6 +5001,7 @@ fn resolve_both_special_fields() -> anyhow::Result<()> {
             dependency_mode: Transitive,
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
+            prerelease_packages: [],
             fork_strategy: RequiresPython,
             dependency_metadata: DependencyMetadata(
                 {},
@@ -4450,6 +5044,7 @@ fn resolve_both_special_fields() -> anyhow::Result<()> {
             upgrade: Upgrade {
                 strategy: None,
                 constraints: {},
+                eager: false,
             },
             reinstall: None,
         },
@@ -4603,11 +5198,27 @@ fn resolve_config_file() -> anyhow::Result<()> {
             native_tls: false,
             http_proxy: None,
             https_proxy: None,
+            all_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
             read_timeout: [TIME],
             connect_timeout: [TIME],
+            stall_timeout: [TIME],
             retries: 3,
+            retry_max_delay: [TIME],
+            allow_index_failure: false,
+            index_cache_ttl: None,
+            index_stale_if_error: false,
+            stale_metadata_report: None,
+            credential_helper: None,
+            attestation_policy: AttestationPolicy {
+                required: false,
+                trusted_publishers: {},
+            },
+            client_cert: None,
+            client_key: None,
+            limit_rate: None,
+            max_connections_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -4628,6 +5239,7 @@ fn resolve_config_file() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        scratch_dir: None,
     }
     PipCompileSettings {
         format: None,
@@ -4645,6 +5257,8 @@ fn resolve_config_file() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        minimal_update: false,
+        emit_graph: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -4686,8 +5300,11 @@ fn resolve_config_file() -> anyhow::Result<()> {
                         format: Simple,
                         publish_url: None,
                         authenticate: Auto,
+                        credentials: None,
+                        ca_bundle: None,
                         ignore_error_codes: None,
                         cache_control: None,
+                        mirrors: [],
                     },
                 ],
                 flat_index: [],
@@ -4727,6 +5344,12 @@ fn resolve_config_file() -> anyhow::Result<()> {
             keyring_provider: Disabled,
             torch_backend: None,
             build_isolation: Isolate,
+            excluded_packages: [],
+            resolution_priority: [],
+            max_backtracks: None,
+            memory_limit: None,
+            protected_packages: [],
+            allow_yanked: [],
             extra_build_dependencies: ExtraBuildDependencies(
                 {},
             ),
@@ -4742,6 +5365,7 @@ fn resolve_config_file() -> anyhow::Result<()> {
             dependency_mode: Transitive,
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
+            prerelease_packages: [],
             fork_strategy: RequiresPython,
             dependency_metadata: DependencyMetadata(
                 {},
@@ -4784,6 +5408,7 @@ fn resolve_config_file() -> anyhow::Result<()> {
             upgrade: Upgrade {
                 strategy: None,
                 constraints: {},
+                eager: false,
             },
             reinstall: None,
         },
@@ -4821,7 +5446,7 @@ fn resolve_config_file() -> anyhow::Result<()> {
       |
     1 | [project]
       |  ^^^^^^^
-    unknown field `project`, expected one of `required-version`, `native-tls`, `offline`, `no-cache`, `cache-dir`, `preview`, `python-preference`, `python-downloads`, `concurrent-downloads`, `concurrent-builds`, `concurrent-installs`, `index`, `index-url`, `extra-index-url`, `no-index`, `find-links`, `index-strategy`, `keyring-provider`, `http-proxy`, `https-proxy`, `no-proxy`, `allow-insecure-host`, `resolution`, `prerelease`, `fork-strategy`, `dependency-metadata`, `config-settings`, `config-settings-package`, `no-build-isolation`, `no-build-isolation-package`, `extra-build-dependencies`, `extra-build-variables`, `exclude-newer`, `exclude-newer-package`, `link-mode`, `compile-bytecode`, `no-sources`, `no-sources-package`, `upgrade`, `upgrade-package`, `reinstall`, `reinstall-package`, `no-build`, `no-build-package`, `no-binary`, `no-binary-package`, `torch-backend`, `python-install-mirror`, `pypy-install-mirror`, `python-downloads-json-url`, `publish-url`, `trusted-publishing`, `check-url`, `add-bounds`, `pip`, `cache-keys`, `override-dependencies`, `exclude-dependencies`, `constraint-dependencies`, `build-constraint-dependencies`, `environments`, `required-environments`, `conflicts`, `workspace`, `sources`, `managed`, `package`, `default-groups`, `dependency-groups`, `dev-dependencies`, `build-backend`
+    unknown field `project`, expected one of `required-version`, `native-tls`, `offline`, `no-cache`, `cache-dir`, `scratch-dir`, `preview`, `python-preference`, `python-downloads`, `concurrent-downloads`, `concurrent-builds`, `concurrent-installs`, `index`, `index-url`, `extra-index-url`, `no-index`, `find-links`, `index-strategy`, `keyring-provider`, `http-proxy`, `https-proxy`, `all-proxy`, `no-proxy`, `credential-helper`, `require-attestations`, `trusted-publishers`, `allow-insecure-host`, `resolution`, `prerelease`, `prerelease-packages`, `fork-strategy`, `dependency-metadata`, `config-settings`, `config-settings-package`, `no-build-isolation`, `no-build-isolation-package`, `extra-build-dependencies`, `extra-build-variables`, `exclude-newer`, `exclude-newer-package`, `allow-yanked`, `link-mode`, `compile-bytecode`, `no-sources`, `no-sources-package`, `excluded`, `excluded-packages`, `resolution-priority`, `max-backtracks`, `protected-packages`, `upgrade`, `upgrade-package`, `reinstall`, `reinstall-package`, `no-build`, `no-build-package`, `no-binary`, `no-binary-package`, `torch-backend`, `python-install-mirror`, `pypy-install-mirror`, `python-downloads-json-url`, `publish-url`, `trusted-publishing`, `check-url`, `add-bounds`, `pip`, `cache-keys`, `override-dependencies`, `exclude-dependencies`, `constraint-dependencies`, `build-constraint-dependencies`, `environments`, `required-environments`, `conflicts`, `workspace`, `sources`, `managed`, `package`, `default-groups`, `dependency-groups`, `dev-dependencies`, `build-backend`
     "
     );
 
@@ -4863,6 +5488,330 @@ fn resolve_config_file() -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Remote configuration files must be fetched over HTTPS, and are only read from the cache when
+/// offline.
+#[test]
+#[cfg_attr(
+    windows,
+    ignore = "Configuration tests are not yet supported on Windows"
+)]
+fn resolve_remote_config_file() -> anyhow::Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("anyio>3.0.0")?;
+
+    uv_snapshot!(context.filters(), add_shared_args(context.pip_compile())
+        .arg("--show-settings")
+        .arg("--config-file")
+        .arg("http://example.com/uv.toml")
+        .arg("requirements.in"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Remote configuration files must be served over HTTPS: `http://example.com/uv.toml`
+    "
+    );
+
+    uv_snapshot!(context.filters(), add_shared_args(context.pip_compile())
+        .arg("--show-settings")
+        .arg("--config-file")
+        .arg("https://example.com/uv.toml")
+        .arg("--offline")
+        .arg("requirements.in"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Configuration file `https://example.com/uv.toml` is not available in the cache, and network access is disabled (i.e., `--offline`)
+    "
+    );
+
+    Ok(())
+}
+
+/// The resolver memory limit is read from `UV_RESOLVER_MEMORY_LIMIT`, in megabytes.
+#[test]
+#[cfg_attr(
+    windows,
+    ignore = "Configuration tests are not yet supported on Windows"
+)]
+fn resolve_memory_limit() -> anyhow::Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    // Write a `uv.toml` file to the directory.
+    let config = context.temp_dir.child("uv.toml");
+    config.write_str(indoc::indoc! {r#"
+        [pip]
+        resolution = "lowest-direct"
+        generate-hashes = true
+        index-url = "https://pypi.org/simple"
+    "#})?;
+
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("anyio>3.0.0")?;
+
+    uv_snapshot!(context.filters(), add_shared_args(context.pip_compile())
+        .arg("--show-settings")
+        .arg("requirements.in")
+        .env(EnvVars::UV_RESOLVER_MEMORY_LIMIT, "64"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    GlobalSettings {
+        required_version: None,
+        quiet: 0,
+        verbose: 0,
+        color: Auto,
+        network_settings: NetworkSettings {
+            connectivity: Online,
+            offline: Disabled,
+            native_tls: false,
+            http_proxy: None,
+            https_proxy: None,
+            all_proxy: None,
+            no_proxy: None,
+            allow_insecure_host: [],
+            read_timeout: [TIME],
+            connect_timeout: [TIME],
+            stall_timeout: [TIME],
+            retries: 3,
+            retry_max_delay: [TIME],
+            allow_index_failure: false,
+            index_cache_ttl: None,
+            index_stale_if_error: false,
+            stale_metadata_report: None,
+            credential_helper: None,
+            attestation_policy: AttestationPolicy {
+                required: false,
+                trusted_publishers: {},
+            },
+            client_cert: None,
+            client_key: None,
+            limit_rate: None,
+            max_connections_per_host: None,
+        },
+        concurrency: Concurrency {
+            downloads: 50,
+            builds: 16,
+            installs: 8,
+        },
+        show_settings: true,
+        preview: Preview {
+            flags: [],
+        },
+        python_preference: Managed,
+        python_downloads: Automatic,
+        no_progress: false,
+        installer_metadata: true,
+    }
+    CacheSettings {
+        no_cache: false,
+        cache_dir: Some(
+            "[CACHE_DIR]/",
+        ),
+        scratch_dir: None,
+    }
+    PipCompileSettings {
+        format: None,
+        src_file: [
+            "requirements.in",
+        ],
+        constraints: [],
+        overrides: [],
+        excludes: [],
+        build_constraints: [],
+        constraints_from_workspace: [],
+        overrides_from_workspace: [],
+        excludes_from_workspace: [],
+        build_constraints_from_workspace: [],
+        environments: SupportedEnvironments(
+            [],
+        ),
+        minimal_update: false,
+        emit_graph: None,
+        refresh: None(
+            Timestamp(
+                SystemTime {
+                    tv_sec: [TIME],
+                    tv_nsec: [TIME],
+                },
+            ),
+        ),
+        settings: PipSettings {
+            index_locations: IndexLocations {
+                indexes: [
+                    Index {
+                        name: None,
+                        url: Pypi(
+                            VerbatimUrl {
+                                url: DisplaySafeUrl {
+                                    scheme: "https",
+                                    cannot_be_a_base: false,
+                                    username: "",
+                                    password: None,
+                                    host: Some(
+                                        Domain(
+                                            "pypi.org",
+                                        ),
+                                    ),
+                                    port: None,
+                                    path: "/simple",
+                                    query: None,
+                                    fragment: None,
+                                },
+                                given: Some(
+                                    "https://pypi.org/simple",
+                                ),
+                            },
+                        ),
+                        explicit: false,
+                        default: true,
+                        origin: Some(
+                            Project,
+                        ),
+                        format: Simple,
+                        publish_url: None,
+                        authenticate: Auto,
+                        credentials: None,
+                        ca_bundle: None,
+                        ignore_error_codes: None,
+                        cache_control: None,
+                        mirrors: [],
+                    },
+                ],
+                flat_index: [],
+                no_index: false,
+            },
+            python: None,
+            install_mirrors: PythonInstallMirrors {
+                python_install_mirror: None,
+                pypy_install_mirror: None,
+                python_downloads_json_url: None,
+            },
+            system: false,
+            extras: ExtrasSpecification(
+                ExtrasSpecificationInner {
+                    include: Some(
+                        [],
+                    ),
+                    exclude: [],
+                    only_extras: false,
+                    history: ExtrasSpecificationHistory {
+                        extra: [],
+                        only_extra: [],
+                        no_extra: [],
+                        all_extras: false,
+                        no_default_extras: false,
+                        defaults: List(
+                            [],
+                        ),
+                    },
+                },
+            ),
+            groups: [],
+            break_system_packages: false,
+            target: None,
+            prefix: None,
+            index_strategy: FirstIndex,
+            keyring_provider: Disabled,
+            torch_backend: None,
+            build_isolation: Isolate,
+            excluded_packages: [],
+            resolution_priority: [],
+            max_backtracks: None,
+            memory_limit: Some(
+                67108864,
+            ),
+            protected_packages: [],
+            allow_yanked: [],
+            extra_build_dependencies: ExtraBuildDependencies(
+                {},
+            ),
+            extra_build_variables: ExtraBuildVariables(
+                {},
+            ),
+            build_options: BuildOptions {
+                no_binary: None,
+                no_build: None,
+            },
+            allow_empty_requirements: false,
+            strict: false,
+            dependency_mode: Transitive,
+            resolution: LowestDirect,
+            prerelease: IfNecessaryOrExplicit,
+            prerelease_packages: [],
+            fork_strategy: RequiresPython,
+            dependency_metadata: DependencyMetadata(
+                {},
+            ),
+            output_file: None,
+            no_strip_extras: false,
+            no_strip_markers: false,
+            no_annotate: false,
+            no_header: false,
+            custom_compile_command: None,
+            generate_hashes: true,
+            config_setting: ConfigSettings(
+                {},
+            ),
+            config_settings_package: PackageConfigSettings(
+                {},
+            ),
+            python_version: None,
+            python_platform: None,
+            universal: false,
+            exclude_newer: ExcludeNewer {
+                global: None,
+                package: ExcludeNewerPackage(
+                    {},
+                ),
+            },
+            no_emit_package: [],
+            emit_index_url: false,
+            emit_find_links: false,
+            emit_build_options: false,
+            emit_marker_expression: false,
+            emit_index_annotation: false,
+            annotation_style: Split,
+            link_mode: Clone,
+            compile_bytecode: false,
+            sources: None,
+            hash_checking: Some(
+                Verify,
+            ),
+            upgrade: Upgrade {
+                strategy: None,
+                constraints: {},
+                eager: false,
+            },
+            reinstall: None,
+        },
+    }
+
+    ----- stderr -----
+    "#
+    );
+
+    uv_snapshot!(context.filters(), add_shared_args(context.pip_compile())
+        .arg("--show-settings")
+        .arg("requirements.in")
+        .env(EnvVars::UV_RESOLVER_MEMORY_LIMIT, "64MB"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Failed to parse environment variable `UV_RESOLVER_MEMORY_LIMIT` with invalid value `64MB`: invalid digit found in string; value should be an integer number of megabytes
+    "
+    );
+
+    Ok(())
+}
+
 /// Ignore empty `pyproject.toml` files when discovering configuration.
 #[test]
 #[cfg_attr(
@@ -4912,11 +5861,27 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
             native_tls: false,
             http_proxy: None,
             https_proxy: None,
+            all_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
             read_timeout: [TIME],
             connect_timeout: [TIME],
+            stall_timeout: [TIME],
             retries: 3,
+            retry_max_delay: [TIME],
+            allow_index_failure: false,
+            index_cache_ttl: None,
+            index_stale_if_error: false,
+            stale_metadata_report: None,
+            credential_helper: None,
+            attestation_policy: AttestationPolicy {
+                required: false,
+                trusted_publishers: {},
+            },
+            client_cert: None,
+            client_key: None,
+            limit_rate: None,
+            max_connections_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -4937,6 +5902,7 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        scratch_dir: None,
     }
     PipCompileSettings {
         format: None,
@@ -4954,6 +5920,8 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        minimal_update: false,
+        emit_graph: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -5002,6 +5970,12 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
             keyring_provider: Disabled,
             torch_backend: None,
             build_isolation: Isolate,
+            excluded_packages: [],
+            resolution_priority: [],
+            max_backtracks: None,
+            memory_limit: None,
+            protected_packages: [],
+            allow_yanked: [],
             extra_build_dependencies: ExtraBuildDependencies(
                 {},
             ),
@@ -5017,6 +5991,7 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
             dependency_mode: Transitive,
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
+            prerelease_packages: [],
             fork_strategy: RequiresPython,
             dependency_metadata: DependencyMetadata(
                 {},
@@ -5059,6 +6034,7 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
             upgrade: Upgrade {
                 strategy: None,
                 constraints: {},
+                eager: false,
             },
             reinstall: None,
         },
@@ -5097,11 +6073,27 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
             native_tls: false,
             http_proxy: None,
             https_proxy: None,
+            all_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
             read_timeout: [TIME],
             connect_timeout: [TIME],
+            stall_timeout: [TIME],
             retries: 3,
+            retry_max_delay: [TIME],
+            allow_index_failure: false,
+            index_cache_ttl: None,
+            index_stale_if_error: false,
+            stale_metadata_report: None,
+            credential_helper: None,
+            attestation_policy: AttestationPolicy {
+                required: false,
+                trusted_publishers: {},
+            },
+            client_cert: None,
+            client_key: None,
+            limit_rate: None,
+            max_connections_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -5122,6 +6114,7 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        scratch_dir: None,
     }
     PipCompileSettings {
         format: None,
@@ -5139,6 +6132,8 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        minimal_update: false,
+        emit_graph: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -5187,6 +6182,12 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
             keyring_provider: Disabled,
             torch_backend: None,
             build_isolation: Isolate,
+            excluded_packages: [],
+            resolution_priority: [],
+            max_backtracks: None,
+            memory_limit: None,
+            protected_packages: [],
+            allow_yanked: [],
             extra_build_dependencies: ExtraBuildDependencies(
                 {},
             ),
@@ -5202,6 +6203,7 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
             dependency_mode: Transitive,
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
+            prerelease_packages: [],
             fork_strategy: RequiresPython,
             dependency_metadata: DependencyMetadata(
                 {},
@@ -5244,6 +6246,7 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
             upgrade: Upgrade {
                 strategy: None,
                 constraints: {},
+                eager: false,
             },
             reinstall: None,
         },
@@ -5290,6 +6293,7 @@ fn allow_insecure_host() -> anyhow::Result<()> {
             native_tls: false,
             http_proxy: None,
             https_proxy: None,
+            all_proxy: None,
             no_proxy: None,
             allow_insecure_host: [
                 Host {
@@ -5305,7 +6309,22 @@ fn allow_insecure_host() -> anyhow::Result<()> {
             ],
             read_timeout: [TIME],
             connect_timeout: [TIME],
+            stall_timeout: [TIME],
             retries: 3,
+            retry_max_delay: [TIME],
+            allow_index_failure: false,
+            index_cache_ttl: None,
+            index_stale_if_error: false,
+            stale_metadata_report: None,
+            credential_helper: None,
+            attestation_policy: AttestationPolicy {
+                required: false,
+                trusted_publishers: {},
+            },
+            client_cert: None,
+            client_key: None,
+            limit_rate: None,
+            max_connections_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -5326,6 +6345,7 @@ fn allow_insecure_host() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        scratch_dir: None,
     }
     PipCompileSettings {
         format: None,
@@ -5343,6 +6363,8 @@ fn allow_insecure_host() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        minimal_update: false,
+        emit_graph: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -5391,6 +6413,12 @@ fn allow_insecure_host() -> anyhow::Result<()> {
             keyring_provider: Disabled,
             torch_backend: None,
             build_isolation: Isolate,
+            excluded_packages: [],
+            resolution_priority: [],
+            max_backtracks: None,
+            memory_limit: None,
+            protected_packages: [],
+            allow_yanked: [],
             extra_build_dependencies: ExtraBuildDependencies(
                 {},
             ),
@@ -5406,6 +6434,7 @@ fn allow_insecure_host() -> anyhow::Result<()> {
             dependency_mode: Transitive,
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
+            prerelease_packages: [],
             fork_strategy: RequiresPython,
             dependency_metadata: DependencyMetadata(
                 {},
@@ -5448,6 +6477,7 @@ fn allow_insecure_host() -> anyhow::Result<()> {
             upgrade: Upgrade {
                 strategy: None,
                 constraints: {},
+                eager: false,
             },
             reinstall: None,
         },
@@ -5497,11 +6527,27 @@ fn index_priority() -> anyhow::Result<()> {
             native_tls: false,
             http_proxy: None,
             https_proxy: None,
+            all_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
             read_timeout: [TIME],
             connect_timeout: [TIME],
+            stall_timeout: [TIME],
             retries: 3,
+            retry_max_delay: [TIME],
+            allow_index_failure: false,
+            index_cache_ttl: None,
+            index_stale_if_error: false,
+            stale_metadata_report: None,
+            credential_helper: None,
+            attestation_policy: AttestationPolicy {
+                required: false,
+                trusted_publishers: {},
+            },
+            client_cert: None,
+            client_key: None,
+            limit_rate: None,
+            max_connections_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -5522,6 +6568,7 @@ fn index_priority() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        scratch_dir: None,
     }
     PipCompileSettings {
         format: None,
@@ -5539,6 +6586,8 @@ fn index_priority() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        minimal_update: false,
+        emit_graph: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -5582,8 +6631,11 @@ fn index_priority() -> anyhow::Result<()> {
                         format: Simple,
                         publish_url: None,
                         authenticate: Auto,
+                        credentials: None,
+                        ca_bundle: None,
                         ignore_error_codes: None,
                         cache_control: None,
+                        mirrors: [],
                     },
                     Index {
                         name: None,
@@ -5617,8 +6669,11 @@ fn index_priority() -> anyhow::Result<()> {
                         format: Simple,
                         publish_url: None,
                         authenticate: Auto,
+                        credentials: None,
+                        ca_bundle: None,
                         ignore_error_codes: None,
                         cache_control: None,
+                        mirrors: [],
                     },
                 ],
                 flat_index: [],
@@ -5658,6 +6713,12 @@ fn index_priority() -> anyhow::Result<()> {
             keyring_provider: Disabled,
             torch_backend: None,
             build_isolation: Isolate,
+            excluded_packages: [],
+            resolution_priority: [],
+            max_backtracks: None,
+            memory_limit: None,
+            protected_packages: [],
+            allow_yanked: [],
             extra_build_dependencies: ExtraBuildDependencies(
                 {},
             ),
@@ -5673,6 +6734,7 @@ fn index_priority() -> anyhow::Result<()> {
             dependency_mode: Transitive,
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
+            prerelease_packages: [],
             fork_strategy: RequiresPython,
             dependency_metadata: DependencyMetadata(
                 {},
@@ -5715,6 +6777,7 @@ fn index_priority() -> anyhow::Result<()> {
             upgrade: Upgrade {
                 strategy: None,
                 constraints: {},
+                eager: false,
             },
             reinstall: None,
         },
@@ -5743,11 +6806,27 @@ fn index_priority() -> anyhow::Result<()> {
             native_tls: false,
             http_proxy: None,
             https_proxy: None,
+            all_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
             read_timeout: [TIME],
             connect_timeout: [TIME],
+            stall_timeout: [TIME],
             retries: 3,
+            retry_max_delay: [TIME],
+            allow_index_failure: false,
+            index_cache_ttl: None,
+            index_stale_if_error: false,
+            stale_metadata_report: None,
+            credential_helper: None,
+            attestation_policy: AttestationPolicy {
+                required: false,
+                trusted_publishers: {},
+            },
+            client_cert: None,
+            client_key: None,
+            limit_rate: None,
+            max_connections_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -5768,6 +6847,7 @@ fn index_priority() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        scratch_dir: None,
     }
     PipCompileSettings {
         format: None,
@@ -5785,6 +6865,8 @@ fn index_priority() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        minimal_update: false,
+        emit_graph: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -5828,8 +6910,11 @@ fn index_priority() -> anyhow::Result<()> {
                         format: Simple,
                         publish_url: None,
                         authenticate: Auto,
+                        credentials: None,
+                        ca_bundle: None,
                         ignore_error_codes: None,
                         cache_control: None,
+                        mirrors: [],
                     },
                     Index {
                         name: None,
@@ -5863,8 +6948,11 @@ fn index_priority() -> anyhow::Result<()> {
                         format: Simple,
                         publish_url: None,
                         authenticate: Auto,
+                        credentials: None,
+                        ca_bundle: None,
                         ignore_error_codes: None,
                         cache_control: None,
+                        mirrors: [],
                     },
                 ],
                 flat_index: [],
@@ -5904,6 +6992,12 @@ fn index_priority() -> anyhow::Result<()> {
             keyring_provider: Disabled,
             torch_backend: None,
             build_isolation: Isolate,
+            excluded_packages: [],
+            resolution_priority: [],
+            max_backtracks: None,
+            memory_limit: None,
+            protected_packages: [],
+            allow_yanked: [],
             extra_build_dependencies: ExtraBuildDependencies(
                 {},
             ),
@@ -5919,6 +7013,7 @@ fn index_priority() -> anyhow::Result<()> {
             dependency_mode: Transitive,
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
+            prerelease_packages: [],
             fork_strategy: RequiresPython,
             dependency_metadata: DependencyMetadata(
                 {},
@@ -5961,6 +7056,7 @@ fn index_priority() -> anyhow::Result<()> {
             upgrade: Upgrade {
                 strategy: None,
                 constraints: {},
+                eager: false,
             },
             reinstall: None,
         },
@@ -5995,11 +7091,27 @@ fn index_priority() -> anyhow::Result<()> {
             native_tls: false,
             http_proxy: None,
             https_proxy: None,
+            all_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
             read_timeout: [TIME],
             connect_timeout: [TIME],
+            stall_timeout: [TIME],
             retries: 3,
+            retry_max_delay: [TIME],
+            allow_index_failure: false,
+            index_cache_ttl: None,
+            index_stale_if_error: false,
+            stale_metadata_report: None,
+            credential_helper: None,
+            attestation_policy: AttestationPolicy {
+                required: false,
+                trusted_publishers: {},
+            },
+            client_cert: None,
+            client_key: None,
+            limit_rate: None,
+            max_connections_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -6020,6 +7132,7 @@ fn index_priority() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        scratch_dir: None,
     }
     PipCompileSettings {
         format: None,
@@ -6037,6 +7150,8 @@ fn index_priority() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        minimal_update: false,
+        emit_graph: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -6080,8 +7195,11 @@ fn index_priority() -> anyhow::Result<()> {
                         format: Simple,
                         publish_url: None,
                         authenticate: Auto,
+                        credentials: None,
+                        ca_bundle: None,
                         ignore_error_codes: None,
                         cache_control: None,
+                        mirrors: [],
                     },
                     Index {
                         name: None,
@@ -6115,8 +7233,11 @@ fn index_priority() -> anyhow::Result<()> {
                         format: Simple,
                         publish_url: None,
                         authenticate: Auto,
+                        credentials: None,
+                        ca_bundle: None,
                         ignore_error_codes: None,
                         cache_control: None,
+                        mirrors: [],
                     },
                 ],
                 flat_index: [],
@@ -6156,6 +7277,12 @@ fn index_priority() -> anyhow::Result<()> {
             keyring_provider: Disabled,
             torch_backend: None,
             build_isolation: Isolate,
+            excluded_packages: [],
+            resolution_priority: [],
+            max_backtracks: None,
+            memory_limit: None,
+            protected_packages: [],
+            allow_yanked: [],
             extra_build_dependencies: ExtraBuildDependencies(
                 {},
             ),
@@ -6171,6 +7298,7 @@ fn index_priority() -> anyhow::Result<()> {
             dependency_mode: Transitive,
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
+            prerelease_packages: [],
             fork_strategy: RequiresPython,
             dependency_metadata: DependencyMetadata(
                 {},
@@ -6213,6 +7341,7 @@ fn index_priority() -> anyhow::Result<()> {
             upgrade: Upgrade {
                 strategy: None,
                 constraints: {},
+                eager: false,
             },
             reinstall: None,
         },
@@ -6242,11 +7371,27 @@ fn index_priority() -> anyhow::Result<()> {
             native_tls: false,
             http_proxy: None,
             https_proxy: None,
+            all_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
             read_timeout: [TIME],
             connect_timeout: [TIME],
+            stall_timeout: [TIME],
             retries: 3,
+            retry_max_delay: [TIME],
+            allow_index_failure: false,
+            index_cache_ttl: None,
+            index_stale_if_error: false,
+            stale_metadata_report: None,
+            credential_helper: None,
+            attestation_policy: AttestationPolicy {
+                required: false,
+                trusted_publishers: {},
+            },
+            client_cert: None,
+            client_key: None,
+            limit_rate: None,
+            max_connections_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -6267,6 +7412,7 @@ fn index_priority() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        scratch_dir: None,
     }
     PipCompileSettings {
         format: None,
@@ -6284,6 +7430,8 @@ fn index_priority() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        minimal_update: false,
+        emit_graph: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -6327,8 +7475,11 @@ fn index_priority() -> anyhow::Result<()> {
                         format: Simple,
                         publish_url: None,
                         authenticate: Auto,
+                        credentials: None,
+                        ca_bundle: None,
                         ignore_error_codes: None,
                         cache_control: None,
+                        mirrors: [],
                     },
                     Index {
                         name: None,
@@ -6362,8 +7513,11 @@ fn index_priority() -> anyhow::Result<()> {
                         format: Simple,
                         publish_url: None,
                         authenticate: Auto,
+                        credentials: None,
+                        ca_bundle: None,
                         ignore_error_codes: None,
                         cache_control: None,
+                        mirrors: [],
                     },
                 ],
                 flat_index: [],
@@ -6403,6 +7557,12 @@ fn index_priority() -> anyhow::Result<()> {
             keyring_provider: Disabled,
             torch_backend: None,
             build_isolation: Isolate,
+            excluded_packages: [],
+            resolution_priority: [],
+            max_backtracks: None,
+            memory_limit: None,
+            protected_packages: [],
+            allow_yanked: [],
             extra_build_dependencies: ExtraBuildDependencies(
                 {},
             ),
@@ -6418,6 +7578,7 @@ fn index_priority() -> anyhow::Result<()> {
             dependency_mode: Transitive,
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
+            prerelease_packages: [],
             fork_strategy: RequiresPython,
             dependency_metadata: DependencyMetadata(
                 {},
@@ -6460,6 +7621,7 @@ fn index_priority() -> anyhow::Result<()> {
             upgrade: Upgrade {
                 strategy: None,
                 constraints: {},
+                eager: false,
             },
             reinstall: None,
         },
@@ -6496,11 +7658,27 @@ fn index_priority() -> anyhow::Result<()> {
             native_tls: false,
             http_proxy: None,
             https_proxy: None,
+            all_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
             read_timeout: [TIME],
             connect_timeout: [TIME],
+            stall_timeout: [TIME],
             retries: 3,
+            retry_max_delay: [TIME],
+            allow_index_failure: false,
+            index_cache_ttl: None,
+            index_stale_if_error: false,
+            stale_metadata_report: None,
+            credential_helper: None,
+            attestation_policy: AttestationPolicy {
+                required: false,
+                trusted_publishers: {},
+            },
+            client_cert: None,
+            client_key: None,
+            limit_rate: None,
+            max_connections_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -6521,6 +7699,7 @@ fn index_priority() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        scratch_dir: None,
     }
     PipCompileSettings {
         format: None,
@@ -6538,6 +7717,8 @@ fn index_priority() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        minimal_update: false,
+        emit_graph: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -6581,8 +7762,11 @@ fn index_priority() -> anyhow::Result<()> {
                         format: Simple,
                         publish_url: None,
                         authenticate: Auto,
+                        credentials: None,
+                        ca_bundle: None,
                         ignore_error_codes: None,
                         cache_control: None,
+                        mirrors: [],
                     },
                     Index {
                         name: None,
@@ -6616,8 +7800,11 @@ fn index_priority() -> anyhow::Result<()> {
                         format: Simple,
                         publish_url: None,
                         authenticate: Auto,
+                        credentials: None,
+                        ca_bundle: None,
                         ignore_error_codes: None,
                         cache_control: None,
+                        mirrors: [],
                     },
                 ],
                 flat_index: [],
@@ -6657,6 +7844,12 @@ fn index_priority() -> anyhow::Result<()> {
             keyring_provider: Disabled,
             torch_backend: None,
             build_isolation: Isolate,
+            excluded_packages: [],
+            resolution_priority: [],
+            max_backtracks: None,
+            memory_limit: None,
+            protected_packages: [],
+            allow_yanked: [],
             extra_build_dependencies: ExtraBuildDependencies(
                 {},
             ),
@@ -6672,6 +7865,7 @@ fn index_priority() -> anyhow::Result<()> {
             dependency_mode: Transitive,
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
+            prerelease_packages: [],
             fork_strategy: RequiresPython,
             dependency_metadata: DependencyMetadata(
                 {},
@@ -6714,6 +7908,7 @@ fn index_priority() -> anyhow::Result<()> {
             upgrade: Upgrade {
                 strategy: None,
                 constraints: {},
+                eager: false,
             },
             reinstall: None,
         },
@@ -6743,11 +7938,27 @@ fn index_priority() -> anyhow::Result<()> {
             native_tls: false,
             http_proxy: None,
             https_proxy: None,
+            all_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
             read_timeout: [TIME],
             connect_timeout: [TIME],
+            stall_timeout: [TIME],
             retries: 3,
+            retry_max_delay: [TIME],
+            allow_index_failure: false,
+            index_cache_ttl: None,
+            index_stale_if_error: false,
+            stale_metadata_report: None,
+            credential_helper: None,
+            attestation_policy: AttestationPolicy {
+                required: false,
+                trusted_publishers: {},
+            },
+            client_cert: None,
+            client_key: None,
+            limit_rate: None,
+            max_connections_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -6768,6 +7979,7 @@ fn index_priority() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        scratch_dir: None,
     }
     PipCompileSettings {
         format: None,
@@ -6785,6 +7997,8 @@ fn index_priority() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        minimal_update: false,
+        emit_graph: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -6828,8 +8042,11 @@ fn index_priority() -> anyhow::Result<()> {
                         format: Simple,
                         publish_url: None,
                         authenticate: Auto,
+                        credentials: None,
+                        ca_bundle: None,
                         ignore_error_codes: None,
                         cache_control: None,
+                        mirrors: [],
                     },
                     Index {
                         name: None,
@@ -6863,8 +8080,11 @@ fn index_priority() -> anyhow::Result<()> {
                         format: Simple,
                         publish_url: None,
                         authenticate: Auto,
+                        credentials: None,
+                        ca_bundle: None,
                         ignore_error_codes: None,
                         cache_control: None,
+                        mirrors: [],
                     },
                 ],
                 flat_index: [],
@@ -6904,6 +8124,12 @@ fn index_priority() -> anyhow::Result<()> {
             keyring_provider: Disabled,
             torch_backend: None,
             build_isolation: Isolate,
+            excluded_packages: [],
+            resolution_priority: [],
+            max_backtracks: None,
+            memory_limit: None,
+            protected_packages: [],
+            allow_yanked: [],
             extra_build_dependencies: ExtraBuildDependencies(
                 {},
             ),
@@ -6919,6 +8145,7 @@ fn index_priority() -> anyhow::Result<()> {
             dependency_mode: Transitive,
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
+            prerelease_packages: [],
             fork_strategy: RequiresPython,
             dependency_metadata: DependencyMetadata(
                 {},
@@ -6961,6 +8188,7 @@ fn index_priority() -> anyhow::Result<()> {
             upgrade: Upgrade {
                 strategy: None,
                 constraints: {},
+                eager: false,
             },
             reinstall: None,
         },
@@ -7003,11 +8231,27 @@ fn verify_hashes() -> anyhow::Result<()> {
             native_tls: false,
             http_proxy: None,
             https_proxy: None,
+            all_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
             read_timeout: [TIME],
             connect_timeout: [TIME],
+            stall_timeout: [TIME],
             retries: 3,
+            retry_max_delay: [TIME],
+            allow_index_failure: false,
+            index_cache_ttl: None,
+            index_stale_if_error: false,
+            stale_metadata_report: None,
+            credential_helper: None,
+            attestation_policy: AttestationPolicy {
+                required: false,
+                trusted_publishers: {},
+            },
+            client_cert: None,
+            client_key: None,
+            limit_rate: None,
+            max_connections_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -7028,6 +8272,7 @@ fn verify_hashes() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        scratch_dir: None,
     }
     PipInstallSettings {
         package: [],
@@ -7035,11 +8280,15 @@ fn verify_hashes() -> anyhow::Result<()> {
             "requirements.in",
         ],
         editables: [],
+        for_import: [],
         constraints: [],
         overrides: [],
         excludes: [],
         build_constraints: [],
         dry_run: Disabled,
+        only_uv_managed: false,
+        verify_record: false,
+        allow_unhashed: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         excludes_from_workspace: [],
@@ -7093,6 +8342,12 @@ fn verify_hashes() -> anyhow::Result<()> {
             keyring_provider: Disabled,
             torch_backend: None,
             build_isolation: Isolate,
+            excluded_packages: [],
+            resolution_priority: [],
+            max_backtracks: None,
+            memory_limit: None,
+            protected_packages: [],
+            allow_yanked: [],
             extra_build_dependencies: ExtraBuildDependencies(
                 {},
             ),
@@ -7108,6 +8363,7 @@ fn verify_hashes() -> anyhow::Result<()> {
             dependency_mode: Transitive,
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
+            prerelease_packages: [],
             fork_strategy: RequiresPython,
             dependency_metadata: DependencyMetadata(
                 {},
@@ -7150,6 +8406,7 @@ fn verify_hashes() -> anyhow::Result<()> {
             upgrade: Upgrade {
                 strategy: None,
                 constraints: {},
+                eager: false,
             },
             reinstall: None,
         },
@@ -7178,11 +8435,27 @@ fn verify_hashes() -> anyhow::Result<()> {
             native_tls: false,
             http_proxy: None,
             https_proxy: None,
+            all_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
             read_timeout: [TIME],
             connect_timeout: [TIME],
+            stall_timeout: [TIME],
             retries: 3,
+            retry_max_delay: [TIME],
+            allow_index_failure: false,
+            index_cache_ttl: None,
+            index_stale_if_error: false,
+            stale_metadata_report: None,
+            credential_helper: None,
+            attestation_policy: AttestationPolicy {
+                required: false,
+                trusted_publishers: {},
+            },
+            client_cert: None,
+            client_key: None,
+            limit_rate: None,
+            max_connections_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -7203,6 +8476,7 @@ fn verify_hashes() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        scratch_dir: None,
     }
     PipInstallSettings {
         package: [],
@@ -7210,11 +8484,15 @@ fn verify_hashes() -> anyhow::Result<()> {
             "requirements.in",
         ],
         editables: [],
+        for_import: [],
         constraints: [],
         overrides: [],
         excludes: [],
         build_constraints: [],
         dry_run: Disabled,
+        only_uv_managed: false,
+        verify_record: false,
+        allow_unhashed: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         excludes_from_workspace: [],
@@ -7268,6 +8546,12 @@ fn verify_hashes() -> anyhow::Result<()> {
             keyring_provider: Disabled,
             torch_backend: None,
             build_isolation: Isolate,
+            excluded_packages: [],
+            resolution_priority: [],
+            max_backtracks: None,
+            memory_limit: None,
+            protected_packages: [],
+            allow_yanked: [],
             extra_build_dependencies: ExtraBuildDependencies(
                 {},
             ),
@@ -7283,6 +8567,7 @@ fn verify_hashes() -> anyhow::Result<()> {
             dependency_mode: Transitive,
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
+            prerelease_packages: [],
             fork_strategy: RequiresPython,
             dependency_metadata: DependencyMetadata(
                 {},
@@ -7323,6 +8608,7 @@ fn verify_hashes() -> anyhow::Result<()> {
             upgrade: Upgrade {
                 strategy: None,
                 constraints: {},
+                eager: false,
             },
             reinstall: None,
         },
@@ -7351,11 +8637,27 @@ fn verify_hashes() -> anyhow::Result<()> {
             native_tls: false,
             http_proxy: None,
             https_proxy: None,
+            all_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
             read_timeout: [TIME],
             connect_timeout: [TIME],
+            stall_timeout: [TIME],
             retries: 3,
+            retry_max_delay: [TIME],
+            allow_index_failure: false,
+            index_cache_ttl: None,
+            index_stale_if_error: false,
+            stale_metadata_report: None,
+            credential_helper: None,
+            attestation_policy: AttestationPolicy {
+                required: false,
+                trusted_publishers: {},
+            },
+            client_cert: None,
+            client_key: None,
+            limit_rate: None,
+            max_connections_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -7376,6 +8678,7 @@ fn verify_hashes() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        scratch_dir: None,
     }
     PipInstallSettings {
         package: [],
@@ -7383,11 +8686,15 @@ fn verify_hashes() -> anyhow::Result<()> {
             "requirements.in",
         ],
         editables: [],
+        for_import: [],
         constraints: [],
         overrides: [],
         excludes: [],
         build_constraints: [],
         dry_run: Disabled,
+        only_uv_managed: false,
+        verify_record: false,
+        allow_unhashed: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         excludes_from_workspace: [],
@@ -7441,6 +8748,12 @@ fn verify_hashes() -> anyhow::Result<()> {
             keyring_provider: Disabled,
             torch_backend: None,
             build_isolation: Isolate,
+            excluded_packages: [],
+            resolution_priority: [],
+            max_backtracks: None,
+            memory_limit: None,
+            protected_packages: [],
+            allow_yanked: [],
             extra_build_dependencies: ExtraBuildDependencies(
                 {},
             ),
@@ -7456,6 +8769,7 @@ fn verify_hashes() -> anyhow::Result<()> {
             dependency_mode: Transitive,
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
+            prerelease_packages: [],
             fork_strategy: RequiresPython,
             dependency_metadata: DependencyMetadata(
                 {},
@@ -7498,6 +8812,7 @@ fn verify_hashes() -> anyhow::Result<()> {
             upgrade: Upgrade {
                 strategy: None,
                 constraints: {},
+                eager: false,
             },
             reinstall: None,
         },
@@ -7526,11 +8841,27 @@ fn verify_hashes() -> anyhow::Result<()> {
             native_tls: false,
             http_proxy: None,
             https_proxy: None,
+            all_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
             read_timeout: [TIME],
             connect_timeout: [TIME],
+            stall_timeout: [TIME],
             retries: 3,
+            retry_max_delay: [TIME],
+            allow_index_failure: false,
+            index_cache_ttl: None,
+            index_stale_if_error: false,
+            stale_metadata_report: None,
+            credential_helper: None,
+            attestation_policy: AttestationPolicy {
+                required: false,
+                trusted_publishers: {},
+            },
+            client_cert: None,
+            client_key: None,
+            limit_rate: None,
+            max_connections_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -7551,6 +8882,7 @@ fn verify_hashes() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        scratch_dir: None,
     }
     PipInstallSettings {
         package: [],
@@ -7558,11 +8890,15 @@ fn verify_hashes() -> anyhow::Result<()> {
             "requirements.in",
         ],
         editables: [],
+        for_import: [],
         constraints: [],
         overrides: [],
         excludes: [],
         build_constraints: [],
         dry_run: Disabled,
+        only_uv_managed: false,
+        verify_record: false,
+        allow_unhashed: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         excludes_from_workspace: [],
@@ -7616,6 +8952,12 @@ fn verify_hashes() -> anyhow::Result<()> {
             keyring_provider: Disabled,
             torch_backend: None,
             build_isolation: Isolate,
+            excluded_packages: [],
+            resolution_priority: [],
+            max_backtracks: None,
+            memory_limit: None,
+            protected_packages: [],
+            allow_yanked: [],
             extra_build_dependencies: ExtraBuildDependencies(
                 {},
             ),
@@ -7631,6 +8973,7 @@ fn verify_hashes() -> anyhow::Result<()> {
             dependency_mode: Transitive,
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
+            prerelease_packages: [],
             fork_strategy: RequiresPython,
             dependency_metadata: DependencyMetadata(
                 {},
@@ -7671,6 +9014,7 @@ fn verify_hashes() -> anyhow::Result<()> {
             upgrade: Upgrade {
                 strategy: None,
                 constraints: {},
+                eager: false,
             },
             reinstall: None,
         },
@@ -7699,11 +9043,27 @@ fn verify_hashes() -> anyhow::Result<()> {
             native_tls: false,
             http_proxy: None,
             https_proxy: None,
+            all_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
             read_timeout: [TIME],
             connect_timeout: [TIME],
+            stall_timeout: [TIME],
             retries: 3,
+            retry_max_delay: [TIME],
+            allow_index_failure: false,
+            index_cache_ttl: None,
+            index_stale_if_error: false,
+            stale_metadata_report: None,
+            credential_helper: None,
+            attestation_policy: AttestationPolicy {
+                required: false,
+                trusted_publishers: {},
+            },
+            client_cert: None,
+            client_key: None,
+            limit_rate: None,
+            max_connections_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -7724,6 +9084,7 @@ fn verify_hashes() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        scratch_dir: None,
     }
     PipInstallSettings {
         package: [],
@@ -7731,11 +9092,15 @@ fn verify_hashes() -> anyhow::Result<()> {
             "requirements.in",
         ],
         editables: [],
+        for_import: [],
         constraints: [],
         overrides: [],
         excludes: [],
         build_constraints: [],
         dry_run: Disabled,
+        only_uv_managed: false,
+        verify_record: false,
+        allow_unhashed: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         excludes_from_workspace: [],
@@ -7789,6 +9154,12 @@ fn verify_hashes() -> anyhow::Result<()> {
             keyring_provider: Disabled,
             torch_backend: None,
             build_isolation: Isolate,
+            excluded_packages: [],
+            resolution_priority: [],
+            max_backtracks: None,
+            memory_limit: None,
+            protected_packages: [],
+            allow_yanked: [],
             extra_build_dependencies: ExtraBuildDependencies(
                 {},
             ),
@@ -7804,6 +9175,7 @@ fn verify_hashes() -> anyhow::Result<()> {
             dependency_mode: Transitive,
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
+            prerelease_packages: [],
             fork_strategy: RequiresPython,
             dependency_metadata: DependencyMetadata(
                 {},
@@ -7844,6 +9216,7 @@ fn verify_hashes() -> anyhow::Result<()> {
             upgrade: Upgrade {
                 strategy: None,
                 constraints: {},
+                eager: false,
             },
             reinstall: None,
         },
@@ -7873,11 +9246,27 @@ fn verify_hashes() -> anyhow::Result<()> {
             native_tls: false,
             http_proxy: None,
             https_proxy: None,
+            all_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
             read_timeout: [TIME],
             connect_timeout: [TIME],
+            stall_timeout: [TIME],
             retries: 3,
+            retry_max_delay: [TIME],
+            allow_index_failure: false,
+            index_cache_ttl: None,
+            index_stale_if_error: false,
+            stale_metadata_report: None,
+            credential_helper: None,
+            attestation_policy: AttestationPolicy {
+                required: false,
+                trusted_publishers: {},
+            },
+            client_cert: None,
+            client_key: None,
+            limit_rate: None,
+            max_connections_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -7898,6 +9287,7 @@ fn verify_hashes() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        scratch_dir: None,
     }
     PipInstallSettings {
         package: [],
@@ -7905,11 +9295,15 @@ fn verify_hashes() -> anyhow::Result<()> {
             "requirements.in",
         ],
         editables: [],
+        for_import: [],
         constraints: [],
         overrides: [],
         excludes: [],
         build_constraints: [],
         dry_run: Disabled,
+        only_uv_managed: false,
+        verify_record: false,
+        allow_unhashed: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         excludes_from_workspace: [],
@@ -7963,6 +9357,12 @@ fn verify_hashes() -> anyhow::Result<()> {
             keyring_provider: Disabled,
             torch_backend: None,
             build_isolation: Isolate,
+            excluded_packages: [],
+            resolution_priority: [],
+            max_backtracks: None,
+            memory_limit: None,
+            protected_packages: [],
+            allow_yanked: [],
             extra_build_dependencies: ExtraBuildDependencies(
                 {},
             ),
@@ -7978,6 +9378,7 @@ fn verify_hashes() -> anyhow::Result<()> {
             dependency_mode: Transitive,
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
+            prerelease_packages: [],
             fork_strategy: RequiresPython,
             dependency_metadata: DependencyMetadata(
                 {},
@@ -8020,6 +9421,7 @@ fn verify_hashes() -> anyhow::Result<()> {
             upgrade: Upgrade {
                 strategy: None,
                 constraints: {},
+                eager: false,
             },
             reinstall: None,
         },
@@ -8062,11 +9464,27 @@ fn preview_features() {
             native_tls: false,
             http_proxy: None,
             https_proxy: None,
+            all_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
             read_timeout: [TIME],
             connect_timeout: [TIME],
+            stall_timeout: [TIME],
             retries: 3,
+            retry_max_delay: [TIME],
+            allow_index_failure: false,
+            index_cache_ttl: None,
+            index_stale_if_error: false,
+            stale_metadata_report: None,
+            credential_helper: None,
+            attestation_policy: AttestationPolicy {
+                required: false,
+                trusted_publishers: {},
+            },
+            client_cert: None,
+            client_key: None,
+            limit_rate: None,
+            max_connections_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -8103,6 +9521,19 @@ fn preview_features() {
                 RelocatableEnvsDefault,
                 PublishRequireNormalized,
                 Audit,
+                WheelRetag,
+                IndexBuild,
+                Vendor,
+                CacheWarm,
+                CacheList,
+                BuildEnvironmentCache,
+                ResolutionHistory,
+                IndexPing,
+                Licenses,
+                Attestations,
+                CacheVerify,
+                CacheBundles,
+                CloudRegistryAuth,
             ],
         },
         python_preference: Managed,
@@ -8115,6 +9546,7 @@ fn preview_features() {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        scratch_dir: None,
     }
     VersionSettings {
         value: None,
@@ -8172,6 +9604,11 @@ fn preview_features() {
                 keyring_provider: Disabled,
                 link_mode: Clone,
                 build_isolation: Isolate,
+                excluded_packages: [],
+                resolution_priority: [],
+                max_backtracks: None,
+                memory_limit: None,
+                allow_yanked: [],
                 extra_build_dependencies: ExtraBuildDependencies(
                     {},
                 ),
@@ -8179,15 +9616,18 @@ fn preview_features() {
                     {},
                 ),
                 prerelease: IfNecessaryOrExplicit,
+                prerelease_packages: [],
                 resolution: Highest,
                 sources: None,
                 torch_backend: None,
                 upgrade: Upgrade {
                     strategy: None,
                     constraints: {},
+                    eager: false,
                 },
             },
             compile_bytecode: false,
+            protected_packages: [],
             reinstall: None,
         },
     }
@@ -8211,11 +9651,27 @@ fn preview_features() {
             native_tls: false,
             http_proxy: None,
             https_proxy: None,
+            all_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
             read_timeout: [TIME],
             connect_timeout: [TIME],
+            stall_timeout: [TIME],
             retries: 3,
+            retry_max_delay: [TIME],
+            allow_index_failure: false,
+            index_cache_ttl: None,
+            index_stale_if_error: false,
+            stale_metadata_report: None,
+            credential_helper: None,
+            attestation_policy: AttestationPolicy {
+                required: false,
+                trusted_publishers: {},
+            },
+            client_cert: None,
+            client_key: None,
+            limit_rate: None,
+            max_connections_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -8236,6 +9692,7 @@ fn preview_features() {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        scratch_dir: None,
     }
     VersionSettings {
         value: None,
@@ -8293,6 +9750,11 @@ fn preview_features() {
                 keyring_provider: Disabled,
                 link_mode: Clone,
                 build_isolation: Isolate,
+                excluded_packages: [],
+                resolution_priority: [],
+                max_backtracks: None,
+                memory_limit: None,
+                allow_yanked: [],
                 extra_build_dependencies: ExtraBuildDependencies(
                     {},
                 ),
@@ -8300,15 +9762,18 @@ fn preview_features() {
                     {},
                 ),
                 prerelease: IfNecessaryOrExplicit,
+                prerelease_packages: [],
                 resolution: Highest,
                 sources: None,
                 torch_backend: None,
                 upgrade: Upgrade {
                     strategy: None,
                     constraints: {},
+                    eager: false,
                 },
             },
             compile_bytecode: false,
+            protected_packages: [],
             reinstall: None,
         },
     }
@@ -8332,11 +9797,27 @@ fn preview_features() {
             native_tls: false,
             http_proxy: None,
             https_proxy: None,
+            all_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
             read_timeout: [TIME],
             connect_timeout: [TIME],
+            stall_timeout: [TIME],
             retries: 3,
+            retry_max_delay: [TIME],
+            allow_index_failure: false,
+            index_cache_ttl: None,
+            index_stale_if_error: false,
+            stale_metadata_report: None,
+            credential_helper: None,
+            attestation_policy: AttestationPolicy {
+                required: false,
+                trusted_publishers: {},
+            },
+            client_cert: None,
+            client_key: None,
+            limit_rate: None,
+            max_connections_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -8373,6 +9854,19 @@ fn preview_features() {
                 RelocatableEnvsDefault,
                 PublishRequireNormalized,
                 Audit,
+                WheelRetag,
+                IndexBuild,
+                Vendor,
+                CacheWarm,
+                CacheList,
+                BuildEnvironmentCache,
+                ResolutionHistory,
+                IndexPing,
+                Licenses,
+                Attestations,
+                CacheVerify,
+                CacheBundles,
+                CloudRegistryAuth,
             ],
         },
         python_preference: Managed,
@@ -8385,6 +9879,7 @@ fn preview_features() {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        scratch_dir: None,
     }
     VersionSettings {
         value: None,
@@ -8442,6 +9937,11 @@ fn preview_features() {
                 keyring_provider: Disabled,
                 link_mode: Clone,
                 build_isolation: Isolate,
+                excluded_packages: [],
+                resolution_priority: [],
+                max_backtracks: None,
+                memory_limit: None,
+                allow_yanked: [],
                 extra_build_dependencies: ExtraBuildDependencies(
                     {},
                 ),
@@ -8449,15 +9949,18 @@ fn preview_features() {
                     {},
                 ),
                 prerelease: IfNecessaryOrExplicit,
+                prerelease_packages: [],
                 resolution: Highest,
                 sources: None,
                 torch_backend: None,
                 upgrade: Upgrade {
                     strategy: None,
                     constraints: {},
+                    eager: false,
                 },
             },
             compile_bytecode: false,
+            protected_packages: [],
             reinstall: None,
         },
     }
@@ -8481,11 +9984,27 @@ fn preview_features() {
             native_tls: false,
             http_proxy: None,
             https_proxy: None,
+            all_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
             read_timeout: [TIME],
             connect_timeout: [TIME],
+            stall_timeout: [TIME],
             retries: 3,
+            retry_max_delay: [TIME],
+            allow_index_failure: false,
+            index_cache_ttl: None,
+            index_stale_if_error: false,
+            stale_metadata_report: None,
+            credential_helper: None,
+            attestation_policy: AttestationPolicy {
+                required: false,
+                trusted_publishers: {},
+            },
+            client_cert: None,
+            client_key: None,
+            limit_rate: None,
+            max_connections_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -8509,6 +10028,7 @@ fn preview_features() {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        scratch_dir: None,
     }
     VersionSettings {
         value: None,
@@ -8566,6 +10086,11 @@ fn preview_features() {
                 keyring_provider: Disabled,
                 link_mode: Clone,
                 build_isolation: Isolate,
+                excluded_packages: [],
+                resolution_priority: [],
+                max_backtracks: None,
+                memory_limit: None,
+                allow_yanked: [],
                 extra_build_dependencies: ExtraBuildDependencies(
                     {},
                 ),
@@ -8573,15 +10098,18 @@ fn preview_features() {
                     {},
                 ),
                 prerelease: IfNecessaryOrExplicit,
+                prerelease_packages: [],
                 resolution: Highest,
                 sources: None,
                 torch_backend: None,
                 upgrade: Upgrade {
                     strategy: None,
                     constraints: {},
+                    eager: false,
                 },
             },
             compile_bytecode: false,
+            protected_packages: [],
             reinstall: None,
         },
     }
@@ -8605,11 +10133,27 @@ fn preview_features() {
             native_tls: false,
             http_proxy: None,
             https_proxy: None,
+            all_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
             read_timeout: [TIME],
             connect_timeout: [TIME],
+            stall_timeout: [TIME],
             retries: 3,
+            retry_max_delay: [TIME],
+            allow_index_failure: false,
+            index_cache_ttl: None,
+            index_stale_if_error: false,
+            stale_metadata_report: None,
+            credential_helper: None,
+            attestation_policy: AttestationPolicy {
+                required: false,
+                trusted_publishers: {},
+            },
+            client_cert: None,
+            client_key: None,
+            limit_rate: None,
+            max_connections_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -8633,6 +10177,7 @@ fn preview_features() {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        scratch_dir: None,
     }
     VersionSettings {
         value: None,
@@ -8690,6 +10235,11 @@ fn preview_features() {
                 keyring_provider: Disabled,
                 link_mode: Clone,
                 build_isolation: Isolate,
+                excluded_packages: [],
+                resolution_priority: [],
+                max_backtracks: None,
+                memory_limit: None,
+                allow_yanked: [],
                 extra_build_dependencies: ExtraBuildDependencies(
                     {},
                 ),
@@ -8697,15 +10247,18 @@ fn preview_features() {
                     {},
                 ),
                 prerelease: IfNecessaryOrExplicit,
+                prerelease_packages: [],
                 resolution: Highest,
                 sources: None,
                 torch_backend: None,
                 upgrade: Upgrade {
                     strategy: None,
                     constraints: {},
+                    eager: false,
                 },
             },
             compile_bytecode: false,
+            protected_packages: [],
             reinstall: None,
         },
     }
@@ -8731,11 +10284,27 @@ fn preview_features() {
             native_tls: false,
             http_proxy: None,
             https_proxy: None,
+            all_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
             read_timeout: [TIME],
             connect_timeout: [TIME],
+            stall_timeout: [TIME],
             retries: 3,
+            retry_max_delay: [TIME],
+            allow_index_failure: false,
+            index_cache_ttl: None,
+            index_stale_if_error: false,
+            stale_metadata_report: None,
+            credential_helper: None,
+            attestation_policy: AttestationPolicy {
+                required: false,
+                trusted_publishers: {},
+            },
+            client_cert: None,
+            client_key: None,
+            limit_rate: None,
+            max_connections_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -8756,6 +10325,7 @@ fn preview_features() {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        scratch_dir: None,
     }
     VersionSettings {
         value: None,
@@ -8813,6 +10383,11 @@ fn preview_features() {
                 keyring_provider: Disabled,
                 link_mode: Clone,
                 build_isolation: Isolate,
+                excluded_packages: [],
+                resolution_priority: [],
+                max_backtracks: None,
+                memory_limit: None,
+                allow_yanked: [],
                 extra_build_dependencies: ExtraBuildDependencies(
                     {},
                 ),
@@ -8820,15 +10395,18 @@ fn preview_features() {
                     {},
                 ),
                 prerelease: IfNecessaryOrExplicit,
+                prerelease_packages: [],
                 resolution: Highest,
                 sources: None,
                 torch_backend: None,
                 upgrade: Upgrade {
                     strategy: None,
                     constraints: {},
+                    eager: false,
                 },
             },
             compile_bytecode: false,
+            protected_packages: [],
             reinstall: None,
         },
     }
@@ -8873,11 +10451,27 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
             native_tls: false,
             http_proxy: None,
             https_proxy: None,
+            all_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
             read_timeout: [TIME],
             connect_timeout: [TIME],
+            stall_timeout: [TIME],
             retries: 3,
+            retry_max_delay: [TIME],
+            allow_index_failure: false,
+            index_cache_ttl: None,
+            index_stale_if_error: false,
+            stale_metadata_report: None,
+            credential_helper: None,
+            attestation_policy: AttestationPolicy {
+                required: false,
+                trusted_publishers: {},
+            },
+            client_cert: None,
+            client_key: None,
+            limit_rate: None,
+            max_connections_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -8898,6 +10492,7 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        scratch_dir: None,
     }
     PipCompileSettings {
         format: None,
@@ -8915,6 +10510,8 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        minimal_update: false,
+        emit_graph: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -8963,6 +10560,12 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
             keyring_provider: Disabled,
             torch_backend: None,
             build_isolation: Isolate,
+            excluded_packages: [],
+            resolution_priority: [],
+            max_backtracks: None,
+            memory_limit: None,
+            protected_packages: [],
+            allow_yanked: [],
             extra_build_dependencies: ExtraBuildDependencies(
                 {},
             ),
@@ -8978,6 +10581,7 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
             dependency_mode: Transitive,
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
+            prerelease_packages: [],
             fork_strategy: RequiresPython,
             dependency_metadata: DependencyMetadata(
                 {},
@@ -9026,6 +10630,7 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
                     },
                 ),
                 constraints: {},
+                eager: false,
             },
             reinstall: None,
         },
@@ -9062,11 +10667,27 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
             native_tls: false,
             http_proxy: None,
             https_proxy: None,
+            all_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
             read_timeout: [TIME],
             connect_timeout: [TIME],
+            stall_timeout: [TIME],
             retries: 3,
+            retry_max_delay: [TIME],
+            allow_index_failure: false,
+            index_cache_ttl: None,
+            index_stale_if_error: false,
+            stale_metadata_report: None,
+            credential_helper: None,
+            attestation_policy: AttestationPolicy {
+                required: false,
+                trusted_publishers: {},
+            },
+            client_cert: None,
+            client_key: None,
+            limit_rate: None,
+            max_connections_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -9087,6 +10708,7 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        scratch_dir: None,
     }
     PipCompileSettings {
         format: None,
@@ -9104,6 +10726,8 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        minimal_update: false,
+        emit_graph: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -9152,6 +10776,12 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
             keyring_provider: Disabled,
             torch_backend: None,
             build_isolation: Isolate,
+            excluded_packages: [],
+            resolution_priority: [],
+            max_backtracks: None,
+            memory_limit: None,
+            protected_packages: [],
+            allow_yanked: [],
             extra_build_dependencies: ExtraBuildDependencies(
                 {},
             ),
@@ -9167,6 +10797,7 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
             dependency_mode: Transitive,
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
+            prerelease_packages: [],
             fork_strategy: RequiresPython,
             dependency_metadata: DependencyMetadata(
                 {},
@@ -9209,6 +10840,7 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
             upgrade: Upgrade {
                 strategy: None,
                 constraints: {},
+                eager: false,
             },
             reinstall: None,
         },
@@ -9245,11 +10877,27 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
             native_tls: false,
             http_proxy: None,
             https_proxy: None,
+            all_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
             read_timeout: [TIME],
             connect_timeout: [TIME],
+            stall_timeout: [TIME],
             retries: 3,
+            retry_max_delay: [TIME],
+            allow_index_failure: false,
+            index_cache_ttl: None,
+            index_stale_if_error: false,
+            stale_metadata_report: None,
+            credential_helper: None,
+            attestation_policy: AttestationPolicy {
+                required: false,
+                trusted_publishers: {},
+            },
+            client_cert: None,
+            client_key: None,
+            limit_rate: None,
+            max_connections_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -9270,6 +10918,7 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        scratch_dir: None,
     }
     PipCompileSettings {
         format: None,
@@ -9287,6 +10936,8 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        minimal_update: false,
+        emit_graph: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -9335,6 +10986,12 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
             keyring_provider: Disabled,
             torch_backend: None,
             build_isolation: Isolate,
+            excluded_packages: [],
+            resolution_priority: [],
+            max_backtracks: None,
+            memory_limit: None,
+            protected_packages: [],
+            allow_yanked: [],
             extra_build_dependencies: ExtraBuildDependencies(
                 {},
             ),
@@ -9350,6 +11007,7 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
             dependency_mode: Transitive,
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
+            prerelease_packages: [],
             fork_strategy: RequiresPython,
             dependency_metadata: DependencyMetadata(
                 {},
@@ -9392,6 +11050,7 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
             upgrade: Upgrade {
                 strategy: All,
                 constraints: {},
+                eager: false,
             },
             reinstall: None,
         },
@@ -9426,11 +11085,27 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
             native_tls: false,
             http_proxy: None,
             https_proxy: None,
+            all_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
             read_timeout: [TIME],
             connect_timeout: [TIME],
+            stall_timeout: [TIME],
             retries: 3,
+            retry_max_delay: [TIME],
+            allow_index_failure: false,
+            index_cache_ttl: None,
+            index_stale_if_error: false,
+            stale_metadata_report: None,
+            credential_helper: None,
+            attestation_policy: AttestationPolicy {
+                required: false,
+                trusted_publishers: {},
+            },
+            client_cert: None,
+            client_key: None,
+            limit_rate: None,
+            max_connections_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -9451,6 +11126,7 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        scratch_dir: None,
     }
     PipCompileSettings {
         format: None,
@@ -9468,6 +11144,8 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        minimal_update: false,
+        emit_graph: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -9516,6 +11194,12 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
             keyring_provider: Disabled,
             torch_backend: None,
             build_isolation: Isolate,
+            excluded_packages: [],
+            resolution_priority: [],
+            max_backtracks: None,
+            memory_limit: None,
+            protected_packages: [],
+            allow_yanked: [],
             extra_build_dependencies: ExtraBuildDependencies(
                 {},
             ),
@@ -9531,6 +11215,7 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
             dependency_mode: Transitive,
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
+            prerelease_packages: [],
             fork_strategy: RequiresPython,
             dependency_metadata: DependencyMetadata(
                 {},
@@ -9579,6 +11264,7 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
                     },
                 ),
                 constraints: {},
+                eager: false,
             },
             reinstall: None,
         },
@@ -9607,11 +11293,27 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
             native_tls: false,
             http_proxy: None,
             https_proxy: None,
+            all_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
             read_timeout: [TIME],
             connect_timeout: [TIME],
+            stall_timeout: [TIME],
             retries: 3,
+            retry_max_delay: [TIME],
+            allow_index_failure: false,
+            index_cache_ttl: None,
+            index_stale_if_error: false,
+            stale_metadata_report: None,
+            credential_helper: None,
+            attestation_policy: AttestationPolicy {
+                required: false,
+                trusted_publishers: {},
+            },
+            client_cert: None,
+            client_key: None,
+            limit_rate: None,
+            max_connections_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -9632,6 +11334,7 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        scratch_dir: None,
     }
     PipCompileSettings {
         format: None,
@@ -9649,6 +11352,8 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        minimal_update: false,
+        emit_graph: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -9697,6 +11402,12 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
             keyring_provider: Disabled,
             torch_backend: None,
             build_isolation: Isolate,
+            excluded_packages: [],
+            resolution_priority: [],
+            max_backtracks: None,
+            memory_limit: None,
+            protected_packages: [],
+            allow_yanked: [],
             extra_build_dependencies: ExtraBuildDependencies(
                 {},
             ),
@@ -9712,6 +11423,7 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
             dependency_mode: Transitive,
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
+            prerelease_packages: [],
             fork_strategy: RequiresPython,
             dependency_metadata: DependencyMetadata(
                 {},
@@ -9760,6 +11472,7 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
                     },
                 ),
                 constraints: {},
+                eager: false,
             },
             reinstall: None,
         },
@@ -9789,11 +11502,27 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
             native_tls: false,
             http_proxy: None,
             https_proxy: None,
+            all_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
             read_timeout: [TIME],
             connect_timeout: [TIME],
+            stall_timeout: [TIME],
             retries: 3,
+            retry_max_delay: [TIME],
+            allow_index_failure: false,
+            index_cache_ttl: None,
+            index_stale_if_error: false,
+            stale_metadata_report: None,
+            credential_helper: None,
+            attestation_policy: AttestationPolicy {
+                required: false,
+                trusted_publishers: {},
+            },
+            client_cert: None,
+            client_key: None,
+            limit_rate: None,
+            max_connections_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -9814,6 +11543,7 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        scratch_dir: None,
     }
     PipCompileSettings {
         format: None,
@@ -9831,6 +11561,8 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        minimal_update: false,
+        emit_graph: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -9879,6 +11611,12 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
             keyring_provider: Disabled,
             torch_backend: None,
             build_isolation: Isolate,
+            excluded_packages: [],
+            resolution_priority: [],
+            max_backtracks: None,
+            memory_limit: None,
+            protected_packages: [],
+            allow_yanked: [],
             extra_build_dependencies: ExtraBuildDependencies(
                 {},
             ),
@@ -9894,6 +11632,7 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
             dependency_mode: Transitive,
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
+            prerelease_packages: [],
             fork_strategy: RequiresPython,
             dependency_metadata: DependencyMetadata(
                 {},
@@ -9945,6 +11684,7 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
                     },
                 ),
                 constraints: {},
+                eager: false,
             },
             reinstall: None,
         },
@@ -9996,11 +11736,27 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
             native_tls: false,
             http_proxy: None,
             https_proxy: None,
+            all_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
             read_timeout: [TIME],
             connect_timeout: [TIME],
+            stall_timeout: [TIME],
             retries: 3,
+            retry_max_delay: [TIME],
+            allow_index_failure: false,
+            index_cache_ttl: None,
+            index_stale_if_error: false,
+            stale_metadata_report: None,
+            credential_helper: None,
+            attestation_policy: AttestationPolicy {
+                required: false,
+                trusted_publishers: {},
+            },
+            client_cert: None,
+            client_key: None,
+            limit_rate: None,
+            max_connections_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -10021,11 +11777,15 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        scratch_dir: None,
     }
     LockSettings {
         lock_check: Disabled,
         frozen: None,
         dry_run: Disabled,
+        minimal_update: false,
+        diff: None,
+        emit_graph: None,
         script: None,
         python: None,
         install_mirrors: PythonInstallMirrors {
@@ -10071,6 +11831,11 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
             keyring_provider: Disabled,
             link_mode: Clone,
             build_isolation: Isolate,
+            excluded_packages: [],
+            resolution_priority: [],
+            max_backtracks: None,
+            memory_limit: None,
+            allow_yanked: [],
             extra_build_dependencies: ExtraBuildDependencies(
                 {},
             ),
@@ -10078,6 +11843,7 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
                 {},
             ),
             prerelease: IfNecessaryOrExplicit,
+            prerelease_packages: [],
             resolution: Highest,
             sources: None,
             torch_backend: None,
@@ -10090,6 +11856,7 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
                     },
                 ),
                 constraints: {},
+                eager: false,
             },
         },
     }
@@ -10128,11 +11895,27 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
             native_tls: false,
             http_proxy: None,
             https_proxy: None,
+            all_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
             read_timeout: [TIME],
             connect_timeout: [TIME],
+            stall_timeout: [TIME],
             retries: 3,
+            retry_max_delay: [TIME],
+            allow_index_failure: false,
+            index_cache_ttl: None,
+            index_stale_if_error: false,
+            stale_metadata_report: None,
+            credential_helper: None,
+            attestation_policy: AttestationPolicy {
+                required: false,
+                trusted_publishers: {},
+            },
+            client_cert: None,
+            client_key: None,
+            limit_rate: None,
+            max_connections_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -10153,11 +11936,15 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        scratch_dir: None,
     }
     LockSettings {
         lock_check: Disabled,
         frozen: None,
         dry_run: Disabled,
+        minimal_update: false,
+        diff: None,
+        emit_graph: None,
         script: None,
         python: None,
         install_mirrors: PythonInstallMirrors {
@@ -10203,6 +11990,11 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
             keyring_provider: Disabled,
             link_mode: Clone,
             build_isolation: Isolate,
+            excluded_packages: [],
+            resolution_priority: [],
+            max_backtracks: None,
+            memory_limit: None,
+            allow_yanked: [],
             extra_build_dependencies: ExtraBuildDependencies(
                 {},
             ),
@@ -10210,12 +12002,14 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
                 {},
             ),
             prerelease: IfNecessaryOrExplicit,
+            prerelease_packages: [],
             resolution: Highest,
             sources: None,
             torch_backend: None,
             upgrade: Upgrade {
                 strategy: None,
                 constraints: {},
+                eager: false,
             },
         },
     }
@@ -10254,11 +12048,27 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
             native_tls: false,
             http_proxy: None,
             https_proxy: None,
+            all_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
             read_timeout: [TIME],
             connect_timeout: [TIME],
+            stall_timeout: [TIME],
             retries: 3,
+            retry_max_delay: [TIME],
+            allow_index_failure: false,
+            index_cache_ttl: None,
+            index_stale_if_error: false,
+            stale_metadata_report: None,
+            credential_helper: None,
+            attestation_policy: AttestationPolicy {
+                required: false,
+                trusted_publishers: {},
+            },
+            client_cert: None,
+            client_key: None,
+            limit_rate: None,
+            max_connections_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -10279,11 +12089,15 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        scratch_dir: None,
     }
     LockSettings {
         lock_check: Disabled,
         frozen: None,
         dry_run: Disabled,
+        minimal_update: false,
+        diff: None,
+        emit_graph: None,
         script: None,
         python: None,
         install_mirrors: PythonInstallMirrors {
@@ -10329,6 +12143,11 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
             keyring_provider: Disabled,
             link_mode: Clone,
             build_isolation: Isolate,
+            excluded_packages: [],
+            resolution_priority: [],
+            max_backtracks: None,
+            memory_limit: None,
+            allow_yanked: [],
             extra_build_dependencies: ExtraBuildDependencies(
                 {},
             ),
@@ -10336,12 +12155,14 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
                 {},
             ),
             prerelease: IfNecessaryOrExplicit,
+            prerelease_packages: [],
             resolution: Highest,
             sources: None,
             torch_backend: None,
             upgrade: Upgrade {
                 strategy: All,
                 constraints: {},
+                eager: false,
             },
         },
     }
@@ -10378,11 +12199,27 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
             native_tls: false,
             http_proxy: None,
             https_proxy: None,
+            all_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
             read_timeout: [TIME],
             connect_timeout: [TIME],
+            stall_timeout: [TIME],
             retries: 3,
+            retry_max_delay: [TIME],
+            allow_index_failure: false,
+            index_cache_ttl: None,
+            index_stale_if_error: false,
+            stale_metadata_report: None,
+            credential_helper: None,
+            attestation_policy: AttestationPolicy {
+                required: false,
+                trusted_publishers: {},
+            },
+            client_cert: None,
+            client_key: None,
+            limit_rate: None,
+            max_connections_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -10403,11 +12240,15 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        scratch_dir: None,
     }
     LockSettings {
         lock_check: Disabled,
         frozen: None,
         dry_run: Disabled,
+        minimal_update: false,
+        diff: None,
+        emit_graph: None,
         script: None,
         python: None,
         install_mirrors: PythonInstallMirrors {
@@ -10453,6 +12294,11 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
             keyring_provider: Disabled,
             link_mode: Clone,
             build_isolation: Isolate,
+            excluded_packages: [],
+            resolution_priority: [],
+            max_backtracks: None,
+            memory_limit: None,
+            allow_yanked: [],
             extra_build_dependencies: ExtraBuildDependencies(
                 {},
             ),
@@ -10460,6 +12306,7 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
                 {},
             ),
             prerelease: IfNecessaryOrExplicit,
+            prerelease_packages: [],
             resolution: Highest,
             sources: None,
             torch_backend: None,
@@ -10472,6 +12319,7 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
                     },
                 ),
                 constraints: {},
+                eager: false,
             },
         },
     }
@@ -10498,11 +12346,27 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
             native_tls: false,
             http_proxy: None,
             https_proxy: None,
+            all_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
             read_timeout: [TIME],
             connect_timeout: [TIME],
+            stall_timeout: [TIME],
             retries: 3,
+            retry_max_delay: [TIME],
+            allow_index_failure: false,
+            index_cache_ttl: None,
+            index_stale_if_error: false,
+            stale_metadata_report: None,
+            credential_helper: None,
+            attestation_policy: AttestationPolicy {
+                required: false,
+                trusted_publishers: {},
+            },
+            client_cert: None,
+            client_key: None,
+            limit_rate: None,
+            max_connections_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -10523,11 +12387,15 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        scratch_dir: None,
     }
     LockSettings {
         lock_check: Disabled,
         frozen: None,
         dry_run: Disabled,
+        minimal_update: false,
+        diff: None,
+        emit_graph: None,
         script: None,
         python: None,
         install_mirrors: PythonInstallMirrors {
@@ -10573,6 +12441,11 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
             keyring_provider: Disabled,
             link_mode: Clone,
             build_isolation: Isolate,
+            excluded_packages: [],
+            resolution_priority: [],
+            max_backtracks: None,
+            memory_limit: None,
+            allow_yanked: [],
             extra_build_dependencies: ExtraBuildDependencies(
                 {},
             ),
@@ -10580,6 +12453,7 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
                 {},
             ),
             prerelease: IfNecessaryOrExplicit,
+            prerelease_packages: [],
             resolution: Highest,
             sources: None,
             torch_backend: None,
@@ -10592,6 +12466,7 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
                     },
                 ),
                 constraints: {},
+                eager: false,
             },
         },
     }
@@ -10619,11 +12494,27 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
             native_tls: false,
             http_proxy: None,
             https_proxy: None,
+            all_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
             read_timeout: [TIME],
             connect_timeout: [TIME],
+            stall_timeout: [TIME],
             retries: 3,
+            retry_max_delay: [TIME],
+            allow_index_failure: false,
+            index_cache_ttl: None,
+            index_stale_if_error: false,
+            stale_metadata_report: None,
+            credential_helper: None,
+            attestation_policy: AttestationPolicy {
+                required: false,
+                trusted_publishers: {},
+            },
+            client_cert: None,
+            client_key: None,
+            limit_rate: None,
+            max_connections_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -10644,11 +12535,15 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        scratch_dir: None,
     }
     LockSettings {
         lock_check: Disabled,
         frozen: None,
         dry_run: Disabled,
+        minimal_update: false,
+        diff: None,
+        emit_graph: None,
         script: None,
         python: None,
         install_mirrors: PythonInstallMirrors {
@@ -10694,6 +12589,11 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
             keyring_provider: Disabled,
             link_mode: Clone,
             build_isolation: Isolate,
+            excluded_packages: [],
+            resolution_priority: [],
+            max_backtracks: None,
+            memory_limit: None,
+            allow_yanked: [],
             extra_build_dependencies: ExtraBuildDependencies(
                 {},
             ),
@@ -10701,6 +12601,7 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
                 {},
             ),
             prerelease: IfNecessaryOrExplicit,
+            prerelease_packages: [],
             resolution: Highest,
             sources: None,
             torch_backend: None,
@@ -10716,6 +12617,7 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
                     },
                 ),
                 constraints: {},
+                eager: false,
             },
         },
     }
@@ -10764,11 +12666,27 @@ fn build_isolation_override() -> anyhow::Result<()> {
             native_tls: false,
             http_proxy: None,
             https_proxy: None,
+            all_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
             read_timeout: [TIME],
             connect_timeout: [TIME],
+            stall_timeout: [TIME],
             retries: 3,
+            retry_max_delay: [TIME],
+            allow_index_failure: false,
+            index_cache_ttl: None,
+            index_stale_if_error: false,
+            stale_metadata_report: None,
+            credential_helper: None,
+            attestation_policy: AttestationPolicy {
+                required: false,
+                trusted_publishers: {},
+            },
+            client_cert: None,
+            client_key: None,
+            limit_rate: None,
+            max_connections_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -10789,6 +12707,7 @@ fn build_isolation_override() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        scratch_dir: None,
     }
     PipCompileSettings {
         format: None,
@@ -10806,6 +12725,8 @@ fn build_isolation_override() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        minimal_update: false,
+        emit_graph: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -10854,6 +12775,12 @@ fn build_isolation_override() -> anyhow::Result<()> {
             keyring_provider: Disabled,
             torch_backend: None,
             build_isolation: Shared,
+            excluded_packages: [],
+            resolution_priority: [],
+            max_backtracks: None,
+            memory_limit: None,
+            protected_packages: [],
+            allow_yanked: [],
             extra_build_dependencies: ExtraBuildDependencies(
                 {},
             ),
@@ -10869,6 +12796,7 @@ fn build_isolation_override() -> anyhow::Result<()> {
             dependency_mode: Transitive,
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
+            prerelease_packages: [],
             fork_strategy: RequiresPython,
             dependency_metadata: DependencyMetadata(
                 {},
@@ -10911,6 +12839,7 @@ fn build_isolation_override() -> anyhow::Result<()> {
             upgrade: Upgrade {
                 strategy: None,
                 constraints: {},
+                eager: false,
             },
             reinstall: None,
         },
@@ -10942,11 +12871,27 @@ fn build_isolation_override() -> anyhow::Result<()> {
             native_tls: false,
             http_proxy: None,
             https_proxy: None,
+            all_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
             read_timeout: [TIME],
             connect_timeout: [TIME],
+            stall_timeout: [TIME],
             retries: 3,
+            retry_max_delay: [TIME],
+            allow_index_failure: false,
+            index_cache_ttl: None,
+            index_stale_if_error: false,
+            stale_metadata_report: None,
+            credential_helper: None,
+            attestation_policy: AttestationPolicy {
+                required: false,
+                trusted_publishers: {},
+            },
+            client_cert: None,
+            client_key: None,
+            limit_rate: None,
+            max_connections_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -10967,6 +12912,7 @@ fn build_isolation_override() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        scratch_dir: None,
     }
     PipCompileSettings {
         format: None,
@@ -10984,6 +12930,8 @@ fn build_isolation_override() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        minimal_update: false,
+        emit_graph: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -11038,6 +12986,12 @@ fn build_isolation_override() -> anyhow::Result<()> {
                     ),
                 ],
             ),
+            excluded_packages: [],
+            resolution_priority: [],
+            max_backtracks: None,
+            memory_limit: None,
+            protected_packages: [],
+            allow_yanked: [],
             extra_build_dependencies: ExtraBuildDependencies(
                 {},
             ),
@@ -11053,6 +13007,7 @@ fn build_isolation_override() -> anyhow::Result<()> {
             dependency_mode: Transitive,
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
+            prerelease_packages: [],
             fork_strategy: RequiresPython,
             dependency_metadata: DependencyMetadata(
                 {},
@@ -11095,6 +13050,7 @@ fn build_isolation_override() -> anyhow::Result<()> {
             upgrade: Upgrade {
                 strategy: None,
                 constraints: {},
+                eager: false,
             },
             reinstall: None,
         },
@@ -14288,6 +14288,155 @@ fn sync_extra_build_variables() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn sync_extra_build_variables_cli() -> Result<()> {
+    let context = uv_test::test_context!("3.12").with_filtered_counts();
+
+    // Create a build backend that asserts that `EXPECTED_ANYIO_VERSION` matches the installed version of `anyio`.
+    let build_backend = context.temp_dir.child("build_backend.py");
+    build_backend.write_str(indoc! {r#"
+        import os
+        import sys
+        from hatchling.build import *
+
+        expected_version = os.environ.get("EXPECTED_ANYIO_VERSION", "")
+        if not expected_version:
+            print("`EXPECTED_ANYIO_VERSION` not set", file=sys.stderr)
+            sys.exit(1)
+
+        from importlib.metadata import version
+        anyio_version = version("anyio")
+
+        if not anyio_version.startswith(expected_version):
+            print(f"Expected `anyio` version {expected_version} but got {anyio_version}", file=sys.stderr)
+            sys.exit(1)
+    "#})?;
+
+    // Set the variable in TOML (to an incorrect value).
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "parent"
+        version = "0.1.0"
+        requires-python = ">=3.9"
+
+        [build-system]
+        requires = ["hatchling", "anyio"]
+        backend-path = ["."]
+        build-backend = "build_backend"
+
+        [tool.uv.extra-build-variables]
+        parent = { EXPECTED_ANYIO_VERSION = "3.0" }
+    "#})?;
+    context.temp_dir.child("src/parent/__init__.py").touch()?;
+
+    // A variable for a different package should not be applied.
+    uv_snapshot!(context.filters(), context.sync()
+        .arg("--extra-build-variable")
+        .arg("anyio:EXPECTED_ANYIO_VERSION=4.3.0"), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved [N] packages in [TIME]
+      × Failed to build `parent @ file://[TEMP_DIR]/`
+      ├─▶ The build backend returned an error
+      ╰─▶ Call to `build_backend.build_editable` failed (exit status: 1)
+
+          [stderr]
+          Expected `anyio` version 3.0 but got 4.3.0
+
+          hint: This usually indicates a problem with the package or the build environment.
+    ");
+
+    // The command-line value should take precedence over the value in TOML.
+    uv_snapshot!(context.filters(), context.sync()
+        .arg("--build-env")
+        .arg("parent:EXPECTED_ANYIO_VERSION=4.3.0"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved [N] packages in [TIME]
+    Prepared [N] packages in [TIME]
+    Installed [N] packages in [TIME]
+     + parent==0.1.0 (from file://[TEMP_DIR]/)
+    ");
+
+    // Malformed entries should be rejected.
+    uv_snapshot!(context.filters(), context.sync()
+        .arg("--extra-build-variable")
+        .arg("EXPECTED_ANYIO_VERSION=4.3.0"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: invalid value 'EXPECTED_ANYIO_VERSION=4.3.0' for '--extra-build-variable <EXTRA_BUILD_VARIABLE>': Invalid build variable: EXPECTED_ANYIO_VERSION=4.3.0 (expected `PACKAGE:VAR=VALUE`)
+
+    For more information, try '--help'.
+    ");
+
+    Ok(())
+}
+
+#[test]
+fn sync_scratch_dir() -> Result<()> {
+    let context = uv_test::test_context!("3.12").with_filtered_counts();
+    let scratch_dir = context.temp_dir.child("scratch");
+
+    // Create a build backend that asserts that temporary files are created in the scratch
+    // directory.
+    let build_backend = context.temp_dir.child("build_backend.py");
+    build_backend.write_str(&formatdoc! {r#"
+        import sys
+        import tempfile
+        from pathlib import Path
+        from hatchling.build import *
+
+        scratch_dir = Path(r"{}").resolve()
+        temp_dir = Path(tempfile.gettempdir()).resolve()
+        if scratch_dir not in temp_dir.parents:
+            print(f"Expected a temporary directory in {{scratch_dir}} but got {{temp_dir}}", file=sys.stderr)
+            sys.exit(1)
+        "#,
+        scratch_dir.path().display(),
+    })?;
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "parent"
+        version = "0.1.0"
+        requires-python = ">=3.9"
+
+        [build-system]
+        requires = ["hatchling"]
+        backend-path = ["."]
+        build-backend = "build_backend"
+    "#})?;
+    context.temp_dir.child("src/parent/__init__.py").touch()?;
+
+    uv_snapshot!(context.filters(), context.sync().arg("--scratch-dir").arg(scratch_dir.path()), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved [N] packages in [TIME]
+    Prepared [N] packages in [TIME]
+    Installed [N] packages in [TIME]
+     + parent==0.1.0 (from file://[TEMP_DIR]/)
+    ");
+
+    // The scratch space is removed once the build completes.
+    assert_eq!(fs_err::read_dir(scratch_dir.path())?.count(), 0);
+
+    Ok(())
+}
+
 #[test]
 fn reject_unmatched_runtime() -> Result<()> {
     let context = uv_test::test_context!("3.12").with_exclude_newer("2025-01-01T00:00Z");
@@ -15598,6 +15747,93 @@ fn workspace_editable_conflict() -> Result<()> {
     Ok(())
 }
 
+/// Editability is a property of a workspace member, so marker-specific sources for the same member
+/// must agree on the `editable` value.
+#[test]
+fn workspace_editable_marker() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let child1 = context.temp_dir.child("child1");
+    let pyproject_toml = child1.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "child1"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig>=1"]
+
+        [build-system]
+        requires = ["hatchling"]
+        build-backend = "hatchling.build"
+        "#,
+    )?;
+    child1
+        .child("src")
+        .child("child1")
+        .child("__init__.py")
+        .touch()?;
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["child1"]
+
+        [tool.uv.workspace]
+        members = ["child1"]
+
+        [tool.uv.sources]
+        child1 = [
+            { workspace = true, editable = false, marker = "sys_platform == 'linux'" },
+            { workspace = true, marker = "sys_platform != 'linux'" },
+        ]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Workspace member `child1` has sources with different `editable` values under different markers, but editability can't vary by platform
+    ");
+
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["child1"]
+
+        [tool.uv.workspace]
+        members = ["child1"]
+
+        [tool.uv.sources]
+        child1 = [
+            { workspace = true, editable = false, marker = "sys_platform == 'linux'" },
+            { workspace = true, editable = false, marker = "sys_platform != 'linux'" },
+        ]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    ");
+
+    Ok(())
+}
+
 #[test]
 fn only_group_and_extra_conflict() -> Result<()> {
     let context = uv_test::test_context!("3.12");
@@ -4725,7 +4725,7 @@ fn tool_install_find_links() {
       ╰─▶ Because only basic-app==0.1 is available and basic-app==0.1 needs to be downloaded from a registry, we can conclude that all versions of basic-app cannot be used.
           And because you require basic-app, we can conclude that your requirements are unsatisfiable.
 
-          hint: Packages were unavailable because the network was disabled. When the network is disabled, registry packages may only be read from the cache.
+          hint: Packages were unavailable because the network was disabled. When the network is disabled, registry packages may only be read from the cache (pre-populate the `simple-v22` and `wheels-v8` cache buckets while online).
     ");
 }
 
@@ -5,7 +5,8 @@ use indoc::{formatdoc, indoc};
 use insta::assert_snapshot;
 use url::Url;
 
-use uv_test::uv_snapshot;
+use uv_static::EnvVars;
+use uv_test::{packse_index_url, uv_snapshot};
 
 #[test]
 fn nested_dependencies() -> Result<()> {
@@ -271,6 +272,44 @@ fn outdated() -> Result<()> {
     Ok(())
 }
 
+/// Packages that only publish pre-releases are compared against their latest pre-release.
+#[test]
+fn outdated_prerelease() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let mut filters = context.filters();
+    filters.push((r"package-only-prereleases-boundary-", "package-"));
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["package-only-prereleases-boundary-a<0.2.0"]
+    "#,
+    )?;
+
+    uv_snapshot!(filters, context.tree()
+        .arg("--outdated")
+        .arg("--index-url")
+        .arg(packse_index_url())
+        .env_remove(EnvVars::UV_EXCLUDE_NEWER), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    project v0.1.0
+    └── package-a v0.1.0a1 (latest: v0.3.0a1)
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    "
+    );
+
+    Ok(())
+}
+
 #[test]
 fn platform_dependencies() -> Result<()> {
     let context = uv_test::test_context!("3.12");
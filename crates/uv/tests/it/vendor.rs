@@ -0,0 +1,197 @@
+use anyhow::Result;
+use assert_cmd::assert::OutputAssertExt;
+use assert_fs::prelude::*;
+use insta::assert_snapshot;
+
+use uv_test::uv_snapshot;
+
+/// Vendor the locked dependencies of a project, then install them without an index.
+#[test]
+fn vendor() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==3.7.0"]
+
+        [build-system]
+        requires = ["uv_build>=0.7,<10000"]
+        build-backend = "uv_build"
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.vendor().arg("--preview-features").arg("vendor"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    Vendored 3 packages in [TIME]
+     + anyio-3.7.0-py3-none-any.whl
+     + idna-3.6-py3-none-any.whl
+     + sniffio-1.3.1-py3-none-any.whl
+    "
+    );
+
+    let vendor = context.temp_dir.child("vendor");
+    assert_snapshot!(fs_err::read_to_string(vendor.child("requirements.txt"))?, @"
+    # This file was autogenerated by uv via the following command:
+    #    uv vendor --cache-dir [CACHE_DIR] --preview-features vendor
+    --no-index
+    anyio @ file:./vendor/anyio-3.7.0-py3-none-any.whl \
+        --hash=sha256:eddca883c4175f14df8aedce21054bfca3adb70ffe76a9f607aef9d7fa2ea7f0
+    idna @ file:./vendor/idna-3.6-py3-none-any.whl \
+        --hash=sha256:c05567e9c24a6b9faaa835c4821bad0590fbb9d5779e7caa6e1cc4978e7eb24f
+    sniffio @ file:./vendor/sniffio-1.3.1-py3-none-any.whl \
+        --hash=sha256:2f6da418d1f1e0fddd844478f41680e794e6051915791a034ff65e5f100525a2
+    "
+    );
+
+    // The project itself should not be vendored.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("--offline")
+        .arg("-r")
+        .arg("vendor/requirements.txt"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Prepared 3 packages in [TIME]
+    Installed 3 packages in [TIME]
+     + anyio==3.7.0 (from file://[TEMP_DIR]/vendor/anyio-3.7.0-py3-none-any.whl)
+     + idna==3.6 (from file://[TEMP_DIR]/vendor/idna-3.6-py3-none-any.whl)
+     + sniffio==1.3.1 (from file://[TEMP_DIR]/vendor/sniffio-1.3.1-py3-none-any.whl)
+    "
+    );
+
+    Ok(())
+}
+
+/// Vendor into a custom directory, writing the requirements file elsewhere.
+#[test]
+fn vendor_dir_output_file() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig==2.0.0"]
+        "#,
+    )?;
+
+    context.lock().assert().success();
+
+    uv_snapshot!(context.filters(), context.vendor()
+        .arg("--preview-features")
+        .arg("vendor")
+        .arg("--vendor-dir")
+        .arg("third_party/wheels")
+        .arg("--output-file")
+        .arg("requirements-vendor.txt")
+        .arg("--no-header"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Vendored 1 package in [TIME]
+     + iniconfig-2.0.0-py3-none-any.whl
+    "
+    );
+
+    context
+        .temp_dir
+        .child("third_party/wheels/iniconfig-2.0.0-py3-none-any.whl")
+        .assert(predicates::path::is_file());
+
+    assert_snapshot!(fs_err::read_to_string(context.temp_dir.child("requirements-vendor.txt"))?, @"
+    --no-index
+    iniconfig @ file:./third_party/wheels/iniconfig-2.0.0-py3-none-any.whl \
+        --hash=sha256:b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374
+    "
+    );
+
+    Ok(())
+}
+
+/// Local directory dependencies can only be vendored with `--build-wheels`.
+#[test]
+fn vendor_directory_dependency() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["child"]
+
+        [tool.uv.sources]
+        child = { path = "child" }
+        "#,
+    )?;
+
+    let child = context.temp_dir.child("child");
+    child.child("pyproject.toml").write_str(
+        r#"
+        [project]
+        name = "child"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig==2.0.0"]
+
+        [build-system]
+        requires = ["uv_build>=0.7,<10000"]
+        build-backend = "uv_build"
+        "#,
+    )?;
+    child
+        .child("src")
+        .child("child")
+        .child("__init__.py")
+        .touch()?;
+
+    uv_snapshot!(context.filters(), context.vendor().arg("--preview-features").arg("vendor"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    error: Unable to vendor `child` without building, since they're not available as a wheel or source distribution archive; use `--build-wheels` to vendor them as wheels
+    "
+    );
+
+    uv_snapshot!(context.filters(), context.vendor()
+        .arg("--preview-features")
+        .arg("vendor")
+        .arg("--build-wheels"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Vendored 2 packages in [TIME]
+     + child-0.1.0-py3-none-any.whl
+     + iniconfig-2.0.0-py3-none-any.whl
+    "
+    );
+
+    Ok(())
+}